@@ -0,0 +1,46 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::sync::LazyLock;
+
+use nautilus_model::identifiers::Venue;
+
+pub const BINANCE: &str = "BINANCE";
+pub static BINANCE_VENUE: LazyLock<Venue> = LazyLock::new(|| Venue::new(BINANCE));
+
+/// The symbol suffix used to distinguish perpetual futures from spot instruments.
+pub const BINANCE_PERP_SUFFIX: &str = "-PERP";
+
+pub const BINANCE_SPOT_HTTP_URL: &str = "https://api.binance.com";
+pub const BINANCE_SPOT_WS_URL: &str = "wss://stream.binance.com:9443";
+pub const BINANCE_SPOT_TESTNET_HTTP_URL: &str = "https://testnet.binance.vision";
+pub const BINANCE_SPOT_TESTNET_WS_URL: &str = "wss://testnet.binance.vision";
+
+pub const BINANCE_USDM_HTTP_URL: &str = "https://fapi.binance.com";
+pub const BINANCE_USDM_WS_URL: &str = "wss://fstream.binance.com";
+pub const BINANCE_USDM_TESTNET_HTTP_URL: &str = "https://testnet.binancefuture.com";
+pub const BINANCE_USDM_TESTNET_WS_URL: &str = "wss://stream.binancefuture.com";
+
+pub const BINANCE_COINM_HTTP_URL: &str = "https://dapi.binance.com";
+pub const BINANCE_COINM_WS_URL: &str = "wss://dstream.binance.com";
+pub const BINANCE_COINM_TESTNET_HTTP_URL: &str = "https://testnet.binancefuture.com";
+pub const BINANCE_COINM_TESTNET_WS_URL: &str = "wss://dstream.binancefuture.com";
+
+/// The default receive window (milliseconds) for signed requests.
+pub const BINANCE_RECV_WINDOW_MS: u64 = 5_000;
+
+/// The interval at which a user data stream listen key must be kept alive (listen keys
+/// expire after 60 minutes without a keepalive).
+pub const BINANCE_LISTEN_KEY_KEEPALIVE_SECS: u64 = 30 * 60;
@@ -0,0 +1,103 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::credentials::{resolve_api_key, Secret};
+use nautilus_cryptography::signing::hmac_signature;
+
+/// The API key and secret used to authenticate and sign Binance requests.
+///
+/// Signed (`TRADE` and `USER_DATA`) endpoints require an HMAC-SHA256 signature of the
+/// full query string, hex encoded, see <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/request-security>.
+#[derive(Clone, Debug)]
+pub struct BinanceCredential {
+    api_key: Secret,
+    api_secret: Secret,
+}
+
+impl BinanceCredential {
+    /// Creates a new [`BinanceCredential`] instance.
+    #[must_use]
+    pub const fn new(api_key: Secret, api_secret: Secret) -> Self {
+        Self {
+            api_key,
+            api_secret,
+        }
+    }
+
+    /// Resolves the credential from the given values if provided, otherwise from the
+    /// `BINANCE_API_KEY` and `BINANCE_API_SECRET` environment variables.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if either value is neither provided nor set in the environment.
+    pub fn resolve(api_key: Option<&str>, api_secret: Option<&str>) -> anyhow::Result<Self> {
+        Ok(Self::new(
+            resolve_api_key(api_key, "BINANCE_API_KEY")?,
+            resolve_api_key(api_secret, "BINANCE_API_SECRET")?,
+        ))
+    }
+
+    /// Returns the API key, sent in the `X-MBX-APIKEY` header.
+    #[must_use]
+    pub const fn api_key(&self) -> &Secret {
+        &self.api_key
+    }
+
+    /// Returns the hex encoded HMAC-SHA256 signature of the given `query` string.
+    #[must_use]
+    pub fn sign(&self, query: &str) -> String {
+        hmac_signature(self.api_secret.expose(), query)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_sign_matches_binance_example() {
+        // Example from the Binance API documentation
+        let credential = BinanceCredential::new(
+            Secret::new("vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A")
+                .unwrap(),
+            Secret::new("NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j")
+                .unwrap(),
+        );
+        let query = "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&recvWindow=5000&timestamp=1499827319559";
+
+        assert_eq!(
+            credential.sign(query),
+            "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+        );
+    }
+
+    #[rstest]
+    fn test_debug_redacts_secrets() {
+        let credential = BinanceCredential::new(
+            Secret::new("my-api-key-value").unwrap(),
+            Secret::new("my-api-secret-value").unwrap(),
+        );
+
+        let debug = format!("{credential:?}");
+
+        assert!(!debug.contains("my-api-key-value"));
+        assert!(!debug.contains("my-api-secret-value"));
+    }
+}
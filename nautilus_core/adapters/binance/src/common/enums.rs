@@ -0,0 +1,209 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::enums::{OrderSide, OrderSideSpecified, OrderStatus};
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumString};
+
+use super::consts::{
+    BINANCE_COINM_HTTP_URL, BINANCE_COINM_TESTNET_HTTP_URL, BINANCE_COINM_TESTNET_WS_URL,
+    BINANCE_COINM_WS_URL, BINANCE_SPOT_HTTP_URL, BINANCE_SPOT_TESTNET_HTTP_URL,
+    BINANCE_SPOT_TESTNET_WS_URL, BINANCE_SPOT_WS_URL, BINANCE_USDM_HTTP_URL,
+    BINANCE_USDM_TESTNET_HTTP_URL, BINANCE_USDM_TESTNET_WS_URL, BINANCE_USDM_WS_URL,
+};
+
+/// The Binance product (API family) a client is connected to.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Display, AsRefStr, EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceProductType {
+    /// Spot trading (`/api/v3`).
+    Spot,
+    /// USD-M (USDT and USDC margined) futures (`/fapi/v1`).
+    UsdM,
+    /// COIN-M (coin margined) futures (`/dapi/v1`).
+    CoinM,
+}
+
+impl BinanceProductType {
+    /// Returns whether the product type is a futures product.
+    #[must_use]
+    pub const fn is_futures(&self) -> bool {
+        matches!(self, Self::UsdM | Self::CoinM)
+    }
+
+    /// Returns the REST API base URL for the product type.
+    #[must_use]
+    pub const fn http_url(&self, is_testnet: bool) -> &'static str {
+        match (self, is_testnet) {
+            (Self::Spot, false) => BINANCE_SPOT_HTTP_URL,
+            (Self::Spot, true) => BINANCE_SPOT_TESTNET_HTTP_URL,
+            (Self::UsdM, false) => BINANCE_USDM_HTTP_URL,
+            (Self::UsdM, true) => BINANCE_USDM_TESTNET_HTTP_URL,
+            (Self::CoinM, false) => BINANCE_COINM_HTTP_URL,
+            (Self::CoinM, true) => BINANCE_COINM_TESTNET_HTTP_URL,
+        }
+    }
+
+    /// Returns the WebSocket base URL for the product type.
+    #[must_use]
+    pub const fn ws_url(&self, is_testnet: bool) -> &'static str {
+        match (self, is_testnet) {
+            (Self::Spot, false) => BINANCE_SPOT_WS_URL,
+            (Self::Spot, true) => BINANCE_SPOT_TESTNET_WS_URL,
+            (Self::UsdM, false) => BINANCE_USDM_WS_URL,
+            (Self::UsdM, true) => BINANCE_USDM_TESTNET_WS_URL,
+            (Self::CoinM, false) => BINANCE_COINM_WS_URL,
+            (Self::CoinM, true) => BINANCE_COINM_TESTNET_WS_URL,
+        }
+    }
+
+    /// Returns the versioned REST API path prefix for the product type.
+    #[must_use]
+    pub const fn api_path(&self) -> &'static str {
+        match self {
+            Self::Spot => "/api/v3",
+            Self::UsdM => "/fapi/v1",
+            Self::CoinM => "/dapi/v1",
+        }
+    }
+
+    /// Returns the REST path used to manage user data stream listen keys.
+    #[must_use]
+    pub const fn listen_key_path(&self) -> &'static str {
+        match self {
+            Self::Spot => "/api/v3/userDataStream",
+            Self::UsdM => "/fapi/v1/listenKey",
+            Self::CoinM => "/dapi/v1/listenKey",
+        }
+    }
+
+    /// Returns the request weight limit per minute for the product type.
+    #[must_use]
+    pub const fn request_weight_per_minute(&self) -> u32 {
+        match self {
+            Self::Spot => 6_000,
+            Self::UsdM | Self::CoinM => 2_400,
+        }
+    }
+}
+
+/// The side of a Binance order.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Display, AsRefStr, EnumString,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceOrderSide {
+    Buy,
+    Sell,
+}
+
+impl From<OrderSideSpecified> for BinanceOrderSide {
+    fn from(value: OrderSideSpecified) -> Self {
+        match value {
+            OrderSideSpecified::Buy => Self::Buy,
+            OrderSideSpecified::Sell => Self::Sell,
+        }
+    }
+}
+
+impl From<BinanceOrderSide> for OrderSide {
+    fn from(value: BinanceOrderSide) -> Self {
+        match value {
+            BinanceOrderSide::Buy => Self::Buy,
+            BinanceOrderSide::Sell => Self::Sell,
+        }
+    }
+}
+
+/// The order types accepted by the Binance Spot and Futures APIs.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Display, AsRefStr, EnumString,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceOrderType {
+    Limit,
+    Market,
+    /// Spot only.
+    StopLoss,
+    /// Spot only.
+    StopLossLimit,
+    /// Spot: take-profit market. Futures: take-profit limit.
+    TakeProfit,
+    /// Spot only.
+    TakeProfitLimit,
+    /// Spot only, a post-only limit order.
+    LimitMaker,
+    /// Futures only, a stop limit order.
+    Stop,
+    /// Futures only.
+    StopMarket,
+    /// Futures only.
+    TakeProfitMarket,
+    /// Futures only.
+    TrailingStopMarket,
+}
+
+/// The time in force instructions accepted by Binance.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Display, AsRefStr, EnumString,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceTimeInForce {
+    Gtc,
+    Ioc,
+    Fok,
+    /// Futures only, good-till-crossing (post-only).
+    Gtx,
+    /// Futures only, good-till-date.
+    Gtd,
+}
+
+/// The status of a Binance order.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Display, AsRefStr, EnumString,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceOrderStatus {
+    New,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    PendingCancel,
+    Rejected,
+    Expired,
+    ExpiredInMatch,
+}
+
+impl From<BinanceOrderStatus> for OrderStatus {
+    fn from(value: BinanceOrderStatus) -> Self {
+        match value {
+            BinanceOrderStatus::New => Self::Accepted,
+            BinanceOrderStatus::PartiallyFilled => Self::PartiallyFilled,
+            BinanceOrderStatus::Filled => Self::Filled,
+            BinanceOrderStatus::Canceled => Self::Canceled,
+            BinanceOrderStatus::PendingCancel => Self::PendingCancel,
+            BinanceOrderStatus::Rejected => Self::Rejected,
+            BinanceOrderStatus::Expired | BinanceOrderStatus::ExpiredInMatch => Self::Expired,
+        }
+    }
+}
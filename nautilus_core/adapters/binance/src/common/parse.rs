@@ -0,0 +1,162 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::str::FromStr;
+
+use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, nanos::UnixNanos};
+use nautilus_model::{
+    enums::AggressorSide,
+    identifiers::{InstrumentId, Symbol},
+    types::{Price, Quantity},
+};
+use rust_decimal::Decimal;
+
+use super::{
+    consts::{BINANCE_PERP_SUFFIX, BINANCE_VENUE},
+    enums::BinanceProductType,
+};
+
+/// Parses a Nautilus symbol from the given Binance `raw_symbol`.
+///
+/// Spot and delivery futures symbols are used as is, perpetual futures are suffixed
+/// with `-PERP` (COIN-M perpetuals replace their `_PERP` suffix).
+#[must_use]
+pub fn parse_symbol(raw_symbol: &str, product_type: BinanceProductType) -> Symbol {
+    if !product_type.is_futures() || raw_symbol.ends_with(|c: char| c.is_ascii_digit()) {
+        return Symbol::new(raw_symbol);
+    }
+
+    match raw_symbol.strip_suffix("_PERP") {
+        Some(base) => Symbol::new(format!("{base}{BINANCE_PERP_SUFFIX}")),
+        None => Symbol::new(format!("{raw_symbol}{BINANCE_PERP_SUFFIX}")),
+    }
+}
+
+/// Parses a Nautilus instrument ID from the given Binance `raw_symbol`.
+#[must_use]
+pub fn parse_instrument_id(raw_symbol: &str, product_type: BinanceProductType) -> InstrumentId {
+    InstrumentId::new(parse_symbol(raw_symbol, product_type), *BINANCE_VENUE)
+}
+
+/// Returns the Binance symbol for the given Nautilus `instrument_id`, reversing [`parse_symbol`].
+#[must_use]
+pub fn format_binance_symbol(
+    instrument_id: &InstrumentId,
+    product_type: BinanceProductType,
+) -> String {
+    let symbol = instrument_id.symbol.as_str();
+    match (product_type, symbol.strip_suffix(BINANCE_PERP_SUFFIX)) {
+        (BinanceProductType::CoinM, Some(base)) => format!("{base}_PERP"),
+        (BinanceProductType::UsdM, Some(base)) => base.to_string(),
+        _ => symbol.to_string(),
+    }
+}
+
+/// Returns the stream name prefix for the given Binance symbol (stream names are lowercase).
+#[must_use]
+pub fn format_stream_symbol(binance_symbol: &str) -> String {
+    binance_symbol.to_lowercase()
+}
+
+/// Converts the given Binance millisecond timestamp to UNIX nanoseconds.
+#[must_use]
+pub fn parse_millis_timestamp(millis: u64) -> UnixNanos {
+    UnixNanos::from(millis * NANOSECONDS_IN_MILLISECOND)
+}
+
+/// Returns the aggressor side of a trade from the Binance `is_buyer_maker` flag.
+#[must_use]
+pub const fn parse_aggressor_side(is_buyer_maker: bool) -> AggressorSide {
+    if is_buyer_maker {
+        AggressorSide::Seller
+    } else {
+        AggressorSide::Buyer
+    }
+}
+
+/// Normalizes a Binance decimal string by removing any trailing zeros,
+/// e.g. a tick size of `"0.01000000"` becomes `"0.01"`.
+///
+/// # Errors
+///
+/// This function returns an error if `value` is not a valid decimal.
+pub fn normalize_decimal_str(value: &str) -> anyhow::Result<String> {
+    Ok(Decimal::from_str(value)?.normalize().to_string())
+}
+
+/// Parses a Binance price string with the given `precision`.
+///
+/// # Errors
+///
+/// This function returns an error if `value` is not a valid price.
+pub fn parse_price(value: &str, precision: u8) -> anyhow::Result<Price> {
+    Price::new_checked(value.parse::<f64>()?, precision)
+}
+
+/// Parses a Binance quantity string with the given `precision`.
+///
+/// # Errors
+///
+/// This function returns an error if `value` is not a valid quantity.
+pub fn parse_quantity(value: &str, precision: u8) -> anyhow::Result<Quantity> {
+    Quantity::new_checked(value.parse::<f64>()?, precision)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("BTCUSDT", BinanceProductType::Spot, "BTCUSDT.BINANCE")]
+    #[case("BTCUSDT", BinanceProductType::UsdM, "BTCUSDT-PERP.BINANCE")]
+    #[case("BTCUSDT_250328", BinanceProductType::UsdM, "BTCUSDT_250328.BINANCE")]
+    #[case("BTCUSD_PERP", BinanceProductType::CoinM, "BTCUSD-PERP.BINANCE")]
+    #[case("BTCUSD_250328", BinanceProductType::CoinM, "BTCUSD_250328.BINANCE")]
+    fn test_parse_and_format_symbol(
+        #[case] raw_symbol: &str,
+        #[case] product_type: BinanceProductType,
+        #[case] expected: &str,
+    ) {
+        let instrument_id = parse_instrument_id(raw_symbol, product_type);
+
+        assert_eq!(instrument_id, InstrumentId::from(expected));
+        assert_eq!(
+            format_binance_symbol(&instrument_id, product_type),
+            raw_symbol
+        );
+    }
+
+    #[rstest]
+    #[case("0.01000000", "0.01")]
+    #[case("1.00000000", "1")]
+    #[case("0.00001000", "0.00001")]
+    #[case("10", "10")]
+    fn test_normalize_decimal_str(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(normalize_decimal_str(value).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_parse_millis_timestamp() {
+        assert_eq!(
+            parse_millis_timestamp(1_700_000_000_123),
+            UnixNanos::from(1_700_000_000_123_000_000)
+        );
+    }
+}
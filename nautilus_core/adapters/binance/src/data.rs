@@ -0,0 +1,828 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a market data client for Binance trades, quotes and order book deltas.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+};
+
+use nautilus_common::{
+    messages::data::{DataEvent, DataRequest, Payload},
+    runtime::ClientTask,
+    watchdog::ClientActivity,
+};
+use nautilus_core::{nanos::UnixNanos, time::get_atomic_clock_realtime, uuid::UUID4};
+use nautilus_data::client::DataClient;
+use nautilus_model::{
+    data::{
+        Bar, BarType, Data, DataType, OrderBookDeltas, OrderBookDeltas_API, QuoteTick, TradeTick,
+    },
+    enums::BookType,
+    identifiers::{ClientId, InstrumentId, Venue},
+    instruments::InstrumentAny,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use ustr::Ustr;
+
+use crate::{
+    common::{
+        consts::BINANCE_VENUE,
+        parse::{format_binance_symbol, format_stream_symbol},
+    },
+    http::BinanceHttpClient,
+    websocket::{
+        book::{BinanceBookSynchronizer, BookSyncError},
+        messages::{parse_stream_message, BinanceDepthUpdateMsg, BinanceWsMessage},
+        parse::{parse_agg_trade, parse_book_ticker, parse_depth_snapshot, parse_depth_update},
+        BinanceWebSocketClient,
+    },
+};
+
+/// The update speed of the subscribed depth diff stream.
+const DEPTH_STREAM_SUFFIX: &str = "depth@100ms";
+
+/// Provides a market data client for a single Binance product type.
+///
+/// Order book deltas are synchronized against a REST snapshot fetched when the first diff
+/// for a symbol arrives, and again after any gap in the diff sequence.
+pub struct BinanceDataClient {
+    http: BinanceHttpClient,
+    ws_url: String,
+    ws: Option<BinanceWebSocketClient>,
+    instruments: HashMap<Ustr, InstrumentAny>,
+    books: HashMap<Ustr, BinanceBookSynchronizer>,
+    pending: VecDeque<Data>,
+    snapshot_depth: Option<u32>,
+}
+
+impl BinanceDataClient {
+    /// Creates a new [`BinanceDataClient`] instance.
+    ///
+    /// The `snapshot_depth` is the number of levels per side requested for order book
+    /// snapshots (`None` uses the Binance default of 1000).
+    #[must_use]
+    pub fn new(http: BinanceHttpClient, is_testnet: bool, snapshot_depth: Option<u32>) -> Self {
+        let ws_url = http.product_type().ws_url(is_testnet).to_string();
+        Self {
+            http,
+            ws_url,
+            ws: None,
+            instruments: HashMap::new(),
+            books: HashMap::new(),
+            pending: VecDeque::new(),
+            snapshot_depth,
+        }
+    }
+
+    /// Returns whether the client is connected to the market data stream.
+    #[must_use]
+    pub fn is_connected(&self) -> bool {
+        self.ws
+            .as_ref()
+            .is_some_and(BinanceWebSocketClient::is_active)
+    }
+
+    /// Connects to the market data stream.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the connection cannot be established.
+    pub async fn connect(&mut self) -> anyhow::Result<()> {
+        self.ws = Some(BinanceWebSocketClient::connect_market_data(&self.ws_url).await?);
+        Ok(())
+    }
+
+    /// Disconnects from the market data stream, discarding all order book state.
+    pub async fn disconnect(&mut self) {
+        if let Some(ws) = self.ws.take() {
+            ws.close().await;
+        }
+        self.books.clear();
+        self.pending.clear();
+    }
+
+    /// Loads all trading instruments for the product type, returning them.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the exchange information request fails.
+    pub async fn load_instruments(&mut self) -> anyhow::Result<Vec<InstrumentAny>> {
+        let instruments = self.http.instruments().await?;
+        for instrument in &instruments {
+            self.instruments
+                .insert(instrument.raw_symbol().inner(), instrument.clone());
+        }
+        Ok(instruments)
+    }
+
+    /// Returns the loaded instrument for the given `instrument_id`, if any.
+    #[must_use]
+    pub fn instrument(&self, instrument_id: &InstrumentId) -> Option<&InstrumentAny> {
+        self.instruments.get(&self.binance_symbol(instrument_id))
+    }
+
+    /// Subscribes to aggregated trades for the given `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument is not loaded, or the client is not connected.
+    pub async fn subscribe_trades(&self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        let stream = self.stream_name(instrument_id, "aggTrade")?;
+        self.ws()?.subscribe(vec![stream]).await?;
+        Ok(())
+    }
+
+    /// Subscribes to best bid and offer quotes for the given `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument is not loaded, or the client is not connected.
+    pub async fn subscribe_quotes(&self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        let stream = self.stream_name(instrument_id, "bookTicker")?;
+        self.ws()?.subscribe(vec![stream]).await?;
+        Ok(())
+    }
+
+    /// Unsubscribes from aggregated trades for the given `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument is not loaded, or the client is not connected.
+    pub async fn unsubscribe_trades(&self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        let stream = self.stream_name(instrument_id, "aggTrade")?;
+        self.ws()?.unsubscribe(vec![stream]).await?;
+        Ok(())
+    }
+
+    /// Unsubscribes from best bid and offer quotes for the given `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument is not loaded, or the client is not connected.
+    pub async fn unsubscribe_quotes(&self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        let stream = self.stream_name(instrument_id, "bookTicker")?;
+        self.ws()?.unsubscribe(vec![stream]).await?;
+        Ok(())
+    }
+
+    /// Subscribes to order book deltas for the given `instrument_id`.
+    ///
+    /// The first deltas emitted are a snapshot, followed by the synchronized diffs.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument is not loaded, or the client is not connected.
+    pub async fn subscribe_book_deltas(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<()> {
+        let stream = self.stream_name(instrument_id, DEPTH_STREAM_SUFFIX)?;
+        self.books.insert(
+            self.binance_symbol(instrument_id),
+            BinanceBookSynchronizer::new(self.http.product_type()),
+        );
+        self.ws()?.subscribe(vec![stream]).await?;
+        Ok(())
+    }
+
+    /// Unsubscribes from order book deltas for the given `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument is not loaded, or the client is not connected.
+    pub async fn unsubscribe_book_deltas(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<()> {
+        let stream = self.stream_name(instrument_id, DEPTH_STREAM_SUFFIX)?;
+        self.books.remove(&self.binance_symbol(instrument_id));
+        self.ws()?.unsubscribe(vec![stream]).await?;
+        Ok(())
+    }
+
+    /// Requests an order book snapshot for the given `instrument_id`, with up to `depth`
+    /// levels per side (`None` uses the configured snapshot depth).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument is not loaded, or the request fails.
+    pub async fn request_book_snapshot(
+        &self,
+        instrument_id: &InstrumentId,
+        depth: Option<u32>,
+    ) -> anyhow::Result<OrderBookDeltas> {
+        let symbol = self.binance_symbol(instrument_id);
+        let instrument = self
+            .instruments
+            .get(&symbol)
+            .ok_or_else(|| anyhow::anyhow!("Instrument {instrument_id} not loaded"))?;
+        let snapshot = self
+            .http
+            .depth_snapshot(symbol.as_str(), depth.or(self.snapshot_depth))
+            .await?;
+        parse_depth_snapshot(
+            &snapshot,
+            instrument.id(),
+            instrument.price_precision(),
+            instrument.size_precision(),
+            get_atomic_clock_realtime().get_time_ns(),
+        )
+    }
+
+    /// Returns the next market data item, or `None` once the connection has closed.
+    ///
+    /// Messages which fail to parse are logged and skipped.
+    pub async fn next_data(&mut self) -> Option<Data> {
+        loop {
+            if let Some(data) = self.pending.pop_front() {
+                return Some(data);
+            }
+
+            let text = self.ws.as_mut()?.next_message().await?;
+            let ts_init = get_atomic_clock_realtime().get_time_ns();
+
+            match parse_stream_message(&text) {
+                Ok(BinanceWsMessage::AggTrade(msg)) => {
+                    if let Some(instrument) = self.instruments.get(&msg.symbol) {
+                        match parse_agg_trade(
+                            &msg,
+                            instrument.id(),
+                            instrument.price_precision(),
+                            instrument.size_precision(),
+                            ts_init,
+                        ) {
+                            Ok(trade) => return Some(Data::Trade(trade)),
+                            Err(e) => tracing::error!("Error parsing trade: {e}"),
+                        }
+                    }
+                }
+                Ok(BinanceWsMessage::BookTicker(msg)) => {
+                    if let Some(instrument) = self.instruments.get(&msg.symbol) {
+                        match parse_book_ticker(
+                            &msg,
+                            instrument.id(),
+                            instrument.price_precision(),
+                            instrument.size_precision(),
+                            ts_init,
+                        ) {
+                            Ok(quote) => return Some(Data::Quote(quote)),
+                            Err(e) => tracing::error!("Error parsing quote: {e}"),
+                        }
+                    }
+                }
+                Ok(BinanceWsMessage::DepthUpdate(msg)) => {
+                    if let Err(e) = self.handle_depth_update(msg, ts_init).await {
+                        tracing::error!("Error handling depth update: {e}");
+                    }
+                }
+                Ok(BinanceWsMessage::Response(response)) => {
+                    if let Some(error) = response.error {
+                        tracing::error!(
+                            "Request {} failed: {} {}",
+                            response.id,
+                            error.code,
+                            error.msg
+                        );
+                    }
+                }
+                Err(e) => tracing::error!("Error parsing message: {e}, {text}"),
+            }
+        }
+    }
+
+    async fn handle_depth_update(
+        &mut self,
+        msg: BinanceDepthUpdateMsg,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let symbol = msg.symbol;
+        let Some(book) = self.books.get_mut(&symbol) else {
+            return Ok(()); // Not subscribed
+        };
+
+        let result = book.handle_update(msg);
+        let is_synced = book.is_synced();
+
+        match result {
+            Ok(Some(update)) => self.push_depth_update(&update, ts_init)?,
+            Ok(None) if is_synced => {} // Already included in the snapshot
+            Ok(None) => self.request_snapshot(symbol).await?,
+            Err(e) => {
+                tracing::warn!("Resynchronizing {symbol} order book: {e}");
+                self.request_snapshot(symbol).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn request_snapshot(&mut self, symbol: Ustr) -> anyhow::Result<()> {
+        let instrument = self
+            .instruments
+            .get(&symbol)
+            .ok_or_else(|| anyhow::anyhow!("Instrument not loaded for {symbol}"))?;
+        let instrument_id = instrument.id();
+        let price_precision = instrument.price_precision();
+        let size_precision = instrument.size_precision();
+
+        let snapshot = self
+            .http
+            .depth_snapshot(symbol.as_str(), self.snapshot_depth)
+            .await?;
+
+        let Some(book) = self.books.get_mut(&symbol) else {
+            return Ok(()); // Unsubscribed while awaiting the snapshot
+        };
+
+        let updates = match book.apply_snapshot(snapshot.last_update_id) {
+            Ok(updates) => updates,
+            Err(e @ BookSyncError::StaleSnapshot { .. }) => {
+                // Diffs remain buffered and a new snapshot is requested on the next diff
+                tracing::debug!("Discarding {symbol} order book snapshot: {e}");
+                return Ok(());
+            }
+            Err(e) => {
+                tracing::warn!("Discarding {symbol} order book snapshot: {e}");
+                return Ok(());
+            }
+        };
+
+        let ts_init = get_atomic_clock_realtime().get_time_ns();
+        let deltas = parse_depth_snapshot(
+            &snapshot,
+            instrument_id,
+            price_precision,
+            size_precision,
+            ts_init,
+        )?;
+        self.pending
+            .push_back(Data::Deltas(OrderBookDeltas_API::new(deltas)));
+
+        for update in &updates {
+            self.push_depth_update(update, ts_init)?;
+        }
+        Ok(())
+    }
+
+    fn push_depth_update(
+        &mut self,
+        update: &BinanceDepthUpdateMsg,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<()> {
+        if update.bids.is_empty() && update.asks.is_empty() {
+            return Ok(()); // Nothing to apply
+        }
+
+        let instrument = self
+            .instruments
+            .get(&update.symbol)
+            .ok_or_else(|| anyhow::anyhow!("Instrument not loaded for {}", update.symbol))?;
+        let deltas = parse_depth_update(
+            update,
+            instrument.id(),
+            instrument.price_precision(),
+            instrument.size_precision(),
+            ts_init,
+        )?;
+        self.pending
+            .push_back(Data::Deltas(OrderBookDeltas_API::new(deltas)));
+        Ok(())
+    }
+
+    fn ws(&self) -> anyhow::Result<&BinanceWebSocketClient> {
+        self.ws
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected"))
+    }
+
+    fn binance_symbol(&self, instrument_id: &InstrumentId) -> Ustr {
+        let symbol = format_binance_symbol(instrument_id, self.http.product_type());
+        Ustr::from(symbol.as_str())
+    }
+
+    fn stream_name(&self, instrument_id: &InstrumentId, stream: &str) -> anyhow::Result<String> {
+        let symbol = self.binance_symbol(instrument_id);
+        if !self.instruments.contains_key(&symbol) {
+            anyhow::bail!("Instrument {instrument_id} not loaded");
+        }
+        Ok(format!(
+            "{}@{stream}",
+            format_stream_symbol(symbol.as_str())
+        ))
+    }
+}
+
+/// Provides a [`DataClient`] for Binance, running a [`BinanceDataClient`] on the shared runtime.
+///
+/// Market data is sent to the data engine on the `data_tx` channel (see `LiveRunner::data_sender`).
+/// Only trades, quotes and `L2_MBP` order book deltas can be subscribed to.
+pub struct BinanceLiveDataClient {
+    client_id: ClientId,
+    task: ClientTask<BinanceDataClient>,
+}
+
+impl BinanceLiveDataClient {
+    /// Creates a new [`BinanceLiveDataClient`] instance, spawning the task running the `client`.
+    #[must_use]
+    pub fn new(
+        client_id: ClientId,
+        client: BinanceDataClient,
+        data_tx: UnboundedSender<DataEvent>,
+    ) -> Self {
+        let task = ClientTask::spawn(
+            client,
+            |client| Box::pin(async move { client.next_data().await.map(DataEvent::Data) }),
+            data_tx,
+        );
+        Self { client_id, task }
+    }
+
+    /// Returns the activity of the client, for monitoring by a `ConnectionWatchdog`.
+    #[must_use]
+    pub fn activity(&self) -> ClientActivity {
+        self.task.activity()
+    }
+
+    fn load_instruments(&self) -> anyhow::Result<Vec<InstrumentAny>> {
+        self.task.call(|client| Box::pin(client.load_instruments()))
+    }
+}
+
+impl DataClient for BinanceLiveDataClient {
+    fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    fn venue(&self) -> Option<Venue> {
+        Some(*BINANCE_VENUE)
+    }
+
+    fn start(&self) {
+        let result = self.task.call(|client| {
+            Box::pin(async move {
+                client.load_instruments().await?;
+                client.connect().await
+            })
+        });
+        if let Err(e) = result {
+            tracing::error!("Error starting {}: {e}", self.client_id);
+        }
+    }
+
+    fn stop(&self) {
+        let result = self.task.call(|client| {
+            Box::pin(async move {
+                client.disconnect().await;
+                Ok(())
+            })
+        });
+        if let Err(e) = result {
+            tracing::error!("Error stopping {}: {e}", self.client_id);
+        }
+    }
+
+    fn reset(&self) {}
+
+    fn dispose(&self) {
+        self.stop();
+    }
+
+    fn is_connected(&self) -> bool {
+        self.task
+            .call(|client| Box::pin(async move { Ok(client.is_connected()) }))
+            .unwrap_or(false)
+    }
+
+    fn is_disconnected(&self) -> bool {
+        !self.is_connected()
+    }
+
+    // -- COMMAND HANDLERS ------------------------------------------------------------------------
+
+    fn subscribe(
+        &mut self,
+        data_type: &DataType,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to {data_type} not supported")
+    }
+
+    fn subscribe_instruments(
+        &mut self,
+        _venue: Option<&Venue>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to instruments not supported")
+    }
+
+    fn subscribe_instrument(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to instrument {instrument_id} not supported")
+    }
+
+    fn subscribe_order_book_deltas(
+        &mut self,
+        instrument_id: &InstrumentId,
+        book_type: BookType,
+        _depth: Option<usize>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        if book_type != BookType::L2_MBP {
+            anyhow::bail!("Book type {book_type} not supported, use `L2_MBP`");
+        }
+        let instrument_id = *instrument_id;
+        self.task.call(move |client| {
+            Box::pin(async move { client.subscribe_book_deltas(&instrument_id).await })
+        })
+    }
+
+    fn subscribe_order_book_snapshots(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _book_type: BookType,
+        _depth: Option<usize>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to order book snapshots for {instrument_id} not supported")
+    }
+
+    fn subscribe_quote_ticks(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        let instrument_id = *instrument_id;
+        self.task.call(move |client| {
+            Box::pin(async move { client.subscribe_quotes(&instrument_id).await })
+        })
+    }
+
+    fn subscribe_trade_ticks(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        let instrument_id = *instrument_id;
+        self.task.call(move |client| {
+            Box::pin(async move { client.subscribe_trades(&instrument_id).await })
+        })
+    }
+
+    fn subscribe_bars(
+        &mut self,
+        bar_type: &BarType,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to bars {bar_type} not supported")
+    }
+
+    fn subscribe_instrument_status(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to instrument status for {instrument_id} not supported")
+    }
+
+    fn subscribe_instrument_close(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to instrument close for {instrument_id} not supported")
+    }
+
+    fn subscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to funding rates for {instrument_id} not supported")
+    }
+
+    fn subscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to mark prices for {instrument_id} not supported")
+    }
+
+    fn subscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to index prices for {instrument_id} not supported")
+    }
+
+    fn unsubscribe(
+        &mut self,
+        data_type: &DataType,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from {data_type} not supported")
+    }
+
+    fn unsubscribe_instruments(
+        &mut self,
+        _venue: Option<&Venue>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from instruments not supported")
+    }
+
+    fn unsubscribe_instrument(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from instrument {instrument_id} not supported")
+    }
+
+    fn unsubscribe_order_book_deltas(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        let instrument_id = *instrument_id;
+        self.task.call(move |client| {
+            Box::pin(async move { client.unsubscribe_book_deltas(&instrument_id).await })
+        })
+    }
+
+    fn unsubscribe_order_book_snapshots(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from order book snapshots for {instrument_id} not supported")
+    }
+
+    fn unsubscribe_quote_ticks(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        let instrument_id = *instrument_id;
+        self.task.call(move |client| {
+            Box::pin(async move { client.unsubscribe_quotes(&instrument_id).await })
+        })
+    }
+
+    fn unsubscribe_trade_ticks(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        let instrument_id = *instrument_id;
+        self.task.call(move |client| {
+            Box::pin(async move { client.unsubscribe_trades(&instrument_id).await })
+        })
+    }
+
+    fn unsubscribe_bars(
+        &mut self,
+        bar_type: &BarType,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from bars {bar_type} not supported")
+    }
+
+    fn unsubscribe_instrument_status(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from instrument status for {instrument_id} not supported")
+    }
+
+    fn unsubscribe_instrument_close(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from instrument close for {instrument_id} not supported")
+    }
+
+    fn unsubscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from funding rates for {instrument_id} not supported")
+    }
+
+    fn unsubscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from mark prices for {instrument_id} not supported")
+    }
+
+    fn unsubscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from index prices for {instrument_id} not supported")
+    }
+
+    // -- DATA REQUEST HANDLERS -------------------------------------------------------------------
+
+    fn request_data(&self, request: DataRequest) {
+        tracing::error!("Requesting {} not supported", request.data_type);
+    }
+
+    fn request_instruments(
+        &self,
+        _correlation_id: UUID4,
+        _venue: Venue,
+        _start: Option<UnixNanos>,
+        _end: Option<UnixNanos>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<Vec<InstrumentAny>> {
+        self.load_instruments()
+    }
+
+    fn request_instrument(
+        &self,
+        _correlation_id: UUID4,
+        instrument_id: InstrumentId,
+        _start: Option<UnixNanos>,
+        _end: Option<UnixNanos>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<InstrumentAny> {
+        self.load_instruments()?
+            .into_iter()
+            .find(|instrument| instrument.id() == instrument_id)
+            .ok_or_else(|| anyhow::anyhow!("Instrument {instrument_id} not found"))
+    }
+
+    fn request_order_book_snapshot(
+        &self,
+        _correlation_id: UUID4,
+        instrument_id: InstrumentId,
+        depth: Option<usize>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<Payload> {
+        let depth = depth.map(|depth| u32::try_from(depth).unwrap_or(u32::MAX));
+        let deltas = self.task.call(move |client| {
+            Box::pin(async move { client.request_book_snapshot(&instrument_id, depth).await })
+        })?;
+        Ok(Arc::new(deltas))
+    }
+
+    fn request_quote_ticks(
+        &self,
+        _correlation_id: UUID4,
+        instrument_id: InstrumentId,
+        _start: Option<UnixNanos>,
+        _end: Option<UnixNanos>,
+        _limit: Option<usize>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<Vec<QuoteTick>> {
+        anyhow::bail!("Requesting quotes for {instrument_id} not supported")
+    }
+
+    fn request_trade_ticks(
+        &self,
+        _correlation_id: UUID4,
+        instrument_id: InstrumentId,
+        _start: Option<UnixNanos>,
+        _end: Option<UnixNanos>,
+        _limit: Option<usize>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<Vec<TradeTick>> {
+        anyhow::bail!("Requesting trades for {instrument_id} not supported")
+    }
+
+    fn request_bars(
+        &self,
+        _correlation_id: UUID4,
+        bar_type: BarType,
+        _start: Option<UnixNanos>,
+        _end: Option<UnixNanos>,
+        _limit: Option<usize>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<Vec<Bar>> {
+        anyhow::bail!("Requesting bars {bar_type} not supported")
+    }
+}
@@ -0,0 +1,533 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides an execution client for Binance order management and the user data stream.
+
+use std::{collections::HashMap, time::Duration};
+
+use nautilus_common::{
+    messages::execution::{
+        BatchCancelOrders, CancelAllOrders, CancelOrder, ModifyOrder, QueryOrder, SubmitOrder,
+        SubmitOrderList,
+    },
+    runtime::ClientTask,
+    watchdog::ClientActivity,
+};
+use nautilus_core::{time::get_atomic_clock_realtime, uuid::UUID4};
+use nautilus_execution::client::LiveExecutionClient;
+use nautilus_model::{
+    enums::{OrderSide, OrderStatus},
+    events::{
+        OrderCancelRejected, OrderEventAny, OrderModifyRejected, OrderRejected, OrderSubmitted,
+    },
+    identifiers::{AccountId, ClientId, ClientOrderId, InstrumentId, Venue},
+    instruments::InstrumentAny,
+    orders::OrderAny,
+};
+use tokio::{sync::mpsc::UnboundedSender, task::JoinHandle, time::Instant};
+
+use super::parse::{parse_order_params, parse_order_update};
+use crate::{
+    common::{
+        consts::{BINANCE_LISTEN_KEY_KEEPALIVE_SECS, BINANCE_VENUE},
+        parse::format_binance_symbol,
+    },
+    http::{models::BinanceOrderResponse, BinanceHttpClient},
+    websocket::{
+        messages::{parse_user_data_event, BinanceUserDataEvent},
+        BinanceWebSocketClient,
+    },
+};
+
+/// Provides an execution client for a single Binance product type.
+///
+/// Orders are submitted and canceled over REST, with order updates received on the user
+/// data stream. The stream listen key is kept alive by a background task while started.
+pub struct BinanceExecutionClient {
+    http: BinanceHttpClient,
+    ws_url: String,
+    listen_key: Option<String>,
+    keepalive_task: Option<JoinHandle<()>>,
+    user_ws: Option<BinanceWebSocketClient>,
+}
+
+impl BinanceExecutionClient {
+    /// Creates a new [`BinanceExecutionClient`] instance.
+    ///
+    /// The `http` client must have credentials for signed requests.
+    #[must_use]
+    pub fn new(http: BinanceHttpClient, is_testnet: bool) -> Self {
+        let ws_url = http.product_type().ws_url(is_testnet).to_string();
+        Self {
+            http,
+            ws_url,
+            listen_key: None,
+            keepalive_task: None,
+            user_ws: None,
+        }
+    }
+
+    /// Returns the listen key of the running user data stream, if any.
+    #[must_use]
+    pub fn listen_key(&self) -> Option<&str> {
+        self.listen_key.as_deref()
+    }
+
+    /// Loads all trading instruments for the product type, returning them.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the exchange information request fails.
+    pub async fn load_instruments(&self) -> anyhow::Result<Vec<InstrumentAny>> {
+        Ok(self.http.instruments().await?)
+    }
+
+    /// Submits the given `order`, returning the Binance order response.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order cannot be represented on Binance, or the request fails.
+    pub async fn submit_order(&self, order: &OrderAny) -> anyhow::Result<BinanceOrderResponse> {
+        let params = parse_order_params(order, self.http.product_type())?;
+        Ok(self.http.new_order(&params).await?)
+    }
+
+    /// Cancels the open `order`, returning the Binance order response.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the request fails.
+    pub async fn cancel_order(&self, order: &OrderAny) -> anyhow::Result<BinanceOrderResponse> {
+        self.cancel_order_by_id(&order.instrument_id(), &order.client_order_id().to_string())
+            .await
+    }
+
+    /// Cancels the open order with the given `client_order_id` for the `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the request fails.
+    pub async fn cancel_order_by_id(
+        &self,
+        instrument_id: &InstrumentId,
+        client_order_id: &str,
+    ) -> anyhow::Result<BinanceOrderResponse> {
+        let symbol = format_binance_symbol(instrument_id, self.http.product_type());
+        Ok(self.http.cancel_order(&symbol, client_order_id).await?)
+    }
+
+    /// Starts the user data stream, connecting to it and spawning the listen key keepalive task.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the listen key cannot be created, or the connection fails.
+    pub async fn start_user_data_stream(&mut self) -> anyhow::Result<()> {
+        if self.listen_key.is_some() {
+            tracing::warn!("User data stream already started");
+            return Ok(());
+        }
+
+        let listen_key = self.http.create_listen_key().await?;
+        let user_ws = BinanceWebSocketClient::connect_user_data(&self.ws_url, &listen_key).await?;
+
+        self.keepalive_task = Some(spawn_keepalive_task(self.http.clone(), listen_key.clone()));
+        self.user_ws = Some(user_ws);
+        self.listen_key = Some(listen_key);
+        Ok(())
+    }
+
+    /// Stops the user data stream, closing the connection and the listen key.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the listen key cannot be closed.
+    pub async fn stop_user_data_stream(&mut self) -> anyhow::Result<()> {
+        if let Some(task) = self.keepalive_task.take() {
+            task.abort();
+        }
+        if let Some(user_ws) = self.user_ws.take() {
+            user_ws.close().await;
+        }
+        if let Some(listen_key) = self.listen_key.take() {
+            self.http.close_listen_key(&listen_key).await?;
+        }
+        Ok(())
+    }
+
+    /// Returns the next user data event, or `None` once the user data stream has closed.
+    ///
+    /// Messages which fail to parse are logged and skipped. A [`BinanceUserDataEvent::ListenKeyExpired`]
+    /// is returned to the caller, which must restart the user data stream.
+    pub async fn next_event(&mut self) -> Option<BinanceUserDataEvent> {
+        loop {
+            let text = self.user_ws.as_mut()?.next_message().await?;
+            match parse_user_data_event(&text) {
+                Ok(BinanceUserDataEvent::OrderUpdate(msg)) => {
+                    let status = OrderStatus::from(msg.order_status);
+                    tracing::debug!(
+                        "Order {} {} ({status})",
+                        msg.client_order_id,
+                        msg.execution_type,
+                    );
+                    return Some(BinanceUserDataEvent::OrderUpdate(msg));
+                }
+                Ok(BinanceUserDataEvent::ListenKeyExpired) => {
+                    tracing::warn!("User data stream listen key expired");
+                    return Some(BinanceUserDataEvent::ListenKeyExpired);
+                }
+                Ok(event) => return Some(event),
+                Err(e) => tracing::error!("Error parsing user data message: {e}, {text}"),
+            }
+        }
+    }
+}
+
+/// The state owned by the task of a [`BinanceLiveExecutionClient`].
+struct BinanceExecutionState {
+    client: BinanceExecutionClient,
+    account_id: AccountId,
+    instruments: HashMap<InstrumentId, InstrumentAny>,
+    /// The open orders submitted through the client, by client order ID.
+    orders: HashMap<ClientOrderId, OrderAny>,
+}
+
+impl BinanceExecutionState {
+    async fn start(&mut self) -> anyhow::Result<()> {
+        for instrument in self.client.load_instruments().await? {
+            self.instruments.insert(instrument.id(), instrument);
+        }
+        self.client.start_user_data_stream().await
+    }
+
+    /// Returns the next order event for an order submitted through the client, or `None`
+    /// once the user data stream has closed.
+    async fn next_order_event(&mut self) -> Option<OrderEventAny> {
+        loop {
+            let msg = match self.client.next_event().await? {
+                BinanceUserDataEvent::OrderUpdate(msg) => msg,
+                BinanceUserDataEvent::ListenKeyExpired => {
+                    if let Err(e) = self.restart_user_data_stream().await {
+                        tracing::error!("Error restarting user data stream: {e}");
+                    }
+                    continue;
+                }
+                BinanceUserDataEvent::Other(_) => continue,
+            };
+
+            let Ok(client_order_id) = ClientOrderId::new_checked(&msg.client_order_id) else {
+                continue; // External order
+            };
+            let Some(order) = self.orders.get(&client_order_id) else {
+                continue; // External order
+            };
+            let Some(instrument) = self.instruments.get(&order.instrument_id()) else {
+                tracing::error!("Instrument {} not loaded", order.instrument_id());
+                continue;
+            };
+
+            let ts_init = get_atomic_clock_realtime().get_time_ns();
+            match parse_order_update(&msg, order, instrument, self.account_id, ts_init) {
+                Ok(event) => {
+                    if matches!(
+                        OrderStatus::from(msg.order_status),
+                        OrderStatus::Filled
+                            | OrderStatus::Canceled
+                            | OrderStatus::Expired
+                            | OrderStatus::Rejected
+                    ) {
+                        self.orders.remove(&client_order_id);
+                    }
+                    if event.is_some() {
+                        return event;
+                    }
+                }
+                Err(e) => tracing::error!("Error parsing order update: {e}"),
+            }
+        }
+    }
+
+    async fn restart_user_data_stream(&mut self) -> anyhow::Result<()> {
+        self.client.stop_user_data_stream().await?;
+        self.client.start_user_data_stream().await
+    }
+
+    fn open_orders(
+        &self,
+        instrument_id: &InstrumentId,
+        order_side: OrderSide,
+    ) -> Vec<(ClientOrderId, OrderAny)> {
+        self.orders
+            .iter()
+            .filter(|(_, order)| {
+                order.instrument_id() == *instrument_id
+                    && (order_side == OrderSide::NoOrderSide || order.order_side() == order_side)
+            })
+            .map(|(client_order_id, order)| (*client_order_id, order.clone()))
+            .collect()
+    }
+}
+
+/// Provides a [`LiveExecutionClient`] for Binance, running a [`BinanceExecutionClient`] on
+/// the shared runtime.
+///
+/// Order updates from the user data stream, for orders submitted through the client, are
+/// sent as order events on the `event_tx` channel for the execution engine to process.
+pub struct BinanceLiveExecutionClient {
+    client_id: ClientId,
+    account_id: AccountId,
+    event_tx: UnboundedSender<OrderEventAny>,
+    task: ClientTask<BinanceExecutionState>,
+}
+
+impl BinanceLiveExecutionClient {
+    /// Creates a new [`BinanceLiveExecutionClient`] instance, spawning the task running the `client`.
+    #[must_use]
+    pub fn new(
+        client_id: ClientId,
+        account_id: AccountId,
+        client: BinanceExecutionClient,
+        event_tx: UnboundedSender<OrderEventAny>,
+    ) -> Self {
+        let state = BinanceExecutionState {
+            client,
+            account_id,
+            instruments: HashMap::new(),
+            orders: HashMap::new(),
+        };
+        let task = ClientTask::spawn(
+            state,
+            |state| Box::pin(state.next_order_event()),
+            event_tx.clone(),
+        );
+        Self {
+            client_id,
+            account_id,
+            event_tx,
+            task,
+        }
+    }
+
+    /// Returns the activity of the user data stream, for monitoring by a `ConnectionWatchdog`.
+    #[must_use]
+    pub fn activity(&self) -> ClientActivity {
+        self.task.activity()
+    }
+
+    fn send_event(&self, event: OrderEventAny) {
+        if let Err(e) = self.event_tx.send(event) {
+            tracing::error!("Error sending order event: {e}");
+        }
+    }
+
+    fn submit(&self, order: OrderAny) {
+        let trader_id = order.trader_id();
+        let strategy_id = order.strategy_id();
+        let instrument_id = order.instrument_id();
+        let client_order_id = order.client_order_id();
+        let ts_now = get_atomic_clock_realtime().get_time_ns();
+        self.send_event(OrderEventAny::Submitted(OrderSubmitted::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            self.account_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        )));
+
+        let result = self.task.call(move |state| {
+            Box::pin(async move {
+                state.orders.insert(client_order_id, order.clone());
+                if let Err(e) = state.client.submit_order(&order).await {
+                    state.orders.remove(&client_order_id);
+                    return Err(e);
+                }
+                Ok(())
+            })
+        });
+
+        if let Err(e) = result {
+            let ts_now = get_atomic_clock_realtime().get_time_ns();
+            self.send_event(OrderEventAny::Rejected(OrderRejected::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                self.account_id,
+                e.to_string().as_str().into(),
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+            )));
+        }
+    }
+
+    fn cancel(&self, command: &CancelOrder) {
+        let instrument_id = command.instrument_id;
+        let client_order_id = command.client_order_id;
+        let result = self.task.call(move |state| {
+            Box::pin(async move {
+                state
+                    .client
+                    .cancel_order_by_id(&instrument_id, client_order_id.as_str())
+                    .await?;
+                Ok(())
+            })
+        });
+
+        if let Err(e) = result {
+            let ts_now = get_atomic_clock_realtime().get_time_ns();
+            self.send_event(OrderEventAny::CancelRejected(OrderCancelRejected::new(
+                command.trader_id,
+                command.strategy_id,
+                command.instrument_id,
+                command.client_order_id,
+                e.to_string().as_str().into(),
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+                Some(command.venue_order_id),
+                Some(self.account_id),
+            )));
+        }
+    }
+}
+
+impl LiveExecutionClient for BinanceLiveExecutionClient {
+    fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    fn venue(&self) -> Venue {
+        *BINANCE_VENUE
+    }
+
+    fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        self.task.call(|state| Box::pin(state.start()))
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        self.task
+            .call(|state| Box::pin(state.client.stop_user_data_stream()))
+    }
+
+    fn is_connected(&self) -> bool {
+        self.task
+            .call(|state| Box::pin(async move { Ok(state.client.listen_key().is_some()) }))
+            .unwrap_or(false)
+    }
+
+    // -- COMMAND HANDLERS ----------------------------------------------------
+
+    fn submit_order(&mut self, command: &SubmitOrder) -> anyhow::Result<()> {
+        self.submit(command.order.clone());
+        Ok(())
+    }
+
+    fn submit_order_list(&mut self, command: &SubmitOrderList) -> anyhow::Result<()> {
+        for order in &command.order_list.orders {
+            self.submit(order.clone());
+        }
+        Ok(())
+    }
+
+    fn modify_order(&mut self, command: &ModifyOrder) -> anyhow::Result<()> {
+        let ts_now = get_atomic_clock_realtime().get_time_ns();
+        self.send_event(OrderEventAny::ModifyRejected(OrderModifyRejected::new(
+            command.trader_id,
+            command.strategy_id,
+            command.instrument_id,
+            command.client_order_id,
+            "Modifying orders not supported".into(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(command.venue_order_id),
+            Some(self.account_id),
+        )));
+        Ok(())
+    }
+
+    fn cancel_order(&mut self, command: &CancelOrder) -> anyhow::Result<()> {
+        self.cancel(command);
+        Ok(())
+    }
+
+    fn cancel_all_orders(&mut self, command: &CancelAllOrders) -> anyhow::Result<()> {
+        let instrument_id = command.instrument_id;
+        let order_side = command.order_side;
+        let orders = self.task.call(move |state| {
+            Box::pin(async move { Ok(state.open_orders(&instrument_id, order_side)) })
+        })?;
+
+        for (client_order_id, order) in orders {
+            if order.strategy_id() != command.strategy_id {
+                continue;
+            }
+            self.cancel(&CancelOrder {
+                trader_id: command.trader_id,
+                client_id: command.client_id,
+                strategy_id: command.strategy_id,
+                instrument_id,
+                client_order_id,
+                venue_order_id: order.venue_order_id().unwrap_or_default(),
+                command_id: UUID4::new(),
+                ts_init: command.ts_init,
+            });
+        }
+        Ok(())
+    }
+
+    fn batch_cancel_orders(&mut self, command: &BatchCancelOrders) -> anyhow::Result<()> {
+        for cancel in &command.cancels {
+            self.cancel(cancel);
+        }
+        Ok(())
+    }
+
+    fn query_order(&mut self, command: &QueryOrder) -> anyhow::Result<()> {
+        anyhow::bail!("Querying order {} not supported", command.client_order_id)
+    }
+}
+
+impl Drop for BinanceExecutionClient {
+    fn drop(&mut self) {
+        if let Some(task) = self.keepalive_task.take() {
+            task.abort();
+        }
+    }
+}
+
+fn spawn_keepalive_task(http: BinanceHttpClient, listen_key: String) -> JoinHandle<()> {
+    let period = Duration::from_secs(BINANCE_LISTEN_KEY_KEEPALIVE_SECS);
+    tokio::task::spawn(async move {
+        let mut interval = tokio::time::interval_at(Instant::now() + period, period);
+        loop {
+            interval.tick().await;
+            match http.keepalive_listen_key(&listen_key).await {
+                Ok(()) => tracing::debug!("Kept alive user data stream listen key"),
+                Err(e) => tracing::error!("Error keeping alive user data stream listen key: {e}"),
+            }
+        }
+    })
+}
@@ -13,16 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-#[cfg(test)]
-#[must_use]
-pub fn load_test_json(file_name: &str) -> String {
-    use std::{fs, path::PathBuf};
+//! Order execution for Binance, translating Nautilus orders into Binance order requests.
 
-    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-        .join("src")
-        .join("tests")
-        .join("data")
-        .join(file_name);
+pub mod client;
+pub mod parse;
 
-    fs::read_to_string(path).expect("Failed to read test JSON file")
-}
+pub use crate::execution::client::BinanceExecutionClient;
@@ -0,0 +1,638 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Functions for translating Nautilus orders into Binance order requests, and Binance order
+//! updates into Nautilus order events.
+
+use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSide, OrderType, TimeInForce, TrailingOffsetType},
+    events::{
+        OrderAccepted, OrderCanceled, OrderEventAny, OrderExpired, OrderFilled, OrderRejected,
+        OrderUpdated,
+    },
+    identifiers::{AccountId, TradeId, VenueOrderId},
+    instruments::InstrumentAny,
+    orders::OrderAny,
+    types::{Currency, Money},
+};
+use rust_decimal::Decimal;
+
+use crate::{
+    common::{
+        enums::{BinanceOrderSide, BinanceOrderType, BinanceProductType, BinanceTimeInForce},
+        parse::{format_binance_symbol, parse_millis_timestamp, parse_price, parse_quantity},
+    },
+    http::models::BinanceNewOrderParams,
+    websocket::messages::BinanceOrderUpdateMsg,
+};
+
+/// Parses the Binance new order request parameters for the given `order`.
+///
+/// Post-only limit orders map to `LIMIT_MAKER` on Spot and `GTX` time in force on futures.
+/// Conditional orders map to the stop/take-profit order types of each product type,
+/// with trailing stops (futures only) requiring a basis point offset.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the order type is not supported for the product type.
+/// - If the order is reduce-only on Spot, or post-only and not a limit order.
+/// - If the time in force is not supported for the product type.
+/// - If a required price or trigger price is missing.
+pub fn parse_order_params(
+    order: &OrderAny,
+    product_type: BinanceProductType,
+) -> anyhow::Result<BinanceNewOrderParams> {
+    let is_futures = product_type.is_futures();
+    let order_type = order.order_type();
+
+    if order.is_reduce_only() && !is_futures {
+        anyhow::bail!("Reduce-only orders are not supported for Binance Spot");
+    }
+    if order.is_post_only() && order_type != OrderType::Limit {
+        anyhow::bail!("Post-only is only supported for `LIMIT` orders, was {order_type}");
+    }
+
+    let binance_order_type = parse_order_type(order_type, order.is_post_only(), is_futures)?;
+
+    let mut time_in_force = match binance_order_type {
+        // Market orders do not accept a time in force on Spot
+        BinanceOrderType::Market
+        | BinanceOrderType::StopLoss
+        | BinanceOrderType::TakeProfit
+        | BinanceOrderType::LimitMaker
+            if !is_futures =>
+        {
+            None
+        }
+        _ => Some(parse_time_in_force(order.time_in_force(), is_futures)?),
+    };
+    if order.is_post_only() && is_futures {
+        time_in_force = Some(BinanceTimeInForce::Gtx);
+    }
+
+    let good_till_date = if time_in_force == Some(BinanceTimeInForce::Gtd) {
+        let expire_time = order
+            .expire_time()
+            .ok_or_else(|| anyhow::anyhow!("`GTD` order has no expire time"))?;
+        Some(expire_time.as_u64() / NANOSECONDS_IN_MILLISECOND)
+    } else {
+        None
+    };
+
+    let price = match order_type {
+        OrderType::Limit | OrderType::StopLimit | OrderType::LimitIfTouched => Some(
+            order
+                .price()
+                .ok_or_else(|| anyhow::anyhow!("{order_type} order has no price"))?
+                .to_string(),
+        ),
+        _ => None,
+    };
+
+    let trigger_price = match order_type {
+        OrderType::StopMarket
+        | OrderType::StopLimit
+        | OrderType::MarketIfTouched
+        | OrderType::LimitIfTouched => Some(
+            order
+                .trigger_price()
+                .ok_or_else(|| anyhow::anyhow!("{order_type} order has no trigger price"))?
+                .to_string(),
+        ),
+        _ => None,
+    };
+
+    let (callback_rate, activation_price) = match order {
+        OrderAny::TrailingStopMarket(order) => {
+            if order.trailing_offset_type != TrailingOffsetType::BasisPoints {
+                anyhow::bail!(
+                    "Trailing offset type {} not supported, use `BASIS_POINTS`",
+                    order.trailing_offset_type
+                );
+            }
+            // Binance callback rate is a percentage
+            let callback_rate = (order.trailing_offset.as_decimal() / Decimal::ONE_HUNDRED)
+                .normalize()
+                .to_string();
+            (Some(callback_rate), Some(order.trigger_price.to_string()))
+        }
+        _ => (None, None),
+    };
+
+    Ok(BinanceNewOrderParams {
+        symbol: format_binance_symbol(&order.instrument_id(), product_type),
+        side: BinanceOrderSide::from(order.order_side_specified()),
+        order_type: binance_order_type,
+        time_in_force,
+        quantity: order.quantity().to_string(),
+        price,
+        stop_price: trigger_price,
+        reduce_only: (is_futures && order.is_reduce_only()).then_some(true),
+        new_client_order_id: order.client_order_id().to_string(),
+        callback_rate,
+        activation_price,
+        good_till_date,
+        new_order_resp_type: (!is_futures).then(|| "RESULT".to_string()),
+    })
+}
+
+/// Parses the order event for the Binance order update `msg` of the given Nautilus `order`.
+///
+/// Returns `None` for execution types without a corresponding order event.
+///
+/// # Errors
+///
+/// This function returns an error if a quantity, price or commission is not a valid decimal.
+pub fn parse_order_update(
+    msg: &BinanceOrderUpdateMsg,
+    order: &OrderAny,
+    instrument: &InstrumentAny,
+    account_id: AccountId,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Option<OrderEventAny>> {
+    let trader_id = order.trader_id();
+    let strategy_id = order.strategy_id();
+    let instrument_id = order.instrument_id();
+    let client_order_id = order.client_order_id();
+    let venue_order_id = VenueOrderId::new(msg.order_id.to_string());
+    let ts_event = parse_millis_timestamp(msg.transaction_time);
+
+    let event = match msg.execution_type.as_str() {
+        "NEW" => OrderEventAny::Accepted(OrderAccepted::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            account_id,
+            UUID4::new(),
+            ts_event,
+            ts_init,
+            false,
+        )),
+        "CANCELED" => OrderEventAny::Canceled(OrderCanceled::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            UUID4::new(),
+            ts_event,
+            ts_init,
+            false,
+            Some(venue_order_id),
+            Some(account_id),
+        )),
+        "EXPIRED" => OrderEventAny::Expired(OrderExpired::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            UUID4::new(),
+            ts_event,
+            ts_init,
+            false,
+            Some(venue_order_id),
+            Some(account_id),
+        )),
+        "REJECTED" => {
+            let reason = msg.reject_reason.as_deref().unwrap_or("NONE");
+            OrderEventAny::Rejected(OrderRejected::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                account_id,
+                reason.into(),
+                UUID4::new(),
+                ts_event,
+                ts_init,
+                false,
+            ))
+        }
+        "REPLACED" | "AMENDMENT" => {
+            let price = match order.price() {
+                Some(_) => Some(parse_price(&msg.price, instrument.price_precision())?),
+                None => None,
+            };
+            OrderEventAny::Updated(OrderUpdated::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                parse_quantity(&msg.quantity, instrument.size_precision())?,
+                UUID4::new(),
+                ts_event,
+                ts_init,
+                false,
+                Some(venue_order_id),
+                Some(account_id),
+                price,
+                order.trigger_price(),
+            ))
+        }
+        // `CALCULATED` is a futures liquidation fill
+        "TRADE" | "CALCULATED" => {
+            let liquidity_side = if msg.is_maker {
+                LiquiditySide::Maker
+            } else {
+                LiquiditySide::Taker
+            };
+            OrderEventAny::Filled(OrderFilled::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                venue_order_id,
+                account_id,
+                TradeId::new(msg.trade_id.to_string()),
+                OrderSide::from(msg.side),
+                order.order_type(),
+                parse_quantity(&msg.last_filled_qty, instrument.size_precision())?,
+                parse_price(&msg.last_filled_price, instrument.price_precision())?,
+                instrument.quote_currency(),
+                liquidity_side,
+                UUID4::new(),
+                ts_event,
+                ts_init,
+                false,
+                None,
+                parse_commission(msg)?,
+            ))
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
+
+/// Parses the commission of the fill in the order update `msg`, if its asset is a known currency.
+fn parse_commission(msg: &BinanceOrderUpdateMsg) -> anyhow::Result<Option<Money>> {
+    let (Some(commission), Some(asset)) = (&msg.commission, msg.commission_asset) else {
+        return Ok(None);
+    };
+    let Some(currency) = Currency::try_from_str(asset.as_str()) else {
+        tracing::warn!("Unknown commission asset {asset}");
+        return Ok(None);
+    };
+    Money::new_checked(commission.parse::<f64>()?, currency).map(Some)
+}
+
+fn parse_order_type(
+    order_type: OrderType,
+    is_post_only: bool,
+    is_futures: bool,
+) -> anyhow::Result<BinanceOrderType> {
+    let binance_order_type = match (order_type, is_futures) {
+        (OrderType::Market, _) => BinanceOrderType::Market,
+        (OrderType::Limit, false) if is_post_only => BinanceOrderType::LimitMaker,
+        (OrderType::Limit, _) => BinanceOrderType::Limit,
+        (OrderType::StopMarket, false) => BinanceOrderType::StopLoss,
+        (OrderType::StopMarket, true) => BinanceOrderType::StopMarket,
+        (OrderType::StopLimit, false) => BinanceOrderType::StopLossLimit,
+        (OrderType::StopLimit, true) => BinanceOrderType::Stop,
+        (OrderType::MarketIfTouched, false) => BinanceOrderType::TakeProfit,
+        (OrderType::MarketIfTouched, true) => BinanceOrderType::TakeProfitMarket,
+        (OrderType::LimitIfTouched, false) => BinanceOrderType::TakeProfitLimit,
+        (OrderType::LimitIfTouched, true) => BinanceOrderType::TakeProfit,
+        (OrderType::TrailingStopMarket, true) => BinanceOrderType::TrailingStopMarket,
+        _ => anyhow::bail!(
+            "Order type {order_type} not supported for Binance {}",
+            if is_futures { "futures" } else { "Spot" }
+        ),
+    };
+    Ok(binance_order_type)
+}
+
+fn parse_time_in_force(
+    time_in_force: TimeInForce,
+    is_futures: bool,
+) -> anyhow::Result<BinanceTimeInForce> {
+    match time_in_force {
+        TimeInForce::Gtc => Ok(BinanceTimeInForce::Gtc),
+        TimeInForce::Ioc => Ok(BinanceTimeInForce::Ioc),
+        TimeInForce::Fok => Ok(BinanceTimeInForce::Fok),
+        TimeInForce::Gtd if is_futures => Ok(BinanceTimeInForce::Gtd),
+        _ => anyhow::bail!("Time in force {time_in_force} not supported"),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        identifiers::{ClientOrderId, InstrumentId},
+        instruments::stubs::{crypto_perpetual_ethusdt, currency_pair_btcusdt},
+        orders::OrderTestBuilder,
+        types::{Price, Quantity},
+    };
+    use nautilus_test_kit::load_test_json;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::websocket::messages::{parse_user_data_event, BinanceUserDataEvent};
+
+    fn instrument_id(product_type: BinanceProductType) -> InstrumentId {
+        match product_type {
+            BinanceProductType::Spot => InstrumentId::from("BTCUSDT.BINANCE"),
+            _ => InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+        }
+    }
+
+    fn limit_order(product_type: BinanceProductType, post_only: bool) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id(product_type))
+            .client_order_id(ClientOrderId::from("O-123"))
+            .side(OrderSide::Buy)
+            .price(Price::from("50000.10"))
+            .quantity(Quantity::from("0.010"))
+            .post_only(post_only)
+            .build()
+    }
+
+    #[rstest]
+    fn test_parse_limit_order_spot() {
+        let order = limit_order(BinanceProductType::Spot, false);
+
+        let params = parse_order_params(&order, BinanceProductType::Spot).unwrap();
+
+        assert_eq!(params.symbol, "BTCUSDT");
+        assert_eq!(params.side, BinanceOrderSide::Buy);
+        assert_eq!(params.order_type, BinanceOrderType::Limit);
+        assert_eq!(params.time_in_force, Some(BinanceTimeInForce::Gtc));
+        assert_eq!(params.price, Some("50000.10".to_string()));
+        assert_eq!(params.quantity, "0.010");
+        assert_eq!(params.new_client_order_id, "O-123");
+        assert_eq!(params.reduce_only, None);
+        assert_eq!(params.new_order_resp_type, Some("RESULT".to_string()));
+    }
+
+    #[rstest]
+    #[case(BinanceProductType::Spot, BinanceOrderType::LimitMaker, None)]
+    #[case(
+        BinanceProductType::UsdM,
+        BinanceOrderType::Limit,
+        Some(BinanceTimeInForce::Gtx)
+    )]
+    fn test_parse_post_only_limit_order(
+        #[case] product_type: BinanceProductType,
+        #[case] expected_type: BinanceOrderType,
+        #[case] expected_tif: Option<BinanceTimeInForce>,
+    ) {
+        let order = limit_order(product_type, true);
+
+        let params = parse_order_params(&order, product_type).unwrap();
+
+        assert_eq!(params.symbol, "BTCUSDT");
+        assert_eq!(params.order_type, expected_type);
+        assert_eq!(params.time_in_force, expected_tif);
+    }
+
+    #[rstest]
+    #[case(
+        OrderType::StopMarket,
+        BinanceProductType::Spot,
+        BinanceOrderType::StopLoss
+    )]
+    #[case(
+        OrderType::StopMarket,
+        BinanceProductType::UsdM,
+        BinanceOrderType::StopMarket
+    )]
+    #[case(
+        OrderType::StopLimit,
+        BinanceProductType::Spot,
+        BinanceOrderType::StopLossLimit
+    )]
+    #[case(OrderType::StopLimit, BinanceProductType::UsdM, BinanceOrderType::Stop)]
+    #[case(
+        OrderType::MarketIfTouched,
+        BinanceProductType::Spot,
+        BinanceOrderType::TakeProfit
+    )]
+    #[case(
+        OrderType::MarketIfTouched,
+        BinanceProductType::UsdM,
+        BinanceOrderType::TakeProfitMarket
+    )]
+    #[case(
+        OrderType::LimitIfTouched,
+        BinanceProductType::Spot,
+        BinanceOrderType::TakeProfitLimit
+    )]
+    #[case(
+        OrderType::LimitIfTouched,
+        BinanceProductType::UsdM,
+        BinanceOrderType::TakeProfit
+    )]
+    fn test_parse_conditional_order(
+        #[case] order_type: OrderType,
+        #[case] product_type: BinanceProductType,
+        #[case] expected: BinanceOrderType,
+    ) {
+        let mut builder = OrderTestBuilder::new(order_type);
+        builder
+            .instrument_id(instrument_id(product_type))
+            .side(OrderSide::Sell)
+            .trigger_price(Price::from("49000.00"))
+            .quantity(Quantity::from("0.010"));
+        if matches!(order_type, OrderType::StopLimit | OrderType::LimitIfTouched) {
+            builder.price(Price::from("48900.00"));
+        }
+        let order = builder.build();
+
+        let params = parse_order_params(&order, product_type).unwrap();
+
+        assert_eq!(params.order_type, expected);
+        assert_eq!(params.stop_price, Some("49000.00".to_string()));
+    }
+
+    #[rstest]
+    fn test_parse_reduce_only_market_order_futures() {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_id(BinanceProductType::UsdM))
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from("0.010"))
+            .reduce_only(true)
+            .build();
+
+        let params = parse_order_params(&order, BinanceProductType::UsdM).unwrap();
+
+        assert_eq!(params.order_type, BinanceOrderType::Market);
+        assert_eq!(params.reduce_only, Some(true));
+        assert_eq!(params.new_order_resp_type, None);
+    }
+
+    #[rstest]
+    fn test_parse_reduce_only_spot_errors() {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_id(BinanceProductType::Spot))
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from("0.010"))
+            .reduce_only(true)
+            .build();
+
+        assert!(parse_order_params(&order, BinanceProductType::Spot).is_err());
+    }
+
+    #[rstest]
+    fn test_parse_trailing_stop_market_order() {
+        let order = OrderTestBuilder::new(OrderType::TrailingStopMarket)
+            .instrument_id(instrument_id(BinanceProductType::UsdM))
+            .side(OrderSide::Sell)
+            .trigger_price(Price::from("51000.00"))
+            .trailing_offset(Price::from("150"))
+            .trailing_offset_type(TrailingOffsetType::BasisPoints)
+            .quantity(Quantity::from("0.010"))
+            .build();
+
+        let params = parse_order_params(&order, BinanceProductType::UsdM).unwrap();
+
+        assert_eq!(params.order_type, BinanceOrderType::TrailingStopMarket);
+        assert_eq!(params.callback_rate, Some("1.5".to_string()));
+        assert_eq!(params.activation_price, Some("51000.00".to_string()));
+        assert_eq!(params.stop_price, None);
+    }
+
+    #[rstest]
+    fn test_parse_gtd_order_futures() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id(BinanceProductType::UsdM))
+            .side(OrderSide::Buy)
+            .price(Price::from("50000.10"))
+            .quantity(Quantity::from("0.010"))
+            .time_in_force(TimeInForce::Gtd)
+            .expire_time(UnixNanos::from(1_700_000_000_123_000_000))
+            .build();
+
+        let params = parse_order_params(&order, BinanceProductType::UsdM).unwrap();
+
+        assert_eq!(params.time_in_force, Some(BinanceTimeInForce::Gtd));
+        assert_eq!(params.good_till_date, Some(1_700_000_000_123));
+    }
+
+    #[rstest]
+    #[case(OrderType::TrailingStopMarket, BinanceProductType::Spot)]
+    #[case(OrderType::MarketToLimit, BinanceProductType::UsdM)]
+    #[case(OrderType::TrailingStopLimit, BinanceProductType::UsdM)]
+    fn test_parse_unsupported_order_type(
+        #[case] order_type: OrderType,
+        #[case] product_type: BinanceProductType,
+    ) {
+        assert!(parse_order_type(order_type, false, product_type.is_futures()).is_err());
+    }
+
+    #[rstest]
+    fn test_parse_day_time_in_force_errors() {
+        assert!(parse_time_in_force(TimeInForce::Day, true).is_err());
+        assert!(parse_time_in_force(TimeInForce::Gtd, false).is_err());
+    }
+
+    fn order_update(file_name: &str) -> BinanceOrderUpdateMsg {
+        let event = parse_user_data_event(&load_test_json!(file_name)).unwrap();
+        let BinanceUserDataEvent::OrderUpdate(msg) = event else {
+            panic!("Expected order update, was {event:?}");
+        };
+        msg
+    }
+
+    #[rstest]
+    fn test_parse_order_update_trade_spot() {
+        let msg = order_update("ws_execution_report.json");
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt());
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .client_order_id(ClientOrderId::from(msg.client_order_id.as_str()))
+            .side(OrderSide::Buy)
+            .price(Price::from("42000.00"))
+            .quantity(Quantity::from("0.010000"))
+            .build();
+        let account_id = AccountId::from("BINANCE-001");
+
+        let event = parse_order_update(&msg, &order, &instrument, account_id, UnixNanos::default())
+            .unwrap()
+            .unwrap();
+
+        let OrderEventAny::Filled(fill) = event else {
+            panic!("Expected fill, was {event:?}");
+        };
+        assert_eq!(fill.client_order_id, order.client_order_id());
+        assert_eq!(fill.venue_order_id, VenueOrderId::from("4293153"));
+        assert_eq!(fill.account_id, account_id);
+        assert_eq!(fill.trade_id, TradeId::from("12345"));
+        assert_eq!(fill.order_side, OrderSide::Buy);
+        assert_eq!(fill.order_type, OrderType::Limit);
+        assert_eq!(fill.last_qty, Quantity::from("0.005000"));
+        assert_eq!(fill.last_px, Price::from("42000.00"));
+        assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+        assert_eq!(fill.commission, Some(Money::from("0.00000500 BTC")));
+        assert_eq!(fill.ts_event, UnixNanos::from(1_700_000_000_299_000_000));
+    }
+
+    #[rstest]
+    fn test_parse_order_update_new_futures() {
+        let msg = order_update("ws_order_trade_update.json");
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt());
+        let order = OrderTestBuilder::new(OrderType::TrailingStopMarket)
+            .instrument_id(instrument.id())
+            .client_order_id(ClientOrderId::from(msg.client_order_id.as_str()))
+            .side(OrderSide::Sell)
+            .trigger_price(Price::from("7476.89"))
+            .trailing_offset(Price::from("500"))
+            .trailing_offset_type(TrailingOffsetType::BasisPoints)
+            .quantity(Quantity::from("0.001"))
+            .build();
+
+        let event = parse_order_update(
+            &msg,
+            &order,
+            &instrument,
+            AccountId::from("BINANCE-001"),
+            UnixNanos::default(),
+        )
+        .unwrap()
+        .unwrap();
+
+        let OrderEventAny::Accepted(accepted) = event else {
+            panic!("Expected accepted, was {event:?}");
+        };
+        assert_eq!(accepted.client_order_id, order.client_order_id());
+        assert_eq!(accepted.venue_order_id, VenueOrderId::from("8886774"));
+    }
+
+    #[rstest]
+    fn test_parse_order_update_unhandled_execution_type() {
+        let mut msg = order_update("ws_execution_report.json");
+        msg.execution_type = "TRADE_PREVENTION".to_string();
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt());
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .quantity(Quantity::from("0.010000"))
+            .build();
+
+        let event = parse_order_update(
+            &msg,
+            &order,
+            &instrument,
+            AccountId::from("BINANCE-001"),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert!(event.is_none());
+    }
+}
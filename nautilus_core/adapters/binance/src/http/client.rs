@@ -0,0 +1,385 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, num::NonZeroU32};
+
+use bytes::Bytes;
+use nautilus_core::{time::get_atomic_clock_realtime, version::USER_AGENT};
+use nautilus_model::instruments::InstrumentAny;
+use nautilus_network::{
+    http::{HttpClient, HttpClientError},
+    ratelimiter::quota::Quota,
+};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+use super::{
+    models::{
+        BinanceDepthSnapshot, BinanceErrorResponse, BinanceExchangeInfo, BinanceListenKey,
+        BinanceNewOrderParams, BinanceOrderResponse,
+    },
+    parse::parse_instrument_any,
+};
+use crate::common::{
+    consts::BINANCE_RECV_WINDOW_MS, credential::BinanceCredential, enums::BinanceProductType,
+};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// HTTP errors for the Binance HTTP client.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error when a signed or keyed request is made without credentials.
+    #[error("Credentials are required for this request")]
+    MissingCredentials,
+    /// An error when sending a request to the server.
+    #[error("Error sending request: {0}")]
+    Request(#[from] HttpClientError),
+    /// An API error returned by Binance.
+    #[error("Binance API error {code}: {message}")]
+    ApiError { code: i64, message: String },
+    /// An unsuccessful response which was not a Binance API error.
+    #[error("Unexpected HTTP status {status}: {body}")]
+    UnexpectedStatus { status: u16, body: String },
+    /// An error when deserializing the response from the server.
+    #[error("Error deserializing message: {0}")]
+    Deserialization(#[from] serde_json::Error),
+}
+
+/// The security type of a Binance endpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Security {
+    /// Public endpoint, no API key required.
+    None,
+    /// Requires the API key header.
+    ApiKey,
+    /// Requires the API key header and a signed query string.
+    Signed,
+}
+
+/// A Binance REST API client for the Spot, USD-M and COIN-M futures APIs.
+///
+/// Requests are weighted against the Binance request weight limit for the product type,
+/// with any `429` responses backing off according to the `Retry-After` header.
+#[derive(Clone)]
+pub struct BinanceHttpClient {
+    product_type: BinanceProductType,
+    base_url: String,
+    credential: Option<BinanceCredential>,
+    client: HttpClient,
+}
+
+impl BinanceHttpClient {
+    /// Creates a new [`BinanceHttpClient`] instance.
+    ///
+    /// Public endpoints can be used without a `credential`.
+    #[must_use]
+    pub fn new(
+        product_type: BinanceProductType,
+        credential: Option<BinanceCredential>,
+        base_url: Option<&str>,
+        is_testnet: bool,
+    ) -> Self {
+        let base_url = base_url.map_or_else(
+            || product_type.http_url(is_testnet).to_string(),
+            ToString::to_string,
+        );
+        let weight_limit = NonZeroU32::new(product_type.request_weight_per_minute())
+            .expect("Request weight limit should be positive");
+        let client = HttpClient::new(
+            HashMap::from([("User-Agent".to_string(), USER_AGENT.to_string())]),
+            vec![],
+            vec![],
+            None,
+            Some(Quota::per_minute(weight_limit)),
+            false,
+            0, // Requests are signed with a timestamp, so retries are left to the caller
+        );
+
+        Self {
+            product_type,
+            base_url,
+            credential,
+            client,
+        }
+    }
+
+    /// Returns the product type for the client.
+    #[must_use]
+    pub const fn product_type(&self) -> BinanceProductType {
+        self.product_type
+    }
+
+    /// Returns the base URL for the client.
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the exchange trading rules and symbol information.
+    /// See <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/general-endpoints#exchange-information>.
+    pub async fn exchange_info(&self) -> Result<BinanceExchangeInfo> {
+        let weight = match self.product_type {
+            BinanceProductType::Spot => 20,
+            BinanceProductType::UsdM | BinanceProductType::CoinM => 1,
+        };
+        let path = format!("{}/exchangeInfo", self.product_type.api_path());
+        self.send_json(Method::GET, &path, vec![], Security::None, weight)
+            .await
+    }
+
+    /// Returns Nautilus instruments for all currently trading Binance symbols.
+    ///
+    /// Symbols which cannot be parsed are logged and skipped.
+    pub async fn instruments(&self) -> Result<Vec<InstrumentAny>> {
+        let info = self.exchange_info().await?;
+        let ts_init = get_atomic_clock_realtime().get_time_ns();
+
+        Ok(info
+            .symbols
+            .iter()
+            .filter(|symbol| symbol.is_trading())
+            .filter_map(
+                |symbol| match parse_instrument_any(symbol, self.product_type, ts_init) {
+                    Ok(instrument) => Some(instrument),
+                    Err(e) => {
+                        tracing::warn!("Failed to parse instrument {}: {e}", symbol.symbol);
+                        None
+                    }
+                },
+            )
+            .collect())
+    }
+
+    /// Returns an order book snapshot for the given `symbol`, with up to `limit` levels per side.
+    /// See <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/market-data-endpoints#order-book>.
+    pub async fn depth_snapshot(
+        &self,
+        symbol: &str,
+        limit: Option<u32>,
+    ) -> Result<BinanceDepthSnapshot> {
+        let limit = limit.unwrap_or(1_000);
+        let path = format!("{}/depth", self.product_type.api_path());
+        let params = vec![
+            ("symbol".to_string(), symbol.to_string()),
+            ("limit".to_string(), limit.to_string()),
+        ];
+        let weight = depth_weight(self.product_type, limit);
+        self.send_json(Method::GET, &path, params, Security::None, weight)
+            .await
+    }
+
+    /// Creates a new user data stream, returning the listen key.
+    pub async fn create_listen_key(&self) -> Result<String> {
+        let path = self.product_type.listen_key_path();
+        let response: BinanceListenKey = self
+            .send_json(Method::POST, path, vec![], Security::ApiKey, 2)
+            .await?;
+        Ok(response.listen_key)
+    }
+
+    /// Extends the validity of the user data stream for the given `listen_key` by 60 minutes.
+    pub async fn keepalive_listen_key(&self, listen_key: &str) -> Result<()> {
+        let path = self.product_type.listen_key_path();
+        let params = vec![("listenKey".to_string(), listen_key.to_string())];
+        self.send(Method::PUT, path, params, Security::ApiKey, 2)
+            .await?;
+        Ok(())
+    }
+
+    /// Closes the user data stream for the given `listen_key`.
+    pub async fn close_listen_key(&self, listen_key: &str) -> Result<()> {
+        let path = self.product_type.listen_key_path();
+        let params = vec![("listenKey".to_string(), listen_key.to_string())];
+        self.send(Method::DELETE, path, params, Security::ApiKey, 2)
+            .await?;
+        Ok(())
+    }
+
+    /// Places a new order.
+    /// See <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/trading-endpoints#new-order-trade>.
+    pub async fn new_order(&self, params: &BinanceNewOrderParams) -> Result<BinanceOrderResponse> {
+        let path = format!("{}/order", self.product_type.api_path());
+        self.send_json(
+            Method::POST,
+            &path,
+            params.to_query_params(),
+            Security::Signed,
+            1,
+        )
+        .await
+    }
+
+    /// Cancels the open order with the given `client_order_id` for the `symbol`.
+    pub async fn cancel_order(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> Result<BinanceOrderResponse> {
+        let path = format!("{}/order", self.product_type.api_path());
+        let params = vec![
+            ("symbol".to_string(), symbol.to_string()),
+            ("origClientOrderId".to_string(), client_order_id.to_string()),
+        ];
+        self.send_json(Method::DELETE, &path, params, Security::Signed, 1)
+            .await
+    }
+
+    async fn send_json<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        params: Vec<(String, String)>,
+        security: Security,
+        weight: u32,
+    ) -> Result<T> {
+        let body = self.send(method, path, params, security, weight).await?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        mut params: Vec<(String, String)>,
+        security: Security,
+        weight: u32,
+    ) -> Result<Bytes> {
+        let mut headers = None;
+        if security != Security::None {
+            let credential = self.credential.as_ref().ok_or(Error::MissingCredentials)?;
+            headers = Some(HashMap::from([(
+                "X-MBX-APIKEY".to_string(),
+                credential.api_key().expose().to_string(),
+            )]));
+
+            if security == Security::Signed {
+                let timestamp = get_atomic_clock_realtime().get_time_ms();
+                params.push(("recvWindow".to_string(), BINANCE_RECV_WINDOW_MS.to_string()));
+                params.push(("timestamp".to_string(), timestamp.to_string()));
+                let signature = credential.sign(&encode_query(&params));
+                params.push(("signature".to_string(), signature));
+            }
+        }
+
+        let url = match params.is_empty() {
+            true => format!("{}{path}", self.base_url),
+            false => format!("{}{path}?{}", self.base_url, encode_query(&params)),
+        };
+        tracing::debug!("Sending {method} {}{path}", self.base_url);
+
+        let response = self
+            .client
+            .request(method, url, headers, None, None, None, Some(weight))
+            .await?;
+
+        if response.status >= 400 {
+            return Err(parse_error_response(response.status, &response.body));
+        }
+
+        Ok(response.body)
+    }
+}
+
+/// Encodes the given `params` as a query string, preserving their order (which must
+/// match the order used to compute a signature).
+fn encode_query(params: &[(String, String)]) -> String {
+    params
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn parse_error_response(status: u16, body: &[u8]) -> Error {
+    match serde_json::from_slice::<BinanceErrorResponse>(body) {
+        Ok(error) => Error::ApiError {
+            code: error.code,
+            message: error.msg,
+        },
+        Err(_) => Error::UnexpectedStatus {
+            status,
+            body: String::from_utf8_lossy(body).to_string(),
+        },
+    }
+}
+
+/// Returns the request weight of the `depth` endpoint for the given `limit`.
+const fn depth_weight(product_type: BinanceProductType, limit: u32) -> u32 {
+    match product_type {
+        BinanceProductType::Spot => match limit {
+            ..=100 => 5,
+            101..=500 => 25,
+            501..=1_000 => 50,
+            _ => 250,
+        },
+        BinanceProductType::UsdM | BinanceProductType::CoinM => match limit {
+            ..=50 => 2,
+            51..=100 => 5,
+            101..=500 => 10,
+            _ => 20,
+        },
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(BinanceProductType::Spot, 100, 5)]
+    #[case(BinanceProductType::Spot, 1_000, 50)]
+    #[case(BinanceProductType::Spot, 5_000, 250)]
+    #[case(BinanceProductType::UsdM, 20, 2)]
+    #[case(BinanceProductType::CoinM, 1_000, 20)]
+    fn test_depth_weight(
+        #[case] product_type: BinanceProductType,
+        #[case] limit: u32,
+        #[case] expected: u32,
+    ) {
+        assert_eq!(depth_weight(product_type, limit), expected);
+    }
+
+    #[rstest]
+    fn test_parse_error_response() {
+        let error = parse_error_response(400, br#"{"code":-1121,"msg":"Invalid symbol."}"#);
+
+        assert!(matches!(
+            error,
+            Error::ApiError { code: -1121, ref message } if message == "Invalid symbol."
+        ));
+    }
+
+    #[rstest]
+    fn test_parse_error_response_without_api_error() {
+        let error = parse_error_response(502, b"Bad Gateway");
+
+        assert!(matches!(error, Error::UnexpectedStatus { status: 502, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_signed_request_without_credentials_fails() {
+        let client = BinanceHttpClient::new(BinanceProductType::Spot, None, None, false);
+
+        let result = client.create_listen_key().await;
+
+        assert!(matches!(result, Err(Error::MissingCredentials)));
+    }
+}
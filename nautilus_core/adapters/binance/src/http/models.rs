@@ -0,0 +1,302 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use serde::Deserialize;
+use ustr::Ustr;
+
+use crate::common::enums::{
+    BinanceOrderSide, BinanceOrderStatus, BinanceOrderType, BinanceTimeInForce,
+};
+
+/// An error response returned by the Binance REST API.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BinanceErrorResponse {
+    pub code: i64,
+    pub msg: String,
+}
+
+/// The response from the `exchangeInfo` endpoint.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceExchangeInfo {
+    pub server_time: u64,
+    pub symbols: Vec<BinanceSymbolInfo>,
+}
+
+/// The trading rules and metadata for a single Binance symbol.
+///
+/// Futures only fields are optional so the same model can be used for every product type.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceSymbolInfo {
+    pub symbol: Ustr,
+    /// The trading status (`contractStatus` for COIN-M futures).
+    #[serde(default, alias = "contractStatus")]
+    pub status: Option<String>,
+    pub base_asset: Ustr,
+    pub quote_asset: Ustr,
+    #[serde(default)]
+    pub margin_asset: Option<Ustr>,
+    #[serde(default)]
+    pub contract_type: Option<String>,
+    #[serde(default)]
+    pub delivery_date: Option<u64>,
+    #[serde(default)]
+    pub onboard_date: Option<u64>,
+    #[serde(default)]
+    pub contract_size: Option<u64>,
+    pub filters: Vec<BinanceSymbolFilter>,
+}
+
+impl BinanceSymbolInfo {
+    /// Returns whether the symbol is currently trading.
+    #[must_use]
+    pub fn is_trading(&self) -> bool {
+        self.status.as_deref() == Some("TRADING")
+    }
+
+    /// Returns whether the symbol is a perpetual futures contract.
+    #[must_use]
+    pub fn is_perpetual(&self) -> bool {
+        self.contract_type.as_deref() == Some("PERPETUAL")
+    }
+}
+
+/// A trading rule filter for a Binance symbol.
+/// See <https://developers.binance.com/docs/binance-spot-api-docs/filters>.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "filterType", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceSymbolFilter {
+    #[serde(rename_all = "camelCase")]
+    PriceFilter {
+        min_price: String,
+        max_price: String,
+        tick_size: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    LotSize {
+        min_qty: String,
+        max_qty: String,
+        step_size: String,
+    },
+    /// The minimum notional (`NOTIONAL` for spot, `notional` field for USD-M futures).
+    #[serde(alias = "NOTIONAL", rename_all = "camelCase")]
+    MinNotional {
+        #[serde(alias = "notional")]
+        min_notional: String,
+    },
+    #[serde(other)]
+    Other,
+}
+
+/// A single price level of an order book `[price, quantity]`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct BinanceBookLevel(pub String, pub String);
+
+/// The response from the `depth` endpoint.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceDepthSnapshot {
+    pub last_update_id: u64,
+    pub bids: Vec<BinanceBookLevel>,
+    pub asks: Vec<BinanceBookLevel>,
+}
+
+/// The response when creating a user data stream.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceListenKey {
+    pub listen_key: String,
+}
+
+/// The parameters for the new order endpoint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BinanceNewOrderParams {
+    pub symbol: String,
+    pub side: BinanceOrderSide,
+    pub order_type: BinanceOrderType,
+    pub time_in_force: Option<BinanceTimeInForce>,
+    pub quantity: String,
+    pub price: Option<String>,
+    pub stop_price: Option<String>,
+    /// Futures only.
+    pub reduce_only: Option<bool>,
+    pub new_client_order_id: String,
+    /// Futures only, the trailing callback rate in percent.
+    pub callback_rate: Option<String>,
+    /// Futures only, the price at which a trailing stop activates.
+    pub activation_price: Option<String>,
+    /// Futures only, the expire time (milliseconds) for `GTD` orders.
+    pub good_till_date: Option<u64>,
+    /// Spot only, requests the order status in the response (`RESULT`).
+    pub new_order_resp_type: Option<String>,
+}
+
+impl BinanceNewOrderParams {
+    /// Returns the parameters as query string key-value pairs, omitting any unset values.
+    #[must_use]
+    pub fn to_query_params(&self) -> Vec<(String, String)> {
+        let mut params = vec![
+            ("symbol".to_string(), self.symbol.clone()),
+            ("side".to_string(), self.side.to_string()),
+            ("type".to_string(), self.order_type.to_string()),
+        ];
+        let mut push = |key: &str, value: Option<String>| {
+            if let Some(value) = value {
+                params.push((key.to_string(), value));
+            }
+        };
+
+        push("timeInForce", self.time_in_force.map(|tif| tif.to_string()));
+        push("quantity", Some(self.quantity.clone()));
+        push("price", self.price.clone());
+        push("stopPrice", self.stop_price.clone());
+        push(
+            "reduceOnly",
+            self.reduce_only.map(|value| value.to_string()),
+        );
+        push("newClientOrderId", Some(self.new_client_order_id.clone()));
+        push("callbackRate", self.callback_rate.clone());
+        push("activationPrice", self.activation_price.clone());
+        push(
+            "goodTillDate",
+            self.good_till_date.map(|value| value.to_string()),
+        );
+        push("newOrderRespType", self.new_order_resp_type.clone());
+        params
+    }
+}
+
+/// The response when placing, querying or canceling an order.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceOrderResponse {
+    pub symbol: Ustr,
+    pub order_id: u64,
+    pub client_order_id: String,
+    /// The client order ID of the canceled order (spot cancel responses only).
+    #[serde(default)]
+    pub orig_client_order_id: Option<String>,
+    pub status: BinanceOrderStatus,
+    pub price: String,
+    pub orig_qty: String,
+    pub executed_qty: String,
+    #[serde(rename = "type")]
+    pub order_type: BinanceOrderType,
+    pub side: BinanceOrderSide,
+    #[serde(default)]
+    pub reduce_only: Option<bool>,
+    #[serde(default, alias = "transactTime")]
+    pub update_time: Option<u64>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_test_kit::load_test_json;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_deserialize_spot_exchange_info() {
+        let json_data = load_test_json!("http_exchange_info_spot.json");
+        let info: BinanceExchangeInfo = serde_json::from_str(&json_data).unwrap();
+        let symbol = &info.symbols[0];
+
+        assert_eq!(symbol.symbol, "BTCUSDT");
+        assert!(symbol.is_trading());
+        assert!(!symbol.is_perpetual());
+        assert!(matches!(
+            &symbol.filters[0],
+            BinanceSymbolFilter::PriceFilter { tick_size, .. } if tick_size == "0.01000000"
+        ));
+        assert!(symbol
+            .filters
+            .iter()
+            .any(|f| matches!(f, BinanceSymbolFilter::MinNotional { min_notional } if min_notional == "5.00000000")));
+        assert!(symbol
+            .filters
+            .iter()
+            .any(|f| matches!(f, BinanceSymbolFilter::Other)));
+    }
+
+    #[rstest]
+    fn test_deserialize_usdm_exchange_info() {
+        let json_data = load_test_json!("http_exchange_info_usdm.json");
+        let info: BinanceExchangeInfo = serde_json::from_str(&json_data).unwrap();
+
+        assert_eq!(info.symbols.len(), 2);
+        assert!(info.symbols[0].is_perpetual());
+        assert_eq!(info.symbols[0].margin_asset, Some(Ustr::from("USDT")));
+        assert!(!info.symbols[1].is_perpetual());
+        assert_eq!(info.symbols[1].delivery_date, Some(1_743_148_800_000));
+    }
+
+    #[rstest]
+    fn test_deserialize_order_response() {
+        let json_data = load_test_json!("http_order_response.json");
+        let response: BinanceOrderResponse = serde_json::from_str(&json_data).unwrap();
+
+        assert_eq!(response.order_id, 22_542_179);
+        assert_eq!(response.status, BinanceOrderStatus::New);
+        assert_eq!(response.order_type, BinanceOrderType::Limit);
+        assert_eq!(response.side, BinanceOrderSide::Sell);
+        assert_eq!(response.reduce_only, Some(true));
+        assert_eq!(response.update_time, Some(1_700_000_000_123));
+    }
+
+    #[rstest]
+    fn test_new_order_params_to_query_params() {
+        let params = BinanceNewOrderParams {
+            symbol: "BTCUSDT".to_string(),
+            side: BinanceOrderSide::Buy,
+            order_type: BinanceOrderType::Limit,
+            time_in_force: Some(BinanceTimeInForce::Gtx),
+            quantity: "0.010".to_string(),
+            price: Some("42000.0".to_string()),
+            stop_price: None,
+            reduce_only: Some(true),
+            new_client_order_id: "O-1".to_string(),
+            callback_rate: None,
+            activation_price: None,
+            good_till_date: None,
+            new_order_resp_type: None,
+        };
+
+        let query: Vec<String> = params
+            .to_query_params()
+            .into_iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect();
+
+        assert_eq!(
+            query.join("&"),
+            "symbol=BTCUSDT&side=BUY&type=LIMIT&timeInForce=GTX&quantity=0.010&price=42000.0&reduceOnly=true&newClientOrderId=O-1"
+        );
+    }
+
+    #[rstest]
+    fn test_deserialize_depth_snapshot() {
+        let json_data = load_test_json!("http_depth_snapshot.json");
+        let snapshot: BinanceDepthSnapshot = serde_json::from_str(&json_data).unwrap();
+
+        assert_eq!(snapshot.last_update_id, 1_027_024);
+        assert_eq!(snapshot.bids.len(), 2);
+        assert_eq!(snapshot.asks[0].0, "4.00000200");
+    }
+}
@@ -0,0 +1,307 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    currencies::CURRENCY_MAP,
+    enums::CurrencyType,
+    identifiers::Symbol,
+    instruments::{CryptoFuture, CryptoPerpetual, CurrencyPair, InstrumentAny},
+    types::{Currency, Money, Price, Quantity},
+};
+
+use super::models::{BinanceSymbolFilter, BinanceSymbolInfo};
+use crate::common::{
+    enums::BinanceProductType,
+    parse::{normalize_decimal_str, parse_instrument_id, parse_millis_timestamp},
+};
+
+/// The trading rules parsed from the filters of a Binance symbol.
+#[derive(Clone, Debug)]
+struct SymbolRules {
+    price_increment: Price,
+    size_increment: Quantity,
+    min_price: Option<Price>,
+    max_price: Option<Price>,
+    min_quantity: Option<Quantity>,
+    max_quantity: Option<Quantity>,
+    min_notional: Option<f64>,
+}
+
+/// Parses a Nautilus instrument from the given Binance symbol `info`.
+///
+/// Spot symbols are parsed as [`CurrencyPair`]s, perpetual futures as [`CryptoPerpetual`]s and
+/// delivery futures as [`CryptoFuture`]s. COIN-M futures are inverse, with the contract size
+/// as the multiplier. The price and size precisions are derived from the tick and step sizes.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the symbol has no `PRICE_FILTER` or `LOT_SIZE` filter.
+/// - If any filter value is not a valid decimal.
+/// - If a futures symbol is missing its contract fields.
+pub fn parse_instrument_any(
+    info: &BinanceSymbolInfo,
+    product_type: BinanceProductType,
+    ts_init: UnixNanos,
+) -> anyhow::Result<InstrumentAny> {
+    let rules = parse_symbol_rules(&info.filters)?;
+    let instrument_id = parse_instrument_id(&info.symbol, product_type);
+    let raw_symbol = Symbol::new(info.symbol);
+    let base_currency = get_currency(&info.base_asset);
+    let quote_currency = get_currency(&info.quote_asset);
+    let min_notional = rules
+        .min_notional
+        .map(|value| Money::new(value, quote_currency));
+
+    if product_type == BinanceProductType::Spot {
+        return Ok(InstrumentAny::CurrencyPair(CurrencyPair::new_checked(
+            instrument_id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            rules.price_increment.precision,
+            rules.size_increment.precision,
+            rules.price_increment,
+            rules.size_increment,
+            None,
+            rules.max_quantity,
+            rules.min_quantity,
+            None,
+            min_notional,
+            rules.max_price,
+            rules.min_price,
+            None,
+            None,
+            None,
+            None,
+            ts_init, // ts_event same as ts_init (no venue timestamp)
+            ts_init,
+        )?));
+    }
+
+    let settlement_currency = info
+        .margin_asset
+        .map_or(quote_currency, |asset| get_currency(&asset));
+    let is_inverse = product_type == BinanceProductType::CoinM;
+    let multiplier = info.contract_size.map(|size| Quantity::from(size as i64));
+
+    if info.is_perpetual() {
+        return Ok(InstrumentAny::CryptoPerpetual(
+            CryptoPerpetual::new_checked(
+                instrument_id,
+                raw_symbol,
+                base_currency,
+                quote_currency,
+                settlement_currency,
+                is_inverse,
+                rules.price_increment.precision,
+                rules.size_increment.precision,
+                rules.price_increment,
+                rules.size_increment,
+                multiplier,
+                None,
+                rules.max_quantity,
+                rules.min_quantity,
+                None,
+                min_notional,
+                rules.max_price,
+                rules.min_price,
+                None,
+                None,
+                None,
+                None,
+                ts_init, // ts_event same as ts_init (no venue timestamp)
+                ts_init,
+            )?,
+        ));
+    }
+
+    let activation = info
+        .onboard_date
+        .ok_or_else(|| anyhow::anyhow!("Missing `onboardDate` for {}", info.symbol))?;
+    let expiration = info
+        .delivery_date
+        .ok_or_else(|| anyhow::anyhow!("Missing `deliveryDate` for {}", info.symbol))?;
+
+    Ok(InstrumentAny::CryptoFuture(CryptoFuture::new_checked(
+        instrument_id,
+        raw_symbol,
+        base_currency,
+        quote_currency,
+        settlement_currency,
+        is_inverse,
+        parse_millis_timestamp(activation),
+        parse_millis_timestamp(expiration),
+        rules.price_increment.precision,
+        rules.size_increment.precision,
+        rules.price_increment,
+        rules.size_increment,
+        multiplier,
+        None,
+        rules.max_quantity,
+        rules.min_quantity,
+        None,
+        min_notional,
+        rules.max_price,
+        rules.min_price,
+        None,
+        None,
+        None,
+        None,
+        ts_init, // ts_event same as ts_init (no venue timestamp)
+        ts_init,
+    )?))
+}
+
+fn parse_symbol_rules(filters: &[BinanceSymbolFilter]) -> anyhow::Result<SymbolRules> {
+    let mut price_filter = None;
+    let mut lot_size = None;
+    let mut min_notional = None;
+
+    for filter in filters {
+        match filter {
+            BinanceSymbolFilter::PriceFilter {
+                min_price,
+                max_price,
+                tick_size,
+            } => price_filter = Some((min_price, max_price, tick_size)),
+            BinanceSymbolFilter::LotSize {
+                min_qty,
+                max_qty,
+                step_size,
+            } => lot_size = Some((min_qty, max_qty, step_size)),
+            BinanceSymbolFilter::MinNotional {
+                min_notional: value,
+            } => min_notional = Some(value.parse::<f64>()?),
+            BinanceSymbolFilter::Other => {}
+        }
+    }
+
+    let (min_price, max_price, tick_size) =
+        price_filter.ok_or_else(|| anyhow::anyhow!("Missing `PRICE_FILTER` filter"))?;
+    let (min_qty, max_qty, step_size) =
+        lot_size.ok_or_else(|| anyhow::anyhow!("Missing `LOT_SIZE` filter"))?;
+
+    let price_increment = Price::from(normalize_decimal_str(tick_size)?);
+    let size_increment = Quantity::from(normalize_decimal_str(step_size)?);
+
+    Ok(SymbolRules {
+        price_increment,
+        size_increment,
+        min_price: parse_optional_limit(min_price)?
+            .map(|value| Price::new(value, price_increment.precision)),
+        max_price: parse_optional_limit(max_price)?
+            .map(|value| Price::new(value, price_increment.precision)),
+        min_quantity: parse_optional_limit(min_qty)?
+            .map(|value| Quantity::new(value, size_increment.precision)),
+        max_quantity: parse_optional_limit(max_qty)?
+            .map(|value| Quantity::new(value, size_increment.precision)),
+        min_notional: min_notional.filter(|value| *value > 0.0),
+    })
+}
+
+/// Parses a filter limit, where a value of zero means the limit is disabled.
+fn parse_optional_limit(value: &str) -> anyhow::Result<Option<f64>> {
+    let value = value.parse::<f64>()?;
+    Ok((value > 0.0).then_some(value))
+}
+
+/// Returns the currency either from the internal currency map or creates a default crypto.
+fn get_currency(code: &str) -> Currency {
+    CURRENCY_MAP
+        .lock()
+        .unwrap()
+        .get(code)
+        .copied()
+        .unwrap_or(Currency::new(code, 8, 0, code, CurrencyType::Crypto))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::identifiers::InstrumentId;
+    use nautilus_test_kit::load_test_json;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::http::models::BinanceExchangeInfo;
+
+    fn exchange_info(file_name: &str) -> BinanceExchangeInfo {
+        serde_json::from_str(&load_test_json!(file_name)).unwrap()
+    }
+
+    #[rstest]
+    fn test_parse_spot_currency_pair() {
+        let info = exchange_info("http_exchange_info_spot.json");
+
+        let instrument = parse_instrument_any(
+            &info.symbols[0],
+            BinanceProductType::Spot,
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert!(matches!(instrument, InstrumentAny::CurrencyPair(_)));
+        assert_eq!(instrument.id(), InstrumentId::from("BTCUSDT.BINANCE"));
+        assert_eq!(instrument.raw_symbol(), Symbol::from("BTCUSDT"));
+        assert_eq!(instrument.base_currency(), Some(Currency::BTC()));
+        assert_eq!(instrument.quote_currency(), Currency::USDT());
+        assert_eq!(instrument.price_precision(), 2);
+        assert_eq!(instrument.size_precision(), 5);
+        assert_eq!(instrument.price_increment(), Price::from("0.01"));
+        assert_eq!(instrument.size_increment(), Quantity::from("0.00001"));
+        assert_eq!(instrument.min_quantity(), Some(Quantity::from("0.00001")));
+        assert_eq!(
+            instrument.min_notional(),
+            Some(Money::new(5.0, Currency::USDT()))
+        );
+    }
+
+    #[rstest]
+    fn test_parse_usdm_perpetual_and_future() {
+        let info = exchange_info("http_exchange_info_usdm.json");
+
+        let perpetual = parse_instrument_any(
+            &info.symbols[0],
+            BinanceProductType::UsdM,
+            UnixNanos::default(),
+        )
+        .unwrap();
+        let future = parse_instrument_any(
+            &info.symbols[1],
+            BinanceProductType::UsdM,
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert!(matches!(perpetual, InstrumentAny::CryptoPerpetual(_)));
+        assert_eq!(perpetual.id(), InstrumentId::from("BTCUSDT-PERP.BINANCE"));
+        assert_eq!(perpetual.settlement_currency(), Currency::USDT());
+        assert!(!perpetual.is_inverse());
+        assert_eq!(perpetual.price_precision(), 1);
+        assert_eq!(perpetual.size_precision(), 3);
+        assert_eq!(perpetual.max_quantity(), Some(Quantity::from("1000.000")));
+
+        assert!(matches!(future, InstrumentAny::CryptoFuture(_)));
+        assert_eq!(future.id(), InstrumentId::from("BTCUSDT_250328.BINANCE"));
+        assert_eq!(
+            future.expiration_ns(),
+            Some(UnixNanos::from(1_743_148_800_000_000_000))
+        );
+    }
+}
@@ -0,0 +1,328 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Local order book synchronization for Binance depth streams.
+//!
+//! Binance publishes order book diffs which must be sequenced against a REST snapshot.
+//! See <https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#how-to-manage-a-local-order-book-correctly>
+//! and <https://developers.binance.com/docs/derivatives/usds-margined-futures/websocket-market-streams/How-to-manage-a-local-order-book-correctly>.
+
+use super::messages::BinanceDepthUpdateMsg;
+use crate::common::enums::BinanceProductType;
+
+/// Errors which require the local order book to be resynchronized from a new snapshot.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum BookSyncError {
+    /// The snapshot is older than the first buffered diff, so a newer snapshot is required.
+    #[error(
+        "Snapshot last update ID {last_update_id} is older than buffered update {first_update_id}"
+    )]
+    StaleSnapshot {
+        last_update_id: u64,
+        first_update_id: u64,
+    },
+    /// A diff was missed between the previous and current update.
+    #[error("Gap in depth updates: previous final update ID {previous}, received update {first_update_id}..={final_update_id}")]
+    Gap {
+        previous: u64,
+        first_update_id: u64,
+        final_update_id: u64,
+    },
+}
+
+/// Sequences Binance depth diffs against a REST snapshot for a single symbol.
+///
+/// Diffs are buffered until a snapshot is applied with [`BinanceBookSynchronizer::apply_snapshot`],
+/// after which each diff is validated for continuity before it is returned to be applied.
+/// On any gap the synchronizer resets, buffering diffs until a new snapshot is applied.
+#[derive(Clone, Debug)]
+pub struct BinanceBookSynchronizer {
+    product_type: BinanceProductType,
+    buffer: Vec<BinanceDepthUpdateMsg>,
+    snapshot_update_id: Option<u64>,
+    last_final_update_id: Option<u64>,
+}
+
+impl BinanceBookSynchronizer {
+    /// Creates a new [`BinanceBookSynchronizer`] instance.
+    #[must_use]
+    pub const fn new(product_type: BinanceProductType) -> Self {
+        Self {
+            product_type,
+            buffer: Vec::new(),
+            snapshot_update_id: None,
+            last_final_update_id: None,
+        }
+    }
+
+    /// Returns whether a snapshot has been applied and diffs are being passed through.
+    #[must_use]
+    pub const fn is_synced(&self) -> bool {
+        self.snapshot_update_id.is_some()
+    }
+
+    /// Returns the number of diffs buffered while awaiting a snapshot.
+    #[must_use]
+    pub fn buffered_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Resets the synchronizer, discarding any buffered diffs.
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.snapshot_update_id = None;
+        self.last_final_update_id = None;
+    }
+
+    /// Applies a snapshot with the given `last_update_id`, returning the buffered diffs
+    /// which should be applied on top of the snapshot, in order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the snapshot is older than the first buffered diff (the buffer is retained).
+    /// - If there is a gap within the buffered diffs (the synchronizer is reset).
+    pub fn apply_snapshot(
+        &mut self,
+        last_update_id: u64,
+    ) -> Result<Vec<BinanceDepthUpdateMsg>, BookSyncError> {
+        // Drop any diffs already included in the snapshot
+        let is_futures = self.product_type.is_futures();
+        self.buffer.retain(|update| {
+            if is_futures {
+                update.final_update_id >= last_update_id
+            } else {
+                update.final_update_id > last_update_id
+            }
+        });
+
+        if let Some(first) = self.buffer.first() {
+            let first_update_id = first.first_update_id;
+            let is_stale = if is_futures {
+                first_update_id > last_update_id
+            } else {
+                first_update_id > last_update_id + 1
+            };
+            if is_stale {
+                return Err(BookSyncError::StaleSnapshot {
+                    last_update_id,
+                    first_update_id,
+                });
+            }
+        }
+
+        self.snapshot_update_id = Some(last_update_id);
+        self.last_final_update_id = None;
+
+        let buffered = std::mem::take(&mut self.buffer);
+        for update in &buffered {
+            if let Err(e) = self.check_sequence(update) {
+                self.reset();
+                return Err(e);
+            }
+            self.last_final_update_id = Some(update.final_update_id);
+        }
+
+        Ok(buffered)
+    }
+
+    /// Handles a depth diff, returning it if it should be applied to the local book.
+    ///
+    /// Returns `Ok(None)` if the diff was buffered (awaiting a snapshot) or is already
+    /// included in the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a diff was missed, in which case the synchronizer
+    /// is reset and the given `update` is buffered for the next snapshot.
+    pub fn handle_update(
+        &mut self,
+        update: BinanceDepthUpdateMsg,
+    ) -> Result<Option<BinanceDepthUpdateMsg>, BookSyncError> {
+        let Some(snapshot_update_id) = self.snapshot_update_id else {
+            self.buffer.push(update);
+            return Ok(None);
+        };
+
+        // Drop diffs already included in the snapshot, before the first diff is applied
+        if self.last_final_update_id.is_none() {
+            let is_included = if self.product_type.is_futures() {
+                update.final_update_id < snapshot_update_id
+            } else {
+                update.final_update_id <= snapshot_update_id
+            };
+            if is_included {
+                return Ok(None);
+            }
+        }
+
+        if let Err(e) = self.check_sequence(&update) {
+            self.reset();
+            self.buffer.push(update);
+            return Err(e);
+        }
+
+        self.last_final_update_id = Some(update.final_update_id);
+        Ok(Some(update))
+    }
+
+    fn check_sequence(&self, update: &BinanceDepthUpdateMsg) -> Result<(), BookSyncError> {
+        // SAFETY: Only called once a snapshot has been applied
+        let snapshot_update_id = self.snapshot_update_id.unwrap();
+        let is_futures = self.product_type.is_futures();
+
+        let (previous, is_valid) = match self.last_final_update_id {
+            // First diff after the snapshot must straddle the snapshot update ID
+            None if is_futures => (
+                snapshot_update_id,
+                update.first_update_id <= snapshot_update_id
+                    && update.final_update_id >= snapshot_update_id,
+            ),
+            None => (
+                snapshot_update_id,
+                update.first_update_id <= snapshot_update_id + 1
+                    && update.final_update_id > snapshot_update_id,
+            ),
+            // Futures diffs reference the previous final update ID
+            Some(previous) if is_futures => {
+                (previous, update.prev_final_update_id == Some(previous))
+            }
+            Some(previous) => (previous, update.first_update_id == previous + 1),
+        };
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(BookSyncError::Gap {
+                previous,
+                first_update_id: update.first_update_id,
+                final_update_id: update.final_update_id,
+            })
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use ustr::Ustr;
+
+    use super::*;
+
+    fn update(first: u64, last: u64, prev: Option<u64>) -> BinanceDepthUpdateMsg {
+        BinanceDepthUpdateMsg {
+            event_time: 0,
+            transaction_time: None,
+            symbol: Ustr::from("BTCUSDT"),
+            first_update_id: first,
+            final_update_id: last,
+            prev_final_update_id: prev,
+            bids: vec![],
+            asks: vec![],
+        }
+    }
+
+    fn final_ids(updates: &[BinanceDepthUpdateMsg]) -> Vec<u64> {
+        updates
+            .iter()
+            .map(|update| update.final_update_id)
+            .collect()
+    }
+
+    #[rstest]
+    fn test_spot_buffers_until_snapshot() {
+        let mut sync = BinanceBookSynchronizer::new(BinanceProductType::Spot);
+
+        assert_eq!(sync.handle_update(update(95, 99, None)), Ok(None));
+        assert_eq!(sync.handle_update(update(100, 104, None)), Ok(None));
+        assert_eq!(sync.handle_update(update(105, 110, None)), Ok(None));
+        assert_eq!(sync.buffered_len(), 3);
+
+        let applied = sync.apply_snapshot(102).unwrap();
+
+        // The first diff is dropped as it is included in the snapshot
+        assert_eq!(final_ids(&applied), vec![104, 110]);
+        assert!(sync.is_synced());
+
+        let next = sync.handle_update(update(111, 115, None)).unwrap();
+        assert_eq!(next.map(|update| update.final_update_id), Some(115));
+    }
+
+    #[rstest]
+    fn test_spot_stale_snapshot_retains_buffer() {
+        let mut sync = BinanceBookSynchronizer::new(BinanceProductType::Spot);
+        sync.handle_update(update(100, 104, None)).unwrap();
+
+        let result = sync.apply_snapshot(90);
+
+        assert_eq!(
+            result,
+            Err(BookSyncError::StaleSnapshot {
+                last_update_id: 90,
+                first_update_id: 100,
+            })
+        );
+        assert!(!sync.is_synced());
+        assert_eq!(sync.buffered_len(), 1);
+    }
+
+    #[rstest]
+    fn test_spot_gap_resets_and_buffers_update() {
+        let mut sync = BinanceBookSynchronizer::new(BinanceProductType::Spot);
+        sync.apply_snapshot(100).unwrap();
+        sync.handle_update(update(101, 105, None)).unwrap();
+
+        let result = sync.handle_update(update(107, 110, None));
+
+        assert!(matches!(
+            result,
+            Err(BookSyncError::Gap { previous: 105, .. })
+        ));
+        assert!(!sync.is_synced());
+        assert_eq!(sync.buffered_len(), 1);
+    }
+
+    #[rstest]
+    fn test_spot_drops_updates_included_in_snapshot() {
+        let mut sync = BinanceBookSynchronizer::new(BinanceProductType::Spot);
+        sync.apply_snapshot(100).unwrap();
+
+        assert_eq!(sync.handle_update(update(90, 100, None)), Ok(None));
+        assert!(sync.handle_update(update(98, 103, None)).unwrap().is_some());
+    }
+
+    #[rstest]
+    fn test_futures_uses_previous_final_update_id() {
+        let mut sync = BinanceBookSynchronizer::new(BinanceProductType::UsdM);
+        sync.handle_update(update(90, 95, Some(89))).unwrap();
+        sync.handle_update(update(96, 102, Some(95))).unwrap();
+
+        let applied = sync.apply_snapshot(100).unwrap();
+        assert_eq!(final_ids(&applied), vec![102]);
+
+        // Futures update IDs need not be contiguous, only linked by `pu`
+        let next = sync.handle_update(update(110, 120, Some(102))).unwrap();
+        assert!(next.is_some());
+
+        let result = sync.handle_update(update(130, 140, Some(125)));
+        assert!(matches!(
+            result,
+            Err(BookSyncError::Gap { previous: 120, .. })
+        ));
+    }
+}
@@ -0,0 +1,208 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a WebSocket client for the Binance market and user data streams.
+
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+use futures_util::{stream::SplitSink, SinkExt, StreamExt};
+use nautilus_cryptography::providers::install_cryptographic_provider;
+use serde_json::json;
+use tokio::{net::TcpStream, sync::mpsc, sync::Mutex, task::JoinHandle};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+type SharedMessageWriter =
+    Arc<Mutex<SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>>>;
+
+/// Provides a WebSocket client for the Binance combined market data and user data streams.
+///
+/// Text frames are forwarded to a channel read with [`BinanceWebSocketClient::next_message`],
+/// pings are answered automatically by the underlying stream.
+pub struct BinanceWebSocketClient {
+    url: String,
+    writer: SharedMessageWriter,
+    rx: mpsc::UnboundedReceiver<String>,
+    read_task: JoinHandle<()>,
+    request_id: AtomicU64,
+}
+
+impl BinanceWebSocketClient {
+    /// Connects to the given WebSocket `url`.
+    ///
+    /// For market data use the combined stream endpoint `{ws_url}/stream`, for user data
+    /// use `{ws_url}/ws/{listenKey}`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the connection cannot be established.
+    pub async fn connect(url: &str) -> anyhow::Result<Self> {
+        install_cryptographic_provider();
+
+        let (stream, _) = connect_async(url).await?;
+        let (writer, mut reader) = stream.split();
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let read_task = tokio::task::spawn(async move {
+            while let Some(message) = reader.next().await {
+                match message {
+                    Ok(Message::Text(text)) => {
+                        if tx.send(text).is_err() {
+                            break; // Client dropped
+                        }
+                    }
+                    Ok(Message::Close(frame)) => {
+                        tracing::debug!("Received close message: {frame:?}");
+                        break;
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        tracing::error!("Error reading from Binance WebSocket: {e}");
+                        break;
+                    }
+                }
+            }
+            tracing::debug!("Binance WebSocket read task completed");
+        });
+
+        tracing::info!("Connected to {url}");
+
+        Ok(Self {
+            url: url.to_string(),
+            writer: Arc::new(Mutex::new(writer)),
+            rx,
+            read_task,
+            request_id: AtomicU64::new(1),
+        })
+    }
+
+    /// Connects to the market data combined stream endpoint for the given `ws_url`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the connection cannot be established.
+    pub async fn connect_market_data(ws_url: &str) -> anyhow::Result<Self> {
+        Self::connect(&format!("{ws_url}/stream")).await
+    }
+
+    /// Connects to the user data stream for the given `ws_url` and `listen_key`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the connection cannot be established.
+    pub async fn connect_user_data(ws_url: &str, listen_key: &str) -> anyhow::Result<Self> {
+        Self::connect(&format!("{ws_url}/ws/{listen_key}")).await
+    }
+
+    /// Returns the URL of the connection.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns whether the read task is still running.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        !self.read_task.is_finished()
+    }
+
+    /// Subscribes to the given `streams`, e.g. `btcusdt@aggTrade`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the request cannot be sent.
+    pub async fn subscribe(&self, streams: Vec<String>) -> anyhow::Result<u64> {
+        self.send_request("SUBSCRIBE", streams).await
+    }
+
+    /// Unsubscribes from the given `streams`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the request cannot be sent.
+    pub async fn unsubscribe(&self, streams: Vec<String>) -> anyhow::Result<u64> {
+        self.send_request("UNSUBSCRIBE", streams).await
+    }
+
+    /// Returns the next text message, or `None` once the connection has closed.
+    pub async fn next_message(&mut self) -> Option<String> {
+        self.rx.recv().await
+    }
+
+    /// Closes the connection.
+    pub async fn close(&self) {
+        if let Err(e) = self.writer.lock().await.close().await {
+            tracing::error!("Error closing Binance WebSocket: {e}");
+        }
+        self.read_task.abort();
+    }
+
+    async fn send_request(&self, method: &str, streams: Vec<String>) -> anyhow::Result<u64> {
+        let id = self.request_id.fetch_add(1, Ordering::Relaxed);
+        let request = subscription_request(method, streams, id);
+        tracing::debug!("Sending {request}");
+
+        self.writer
+            .lock()
+            .await
+            .send(Message::Text(request))
+            .await?;
+        Ok(id)
+    }
+}
+
+impl Drop for BinanceWebSocketClient {
+    fn drop(&mut self) {
+        self.read_task.abort();
+    }
+}
+
+fn subscription_request(method: &str, streams: Vec<String>, id: u64) -> String {
+    json!({
+        "method": method,
+        "params": streams,
+        "id": id,
+    })
+    .to_string()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_subscription_request() {
+        let request = subscription_request(
+            "SUBSCRIBE",
+            vec![
+                "btcusdt@aggTrade".to_string(),
+                "btcusdt@depth@100ms".to_string(),
+            ],
+            7,
+        );
+        let value: serde_json::Value = serde_json::from_str(&request).unwrap();
+
+        assert_eq!(value["method"], "SUBSCRIBE");
+        assert_eq!(value["params"][1], "btcusdt@depth@100ms");
+        assert_eq!(value["id"], 7);
+    }
+}
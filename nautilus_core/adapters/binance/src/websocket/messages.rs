@@ -0,0 +1,334 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use serde::Deserialize;
+use ustr::Ustr;
+
+use crate::{
+    common::enums::{BinanceOrderSide, BinanceOrderStatus, BinanceOrderType, BinanceTimeInForce},
+    http::models::BinanceBookLevel,
+};
+
+/// A market data message received on a combined stream connection.
+#[derive(Clone, Debug)]
+pub enum BinanceWsMessage {
+    AggTrade(BinanceAggTradeMsg),
+    BookTicker(BinanceBookTickerMsg),
+    DepthUpdate(BinanceDepthUpdateMsg),
+    Response(BinanceWsResponse),
+}
+
+/// The envelope for messages on a combined stream (`/stream?streams=`).
+#[derive(Clone, Debug, Deserialize)]
+pub struct BinanceStreamWrapper {
+    pub stream: String,
+    pub data: serde_json::Value,
+}
+
+/// The response to a `SUBSCRIBE` or `UNSUBSCRIBE` request.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BinanceWsResponse {
+    pub id: u64,
+    #[serde(default)]
+    pub result: Option<serde_json::Value>,
+    #[serde(default)]
+    pub error: Option<BinanceWsError>,
+}
+
+/// An error returned for a WebSocket request.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BinanceWsError {
+    pub code: i64,
+    pub msg: String,
+}
+
+/// An aggregate trade (`<symbol>@aggTrade`).
+#[derive(Clone, Debug, Deserialize)]
+pub struct BinanceAggTradeMsg {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: Ustr,
+    #[serde(rename = "a")]
+    pub agg_trade_id: u64,
+    #[serde(rename = "p")]
+    pub price: String,
+    #[serde(rename = "q")]
+    pub quantity: String,
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
+/// The best bid and ask (`<symbol>@bookTicker`).
+///
+/// Spot tickers carry no event or transaction time.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BinanceBookTickerMsg {
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    #[serde(rename = "s")]
+    pub symbol: Ustr,
+    #[serde(rename = "b")]
+    pub best_bid_price: String,
+    #[serde(rename = "B")]
+    pub best_bid_qty: String,
+    #[serde(rename = "a")]
+    pub best_ask_price: String,
+    #[serde(rename = "A")]
+    pub best_ask_qty: String,
+    #[serde(rename = "E", default)]
+    pub event_time: Option<u64>,
+    #[serde(rename = "T", default)]
+    pub transaction_time: Option<u64>,
+}
+
+/// A diff of the order book (`<symbol>@depth` or `<symbol>@depth@100ms`).
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct BinanceDepthUpdateMsg {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T", default)]
+    pub transaction_time: Option<u64>,
+    #[serde(rename = "s")]
+    pub symbol: Ustr,
+    /// The first update ID in the event.
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+    /// The final update ID in the event.
+    #[serde(rename = "u")]
+    pub final_update_id: u64,
+    /// The final update ID of the previous event (futures only).
+    #[serde(rename = "pu", default)]
+    pub prev_final_update_id: Option<u64>,
+    #[serde(rename = "b")]
+    pub bids: Vec<BinanceBookLevel>,
+    #[serde(rename = "a")]
+    pub asks: Vec<BinanceBookLevel>,
+}
+
+/// An event received on a user data stream.
+#[derive(Clone, Debug)]
+pub enum BinanceUserDataEvent {
+    OrderUpdate(BinanceOrderUpdateMsg),
+    ListenKeyExpired,
+    /// Any other event, by event type (e.g. account and balance updates).
+    Other(String),
+}
+
+/// An order update (spot `executionReport`, or the `o` object of a futures `ORDER_TRADE_UPDATE`).
+#[derive(Clone, Debug, Deserialize)]
+pub struct BinanceOrderUpdateMsg {
+    #[serde(rename = "s")]
+    pub symbol: Ustr,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    #[serde(rename = "S")]
+    pub side: BinanceOrderSide,
+    #[serde(rename = "o")]
+    pub order_type: BinanceOrderType,
+    #[serde(rename = "f")]
+    pub time_in_force: BinanceTimeInForce,
+    #[serde(rename = "q")]
+    pub quantity: String,
+    #[serde(rename = "p")]
+    pub price: String,
+    #[serde(rename = "x")]
+    pub execution_type: String,
+    #[serde(rename = "X")]
+    pub order_status: BinanceOrderStatus,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "l")]
+    pub last_filled_qty: String,
+    #[serde(rename = "z")]
+    pub cumulative_filled_qty: String,
+    #[serde(rename = "L")]
+    pub last_filled_price: String,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+    #[serde(rename = "m", default)]
+    pub is_maker: bool,
+    #[serde(rename = "n", default)]
+    pub commission: Option<String>,
+    #[serde(rename = "N", default)]
+    pub commission_asset: Option<Ustr>,
+    /// Spot only.
+    #[serde(rename = "r", default)]
+    pub reject_reason: Option<String>,
+    /// Futures only.
+    #[serde(rename = "R", default)]
+    pub reduce_only: bool,
+}
+
+/// Parses a market data message from a combined stream connection.
+///
+/// # Errors
+///
+/// This function returns an error if the message is not valid JSON, or is from an unsupported stream.
+pub fn parse_stream_message(text: &str) -> anyhow::Result<BinanceWsMessage> {
+    let value: serde_json::Value = serde_json::from_str(text)?;
+
+    if value.get("stream").is_none() {
+        return Ok(BinanceWsMessage::Response(serde_json::from_value(value)?));
+    }
+
+    let wrapper: BinanceStreamWrapper = serde_json::from_value(value)?;
+    let stream_type = wrapper
+        .stream
+        .split('@')
+        .nth(1)
+        .ok_or_else(|| anyhow::anyhow!("Invalid stream name '{}'", wrapper.stream))?;
+
+    match stream_type {
+        "aggTrade" => Ok(BinanceWsMessage::AggTrade(serde_json::from_value(
+            wrapper.data,
+        )?)),
+        "bookTicker" => Ok(BinanceWsMessage::BookTicker(serde_json::from_value(
+            wrapper.data,
+        )?)),
+        "depth" => Ok(BinanceWsMessage::DepthUpdate(serde_json::from_value(
+            wrapper.data,
+        )?)),
+        _ => anyhow::bail!("Unsupported stream '{}'", wrapper.stream),
+    }
+}
+
+/// Parses an event from a user data stream connection.
+///
+/// # Errors
+///
+/// This function returns an error if the message is not valid JSON, or an order update is invalid.
+pub fn parse_user_data_event(text: &str) -> anyhow::Result<BinanceUserDataEvent> {
+    let mut value: serde_json::Value = serde_json::from_str(text)?;
+    let event_type = value
+        .get("e")
+        .and_then(serde_json::Value::as_str)
+        .ok_or_else(|| anyhow::anyhow!("Missing event type in user data message"))?
+        .to_string();
+
+    match event_type.as_str() {
+        "executionReport" => Ok(BinanceUserDataEvent::OrderUpdate(serde_json::from_value(
+            value,
+        )?)),
+        "ORDER_TRADE_UPDATE" => Ok(BinanceUserDataEvent::OrderUpdate(serde_json::from_value(
+            value["o"].take(),
+        )?)),
+        "listenKeyExpired" => Ok(BinanceUserDataEvent::ListenKeyExpired),
+        _ => Ok(BinanceUserDataEvent::Other(event_type)),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_test_kit::load_test_json;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_parse_agg_trade_message() {
+        let msg = parse_stream_message(&load_test_json!("ws_agg_trade.json")).unwrap();
+
+        let BinanceWsMessage::AggTrade(trade) = msg else {
+            panic!("Expected aggregate trade, was {msg:?}");
+        };
+        assert_eq!(trade.symbol, "BTCUSDT");
+        assert_eq!(trade.agg_trade_id, 26_129);
+        assert!(trade.is_buyer_maker);
+    }
+
+    #[rstest]
+    fn test_parse_spot_book_ticker_message() {
+        let msg = parse_stream_message(&load_test_json!("ws_book_ticker.json")).unwrap();
+
+        let BinanceWsMessage::BookTicker(ticker) = msg else {
+            panic!("Expected book ticker, was {msg:?}");
+        };
+        assert_eq!(ticker.update_id, 400_900_217);
+        assert_eq!(ticker.event_time, None);
+    }
+
+    #[rstest]
+    fn test_parse_depth_update_message() {
+        let msg = parse_stream_message(&load_test_json!("ws_depth_update.json")).unwrap();
+
+        let BinanceWsMessage::DepthUpdate(update) = msg else {
+            panic!("Expected depth update, was {msg:?}");
+        };
+        assert_eq!(update.first_update_id, 1_027_025);
+        assert_eq!(update.final_update_id, 1_027_027);
+        assert_eq!(update.prev_final_update_id, None);
+        assert_eq!(update.bids.len(), 2);
+        assert_eq!(update.asks.len(), 1);
+    }
+
+    #[rstest]
+    fn test_parse_subscription_response() {
+        let msg = parse_stream_message(r#"{"result":null,"id":3}"#).unwrap();
+
+        assert!(matches!(
+            msg,
+            BinanceWsMessage::Response(BinanceWsResponse { id: 3, .. })
+        ));
+    }
+
+    #[rstest]
+    fn test_parse_unsupported_stream_fails() {
+        let result = parse_stream_message(r#"{"stream":"btcusdt@kline_1m","data":{}}"#);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_parse_spot_execution_report() {
+        let event = parse_user_data_event(&load_test_json!("ws_execution_report.json")).unwrap();
+
+        let BinanceUserDataEvent::OrderUpdate(update) = event else {
+            panic!("Expected order update, was {event:?}");
+        };
+        assert_eq!(update.order_status, BinanceOrderStatus::PartiallyFilled);
+        assert_eq!(update.order_type, BinanceOrderType::Limit);
+        assert_eq!(update.execution_type, "TRADE");
+        assert_eq!(update.last_filled_qty, "0.00500000");
+        assert!(!update.reduce_only);
+    }
+
+    #[rstest]
+    fn test_parse_futures_order_trade_update() {
+        let event = parse_user_data_event(&load_test_json!("ws_order_trade_update.json")).unwrap();
+
+        let BinanceUserDataEvent::OrderUpdate(update) = event else {
+            panic!("Expected order update, was {event:?}");
+        };
+        assert_eq!(update.order_status, BinanceOrderStatus::New);
+        assert_eq!(update.order_type, BinanceOrderType::TrailingStopMarket);
+        assert_eq!(update.side, BinanceOrderSide::Sell);
+        assert!(update.reduce_only);
+    }
+
+    #[rstest]
+    fn test_parse_listen_key_expired() {
+        let event = parse_user_data_event(r#"{"e":"listenKeyExpired","E":1700000000000}"#).unwrap();
+
+        assert!(matches!(event, BinanceUserDataEvent::ListenKeyExpired));
+    }
+}
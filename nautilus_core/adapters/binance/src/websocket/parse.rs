@@ -0,0 +1,283 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::{BookOrder, OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick},
+    enums::{BookAction, OrderSide, RecordFlag},
+    identifiers::{InstrumentId, TradeId},
+};
+
+use super::messages::{BinanceAggTradeMsg, BinanceBookTickerMsg, BinanceDepthUpdateMsg};
+use crate::{
+    common::parse::{parse_aggressor_side, parse_millis_timestamp, parse_price, parse_quantity},
+    http::models::{BinanceBookLevel, BinanceDepthSnapshot},
+};
+
+/// Parses a Nautilus trade tick from a Binance aggregate trade.
+///
+/// # Errors
+///
+/// This function returns an error if the price or quantity is invalid.
+pub fn parse_agg_trade(
+    msg: &BinanceAggTradeMsg,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    ts_init: UnixNanos,
+) -> anyhow::Result<TradeTick> {
+    Ok(TradeTick::new(
+        instrument_id,
+        parse_price(&msg.price, price_precision)?,
+        parse_quantity(&msg.quantity, size_precision)?,
+        parse_aggressor_side(msg.is_buyer_maker),
+        TradeId::new(msg.agg_trade_id.to_string()),
+        parse_millis_timestamp(msg.trade_time),
+        ts_init,
+    ))
+}
+
+/// Parses a Nautilus quote tick from a Binance book ticker.
+///
+/// Spot tickers carry no timestamp, in which case `ts_event` is set to `ts_init`.
+///
+/// # Errors
+///
+/// This function returns an error if any price or quantity is invalid.
+pub fn parse_book_ticker(
+    msg: &BinanceBookTickerMsg,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    ts_init: UnixNanos,
+) -> anyhow::Result<QuoteTick> {
+    let ts_event = msg
+        .transaction_time
+        .or(msg.event_time)
+        .map_or(ts_init, parse_millis_timestamp);
+
+    QuoteTick::new_checked(
+        instrument_id,
+        parse_price(&msg.best_bid_price, price_precision)?,
+        parse_price(&msg.best_ask_price, price_precision)?,
+        parse_quantity(&msg.best_bid_qty, size_precision)?,
+        parse_quantity(&msg.best_ask_qty, size_precision)?,
+        ts_event,
+        ts_init,
+    )
+}
+
+/// Parses Nautilus order book deltas from a Binance depth diff.
+///
+/// Levels with a zero quantity are deletes, all others update the level. The last
+/// delta is flagged with `F_LAST` and all deltas carry the final update ID as their sequence.
+///
+/// # Errors
+///
+/// This function returns an error if any level is invalid, or the update contains no levels.
+pub fn parse_depth_update(
+    msg: &BinanceDepthUpdateMsg,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    ts_init: UnixNanos,
+) -> anyhow::Result<OrderBookDeltas> {
+    let ts_event = parse_millis_timestamp(msg.transaction_time.unwrap_or(msg.event_time));
+    let levels = msg
+        .bids
+        .iter()
+        .map(|level| (OrderSide::Buy, level))
+        .chain(msg.asks.iter().map(|level| (OrderSide::Sell, level)));
+    let count = msg.bids.len() + msg.asks.len();
+
+    let mut deltas = Vec::with_capacity(count);
+    for (i, (side, level)) in levels.enumerate() {
+        let order = parse_book_order(level, side, price_precision, size_precision)?;
+        let action = if order.size.is_zero() {
+            BookAction::Delete
+        } else {
+            BookAction::Update
+        };
+        let flags = if i == count - 1 {
+            RecordFlag::F_LAST as u8
+        } else {
+            0
+        };
+
+        deltas.push(OrderBookDelta::new(
+            instrument_id,
+            action,
+            order,
+            flags,
+            msg.final_update_id,
+            ts_event,
+            ts_init,
+        ));
+    }
+
+    OrderBookDeltas::new_checked(instrument_id, deltas)
+}
+
+/// Parses Nautilus order book deltas from a Binance depth snapshot.
+///
+/// The deltas begin with a `Clear` followed by an `Add` for each level, all flagged with
+/// `F_SNAPSHOT`, and the snapshot last update ID as their sequence.
+///
+/// # Errors
+///
+/// This function returns an error if any level is invalid.
+pub fn parse_depth_snapshot(
+    snapshot: &BinanceDepthSnapshot,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    ts_init: UnixNanos,
+) -> anyhow::Result<OrderBookDeltas> {
+    let sequence = snapshot.last_update_id;
+    let snapshot_flag = RecordFlag::F_SNAPSHOT as u8;
+
+    let mut deltas = Vec::with_capacity(snapshot.bids.len() + snapshot.asks.len() + 1);
+    let mut clear = OrderBookDelta::clear(instrument_id, sequence, ts_init, ts_init);
+    clear.flags |= snapshot_flag;
+    deltas.push(clear);
+
+    let levels = snapshot
+        .bids
+        .iter()
+        .map(|level| (OrderSide::Buy, level))
+        .chain(snapshot.asks.iter().map(|level| (OrderSide::Sell, level)));
+    for (side, level) in levels {
+        let order = parse_book_order(level, side, price_precision, size_precision)?;
+        deltas.push(OrderBookDelta::new(
+            instrument_id,
+            BookAction::Add,
+            order,
+            snapshot_flag,
+            sequence,
+            ts_init,
+            ts_init,
+        ));
+    }
+
+    // SAFETY: Deltas always contain at least the clear
+    deltas.last_mut().unwrap().flags |= RecordFlag::F_LAST as u8;
+
+    OrderBookDeltas::new_checked(instrument_id, deltas)
+}
+
+fn parse_book_order(
+    level: &BinanceBookLevel,
+    side: OrderSide,
+    price_precision: u8,
+    size_precision: u8,
+) -> anyhow::Result<BookOrder> {
+    Ok(BookOrder::new(
+        side,
+        parse_price(&level.0, price_precision)?,
+        parse_quantity(&level.1, size_precision)?,
+        0, // Order IDs are not provided for aggregated price levels
+    ))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::AggressorSide,
+        types::{Price, Quantity},
+    };
+    use nautilus_test_kit::load_test_json;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::websocket::messages::{parse_stream_message, BinanceWsMessage};
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("BTCUSDT.BINANCE")
+    }
+
+    #[rstest]
+    fn test_parse_agg_trade() {
+        let BinanceWsMessage::AggTrade(msg) =
+            parse_stream_message(&load_test_json!("ws_agg_trade.json")).unwrap()
+        else {
+            panic!("Expected aggregate trade");
+        };
+
+        let trade = parse_agg_trade(&msg, instrument_id(), 2, 5, UnixNanos::from(1)).unwrap();
+
+        assert_eq!(trade.price, Price::from("42000.01"));
+        assert_eq!(trade.size, Quantity::from("0.50000"));
+        assert_eq!(trade.aggressor_side, AggressorSide::Seller);
+        assert_eq!(trade.trade_id, TradeId::new("26129"));
+        assert_eq!(trade.ts_event, UnixNanos::from(1_700_000_000_099_000_000));
+        assert_eq!(trade.ts_init, UnixNanos::from(1));
+    }
+
+    #[rstest]
+    fn test_parse_book_ticker_without_timestamp() {
+        let BinanceWsMessage::BookTicker(msg) =
+            parse_stream_message(&load_test_json!("ws_book_ticker.json")).unwrap()
+        else {
+            panic!("Expected book ticker");
+        };
+
+        let quote = parse_book_ticker(&msg, instrument_id(), 2, 5, UnixNanos::from(1)).unwrap();
+
+        assert_eq!(quote.bid_price, Price::from("42000.00"));
+        assert_eq!(quote.ask_price, Price::from("42000.01"));
+        assert_eq!(quote.bid_size, Quantity::from("1.25000"));
+        assert_eq!(quote.ts_event, UnixNanos::from(1));
+    }
+
+    #[rstest]
+    fn test_parse_depth_update() {
+        let BinanceWsMessage::DepthUpdate(msg) =
+            parse_stream_message(&load_test_json!("ws_depth_update.json")).unwrap()
+        else {
+            panic!("Expected depth update");
+        };
+
+        let deltas = parse_depth_update(&msg, instrument_id(), 8, 8, UnixNanos::from(1)).unwrap();
+
+        assert_eq!(deltas.deltas.len(), 3);
+        assert_eq!(deltas.deltas[0].action, BookAction::Delete);
+        assert_eq!(deltas.deltas[1].action, BookAction::Update);
+        assert_eq!(deltas.deltas[2].order.side, OrderSide::Sell);
+        assert_eq!(deltas.deltas[2].flags, RecordFlag::F_LAST as u8);
+        assert_eq!(deltas.sequence, 1_027_027);
+    }
+
+    #[rstest]
+    fn test_parse_depth_snapshot() {
+        let snapshot: BinanceDepthSnapshot =
+            serde_json::from_str(&load_test_json!("http_depth_snapshot.json")).unwrap();
+
+        let deltas =
+            parse_depth_snapshot(&snapshot, instrument_id(), 8, 8, UnixNanos::from(1)).unwrap();
+
+        assert_eq!(deltas.deltas.len(), 5);
+        assert_eq!(deltas.deltas[0].action, BookAction::Clear);
+        assert!(deltas
+            .deltas
+            .iter()
+            .all(|delta| RecordFlag::F_SNAPSHOT.matches(delta.flags)));
+        assert!(RecordFlag::F_LAST.matches(deltas.deltas[4].flags));
+        assert!(!RecordFlag::F_LAST.matches(deltas.deltas[3].flags));
+        assert_eq!(deltas.sequence, 1_027_024);
+    }
+}
@@ -0,0 +1,35 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::sync::LazyLock;
+
+use nautilus_model::identifiers::Venue;
+
+pub const COINBASE_INTX: &str = "COINBASE_INTX";
+pub static COINBASE_INTX_VENUE: LazyLock<Venue> = LazyLock::new(|| Venue::new(COINBASE_INTX));
+
+pub const COINBASE_INTX_HTTP_URL: &str = "https://api.international.coinbase.com";
+pub const COINBASE_INTX_WS_URL: &str = "wss://ws-md.international.coinbase.com";
+pub const COINBASE_INTX_SANDBOX_HTTP_URL: &str = "https://api-n5e1.coinbase.com";
+pub const COINBASE_INTX_SANDBOX_WS_URL: &str = "wss://ws-md.n5e2.coinbase.com";
+
+/// The path prefix of the REST API.
+pub const COINBASE_INTX_API_PATH: &str = "/api/v1";
+
+/// The REST API rate limit (requests per second).
+pub const COINBASE_INTX_REQUESTS_PER_SECOND: u32 = 40;
+
+/// The fixed component of the WebSocket subscription signature for market data.
+pub const COINBASE_INTX_WS_SIGNATURE_SUFFIX: &str = "CBINTLMD";
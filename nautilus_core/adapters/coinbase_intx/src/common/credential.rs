@@ -0,0 +1,168 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use nautilus_core::credentials::{resolve_api_key, Secret};
+use ring::hmac;
+
+use super::consts::COINBASE_INTX_WS_SIGNATURE_SUFFIX;
+
+/// The API key, secret and passphrase used to authenticate Coinbase International requests.
+///
+/// Requests are signed with an HMAC-SHA256 of the prehash string keyed by the base64 decoded
+/// secret, base64 encoded, see <https://docs.cdp.coinbase.com/intx/docs/rest-auth>.
+#[derive(Clone, Debug)]
+pub struct CoinbaseIntxCredential {
+    api_key: Secret,
+    api_secret: Secret,
+    api_passphrase: Secret,
+}
+
+impl CoinbaseIntxCredential {
+    /// Creates a new [`CoinbaseIntxCredential`] instance.
+    #[must_use]
+    pub const fn new(api_key: Secret, api_secret: Secret, api_passphrase: Secret) -> Self {
+        Self {
+            api_key,
+            api_secret,
+            api_passphrase,
+        }
+    }
+
+    /// Resolves the credential from the given values if provided, otherwise from the
+    /// `COINBASE_INTX_API_KEY`, `COINBASE_INTX_API_SECRET` and `COINBASE_INTX_API_PASSPHRASE`
+    /// environment variables.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any value is neither provided nor set in the environment.
+    pub fn resolve(
+        api_key: Option<&str>,
+        api_secret: Option<&str>,
+        api_passphrase: Option<&str>,
+    ) -> anyhow::Result<Self> {
+        Ok(Self::new(
+            resolve_api_key(api_key, "COINBASE_INTX_API_KEY")?,
+            resolve_api_key(api_secret, "COINBASE_INTX_API_SECRET")?,
+            resolve_api_key(api_passphrase, "COINBASE_INTX_API_PASSPHRASE")?,
+        ))
+    }
+
+    /// Returns the API key, sent in the `CB-ACCESS-KEY` header.
+    #[must_use]
+    pub const fn api_key(&self) -> &Secret {
+        &self.api_key
+    }
+
+    /// Returns the API passphrase, sent in the `CB-ACCESS-PASSPHRASE` header.
+    #[must_use]
+    pub const fn api_passphrase(&self) -> &Secret {
+        &self.api_passphrase
+    }
+
+    /// Returns the signature of a REST request, where `timestamp` is in UNIX seconds and
+    /// `request_path` includes any query string.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the API secret is not valid base64.
+    pub fn sign_request(
+        &self,
+        timestamp: &str,
+        method: &str,
+        request_path: &str,
+        body: &str,
+    ) -> anyhow::Result<String> {
+        self.sign(&format!("{timestamp}{method}{request_path}{body}"))
+    }
+
+    /// Returns the signature of a WebSocket subscription, where `timestamp` is in UNIX seconds.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the API secret is not valid base64.
+    pub fn sign_ws(&self, timestamp: &str) -> anyhow::Result<String> {
+        self.sign(&format!(
+            "{timestamp}{}{COINBASE_INTX_WS_SIGNATURE_SUFFIX}{}",
+            self.api_key.expose(),
+            self.api_passphrase.expose(),
+        ))
+    }
+
+    fn sign(&self, message: &str) -> anyhow::Result<String> {
+        let secret = STANDARD.decode(self.api_secret.expose())?;
+        let key = hmac::Key::new(hmac::HMAC_SHA256, &secret);
+        let signature = hmac::sign(&key, message.as_bytes());
+        Ok(STANDARD.encode(signature.as_ref()))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn credential() -> CoinbaseIntxCredential {
+        CoinbaseIntxCredential::new(
+            Secret::new("my-api-key").unwrap(),
+            Secret::new("Y29pbmJhc2UtaW50eC10ZXN0LXNlY3JldA==").unwrap(),
+            Secret::new("my-passphrase").unwrap(),
+        )
+    }
+
+    #[rstest]
+    fn test_sign_request() {
+        let signature = credential()
+            .sign_request(
+                "1700000000",
+                "POST",
+                "/api/v1/orders",
+                r#"{"size":"0.001"}"#,
+            )
+            .unwrap();
+
+        assert_eq!(signature, "WKyOnoijzDR7XUzNjXEMWjOZO8rE49AECnfSNs8zrQU=");
+    }
+
+    #[rstest]
+    fn test_sign_ws() {
+        let signature = credential().sign_ws("1700000000").unwrap();
+
+        assert_eq!(signature, "qEKXphog/bMhmuJiKwgKYgTF77tWtfwgNdKKK7+7BQo=");
+    }
+
+    #[rstest]
+    fn test_sign_with_invalid_secret_fails() {
+        let credential = CoinbaseIntxCredential::new(
+            Secret::new("my-api-key").unwrap(),
+            Secret::new("not-base64!").unwrap(),
+            Secret::new("my-passphrase").unwrap(),
+        );
+
+        assert!(credential.sign_ws("1700000000").is_err());
+    }
+
+    #[rstest]
+    fn test_debug_redacts_secrets() {
+        let debug = format!("{:?}", credential());
+
+        assert!(!debug.contains("my-api-key"));
+        assert!(!debug.contains("my-passphrase"));
+    }
+}
@@ -0,0 +1,154 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::enums::{AggressorSide, OrderSide, OrderSideSpecified};
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumString};
+
+/// The type of a Coinbase International instrument.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Display, AsRefStr, EnumString,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CoinbaseIntxInstrumentType {
+    Spot,
+    Perp,
+    /// Any instrument type not supported by the adapter.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The side of a Coinbase International order, or the aggressor side of a trade.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Display, AsRefStr, EnumString,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CoinbaseIntxSide {
+    Buy,
+    Sell,
+    /// The aggressor side of trades matched in the opening auction.
+    OpeningFill,
+}
+
+impl From<OrderSideSpecified> for CoinbaseIntxSide {
+    fn from(value: OrderSideSpecified) -> Self {
+        match value {
+            OrderSideSpecified::Buy => Self::Buy,
+            OrderSideSpecified::Sell => Self::Sell,
+        }
+    }
+}
+
+impl From<CoinbaseIntxSide> for OrderSide {
+    fn from(value: CoinbaseIntxSide) -> Self {
+        match value {
+            CoinbaseIntxSide::Buy => Self::Buy,
+            CoinbaseIntxSide::Sell => Self::Sell,
+            CoinbaseIntxSide::OpeningFill => Self::NoOrderSide,
+        }
+    }
+}
+
+impl From<CoinbaseIntxSide> for AggressorSide {
+    fn from(value: CoinbaseIntxSide) -> Self {
+        match value {
+            CoinbaseIntxSide::Buy => Self::Buyer,
+            CoinbaseIntxSide::Sell => Self::Seller,
+            CoinbaseIntxSide::OpeningFill => Self::NoAggressor,
+        }
+    }
+}
+
+/// The order types accepted by Coinbase International.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Display, AsRefStr, EnumString,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CoinbaseIntxOrderType {
+    Limit,
+    Market,
+    /// A stop market order.
+    Stop,
+    StopLimit,
+    TakeProfitStopLoss,
+}
+
+/// The time in force instructions accepted by Coinbase International.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Display, AsRefStr, EnumString,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CoinbaseIntxTimeInForce {
+    Gtc,
+    Ioc,
+    Fok,
+    /// Good-till-time, requires an expire time.
+    Gtt,
+}
+
+/// The most recent event for a Coinbase International order.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Display, AsRefStr, EnumString,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CoinbaseIntxOrderEventType {
+    PendingNew,
+    New,
+    Trade,
+    PendingCancel,
+    Canceled,
+    PendingReplace,
+    Replaced,
+    StopTriggered,
+    Rejected,
+    Expired,
+}
+
+/// The status of a Coinbase International order.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Display, AsRefStr, EnumString,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CoinbaseIntxOrderStatus {
+    Working,
+    Done,
+}
+
+/// The market data channels of the Coinbase International WebSocket feed.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Display, AsRefStr, EnumString,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CoinbaseIntxWsChannel {
+    /// Best bid and offer.
+    #[serde(rename = "LEVEL1")]
+    #[strum(serialize = "LEVEL1")]
+    Level1,
+    /// Order book snapshots and updates.
+    #[serde(rename = "LEVEL2")]
+    #[strum(serialize = "LEVEL2")]
+    Level2,
+    /// Trades.
+    Match,
+    Instruments,
+    Subscriptions,
+}
@@ -0,0 +1,94 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::str::FromStr;
+
+use nautilus_model::{
+    identifiers::{InstrumentId, Symbol},
+    types::{Price, Quantity},
+};
+use rust_decimal::Decimal;
+
+use super::consts::COINBASE_INTX_VENUE;
+
+/// Parses a Nautilus instrument ID from the given Coinbase International `symbol`,
+/// e.g. `BTC-PERP` becomes `BTC-PERP.COINBASE_INTX`.
+#[must_use]
+pub fn parse_instrument_id(symbol: &str) -> InstrumentId {
+    InstrumentId::new(Symbol::new(symbol), *COINBASE_INTX_VENUE)
+}
+
+/// Normalizes a Coinbase International decimal string by removing any trailing zeros,
+/// e.g. an increment of `"0.10"` becomes `"0.1"`.
+///
+/// # Errors
+///
+/// This function returns an error if `value` is not a valid decimal.
+pub fn normalize_decimal_str(value: &str) -> anyhow::Result<String> {
+    Ok(Decimal::from_str(value)?.normalize().to_string())
+}
+
+/// Parses a Coinbase International price string with the given `precision`.
+///
+/// # Errors
+///
+/// This function returns an error if `value` is not a valid price.
+pub fn parse_price(value: &str, precision: u8) -> anyhow::Result<Price> {
+    Price::new_checked(value.parse::<f64>()?, precision)
+}
+
+/// Parses a Coinbase International quantity string with the given `precision`.
+///
+/// # Errors
+///
+/// This function returns an error if `value` is not a valid quantity.
+pub fn parse_quantity(value: &str, precision: u8) -> anyhow::Result<Quantity> {
+    Quantity::new_checked(value.parse::<f64>()?, precision)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("BTC-PERP", "BTC-PERP.COINBASE_INTX")]
+    #[case("ETH-USDC", "ETH-USDC.COINBASE_INTX")]
+    fn test_parse_instrument_id(#[case] symbol: &str, #[case] expected: &str) {
+        assert_eq!(parse_instrument_id(symbol), InstrumentId::from(expected));
+    }
+
+    #[rstest]
+    #[case("0.10", "0.1")]
+    #[case("0.00010000", "0.0001")]
+    #[case("1", "1")]
+    fn test_normalize_decimal_str(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(normalize_decimal_str(value).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_parse_price_and_quantity() {
+        assert_eq!(parse_price("28833.1", 1).unwrap(), Price::from("28833.1"));
+        assert_eq!(
+            parse_quantity("0.0060", 4).unwrap(),
+            Quantity::from("0.0060")
+        );
+        assert!(parse_price("invalid", 1).is_err());
+    }
+}
@@ -0,0 +1,762 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a market data client for Coinbase International quotes, trades and order book deltas.
+
+use std::collections::HashMap;
+
+use nautilus_common::{
+    messages::data::{DataEvent, DataRequest, Payload},
+    runtime::ClientTask,
+    watchdog::ClientActivity,
+};
+use nautilus_core::{nanos::UnixNanos, time::get_atomic_clock_realtime, uuid::UUID4};
+use nautilus_data::client::DataClient;
+use nautilus_model::{
+    data::{Bar, BarType, Data, DataType, OrderBookDeltas_API, QuoteTick, TradeTick},
+    enums::BookType,
+    identifiers::{ClientId, InstrumentId, Venue},
+    instruments::InstrumentAny,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use ustr::Ustr;
+
+use crate::{
+    common::{
+        consts::{COINBASE_INTX_SANDBOX_WS_URL, COINBASE_INTX_VENUE, COINBASE_INTX_WS_URL},
+        credential::CoinbaseIntxCredential,
+        enums::CoinbaseIntxWsChannel,
+    },
+    http::CoinbaseIntxHttpClient,
+    websocket::{
+        messages::{parse_ws_message, CoinbaseIntxWsMessage},
+        parse::{parse_level1, parse_level2_snapshot, parse_level2_update, parse_match},
+        CoinbaseIntxWebSocketClient,
+    },
+};
+
+/// Provides a market data client for Coinbase International.
+///
+/// Order book updates are checked for gaps in their sequence numbers, in which case the
+/// `LEVEL2` channel is resubscribed to receive a new snapshot.
+pub struct CoinbaseIntxDataClient {
+    http: CoinbaseIntxHttpClient,
+    credential: CoinbaseIntxCredential,
+    ws_url: String,
+    ws: Option<CoinbaseIntxWebSocketClient>,
+    instruments: HashMap<Ustr, InstrumentAny>,
+    book_sequences: HashMap<Ustr, Option<u64>>,
+}
+
+impl CoinbaseIntxDataClient {
+    /// Creates a new [`CoinbaseIntxDataClient`] instance.
+    #[must_use]
+    pub fn new(
+        http: CoinbaseIntxHttpClient,
+        credential: CoinbaseIntxCredential,
+        is_sandbox: bool,
+    ) -> Self {
+        let ws_url = match is_sandbox {
+            true => COINBASE_INTX_SANDBOX_WS_URL,
+            false => COINBASE_INTX_WS_URL,
+        };
+        Self {
+            http,
+            credential,
+            ws_url: ws_url.to_string(),
+            ws: None,
+            instruments: HashMap::new(),
+            book_sequences: HashMap::new(),
+        }
+    }
+
+    /// Returns whether the client is connected to the market data feed.
+    #[must_use]
+    pub fn is_connected(&self) -> bool {
+        self.ws
+            .as_ref()
+            .is_some_and(CoinbaseIntxWebSocketClient::is_active)
+    }
+
+    /// Connects to the market data feed.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the connection cannot be established.
+    pub async fn connect(&mut self) -> anyhow::Result<()> {
+        self.ws = Some(
+            CoinbaseIntxWebSocketClient::connect(&self.ws_url, self.credential.clone()).await?,
+        );
+        Ok(())
+    }
+
+    /// Disconnects from the market data feed, discarding all order book state.
+    pub async fn disconnect(&mut self) {
+        if let Some(ws) = self.ws.take() {
+            ws.close().await;
+        }
+        self.book_sequences.clear();
+    }
+
+    /// Loads all trading perpetual and spot instruments, returning them.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instruments request fails.
+    pub async fn load_instruments(&mut self) -> anyhow::Result<Vec<InstrumentAny>> {
+        let instruments = self.http.instruments().await?;
+        for instrument in &instruments {
+            self.instruments
+                .insert(instrument.raw_symbol().inner(), instrument.clone());
+        }
+        Ok(instruments)
+    }
+
+    /// Returns the loaded instrument for the given `instrument_id`, if any.
+    #[must_use]
+    pub fn instrument(&self, instrument_id: &InstrumentId) -> Option<&InstrumentAny> {
+        self.instruments.get(&instrument_id.symbol.inner())
+    }
+
+    /// Subscribes to best bid and offer quotes for the given `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument is not loaded, or the subscription fails.
+    pub async fn subscribe_quotes(&self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.subscribe(CoinbaseIntxWsChannel::Level1, instrument_id)
+            .await
+    }
+
+    /// Subscribes to trades for the given `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument is not loaded, or the subscription fails.
+    pub async fn subscribe_trades(&self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.subscribe(CoinbaseIntxWsChannel::Match, instrument_id)
+            .await
+    }
+
+    /// Unsubscribes from best bid and offer quotes for the given `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the client is not connected, or the request fails.
+    pub async fn unsubscribe_quotes(&self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.ws()?
+            .unsubscribe(
+                CoinbaseIntxWsChannel::Level1,
+                &[instrument_id.symbol.inner()],
+            )
+            .await
+    }
+
+    /// Unsubscribes from trades for the given `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the client is not connected, or the request fails.
+    pub async fn unsubscribe_trades(&self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.ws()?
+            .unsubscribe(
+                CoinbaseIntxWsChannel::Match,
+                &[instrument_id.symbol.inner()],
+            )
+            .await
+    }
+
+    /// Subscribes to order book deltas for the given `instrument_id`.
+    ///
+    /// The first deltas emitted are a snapshot, followed by the updates.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument is not loaded, or the subscription fails.
+    pub async fn subscribe_book_deltas(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<()> {
+        self.subscribe(CoinbaseIntxWsChannel::Level2, instrument_id)
+            .await?;
+        self.book_sequences
+            .insert(instrument_id.symbol.inner(), None);
+        Ok(())
+    }
+
+    /// Unsubscribes from order book deltas for the given `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the client is not connected, or the request fails.
+    pub async fn unsubscribe_book_deltas(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<()> {
+        let symbol = instrument_id.symbol.inner();
+        self.book_sequences.remove(&symbol);
+        self.ws()?
+            .unsubscribe(CoinbaseIntxWsChannel::Level2, &[symbol])
+            .await
+    }
+
+    /// Returns the next market data item, or `None` once the connection has closed.
+    ///
+    /// Messages which fail to parse are logged and skipped.
+    pub async fn next_data(&mut self) -> Option<Data> {
+        loop {
+            let text = self.ws.as_mut()?.next_message().await?;
+            let ts_init = get_atomic_clock_realtime().get_time_ns();
+
+            let msg = match parse_ws_message(&text) {
+                Ok(msg) => msg,
+                Err(e) => {
+                    tracing::error!("Error parsing message: {e}, {text}");
+                    continue;
+                }
+            };
+
+            let result = match msg {
+                CoinbaseIntxWsMessage::Level1(msg) => {
+                    let Some(instrument) = self.instruments.get(&msg.product_id) else {
+                        continue;
+                    };
+                    parse_level1(
+                        &msg,
+                        instrument.id(),
+                        instrument.price_precision(),
+                        instrument.size_precision(),
+                        ts_init,
+                    )
+                    .map(|quote| quote.map(Data::Quote))
+                }
+                CoinbaseIntxWsMessage::Match(msg) => {
+                    let Some(instrument) = self.instruments.get(&msg.product_id) else {
+                        continue;
+                    };
+                    parse_match(
+                        &msg,
+                        instrument.id(),
+                        instrument.price_precision(),
+                        instrument.size_precision(),
+                        ts_init,
+                    )
+                    .map(|trade| Some(Data::Trade(trade)))
+                }
+                CoinbaseIntxWsMessage::Level2Snapshot(msg) => {
+                    let Some(instrument) = self.instruments.get(&msg.product_id) else {
+                        continue;
+                    };
+                    if !self.book_sequences.contains_key(&msg.product_id) {
+                        continue; // Not subscribed
+                    }
+                    let result = parse_level2_snapshot(
+                        &msg,
+                        instrument.id(),
+                        instrument.price_precision(),
+                        instrument.size_precision(),
+                        ts_init,
+                    );
+                    self.book_sequences
+                        .insert(msg.product_id, Some(msg.sequence));
+                    result.map(|deltas| Some(Data::Deltas(OrderBookDeltas_API::new(deltas))))
+                }
+                CoinbaseIntxWsMessage::Level2Update(msg) => {
+                    let Some(instrument) = self.instruments.get(&msg.product_id) else {
+                        continue;
+                    };
+                    let Some(Some(last_sequence)) =
+                        self.book_sequences.get(&msg.product_id).copied()
+                    else {
+                        continue; // Not subscribed, or awaiting a snapshot
+                    };
+                    if msg.sequence != last_sequence + 1 {
+                        tracing::warn!(
+                            "Gap in {} order book sequence: expected {}, received {}",
+                            msg.product_id,
+                            last_sequence + 1,
+                            msg.sequence,
+                        );
+                        self.resubscribe_book(msg.product_id).await;
+                        continue;
+                    }
+                    if msg.changes.is_empty() {
+                        continue; // Nothing to apply
+                    }
+                    let result = parse_level2_update(
+                        &msg,
+                        instrument.id(),
+                        instrument.price_precision(),
+                        instrument.size_precision(),
+                        ts_init,
+                    );
+                    self.book_sequences
+                        .insert(msg.product_id, Some(msg.sequence));
+                    result.map(|deltas| Some(Data::Deltas(OrderBookDeltas_API::new(deltas))))
+                }
+                CoinbaseIntxWsMessage::Subscriptions(msg) => {
+                    tracing::debug!("Subscriptions: {:?}", msg.channels);
+                    continue;
+                }
+                CoinbaseIntxWsMessage::Reject(msg) => {
+                    tracing::error!(
+                        "Request rejected: {} ({})",
+                        msg.message,
+                        msg.reason.unwrap_or_default()
+                    );
+                    continue;
+                }
+                CoinbaseIntxWsMessage::Other(_) => continue,
+            };
+
+            match result {
+                Ok(Some(data)) => return Some(data),
+                Ok(None) => {}
+                Err(e) => tracing::error!("Error parsing market data: {e}"),
+            }
+        }
+    }
+
+    async fn subscribe(
+        &self,
+        channel: CoinbaseIntxWsChannel,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<()> {
+        let symbol = instrument_id.symbol.inner();
+        if !self.instruments.contains_key(&symbol) {
+            anyhow::bail!("Instrument {instrument_id} not loaded");
+        }
+        self.ws()?.subscribe(channel, &[symbol]).await
+    }
+
+    /// Resubscribes to the `LEVEL2` channel for the given `symbol`, to receive a new snapshot.
+    async fn resubscribe_book(&mut self, symbol: Ustr) {
+        self.book_sequences.insert(symbol, None);
+        let Some(ws) = self.ws.as_ref() else {
+            return;
+        };
+        let channel = CoinbaseIntxWsChannel::Level2;
+        if let Err(e) = ws.unsubscribe(channel, &[symbol]).await {
+            tracing::error!("Error unsubscribing from {channel} for {symbol}: {e}");
+        }
+        if let Err(e) = ws.subscribe(channel, &[symbol]).await {
+            tracing::error!("Error subscribing to {channel} for {symbol}: {e}");
+        }
+    }
+
+    fn ws(&self) -> anyhow::Result<&CoinbaseIntxWebSocketClient> {
+        self.ws
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected"))
+    }
+}
+
+/// Provides a [`DataClient`] for Coinbase International, running a [`CoinbaseIntxDataClient`] on the shared runtime.
+///
+/// Market data is sent to the data engine on the `data_tx` channel (see `LiveRunner::data_sender`).
+/// Only trades, quotes and `L2_MBP` order book deltas can be subscribed to.
+pub struct CoinbaseIntxLiveDataClient {
+    client_id: ClientId,
+    task: ClientTask<CoinbaseIntxDataClient>,
+}
+
+impl CoinbaseIntxLiveDataClient {
+    /// Creates a new [`CoinbaseIntxLiveDataClient`] instance, spawning the task running the `client`.
+    #[must_use]
+    pub fn new(
+        client_id: ClientId,
+        client: CoinbaseIntxDataClient,
+        data_tx: UnboundedSender<DataEvent>,
+    ) -> Self {
+        let task = ClientTask::spawn(
+            client,
+            |client| Box::pin(async move { client.next_data().await.map(DataEvent::Data) }),
+            data_tx,
+        );
+        Self { client_id, task }
+    }
+
+    /// Returns the activity of the client, for monitoring by a `ConnectionWatchdog`.
+    #[must_use]
+    pub fn activity(&self) -> ClientActivity {
+        self.task.activity()
+    }
+
+    fn load_instruments(&self) -> anyhow::Result<Vec<InstrumentAny>> {
+        self.task.call(|client| Box::pin(client.load_instruments()))
+    }
+}
+
+impl DataClient for CoinbaseIntxLiveDataClient {
+    fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    fn venue(&self) -> Option<Venue> {
+        Some(*COINBASE_INTX_VENUE)
+    }
+
+    fn start(&self) {
+        let result = self.task.call(|client| {
+            Box::pin(async move {
+                client.load_instruments().await?;
+                client.connect().await
+            })
+        });
+        if let Err(e) = result {
+            tracing::error!("Error starting {}: {e}", self.client_id);
+        }
+    }
+
+    fn stop(&self) {
+        let result = self.task.call(|client| {
+            Box::pin(async move {
+                client.disconnect().await;
+                Ok(())
+            })
+        });
+        if let Err(e) = result {
+            tracing::error!("Error stopping {}: {e}", self.client_id);
+        }
+    }
+
+    fn reset(&self) {}
+
+    fn dispose(&self) {
+        self.stop();
+    }
+
+    fn is_connected(&self) -> bool {
+        self.task
+            .call(|client| Box::pin(async move { Ok(client.is_connected()) }))
+            .unwrap_or(false)
+    }
+
+    fn is_disconnected(&self) -> bool {
+        !self.is_connected()
+    }
+
+    // -- COMMAND HANDLERS ------------------------------------------------------------------------
+
+    fn subscribe(
+        &mut self,
+        data_type: &DataType,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to {data_type} not supported")
+    }
+
+    fn subscribe_instruments(
+        &mut self,
+        _venue: Option<&Venue>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to instruments not supported")
+    }
+
+    fn subscribe_instrument(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to instrument {instrument_id} not supported")
+    }
+
+    fn subscribe_order_book_deltas(
+        &mut self,
+        instrument_id: &InstrumentId,
+        book_type: BookType,
+        _depth: Option<usize>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        if book_type != BookType::L2_MBP {
+            anyhow::bail!("Book type {book_type} not supported, use `L2_MBP`");
+        }
+        let instrument_id = *instrument_id;
+        self.task.call(move |client| {
+            Box::pin(async move { client.subscribe_book_deltas(&instrument_id).await })
+        })
+    }
+
+    fn subscribe_order_book_snapshots(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _book_type: BookType,
+        _depth: Option<usize>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to order book snapshots for {instrument_id} not supported")
+    }
+
+    fn subscribe_quote_ticks(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        let instrument_id = *instrument_id;
+        self.task.call(move |client| {
+            Box::pin(async move { client.subscribe_quotes(&instrument_id).await })
+        })
+    }
+
+    fn subscribe_trade_ticks(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        let instrument_id = *instrument_id;
+        self.task.call(move |client| {
+            Box::pin(async move { client.subscribe_trades(&instrument_id).await })
+        })
+    }
+
+    fn subscribe_bars(
+        &mut self,
+        bar_type: &BarType,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to bars {bar_type} not supported")
+    }
+
+    fn subscribe_instrument_status(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to instrument status for {instrument_id} not supported")
+    }
+
+    fn subscribe_instrument_close(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to instrument close for {instrument_id} not supported")
+    }
+
+    fn subscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to funding rates for {instrument_id} not supported")
+    }
+
+    fn subscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to mark prices for {instrument_id} not supported")
+    }
+
+    fn subscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Subscribing to index prices for {instrument_id} not supported")
+    }
+
+    fn unsubscribe(
+        &mut self,
+        data_type: &DataType,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from {data_type} not supported")
+    }
+
+    fn unsubscribe_instruments(
+        &mut self,
+        _venue: Option<&Venue>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from instruments not supported")
+    }
+
+    fn unsubscribe_instrument(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from instrument {instrument_id} not supported")
+    }
+
+    fn unsubscribe_order_book_deltas(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        let instrument_id = *instrument_id;
+        self.task.call(move |client| {
+            Box::pin(async move { client.unsubscribe_book_deltas(&instrument_id).await })
+        })
+    }
+
+    fn unsubscribe_order_book_snapshots(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from order book snapshots for {instrument_id} not supported")
+    }
+
+    fn unsubscribe_quote_ticks(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        let instrument_id = *instrument_id;
+        self.task.call(move |client| {
+            Box::pin(async move { client.unsubscribe_quotes(&instrument_id).await })
+        })
+    }
+
+    fn unsubscribe_trade_ticks(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        let instrument_id = *instrument_id;
+        self.task.call(move |client| {
+            Box::pin(async move { client.unsubscribe_trades(&instrument_id).await })
+        })
+    }
+
+    fn unsubscribe_bars(
+        &mut self,
+        bar_type: &BarType,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from bars {bar_type} not supported")
+    }
+
+    fn unsubscribe_instrument_status(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from instrument status for {instrument_id} not supported")
+    }
+
+    fn unsubscribe_instrument_close(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from instrument close for {instrument_id} not supported")
+    }
+
+    fn unsubscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from funding rates for {instrument_id} not supported")
+    }
+
+    fn unsubscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from mark prices for {instrument_id} not supported")
+    }
+
+    fn unsubscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("Unsubscribing from index prices for {instrument_id} not supported")
+    }
+
+    // -- DATA REQUEST HANDLERS -------------------------------------------------------------------
+
+    fn request_data(&self, request: DataRequest) {
+        tracing::error!("Requesting {} not supported", request.data_type);
+    }
+
+    fn request_instruments(
+        &self,
+        _correlation_id: UUID4,
+        _venue: Venue,
+        _start: Option<UnixNanos>,
+        _end: Option<UnixNanos>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<Vec<InstrumentAny>> {
+        self.load_instruments()
+    }
+
+    fn request_instrument(
+        &self,
+        _correlation_id: UUID4,
+        instrument_id: InstrumentId,
+        _start: Option<UnixNanos>,
+        _end: Option<UnixNanos>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<InstrumentAny> {
+        self.load_instruments()?
+            .into_iter()
+            .find(|instrument| instrument.id() == instrument_id)
+            .ok_or_else(|| anyhow::anyhow!("Instrument {instrument_id} not found"))
+    }
+
+    fn request_order_book_snapshot(
+        &self,
+        _correlation_id: UUID4,
+        instrument_id: InstrumentId,
+        _depth: Option<usize>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<Payload> {
+        anyhow::bail!("Requesting order book snapshots for {instrument_id} not supported")
+    }
+
+    fn request_quote_ticks(
+        &self,
+        _correlation_id: UUID4,
+        instrument_id: InstrumentId,
+        _start: Option<UnixNanos>,
+        _end: Option<UnixNanos>,
+        _limit: Option<usize>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<Vec<QuoteTick>> {
+        anyhow::bail!("Requesting quotes for {instrument_id} not supported")
+    }
+
+    fn request_trade_ticks(
+        &self,
+        _correlation_id: UUID4,
+        instrument_id: InstrumentId,
+        _start: Option<UnixNanos>,
+        _end: Option<UnixNanos>,
+        _limit: Option<usize>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<Vec<TradeTick>> {
+        anyhow::bail!("Requesting trades for {instrument_id} not supported")
+    }
+
+    fn request_bars(
+        &self,
+        _correlation_id: UUID4,
+        bar_type: BarType,
+        _start: Option<UnixNanos>,
+        _end: Option<UnixNanos>,
+        _limit: Option<usize>,
+        _params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<Vec<Bar>> {
+        anyhow::bail!("Requesting bars {bar_type} not supported")
+    }
+}
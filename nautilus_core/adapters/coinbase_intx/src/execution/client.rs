@@ -0,0 +1,376 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides an execution client for Coinbase International order management.
+
+use std::collections::HashMap;
+
+use nautilus_common::{
+    messages::execution::{
+        BatchCancelOrders, CancelAllOrders, CancelOrder, ModifyOrder, QueryOrder, SubmitOrder,
+        SubmitOrderList,
+    },
+    runtime::get_runtime,
+};
+use nautilus_core::{time::get_atomic_clock_realtime, uuid::UUID4};
+use nautilus_execution::client::LiveExecutionClient;
+use nautilus_model::{
+    enums::{OrderSide, OrderStatus},
+    events::{
+        OrderCancelRejected, OrderEventAny, OrderModifyRejected, OrderRejected, OrderSubmitted,
+    },
+    identifiers::{AccountId, ClientId, ClientOrderId, InstrumentId, Venue},
+    instruments::InstrumentAny,
+    orders::OrderAny,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::parse::{parse_order_events, parse_order_params, parse_order_status};
+use crate::{
+    common::consts::COINBASE_INTX_VENUE,
+    http::{models::CoinbaseIntxOrder, CoinbaseIntxHttpClient},
+};
+
+/// Provides an execution client for Coinbase International over the REST API.
+///
+/// Orders are submitted, canceled and queried by client order ID. The venue publishes
+/// order updates over FIX drop copy only, so order state is reconciled by querying.
+#[derive(Clone)]
+pub struct CoinbaseIntxExecutionClient {
+    http: CoinbaseIntxHttpClient,
+}
+
+impl CoinbaseIntxExecutionClient {
+    /// Creates a new [`CoinbaseIntxExecutionClient`] instance.
+    ///
+    /// The `http` client must have credentials for authenticated requests.
+    #[must_use]
+    pub const fn new(http: CoinbaseIntxHttpClient) -> Self {
+        Self { http }
+    }
+
+    /// Loads all trading perpetual and spot instruments, returning them.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instruments request fails.
+    pub async fn load_instruments(&self) -> anyhow::Result<Vec<InstrumentAny>> {
+        Ok(self.http.instruments().await?)
+    }
+
+    /// Submits the given `order`, returning the venue order and its Nautilus order status.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order cannot be represented on the venue, or the request fails.
+    pub async fn submit_order(
+        &self,
+        order: &OrderAny,
+    ) -> anyhow::Result<(CoinbaseIntxOrder, OrderStatus)> {
+        let params = parse_order_params(order)?;
+        let response = self.http.create_order(&params).await?;
+        let status = parse_order_status(&response)?;
+        Ok((response, status))
+    }
+
+    /// Cancels the open order with the given `client_order_id`, returning the venue order
+    /// and its Nautilus order status.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the request fails.
+    pub async fn cancel_order(
+        &self,
+        client_order_id: &ClientOrderId,
+    ) -> anyhow::Result<(CoinbaseIntxOrder, OrderStatus)> {
+        let response = self.http.cancel_order(client_order_id.as_str()).await?;
+        let status = parse_order_status(&response)?;
+        Ok((response, status))
+    }
+
+    /// Queries the order with the given `client_order_id`, returning the venue order and
+    /// its Nautilus order status.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the request fails.
+    pub async fn query_order(
+        &self,
+        client_order_id: &ClientOrderId,
+    ) -> anyhow::Result<(CoinbaseIntxOrder, OrderStatus)> {
+        let response = self.http.get_order(client_order_id.as_str()).await?;
+        let status = parse_order_status(&response)?;
+        Ok((response, status))
+    }
+}
+
+/// Provides a [`LiveExecutionClient`] for Coinbase International, running a
+/// [`CoinbaseIntxExecutionClient`] on the shared runtime.
+///
+/// The venue order returned by each request is compared against the client's copy of the
+/// order, and the resulting order events are sent on the `event_tx` channel for the
+/// execution engine to process.
+pub struct CoinbaseIntxLiveExecutionClient {
+    client_id: ClientId,
+    account_id: AccountId,
+    client: CoinbaseIntxExecutionClient,
+    event_tx: UnboundedSender<OrderEventAny>,
+    instruments: HashMap<InstrumentId, InstrumentAny>,
+    /// The open orders submitted through the client, by client order ID.
+    orders: HashMap<ClientOrderId, OrderAny>,
+    is_connected: bool,
+}
+
+impl CoinbaseIntxLiveExecutionClient {
+    /// Creates a new [`CoinbaseIntxLiveExecutionClient`] instance.
+    #[must_use]
+    pub fn new(
+        client_id: ClientId,
+        account_id: AccountId,
+        client: CoinbaseIntxExecutionClient,
+        event_tx: UnboundedSender<OrderEventAny>,
+    ) -> Self {
+        Self {
+            client_id,
+            account_id,
+            client,
+            event_tx,
+            instruments: HashMap::new(),
+            orders: HashMap::new(),
+            is_connected: false,
+        }
+    }
+
+    fn send_event(&self, event: OrderEventAny) {
+        if let Err(e) = self.event_tx.send(event) {
+            tracing::error!("Error sending order event: {e}");
+        }
+    }
+
+    /// Applies the order events for the given `venue_order` to the client's copy of the
+    /// order, sending each event.
+    fn process_venue_order(
+        &mut self,
+        client_order_id: &ClientOrderId,
+        venue_order: &CoinbaseIntxOrder,
+    ) {
+        let Some(order) = self.orders.get_mut(client_order_id) else {
+            return;
+        };
+        let Some(instrument) = self.instruments.get(&order.instrument_id()) else {
+            tracing::error!("Instrument {} not loaded", order.instrument_id());
+            return;
+        };
+
+        let ts_init = get_atomic_clock_realtime().get_time_ns();
+        let events =
+            match parse_order_events(venue_order, order, instrument, self.account_id, ts_init) {
+                Ok(events) => events,
+                Err(e) => {
+                    tracing::error!("Error parsing order {client_order_id}: {e}");
+                    return;
+                }
+            };
+
+        for event in &events {
+            if let Err(e) = order.apply(event.clone()) {
+                tracing::error!("Error applying event to order {client_order_id}: {e}");
+            }
+        }
+        if order.is_closed() {
+            self.orders.remove(client_order_id);
+        }
+        for event in events {
+            self.send_event(event);
+        }
+    }
+
+    fn submit(&mut self, mut order: OrderAny) {
+        let ts_now = get_atomic_clock_realtime().get_time_ns();
+        let submitted = OrderEventAny::Submitted(OrderSubmitted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            self.account_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        ));
+        if let Err(e) = order.apply(submitted.clone()) {
+            tracing::error!("Error submitting order {}: {e}", order.client_order_id());
+            return;
+        }
+        self.send_event(submitted);
+
+        let client_order_id = order.client_order_id();
+        match get_runtime().block_on(self.client.submit_order(&order)) {
+            Ok((venue_order, _)) => {
+                self.orders.insert(client_order_id, order);
+                self.process_venue_order(&client_order_id, &venue_order);
+            }
+            Err(e) => {
+                let ts_now = get_atomic_clock_realtime().get_time_ns();
+                self.send_event(OrderEventAny::Rejected(OrderRejected::new(
+                    order.trader_id(),
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    client_order_id,
+                    self.account_id,
+                    e.to_string().as_str().into(),
+                    UUID4::new(),
+                    ts_now,
+                    ts_now,
+                    false,
+                )));
+            }
+        }
+    }
+
+    fn cancel(&mut self, command: &CancelOrder) {
+        match get_runtime().block_on(self.client.cancel_order(&command.client_order_id)) {
+            Ok((venue_order, _)) => {
+                self.process_venue_order(&command.client_order_id, &venue_order)
+            }
+            Err(e) => {
+                let ts_now = get_atomic_clock_realtime().get_time_ns();
+                self.send_event(OrderEventAny::CancelRejected(OrderCancelRejected::new(
+                    command.trader_id,
+                    command.strategy_id,
+                    command.instrument_id,
+                    command.client_order_id,
+                    e.to_string().as_str().into(),
+                    UUID4::new(),
+                    ts_now,
+                    ts_now,
+                    false,
+                    Some(command.venue_order_id),
+                    Some(self.account_id),
+                )));
+            }
+        }
+    }
+}
+
+impl LiveExecutionClient for CoinbaseIntxLiveExecutionClient {
+    fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    fn venue(&self) -> Venue {
+        *COINBASE_INTX_VENUE
+    }
+
+    fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        let instruments = get_runtime().block_on(self.client.load_instruments())?;
+        for instrument in instruments {
+            self.instruments.insert(instrument.id(), instrument);
+        }
+        self.is_connected = true;
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        self.is_connected = false;
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.is_connected
+    }
+
+    // -- COMMAND HANDLERS ----------------------------------------------------
+
+    fn submit_order(&mut self, command: &SubmitOrder) -> anyhow::Result<()> {
+        self.submit(command.order.clone());
+        Ok(())
+    }
+
+    fn submit_order_list(&mut self, command: &SubmitOrderList) -> anyhow::Result<()> {
+        for order in &command.order_list.orders {
+            self.submit(order.clone());
+        }
+        Ok(())
+    }
+
+    fn modify_order(&mut self, command: &ModifyOrder) -> anyhow::Result<()> {
+        let ts_now = get_atomic_clock_realtime().get_time_ns();
+        self.send_event(OrderEventAny::ModifyRejected(OrderModifyRejected::new(
+            command.trader_id,
+            command.strategy_id,
+            command.instrument_id,
+            command.client_order_id,
+            "Modifying orders not supported".into(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(command.venue_order_id),
+            Some(self.account_id),
+        )));
+        Ok(())
+    }
+
+    fn cancel_order(&mut self, command: &CancelOrder) -> anyhow::Result<()> {
+        self.cancel(command);
+        Ok(())
+    }
+
+    fn cancel_all_orders(&mut self, command: &CancelAllOrders) -> anyhow::Result<()> {
+        let orders: Vec<OrderAny> = self
+            .orders
+            .values()
+            .filter(|order| {
+                order.instrument_id() == command.instrument_id
+                    && order.strategy_id() == command.strategy_id
+                    && (command.order_side == OrderSide::NoOrderSide
+                        || order.order_side() == command.order_side)
+            })
+            .cloned()
+            .collect();
+
+        for order in orders {
+            self.cancel(&CancelOrder {
+                trader_id: command.trader_id,
+                client_id: command.client_id,
+                strategy_id: command.strategy_id,
+                instrument_id: command.instrument_id,
+                client_order_id: order.client_order_id(),
+                venue_order_id: order.venue_order_id().unwrap_or_default(),
+                command_id: UUID4::new(),
+                ts_init: command.ts_init,
+            });
+        }
+        Ok(())
+    }
+
+    fn batch_cancel_orders(&mut self, command: &BatchCancelOrders) -> anyhow::Result<()> {
+        for cancel in &command.cancels {
+            self.cancel(cancel);
+        }
+        Ok(())
+    }
+
+    fn query_order(&mut self, command: &QueryOrder) -> anyhow::Result<()> {
+        let (venue_order, _) =
+            get_runtime().block_on(self.client.query_order(&command.client_order_id))?;
+        self.process_venue_order(&command.client_order_id, &venue_order);
+        Ok(())
+    }
+}
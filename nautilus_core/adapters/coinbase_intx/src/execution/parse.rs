@@ -0,0 +1,602 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Functions for translating between Nautilus orders and Coinbase International orders.
+
+use nautilus_core::{datetime::unix_nanos_to_iso8601, nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderStatus, OrderType, TimeInForce},
+    events::{
+        OrderAccepted, OrderCanceled, OrderEventAny, OrderExpired, OrderFilled, OrderRejected,
+        OrderTriggered,
+    },
+    identifiers::{AccountId, TradeId, VenueOrderId},
+    instruments::InstrumentAny,
+    orders::OrderAny,
+    types::Price,
+};
+
+use crate::{
+    common::{
+        enums::{
+            CoinbaseIntxOrderEventType, CoinbaseIntxOrderStatus, CoinbaseIntxOrderType,
+            CoinbaseIntxSide, CoinbaseIntxTimeInForce,
+        },
+        parse::parse_quantity,
+    },
+    http::models::{CoinbaseIntxNewOrderParams, CoinbaseIntxOrder},
+};
+
+/// Parses the Coinbase International new order request parameters for the given `order`.
+///
+/// Market orders are sent as `IOC` (or `FOK`), `GTD` orders are sent as `GTT` with an
+/// expire time, and reduce-only orders are sent as close-only.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the order type is not supported.
+/// - If the order is post-only and not a resting limit order.
+/// - If the time in force is not supported for the order type.
+/// - If a required price, trigger price or expire time is missing.
+pub fn parse_order_params(order: &OrderAny) -> anyhow::Result<CoinbaseIntxNewOrderParams> {
+    let order_type = order.order_type();
+    let intx_order_type = match order_type {
+        OrderType::Market => CoinbaseIntxOrderType::Market,
+        OrderType::Limit => CoinbaseIntxOrderType::Limit,
+        OrderType::StopMarket => CoinbaseIntxOrderType::Stop,
+        OrderType::StopLimit => CoinbaseIntxOrderType::StopLimit,
+        _ => anyhow::bail!("Order type {order_type} not supported for Coinbase International"),
+    };
+
+    let time_in_force = order.time_in_force();
+    let tif = match (order_type, time_in_force) {
+        (OrderType::Market, TimeInForce::Fok) => CoinbaseIntxTimeInForce::Fok,
+        (OrderType::Market, _) => CoinbaseIntxTimeInForce::Ioc,
+        (_, TimeInForce::Gtc) => CoinbaseIntxTimeInForce::Gtc,
+        (_, TimeInForce::Gtd) => CoinbaseIntxTimeInForce::Gtt,
+        (OrderType::Limit, TimeInForce::Ioc) => CoinbaseIntxTimeInForce::Ioc,
+        (OrderType::Limit, TimeInForce::Fok) => CoinbaseIntxTimeInForce::Fok,
+        _ => anyhow::bail!("Time in force {time_in_force} not supported for {order_type} orders"),
+    };
+
+    let post_only = order.is_post_only();
+    if post_only
+        && (order_type != OrderType::Limit
+            || matches!(
+                tif,
+                CoinbaseIntxTimeInForce::Ioc | CoinbaseIntxTimeInForce::Fok
+            ))
+    {
+        anyhow::bail!("Post-only is only supported for resting `LIMIT` orders");
+    }
+
+    let expire_time = match tif {
+        CoinbaseIntxTimeInForce::Gtt => {
+            Some(unix_nanos_to_iso8601(order.expire_time().ok_or_else(
+                || anyhow::anyhow!("`GTD` order has no expire time"),
+            )?))
+        }
+        _ => None,
+    };
+
+    let price = match order_type {
+        OrderType::Limit | OrderType::StopLimit => Some(
+            order
+                .price()
+                .ok_or_else(|| anyhow::anyhow!("{order_type} order has no price"))?
+                .to_string(),
+        ),
+        _ => None,
+    };
+
+    let stop_price = match order_type {
+        OrderType::StopMarket | OrderType::StopLimit => Some(
+            order
+                .trigger_price()
+                .ok_or_else(|| anyhow::anyhow!("{order_type} order has no trigger price"))?
+                .to_string(),
+        ),
+        _ => None,
+    };
+
+    Ok(CoinbaseIntxNewOrderParams {
+        client_order_id: order.client_order_id().to_string(),
+        side: CoinbaseIntxSide::from(order.order_side_specified()),
+        size: order.quantity().to_string(),
+        tif,
+        instrument: order.instrument_id().symbol.to_string(),
+        order_type: intx_order_type,
+        price,
+        stop_price,
+        expire_time,
+        portfolio: None,
+        post_only: post_only.then_some(true),
+        close_only: order.is_reduce_only().then_some(true),
+    })
+}
+
+/// Parses the Nautilus order status of the given Coinbase International `order`.
+///
+/// # Errors
+///
+/// This function returns an error if the executed or leaves quantity is not a valid decimal.
+pub fn parse_order_status(order: &CoinbaseIntxOrder) -> anyhow::Result<OrderStatus> {
+    let status = match order.event_type {
+        CoinbaseIntxOrderEventType::PendingNew => OrderStatus::Submitted,
+        CoinbaseIntxOrderEventType::PendingCancel => OrderStatus::PendingCancel,
+        CoinbaseIntxOrderEventType::PendingReplace => OrderStatus::PendingUpdate,
+        CoinbaseIntxOrderEventType::StopTriggered => OrderStatus::Triggered,
+        CoinbaseIntxOrderEventType::Rejected => OrderStatus::Rejected,
+        CoinbaseIntxOrderEventType::Canceled => OrderStatus::Canceled,
+        CoinbaseIntxOrderEventType::Expired => OrderStatus::Expired,
+        CoinbaseIntxOrderEventType::New
+        | CoinbaseIntxOrderEventType::Replaced
+        | CoinbaseIntxOrderEventType::Trade => {
+            let exec_qty = order.exec_qty.parse::<f64>()?;
+            let leaves_qty = order.leaves_qty.parse::<f64>()?;
+            if order.order_status == CoinbaseIntxOrderStatus::Done && leaves_qty == 0.0 {
+                OrderStatus::Filled
+            } else if exec_qty > 0.0 {
+                OrderStatus::PartiallyFilled
+            } else {
+                OrderStatus::Accepted
+            }
+        }
+    };
+    Ok(status)
+}
+
+/// Parses the order events which move the Nautilus `order` to the state of the given
+/// Coinbase International `venue_order`.
+///
+/// The venue reports only the executed quantity and average price of an order, so each fill
+/// since the last known state is reported as a single fill at its implied price.
+///
+/// # Errors
+///
+/// This function returns an error if a quantity or price is not a valid decimal.
+pub fn parse_order_events(
+    venue_order: &CoinbaseIntxOrder,
+    order: &OrderAny,
+    instrument: &InstrumentAny,
+    account_id: AccountId,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Vec<OrderEventAny>> {
+    let trader_id = order.trader_id();
+    let strategy_id = order.strategy_id();
+    let instrument_id = order.instrument_id();
+    let client_order_id = order.client_order_id();
+    let venue_order_id = VenueOrderId::new(&venue_order.order_id);
+    let status = parse_order_status(venue_order)?;
+    let mut events = Vec::new();
+
+    if status == OrderStatus::Rejected {
+        if order.status() != OrderStatus::Rejected {
+            events.push(OrderEventAny::Rejected(OrderRejected::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                account_id,
+                "REJECTED".into(),
+                UUID4::new(),
+                ts_init,
+                ts_init,
+                false,
+            )));
+        }
+        return Ok(events);
+    }
+
+    if order.status() == OrderStatus::Submitted && status != OrderStatus::Submitted {
+        events.push(OrderEventAny::Accepted(OrderAccepted::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            account_id,
+            UUID4::new(),
+            ts_init,
+            ts_init,
+            false,
+        )));
+    }
+
+    if status == OrderStatus::Triggered && order.status() != OrderStatus::Triggered {
+        events.push(OrderEventAny::Triggered(OrderTriggered::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            UUID4::new(),
+            ts_init,
+            ts_init,
+            false,
+            Some(venue_order_id),
+            Some(account_id),
+        )));
+    }
+
+    let exec_qty = parse_quantity(&venue_order.exec_qty, instrument.size_precision())?;
+    let filled_qty = order.filled_qty();
+    if exec_qty > filled_qty {
+        let avg_price = venue_order
+            .avg_price
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("Order {client_order_id} has no average price"))?
+            .parse::<f64>()?;
+        let last_qty = exec_qty - filled_qty;
+        let prev_notional = order.avg_px().unwrap_or(0.0) * filled_qty.as_f64();
+        let last_px = (avg_price * exec_qty.as_f64() - prev_notional) / last_qty.as_f64();
+        events.push(OrderEventAny::Filled(OrderFilled::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            account_id,
+            TradeId::new(format!("{}-{}", venue_order.order_id, exec_qty)),
+            order.order_side(),
+            order.order_type(),
+            last_qty,
+            Price::new_checked(last_px, instrument.price_precision())?,
+            instrument.quote_currency(),
+            LiquiditySide::NoLiquiditySide,
+            UUID4::new(),
+            ts_init,
+            ts_init,
+            false,
+            None,
+            None,
+        )));
+    }
+
+    if status == OrderStatus::Canceled && order.status() != OrderStatus::Canceled {
+        events.push(OrderEventAny::Canceled(OrderCanceled::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            UUID4::new(),
+            ts_init,
+            ts_init,
+            false,
+            Some(venue_order_id),
+            Some(account_id),
+        )));
+    }
+
+    if status == OrderStatus::Expired && order.status() != OrderStatus::Expired {
+        events.push(OrderEventAny::Expired(OrderExpired::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            UUID4::new(),
+            ts_init,
+            ts_init,
+            false,
+            Some(venue_order_id),
+            Some(account_id),
+        )));
+    }
+
+    Ok(events)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderSide,
+        identifiers::{ClientOrderId, InstrumentId},
+        orders::{stubs::TestOrderEventStubs, OrderTestBuilder},
+        types::Quantity,
+    };
+    use nautilus_test_kit::load_test_json;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::http::{models::CoinbaseIntxInstrument, parse::parse_instrument_any};
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("BTC-PERP.COINBASE_INTX")
+    }
+
+    #[rstest]
+    fn test_parse_limit_order() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id())
+            .client_order_id(ClientOrderId::from("O-123"))
+            .side(OrderSide::Buy)
+            .price(Price::from("46000.1"))
+            .quantity(Quantity::from("0.0100"))
+            .post_only(true)
+            .build();
+
+        let params = parse_order_params(&order).unwrap();
+
+        assert_eq!(params.client_order_id, "O-123");
+        assert_eq!(params.instrument, "BTC-PERP");
+        assert_eq!(params.side, CoinbaseIntxSide::Buy);
+        assert_eq!(params.order_type, CoinbaseIntxOrderType::Limit);
+        assert_eq!(params.tif, CoinbaseIntxTimeInForce::Gtc);
+        assert_eq!(params.price, Some("46000.1".to_string()));
+        assert_eq!(params.size, "0.0100");
+        assert_eq!(params.post_only, Some(true));
+        assert_eq!(params.close_only, None);
+    }
+
+    #[rstest]
+    fn test_parse_reduce_only_market_order() {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_id())
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from("0.0100"))
+            .reduce_only(true)
+            .build();
+
+        let params = parse_order_params(&order).unwrap();
+
+        assert_eq!(params.order_type, CoinbaseIntxOrderType::Market);
+        assert_eq!(params.tif, CoinbaseIntxTimeInForce::Ioc);
+        assert_eq!(params.close_only, Some(true));
+        assert_eq!(params.price, None);
+    }
+
+    #[rstest]
+    #[case(OrderType::StopMarket, CoinbaseIntxOrderType::Stop, None)]
+    #[case(
+        OrderType::StopLimit,
+        CoinbaseIntxOrderType::StopLimit,
+        Some("45900.0".to_string())
+    )]
+    fn test_parse_stop_order(
+        #[case] order_type: OrderType,
+        #[case] expected_type: CoinbaseIntxOrderType,
+        #[case] expected_price: Option<String>,
+    ) {
+        let mut builder = OrderTestBuilder::new(order_type);
+        builder
+            .instrument_id(instrument_id())
+            .side(OrderSide::Sell)
+            .trigger_price(Price::from("46000.0"))
+            .quantity(Quantity::from("0.0100"));
+        if order_type == OrderType::StopLimit {
+            builder.price(Price::from("45900.0"));
+        }
+        let order = builder.build();
+
+        let params = parse_order_params(&order).unwrap();
+
+        assert_eq!(params.order_type, expected_type);
+        assert_eq!(params.stop_price, Some("46000.0".to_string()));
+        assert_eq!(params.price, expected_price);
+    }
+
+    #[rstest]
+    fn test_parse_gtd_limit_order() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id())
+            .side(OrderSide::Buy)
+            .price(Price::from("46000.1"))
+            .quantity(Quantity::from("0.0100"))
+            .time_in_force(TimeInForce::Gtd)
+            .expire_time(UnixNanos::from(1_704_888_000_000_000_000))
+            .build();
+
+        let params = parse_order_params(&order).unwrap();
+
+        assert_eq!(params.tif, CoinbaseIntxTimeInForce::Gtt);
+        assert_eq!(
+            params.expire_time,
+            Some("2024-01-10T12:00:00.000000000Z".to_string())
+        );
+    }
+
+    #[rstest]
+    fn test_parse_post_only_ioc_errors() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id())
+            .side(OrderSide::Buy)
+            .price(Price::from("46000.1"))
+            .quantity(Quantity::from("0.0100"))
+            .time_in_force(TimeInForce::Ioc)
+            .post_only(true)
+            .build();
+
+        assert!(parse_order_params(&order).is_err());
+    }
+
+    #[rstest]
+    fn test_parse_unsupported_order_type_errors() {
+        let order = OrderTestBuilder::new(OrderType::MarketIfTouched)
+            .instrument_id(instrument_id())
+            .side(OrderSide::Buy)
+            .trigger_price(Price::from("46000.0"))
+            .quantity(Quantity::from("0.0100"))
+            .build();
+
+        assert!(parse_order_params(&order).is_err());
+    }
+
+    #[rstest]
+    #[case(
+        CoinbaseIntxOrderEventType::Trade,
+        CoinbaseIntxOrderStatus::Working,
+        "0.0040",
+        OrderStatus::PartiallyFilled
+    )]
+    #[case(
+        CoinbaseIntxOrderEventType::Trade,
+        CoinbaseIntxOrderStatus::Done,
+        "0",
+        OrderStatus::Filled
+    )]
+    #[case(
+        CoinbaseIntxOrderEventType::Canceled,
+        CoinbaseIntxOrderStatus::Done,
+        "0.0040",
+        OrderStatus::Canceled
+    )]
+    #[case(
+        CoinbaseIntxOrderEventType::StopTriggered,
+        CoinbaseIntxOrderStatus::Working,
+        "0.0040",
+        OrderStatus::Triggered
+    )]
+    fn test_parse_order_status(
+        #[case] event_type: CoinbaseIntxOrderEventType,
+        #[case] order_status: CoinbaseIntxOrderStatus,
+        #[case] leaves_qty: &str,
+        #[case] expected: OrderStatus,
+    ) {
+        let mut order: CoinbaseIntxOrder =
+            serde_json::from_str(&load_test_json!("http_order.json")).unwrap();
+        order.event_type = event_type;
+        order.order_status = order_status;
+        order.leaves_qty = leaves_qty.to_string();
+
+        assert_eq!(parse_order_status(&order).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_parse_new_order_status_accepted() {
+        let mut order: CoinbaseIntxOrder =
+            serde_json::from_str(&load_test_json!("http_order.json")).unwrap();
+        order.event_type = CoinbaseIntxOrderEventType::New;
+        order.exec_qty = "0".to_string();
+
+        assert_eq!(parse_order_status(&order).unwrap(), OrderStatus::Accepted);
+    }
+
+    fn btc_perp() -> InstrumentAny {
+        let instruments: Vec<CoinbaseIntxInstrument> =
+            serde_json::from_str(&load_test_json!("http_instruments.json")).unwrap();
+        parse_instrument_any(&instruments[0], UnixNanos::default()).unwrap()
+    }
+
+    fn submitted_order(account_id: AccountId) -> OrderAny {
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id())
+            .client_order_id(ClientOrderId::from("O-20240101-000000-001-001-1"))
+            .side(OrderSide::Buy)
+            .price(Price::from("60000.0"))
+            .quantity(Quantity::from("0.0100"))
+            .build();
+        order
+            .apply(TestOrderEventStubs::order_submitted(&order, account_id))
+            .unwrap();
+        order
+    }
+
+    #[rstest]
+    fn test_parse_order_events_accepts_and_fills() {
+        let venue_order: CoinbaseIntxOrder =
+            serde_json::from_str(&load_test_json!("http_order.json")).unwrap();
+        let account_id = AccountId::from("COINBASE_INTX-001");
+        let order = submitted_order(account_id);
+
+        let events = parse_order_events(
+            &venue_order,
+            &order,
+            &btc_perp(),
+            account_id,
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+        let OrderEventAny::Filled(fill) = &events[1] else {
+            panic!("Expected fill, was {:?}", events[1]);
+        };
+        assert_eq!(
+            fill.venue_order_id,
+            VenueOrderId::from("1838255049372307456")
+        );
+        assert_eq!(fill.last_qty, Quantity::from("0.0060"));
+        assert_eq!(fill.last_px, Price::from("59999.9"));
+        assert_eq!(fill.order_side, OrderSide::Buy);
+    }
+
+    #[rstest]
+    fn test_parse_order_events_reports_only_new_fill_quantity() {
+        let mut venue_order: CoinbaseIntxOrder =
+            serde_json::from_str(&load_test_json!("http_order.json")).unwrap();
+        let account_id = AccountId::from("COINBASE_INTX-001");
+        let instrument = btc_perp();
+        let mut order = submitted_order(account_id);
+        for event in parse_order_events(
+            &venue_order,
+            &order,
+            &instrument,
+            account_id,
+            UnixNanos::default(),
+        )
+        .unwrap()
+        {
+            order.apply(event).unwrap();
+        }
+
+        // Remaining 0.0040 filled at 60000.2, for an average price of 60000.02
+        venue_order.event_type = CoinbaseIntxOrderEventType::Trade;
+        venue_order.order_status = CoinbaseIntxOrderStatus::Done;
+        venue_order.exec_qty = "0.0100".to_string();
+        venue_order.leaves_qty = "0".to_string();
+        venue_order.avg_price = Some("60000.02".to_string());
+
+        let events = parse_order_events(
+            &venue_order,
+            &order,
+            &instrument,
+            account_id,
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(events.len(), 1);
+        let OrderEventAny::Filled(fill) = &events[0] else {
+            panic!("Expected fill, was {:?}", events[0]);
+        };
+        assert_eq!(fill.last_qty, Quantity::from("0.0040"));
+        assert_eq!(fill.last_px, Price::from("60000.2"));
+    }
+
+    #[rstest]
+    fn test_parse_order_events_rejected() {
+        let mut venue_order: CoinbaseIntxOrder =
+            serde_json::from_str(&load_test_json!("http_order.json")).unwrap();
+        venue_order.event_type = CoinbaseIntxOrderEventType::Rejected;
+        let account_id = AccountId::from("COINBASE_INTX-001");
+        let order = submitted_order(account_id);
+
+        let events = parse_order_events(
+            &venue_order,
+            &order,
+            &btc_perp(),
+            account_id,
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::Rejected(_)));
+    }
+}
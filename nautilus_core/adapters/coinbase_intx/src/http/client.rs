@@ -0,0 +1,321 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides an HTTP client for the Coinbase International REST API.
+
+use std::{collections::HashMap, num::NonZeroU32};
+
+use bytes::Bytes;
+use nautilus_core::{time::get_atomic_clock_realtime, version::USER_AGENT};
+use nautilus_model::instruments::InstrumentAny;
+use nautilus_network::{
+    http::{HttpClient, HttpClientError},
+    ratelimiter::quota::Quota,
+};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+use super::{
+    models::{
+        CoinbaseIntxErrorResponse, CoinbaseIntxInstrument, CoinbaseIntxNewOrderParams,
+        CoinbaseIntxOrder,
+    },
+    parse::parse_instrument_any,
+};
+use crate::common::{
+    consts::{
+        COINBASE_INTX_API_PATH, COINBASE_INTX_HTTP_URL, COINBASE_INTX_REQUESTS_PER_SECOND,
+        COINBASE_INTX_SANDBOX_HTTP_URL,
+    },
+    credential::CoinbaseIntxCredential,
+    enums::CoinbaseIntxInstrumentType,
+};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// HTTP errors for the Coinbase International HTTP client.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An error when an authenticated request is made without credentials.
+    #[error("Credentials are required for this request")]
+    MissingCredentials,
+    /// An error when signing a request.
+    #[error("Error signing request: {0}")]
+    Signing(String),
+    /// An error when sending a request to the server.
+    #[error("Error sending request: {0}")]
+    Request(#[from] HttpClientError),
+    /// An API error returned by Coinbase International.
+    #[error("Coinbase International API error {status}: {message}")]
+    ApiError { status: u16, message: String },
+    /// An error when serializing or deserializing a message.
+    #[error("Error deserializing message: {0}")]
+    Deserialization(#[from] serde_json::Error),
+}
+
+/// A Coinbase International REST API client.
+///
+/// Public endpoints can be used without credentials. Requests are rate limited to the
+/// venue limit, with any `429` responses backing off according to the `Retry-After` header.
+#[derive(Clone)]
+pub struct CoinbaseIntxHttpClient {
+    base_url: String,
+    credential: Option<CoinbaseIntxCredential>,
+    portfolio_id: Option<String>,
+    client: HttpClient,
+}
+
+impl CoinbaseIntxHttpClient {
+    /// Creates a new [`CoinbaseIntxHttpClient`] instance.
+    ///
+    /// Orders are placed for the given `portfolio_id`, or the default portfolio if `None`.
+    #[must_use]
+    pub fn new(
+        credential: Option<CoinbaseIntxCredential>,
+        portfolio_id: Option<String>,
+        base_url: Option<&str>,
+        is_sandbox: bool,
+    ) -> Self {
+        let base_url = base_url.map_or_else(
+            || match is_sandbox {
+                true => COINBASE_INTX_SANDBOX_HTTP_URL.to_string(),
+                false => COINBASE_INTX_HTTP_URL.to_string(),
+            },
+            ToString::to_string,
+        );
+        let rate_limit = NonZeroU32::new(COINBASE_INTX_REQUESTS_PER_SECOND)
+            .expect("Rate limit should be positive");
+        let client = HttpClient::new(
+            HashMap::from([
+                ("User-Agent".to_string(), USER_AGENT.to_string()),
+                ("Content-Type".to_string(), "application/json".to_string()),
+            ]),
+            vec![],
+            vec![],
+            None,
+            Some(Quota::per_second(rate_limit)),
+            false,
+            0, // Requests are signed with a timestamp, so retries are left to the caller
+        );
+
+        Self {
+            base_url,
+            credential,
+            portfolio_id,
+            client,
+        }
+    }
+
+    /// Returns the base URL for the client.
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the portfolio ID orders are placed for, if any.
+    #[must_use]
+    pub fn portfolio_id(&self) -> Option<&str> {
+        self.portfolio_id.as_deref()
+    }
+
+    /// Returns all instruments listed on the venue.
+    /// See <https://docs.cdp.coinbase.com/intx/reference/getinstruments>.
+    pub async fn list_instruments(&self) -> Result<Vec<CoinbaseIntxInstrument>> {
+        self.send_json(Method::GET, "/instruments", None, false)
+            .await
+    }
+
+    /// Returns the currently trading perpetual and spot instruments as Nautilus instruments.
+    ///
+    /// Instruments which fail to parse are logged and skipped.
+    pub async fn instruments(&self) -> Result<Vec<InstrumentAny>> {
+        let ts_init = get_atomic_clock_realtime().get_time_ns();
+        let instruments = self.list_instruments().await?;
+
+        Ok(instruments
+            .iter()
+            .filter(|instrument| {
+                instrument.is_trading()
+                    && instrument.instrument_type != CoinbaseIntxInstrumentType::Unknown
+            })
+            .filter_map(
+                |instrument| match parse_instrument_any(instrument, ts_init) {
+                    Ok(instrument) => Some(instrument),
+                    Err(e) => {
+                        tracing::warn!("Skipping instrument {}: {e}", instrument.symbol);
+                        None
+                    }
+                },
+            )
+            .collect())
+    }
+
+    /// Places a new order.
+    /// See <https://docs.cdp.coinbase.com/intx/reference/createorder>.
+    pub async fn create_order(
+        &self,
+        params: &CoinbaseIntxNewOrderParams,
+    ) -> Result<CoinbaseIntxOrder> {
+        let mut params = params.clone();
+        if params.portfolio.is_none() {
+            params.portfolio.clone_from(&self.portfolio_id);
+        }
+        let body = serde_json::to_vec(&params)?;
+        self.send_json(Method::POST, "/orders", Some(body), true)
+            .await
+    }
+
+    /// Cancels the open order with the given `id`, which may be the venue order ID or
+    /// the client order ID.
+    /// See <https://docs.cdp.coinbase.com/intx/reference/cancelorder>.
+    pub async fn cancel_order(&self, id: &str) -> Result<CoinbaseIntxOrder> {
+        let path = self.order_path(id);
+        self.send_json(Method::DELETE, &path, None, true).await
+    }
+
+    /// Returns the order with the given `id`, which may be the venue order ID or the client order ID.
+    /// See <https://docs.cdp.coinbase.com/intx/reference/getorder>.
+    pub async fn get_order(&self, id: &str) -> Result<CoinbaseIntxOrder> {
+        let path = self.order_path(id);
+        self.send_json(Method::GET, &path, None, true).await
+    }
+
+    fn order_path(&self, id: &str) -> String {
+        match &self.portfolio_id {
+            Some(portfolio_id) => format!("/orders/{id}?portfolio={portfolio_id}"),
+            None => format!("/orders/{id}"),
+        }
+    }
+
+    async fn send_json<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<Vec<u8>>,
+        authenticate: bool,
+    ) -> Result<T> {
+        let body = self.send(method, path, body, authenticate).await?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<Vec<u8>>,
+        authenticate: bool,
+    ) -> Result<Bytes> {
+        let request_path = format!("{COINBASE_INTX_API_PATH}{path}");
+
+        let mut headers = None;
+        if authenticate {
+            let credential = self.credential.as_ref().ok_or(Error::MissingCredentials)?;
+            let timestamp = (get_atomic_clock_realtime().get_time_ms() / 1_000).to_string();
+            let body_str = body
+                .as_deref()
+                .map(String::from_utf8_lossy)
+                .unwrap_or_default();
+            let signature = credential
+                .sign_request(&timestamp, method.as_str(), &request_path, &body_str)
+                .map_err(|e| Error::Signing(e.to_string()))?;
+
+            headers = Some(HashMap::from([
+                (
+                    "CB-ACCESS-KEY".to_string(),
+                    credential.api_key().expose().to_string(),
+                ),
+                (
+                    "CB-ACCESS-PASSPHRASE".to_string(),
+                    credential.api_passphrase().expose().to_string(),
+                ),
+                ("CB-ACCESS-SIGN".to_string(), signature),
+                ("CB-ACCESS-TIMESTAMP".to_string(), timestamp),
+            ]));
+        }
+
+        let url = format!("{}{request_path}", self.base_url);
+        tracing::debug!("Sending {method} {url}");
+
+        let response = self
+            .client
+            .request(method, url, headers, body, None, None, None)
+            .await?;
+
+        if response.status >= 400 {
+            return Err(parse_error_response(response.status, &response.body));
+        }
+
+        Ok(response.body)
+    }
+}
+
+fn parse_error_response(status: u16, body: &[u8]) -> Error {
+    let message = match serde_json::from_slice::<CoinbaseIntxErrorResponse>(body) {
+        Ok(error) => error.title,
+        Err(_) => String::from_utf8_lossy(body).to_string(),
+    };
+    Error::ApiError { status, message }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_parse_error_response() {
+        let error = parse_error_response(400, br#"{"title":"Insufficient margin","status":400}"#);
+
+        assert!(matches!(
+            error,
+            Error::ApiError { status: 400, ref message } if message == "Insufficient margin"
+        ));
+    }
+
+    #[rstest]
+    fn test_parse_error_response_without_api_error() {
+        let error = parse_error_response(502, b"Bad Gateway");
+
+        assert!(matches!(
+            error,
+            Error::ApiError { status: 502, ref message } if message == "Bad Gateway"
+        ));
+    }
+
+    #[rstest]
+    fn test_order_path_includes_portfolio() {
+        let client =
+            CoinbaseIntxHttpClient::new(None, Some("1wp37qsc-1-0".to_string()), None, true);
+
+        assert_eq!(client.base_url(), COINBASE_INTX_SANDBOX_HTTP_URL);
+        assert_eq!(
+            client.order_path("123"),
+            "/orders/123?portfolio=1wp37qsc-1-0"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_request_without_credentials_fails() {
+        let client = CoinbaseIntxHttpClient::new(None, None, None, false);
+
+        let result = client.get_order("123").await;
+
+        assert!(matches!(result, Err(Error::MissingCredentials)));
+    }
+}
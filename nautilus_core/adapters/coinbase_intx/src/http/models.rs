@@ -0,0 +1,190 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Coinbase International REST API request and response models.
+
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::common::enums::{
+    CoinbaseIntxInstrumentType, CoinbaseIntxOrderEventType, CoinbaseIntxOrderStatus,
+    CoinbaseIntxOrderType, CoinbaseIntxSide, CoinbaseIntxTimeInForce,
+};
+
+/// An error response from the Coinbase International REST API.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseIntxErrorResponse {
+    #[serde(alias = "message")]
+    pub title: String,
+    #[serde(default)]
+    pub status: Option<u16>,
+}
+
+/// An instrument from the `/instruments` endpoint.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseIntxInstrument {
+    pub instrument_id: String,
+    pub symbol: Ustr,
+    #[serde(rename = "type")]
+    pub instrument_type: CoinbaseIntxInstrumentType,
+    pub base_asset_name: Ustr,
+    pub quote_asset_name: Ustr,
+    pub base_increment: String,
+    pub quote_increment: String,
+    /// The base initial margin fraction (perpetuals only).
+    #[serde(default)]
+    pub base_imf: Option<String>,
+    #[serde(default)]
+    pub min_notional_value: Option<String>,
+    pub trading_state: String,
+}
+
+impl CoinbaseIntxInstrument {
+    /// Returns whether the instrument is currently trading.
+    #[must_use]
+    pub fn is_trading(&self) -> bool {
+        self.trading_state == "TRADING"
+    }
+}
+
+/// The body of a new order request.
+/// See <https://docs.cdp.coinbase.com/intx/reference/createorder>.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CoinbaseIntxNewOrderParams {
+    pub client_order_id: String,
+    pub side: CoinbaseIntxSide,
+    pub size: String,
+    pub tif: CoinbaseIntxTimeInForce,
+    pub instrument: String,
+    #[serde(rename = "type")]
+    pub order_type: CoinbaseIntxOrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<String>,
+    /// The ISO 8601 expire time for `GTT` orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub portfolio: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only: Option<bool>,
+    /// Whether the order may only reduce a position.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_only: Option<bool>,
+}
+
+/// An order returned by the order endpoints.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseIntxOrder {
+    pub order_id: String,
+    pub client_order_id: String,
+    pub side: CoinbaseIntxSide,
+    pub symbol: Ustr,
+    #[serde(rename = "type")]
+    pub order_type: CoinbaseIntxOrderType,
+    #[serde(default)]
+    pub price: Option<String>,
+    #[serde(default)]
+    pub stop_price: Option<String>,
+    pub size: String,
+    pub tif: CoinbaseIntxTimeInForce,
+    pub event_type: CoinbaseIntxOrderEventType,
+    pub order_status: CoinbaseIntxOrderStatus,
+    pub leaves_qty: String,
+    pub exec_qty: String,
+    #[serde(default)]
+    pub avg_price: Option<String>,
+    #[serde(default)]
+    pub post_only: bool,
+    #[serde(default)]
+    pub close_only: bool,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_test_kit::load_test_json;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_deserialize_instruments() {
+        let instruments: Vec<CoinbaseIntxInstrument> =
+            serde_json::from_str(&load_test_json!("http_instruments.json")).unwrap();
+
+        assert_eq!(instruments.len(), 3);
+        assert_eq!(instruments[0].symbol.as_str(), "BTC-PERP");
+        assert_eq!(
+            instruments[0].instrument_type,
+            CoinbaseIntxInstrumentType::Perp
+        );
+        assert_eq!(instruments[0].base_imf, Some("0.1".to_string()));
+        assert!(instruments[0].is_trading());
+        assert_eq!(
+            instruments[1].instrument_type,
+            CoinbaseIntxInstrumentType::Spot
+        );
+        assert_eq!(instruments[1].base_imf, None);
+        assert_eq!(
+            instruments[2].instrument_type,
+            CoinbaseIntxInstrumentType::Unknown
+        );
+    }
+
+    #[rstest]
+    fn test_deserialize_order() {
+        let order: CoinbaseIntxOrder =
+            serde_json::from_str(&load_test_json!("http_order.json")).unwrap();
+
+        assert_eq!(order.order_id, "1838255049372307456");
+        assert_eq!(order.side, CoinbaseIntxSide::Buy);
+        assert_eq!(order.order_type, CoinbaseIntxOrderType::Limit);
+        assert_eq!(order.event_type, CoinbaseIntxOrderEventType::Trade);
+        assert_eq!(order.order_status, CoinbaseIntxOrderStatus::Working);
+        assert_eq!(order.exec_qty, "0.0060");
+        assert_eq!(order.stop_price, None);
+    }
+
+    #[rstest]
+    fn test_serialize_new_order_params() {
+        let params = CoinbaseIntxNewOrderParams {
+            client_order_id: "O-123".to_string(),
+            side: CoinbaseIntxSide::Sell,
+            size: "0.0100".to_string(),
+            tif: CoinbaseIntxTimeInForce::Gtc,
+            instrument: "BTC-PERP".to_string(),
+            order_type: CoinbaseIntxOrderType::StopLimit,
+            price: Some("45000.0".to_string()),
+            stop_price: Some("45100.0".to_string()),
+            expire_time: None,
+            portfolio: None,
+            post_only: None,
+            close_only: Some(true),
+        };
+
+        let value = serde_json::to_value(&params).unwrap();
+
+        assert_eq!(value["side"], "SELL");
+        assert_eq!(value["type"], "STOP_LIMIT");
+        assert_eq!(value["stop_price"], "45100.0");
+        assert_eq!(value["close_only"], true);
+        assert!(value.get("expire_time").is_none());
+        assert!(value.get("post_only").is_none());
+    }
+}
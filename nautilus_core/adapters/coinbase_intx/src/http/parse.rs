@@ -0,0 +1,196 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::str::FromStr;
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    currencies::CURRENCY_MAP,
+    enums::CurrencyType,
+    identifiers::Symbol,
+    instruments::{CryptoPerpetual, CurrencyPair, InstrumentAny},
+    types::{Currency, Money, Price, Quantity},
+};
+use rust_decimal::Decimal;
+
+use super::models::CoinbaseIntxInstrument;
+use crate::common::{
+    enums::CoinbaseIntxInstrumentType,
+    parse::{normalize_decimal_str, parse_instrument_id},
+};
+
+/// Parses a Nautilus instrument from the given Coinbase International `instrument`.
+///
+/// Perpetuals are parsed as linear [`CryptoPerpetual`]s settled in the quote currency, with
+/// the base initial margin fraction as the initial margin, and spot instruments as
+/// [`CurrencyPair`]s. The price and size precisions are derived from the increments.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the instrument type is not supported.
+/// - If any increment or limit is not a valid decimal.
+pub fn parse_instrument_any(
+    instrument: &CoinbaseIntxInstrument,
+    ts_init: UnixNanos,
+) -> anyhow::Result<InstrumentAny> {
+    let instrument_id = parse_instrument_id(&instrument.symbol);
+    let raw_symbol = Symbol::new(instrument.symbol);
+    let base_currency = get_currency(&instrument.base_asset_name);
+    let quote_currency = get_currency(&instrument.quote_asset_name);
+    let price_increment = Price::from(normalize_decimal_str(&instrument.quote_increment)?);
+    let size_increment = Quantity::from(normalize_decimal_str(&instrument.base_increment)?);
+    let min_notional = instrument
+        .min_notional_value
+        .as_deref()
+        .map(|value| value.parse::<f64>())
+        .transpose()?
+        .filter(|value| *value > 0.0)
+        .map(|value| Money::new(value, quote_currency));
+
+    match instrument.instrument_type {
+        CoinbaseIntxInstrumentType::Perp => {
+            let margin_init = instrument
+                .base_imf
+                .as_deref()
+                .map(Decimal::from_str)
+                .transpose()?;
+
+            Ok(InstrumentAny::CryptoPerpetual(
+                CryptoPerpetual::new_checked(
+                    instrument_id,
+                    raw_symbol,
+                    base_currency,
+                    quote_currency,
+                    quote_currency,
+                    false,
+                    price_increment.precision,
+                    size_increment.precision,
+                    price_increment,
+                    size_increment,
+                    Some(Quantity::from(1)),
+                    None,
+                    None,
+                    Some(size_increment),
+                    None,
+                    min_notional,
+                    None,
+                    None,
+                    margin_init,
+                    None,
+                    None,
+                    None,
+                    ts_init, // ts_event same as ts_init (no venue timestamp)
+                    ts_init,
+                )?,
+            ))
+        }
+        CoinbaseIntxInstrumentType::Spot => {
+            Ok(InstrumentAny::CurrencyPair(CurrencyPair::new_checked(
+                instrument_id,
+                raw_symbol,
+                base_currency,
+                quote_currency,
+                price_increment.precision,
+                size_increment.precision,
+                price_increment,
+                size_increment,
+                None,
+                None,
+                Some(size_increment),
+                None,
+                min_notional,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                ts_init, // ts_event same as ts_init (no venue timestamp)
+                ts_init,
+            )?))
+        }
+        CoinbaseIntxInstrumentType::Unknown => {
+            anyhow::bail!("Unsupported instrument type for {}", instrument.symbol)
+        }
+    }
+}
+
+/// Returns the currency either from the internal currency map or creates a default crypto.
+fn get_currency(code: &str) -> Currency {
+    CURRENCY_MAP
+        .lock()
+        .unwrap()
+        .get(code)
+        .copied()
+        .unwrap_or(Currency::new(code, 8, 0, code, CurrencyType::Crypto))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::identifiers::InstrumentId;
+    use nautilus_test_kit::load_test_json;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn instruments() -> Vec<CoinbaseIntxInstrument> {
+        serde_json::from_str(&load_test_json!("http_instruments.json")).unwrap()
+    }
+
+    #[rstest]
+    fn test_parse_perpetual() {
+        let instrument = parse_instrument_any(&instruments()[0], UnixNanos::default()).unwrap();
+
+        assert!(matches!(instrument, InstrumentAny::CryptoPerpetual(_)));
+        assert_eq!(
+            instrument.id(),
+            InstrumentId::from("BTC-PERP.COINBASE_INTX")
+        );
+        assert_eq!(instrument.base_currency(), Some(Currency::BTC()));
+        assert_eq!(instrument.quote_currency(), Currency::USDC());
+        assert_eq!(instrument.settlement_currency(), Currency::USDC());
+        assert!(!instrument.is_inverse());
+        assert_eq!(instrument.price_precision(), 1);
+        assert_eq!(instrument.size_precision(), 4);
+        assert_eq!(instrument.price_increment(), Price::from("0.1"));
+        assert_eq!(instrument.size_increment(), Quantity::from("0.0001"));
+        assert_eq!(
+            instrument.min_notional(),
+            Some(Money::new(10.0, Currency::USDC()))
+        );
+    }
+
+    #[rstest]
+    fn test_parse_spot() {
+        let instrument = parse_instrument_any(&instruments()[1], UnixNanos::default()).unwrap();
+
+        assert!(matches!(instrument, InstrumentAny::CurrencyPair(_)));
+        assert_eq!(
+            instrument.id(),
+            InstrumentId::from("ETH-USDC.COINBASE_INTX")
+        );
+        assert_eq!(instrument.price_precision(), 2);
+        assert_eq!(instrument.size_precision(), 3);
+    }
+
+    #[rstest]
+    fn test_parse_unsupported_instrument_type() {
+        assert!(parse_instrument_any(&instruments()[2], UnixNanos::default()).is_err());
+    }
+}
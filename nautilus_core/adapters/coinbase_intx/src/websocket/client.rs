@@ -0,0 +1,234 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a WebSocket client for the Coinbase International market data feed.
+
+use std::sync::Arc;
+
+use futures_util::{stream::SplitSink, SinkExt, StreamExt};
+use nautilus_core::time::get_atomic_clock_realtime;
+use nautilus_cryptography::providers::install_cryptographic_provider;
+use serde_json::json;
+use tokio::{
+    net::TcpStream,
+    sync::{mpsc, Mutex},
+    task::JoinHandle,
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+use ustr::Ustr;
+
+use crate::common::{credential::CoinbaseIntxCredential, enums::CoinbaseIntxWsChannel};
+
+type SharedMessageWriter =
+    Arc<Mutex<SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>>>;
+
+/// Provides a WebSocket client for the Coinbase International market data feed.
+///
+/// All subscriptions are authenticated, so a credential is required even for public
+/// market data. Text frames are forwarded to a channel read with
+/// [`CoinbaseIntxWebSocketClient::next_message`].
+pub struct CoinbaseIntxWebSocketClient {
+    url: String,
+    credential: CoinbaseIntxCredential,
+    writer: SharedMessageWriter,
+    rx: mpsc::UnboundedReceiver<String>,
+    read_task: JoinHandle<()>,
+}
+
+impl CoinbaseIntxWebSocketClient {
+    /// Connects to the market data feed at the given `url`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the connection cannot be established.
+    pub async fn connect(url: &str, credential: CoinbaseIntxCredential) -> anyhow::Result<Self> {
+        install_cryptographic_provider();
+
+        let (stream, _) = connect_async(url).await?;
+        let (writer, mut reader) = stream.split();
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let read_task = tokio::task::spawn(async move {
+            while let Some(message) = reader.next().await {
+                match message {
+                    Ok(Message::Text(text)) => {
+                        if tx.send(text).is_err() {
+                            break; // Client dropped
+                        }
+                    }
+                    Ok(Message::Close(frame)) => {
+                        tracing::debug!("Received close message: {frame:?}");
+                        break;
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        tracing::error!("Error reading from Coinbase International WebSocket: {e}");
+                        break;
+                    }
+                }
+            }
+            tracing::debug!("Coinbase International WebSocket read task completed");
+        });
+
+        tracing::info!("Connected to {url}");
+
+        Ok(Self {
+            url: url.to_string(),
+            credential,
+            writer: Arc::new(Mutex::new(writer)),
+            rx,
+            read_task,
+        })
+    }
+
+    /// Returns the URL of the connection.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns whether the read task is still running.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        !self.read_task.is_finished()
+    }
+
+    /// Subscribes to the given `channel` for the `product_ids`, e.g. `BTC-PERP`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the request cannot be signed or sent.
+    pub async fn subscribe(
+        &self,
+        channel: CoinbaseIntxWsChannel,
+        product_ids: &[Ustr],
+    ) -> anyhow::Result<()> {
+        self.send_request("SUBSCRIBE", channel, product_ids).await
+    }
+
+    /// Unsubscribes from the given `channel` for the `product_ids`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the request cannot be signed or sent.
+    pub async fn unsubscribe(
+        &self,
+        channel: CoinbaseIntxWsChannel,
+        product_ids: &[Ustr],
+    ) -> anyhow::Result<()> {
+        self.send_request("UNSUBSCRIBE", channel, product_ids).await
+    }
+
+    /// Returns the next text message, or `None` once the connection has closed.
+    pub async fn next_message(&mut self) -> Option<String> {
+        self.rx.recv().await
+    }
+
+    /// Closes the connection.
+    pub async fn close(&self) {
+        if let Err(e) = self.writer.lock().await.close().await {
+            tracing::error!("Error closing Coinbase International WebSocket: {e}");
+        }
+        self.read_task.abort();
+    }
+
+    async fn send_request(
+        &self,
+        request_type: &str,
+        channel: CoinbaseIntxWsChannel,
+        product_ids: &[Ustr],
+    ) -> anyhow::Result<()> {
+        let timestamp = (get_atomic_clock_realtime().get_time_ms() / 1_000).to_string();
+        let request = subscription_request(
+            &self.credential,
+            request_type,
+            channel,
+            product_ids,
+            &timestamp,
+        )?;
+        tracing::debug!("Sending {request_type} {channel} {product_ids:?}");
+
+        self.writer
+            .lock()
+            .await
+            .send(Message::Text(request))
+            .await?;
+        Ok(())
+    }
+}
+
+impl Drop for CoinbaseIntxWebSocketClient {
+    fn drop(&mut self) {
+        self.read_task.abort();
+    }
+}
+
+fn subscription_request(
+    credential: &CoinbaseIntxCredential,
+    request_type: &str,
+    channel: CoinbaseIntxWsChannel,
+    product_ids: &[Ustr],
+    timestamp: &str,
+) -> anyhow::Result<String> {
+    Ok(json!({
+        "type": request_type,
+        "product_ids": product_ids,
+        "channels": [channel],
+        "time": timestamp,
+        "key": credential.api_key().expose(),
+        "passphrase": credential.api_passphrase().expose(),
+        "signature": credential.sign_ws(timestamp)?,
+    })
+    .to_string())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::credentials::Secret;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_subscription_request() {
+        let credential = CoinbaseIntxCredential::new(
+            Secret::new("my-api-key").unwrap(),
+            Secret::new("Y29pbmJhc2UtaW50eC10ZXN0LXNlY3JldA==").unwrap(),
+            Secret::new("my-passphrase").unwrap(),
+        );
+
+        let request = subscription_request(
+            &credential,
+            "SUBSCRIBE",
+            CoinbaseIntxWsChannel::Level2,
+            &[Ustr::from("BTC-PERP")],
+            "1700000000",
+        )
+        .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&request).unwrap();
+
+        assert_eq!(value["type"], "SUBSCRIBE");
+        assert_eq!(value["product_ids"][0], "BTC-PERP");
+        assert_eq!(value["channels"][0], "LEVEL2");
+        assert_eq!(value["time"], "1700000000");
+        assert_eq!(
+            value["signature"],
+            "qEKXphog/bMhmuJiKwgKYgTF77tWtfwgNdKKK7+7BQo="
+        );
+    }
+}
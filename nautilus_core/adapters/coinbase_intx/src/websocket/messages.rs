@@ -0,0 +1,238 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Coinbase International WebSocket market data messages.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use ustr::Ustr;
+
+use crate::common::enums::CoinbaseIntxSide;
+
+/// A message received on the market data WebSocket feed.
+#[derive(Clone, Debug)]
+pub enum CoinbaseIntxWsMessage {
+    Level1(CoinbaseIntxLevel1Msg),
+    Level2Snapshot(CoinbaseIntxLevel2SnapshotMsg),
+    Level2Update(CoinbaseIntxLevel2UpdateMsg),
+    Match(CoinbaseIntxMatchMsg),
+    Subscriptions(CoinbaseIntxSubscriptionsMsg),
+    Reject(CoinbaseIntxRejectMsg),
+    /// Any other message, by channel (e.g. instrument updates).
+    Other(String),
+}
+
+/// A best bid and offer update from the `LEVEL1` channel.
+///
+/// A side is absent when there are no orders on that side of the book.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseIntxLevel1Msg {
+    pub sequence: u64,
+    pub product_id: Ustr,
+    pub time: DateTime<Utc>,
+    #[serde(default)]
+    pub bid_price: Option<String>,
+    #[serde(default)]
+    pub bid_qty: Option<String>,
+    #[serde(default)]
+    pub ask_price: Option<String>,
+    #[serde(default)]
+    pub ask_qty: Option<String>,
+}
+
+/// A single price level of an order book `[price, quantity]`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct CoinbaseIntxBookLevel(pub String, pub String);
+
+/// A single change to an order book `[side, price, quantity]`, where a zero quantity
+/// removes the level.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct CoinbaseIntxBookChange(pub CoinbaseIntxSide, pub String, pub String);
+
+/// An order book snapshot from the `LEVEL2` channel.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseIntxLevel2SnapshotMsg {
+    pub sequence: u64,
+    pub product_id: Ustr,
+    pub time: DateTime<Utc>,
+    pub bids: Vec<CoinbaseIntxBookLevel>,
+    pub asks: Vec<CoinbaseIntxBookLevel>,
+}
+
+/// An order book update from the `LEVEL2` channel.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseIntxLevel2UpdateMsg {
+    pub sequence: u64,
+    pub product_id: Ustr,
+    pub time: DateTime<Utc>,
+    pub changes: Vec<CoinbaseIntxBookChange>,
+}
+
+/// A trade from the `MATCH` channel.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseIntxMatchMsg {
+    pub sequence: u64,
+    pub product_id: Ustr,
+    pub time: DateTime<Utc>,
+    pub match_id: String,
+    pub trade_price: String,
+    pub trade_qty: String,
+    pub aggressor_side: CoinbaseIntxSide,
+}
+
+/// The current subscriptions, sent in response to each subscription request.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseIntxSubscriptionsMsg {
+    #[serde(default)]
+    pub authenticated: bool,
+    /// The subscribed product IDs by channel.
+    #[serde(default)]
+    pub channels: HashMap<String, Vec<Ustr>>,
+}
+
+/// A rejected request.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseIntxRejectMsg {
+    pub message: String,
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub channel: Option<String>,
+}
+
+/// Parses a message from the market data WebSocket feed.
+///
+/// # Errors
+///
+/// This function returns an error if the message is not valid JSON, or a market data message is invalid.
+pub fn parse_ws_message(text: &str) -> anyhow::Result<CoinbaseIntxWsMessage> {
+    let value: serde_json::Value = serde_json::from_str(text)?;
+    let msg_type = value
+        .get("type")
+        .and_then(serde_json::Value::as_str)
+        .unwrap_or_default();
+
+    if msg_type == "REJECT" {
+        return Ok(CoinbaseIntxWsMessage::Reject(serde_json::from_value(
+            value,
+        )?));
+    }
+
+    let channel = value
+        .get("channel")
+        .and_then(serde_json::Value::as_str)
+        .ok_or_else(|| anyhow::anyhow!("Missing channel in message"))?
+        .to_string();
+
+    match (channel.as_str(), msg_type) {
+        ("LEVEL1", _) => Ok(CoinbaseIntxWsMessage::Level1(serde_json::from_value(
+            value,
+        )?)),
+        ("LEVEL2", "SNAPSHOT") => Ok(CoinbaseIntxWsMessage::Level2Snapshot(
+            serde_json::from_value(value)?,
+        )),
+        ("LEVEL2", _) => Ok(CoinbaseIntxWsMessage::Level2Update(serde_json::from_value(
+            value,
+        )?)),
+        ("MATCH", _) => Ok(CoinbaseIntxWsMessage::Match(serde_json::from_value(value)?)),
+        ("SUBSCRIPTIONS", _) => Ok(CoinbaseIntxWsMessage::Subscriptions(
+            serde_json::from_value(value)?,
+        )),
+        _ => Ok(CoinbaseIntxWsMessage::Other(channel)),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_test_kit::load_test_json;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_parse_level1() {
+        let msg = parse_ws_message(&load_test_json!("ws_level1.json")).unwrap();
+
+        let CoinbaseIntxWsMessage::Level1(msg) = msg else {
+            panic!("Expected LEVEL1 message, was {msg:?}");
+        };
+        assert_eq!(msg.sequence, 2);
+        assert_eq!(msg.product_id.as_str(), "BTC-PERP");
+        assert_eq!(msg.bid_price, Some("46000.1".to_string()));
+        assert_eq!(msg.ask_qty, Some("0.2500".to_string()));
+    }
+
+    #[rstest]
+    fn test_parse_level2_snapshot_and_update() {
+        let snapshot = parse_ws_message(&load_test_json!("ws_level2_snapshot.json")).unwrap();
+        let update = parse_ws_message(&load_test_json!("ws_level2_update.json")).unwrap();
+
+        let CoinbaseIntxWsMessage::Level2Snapshot(snapshot) = snapshot else {
+            panic!("Expected LEVEL2 snapshot, was {snapshot:?}");
+        };
+        assert_eq!(snapshot.bids.len(), 2);
+        assert_eq!(
+            snapshot.asks[0],
+            CoinbaseIntxBookLevel("46000.2".to_string(), "0.2500".to_string())
+        );
+
+        let CoinbaseIntxWsMessage::Level2Update(update) = update else {
+            panic!("Expected LEVEL2 update, was {update:?}");
+        };
+        assert_eq!(update.sequence, 1);
+        assert_eq!(update.changes[0].0, CoinbaseIntxSide::Buy);
+        assert_eq!(update.changes[1].2, "0.7500");
+    }
+
+    #[rstest]
+    fn test_parse_match() {
+        let msg = parse_ws_message(&load_test_json!("ws_match.json")).unwrap();
+
+        let CoinbaseIntxWsMessage::Match(msg) = msg else {
+            panic!("Expected MATCH message, was {msg:?}");
+        };
+        assert_eq!(msg.match_id, "177101110052388865");
+        assert_eq!(msg.aggressor_side, CoinbaseIntxSide::Sell);
+        assert_eq!(msg.trade_price, "46000.1");
+    }
+
+    #[rstest]
+    fn test_parse_subscriptions() {
+        let msg = parse_ws_message(&load_test_json!("ws_subscriptions.json")).unwrap();
+
+        let CoinbaseIntxWsMessage::Subscriptions(msg) = msg else {
+            panic!("Expected subscriptions message, was {msg:?}");
+        };
+        assert!(msg.authenticated);
+        assert_eq!(msg.channels["LEVEL1"], vec![Ustr::from("BTC-PERP")]);
+    }
+
+    #[rstest]
+    fn test_parse_reject() {
+        let text = r#"{"type":"REJECT","message":"failed to subscribe","reason":"invalid signature","channel":"LEVEL1"}"#;
+
+        let msg = parse_ws_message(text).unwrap();
+
+        assert!(matches!(
+            msg,
+            CoinbaseIntxWsMessage::Reject(ref reject) if reject.reason.as_deref() == Some("invalid signature")
+        ));
+    }
+}
@@ -0,0 +1,311 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Functions for parsing Coinbase International market data messages into Nautilus data.
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::{BookOrder, OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick},
+    enums::{BookAction, OrderSide, RecordFlag},
+    identifiers::{InstrumentId, TradeId},
+};
+
+use super::messages::{
+    CoinbaseIntxLevel1Msg, CoinbaseIntxLevel2SnapshotMsg, CoinbaseIntxLevel2UpdateMsg,
+    CoinbaseIntxMatchMsg,
+};
+use crate::common::parse::{parse_price, parse_quantity};
+
+/// Parses a Nautilus quote tick from a Coinbase International `LEVEL1` message.
+///
+/// Returns `None` if either side of the book is empty.
+///
+/// # Errors
+///
+/// This function returns an error if any price or quantity is invalid.
+pub fn parse_level1(
+    msg: &CoinbaseIntxLevel1Msg,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Option<QuoteTick>> {
+    let (Some(bid_price), Some(bid_qty), Some(ask_price), Some(ask_qty)) =
+        (&msg.bid_price, &msg.bid_qty, &msg.ask_price, &msg.ask_qty)
+    else {
+        return Ok(None);
+    };
+
+    QuoteTick::new_checked(
+        instrument_id,
+        parse_price(bid_price, price_precision)?,
+        parse_price(ask_price, price_precision)?,
+        parse_quantity(bid_qty, size_precision)?,
+        parse_quantity(ask_qty, size_precision)?,
+        UnixNanos::from(msg.time),
+        ts_init,
+    )
+    .map(Some)
+}
+
+/// Parses a Nautilus trade tick from a Coinbase International `MATCH` message.
+///
+/// # Errors
+///
+/// This function returns an error if the price or quantity is invalid.
+pub fn parse_match(
+    msg: &CoinbaseIntxMatchMsg,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    ts_init: UnixNanos,
+) -> anyhow::Result<TradeTick> {
+    Ok(TradeTick::new(
+        instrument_id,
+        parse_price(&msg.trade_price, price_precision)?,
+        parse_quantity(&msg.trade_qty, size_precision)?,
+        msg.aggressor_side.into(),
+        TradeId::new(&msg.match_id),
+        UnixNanos::from(msg.time),
+        ts_init,
+    ))
+}
+
+/// Parses Nautilus order book deltas from a Coinbase International `LEVEL2` snapshot.
+///
+/// The deltas begin with a `Clear` followed by an `Add` for each level, all flagged with
+/// `F_SNAPSHOT`, with the last also flagged with `F_LAST`.
+///
+/// # Errors
+///
+/// This function returns an error if any level is invalid.
+pub fn parse_level2_snapshot(
+    msg: &CoinbaseIntxLevel2SnapshotMsg,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    ts_init: UnixNanos,
+) -> anyhow::Result<OrderBookDeltas> {
+    let ts_event = UnixNanos::from(msg.time);
+    let snapshot_flag = RecordFlag::F_SNAPSHOT as u8;
+
+    let mut deltas = Vec::with_capacity(msg.bids.len() + msg.asks.len() + 1);
+    let mut clear = OrderBookDelta::clear(instrument_id, msg.sequence, ts_event, ts_init);
+    clear.flags |= snapshot_flag;
+    deltas.push(clear);
+
+    let levels = msg
+        .bids
+        .iter()
+        .map(|level| (OrderSide::Buy, level))
+        .chain(msg.asks.iter().map(|level| (OrderSide::Sell, level)));
+    for (side, level) in levels {
+        let order = parse_book_order(side, &level.0, &level.1, price_precision, size_precision)?;
+        deltas.push(OrderBookDelta::new(
+            instrument_id,
+            BookAction::Add,
+            order,
+            snapshot_flag,
+            msg.sequence,
+            ts_event,
+            ts_init,
+        ));
+    }
+
+    // SAFETY: Deltas always contain at least the clear
+    deltas.last_mut().unwrap().flags |= RecordFlag::F_LAST as u8;
+
+    OrderBookDeltas::new_checked(instrument_id, deltas)
+}
+
+/// Parses Nautilus order book deltas from a Coinbase International `LEVEL2` update.
+///
+/// Changes with a zero quantity are deletes, all others update the level. The last
+/// delta is flagged with `F_LAST`.
+///
+/// # Errors
+///
+/// This function returns an error if any change is invalid, or the update contains no changes.
+pub fn parse_level2_update(
+    msg: &CoinbaseIntxLevel2UpdateMsg,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    ts_init: UnixNanos,
+) -> anyhow::Result<OrderBookDeltas> {
+    let ts_event = UnixNanos::from(msg.time);
+    let count = msg.changes.len();
+
+    let mut deltas = Vec::with_capacity(count);
+    for (i, change) in msg.changes.iter().enumerate() {
+        let order = parse_book_order(
+            change.0.into(),
+            &change.1,
+            &change.2,
+            price_precision,
+            size_precision,
+        )?;
+        let action = if order.size.is_zero() {
+            BookAction::Delete
+        } else {
+            BookAction::Update
+        };
+        let flags = if i == count - 1 {
+            RecordFlag::F_LAST as u8
+        } else {
+            0
+        };
+
+        deltas.push(OrderBookDelta::new(
+            instrument_id,
+            action,
+            order,
+            flags,
+            msg.sequence,
+            ts_event,
+            ts_init,
+        ));
+    }
+
+    OrderBookDeltas::new_checked(instrument_id, deltas)
+}
+
+fn parse_book_order(
+    side: OrderSide,
+    price: &str,
+    size: &str,
+    price_precision: u8,
+    size_precision: u8,
+) -> anyhow::Result<BookOrder> {
+    Ok(BookOrder::new(
+        side,
+        parse_price(price, price_precision)?,
+        parse_quantity(size, size_precision)?,
+        0, // Order IDs are not provided for aggregated price levels
+    ))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::AggressorSide,
+        types::{Price, Quantity},
+    };
+    use nautilus_test_kit::load_test_json;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::websocket::messages::{parse_ws_message, CoinbaseIntxWsMessage};
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("BTC-PERP.COINBASE_INTX")
+    }
+
+    #[rstest]
+    fn test_parse_level1() {
+        let CoinbaseIntxWsMessage::Level1(msg) =
+            parse_ws_message(&load_test_json!("ws_level1.json")).unwrap()
+        else {
+            panic!("Expected LEVEL1 message");
+        };
+
+        let quote = parse_level1(&msg, instrument_id(), 1, 4, UnixNanos::from(1))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(quote.bid_price, Price::from("46000.1"));
+        assert_eq!(quote.ask_price, Price::from("46000.2"));
+        assert_eq!(quote.bid_size, Quantity::from("1.5000"));
+        assert_eq!(quote.ask_size, Quantity::from("0.2500"));
+        assert_eq!(quote.ts_event, UnixNanos::from(1_704_888_000_123_000_000));
+        assert_eq!(quote.ts_init, UnixNanos::from(1));
+    }
+
+    #[rstest]
+    fn test_parse_level1_with_empty_side() {
+        let CoinbaseIntxWsMessage::Level1(mut msg) =
+            parse_ws_message(&load_test_json!("ws_level1.json")).unwrap()
+        else {
+            panic!("Expected LEVEL1 message");
+        };
+        msg.ask_price = None;
+        msg.ask_qty = None;
+
+        let quote = parse_level1(&msg, instrument_id(), 1, 4, UnixNanos::from(1)).unwrap();
+
+        assert!(quote.is_none());
+    }
+
+    #[rstest]
+    fn test_parse_match() {
+        let CoinbaseIntxWsMessage::Match(msg) =
+            parse_ws_message(&load_test_json!("ws_match.json")).unwrap()
+        else {
+            panic!("Expected MATCH message");
+        };
+
+        let trade = parse_match(&msg, instrument_id(), 1, 4, UnixNanos::from(1)).unwrap();
+
+        assert_eq!(trade.price, Price::from("46000.1"));
+        assert_eq!(trade.size, Quantity::from("0.0060"));
+        assert_eq!(trade.aggressor_side, AggressorSide::Seller);
+        assert_eq!(trade.trade_id, TradeId::new("177101110052388865"));
+        assert_eq!(trade.ts_event, UnixNanos::from(1_704_888_001_500_000_000));
+    }
+
+    #[rstest]
+    fn test_parse_level2_snapshot() {
+        let CoinbaseIntxWsMessage::Level2Snapshot(msg) =
+            parse_ws_message(&load_test_json!("ws_level2_snapshot.json")).unwrap()
+        else {
+            panic!("Expected LEVEL2 snapshot");
+        };
+
+        let deltas =
+            parse_level2_snapshot(&msg, instrument_id(), 1, 4, UnixNanos::from(1)).unwrap();
+
+        assert_eq!(deltas.deltas.len(), 4);
+        assert_eq!(deltas.deltas[0].action, BookAction::Clear);
+        assert_eq!(deltas.deltas[3].order.side, OrderSide::Sell);
+        assert!(deltas
+            .deltas
+            .iter()
+            .all(|delta| RecordFlag::F_SNAPSHOT.matches(delta.flags)));
+        assert!(RecordFlag::F_LAST.matches(deltas.deltas[3].flags));
+        assert!(!RecordFlag::F_LAST.matches(deltas.deltas[2].flags));
+    }
+
+    #[rstest]
+    fn test_parse_level2_update() {
+        let CoinbaseIntxWsMessage::Level2Update(msg) =
+            parse_ws_message(&load_test_json!("ws_level2_update.json")).unwrap()
+        else {
+            panic!("Expected LEVEL2 update");
+        };
+
+        let deltas = parse_level2_update(&msg, instrument_id(), 1, 4, UnixNanos::from(1)).unwrap();
+
+        assert_eq!(deltas.deltas.len(), 2);
+        assert_eq!(deltas.deltas[0].action, BookAction::Delete);
+        assert_eq!(deltas.deltas[0].order.side, OrderSide::Buy);
+        assert_eq!(deltas.deltas[1].action, BookAction::Update);
+        assert_eq!(deltas.deltas[1].order.price, Price::from("46000.3"));
+        assert_eq!(deltas.deltas[1].flags, RecordFlag::F_LAST as u8);
+        assert_eq!(deltas.sequence, 1);
+    }
+}
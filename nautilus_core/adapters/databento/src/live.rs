@@ -22,8 +22,11 @@ use databento::{
     dbn::{self, PitSymbolMap, Publisher, Record, SymbolIndex, VersionUpgradePolicy},
     live::Subscription,
 };
-use indexmap::IndexMap;
-use nautilus_core::{nanos::UnixNanos, python::to_pyruntime_err, time::get_atomic_clock_realtime};
+use indexmap::{IndexMap, IndexSet};
+use nautilus_core::{
+    credentials::Secret, nanos::UnixNanos, python::to_pyruntime_err,
+    time::get_atomic_clock_realtime,
+};
 use nautilus_model::{
     data::{Data, InstrumentStatus, OrderBookDelta, OrderBookDeltas, OrderBookDeltas_API},
     enums::RecordFlag,
@@ -63,13 +66,113 @@ pub enum LiveMessage {
     Close,
 }
 
+/// Tracks the symbols subscribed to for each schema during a live session, so that
+/// repeated subscriptions for the same data are only sent to the gateway once.
+#[derive(Debug, Default)]
+pub struct SubscriptionRegistry {
+    subscriptions: IndexMap<String, IndexSet<String>>,
+}
+
+impl SubscriptionRegistry {
+    /// Creates a new [`SubscriptionRegistry`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the given `symbols` for the `schema`, returning only the symbols
+    /// which were not already subscribed to.
+    pub fn register(&mut self, schema: &str, symbols: &[String]) -> Vec<String> {
+        let subscribed = self.subscriptions.entry(schema.to_string()).or_default();
+        symbols
+            .iter()
+            .filter(|symbol| subscribed.insert((*symbol).clone()))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns whether the `symbol` is subscribed to for the `schema`.
+    #[must_use]
+    pub fn is_subscribed(&self, schema: &str, symbol: &str) -> bool {
+        self.subscriptions
+            .get(schema)
+            .is_some_and(|symbols| symbols.contains(symbol))
+    }
+
+    /// Returns the symbols subscribed to for the `schema`, in subscription order.
+    #[must_use]
+    pub fn symbols(&self, schema: &str) -> Vec<String> {
+        self.subscriptions
+            .get(schema)
+            .map(|symbols| symbols.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Assembles MBO deltas into [`OrderBookDeltas`] batches per instrument.
+///
+/// Deltas are buffered until the last record of a book event (`F_LAST`). A snapshot is
+/// emitted as a single batch once its last record arrives, so the book can be rebuilt
+/// before any increments are applied. When replaying from a start time, deltas are
+/// buffered until the replay catches up with the `buffering_start` timestamp.
+#[derive(Debug, Default)]
+pub struct BookAssembler {
+    buffered_deltas: HashMap<InstrumentId, Vec<OrderBookDelta>>,
+    buffering_start: Option<UnixNanos>,
+}
+
+impl BookAssembler {
+    /// Creates a new [`BookAssembler`] instance.
+    #[must_use]
+    pub fn new(buffering_start: Option<UnixNanos>) -> Self {
+        Self {
+            buffered_deltas: HashMap::new(),
+            buffering_start,
+        }
+    }
+
+    /// Sets the timestamp up to which replayed deltas are buffered.
+    pub fn set_buffering_start(&mut self, buffering_start: Option<UnixNanos>) {
+        self.buffering_start = buffering_start;
+    }
+
+    /// Buffers the given `delta`, returning the batch of deltas for its instrument
+    /// once the book event (or snapshot) is complete.
+    pub fn push(&mut self, delta: OrderBookDelta) -> Option<OrderBookDeltas> {
+        let instrument_id = delta.instrument_id;
+        self.buffered_deltas
+            .entry(instrument_id)
+            .or_default()
+            .push(delta);
+
+        // Check if last message in the book event
+        if !RecordFlag::F_LAST.matches(delta.flags) {
+            return None; // NOT last message
+        }
+
+        // Check if buffering a replay (snapshots are always emitted once complete)
+        if !RecordFlag::F_SNAPSHOT.matches(delta.flags) {
+            if let Some(start_ns) = self.buffering_start {
+                if delta.ts_event <= start_ns {
+                    return None; // Continue buffering replay
+                }
+                self.buffering_start = None;
+            }
+        }
+
+        self.buffered_deltas
+            .remove(&instrument_id)
+            .map(|buffer| OrderBookDeltas::new(instrument_id, buffer))
+    }
+}
+
 /// Handles a raw TCP data feed from the Databento LSG for a single dataset.
 ///
 /// [`LiveCommand`] messages are recieved synchronously across a channel,
 /// decoded records are sent asynchronously on a tokio channel as [`LiveMessage`]s
 /// back to a message processing task.
 pub struct DatabentoFeedHandler {
-    key: String,
+    key: Secret,
     dataset: String,
     cmd_rx: tokio::sync::mpsc::UnboundedReceiver<LiveCommand>,
     msg_tx: tokio::sync::mpsc::Sender<LiveMessage>,
@@ -82,7 +185,7 @@ impl DatabentoFeedHandler {
     /// Creates a new [`DatabentoFeedHandler`] instance.
     #[must_use]
     pub const fn new(
-        key: String,
+        key: Secret,
         dataset: String,
         rx: tokio::sync::mpsc::UnboundedReceiver<LiveCommand>,
         tx: tokio::sync::mpsc::Sender<LiveMessage>,
@@ -107,14 +210,13 @@ impl DatabentoFeedHandler {
         let mut symbol_map = PitSymbolMap::new();
         let mut instrument_id_map: HashMap<u32, InstrumentId> = HashMap::new();
 
-        let mut buffering_start = None;
-        let mut buffered_deltas: HashMap<InstrumentId, Vec<OrderBookDelta>> = HashMap::new();
+        let mut book_assembler = BookAssembler::default();
         let mut deltas_count = 0_u64;
 
         let result = timeout(
             Duration::from_secs(5), // Hard-coded timeout for now
             databento::LiveClient::builder()
-                .key(self.key.clone())?
+                .key(self.key.expose())?
                 .dataset(self.dataset.clone())
                 .upgrade_policy(VersionUpgradePolicy::UpgradeToV2)
                 .build(),
@@ -153,10 +255,10 @@ impl DatabentoFeedHandler {
                             client.subscribe(&sub).await.map_err(to_pyruntime_err)?;
                         }
                         LiveCommand::Start => {
-                            buffering_start = match self.replay {
+                            book_assembler.set_buffering_start(match self.replay {
                                 true => Some(clock.get_time_ns()),
                                 false => None,
-                            };
+                            });
                             client.start().await.map_err(to_pyruntime_err)?;
                             running = true;
                             tracing::debug!("Started");
@@ -282,41 +384,19 @@ impl DatabentoFeedHandler {
                     }
                 };
 
-                if let Some(msg) = record.get::<dbn::MboMsg>() {
-                    if let Data::Delta(delta) = data1.clone().expect("MBO should decode a delta") {
-                        let buffer = buffered_deltas.entry(delta.instrument_id).or_default();
-                        buffer.push(delta);
-
+                if record.get::<dbn::MboMsg>().is_some() {
+                    // MBO trades decode to `data2` only, so only deltas are buffered
+                    if let Some(Data::Delta(delta)) = data1 {
                         deltas_count += 1;
                         tracing::trace!(
-                            "Buffering delta: {deltas_count} {} {buffering_start:?} flags={}",
+                            "Buffering delta: {deltas_count} {} flags={}",
                             delta.ts_event,
-                            msg.flags.raw(),
+                            delta.flags,
                         );
 
-                        // Check if last message in the book event
-                        if !RecordFlag::F_LAST.matches(msg.flags.raw()) {
-                            continue; // NOT last message
-                        }
-
-                        // Check if snapshot
-                        if RecordFlag::F_SNAPSHOT.matches(msg.flags.raw()) {
-                            continue; // Buffer snapshot
-                        }
-
-                        // Check if buffering a replay
-                        if let Some(start_ns) = buffering_start {
-                            if delta.ts_event <= start_ns {
-                                continue; // Continue buffering replay
-                            }
-                            buffering_start = None;
-                        }
-
-                        // SAFETY: We can guarantee a deltas vec exists
-                        let buffer = buffered_deltas.remove(&delta.instrument_id).unwrap();
-                        let deltas = OrderBookDeltas::new(delta.instrument_id, buffer);
-                        let deltas = OrderBookDeltas_API::new(deltas);
-                        data1 = Some(Data::Deltas(deltas));
+                        data1 = book_assembler
+                            .push(delta)
+                            .map(|deltas| Data::Deltas(OrderBookDeltas_API::new(deltas)));
                     }
                 };
 
@@ -528,3 +608,107 @@ fn handle_record(
         true, // Always include trades
     )
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::BookOrder,
+        enums::{BookAction, OrderSide},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn delta(action: BookAction, flags: u8, sequence: u64, ts_event: u64) -> OrderBookDelta {
+        OrderBookDelta::new(
+            InstrumentId::from("ESM4.GLBX"),
+            action,
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("5000.00"),
+                Quantity::from(1),
+                sequence,
+            ),
+            flags,
+            sequence,
+            ts_event.into(),
+            ts_event.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_registry_dedupes_symbols() {
+        let mut registry = SubscriptionRegistry::new();
+
+        let added = registry.register("mbo", &["ESM4".to_string(), "NQM4".to_string()]);
+        let added_again = registry.register("mbo", &["ESM4".to_string(), "CLM4".to_string()]);
+        let added_other = registry.register("trades", &["ESM4".to_string()]);
+
+        assert_eq!(added, vec!["ESM4", "NQM4"]);
+        assert_eq!(added_again, vec!["CLM4"]);
+        assert_eq!(added_other, vec!["ESM4"]);
+        assert_eq!(registry.symbols("mbo"), vec!["ESM4", "NQM4", "CLM4"]);
+        assert!(registry.is_subscribed("trades", "ESM4"));
+        assert!(!registry.is_subscribed("trades", "NQM4"));
+        assert!(registry.symbols("ohlcv-1s").is_empty());
+    }
+
+    #[rstest]
+    fn test_assembler_buffers_until_last() {
+        let mut assembler = BookAssembler::default();
+
+        assert!(assembler.push(delta(BookAction::Add, 0, 1, 1)).is_none());
+        let deltas = assembler
+            .push(delta(BookAction::Add, RecordFlag::F_LAST as u8, 2, 2))
+            .unwrap();
+
+        assert_eq!(deltas.deltas.len(), 2);
+        assert_eq!(deltas.sequence, 2);
+    }
+
+    #[rstest]
+    fn test_assembler_emits_snapshot_before_increments() {
+        let mut assembler = BookAssembler::default();
+        let snapshot = RecordFlag::F_SNAPSHOT as u8;
+        let last = RecordFlag::F_LAST as u8;
+
+        assert!(assembler
+            .push(delta(BookAction::Clear, snapshot, 0, 1))
+            .is_none());
+        assert!(assembler
+            .push(delta(BookAction::Add, snapshot, 0, 1))
+            .is_none());
+        let snapshot_deltas = assembler
+            .push(delta(BookAction::Add, snapshot | last, 0, 1))
+            .unwrap();
+        let increment = assembler
+            .push(delta(BookAction::Update, last, 3, 2))
+            .unwrap();
+
+        assert_eq!(snapshot_deltas.deltas.len(), 3);
+        assert_eq!(snapshot_deltas.deltas[0].action, BookAction::Clear);
+        assert_eq!(increment.deltas.len(), 1);
+    }
+
+    #[rstest]
+    fn test_assembler_buffers_replay_until_start() {
+        let mut assembler = BookAssembler::new(Some(UnixNanos::from(10)));
+        let last = RecordFlag::F_LAST as u8;
+
+        assert!(assembler.push(delta(BookAction::Add, last, 1, 5)).is_none());
+        assert!(assembler
+            .push(delta(BookAction::Add, last, 2, 10))
+            .is_none());
+        let deltas = assembler.push(delta(BookAction::Add, last, 3, 11)).unwrap();
+
+        assert_eq!(deltas.deltas.len(), 3);
+
+        // Replay has caught up, so subsequent events are emitted immediately
+        let deltas = assembler.push(delta(BookAction::Add, last, 4, 12)).unwrap();
+        assert_eq!(deltas.deltas.len(), 1);
+    }
+}
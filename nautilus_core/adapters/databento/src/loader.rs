@@ -17,6 +17,7 @@ use std::{
     collections::HashMap,
     env, fs,
     path::{Path, PathBuf},
+    str::FromStr,
 };
 
 use databento::dbn;
@@ -33,6 +34,7 @@ use nautilus_model::{
     instruments::InstrumentAny,
     types::Currency,
 };
+use nautilus_persistence::backend::catalog::ParquetDataCatalog;
 use ustr::Ustr;
 
 use super::{
@@ -429,6 +431,74 @@ impl DatabentoDataLoader {
             .collect()
     }
 
+    /// Loads all records from the DBN file at `filepath` and writes them to the given `catalog`,
+    /// dispatching on the schema recorded in the file metadata. Returns the paths of the files written.
+    ///
+    /// TBBO files are written as both quotes and trades.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the file metadata has no schema, or the schema cannot be written to the catalog.
+    /// - If decoding any of the records fails.
+    /// - If writing to the catalog fails.
+    pub fn write_to_catalog(
+        &mut self,
+        filepath: &Path,
+        catalog: &ParquetDataCatalog,
+        instrument_id: Option<InstrumentId>,
+        price_precision: Option<u8>,
+        use_exchange_as_venue: bool,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        let schema = self
+            .schema_from_file(filepath)?
+            .ok_or_else(|| anyhow::anyhow!("No schema found in metadata for {filepath:?}"))?;
+
+        match dbn::Schema::from_str(&schema)? {
+            dbn::Schema::Definition => {
+                let instruments = self.load_instruments(filepath, use_exchange_as_venue)?;
+                catalog.write_instruments(instruments)
+            }
+            dbn::Schema::Mbo => {
+                let deltas =
+                    self.load_order_book_deltas(filepath, instrument_id, price_precision)?;
+                catalog.write_to_partitioned_parquet("order_book_delta", deltas)
+            }
+            dbn::Schema::Mbp10 => {
+                let depths =
+                    self.load_order_book_depth10(filepath, instrument_id, price_precision)?;
+                catalog.write_to_partitioned_parquet("order_book_depth10", depths)
+            }
+            dbn::Schema::Mbp1 => {
+                let quotes = self.load_quotes(filepath, instrument_id, price_precision)?;
+                catalog.write_to_partitioned_parquet("quote_tick", quotes)
+            }
+            dbn::Schema::Bbo1S | dbn::Schema::Bbo1M => {
+                let quotes = self.load_bbo_quotes(filepath, instrument_id, price_precision)?;
+                catalog.write_to_partitioned_parquet("quote_tick", quotes)
+            }
+            dbn::Schema::Tbbo => {
+                let quotes = self.load_quotes(filepath, instrument_id, price_precision)?;
+                let trades = self.load_tbbo_trades(filepath, instrument_id, price_precision)?;
+                let mut paths = catalog.write_to_partitioned_parquet("quote_tick", quotes)?;
+                paths.extend(catalog.write_to_partitioned_parquet("trade_tick", trades)?);
+                Ok(paths)
+            }
+            dbn::Schema::Trades => {
+                let trades = self.load_trades(filepath, instrument_id, price_precision)?;
+                catalog.write_to_partitioned_parquet("trade_tick", trades)
+            }
+            dbn::Schema::Ohlcv1S
+            | dbn::Schema::Ohlcv1M
+            | dbn::Schema::Ohlcv1H
+            | dbn::Schema::Ohlcv1D => {
+                let bars = self.load_bars(filepath, instrument_id, price_precision)?;
+                catalog.write_to_partitioned_parquet("bar", bars)
+            }
+            schema => anyhow::bail!("Cannot write schema '{schema}' to the catalog"),
+        }
+    }
+
     pub fn load_status_records<T>(
         &self,
         filepath: &Path,
@@ -695,4 +765,50 @@ mod tests {
 
         assert_eq!(bars.len(), 2);
     }
+
+    #[rstest]
+    #[case("test_data.mbo.dbn.zst", "order_book_delta")]
+    #[case("test_data.mbp-10.dbn.zst", "order_book_depth10")]
+    #[case("test_data.mbp-1.dbn.zst", "quote_tick")]
+    #[case("test_data.trades.dbn.zst", "trade_tick")]
+    #[case("test_data.ohlcv-1s.dbn.zst", "bar")]
+    fn test_write_to_catalog(#[case] filename: &str, #[case] type_name: &str) {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+        let mut loader = data_loader();
+        let instrument_id = InstrumentId::from("ESM4.GLBX");
+
+        let paths = loader
+            .write_to_catalog(
+                &test_data_path().join(filename),
+                &catalog,
+                Some(instrument_id),
+                None,
+                false,
+            )
+            .unwrap();
+
+        assert!(!paths.is_empty());
+        for path in paths {
+            assert!(path.exists());
+            assert!(path.starts_with(temp_dir.path().join("data").join(type_name)));
+        }
+    }
+
+    #[rstest]
+    fn test_write_to_catalog_with_unsupported_schema() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+        let mut loader = data_loader();
+
+        let result = loader.write_to_catalog(
+            &test_data_path().join("test_data.statistics.dbn.zst"),
+            &catalog,
+            None,
+            None,
+            false,
+        );
+
+        assert!(result.is_err());
+    }
 }
@@ -28,6 +28,7 @@ use databento::{
 };
 use indexmap::IndexMap;
 use nautilus_core::{
+    credentials::Secret,
     python::to_pyvalue_err,
     time::{get_atomic_clock_realtime, AtomicTime},
 };
@@ -63,8 +64,7 @@ use crate::{
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.databento")
 )]
 pub struct DatabentoHistoricalClient {
-    #[pyo3(get)]
-    pub key: String,
+    key: Secret,
     clock: &'static AtomicTime,
     inner: Arc<Mutex<databento::HistoricalClient>>,
     publisher_venue_map: Arc<IndexMap<PublisherId, Venue>>,
@@ -75,8 +75,9 @@ pub struct DatabentoHistoricalClient {
 impl DatabentoHistoricalClient {
     #[new]
     fn py_new(key: String, publishers_filepath: PathBuf) -> PyResult<Self> {
+        let key = Secret::new(key).map_err(to_pyvalue_err)?;
         let client = databento::HistoricalClient::builder()
-            .key(key.clone())
+            .key(key.expose())
             .map_err(to_pyvalue_err)?
             .build()
             .map_err(to_pyvalue_err)?;
@@ -99,6 +100,13 @@ impl DatabentoHistoricalClient {
         })
     }
 
+    /// Returns the API key redacted, so it cannot leak through logs or reprs.
+    #[getter]
+    #[pyo3(name = "key")]
+    fn py_key(&self) -> String {
+        self.key.redacted()
+    }
+
     #[pyo3(name = "get_dataset_range")]
     fn py_get_dataset_range<'py>(
         &self,
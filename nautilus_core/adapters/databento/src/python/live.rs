@@ -23,7 +23,10 @@ use std::{
 
 use databento::{dbn, live::Subscription};
 use indexmap::IndexMap;
-use nautilus_core::python::{to_pyruntime_err, to_pyvalue_err};
+use nautilus_core::{
+    credentials::Secret,
+    python::{to_pyruntime_err, to_pyvalue_err},
+};
 use nautilus_model::{
     identifiers::{InstrumentId, Symbol, Venue},
     python::{data::data_to_pycapsule, instruments::instrument_any_to_pyobject},
@@ -32,7 +35,7 @@ use pyo3::prelude::*;
 use time::OffsetDateTime;
 
 use crate::{
-    live::{DatabentoFeedHandler, LiveCommand, LiveMessage},
+    live::{DatabentoFeedHandler, LiveCommand, LiveMessage, SubscriptionRegistry},
     symbology::{check_consistent_symbology, infer_symbology_type, instrument_id_to_symbol_string},
     types::DatabentoPublisher,
 };
@@ -42,8 +45,7 @@ use crate::{
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.databento")
 )]
 pub struct DatabentoLiveClient {
-    #[pyo3(get)]
-    pub key: String,
+    key: Secret,
     #[pyo3(get)]
     pub dataset: String,
     is_running: bool,
@@ -53,6 +55,7 @@ pub struct DatabentoLiveClient {
     buffer_size: usize,
     publisher_venue_map: IndexMap<u16, Venue>,
     symbol_venue_map: Arc<RwLock<HashMap<Symbol, Venue>>>,
+    subscriptions: SubscriptionRegistry,
 }
 
 impl DatabentoLiveClient {
@@ -128,6 +131,7 @@ fn call_python(py: Python, callback: &PyObject, py_obj: PyObject) -> PyResult<()
 impl DatabentoLiveClient {
     #[new]
     pub fn py_new(key: String, dataset: String, publishers_filepath: PathBuf) -> PyResult<Self> {
+        let key = Secret::new(key).map_err(to_pyvalue_err)?;
         let publishers_json = fs::read_to_string(publishers_filepath)?;
         let publishers_vec: Vec<DatabentoPublisher> =
             serde_json::from_str(&publishers_json).map_err(to_pyvalue_err)?;
@@ -151,9 +155,17 @@ impl DatabentoLiveClient {
             is_closed: false,
             publisher_venue_map,
             symbol_venue_map: Arc::new(RwLock::new(HashMap::new())),
+            subscriptions: SubscriptionRegistry::new(),
         })
     }
 
+    /// Returns the API key redacted, so it cannot leak through logs or reprs.
+    #[getter]
+    #[pyo3(name = "key")]
+    fn py_key(&self) -> String {
+        self.key.redacted()
+    }
+
     #[pyo3(name = "is_running")]
     const fn py_is_running(&self) -> bool {
         self.is_running
@@ -180,12 +192,29 @@ impl DatabentoLiveClient {
                 instrument_id_to_symbol_string(*instrument_id, &mut symbol_venue_map)
             })
             .collect();
+        drop(symbol_venue_map);
+
+        let dbn_schema = dbn::Schema::from_str(&schema).map_err(to_pyvalue_err)?;
         let stype_in = infer_symbology_type(symbols.first().unwrap());
+        check_consistent_symbology(&symbols.iter().map(String::as_str).collect::<Vec<&str>>())
+            .map_err(to_pyvalue_err)?;
+
+        // Replays and snapshots are always requested, otherwise only new symbols are sent
+        let added = self.subscriptions.register(&schema, &symbols);
+        let symbols = if start.is_some() || snapshot.unwrap_or(false) {
+            symbols
+        } else {
+            added
+        };
+        if symbols.is_empty() {
+            tracing::debug!("Already subscribed to {schema} for {instrument_ids:?}");
+            return Ok(());
+        }
+
         let symbols: Vec<&str> = symbols.iter().map(String::as_str).collect();
-        check_consistent_symbology(symbols.as_slice()).map_err(to_pyvalue_err)?;
         let mut sub = Subscription::builder()
             .symbols(symbols)
-            .schema(dbn::Schema::from_str(&schema).map_err(to_pyvalue_err)?)
+            .schema(dbn_schema)
             .stype_in(stype_in)
             .build();
 
@@ -199,6 +228,11 @@ impl DatabentoLiveClient {
         self.send_command(LiveCommand::Subscribe(sub))
     }
 
+    #[pyo3(name = "subscriptions")]
+    fn py_subscriptions(&self, schema: &str) -> Vec<String> {
+        self.subscriptions.symbols(schema)
+    }
+
     #[pyo3(name = "start")]
     fn py_start<'py>(
         &mut self,
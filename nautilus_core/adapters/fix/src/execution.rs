@@ -0,0 +1,997 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Mapping between FIX application messages and Nautilus execution commands and order events.
+
+use std::{collections::HashMap, str::FromStr};
+
+use nautilus_common::messages::execution::{CancelOrder, ModifyOrder, SubmitOrder};
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSide, OrderType, TimeInForce},
+    events::{
+        OrderAccepted, OrderCancelRejected, OrderCanceled, OrderEventAny, OrderExpired,
+        OrderFilled, OrderModifyRejected, OrderPendingCancel, OrderPendingUpdate, OrderRejected,
+        OrderTriggered, OrderUpdated,
+    },
+    identifiers::{
+        AccountId, ClientOrderId, InstrumentId, StrategyId, TradeId, TraderId, VenueOrderId,
+    },
+    types::{Currency, Money, Price, Quantity},
+};
+use nautilus_network::fix::{
+    message::{format_utc_timestamp, parse_utc_timestamp, FixMessage},
+    tags::{
+        msg_type, ACCOUNT, CL_ORD_ID, COMMISSION, COMM_TYPE, CURRENCY, CXL_REJ_REASON,
+        CXL_REJ_RESPONSE_TO, EXEC_ID, EXEC_INST, EXEC_TYPE, EXPIRE_TIME, HANDL_INST,
+        LAST_LIQUIDITY_IND, LAST_PX, LAST_QTY, ORDER_ID, ORDER_QTY, ORD_REJ_REASON, ORD_STATUS,
+        ORD_TYPE, ORIG_CL_ORD_ID, PRICE, SIDE, STOP_PX, SYMBOL, TEXT, TIME_IN_FORCE, TRANSACT_TIME,
+    },
+};
+use ustr::Ustr;
+
+/// The state of an order submitted through the mapper, used to build subsequent cancel and
+/// replace requests and to resolve the order of inbound reports.
+#[derive(Clone, Debug)]
+struct FixOrderState {
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    order_side: OrderSide,
+    order_type: OrderType,
+    time_in_force: TimeInForce,
+    quantity: Quantity,
+    price: Option<Price>,
+    trigger_price: Option<Price>,
+    /// The `ClOrdID` (11) of the latest accepted version of the order.
+    cl_ord_id: String,
+}
+
+impl FixOrderState {
+    fn price_precision(&self) -> Option<u8> {
+        self.price
+            .or(self.trigger_price)
+            .map(|price| price.precision)
+    }
+}
+
+/// Provides mapping of Nautilus execution commands to FIX 4.4 order messages, and of the
+/// resulting `ExecutionReport` and `OrderCancelReject` messages to Nautilus order events.
+///
+/// FIX requires a new `ClOrdID` for every cancel and replace request, so the mapper tracks
+/// each `ClOrdID` it sends and resolves reports back to the originating [`ClientOrderId`].
+#[derive(Debug)]
+pub struct FixExecutionMapper {
+    account_id: AccountId,
+    account: Option<String>,
+    currency: Currency,
+    orders: HashMap<ClientOrderId, FixOrderState>,
+    cl_ord_ids: HashMap<String, ClientOrderId>,
+}
+
+impl FixExecutionMapper {
+    /// Creates a new [`FixExecutionMapper`] instance.
+    ///
+    /// The `account` is sent as `Account` (1) on order messages when provided, and the
+    /// `currency` is used for fills which do not specify a `Currency` (15).
+    #[must_use]
+    pub fn new(account_id: AccountId, account: Option<String>, currency: Currency) -> Self {
+        Self {
+            account_id,
+            account,
+            currency,
+            orders: HashMap::new(),
+            cl_ord_ids: HashMap::new(),
+        }
+    }
+
+    /// Maps the given `command` to a `NewOrderSingle` (D) message.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the order type is not supported by FIX 4.4.
+    /// - If the order is missing a price or trigger price required by its type.
+    pub fn submit_order(&mut self, command: &SubmitOrder) -> anyhow::Result<FixMessage> {
+        let order = &command.order;
+        let state = FixOrderState {
+            trader_id: command.trader_id,
+            strategy_id: command.strategy_id,
+            instrument_id: command.instrument_id,
+            order_side: order.order_side(),
+            order_type: order.order_type(),
+            time_in_force: order.time_in_force(),
+            quantity: order.quantity(),
+            price: order.price(),
+            trigger_price: order.trigger_price(),
+            cl_ord_id: command.client_order_id.to_string(),
+        };
+
+        let mut message = FixMessage::new(msg_type::NEW_ORDER_SINGLE)
+            .with(CL_ORD_ID, &state.cl_ord_id)
+            .with(HANDL_INST, 1);
+        self.push_order_fields(&mut message, &state, command.ts_init)?;
+
+        if let Some(expire_time) = order.expire_time() {
+            if state.time_in_force == TimeInForce::Gtd {
+                message.push(EXPIRE_TIME, format_utc_timestamp(expire_time));
+            }
+        }
+
+        let mut exec_inst = Vec::new();
+        if order.is_post_only() {
+            exec_inst.push("6"); // Participate don't initiate
+        }
+        if order.is_reduce_only() {
+            exec_inst.push("E"); // Do not increase
+        }
+        if !exec_inst.is_empty() {
+            message.push(EXEC_INST, exec_inst.join(" "));
+        }
+
+        self.cl_ord_ids
+            .insert(state.cl_ord_id.clone(), command.client_order_id);
+        self.orders.insert(command.client_order_id, state);
+        Ok(message)
+    }
+
+    /// Maps the given `command` to an `OrderCancelRequest` (F) message.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order was not submitted through this mapper.
+    pub fn cancel_order(&mut self, command: &CancelOrder) -> anyhow::Result<FixMessage> {
+        let state = self.order_state(&command.client_order_id)?;
+        let cl_ord_id = command.command_id.to_string();
+
+        let mut message = FixMessage::new(msg_type::ORDER_CANCEL_REQUEST)
+            .with(ORIG_CL_ORD_ID, &state.cl_ord_id)
+            .with(ORDER_ID, command.venue_order_id)
+            .with(CL_ORD_ID, &cl_ord_id);
+        if let Some(account) = &self.account {
+            message.push(ACCOUNT, account);
+        }
+        message.push(SYMBOL, state.instrument_id.symbol);
+        message.push(SIDE, fix_side(state.order_side)?);
+        message.push(TRANSACT_TIME, format_utc_timestamp(command.ts_init));
+        message.push(ORDER_QTY, state.quantity);
+
+        self.cl_ord_ids.insert(cl_ord_id, command.client_order_id);
+        Ok(message)
+    }
+
+    /// Maps the given `command` to an `OrderCancelReplaceRequest` (G) message.
+    ///
+    /// Fields not being modified are carried over from the current state of the order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order was not submitted through this mapper.
+    pub fn modify_order(&mut self, command: &ModifyOrder) -> anyhow::Result<FixMessage> {
+        let mut state = self.order_state(&command.client_order_id)?.clone();
+        let orig_cl_ord_id = state.cl_ord_id.clone();
+        let cl_ord_id = command.command_id.to_string();
+
+        state.quantity = command.quantity.unwrap_or(state.quantity);
+        state.price = command.price.or(state.price);
+        state.trigger_price = command.trigger_price.or(state.trigger_price);
+
+        let mut message = FixMessage::new(msg_type::ORDER_CANCEL_REPLACE_REQUEST)
+            .with(ORIG_CL_ORD_ID, orig_cl_ord_id)
+            .with(ORDER_ID, command.venue_order_id)
+            .with(CL_ORD_ID, &cl_ord_id)
+            .with(HANDL_INST, 1);
+        self.push_order_fields(&mut message, &state, command.ts_init)?;
+
+        self.cl_ord_ids.insert(cl_ord_id, command.client_order_id);
+        Ok(message)
+    }
+
+    /// Parses the given inbound application `message` into an order event.
+    ///
+    /// Returns `None` for messages which do not map to an order event, such as order status
+    /// reports, and for reports of orders which were not submitted through this mapper.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a required field is missing or invalid.
+    pub fn parse_message(
+        &mut self,
+        message: &FixMessage,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Option<OrderEventAny>> {
+        match message.msg_type() {
+            msg_type::EXECUTION_REPORT => self.parse_execution_report(message, ts_init),
+            msg_type::ORDER_CANCEL_REJECT => self.parse_cancel_reject(message, ts_init),
+            _ => Ok(None),
+        }
+    }
+
+    fn order_state(&self, client_order_id: &ClientOrderId) -> anyhow::Result<&FixOrderState> {
+        self.orders
+            .get(client_order_id)
+            .ok_or_else(|| anyhow::anyhow!("Order {client_order_id} not known to FIX mapper"))
+    }
+
+    fn push_order_fields(
+        &self,
+        message: &mut FixMessage,
+        state: &FixOrderState,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<()> {
+        if let Some(account) = &self.account {
+            message.push(ACCOUNT, account);
+        }
+        message.push(SYMBOL, state.instrument_id.symbol);
+        message.push(SIDE, fix_side(state.order_side)?);
+        message.push(TRANSACT_TIME, format_utc_timestamp(ts_init));
+        message.push(ORDER_QTY, state.quantity);
+        message.push(ORD_TYPE, fix_ord_type(state.order_type)?);
+
+        if matches!(
+            state.order_type,
+            OrderType::Limit | OrderType::StopLimit | OrderType::LimitIfTouched
+        ) {
+            let price = state
+                .price
+                .ok_or_else(|| anyhow::anyhow!("{} order requires a price", state.order_type))?;
+            message.push(PRICE, price);
+        }
+        if matches!(
+            state.order_type,
+            OrderType::StopMarket | OrderType::StopLimit | OrderType::MarketIfTouched
+        ) {
+            let trigger_price = state.trigger_price.ok_or_else(|| {
+                anyhow::anyhow!("{} order requires a trigger price", state.order_type)
+            })?;
+            message.push(STOP_PX, trigger_price);
+        }
+
+        message.push(TIME_IN_FORCE, fix_time_in_force(state.time_in_force));
+        Ok(())
+    }
+
+    fn resolve(&self, message: &FixMessage) -> Option<(ClientOrderId, FixOrderState)> {
+        let client_order_id = [CL_ORD_ID, ORIG_CL_ORD_ID]
+            .iter()
+            .filter_map(|tag| message.get(*tag))
+            .find_map(|cl_ord_id| self.cl_ord_ids.get(cl_ord_id))?;
+        let state = self.orders.get(client_order_id)?;
+        Some((*client_order_id, state.clone()))
+    }
+
+    fn remove_order(&mut self, client_order_id: &ClientOrderId) {
+        self.orders.remove(client_order_id);
+        self.cl_ord_ids.retain(|_, id| id != client_order_id);
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn parse_execution_report(
+        &mut self,
+        message: &FixMessage,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Option<OrderEventAny>> {
+        let exec_type = message.require(EXEC_TYPE)?;
+        let Some((client_order_id, state)) = self.resolve(message) else {
+            tracing::debug!("Ignoring execution report for unknown order: {message}");
+            return Ok(None);
+        };
+
+        let venue_order_id = parse_venue_order_id(message);
+        let ts_event = match message.get(TRANSACT_TIME) {
+            Some(value) => parse_utc_timestamp(TRANSACT_TIME, value)?,
+            None => ts_init,
+        };
+        let event_id = UUID4::new();
+
+        let event = match exec_type {
+            // New
+            "0" => OrderEventAny::Accepted(OrderAccepted::new(
+                state.trader_id,
+                state.strategy_id,
+                state.instrument_id,
+                client_order_id,
+                venue_order_id.ok_or_else(|| anyhow::anyhow!("Missing OrderID (37)"))?,
+                self.account_id,
+                event_id,
+                ts_event,
+                ts_init,
+                false,
+            )),
+            // Canceled
+            "4" => {
+                self.remove_order(&client_order_id);
+                OrderEventAny::Canceled(OrderCanceled::new(
+                    state.trader_id,
+                    state.strategy_id,
+                    state.instrument_id,
+                    client_order_id,
+                    event_id,
+                    ts_event,
+                    ts_init,
+                    false,
+                    venue_order_id,
+                    Some(self.account_id),
+                ))
+            }
+            // Replaced
+            "5" => {
+                let quantity = parse_quantity(message, ORDER_QTY, state.quantity.precision)?
+                    .unwrap_or(state.quantity);
+                let price_precision = state.price_precision();
+                let price = parse_price(message, PRICE, price_precision)?.or(state.price);
+                let trigger_price =
+                    parse_price(message, STOP_PX, price_precision)?.or(state.trigger_price);
+
+                if let Some(order) = self.orders.get_mut(&client_order_id) {
+                    order.quantity = quantity;
+                    order.price = price;
+                    order.trigger_price = trigger_price;
+                    if let Some(cl_ord_id) = message.get(CL_ORD_ID) {
+                        order.cl_ord_id = cl_ord_id.to_string();
+                    }
+                }
+
+                OrderEventAny::Updated(OrderUpdated::new(
+                    state.trader_id,
+                    state.strategy_id,
+                    state.instrument_id,
+                    client_order_id,
+                    quantity,
+                    event_id,
+                    ts_event,
+                    ts_init,
+                    false,
+                    venue_order_id,
+                    Some(self.account_id),
+                    price,
+                    trigger_price,
+                ))
+            }
+            // Pending Cancel
+            "6" => OrderEventAny::PendingCancel(OrderPendingCancel::new(
+                state.trader_id,
+                state.strategy_id,
+                state.instrument_id,
+                client_order_id,
+                self.account_id,
+                event_id,
+                ts_event,
+                ts_init,
+                false,
+                venue_order_id,
+            )),
+            // Rejected
+            "8" => {
+                self.remove_order(&client_order_id);
+                OrderEventAny::Rejected(OrderRejected::new(
+                    state.trader_id,
+                    state.strategy_id,
+                    state.instrument_id,
+                    client_order_id,
+                    self.account_id,
+                    parse_reason(message, ORD_REJ_REASON),
+                    event_id,
+                    ts_event,
+                    ts_init,
+                    false,
+                ))
+            }
+            // Expired
+            "C" => {
+                self.remove_order(&client_order_id);
+                OrderEventAny::Expired(OrderExpired::new(
+                    state.trader_id,
+                    state.strategy_id,
+                    state.instrument_id,
+                    client_order_id,
+                    event_id,
+                    ts_event,
+                    ts_init,
+                    false,
+                    venue_order_id,
+                    Some(self.account_id),
+                ))
+            }
+            // Pending Replace
+            "E" => OrderEventAny::PendingUpdate(OrderPendingUpdate::new(
+                state.trader_id,
+                state.strategy_id,
+                state.instrument_id,
+                client_order_id,
+                self.account_id,
+                event_id,
+                ts_event,
+                ts_init,
+                false,
+                venue_order_id,
+            )),
+            // Trade
+            "F" => {
+                let fill = self.parse_fill(
+                    message,
+                    &state,
+                    client_order_id,
+                    venue_order_id,
+                    event_id,
+                    ts_event,
+                    ts_init,
+                )?;
+                // Order status 2 = Filled
+                if message.get(ORD_STATUS) == Some("2") {
+                    self.remove_order(&client_order_id);
+                    OrderEventAny::Filled(fill)
+                } else {
+                    OrderEventAny::PartiallyFilled(fill)
+                }
+            }
+            // Triggered or Activated by System
+            "L" => OrderEventAny::Triggered(OrderTriggered::new(
+                state.trader_id,
+                state.strategy_id,
+                state.instrument_id,
+                client_order_id,
+                event_id,
+                ts_event,
+                ts_init,
+                false,
+                venue_order_id,
+                Some(self.account_id),
+            )),
+            // Pending New, Restated, Order Status and others carry no order event
+            _ => return Ok(None),
+        };
+
+        Ok(Some(event))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn parse_fill(
+        &self,
+        message: &FixMessage,
+        state: &FixOrderState,
+        client_order_id: ClientOrderId,
+        venue_order_id: Option<VenueOrderId>,
+        event_id: UUID4,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<OrderFilled> {
+        let venue_order_id =
+            venue_order_id.ok_or_else(|| anyhow::anyhow!("Missing OrderID (37)"))?;
+        let trade_id = TradeId::new_checked(message.require(EXEC_ID)?)?;
+        let last_qty = parse_quantity(message, LAST_QTY, state.quantity.precision)?
+            .ok_or_else(|| anyhow::anyhow!("Missing LastQty (32)"))?;
+        let last_px = parse_price(message, LAST_PX, state.price_precision())?
+            .ok_or_else(|| anyhow::anyhow!("Missing LastPx (31)"))?;
+        let currency = match message.get(CURRENCY) {
+            Some(code) => Currency::from_str(code)?,
+            None => self.currency,
+        };
+        let liquidity_side = match message.get(LAST_LIQUIDITY_IND) {
+            Some("1") => LiquiditySide::Maker,
+            Some("2") => LiquiditySide::Taker,
+            _ => LiquiditySide::NoLiquiditySide,
+        };
+
+        // Only absolute commissions (or those without a type) can be represented as money
+        let commission = match (
+            message.get_parsed::<f64>(COMMISSION)?,
+            message.get(COMM_TYPE),
+        ) {
+            (Some(amount), None | Some("3")) => Some(Money::new(amount, currency)),
+            (Some(_), Some(comm_type)) => {
+                tracing::warn!("Ignoring commission with unsupported CommType {comm_type}");
+                None
+            }
+            (None, _) => None,
+        };
+
+        Ok(OrderFilled::new(
+            state.trader_id,
+            state.strategy_id,
+            state.instrument_id,
+            client_order_id,
+            venue_order_id,
+            self.account_id,
+            trade_id,
+            state.order_side,
+            state.order_type,
+            last_qty,
+            last_px,
+            currency,
+            liquidity_side,
+            event_id,
+            ts_event,
+            ts_init,
+            false,
+            None,
+            commission,
+        ))
+    }
+
+    fn parse_cancel_reject(
+        &mut self,
+        message: &FixMessage,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Option<OrderEventAny>> {
+        let Some((client_order_id, state)) = self.resolve(message) else {
+            tracing::debug!("Ignoring cancel reject for unknown order: {message}");
+            return Ok(None);
+        };
+
+        let reason = parse_reason(message, CXL_REJ_REASON);
+        let venue_order_id = parse_venue_order_id(message);
+        let event_id = UUID4::new();
+
+        let event = match message.require(CXL_REJ_RESPONSE_TO)? {
+            // Order cancel request
+            "1" => OrderEventAny::CancelRejected(OrderCancelRejected::new(
+                state.trader_id,
+                state.strategy_id,
+                state.instrument_id,
+                client_order_id,
+                reason,
+                event_id,
+                ts_init,
+                ts_init,
+                false,
+                venue_order_id,
+                Some(self.account_id),
+            )),
+            // Order cancel/replace request
+            "2" => OrderEventAny::ModifyRejected(OrderModifyRejected::new(
+                state.trader_id,
+                state.strategy_id,
+                state.instrument_id,
+                client_order_id,
+                reason,
+                event_id,
+                ts_init,
+                ts_init,
+                false,
+                venue_order_id,
+                Some(self.account_id),
+            )),
+            value => anyhow::bail!("Invalid CxlRejResponseTo (434) '{value}'"),
+        };
+
+        Ok(Some(event))
+    }
+}
+
+fn fix_side(side: OrderSide) -> anyhow::Result<&'static str> {
+    match side {
+        OrderSide::Buy => Ok("1"),
+        OrderSide::Sell => Ok("2"),
+        OrderSide::NoOrderSide => anyhow::bail!("Order side must be specified"),
+    }
+}
+
+fn fix_ord_type(order_type: OrderType) -> anyhow::Result<&'static str> {
+    match order_type {
+        OrderType::Market => Ok("1"),
+        OrderType::Limit => Ok("2"),
+        OrderType::StopMarket => Ok("3"),
+        OrderType::StopLimit => Ok("4"),
+        OrderType::MarketIfTouched => Ok("J"),
+        OrderType::MarketToLimit => Ok("K"),
+        _ => anyhow::bail!("Order type {order_type} not supported by FIX 4.4"),
+    }
+}
+
+const fn fix_time_in_force(time_in_force: TimeInForce) -> &'static str {
+    match time_in_force {
+        TimeInForce::Day => "0",
+        TimeInForce::Gtc => "1",
+        TimeInForce::AtTheOpen => "2",
+        TimeInForce::Ioc => "3",
+        TimeInForce::Fok => "4",
+        TimeInForce::Gtd => "6",
+        TimeInForce::AtTheClose => "7",
+    }
+}
+
+fn parse_venue_order_id(message: &FixMessage) -> Option<VenueOrderId> {
+    // Venues send "NONE" when the order was never assigned an identifier
+    message
+        .get(ORDER_ID)
+        .filter(|value| !value.is_empty() && *value != "NONE")
+        .map(VenueOrderId::new)
+}
+
+fn parse_reason(message: &FixMessage, reason_tag: u32) -> Ustr {
+    match (message.get(TEXT), message.get(reason_tag)) {
+        (Some(text), _) => Ustr::from(text),
+        (None, Some(code)) => Ustr::from(format!("Reason code {code}").as_str()),
+        (None, None) => Ustr::from("Unknown"),
+    }
+}
+
+fn parse_quantity(
+    message: &FixMessage,
+    tag: u32,
+    precision: u8,
+) -> anyhow::Result<Option<Quantity>> {
+    message
+        .get_parsed::<f64>(tag)?
+        .map(|value| Quantity::new_checked(value, precision))
+        .transpose()
+}
+
+fn parse_price(
+    message: &FixMessage,
+    tag: u32,
+    precision: Option<u8>,
+) -> anyhow::Result<Option<Price>> {
+    let Some(value) = message.get(tag) else {
+        return Ok(None);
+    };
+    match precision {
+        Some(precision) => {
+            let value: f64 = message.require_parsed(tag)?;
+            Price::new_checked(value, precision).map(Some)
+        }
+        // Infer the precision from the value when the order carries no price
+        None => Price::from_str(value).map(Some).map_err(anyhow::Error::msg),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        identifiers::ClientId,
+        orders::{OrderAny, OrderTestBuilder},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn mapper() -> FixExecutionMapper {
+        FixExecutionMapper::new(
+            AccountId::from("FIX-001"),
+            Some("ACC1".to_string()),
+            Currency::USD(),
+        )
+    }
+
+    fn limit_order() -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AAPL.XNAS"))
+            .side(OrderSide::Buy)
+            .price(Price::from("150.25"))
+            .quantity(Quantity::from(100))
+            .build()
+    }
+
+    fn submit(order: OrderAny) -> SubmitOrder {
+        SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("FIX"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("1"),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::from(1_704_067_200_000_000_000),
+        )
+        .unwrap()
+    }
+
+    fn execution_report(cl_ord_id: &str, exec_type: &str, ord_status: &str) -> FixMessage {
+        FixMessage::new(msg_type::EXECUTION_REPORT)
+            .with(ORDER_ID, "V-1")
+            .with(CL_ORD_ID, cl_ord_id)
+            .with(EXEC_ID, "E-1")
+            .with(EXEC_TYPE, exec_type)
+            .with(ORD_STATUS, ord_status)
+            .with(TRANSACT_TIME, "20240101-00:00:01.000")
+    }
+
+    #[rstest]
+    fn test_submit_order_limit() {
+        let mut mapper = mapper();
+        let command = submit(limit_order());
+
+        let message = mapper.submit_order(&command).unwrap();
+
+        assert_eq!(message.msg_type(), msg_type::NEW_ORDER_SINGLE);
+        assert_eq!(
+            message.get(CL_ORD_ID),
+            Some(command.client_order_id.as_str())
+        );
+        assert_eq!(message.get(ACCOUNT), Some("ACC1"));
+        assert_eq!(message.get(SYMBOL), Some("AAPL"));
+        assert_eq!(message.get(SIDE), Some("1"));
+        assert_eq!(message.get(ORDER_QTY), Some("100"));
+        assert_eq!(message.get(ORD_TYPE), Some("2"));
+        assert_eq!(message.get(PRICE), Some("150.25"));
+        assert_eq!(message.get(TIME_IN_FORCE), Some("1"));
+        assert_eq!(message.get(TRANSACT_TIME), Some("20240101-00:00:00.000"));
+        assert_eq!(message.get(STOP_PX), None);
+    }
+
+    #[rstest]
+    fn test_submit_order_stop_limit_gtd_reduce_only() {
+        let mut mapper = mapper();
+        let order = OrderTestBuilder::new(OrderType::StopLimit)
+            .instrument_id(InstrumentId::from("AAPL.XNAS"))
+            .side(OrderSide::Sell)
+            .price(Price::from("149.00"))
+            .trigger_price(Price::from("149.50"))
+            .quantity(Quantity::from(10))
+            .time_in_force(TimeInForce::Gtd)
+            .expire_time(UnixNanos::from(1_704_153_600_000_000_000))
+            .reduce_only(true)
+            .build();
+
+        let message = mapper.submit_order(&submit(order)).unwrap();
+
+        assert_eq!(message.get(SIDE), Some("2"));
+        assert_eq!(message.get(ORD_TYPE), Some("4"));
+        assert_eq!(message.get(PRICE), Some("149.00"));
+        assert_eq!(message.get(STOP_PX), Some("149.50"));
+        assert_eq!(message.get(TIME_IN_FORCE), Some("6"));
+        assert_eq!(message.get(EXPIRE_TIME), Some("20240102-00:00:00.000"));
+        assert_eq!(message.get(EXEC_INST), Some("E"));
+    }
+
+    #[rstest]
+    fn test_submit_order_unsupported_type() {
+        let mut mapper = mapper();
+        let order = OrderTestBuilder::new(OrderType::TrailingStopMarket)
+            .instrument_id(InstrumentId::from("AAPL.XNAS"))
+            .trigger_price(Price::from("149.50"))
+            .trailing_offset(Price::from("1.00"))
+            .trailing_offset_type(nautilus_model::enums::TrailingOffsetType::Price)
+            .quantity(Quantity::from(10))
+            .build();
+
+        assert!(mapper.submit_order(&submit(order)).is_err());
+    }
+
+    #[rstest]
+    fn test_cancel_order() {
+        let mut mapper = mapper();
+        let submit = submit(limit_order());
+        mapper.submit_order(&submit).unwrap();
+        let command = CancelOrder::new(
+            submit.trader_id,
+            submit.client_id,
+            submit.strategy_id,
+            submit.instrument_id,
+            submit.client_order_id,
+            VenueOrderId::from("V-1"),
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        let message = mapper.cancel_order(&command).unwrap();
+
+        assert_eq!(message.msg_type(), msg_type::ORDER_CANCEL_REQUEST);
+        assert_eq!(
+            message.get(ORIG_CL_ORD_ID),
+            Some(submit.client_order_id.as_str())
+        );
+        assert_eq!(
+            message.get(CL_ORD_ID),
+            Some(command.command_id.to_string().as_str())
+        );
+        assert_eq!(message.get(ORDER_ID), Some("V-1"));
+        assert_eq!(message.get(ORDER_QTY), Some("100"));
+    }
+
+    #[rstest]
+    fn test_cancel_unknown_order() {
+        let mut mapper = mapper();
+        let command = CancelOrder::new(
+            TraderId::from("TRADER-001"),
+            ClientId::from("FIX"),
+            StrategyId::from("S-001"),
+            InstrumentId::from("AAPL.XNAS"),
+            ClientOrderId::from("O-UNKNOWN"),
+            VenueOrderId::from("V-1"),
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert!(mapper.cancel_order(&command).is_err());
+    }
+
+    #[rstest]
+    fn test_modify_then_replaced_updates_tracked_cl_ord_id() {
+        let mut mapper = mapper();
+        let submit = submit(limit_order());
+        mapper.submit_order(&submit).unwrap();
+        let modify = ModifyOrder::new(
+            submit.trader_id,
+            submit.client_id,
+            submit.strategy_id,
+            submit.instrument_id,
+            submit.client_order_id,
+            VenueOrderId::from("V-1"),
+            None,
+            Some(Price::from("151.00")),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        let new_cl_ord_id = modify.command_id.to_string();
+
+        let message = mapper.modify_order(&modify).unwrap();
+        let report = execution_report(&new_cl_ord_id, "5", "0")
+            .with(ORIG_CL_ORD_ID, submit.client_order_id)
+            .with(ORDER_QTY, "100")
+            .with(PRICE, "151.00");
+        let event = mapper.parse_message(&report, UnixNanos::default()).unwrap();
+
+        assert_eq!(message.msg_type(), msg_type::ORDER_CANCEL_REPLACE_REQUEST);
+        assert_eq!(message.get(PRICE), Some("151.00"));
+        assert_eq!(message.get(ORDER_QTY), Some("100"));
+        match event {
+            Some(OrderEventAny::Updated(updated)) => {
+                assert_eq!(updated.client_order_id, submit.client_order_id);
+                assert_eq!(updated.price, Some(Price::from("151.00")));
+                assert_eq!(updated.quantity, Quantity::from(100));
+            }
+            event => panic!("Unexpected event {event:?}"),
+        }
+        assert_eq!(
+            mapper.orders[&submit.client_order_id].cl_ord_id,
+            new_cl_ord_id
+        );
+    }
+
+    #[rstest]
+    fn test_parse_accepted() {
+        let mut mapper = mapper();
+        let submit = submit(limit_order());
+        mapper.submit_order(&submit).unwrap();
+        let report = execution_report(submit.client_order_id.as_str(), "0", "0");
+
+        let event = mapper.parse_message(&report, UnixNanos::default()).unwrap();
+
+        match event {
+            Some(OrderEventAny::Accepted(accepted)) => {
+                assert_eq!(accepted.client_order_id, submit.client_order_id);
+                assert_eq!(accepted.venue_order_id, VenueOrderId::from("V-1"));
+                assert_eq!(accepted.account_id, AccountId::from("FIX-001"));
+                assert_eq!(accepted.ts_event, 1_704_067_201_000_000_000);
+            }
+            event => panic!("Unexpected event {event:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_parse_partial_then_full_fill() {
+        let mut mapper = mapper();
+        let submit = submit(limit_order());
+        mapper.submit_order(&submit).unwrap();
+        let cl_ord_id = submit.client_order_id.as_str();
+        let partial = execution_report(cl_ord_id, "F", "1")
+            .with(LAST_QTY, "40")
+            .with(LAST_PX, "150.25")
+            .with(LAST_LIQUIDITY_IND, "1")
+            .with(COMMISSION, "0.40")
+            .with(COMM_TYPE, "3");
+        let full = execution_report(cl_ord_id, "F", "2")
+            .with(LAST_QTY, "60")
+            .with(LAST_PX, "150.2")
+            .with(LAST_LIQUIDITY_IND, "2");
+
+        let partial = mapper
+            .parse_message(&partial, UnixNanos::default())
+            .unwrap();
+        let full = mapper.parse_message(&full, UnixNanos::default()).unwrap();
+
+        match partial {
+            Some(OrderEventAny::PartiallyFilled(fill)) => {
+                assert_eq!(fill.last_qty, Quantity::from(40));
+                assert_eq!(fill.last_px, Price::from("150.25"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+                assert_eq!(fill.trade_id, TradeId::from("E-1"));
+                assert_eq!(fill.commission, Some(Money::from_str("0.40 USD").unwrap()));
+            }
+            event => panic!("Unexpected event {event:?}"),
+        }
+        match full {
+            Some(OrderEventAny::Filled(fill)) => {
+                assert_eq!(fill.last_px, Price::from("150.20"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+                assert_eq!(fill.commission, None);
+            }
+            event => panic!("Unexpected event {event:?}"),
+        }
+        assert!(mapper.orders.is_empty());
+        assert!(mapper.cl_ord_ids.is_empty());
+    }
+
+    #[rstest]
+    fn test_parse_rejected() {
+        let mut mapper = mapper();
+        let submit = submit(limit_order());
+        mapper.submit_order(&submit).unwrap();
+        let report = execution_report(submit.client_order_id.as_str(), "8", "8")
+            .with(TEXT, "Insufficient margin");
+
+        let event = mapper.parse_message(&report, UnixNanos::default()).unwrap();
+
+        match event {
+            Some(OrderEventAny::Rejected(rejected)) => {
+                assert_eq!(rejected.reason, Ustr::from("Insufficient margin"));
+            }
+            event => panic!("Unexpected event {event:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case("1", true)]
+    #[case("2", false)]
+    fn test_parse_cancel_reject(#[case] response_to: &str, #[case] is_cancel: bool) {
+        let mut mapper = mapper();
+        let submit = submit(limit_order());
+        mapper.submit_order(&submit).unwrap();
+        let reject = FixMessage::new(msg_type::ORDER_CANCEL_REJECT)
+            .with(ORDER_ID, "NONE")
+            .with(CL_ORD_ID, "C-1")
+            .with(ORIG_CL_ORD_ID, submit.client_order_id)
+            .with(ORD_STATUS, "8")
+            .with(CXL_REJ_RESPONSE_TO, response_to)
+            .with(CXL_REJ_REASON, "1");
+
+        let event = mapper.parse_message(&reject, UnixNanos::default()).unwrap();
+
+        match event {
+            Some(OrderEventAny::CancelRejected(rejected)) if is_cancel => {
+                assert_eq!(rejected.reason, Ustr::from("Reason code 1"));
+                assert_eq!(rejected.venue_order_id, None);
+            }
+            Some(OrderEventAny::ModifyRejected(rejected)) if !is_cancel => {
+                assert_eq!(rejected.client_order_id, submit.client_order_id);
+            }
+            event => panic!("Unexpected event {event:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_parse_unknown_order_ignored() {
+        let mut mapper = mapper();
+        let report = execution_report("O-UNKNOWN", "0", "0");
+
+        let event = mapper.parse_message(&report, UnixNanos::default()).unwrap();
+
+        assert!(event.is_none());
+    }
+
+    #[rstest]
+    fn test_parse_order_status_ignored() {
+        let mut mapper = mapper();
+        let submit = submit(limit_order());
+        mapper.submit_order(&submit).unwrap();
+        let report = execution_report(submit.client_order_id.as_str(), "I", "0");
+
+        let event = mapper.parse_message(&report, UnixNanos::default()).unwrap();
+
+        assert!(event.is_none());
+    }
+}
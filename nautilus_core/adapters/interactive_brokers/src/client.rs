@@ -0,0 +1,286 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a client for the TWS API socket protocol, connecting to TWS or IB Gateway.
+
+use std::sync::{
+    atomic::{AtomicI64, Ordering},
+    Arc,
+};
+
+use nautilus_network::socket::MessageFraming;
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWriteExt},
+    net::{tcp::OwnedWriteHalf, TcpStream},
+    sync::{mpsc, Mutex},
+    task::JoinHandle,
+};
+
+use crate::{
+    common::consts::IB_SERVER_VERSION,
+    protocol::{
+        codec::{encode_handshake, ib_framing, IbFieldDecoder},
+        incoming::{decode_message, IbIncomingMessage},
+        outgoing::encode_start_api,
+    },
+};
+
+/// Provides a client for the TWS API socket protocol.
+///
+/// Connecting performs the version handshake and starts the API session for the client ID.
+/// Incoming messages are decoded by a read task and forwarded to a channel read with
+/// [`IbClient::next_message`].
+///
+/// TWS allows one connection per client ID, so separate clients (e.g. for market data and
+/// execution) must use different client IDs.
+pub struct IbClient {
+    client_id: i64,
+    server_version: i64,
+    connection_time: String,
+    framing: MessageFraming,
+    writer: Arc<Mutex<OwnedWriteHalf>>,
+    rx: mpsc::UnboundedReceiver<IbIncomingMessage>,
+    read_task: JoinHandle<()>,
+    next_request_id: AtomicI64,
+}
+
+impl IbClient {
+    /// Connects to TWS or IB Gateway at the given `host` and `port` as `client_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the connection cannot be established.
+    /// - If the handshake fails, or the server does not support [`IB_SERVER_VERSION`].
+    pub async fn connect(host: &str, port: u16, client_id: i64) -> anyhow::Result<Self> {
+        let mut stream = TcpStream::connect((host, port)).await?;
+        stream.set_nodelay(true)?;
+        stream
+            .write_all(&encode_handshake(
+                IB_SERVER_VERSION,
+                IB_SERVER_VERSION,
+                None,
+            )?)
+            .await?;
+
+        let framing = ib_framing();
+        let mut buf = Vec::new();
+        let reply = read_message(&mut stream, &framing, &mut buf).await?;
+        let mut decoder = IbFieldDecoder::new(&reply)?;
+        let server_version = decoder.next_int()?;
+        let connection_time = decoder.next_string()?;
+        if server_version != IB_SERVER_VERSION {
+            anyhow::bail!(
+                "Server version {server_version} not supported, expected {IB_SERVER_VERSION}"
+            );
+        }
+
+        let (mut reader, mut writer) = stream.into_split();
+        writer
+            .write_all(&framing.encode(&encode_start_api(client_id, ""))?)
+            .await?;
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let read_framing = framing.clone();
+        let read_task = tokio::task::spawn(async move {
+            loop {
+                let payload = match read_message(&mut reader, &read_framing, &mut buf).await {
+                    Ok(payload) => payload,
+                    Err(e) => {
+                        tracing::error!("Error reading from TWS API socket: {e}");
+                        break;
+                    }
+                };
+                match decode_message(&payload) {
+                    Ok(msg) => {
+                        if tx.send(msg).is_err() {
+                            break; // Client dropped
+                        }
+                    }
+                    Err(e) => tracing::error!("Error decoding TWS API message: {e}"),
+                }
+            }
+            tracing::debug!("TWS API read task completed");
+        });
+
+        tracing::info!(
+            "Connected to {host}:{port} as client {client_id} (server version {server_version})"
+        );
+
+        Ok(Self {
+            client_id,
+            server_version,
+            connection_time,
+            framing,
+            writer: Arc::new(Mutex::new(writer)),
+            rx,
+            read_task,
+            next_request_id: AtomicI64::new(1),
+        })
+    }
+
+    /// Returns the client ID of the API session.
+    #[must_use]
+    pub const fn client_id(&self) -> i64 {
+        self.client_id
+    }
+
+    /// Returns the negotiated server version.
+    #[must_use]
+    pub const fn server_version(&self) -> i64 {
+        self.server_version
+    }
+
+    /// Returns the connection time reported by the server.
+    #[must_use]
+    pub fn connection_time(&self) -> &str {
+        &self.connection_time
+    }
+
+    /// Returns whether the read task is still running.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        !self.read_task.is_finished()
+    }
+
+    /// Returns a new request ID, unique for the connection.
+    pub fn next_request_id(&self) -> i64 {
+        self.next_request_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Sends the given encoded message `payload`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the message cannot be framed or written.
+    pub async fn send(&self, payload: &[u8]) -> anyhow::Result<()> {
+        let frame = self.framing.encode(payload)?;
+        self.writer.lock().await.write_all(&frame).await?;
+        Ok(())
+    }
+
+    /// Returns the next decoded message, or `None` once the connection has closed.
+    pub async fn next_message(&mut self) -> Option<IbIncomingMessage> {
+        self.rx.recv().await
+    }
+
+    /// Closes the connection.
+    pub async fn close(&self) {
+        if let Err(e) = self.writer.lock().await.shutdown().await {
+            tracing::error!("Error closing TWS API socket: {e}");
+        }
+        self.read_task.abort();
+    }
+}
+
+/// Reads from `reader` into `buf` until a complete message is available, returning it.
+async fn read_message<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    framing: &MessageFraming,
+    buf: &mut Vec<u8>,
+) -> anyhow::Result<Vec<u8>> {
+    loop {
+        if let Some(payload) = framing.decode(buf)? {
+            return Ok(payload);
+        }
+        if reader.read_buf(buf).await? == 0 {
+            anyhow::bail!("Connection closed by server");
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::{common::consts::IB_API_PREFIX, tests::payload};
+
+    #[tokio::test]
+    async fn test_connect_and_receive() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let server = tokio::task::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let framing = ib_framing();
+            let mut prefix = [0u8; 4];
+            stream.read_exact(&mut prefix).await.unwrap();
+            assert_eq!(&prefix, IB_API_PREFIX);
+
+            let mut buf = Vec::new();
+            let versions = read_message(&mut stream, &framing, &mut buf).await.unwrap();
+            assert_eq!(versions, b"v151..151");
+
+            let reply = payload(&["151", "20250107 09:30:00 EST"]);
+            stream
+                .write_all(&framing.encode(&reply).unwrap())
+                .await
+                .unwrap();
+
+            let start_api = read_message(&mut stream, &framing, &mut buf).await.unwrap();
+            assert_eq!(start_api, encode_start_api(7, ""));
+
+            let next_valid_id = payload(&["9", "1", "100"]);
+            stream
+                .write_all(&framing.encode(&next_valid_id).unwrap())
+                .await
+                .unwrap();
+        });
+
+        let mut client = IbClient::connect("127.0.0.1", port, 7).await.unwrap();
+
+        assert_eq!(client.server_version(), 151);
+        assert_eq!(client.connection_time(), "20250107 09:30:00 EST");
+        assert_eq!(
+            client.next_message().await,
+            Some(IbIncomingMessage::NextValidId(100))
+        );
+        assert_eq!(client.next_request_id(), 1);
+        assert_eq!(client.next_request_id(), 2);
+
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_connect_with_unsupported_server_version() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        tokio::task::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let reply = payload(&["100", "20250107 09:30:00 EST"]);
+            stream
+                .write_all(&ib_framing().encode(&reply).unwrap())
+                .await
+                .unwrap();
+        });
+
+        assert!(IbClient::connect("127.0.0.1", port, 7).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_read_message_when_closed() {
+        let mut reader: &[u8] = b"\0\0\0\x05ab";
+        let mut buf = Vec::new();
+
+        assert!(read_message(&mut reader, &ib_framing(), &mut buf)
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,45 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_network::socket::PrefixWidth;
+
+pub const IB_DEFAULT_HOST: &str = "127.0.0.1";
+
+/// The default socket port of TWS for paper trading (live trading uses 7496).
+pub const IB_TWS_PAPER_PORT: u16 = 7497;
+pub const IB_TWS_LIVE_PORT: u16 = 7496;
+
+/// The default socket port of IB Gateway for paper trading (live trading uses 4001).
+pub const IB_GATEWAY_PAPER_PORT: u16 = 4002;
+pub const IB_GATEWAY_LIVE_PORT: u16 = 4001;
+
+/// The prefix sent before the version handshake of a connection.
+pub const IB_API_PREFIX: &[u8] = b"API\0";
+
+/// The width of the big-endian length prefix framing each message.
+pub const IB_LENGTH_PREFIX_WIDTH: PrefixWidth = PrefixWidth::FOUR;
+
+/// The maximum length of a message accepted by the TWS API (just under 16 MiB).
+pub const IB_MAX_FRAME_LEN: usize = 0x00FF_FFFF;
+
+/// The TWS API server version negotiated for connections.
+///
+/// The version is pinned so the layout of every message is known in advance. It includes
+/// tick-by-tick data and fractional positions, and is supported by all current TWS and
+/// IB Gateway releases.
+pub const IB_SERVER_VERSION: i64 = 151;
+
+/// The request ID used by TWS for errors and notices which are not related to a request.
+pub const IB_NO_VALID_ID: i64 = -1;
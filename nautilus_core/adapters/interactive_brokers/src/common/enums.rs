@@ -0,0 +1,165 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::enums::OrderSideSpecified;
+use strum::{AsRefStr, Display, EnumString, FromRepr};
+
+/// The security type of an Interactive Brokers contract.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum IbSecType {
+    /// Stock or ETF.
+    Stk,
+    /// Future.
+    Fut,
+    /// Option.
+    Opt,
+    /// Futures option.
+    Fop,
+    /// Forex pair.
+    Cash,
+    /// Index.
+    Ind,
+    /// Contract for difference.
+    Cfd,
+    /// Bond.
+    Bond,
+    /// Commodity.
+    Cmdty,
+    /// Cryptocurrency.
+    Crypto,
+    /// Combo of other contracts.
+    Bag,
+}
+
+/// The type of a market data tick, identified by its TWS API tick type ID.
+#[repr(i64)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, AsRefStr, FromRepr)]
+pub enum IbTickType {
+    BidSize = 0,
+    Bid = 1,
+    Ask = 2,
+    AskSize = 3,
+    Last = 4,
+    LastSize = 5,
+    High = 6,
+    Low = 7,
+    Volume = 8,
+    Close = 9,
+    Open = 14,
+    DelayedBid = 66,
+    DelayedAsk = 67,
+    DelayedLast = 68,
+    DelayedBidSize = 69,
+    DelayedAskSize = 70,
+    DelayedLastSize = 71,
+}
+
+/// The type of a tick-by-tick data subscription, identified by its TWS API ID.
+#[repr(i64)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, AsRefStr, EnumString, FromRepr)]
+pub enum IbTickByTickType {
+    /// Trades reported to the tape, excluding odd lots and combos.
+    Last = 1,
+    /// All trades, including odd lots and combos.
+    AllLast = 2,
+    /// Best bid and ask.
+    BidAsk = 3,
+    /// Midpoint of the best bid and ask.
+    MidPoint = 4,
+}
+
+/// The type of market data returned by TWS for top-of-book subscriptions.
+#[repr(i64)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, AsRefStr, FromRepr)]
+pub enum IbMarketDataType {
+    Realtime = 1,
+    Frozen = 2,
+    /// Delayed data, for accounts without market data subscriptions.
+    Delayed = 3,
+    DelayedFrozen = 4,
+}
+
+/// The action (side) of an Interactive Brokers order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum IbOrderAction {
+    Buy,
+    Sell,
+}
+
+impl From<OrderSideSpecified> for IbOrderAction {
+    fn from(value: OrderSideSpecified) -> Self {
+        match value {
+            OrderSideSpecified::Buy => Self::Buy,
+            OrderSideSpecified::Sell => Self::Sell,
+        }
+    }
+}
+
+/// The type of an Interactive Brokers order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, AsRefStr, EnumString)]
+pub enum IbOrderType {
+    #[strum(serialize = "MKT")]
+    Market,
+    #[strum(serialize = "LMT")]
+    Limit,
+    #[strum(serialize = "STP")]
+    Stop,
+    #[strum(serialize = "STP LMT")]
+    StopLimit,
+    #[strum(serialize = "MIT")]
+    MarketIfTouched,
+    #[strum(serialize = "LIT")]
+    LimitIfTouched,
+    #[strum(serialize = "TRAIL")]
+    TrailingStop,
+    #[strum(serialize = "MTL")]
+    MarketToLimit,
+}
+
+/// The time in force of an Interactive Brokers order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, AsRefStr, EnumString)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum IbTimeInForce {
+    Day,
+    Gtc,
+    Ioc,
+    Fok,
+    Gtd,
+    /// Executes at the opening auction.
+    Opg,
+}
+
+/// The status of an Interactive Brokers order, as reported in order status messages.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Display, AsRefStr, EnumString)]
+pub enum IbOrderStatus {
+    /// Not yet acknowledged by TWS.
+    ApiPending,
+    /// Transmitted by TWS, but not yet accepted by the destination.
+    PendingSubmit,
+    /// A cancel request was sent, but not yet confirmed.
+    PendingCancel,
+    /// Accepted, but held by IB until its conditions are met (e.g. a simulated stop).
+    PreSubmitted,
+    /// Accepted by the destination and working.
+    Submitted,
+    /// Canceled by the API before being submitted.
+    ApiCancelled,
+    Cancelled,
+    Filled,
+    /// Not working, e.g. rejected or outside its valid time range.
+    Inactive,
+}
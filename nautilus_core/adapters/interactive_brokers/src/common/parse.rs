@@ -0,0 +1,442 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::str::FromStr;
+
+use chrono::{NaiveDate, NaiveTime};
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, nanos::UnixNanos};
+use nautilus_model::{
+    data::{Data, QuoteTick, TradeTick},
+    enums::{AggressorSide, AssetClass},
+    identifiers::{InstrumentId, Symbol, TradeId, Venue},
+    instruments::{Equity, FuturesContract, InstrumentAny},
+    types::{Currency, Price, Quantity},
+};
+use ustr::Ustr;
+
+use super::enums::IbSecType;
+use crate::protocol::{
+    incoming::IbTickByTick,
+    models::{IbContract, IbContractDetails},
+};
+
+/// Parses a Nautilus instrument ID for the given Interactive Brokers `contract`.
+///
+/// Stocks use their symbol and primary exchange, e.g. `AAPL.NASDAQ` (with any spaces in
+/// the symbol replaced by `.`, e.g. `BRK.B.NYSE`). Other contracts use their local symbol
+/// and exchange, e.g. `ESH5.CME`.
+///
+/// # Errors
+///
+/// This function returns an error if the contract has no symbol or exchange.
+pub fn parse_instrument_id(contract: &IbContract) -> anyhow::Result<InstrumentId> {
+    let (symbol, venue) = match IbSecType::from_str(&contract.sec_type) {
+        Ok(IbSecType::Stk) => {
+            let venue = if contract.primary_exchange.is_empty() {
+                &contract.exchange
+            } else {
+                &contract.primary_exchange
+            };
+            (contract.symbol.replace(' ', "."), venue)
+        }
+        _ => (contract.local_symbol.replace(' ', "."), &contract.exchange),
+    };
+    if symbol.is_empty() || venue.is_empty() {
+        anyhow::bail!(
+            "Contract {} has no symbol or exchange for an instrument ID",
+            contract.con_id
+        );
+    }
+    Ok(InstrumentId::new(
+        Symbol::new_checked(symbol)?,
+        Venue::new_checked(venue)?,
+    ))
+}
+
+/// Parses an Interactive Brokers date as `YYYYMMDD` into UNIX nanoseconds at midnight UTC.
+///
+/// # Errors
+///
+/// This function returns an error if `value` is not a valid date.
+pub fn parse_contract_date(value: &str) -> anyhow::Result<UnixNanos> {
+    let date = NaiveDate::parse_from_str(value, "%Y%m%d")
+        .map_err(|e| anyhow::anyhow!("Invalid contract date '{value}': {e}"))?;
+    Ok(UnixNanos::from(date.and_time(NaiveTime::MIN).and_utc()))
+}
+
+/// Parses a TWS API timestamp in UNIX seconds into UNIX nanoseconds.
+///
+/// # Errors
+///
+/// This function returns an error if `secs` is negative.
+pub fn parse_unix_seconds(secs: i64) -> anyhow::Result<UnixNanos> {
+    let secs = u64::try_from(secs).map_err(|_| anyhow::anyhow!("Invalid timestamp {secs}"))?;
+    Ok(UnixNanos::from(secs * NANOSECONDS_IN_SECOND))
+}
+
+/// Parses a TWS API price with the given `precision`.
+///
+/// # Errors
+///
+/// This function returns an error if `value` is not a valid price.
+pub fn parse_price(value: f64, precision: u8) -> anyhow::Result<Price> {
+    Price::new_checked(value, precision)
+}
+
+/// Parses a TWS API size with the given `precision`.
+///
+/// # Errors
+///
+/// This function returns an error if `value` is not a valid quantity.
+pub fn parse_quantity(value: f64, precision: u8) -> anyhow::Result<Quantity> {
+    Quantity::new_checked(value, precision)
+}
+
+/// Parses Nautilus market data from the given tick-by-tick `tick`.
+///
+/// Trades are parsed as [`TradeTick`] (with no aggressor side, and a trade ID from `ts_init`
+/// as none is provided), and bid/ask ticks as [`QuoteTick`]. Midpoint ticks return `None`.
+///
+/// # Errors
+///
+/// This function returns an error if a price, size or timestamp is invalid.
+pub fn parse_tick_by_tick(
+    tick: &IbTickByTick,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Option<Data>> {
+    let data = match tick {
+        IbTickByTick::Last {
+            time, price, size, ..
+        } => Some(Data::Trade(TradeTick::new(
+            instrument_id,
+            parse_price(*price, price_precision)?,
+            parse_quantity(*size, size_precision)?,
+            AggressorSide::NoAggressor,
+            TradeId::new(ts_init.to_string()),
+            parse_unix_seconds(*time)?,
+            ts_init,
+        ))),
+        IbTickByTick::BidAsk {
+            time,
+            bid_price,
+            ask_price,
+            bid_size,
+            ask_size,
+            ..
+        } => Some(Data::Quote(QuoteTick::new_checked(
+            instrument_id,
+            parse_price(*bid_price, price_precision)?,
+            parse_price(*ask_price, price_precision)?,
+            parse_quantity(*bid_size, size_precision)?,
+            parse_quantity(*ask_size, size_precision)?,
+            parse_unix_seconds(*time)?,
+            ts_init,
+        )?)),
+        IbTickByTick::MidPoint { .. } => None,
+    };
+    Ok(data)
+}
+
+/// Parses a Nautilus instrument from the given Interactive Brokers contract `details`.
+///
+/// Stocks are parsed as [`Equity`] and futures as [`FuturesContract`] instruments.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the security type is not supported.
+/// - If the currency, minimum tick, multiplier or expiry is invalid.
+pub fn parse_instrument(
+    details: &IbContractDetails,
+    ts_init: UnixNanos,
+) -> anyhow::Result<InstrumentAny> {
+    let contract = &details.contract;
+    let instrument_id = parse_instrument_id(contract)?;
+    let raw_symbol = Symbol::new_checked(&contract.local_symbol)?;
+    let currency = Currency::from_str(&contract.currency)?;
+    let price_increment = Price::from_str(&details.min_tick.to_string())
+        .map_err(|e| anyhow::anyhow!("Invalid minimum tick {}: {e}", details.min_tick))?;
+
+    match IbSecType::from_str(&contract.sec_type) {
+        Ok(IbSecType::Stk) => {
+            let isin = details
+                .sec_id_list
+                .iter()
+                .find(|(tag, _)| tag == "ISIN")
+                .map(|(_, value)| Ustr::from(value.as_str()));
+            let instrument = Equity::new_checked(
+                instrument_id,
+                raw_symbol,
+                isin,
+                currency,
+                price_increment.precision,
+                price_increment,
+                Some(Quantity::from(1)),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                ts_init,
+                ts_init,
+            )?;
+            Ok(InstrumentAny::Equity(instrument))
+        }
+        Ok(IbSecType::Fut) => {
+            let expiry = if details.real_expiration_date.is_empty() {
+                &contract.last_trade_date_or_contract_month
+            } else {
+                &details.real_expiration_date
+            };
+            let multiplier = match contract.multiplier.as_str() {
+                "" => Quantity::from(1),
+                value => Quantity::from_str(value)
+                    .map_err(|e| anyhow::anyhow!("Invalid multiplier '{value}': {e}"))?,
+            };
+            let underlying = if details.under_symbol.is_empty() {
+                &contract.symbol
+            } else {
+                &details.under_symbol
+            };
+            let instrument = FuturesContract::new_checked(
+                instrument_id,
+                raw_symbol,
+                parse_asset_class(&details.under_sec_type),
+                Some(Ustr::from(contract.exchange.as_str())),
+                Ustr::from(underlying.as_str()),
+                UnixNanos::default(), // Not provided
+                parse_contract_date(expiry)?,
+                currency,
+                price_increment.precision,
+                price_increment,
+                multiplier,
+                Quantity::from(1),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                ts_init,
+                ts_init,
+            )?;
+            Ok(InstrumentAny::FuturesContract(instrument))
+        }
+        _ => anyhow::bail!(
+            "Security type '{}' not supported for contract {}",
+            contract.sec_type,
+            contract.con_id
+        ),
+    }
+}
+
+fn parse_asset_class(under_sec_type: &str) -> AssetClass {
+    match IbSecType::from_str(under_sec_type) {
+        Ok(IbSecType::Stk) => AssetClass::Equity,
+        Ok(IbSecType::Ind) => AssetClass::Index,
+        Ok(IbSecType::Cash) => AssetClass::FX,
+        Ok(IbSecType::Bond) => AssetClass::Debt,
+        Ok(IbSecType::Crypto) => AssetClass::Cryptocurrency,
+        _ => AssetClass::Commodity,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::instruments::Instrument;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        protocol::incoming::{decode_message, IbIncomingMessage},
+        tests::{payload, AAPL_CONTRACT_DATA, ES_CONTRACT_DATA},
+    };
+
+    fn contract_details(fields: &[&str]) -> IbContractDetails {
+        match decode_message(&payload(fields)).unwrap() {
+            IbIncomingMessage::ContractData { details, .. } => *details,
+            msg => panic!("Expected contract data, was {msg:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_parse_instrument_id() {
+        let mut contract = IbContract::stock("BRK B", "SMART", "USD");
+        contract.primary_exchange = "NYSE".to_string();
+
+        assert_eq!(
+            parse_instrument_id(&contract).unwrap(),
+            InstrumentId::from("BRK.B.NYSE")
+        );
+
+        contract.sec_type = "FUT".to_string();
+        contract.local_symbol = "ESH5".to_string();
+        contract.exchange = "CME".to_string();
+
+        assert_eq!(
+            parse_instrument_id(&contract).unwrap(),
+            InstrumentId::from("ESH5.CME")
+        );
+    }
+
+    #[rstest]
+    fn test_parse_instrument_id_without_exchange() {
+        let contract = IbContract::stock("AAPL", "", "USD");
+
+        assert!(parse_instrument_id(&contract).is_err());
+    }
+
+    #[rstest]
+    fn test_parse_contract_date() {
+        assert_eq!(
+            parse_contract_date("20250321").unwrap(),
+            UnixNanos::from(1_742_515_200_000_000_000)
+        );
+        assert!(parse_contract_date("202503").is_err());
+    }
+
+    #[rstest]
+    fn test_parse_unix_seconds() {
+        assert_eq!(
+            parse_unix_seconds(1_736_267_400).unwrap(),
+            UnixNanos::from(1_736_267_400_000_000_000)
+        );
+        assert!(parse_unix_seconds(-1).is_err());
+    }
+
+    #[rstest]
+    fn test_parse_tick_by_tick_trade() {
+        let tick = IbTickByTick::Last {
+            req_id: 4,
+            time: 1_736_267_400,
+            price: 5885.25,
+            size: 3.0,
+            attr_mask: 0,
+            exchange: "CME".to_string(),
+            special_conditions: String::new(),
+        };
+        let instrument_id = InstrumentId::from("ESH5.CME");
+
+        let data = parse_tick_by_tick(&tick, instrument_id, 2, 0, UnixNanos::from(2)).unwrap();
+
+        let Some(Data::Trade(trade)) = data else {
+            panic!("Expected trade, was {data:?}");
+        };
+        assert_eq!(trade.instrument_id, instrument_id);
+        assert_eq!(trade.price, Price::from("5885.25"));
+        assert_eq!(trade.size, Quantity::from(3));
+        assert_eq!(trade.aggressor_side, AggressorSide::NoAggressor);
+        assert_eq!(trade.trade_id, TradeId::new("2"));
+        assert_eq!(trade.ts_event, UnixNanos::from(1_736_267_400_000_000_000));
+    }
+
+    #[rstest]
+    fn test_parse_tick_by_tick_quote() {
+        let tick = IbTickByTick::BidAsk {
+            req_id: 4,
+            time: 1_736_267_400,
+            bid_price: 5885.0,
+            ask_price: 5885.25,
+            bid_size: 12.0,
+            ask_size: 9.0,
+            attr_mask: 0,
+        };
+        let instrument_id = InstrumentId::from("ESH5.CME");
+
+        let data = parse_tick_by_tick(&tick, instrument_id, 2, 0, UnixNanos::from(2)).unwrap();
+
+        let Some(Data::Quote(quote)) = data else {
+            panic!("Expected quote, was {data:?}");
+        };
+        assert_eq!(quote.bid_price, Price::from("5885.00"));
+        assert_eq!(quote.ask_price, Price::from("5885.25"));
+        assert_eq!(quote.bid_size, Quantity::from(12));
+        assert_eq!(quote.ask_size, Quantity::from(9));
+    }
+
+    #[rstest]
+    fn test_parse_tick_by_tick_mid_point() {
+        let tick = IbTickByTick::MidPoint {
+            req_id: 4,
+            time: 1_736_267_400,
+            mid_point: 5885.125,
+        };
+
+        let data = parse_tick_by_tick(
+            &tick,
+            InstrumentId::from("ESH5.CME"),
+            2,
+            0,
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert!(data.is_none());
+    }
+
+    #[rstest]
+    fn test_parse_equity() {
+        let details = contract_details(AAPL_CONTRACT_DATA);
+
+        let instrument = parse_instrument(&details, UnixNanos::from(1)).unwrap();
+
+        let InstrumentAny::Equity(equity) = instrument else {
+            panic!("Expected equity, was {instrument:?}");
+        };
+        assert_eq!(equity.id(), InstrumentId::from("AAPL.NASDAQ"));
+        assert_eq!(equity.isin, Some(Ustr::from("US0378331005")));
+        assert_eq!(equity.price_increment(), Price::from("0.01"));
+        assert_eq!(equity.quote_currency(), Currency::USD());
+    }
+
+    #[rstest]
+    fn test_parse_futures_contract() {
+        let details = contract_details(ES_CONTRACT_DATA);
+
+        let instrument = parse_instrument(&details, UnixNanos::from(1)).unwrap();
+
+        let InstrumentAny::FuturesContract(future) = instrument else {
+            panic!("Expected futures contract, was {instrument:?}");
+        };
+        assert_eq!(future.id(), InstrumentId::from("ESH5.CME"));
+        assert_eq!(future.asset_class, AssetClass::Index);
+        assert_eq!(future.underlying, Ustr::from("ES"));
+        assert_eq!(future.multiplier, Quantity::from(50));
+        assert_eq!(future.price_increment(), Price::from("0.25"));
+        assert_eq!(
+            future.expiration_ns,
+            UnixNanos::from(1_742_515_200_000_000_000)
+        );
+    }
+
+    #[rstest]
+    fn test_parse_unsupported_instrument() {
+        let mut details = contract_details(AAPL_CONTRACT_DATA);
+        details.contract.sec_type = "OPT".to_string();
+
+        assert!(parse_instrument(&details, UnixNanos::default()).is_err());
+    }
+}
@@ -0,0 +1,563 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a market data client for Interactive Brokers instruments, quotes and trades.
+
+use std::collections::{HashMap, VecDeque};
+
+use nautilus_core::{nanos::UnixNanos, time::get_atomic_clock_realtime};
+use nautilus_model::{
+    data::{Data, QuoteTick},
+    identifiers::InstrumentId,
+    instruments::InstrumentAny,
+};
+
+use crate::{
+    client::IbClient,
+    common::{
+        enums::{IbMarketDataType, IbTickByTickType, IbTickType},
+        parse::{parse_instrument, parse_price, parse_quantity, parse_tick_by_tick},
+    },
+    protocol::{
+        incoming::{IbErrorMessage, IbIncomingMessage, IbTickPrice, IbTickSize},
+        models::IbContract,
+        outgoing::{
+            encode_cancel_mkt_data, encode_cancel_tick_by_tick_data, encode_req_contract_details,
+            encode_req_market_data_type, encode_req_mkt_data, encode_req_tick_by_tick_data,
+        },
+    },
+};
+
+/// The kind of a market data subscription.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum IbSubscriptionKind {
+    /// Top-of-book quotes from streaming market data.
+    TopOfBook,
+    /// Tick-by-tick bid/ask quotes.
+    Quotes,
+    /// Tick-by-tick trades.
+    Trades,
+}
+
+/// The latest top-of-book state of a streaming market data subscription.
+#[derive(Clone, Debug, Default)]
+struct IbTopOfBook {
+    bid_price: Option<f64>,
+    ask_price: Option<f64>,
+    bid_size: Option<f64>,
+    ask_size: Option<f64>,
+}
+
+impl IbTopOfBook {
+    /// Applies the given tick, returning whether the top of book changed.
+    fn apply(&mut self, tick_type: IbTickType, value: f64) -> bool {
+        let field = match tick_type {
+            IbTickType::Bid | IbTickType::DelayedBid => &mut self.bid_price,
+            IbTickType::Ask | IbTickType::DelayedAsk => &mut self.ask_price,
+            IbTickType::BidSize | IbTickType::DelayedBidSize => &mut self.bid_size,
+            IbTickType::AskSize | IbTickType::DelayedAskSize => &mut self.ask_size,
+            _ => return false,
+        };
+        let changed = *field != Some(value);
+        *field = Some(value);
+        changed
+    }
+
+    fn to_quote(
+        &self,
+        instrument: &InstrumentAny,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Option<QuoteTick>> {
+        let (Some(bid_price), Some(ask_price), Some(bid_size), Some(ask_size)) =
+            (self.bid_price, self.ask_price, self.bid_size, self.ask_size)
+        else {
+            return Ok(None); // Awaiting both sides
+        };
+        let price_precision = instrument.price_precision();
+        let size_precision = instrument.size_precision();
+        let quote = QuoteTick::new_checked(
+            instrument.id(),
+            parse_price(bid_price, price_precision)?,
+            parse_price(ask_price, price_precision)?,
+            parse_quantity(bid_size, size_precision)?,
+            parse_quantity(ask_size, size_precision)?,
+            ts_init,
+            ts_init,
+        )?;
+        Ok(Some(quote))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct IbSubscription {
+    instrument_id: InstrumentId,
+    kind: IbSubscriptionKind,
+    top_of_book: IbTopOfBook,
+}
+
+/// Provides a market data client for Interactive Brokers over the TWS API.
+///
+/// Instruments are loaded from contract details requests, and their contracts are used for
+/// subscriptions. Quotes are available as top-of-book streaming market data (conflated by
+/// TWS) or as tick-by-tick bid/ask data, and trades as tick-by-tick data.
+pub struct IbDataClient {
+    host: String,
+    port: u16,
+    client_id: i64,
+    client: Option<IbClient>,
+    instruments: HashMap<InstrumentId, InstrumentAny>,
+    contracts: HashMap<InstrumentId, IbContract>,
+    subscriptions: HashMap<i64, IbSubscription>,
+    pending: VecDeque<IbIncomingMessage>,
+}
+
+impl IbDataClient {
+    /// Creates a new [`IbDataClient`] instance for TWS or IB Gateway at `host` and `port`.
+    #[must_use]
+    pub fn new(host: &str, port: u16, client_id: i64) -> Self {
+        Self {
+            host: host.to_string(),
+            port,
+            client_id,
+            client: None,
+            instruments: HashMap::new(),
+            contracts: HashMap::new(),
+            subscriptions: HashMap::new(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Returns whether the client is connected.
+    #[must_use]
+    pub fn is_connected(&self) -> bool {
+        self.client.as_ref().is_some_and(IbClient::is_active)
+    }
+
+    /// Connects to TWS or IB Gateway.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the connection cannot be established.
+    pub async fn connect(&mut self) -> anyhow::Result<()> {
+        self.client = Some(IbClient::connect(&self.host, self.port, self.client_id).await?);
+        Ok(())
+    }
+
+    /// Disconnects, discarding all subscriptions.
+    pub async fn disconnect(&mut self) {
+        if let Some(client) = self.client.take() {
+            client.close().await;
+        }
+        self.subscriptions.clear();
+        self.pending.clear();
+    }
+
+    /// Sets the type of subsequent top-of-book market data, e.g. delayed data for accounts
+    /// without market data subscriptions.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the client is not connected, or the request fails.
+    pub async fn set_market_data_type(
+        &self,
+        market_data_type: IbMarketDataType,
+    ) -> anyhow::Result<()> {
+        self.client()?
+            .send(&encode_req_market_data_type(market_data_type))
+            .await
+    }
+
+    /// Loads the instruments for all contracts matching `contract`, returning them.
+    ///
+    /// Contracts with an unsupported security type are logged and skipped.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the client is not connected, the request fails,
+    /// or TWS returns an error for the request.
+    pub async fn load_instruments(
+        &mut self,
+        contract: &IbContract,
+    ) -> anyhow::Result<Vec<InstrumentAny>> {
+        let client = self
+            .client
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("Not connected"))?;
+        let req_id = client.next_request_id();
+        client
+            .send(&encode_req_contract_details(req_id, contract))
+            .await?;
+
+        let mut instruments = Vec::new();
+        loop {
+            let msg = client
+                .next_message()
+                .await
+                .ok_or_else(|| anyhow::anyhow!("Connection closed"))?;
+            match msg {
+                IbIncomingMessage::ContractData {
+                    req_id: id,
+                    details,
+                } if id == req_id => {
+                    let ts_init = get_atomic_clock_realtime().get_time_ns();
+                    match parse_instrument(&details, ts_init) {
+                        Ok(instrument) => {
+                            self.contracts.insert(instrument.id(), details.contract);
+                            instruments.push(instrument);
+                        }
+                        Err(e) => tracing::warn!("Skipping contract: {e}"),
+                    }
+                }
+                IbIncomingMessage::ContractDataEnd(id) if id == req_id => break,
+                IbIncomingMessage::Error(error) if error.id == req_id => {
+                    anyhow::bail!(
+                        "Contract details request failed: {} ({})",
+                        error.message,
+                        error.code
+                    );
+                }
+                msg => self.pending.push_back(msg),
+            }
+        }
+
+        for instrument in &instruments {
+            self.instruments.insert(instrument.id(), instrument.clone());
+        }
+        Ok(instruments)
+    }
+
+    /// Returns the loaded instrument for the given `instrument_id`, if any.
+    #[must_use]
+    pub fn instrument(&self, instrument_id: &InstrumentId) -> Option<&InstrumentAny> {
+        self.instruments.get(instrument_id)
+    }
+
+    /// Returns the contract of the loaded instrument for the given `instrument_id`, if any.
+    #[must_use]
+    pub fn contract(&self, instrument_id: &InstrumentId) -> Option<&IbContract> {
+        self.contracts.get(instrument_id)
+    }
+
+    /// Subscribes to top-of-book quotes from streaming market data for `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument is not loaded, or the request fails.
+    pub async fn subscribe_top_of_book(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<()> {
+        self.subscribe(instrument_id, IbSubscriptionKind::TopOfBook)
+            .await
+    }
+
+    /// Subscribes to tick-by-tick bid/ask quotes for `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument is not loaded, or the request fails.
+    pub async fn subscribe_quotes(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.subscribe(instrument_id, IbSubscriptionKind::Quotes)
+            .await
+    }
+
+    /// Subscribes to tick-by-tick trades (including odd lots) for `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument is not loaded, or the request fails.
+    pub async fn subscribe_trades(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.subscribe(instrument_id, IbSubscriptionKind::Trades)
+            .await
+    }
+
+    /// Unsubscribes from top-of-book quotes for `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the client is not connected, or the request fails.
+    pub async fn unsubscribe_top_of_book(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<()> {
+        self.unsubscribe(instrument_id, IbSubscriptionKind::TopOfBook)
+            .await
+    }
+
+    /// Unsubscribes from tick-by-tick quotes for `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the client is not connected, or the request fails.
+    pub async fn unsubscribe_quotes(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.unsubscribe(instrument_id, IbSubscriptionKind::Quotes)
+            .await
+    }
+
+    /// Unsubscribes from tick-by-tick trades for `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the client is not connected, or the request fails.
+    pub async fn unsubscribe_trades(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        self.unsubscribe(instrument_id, IbSubscriptionKind::Trades)
+            .await
+    }
+
+    /// Returns the next market data item, or `None` once the connection has closed.
+    ///
+    /// Messages which fail to parse are logged and skipped.
+    pub async fn next_data(&mut self) -> Option<Data> {
+        loop {
+            let msg = match self.pending.pop_front() {
+                Some(msg) => msg,
+                None => self.client.as_mut()?.next_message().await?,
+            };
+            let ts_init = get_atomic_clock_realtime().get_time_ns();
+
+            let result = match msg {
+                IbIncomingMessage::TickPrice(tick) => self.handle_tick_price(&tick, ts_init),
+                IbIncomingMessage::TickSize(tick) => self.handle_tick_size(&tick, ts_init),
+                IbIncomingMessage::TickByTick(tick) => {
+                    let Some(instrument) = self.subscribed_instrument(tick.req_id()) else {
+                        continue;
+                    };
+                    parse_tick_by_tick(
+                        &tick,
+                        instrument.id(),
+                        instrument.price_precision(),
+                        instrument.size_precision(),
+                        ts_init,
+                    )
+                }
+                IbIncomingMessage::Error(error) => {
+                    self.handle_error(&error);
+                    continue;
+                }
+                _ => continue,
+            };
+
+            match result {
+                Ok(Some(data)) => return Some(data),
+                Ok(None) => {}
+                Err(e) => tracing::error!("Error parsing market data: {e}"),
+            }
+        }
+    }
+
+    fn handle_tick_price(
+        &mut self,
+        tick: &IbTickPrice,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Option<Data>> {
+        let Some(tick_type) = IbTickType::from_repr(tick.tick_type) else {
+            return Ok(None);
+        };
+        let size_tick_type = match tick_type {
+            IbTickType::Bid => IbTickType::BidSize,
+            IbTickType::Ask => IbTickType::AskSize,
+            IbTickType::DelayedBid => IbTickType::DelayedBidSize,
+            IbTickType::DelayedAsk => IbTickType::DelayedAskSize,
+            _ => return Ok(None),
+        };
+        self.update_top_of_book(
+            tick.req_id,
+            &[(tick_type, tick.price), (size_tick_type, tick.size)],
+            ts_init,
+        )
+    }
+
+    fn handle_tick_size(
+        &mut self,
+        tick: &IbTickSize,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Option<Data>> {
+        let Some(tick_type) = IbTickType::from_repr(tick.tick_type) else {
+            return Ok(None);
+        };
+        self.update_top_of_book(tick.req_id, &[(tick_type, tick.size)], ts_init)
+    }
+
+    fn update_top_of_book(
+        &mut self,
+        req_id: i64,
+        ticks: &[(IbTickType, f64)],
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Option<Data>> {
+        let Some(subscription) = self.subscriptions.get_mut(&req_id) else {
+            return Ok(None);
+        };
+        let Some(instrument) = self.instruments.get(&subscription.instrument_id) else {
+            return Ok(None);
+        };
+        let mut changed = false;
+        for (tick_type, value) in ticks {
+            changed |= subscription.top_of_book.apply(*tick_type, *value);
+        }
+        if !changed {
+            return Ok(None);
+        }
+        let quote = subscription.top_of_book.to_quote(instrument, ts_init)?;
+        Ok(quote.map(Data::Quote))
+    }
+
+    fn handle_error(&mut self, error: &IbErrorMessage) {
+        if error.is_notice() {
+            tracing::info!("{} ({})", error.message, error.code);
+        } else if let Some(subscription) = self.subscriptions.remove(&error.id) {
+            tracing::error!(
+                "{:?} subscription for {} failed: {} ({})",
+                subscription.kind,
+                subscription.instrument_id,
+                error.message,
+                error.code,
+            );
+        } else {
+            tracing::error!(
+                "Error for request {}: {} ({})",
+                error.id,
+                error.message,
+                error.code
+            );
+        }
+    }
+
+    fn subscribed_instrument(&self, req_id: i64) -> Option<&InstrumentAny> {
+        let subscription = self.subscriptions.get(&req_id)?;
+        self.instruments.get(&subscription.instrument_id)
+    }
+
+    async fn subscribe(
+        &mut self,
+        instrument_id: &InstrumentId,
+        kind: IbSubscriptionKind,
+    ) -> anyhow::Result<()> {
+        let contract = self
+            .contracts
+            .get(instrument_id)
+            .ok_or_else(|| anyhow::anyhow!("Instrument {instrument_id} not loaded"))?;
+        let client = self.client()?;
+        let req_id = client.next_request_id();
+        let payload = match kind {
+            IbSubscriptionKind::TopOfBook => encode_req_mkt_data(req_id, contract, "", false),
+            IbSubscriptionKind::Quotes => {
+                encode_req_tick_by_tick_data(req_id, contract, IbTickByTickType::BidAsk, 0, false)
+            }
+            IbSubscriptionKind::Trades => {
+                encode_req_tick_by_tick_data(req_id, contract, IbTickByTickType::AllLast, 0, false)
+            }
+        };
+        client.send(&payload).await?;
+        self.subscriptions.insert(
+            req_id,
+            IbSubscription {
+                instrument_id: *instrument_id,
+                kind,
+                top_of_book: IbTopOfBook::default(),
+            },
+        );
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &mut self,
+        instrument_id: &InstrumentId,
+        kind: IbSubscriptionKind,
+    ) -> anyhow::Result<()> {
+        let req_ids: Vec<i64> = self
+            .subscriptions
+            .iter()
+            .filter(|(_, sub)| sub.instrument_id == *instrument_id && sub.kind == kind)
+            .map(|(req_id, _)| *req_id)
+            .collect();
+        for req_id in req_ids {
+            self.subscriptions.remove(&req_id);
+            let payload = match kind {
+                IbSubscriptionKind::TopOfBook => encode_cancel_mkt_data(req_id),
+                IbSubscriptionKind::Quotes | IbSubscriptionKind::Trades => {
+                    encode_cancel_tick_by_tick_data(req_id)
+                }
+            };
+            self.client()?.send(&payload).await?;
+        }
+        Ok(())
+    }
+
+    fn client(&self) -> anyhow::Result<&IbClient> {
+        self.client
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected"))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::types::{Price, Quantity};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::{
+        protocol::incoming::decode_message,
+        tests::{payload, AAPL_CONTRACT_DATA},
+    };
+
+    #[fixture]
+    fn aapl() -> InstrumentAny {
+        match decode_message(&payload(AAPL_CONTRACT_DATA)).unwrap() {
+            IbIncomingMessage::ContractData { details, .. } => {
+                parse_instrument(&details, UnixNanos::default()).unwrap()
+            }
+            msg => panic!("Expected contract data, was {msg:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_top_of_book_awaits_both_sides(aapl: InstrumentAny) {
+        let mut top_of_book = IbTopOfBook::default();
+
+        assert!(top_of_book.apply(IbTickType::Bid, 150.25));
+        assert!(top_of_book.apply(IbTickType::BidSize, 300.0));
+        assert!(!top_of_book.apply(IbTickType::Last, 150.26));
+
+        assert_eq!(
+            top_of_book.to_quote(&aapl, UnixNanos::default()).unwrap(),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_top_of_book_quote(aapl: InstrumentAny) {
+        let mut top_of_book = IbTopOfBook::default();
+        top_of_book.apply(IbTickType::DelayedBid, 150.25);
+        top_of_book.apply(IbTickType::DelayedBidSize, 300.0);
+        top_of_book.apply(IbTickType::DelayedAsk, 150.27);
+        top_of_book.apply(IbTickType::DelayedAskSize, 200.0);
+
+        let quote = top_of_book
+            .to_quote(&aapl, UnixNanos::from(1))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(quote.instrument_id, aapl.id());
+        assert_eq!(quote.bid_price, Price::from("150.25"));
+        assert_eq!(quote.ask_price, Price::from("150.27"));
+        assert_eq!(quote.bid_size, Quantity::from(300));
+        assert_eq!(quote.ask_size, Quantity::from(200));
+        assert!(!top_of_book.apply(IbTickType::DelayedAsk, 150.27));
+    }
+}
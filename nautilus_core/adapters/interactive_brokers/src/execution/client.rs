@@ -0,0 +1,239 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides an execution client for Interactive Brokers order management.
+
+use std::{collections::HashMap, time::Duration};
+
+use nautilus_model::{
+    enums::OrderStatus,
+    identifiers::{ClientOrderId, InstrumentId},
+    orders::OrderAny,
+};
+
+use super::parse::{parse_order, parse_order_status};
+use crate::{
+    client::IbClient,
+    protocol::{
+        incoming::{IbIncomingMessage, IbOrderStatusUpdate},
+        models::IbContract,
+        outgoing::{encode_cancel_order, encode_place_order, encode_req_ids},
+    },
+};
+
+/// The timeout for the next valid order ID after connecting.
+const NEXT_VALID_ID_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Represents an order event from Interactive Brokers.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IbExecutionEvent {
+    /// The order status changed, with its Nautilus order status.
+    OrderStatus {
+        client_order_id: ClientOrderId,
+        status: OrderStatus,
+        update: IbOrderStatusUpdate,
+    },
+    /// An error was reported for the order, e.g. a rejection.
+    OrderError {
+        client_order_id: ClientOrderId,
+        code: i64,
+        message: String,
+    },
+}
+
+/// Provides an execution client for Interactive Brokers over the TWS API.
+///
+/// Orders are placed with order IDs allocated from the next valid ID reported by TWS, and
+/// order status updates are matched back to client order IDs.
+pub struct IbExecutionClient {
+    host: String,
+    port: u16,
+    client_id: i64,
+    account: String,
+    client: Option<IbClient>,
+    contracts: HashMap<InstrumentId, IbContract>,
+    next_order_id: i64,
+    client_order_ids: HashMap<i64, ClientOrderId>,
+    order_ids: HashMap<ClientOrderId, i64>,
+}
+
+impl IbExecutionClient {
+    /// Creates a new [`IbExecutionClient`] instance for TWS or IB Gateway at `host` and
+    /// `port`, placing orders in `account`.
+    ///
+    /// The `client_id` must differ from that of any data client on the same host.
+    #[must_use]
+    pub fn new(host: &str, port: u16, client_id: i64, account: &str) -> Self {
+        Self {
+            host: host.to_string(),
+            port,
+            client_id,
+            account: account.to_string(),
+            client: None,
+            contracts: HashMap::new(),
+            next_order_id: 0,
+            client_order_ids: HashMap::new(),
+            order_ids: HashMap::new(),
+        }
+    }
+
+    /// Returns whether the client is connected.
+    #[must_use]
+    pub fn is_connected(&self) -> bool {
+        self.client.as_ref().is_some_and(IbClient::is_active)
+    }
+
+    /// Connects to TWS or IB Gateway, waiting for the next valid order ID.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the connection cannot be established, or the next
+    /// valid order ID is not received.
+    pub async fn connect(&mut self) -> anyhow::Result<()> {
+        let mut client = IbClient::connect(&self.host, self.port, self.client_id).await?;
+        client.send(&encode_req_ids()).await?;
+
+        let next_order_id = tokio::time::timeout(NEXT_VALID_ID_TIMEOUT, async {
+            while let Some(msg) = client.next_message().await {
+                match msg {
+                    IbIncomingMessage::NextValidId(order_id) => return Some(order_id),
+                    IbIncomingMessage::ManagedAccounts(accounts) => {
+                        tracing::info!("Managed accounts: {}", accounts.join(","));
+                    }
+                    _ => {}
+                }
+            }
+            None
+        })
+        .await
+        .map_err(|_| anyhow::anyhow!("Timed out waiting for the next valid order ID"))?
+        .ok_or_else(|| anyhow::anyhow!("Connection closed"))?;
+
+        self.next_order_id = next_order_id;
+        self.client = Some(client);
+        Ok(())
+    }
+
+    /// Disconnects from TWS or IB Gateway.
+    pub async fn disconnect(&mut self) {
+        if let Some(client) = self.client.take() {
+            client.close().await;
+        }
+    }
+
+    /// Adds the `contract` to place orders with for the given `instrument_id`.
+    pub fn add_contract(&mut self, instrument_id: InstrumentId, contract: IbContract) {
+        self.contracts.insert(instrument_id, contract);
+    }
+
+    /// Returns the order ID of the order with the given `client_order_id`, if placed.
+    #[must_use]
+    pub fn order_id(&self, client_order_id: &ClientOrderId) -> Option<i64> {
+        self.order_ids.get(client_order_id).copied()
+    }
+
+    /// Submits the given `order`, returning its order ID.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the client is not connected, or there is no contract for the instrument.
+    /// - If the order cannot be represented as an Interactive Brokers order.
+    /// - If the request fails.
+    pub async fn submit_order(&mut self, order: &OrderAny) -> anyhow::Result<i64> {
+        let instrument_id = order.instrument_id();
+        let contract = self
+            .contracts
+            .get(&instrument_id)
+            .ok_or_else(|| anyhow::anyhow!("No contract for {instrument_id}"))?;
+        let ib_order = parse_order(order, &self.account)?;
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected"))?;
+
+        let order_id = self.next_order_id;
+        client
+            .send(&encode_place_order(order_id, contract, &ib_order))
+            .await?;
+
+        self.next_order_id += 1;
+        let client_order_id = order.client_order_id();
+        self.client_order_ids.insert(order_id, client_order_id);
+        self.order_ids.insert(client_order_id, order_id);
+        Ok(order_id)
+    }
+
+    /// Cancels the order with the given `client_order_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the client is not connected, the order was not
+    /// placed by this client, or the request fails.
+    pub async fn cancel_order(&self, client_order_id: &ClientOrderId) -> anyhow::Result<()> {
+        let order_id = self
+            .order_id(client_order_id)
+            .ok_or_else(|| anyhow::anyhow!("Order {client_order_id} not found"))?;
+        self.client
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Not connected"))?
+            .send(&encode_cancel_order(order_id))
+            .await
+    }
+
+    /// Returns the next event for an order placed by this client, or `None` once the
+    /// connection has closed.
+    ///
+    /// Order status updates which fail to parse are logged and skipped.
+    pub async fn next_event(&mut self) -> Option<IbExecutionEvent> {
+        loop {
+            let msg = self.client.as_mut()?.next_message().await?;
+            match msg {
+                IbIncomingMessage::OrderStatus(update) => {
+                    let Some(client_order_id) = self.client_order_ids.get(&update.order_id) else {
+                        continue; // Not placed by this client
+                    };
+                    match parse_order_status(&update.status, update.filled) {
+                        Ok(status) => {
+                            return Some(IbExecutionEvent::OrderStatus {
+                                client_order_id: *client_order_id,
+                                status,
+                                update,
+                            })
+                        }
+                        Err(e) => tracing::error!("Error parsing order status: {e}"),
+                    }
+                }
+                IbIncomingMessage::Error(error) => {
+                    if let Some(client_order_id) = self.client_order_ids.get(&error.id) {
+                        return Some(IbExecutionEvent::OrderError {
+                            client_order_id: *client_order_id,
+                            code: error.code,
+                            message: error.message,
+                        });
+                    } else if error.is_notice() {
+                        tracing::info!("{} ({})", error.message, error.code);
+                    } else {
+                        tracing::error!("{} ({})", error.message, error.code);
+                    }
+                }
+                IbIncomingMessage::NextValidId(order_id) => {
+                    self.next_order_id = self.next_order_id.max(order_id);
+                }
+                _ => {}
+            }
+        }
+    }
+}
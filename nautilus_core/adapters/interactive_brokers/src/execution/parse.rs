@@ -0,0 +1,319 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Functions for translating between Nautilus orders and Interactive Brokers orders.
+
+use std::str::FromStr;
+
+use chrono::DateTime;
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    enums::{OrderStatus, OrderType, TimeInForce, TrailingOffsetType},
+    orders::OrderAny,
+};
+
+use crate::{
+    common::enums::{IbOrderAction, IbOrderStatus, IbOrderType, IbTimeInForce},
+    protocol::models::IbOrder,
+};
+
+/// Formats the given `expire_time` as an Interactive Brokers good-till date in UTC.
+#[must_use]
+pub fn format_good_till_date(expire_time: UnixNanos) -> String {
+    DateTime::from_timestamp_nanos(expire_time.as_i64())
+        .format("%Y%m%d-%H:%M:%S")
+        .to_string()
+}
+
+/// Parses the Interactive Brokers order for the given `order`, placed in `account`.
+///
+/// The client order ID is carried as the order reference. Trailing stops use a price
+/// offset as the trailing amount, or a basis point offset as the trailing percentage.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the order type or time in force is not supported.
+/// - If the order is post-only or reduce-only, which are not supported.
+/// - If a required price, trigger price or expire time is missing.
+pub fn parse_order(order: &OrderAny, account: &str) -> anyhow::Result<IbOrder> {
+    let order_type = order.order_type();
+    let ib_order_type = match order_type {
+        OrderType::Market => IbOrderType::Market,
+        OrderType::Limit => IbOrderType::Limit,
+        OrderType::StopMarket => IbOrderType::Stop,
+        OrderType::StopLimit => IbOrderType::StopLimit,
+        OrderType::MarketIfTouched => IbOrderType::MarketIfTouched,
+        OrderType::LimitIfTouched => IbOrderType::LimitIfTouched,
+        OrderType::TrailingStopMarket => IbOrderType::TrailingStop,
+        OrderType::MarketToLimit => IbOrderType::MarketToLimit,
+        _ => anyhow::bail!("Order type {order_type} not supported for Interactive Brokers"),
+    };
+
+    if order.is_post_only() {
+        anyhow::bail!("Post-only orders not supported for Interactive Brokers");
+    }
+    if order.is_reduce_only() {
+        anyhow::bail!("Reduce-only orders not supported for Interactive Brokers");
+    }
+
+    let time_in_force = order.time_in_force();
+    let tif = match time_in_force {
+        TimeInForce::Day => IbTimeInForce::Day,
+        TimeInForce::Gtc => IbTimeInForce::Gtc,
+        TimeInForce::Ioc => IbTimeInForce::Ioc,
+        TimeInForce::Fok => IbTimeInForce::Fok,
+        TimeInForce::Gtd => IbTimeInForce::Gtd,
+        TimeInForce::AtTheOpen => IbTimeInForce::Opg,
+        _ => anyhow::bail!("Time in force {time_in_force} not supported for Interactive Brokers"),
+    };
+
+    let good_till_date = match tif {
+        IbTimeInForce::Gtd => format_good_till_date(
+            order
+                .expire_time()
+                .ok_or_else(|| anyhow::anyhow!("`GTD` order has no expire time"))?,
+        ),
+        _ => String::new(),
+    };
+
+    let lmt_price = match order_type {
+        OrderType::Limit | OrderType::StopLimit | OrderType::LimitIfTouched => Some(
+            order
+                .price()
+                .ok_or_else(|| anyhow::anyhow!("{order_type} order has no price"))?
+                .as_f64(),
+        ),
+        _ => None,
+    };
+
+    let (aux_price, trail_stop_price, trailing_percent) = match order {
+        OrderAny::TrailingStopMarket(order) => {
+            let (aux_price, trailing_percent) = match order.trailing_offset_type {
+                TrailingOffsetType::Price => (Some(order.trailing_offset.as_f64()), None),
+                // Interactive Brokers trailing percent is a percentage
+                TrailingOffsetType::BasisPoints => {
+                    (None, Some(order.trailing_offset.as_f64() / 100.0))
+                }
+                offset_type => {
+                    anyhow::bail!("Trailing offset type {offset_type} not supported")
+                }
+            };
+            (
+                aux_price,
+                Some(order.trigger_price.as_f64()),
+                trailing_percent,
+            )
+        }
+        _ => match order_type {
+            OrderType::StopMarket
+            | OrderType::StopLimit
+            | OrderType::MarketIfTouched
+            | OrderType::LimitIfTouched => {
+                let trigger_price = order
+                    .trigger_price()
+                    .ok_or_else(|| anyhow::anyhow!("{order_type} order has no trigger price"))?;
+                (Some(trigger_price.as_f64()), None, None)
+            }
+            _ => (None, None, None),
+        },
+    };
+
+    Ok(IbOrder {
+        action: IbOrderAction::from(order.order_side_specified()),
+        total_quantity: order.quantity().as_f64(),
+        order_type: ib_order_type,
+        lmt_price,
+        aux_price,
+        tif,
+        good_till_date,
+        account: account.to_string(),
+        order_ref: order.client_order_id().to_string(),
+        outside_rth: false,
+        trail_stop_price,
+        trailing_percent,
+        transmit: true,
+    })
+}
+
+/// Parses the Nautilus order status for the given Interactive Brokers `status`, with the
+/// `filled` quantity of the order.
+///
+/// # Errors
+///
+/// This function returns an error if `status` is not a valid order status.
+pub fn parse_order_status(status: &str, filled: f64) -> anyhow::Result<OrderStatus> {
+    let status = IbOrderStatus::from_str(status)
+        .map_err(|_| anyhow::anyhow!("Invalid order status '{status}'"))?;
+    let status = match status {
+        IbOrderStatus::ApiPending | IbOrderStatus::PendingSubmit => OrderStatus::Submitted,
+        IbOrderStatus::PreSubmitted | IbOrderStatus::Submitted if filled > 0.0 => {
+            OrderStatus::PartiallyFilled
+        }
+        IbOrderStatus::PreSubmitted | IbOrderStatus::Submitted => OrderStatus::Accepted,
+        IbOrderStatus::PendingCancel => OrderStatus::PendingCancel,
+        IbOrderStatus::ApiCancelled | IbOrderStatus::Cancelled => OrderStatus::Canceled,
+        IbOrderStatus::Filled => OrderStatus::Filled,
+        IbOrderStatus::Inactive => OrderStatus::Rejected,
+    };
+    Ok(status)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderSide,
+        identifiers::{ClientOrderId, InstrumentId},
+        orders::OrderTestBuilder,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("AAPL.NASDAQ")
+    }
+
+    #[rstest]
+    fn test_format_good_till_date() {
+        assert_eq!(
+            format_good_till_date(UnixNanos::from(1_738_357_200_000_000_000)),
+            "20250131-21:00:00"
+        );
+    }
+
+    #[rstest]
+    fn test_parse_limit_order() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id())
+            .client_order_id(ClientOrderId::from("O-123"))
+            .side(OrderSide::Buy)
+            .price(Price::from("150.25"))
+            .quantity(Quantity::from(100))
+            .time_in_force(TimeInForce::Day)
+            .build();
+
+        let ib_order = parse_order(&order, "DU123456").unwrap();
+
+        assert_eq!(ib_order.action, IbOrderAction::Buy);
+        assert_eq!(ib_order.order_type, IbOrderType::Limit);
+        assert_eq!(ib_order.total_quantity, 100.0);
+        assert_eq!(ib_order.lmt_price, Some(150.25));
+        assert_eq!(ib_order.aux_price, None);
+        assert_eq!(ib_order.tif, IbTimeInForce::Day);
+        assert_eq!(ib_order.account, "DU123456");
+        assert_eq!(ib_order.order_ref, "O-123");
+        assert!(ib_order.transmit);
+    }
+
+    #[rstest]
+    fn test_parse_stop_limit_gtd_order() {
+        let order = OrderTestBuilder::new(OrderType::StopLimit)
+            .instrument_id(instrument_id())
+            .side(OrderSide::Sell)
+            .price(Price::from("149.50"))
+            .trigger_price(Price::from("150.00"))
+            .quantity(Quantity::from(10))
+            .time_in_force(TimeInForce::Gtd)
+            .expire_time(UnixNanos::from(1_738_357_200_000_000_000))
+            .build();
+
+        let ib_order = parse_order(&order, "").unwrap();
+
+        assert_eq!(ib_order.action, IbOrderAction::Sell);
+        assert_eq!(ib_order.order_type, IbOrderType::StopLimit);
+        assert_eq!(ib_order.lmt_price, Some(149.5));
+        assert_eq!(ib_order.aux_price, Some(150.0));
+        assert_eq!(ib_order.tif, IbTimeInForce::Gtd);
+        assert_eq!(ib_order.good_till_date, "20250131-21:00:00");
+    }
+
+    #[rstest]
+    #[case(TrailingOffsetType::Price, "0.50", Some(0.5), None)]
+    #[case(TrailingOffsetType::BasisPoints, "150", None, Some(1.5))]
+    fn test_parse_trailing_stop_order(
+        #[case] offset_type: TrailingOffsetType,
+        #[case] offset: &str,
+        #[case] expected_aux_price: Option<f64>,
+        #[case] expected_trailing_percent: Option<f64>,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::TrailingStopMarket)
+            .instrument_id(instrument_id())
+            .side(OrderSide::Sell)
+            .trigger_price(Price::from("148.00"))
+            .trailing_offset(Price::from(offset))
+            .trailing_offset_type(offset_type)
+            .quantity(Quantity::from(10))
+            .build();
+
+        let ib_order = parse_order(&order, "").unwrap();
+
+        assert_eq!(ib_order.order_type, IbOrderType::TrailingStop);
+        assert_eq!(ib_order.aux_price, expected_aux_price);
+        assert_eq!(ib_order.trailing_percent, expected_trailing_percent);
+        assert_eq!(ib_order.trail_stop_price, Some(148.0));
+    }
+
+    #[rstest]
+    fn test_parse_reduce_only_order_fails() {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_id())
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from(10))
+            .reduce_only(true)
+            .build();
+
+        assert!(parse_order(&order, "").is_err());
+    }
+
+    #[rstest]
+    fn test_parse_at_the_close_order_fails() {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(10))
+            .time_in_force(TimeInForce::AtTheClose)
+            .build();
+
+        assert!(parse_order(&order, "").is_err());
+    }
+
+    #[rstest]
+    #[case("PendingSubmit", 0.0, OrderStatus::Submitted)]
+    #[case("PreSubmitted", 0.0, OrderStatus::Accepted)]
+    #[case("Submitted", 0.0, OrderStatus::Accepted)]
+    #[case("Submitted", 50.0, OrderStatus::PartiallyFilled)]
+    #[case("PendingCancel", 0.0, OrderStatus::PendingCancel)]
+    #[case("Cancelled", 0.0, OrderStatus::Canceled)]
+    #[case("ApiCancelled", 0.0, OrderStatus::Canceled)]
+    #[case("Filled", 100.0, OrderStatus::Filled)]
+    #[case("Inactive", 0.0, OrderStatus::Rejected)]
+    fn test_parse_order_status(
+        #[case] status: &str,
+        #[case] filled: f64,
+        #[case] expected: OrderStatus,
+    ) {
+        assert_eq!(parse_order_status(status, filled).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_parse_invalid_order_status() {
+        assert!(parse_order_status("Unknown", 0.0).is_err());
+    }
+}
@@ -0,0 +1,30 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The [Interactive Brokers](https://www.interactivebrokers.com) integration adapter.
+//!
+//! Implements the TWS API socket protocol natively, connecting to Trader Workstation (TWS)
+//! or IB Gateway without the Python `ibapi` package. Supports contract details as
+//! instruments, top-of-book and tick-by-tick market data, and order placement with
+//! order status updates.
+
+pub mod client;
+pub mod common;
+pub mod data;
+pub mod execution;
+pub mod protocol;
+
+#[cfg(test)]
+pub mod tests;
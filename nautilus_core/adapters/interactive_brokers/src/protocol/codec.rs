@@ -0,0 +1,307 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the field encoding and decoding of the TWS API wire protocol.
+//!
+//! Every message is a sequence of NUL-terminated ASCII fields, sent on the socket with a
+//! 4-byte big-endian length prefix. Unset optional numbers are sent as empty fields.
+
+use nautilus_network::socket::MessageFraming;
+
+use crate::common::consts::{IB_API_PREFIX, IB_LENGTH_PREFIX_WIDTH, IB_MAX_FRAME_LEN};
+
+/// Returns the framing of TWS API messages on the socket.
+#[must_use]
+pub const fn ib_framing() -> MessageFraming {
+    MessageFraming::LengthPrefixed {
+        width: IB_LENGTH_PREFIX_WIDTH,
+        max_frame_len: IB_MAX_FRAME_LEN,
+    }
+}
+
+/// Encodes the handshake which opens a connection, for the given range of server versions.
+///
+/// The handshake is the unframed `API\0` prefix, followed by the framed version range
+/// with any `connect_options` (e.g. `+PACEAPI`).
+///
+/// # Errors
+///
+/// This function returns an error if the version range cannot be framed.
+pub fn encode_handshake(
+    min_version: i64,
+    max_version: i64,
+    connect_options: Option<&str>,
+) -> anyhow::Result<Vec<u8>> {
+    let mut versions = format!("v{min_version}..{max_version}");
+    if let Some(options) = connect_options.filter(|options| !options.is_empty()) {
+        versions.push(' ');
+        versions.push_str(options);
+    }
+    let mut buf = IB_API_PREFIX.to_vec();
+    buf.extend(ib_framing().encode(versions.as_bytes())?);
+    Ok(buf)
+}
+
+/// Encodes the fields of an outgoing TWS API message.
+#[derive(Debug)]
+pub struct IbFieldEncoder {
+    buf: Vec<u8>,
+}
+
+impl IbFieldEncoder {
+    /// Creates a new [`IbFieldEncoder`] instance for a message with the given `msg_id`.
+    #[must_use]
+    pub fn new(msg_id: i64) -> Self {
+        Self { buf: Vec::new() }.int(msg_id)
+    }
+
+    /// Appends a string field.
+    #[must_use]
+    pub fn str(mut self, value: &str) -> Self {
+        self.buf.extend_from_slice(value.as_bytes());
+        self.buf.push(0);
+        self
+    }
+
+    /// Appends an integer field.
+    #[must_use]
+    pub fn int(self, value: i64) -> Self {
+        self.str(&value.to_string())
+    }
+
+    /// Appends a floating point field.
+    #[must_use]
+    pub fn float(self, value: f64) -> Self {
+        self.str(&value.to_string())
+    }
+
+    /// Appends a boolean field, encoded as `1` or `0`.
+    #[must_use]
+    pub fn bool(self, value: bool) -> Self {
+        self.int(i64::from(value))
+    }
+
+    /// Appends an optional integer field, which is empty when unset.
+    #[must_use]
+    pub fn opt_int(self, value: Option<i64>) -> Self {
+        match value {
+            Some(value) => self.int(value),
+            None => self.str(""),
+        }
+    }
+
+    /// Appends an optional floating point field, which is empty when unset.
+    #[must_use]
+    pub fn opt_float(self, value: Option<f64>) -> Self {
+        match value {
+            Some(value) => self.float(value),
+            None => self.str(""),
+        }
+    }
+
+    /// Returns the encoded message, without its length prefix.
+    #[must_use]
+    pub fn finish(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+/// Decodes the fields of an incoming TWS API message.
+///
+/// Following the reference implementations, empty numeric fields decode as zero.
+#[derive(Debug)]
+pub struct IbFieldDecoder<'a> {
+    fields: Vec<&'a str>,
+    index: usize,
+}
+
+impl<'a> IbFieldDecoder<'a> {
+    /// Creates a new [`IbFieldDecoder`] instance for the given message `payload`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `payload` is not valid UTF-8.
+    pub fn new(payload: &'a [u8]) -> anyhow::Result<Self> {
+        let text = std::str::from_utf8(payload)?;
+        let mut fields: Vec<&str> = text.split('\0').collect();
+        if text.ends_with('\0') {
+            fields.pop(); // Remove the empty remainder after the last terminator
+        }
+        Ok(Self { fields, index: 0 })
+    }
+
+    /// Returns the number of fields not yet decoded.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.fields.len() - self.index
+    }
+
+    /// Decodes the next field as a string.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if there are no fields remaining.
+    pub fn next_str(&mut self) -> anyhow::Result<&'a str> {
+        let field = self
+            .fields
+            .get(self.index)
+            .ok_or_else(|| anyhow::anyhow!("Missing field at index {}", self.index))?;
+        self.index += 1;
+        Ok(field)
+    }
+
+    /// Decodes the next field as an owned string.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if there are no fields remaining.
+    pub fn next_string(&mut self) -> anyhow::Result<String> {
+        self.next_str().map(ToString::to_string)
+    }
+
+    /// Decodes the next field as an integer.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if there are no fields remaining, or the field is not
+    /// a valid integer.
+    pub fn next_int(&mut self) -> anyhow::Result<i64> {
+        let field = self.next_str()?;
+        if field.is_empty() {
+            return Ok(0);
+        }
+        field
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid integer field '{field}': {e}"))
+    }
+
+    /// Decodes the next field as a floating point number.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if there are no fields remaining, or the field is not
+    /// a valid number.
+    pub fn next_float(&mut self) -> anyhow::Result<f64> {
+        let field = self.next_str()?;
+        if field.is_empty() {
+            return Ok(0.0);
+        }
+        field
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid float field '{field}': {e}"))
+    }
+
+    /// Decodes the next field as a boolean, which is `true` for any non-zero integer.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if there are no fields remaining, or the field is not
+    /// a valid integer.
+    pub fn next_bool(&mut self) -> anyhow::Result<bool> {
+        Ok(self.next_int()? != 0)
+    }
+
+    /// Skips the next `count` fields.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if fewer than `count` fields remain.
+    pub fn skip(&mut self, count: usize) -> anyhow::Result<()> {
+        if self.remaining() < count {
+            anyhow::bail!(
+                "Cannot skip {count} fields, only {} remaining",
+                self.remaining()
+            );
+        }
+        self.index += count;
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_encode_handshake() {
+        let buf = encode_handshake(151, 151, None).unwrap();
+
+        assert_eq!(buf, b"API\0\0\0\0\x09v151..151".to_vec());
+    }
+
+    #[rstest]
+    fn test_encode_handshake_with_connect_options() {
+        let buf = encode_handshake(100, 151, Some("+PACEAPI")).unwrap();
+
+        assert_eq!(&buf[..4], b"API\0");
+        assert_eq!(&buf[8..], b"v100..151 +PACEAPI");
+        assert_eq!(buf[7] as usize, buf.len() - 8);
+    }
+
+    #[rstest]
+    fn test_encode_fields() {
+        let payload = IbFieldEncoder::new(9)
+            .int(8)
+            .str("AAPL")
+            .float(0.0)
+            .float(101.25)
+            .bool(true)
+            .opt_float(None)
+            .opt_int(Some(-1))
+            .finish();
+
+        assert_eq!(payload, b"9\08\0AAPL\00\0101.25\01\0\0-1\0".to_vec());
+    }
+
+    #[rstest]
+    fn test_decode_fields() {
+        let payload = b"1\0AAPL\0\0101.25\0\0abc\0";
+        let mut decoder = IbFieldDecoder::new(payload).unwrap();
+
+        assert_eq!(decoder.remaining(), 6);
+        assert!(decoder.next_bool().unwrap());
+        assert_eq!(decoder.next_str().unwrap(), "AAPL");
+        assert_eq!(decoder.next_int().unwrap(), 0);
+        assert_eq!(decoder.next_float().unwrap(), 101.25);
+        assert_eq!(decoder.next_float().unwrap(), 0.0);
+        assert!(decoder.next_int().is_err());
+        assert!(decoder.next_str().is_err());
+    }
+
+    #[rstest]
+    fn test_decode_skip() {
+        let mut decoder = IbFieldDecoder::new(b"1\02\03\0").unwrap();
+
+        decoder.skip(2).unwrap();
+
+        assert_eq!(decoder.next_int().unwrap(), 3);
+        assert!(decoder.skip(1).is_err());
+    }
+
+    #[rstest]
+    fn test_framing_round_trip() {
+        let framing = ib_framing();
+        let payload = IbFieldEncoder::new(49).int(1).int(1_700_000_000).finish();
+        let mut buf = framing.encode(&payload).unwrap();
+
+        assert_eq!(framing.decode(&mut buf).unwrap(), Some(payload));
+        assert!(buf.is_empty());
+    }
+}
@@ -0,0 +1,568 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Functions for decoding incoming TWS API messages.
+//!
+//! Messages are decoded with the field layout of [`IB_SERVER_VERSION`]. Messages not used
+//! by the adapter are returned as [`IbIncomingMessage::Other`] without decoding.
+//!
+//! [`IB_SERVER_VERSION`]: crate::common::consts::IB_SERVER_VERSION
+
+use super::{
+    codec::IbFieldDecoder,
+    models::{IbContract, IbContractDetails},
+};
+
+const TICK_PRICE: i64 = 1;
+const TICK_SIZE: i64 = 2;
+const ORDER_STATUS: i64 = 3;
+const ERR_MSG: i64 = 4;
+const NEXT_VALID_ID: i64 = 9;
+const CONTRACT_DATA: i64 = 10;
+const MANAGED_ACCTS: i64 = 15;
+const CURRENT_TIME: i64 = 49;
+const CONTRACT_DATA_END: i64 = 52;
+const TICK_BY_TICK: i64 = 99;
+
+/// Represents a price tick of a top-of-book market data subscription.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IbTickPrice {
+    pub req_id: i64,
+    /// The TWS API tick type ID, see [`IbTickType`](crate::common::enums::IbTickType).
+    pub tick_type: i64,
+    pub price: f64,
+    /// The size at the price, for bid, ask and last ticks.
+    pub size: f64,
+    pub attr_mask: i64,
+}
+
+/// Represents a size tick of a top-of-book market data subscription.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IbTickSize {
+    pub req_id: i64,
+    /// The TWS API tick type ID, see [`IbTickType`](crate::common::enums::IbTickType).
+    pub tick_type: i64,
+    pub size: f64,
+}
+
+/// Represents a tick of a tick-by-tick data subscription.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IbTickByTick {
+    /// A trade, for `Last` and `AllLast` subscriptions.
+    Last {
+        req_id: i64,
+        /// The trade time (UNIX seconds).
+        time: i64,
+        price: f64,
+        size: f64,
+        /// Bit 0 is set for trades past the limit, bit 1 for unreported trades.
+        attr_mask: i64,
+        exchange: String,
+        special_conditions: String,
+    },
+    /// The best bid and ask, for `BidAsk` subscriptions.
+    BidAsk {
+        req_id: i64,
+        /// The quote time (UNIX seconds).
+        time: i64,
+        bid_price: f64,
+        ask_price: f64,
+        bid_size: f64,
+        ask_size: f64,
+        attr_mask: i64,
+    },
+    /// The midpoint of the best bid and ask, for `MidPoint` subscriptions.
+    MidPoint {
+        req_id: i64,
+        /// The quote time (UNIX seconds).
+        time: i64,
+        mid_point: f64,
+    },
+}
+
+impl IbTickByTick {
+    /// Returns the request ID of the subscription.
+    #[must_use]
+    pub const fn req_id(&self) -> i64 {
+        match self {
+            Self::Last { req_id, .. }
+            | Self::BidAsk { req_id, .. }
+            | Self::MidPoint { req_id, .. } => *req_id,
+        }
+    }
+}
+
+/// Represents an update of the status of an order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IbOrderStatusUpdate {
+    pub order_id: i64,
+    /// The status, see [`IbOrderStatus`](crate::common::enums::IbOrderStatus).
+    pub status: String,
+    pub filled: f64,
+    pub remaining: f64,
+    pub avg_fill_price: f64,
+    pub perm_id: i64,
+    pub parent_id: i64,
+    pub last_fill_price: f64,
+    pub client_id: i64,
+    pub why_held: String,
+    pub mkt_cap_price: f64,
+}
+
+/// Represents an error or notice for a request, order or the connection.
+///
+/// The `id` is the request or order ID, or [`IB_NO_VALID_ID`] for notices about the
+/// connection (such as market data farm status).
+///
+/// [`IB_NO_VALID_ID`]: crate::common::consts::IB_NO_VALID_ID
+#[derive(Clone, Debug, PartialEq)]
+pub struct IbErrorMessage {
+    pub id: i64,
+    pub code: i64,
+    pub message: String,
+}
+
+impl IbErrorMessage {
+    /// Returns whether the message is an informational notice rather than an error.
+    ///
+    /// Codes 2100-2169 are warnings and notices, e.g. `2104` (market data farm connection is OK).
+    #[must_use]
+    pub fn is_notice(&self) -> bool {
+        (2100..2170).contains(&self.code)
+    }
+}
+
+/// Represents an incoming TWS API message.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IbIncomingMessage {
+    TickPrice(IbTickPrice),
+    TickSize(IbTickSize),
+    TickByTick(IbTickByTick),
+    OrderStatus(IbOrderStatusUpdate),
+    Error(IbErrorMessage),
+    /// The next valid order ID, sent on connection and in response to an IDs request.
+    NextValidId(i64),
+    ContractData {
+        req_id: i64,
+        details: Box<IbContractDetails>,
+    },
+    /// The end of the contract details for a request.
+    ContractDataEnd(i64),
+    /// The accounts managed by the connected user.
+    ManagedAccounts(Vec<String>),
+    /// The current server time (UNIX seconds).
+    CurrentTime(i64),
+    /// Any message not used by the adapter, with its message ID.
+    Other(i64),
+}
+
+/// Decodes the given message `payload`, without its length prefix.
+///
+/// # Errors
+///
+/// This function returns an error if a used message has missing or invalid fields.
+pub fn decode_message(payload: &[u8]) -> anyhow::Result<IbIncomingMessage> {
+    let mut decoder = IbFieldDecoder::new(payload)?;
+    let msg_id = decoder.next_int()?;
+
+    let msg = match msg_id {
+        TICK_PRICE => {
+            decoder.skip(1)?; // Version
+            IbIncomingMessage::TickPrice(IbTickPrice {
+                req_id: decoder.next_int()?,
+                tick_type: decoder.next_int()?,
+                price: decoder.next_float()?,
+                size: decoder.next_float()?,
+                attr_mask: decoder.next_int()?,
+            })
+        }
+        TICK_SIZE => {
+            decoder.skip(1)?; // Version
+            IbIncomingMessage::TickSize(IbTickSize {
+                req_id: decoder.next_int()?,
+                tick_type: decoder.next_int()?,
+                size: decoder.next_float()?,
+            })
+        }
+        TICK_BY_TICK => IbIncomingMessage::TickByTick(decode_tick_by_tick(&mut decoder)?),
+        ORDER_STATUS => IbIncomingMessage::OrderStatus(IbOrderStatusUpdate {
+            order_id: decoder.next_int()?,
+            status: decoder.next_string()?,
+            filled: decoder.next_float()?,
+            remaining: decoder.next_float()?,
+            avg_fill_price: decoder.next_float()?,
+            perm_id: decoder.next_int()?,
+            parent_id: decoder.next_int()?,
+            last_fill_price: decoder.next_float()?,
+            client_id: decoder.next_int()?,
+            why_held: decoder.next_string()?,
+            mkt_cap_price: decoder.next_float()?,
+        }),
+        ERR_MSG => {
+            decoder.skip(1)?; // Version
+            IbIncomingMessage::Error(IbErrorMessage {
+                id: decoder.next_int()?,
+                code: decoder.next_int()?,
+                message: decoder.next_string()?,
+            })
+        }
+        NEXT_VALID_ID => {
+            decoder.skip(1)?; // Version
+            IbIncomingMessage::NextValidId(decoder.next_int()?)
+        }
+        CONTRACT_DATA => {
+            let (req_id, details) = decode_contract_details(&mut decoder)?;
+            IbIncomingMessage::ContractData {
+                req_id,
+                details: Box::new(details),
+            }
+        }
+        CONTRACT_DATA_END => {
+            decoder.skip(1)?; // Version
+            IbIncomingMessage::ContractDataEnd(decoder.next_int()?)
+        }
+        MANAGED_ACCTS => {
+            decoder.skip(1)?; // Version
+            let accounts = decoder
+                .next_str()?
+                .split(',')
+                .filter(|account| !account.is_empty())
+                .map(ToString::to_string)
+                .collect();
+            IbIncomingMessage::ManagedAccounts(accounts)
+        }
+        CURRENT_TIME => {
+            decoder.skip(1)?; // Version
+            IbIncomingMessage::CurrentTime(decoder.next_int()?)
+        }
+        _ => IbIncomingMessage::Other(msg_id),
+    };
+    Ok(msg)
+}
+
+fn decode_tick_by_tick(decoder: &mut IbFieldDecoder) -> anyhow::Result<IbTickByTick> {
+    let req_id = decoder.next_int()?;
+    let tick_type = decoder.next_int()?;
+    let time = decoder.next_int()?;
+
+    let tick = match tick_type {
+        1 | 2 => IbTickByTick::Last {
+            req_id,
+            time,
+            price: decoder.next_float()?,
+            size: decoder.next_float()?,
+            attr_mask: decoder.next_int()?,
+            exchange: decoder.next_string()?,
+            special_conditions: decoder.next_string()?,
+        },
+        3 => IbTickByTick::BidAsk {
+            req_id,
+            time,
+            bid_price: decoder.next_float()?,
+            ask_price: decoder.next_float()?,
+            bid_size: decoder.next_float()?,
+            ask_size: decoder.next_float()?,
+            attr_mask: decoder.next_int()?,
+        },
+        4 => IbTickByTick::MidPoint {
+            req_id,
+            time,
+            mid_point: decoder.next_float()?,
+        },
+        _ => anyhow::bail!("Invalid tick-by-tick type {tick_type}"),
+    };
+    Ok(tick)
+}
+
+fn decode_contract_details(
+    decoder: &mut IbFieldDecoder,
+) -> anyhow::Result<(i64, IbContractDetails)> {
+    let version = decoder.next_int()?;
+    let req_id = if version >= 3 {
+        decoder.next_int()?
+    } else {
+        -1
+    };
+
+    let mut contract = IbContract {
+        symbol: decoder.next_string()?,
+        sec_type: decoder.next_string()?,
+        ..Default::default()
+    };
+    // May be followed by the last trade time and time zone, e.g. `20250321 08:30 US/Central`
+    contract.last_trade_date_or_contract_month = decoder
+        .next_str()?
+        .split([' ', '-'])
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    contract.strike = decoder.next_float()?;
+    contract.right = decoder.next_string()?;
+    contract.exchange = decoder.next_string()?;
+    contract.currency = decoder.next_string()?;
+    contract.local_symbol = decoder.next_string()?;
+
+    let mut details = IbContractDetails {
+        market_name: decoder.next_string()?,
+        ..Default::default()
+    };
+    contract.trading_class = decoder.next_string()?;
+    contract.con_id = decoder.next_int()?;
+    details.min_tick = decoder.next_float()?;
+    details.md_size_multiplier = decoder.next_int()?;
+    contract.multiplier = decoder.next_string()?;
+    details.order_types = decoder.next_string()?;
+    details.valid_exchanges = decoder.next_string()?;
+    details.price_magnifier = decoder.next_int()?;
+    if version >= 4 {
+        details.under_con_id = decoder.next_int()?;
+    }
+    if version >= 5 {
+        details.long_name = decoder.next_string()?;
+        contract.primary_exchange = decoder.next_string()?;
+    }
+    if version >= 6 {
+        details.contract_month = decoder.next_string()?;
+        details.industry = decoder.next_string()?;
+        details.category = decoder.next_string()?;
+        details.subcategory = decoder.next_string()?;
+        details.time_zone_id = decoder.next_string()?;
+        details.trading_hours = decoder.next_string()?;
+        details.liquid_hours = decoder.next_string()?;
+    }
+    if version >= 8 {
+        details.ev_rule = decoder.next_string()?;
+        details.ev_multiplier = decoder.next_float()?;
+    }
+    if version >= 7 {
+        let count = decoder.next_int()?;
+        for _ in 0..count {
+            let tag = decoder.next_string()?;
+            let value = decoder.next_string()?;
+            details.sec_id_list.push((tag, value));
+        }
+    }
+    details.agg_group = decoder.next_int()?;
+    details.under_symbol = decoder.next_string()?;
+    details.under_sec_type = decoder.next_string()?;
+    details.market_rule_ids = decoder.next_string()?;
+    details.real_expiration_date = decoder.next_string()?;
+
+    details.contract = contract;
+    Ok((req_id, details))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::tests::{payload, AAPL_CONTRACT_DATA, ES_CONTRACT_DATA};
+
+    #[rstest]
+    fn test_decode_tick_price() {
+        let msg = decode_message(&payload(&["1", "6", "5", "1", "150.25", "300", "1"])).unwrap();
+
+        assert_eq!(
+            msg,
+            IbIncomingMessage::TickPrice(IbTickPrice {
+                req_id: 5,
+                tick_type: 1,
+                price: 150.25,
+                size: 300.0,
+                attr_mask: 1,
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_decode_tick_size() {
+        let msg = decode_message(&payload(&["2", "6", "5", "3", "200"])).unwrap();
+
+        assert_eq!(
+            msg,
+            IbIncomingMessage::TickSize(IbTickSize {
+                req_id: 5,
+                tick_type: 3,
+                size: 200.0,
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_decode_tick_by_tick_last() {
+        let msg = decode_message(&payload(&[
+            "99",
+            "4",
+            "2",
+            "1736267400",
+            "5885.25",
+            "3",
+            "0",
+            "CME",
+            "",
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            msg,
+            IbIncomingMessage::TickByTick(IbTickByTick::Last {
+                req_id: 4,
+                time: 1_736_267_400,
+                price: 5885.25,
+                size: 3.0,
+                attr_mask: 0,
+                exchange: "CME".to_string(),
+                special_conditions: String::new(),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_decode_tick_by_tick_bid_ask() {
+        let msg = decode_message(&payload(&[
+            "99",
+            "4",
+            "3",
+            "1736267400",
+            "5885.00",
+            "5885.25",
+            "12",
+            "9",
+            "0",
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            msg,
+            IbIncomingMessage::TickByTick(IbTickByTick::BidAsk {
+                req_id: 4,
+                time: 1_736_267_400,
+                bid_price: 5885.0,
+                ask_price: 5885.25,
+                bid_size: 12.0,
+                ask_size: 9.0,
+                attr_mask: 0,
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_decode_order_status() {
+        let msg = decode_message(&payload(&[
+            "3", "42", "Filled", "100", "0", "150.01", "1234567", "0", "150.01", "7", "", "0",
+        ]))
+        .unwrap();
+
+        let IbIncomingMessage::OrderStatus(update) = msg else {
+            panic!("Expected order status, was {msg:?}");
+        };
+        assert_eq!(update.order_id, 42);
+        assert_eq!(update.status, "Filled");
+        assert_eq!(update.filled, 100.0);
+        assert_eq!(update.remaining, 0.0);
+        assert_eq!(update.avg_fill_price, 150.01);
+        assert_eq!(update.client_id, 7);
+    }
+
+    #[rstest]
+    fn test_decode_error_message() {
+        let msg = decode_message(&payload(&[
+            "4",
+            "2",
+            "-1",
+            "2104",
+            "Market data farm connection is OK:usfarm",
+        ]))
+        .unwrap();
+
+        let IbIncomingMessage::Error(error) = msg else {
+            panic!("Expected error, was {msg:?}");
+        };
+        assert_eq!(error.id, -1);
+        assert_eq!(error.code, 2104);
+        assert!(error.is_notice());
+    }
+
+    #[rstest]
+    fn test_decode_session_messages() {
+        assert_eq!(
+            decode_message(&payload(&["9", "1", "100"])).unwrap(),
+            IbIncomingMessage::NextValidId(100)
+        );
+        assert_eq!(
+            decode_message(&payload(&["15", "1", "DU123456,DU654321"])).unwrap(),
+            IbIncomingMessage::ManagedAccounts(vec![
+                "DU123456".to_string(),
+                "DU654321".to_string()
+            ])
+        );
+        assert_eq!(
+            decode_message(&payload(&["52", "1", "3"])).unwrap(),
+            IbIncomingMessage::ContractDataEnd(3)
+        );
+        assert_eq!(
+            decode_message(&payload(&["5", "1", "2", "3"])).unwrap(),
+            IbIncomingMessage::Other(5)
+        );
+    }
+
+    #[rstest]
+    fn test_decode_stock_contract_details() {
+        let msg = decode_message(&payload(AAPL_CONTRACT_DATA)).unwrap();
+
+        let IbIncomingMessage::ContractData { req_id, details } = msg else {
+            panic!("Expected contract data, was {msg:?}");
+        };
+        assert_eq!(req_id, 3);
+        assert_eq!(details.contract.con_id, 265_598);
+        assert_eq!(details.contract.symbol, "AAPL");
+        assert_eq!(details.contract.sec_type, "STK");
+        assert_eq!(details.contract.primary_exchange, "NASDAQ");
+        assert_eq!(details.min_tick, 0.01);
+        assert_eq!(details.long_name, "APPLE INC");
+        assert_eq!(
+            details.sec_id_list,
+            vec![("ISIN".to_string(), "US0378331005".to_string())]
+        );
+        assert_eq!(details.market_rule_ids, "26,26,26");
+    }
+
+    #[rstest]
+    fn test_decode_future_contract_details() {
+        let msg = decode_message(&payload(ES_CONTRACT_DATA)).unwrap();
+
+        let IbIncomingMessage::ContractData { req_id, details } = msg else {
+            panic!("Expected contract data, was {msg:?}");
+        };
+        assert_eq!(req_id, 4);
+        assert_eq!(
+            details.contract.last_trade_date_or_contract_month,
+            "20250321"
+        );
+        assert_eq!(details.contract.local_symbol, "ESH5");
+        assert_eq!(details.contract.multiplier, "50");
+        assert_eq!(details.under_sec_type, "IND");
+        assert_eq!(details.real_expiration_date, "20250321");
+    }
+
+    #[rstest]
+    fn test_decode_truncated_message() {
+        assert!(decode_message(&payload(&["1", "6", "5"])).is_err());
+    }
+}
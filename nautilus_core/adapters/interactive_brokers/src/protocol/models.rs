@@ -0,0 +1,143 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Data structures of the TWS API for contracts and orders.
+
+use crate::common::enums::{IbOrderAction, IbOrderType, IbSecType, IbTimeInForce};
+
+/// Represents an Interactive Brokers contract.
+///
+/// Requests identify a contract either by its contract ID (`con_id`), or by the combination
+/// of its symbol, security type, exchange and currency (plus expiry for derivatives).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IbContract {
+    pub con_id: i64,
+    pub symbol: String,
+    pub sec_type: String,
+    /// The expiry as `YYYYMMDD`, or the contract month as `YYYYMM`.
+    pub last_trade_date_or_contract_month: String,
+    pub strike: f64,
+    pub right: String,
+    pub multiplier: String,
+    pub exchange: String,
+    pub primary_exchange: String,
+    pub currency: String,
+    pub local_symbol: String,
+    pub trading_class: String,
+    pub include_expired: bool,
+    pub sec_id_type: String,
+    pub sec_id: String,
+}
+
+impl IbContract {
+    /// Creates a new stock [`IbContract`] routed via the given `exchange` (e.g. `SMART`).
+    #[must_use]
+    pub fn stock(symbol: &str, exchange: &str, currency: &str) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            sec_type: IbSecType::Stk.to_string(),
+            exchange: exchange.to_string(),
+            currency: currency.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new futures [`IbContract`] for the given contract month or expiry.
+    #[must_use]
+    pub fn future(
+        symbol: &str,
+        last_trade_date_or_contract_month: &str,
+        exchange: &str,
+        currency: &str,
+    ) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            sec_type: IbSecType::Fut.to_string(),
+            last_trade_date_or_contract_month: last_trade_date_or_contract_month.to_string(),
+            exchange: exchange.to_string(),
+            currency: currency.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new [`IbContract`] identified by its contract ID, routed via `exchange`.
+    #[must_use]
+    pub fn from_con_id(con_id: i64, exchange: &str) -> Self {
+        Self {
+            con_id,
+            exchange: exchange.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Represents the details of an Interactive Brokers contract, as returned by a contract
+/// details request.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IbContractDetails {
+    pub contract: IbContract,
+    pub market_name: String,
+    pub min_tick: f64,
+    pub md_size_multiplier: i64,
+    /// The comma-separated order types supported for the contract.
+    pub order_types: String,
+    /// The comma-separated exchanges the contract can be routed to.
+    pub valid_exchanges: String,
+    pub price_magnifier: i64,
+    pub under_con_id: i64,
+    pub long_name: String,
+    pub contract_month: String,
+    pub industry: String,
+    pub category: String,
+    pub subcategory: String,
+    pub time_zone_id: String,
+    pub trading_hours: String,
+    pub liquid_hours: String,
+    pub ev_rule: String,
+    pub ev_multiplier: f64,
+    /// Security identifiers as `(type, value)` pairs, e.g. `("ISIN", "US0378331005")`.
+    pub sec_id_list: Vec<(String, String)>,
+    pub agg_group: i64,
+    pub under_symbol: String,
+    pub under_sec_type: String,
+    pub market_rule_ids: String,
+    /// The actual expiry as `YYYYMMDD`, for derivatives.
+    pub real_expiration_date: String,
+}
+
+/// Represents an Interactive Brokers order.
+///
+/// Only the fields used by the adapter are represented, all other order fields are sent
+/// with their default (unset) values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IbOrder {
+    pub action: IbOrderAction,
+    pub total_quantity: f64,
+    pub order_type: IbOrderType,
+    pub lmt_price: Option<f64>,
+    /// The stop or trigger price, or the trailing amount for trailing stops.
+    pub aux_price: Option<f64>,
+    pub tif: IbTimeInForce,
+    /// The expire time for `GTD` orders, as `YYYYMMDD-HH:MM:SS` in UTC.
+    pub good_till_date: String,
+    pub account: String,
+    /// The reference of the order, used to carry the Nautilus client order ID.
+    pub order_ref: String,
+    pub outside_rth: bool,
+    pub trail_stop_price: Option<f64>,
+    /// The trailing amount as a percentage, instead of `aux_price`.
+    pub trailing_percent: Option<f64>,
+    pub transmit: bool,
+}
@@ -0,0 +1,409 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Functions for encoding outgoing TWS API requests.
+//!
+//! Messages are encoded with the field layout of [`IB_SERVER_VERSION`], and returned
+//! without their length prefix.
+//!
+//! [`IB_SERVER_VERSION`]: crate::common::consts::IB_SERVER_VERSION
+
+use super::{
+    codec::IbFieldEncoder,
+    models::{IbContract, IbOrder},
+};
+use crate::common::enums::{IbMarketDataType, IbTickByTickType};
+
+const REQ_MKT_DATA: i64 = 1;
+const CANCEL_MKT_DATA: i64 = 2;
+const PLACE_ORDER: i64 = 3;
+const CANCEL_ORDER: i64 = 4;
+const REQ_IDS: i64 = 8;
+const REQ_CONTRACT_DATA: i64 = 9;
+const REQ_MARKET_DATA_TYPE: i64 = 59;
+const START_API: i64 = 71;
+const REQ_TICK_BY_TICK_DATA: i64 = 97;
+const CANCEL_TICK_BY_TICK_DATA: i64 = 98;
+
+/// Encodes the request which starts the API session for `client_id`, after the handshake.
+#[must_use]
+pub fn encode_start_api(client_id: i64, optional_capabilities: &str) -> Vec<u8> {
+    IbFieldEncoder::new(START_API)
+        .int(2)
+        .int(client_id)
+        .str(optional_capabilities)
+        .finish()
+}
+
+/// Encodes a request for the next valid order ID.
+#[must_use]
+pub fn encode_req_ids() -> Vec<u8> {
+    IbFieldEncoder::new(REQ_IDS).int(1).int(1).finish()
+}
+
+/// Encodes a request for the details of all contracts matching `contract`.
+#[must_use]
+pub fn encode_req_contract_details(req_id: i64, contract: &IbContract) -> Vec<u8> {
+    IbFieldEncoder::new(REQ_CONTRACT_DATA)
+        .int(8)
+        .int(req_id)
+        .int(contract.con_id)
+        .str(&contract.symbol)
+        .str(&contract.sec_type)
+        .str(&contract.last_trade_date_or_contract_month)
+        .float(contract.strike)
+        .str(&contract.right)
+        .str(&contract.multiplier)
+        .str(&contract.exchange)
+        .str(&contract.primary_exchange)
+        .str(&contract.currency)
+        .str(&contract.local_symbol)
+        .str(&contract.trading_class)
+        .bool(contract.include_expired)
+        .str(&contract.sec_id_type)
+        .str(&contract.sec_id)
+        .finish()
+}
+
+/// Encodes a request for streaming top-of-book market data for `contract`.
+///
+/// The `generic_tick_list` requests additional tick types (comma-separated IDs).
+#[must_use]
+pub fn encode_req_mkt_data(
+    req_id: i64,
+    contract: &IbContract,
+    generic_tick_list: &str,
+    snapshot: bool,
+) -> Vec<u8> {
+    IbFieldEncoder::new(REQ_MKT_DATA)
+        .int(11)
+        .int(req_id)
+        .int(contract.con_id)
+        .str(&contract.symbol)
+        .str(&contract.sec_type)
+        .str(&contract.last_trade_date_or_contract_month)
+        .float(contract.strike)
+        .str(&contract.right)
+        .str(&contract.multiplier)
+        .str(&contract.exchange)
+        .str(&contract.primary_exchange)
+        .str(&contract.currency)
+        .str(&contract.local_symbol)
+        .str(&contract.trading_class)
+        .bool(false) // No delta neutral contract
+        .str(generic_tick_list)
+        .bool(snapshot)
+        .bool(false) // No regulatory snapshot
+        .str("") // No market data options
+        .finish()
+}
+
+/// Encodes a request to cancel the market data subscription for `req_id`.
+#[must_use]
+pub fn encode_cancel_mkt_data(req_id: i64) -> Vec<u8> {
+    IbFieldEncoder::new(CANCEL_MKT_DATA)
+        .int(2)
+        .int(req_id)
+        .finish()
+}
+
+/// Encodes a request to switch the type of subsequent top-of-book market data.
+#[must_use]
+pub fn encode_req_market_data_type(market_data_type: IbMarketDataType) -> Vec<u8> {
+    IbFieldEncoder::new(REQ_MARKET_DATA_TYPE)
+        .int(1)
+        .int(market_data_type as i64)
+        .finish()
+}
+
+/// Encodes a request for streaming tick-by-tick data of `tick_type` for `contract`.
+#[must_use]
+pub fn encode_req_tick_by_tick_data(
+    req_id: i64,
+    contract: &IbContract,
+    tick_type: IbTickByTickType,
+    number_of_ticks: i64,
+    ignore_size: bool,
+) -> Vec<u8> {
+    IbFieldEncoder::new(REQ_TICK_BY_TICK_DATA)
+        .int(req_id)
+        .int(contract.con_id)
+        .str(&contract.symbol)
+        .str(&contract.sec_type)
+        .str(&contract.last_trade_date_or_contract_month)
+        .float(contract.strike)
+        .str(&contract.right)
+        .str(&contract.multiplier)
+        .str(&contract.exchange)
+        .str(&contract.primary_exchange)
+        .str(&contract.currency)
+        .str(&contract.local_symbol)
+        .str(&contract.trading_class)
+        .str(tick_type.as_ref())
+        .int(number_of_ticks)
+        .bool(ignore_size)
+        .finish()
+}
+
+/// Encodes a request to cancel the tick-by-tick data subscription for `req_id`.
+#[must_use]
+pub fn encode_cancel_tick_by_tick_data(req_id: i64) -> Vec<u8> {
+    IbFieldEncoder::new(CANCEL_TICK_BY_TICK_DATA)
+        .int(req_id)
+        .finish()
+}
+
+/// Encodes a request to place (or modify) the order with `order_id` for `contract`.
+#[must_use]
+pub fn encode_place_order(order_id: i64, contract: &IbContract, order: &IbOrder) -> Vec<u8> {
+    IbFieldEncoder::new(PLACE_ORDER)
+        .int(order_id)
+        // Contract
+        .int(contract.con_id)
+        .str(&contract.symbol)
+        .str(&contract.sec_type)
+        .str(&contract.last_trade_date_or_contract_month)
+        .float(contract.strike)
+        .str(&contract.right)
+        .str(&contract.multiplier)
+        .str(&contract.exchange)
+        .str(&contract.primary_exchange)
+        .str(&contract.currency)
+        .str(&contract.local_symbol)
+        .str(&contract.trading_class)
+        .str(&contract.sec_id_type)
+        .str(&contract.sec_id)
+        // Main order fields
+        .str(order.action.as_ref())
+        .float(order.total_quantity)
+        .str(order.order_type.as_ref())
+        .opt_float(order.lmt_price)
+        .opt_float(order.aux_price)
+        // Extended order fields
+        .str(order.tif.as_ref())
+        .str("") // OCA group
+        .str(&order.account)
+        .str("O") // Open/close
+        .int(0) // Origin (customer)
+        .str(&order.order_ref)
+        .bool(order.transmit)
+        .int(0) // Parent ID
+        .bool(false) // Block order
+        .bool(false) // Sweep to fill
+        .int(0) // Display size
+        .int(0) // Trigger method
+        .bool(order.outside_rth)
+        .bool(false) // Hidden
+        .str("") // Deprecated shares allocation
+        .float(0.0) // Discretionary amount
+        .str("") // Good after time
+        .str(&order.good_till_date)
+        .str("") // FA group
+        .str("") // FA method
+        .str("") // FA percentage
+        .str("") // FA profile
+        .str("") // Model code
+        .int(0) // Short sale slot
+        .str("") // Designated location
+        .int(-1) // Exempt code
+        .int(0) // OCA type
+        .str("") // Rule 80A
+        .str("") // Settling firm
+        .bool(false) // All or none
+        .opt_int(None) // Minimum quantity
+        .opt_float(None) // Percent offset
+        .bool(false) // eTrade only
+        .bool(false) // Firm quote only
+        .opt_float(None) // NBBO price cap
+        .int(0) // Auction strategy
+        .opt_float(None) // Starting price
+        .opt_float(None) // Stock reference price
+        .opt_float(None) // Delta
+        .opt_float(None) // Stock range lower
+        .opt_float(None) // Stock range upper
+        .bool(false) // Override percentage constraints
+        // Volatility orders
+        .opt_float(None) // Volatility
+        .opt_int(None) // Volatility type
+        .str("") // Delta neutral order type
+        .opt_float(None) // Delta neutral aux price
+        .bool(false) // Continuous update
+        .opt_int(None) // Reference price type
+        // Trailing stops
+        .opt_float(order.trail_stop_price)
+        .opt_float(order.trailing_percent)
+        // Scale orders
+        .opt_int(None) // Scale initial level size
+        .opt_int(None) // Scale subsequent level size
+        .opt_float(None) // Scale price increment
+        .str("") // Scale table
+        .str("") // Active start time
+        .str("") // Active stop time
+        .str("") // Hedge type
+        .bool(false) // Opt out of SMART routing
+        .str("") // Clearing account
+        .str("") // Clearing intent
+        .bool(false) // Not held
+        .bool(false) // No delta neutral contract
+        .str("") // Algo strategy
+        .str("") // Algo ID
+        .bool(false) // What-if
+        .str("") // Misc options
+        .bool(false) // Solicited
+        .bool(false) // Randomize size
+        .bool(false) // Randomize price
+        .int(0) // Conditions count
+        .str("") // Adjusted order type
+        .opt_float(None) // Trigger price
+        .opt_float(None) // Limit price offset
+        .opt_float(None) // Adjusted stop price
+        .opt_float(None) // Adjusted stop limit price
+        .opt_float(None) // Adjusted trailing amount
+        .int(0) // Adjustable trailing unit
+        .str("") // Ext operator
+        .str("") // Soft dollar tier name
+        .str("") // Soft dollar tier value
+        .opt_float(None) // Cash quantity
+        .str("") // MiFID II decision maker
+        .str("") // MiFID II decision algo
+        .str("") // MiFID II execution trader
+        .str("") // MiFID II execution algo
+        .bool(false) // Don't use auto price for hedge
+        .bool(false) // Is OMS container
+        .bool(false) // Discretionary up to limit price
+        .str("") // Use price management algo (unset)
+        .finish()
+}
+
+/// Encodes a request to cancel the order with `order_id`.
+#[must_use]
+pub fn encode_cancel_order(order_id: i64) -> Vec<u8> {
+    IbFieldEncoder::new(CANCEL_ORDER)
+        .int(1)
+        .int(order_id)
+        .finish()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        common::enums::{IbOrderAction, IbOrderType, IbTimeInForce},
+        protocol::codec::IbFieldDecoder,
+    };
+
+    fn fields(payload: &[u8]) -> Vec<String> {
+        let mut decoder = IbFieldDecoder::new(payload).unwrap();
+        let mut fields = Vec::new();
+        while decoder.remaining() > 0 {
+            fields.push(decoder.next_string().unwrap());
+        }
+        fields
+    }
+
+    #[rstest]
+    fn test_encode_start_api() {
+        assert_eq!(fields(&encode_start_api(7, "")), ["71", "2", "7", ""]);
+    }
+
+    #[rstest]
+    fn test_encode_req_contract_details() {
+        let contract = IbContract::stock("AAPL", "SMART", "USD");
+
+        let fields = fields(&encode_req_contract_details(3, &contract));
+
+        assert_eq!(
+            fields,
+            [
+                "9", "8", "3", "0", "AAPL", "STK", "", "0", "", "", "SMART", "", "USD", "", "",
+                "0", "", ""
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_encode_req_mkt_data() {
+        let contract = IbContract::from_con_id(265_598, "SMART");
+
+        let fields = fields(&encode_req_mkt_data(5, &contract, "", false));
+
+        assert_eq!(fields.len(), 20);
+        assert_eq!(&fields[..4], ["1", "11", "5", "265598"]);
+        assert_eq!(fields[10], "SMART");
+        assert_eq!(&fields[15..], ["0", "", "0", "0", ""]);
+    }
+
+    #[rstest]
+    fn test_encode_req_tick_by_tick_data() {
+        let contract = IbContract::future("ES", "202503", "CME", "USD");
+
+        let fields = fields(&encode_req_tick_by_tick_data(
+            4,
+            &contract,
+            IbTickByTickType::BidAsk,
+            0,
+            false,
+        ));
+
+        assert_eq!(fields.len(), 17);
+        assert_eq!(&fields[..6], ["97", "4", "0", "ES", "FUT", "202503"]);
+        assert_eq!(&fields[14..], ["BidAsk", "0", "0"]);
+    }
+
+    #[rstest]
+    fn test_encode_place_order() {
+        let contract = IbContract::stock("AAPL", "SMART", "USD");
+        let order = IbOrder {
+            action: IbOrderAction::Buy,
+            total_quantity: 100.0,
+            order_type: IbOrderType::StopLimit,
+            lmt_price: Some(150.5),
+            aux_price: Some(150.0),
+            tif: IbTimeInForce::Gtd,
+            good_till_date: "20250131-21:00:00".to_string(),
+            account: "DU123456".to_string(),
+            order_ref: "O-123".to_string(),
+            outside_rth: false,
+            trail_stop_price: None,
+            trailing_percent: None,
+            transmit: true,
+        };
+
+        let fields = fields(&encode_place_order(42, &contract, &order));
+
+        assert_eq!(fields.len(), 110);
+        assert_eq!(&fields[..3], ["3", "42", "0"]);
+        assert_eq!(&fields[16..21], ["BUY", "100", "STP LMT", "150.5", "150"]);
+        assert_eq!(
+            &fields[21..28],
+            ["GTD", "", "DU123456", "O", "0", "O-123", "1"]
+        );
+        assert_eq!(fields[38], "20250131-21:00:00");
+        assert_eq!(fields[109], "");
+    }
+
+    #[rstest]
+    fn test_encode_cancel_requests() {
+        assert_eq!(fields(&encode_cancel_order(42)), ["4", "1", "42"]);
+        assert_eq!(fields(&encode_cancel_mkt_data(5)), ["2", "2", "5"]);
+        assert_eq!(fields(&encode_cancel_tick_by_tick_data(4)), ["98", "4"]);
+        assert_eq!(fields(&encode_req_ids()), ["8", "1", "1"]);
+    }
+}
@@ -0,0 +1,111 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Raw TWS API messages for tests, as lists of fields.
+
+/// Encodes the given `fields` as a message payload.
+#[must_use]
+pub fn payload(fields: &[&str]) -> Vec<u8> {
+    fields
+        .iter()
+        .flat_map(|field| format!("{field}\0").into_bytes())
+        .collect()
+}
+
+/// The contract details of the AAPL stock, for request ID 3.
+pub const AAPL_CONTRACT_DATA: &[&str] = &[
+    "10",
+    "8",
+    "3",
+    "AAPL",
+    "STK",
+    "",
+    "0",
+    "",
+    "SMART",
+    "USD",
+    "AAPL",
+    "NMS",
+    "NMS",
+    "265598",
+    "0.01",
+    "100",
+    "",
+    "ACTIVETIM,AD,ALERT,ALLOC,LMT,MKT,STP,STPLMT,TRAIL",
+    "SMART,AMEX,NYSE,ISLAND,ARCA",
+    "1",
+    "0",
+    "APPLE INC",
+    "NASDAQ",
+    "",
+    "Technology",
+    "Computers",
+    "Computers",
+    "US/Eastern",
+    "20250103:0400-20250103:2000",
+    "20250103:0930-20250103:1600",
+    "",
+    "",
+    "1",
+    "ISIN",
+    "US0378331005",
+    "1",
+    "",
+    "",
+    "26,26,26",
+    "",
+];
+
+/// The contract details of the ES March 2025 future, for request ID 4.
+pub const ES_CONTRACT_DATA: &[&str] = &[
+    "10",
+    "8",
+    "4",
+    "ES",
+    "FUT",
+    "20250321 08:30 US/Central",
+    "0",
+    "",
+    "CME",
+    "USD",
+    "ESH5",
+    "ES",
+    "ES",
+    "620731015",
+    "0.25",
+    "1",
+    "50",
+    "LMT,MKT,STP,STPLMT,TRAIL",
+    "CME,QBALGO",
+    "1",
+    "11004968",
+    "E-mini S&P 500",
+    "",
+    "202503",
+    "",
+    "",
+    "",
+    "US/Central",
+    "",
+    "",
+    "",
+    "",
+    "0",
+    "2",
+    "ES",
+    "IND",
+    "67,67",
+    "20250321",
+];
@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-mod record;
+pub(crate) mod record;
 
 use std::{error::Error, fs::File, io::BufReader, path::Path};
 
@@ -31,9 +31,10 @@ use nautilus_model::{
 
 use super::{
     csv::record::{
-        TardisBookUpdateRecord, TardisOrderBookSnapshot25Record, TardisOrderBookSnapshot5Record,
-        TardisQuoteRecord, TardisTradeRecord,
+        TardisBookUpdateRecord, TardisDerivativeTickerRecord, TardisOrderBookSnapshot25Record,
+        TardisOrderBookSnapshot5Record, TardisQuoteRecord, TardisTradeRecord,
     },
+    data::TardisDerivativeTicker,
     parse::{
         parse_aggressor_side, parse_book_action, parse_instrument_id, parse_order_side,
         parse_timestamp,
@@ -78,17 +79,9 @@ pub fn load_deltas<P: AsRef<Path>>(
             Some(id) => *id,
             None => parse_instrument_id(&record.exchange, record.symbol),
         };
-        let side = parse_order_side(&record.side);
-        let price = Price::new(record.price, price_precision);
-        let size = Quantity::new(record.amount, size_precision);
-        let order_id = 0; // Not applicable for L2 data
-        let order = BookOrder::new(side, price, size, order_id);
-
-        let action = parse_book_action(record.is_snapshot, record.amount);
-        let flags = 0; // Flags always zero until timestamp changes
-        let sequence = 0; // Sequence not available
-        let ts_event = parse_timestamp(record.timestamp);
-        let ts_init = parse_timestamp(record.local_timestamp);
+        let delta =
+            parse_book_update_record(&record, price_precision, size_precision, instrument_id);
+        let ts_event = delta.ts_event;
 
         // Check if timestamp is different from last timestamp
         if last_ts_event != ts_event {
@@ -100,16 +93,6 @@ pub fn load_deltas<P: AsRef<Path>>(
 
         last_ts_event = ts_event;
 
-        let delta = OrderBookDelta::new(
-            instrument_id,
-            action,
-            order,
-            flags,
-            sequence,
-            ts_event,
-            ts_init,
-        );
-
         deltas.push(delta);
 
         if let Some(limit) = limit {
@@ -127,6 +110,35 @@ pub fn load_deltas<P: AsRef<Path>>(
     Ok(deltas)
 }
 
+pub(crate) fn parse_book_update_record(
+    record: &TardisBookUpdateRecord,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: InstrumentId,
+) -> OrderBookDelta {
+    let side = parse_order_side(&record.side);
+    let price = Price::new(record.price, price_precision);
+    let size = Quantity::new(record.amount, size_precision);
+    let order_id = 0; // Not applicable for L2 data
+    let order = BookOrder::new(side, price, size, order_id);
+
+    let action = parse_book_action(record.is_snapshot, record.amount);
+    let flags = 0; // Flags always zero until timestamp changes
+    let sequence = 0; // Sequence not available
+    let ts_event = parse_timestamp(record.timestamp);
+    let ts_init = parse_timestamp(record.local_timestamp);
+
+    OrderBookDelta::new(
+        instrument_id,
+        action,
+        order,
+        flags,
+        sequence,
+        ts_event,
+        ts_init,
+    )
+}
+
 fn create_book_order(
     side: OrderSide,
     price: Option<f64>,
@@ -398,22 +410,7 @@ pub fn load_quote_ticks<P: AsRef<Path>>(
             Some(id) => *id,
             None => parse_instrument_id(&record.exchange, record.symbol),
         };
-        let bid_price = Price::new(record.bid_price.unwrap_or(0.0), price_precision);
-        let bid_size = Quantity::new(record.bid_amount.unwrap_or(0.0), size_precision);
-        let ask_price = Price::new(record.ask_price.unwrap_or(0.0), price_precision);
-        let ask_size = Quantity::new(record.ask_amount.unwrap_or(0.0), size_precision);
-        let ts_event = parse_timestamp(record.timestamp);
-        let ts_init = parse_timestamp(record.local_timestamp);
-
-        let quote = QuoteTick::new(
-            instrument_id,
-            bid_price,
-            ask_price,
-            bid_size,
-            ask_size,
-            ts_event,
-            ts_init,
-        );
+        let quote = parse_quote_record(&record, price_precision, size_precision, instrument_id);
 
         quotes.push(quote);
 
@@ -427,6 +424,30 @@ pub fn load_quote_ticks<P: AsRef<Path>>(
     Ok(quotes)
 }
 
+pub(crate) fn parse_quote_record(
+    record: &TardisQuoteRecord,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: InstrumentId,
+) -> QuoteTick {
+    let bid_price = Price::new(record.bid_price.unwrap_or(0.0), price_precision);
+    let bid_size = Quantity::new(record.bid_amount.unwrap_or(0.0), size_precision);
+    let ask_price = Price::new(record.ask_price.unwrap_or(0.0), price_precision);
+    let ask_size = Quantity::new(record.ask_amount.unwrap_or(0.0), size_precision);
+    let ts_event = parse_timestamp(record.timestamp);
+    let ts_init = parse_timestamp(record.local_timestamp);
+
+    QuoteTick::new(
+        instrument_id,
+        bid_price,
+        ask_price,
+        bid_size,
+        ask_size,
+        ts_event,
+        ts_init,
+    )
+}
+
 /// Load [`TradeTick`]s from a Tardis format CSV at the given `filepath`.
 pub fn load_trade_ticks<P: AsRef<Path>>(
     filepath: P,
@@ -446,22 +467,7 @@ pub fn load_trade_ticks<P: AsRef<Path>>(
             Some(id) => *id,
             None => parse_instrument_id(&record.exchange, record.symbol),
         };
-        let price = Price::new(record.price, price_precision);
-        let size = Quantity::new(record.amount, size_precision);
-        let aggressor_side = parse_aggressor_side(&record.side);
-        let trade_id = TradeId::new(&record.id);
-        let ts_event = parse_timestamp(record.timestamp);
-        let ts_init = parse_timestamp(record.local_timestamp);
-
-        let trade = TradeTick::new(
-            instrument_id,
-            price,
-            size,
-            aggressor_side,
-            trade_id,
-            ts_event,
-            ts_init,
-        );
+        let trade = parse_trade_record(&record, price_precision, size_precision, instrument_id);
 
         trades.push(trade);
 
@@ -475,6 +481,83 @@ pub fn load_trade_ticks<P: AsRef<Path>>(
     Ok(trades)
 }
 
+pub(crate) fn parse_trade_record(
+    record: &TardisTradeRecord,
+    price_precision: u8,
+    size_precision: u8,
+    instrument_id: InstrumentId,
+) -> TradeTick {
+    let price = Price::new(record.price, price_precision);
+    let size = Quantity::new(record.amount, size_precision);
+    let aggressor_side = parse_aggressor_side(&record.side);
+    let trade_id = TradeId::new(&record.id);
+    let ts_event = parse_timestamp(record.timestamp);
+    let ts_init = parse_timestamp(record.local_timestamp);
+
+    TradeTick::new(
+        instrument_id,
+        price,
+        size,
+        aggressor_side,
+        trade_id,
+        ts_event,
+        ts_init,
+    )
+}
+
+/// Load [`TardisDerivativeTicker`]s from a Tardis format CSV at the given `filepath`.
+pub fn load_derivative_tickers<P: AsRef<Path>>(
+    filepath: P,
+    price_precision: u8,
+    instrument_id: Option<InstrumentId>,
+    limit: Option<usize>,
+) -> Result<Vec<TardisDerivativeTicker>, Box<dyn Error>> {
+    let mut csv_reader = create_csv_reader(filepath)?;
+    let mut tickers = Vec::new();
+
+    let mut raw_record = StringRecord::new();
+    while csv_reader.read_record(&mut raw_record)? {
+        let record: TardisDerivativeTickerRecord = raw_record.deserialize(None)?;
+
+        let instrument_id = match &instrument_id {
+            Some(id) => *id,
+            None => parse_instrument_id(&record.exchange, record.symbol),
+        };
+        let ticker = parse_derivative_ticker_record(&record, price_precision, instrument_id);
+
+        tickers.push(ticker);
+
+        if let Some(limit) = limit {
+            if tickers.len() >= limit {
+                break;
+            }
+        }
+    }
+
+    Ok(tickers)
+}
+
+pub(crate) fn parse_derivative_ticker_record(
+    record: &TardisDerivativeTickerRecord,
+    price_precision: u8,
+    instrument_id: InstrumentId,
+) -> TardisDerivativeTicker {
+    let parse_price = |value: Option<f64>| value.map(|v| Price::new(v, price_precision));
+
+    TardisDerivativeTicker::new(
+        instrument_id,
+        parse_price(record.last_price),
+        parse_price(record.index_price),
+        parse_price(record.mark_price),
+        record.open_interest,
+        record.funding_rate,
+        record.predicted_funding_rate,
+        record.funding_timestamp.map(parse_timestamp),
+        parse_timestamp(record.timestamp),
+        parse_timestamp(record.local_timestamp),
+    )
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -601,4 +684,39 @@ mod tests {
         assert_eq!(trades[0].ts_event, 1583020803145000000);
         assert_eq!(trades[0].ts_init, 1583020803307160000);
     }
+
+    #[rstest]
+    pub fn test_read_derivative_tickers() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let filepath = temp_dir.path().join("derivative_ticker.csv");
+        std::fs::write(
+            &filepath,
+            "exchange,symbol,timestamp,local_timestamp,funding_timestamp,funding_rate,predicted_funding_rate,open_interest,last_price,index_price,mark_price\n\
+             deribit,BTC-PERPETUAL,1585699200245000,1585699200355684,,-0.00245,,41110870,6425.5,6423.2,6425.46\n\
+             deribit,BTC-PERPETUAL,1585699201245000,1585699201355684,1585728000000000,-0.00246,0.0001,41110880,6425.0,,6425.12\n",
+        )
+        .unwrap();
+
+        let tickers = load_derivative_tickers(filepath, 2, None, None).unwrap();
+
+        assert_eq!(tickers.len(), 2);
+        assert_eq!(
+            tickers[0].instrument_id,
+            InstrumentId::from("BTC-PERPETUAL.DERIBIT")
+        );
+        assert_eq!(tickers[0].last_price, Some(Price::from("6425.50")));
+        assert_eq!(tickers[0].index_price, Some(Price::from("6423.20")));
+        assert_eq!(tickers[0].mark_price, Some(Price::from("6425.46")));
+        assert_eq!(tickers[0].open_interest, Some(41_110_870.0));
+        assert_eq!(tickers[0].funding_rate, Some(-0.00245));
+        assert_eq!(tickers[0].predicted_funding_rate, None);
+        assert_eq!(tickers[0].funding_timestamp, None);
+        assert_eq!(tickers[0].ts_event, 1585699200245000000);
+        assert_eq!(tickers[0].ts_init, 1585699200355684000);
+        assert_eq!(tickers[1].index_price, None);
+        assert_eq!(
+            tickers[1].funding_timestamp,
+            Some(UnixNanos::from(1585728000000000000))
+        );
+    }
 }
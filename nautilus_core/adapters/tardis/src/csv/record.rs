@@ -276,3 +276,31 @@ pub struct TardisTradeRecord {
     /// The trade amount as provided by the exchange.
     pub amount: f64,
 }
+
+/// Represents a Tardis format derivative ticker record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TardisDerivativeTickerRecord {
+    /// The exchange ID.
+    pub exchange: Exchange,
+    /// The instrument symbol as provided by the exchange.
+    #[serde(deserialize_with = "deserialize_uppercase")]
+    pub symbol: Ustr,
+    // UNIX microseconds timestamp provided by the exchange.
+    pub timestamp: u64,
+    // UNIX microseconds timestamp of message received.
+    pub local_timestamp: u64,
+    // UNIX microseconds timestamp of the next funding event, empty if not provided.
+    pub funding_timestamp: Option<u64>,
+    // The current funding rate, empty if not provided.
+    pub funding_rate: Option<f64>,
+    // The predicted funding rate for the next period, empty if not provided.
+    pub predicted_funding_rate: Option<f64>,
+    // The current open interest, empty if not provided.
+    pub open_interest: Option<f64>,
+    // The last traded price, empty if not provided.
+    pub last_price: Option<f64>,
+    // The index price, empty if not provided.
+    pub index_price: Option<f64>,
+    // The mark price, empty if not provided.
+    pub mark_price: Option<f64>,
+}
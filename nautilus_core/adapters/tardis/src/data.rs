@@ -0,0 +1,161 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use bytes::Bytes;
+use indexmap::IndexMap;
+use nautilus_common::custom::CustomData;
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::{DataType, GetTsInit},
+    identifiers::InstrumentId,
+    types::Price,
+};
+use serde::{Deserialize, Serialize};
+
+/// The type name for Tardis derivative ticker custom data.
+pub const DERIVATIVE_TICKER_TYPE_NAME: &str = "TardisDerivativeTicker";
+
+/// Represents a Tardis derivative ticker snapshot (funding, open interest, index and mark prices).
+///
+/// Nautilus has no built-in data type for these values, so they are carried as [`CustomData`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TardisDerivativeTicker {
+    /// The instrument ID for the ticker.
+    pub instrument_id: InstrumentId,
+    /// The last traded price, if provided by the exchange.
+    pub last_price: Option<Price>,
+    /// The index price, if provided by the exchange.
+    pub index_price: Option<Price>,
+    /// The mark price, if provided by the exchange.
+    pub mark_price: Option<Price>,
+    /// The open interest, if provided by the exchange.
+    pub open_interest: Option<f64>,
+    /// The current funding rate, if provided by the exchange.
+    pub funding_rate: Option<f64>,
+    /// The predicted funding rate for the next period, if provided by the exchange.
+    pub predicted_funding_rate: Option<f64>,
+    /// UNIX timestamp (nanoseconds) of the next funding event, if provided by the exchange.
+    pub funding_timestamp: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) when the ticker event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl TardisDerivativeTicker {
+    /// Creates a new [`TardisDerivativeTicker`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub const fn new(
+        instrument_id: InstrumentId,
+        last_price: Option<Price>,
+        index_price: Option<Price>,
+        mark_price: Option<Price>,
+        open_interest: Option<f64>,
+        funding_rate: Option<f64>,
+        predicted_funding_rate: Option<f64>,
+        funding_timestamp: Option<UnixNanos>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            last_price,
+            index_price,
+            mark_price,
+            open_interest,
+            funding_rate,
+            predicted_funding_rate,
+            funding_timestamp,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the [`DataType`] for tickers of the given `instrument_id`.
+    #[must_use]
+    pub fn data_type(instrument_id: &InstrumentId) -> DataType {
+        let metadata = IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())]);
+        DataType::new(DERIVATIVE_TICKER_TYPE_NAME, Some(metadata))
+    }
+
+    /// Converts the ticker into [`CustomData`] with a JSON encoded value.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the ticker fails to serialize to JSON.
+    pub fn to_custom_data(&self) -> anyhow::Result<CustomData> {
+        let value = serde_json::to_vec(self)?;
+        Ok(CustomData::new(
+            Self::data_type(&self.instrument_id),
+            Bytes::from(value),
+            self.ts_event,
+            self.ts_init,
+        ))
+    }
+}
+
+impl GetTsInit for TardisDerivativeTicker {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ticker() -> TardisDerivativeTicker {
+        TardisDerivativeTicker::new(
+            InstrumentId::from("BTC-PERPETUAL.DERIBIT"),
+            Some(Price::from("7987.5")),
+            Some(Price::from("7989.28")),
+            Some(Price::from("7987.56")),
+            Some(84_129_491.0),
+            Some(-0.000_015_68),
+            None,
+            None,
+            UnixNanos::from(1_571_830_469_302_000_000),
+            UnixNanos::from(1_571_830_469_416_000_000),
+        )
+    }
+
+    #[rstest]
+    fn test_data_type() {
+        let data_type = TardisDerivativeTicker::data_type(&ticker().instrument_id);
+
+        assert_eq!(data_type.type_name(), DERIVATIVE_TICKER_TYPE_NAME);
+        assert_eq!(
+            data_type.metadata().unwrap().get("instrument_id"),
+            Some(&"BTC-PERPETUAL.DERIBIT".to_string())
+        );
+    }
+
+    #[rstest]
+    fn test_to_custom_data_round_trip() {
+        let ticker = ticker();
+        let custom = ticker.to_custom_data().unwrap();
+        let decoded: TardisDerivativeTicker = serde_json::from_slice(&custom.value).unwrap();
+
+        assert_eq!(custom.ts_event, ticker.ts_event);
+        assert_eq!(custom.ts_init, ticker.ts_init);
+        assert_eq!(decoded, ticker);
+    }
+}
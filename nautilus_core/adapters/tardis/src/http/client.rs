@@ -13,10 +13,14 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{env, time::Duration};
+use std::time::Duration;
 
 use chrono::Utc;
-use nautilus_core::{nanos::UnixNanos, version::USER_AGENT};
+use nautilus_core::{
+    credentials::{resolve_api_key, Secret},
+    nanos::UnixNanos,
+    version::USER_AGENT,
+};
 use nautilus_model::instruments::InstrumentAny;
 
 use super::{
@@ -51,7 +55,7 @@ pub enum Error {
 #[derive(Debug, Clone)]
 pub struct TardisHttpClient {
     base_url: String,
-    api_key: String,
+    api_key: Secret,
     client: reqwest::Client,
     normalize_symbols: bool,
 }
@@ -64,14 +68,7 @@ impl TardisHttpClient {
         timeout_secs: Option<u64>,
         normalize_symbols: bool,
     ) -> anyhow::Result<Self> {
-        let api_key = match api_key {
-            Some(key) => key.to_string(),
-            None => env::var("TARDIS_API_KEY").map_err(|_| {
-                anyhow::anyhow!(
-                    "API key must be provided or set in the 'TARDIS_API_KEY' environment variable"
-                )
-            })?,
-        };
+        let api_key = resolve_api_key(api_key, "TARDIS_API_KEY")?;
 
         let base_url = base_url.map_or_else(|| TARDIS_BASE_URL.to_string(), ToString::to_string);
         let timeout = timeout_secs.map_or_else(|| Duration::from_secs(60), Duration::from_secs);
@@ -100,7 +97,7 @@ impl TardisHttpClient {
         Ok(self
             .client
             .get(format!("{}/instruments/{exchange}", &self.base_url))
-            .bearer_auth(&self.api_key)
+            .bearer_auth(self.api_key.expose())
             .send()
             .await?
             .json::<Response<Vec<InstrumentInfo>>>()
@@ -122,7 +119,7 @@ impl TardisHttpClient {
                 "{}/instruments/{exchange}/{symbol}",
                 &self.base_url
             ))
-            .bearer_auth(&self.api_key)
+            .bearer_auth(self.api_key.expose())
             .send()
             .await?
             .json::<Response<InstrumentInfo>>()
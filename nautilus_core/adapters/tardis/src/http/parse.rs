@@ -318,14 +318,14 @@ fn parse_datetime_to_unix_nanos(value: Option<&str>, field: &str) -> UnixNanos {
 #[cfg(test)]
 mod tests {
     use nautilus_model::identifiers::InstrumentId;
+    use nautilus_test_kit::load_test_json;
     use rstest::rstest;
 
     use super::*;
-    use crate::tests::load_test_json;
 
     #[rstest]
     fn test_parse_instrument_crypto_perpetual() {
-        let json_data = load_test_json("instrument_perpetual.json");
+        let json_data = load_test_json!("instrument_perpetual.json");
         let info: InstrumentInfo = serde_json::from_str(&json_data).unwrap();
 
         let instrument = parse_instrument_any(info, UnixNanos::default(), false);
@@ -17,14 +17,13 @@
 
 pub mod config;
 pub mod csv;
+pub mod data;
 pub mod enums;
 pub mod http;
+pub mod loader;
 pub mod machine;
 pub mod parse;
 pub mod replay;
 
 #[cfg(feature = "python")]
 pub mod python;
-
-#[cfg(test)]
-pub mod tests;
@@ -0,0 +1,773 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Loading of Tardis historical datasets into Nautilus data.
+//!
+//! Both the Tardis CSV datasets and the normalized JSON (newline delimited) format produced by
+//! Tardis Machine are supported, optionally gzip compressed. Records are streamed from disk one at a
+//! time so large files can be written to the catalog or fed into a backtest without loading the
+//! whole dataset into memory first.
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufRead, BufReader, Lines, Read},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use csv::{Reader, StringRecord};
+use flate2::read::GzDecoder;
+use nautilus_core::parsing::precision_from_str;
+use nautilus_model::{
+    data::{Data, OrderBookDeltas, OrderBookDeltas_API},
+    enums::RecordFlag,
+    identifiers::InstrumentId,
+};
+use nautilus_persistence::backend::catalog::ParquetDataCatalog;
+use serde::de::DeserializeOwned;
+use ustr::Ustr;
+
+use crate::{
+    csv::{
+        create_csv_reader, parse_book_update_record, parse_derivative_ticker_record,
+        parse_quote_record, parse_trade_record,
+        record::{
+            TardisBookUpdateRecord, TardisDerivativeTickerRecord, TardisQuoteRecord,
+            TardisTradeRecord,
+        },
+    },
+    data::{TardisDerivativeTicker, DERIVATIVE_TICKER_TYPE_NAME},
+    enums::Exchange,
+    http::types::InstrumentInfo,
+    machine::{
+        message::WsMessage,
+        parse::{parse_derivative_ticker_msg, parse_tardis_ws_message},
+        types::{InstrumentMiniInfo, TardisInstrumentKey},
+    },
+    parse::{normalize_instrument_id, parse_instrument_id},
+};
+
+/// Represents a record loaded from a Tardis dataset.
+#[derive(Clone, Debug)]
+pub enum TardisRecord {
+    /// Market data with a native Nautilus representation.
+    Data(Data),
+    /// A derivative ticker, which is carried as custom data.
+    DerivativeTicker(TardisDerivativeTicker),
+}
+
+impl TardisRecord {
+    /// Returns the market data, or `None` for a derivative ticker.
+    #[must_use]
+    pub fn into_data(self) -> Option<Data> {
+        match self {
+            Self::Data(data) => Some(data),
+            Self::DerivativeTicker(_) => None,
+        }
+    }
+}
+
+/// The kind of dataset contained in a Tardis CSV file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TardisCsvKind {
+    /// Tick-level incremental L2 order book updates (`incremental_book_L2`).
+    IncrementalBookL2,
+    /// Individual trades (`trades`).
+    Trades,
+    /// Top of book quotes (`quotes`).
+    Quotes,
+    /// Derivative instrument tickers (`derivative_ticker`).
+    DerivativeTicker,
+}
+
+impl TardisCsvKind {
+    /// Determines the dataset kind from the given CSV `headers`.
+    #[must_use]
+    pub fn from_headers(headers: &StringRecord) -> Option<Self> {
+        let has = |name: &str| headers.iter().any(|header| header == name);
+
+        if has("is_snapshot") {
+            Some(Self::IncrementalBookL2)
+        } else if has("funding_rate") {
+            Some(Self::DerivativeTicker)
+        } else if has("bid_price") && has("ask_price") {
+            Some(Self::Quotes)
+        } else if has("id") && has("side") {
+            Some(Self::Trades)
+        } else {
+            None
+        }
+    }
+}
+
+/// Resolves Tardis exchange symbols to instrument IDs and precisions.
+#[derive(Clone, Debug)]
+struct InstrumentResolver {
+    instruments: HashMap<TardisInstrumentKey, Arc<InstrumentMiniInfo>>,
+    instrument_id: Option<InstrumentId>,
+    price_precision: Option<u8>,
+    size_precision: Option<u8>,
+}
+
+impl InstrumentResolver {
+    /// Resolves the instrument for the given `exchange` and `symbol`.
+    ///
+    /// Explicit overrides take precedence over the registered instrument info. When no
+    /// instrument info is registered the instrument ID is parsed from the raw symbol, and the
+    /// size precision defaults to zero for datasets without sizes (`needs_size` false).
+    fn resolve(
+        &self,
+        exchange: &Exchange,
+        symbol: Ustr,
+        needs_size: bool,
+    ) -> anyhow::Result<InstrumentMiniInfo> {
+        let key = TardisInstrumentKey::new(symbol, exchange.clone());
+        let info = self.instruments.get(&key);
+
+        let instrument_id = self
+            .instrument_id
+            .or_else(|| info.map(|info| info.instrument_id))
+            .unwrap_or_else(|| parse_instrument_id(exchange, symbol));
+        let price_precision = self
+            .price_precision
+            .or_else(|| info.map(|info| info.price_precision))
+            .ok_or_else(|| {
+                anyhow::anyhow!("No price precision for {key:?}, add instrument info or set one")
+            })?;
+        let size_precision = match self
+            .size_precision
+            .or_else(|| info.map(|info| info.size_precision))
+        {
+            Some(precision) => precision,
+            None if !needs_size => 0,
+            None => anyhow::bail!("No size precision for {key:?}, add instrument info or set one"),
+        };
+
+        Ok(InstrumentMiniInfo::new(
+            instrument_id,
+            Some(symbol),
+            exchange.clone(),
+            price_precision,
+            size_precision,
+        ))
+    }
+}
+
+/// Provides a means of loading Tardis historical datasets.
+///
+/// Instrument definitions can be registered so that exchange symbols are normalized to Nautilus
+/// instrument IDs, and prices and sizes are parsed with the correct precisions.
+#[derive(Clone, Debug, Default)]
+pub struct TardisDataLoader {
+    instruments: HashMap<TardisInstrumentKey, Arc<InstrumentMiniInfo>>,
+    normalize_symbols: bool,
+}
+
+impl TardisDataLoader {
+    /// Creates a new [`TardisDataLoader`] instance.
+    #[must_use]
+    pub fn new(normalize_symbols: bool) -> Self {
+        Self {
+            instruments: HashMap::new(),
+            normalize_symbols,
+        }
+    }
+
+    /// Adds the given instrument `info` used to resolve symbols and precisions.
+    pub fn add_instrument_info(&mut self, info: InstrumentMiniInfo) {
+        let key = info.as_tardis_instrument_key();
+        self.instruments.insert(key, Arc::new(info));
+    }
+
+    /// Adds the instrument info for the given Tardis instrument definition.
+    ///
+    /// The symbol is normalized if the loader was created with `normalize_symbols`.
+    pub fn add_instrument_definition(&mut self, definition: &InstrumentInfo) {
+        let instrument_id = if self.normalize_symbols {
+            normalize_instrument_id(
+                &definition.exchange,
+                definition.id,
+                definition.instrument_type.clone(),
+                definition.inverse,
+            )
+        } else {
+            parse_instrument_id(&definition.exchange, definition.id)
+        };
+
+        self.add_instrument_info(InstrumentMiniInfo::new(
+            instrument_id,
+            Some(definition.id),
+            definition.exchange.clone(),
+            precision_from_str(&definition.price_increment.to_string()),
+            precision_from_str(&definition.amount_increment.to_string()),
+        ));
+    }
+
+    /// Returns an iterator over the records of the Tardis CSV at the given `filepath`.
+    ///
+    /// The optional `instrument_id`, `price_precision` and `size_precision` override the values
+    /// resolved from the registered instrument info.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the file cannot be opened or its headers cannot be read.
+    /// - If the dataset kind cannot be determined from the headers.
+    pub fn csv_iter<P: AsRef<Path>>(
+        &self,
+        filepath: P,
+        instrument_id: Option<InstrumentId>,
+        price_precision: Option<u8>,
+        size_precision: Option<u8>,
+    ) -> anyhow::Result<TardisCsvIterator> {
+        let filepath = filepath.as_ref();
+        let mut reader = create_csv_reader(filepath)?;
+        let kind = TardisCsvKind::from_headers(reader.headers()?)
+            .ok_or_else(|| anyhow::anyhow!("Unsupported Tardis CSV dataset {filepath:?}"))?;
+
+        Ok(TardisCsvIterator {
+            reader,
+            kind,
+            resolver: self.resolver(instrument_id, price_precision, size_precision),
+            raw_record: StringRecord::new(),
+            pending: None,
+        })
+    }
+
+    /// Returns an iterator over the records of the Tardis normalized JSON at the given `filepath`.
+    ///
+    /// The optional `instrument_id`, `price_precision` and `size_precision` override the values
+    /// resolved from the registered instrument info.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the file cannot be opened.
+    pub fn json_iter<P: AsRef<Path>>(
+        &self,
+        filepath: P,
+        instrument_id: Option<InstrumentId>,
+        price_precision: Option<u8>,
+        size_precision: Option<u8>,
+    ) -> anyhow::Result<TardisJsonIterator> {
+        let filepath = filepath.as_ref();
+        let file = BufReader::new(File::open(filepath)?);
+        let reader: Box<dyn BufRead> = if filepath.extension().unwrap_or_default() == "gz" {
+            Box::new(BufReader::new(GzDecoder::new(file)))
+        } else {
+            Box::new(file)
+        };
+
+        Ok(TardisJsonIterator {
+            lines: reader.lines(),
+            resolver: self.resolver(instrument_id, price_precision, size_precision),
+        })
+    }
+
+    /// Returns an iterator over the records of the Tardis CSV or normalized JSON at the given
+    /// `filepath`, with the format determined by the file extension.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the underlying iterator cannot be created.
+    pub fn iter<P: AsRef<Path>>(
+        &self,
+        filepath: P,
+        instrument_id: Option<InstrumentId>,
+        price_precision: Option<u8>,
+        size_precision: Option<u8>,
+    ) -> anyhow::Result<Box<dyn Iterator<Item = anyhow::Result<TardisRecord>>>> {
+        let filepath = filepath.as_ref();
+        if is_csv(filepath) {
+            let iter = self.csv_iter(filepath, instrument_id, price_precision, size_precision)?;
+            Ok(Box::new(iter))
+        } else {
+            let iter = self.json_iter(filepath, instrument_id, price_precision, size_precision)?;
+            Ok(Box::new(iter))
+        }
+    }
+
+    /// Loads the market data from the Tardis dataset at the given `filepath`, ready to be added
+    /// to a backtest. Derivative tickers have no native representation and are skipped.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any record fails to load.
+    pub fn load_data<P: AsRef<Path>>(
+        &self,
+        filepath: P,
+        instrument_id: Option<InstrumentId>,
+        price_precision: Option<u8>,
+        size_precision: Option<u8>,
+    ) -> anyhow::Result<Vec<Data>> {
+        let mut data = Vec::new();
+        for record in self.iter(filepath, instrument_id, price_precision, size_precision)? {
+            data.extend(record?.into_data());
+        }
+        Ok(data)
+    }
+
+    /// Writes the Tardis dataset at the given `filepath` to the `catalog`.
+    ///
+    /// Market data is written under its native type directories, and derivative tickers are
+    /// written as custom data under the [`DERIVATIVE_TICKER_TYPE_NAME`] directory.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If any record fails to load.
+    /// - If writing to the catalog fails.
+    pub fn write_to_catalog<P: AsRef<Path>>(
+        &self,
+        filepath: P,
+        catalog: &ParquetDataCatalog,
+        instrument_id: Option<InstrumentId>,
+        price_precision: Option<u8>,
+        size_precision: Option<u8>,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        let mut data = Vec::new();
+        let mut tickers = Vec::new();
+
+        for record in self.iter(filepath, instrument_id, price_precision, size_precision)? {
+            match record? {
+                TardisRecord::Data(d) => data.push(d),
+                TardisRecord::DerivativeTicker(ticker) => tickers.push(ticker.to_custom_data()?),
+            }
+        }
+
+        let mut paths = catalog.write_data_enum_partitioned(data)?;
+        paths.extend(catalog.write_to_partitioned_parquet(DERIVATIVE_TICKER_TYPE_NAME, tickers)?);
+        Ok(paths)
+    }
+
+    fn resolver(
+        &self,
+        instrument_id: Option<InstrumentId>,
+        price_precision: Option<u8>,
+        size_precision: Option<u8>,
+    ) -> InstrumentResolver {
+        InstrumentResolver {
+            instruments: self.instruments.clone(),
+            instrument_id,
+            price_precision,
+            size_precision,
+        }
+    }
+}
+
+fn is_csv(filepath: &Path) -> bool {
+    filepath
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.ends_with(".csv") || name.ends_with(".csv.gz"))
+}
+
+/// Streams records from a Tardis CSV dataset.
+///
+/// Incremental book updates sharing an exchange timestamp are grouped into a single
+/// [`OrderBookDeltas`], with the `F_LAST` flag set on the final delta.
+pub struct TardisCsvIterator {
+    reader: Reader<Box<dyn Read>>,
+    kind: TardisCsvKind,
+    resolver: InstrumentResolver,
+    raw_record: StringRecord,
+    pending: Option<TardisBookUpdateRecord>,
+}
+
+impl TardisCsvIterator {
+    /// Returns the kind of dataset being read.
+    #[must_use]
+    pub const fn kind(&self) -> TardisCsvKind {
+        self.kind
+    }
+
+    fn read_next<T: DeserializeOwned>(&mut self) -> anyhow::Result<Option<T>> {
+        if !self.reader.read_record(&mut self.raw_record)? {
+            return Ok(None);
+        }
+        Ok(Some(self.raw_record.deserialize(None)?))
+    }
+
+    fn next_record(&mut self) -> anyhow::Result<Option<TardisRecord>> {
+        match self.kind {
+            TardisCsvKind::IncrementalBookL2 => self.next_deltas(),
+            TardisCsvKind::Trades => {
+                let Some(record) = self.read_next::<TardisTradeRecord>()? else {
+                    return Ok(None);
+                };
+                let info = self
+                    .resolver
+                    .resolve(&record.exchange, record.symbol, true)?;
+                let trade = parse_trade_record(
+                    &record,
+                    info.price_precision,
+                    info.size_precision,
+                    info.instrument_id,
+                );
+                Ok(Some(TardisRecord::Data(Data::Trade(trade))))
+            }
+            TardisCsvKind::Quotes => {
+                let Some(record) = self.read_next::<TardisQuoteRecord>()? else {
+                    return Ok(None);
+                };
+                let info = self
+                    .resolver
+                    .resolve(&record.exchange, record.symbol, true)?;
+                let quote = parse_quote_record(
+                    &record,
+                    info.price_precision,
+                    info.size_precision,
+                    info.instrument_id,
+                );
+                Ok(Some(TardisRecord::Data(Data::Quote(quote))))
+            }
+            TardisCsvKind::DerivativeTicker => {
+                let Some(record) = self.read_next::<TardisDerivativeTickerRecord>()? else {
+                    return Ok(None);
+                };
+                let info = self
+                    .resolver
+                    .resolve(&record.exchange, record.symbol, false)?;
+                let ticker = parse_derivative_ticker_record(
+                    &record,
+                    info.price_precision,
+                    info.instrument_id,
+                );
+                Ok(Some(TardisRecord::DerivativeTicker(ticker)))
+            }
+        }
+    }
+
+    fn next_deltas(&mut self) -> anyhow::Result<Option<TardisRecord>> {
+        let first = match self.pending.take() {
+            Some(record) => record,
+            None => match self.read_next::<TardisBookUpdateRecord>()? {
+                Some(record) => record,
+                None => return Ok(None),
+            },
+        };
+
+        let info = self.resolver.resolve(&first.exchange, first.symbol, true)?;
+        let parse = |record: &TardisBookUpdateRecord| {
+            parse_book_update_record(
+                record,
+                info.price_precision,
+                info.size_precision,
+                info.instrument_id,
+            )
+        };
+
+        let mut deltas = vec![parse(&first)];
+        while let Some(record) = self.read_next::<TardisBookUpdateRecord>()? {
+            if record.timestamp != first.timestamp
+                || record.symbol != first.symbol
+                || record.exchange != first.exchange
+            {
+                self.pending = Some(record);
+                break;
+            }
+            deltas.push(parse(&record));
+        }
+
+        if let Some(last_delta) = deltas.last_mut() {
+            last_delta.flags |= RecordFlag::F_LAST.value();
+        }
+
+        // TODO: Opaque pointer wrapper necessary for Cython (remove once Cython gone)
+        let deltas = OrderBookDeltas_API::new(OrderBookDeltas::new(info.instrument_id, deltas));
+        Ok(Some(TardisRecord::Data(Data::Deltas(deltas))))
+    }
+}
+
+impl Iterator for TardisCsvIterator {
+    type Item = anyhow::Result<TardisRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_record().transpose()
+    }
+}
+
+/// Streams records from a Tardis normalized JSON dataset (one message per line).
+pub struct TardisJsonIterator {
+    lines: Lines<Box<dyn BufRead>>,
+    resolver: InstrumentResolver,
+}
+
+impl Iterator for TardisJsonIterator {
+    type Item = anyhow::Result<TardisRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for line in self.lines.by_ref() {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let result = serde_json::from_str::<WsMessage>(&line)
+                .map_err(anyhow::Error::from)
+                .and_then(|msg| parse_ws_message(&self.resolver, msg));
+
+            match result {
+                Ok(Some(record)) => return Some(Ok(record)),
+                Ok(None) => continue,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        None
+    }
+}
+
+fn parse_ws_message(
+    resolver: &InstrumentResolver,
+    msg: WsMessage,
+) -> anyhow::Result<Option<TardisRecord>> {
+    let info = match &msg {
+        WsMessage::BookChange(msg) => resolver.resolve(&msg.exchange, msg.symbol, true)?,
+        WsMessage::BookSnapshot(msg) => resolver.resolve(&msg.exchange, msg.symbol, true)?,
+        WsMessage::Trade(msg) => resolver.resolve(&msg.exchange, msg.symbol, true)?,
+        WsMessage::TradeBar(msg) => resolver.resolve(&msg.exchange, msg.symbol, true)?,
+        WsMessage::DerivativeTicker(msg) => resolver.resolve(&msg.exchange, msg.symbol, false)?,
+        WsMessage::Disconnect(_) => return Ok(None),
+    };
+
+    if let WsMessage::DerivativeTicker(msg) = msg {
+        let ticker = parse_derivative_ticker_msg(msg, info.price_precision, info.instrument_id);
+        return Ok(Some(TardisRecord::DerivativeTicker(ticker)));
+    }
+
+    Ok(parse_tardis_ws_message(msg, Arc::new(info)).map(TardisRecord::Data))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use nautilus_model::{
+        enums::AggressorSide,
+        types::{Price, Quantity},
+    };
+    use nautilus_test_kit::load_test_json;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::enums::InstrumentType;
+
+    const TRADES_CSV: &str = "exchange,symbol,timestamp,local_timestamp,id,side,price,amount\n\
+        binance-futures,btcusdt,1585699200245000,1585699200355684,1,buy,6425.5,0.012\n\
+        binance-futures,btcusdt,1585699201245000,1585699201355684,2,sell,6425.4,0.100\n";
+
+    const BOOK_L2_CSV: &str =
+        "exchange,symbol,timestamp,local_timestamp,is_snapshot,side,price,amount\n\
+        deribit,BTC-PERPETUAL,1585699200245000,1585699200355684,false,ask,6443.5,38640\n\
+        deribit,BTC-PERPETUAL,1585699200245000,1585699200355684,false,bid,6430.0,0\n\
+        deribit,BTC-PERPETUAL,1585699200305000,1585699200415684,false,bid,6429.5,2000\n";
+
+    fn write_file(dir: &Path, name: &str, contents: &str) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn to_ndjson(file_names: &[&str]) -> String {
+        file_names
+            .iter()
+            .map(|name| {
+                let value: serde_json::Value =
+                    serde_json::from_str(&load_test_json!(name)).unwrap();
+                value.to_string() + "\n"
+            })
+            .collect()
+    }
+
+    fn binance_perp_info() -> InstrumentMiniInfo {
+        let exchange = Exchange::BinanceFutures;
+        let symbol = Ustr::from("BTCUSDT");
+        let instrument_id =
+            normalize_instrument_id(&exchange, symbol, InstrumentType::Perpetual, None);
+        InstrumentMiniInfo::new(instrument_id, Some(symbol), exchange, 1, 3)
+    }
+
+    #[rstest]
+    #[case(BOOK_L2_CSV, Some(TardisCsvKind::IncrementalBookL2))]
+    #[case(TRADES_CSV, Some(TardisCsvKind::Trades))]
+    #[case(
+        "exchange,symbol,timestamp,local_timestamp,ask_amount,ask_price,bid_price,bid_amount\n",
+        Some(TardisCsvKind::Quotes)
+    )]
+    #[case(
+        "exchange,symbol,timestamp,local_timestamp,funding_timestamp,funding_rate,predicted_funding_rate,open_interest,last_price,index_price,mark_price\n",
+        Some(TardisCsvKind::DerivativeTicker)
+    )]
+    #[case("exchange,symbol,timestamp,local_timestamp,unknown\n", None)]
+    fn test_csv_kind_from_headers(#[case] csv: &str, #[case] expected: Option<TardisCsvKind>) {
+        let mut reader = csv::ReaderBuilder::new().from_reader(csv.as_bytes());
+        let headers = reader.headers().unwrap();
+
+        assert_eq!(TardisCsvKind::from_headers(headers), expected);
+    }
+
+    #[rstest]
+    fn test_csv_iter_trades_with_normalized_symbol() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let filepath = write_file(temp_dir.path(), "trades.csv", TRADES_CSV);
+        let mut loader = TardisDataLoader::new(true);
+        loader.add_instrument_info(binance_perp_info());
+
+        let iter = loader.csv_iter(&filepath, None, None, None).unwrap();
+        assert_eq!(iter.kind(), TardisCsvKind::Trades);
+
+        let records: Vec<TardisRecord> = iter.map(Result::unwrap).collect();
+        assert_eq!(records.len(), 2);
+        match &records[0] {
+            TardisRecord::Data(Data::Trade(trade)) => {
+                assert_eq!(
+                    trade.instrument_id,
+                    InstrumentId::from("BTCUSDT-PERP.BINANCE")
+                );
+                assert_eq!(trade.price, Price::from("6425.5"));
+                assert_eq!(trade.size, Quantity::from("0.012"));
+                assert_eq!(trade.aggressor_side, AggressorSide::Buyer);
+                assert_eq!(trade.ts_event, 1585699200245000000);
+                assert_eq!(trade.ts_init, 1585699200355684000);
+            }
+            other => panic!("Unexpected record {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_add_instrument_definition() {
+        let definition: InstrumentInfo =
+            serde_json::from_str(&load_test_json!("instrument_perpetual.json")).unwrap();
+        let mut loader = TardisDataLoader::new(true);
+        loader.add_instrument_definition(&definition);
+
+        let info = loader
+            .resolver(None, None, None)
+            .resolve(&Exchange::Bitmex, Ustr::from("XBTUSD"), true)
+            .unwrap();
+
+        assert_eq!(info.instrument_id, InstrumentId::from("XBTUSD.BITMEX"));
+        assert_eq!(info.price_precision, 1);
+        assert_eq!(info.size_precision, 0);
+    }
+
+    #[rstest]
+    fn test_csv_iter_without_precision_returns_error() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let filepath = write_file(temp_dir.path(), "trades.csv", TRADES_CSV);
+        let loader = TardisDataLoader::default();
+
+        let mut iter = loader.csv_iter(&filepath, None, None, None).unwrap();
+
+        assert!(iter.next().unwrap().is_err());
+    }
+
+    #[rstest]
+    fn test_csv_iter_groups_book_updates_by_timestamp() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let filepath = write_file(temp_dir.path(), "book.csv", BOOK_L2_CSV);
+        let loader = TardisDataLoader::default();
+
+        let data = loader.load_data(&filepath, None, Some(1), Some(0)).unwrap();
+
+        assert_eq!(data.len(), 2);
+        let Data::Deltas(first) = &data[0] else {
+            panic!("Expected deltas");
+        };
+        assert_eq!(
+            first.instrument_id,
+            InstrumentId::from("BTC-PERPETUAL.DERIBIT")
+        );
+        assert_eq!(first.deltas.len(), 2);
+        assert_eq!(first.deltas[0].flags, 0);
+        assert_eq!(first.deltas[1].flags, RecordFlag::F_LAST.value());
+        let Data::Deltas(second) = &data[1] else {
+            panic!("Expected deltas");
+        };
+        assert_eq!(second.deltas.len(), 1);
+        assert_eq!(second.ts_event, 1585699200305000000);
+    }
+
+    #[rstest]
+    fn test_json_iter() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let contents = to_ndjson(&["trade.json", "derivative_ticker.json", "disconnect.json"]);
+        let filepath = write_file(temp_dir.path(), "messages.json", &contents);
+        let loader = TardisDataLoader::default();
+
+        let records: Vec<TardisRecord> = loader
+            .json_iter(&filepath, None, Some(2), Some(0))
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!(records.len(), 2);
+        match &records[0] {
+            TardisRecord::Data(Data::Trade(trade)) => {
+                assert_eq!(trade.instrument_id, InstrumentId::from("XBTUSD.BITMEX"));
+                assert_eq!(trade.price, Price::from("7996.00"));
+            }
+            other => panic!("Unexpected record {other:?}"),
+        }
+        match &records[1] {
+            TardisRecord::DerivativeTicker(ticker) => {
+                assert_eq!(
+                    ticker.instrument_id,
+                    InstrumentId::from("BTC-PERPETUAL.DERIBIT")
+                );
+                assert_eq!(ticker.mark_price, Some(Price::from("7987.56")));
+            }
+            other => panic!("Unexpected record {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_write_to_catalog() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let catalog_dir = temp_dir.path().join("catalog");
+        let catalog = ParquetDataCatalog::new(catalog_dir.clone(), None);
+        let mut loader = TardisDataLoader::new(true);
+        loader.add_instrument_info(binance_perp_info());
+
+        let trades_path = write_file(temp_dir.path(), "trades.csv", TRADES_CSV);
+        let messages_path = write_file(
+            temp_dir.path(),
+            "messages.json",
+            &to_ndjson(&["derivative_ticker.json"]),
+        );
+
+        let mut paths = loader
+            .write_to_catalog(&trades_path, &catalog, None, None, None)
+            .unwrap();
+        paths.extend(
+            loader
+                .write_to_catalog(&messages_path, &catalog, None, Some(2), None)
+                .unwrap(),
+        );
+
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].starts_with(catalog_dir.join("data").join("trade_tick")));
+        assert!(paths[1].starts_with(catalog_dir.join("data").join(DERIVATIVE_TICKER_TYPE_NAME)));
+        for path in paths {
+            assert!(path.exists());
+        }
+    }
+}
@@ -195,14 +195,14 @@ pub enum WsMessage {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_test_kit::load_test_json;
     use rstest::rstest;
 
     use super::*;
-    use crate::tests::load_test_json;
 
     #[rstest]
     fn test_parse_book_change_message() {
-        let json_data = load_test_json("book_change.json");
+        let json_data = load_test_json!("book_change.json");
         let message: BookChangeMsg = serde_json::from_str(&json_data).unwrap();
 
         assert_eq!(message.symbol, "XBTUSD");
@@ -224,7 +224,7 @@ mod tests {
 
     #[rstest]
     fn test_parse_book_snapshot_message() {
-        let json_data = load_test_json("book_snapshot.json");
+        let json_data = load_test_json!("book_snapshot.json");
         let message: BookSnapshotMsg = serde_json::from_str(&json_data).unwrap();
 
         assert_eq!(message.symbol, "XBTUSD");
@@ -250,7 +250,7 @@ mod tests {
 
     #[rstest]
     fn test_parse_trade_message() {
-        let json_data = load_test_json("trade.json");
+        let json_data = load_test_json!("trade.json");
         let message: TradeMsg = serde_json::from_str(&json_data).unwrap();
 
         assert_eq!(message.symbol, "XBTUSD");
@@ -274,7 +274,7 @@ mod tests {
 
     #[rstest]
     fn test_parse_derivative_ticker_message() {
-        let json_data = load_test_json("derivative_ticker.json");
+        let json_data = load_test_json!("derivative_ticker.json");
         let message: DerivativeTickerMsg = serde_json::from_str(&json_data).unwrap();
 
         assert_eq!(message.symbol, "BTC-PERPETUAL");
@@ -296,7 +296,7 @@ mod tests {
 
     #[rstest]
     fn test_parse_bar_message() {
-        let json_data = load_test_json("bar.json");
+        let json_data = load_test_json!("bar.json");
         let message: BarMsg = serde_json::from_str(&json_data).unwrap();
 
         assert_eq!(message.symbol, "XBTUSD");
@@ -332,7 +332,7 @@ mod tests {
 
     #[rstest]
     fn test_parse_disconnect_message() {
-        let json_data = load_test_json("disconnect.json");
+        let json_data = load_test_json!("disconnect.json");
         let message: DisconnectMsg = serde_json::from_str(&json_data).unwrap();
 
         assert_eq!(message.exchange, Exchange::Deribit);
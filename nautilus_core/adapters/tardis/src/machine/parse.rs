@@ -29,10 +29,15 @@ use nautilus_model::{
 use uuid::Uuid;
 
 use super::{
-    message::{BarMsg, BookChangeMsg, BookLevel, BookSnapshotMsg, TradeMsg, WsMessage},
+    message::{
+        BarMsg, BookChangeMsg, BookLevel, BookSnapshotMsg, DerivativeTickerMsg, TradeMsg, WsMessage,
+    },
     types::InstrumentMiniInfo,
 };
-use crate::parse::{parse_aggressor_side, parse_bar_spec, parse_book_action};
+use crate::{
+    data::TardisDerivativeTicker,
+    parse::{parse_aggressor_side, parse_bar_spec, parse_book_action},
+};
 
 #[must_use]
 pub fn parse_tardis_ws_message(msg: WsMessage, info: Arc<InstrumentMiniInfo>) -> Option<Data> {
@@ -273,20 +278,44 @@ pub fn parse_bar_msg(
     Bar::new(bar_type, open, high, low, close, volume, ts_event, ts_init)
 }
 
+#[must_use]
+pub fn parse_derivative_ticker_msg(
+    msg: DerivativeTickerMsg,
+    price_precision: u8,
+    instrument_id: InstrumentId,
+) -> TardisDerivativeTicker {
+    let parse_price = |value: Option<f64>| value.map(|v| Price::new(v, price_precision));
+    let ts_event = UnixNanos::from(msg.timestamp.timestamp_nanos_opt().unwrap() as u64);
+    let ts_init = UnixNanos::from(msg.local_timestamp.timestamp_nanos_opt().unwrap() as u64);
+
+    TardisDerivativeTicker::new(
+        instrument_id,
+        parse_price(msg.last_price),
+        parse_price(msg.index_price),
+        parse_price(msg.mark_price),
+        msg.open_interest,
+        msg.funding_rate,
+        None, // Predicted funding rate not provided
+        None, // Funding timestamp not provided
+        ts_event,
+        ts_init,
+    )
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
     use nautilus_model::enums::{AggressorSide, BookAction};
+    use nautilus_test_kit::load_test_json;
     use rstest::rstest;
 
     use super::*;
-    use crate::tests::load_test_json;
 
     #[rstest]
     fn test_parse_book_change_message() {
-        let json_data = load_test_json("book_change.json");
+        let json_data = load_test_json!("book_change.json");
         let msg: BookChangeMsg = serde_json::from_str(&json_data).unwrap();
 
         let price_precision = 0;
@@ -323,7 +352,7 @@ mod tests {
 
     #[rstest]
     fn test_parse_book_snapshot_message_as_deltas() {
-        let json_data = load_test_json("book_snapshot.json");
+        let json_data = load_test_json!("book_snapshot.json");
         let msg: BookSnapshotMsg = serde_json::from_str(&json_data).unwrap();
 
         let price_precision = 1;
@@ -367,7 +396,7 @@ mod tests {
 
     #[rstest]
     fn test_parse_book_snapshot_message_as_quote() {
-        let json_data = load_test_json("book_snapshot.json");
+        let json_data = load_test_json!("book_snapshot.json");
         let msg: BookSnapshotMsg = serde_json::from_str(&json_data).unwrap();
 
         let price_precision = 1;
@@ -387,7 +416,7 @@ mod tests {
 
     #[rstest]
     fn test_parse_trade_message() {
-        let json_data = load_test_json("trade.json");
+        let json_data = load_test_json!("trade.json");
         let msg: TradeMsg = serde_json::from_str(&json_data).unwrap();
 
         let price_precision = 0;
@@ -405,7 +434,7 @@ mod tests {
 
     #[rstest]
     fn test_parse_bar_message() {
-        let json_data = load_test_json("bar.json");
+        let json_data = load_test_json!("bar.json");
         let msg: BarMsg = serde_json::from_str(&json_data).unwrap();
 
         let price_precision = 1;
@@ -425,4 +454,24 @@ mod tests {
         assert_eq!(bar.ts_event, UnixNanos::from(1572009100000000000));
         assert_eq!(bar.ts_init, UnixNanos::from(1572009100369000000));
     }
+
+    #[rstest]
+    fn test_parse_derivative_ticker_message() {
+        let json_data = load_test_json!("derivative_ticker.json");
+        let msg: DerivativeTickerMsg = serde_json::from_str(&json_data).unwrap();
+
+        let price_precision = 2;
+        let instrument_id = InstrumentId::from("BTC-PERPETUAL.DERIBIT");
+        let ticker = parse_derivative_ticker_msg(msg, price_precision, instrument_id);
+
+        assert_eq!(ticker.instrument_id, instrument_id);
+        assert_eq!(ticker.last_price, Some(Price::from("7987.50")));
+        assert_eq!(ticker.index_price, Some(Price::from("7989.28")));
+        assert_eq!(ticker.mark_price, Some(Price::from("7987.56")));
+        assert_eq!(ticker.open_interest, Some(84_129_491.0));
+        assert_eq!(ticker.funding_rate, Some(-0.000_015_68));
+        assert_eq!(ticker.predicted_funding_rate, None);
+        assert_eq!(ticker.ts_event, UnixNanos::from(1571830469302000000));
+        assert_eq!(ticker.ts_init, UnixNanos::from(1571830469416000000));
+    }
 }
@@ -28,7 +28,7 @@ use nautilus_model::{
 };
 use rust_decimal::Decimal;
 
-use crate::{statistic::PortfolioStatistic, Returns};
+use crate::{montecarlo::MonteCarloResampler, statistic::PortfolioStatistic, Returns};
 
 pub type Statistic = Arc<dyn PortfolioStatistic<Item = f64> + Send + Sync>;
 
@@ -304,6 +304,24 @@ impl PortfolioAnalyzer {
         output
     }
 
+    /// Gets Monte Carlo robustness statistics by resampling the realized trade `PnLs`.
+    #[must_use]
+    pub fn get_performance_stats_monte_carlo(
+        &self,
+        currency: Option<&Currency>,
+        resampler: &mut MonteCarloResampler,
+    ) -> HashMap<String, f64> {
+        let Some(realized_pnls) = self.realized_pnls(currency) else {
+            return HashMap::new();
+        };
+
+        let pnls: Vec<f64> = realized_pnls.iter().map(|(_, pnl)| *pnl).collect();
+        resampler
+            .resample(&pnls)
+            .map(|result| result.to_stats())
+            .unwrap_or_default()
+    }
+
     /// Calculates the maximum length of statistic names for formatting.
     fn get_max_length_name(&self) -> usize {
         self.statistics.keys().map(String::len).max().unwrap_or(0)
@@ -702,6 +720,29 @@ mod tests {
         assert!(general_stats.contains_key("test_stat"));
     }
 
+    #[test]
+    fn test_performance_stats_monte_carlo() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        let currency = Currency::USD();
+        let mut resampler = MonteCarloResampler::new(100, 0.95, Some(42));
+
+        // No trades yet
+        assert!(analyzer
+            .get_performance_stats_monte_carlo(Some(&currency), &mut resampler)
+            .is_empty());
+
+        let positions = vec![
+            create_mock_position("AUD/USD".to_owned(), 100.0, 0.1, currency),
+            create_mock_position("AUD/USD".to_owned(), -50.0, -0.05, currency),
+        ];
+        analyzer.add_positions(&positions);
+
+        let stats = analyzer.get_performance_stats_monte_carlo(Some(&currency), &mut resampler);
+        assert!(stats["MC PnL (total) (lower)"] >= -100.0);
+        assert!(stats["MC PnL (total) (upper)"] <= 200.0);
+        assert!(stats.contains_key("MC Max Drawdown (median)"));
+    }
+
     #[test]
     fn test_formatted_output() {
         let mut analyzer = PortfolioAnalyzer::new();
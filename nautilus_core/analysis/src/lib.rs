@@ -32,6 +32,7 @@
 //! - `python`: Enables Python bindings from `pyo3`.
 
 pub mod analyzer;
+pub mod montecarlo;
 pub mod statistic;
 pub mod statistics;
 
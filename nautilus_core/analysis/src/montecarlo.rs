@@ -0,0 +1,316 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Monte Carlo (bootstrap) resampling of backtest trade results.
+//!
+//! The realized PnLs of closed trades are resampled with replacement to build many
+//! alternative trade sequences, from which confidence intervals are estimated for the
+//! total PnL, maximum drawdown and Sharpe ratio of the strategy.
+
+use std::{collections::HashMap, fmt::Display};
+
+use nautilus_core::correctness::{check_in_range_inclusive_f64, check_positive_u64, FAILED};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Represents a confidence interval estimated from a resampled distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConfidenceInterval {
+    /// The lower bound of the interval.
+    pub lower: f64,
+    /// The median of the distribution.
+    pub median: f64,
+    /// The upper bound of the interval.
+    pub upper: f64,
+}
+
+impl ConfidenceInterval {
+    /// Creates a new [`ConfidenceInterval`] from the given `samples` and `confidence_level`.
+    ///
+    /// Returns `None` if `samples` contains no finite values.
+    #[must_use]
+    pub fn from_samples(samples: &[f64], confidence_level: f64) -> Option<Self> {
+        let mut sorted: Vec<f64> = samples.iter().copied().filter(|x| x.is_finite()).collect();
+        if sorted.is_empty() {
+            return None;
+        }
+        sorted.sort_by(f64::total_cmp);
+
+        let alpha = (1.0 - confidence_level) / 2.0;
+        Some(Self {
+            lower: percentile(&sorted, alpha),
+            median: percentile(&sorted, 0.5),
+            upper: percentile(&sorted, 1.0 - alpha),
+        })
+    }
+}
+
+impl Display for ConfidenceInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{:.2}, {:.2}] (median={:.2})",
+            self.lower, self.upper, self.median
+        )
+    }
+}
+
+/// Represents the robustness statistics produced by a [`MonteCarloResampler`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonteCarloResult {
+    /// The number of resampled trade sequences.
+    pub num_samples: usize,
+    /// The confidence level of the intervals.
+    pub confidence_level: f64,
+    /// The confidence interval for the total PnL.
+    pub total_pnl: ConfidenceInterval,
+    /// The confidence interval for the maximum drawdown (as a positive PnL amount).
+    pub max_drawdown: ConfidenceInterval,
+    /// The confidence interval for the (non-annualized) per-trade Sharpe ratio.
+    pub sharpe_ratio: Option<ConfidenceInterval>,
+}
+
+impl MonteCarloResult {
+    /// Returns the result as a flat map of named statistics.
+    #[must_use]
+    pub fn to_stats(&self) -> HashMap<String, f64> {
+        let mut output = HashMap::new();
+        let mut insert = |name: &str, interval: &ConfidenceInterval| {
+            output.insert(format!("MC {name} (lower)"), interval.lower);
+            output.insert(format!("MC {name} (median)"), interval.median);
+            output.insert(format!("MC {name} (upper)"), interval.upper);
+        };
+
+        insert("PnL (total)", &self.total_pnl);
+        insert("Max Drawdown", &self.max_drawdown);
+        if let Some(sharpe_ratio) = &self.sharpe_ratio {
+            insert("Sharpe Ratio", sharpe_ratio);
+        }
+
+        output
+    }
+}
+
+/// Provides bootstrap resampling of a backtests realized trade PnLs.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.analysis")
+)]
+pub struct MonteCarloResampler {
+    num_samples: usize,
+    confidence_level: f64,
+    rng: StdRng,
+}
+
+impl MonteCarloResampler {
+    /// Creates a new [`MonteCarloResampler`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `num_samples` is not positive.
+    /// - If `confidence_level` is not in the range [0, 1].
+    #[must_use]
+    pub fn new(num_samples: usize, confidence_level: f64, random_seed: Option<u64>) -> Self {
+        check_positive_u64(num_samples as u64, "num_samples").expect(FAILED);
+        check_in_range_inclusive_f64(confidence_level, 0.0, 1.0, "confidence_level").expect(FAILED);
+
+        let rng = match random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        Self {
+            num_samples,
+            confidence_level,
+            rng,
+        }
+    }
+
+    /// Returns the number of resampled trade sequences per run.
+    #[must_use]
+    pub const fn num_samples(&self) -> usize {
+        self.num_samples
+    }
+
+    /// Returns the confidence level of the estimated intervals.
+    #[must_use]
+    pub const fn confidence_level(&self) -> f64 {
+        self.confidence_level
+    }
+
+    /// Resamples the given realized trade `pnls` (in trade order) with replacement.
+    ///
+    /// Returns `None` if there are no trades to resample.
+    pub fn resample(&mut self, pnls: &[f64]) -> Option<MonteCarloResult> {
+        if pnls.is_empty() {
+            return None;
+        }
+
+        let mut total_pnls = Vec::with_capacity(self.num_samples);
+        let mut max_drawdowns = Vec::with_capacity(self.num_samples);
+        let mut sharpe_ratios = Vec::with_capacity(self.num_samples);
+        let mut sample = vec![0.0; pnls.len()];
+
+        for _ in 0..self.num_samples {
+            for value in &mut sample {
+                *value = pnls[self.rng.gen_range(0..pnls.len())];
+            }
+
+            total_pnls.push(sample.iter().sum());
+            max_drawdowns.push(max_drawdown(&sample));
+            if let Some(sharpe_ratio) = sharpe_ratio(&sample) {
+                sharpe_ratios.push(sharpe_ratio);
+            }
+        }
+
+        Some(MonteCarloResult {
+            num_samples: self.num_samples,
+            confidence_level: self.confidence_level,
+            total_pnl: ConfidenceInterval::from_samples(&total_pnls, self.confidence_level)?,
+            max_drawdown: ConfidenceInterval::from_samples(&max_drawdowns, self.confidence_level)?,
+            sharpe_ratio: ConfidenceInterval::from_samples(&sharpe_ratios, self.confidence_level),
+        })
+    }
+}
+
+/// Returns the maximum peak-to-trough decline of the cumulative `pnls`.
+#[must_use]
+pub fn max_drawdown(pnls: &[f64]) -> f64 {
+    let mut cumulative = 0.0;
+    let mut peak = 0.0_f64;
+    let mut max_drawdown = 0.0_f64;
+
+    for pnl in pnls {
+        cumulative += pnl;
+        peak = peak.max(cumulative);
+        max_drawdown = max_drawdown.max(peak - cumulative);
+    }
+
+    max_drawdown
+}
+
+/// Returns the (non-annualized) Sharpe ratio of the given per-trade `pnls`.
+///
+/// Returns `None` if there are fewer than two trades or no variance.
+#[must_use]
+pub fn sharpe_ratio(pnls: &[f64]) -> Option<f64> {
+    let n = pnls.len() as f64;
+    if n < 2.0 {
+        return None;
+    }
+
+    let mean = pnls.iter().sum::<f64>() / n;
+    let variance = pnls.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    let std = variance.sqrt();
+
+    if std < f64::EPSILON {
+        return None;
+    }
+
+    Some(mean / std)
+}
+
+/// Returns the linearly interpolated percentile `q` (in [0, 1]) of the `sorted` values.
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    let rank = q * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let weight = rank - lower as f64;
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * weight
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_max_drawdown() {
+        let pnls = [100.0, -50.0, -70.0, 200.0, -10.0];
+        assert_eq!(max_drawdown(&pnls), 120.0);
+    }
+
+    #[rstest]
+    fn test_max_drawdown_only_winners() {
+        let pnls = [10.0, 20.0, 30.0];
+        assert_eq!(max_drawdown(&pnls), 0.0);
+    }
+
+    #[rstest]
+    fn test_sharpe_ratio_no_variance() {
+        assert!(sharpe_ratio(&[10.0, 10.0, 10.0]).is_none());
+        assert!(sharpe_ratio(&[10.0]).is_none());
+    }
+
+    #[rstest]
+    fn test_confidence_interval_from_samples() {
+        let samples: Vec<f64> = (0..=100).map(f64::from).collect();
+        let interval = ConfidenceInterval::from_samples(&samples, 0.9).unwrap();
+
+        assert!((interval.lower - 5.0).abs() < 1e-9);
+        assert!((interval.median - 50.0).abs() < 1e-9);
+        assert!((interval.upper - 95.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_resample_empty_pnls() {
+        let mut resampler = MonteCarloResampler::new(100, 0.95, Some(42));
+        assert!(resampler.resample(&[]).is_none());
+    }
+
+    #[rstest]
+    fn test_resample_constant_pnls() {
+        let mut resampler = MonteCarloResampler::new(100, 0.95, Some(42));
+        let result = resampler.resample(&[10.0, 10.0, 10.0]).unwrap();
+
+        assert_eq!(result.num_samples, 100);
+        assert_eq!(result.total_pnl.lower, 30.0);
+        assert_eq!(result.total_pnl.upper, 30.0);
+        assert_eq!(result.max_drawdown.upper, 0.0);
+        assert!(result.sharpe_ratio.is_none());
+    }
+
+    #[rstest]
+    fn test_resample_is_deterministic_with_seed() {
+        let pnls = [100.0, -50.0, 25.0, -75.0, 60.0, 10.0];
+        let result1 = MonteCarloResampler::new(500, 0.95, Some(1))
+            .resample(&pnls)
+            .unwrap();
+        let result2 = MonteCarloResampler::new(500, 0.95, Some(1))
+            .resample(&pnls)
+            .unwrap();
+
+        assert_eq!(result1, result2);
+        assert!(result1.total_pnl.lower <= result1.total_pnl.median);
+        assert!(result1.total_pnl.median <= result1.total_pnl.upper);
+        assert!(result1.sharpe_ratio.is_some());
+    }
+
+    #[rstest]
+    fn test_to_stats() {
+        let mut resampler = MonteCarloResampler::new(10, 0.95, Some(42));
+        let stats = resampler.resample(&[10.0, -5.0]).unwrap().to_stats();
+
+        assert!(stats.contains_key("MC PnL (total) (lower)"));
+        assert!(stats.contains_key("MC Max Drawdown (upper)"));
+        assert!(stats.contains_key("MC Sharpe Ratio (median)"));
+    }
+}
@@ -13,4 +13,36 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+//! Python bindings from `pyo3`.
+
+pub mod montecarlo;
 pub mod statistics;
+
+use pyo3::prelude::*;
+
+use crate::{montecarlo::MonteCarloResampler, statistics};
+
+/// Loaded as nautilus_pyo3.analysis
+#[pymodule]
+#[rustfmt::skip]
+pub fn analysis(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<MonteCarloResampler>()?;
+    m.add_class::<statistics::expectancy::Expectancy>()?;
+    m.add_class::<statistics::long_ratio::LongRatio>()?;
+    m.add_class::<statistics::loser_avg::AvgLoser>()?;
+    m.add_class::<statistics::loser_max::MaxLoser>()?;
+    m.add_class::<statistics::loser_min::MinLoser>()?;
+    m.add_class::<statistics::profit_factor::ProfitFactor>()?;
+    m.add_class::<statistics::returns_avg::ReturnsAverage>()?;
+    m.add_class::<statistics::returns_avg_loss::ReturnsAverageLoss>()?;
+    m.add_class::<statistics::returns_avg_win::ReturnsAverageWin>()?;
+    m.add_class::<statistics::returns_volatility::ReturnsVolatility>()?;
+    m.add_class::<statistics::risk_return_ratio::RiskReturnRatio>()?;
+    m.add_class::<statistics::sharpe_ratio::SharpeRatio>()?;
+    m.add_class::<statistics::sortino_ratio::SortinoRatio>()?;
+    m.add_class::<statistics::win_rate::WinRate>()?;
+    m.add_class::<statistics::winner_avg::AvgWinner>()?;
+    m.add_class::<statistics::winner_max::MaxWinner>()?;
+    m.add_class::<statistics::winner_min::MinWinner>()?;
+    Ok(())
+}
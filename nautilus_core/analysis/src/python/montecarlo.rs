@@ -0,0 +1,56 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use pyo3::prelude::*;
+
+use crate::montecarlo::MonteCarloResampler;
+
+#[pymethods]
+impl MonteCarloResampler {
+    #[new]
+    #[pyo3(signature = (num_samples, confidence_level, random_seed=None))]
+    fn py_new(num_samples: usize, confidence_level: f64, random_seed: Option<u64>) -> Self {
+        Self::new(num_samples, confidence_level, random_seed)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "MonteCarloResampler(num_samples={}, confidence_level={})",
+            self.num_samples(),
+            self.confidence_level(),
+        )
+    }
+
+    #[getter]
+    #[pyo3(name = "num_samples")]
+    fn py_num_samples(&self) -> usize {
+        self.num_samples()
+    }
+
+    #[getter]
+    #[pyo3(name = "confidence_level")]
+    fn py_confidence_level(&self) -> f64 {
+        self.confidence_level()
+    }
+
+    #[pyo3(name = "resample")]
+    fn py_resample(&mut self, pnls: Vec<f64>) -> HashMap<String, f64> {
+        self.resample(&pnls)
+            .map(|result| result.to_stats())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,152 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Actors and strategies run by the `BacktestEngine`.
+//!
+//! Actors and strategies are constructed with the engines clock, cache and message bus, and
+//! receive the data they subscribe to through an [`ActorHandler`]. Strategies additionally
+//! receive the events for their orders, and send trading commands to the risk engine.
+
+use std::{any::Any, cell::RefCell, collections::VecDeque, rc::Rc};
+
+use nautilus_common::{messages::data::DataResponse, msgbus::handler::MessageHandler};
+use nautilus_model::{
+    data::{Bar, Data, QuoteTick, TradeTick},
+    enums::OmsType,
+    events::OrderEventAny,
+    identifiers::StrategyId,
+};
+use ustr::Ustr;
+
+/// Provides the interface for an actor run by the `BacktestEngine`.
+pub trait Actor {
+    /// Returns the actor ID, which must be unique within the engine.
+    fn id(&self) -> Ustr;
+
+    /// Returns the data topic patterns the actor is subscribed to when added to the engine,
+    /// such as `data.quotes.BINANCE.*`.
+    fn subscriptions(&self) -> Vec<Ustr> {
+        Vec::new()
+    }
+
+    /// Called when a backtest run starts.
+    fn on_start(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Called when a backtest run completes.
+    fn on_stop(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Handles a `quote` for a subscribed topic.
+    fn on_quote(&mut self, _quote: &QuoteTick) {}
+
+    /// Handles a `trade` for a subscribed topic.
+    fn on_trade(&mut self, _trade: &TradeTick) {}
+
+    /// Handles a `bar` for a subscribed topic.
+    fn on_bar(&mut self, _bar: &Bar) {}
+
+    /// Handles an order `event` (only dispatched to strategies, for their own orders).
+    fn on_order_event(&mut self, _event: &OrderEventAny) {}
+}
+
+/// Provides the interface for a strategy run by the `BacktestEngine`.
+///
+/// Strategies send trading commands to the `RiskEngine.execute` endpoint, so that orders are
+/// checked by the risk engine before being executed.
+pub trait Strategy: Actor {
+    /// Returns the strategy ID, under which its orders and positions are held.
+    fn strategy_id(&self) -> StrategyId;
+
+    /// Returns the OMS type the execution engine uses for the strategies positions, or `None`
+    /// to use the venue OMS type.
+    fn oms_type(&self) -> Option<OmsType> {
+        None
+    }
+}
+
+enum ActorMessage {
+    Quote(QuoteTick),
+    Trade(TradeTick),
+    Bar(Bar),
+    Event(OrderEventAny),
+}
+
+/// Dispatches data and order events from the message bus to an [`Actor`].
+///
+/// Messages received while the actor is already handling a message (such as data published
+/// in response to its own commands) are queued and dispatched once it returns.
+pub struct ActorHandler {
+    id: Ustr,
+    actor: Rc<RefCell<dyn Actor>>,
+    pending: RefCell<VecDeque<ActorMessage>>,
+}
+
+impl ActorHandler {
+    /// Creates a new [`ActorHandler`] instance.
+    pub fn new(actor: Rc<RefCell<dyn Actor>>) -> Self {
+        let id = actor.borrow().id();
+        Self {
+            id,
+            actor,
+            pending: RefCell::new(VecDeque::new()),
+        }
+    }
+
+    fn dispatch(&self, message: ActorMessage) {
+        self.pending.borrow_mut().push_back(message);
+
+        let Ok(mut actor) = self.actor.try_borrow_mut() else {
+            return; // Dispatched by the outer call
+        };
+
+        loop {
+            let message = self.pending.borrow_mut().pop_front();
+            match message {
+                Some(ActorMessage::Quote(quote)) => actor.on_quote(&quote),
+                Some(ActorMessage::Trade(trade)) => actor.on_trade(&trade),
+                Some(ActorMessage::Bar(bar)) => actor.on_bar(&bar),
+                Some(ActorMessage::Event(event)) => actor.on_order_event(&event),
+                None => break,
+            }
+        }
+    }
+}
+
+impl MessageHandler for ActorHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        if let Some(quote) = message.downcast_ref::<QuoteTick>() {
+            self.dispatch(ActorMessage::Quote(*quote));
+        } else if let Some(trade) = message.downcast_ref::<TradeTick>() {
+            self.dispatch(ActorMessage::Trade(*trade));
+        } else if let Some(bar) = message.downcast_ref::<Bar>() {
+            self.dispatch(ActorMessage::Bar(*bar));
+        } else if let Some(event) = message.downcast_ref::<OrderEventAny>() {
+            self.dispatch(ActorMessage::Event(event.clone()));
+        }
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, _data: Data) {}
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,75 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Configuration for `BacktestEngine` instances.
+
+use nautilus_common::cache::CacheConfig;
+use nautilus_data::engine::config::DataEngineConfig;
+use nautilus_execution::engine::config::ExecutionEngineConfig;
+use nautilus_model::identifiers::TraderId;
+use nautilus_portfolio::PortfolioConfig;
+use nautilus_risk::engine::config::RiskEngineConfig;
+
+/// Configuration for `BacktestEngine` instances.
+pub struct BacktestEngineConfig {
+    /// The trader ID for the backtest node.
+    pub trader_id: TraderId,
+    /// The configuration for the engines cache.
+    pub cache: Option<CacheConfig>,
+    /// The configuration for the engines data engine.
+    pub data_engine: Option<DataEngineConfig>,
+    /// The configuration for the engines execution engine.
+    pub exec_engine: Option<ExecutionEngineConfig>,
+    /// The configuration for the engines risk engine.
+    pub risk_engine: Option<RiskEngineConfig>,
+    /// The configuration for the engines portfolio.
+    pub portfolio: Option<PortfolioConfig>,
+}
+
+impl BacktestEngineConfig {
+    /// Creates a new [`BacktestEngineConfig`] instance.
+    #[must_use]
+    pub const fn new(
+        trader_id: TraderId,
+        cache: Option<CacheConfig>,
+        data_engine: Option<DataEngineConfig>,
+        exec_engine: Option<ExecutionEngineConfig>,
+        risk_engine: Option<RiskEngineConfig>,
+        portfolio: Option<PortfolioConfig>,
+    ) -> Self {
+        Self {
+            trader_id,
+            cache,
+            data_engine,
+            exec_engine,
+            risk_engine,
+            portfolio,
+        }
+    }
+}
+
+impl Default for BacktestEngineConfig {
+    /// Creates a new default [`BacktestEngineConfig`] instance.
+    fn default() -> Self {
+        Self {
+            trader_id: TraderId::from("BACKTESTER-001"),
+            cache: None,
+            data_engine: None,
+            exec_engine: None,
+            risk_engine: None,
+            portfolio: None,
+        }
+    }
+}
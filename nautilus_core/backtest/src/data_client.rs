@@ -148,6 +148,30 @@ impl DataClient for BacktestDataClient {
         Ok(())
     }
 
+    fn subscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn subscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn subscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn unsubscribe(
         &mut self,
         data_type: &DataType,
@@ -228,6 +252,30 @@ impl DataClient for BacktestDataClient {
         Ok(())
     }
 
+    fn unsubscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     // -- DATA REQUEST HANDLERS ---------------------------------------------------------------------------
 
     fn request_data(&self, request: DataRequest) {
@@ -241,7 +289,7 @@ impl DataClient for BacktestDataClient {
         start: Option<UnixNanos>,
         end: Option<UnixNanos>,
         params: &Option<HashMap<String, String>>,
-    ) -> Vec<InstrumentAny> {
+    ) -> anyhow::Result<Vec<InstrumentAny>> {
         todo!()
     }
 
@@ -252,7 +300,7 @@ impl DataClient for BacktestDataClient {
         start: Option<UnixNanos>,
         end: Option<UnixNanos>,
         params: &Option<HashMap<String, String>>,
-    ) -> InstrumentAny {
+    ) -> anyhow::Result<InstrumentAny> {
         todo!()
     }
 
@@ -263,7 +311,7 @@ impl DataClient for BacktestDataClient {
         instrument_id: InstrumentId,
         depth: Option<usize>,
         params: &Option<HashMap<String, String>>,
-    ) -> Payload {
+    ) -> anyhow::Result<Payload> {
         todo!()
     }
 
@@ -275,7 +323,7 @@ impl DataClient for BacktestDataClient {
         end: Option<UnixNanos>,
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
-    ) -> Vec<QuoteTick> {
+    ) -> anyhow::Result<Vec<QuoteTick>> {
         todo!()
     }
 
@@ -287,7 +335,7 @@ impl DataClient for BacktestDataClient {
         end: Option<UnixNanos>,
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
-    ) -> Vec<TradeTick> {
+    ) -> anyhow::Result<Vec<TradeTick>> {
         todo!()
     }
 
@@ -299,7 +347,7 @@ impl DataClient for BacktestDataClient {
         end: Option<UnixNanos>,
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
-    ) -> Vec<Bar> {
+    ) -> anyhow::Result<Vec<Bar>> {
         todo!()
     }
 }
@@ -15,8 +15,51 @@
 
 //! The core `BacktestEngine` for backtesting on historical data.
 
-use nautilus_common::{clock::TestClock, timer::TimeEventHandlerV2};
-use nautilus_core::nanos::UnixNanos;
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+    rc::Rc,
+};
+
+use nautilus_common::{
+    cache::Cache,
+    clock::{Clock, TestClock},
+    messages::{data::DataResponse, execution::TradingCommand},
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        DispatchMode, MessageBus,
+    },
+    profiling::{profile, ResourceProfiler},
+    timer::TimeEventHandlerV2,
+};
+use nautilus_core::{
+    datetime::nanos_to_secs,
+    nanos::UnixNanos,
+    time::{get_atomic_clock_realtime, get_atomic_clock_static, AtomicTime},
+    uuid::UUID4,
+};
+use nautilus_data::engine::DataEngine;
+use nautilus_execution::{client::ExecutionClient, engine::ExecutionEngine};
+use nautilus_model::{
+    accounts::AccountAny,
+    data::{Data, GetTsInit},
+    events::OrderEventAny,
+    identifiers::{AccountId, ClientId, TraderId, Venue},
+    instruments::InstrumentAny,
+    position::Position,
+};
+use nautilus_portfolio::portfolio::Portfolio;
+use nautilus_risk::engine::{register_risk_engine, RiskEngine};
+use ustr::Ustr;
+
+use crate::{
+    actor::{Actor, ActorHandler, Strategy},
+    config::BacktestEngineConfig,
+    exchange::SimulatedExchange,
+    models::latency::DataLatencyModel,
+};
 
 /// Provides a means of accumulating and draining time event handlers.
 pub struct TimeEventAccumulator {
@@ -56,18 +99,1051 @@ impl Default for TimeEventAccumulator {
     }
 }
 
+/// Queues messages of type `T` sent to an endpoint, for deferred processing by the engine.
+struct QueueingMessageHandler<T> {
+    id: Ustr,
+    queue: Rc<RefCell<VecDeque<T>>>,
+}
+
+impl<T: Clone + 'static> MessageHandler for QueueingMessageHandler<T> {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        match message.downcast_ref::<T>() {
+            Some(message) => self.queue.borrow_mut().push_back(message.clone()),
+            None => log::error!("{} cannot handle message {message:?}", self.id),
+        }
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+
+    fn handle_data(&self, _data: Data) {}
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Represents the results of a backtest run.
+#[derive(Clone, Debug)]
+pub struct BacktestResult {
+    pub trader_id: TraderId,
+    pub instance_id: UUID4,
+    pub run_id: Option<UUID4>,
+    pub run_started: Option<UnixNanos>,
+    pub run_finished: Option<UnixNanos>,
+    pub backtest_start: Option<UnixNanos>,
+    pub backtest_end: Option<UnixNanos>,
+    /// The wall-clock duration of the run in seconds.
+    pub elapsed_time: f64,
+    pub iterations: usize,
+    pub total_events: usize,
+    pub total_orders: usize,
+    pub total_positions: usize,
+    /// The PnL performance statistics for each account currency, keyed by currency code.
+    pub stats_pnls: HashMap<String, HashMap<String, f64>>,
+    /// The returns performance statistics.
+    pub stats_returns: HashMap<String, f64>,
+}
+
+impl Display for BacktestResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BacktestResult(trader_id={}, run_id={}, backtest_start={}, backtest_end={}, \
+            elapsed_time={:.3}s, iterations={}, total_events={}, total_orders={}, total_positions={})",
+            self.trader_id,
+            self.run_id.map_or("None".to_string(), |id| id.to_string()),
+            self.backtest_start.map_or("None".to_string(), |ts| ts.to_string()),
+            self.backtest_end.map_or("None".to_string(), |ts| ts.to_string()),
+            self.elapsed_time,
+            self.iterations,
+            self.total_events,
+            self.total_orders,
+            self.total_positions,
+        )
+    }
+}
+
+/// A named stream of data batches, pulled into the engine as the backtest runs.
+struct DataStream {
+    name: String,
+    batches: Box<dyn Iterator<Item = Vec<Data>>>,
+    buffer: VecDeque<Data>,
+}
+
+impl DataStream {
+    /// Returns the `ts_init` of the next data point in the stream without consuming it,
+    /// pulling batches until one is not empty.
+    ///
+    /// Each pulled batch is checked for instruments in the `cache`, and delayed by the
+    /// `data_latency_model` (if any).
+    fn peek(
+        &mut self,
+        cache: &Cache,
+        data_latency_model: Option<&mut DataLatencyModel>,
+    ) -> anyhow::Result<Option<UnixNanos>> {
+        if self.buffer.is_empty() {
+            let Some(mut batch) = self.batches.by_ref().find(|batch| !batch.is_empty()) else {
+                return Ok(None);
+            };
+
+            if let Some(item) = batch
+                .iter()
+                .find(|item| cache.instrument(&item.instrument_id()).is_none())
+            {
+                anyhow::bail!(
+                    "Cannot stream data from '{}', instrument {} has not been added",
+                    self.name,
+                    item.instrument_id()
+                );
+            }
+
+            if let Some(data_latency_model) = data_latency_model {
+                batch.sort_by_key(GetTsInit::ts_init);
+                batch = data_latency_model.apply(batch);
+            }
+            self.buffer.extend(batch);
+        }
+
+        Ok(self.buffer.front().map(GetTsInit::ts_init))
+    }
+}
+
+/// Provides a backtest engine to run a portfolio of strategies over historical
+/// data, through simulated venues.
+///
+/// Actors and strategies receive data and events through the message bus, and strategies
+/// send trading commands to the `RiskEngine.execute` endpoint. Commands passing the
+/// [`RiskEngine`] checks are executed by the [`ExecutionEngine`], which routes them through
+/// an execution client to the simulated venue, and the resulting order events are processed
+/// by the execution engine before being published. All components share the engines
+/// [`TestClock`], from which time events are dispatched as it is advanced through the data
+/// stream. Performance statistics are calculated by the portfolio analyzer at the end of
+/// each run.
+pub struct BacktestEngine {
+    trader_id: TraderId,
+    instance_id: UUID4,
+    clock: Rc<RefCell<TestClock>>,
+    time: &'static AtomicTime,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: DataEngine,
+    exec_engine: ExecutionEngine,
+    risk_engine: Rc<RefCell<RiskEngine>>,
+    portfolio: Portfolio,
+    actors: Vec<Rc<RefCell<dyn Actor>>>,
+    profiler: Rc<RefCell<ResourceProfiler>>,
+    accumulator: TimeEventAccumulator,
+    venues: HashMap<Venue, SimulatedExchange>,
+    data_latency_model: Option<DataLatencyModel>,
+    data: Vec<Data>,
+    index: usize,
+    streams: Vec<DataStream>,
+    command_queue: Rc<RefCell<VecDeque<TradingCommand>>>,
+    venue_command_queue: Rc<RefCell<VecDeque<TradingCommand>>>,
+    event_queue: Rc<RefCell<VecDeque<OrderEventAny>>>,
+    run_id: Option<UUID4>,
+    run_started: Option<UnixNanos>,
+    run_finished: Option<UnixNanos>,
+    backtest_start: Option<UnixNanos>,
+    backtest_end: Option<UnixNanos>,
+    iteration: usize,
+    total_events: usize,
+    stats_pnls: HashMap<String, HashMap<String, f64>>,
+    stats_returns: HashMap<String, f64>,
+}
+
+impl BacktestEngine {
+    /// Creates a new [`BacktestEngine`] instance.
+    #[must_use]
+    pub fn new(config: BacktestEngineConfig) -> Self {
+        let instance_id = UUID4::new();
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let cache = Rc::new(RefCell::new(Cache::new(config.cache, None)));
+        // Sequenced dispatch makes the order of nested messages exactly reproducible
+        let msgbus = Rc::new(RefCell::new(MessageBus::new(
+            config.trader_id,
+            instance_id,
+            None,
+            None,
+            Some(DispatchMode::Sequenced),
+        )));
+        let shared_clock: Rc<RefCell<dyn Clock>> = clock.clone();
+        let data_engine = DataEngine::new(
+            shared_clock.clone(),
+            cache.clone(),
+            msgbus.clone(),
+            config.data_engine,
+        );
+        let exec_engine = ExecutionEngine::new(
+            shared_clock.clone(),
+            cache.clone(),
+            msgbus.clone(),
+            config.exec_engine.unwrap_or_default(),
+        );
+        let risk_engine = Rc::new(RefCell::new(RiskEngine::new(
+            config.risk_engine.unwrap_or_default(),
+            shared_clock.clone(),
+            cache.clone(),
+            msgbus.clone(),
+        )));
+        register_risk_engine(risk_engine.clone(), &mut msgbus.borrow_mut());
+        let portfolio = Portfolio::new(
+            msgbus.clone(),
+            cache.clone(),
+            shared_clock,
+            config.portfolio,
+        );
+
+        let command_queue = Rc::new(RefCell::new(VecDeque::new()));
+        let venue_command_queue = Rc::new(RefCell::new(VecDeque::new()));
+        let event_queue = Rc::new(RefCell::new(VecDeque::new()));
+        {
+            let mut msgbus = msgbus.borrow_mut();
+            let execute_endpoint = msgbus.switchboard.exec_engine_execute;
+            let process_endpoint = msgbus.switchboard.exec_engine_process;
+            msgbus.register(
+                execute_endpoint,
+                ShareableMessageHandler(Rc::new(QueueingMessageHandler {
+                    id: execute_endpoint,
+                    queue: command_queue.clone(),
+                })),
+            );
+            msgbus.register(
+                process_endpoint,
+                ShareableMessageHandler(Rc::new(QueueingMessageHandler {
+                    id: process_endpoint,
+                    queue: event_queue.clone(),
+                })),
+            );
+        }
+
+        Self {
+            trader_id: config.trader_id,
+            instance_id,
+            clock,
+            time: get_atomic_clock_static(),
+            cache,
+            msgbus,
+            data_engine,
+            exec_engine,
+            risk_engine,
+            portfolio,
+            actors: Vec::new(),
+            profiler: Rc::new(RefCell::new(ResourceProfiler::new(false))),
+            accumulator: TimeEventAccumulator::new(),
+            venues: HashMap::new(),
+            data_latency_model: None,
+            data: Vec::new(),
+            index: 0,
+            streams: Vec::new(),
+            command_queue,
+            venue_command_queue,
+            event_queue,
+            run_id: None,
+            run_started: None,
+            run_finished: None,
+            backtest_start: None,
+            backtest_end: None,
+            iteration: 0,
+            total_events: 0,
+            stats_pnls: HashMap::new(),
+            stats_returns: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn trader_id(&self) -> TraderId {
+        self.trader_id
+    }
+
+    #[must_use]
+    pub const fn instance_id(&self) -> UUID4 {
+        self.instance_id
+    }
+
+    #[must_use]
+    pub const fn iteration(&self) -> usize {
+        self.iteration
+    }
+
+    /// Returns the engines resource profiler, for actors and strategies to profile their
+    /// handlers within.
+    #[must_use]
+    pub fn profiler(&self) -> Rc<RefCell<ResourceProfiler>> {
+        self.profiler.clone()
+    }
+
+    /// Sets whether the CPU time and allocations of the engine components are profiled,
+    /// with a resource report logged at the end of each run.
+    pub fn set_profiling(&mut self, enabled: bool) {
+        self.profiler.borrow_mut().set_enabled(enabled);
+    }
+
+    /// Returns the engines clock, for actors and strategies to set timers.
+    #[must_use]
+    pub fn clock(&self) -> Rc<RefCell<TestClock>> {
+        self.clock.clone()
+    }
+
+    /// Returns the atomic time which simulated venues must be constructed with.
+    #[must_use]
+    pub const fn time(&self) -> &'static AtomicTime {
+        self.time
+    }
+
+    #[must_use]
+    pub fn cache(&self) -> Rc<RefCell<Cache>> {
+        self.cache.clone()
+    }
+
+    #[must_use]
+    pub fn msgbus(&self) -> Rc<RefCell<MessageBus>> {
+        self.msgbus.clone()
+    }
+
+    #[must_use]
+    pub const fn portfolio(&self) -> &Portfolio {
+        &self.portfolio
+    }
+
+    #[must_use]
+    pub fn risk_engine(&self) -> Rc<RefCell<RiskEngine>> {
+        self.risk_engine.clone()
+    }
+
+    #[must_use]
+    pub fn get_venue(&self, venue: &Venue) -> Option<&SimulatedExchange> {
+        self.venues.get(venue)
+    }
+
+    /// Adds the given simulated `exchange` to the engine.
+    ///
+    /// The exchange must be constructed with the engines message bus, cache and time. An
+    /// execution client for the venue is registered with the execution engine, and the
+    /// venue account is initialized with the exchanges starting balances.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a venue with the same ID has already been added, or if the
+    /// venue account cannot be initialized.
+    pub fn add_venue(&mut self, mut exchange: SimulatedExchange) -> anyhow::Result<()> {
+        let venue = exchange.id();
+        if self.venues.contains_key(&venue) {
+            anyhow::bail!("Venue {venue} has already been added");
+        }
+
+        let exec_client = self.create_exec_client(&exchange);
+        let endpoint = exec_client.execute_endpoint();
+        self.msgbus.borrow_mut().register(
+            endpoint,
+            ShareableMessageHandler(Rc::new(QueueingMessageHandler {
+                id: endpoint,
+                queue: self.venue_command_queue.clone(),
+            })),
+        );
+        self.exec_engine.register_client(exec_client)?;
+
+        exchange.register_client(self.create_exec_client(&exchange));
+        exchange.initialize_account()?;
+
+        self.venues.insert(venue, exchange);
+        log::info!("Added SimulatedExchange({venue})");
+        Ok(())
+    }
+
+    /// Adds the given `actor` to the engine, subscribing it to its data topics.
+    ///
+    /// The actor is started at the beginning of each run, and stopped at the end.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an actor or strategy with the same ID has already been added.
+    pub fn add_actor<A: Actor + 'static>(&mut self, actor: Rc<RefCell<A>>) -> anyhow::Result<()> {
+        let actor: Rc<RefCell<dyn Actor>> = actor;
+        self.register_actor(actor.clone())?;
+        log::info!("Added Actor({})", actor.borrow().id());
+        Ok(())
+    }
+
+    /// Adds the given `strategy` to the engine, subscribing it to its data topics and to the
+    /// events for its orders.
+    ///
+    /// The strategy is started at the beginning of each run, and stopped at the end.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an actor or strategy with the same ID has already been added.
+    pub fn add_strategy<S: Strategy + 'static>(
+        &mut self,
+        strategy: Rc<RefCell<S>>,
+    ) -> anyhow::Result<()> {
+        let (strategy_id, oms_type) = {
+            let strategy = strategy.borrow();
+            (strategy.strategy_id(), strategy.oms_type())
+        };
+        let handler = self.register_actor(strategy)?;
+
+        if let Some(oms_type) = oms_type {
+            self.exec_engine.register_oms_type(strategy_id, oms_type);
+        }
+        self.msgbus
+            .borrow_mut()
+            .subscribe(format!("events.order.{strategy_id}"), handler, None);
+        log::info!("Added Strategy({strategy_id})");
+        Ok(())
+    }
+
+    fn register_actor(
+        &mut self,
+        actor: Rc<RefCell<dyn Actor>>,
+    ) -> anyhow::Result<ShareableMessageHandler> {
+        let (id, subscriptions) = {
+            let actor = actor.borrow();
+            (actor.id(), actor.subscriptions())
+        };
+        if self
+            .actors
+            .iter()
+            .any(|existing| existing.borrow().id() == id)
+        {
+            anyhow::bail!("Actor {id} has already been added");
+        }
+
+        let handler = ShareableMessageHandler(Rc::new(ActorHandler::new(actor.clone())));
+        let mut msgbus = self.msgbus.borrow_mut();
+        for topic in subscriptions {
+            msgbus.subscribe(topic, handler.clone(), None);
+        }
+        self.actors.push(actor);
+        Ok(handler)
+    }
+
+    fn create_exec_client(&self, exchange: &SimulatedExchange) -> ExecutionClient {
+        let venue = exchange.id();
+        ExecutionClient::new(
+            self.trader_id,
+            ClientId::new(venue.as_str()),
+            venue,
+            exchange.oms_type(),
+            AccountId::new(format!("{venue}-001")),
+            exchange.account_type(),
+            exchange.base_currency(),
+            self.time,
+            self.cache.clone(),
+            self.msgbus.clone(),
+        )
+    }
+
+    /// Adds the given `instrument` to the cache and to the simulated venue it trades on.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instruments venue has not been added.
+    pub fn add_instrument(&mut self, instrument: InstrumentAny) -> anyhow::Result<()> {
+        let venue = instrument.id().venue;
+        let Some(exchange) = self.venues.get_mut(&venue) else {
+            anyhow::bail!(
+                "Cannot add instrument {}, venue {venue} has not been added",
+                instrument.id()
+            );
+        };
+
+        exchange.add_instrument(instrument.clone())?;
+        self.cache.borrow_mut().add_instrument(instrument)
+    }
+
+    /// Sets the `data_latency_model` which delays the delivery of data added from then on.
+    pub fn set_data_latency_model(&mut self, data_latency_model: DataLatencyModel) {
+        log::info!("Setting data latency model to {data_latency_model}");
+        self.data_latency_model = Some(data_latency_model);
+    }
+
+    /// Adds the given `data` to the engine, merged into the stream by `ts_init`.
+    ///
+    /// When a data latency model is set, the `ts_init` of each data point is first delayed
+    /// to its delivery time at the engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instrument for any of the data has not been added.
+    pub fn add_data(&mut self, mut data: Vec<Data>) -> anyhow::Result<()> {
+        {
+            let cache = self.cache.borrow();
+            if let Some(item) = data
+                .iter()
+                .find(|item| cache.instrument(&item.instrument_id()).is_none())
+            {
+                anyhow::bail!(
+                    "Cannot add data, instrument {} has not been added",
+                    item.instrument_id()
+                );
+            }
+        }
+
+        if let Some(data_latency_model) = &mut self.data_latency_model {
+            data.sort_by_key(GetTsInit::ts_init);
+            data = data_latency_model.apply(data);
+        }
+
+        let count = data.len();
+        self.data.extend(data);
+        // Stable sort so data with equal timestamps retains the order it was added in
+        self.data.sort_by_key(GetTsInit::ts_init);
+        log::info!("Added {count} data elements");
+        Ok(())
+    }
+
+    /// Adds the given `batches` of data to the engine as a stream named `data_name`.
+    ///
+    /// Batches are pulled as the backtest runs and merged with all other data by `ts_init`,
+    /// so data larger than memory (such as the `QueryResultBatches` of a catalog query) can
+    /// be streamed through the engine. Each batch is expected in `ts_init` order, and is
+    /// delayed by the data latency model (if any) as it is pulled. A stream is consumed
+    /// by the run it is pulled into.
+    pub fn add_data_iterator<I>(&mut self, data_name: &str, batches: I)
+    where
+        I: Iterator<Item = Vec<Data>> + 'static,
+    {
+        self.streams.push(DataStream {
+            name: data_name.to_string(),
+            batches: Box::new(batches),
+            buffer: VecDeque::new(),
+        });
+        log::info!("Added data stream '{data_name}'");
+    }
+
+    /// Clears all data and data streams from the engine, and resets the stream position.
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+        self.index = 0;
+        self.streams.clear();
+    }
+
+    /// Runs the backtest over the data stream from `start` to `end` (inclusive).
+    ///
+    /// When `start` is not provided the first data timestamp is used, and when `end` is not
+    /// provided the run continues until all data (including all data streams) is consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no data has been added, if `start` is after `end`, or if a data
+    /// stream yields data for an instrument which has not been added.
+    pub fn run(&mut self, start: Option<UnixNanos>, end: Option<UnixNanos>) -> anyhow::Result<()> {
+        self.index = 0;
+        let Some(first) = self.peek_next_data()?.map(|(ts_init, _)| ts_init) else {
+            anyhow::bail!("No data has been added to the engine");
+        };
+        let start = start.unwrap_or(first);
+        if let Some(end) = end {
+            if start > end {
+                anyhow::bail!("`start` {start} was after `end` {end}");
+            }
+        }
+
+        self.run_id = Some(UUID4::new());
+        self.run_started = Some(get_atomic_clock_realtime().get_time_ns());
+        self.run_finished = None;
+        self.backtest_start = Some(start);
+        self.backtest_end = None;
+
+        // Skip data before the start of the run
+        self.index = self.data.partition_point(|data| data.ts_init() < start);
+        while let Some((ts_init, _)) = self.peek_next_data()? {
+            if ts_init >= start {
+                break;
+            }
+            self.next_data()?;
+        }
+        self.set_time(start);
+        match end {
+            Some(end) => log::info!("Running backtest from {start} to {end}"),
+            None => log::info!("Running backtest from {start}"),
+        }
+
+        for actor in &self.actors {
+            let mut actor = actor.borrow_mut();
+            let id = actor.id();
+            actor
+                .on_start()
+                .map_err(|e| anyhow::anyhow!("Error starting {id}: {e}"))?;
+        }
+        self.process_venues(start);
+
+        let mut last_ns = start;
+        while let Some((ts_init, _)) = self.peek_next_data()? {
+            if end.is_some_and(|end| ts_init > end) {
+                break;
+            }
+            let Some(data) = self.next_data()? else {
+                break;
+            };
+
+            if ts_init > last_ns {
+                self.advance_time(ts_init);
+                last_ns = ts_init;
+            }
+
+            self.process_venue_data(&data);
+            profile(&self.profiler, Ustr::from("DataEngine"), || {
+                self.data_engine.process_data(data);
+            });
+            self.process_venues(ts_init);
+            self.msgbus.borrow().drain_buffers();
+
+            self.iteration += 1;
+        }
+
+        // Dispatch any remaining time events up to the end of the run
+        let end = end.unwrap_or(last_ns);
+        if end > last_ns {
+            self.advance_time(end);
+        }
+
+        for actor in &self.actors {
+            let mut actor = actor.borrow_mut();
+            if let Err(e) = actor.on_stop() {
+                log::error!("Error stopping {}: {e}", actor.id());
+            }
+        }
+        self.process_venues(end);
+        self.calculate_statistics();
+
+        self.backtest_end = Some(end);
+        self.run_finished = Some(get_atomic_clock_realtime().get_time_ns());
+        log::info!("Backtest run {} completed", self.run_id.unwrap());
+
+        let mut profiler = self.profiler.borrow_mut();
+        if profiler.is_enabled() {
+            log::info!("{}", profiler.report());
+        }
+        Ok(())
+    }
+
+    /// Returns the results summary for the last run.
+    #[must_use]
+    pub fn get_result(&self) -> BacktestResult {
+        let elapsed_time = match (self.run_started, self.run_finished) {
+            (Some(started), Some(finished)) => {
+                nanos_to_secs(finished.as_u64().saturating_sub(started.as_u64()))
+            }
+            _ => 0.0,
+        };
+        let cache = self.cache.borrow();
+
+        BacktestResult {
+            trader_id: self.trader_id,
+            instance_id: self.instance_id,
+            run_id: self.run_id,
+            run_started: self.run_started,
+            run_finished: self.run_finished,
+            backtest_start: self.backtest_start,
+            backtest_end: self.backtest_end,
+            elapsed_time,
+            iterations: self.iteration,
+            total_events: self.total_events,
+            total_orders: cache.orders_total_count(None, None, None, None),
+            total_positions: cache.positions_total_count(None, None, None, None),
+            stats_pnls: self.stats_pnls.clone(),
+            stats_returns: self.stats_returns.clone(),
+        }
+    }
+
+    /// Calculates the performance statistics of each venue account with the portfolio
+    /// analyzer, for the results of the run.
+    fn calculate_statistics(&mut self) {
+        self.stats_pnls.clear();
+        self.stats_returns.clear();
+
+        let cache = self.cache.borrow();
+        let mut analyzer = self.portfolio.analyzer();
+        let mut venues: Vec<&Venue> = self.venues.keys().collect();
+        venues.sort();
+
+        for venue in venues {
+            let Some(account) = cache.account_for_venue(venue) else {
+                continue;
+            };
+            let positions: Vec<Position> = cache
+                .positions(Some(venue), None, None, None)
+                .into_iter()
+                .cloned()
+                .collect();
+            match account {
+                AccountAny::Cash(account) => analyzer.calculate_statistics(account, &positions),
+                AccountAny::Margin(account) => analyzer.calculate_statistics(account, &positions),
+            }
+
+            for currency in analyzer.currencies() {
+                match analyzer.get_performance_stats_pnls(Some(currency), None) {
+                    Ok(stats) => {
+                        self.stats_pnls.insert(currency.code.to_string(), stats);
+                    }
+                    Err(e) => log::error!("Error calculating PnL statistics for {currency}: {e}"),
+                }
+            }
+            self.stats_returns
+                .extend(analyzer.get_performance_stats_returns());
+        }
+    }
+
+    /// Returns the `ts_init` and source of the next data point in the run, without consuming
+    /// it, where the source is the index of a data stream or `None` for the added data.
+    ///
+    /// Ties are resolved in favor of the added data, then of the earliest added stream.
+    fn peek_next_data(&mut self) -> anyhow::Result<Option<(UnixNanos, Option<usize>)>> {
+        let mut next = self.data.get(self.index).map(|data| (data.ts_init(), None));
+
+        let cache = self.cache.borrow();
+        for (i, stream) in self.streams.iter_mut().enumerate() {
+            let Some(ts_init) = stream.peek(&cache, self.data_latency_model.as_mut())? else {
+                continue;
+            };
+            if next.is_none_or(|(next_ts, _)| ts_init < next_ts) {
+                next = Some((ts_init, Some(i)));
+            }
+        }
+        Ok(next)
+    }
+
+    /// Consumes and returns the next data point in the run.
+    fn next_data(&mut self) -> anyhow::Result<Option<Data>> {
+        match self.peek_next_data()? {
+            Some((_, Some(i))) => Ok(self.streams[i].buffer.pop_front()),
+            Some((_, None)) => {
+                let data = self.data[self.index].clone();
+                self.index += 1;
+                Ok(Some(data))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn set_time(&self, ts: UnixNanos) {
+        self.clock.borrow().set_time(ts);
+        self.time.set_time(ts);
+    }
+
+    /// Advances the clock to `ts_now`, dispatching time events in order and processing
+    /// the venues at the time of each event.
+    fn advance_time(&mut self, ts_now: UnixNanos) {
+        self.accumulator
+            .advance_clock(&mut self.clock.borrow_mut(), ts_now, false);
+
+        for handler in self.accumulator.drain() {
+            let ts_event = handler.event.ts_event;
+            self.set_time(ts_event);
+            handler.run();
+            self.process_venues(ts_event);
+        }
+
+        self.set_time(ts_now);
+    }
+
+    fn process_venue_data(&mut self, data: &Data) {
+        let venue = data.instrument_id().venue;
+        let Some(exchange) = self.venues.get_mut(&venue) else {
+            return; // Data is only dispatched to actors and strategies
+        };
+
+        profile(
+            &self.profiler,
+            Ustr::from("SimulatedExchange"),
+            || match data {
+                Data::Delta(delta) => exchange.process_order_book_delta(*delta),
+                Data::Deltas(deltas) => exchange.process_order_book_deltas((**deltas).clone()),
+                Data::Depth10(depth) => exchange.process_order_book_depth10(depth),
+                Data::Quote(quote) => exchange.process_quote_tick(quote),
+                Data::Trade(trade) => exchange.process_trade_tick(trade),
+                Data::Bar(bar) => exchange.process_bar(*bar),
+            },
+        );
+    }
+
+    /// Executes queued trading commands through the execution engine, and processes the
+    /// venues at `ts_now`, until no further commands are sent in response to the
+    /// resulting order events.
+    fn process_venues(&mut self, ts_now: UnixNanos) {
+        self.exec_engine.check_inflight_orders();
+
+        loop {
+            let commands: Vec<TradingCommand> = self.command_queue.borrow_mut().drain(..).collect();
+            for command in commands {
+                profile(&self.profiler, Ustr::from("ExecEngine"), || {
+                    self.exec_engine.execute(command);
+                });
+            }
+
+            let commands: Vec<TradingCommand> =
+                self.venue_command_queue.borrow_mut().drain(..).collect();
+            for command in commands {
+                let venue = command.instrument_id().venue;
+                match self.venues.get_mut(&venue) {
+                    Some(exchange) => exchange.send(command),
+                    None => log::error!("Cannot send command {command}, venue {venue} not found"),
+                }
+            }
+
+            for exchange in self.venues.values_mut() {
+                profile(&self.profiler, Ustr::from("SimulatedExchange"), || {
+                    exchange.process(ts_now);
+                });
+            }
+
+            let events: Vec<OrderEventAny> = self.event_queue.borrow_mut().drain(..).collect();
+            for event in events {
+                self.handle_order_event(&event);
+            }
+
+            if self.command_queue.borrow().is_empty()
+                && self.venue_command_queue.borrow().is_empty()
+            {
+                break;
+            }
+        }
+    }
+
+    /// Processes the `event` through the execution engine, which applies it to the cached
+    /// order and position before publishing it to the owning strategy.
+    fn handle_order_event(&mut self, event: &OrderEventAny) {
+        self.total_events += 1;
+        profile(&self.profiler, Ustr::from("ExecEngine"), || {
+            self.exec_engine.process(event);
+        });
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use nautilus_common::timer::{TimeEvent, TimeEventCallback};
+    use std::{collections::HashMap, num::NonZeroUsize};
+
+    use nautilus_common::{
+        messages::execution::SubmitOrder,
+        msgbus::{
+            buffer::OverflowPolicy,
+            stubs::{get_message_saving_handler, get_saved_messages},
+        },
+        timer::{TimeEvent, TimeEventCallback},
+    };
     use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        data::QuoteTick,
+        enums::{AccountType, BookType, OmsType, OrderSide, OrderStatus, OrderType, PositionSide},
+        events::OrderEventType,
+        identifiers::{AccountId, ClientId, InstrumentId, StrategyId, TradeId, VenueOrderId},
+        instruments::{stubs::crypto_perpetual_ethusdt, CryptoPerpetual},
+        orders::{stubs::TestOrderEventStubs, OrderAny, OrderTestBuilder},
+        types::{Currency, Money, Price, Quantity},
+    };
     use pyo3::{prelude::*, types::PyList, Py, Python};
     use rstest::*;
+    use rust_decimal::Decimal;
     use ustr::Ustr;
 
     use super::*;
+    use crate::models::{
+        fee::{FeeModelAny, MakerTakerFeeModel},
+        fill::FillModel,
+        latency::{FeedDelay, LatencyModel},
+        liquidation::LiquidationModel,
+    };
+
+    fn get_engine(instrument: &InstrumentAny) -> BacktestEngine {
+        let mut engine = BacktestEngine::new(BacktestEngineConfig::default());
+        let exchange = SimulatedExchange::new(
+            instrument.id().venue,
+            OmsType::Netting,
+            AccountType::Margin,
+            vec![Money::new(10_000.0, Currency::USDT())],
+            None,
+            1.into(),
+            HashMap::new(),
+            vec![],
+            engine.msgbus(),
+            engine.cache(),
+            engine.time(),
+            FillModel::default(),
+            FeeModelAny::MakerTaker(MakerTakerFeeModel),
+            LatencyModel::default(),
+            BookType::L1_MBP,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        engine.add_venue(exchange).unwrap();
+        engine.add_instrument(instrument.clone()).unwrap();
+        engine
+    }
+
+    fn get_quote(instrument_id: InstrumentId, bid: &str, ask: &str, ts: u64) -> Data {
+        Data::Quote(QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            ts.into(),
+            ts.into(),
+        ))
+    }
+
+    fn submit_market_order(
+        engine: &BacktestEngine,
+        instrument: &InstrumentAny,
+        side: OrderSide,
+        quantity: &str,
+    ) -> OrderAny {
+        let venue = instrument.id().venue;
+        let mut order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .build();
+        order
+            .apply(TestOrderEventStubs::order_submitted(
+                &order,
+                AccountId::new(format!("{venue}-001")),
+            ))
+            .unwrap();
+        engine
+            .cache()
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+
+        let msgbus = engine.msgbus();
+        let msgbus = msgbus.borrow();
+        msgbus.send(
+            &msgbus.switchboard.exec_engine_execute,
+            &submit_order_command(&order) as &dyn Any,
+        );
+        order
+    }
+
+    fn submit_order_command(order: &OrderAny) -> TradingCommand {
+        let venue = order.instrument_id().venue;
+        TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                order.trader_id(),
+                ClientId::new(venue.as_str()),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                VenueOrderId::from("1"),
+                order.clone(),
+                None,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    /// Buys once on the first quote, through the risk engine.
+    struct BuyOnceStrategy {
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        started: bool,
+        stopped: bool,
+        submitted: bool,
+        events: Vec<OrderEventAny>,
+    }
+
+    impl BuyOnceStrategy {
+        fn new(engine: &BacktestEngine, strategy_id: &str, instrument_id: InstrumentId) -> Self {
+            Self {
+                strategy_id: StrategyId::from(strategy_id),
+                instrument_id,
+                cache: engine.cache(),
+                msgbus: engine.msgbus(),
+                started: false,
+                stopped: false,
+                submitted: false,
+                events: Vec::new(),
+            }
+        }
+    }
+
+    impl Actor for BuyOnceStrategy {
+        fn id(&self) -> Ustr {
+            Ustr::from(self.strategy_id.as_str())
+        }
+
+        fn subscriptions(&self) -> Vec<Ustr> {
+            vec![Ustr::from(&format!(
+                "data.quotes.{}.*",
+                self.instrument_id.venue
+            ))]
+        }
+
+        fn on_start(&mut self) -> anyhow::Result<()> {
+            self.started = true;
+            Ok(())
+        }
+
+        fn on_stop(&mut self) -> anyhow::Result<()> {
+            self.stopped = true;
+            Ok(())
+        }
+
+        fn on_quote(&mut self, _quote: &QuoteTick) {
+            if self.submitted {
+                return;
+            }
+            self.submitted = true;
+
+            let venue = self.instrument_id.venue;
+            let mut order = OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(self.instrument_id)
+                .strategy_id(self.strategy_id)
+                .side(OrderSide::Buy)
+                .quantity(Quantity::from("1.000"))
+                .build();
+            order
+                .apply(TestOrderEventStubs::order_submitted(
+                    &order,
+                    AccountId::new(format!("{venue}-001")),
+                ))
+                .unwrap();
+            self.cache
+                .borrow_mut()
+                .add_order(order.clone(), None, None, false)
+                .unwrap();
+
+            let msgbus = self.msgbus.borrow();
+            msgbus.send(
+                &msgbus.switchboard.risk_engine_execute,
+                &submit_order_command(&order) as &dyn Any,
+            );
+        }
+
+        fn on_order_event(&mut self, event: &OrderEventAny) {
+            self.events.push(event.clone());
+        }
+    }
+
+    impl Strategy for BuyOnceStrategy {
+        fn strategy_id(&self) -> StrategyId {
+            self.strategy_id
+        }
+    }
 
     #[rstest]
     fn test_accumulator_drain_sorted() {
@@ -119,4 +1195,449 @@ mod tests {
             assert_eq!(drained_handlers[2].event.ts_event, time_event2.ts_event);
         });
     }
+
+    #[rstest]
+    fn test_run_dispatches_data_in_timestamp_order(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut engine = get_engine(&instrument);
+        let handler = get_message_saving_handler::<QuoteTick>(None);
+        {
+            let msgbus = engine.msgbus();
+            let mut msgbus = msgbus.borrow_mut();
+            let topic = msgbus.switchboard.get_quotes_topic(instrument.id());
+            msgbus.subscribe(topic, handler.clone(), None);
+        }
+        engine
+            .add_data(vec![
+                get_quote(instrument.id(), "1000.00", "1001.00", 3_000),
+                get_quote(instrument.id(), "1001.00", "1002.00", 1_000),
+                get_quote(instrument.id(), "1002.00", "1003.00", 2_000),
+            ])
+            .unwrap();
+
+        engine.run(None, None).unwrap();
+
+        let quotes = get_saved_messages::<QuoteTick>(handler);
+        let result = engine.get_result();
+        assert_eq!(quotes.len(), 3);
+        assert_eq!(quotes[0].ts_init, UnixNanos::from(1_000));
+        assert_eq!(quotes[2].ts_init, UnixNanos::from(3_000));
+        assert_eq!(result.iterations, 3);
+        assert_eq!(result.backtest_start, Some(UnixNanos::from(1_000)));
+        assert_eq!(result.backtest_end, Some(UnixNanos::from(3_000)));
+        assert_eq!(
+            engine
+                .get_venue(&instrument.id().venue)
+                .unwrap()
+                .best_ask_price(instrument.id()),
+            Some(Price::from("1001.00"))
+        );
+    }
+
+    #[rstest]
+    fn test_run_merges_data_iterator_with_added_data(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut engine = get_engine(&instrument);
+        let handler = get_message_saving_handler::<QuoteTick>(None);
+        {
+            let msgbus = engine.msgbus();
+            let mut msgbus = msgbus.borrow_mut();
+            let topic = msgbus.switchboard.get_quotes_topic(instrument.id());
+            msgbus.subscribe(topic, handler.clone(), None);
+        }
+        engine
+            .add_data(vec![
+                get_quote(instrument.id(), "1000.00", "1001.00", 2_000),
+                get_quote(instrument.id(), "1001.00", "1002.00", 5_000),
+            ])
+            .unwrap();
+        let batches = vec![
+            vec![
+                get_quote(instrument.id(), "1002.00", "1003.00", 1_000),
+                get_quote(instrument.id(), "1003.00", "1004.00", 3_000),
+            ],
+            vec![],
+            vec![get_quote(instrument.id(), "1004.00", "1005.00", 4_000)],
+        ];
+        engine.add_data_iterator("quotes", batches.into_iter());
+
+        engine.run(None, None).unwrap();
+
+        let ts_inits: Vec<u64> = get_saved_messages::<QuoteTick>(handler)
+            .iter()
+            .map(|quote| quote.ts_init.as_u64())
+            .collect();
+        let result = engine.get_result();
+        assert_eq!(ts_inits, vec![1_000, 2_000, 3_000, 4_000, 5_000]);
+        assert_eq!(result.iterations, 5);
+        assert_eq!(result.backtest_start, Some(UnixNanos::from(1_000)));
+        assert_eq!(result.backtest_end, Some(UnixNanos::from(5_000)));
+    }
+
+    #[rstest]
+    fn test_run_data_iterator_for_unknown_instrument(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut engine = BacktestEngine::new(BacktestEngineConfig::default());
+        let batches = vec![vec![get_quote(
+            crypto_perpetual_ethusdt.id,
+            "1000.00",
+            "1001.00",
+            1_000,
+        )]];
+        engine.add_data_iterator("quotes", batches.into_iter());
+
+        assert!(engine.run(None, None).is_err());
+    }
+
+    #[rstest]
+    fn test_run_with_profiling_profiles_engine_components(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut engine = get_engine(&instrument);
+        engine.set_profiling(true);
+        engine
+            .add_data(vec![get_quote(
+                instrument.id(),
+                "1000.00",
+                "1001.00",
+                1_000,
+            )])
+            .unwrap();
+
+        engine.run(None, None).unwrap();
+
+        let components = engine.profiler().borrow().components();
+        assert!(components.contains(&Ustr::from("DataEngine")));
+        assert!(components.contains(&Ustr::from("SimulatedExchange")));
+    }
+
+    #[rstest]
+    fn test_run_drains_buffered_subscriptions(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut engine = get_engine(&instrument);
+        let handler = get_message_saving_handler::<QuoteTick>(None);
+        {
+            let msgbus = engine.msgbus();
+            let mut msgbus = msgbus.borrow_mut();
+            let topic = msgbus.switchboard.get_quotes_topic(instrument.id());
+            msgbus.subscribe_buffered(
+                topic,
+                handler.clone(),
+                None,
+                NonZeroUsize::new(1).unwrap(),
+                OverflowPolicy::DropOldest,
+            );
+        }
+        engine
+            .add_data(vec![
+                get_quote(instrument.id(), "1000.00", "1001.00", 1_000),
+                get_quote(instrument.id(), "1001.00", "1002.00", 2_000),
+            ])
+            .unwrap();
+
+        engine.run(None, None).unwrap();
+
+        // Each quote is delivered before the next is published, so none are dropped
+        assert_eq!(get_saved_messages::<QuoteTick>(handler).len(), 2);
+        assert_eq!(engine.msgbus().borrow().buffer_stats()[0].dropped, 0);
+    }
+
+    #[rstest]
+    fn test_add_data_for_unknown_instrument(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut engine = BacktestEngine::new(BacktestEngineConfig::default());
+        let result = engine.add_data(vec![get_quote(
+            crypto_perpetual_ethusdt.id,
+            "1000.00",
+            "1001.00",
+            1_000,
+        )]);
+
+        assert!(result.is_err());
+        assert!(engine.run(None, None).is_err());
+    }
+
+    #[rstest]
+    fn test_msgbus_uses_sequenced_dispatch() {
+        let engine = BacktestEngine::new(BacktestEngineConfig::default());
+
+        assert_eq!(
+            engine.msgbus().borrow().dispatch_mode(),
+            DispatchMode::Sequenced
+        );
+    }
+
+    #[rstest]
+    fn test_run_routes_submitted_order_to_venue(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut engine = get_engine(&instrument);
+        engine
+            .add_data(vec![get_quote(
+                instrument.id(),
+                "1000.00",
+                "1001.00",
+                1_000,
+            )])
+            .unwrap();
+
+        let mut order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000"))
+            .build();
+        order
+            .apply(TestOrderEventStubs::order_submitted(
+                &order,
+                AccountId::from("BINANCE-001"),
+            ))
+            .unwrap();
+        let client_order_id = order.client_order_id();
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        {
+            let msgbus = engine.msgbus();
+            let mut msgbus = msgbus.borrow_mut();
+            msgbus.subscribe(
+                format!("events.order.{}", order.strategy_id()),
+                handler.clone(),
+                None,
+            );
+        }
+        engine
+            .cache()
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+        let command = TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                order.trader_id(),
+                ClientId::from("BINANCE"),
+                order.strategy_id(),
+                instrument.id(),
+                client_order_id,
+                VenueOrderId::from("1"),
+                order,
+                None,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        );
+        {
+            let msgbus = engine.msgbus();
+            let msgbus = msgbus.borrow();
+            msgbus.send(
+                &msgbus.switchboard.exec_engine_execute,
+                &command as &dyn Any,
+            );
+        }
+
+        engine.run(None, None).unwrap();
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        let cache = engine.cache();
+        let cache = cache.borrow();
+        assert_eq!(events.last().unwrap().event_type(), OrderEventType::Filled);
+        assert_eq!(
+            cache.order(&client_order_id).unwrap().status(),
+            OrderStatus::Filled
+        );
+        assert_eq!(engine.get_result().total_orders, 1);
+        assert_eq!(engine.get_result().total_events, events.len());
+    }
+
+    #[rstest]
+    fn test_run_fill_opens_position_and_updates_account_balance(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut engine = get_engine(&instrument);
+        engine
+            .add_data(vec![get_quote(
+                instrument.id(),
+                "1000.00",
+                "1001.00",
+                1_000,
+            )])
+            .unwrap();
+        let order = submit_market_order(&engine, &instrument, OrderSide::Buy, "1.000");
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        {
+            let msgbus = engine.msgbus();
+            let mut msgbus = msgbus.borrow_mut();
+            msgbus.subscribe(
+                format!("events.order.{}", order.strategy_id()),
+                handler.clone(),
+                None,
+            );
+        }
+
+        engine.run(None, None).unwrap();
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        let Some(OrderEventAny::Filled(fill)) = events.last() else {
+            panic!("Expected a fill, was {events:?}");
+        };
+        let commission = fill.commission.unwrap();
+        let cache = engine.cache();
+        let cache = cache.borrow();
+        let positions = cache.positions_open(None, Some(&instrument.id()), None, None);
+        let account = cache.account_for_venue(&instrument.id().venue).unwrap();
+        let balance = account.balances()[&Currency::USDT()];
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].side, PositionSide::Long);
+        assert_eq!(positions[0].quantity, Quantity::from("1.000"));
+        assert_eq!(positions[0].avg_px_open, 1001.0);
+        assert!(commission.as_f64() > 0.0);
+        assert_eq!(
+            balance.total,
+            Money::new(10_000.0, Currency::USDT()) - commission
+        );
+    }
+
+    #[rstest]
+    fn test_run_liquidates_position_when_equity_below_maintenance_margin(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut engine = get_engine(&instrument);
+        {
+            let exchange = engine.venues.get_mut(&instrument.id().venue).unwrap();
+            exchange.set_leverage(instrument.id(), Decimal::TEN);
+            exchange.set_liquidation_model(LiquidationModel::default());
+        }
+        engine
+            .add_data(vec![
+                Data::Quote(QuoteTick::new(
+                    instrument.id(),
+                    Price::from("1000.00"),
+                    Price::from("1001.00"),
+                    Quantity::from("20.000"),
+                    Quantity::from("20.000"),
+                    1_000.into(),
+                    1_000.into(),
+                )),
+                // Equity falls below the maintenance margin at a mark price of 510.50
+                get_quote(instrument.id(), "510.00", "511.00", 2_000),
+            ])
+            .unwrap();
+        let order = submit_market_order(&engine, &instrument, OrderSide::Buy, "20.000");
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        {
+            let msgbus = engine.msgbus();
+            let mut msgbus = msgbus.borrow_mut();
+            msgbus.subscribe(
+                format!("events.order.{}", order.strategy_id()),
+                handler.clone(),
+                None,
+            );
+        }
+
+        engine.run(None, None).unwrap();
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        let Some(OrderEventAny::Filled(fill)) = events.last() else {
+            panic!("Expected a liquidation fill, was {events:?}");
+        };
+        let cache = engine.cache();
+        let cache = cache.borrow();
+        assert_ne!(fill.client_order_id, order.client_order_id());
+        assert_eq!(fill.trade_id, TradeId::from("BINANCE-LIQ-1"));
+        assert_eq!(fill.order_side, OrderSide::Sell);
+        assert_eq!(fill.last_qty, Quantity::from("20.000"));
+        assert_eq!(fill.last_px, Price::from("510.50"));
+        assert_eq!(
+            cache.order(&fill.client_order_id).unwrap().status(),
+            OrderStatus::Filled
+        );
+        assert!(cache
+            .positions_open(None, Some(&instrument.id()), None, None)
+            .is_empty());
+        assert_eq!(
+            cache
+                .positions_closed(None, Some(&instrument.id()), None, None)
+                .len(),
+            1
+        );
+    }
+
+    #[rstest]
+    fn test_add_data_applies_data_latency_model(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut engine = get_engine(&instrument);
+        engine.set_data_latency_model(DataLatencyModel::new(FeedDelay::Fixed(500), Some(42)));
+        let handler = get_message_saving_handler::<QuoteTick>(None);
+        {
+            let msgbus = engine.msgbus();
+            let mut msgbus = msgbus.borrow_mut();
+            let topic = msgbus.switchboard.get_quotes_topic(instrument.id());
+            msgbus.subscribe(topic, handler.clone(), None);
+        }
+        engine
+            .add_data(vec![
+                get_quote(instrument.id(), "1001.00", "1002.00", 2_000),
+                get_quote(instrument.id(), "1000.00", "1001.00", 1_000),
+            ])
+            .unwrap();
+
+        engine.run(None, None).unwrap();
+
+        let quotes = get_saved_messages::<QuoteTick>(handler);
+        let result = engine.get_result();
+        assert_eq!(quotes.len(), 2);
+        assert_eq!(quotes[0].ts_event, UnixNanos::from(1_000));
+        assert_eq!(quotes[0].ts_init, UnixNanos::from(1_500));
+        assert_eq!(quotes[1].ts_init, UnixNanos::from(2_500));
+        assert_eq!(result.backtest_start, Some(UnixNanos::from(1_500)));
+        assert_eq!(result.backtest_end, Some(UnixNanos::from(2_500)));
+    }
+
+    #[rstest]
+    fn test_run_dispatches_to_strategy_through_risk_engine(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut engine = get_engine(&instrument);
+        engine
+            .add_data(vec![
+                get_quote(instrument.id(), "1000.00", "1001.00", 1_000),
+                get_quote(instrument.id(), "1010.00", "1011.00", 2_000),
+            ])
+            .unwrap();
+        let strategy = Rc::new(RefCell::new(BuyOnceStrategy::new(
+            &engine,
+            "S-001",
+            instrument.id(),
+        )));
+        engine.add_strategy(strategy.clone()).unwrap();
+
+        engine.run(None, None).unwrap();
+
+        let strategy = strategy.borrow();
+        let result = engine.get_result();
+        assert!(strategy.started);
+        assert!(strategy.stopped);
+        assert_eq!(
+            strategy.events.last().unwrap().event_type(),
+            OrderEventType::Filled
+        );
+        assert_eq!(result.total_orders, 1);
+        assert_eq!(result.total_positions, 1);
+        assert!(result.stats_pnls["USDT"]["PnL (total)"] < 0.0); // Commission paid
+    }
+
+    #[rstest]
+    fn test_add_strategy_with_duplicate_id(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut engine = get_engine(&instrument);
+        let strategy1 = BuyOnceStrategy::new(&engine, "S-001", instrument.id());
+        let strategy2 = BuyOnceStrategy::new(&engine, "S-001", instrument.id());
+
+        engine
+            .add_strategy(Rc::new(RefCell::new(strategy1)))
+            .unwrap();
+
+        assert!(engine
+            .add_strategy(Rc::new(RefCell::new(strategy2)))
+            .is_err());
+    }
 }
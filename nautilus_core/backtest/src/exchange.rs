@@ -19,55 +19,106 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{
+    any::Any,
+    cell::RefCell,
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    rc::Rc,
+};
 
-use nautilus_common::{cache::Cache, msgbus::MessageBus};
+use nautilus_common::{cache::Cache, messages::execution::TradingCommand, msgbus::MessageBus};
 use nautilus_core::{
     correctness::{check_equal, FAILED},
     nanos::UnixNanos,
     time::AtomicTime,
+    uuid::UUID4,
 };
-use nautilus_execution::{client::ExecutionClient, messages::TradingCommand};
+use nautilus_execution::client::ExecutionClient;
 use nautilus_model::{
-    accounts::AccountAny,
+    accounts::{AccountAny, CashAccount, MarginAccount},
     data::{
         Bar, Data, InstrumentStatus, OrderBookDelta, OrderBookDeltas, OrderBookDeltas_API,
-        QuoteTick, TradeTick,
+        OrderBookDepth10, QuoteTick, TradeTick,
     },
-    enums::{AccountType, BookType, OmsType},
-    identifiers::{InstrumentId, Venue},
+    enums::{AccountType, BookType, LiquiditySide, OmsType, OrderType, TimeInForce},
+    events::{AccountState, OrderAccepted, OrderEventAny, OrderFilled, OrderSubmitted},
+    identifiers::{AccountId, ClientOrderId, InstrumentId, TradeId, Venue, VenueOrderId},
     instruments::InstrumentAny,
     orderbook::OrderBook,
-    orders::PassiveOrderAny,
-    types::{Currency, Money, Price},
+    orders::{MarketOrder, OrderAny, PassiveOrderAny},
+    types::{AccountBalance, Currency, Money, Price, Quantity},
+};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
 };
-use rust_decimal::Decimal;
+use ustr::Ustr;
 
 use crate::{
     matching_engine::{config::OrderMatchingEngineConfig, OrderMatchingEngine},
-    models::{fee::FeeModelAny, fill::FillModel, latency::LatencyModel},
+    models::{
+        fee::FeeModelAny,
+        fill::FillModel,
+        latency::LatencyModel,
+        liquidation::{Liquidation, LiquidationModel, MarginEvent, MarkedPosition},
+    },
     modules::SimulationModule,
 };
 
+/// A trading command in flight to the venue, ordered by its arrival time.
+struct InflightCommand {
+    ts: UnixNanos,
+    counter: u32,
+    command: TradingCommand,
+}
+
+impl PartialEq for InflightCommand {
+    fn eq(&self, other: &Self) -> bool {
+        self.ts == other.ts && self.counter == other.counter
+    }
+}
+
+impl Eq for InflightCommand {}
+
+impl PartialOrd for InflightCommand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for InflightCommand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed for a min-heap, the earliest arrival is processed first
+        (other.ts, other.counter).cmp(&(self.ts, self.counter))
+    }
+}
+
 pub struct SimulatedExchange {
     id: Venue,
     oms_type: OmsType,
     account_type: AccountType,
+    starting_balances: Vec<Money>,
+    base_currency: Option<Currency>,
     book_type: BookType,
     default_leverage: Decimal,
     exec_client: Option<ExecutionClient>,
     fee_model: FeeModelAny,
+    fee_model_overrides: HashMap<InstrumentId, FeeModelAny>,
     fill_model: FillModel,
     latency_model: LatencyModel,
+    liquidation_model: Option<LiquidationModel>,
     instruments: HashMap<InstrumentId, InstrumentAny>,
     matching_engines: HashMap<InstrumentId, OrderMatchingEngine>,
     leverages: HashMap<InstrumentId, Decimal>,
+    margin_overrides: HashMap<InstrumentId, (Decimal, Decimal)>,
     modules: Vec<Box<dyn SimulationModule>>,
     clock: &'static AtomicTime,
     msgbus: Rc<RefCell<MessageBus>>,
     cache: Rc<RefCell<Cache>>,
     frozen_account: bool,
     bar_execution: bool,
+    bar_adaptive_high_low_ordering: bool,
     reject_stop_orders: bool,
     support_gtd_orders: bool,
     support_contingent_orders: bool,
@@ -75,6 +126,10 @@ pub struct SimulatedExchange {
     use_random_ids: bool,
     use_reduce_only: bool,
     use_message_queue: bool,
+    message_queue: VecDeque<TradingCommand>,
+    inflight_queue: BinaryHeap<InflightCommand>,
+    inflight_counter: HashMap<UnixNanos, u32>,
+    liquidation_count: u32,
 }
 
 impl SimulatedExchange {
@@ -98,6 +153,7 @@ impl SimulatedExchange {
         book_type: BookType,
         frozen_account: Option<bool>,
         bar_execution: Option<bool>,
+        bar_adaptive_high_low_ordering: Option<bool>,
         reject_stop_orders: Option<bool>,
         support_gtd_orders: Option<bool>,
         support_contingent_orders: Option<bool>,
@@ -117,21 +173,27 @@ impl SimulatedExchange {
             id: venue,
             oms_type,
             account_type,
+            starting_balances,
+            base_currency,
             book_type,
             default_leverage,
             exec_client: None,
             fee_model,
+            fee_model_overrides: HashMap::new(),
             fill_model,
             latency_model,
+            liquidation_model: None,
             instruments: HashMap::new(),
             matching_engines: HashMap::new(),
             leverages,
+            margin_overrides: HashMap::new(),
             modules,
             clock,
             msgbus,
             cache,
             frozen_account: frozen_account.unwrap_or(false),
             bar_execution: bar_execution.unwrap_or(true),
+            bar_adaptive_high_low_ordering: bar_adaptive_high_low_ordering.unwrap_or(false),
             reject_stop_orders: reject_stop_orders.unwrap_or(true),
             support_gtd_orders: support_gtd_orders.unwrap_or(true),
             support_contingent_orders: support_contingent_orders.unwrap_or(true),
@@ -139,9 +201,37 @@ impl SimulatedExchange {
             use_random_ids: use_random_ids.unwrap_or(false),
             use_reduce_only: use_reduce_only.unwrap_or(true),
             use_message_queue: use_message_queue.unwrap_or(true),
+            message_queue: VecDeque::new(),
+            inflight_queue: BinaryHeap::new(),
+            inflight_counter: HashMap::new(),
+            liquidation_count: 0,
         })
     }
 
+    /// Returns the venue for the exchange.
+    #[must_use]
+    pub const fn id(&self) -> Venue {
+        self.id
+    }
+
+    /// Returns the order management system type for the exchange.
+    #[must_use]
+    pub const fn oms_type(&self) -> OmsType {
+        self.oms_type
+    }
+
+    /// Returns the account type for the exchange.
+    #[must_use]
+    pub const fn account_type(&self) -> AccountType {
+        self.account_type
+    }
+
+    /// Returns the base currency of the exchange account, if single-currency.
+    #[must_use]
+    pub const fn base_currency(&self) -> Option<Currency> {
+        self.base_currency
+    }
+
     pub fn register_client(&mut self, client: ExecutionClient) {
         let client_id = client.client_id;
         self.exec_client = Some(client);
@@ -160,13 +250,161 @@ impl SimulatedExchange {
         self.fill_model = fill_model;
     }
 
+    /// Sets the fee model for the given `instrument_id`, or the default fee model for all
+    /// instruments without an override when `None`.
+    pub fn set_fee_model(&mut self, fee_model: FeeModelAny, instrument_id: Option<InstrumentId>) {
+        match instrument_id {
+            Some(instrument_id) => {
+                if let Some(matching_engine) = self.matching_engines.get_mut(&instrument_id) {
+                    matching_engine.set_fee_model(fee_model.clone());
+                }
+                self.fee_model_overrides.insert(instrument_id, fee_model);
+                log::info!("Setting fee model for {instrument_id}");
+            }
+            None => {
+                for (instrument_id, matching_engine) in &mut self.matching_engines {
+                    if !self.fee_model_overrides.contains_key(instrument_id) {
+                        matching_engine.set_fee_model(fee_model.clone());
+                    }
+                }
+                self.fee_model = fee_model;
+                log::info!("Setting default fee model for {}", self.id);
+            }
+        }
+    }
+
     pub fn set_latency_model(&mut self, latency_model: LatencyModel) {
         self.latency_model = latency_model;
         log::info!("Setting latency model to {}", self.latency_model);
     }
 
-    pub fn initialize_account(&mut self, _account_id: u64) {
-        todo!("initialize account")
+    pub fn set_liquidation_model(&mut self, liquidation_model: LiquidationModel) {
+        log::info!("Setting liquidation model to {liquidation_model}");
+        self.liquidation_model = Some(liquidation_model);
+    }
+
+    /// Sets the `leverage` for the given `instrument_id`, overriding the default leverage.
+    ///
+    /// The leverage is also applied to the venue margin account (if initialized).
+    pub fn set_leverage(&mut self, instrument_id: InstrumentId, leverage: Decimal) {
+        log::info!("Setting leverage for {instrument_id} to {leverage}");
+        self.leverages.insert(instrument_id, leverage);
+
+        let mut cache = self.cache.as_ref().borrow_mut();
+        if let Some(AccountAny::Margin(account)) = cache.account_for_venue(&self.id) {
+            let mut account = account.clone();
+            account.set_leverage(instrument_id, leverage.to_f64().unwrap_or(1.0));
+            if let Err(e) = cache.update_account(AccountAny::Margin(account)) {
+                log::error!("Cannot update account leverage: {e}");
+            }
+        }
+    }
+
+    /// Returns the leverage for the given `instrument_id`.
+    #[must_use]
+    pub fn leverage(&self, instrument_id: InstrumentId) -> Decimal {
+        self.leverages
+            .get(&instrument_id)
+            .copied()
+            .unwrap_or(self.default_leverage)
+    }
+
+    /// Sets the initial and maintenance margin rates for the given `instrument_id`,
+    /// overriding the rates of the instrument definition.
+    pub fn set_margin_rates(
+        &mut self,
+        instrument_id: InstrumentId,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+    ) {
+        log::info!(
+            "Setting margin rates for {instrument_id} to init={margin_init}, maint={margin_maint}"
+        );
+        self.margin_overrides
+            .insert(instrument_id, (margin_init, margin_maint));
+    }
+
+    /// Returns the initial and maintenance margin rates for the given `instrument`.
+    #[must_use]
+    pub fn margin_rates(&self, instrument: &InstrumentAny) -> (Decimal, Decimal) {
+        self.margin_overrides
+            .get(&instrument.id())
+            .copied()
+            .unwrap_or_else(|| (instrument.margin_init(), instrument.margin_maint()))
+    }
+
+    /// Returns the initial margin required to open a position of `quantity` at `price`
+    /// for the given `instrument_id`, accounting for its leverage.
+    #[must_use]
+    pub fn initial_margin(
+        &self,
+        instrument_id: InstrumentId,
+        quantity: Quantity,
+        price: Price,
+    ) -> Option<Money> {
+        let instrument = self.instruments.get(&instrument_id)?;
+        let notional = instrument.calculate_notional_value(quantity, price, None);
+        let leverage = self.leverage(instrument_id).max(Decimal::ONE);
+        let (margin_init, _) = self.margin_rates(instrument);
+        let margin = notional.as_f64() / leverage.to_f64().unwrap_or(1.0)
+            * margin_init.to_f64().unwrap_or(0.0);
+        Some(Money::new(margin, notional.currency))
+    }
+
+    /// Initializes the venue account of the registered execution client with the
+    /// starting balances.
+    ///
+    /// The account is added to the cache as a calculated account, so its balances are
+    /// updated by the portfolio as orders are filled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no execution client is registered, or the account already exists.
+    pub fn initialize_account(&mut self) -> anyhow::Result<()> {
+        let Some(client) = &self.exec_client else {
+            anyhow::bail!(
+                "Cannot initialize account: no execution client registered for {}",
+                self.id
+            );
+        };
+
+        let ts_now = self.clock.get_time_ns();
+        let account_state = AccountState::new(
+            client.account_id,
+            self.account_type,
+            self.starting_account_balances(),
+            vec![],
+            true,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            self.base_currency,
+        );
+        let account = match self.account_type {
+            AccountType::Cash => AccountAny::Cash(CashAccount::new(account_state, true)),
+            AccountType::Margin => {
+                let mut account = MarginAccount::new(account_state, true);
+                account.set_default_leverage(self.default_leverage.to_f64().unwrap_or(1.0));
+                for (instrument_id, leverage) in &self.leverages {
+                    account.set_leverage(*instrument_id, leverage.to_f64().unwrap_or(1.0));
+                }
+                AccountAny::Margin(account)
+            }
+            AccountType::Betting => anyhow::bail!("Betting accounts are not supported"),
+        };
+        self.cache.as_ref().borrow_mut().add_account(account)?;
+
+        log::info!("Initialized account {}", client.account_id);
+        Ok(())
+    }
+
+    fn starting_account_balances(&self) -> Vec<AccountBalance> {
+        self.starting_balances
+            .iter()
+            .map(|balance| {
+                AccountBalance::new(*balance, Money::new(0.0, balance.currency), *balance)
+            })
+            .collect()
     }
 
     pub fn add_instrument(&mut self, instrument: InstrumentAny) -> anyhow::Result<()> {
@@ -189,6 +427,7 @@ impl SimulatedExchange {
 
         let matching_engine_config = OrderMatchingEngineConfig::new(
             self.bar_execution,
+            self.bar_adaptive_high_low_ordering,
             self.reject_stop_orders,
             self.support_gtd_orders,
             self.support_contingent_orders,
@@ -201,6 +440,10 @@ impl SimulatedExchange {
             instrument,
             self.instruments.len() as u32,
             self.fill_model.clone(),
+            self.fee_model_overrides
+                .get(&instrument_id)
+                .cloned()
+                .unwrap_or_else(|| self.fee_model.clone()),
             self.book_type,
             self.oms_type,
             self.account_type,
@@ -309,16 +552,85 @@ impl SimulatedExchange {
             .map(nautilus_execution::client::ExecutionClient::get_account)
     }
 
-    pub fn adjust_account(&mut self, _adjustment: Money) {
-        todo!("adjust account")
+    /// Adjusts the venue account balance in the currency of the `adjustment`, such as
+    /// for funding payments or rollover interest.
+    pub fn adjust_account(&mut self, adjustment: Money) {
+        if self.frozen_account {
+            return; // Nothing to adjust
+        }
+
+        let Some(client) = &self.exec_client else {
+            log::error!(
+                "Cannot adjust account: no execution client registered for {}",
+                self.id
+            );
+            return;
+        };
+
+        let (balance, margins) = {
+            let cache = self.cache.as_ref().borrow();
+            let Some(account) = cache.account_for_venue(&self.id) else {
+                log::error!("Cannot adjust account: no account found for {}", self.id);
+                return;
+            };
+            let Some(balance) = account.balances().get(&adjustment.currency).copied() else {
+                log::error!(
+                    "Cannot adjust account: no balance found for {}",
+                    adjustment.currency
+                );
+                return;
+            };
+            let margins = match account {
+                AccountAny::Margin(margin_account) => {
+                    margin_account.margins.values().copied().collect()
+                }
+                AccountAny::Cash(_) => vec![],
+            };
+            (balance, margins)
+        };
+
+        let balance = AccountBalance::new(
+            balance.total + adjustment,
+            balance.locked,
+            balance.free + adjustment,
+        );
+        if let Err(e) =
+            client.generate_account_state(vec![balance], margins, true, self.clock.get_time_ns())
+        {
+            log::error!("Error adjusting account: {e}");
+        }
     }
 
-    pub fn send(&self, _command: TradingCommand) {
-        todo!("send")
+    /// Sends the given trading `command` to the exchange.
+    ///
+    /// With the message queue enabled the command arrives after the latency sampled
+    /// from the latency model, and is processed on the next call to `process`.
+    pub fn send(&mut self, command: TradingCommand) {
+        if !self.use_message_queue {
+            self.process_trading_command(command);
+            return;
+        }
+
+        let inflight = self.generate_inflight_command(command);
+        if inflight.ts == self.clock.get_time_ns() {
+            self.message_queue.push_back(inflight.command);
+        } else {
+            self.inflight_queue.push(inflight);
+        }
     }
 
-    pub fn generate_inflight_command(&self, _command: TradingCommand) {
-        todo!("generate inflight command")
+    fn generate_inflight_command(&mut self, command: TradingCommand) -> InflightCommand {
+        let ts_init = command.ts_init();
+        let counter = self.inflight_counter.entry(ts_init).or_default();
+        *counter += 1;
+        let counter = *counter;
+
+        let latency = self.latency_model.command_latency(&command);
+        InflightCommand {
+            ts: ts_init + latency,
+            counter,
+            command,
+        }
     }
 
     pub fn process_order_book_delta(&mut self, delta: OrderBookDelta) {
@@ -377,6 +689,34 @@ impl SimulatedExchange {
         }
     }
 
+    pub fn process_order_book_depth10(&mut self, depth: &OrderBookDepth10) {
+        for module in &self.modules {
+            module.pre_process(Data::Depth10(depth.clone()));
+        }
+
+        if !self.matching_engines.contains_key(&depth.instrument_id) {
+            let instrument = {
+                let cache = self.cache.as_ref().borrow();
+                cache.instrument(&depth.instrument_id).cloned()
+            };
+
+            if let Some(instrument) = instrument {
+                self.add_instrument(instrument).unwrap();
+            } else {
+                panic!(
+                    "No matching engine found for instrument {}",
+                    depth.instrument_id
+                );
+            }
+        }
+
+        if let Some(matching_engine) = self.matching_engines.get_mut(&depth.instrument_id) {
+            matching_engine.process_order_book_depth10(depth);
+        } else {
+            panic!("Matching engine should be initialized");
+        }
+    }
+
     pub fn process_quote_tick(&mut self, quote: &QuoteTick) {
         for module in &self.modules {
             module.pre_process(Data::Quote(quote.to_owned()));
@@ -487,20 +827,384 @@ impl SimulatedExchange {
         }
     }
 
-    pub fn process(&mut self, _ts_now: UnixNanos) {
-        todo!("process")
+    /// Returns the mark price for the given `instrument_id`, taken as the mid of the
+    /// best bid and ask (or either side if the other is not available).
+    #[must_use]
+    pub fn mark_price(&self, instrument_id: InstrumentId) -> Option<Price> {
+        let instrument = self.instruments.get(&instrument_id)?;
+        match (
+            self.best_bid_price(instrument_id),
+            self.best_ask_price(instrument_id),
+        ) {
+            (Some(bid), Some(ask)) => {
+                Some(instrument.make_price((bid.as_f64() + ask.as_f64()) / 2.0))
+            }
+            (Some(price), None) | (None, Some(price)) => Some(price),
+            (None, None) => None,
+        }
+    }
+
+    /// Checks the margin of the venue account against its open positions, issuing margin
+    /// calls and liquidating positions at the mark price when equity falls below maintenance.
+    ///
+    /// Margin calls are published on the `events.margin.{venue}` topic, and liquidations
+    /// generate `OrderFilled` events sent to the execution engine.
+    pub fn check_margin(&mut self, ts_now: UnixNanos) -> Vec<MarginEvent> {
+        if self.account_type != AccountType::Margin || self.liquidation_model.is_none() {
+            return Vec::new();
+        }
+
+        let events = {
+            let cache = self.cache.as_ref().borrow();
+            let Some(account) = cache.account_for_venue(&self.id) else {
+                return Vec::new();
+            };
+            let balances: Vec<Money> = account.balances().values().map(|b| b.total).collect();
+
+            let mut positions = Vec::new();
+            for position in cache.positions_open(Some(&self.id), None, None, None) {
+                let instrument_id = position.instrument_id;
+                let (Some(instrument), Some(mark_price)) = (
+                    self.instruments.get(&instrument_id),
+                    self.mark_price(instrument_id),
+                ) else {
+                    log::warn!("Cannot check margin for {instrument_id}: no mark price");
+                    continue;
+                };
+                let (_, margin_maint) = self.margin_rates(instrument);
+                let leverage = match account {
+                    AccountAny::Margin(margin_account) => {
+                        Decimal::from_f64(margin_account.get_leverage(&instrument_id))
+                            .unwrap_or(Decimal::ONE)
+                    }
+                    AccountAny::Cash(_) => Decimal::ONE,
+                };
+                positions.push(MarkedPosition {
+                    position,
+                    mark_price,
+                    margin_maint,
+                    leverage,
+                });
+            }
+
+            self.liquidation_model.as_mut().unwrap().check(
+                account.id(),
+                &balances,
+                &positions,
+                ts_now,
+            )
+        };
+
+        for event in &events {
+            match event {
+                MarginEvent::MarginCall(_) => {
+                    let topic = Ustr::from(&format!("events.margin.{}", self.id));
+                    let msgbus = self.msgbus.as_ref().borrow();
+                    msgbus.publish(&topic, event);
+                }
+                MarginEvent::Liquidation(liquidation) => {
+                    self.generate_liquidation_fill(liquidation);
+                }
+            }
+        }
+
+        events
     }
 
+    /// Generates a market order which closes the liquidated position, caching it as
+    /// submitted, then sends its `OrderAccepted` and `OrderFilled` events at the mark price.
+    fn generate_liquidation_fill(&mut self, liquidation: &Liquidation) {
+        let Some(instrument) = self.instruments.get(&liquidation.instrument_id) else {
+            return;
+        };
+        let quote_currency = instrument.quote_currency();
+        self.liquidation_count += 1;
+
+        let trader_id = self.msgbus.as_ref().borrow().trader_id;
+        let ts_event = liquidation.ts_event;
+        let liquidation_id = format!("LIQ-{}-{}", liquidation.position_id, ts_event);
+        let client_order_id = ClientOrderId::new(&liquidation_id);
+        let venue_order_id = VenueOrderId::new(&liquidation_id);
+        let trade_id = TradeId::new(format!("{}-LIQ-{}", self.id, self.liquidation_count));
+
+        let mut order = OrderAny::Market(MarketOrder::new(
+            trader_id,
+            liquidation.strategy_id,
+            liquidation.instrument_id,
+            client_order_id,
+            liquidation.order_side,
+            liquidation.quantity,
+            TimeInForce::Ioc,
+            UUID4::new(),
+            ts_event,
+            true,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ));
+        let submitted = OrderEventAny::Submitted(OrderSubmitted::new(
+            trader_id,
+            liquidation.strategy_id,
+            liquidation.instrument_id,
+            client_order_id,
+            liquidation.account_id,
+            UUID4::new(),
+            ts_event,
+            ts_event,
+        ));
+        if let Err(e) = order.apply(submitted) {
+            log::error!("Cannot generate liquidation order {client_order_id}: {e}");
+            return;
+        }
+        let client_id = self.exec_client.as_ref().map(|client| client.client_id);
+        if let Err(e) = self.cache.as_ref().borrow_mut().add_order(
+            order,
+            Some(liquidation.position_id),
+            client_id,
+            false,
+        ) {
+            log::error!("Cannot cache liquidation order {client_order_id}: {e}");
+            return;
+        }
+
+        let accepted = OrderEventAny::Accepted(OrderAccepted::new(
+            trader_id,
+            liquidation.strategy_id,
+            liquidation.instrument_id,
+            client_order_id,
+            venue_order_id,
+            liquidation.account_id,
+            UUID4::new(),
+            ts_event,
+            ts_event,
+            false,
+        ));
+        let filled = OrderEventAny::Filled(OrderFilled::new(
+            trader_id,
+            liquidation.strategy_id,
+            liquidation.instrument_id,
+            client_order_id,
+            venue_order_id,
+            liquidation.account_id,
+            trade_id,
+            liquidation.order_side,
+            OrderType::Market,
+            liquidation.quantity,
+            liquidation.mark_price,
+            quote_currency,
+            LiquiditySide::Taker,
+            UUID4::new(),
+            ts_event,
+            ts_event,
+            false,
+            Some(liquidation.position_id),
+            Some(liquidation.fee),
+        ));
+        let msgbus = self.msgbus.as_ref().borrow();
+        msgbus.send(
+            &msgbus.switchboard.exec_engine_process,
+            &accepted as &dyn Any,
+        );
+        msgbus.send(&msgbus.switchboard.exec_engine_process, &filled as &dyn Any);
+    }
+
+    /// Processes all commands which have arrived at the exchange by `ts_now`, then
+    /// iterates the matching engines.
+    pub fn process(&mut self, ts_now: UnixNanos) {
+        // The clock is shared with the engine, so is never moved backwards
+        let ts_clock = self.clock.get_time_ns();
+
+        // Process inflight commands in order of arrival
+        while self
+            .inflight_queue
+            .peek()
+            .is_some_and(|inflight| inflight.ts <= ts_now)
+        {
+            let inflight = self.inflight_queue.pop().unwrap();
+            // Events are generated at the commands arrival time
+            self.clock.set_time(inflight.ts.max(ts_clock));
+            self.process_trading_command(inflight.command);
+        }
+        self.clock.set_time(ts_now.max(ts_clock));
+
+        // Process the message queue
+        while let Some(command) = self.message_queue.pop_front() {
+            self.process_trading_command(command);
+        }
+
+        for module in &self.modules {
+            module.process(ts_now);
+        }
+
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.iterate(ts_now);
+        }
+
+        // Mark open positions to the new prices and enforce maintenance margin
+        self.check_margin(ts_now);
+    }
+
+    /// Checks the initial margin for the given `order` against the free balance of the
+    /// venue account, returning the reason to reject the order if it is insufficient.
+    ///
+    /// Reduce-only orders, and orders reducing an open position, require no initial margin.
+    fn check_initial_margin(&self, order: &OrderAny) -> Option<String> {
+        if self.account_type != AccountType::Margin || order.is_reduce_only() {
+            return None;
+        }
+
+        let instrument_id = order.instrument_id();
+        let price = order
+            .price()
+            .or_else(|| order.trigger_price())
+            .or_else(|| self.mark_price(instrument_id))?;
+        let initial_margin = self.initial_margin(instrument_id, order.quantity(), price)?;
+
+        let cache = self.cache.as_ref().borrow();
+        let account = cache.account_for_venue(&self.id)?;
+        let is_reducing = cache
+            .positions_open(Some(&self.id), Some(&instrument_id), None, None)
+            .iter()
+            .any(|position| {
+                position.is_opposite_side(order.order_side())
+                    && order.quantity() <= position.quantity
+            });
+        if is_reducing {
+            return None;
+        }
+
+        let free = account
+            .balances()
+            .get(&initial_margin.currency)
+            .map_or(0.0, |balance| balance.free.as_f64());
+        if initial_margin.as_f64() > free {
+            return Some(format!(
+                "Insufficient margin: initial margin {initial_margin} exceeds free balance {}",
+                Money::new(free, initial_margin.currency),
+            ));
+        }
+        None
+    }
+
+    /// Resets the exchange to its initial state, restoring the starting account balances.
     pub fn reset(&mut self) {
-        todo!("reset")
+        for module in &self.modules {
+            module.reset();
+        }
+
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.reset();
+        }
+
+        self.message_queue.clear();
+        self.inflight_queue.clear();
+        self.inflight_counter.clear();
+        self.liquidation_count = 0;
+
+        if let Some(liquidation_model) = &mut self.liquidation_model {
+            liquidation_model.reset();
+        }
+
+        self.generate_fresh_account_state();
+
+        log::info!("Reset {}", self.id);
     }
 
-    pub fn process_trading_command(&mut self, _command: TradingCommand) {
-        todo!("process trading command")
+    pub fn process_trading_command(&mut self, command: TradingCommand) {
+        let account_id = self.account_id();
+        let instrument_id = command.instrument_id();
+        let margin_rejections: Vec<(OrderAny, String)> = match &command {
+            TradingCommand::SubmitOrder(command) => vec![&command.order],
+            TradingCommand::SubmitOrderList(command) => command.order_list.orders.iter().collect(),
+            _ => vec![],
+        }
+        .into_iter()
+        .filter_map(|order| {
+            self.check_initial_margin(order)
+                .map(|reason| (order.clone(), reason))
+        })
+        .collect();
+
+        let Some(matching_engine) = self.matching_engines.get_mut(&instrument_id) else {
+            panic!("Matching engine not found for {instrument_id}");
+        };
+
+        for (order, reason) in &margin_rejections {
+            matching_engine.generate_order_rejected(order, Ustr::from(reason));
+        }
+        let is_rejected = |order: &OrderAny| {
+            margin_rejections
+                .iter()
+                .any(|(rejected, _)| rejected.client_order_id() == order.client_order_id())
+        };
+
+        match command {
+            TradingCommand::SubmitOrder(command) => {
+                if !is_rejected(&command.order) {
+                    matching_engine.process_order(&command.order, account_id);
+                }
+            }
+            TradingCommand::SubmitOrderList(command) => {
+                for order in &command.order_list.orders {
+                    if !is_rejected(order) {
+                        matching_engine.process_order(order, account_id);
+                    }
+                }
+            }
+            TradingCommand::ModifyOrder(command) => {
+                matching_engine.process_modify(&command, account_id);
+            }
+            TradingCommand::CancelOrder(command) => {
+                matching_engine.process_cancel(&command, account_id);
+            }
+            TradingCommand::CancelAllOrders(command) => {
+                matching_engine.process_cancel_all(&command, account_id);
+            }
+            TradingCommand::BatchCancelOrders(command) => {
+                for cancel in &command.cancels {
+                    matching_engine.process_cancel(cancel, account_id);
+                }
+            }
+            TradingCommand::QueryOrder(command) => {
+                log::debug!("Query for order {} not supported", command.client_order_id);
+            }
+        }
     }
 
+    /// Returns the account ID of the registered execution client, or the venues
+    /// default backtest account ID when no client is registered.
+    fn account_id(&self) -> AccountId {
+        self.exec_client.as_ref().map_or_else(
+            || AccountId::from(format!("{}-001", self.id).as_str()),
+            |client| client.account_id,
+        )
+    }
+
+    /// Generates an account state with the starting balances for the registered
+    /// execution client.
     pub fn generate_fresh_account_state(&self) {
-        todo!("generate fresh account state")
+        let Some(client) = &self.exec_client else {
+            log::error!(
+                "Cannot generate account state: no execution client registered for {}",
+                self.id
+            );
+            return;
+        };
+
+        if let Err(e) = client.generate_account_state(
+            self.starting_account_balances(),
+            vec![],
+            true,
+            self.clock.get_time_ns(),
+        ) {
+            log::error!("Error generating account state: {e}");
+        }
     }
 }
 
@@ -511,22 +1215,34 @@ impl SimulatedExchange {
 mod tests {
     use std::{cell::RefCell, collections::HashMap, rc::Rc, sync::LazyLock};
 
-    use nautilus_common::{cache::Cache, msgbus::MessageBus};
-    use nautilus_core::{nanos::UnixNanos, time::AtomicTime};
+    use nautilus_common::{
+        cache::Cache,
+        messages::execution::{SubmitOrder, TradingCommand},
+        msgbus::{
+            stubs::{get_message_saving_handler, get_saved_messages},
+            MessageBus,
+        },
+    };
+    use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
     use nautilus_model::{
+        accounts::{AccountAny, MarginAccount},
         data::{
             Bar, BarType, BookOrder, InstrumentStatus, OrderBookDelta, OrderBookDeltas, QuoteTick,
             TradeTick,
         },
         enums::{
             AccountType, AggressorSide, BookAction, BookType, MarketStatus, MarketStatusAction,
-            OmsType, OrderSide,
+            OmsType, OrderSide, OrderType,
         },
-        identifiers::{TradeId, Venue},
+        events::{AccountState, OrderEventAny, OrderEventType},
+        identifiers::{AccountId, ClientId, TradeId, Venue, VenueOrderId},
         instruments::{stubs::crypto_perpetual_ethusdt, CryptoPerpetual, InstrumentAny},
-        types::{Currency, Money, Price, Quantity},
+        orders::{stubs::TestOrderEventStubs, OrderTestBuilder},
+        types::{AccountBalance, Currency, Money, Price, Quantity},
     };
     use rstest::rstest;
+    use rust_decimal::Decimal;
+    use ustr::Ustr;
 
     use crate::{
         exchange::SimulatedExchange,
@@ -534,12 +1250,19 @@ mod tests {
             fee::{FeeModelAny, MakerTakerFeeModel},
             fill::FillModel,
             latency::LatencyModel,
+            liquidation::LiquidationModel,
         },
     };
 
     static ATOMIC_TIME: LazyLock<AtomicTime> =
         LazyLock::new(|| AtomicTime::new(true, UnixNanos::default()));
 
+    static STATIC_TIME: LazyLock<AtomicTime> =
+        LazyLock::new(|| AtomicTime::new(false, UnixNanos::default()));
+
+    static LAGGED_TIME: LazyLock<AtomicTime> =
+        LazyLock::new(|| AtomicTime::new(false, UnixNanos::default()));
+
     fn get_exchange(
         venue: Venue,
         account_type: AccountType,
@@ -559,7 +1282,7 @@ mod tests {
             &ATOMIC_TIME,
             FillModel::default(),
             FeeModelAny::MakerTaker(MakerTakerFeeModel),
-            LatencyModel,
+            LatencyModel::default(),
             book_type,
             None,
             None,
@@ -570,6 +1293,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap()
     }
@@ -828,6 +1552,39 @@ mod tests {
         assert_eq!(best_ask_price, Some(Price::from("1000.00")));
     }
 
+    #[rstest]
+    fn test_exchange_mark_price(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut exchange: SimulatedExchange =
+            get_exchange(Venue::new("BINANCE"), AccountType::Margin, BookType::L1_MBP);
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        exchange.add_instrument(instrument).unwrap();
+
+        let quote_tick = QuoteTick::new(
+            crypto_perpetual_ethusdt.id,
+            Price::from("1000.00"),
+            Price::from("1001.00"),
+            Quantity::from(1),
+            Quantity::from(1),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        exchange.process_quote_tick(&quote_tick);
+
+        let mark_price = exchange.mark_price(crypto_perpetual_ethusdt.id);
+        assert_eq!(mark_price, Some(Price::from("1000.50")));
+    }
+
+    #[rstest]
+    fn test_exchange_check_margin_without_account(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut exchange: SimulatedExchange =
+            get_exchange(Venue::new("BINANCE"), AccountType::Margin, BookType::L1_MBP);
+        exchange.set_liquidation_model(LiquidationModel::default());
+
+        let events = exchange.check_margin(UnixNanos::default());
+
+        assert!(events.is_empty());
+    }
+
     fn test_exchange_process_instrument_status(crypto_perpetual_ethusdt: CryptoPerpetual) {
         let mut exchange: SimulatedExchange =
             get_exchange(Venue::new("BINANCE"), AccountType::Margin, BookType::L2_MBP);
@@ -855,4 +1612,339 @@ mod tests {
             .unwrap();
         assert_eq!(matching_engine.market_status, MarketStatus::Closed);
     }
+
+    #[rstest]
+    fn test_exchange_send_applies_insert_latency(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut msgbus = MessageBus::default();
+        let handler =
+            get_message_saving_handler::<OrderEventAny>(Some(Ustr::from("ExecEngine.process")));
+        msgbus.register(msgbus.switchboard.exec_engine_process, handler.clone());
+
+        let mut exchange = SimulatedExchange::new(
+            Venue::new("BINANCE"),
+            OmsType::Netting,
+            AccountType::Margin,
+            vec![Money::new(1000.0, Currency::USD())],
+            None,
+            1.into(),
+            HashMap::new(),
+            vec![],
+            Rc::new(RefCell::new(msgbus)),
+            Rc::new(RefCell::new(Cache::default())),
+            &STATIC_TIME,
+            FillModel::default(),
+            FeeModelAny::MakerTaker(MakerTakerFeeModel),
+            LatencyModel::new(100, 50, 0, 0, None, None),
+            BookType::L1_MBP,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        exchange.add_instrument(instrument.clone()).unwrap();
+        exchange.process_quote_tick(&QuoteTick::new(
+            instrument.id(),
+            Price::from("1000.00"),
+            Price::from("1001.00"),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        ));
+
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("999.00"))
+            .quantity(Quantity::from("1.000"))
+            .build();
+        order
+            .apply(TestOrderEventStubs::order_submitted(
+                &order,
+                AccountId::from("BINANCE-001"),
+            ))
+            .unwrap();
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("BINANCE"),
+            order.strategy_id(),
+            instrument.id(),
+            order.client_order_id(),
+            VenueOrderId::from("1"),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::from(1_000),
+        )
+        .unwrap();
+        exchange.send(TradingCommand::SubmitOrder(command));
+
+        // Still in flight until the insert latency (base + insert) has elapsed
+        exchange.process(UnixNanos::from(1_100));
+        assert!(get_saved_messages::<OrderEventAny>(handler.clone()).is_empty());
+
+        exchange.process(UnixNanos::from(1_150));
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), OrderEventType::Accepted);
+        assert_eq!(events[0].ts_event(), UnixNanos::from(1_150));
+    }
+
+    #[rstest]
+    fn test_exchange_process_never_moves_clock_backwards(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let mut msgbus = MessageBus::default();
+        let handler =
+            get_message_saving_handler::<OrderEventAny>(Some(Ustr::from("ExecEngine.process")));
+        msgbus.register(msgbus.switchboard.exec_engine_process, handler.clone());
+
+        let mut exchange = SimulatedExchange::new(
+            Venue::new("BINANCE"),
+            OmsType::Netting,
+            AccountType::Margin,
+            vec![Money::new(1000.0, Currency::USD())],
+            None,
+            1.into(),
+            HashMap::new(),
+            vec![],
+            Rc::new(RefCell::new(msgbus)),
+            Rc::new(RefCell::new(Cache::default())),
+            &LAGGED_TIME,
+            FillModel::default(),
+            FeeModelAny::MakerTaker(MakerTakerFeeModel),
+            LatencyModel::new(100, 50, 0, 0, None, None),
+            BookType::L1_MBP,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        exchange.add_instrument(instrument.clone()).unwrap();
+        exchange.process_quote_tick(&QuoteTick::new(
+            instrument.id(),
+            Price::from("1000.00"),
+            Price::from("1001.00"),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        ));
+
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("999.00"))
+            .quantity(Quantity::from("1.000"))
+            .build();
+        order
+            .apply(TestOrderEventStubs::order_submitted(
+                &order,
+                AccountId::from("BINANCE-001"),
+            ))
+            .unwrap();
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("BINANCE"),
+            order.strategy_id(),
+            instrument.id(),
+            order.client_order_id(),
+            VenueOrderId::from("1"),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::from(1_000),
+        )
+        .unwrap();
+        exchange.send(TradingCommand::SubmitOrder(command));
+
+        // The engine has already advanced the clock past the commands arrival time
+        LAGGED_TIME.set_time(UnixNanos::from(2_000));
+        exchange.process(UnixNanos::from(2_000));
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), OrderEventType::Accepted);
+        assert_eq!(events[0].ts_event(), UnixNanos::from(2_000));
+        assert_eq!(LAGGED_TIME.get_time_ns(), UnixNanos::from(2_000));
+    }
+
+    #[rstest]
+    fn test_exchange_initial_margin_with_leverage_and_rate_overrides(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let mut exchange: SimulatedExchange =
+            get_exchange(Venue::new("BINANCE"), AccountType::Margin, BookType::L1_MBP);
+        let instrument_id = crypto_perpetual_ethusdt.id;
+        exchange
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        let quantity = Quantity::from("1.000");
+        let price = Price::from("1000.00");
+
+        exchange.set_leverage(instrument_id, Decimal::TEN);
+        let margin_leveraged = exchange.initial_margin(instrument_id, quantity, price);
+        exchange.set_margin_rates(instrument_id, Decimal::new(1, 1), Decimal::new(5, 2));
+        let margin_overridden = exchange.initial_margin(instrument_id, quantity, price);
+
+        assert_eq!(exchange.leverage(instrument_id), Decimal::TEN);
+        assert_eq!(margin_leveraged, Some(Money::from("100 USDT")));
+        assert_eq!(margin_overridden, Some(Money::from("10 USDT")));
+    }
+
+    #[rstest]
+    fn test_exchange_set_leverage_updates_account_leverage(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let account_id = AccountId::from("BINANCE-001");
+        let mut exchange =
+            get_exchange(Venue::new("BINANCE"), AccountType::Margin, BookType::L1_MBP);
+        let account_state = AccountState::new(
+            account_id,
+            AccountType::Margin,
+            vec![AccountBalance::new(
+                Money::from("1000 USD"),
+                Money::from("0 USD"),
+                Money::from("1000 USD"),
+            )],
+            vec![],
+            true,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            None,
+        );
+        exchange
+            .cache
+            .borrow_mut()
+            .add_account(AccountAny::Margin(MarginAccount::new(account_state, true)))
+            .unwrap();
+
+        exchange.set_leverage(crypto_perpetual_ethusdt.id, Decimal::TEN);
+
+        let cache = exchange.cache.borrow();
+        let Some(AccountAny::Margin(account)) = cache.account(&account_id) else {
+            panic!("Expected margin account");
+        };
+        assert_eq!(account.get_leverage(&crypto_perpetual_ethusdt.id), 10.0);
+    }
+
+    #[rstest]
+    #[case(Decimal::ONE, OrderEventType::Rejected)]
+    #[case(Decimal::ONE_HUNDRED, OrderEventType::Filled)]
+    fn test_exchange_checks_initial_margin_on_submit(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] leverage: Decimal,
+        #[case] expected_event_type: OrderEventType,
+    ) {
+        let account_id = AccountId::from("BINANCE-001");
+        let mut msgbus = MessageBus::default();
+        let handler =
+            get_message_saving_handler::<OrderEventAny>(Some(Ustr::from("ExecEngine.process")));
+        msgbus.register(msgbus.switchboard.exec_engine_process, handler.clone());
+        let mut cache = Cache::default();
+        let account_state = AccountState::new(
+            account_id,
+            AccountType::Margin,
+            vec![AccountBalance::new(
+                Money::from("50 USDT"),
+                Money::from("0 USDT"),
+                Money::from("50 USDT"),
+            )],
+            vec![],
+            true,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            None,
+        );
+        cache
+            .add_account(AccountAny::Margin(MarginAccount::new(account_state, true)))
+            .unwrap();
+
+        let mut exchange = SimulatedExchange::new(
+            Venue::new("BINANCE"),
+            OmsType::Netting,
+            AccountType::Margin,
+            vec![Money::from("50 USDT")],
+            None,
+            leverage,
+            HashMap::new(),
+            vec![],
+            Rc::new(RefCell::new(msgbus)),
+            Rc::new(RefCell::new(cache)),
+            &STATIC_TIME,
+            FillModel::default(),
+            FeeModelAny::MakerTaker(MakerTakerFeeModel),
+            LatencyModel::default(),
+            BookType::L1_MBP,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        exchange.add_instrument(instrument.clone()).unwrap();
+        exchange.process_quote_tick(&QuoteTick::new(
+            instrument.id(),
+            Price::from("1000.00"),
+            Price::from("1001.00"),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        ));
+
+        let mut order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000"))
+            .build();
+        order
+            .apply(TestOrderEventStubs::order_submitted(&order, account_id))
+            .unwrap();
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("BINANCE"),
+            order.strategy_id(),
+            instrument.id(),
+            order.client_order_id(),
+            VenueOrderId::from("1"),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        exchange.process_trading_command(TradingCommand::SubmitOrder(command));
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(events.last().unwrap().event_type(), expected_event_type);
+    }
 }
@@ -27,6 +27,8 @@
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`.
 //! - `python`: Enables Python bindings from `pyo3`.
 
+pub mod actor;
+pub mod config;
 pub mod data_client;
 pub mod engine;
 pub mod exchange;
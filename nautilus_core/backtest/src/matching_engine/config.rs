@@ -17,6 +17,9 @@
 #[derive(Debug, Clone)]
 pub struct OrderMatchingEngineConfig {
     pub bar_execution: bool,
+    /// If the high/low of each bar is ordered adaptively, by which is closest to the open,
+    /// rather than always processing the high before the low.
+    pub bar_adaptive_high_low_ordering: bool,
     pub reject_stop_orders: bool,
     pub support_gtd_orders: bool,
     pub support_contingent_orders: bool,
@@ -30,6 +33,7 @@ impl OrderMatchingEngineConfig {
     #[must_use]
     pub const fn new(
         bar_execution: bool,
+        bar_adaptive_high_low_ordering: bool,
         reject_stop_orders: bool,
         support_gtd_orders: bool,
         support_contingent_orders: bool,
@@ -39,6 +43,7 @@ impl OrderMatchingEngineConfig {
     ) -> Self {
         Self {
             bar_execution,
+            bar_adaptive_high_low_ordering,
             reject_stop_orders,
             support_gtd_orders,
             support_contingent_orders,
@@ -55,6 +60,7 @@ impl Default for OrderMatchingEngineConfig {
     fn default() -> Self {
         Self {
             bar_execution: false,
+            bar_adaptive_high_low_ordering: false,
             reject_stop_orders: false,
             support_gtd_orders: false,
             support_contingent_orders: false,
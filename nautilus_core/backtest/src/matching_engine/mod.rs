@@ -25,15 +25,22 @@ mod tests;
 use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
 
 use chrono::TimeDelta;
-use nautilus_common::{cache::Cache, msgbus::MessageBus};
+use nautilus_common::{
+    cache::Cache,
+    messages::execution::{CancelAllOrders, CancelOrder, ModifyOrder},
+    msgbus::MessageBus,
+};
 use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
-use nautilus_execution::matching_core::OrderMatchingCore;
+use nautilus_execution::{matching_core::OrderMatchingCore, trailing::trailing_stop_calculate};
 use nautilus_model::{
-    data::{Bar, BarType, OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick},
+    data::{
+        Bar, BarType, BookOrder, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick,
+        TradeTick,
+    },
     enums::{
         AccountType, AggregationSource, AggressorSide, BarAggregation, BookType, ContingencyType,
-        LiquiditySide, MarketStatus, MarketStatusAction, OmsType, OrderSide, OrderStatus,
-        OrderType, PriceType,
+        LiquiditySide, MarketStatus, MarketStatusAction, OmsType, OrderSide, OrderSideSpecified,
+        OrderStatus, OrderType, PriceType, TimeInForce,
     },
     events::{
         OrderAccepted, OrderCancelRejected, OrderCanceled, OrderEventAny, OrderExpired,
@@ -45,16 +52,20 @@ use nautilus_model::{
     },
     instruments::{InstrumentAny, EXPIRING_INSTRUMENT_TYPES},
     orderbook::OrderBook,
-    orders::{
-        OrderAny, PassiveOrderAny, StopOrderAny, TrailingStopLimitOrder, TrailingStopMarketOrder,
-    },
+    orders::{OrderAny, PassiveOrderAny},
     position::Position,
     types::{Currency, Money, Price, Quantity},
 };
 use ustr::Ustr;
 use uuid::Uuid;
 
-use crate::{matching_engine::config::OrderMatchingEngineConfig, models::fill::FillModel};
+use crate::{
+    matching_engine::config::OrderMatchingEngineConfig,
+    models::{
+        fee::{FeeModel, FeeModelAny},
+        fill::FillModel,
+    },
+};
 
 /// An order matching engine for a single market.
 pub struct OrderMatchingEngine {
@@ -80,6 +91,7 @@ pub struct OrderMatchingEngine {
     book: OrderBook,
     core: OrderMatchingCore,
     fill_model: FillModel,
+    fee_model: FeeModelAny,
     target_bid: Option<Price>,
     target_ask: Option<Price>,
     target_last: Option<Price>,
@@ -88,6 +100,8 @@ pub struct OrderMatchingEngine {
     execution_bar_types: HashMap<InstrumentId, BarType>,
     execution_bar_deltas: HashMap<BarType, TimeDelta>,
     account_ids: HashMap<TraderId, AccountId>,
+    orders: HashMap<ClientOrderId, OrderAny>,
+    held_orders: HashMap<ClientOrderId, OrderAny>,
     position_count: usize,
     order_count: usize,
     execution_count: usize,
@@ -100,6 +114,7 @@ impl OrderMatchingEngine {
         instrument: InstrumentAny,
         raw_id: u32,
         fill_model: FillModel,
+        fee_model: FeeModelAny,
         book_type: BookType,
         oms_type: OmsType,
         account_type: AccountType,
@@ -121,6 +136,7 @@ impl OrderMatchingEngine {
             instrument,
             raw_id,
             fill_model,
+            fee_model,
             book_type,
             oms_type,
             account_type,
@@ -139,6 +155,8 @@ impl OrderMatchingEngine {
             execution_bar_types: HashMap::new(),
             execution_bar_deltas: HashMap::new(),
             account_ids: HashMap::new(),
+            orders: HashMap::new(),
+            held_orders: HashMap::new(),
             position_count: 0,
             order_count: 0,
             execution_count: 0,
@@ -150,6 +168,11 @@ impl OrderMatchingEngine {
         self.execution_bar_types.clear();
         self.execution_bar_deltas.clear();
         self.account_ids.clear();
+        self.orders.clear();
+        self.held_orders.clear();
+        if let Some(queue) = self.fill_model.queue_position_mut() {
+            queue.reset();
+        }
         self.core.reset();
         self.target_bid = None;
         self.target_ask = None;
@@ -165,6 +188,10 @@ impl OrderMatchingEngine {
         self.fill_model = fill_model;
     }
 
+    pub fn set_fee_model(&mut self, fee_model: FeeModelAny) {
+        self.fee_model = fee_model;
+    }
+
     #[must_use]
     pub fn best_bid_price(&self) -> Option<Price> {
         self.book.best_bid_price()
@@ -213,6 +240,7 @@ impl OrderMatchingEngine {
         if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
             self.book.apply_delta(delta);
         }
+        self.update_queue_positions();
 
         self.iterate(delta.ts_event);
     }
@@ -223,16 +251,29 @@ impl OrderMatchingEngine {
         if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
             self.book.apply_deltas(deltas);
         }
+        self.update_queue_positions();
 
         self.iterate(deltas.ts_event);
     }
 
+    pub fn process_order_book_depth10(&mut self, depth: &OrderBookDepth10) {
+        log::debug!("Processing {depth}");
+
+        if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
+            self.book.apply_depth(depth);
+        }
+        self.update_queue_positions();
+
+        self.iterate(depth.ts_event);
+    }
+
     pub fn process_quote_tick(&mut self, quote: &QuoteTick) {
         log::debug!("Processing {quote}");
 
         if self.book_type == BookType::L1_MBP {
             self.book.update_quote_tick(quote).unwrap();
         }
+        self.update_queue_positions();
 
         self.iterate(quote.ts_event);
     }
@@ -319,38 +360,44 @@ impl OrderMatchingEngine {
         );
 
         // Open
-        // Check if not initialized, if it is, it will be updated by the close or last
-        if !self.core.is_last_initialized {
-            self.book.update_trade_tick(&trade_tick).unwrap();
-            self.iterate(trade_tick.ts_init);
-            self.core.set_last_raw(trade_tick.price);
-        }
-
-        // High
-        // Check if higher than last
-        if self.core.last.is_some_and(|last| bar.high > last) {
-            trade_tick.price = bar.high;
-            trade_tick.aggressor_side = AggressorSide::Buyer;
-            trade_tick.trade_id = self.generate_trade_id();
-
+        // Check if not initialized, or if the bar gapped away from the last price, so that
+        // orders between the last price and the open are triggered and filled at the open
+        if !self.core.is_last_initialized || self.core.last != Some(bar.open) {
             self.book.update_trade_tick(&trade_tick).unwrap();
             self.iterate(trade_tick.ts_init);
-
             self.core.set_last_raw(trade_tick.price);
         }
 
-        // Low
-        // Check if lower than last
-        // Assumption: market traded down, aggressor hitting the bid(setting aggressor to seller)
-        if self.core.last.is_some_and(|last| bar.low < last) {
-            trade_tick.price = bar.low;
-            trade_tick.aggressor_side = AggressorSide::Seller;
-            trade_tick.trade_id = self.generate_trade_id();
-
-            self.book.update_trade_tick(&trade_tick).unwrap();
-            self.iterate(trade_tick.ts_init);
-
-            self.core.set_last_raw(trade_tick.price);
+        // High and low
+        // Assumption: market traded up to the high, aggressor lifting the ask (buyer)
+        // Assumption: market traded down to the low, aggressor hitting the bid (seller)
+        let path = if self.is_high_first(bar) {
+            [
+                (bar.high, AggressorSide::Buyer),
+                (bar.low, AggressorSide::Seller),
+            ]
+        } else {
+            [
+                (bar.low, AggressorSide::Seller),
+                (bar.high, AggressorSide::Buyer),
+            ]
+        };
+        for (price, aggressor_side) in path {
+            // Check if higher (or lower) than last
+            let is_extension = self.core.last.is_some_and(|last| match aggressor_side {
+                AggressorSide::Buyer => price > last,
+                _ => price < last,
+            });
+            if is_extension {
+                trade_tick.price = price;
+                trade_tick.aggressor_side = aggressor_side;
+                trade_tick.trade_id = self.generate_trade_id();
+
+                self.book.update_trade_tick(&trade_tick).unwrap();
+                self.iterate(trade_tick.ts_init);
+
+                self.core.set_last_raw(trade_tick.price);
+            }
         }
 
         // Close
@@ -401,17 +448,18 @@ impl OrderMatchingEngine {
         self.book.update_quote_tick(&quote_tick).unwrap();
         self.iterate(quote_tick.ts_init);
 
-        // High
-        quote_tick.bid_price = bid_bar.high;
-        quote_tick.ask_price = ask_bar.high;
-        self.book.update_quote_tick(&quote_tick).unwrap();
-        self.iterate(quote_tick.ts_init);
-
-        // Low
-        quote_tick.bid_price = bid_bar.low;
-        quote_tick.ask_price = ask_bar.low;
-        self.book.update_quote_tick(&quote_tick).unwrap();
-        self.iterate(quote_tick.ts_init);
+        // High and low (ordered by the bid bar)
+        let path = if self.is_high_first(&bid_bar) {
+            [(bid_bar.high, ask_bar.high), (bid_bar.low, ask_bar.low)]
+        } else {
+            [(bid_bar.low, ask_bar.low), (bid_bar.high, ask_bar.high)]
+        };
+        for (bid_price, ask_price) in path {
+            quote_tick.bid_price = bid_price;
+            quote_tick.ask_price = ask_price;
+            self.book.update_quote_tick(&quote_tick).unwrap();
+            self.iterate(quote_tick.ts_init);
+        }
 
         // Close
         quote_tick.bid_price = bid_bar.close;
@@ -424,9 +472,29 @@ impl OrderMatchingEngine {
         self.last_bar_ask = None;
     }
 
+    /// Returns whether the high of the `bar` should be processed before its low.
+    ///
+    /// With adaptive ordering the extreme closest to the open is assumed to have traded first,
+    /// otherwise the path is always open, high, low, close.
+    fn is_high_first(&self, bar: &Bar) -> bool {
+        if !self.config.bar_adaptive_high_low_ordering {
+            return true;
+        }
+        (bar.high.raw - bar.open.raw).abs() < (bar.open.raw - bar.low.raw).abs()
+    }
+
     pub fn process_trade_tick(&mut self, trade: &TradeTick) {
         log::debug!("Processing {trade}");
 
+        // Trades at a resting orders price consume the queue ahead of it
+        if let Some(queue) = self.fill_model.queue_position_mut() {
+            for order in self.orders.values() {
+                if order.price() == Some(trade.price) {
+                    queue.on_trade(&order.client_order_id(), trade.size.as_f64());
+                }
+            }
+        }
+
         if self.book_type == BookType::L1_MBP {
             self.book.update_trade_tick(trade).unwrap();
         }
@@ -511,7 +579,6 @@ impl OrderMatchingEngine {
             // Contingent orders checks
             if self.config.support_contingent_orders {
                 if let Some(parent_order_id) = order.parent_order_id() {
-                    println!("Search for parent order {parent_order_id}");
                     let parent_order = cache_borrow.order(&parent_order_id);
                     if parent_order.is_none()
                         || parent_order.unwrap().contingency_type().unwrap() != ContingencyType::Oto
@@ -519,21 +586,27 @@ impl OrderMatchingEngine {
                         panic!("OTO parent not found");
                     }
                     if let Some(parent_order) = parent_order {
-                        let parent_order_status = parent_order.status();
-                        let order_is_open = order.is_open();
-                        if parent_order.status() == OrderStatus::Rejected && order.is_open() {
+                        // The engine's copy of a working parent is more recent than the cache's
+                        let parent_order_status = self
+                            .orders
+                            .get(&parent_order_id)
+                            .map_or(parent_order.status(), OrderAny::status);
+                        if parent_order_status == OrderStatus::Rejected && !order.is_closed() {
                             self.generate_order_rejected(
                                 order,
                                 format!("Rejected OTO order from {parent_order_id}").into(),
                             );
                             return;
-                        } else if parent_order.status() == OrderStatus::Accepted
-                            && parent_order.status() == OrderStatus::Triggered
-                        {
+                        } else if matches!(
+                            parent_order_status,
+                            OrderStatus::Accepted | OrderStatus::Triggered
+                        ) {
                             log::info!(
                                 "Pending OTO order {} triggers from {parent_order_id}",
                                 order.client_order_id(),
                             );
+                            self.held_orders
+                                .insert(order.client_order_id(), order.clone());
                             return;
                         }
                     }
@@ -593,8 +666,8 @@ impl OrderMatchingEngine {
                         )
                             .into(),
                     );
+                    return;
                 }
-                return;
             }
 
             // Check for valid order trigger price precision
@@ -699,39 +772,222 @@ impl OrderMatchingEngine {
             return;
         }
 
-        self.fill_market_order(order);
+        let mut order = order.clone();
+        self.fill_market_order(&mut order);
     }
 
     fn process_limit_order(&mut self, order: &OrderAny) {
-        todo!("process_limit_order")
+        let mut order = order.clone();
+        let limit_px = order.price().expect("Limit order must have a price");
+        let is_marketable = self
+            .core
+            .is_limit_price_matched(order.order_side_specified(), limit_px);
+
+        if order.is_post_only() && is_marketable {
+            self.generate_order_rejected(
+                &order,
+                format!(
+                    "POST_ONLY {} {} order limit px of {} would have been a TAKER: bid={}, ask={}",
+                    order.order_type(),
+                    order.order_side(),
+                    limit_px,
+                    format_price(self.core.bid),
+                    format_price(self.core.ask),
+                )
+                .into(),
+            );
+            return;
+        }
+
+        self.accept_order(&mut order);
+
+        if is_marketable {
+            self.fill_limit_order(&mut order, LiquiditySide::Taker);
+        } else if matches!(order.time_in_force(), TimeInForce::Ioc | TimeInForce::Fok) {
+            self.cancel_order(&mut order, true);
+        }
     }
 
     fn process_market_to_limit_order(&mut self, order: &OrderAny) {
-        todo!("process_market_to_limit_order")
+        // Check if market exists
+        let is_market_initialized = match order.order_side() {
+            OrderSide::Buy => self.core.is_ask_initialized,
+            _ => self.core.is_bid_initialized,
+        };
+        let fill_px = self
+            .determine_market_price_and_volume(order)
+            .first()
+            .map(|(price, _)| *price);
+        let Some(fill_px) = fill_px.filter(|_| is_market_initialized) else {
+            self.generate_order_rejected(
+                order,
+                format!("No market for {}", order.instrument_id()).into(),
+            );
+            return;
+        };
+
+        // The order takes the best price level as a market order, with any remaining
+        // quantity resting as a limit order at that price
+        let mut order = order.clone();
+        let venue_order_id = self.generate_venue_order_id();
+        let event = self.generate_order_accepted(&order, venue_order_id);
+        apply_event(&mut order, event);
+        let event = self.generate_order_updated(&order, order.quantity(), Some(fill_px), None);
+        apply_event(&mut order, event);
+
+        self.fill_limit_order(&mut order, LiquiditySide::Taker);
+        if order.is_open() {
+            self.add_resting_order(&order);
+        }
     }
 
     fn process_stop_market_order(&mut self, order: &OrderAny) {
-        todo!("process_stop_market_order")
+        self.process_trigger_market_order(order);
     }
 
     fn process_stop_limit_order(&mut self, order: &OrderAny) {
-        todo!("process_stop_limit_order")
+        self.process_trigger_limit_order(order);
     }
 
     fn process_market_if_touched_order(&mut self, order: &OrderAny) {
-        todo!("process_market_if_touched_order")
+        self.process_trigger_market_order(order);
     }
 
     fn process_limit_if_touched_order(&mut self, order: &OrderAny) {
-        todo!("process_limit_if_touched_order")
+        self.process_trigger_limit_order(order);
     }
 
     fn process_trailing_stop_market_order(&mut self, order: &OrderAny) {
-        todo!("process_trailing_stop_market_order")
+        self.process_trigger_market_order(order);
+        self.trail_accepted_order(order.client_order_id());
     }
 
     fn process_trailing_stop_limit_order(&mut self, order: &OrderAny) {
-        todo!("process_trailing_stop_limit_order")
+        self.process_trigger_limit_order(order);
+        self.trail_accepted_order(order.client_order_id());
+    }
+
+    /// Trails a newly accepted (and not yet triggered) trailing stop order off the current market.
+    fn trail_accepted_order(&mut self, client_order_id: ClientOrderId) {
+        let Some(mut order) = self.orders.get(&client_order_id).cloned() else {
+            return;
+        };
+        if order.is_open() && order.is_triggered() != Some(true) {
+            self.update_trailing_stop_order(&mut order);
+        }
+    }
+
+    fn process_trigger_market_order(&mut self, order: &OrderAny) {
+        let mut order = order.clone();
+        if self.is_trigger_matched(&order) {
+            if self.config.reject_stop_orders {
+                self.reject_trigger_in_market(&order);
+                return;
+            }
+            self.fill_market_order(&mut order);
+        } else {
+            self.accept_order(&mut order);
+        }
+    }
+
+    fn process_trigger_limit_order(&mut self, order: &OrderAny) {
+        let mut order = order.clone();
+        if self.is_trigger_matched(&order) {
+            if self.config.reject_stop_orders {
+                self.reject_trigger_in_market(&order);
+                return;
+            }
+            self.accept_order(&mut order);
+            self.trigger_stop_order(&mut order);
+        } else {
+            self.accept_order(&mut order);
+        }
+    }
+
+    fn reject_trigger_in_market(&self, order: &OrderAny) {
+        self.generate_order_rejected(
+            order,
+            format!(
+                "{} {} order trigger px of {} was in the market: bid={}, ask={}",
+                order.order_type(),
+                order.order_side(),
+                format_price(order.trigger_price()),
+                format_price(self.core.bid),
+                format_price(self.core.ask),
+            )
+            .into(),
+        );
+    }
+
+    /// Processes the given modify `command`, updating the open order if the
+    /// new values are valid for the current market.
+    pub fn process_modify(&mut self, command: &ModifyOrder, account_id: AccountId) {
+        self.account_ids.insert(command.trader_id, account_id);
+
+        if let Some(mut order) = self.orders.get(&command.client_order_id).cloned() {
+            self.update_order(
+                &mut order,
+                command.quantity,
+                command.price,
+                command.trigger_price,
+                true,
+            );
+        } else {
+            self.generate_order_modify_rejected(
+                command.trader_id,
+                command.strategy_id,
+                account_id,
+                command.instrument_id,
+                command.client_order_id,
+                command.venue_order_id,
+                format!("Order {} not found", command.client_order_id).into(),
+            );
+        }
+    }
+
+    /// Processes the given cancel `command` for an open order.
+    pub fn process_cancel(&mut self, command: &CancelOrder, account_id: AccountId) {
+        self.account_ids.insert(command.trader_id, account_id);
+
+        if let Some(mut order) = self
+            .orders
+            .get(&command.client_order_id)
+            .cloned()
+            .or_else(|| self.held_orders.remove(&command.client_order_id))
+        {
+            self.cancel_order(&mut order, true);
+        } else {
+            self.generate_order_cancel_rejected(
+                command.trader_id,
+                command.strategy_id,
+                account_id,
+                command.instrument_id,
+                command.client_order_id,
+                command.venue_order_id,
+                format!("Order {} not found", command.client_order_id).into(),
+            );
+        }
+    }
+
+    /// Processes the given cancel all `command`, canceling the strategies open orders
+    /// on the commands side (or on both sides for `NoOrderSide`).
+    pub fn process_cancel_all(&mut self, command: &CancelAllOrders, account_id: AccountId) {
+        self.account_ids.insert(command.trader_id, account_id);
+
+        let mut orders: Vec<OrderAny> = self
+            .get_open_orders()
+            .iter()
+            .filter_map(|order| self.orders.get(&order.client_order_id()).cloned())
+            .filter(|order| {
+                order.strategy_id() == command.strategy_id
+                    && (command.order_side == OrderSide::NoOrderSide
+                        || order.order_side() == command.order_side)
+            })
+            .collect();
+
+        for order in &mut orders {
+            self.cancel_order(order, true);
+        }
     }
 
     // -- ORDER PROCESSING ----------------------------------------------------
@@ -741,14 +997,13 @@ impl OrderMatchingEngine {
     pub fn iterate(&mut self, timestamp_ns: UnixNanos) {
         self.clock.set_time(timestamp_ns);
 
-        // Check for updates in orderbook and set bid and ask in order matching core and iterate
+        // Check for updates in orderbook and set bid and ask in order matching core
         if self.book.has_bid() {
             self.core.set_bid_raw(self.book.best_bid_price().unwrap());
         }
         if self.book.has_ask() {
             self.core.set_ask_raw(self.book.best_ask_price().unwrap());
         }
-        self.core.iterate();
 
         self.core.bid = self.book.best_bid_price();
         self.core.ask = self.book.best_ask_price();
@@ -761,35 +1016,46 @@ impl OrderMatchingEngine {
     }
 
     fn iterate_orders(&mut self, timestamp_ns: UnixNanos, orders: &[PassiveOrderAny]) {
-        for order in orders {
+        for passive_order in orders {
+            // The order may have been closed by an earlier fill or cancel in this iteration
+            let Some(mut order) = self.orders.get(&passive_order.client_order_id()).cloned() else {
+                continue;
+            };
             if order.is_closed() {
                 continue;
             };
 
             // Check expiration
             if self.config.support_gtd_orders {
-                if let Some(expire_time) = order.expire_time() {
+                if let Some(expire_time) = passive_order.expire_time() {
                     if timestamp_ns >= expire_time {
-                        // SAFTEY: We know this order is in the core
-                        self.core.delete_order(order).unwrap();
-                        self.expire_order(order);
+                        self.expire_order(&mut order);
+                        continue;
                     }
                 }
             }
 
             // Manage trailing stop
-            if let PassiveOrderAny::Stop(o) = order {
-                match o {
-                    StopOrderAny::TrailingStopMarket(o) => self.update_trailing_stop_market(o),
-                    StopOrderAny::TrailingStopLimit(o) => self.update_trailing_stop_limit(o),
-                    _ => {}
-                }
+            if matches!(
+                order.order_type(),
+                OrderType::TrailingStopMarket | OrderType::TrailingStopLimit
+            ) && order.is_triggered() != Some(true)
+            {
+                self.update_trailing_stop_order(&mut order);
             }
 
+            self.match_order(&mut order);
+
             // Move market back to targets
-            self.core.bid = self.target_bid;
-            self.core.ask = self.target_ask;
-            self.core.last = self.target_last;
+            if let Some(target_bid) = self.target_bid {
+                self.core.bid = Some(target_bid);
+            }
+            if let Some(target_ask) = self.target_ask {
+                self.core.ask = Some(target_ask);
+            }
+            if let Some(target_last) = self.target_last {
+                self.core.last = Some(target_last);
+            }
         }
 
         // Reset any targets after iteration
@@ -798,51 +1064,287 @@ impl OrderMatchingEngine {
         self.target_last = None;
     }
 
-    fn determine_limit_price_and_volume(&self, order: &OrderAny) {
-        todo!("determine_limit_price_and_volume")
+    fn match_order(&mut self, order: &mut OrderAny) {
+        match order.order_type() {
+            OrderType::Limit | OrderType::MarketToLimit => self.match_limit_order(order),
+            OrderType::StopMarket | OrderType::MarketIfTouched | OrderType::TrailingStopMarket => {
+                if self.is_trigger_matched(order) {
+                    self.trigger_stop_order(order);
+                }
+            }
+            OrderType::StopLimit | OrderType::LimitIfTouched | OrderType::TrailingStopLimit => {
+                if order.is_triggered() == Some(true) {
+                    self.match_limit_order(order);
+                } else if self.is_trigger_matched(order) {
+                    self.trigger_stop_order(order);
+                }
+            }
+            // Market orders never rest
+            OrderType::Market => {}
+        }
+    }
+
+    fn match_limit_order(&mut self, order: &mut OrderAny) {
+        let limit_px = order.price().expect("Limit order must have a price");
+        let order_side = order.order_side_specified();
+        if !self.core.is_limit_price_matched(order_side, limit_px) {
+            return;
+        }
+
+        // When the market only touches the limit price the order may still be queued
+        let is_touched = match order_side {
+            OrderSideSpecified::Buy => self.core.ask == Some(limit_px),
+            OrderSideSpecified::Sell => self.core.bid == Some(limit_px),
+        };
+        if is_touched {
+            // With queue positions modeled the order only fills once the size ahead has traded,
+            // otherwise an L1 book carries no queue so the fill model decides
+            let client_order_id = order.client_order_id();
+            if let Some(queue) = self.fill_model.queue_position() {
+                if !queue.is_at_front(&client_order_id) {
+                    return;
+                }
+            } else if self.book_type == BookType::L1_MBP && !self.fill_model.is_limit_filled() {
+                return;
+            }
+        }
+
+        self.fill_limit_order(order, LiquiditySide::Maker);
+    }
+
+    fn is_trigger_matched(&self, order: &OrderAny) -> bool {
+        order.trigger_price().is_some_and(|trigger_price| {
+            self.is_trigger_price_matched(
+                order.order_type(),
+                order.order_side_specified(),
+                trigger_price,
+            )
+        })
+    }
+
+    fn is_trigger_price_matched(
+        &self,
+        order_type: OrderType,
+        order_side: OrderSideSpecified,
+        trigger_price: Price,
+    ) -> bool {
+        match order_type {
+            OrderType::MarketIfTouched | OrderType::LimitIfTouched => {
+                self.core.is_touch_triggered(order_side, trigger_price)
+            }
+            _ => self.core.is_stop_triggered(order_side, trigger_price),
+        }
+    }
+
+    fn determine_limit_price_and_volume(&self, order: &OrderAny) -> Vec<(Price, Quantity)> {
+        let limit_px = order.price().expect("Limit order must have a price");
+        let book_order = BookOrder::new(order.order_side(), limit_px, order.leaves_qty(), 0);
+        self.book.simulate_fills(&book_order)
     }
 
-    fn determine_market_price_and_volume(&self, order: &OrderAny) {
-        todo!("determine_market_price_and_volume")
+    fn determine_market_price_and_volume(&self, order: &OrderAny) -> Vec<(Price, Quantity)> {
+        let price_precision = self.instrument.price_precision();
+        let price = match order.order_side_specified() {
+            OrderSideSpecified::Buy => Price::max(price_precision),
+            OrderSideSpecified::Sell => Price::min(price_precision),
+        };
+        let book_order = BookOrder::new(order.order_side(), price, order.leaves_qty(), 0);
+        self.book.simulate_fills(&book_order)
     }
 
-    fn fill_market_order(&mut self, order: &OrderAny) {
-        todo!("fill_market_order")
+    fn fill_market_order(&mut self, order: &mut OrderAny) {
+        let fills = self.determine_market_price_and_volume(order);
+        self.apply_fills(order, fills, LiquiditySide::Taker);
     }
 
-    fn fill_limit_order(&mut self, order: &OrderAny) {
-        todo!("fill_limit_order")
+    fn fill_limit_order(&mut self, order: &mut OrderAny, liquidity_side: LiquiditySide) {
+        let limit_px = order.price().expect("Limit order must have a price");
+        let mut fills = self.determine_limit_price_and_volume(order);
+
+        if liquidity_side == LiquiditySide::Maker {
+            if self.book_type == BookType::L1_MBP {
+                // Top-of-book sizes do not bound a resting order, fill it completely
+                fills = vec![(limit_px, order.leaves_qty())];
+            } else {
+                // Resting orders are filled at their limit price
+                for fill in &mut fills {
+                    fill.0 = limit_px;
+                }
+            }
+
+            // Passive orders may only partially fill when matched
+            let fill_qty = self.fill_model.fill_quantity(order.leaves_qty());
+            fills = truncate_fills(fills, fill_qty);
+        }
+
+        self.apply_fills(order, fills, liquidity_side);
     }
 
     fn apply_fills(
         &mut self,
-        order: &OrderAny,
+        order: &mut OrderAny,
         fills: Vec<(Price, Quantity)>,
         liquidity_side: LiquiditySide,
-        venue_position_id: Option<PositionId>,
-        position: Option<Position>,
     ) {
-        todo!("apply_fills")
+        if fills.is_empty() {
+            log::error!(
+                "Cannot fill order {}: no fills from book when fills were expected (check sizes in data)",
+                order.client_order_id()
+            );
+            return;
+        }
+
+        if order.time_in_force() == TimeInForce::Fok {
+            let fillable_qty = fills.iter().map(|(_, qty)| qty.as_f64()).sum::<f64>();
+            if fillable_qty < order.leaves_qty().as_f64() {
+                self.cancel_order(order, true);
+                return;
+            }
+        }
+
+        let order_side = order.order_side_specified();
+        // Market-to-limit orders rest at their fill price once partially filled
+        let is_market_like = matches!(
+            order.order_type(),
+            OrderType::Market
+                | OrderType::StopMarket
+                | OrderType::MarketIfTouched
+                | OrderType::TrailingStopMarket
+        );
+        let venue_position_id = self.get_position_id(order, None);
+
+        let mut last_fill_px = None;
+        for (mut fill_px, fill_qty) in fills {
+            if order.is_closed() {
+                break;
+            }
+            if self.book_type == BookType::L1_MBP
+                && liquidity_side == LiquiditySide::Taker
+                && self.fill_model.is_slipped()
+            {
+                fill_px = self.slip_price(order_side, fill_px);
+            }
+            let fill_qty = fill_qty.min(order.leaves_qty());
+            self.fill_order(order, fill_px, fill_qty, liquidity_side, venue_position_id);
+            last_fill_px = Some(fill_px);
+        }
+
+        // An L1 book only holds the top level, so any remaining market quantity
+        // is filled one tick through the last fill price
+        if self.book_type == BookType::L1_MBP && is_market_like && !order.is_closed() {
+            if let Some(last_fill_px) = last_fill_px {
+                let fill_px = self.slip_price(order_side, last_fill_px);
+                let fill_qty = order.leaves_qty();
+                self.fill_order(order, fill_px, fill_qty, liquidity_side, venue_position_id);
+            }
+        }
+
+        if order.is_open() && (is_market_like || order.time_in_force() == TimeInForce::Ioc) {
+            self.cancel_order(order, true);
+        }
     }
 
     fn fill_order(
         &mut self,
-        order: &OrderAny,
+        order: &mut OrderAny,
         price: Price,
         quantity: Quantity,
         liquidity_side: LiquiditySide,
         venue_position_id: Option<PositionId>,
-        position: Option<Position>,
     ) {
-        todo!("fill_order")
+        let venue_order_id = order
+            .venue_order_id()
+            .unwrap_or_else(|| self.generate_venue_order_id());
+        let commission = self.calculate_commission(order, price, quantity, liquidity_side);
+        let event = self.generate_order_filled(
+            order,
+            venue_order_id,
+            venue_position_id,
+            quantity,
+            price,
+            self.instrument.quote_currency(),
+            commission,
+            liquidity_side,
+        );
+        apply_event(order, event);
+
+        if order.is_closed() {
+            self.remove_order(order);
+        } else if self.orders.contains_key(&order.client_order_id()) {
+            self.update_core_order(order);
+        }
+
+        if self.config.support_contingent_orders {
+            match order.contingency_type() {
+                Some(ContingencyType::Oto) => self.release_held_orders(order),
+                Some(ContingencyType::Oco) => self.cancel_contingent_orders(order),
+                Some(ContingencyType::Ouo) if order.is_closed() => {
+                    self.cancel_contingent_orders(order);
+                }
+                Some(ContingencyType::Ouo) => self.update_contingent_order(order),
+                _ => {}
+            }
+        }
     }
 
-    fn update_trailing_stop_market(&mut self, order: &TrailingStopMarketOrder) {
-        todo!()
+    /// Returns the commission for the fill from the fee model, falling back to no commission
+    /// in the instrument settlement currency if it cannot be calculated.
+    fn calculate_commission(
+        &self,
+        order: &OrderAny,
+        price: Price,
+        quantity: Quantity,
+        liquidity_side: LiquiditySide,
+    ) -> Money {
+        self.fee_model
+            .get_commission(order, quantity, price, liquidity_side, &self.instrument)
+            .unwrap_or_else(|e| {
+                log::error!(
+                    "Error calculating commission for {}: {e}",
+                    order.client_order_id()
+                );
+                Money::new(0.0, self.instrument.settlement_currency())
+            })
     }
 
-    fn update_trailing_stop_limit(&mut self, order: &TrailingStopLimitOrder) {
-        todo!()
+    fn slip_price(&self, order_side: OrderSideSpecified, price: Price) -> Price {
+        match order_side {
+            OrderSideSpecified::Buy => price + self.instrument.price_increment(),
+            OrderSideSpecified::Sell => price - self.instrument.price_increment(),
+        }
+    }
+
+    /// Moves the trigger (and limit) price of the trailing stop `order` towards the market,
+    /// emitting an `OrderUpdated` event when either price changes.
+    fn update_trailing_stop_order(&mut self, order: &mut OrderAny) {
+        let (new_trigger_price, new_price) = match trailing_stop_calculate(
+            self.instrument.price_increment(),
+            order,
+            self.core.bid,
+            self.core.ask,
+            self.core.last,
+        ) {
+            Ok(prices) => prices,
+            Err(e) => {
+                log::warn!(
+                    "Cannot update trailing stop for {}: {e}",
+                    order.client_order_id()
+                );
+                return;
+            }
+        };
+        if new_trigger_price.is_none() && new_price.is_none() {
+            return;
+        }
+
+        let event = self.generate_order_updated(
+            order,
+            order.quantity(),
+            new_price.or(order.price()),
+            new_trigger_price.or(order.trigger_price()),
+        );
+        apply_event(order, event);
+        self.update_core_order(order);
     }
 
     // -- IDENTIFIER GENERATORS -----------------------------------------------------
@@ -857,6 +1359,18 @@ impl OrderMatchingEngine {
         TradeId::from(trade_id.as_str())
     }
 
+    fn generate_venue_order_id(&mut self) -> VenueOrderId {
+        self.order_count += 1;
+        if self.config.use_random_ids {
+            VenueOrderId::new(Uuid::new_v4().to_string())
+        } else {
+            VenueOrderId::new(format!(
+                "{}-{}-{}",
+                self.venue, self.raw_id, self.order_count
+            ))
+        }
+    }
+
     fn get_position_id(&mut self, order: &OrderAny, generate: Option<bool>) -> Option<PositionId> {
         let generate = generate.unwrap_or(true);
         if self.oms_type == OmsType::Hedging {
@@ -902,37 +1416,264 @@ impl OrderMatchingEngine {
 
     // -- EVENT HANDLING -----------------------------------------------------
 
-    fn accept_order(&mut self, order: &OrderAny) {
-        todo!("accept_order")
+    fn accept_order(&mut self, order: &mut OrderAny) {
+        let venue_order_id = self.generate_venue_order_id();
+        let event = self.generate_order_accepted(order, venue_order_id);
+        apply_event(order, event);
+        self.add_resting_order(order);
     }
 
-    fn expire_order(&mut self, order: &PassiveOrderAny) {
-        todo!("expire_order")
+    fn add_resting_order(&mut self, order: &OrderAny) {
+        self.orders.insert(order.client_order_id(), order.clone());
+        if let Err(e) = self.core.add_order(order.clone().into()) {
+            log::error!("Cannot add order {} to core: {e}", order.client_order_id());
+        }
+        self.add_queue_position(order);
     }
 
-    fn cancel_order(&mut self, order: &OrderAny) {
-        todo!("cancel_order")
+    fn expire_order(&mut self, order: &mut OrderAny) {
+        self.remove_order(order);
+        let event = self.generate_order_expired(order);
+        apply_event(order, event);
+
+        if self.config.support_contingent_orders {
+            self.cancel_contingent_orders(order);
+        }
     }
 
-    fn update_order(&mut self, order: &OrderAny) {
-        todo!("update_order")
+    fn cancel_order(&mut self, order: &mut OrderAny, cancel_contingencies: bool) {
+        self.remove_order(order);
+        let venue_order_id = order
+            .venue_order_id()
+            .unwrap_or_else(|| self.generate_venue_order_id());
+        let event = self.generate_order_canceled(order, venue_order_id);
+        apply_event(order, event);
+
+        if cancel_contingencies && self.config.support_contingent_orders {
+            self.cancel_contingent_orders(order);
+        }
     }
 
-    fn trigger_stop_order(&mut self, order: &OrderAny) {
-        todo!("trigger_stop_order")
+    fn update_order(
+        &mut self,
+        order: &mut OrderAny,
+        quantity: Option<Quantity>,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+        update_contingencies: bool,
+    ) {
+        let quantity = quantity.unwrap_or(order.quantity());
+        let price = price.or(order.price());
+        let trigger_price = trigger_price.or(order.trigger_price());
+        let order_side = order.order_side_specified();
+
+        let reason = if quantity < order.filled_qty() {
+            Some(format!(
+                "Modified quantity {quantity} was less than filled quantity {}",
+                order.filled_qty()
+            ))
+        } else if order.is_triggered() == Some(false)
+            && trigger_price.is_some_and(|trigger_price| {
+                self.is_trigger_price_matched(order.order_type(), order_side, trigger_price)
+            })
+        {
+            Some(format!(
+                "{} {} order new trigger px of {} was in the market: bid={}, ask={}",
+                order.order_type(),
+                order.order_side(),
+                format_price(trigger_price),
+                format_price(self.core.bid),
+                format_price(self.core.ask),
+            ))
+        } else if order.is_post_only()
+            && price.is_some_and(|price| self.core.is_limit_price_matched(order_side, price))
+        {
+            Some(format!(
+                "POST_ONLY {} {} order new limit px of {} would have been a TAKER: bid={}, ask={}",
+                order.order_type(),
+                order.order_side(),
+                format_price(price),
+                format_price(self.core.bid),
+                format_price(self.core.ask),
+            ))
+        } else {
+            None
+        };
+
+        if let Some(reason) = reason {
+            let account_id = order
+                .account_id()
+                .unwrap_or(self.account_ids.get(&order.trader_id()).unwrap().to_owned());
+            self.generate_order_modify_rejected(
+                order.trader_id(),
+                order.strategy_id(),
+                account_id,
+                order.instrument_id(),
+                order.client_order_id(),
+                order
+                    .venue_order_id()
+                    .expect("Open order must have a venue order ID"),
+                reason.into(),
+            );
+            return;
+        }
+
+        let is_repriced = price != order.price();
+        let event = self.generate_order_updated(order, quantity, price, trigger_price);
+        apply_event(order, event);
+        self.update_core_order(order);
+
+        // A repriced order joins the back of the queue at its new level
+        if is_repriced {
+            self.add_queue_position(order);
+        }
+
+        if update_contingencies && self.config.support_contingent_orders {
+            self.update_contingent_order(order);
+        }
+
+        // The modified limit price may now be marketable
+        if is_resting_limit(order)
+            && price.is_some_and(|price| self.core.is_limit_price_matched(order_side, price))
+        {
+            self.fill_limit_order(order, LiquiditySide::Taker);
+        }
+    }
+
+    fn trigger_stop_order(&mut self, order: &mut OrderAny) {
+        match order.order_type() {
+            OrderType::StopLimit | OrderType::LimitIfTouched | OrderType::TrailingStopLimit => {
+                let event = self.generate_order_triggered(order);
+                apply_event(order, event);
+
+                let limit_px = order.price().expect("Limit order must have a price");
+                if self
+                    .core
+                    .is_limit_price_matched(order.order_side_specified(), limit_px)
+                {
+                    self.fill_limit_order(order, LiquiditySide::Taker);
+                } else {
+                    self.update_core_order(order);
+                    self.add_queue_position(order);
+                }
+            }
+            _ => self.fill_market_order(order),
+        }
     }
 
+    /// Processes the OTO child orders held for the given (at least partially) filled parent `order`.
+    fn release_held_orders(&mut self, order: &OrderAny) {
+        let account_id = self.account_ids.get(&order.trader_id()).copied();
+        for client_order_id in order.linked_order_ids().unwrap_or_default() {
+            let Some(child_order) = self.held_orders.remove(&client_order_id) else {
+                continue;
+            };
+            match account_id.or(child_order.account_id()) {
+                Some(account_id) => self.process_order(&child_order, account_id),
+                None => log::error!("Cannot release OTO order {client_order_id}: no account ID"),
+            }
+        }
+    }
+
+    /// Reduces the open OUO orders linked to the given `order` to its leaves quantity.
     fn update_contingent_order(&mut self, order: &OrderAny) {
-        todo!("update_contingent_order")
+        if order.contingency_type() != Some(ContingencyType::Ouo) {
+            return;
+        }
+
+        for client_order_id in order.linked_order_ids().unwrap_or_default() {
+            let Some(mut ouo_order) = self.orders.get(&client_order_id).cloned() else {
+                continue;
+            };
+            if ouo_order.leaves_qty() != order.leaves_qty() {
+                let quantity = ouo_order.filled_qty() + order.leaves_qty();
+                self.update_order(&mut ouo_order, Some(quantity), None, None, false);
+            }
+        }
     }
 
+    /// Cancels the open orders linked to the given `order`.
+    ///
+    /// Children of an OTO parent are only canceled while still held, once released
+    /// they protect the parents fills and work independently.
     fn cancel_contingent_orders(&mut self, order: &OrderAny) {
-        todo!("cancel_contingent_orders")
+        let is_oto = order.contingency_type() == Some(ContingencyType::Oto);
+        for client_order_id in order.linked_order_ids().unwrap_or_default() {
+            let contingent_order = if is_oto {
+                self.held_orders.remove(&client_order_id)
+            } else {
+                self.orders
+                    .get(&client_order_id)
+                    .cloned()
+                    .or_else(|| self.held_orders.remove(&client_order_id))
+            };
+            if let Some(mut contingent_order) = contingent_order {
+                if !contingent_order.is_closed() {
+                    self.cancel_order(&mut contingent_order, false);
+                }
+            }
+        }
+    }
+
+    /// Replaces the core's copy of the given open `order` with its latest state.
+    fn update_core_order(&mut self, order: &OrderAny) {
+        let passive_order: PassiveOrderAny = order.clone().into();
+        if self.core.delete_order(&passive_order).is_ok() {
+            if let Err(e) = self.core.add_order(passive_order) {
+                log::error!("Cannot add order {} to core: {e}", order.client_order_id());
+            }
+        }
+        self.orders.insert(order.client_order_id(), order.clone());
+    }
+
+    /// Places the resting limit `order` at the back of the queue at its price level
+    /// (if queue positions are modeled).
+    fn add_queue_position(&mut self, order: &OrderAny) {
+        if !is_resting_limit(order) {
+            return;
+        }
+        let Some(price) = order.price() else {
+            return;
+        };
+        let size_ahead = level_size(&self.book, order.order_side(), price);
+        if let Some(queue) = self.fill_model.queue_position_mut() {
+            queue.add_order(order.client_order_id(), size_ahead);
+        }
+    }
+
+    /// Caps the size ahead of tracked orders at their displayed level sizes.
+    ///
+    /// Levels which are not displayed (beyond the top of an L1 book) are left unchanged.
+    fn update_queue_positions(&mut self) {
+        let Some(queue) = self.fill_model.queue_position_mut() else {
+            return;
+        };
+        for order in self.orders.values() {
+            let Some(price) = order.price() else {
+                continue;
+            };
+            let size = level_size(&self.book, order.order_side(), price);
+            if size > 0.0 {
+                queue.on_level_update(&order.client_order_id(), size);
+            }
+        }
+    }
+
+    fn remove_order(&mut self, order: &OrderAny) {
+        if let Some(queue) = self.fill_model.queue_position_mut() {
+            queue.remove_order(&order.client_order_id());
+        }
+        if let Some(order) = self.orders.remove(&order.client_order_id()) {
+            let passive_order: PassiveOrderAny = order.into();
+            if let Err(e) = self.core.delete_order(&passive_order) {
+                log::error!("Cannot delete order from core: {e}");
+            }
+        }
     }
 
     // -- EVENT GENERATORS -----------------------------------------------------
 
-    fn generate_order_rejected(&self, order: &OrderAny, reason: Ustr) {
+    pub(crate) fn generate_order_rejected(&self, order: &OrderAny, reason: Ustr) {
         let ts_now = self.clock.get_time_ns();
         let account_id = order
             .account_id()
@@ -954,7 +1695,11 @@ impl OrderMatchingEngine {
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
     }
 
-    fn generate_order_accepted(&self, order: &OrderAny, venue_order_id: VenueOrderId) {
+    fn generate_order_accepted(
+        &self,
+        order: &OrderAny,
+        venue_order_id: VenueOrderId,
+    ) -> OrderEventAny {
         let ts_now = self.clock.get_time_ns();
         let account_id = order
             .account_id()
@@ -973,6 +1718,7 @@ impl OrderMatchingEngine {
         ));
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        event
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -1037,9 +1783,9 @@ impl OrderMatchingEngine {
         &self,
         order: &OrderAny,
         quantity: Quantity,
-        price: Price,
-        trigger_price: Price,
-    ) {
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+    ) -> OrderEventAny {
         let ts_now = self.clock.get_time_ns();
         let event = OrderEventAny::Updated(OrderUpdated::new(
             order.trader_id(),
@@ -1053,14 +1799,19 @@ impl OrderMatchingEngine {
             false,
             order.venue_order_id(),
             order.account_id(),
-            Some(price),
-            Some(trigger_price),
+            price,
+            trigger_price,
         ));
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        event
     }
 
-    fn generate_order_canceled(&self, order: &OrderAny, venue_order_id: VenueOrderId) {
+    fn generate_order_canceled(
+        &self,
+        order: &OrderAny,
+        venue_order_id: VenueOrderId,
+    ) -> OrderEventAny {
         let ts_now = self.clock.get_time_ns();
         let event = OrderEventAny::Canceled(OrderCanceled::new(
             order.trader_id(),
@@ -1076,9 +1827,10 @@ impl OrderMatchingEngine {
         ));
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        event
     }
 
-    fn generate_order_triggered(&self, order: &OrderAny) {
+    fn generate_order_triggered(&self, order: &OrderAny) -> OrderEventAny {
         let ts_now = self.clock.get_time_ns();
         let event = OrderEventAny::Triggered(OrderTriggered::new(
             order.trader_id(),
@@ -1094,9 +1846,10 @@ impl OrderMatchingEngine {
         ));
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        event
     }
 
-    fn generate_order_expired(&self, order: &OrderAny) {
+    fn generate_order_expired(&self, order: &OrderAny) -> OrderEventAny {
         let ts_now = self.clock.get_time_ns();
         let event = OrderEventAny::Expired(OrderExpired::new(
             order.trader_id(),
@@ -1112,6 +1865,7 @@ impl OrderMatchingEngine {
         ));
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        event
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -1119,13 +1873,13 @@ impl OrderMatchingEngine {
         &mut self,
         order: &OrderAny,
         venue_order_id: VenueOrderId,
-        venue_position_id: PositionId,
+        venue_position_id: Option<PositionId>,
         last_qty: Quantity,
         last_px: Price,
         quote_currency: Currency,
         commission: Money,
         liquidity_side: LiquiditySide,
-    ) {
+    ) -> OrderEventAny {
         let ts_now = self.clock.get_time_ns();
         let account_id = order
             .account_id()
@@ -1148,10 +1902,56 @@ impl OrderMatchingEngine {
             ts_now,
             ts_now,
             false,
-            Some(venue_position_id),
+            venue_position_id,
             Some(commission),
         ));
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        event
+    }
+}
+
+/// Applies the given `event` to the engines local copy of the `order`.
+fn apply_event(order: &mut OrderAny, event: OrderEventAny) {
+    if let Err(e) = order.apply(event) {
+        log::error!(
+            "Error applying event to order {}: {e}",
+            order.client_order_id()
+        );
+    }
+}
+
+fn format_price(price: Option<Price>) -> String {
+    price.map_or("None".to_string(), |price| price.to_string())
+}
+
+/// Returns whether the `order` rests at its limit price in the book.
+fn is_resting_limit(order: &OrderAny) -> bool {
+    match order.order_type() {
+        OrderType::Limit | OrderType::MarketToLimit => true,
+        _ => order.is_triggered() == Some(true) && order.price().is_some(),
+    }
+}
+
+/// Returns the total displayed size at the given `price` level on the `side` of the `book`.
+fn level_size(book: &OrderBook, side: OrderSide, price: Price) -> f64 {
+    book.orders_at_price(side, price)
+        .iter()
+        .map(|order| order.size.as_f64())
+        .sum()
+}
+
+/// Truncates the given `fills` to at most `max_qty` in total.
+fn truncate_fills(fills: Vec<(Price, Quantity)>, max_qty: Quantity) -> Vec<(Price, Quantity)> {
+    let mut remaining = max_qty;
+    let mut truncated = Vec::with_capacity(fills.len());
+    for (price, qty) in fills {
+        if !remaining.is_positive() {
+            break;
+        }
+        let fill_qty = qty.min(remaining);
+        truncated.push((price, fill_qty));
+        remaining -= fill_qty;
     }
+    truncated
 }
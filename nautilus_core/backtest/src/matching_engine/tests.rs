@@ -20,6 +20,7 @@ use std::{cell::RefCell, rc::Rc, sync::LazyLock};
 use chrono::{TimeZone, Utc};
 use nautilus_common::{
     cache::Cache,
+    messages::execution::{CancelOrder, ModifyOrder},
     msgbus::{
         handler::ShareableMessageHandler,
         stubs::{get_message_saving_handler, get_saved_messages},
@@ -28,30 +29,36 @@ use nautilus_common::{
 };
 use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
 use nautilus_model::{
-    data::{BookOrder, OrderBookDelta},
+    data::{Bar, BarType, BookOrder, OrderBookDelta, QuoteTick, TradeTick},
     enums::{
-        AccountType, BookAction, BookType, ContingencyType, LiquiditySide, OmsType, OrderSide,
-        OrderType,
+        AccountType, AggressorSide, BookAction, BookType, ContingencyType, LiquiditySide, OmsType,
+        OrderSide, OrderType, TrailingOffsetType, TriggerType,
     },
     events::{
         order::rejected::OrderRejectedBuilder, OrderEventAny, OrderEventType, OrderFilled,
         OrderRejected,
     },
-    identifiers::{AccountId, ClientOrderId, PositionId, TradeId, VenueOrderId},
+    identifiers::{AccountId, ClientId, ClientOrderId, PositionId, TradeId, VenueOrderId},
     instruments::{
-        stubs::{crypto_perpetual_ethusdt, equity_aapl, futures_contract_es},
+        stubs::{crypto_perpetual_ethusdt, equity_aapl, futures_contract_es, xbtusd_bitmex},
         CryptoPerpetual, Equity, InstrumentAny,
     },
-    orders::{stubs::TestOrderStubs, OrderAny, OrderTestBuilder},
+    orders::{
+        stubs::{TestOrderEventStubs, TestOrderStubs},
+        OrderAny, OrderTestBuilder,
+    },
     position::Position,
-    types::{Price, Quantity},
+    types::{Currency, Money, Price, Quantity},
 };
 use rstest::{fixture, rstest};
 use ustr::Ustr;
 
 use crate::{
     matching_engine::{config::OrderMatchingEngineConfig, OrderMatchingEngine},
-    models::fill::FillModel,
+    models::{
+        fee::{FeeModelAny, MakerTakerFeeModel, PerContractFeeModel},
+        fill::FillModel,
+    },
 };
 
 static ATOMIC_TIME: LazyLock<AtomicTime> =
@@ -153,6 +160,7 @@ fn instrument_es() -> InstrumentAny {
 fn engine_config() -> OrderMatchingEngineConfig {
     OrderMatchingEngineConfig {
         bar_execution: false,
+        bar_adaptive_high_low_ordering: false,
         reject_stop_orders: false,
         support_gtd_orders: false,
         support_contingent_orders: true,
@@ -176,6 +184,7 @@ fn get_order_matching_engine(
         instrument,
         1,
         FillModel::default(),
+        FeeModelAny::MakerTaker(MakerTakerFeeModel),
         BookType::L1_MBP,
         OmsType::Netting,
         account_type.unwrap_or(AccountType::Cash),
@@ -199,6 +208,7 @@ fn get_order_matching_engine_l2(
         instrument,
         1,
         FillModel::default(),
+        FeeModelAny::MakerTaker(MakerTakerFeeModel),
         BookType::L2_MBP,
         OmsType::Netting,
         account_type.unwrap_or(AccountType::Cash),
@@ -213,6 +223,128 @@ fn get_order_event_handler_messages(event_handler: ShareableMessageHandler) -> V
     get_saved_messages::<OrderEventAny>(event_handler)
 }
 
+fn get_order_matching_engine_no_slippage(
+    instrument: InstrumentAny,
+    msgbus: Rc<RefCell<MessageBus>>,
+) -> OrderMatchingEngine {
+    get_order_matching_engine_with_fill_model(
+        instrument,
+        msgbus,
+        FillModel::new(1.0, 1.0, 0.0, 0.0, false, None).unwrap(),
+    )
+}
+
+fn get_order_matching_engine_with_fill_model(
+    instrument: InstrumentAny,
+    msgbus: Rc<RefCell<MessageBus>>,
+    fill_model: FillModel,
+) -> OrderMatchingEngine {
+    OrderMatchingEngine::new(
+        instrument,
+        1,
+        fill_model,
+        FeeModelAny::MakerTaker(MakerTakerFeeModel),
+        BookType::L1_MBP,
+        OmsType::Netting,
+        AccountType::Margin,
+        &ATOMIC_TIME,
+        msgbus,
+        Rc::new(RefCell::new(Cache::default())),
+        OrderMatchingEngineConfig::default(),
+    )
+}
+
+fn get_order_matching_engine_contingent(
+    instrument: InstrumentAny,
+    msgbus: Rc<RefCell<MessageBus>>,
+    cache: Rc<RefCell<Cache>>,
+) -> OrderMatchingEngine {
+    OrderMatchingEngine::new(
+        instrument,
+        1,
+        FillModel::new(1.0, 1.0, 0.0, 0.0, false, None).unwrap(),
+        FeeModelAny::MakerTaker(MakerTakerFeeModel),
+        BookType::L1_MBP,
+        OmsType::Netting,
+        AccountType::Margin,
+        &ATOMIC_TIME,
+        msgbus,
+        cache,
+        OrderMatchingEngineConfig {
+            support_contingent_orders: true,
+            ..Default::default()
+        },
+    )
+}
+
+fn get_order_matching_engine_bar_execution(
+    instrument: InstrumentAny,
+    msgbus: Rc<RefCell<MessageBus>>,
+    bar_adaptive_high_low_ordering: bool,
+) -> OrderMatchingEngine {
+    OrderMatchingEngine::new(
+        instrument,
+        1,
+        FillModel::new(1.0, 1.0, 0.0, 0.0, false, None).unwrap(),
+        FeeModelAny::MakerTaker(MakerTakerFeeModel),
+        BookType::L1_MBP,
+        OmsType::Netting,
+        AccountType::Margin,
+        &ATOMIC_TIME,
+        msgbus,
+        Rc::new(RefCell::new(Cache::default())),
+        OrderMatchingEngineConfig {
+            bar_execution: true,
+            bar_adaptive_high_low_ordering,
+            ..OrderMatchingEngineConfig::default()
+        },
+    )
+}
+
+fn get_bar(instrument: &InstrumentAny, open: &str, high: &str, low: &str, close: &str) -> Bar {
+    Bar::new(
+        BarType::from(format!("{}-1-MINUTE-LAST-EXTERNAL", instrument.id()).as_str()),
+        Price::from(open),
+        Price::from(high),
+        Price::from(low),
+        Price::from(close),
+        Quantity::from("100.000"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
+fn get_quote_tick(instrument: &InstrumentAny, bid: &str, ask: &str) -> QuoteTick {
+    QuoteTick::new(
+        instrument.id(),
+        Price::from(bid),
+        Price::from(ask),
+        Quantity::from("1.000"),
+        Quantity::from("1.000"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
+fn get_trade_tick(instrument: &InstrumentAny, price: &str, size: &str) -> TradeTick {
+    TradeTick::new(
+        instrument.id(),
+        Price::from(price),
+        Quantity::from(size),
+        AggressorSide::Seller,
+        TradeId::new("1"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
+fn submit_order(mut order: OrderAny, account_id: AccountId) -> OrderAny {
+    order
+        .apply(TestOrderEventStubs::order_submitted(&order, account_id))
+        .unwrap();
+    order
+}
+
 // -- TESTS -----------------------------------------------------------------------------------
 
 #[rstest]
@@ -801,6 +933,7 @@ fn test_get_position_id_hedging_with_existing_position(
         instrument_eth_usdt.clone(),
         1,
         FillModel::default(),
+        FeeModelAny::MakerTaker(MakerTakerFeeModel),
         BookType::L1_MBP,
         OmsType::Hedging,
         AccountType::Cash,
@@ -841,6 +974,7 @@ fn test_get_position_id_hedging_with_generated_position(
         instrument_eth_usdt,
         1,
         FillModel::default(),
+        FeeModelAny::MakerTaker(MakerTakerFeeModel),
         BookType::L1_MBP,
         OmsType::Hedging,
         AccountType::Cash,
@@ -868,6 +1002,7 @@ fn test_get_position_id_netting(
         instrument_eth_usdt.clone(),
         1,
         FillModel::default(),
+        FeeModelAny::MakerTaker(MakerTakerFeeModel),
         BookType::L1_MBP,
         OmsType::Netting,
         AccountType::Cash,
@@ -894,3 +1029,1008 @@ fn test_get_position_id_netting(
     let position_id = engine.get_position_id(&market_order_buy, None);
     assert_eq!(position_id, Some(position.id));
 }
+
+#[rstest]
+fn test_process_limit_order_accepted_then_filled_as_maker(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_no_slippage(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1000.00", "1001.00"));
+
+    let limit_order = submit_order(
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("999.00"))
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&limit_order, account_id);
+    assert!(engine.order_exists(limit_order.client_order_id()));
+
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "997.00", "998.00"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    let OrderEventAny::Filled(fill) = &saved_messages[1] else {
+        panic!("Expected fill, was {:?}", saved_messages[1]);
+    };
+    assert_eq!(fill.venue_order_id, VenueOrderId::from("BINANCE-1-1"));
+    assert_eq!(fill.last_px, Price::from("999.00"));
+    assert_eq!(fill.last_qty, Quantity::from("1.000"));
+    assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+    assert!(!engine.order_exists(limit_order.client_order_id()));
+}
+
+#[rstest]
+fn test_process_market_order_filled_at_best_ask(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_no_slippage(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1000.00", "1001.00"));
+
+    let market_order = submit_order(
+        OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&market_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let OrderEventAny::Filled(fill) = &saved_messages[0] else {
+        panic!("Expected fill, was {:?}", saved_messages[0]);
+    };
+    assert_eq!(fill.last_px, Price::from("1001.00"));
+    assert_eq!(fill.last_qty, Quantity::from("1.000"));
+    assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+}
+
+#[rstest]
+fn test_process_market_to_limit_order_rests_remainder_at_fill_price(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_no_slippage(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1000.00", "1001.00"));
+
+    let order = submit_order(
+        OrderTestBuilder::new(OrderType::MarketToLimit)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("2.000"))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&order, account_id);
+    assert!(engine.order_exists(order.client_order_id()));
+
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "999.00", "1000.00"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 4);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    let OrderEventAny::Updated(updated) = &saved_messages[1] else {
+        panic!("Expected update, was {:?}", saved_messages[1]);
+    };
+    assert_eq!(updated.price, Some(Price::from("1001.00")));
+    let OrderEventAny::Filled(fill) = &saved_messages[2] else {
+        panic!("Expected fill, was {:?}", saved_messages[2]);
+    };
+    assert_eq!(fill.last_px, Price::from("1001.00"));
+    assert_eq!(fill.last_qty, Quantity::from("1.000"));
+    assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+    let OrderEventAny::Filled(fill) = &saved_messages[3] else {
+        panic!("Expected fill, was {:?}", saved_messages[3]);
+    };
+    assert_eq!(fill.last_px, Price::from("1001.00"));
+    assert_eq!(fill.last_qty, Quantity::from("1.000"));
+    assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+    assert!(!engine.order_exists(order.client_order_id()));
+}
+
+#[rstest]
+fn test_process_market_to_limit_order_no_market_rejected(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_no_slippage(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+    );
+
+    let order = submit_order(
+        OrderTestBuilder::new(OrderType::MarketToLimit)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Rejected);
+    assert_eq!(
+        saved_messages[0].message().unwrap(),
+        Ustr::from("No market for ETHUSDT-PERP.BINANCE")
+    );
+}
+
+#[rstest]
+fn test_process_post_only_limit_order_which_would_take_rejected(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_no_slippage(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1000.00", "1001.00"));
+
+    let limit_order = submit_order(
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("1001.00"))
+            .quantity(Quantity::from("1.000"))
+            .post_only(true)
+            .build(),
+        account_id,
+    );
+    engine.process_order(&limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Rejected);
+    assert!(saved_messages[0]
+        .message()
+        .unwrap()
+        .starts_with("POST_ONLY"));
+    assert!(!engine.order_exists(limit_order.client_order_id()));
+}
+
+#[rstest]
+fn test_process_stop_market_order_triggered_and_filled(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_no_slippage(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1000.00", "1001.00"));
+
+    let stop_order = submit_order(
+        OrderTestBuilder::new(OrderType::StopMarket)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Sell)
+            .trigger_price(Price::from("990.00"))
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&stop_order, account_id);
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "989.00", "990.00"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    let OrderEventAny::Filled(fill) = &saved_messages[1] else {
+        panic!("Expected fill, was {:?}", saved_messages[1]);
+    };
+    assert_eq!(fill.last_px, Price::from("989.00"));
+    assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+    assert!(!engine.order_exists(stop_order.client_order_id()));
+}
+
+#[rstest]
+fn test_process_trailing_stop_market_order_trails_then_filled(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_no_slippage(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1000.00", "1001.00"));
+
+    let trailing_order = submit_order(
+        OrderTestBuilder::new(OrderType::TrailingStopMarket)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Sell)
+            .trigger_price(Price::from("990.00"))
+            .trigger_type(TriggerType::BidAsk)
+            .trailing_offset(Price::from("10.00"))
+            .trailing_offset_type(TrailingOffsetType::Price)
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&trailing_order, account_id);
+    // Market rallies (trigger trails up), then retraces without and with reaching the trigger
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1010.00", "1011.00"));
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1005.00", "1006.00"));
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "999.00", "1000.00"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    let OrderEventAny::Updated(updated) = &saved_messages[1] else {
+        panic!("Expected update, was {:?}", saved_messages[1]);
+    };
+    assert_eq!(updated.trigger_price, Some(Price::from("1000.00")));
+    assert_eq!(updated.price, None);
+    let OrderEventAny::Filled(fill) = &saved_messages[2] else {
+        panic!("Expected fill, was {:?}", saved_messages[2]);
+    };
+    assert_eq!(fill.last_px, Price::from("999.00"));
+    assert!(!engine.order_exists(trailing_order.client_order_id()));
+}
+
+#[rstest]
+fn test_process_trailing_stop_limit_order_trails_then_triggered(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_no_slippage(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "999.00", "1000.00"));
+
+    let trailing_order = submit_order(
+        OrderTestBuilder::new(OrderType::TrailingStopLimit)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("1030.00"))
+            .trigger_price(Price::from("1020.00"))
+            .trigger_type(TriggerType::BidAsk)
+            .limit_offset(Price::from("100"))
+            .trailing_offset(Price::from("50"))
+            .trailing_offset_type(TrailingOffsetType::BasisPoints)
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&trailing_order, account_id);
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1010.00", "1011.00"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    // Trailed down off the ask on acceptance: 1000.00 + 0.5% and 1000.00 + 1%
+    let OrderEventAny::Updated(updated) = &saved_messages[1] else {
+        panic!("Expected update, was {:?}", saved_messages[1]);
+    };
+    assert_eq!(updated.trigger_price, Some(Price::from("1005.00")));
+    assert_eq!(updated.price, Some(Price::from("1010.00")));
+    assert_eq!(saved_messages[2].event_type(), OrderEventType::Triggered);
+}
+
+#[rstest]
+fn test_process_stop_limit_order_triggered_then_filled(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_no_slippage(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1000.00", "1001.00"));
+
+    let stop_limit_order = submit_order(
+        OrderTestBuilder::new(OrderType::StopLimit)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Buy)
+            .trigger_price(Price::from("1005.00"))
+            .price(Price::from("1004.00"))
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&stop_limit_order, account_id);
+
+    // Trigger the order without reaching its limit price
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1004.00", "1005.00"));
+    assert!(engine.order_exists(stop_limit_order.client_order_id()));
+
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1002.00", "1003.00"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    assert_eq!(saved_messages[1].event_type(), OrderEventType::Triggered);
+    let OrderEventAny::Filled(fill) = &saved_messages[2] else {
+        panic!("Expected fill, was {:?}", saved_messages[2]);
+    };
+    assert_eq!(fill.last_px, Price::from("1004.00"));
+    assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+}
+
+#[rstest]
+fn test_process_market_if_touched_order_filled_when_touched(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_no_slippage(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1000.00", "1001.00"));
+
+    let mit_order = submit_order(
+        OrderTestBuilder::new(OrderType::MarketIfTouched)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Sell)
+            .trigger_price(Price::from("1010.00"))
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&mit_order, account_id);
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1010.00", "1011.00"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    let OrderEventAny::Filled(fill) = &saved_messages[1] else {
+        panic!("Expected fill, was {:?}", saved_messages[1]);
+    };
+    assert_eq!(fill.last_px, Price::from("1010.00"));
+}
+
+#[rstest]
+fn test_process_modify_and_cancel_limit_order(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_no_slippage(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1000.00", "1001.00"));
+
+    let limit_order = submit_order(
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("990.00"))
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&limit_order, account_id);
+
+    let modify = ModifyOrder::new(
+        limit_order.trader_id(),
+        ClientId::from("BINANCE"),
+        limit_order.strategy_id(),
+        limit_order.instrument_id(),
+        limit_order.client_order_id(),
+        VenueOrderId::from("BINANCE-1-1"),
+        None,
+        Some(Price::from("995.00")),
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine.process_modify(&modify, account_id);
+
+    let cancel = CancelOrder::new(
+        limit_order.trader_id(),
+        ClientId::from("BINANCE"),
+        limit_order.strategy_id(),
+        limit_order.instrument_id(),
+        limit_order.client_order_id(),
+        VenueOrderId::from("BINANCE-1-1"),
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine.process_cancel(&cancel, account_id);
+    engine.process_cancel(&cancel, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 4);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    let OrderEventAny::Updated(updated) = &saved_messages[1] else {
+        panic!("Expected update, was {:?}", saved_messages[1]);
+    };
+    assert_eq!(updated.price, Some(Price::from("995.00")));
+    assert_eq!(saved_messages[2].event_type(), OrderEventType::Canceled);
+    assert_eq!(
+        saved_messages[3].event_type(),
+        OrderEventType::CancelRejected
+    );
+    assert!(!engine.order_exists(limit_order.client_order_id()));
+}
+
+#[rstest]
+fn test_oto_child_order_held_until_parent_filled(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let mut engine = get_order_matching_engine_contingent(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        cache.clone(),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1001.00", "1002.00"));
+
+    let entry_client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let take_profit_client_order_id = ClientOrderId::from("O-19700101-000000-001-001-2");
+    let entry_order = submit_order(
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(entry_client_order_id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1000.00"))
+            .quantity(Quantity::from("1.000"))
+            .contingency_type(ContingencyType::Oto)
+            .linked_order_ids(vec![take_profit_client_order_id])
+            .build(),
+        account_id,
+    );
+    let take_profit_order = submit_order(
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(take_profit_client_order_id)
+            .side(OrderSide::Sell)
+            .price(Price::from("1050.00"))
+            .quantity(Quantity::from("1.000"))
+            .parent_order_id(entry_client_order_id)
+            .build(),
+        account_id,
+    );
+    for order in [&entry_order, &take_profit_order] {
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+    }
+
+    engine.process_order(&entry_order, account_id);
+    engine.process_order(&take_profit_order, account_id);
+    assert!(!engine.order_exists(take_profit_client_order_id));
+
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "999.00", "1000.00"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    assert_eq!(saved_messages[1].event_type(), OrderEventType::Filled);
+    assert_eq!(saved_messages[2].event_type(), OrderEventType::Accepted);
+    assert_eq!(
+        saved_messages[2].client_order_id(),
+        take_profit_client_order_id
+    );
+    assert!(engine.order_exists(take_profit_client_order_id));
+}
+
+#[rstest]
+fn test_oco_order_filled_cancels_linked_order(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let mut engine = get_order_matching_engine_contingent(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        cache.clone(),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1000.00", "1001.00"));
+
+    let limit_client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let stop_client_order_id = ClientOrderId::from("O-19700101-000000-001-001-2");
+    let limit_order = submit_order(
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(limit_client_order_id)
+            .side(OrderSide::Sell)
+            .price(Price::from("1050.00"))
+            .quantity(Quantity::from("1.000"))
+            .contingency_type(ContingencyType::Oco)
+            .linked_order_ids(vec![stop_client_order_id])
+            .build(),
+        account_id,
+    );
+    let stop_order = submit_order(
+        OrderTestBuilder::new(OrderType::StopMarket)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(stop_client_order_id)
+            .side(OrderSide::Sell)
+            .trigger_price(Price::from("950.00"))
+            .quantity(Quantity::from("1.000"))
+            .contingency_type(ContingencyType::Oco)
+            .linked_order_ids(vec![limit_client_order_id])
+            .build(),
+        account_id,
+    );
+    for order in [&limit_order, &stop_order] {
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+    }
+
+    engine.process_order(&limit_order, account_id);
+    engine.process_order(&stop_order, account_id);
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1050.00", "1051.00"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 4);
+    assert_eq!(saved_messages[2].event_type(), OrderEventType::Filled);
+    assert_eq!(saved_messages[2].client_order_id(), limit_client_order_id);
+    assert_eq!(saved_messages[3].event_type(), OrderEventType::Canceled);
+    assert_eq!(saved_messages[3].client_order_id(), stop_client_order_id);
+    assert!(!engine.order_exists(stop_client_order_id));
+}
+
+#[rstest]
+fn test_ouo_order_modified_updates_linked_order(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let mut engine = get_order_matching_engine_contingent(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        cache.clone(),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1000.00", "1001.00"));
+
+    let limit_client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let stop_client_order_id = ClientOrderId::from("O-19700101-000000-001-001-2");
+    let limit_order = submit_order(
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(limit_client_order_id)
+            .side(OrderSide::Sell)
+            .price(Price::from("1050.00"))
+            .quantity(Quantity::from("2.000"))
+            .contingency_type(ContingencyType::Ouo)
+            .linked_order_ids(vec![stop_client_order_id])
+            .build(),
+        account_id,
+    );
+    let stop_order = submit_order(
+        OrderTestBuilder::new(OrderType::StopMarket)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(stop_client_order_id)
+            .side(OrderSide::Sell)
+            .trigger_price(Price::from("950.00"))
+            .quantity(Quantity::from("2.000"))
+            .contingency_type(ContingencyType::Ouo)
+            .linked_order_ids(vec![limit_client_order_id])
+            .build(),
+        account_id,
+    );
+    for order in [&limit_order, &stop_order] {
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+    }
+
+    engine.process_order(&limit_order, account_id);
+    engine.process_order(&stop_order, account_id);
+
+    let modify = ModifyOrder::new(
+        limit_order.trader_id(),
+        ClientId::from("BINANCE"),
+        limit_order.strategy_id(),
+        limit_order.instrument_id(),
+        limit_client_order_id,
+        VenueOrderId::from("BINANCE-1-1"),
+        Some(Quantity::from("1.000")),
+        None,
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine.process_modify(&modify, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 4);
+    let OrderEventAny::Updated(updated) = &saved_messages[3] else {
+        panic!("Expected update, was {:?}", saved_messages[3]);
+    };
+    assert_eq!(updated.client_order_id, stop_client_order_id);
+    assert_eq!(updated.quantity, Quantity::from("1.000"));
+}
+
+#[rstest]
+fn test_passive_limit_order_fills_when_queue_ahead_has_traded(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_with_fill_model(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        FillModel::new(1.0, 1.0, 0.0, 0.0, true, None).unwrap(),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1000.00", "1001.00"));
+
+    // Joins the bid behind the displayed 1.000
+    let limit_order = submit_order(
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("1000.00"))
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&limit_order, account_id);
+
+    engine.process_trade_tick(&get_trade_tick(&instrument_eth_usdt, "1000.00", "0.500"));
+    assert!(engine.order_exists(limit_order.client_order_id()));
+    assert_eq!(
+        get_order_event_handler_messages(order_event_handler.clone()).len(),
+        1
+    );
+
+    engine.process_trade_tick(&get_trade_tick(&instrument_eth_usdt, "1000.00", "0.500"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let OrderEventAny::Filled(fill) = &saved_messages[1] else {
+        panic!("Expected fill, was {:?}", saved_messages[1]);
+    };
+    assert_eq!(fill.last_px, Price::from("1000.00"));
+    assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+    assert!(!engine.order_exists(limit_order.client_order_id()));
+}
+
+#[rstest]
+fn test_passive_limit_order_partially_filled(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_with_fill_model(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        FillModel::new(1.0, 1.0, 0.0, 1.0, false, Some(42)).unwrap(),
+    );
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1000.00", "1001.00"));
+
+    let limit_order = submit_order(
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("999.00"))
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&limit_order, account_id);
+
+    // Every match fills at least one unit, so the order completes within 1000 quotes
+    for _ in 0..1000 {
+        if !engine.order_exists(limit_order.client_order_id()) {
+            break;
+        }
+        engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "997.00", "998.00"));
+    }
+
+    let fills: Vec<OrderFilled> = get_order_event_handler_messages(order_event_handler)
+        .into_iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(fill) => Some(fill),
+            _ => None,
+        })
+        .collect();
+    let filled_qty: f64 = fills.iter().map(|fill| fill.last_qty.as_f64()).sum();
+    assert!(fills.len() > 1);
+    assert!((filled_qty - 1.0).abs() < 1e-9);
+    assert!(fills
+        .iter()
+        .all(|fill| fill.last_px == Price::from("999.00")));
+    assert!(!engine.order_exists(limit_order.client_order_id()));
+}
+
+#[rstest]
+fn test_fill_commission_from_fee_model(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_no_slippage(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+    );
+    engine.set_fee_model(FeeModelAny::PerContract(
+        PerContractFeeModel::new(Money::new(0.5, Currency::USDT())).unwrap(),
+    ));
+    engine.process_quote_tick(&get_quote_tick(&instrument_eth_usdt, "1000.00", "1001.00"));
+
+    let market_order = submit_order(
+        OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&market_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let OrderEventAny::Filled(fill) = &saved_messages[0] else {
+        panic!("Expected fill, was {:?}", saved_messages[0]);
+    };
+    assert_eq!(fill.commission, Some(Money::new(0.5, Currency::USDT())));
+}
+
+#[rstest]
+fn test_fill_commission_for_inverse_instrument(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    xbtusd_bitmex: CryptoPerpetual,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let instrument = InstrumentAny::CryptoPerpetual(xbtusd_bitmex);
+    let mut engine =
+        get_order_matching_engine_no_slippage(instrument.clone(), Rc::new(RefCell::new(msgbus)));
+    engine.set_fee_model(FeeModelAny::MakerTaker(MakerTakerFeeModel));
+    engine.process_quote_tick(&QuoteTick::new(
+        instrument.id(),
+        Price::from("10000.0"),
+        Price::from("10000.5"),
+        Quantity::from(1_000),
+        Quantity::from(1_000),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    ));
+
+    let market_order = submit_order(
+        OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&market_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let OrderEventAny::Filled(fill) = &saved_messages[0] else {
+        panic!("Expected fill, was {:?}", saved_messages[0]);
+    };
+    assert_eq!(fill.commission.unwrap().currency, Currency::BTC());
+}
+
+#[rstest]
+fn test_bar_execution_stop_order_filled_at_gapped_open(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_bar_execution(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        false,
+    );
+    engine.process_bar(&get_bar(
+        &instrument_eth_usdt,
+        "1000.00",
+        "1000.00",
+        "1000.00",
+        "1000.00",
+    ));
+
+    let stop_order = submit_order(
+        OrderTestBuilder::new(OrderType::StopMarket)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Sell)
+            .trigger_price(Price::from("990.00"))
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        account_id,
+    );
+    engine.process_order(&stop_order, account_id);
+    // Next bar gaps down through the stop
+    engine.process_bar(&get_bar(
+        &instrument_eth_usdt,
+        "980.00",
+        "985.00",
+        "975.00",
+        "982.00",
+    ));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let OrderEventAny::Filled(fill) = saved_messages.last().unwrap() else {
+        panic!("Expected fill, was {:?}", saved_messages.last());
+    };
+    assert_eq!(fill.last_px, Price::from("980.00"));
+}
+
+#[rstest]
+#[case(false, OrderSide::Buy)]
+#[case(true, OrderSide::Sell)]
+fn test_bar_execution_high_low_ordering(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+    #[case] bar_adaptive_high_low_ordering: bool,
+    #[case] expected_first_fill_side: OrderSide,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine_bar_execution(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        bar_adaptive_high_low_ordering,
+    );
+    engine.process_bar(&get_bar(
+        &instrument_eth_usdt,
+        "1000.00",
+        "1000.00",
+        "1000.00",
+        "1000.00",
+    ));
+
+    for (side, trigger_price) in [(OrderSide::Buy, "1015.00"), (OrderSide::Sell, "999.00")] {
+        let stop_order = submit_order(
+            OrderTestBuilder::new(OrderType::StopMarket)
+                .instrument_id(instrument_eth_usdt.id())
+                .side(side)
+                .trigger_price(Price::from(trigger_price))
+                .quantity(Quantity::from("1.000"))
+                .build(),
+            account_id,
+        );
+        engine.process_order(&stop_order, account_id);
+    }
+    // The low is closest to the open, so is traded first with adaptive ordering
+    engine.process_bar(&get_bar(
+        &instrument_eth_usdt,
+        "1000.00",
+        "1020.00",
+        "998.00",
+        "1010.00",
+    ));
+
+    let fills: Vec<OrderFilled> = get_order_event_handler_messages(order_event_handler)
+        .into_iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(fill) => Some(fill),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(fills.len(), 2);
+    assert_eq!(fills[0].order_side, expected_first_fill_side);
+}
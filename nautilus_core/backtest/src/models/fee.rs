@@ -17,9 +17,9 @@ use nautilus_model::{
     enums::LiquiditySide,
     instruments::InstrumentAny,
     orders::OrderAny,
-    types::{Money, Price, Quantity},
+    types::{Currency, Money, Price, Quantity},
 };
-use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 
 pub trait FeeModel {
     fn get_commission(
@@ -27,6 +27,7 @@ pub trait FeeModel {
         order: &OrderAny,
         fill_quantity: Quantity,
         fill_px: Price,
+        liquidity_side: LiquiditySide,
         instrument: &InstrumentAny,
     ) -> anyhow::Result<Money>;
 }
@@ -35,6 +36,34 @@ pub trait FeeModel {
 pub enum FeeModelAny {
     Fixed(FixedFeeModel),
     MakerTaker(MakerTakerFeeModel),
+    MakerTakerBps(MakerTakerBpsFeeModel),
+    PerContract(PerContractFeeModel),
+}
+
+impl FeeModel for FeeModelAny {
+    fn get_commission(
+        &self,
+        order: &OrderAny,
+        fill_quantity: Quantity,
+        fill_px: Price,
+        liquidity_side: LiquiditySide,
+        instrument: &InstrumentAny,
+    ) -> anyhow::Result<Money> {
+        match self {
+            Self::Fixed(model) => {
+                model.get_commission(order, fill_quantity, fill_px, liquidity_side, instrument)
+            }
+            Self::MakerTaker(model) => {
+                model.get_commission(order, fill_quantity, fill_px, liquidity_side, instrument)
+            }
+            Self::MakerTakerBps(model) => {
+                model.get_commission(order, fill_quantity, fill_px, liquidity_side, instrument)
+            }
+            Self::PerContract(model) => {
+                model.get_commission(order, fill_quantity, fill_px, liquidity_side, instrument)
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -65,6 +94,7 @@ impl FeeModel for FixedFeeModel {
         order: &OrderAny,
         _fill_quantity: Quantity,
         _fill_px: Price,
+        _liquidity_side: LiquiditySide,
         _instrument: &InstrumentAny,
     ) -> anyhow::Result<Money> {
         if !self.change_commission_once || order.filled_qty().is_zero() {
@@ -75,32 +105,132 @@ impl FeeModel for FixedFeeModel {
     }
 }
 
+/// Charges the instruments maker and taker fee rates on the notional value of each fill.
 #[derive(Debug, Clone)]
 pub struct MakerTakerFeeModel;
 
 impl FeeModel for MakerTakerFeeModel {
     fn get_commission(
         &self,
-        order: &OrderAny,
+        _order: &OrderAny,
+        fill_quantity: Quantity,
+        fill_px: Price,
+        liquidity_side: LiquiditySide,
+        instrument: &InstrumentAny,
+    ) -> anyhow::Result<Money> {
+        let fee_rate = match liquidity_side {
+            LiquiditySide::Maker => instrument.maker_fee(),
+            LiquiditySide::Taker => instrument.taker_fee(),
+            LiquiditySide::NoLiquiditySide => anyhow::bail!("Liquidity side not set."),
+        };
+        notional_commission(instrument, fill_quantity, fill_px, fee_rate)
+    }
+}
+
+/// Charges maker and taker fees in basis points of the notional value of each fill,
+/// independent of the fee rates defined on the instrument.
+///
+/// A negative maker fee models a rebate.
+#[derive(Debug, Clone)]
+pub struct MakerTakerBpsFeeModel {
+    maker_fee_bps: Decimal,
+    taker_fee_bps: Decimal,
+}
+
+impl MakerTakerBpsFeeModel {
+    /// Creates a new [`MakerTakerBpsFeeModel`] instance.
+    #[must_use]
+    pub const fn new(maker_fee_bps: Decimal, taker_fee_bps: Decimal) -> Self {
+        Self {
+            maker_fee_bps,
+            taker_fee_bps,
+        }
+    }
+}
+
+impl FeeModel for MakerTakerBpsFeeModel {
+    fn get_commission(
+        &self,
+        _order: &OrderAny,
         fill_quantity: Quantity,
         fill_px: Price,
+        liquidity_side: LiquiditySide,
         instrument: &InstrumentAny,
     ) -> anyhow::Result<Money> {
-        let notional = instrument.calculate_notional_value(fill_quantity, fill_px, Some(false));
-        let commission = match order.liquidity_side() {
-            Some(LiquiditySide::Maker) => notional * instrument.maker_fee().to_f64().unwrap(),
-            Some(LiquiditySide::Taker) => notional * instrument.taker_fee().to_f64().unwrap(),
-            Some(LiquiditySide::NoLiquiditySide) | None => anyhow::bail!("Liquidity side not set."),
+        let fee_bps = match liquidity_side {
+            LiquiditySide::Maker => self.maker_fee_bps,
+            LiquiditySide::Taker => self.taker_fee_bps,
+            LiquiditySide::NoLiquiditySide => anyhow::bail!("Liquidity side not set."),
         };
-        match instrument.is_inverse() {
-            true => Ok(Money::new(commission, instrument.base_currency().unwrap())),
-            false => Ok(Money::new(commission, instrument.quote_currency())),
+        let fee_rate = fee_bps / Decimal::from(10_000);
+        notional_commission(instrument, fill_quantity, fill_px, fee_rate)
+    }
+}
+
+/// Charges a fixed commission per contract (unit of quantity) filled, as is common
+/// for futures and options venues.
+#[derive(Debug, Clone)]
+pub struct PerContractFeeModel {
+    commission: Money,
+}
+
+impl PerContractFeeModel {
+    /// Creates a new [`PerContractFeeModel`] instance.
+    pub fn new(commission: Money) -> anyhow::Result<Self> {
+        if commission.as_f64() < 0.0 {
+            anyhow::bail!("Commission must be greater than or equal to zero.")
         }
+        Ok(Self { commission })
+    }
+}
+
+impl FeeModel for PerContractFeeModel {
+    fn get_commission(
+        &self,
+        _order: &OrderAny,
+        fill_quantity: Quantity,
+        _fill_px: Price,
+        _liquidity_side: LiquiditySide,
+        _instrument: &InstrumentAny,
+    ) -> anyhow::Result<Money> {
+        Ok(Money::new(
+            self.commission.as_f64() * fill_quantity.as_f64(),
+            self.commission.currency,
+        ))
+    }
+}
+
+fn notional_commission(
+    instrument: &InstrumentAny,
+    fill_quantity: Quantity,
+    fill_px: Price,
+    fee_rate: Decimal,
+) -> anyhow::Result<Money> {
+    let notional = instrument.calculate_notional_value(fill_quantity, fill_px, Some(false));
+    let fee_rate = fee_rate
+        .to_f64()
+        .ok_or_else(|| anyhow::anyhow!("Cannot convert fee rate {fee_rate} to f64"))?;
+    Ok(Money::new(
+        notional * fee_rate,
+        commission_currency(instrument)?,
+    ))
+}
+
+/// Returns the currency commissions are charged in for the given `instrument`.
+fn commission_currency(instrument: &InstrumentAny) -> anyhow::Result<Currency> {
+    if instrument.is_inverse() {
+        instrument
+            .base_currency()
+            .ok_or_else(|| anyhow::anyhow!("No base currency for inverse {}", instrument.id()))
+    } else {
+        Ok(instrument.quote_currency())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use nautilus_model::{
         enums::{LiquiditySide, OrderSide, OrderType},
         instruments::{stubs::audusd_sim, InstrumentAny},
@@ -111,9 +241,12 @@ mod tests {
         types::{Currency, Money, Price, Quantity},
     };
     use rstest::rstest;
-    use rust_decimal::prelude::ToPrimitive;
+    use rust_decimal::{prelude::ToPrimitive, Decimal};
 
-    use crate::models::fee::{FeeModel, FixedFeeModel, MakerTakerFeeModel};
+    use crate::models::fee::{
+        FeeModel, FeeModelAny, FixedFeeModel, MakerTakerBpsFeeModel, MakerTakerFeeModel,
+        PerContractFeeModel,
+    };
 
     #[rstest]
     fn test_fixed_model_single_fill() {
@@ -131,6 +264,7 @@ mod tests {
                 &accepted_order,
                 Quantity::from(100_000),
                 Price::from("1.0"),
+                LiquiditySide::Taker,
                 &aud_usd,
             )
             .unwrap();
@@ -162,6 +296,7 @@ mod tests {
                 &accepted_order,
                 Quantity::from(50_000),
                 Price::from("1.0"),
+                LiquiditySide::Taker,
                 &aud_usd,
             )
             .unwrap();
@@ -183,6 +318,7 @@ mod tests {
                 &accepted_order,
                 Quantity::from(50_000),
                 Price::from("1.0"),
+                LiquiditySide::Taker,
                 &aud_usd,
             )
             .unwrap();
@@ -211,6 +347,7 @@ mod tests {
                 &order_filled,
                 Quantity::from(100_000),
                 Price::from("1.0"),
+                LiquiditySide::Maker,
                 &aud_usd,
             )
             .unwrap();
@@ -239,9 +376,70 @@ mod tests {
                 &order_filled,
                 Quantity::from(100_000),
                 Price::from("1.0"),
+                LiquiditySide::Taker,
                 &aud_usd,
             )
             .unwrap();
         assert_eq!(commission.as_f64(), expected_commission_amount);
     }
+
+    #[rstest]
+    #[case(LiquiditySide::Maker, -0.2)] // Rebate
+    #[case(LiquiditySide::Taker, 5.0)]
+    fn test_maker_taker_bps_fee_model(
+        #[case] liquidity_side: LiquiditySide,
+        #[case] expected_commission: f64,
+    ) {
+        let fee_model = FeeModelAny::MakerTakerBps(MakerTakerBpsFeeModel::new(
+            Decimal::from_str("-0.2").unwrap(),
+            Decimal::from(5),
+        ));
+        let aud_usd = InstrumentAny::CurrencyPair(audusd_sim());
+        let limit_order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(aud_usd.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("1.0"))
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let commission = fee_model
+            .get_commission(
+                &limit_order,
+                Quantity::from(10_000),
+                Price::from("1.0"),
+                liquidity_side,
+                &aud_usd,
+            )
+            .unwrap();
+
+        assert_eq!(commission, Money::new(expected_commission, Currency::USD()));
+    }
+
+    #[rstest]
+    fn test_per_contract_fee_model() {
+        let fee_model = PerContractFeeModel::new(Money::from("2.50 USD")).unwrap();
+        let aud_usd = InstrumentAny::CurrencyPair(audusd_sim());
+        let market_order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(aud_usd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(10))
+            .build();
+
+        let commission = fee_model
+            .get_commission(
+                &market_order,
+                Quantity::from(4),
+                Price::from("1.0"),
+                LiquiditySide::Taker,
+                &aud_usd,
+            )
+            .unwrap();
+
+        assert_eq!(commission, Money::from("10.00 USD"));
+    }
+
+    #[rstest]
+    fn test_per_contract_fee_model_negative_commission_error() {
+        assert!(PerContractFeeModel::new(Money::from("-1.00 USD")).is_err());
+    }
 }
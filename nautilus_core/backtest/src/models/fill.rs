@@ -13,9 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
 
 use nautilus_core::correctness::{check_in_range_inclusive_f64, FAILED};
+use nautilus_model::{identifiers::ClientOrderId, types::Quantity};
 use rand::{rngs::StdRng, Rng, SeedableRng};
 
 #[derive(Debug, Clone)]
@@ -26,6 +27,10 @@ pub struct FillModel {
     prob_fill_on_stop: f64,
     /// The probability of order fill prices slipping by one tick.
     prob_slippage: f64,
+    /// The probability of a passive order only partially filling when matched.
+    prob_partial_fill: f64,
+    /// The optional model of the displayed size queued ahead of resting orders.
+    queue_position: Option<QueuePositionModel>,
     /// Random number generator
     rng: StdRng,
 }
@@ -36,6 +41,8 @@ impl FillModel {
         prob_fill_on_limit: f64,
         prob_fill_on_stop: f64,
         prob_slippage: f64,
+        prob_partial_fill: f64,
+        use_queue_position: bool,
         random_seed: Option<u64>,
     ) -> anyhow::Result<Self> {
         check_in_range_inclusive_f64(prob_fill_on_limit, 0.0, 1.0, "prob_fill_on_limit")
@@ -43,6 +50,8 @@ impl FillModel {
         check_in_range_inclusive_f64(prob_fill_on_stop, 0.0, 1.0, "prob_fill_on_stop")
             .expect(FAILED);
         check_in_range_inclusive_f64(prob_slippage, 0.0, 1.0, "prob_slippage").expect(FAILED);
+        check_in_range_inclusive_f64(prob_partial_fill, 0.0, 1.0, "prob_partial_fill")
+            .expect(FAILED);
         let rng = match random_seed {
             Some(seed) => StdRng::seed_from_u64(seed),
             None => StdRng::from_entropy(),
@@ -51,6 +60,8 @@ impl FillModel {
             prob_fill_on_limit,
             prob_fill_on_stop,
             prob_slippage,
+            prob_partial_fill,
+            queue_position: use_queue_position.then(QueuePositionModel::new),
             rng,
         })
     }
@@ -67,6 +78,40 @@ impl FillModel {
         self.event_success(self.prob_slippage)
     }
 
+    pub fn is_partially_filled(&mut self) -> bool {
+        self.event_success(self.prob_partial_fill)
+    }
+
+    /// Returns the quantity of a passive fill for the given remaining `quantity`.
+    ///
+    /// When the fill is partial, a random fraction of the quantity is filled (at least
+    /// one unit at the quantities precision).
+    pub fn fill_quantity(&mut self, quantity: Quantity) -> Quantity {
+        if !self.is_partially_filled() {
+            return quantity;
+        }
+
+        let min_quantity = Quantity::new(
+            10f64.powi(-i32::from(quantity.precision)),
+            quantity.precision,
+        );
+        let fraction = self.rng.gen_range(0.0..1.0);
+        Quantity::new(quantity.as_f64() * fraction, quantity.precision)
+            .max(min_quantity)
+            .min(quantity)
+    }
+
+    /// Returns the queue position model (if enabled).
+    #[must_use]
+    pub const fn queue_position(&self) -> Option<&QueuePositionModel> {
+        self.queue_position.as_ref()
+    }
+
+    /// Returns a mutable reference to the queue position model (if enabled).
+    pub fn queue_position_mut(&mut self) -> Option<&mut QueuePositionModel> {
+        self.queue_position.as_mut()
+    }
+
     fn event_success(&mut self, probability: f64) -> bool {
         match probability {
             0.0 => false,
@@ -80,8 +125,12 @@ impl Display for FillModel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "FillModel(prob_fill_on_limit: {}, prob_fill_on_stop: {}, prob_slippage: {})",
-            self.prob_fill_on_limit, self.prob_fill_on_stop, self.prob_slippage
+            "FillModel(prob_fill_on_limit: {}, prob_fill_on_stop: {}, prob_slippage: {}, prob_partial_fill: {}, use_queue_position: {})",
+            self.prob_fill_on_limit,
+            self.prob_fill_on_stop,
+            self.prob_slippage,
+            self.prob_partial_fill,
+            self.queue_position.is_some(),
         )
     }
 }
@@ -89,7 +138,66 @@ impl Display for FillModel {
 impl Default for FillModel {
     /// Creates a new default [`FillModel`] instance.
     fn default() -> Self {
-        Self::new(0.5, 0.5, 0.1, None).unwrap()
+        Self::new(0.5, 0.5, 0.1, 0.0, false, None).unwrap()
+    }
+}
+
+/// Tracks the displayed size queued ahead of resting passive orders at their price level.
+///
+/// A passive order joins the back of its level, then advances as trades execute at its
+/// price. The size ahead can never exceed the displayed size of the level, so any
+/// reduction of the level also advances the order.
+#[derive(Debug, Clone, Default)]
+pub struct QueuePositionModel {
+    size_ahead: HashMap<ClientOrderId, f64>,
+}
+
+impl QueuePositionModel {
+    /// Creates a new [`QueuePositionModel`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Places the order at the back of its level, behind the given displayed `size_ahead`.
+    pub fn add_order(&mut self, client_order_id: ClientOrderId, size_ahead: f64) {
+        self.size_ahead.insert(client_order_id, size_ahead);
+    }
+
+    pub fn remove_order(&mut self, client_order_id: &ClientOrderId) {
+        self.size_ahead.remove(client_order_id);
+    }
+
+    /// Advances the order by the given `traded_size` executed at its price.
+    pub fn on_trade(&mut self, client_order_id: &ClientOrderId, traded_size: f64) {
+        if let Some(size_ahead) = self.size_ahead.get_mut(client_order_id) {
+            *size_ahead = (*size_ahead - traded_size).max(0.0);
+        }
+    }
+
+    /// Caps the size ahead of the order at the displayed `level_size` of its price level.
+    pub fn on_level_update(&mut self, client_order_id: &ClientOrderId, level_size: f64) {
+        if let Some(size_ahead) = self.size_ahead.get_mut(client_order_id) {
+            *size_ahead = size_ahead.min(level_size);
+        }
+    }
+
+    /// Returns the displayed size ahead of the order (if tracked).
+    #[must_use]
+    pub fn size_ahead(&self, client_order_id: &ClientOrderId) -> Option<f64> {
+        self.size_ahead.get(client_order_id).copied()
+    }
+
+    /// Returns whether the order is at the front of its level (untracked orders always are).
+    #[must_use]
+    pub fn is_at_front(&self, client_order_id: &ClientOrderId) -> bool {
+        self.size_ahead
+            .get(client_order_id)
+            .is_none_or(|size_ahead| *size_ahead <= 0.0)
+    }
+
+    pub fn reset(&mut self) {
+        self.size_ahead.clear();
     }
 }
 
@@ -105,7 +213,7 @@ mod tests {
     #[fixture]
     fn fill_model() -> FillModel {
         let seed = 42;
-        FillModel::new(0.5, 0.5, 0.1, Some(seed)).unwrap()
+        FillModel::new(0.5, 0.5, 0.1, 0.0, false, Some(seed)).unwrap()
     }
 
     #[rstest]
@@ -113,7 +221,7 @@ mod tests {
         expected = "Condition failed: invalid f64 for 'prob_fill_on_limit' not in range [0, 1], was 1.1"
     )]
     fn test_fill_model_param_prob_fill_on_limit_error() {
-        let _ = super::FillModel::new(1.1, 0.5, 0.1, None).unwrap();
+        let _ = super::FillModel::new(1.1, 0.5, 0.1, 0.0, false, None).unwrap();
     }
 
     #[rstest]
@@ -121,7 +229,7 @@ mod tests {
         expected = "Condition failed: invalid f64 for 'prob_fill_on_stop' not in range [0, 1], was 1.1"
     )]
     fn test_fill_model_param_prob_fill_on_stop_error() {
-        let _ = super::FillModel::new(0.5, 1.1, 0.1, None).unwrap();
+        let _ = super::FillModel::new(0.5, 1.1, 0.1, 0.0, false, None).unwrap();
     }
 
     #[rstest]
@@ -129,7 +237,15 @@ mod tests {
         expected = "Condition failed: invalid f64 for 'prob_slippage' not in range [0, 1], was 1.1"
     )]
     fn test_fill_model_param_prob_slippage_error() {
-        let _ = super::FillModel::new(0.5, 0.5, 1.1, None).unwrap();
+        let _ = super::FillModel::new(0.5, 0.5, 1.1, 0.0, false, None).unwrap();
+    }
+
+    #[rstest]
+    #[should_panic(
+        expected = "Condition failed: invalid f64 for 'prob_partial_fill' not in range [0, 1], was 1.1"
+    )]
+    fn test_fill_model_param_prob_partial_fill_error() {
+        let _ = super::FillModel::new(0.5, 0.5, 0.1, 1.1, false, None).unwrap();
     }
 
     #[rstest]
@@ -152,4 +268,54 @@ mod tests {
         let result = fill_model.is_slipped();
         assert!(!result);
     }
+
+    #[rstest]
+    fn test_fill_model_fill_quantity_when_no_partial_fills(mut fill_model: FillModel) {
+        let quantity = Quantity::from("10.00");
+
+        assert_eq!(fill_model.fill_quantity(quantity), quantity);
+    }
+
+    #[rstest]
+    fn test_fill_model_fill_quantity_when_partial_fills() {
+        let mut fill_model = FillModel::new(1.0, 1.0, 0.0, 1.0, false, Some(42)).unwrap();
+        let quantity = Quantity::from("10.00");
+
+        for _ in 0..100 {
+            let fill_qty = fill_model.fill_quantity(quantity);
+            assert!(fill_qty >= Quantity::from("0.01"));
+            assert!(fill_qty <= quantity);
+            assert_eq!(fill_qty.precision, 2);
+        }
+    }
+
+    #[rstest]
+    fn test_queue_position_model_disabled_by_default(fill_model: FillModel) {
+        assert!(fill_model.queue_position().is_none());
+    }
+
+    #[rstest]
+    fn test_queue_position_model_advances_on_trades_and_level_updates() {
+        let mut queue = QueuePositionModel::new();
+        let client_order_id = ClientOrderId::from("O-123456");
+        queue.add_order(client_order_id, 10.0);
+
+        queue.on_trade(&client_order_id, 4.0);
+        assert_eq!(queue.size_ahead(&client_order_id), Some(6.0));
+        assert!(!queue.is_at_front(&client_order_id));
+
+        queue.on_level_update(&client_order_id, 8.0);
+        assert_eq!(queue.size_ahead(&client_order_id), Some(6.0));
+
+        queue.on_level_update(&client_order_id, 2.0);
+        assert_eq!(queue.size_ahead(&client_order_id), Some(2.0));
+
+        queue.on_trade(&client_order_id, 5.0);
+        assert_eq!(queue.size_ahead(&client_order_id), Some(0.0));
+        assert!(queue.is_at_front(&client_order_id));
+
+        queue.remove_order(&client_order_id);
+        assert_eq!(queue.size_ahead(&client_order_id), None);
+        assert!(queue.is_at_front(&client_order_id));
+    }
 }
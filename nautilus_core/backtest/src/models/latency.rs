@@ -13,12 +13,396 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
 
-pub struct LatencyModel;
+use nautilus_common::messages::execution::TradingCommand;
+use nautilus_core::{
+    correctness::{check_predicate_true, FAILED},
+    nanos::UnixNanos,
+};
+use nautilus_model::{
+    data::{Data, GetTsInit},
+    identifiers::Venue,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Provides a latency model for the round trip of trading commands to a simulated venue.
+///
+/// Insert, update and cancel latencies are added to the base latency, with an optional
+/// jitter sampled per command.
+#[derive(Debug, Clone)]
+pub struct LatencyModel {
+    /// The base latency (nanoseconds) applied to every command.
+    pub base_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order inserts.
+    pub insert_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order updates.
+    pub update_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order cancels.
+    pub cancel_latency_nanos: u64,
+    jitter: Option<FeedDelay>,
+    rng: StdRng,
+}
+
+impl LatencyModel {
+    /// Creates a new [`LatencyModel`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `jitter` has invalid parameters.
+    #[must_use]
+    pub fn new(
+        base_latency_nanos: u64,
+        insert_latency_nanos: u64,
+        update_latency_nanos: u64,
+        cancel_latency_nanos: u64,
+        jitter: Option<FeedDelay>,
+        random_seed: Option<u64>,
+    ) -> Self {
+        if let Some(jitter) = jitter {
+            jitter.validate();
+        }
+        let rng = match random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Self {
+            base_latency_nanos,
+            insert_latency_nanos,
+            update_latency_nanos,
+            cancel_latency_nanos,
+            jitter,
+            rng,
+        }
+    }
+
+    /// Samples the total latency (nanoseconds) for an order insert.
+    pub fn insert_latency(&mut self) -> u64 {
+        self.sample(self.insert_latency_nanos)
+    }
+
+    /// Samples the total latency (nanoseconds) for an order update.
+    pub fn update_latency(&mut self) -> u64 {
+        self.sample(self.update_latency_nanos)
+    }
+
+    /// Samples the total latency (nanoseconds) for an order cancel.
+    pub fn cancel_latency(&mut self) -> u64 {
+        self.sample(self.cancel_latency_nanos)
+    }
+
+    /// Samples the total latency (nanoseconds) for the given trading `command`.
+    pub fn command_latency(&mut self, command: &TradingCommand) -> u64 {
+        match command {
+            TradingCommand::SubmitOrder(_) | TradingCommand::SubmitOrderList(_) => {
+                self.insert_latency()
+            }
+            TradingCommand::ModifyOrder(_) => self.update_latency(),
+            TradingCommand::CancelOrder(_)
+            | TradingCommand::CancelAllOrders(_)
+            | TradingCommand::BatchCancelOrders(_) => self.cancel_latency(),
+            TradingCommand::QueryOrder(_) => self.sample(0),
+        }
+    }
+
+    fn sample(&mut self, latency_nanos: u64) -> u64 {
+        let jitter = self.jitter.map_or(0, |jitter| jitter.sample(&mut self.rng));
+        self.base_latency_nanos + latency_nanos + jitter
+    }
+}
+
+impl Default for LatencyModel {
+    /// Creates a new default [`LatencyModel`] instance with zero latency.
+    fn default() -> Self {
+        Self::new(0, 0, 0, 0, None, None)
+    }
+}
 
 impl Display for LatencyModel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "LatencyModel()")
+        write!(
+            f,
+            "LatencyModel(base={}, insert={}, update={}, cancel={}, jitter={})",
+            self.base_latency_nanos,
+            self.insert_latency_nanos,
+            self.update_latency_nanos,
+            self.cancel_latency_nanos,
+            self.jitter
+                .map_or("None".to_string(), |jitter| jitter.to_string()),
+        )
+    }
+}
+
+/// Represents a distribution of market data feed delays (nanoseconds).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FeedDelay {
+    /// A constant delay.
+    Fixed(u64),
+    /// A delay drawn uniformly from `min` to `max` (inclusive).
+    Uniform { min: u64, max: u64 },
+    /// A delay drawn from a normal distribution, truncated at zero.
+    Normal { mean: f64, std: f64 },
+}
+
+impl FeedDelay {
+    fn sample(&self, rng: &mut StdRng) -> u64 {
+        match *self {
+            Self::Fixed(delay) => delay,
+            Self::Uniform { min, max } => rng.gen_range(min..=max),
+            Self::Normal { mean, std } => {
+                // Box-Muller transform
+                let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+                let u2: f64 = rng.gen();
+                let z = (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos();
+                (z.mul_add(std, mean)).max(0.0).round() as u64
+            }
+        }
+    }
+
+    fn validate(&self) {
+        match *self {
+            Self::Fixed(_) => {}
+            Self::Uniform { min, max } => {
+                check_predicate_true(min <= max, "`min` was greater than `max`").expect(FAILED);
+            }
+            Self::Normal { mean, std } => {
+                check_predicate_true(mean >= 0.0, "`mean` was negative").expect(FAILED);
+                check_predicate_true(std >= 0.0, "`std` was negative").expect(FAILED);
+            }
+        }
+    }
+}
+
+impl Display for FeedDelay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fixed(delay) => write!(f, "Fixed({delay})"),
+            Self::Uniform { min, max } => write!(f, "Uniform(min={min}, max={max})"),
+            Self::Normal { mean, std } => write!(f, "Normal(mean={mean}, std={std})"),
+        }
+    }
+}
+
+/// Provides a per-venue market data latency model for backtesting.
+///
+/// The model is separate from order latency, and simulates the delay between the time data
+/// is initialized at the venue (`ts_init`) and its delivery to the engine. Delays are
+/// sampled per data point, while delivery remains in order (FIFO) for each venue feed.
+#[derive(Debug, Clone)]
+pub struct DataLatencyModel {
+    default_delay: FeedDelay,
+    venue_delays: HashMap<Venue, FeedDelay>,
+    rng: StdRng,
+}
+
+impl DataLatencyModel {
+    /// Creates a new [`DataLatencyModel`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `default_delay` has invalid parameters.
+    #[must_use]
+    pub fn new(default_delay: FeedDelay, random_seed: Option<u64>) -> Self {
+        default_delay.validate();
+        let rng = match random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Self {
+            default_delay,
+            venue_delays: HashMap::new(),
+            rng,
+        }
+    }
+
+    /// Sets the feed delay distribution for the given `venue`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `delay` has invalid parameters.
+    pub fn set_venue_delay(&mut self, venue: Venue, delay: FeedDelay) {
+        delay.validate();
+        self.venue_delays.insert(venue, delay);
+    }
+
+    /// Returns the feed delay distribution for the given `venue`.
+    #[must_use]
+    pub fn delay_for(&self, venue: &Venue) -> FeedDelay {
+        self.venue_delays
+            .get(venue)
+            .copied()
+            .unwrap_or(self.default_delay)
+    }
+
+    /// Samples a feed delay (nanoseconds) for the given `venue`.
+    pub fn sample(&mut self, venue: &Venue) -> u64 {
+        self.delay_for(venue).sample(&mut self.rng)
+    }
+
+    /// Applies feed delays to the given `data`, returning the data with `ts_init` set to
+    /// the engine delivery time and sorted by it.
+    ///
+    /// The input is expected in `ts_init` order, delivery for each venue never precedes
+    /// the delivery of earlier data from the same venue.
+    pub fn apply(&mut self, data: Vec<Data>) -> Vec<Data> {
+        let mut last_delivery: HashMap<Venue, UnixNanos> = HashMap::new();
+
+        let mut delayed: Vec<Data> = data
+            .into_iter()
+            .map(|data| {
+                let venue = data.instrument_id().venue;
+                let delay = self.sample(&venue);
+                let last = last_delivery.entry(venue).or_default();
+                let delivery = (data.ts_init() + delay).max(*last);
+                *last = delivery;
+                with_ts_init(data, delivery)
+            })
+            .collect();
+
+        delayed.sort_by_key(GetTsInit::ts_init); // Stable
+        delayed
+    }
+}
+
+impl Display for DataLatencyModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DataLatencyModel(default_delay={}, venues={})",
+            self.default_delay,
+            self.venue_delays.len()
+        )
+    }
+}
+
+fn with_ts_init(mut data: Data, ts_init: UnixNanos) -> Data {
+    match &mut data {
+        Data::Delta(delta) => delta.ts_init = ts_init,
+        Data::Deltas(deltas) => {
+            deltas.ts_init = ts_init;
+            for delta in &mut deltas.deltas {
+                delta.ts_init = ts_init;
+            }
+        }
+        Data::Depth10(depth) => depth.ts_init = ts_init,
+        Data::Quote(quote) => quote.ts_init = ts_init,
+        Data::Trade(trade) => trade.ts_init = ts_init,
+        Data::Bar(bar) => bar.ts_init = ts_init,
+    }
+    data
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{
+        stubs::{quote_audusd, quote_ethusdt_binance},
+        QuoteTick,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote_at(mut quote: QuoteTick, ts: u64) -> Data {
+        quote.ts_event = UnixNanos::from(ts);
+        quote.ts_init = UnixNanos::from(ts);
+        Data::Quote(quote)
+    }
+
+    #[rstest]
+    fn test_fixed_delay_applied_per_venue(
+        quote_audusd: QuoteTick,
+        quote_ethusdt_binance: QuoteTick,
+    ) {
+        let mut model = DataLatencyModel::new(FeedDelay::Fixed(0), Some(42));
+        model.set_venue_delay(Venue::new("BINANCE"), FeedDelay::Fixed(50));
+
+        let data = vec![
+            quote_at(quote_ethusdt_binance, 100),
+            quote_at(quote_audusd, 120),
+        ];
+        let result = model.apply(data);
+
+        assert_eq!(result[0].instrument_id(), quote_audusd.instrument_id);
+        assert_eq!(result[0].ts_init(), UnixNanos::from(120));
+        assert_eq!(
+            result[1].instrument_id(),
+            quote_ethusdt_binance.instrument_id
+        );
+        assert_eq!(result[1].ts_init(), UnixNanos::from(150));
+    }
+
+    #[rstest]
+    fn test_delivery_is_fifo_per_venue(quote_audusd: QuoteTick) {
+        let mut model = DataLatencyModel::new(FeedDelay::Uniform { min: 0, max: 1_000 }, Some(1));
+
+        let data: Vec<Data> = (0..100).map(|i| quote_at(quote_audusd, i * 10)).collect();
+        let result = model.apply(data);
+
+        let ts_events: Vec<UnixNanos> = result
+            .iter()
+            .map(|d| match d {
+                Data::Quote(q) => q.ts_event,
+                _ => panic!("Expected quote"),
+            })
+            .collect();
+        assert!(ts_events.windows(2).all(|w| w[0] <= w[1]));
+        assert!(result
+            .iter()
+            .zip(0..)
+            .all(|(d, i)| d.ts_init() >= UnixNanos::from(i * 10)));
+    }
+
+    #[rstest]
+    fn test_sampling_is_deterministic_with_seed() {
+        let delay = FeedDelay::Normal {
+            mean: 1_000.0,
+            std: 100.0,
+        };
+        let mut model1 = DataLatencyModel::new(delay, Some(7));
+        let mut model2 = DataLatencyModel::new(delay, Some(7));
+        let venue = Venue::new("SIM");
+
+        let samples1: Vec<u64> = (0..10).map(|_| model1.sample(&venue)).collect();
+        let samples2: Vec<u64> = (0..10).map(|_| model2.sample(&venue)).collect();
+
+        assert_eq!(samples1, samples2);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`min` was greater than `max`")]
+    fn test_invalid_uniform_delay_panics() {
+        let _ = DataLatencyModel::new(FeedDelay::Uniform { min: 10, max: 5 }, None);
+    }
+
+    #[rstest]
+    fn test_latency_model_default_has_zero_latency() {
+        let mut model = LatencyModel::default();
+
+        assert_eq!(model.insert_latency(), 0);
+        assert_eq!(model.update_latency(), 0);
+        assert_eq!(model.cancel_latency(), 0);
+    }
+
+    #[rstest]
+    fn test_latency_model_adds_base_latency() {
+        let mut model = LatencyModel::new(100, 20, 30, 40, None, None);
+
+        assert_eq!(model.insert_latency(), 120);
+        assert_eq!(model.update_latency(), 130);
+        assert_eq!(model.cancel_latency(), 140);
+    }
+
+    #[rstest]
+    fn test_latency_model_jitter_within_distribution() {
+        let jitter = FeedDelay::Uniform { min: 0, max: 10 };
+        let mut model = LatencyModel::new(100, 20, 0, 0, Some(jitter), Some(42));
+
+        for _ in 0..100 {
+            let latency = model.insert_latency();
+            assert!((120..=130).contains(&latency));
+        }
     }
 }
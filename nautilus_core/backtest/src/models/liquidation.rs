@@ -0,0 +1,391 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Margin monitoring and forced liquidation for simulated margin accounts.
+
+use std::{collections::HashSet, fmt::Display};
+
+use nautilus_core::{correctness::check_in_range_inclusive_f64, nanos::UnixNanos};
+use nautilus_model::{
+    enums::OrderSide,
+    identifiers::{AccountId, InstrumentId, PositionId, StrategyId},
+    orders::base::OrderCore,
+    position::Position,
+    types::{Currency, Money, Price, Quantity},
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+/// Represents a margin call, issued when account equity approaches the maintenance margin.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarginCall {
+    pub account_id: AccountId,
+    pub equity: Money,
+    pub maintenance_margin: Money,
+    pub ts_event: UnixNanos,
+}
+
+/// Represents the forced liquidation of a position at the mark price.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Liquidation {
+    pub account_id: AccountId,
+    pub position_id: PositionId,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    /// The side of the liquidating (closing) order.
+    pub order_side: OrderSide,
+    pub quantity: Quantity,
+    pub mark_price: Price,
+    pub fee: Money,
+    pub ts_event: UnixNanos,
+}
+
+/// Represents an event generated by the [`LiquidationModel`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum MarginEvent {
+    MarginCall(MarginCall),
+    Liquidation(Liquidation),
+}
+
+/// Represents an open position with its current mark price, maintenance margin rate
+/// and leverage.
+#[derive(Clone, Copy, Debug)]
+pub struct MarkedPosition<'a> {
+    pub position: &'a Position,
+    pub mark_price: Price,
+    pub margin_maint: Decimal,
+    pub leverage: Decimal,
+}
+
+/// Provides margin monitoring for a simulated margin account.
+///
+/// For each settlement currency the account equity (total balance plus unrealized PnL at the
+/// mark price) is compared against the maintenance margin of the open positions. A margin call
+/// is issued once when the equity falls below `margin_call_ratio` times the maintenance margin,
+/// and all positions settled in that currency are liquidated at the mark price when the equity
+/// falls below the maintenance margin.
+#[derive(Debug, Clone)]
+pub struct LiquidationModel {
+    /// The fee charged on liquidation as a fraction of the liquidated notional value.
+    liquidation_fee_rate: f64,
+    /// The ratio of equity to maintenance margin below which a margin call is issued.
+    margin_call_ratio: f64,
+    /// The currencies for which a margin call is currently outstanding.
+    margin_called: HashSet<Currency>,
+}
+
+impl LiquidationModel {
+    /// Creates a new [`LiquidationModel`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - `liquidation_fee_rate` is not in the range [0, 1].
+    /// - `margin_call_ratio` is less than 1.
+    pub fn new(liquidation_fee_rate: f64, margin_call_ratio: f64) -> anyhow::Result<Self> {
+        check_in_range_inclusive_f64(liquidation_fee_rate, 0.0, 1.0, "liquidation_fee_rate")?;
+        check_in_range_inclusive_f64(margin_call_ratio, 1.0, f64::MAX, "margin_call_ratio")?;
+        Ok(Self {
+            liquidation_fee_rate,
+            margin_call_ratio,
+            margin_called: HashSet::new(),
+        })
+    }
+
+    /// Returns the maintenance margin for the given `positions` settled in `currency`.
+    ///
+    /// The margin for each position is its notional value at the mark price divided by
+    /// its leverage, multiplied by the maintenance margin rate.
+    #[must_use]
+    pub fn maintenance_margin(&self, currency: Currency, positions: &[MarkedPosition]) -> Money {
+        let margin = positions
+            .iter()
+            .filter(|p| p.position.settlement_currency == currency)
+            .map(|p| {
+                let notional = p.position.notional_value(p.mark_price).as_f64();
+                let leverage = p.leverage.to_f64().unwrap_or(1.0).max(1.0);
+                notional / leverage * p.margin_maint.to_f64().unwrap_or(0.0)
+            })
+            .sum();
+        Money::new(margin, currency)
+    }
+
+    /// Returns the account equity for `currency` given the `balance` and open `positions`.
+    #[must_use]
+    pub fn equity(&self, balance: Money, positions: &[MarkedPosition]) -> Money {
+        let unrealized_pnl: f64 = positions
+            .iter()
+            .filter(|p| p.position.settlement_currency == balance.currency)
+            .map(|p| p.position.unrealized_pnl(p.mark_price).as_f64())
+            .sum();
+        Money::new(balance.as_f64() + unrealized_pnl, balance.currency)
+    }
+
+    /// Checks the margin of the account with the given total `balances` and open `positions`,
+    /// returning any margin calls and liquidations.
+    pub fn check(
+        &mut self,
+        account_id: AccountId,
+        balances: &[Money],
+        positions: &[MarkedPosition],
+        ts_event: UnixNanos,
+    ) -> Vec<MarginEvent> {
+        let mut events = Vec::new();
+
+        for balance in balances {
+            let currency = balance.currency;
+            let maintenance_margin = self.maintenance_margin(currency, positions);
+            if maintenance_margin.as_f64() <= 0.0 {
+                self.margin_called.remove(&currency);
+                continue;
+            }
+
+            let equity = self.equity(*balance, positions);
+
+            if equity < maintenance_margin {
+                log::warn!(
+                    "Liquidating {currency} positions for {account_id}: \
+                    equity {equity} below maintenance margin {maintenance_margin}"
+                );
+                self.margin_called.remove(&currency);
+                events.extend(
+                    positions
+                        .iter()
+                        .filter(|p| p.position.settlement_currency == currency)
+                        .map(|p| MarginEvent::Liquidation(self.liquidate(account_id, p, ts_event))),
+                );
+            } else if equity.as_f64() < maintenance_margin.as_f64() * self.margin_call_ratio {
+                if self.margin_called.insert(currency) {
+                    log::warn!(
+                        "Margin call for {account_id}: \
+                        equity {equity} approaching maintenance margin {maintenance_margin}"
+                    );
+                    events.push(MarginEvent::MarginCall(MarginCall {
+                        account_id,
+                        equity,
+                        maintenance_margin,
+                        ts_event,
+                    }));
+                }
+            } else {
+                self.margin_called.remove(&currency);
+            }
+        }
+
+        events
+    }
+
+    /// Resets the model to its initial state.
+    pub fn reset(&mut self) {
+        self.margin_called.clear();
+    }
+
+    fn liquidate(
+        &self,
+        account_id: AccountId,
+        marked: &MarkedPosition,
+        ts_event: UnixNanos,
+    ) -> Liquidation {
+        let position = marked.position;
+        let notional = position.notional_value(marked.mark_price);
+        Liquidation {
+            account_id,
+            position_id: position.id,
+            strategy_id: position.strategy_id,
+            instrument_id: position.instrument_id,
+            order_side: OrderCore::closing_side(position.side),
+            quantity: position.quantity,
+            mark_price: marked.mark_price,
+            fee: Money::new(
+                notional.as_f64() * self.liquidation_fee_rate,
+                position.settlement_currency,
+            ),
+            ts_event,
+        }
+    }
+}
+
+impl Display for LiquidationModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LiquidationModel(liquidation_fee_rate: {}, margin_call_ratio: {})",
+            self.liquidation_fee_rate, self.margin_call_ratio
+        )
+    }
+}
+
+impl Default for LiquidationModel {
+    /// Creates a new default [`LiquidationModel`] instance.
+    fn default() -> Self {
+        Self::new(0.005, 1.5).unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderType,
+        identifiers::PositionId,
+        instruments::{stubs::audusd_sim, CurrencyPair, InstrumentAny},
+        orders::{stubs::TestOrderEventStubs, OrderTestBuilder},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn position(audusd_sim: CurrencyPair) -> Position {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &audusd_sim,
+            None,
+            Some(PositionId::new("P-1")),
+            Some(Price::from("1.00000")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        Position::new(&audusd_sim, fill.into())
+    }
+
+    fn check(
+        model: &mut LiquidationModel,
+        position: &Position,
+        mark_price: &str,
+    ) -> Vec<MarginEvent> {
+        let positions = [MarkedPosition {
+            position,
+            mark_price: Price::from(mark_price),
+            margin_maint: Decimal::new(3, 2),
+            leverage: Decimal::ONE,
+        }];
+        model.check(
+            AccountId::new("SIM-001"),
+            &[Money::from("5000 USD")],
+            &positions,
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    fn test_liquidation_model_param_fee_rate_error() {
+        let result = LiquidationModel::new(1.1, 1.5);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid f64 for 'liquidation_fee_rate' not in range [0, 1], was 1.1"
+        );
+    }
+
+    #[rstest]
+    fn test_liquidation_model_param_margin_call_ratio_error() {
+        assert!(LiquidationModel::new(0.005, 0.5).is_err());
+    }
+
+    #[rstest]
+    fn test_maintenance_margin_and_equity(position: Position) {
+        let model = LiquidationModel::default();
+        let positions = [MarkedPosition {
+            position: &position,
+            mark_price: Price::from("0.99000"),
+            margin_maint: Decimal::new(3, 2),
+            leverage: Decimal::ONE,
+        }];
+
+        let margin = model.maintenance_margin(Currency::USD(), &positions);
+        let equity = model.equity(Money::from("5000 USD"), &positions);
+
+        assert_eq!(margin, Money::from("2970 USD"));
+        assert_eq!(equity, Money::from("4000 USD"));
+    }
+
+    #[rstest]
+    fn test_maintenance_margin_with_leverage(position: Position) {
+        let model = LiquidationModel::default();
+        let positions = [MarkedPosition {
+            position: &position,
+            mark_price: Price::from("0.99000"),
+            margin_maint: Decimal::new(3, 2),
+            leverage: Decimal::TEN,
+        }];
+
+        let margin = model.maintenance_margin(Currency::USD(), &positions);
+
+        assert_eq!(margin, Money::from("297 USD"));
+    }
+
+    #[rstest]
+    fn test_no_events_when_sufficiently_margined(position: Position) {
+        let mut model = LiquidationModel::default();
+
+        let events = check(&mut model, &position, "1.00000");
+
+        assert!(events.is_empty());
+    }
+
+    #[rstest]
+    fn test_margin_call_issued_once(position: Position) {
+        let mut model = LiquidationModel::default();
+
+        let events1 = check(&mut model, &position, "0.98500");
+        let events2 = check(&mut model, &position, "0.98000");
+
+        assert_eq!(events1.len(), 1);
+        assert!(matches!(events1[0], MarginEvent::MarginCall(_)));
+        assert!(events2.is_empty());
+    }
+
+    #[rstest]
+    fn test_margin_call_reissued_after_recovery(position: Position) {
+        let mut model = LiquidationModel::default();
+
+        check(&mut model, &position, "0.98500");
+        check(&mut model, &position, "1.00000");
+        let events = check(&mut model, &position, "0.98500");
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], MarginEvent::MarginCall(_)));
+    }
+
+    #[rstest]
+    fn test_liquidation_at_mark_price(position: Position) {
+        let mut model = LiquidationModel::default();
+
+        let events = check(&mut model, &position, "0.97000");
+
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            MarginEvent::Liquidation(liquidation) => {
+                assert_eq!(liquidation.position_id, PositionId::new("P-1"));
+                assert_eq!(liquidation.order_side, OrderSide::Sell);
+                assert_eq!(liquidation.quantity, Quantity::from(100_000));
+                assert_eq!(liquidation.mark_price, Price::from("0.97000"));
+                assert_eq!(liquidation.fee, Money::from("485 USD"));
+            }
+            MarginEvent::MarginCall(_) => panic!("Expected liquidation"),
+        }
+    }
+}
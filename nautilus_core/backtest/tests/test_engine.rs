@@ -0,0 +1,67 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_backtest::{config::BacktestEngineConfig, engine::BacktestEngine};
+use nautilus_common::msgbus::stubs::{get_message_saving_handler, get_saved_messages};
+use nautilus_model::{
+    data::{Data, QuoteTick},
+    identifiers::{Symbol, Venue},
+    instruments::{stubs::default_fx_ccy, InstrumentAny},
+};
+use nautilus_persistence::backend::session::{DataBackendSession, QueryResultBatches};
+use nautilus_test_kit::common::get_test_data_file_path;
+use rstest::rstest;
+
+#[rstest]
+fn test_run_streams_query_result_batches() {
+    let instrument = InstrumentAny::CurrencyPair(default_fx_ccy(
+        Symbol::from("EUR/USD"),
+        Some(Venue::from("SIM")),
+    ));
+    let file_path = get_test_data_file_path("nautilus/quotes.parquet");
+    let mut session = DataBackendSession::new(1000);
+    session
+        .add_file::<QuoteTick>("expected_quotes", file_path.as_str(), None)
+        .unwrap();
+    let expected: Vec<Data> = session.get_query_result().collect();
+    session
+        .add_file::<QuoteTick>("quotes", file_path.as_str(), None)
+        .unwrap();
+
+    let mut engine = BacktestEngine::new(BacktestEngineConfig::default());
+    engine
+        .cache()
+        .borrow_mut()
+        .add_instrument(instrument.clone())
+        .unwrap();
+    let handler = get_message_saving_handler::<QuoteTick>(None);
+    {
+        let msgbus = engine.msgbus();
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_quotes_topic(instrument.id());
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+    engine.add_data_iterator(
+        "quotes",
+        QueryResultBatches::new(session.get_query_result(), 100),
+    );
+
+    engine.run(None, None).unwrap();
+
+    let quotes = get_saved_messages::<QuoteTick>(handler);
+    assert_eq!(quotes.len(), expected.len());
+    assert_eq!(engine.get_result().iterations, expected.len());
+    assert!(quotes.windows(2).all(|w| w[0].ts_init <= w[1].ts_init));
+}
@@ -35,7 +35,7 @@ use nautilus_model::{
     orderbook::OrderBook,
     orders::OrderAny,
     position::Position,
-    types::Currency,
+    types::{Currency, Money},
 };
 use ustr::Ustr;
 
@@ -60,6 +60,8 @@ pub trait CacheDatabaseAdapter {
 
     fn load_positions(&mut self) -> anyhow::Result<HashMap<PositionId, Position>>;
 
+    fn load_position_snapshots(&mut self) -> anyhow::Result<HashMap<PositionId, Vec<Position>>>;
+
     fn load_index_order_position(&self) -> anyhow::Result<HashMap<ClientOrderId, Position>>;
 
     fn load_index_order_client(&self) -> anyhow::Result<HashMap<ClientOrderId, ClientId>>;
@@ -149,7 +151,18 @@ pub trait CacheDatabaseAdapter {
 
     fn snapshot_order_state(&self, order: &OrderAny) -> anyhow::Result<()>;
 
-    fn snapshot_position_state(&self, position: &Position) -> anyhow::Result<()>;
+    fn snapshot_position(
+        &self,
+        position_id: &PositionId,
+        snapshot: &Position,
+    ) -> anyhow::Result<()>;
+
+    fn snapshot_position_state(
+        &self,
+        position: &Position,
+        ts_snapshot: UnixNanos,
+        unrealized_pnl: Option<Money>,
+    ) -> anyhow::Result<()>;
 
     fn heartbeat(&self, timestamp: UnixNanos) -> anyhow::Result<()>;
 }
@@ -31,12 +31,20 @@ use std::{
 
 use bytes::Bytes;
 use database::CacheDatabaseAdapter;
-use nautilus_core::correctness::{
-    check_key_not_in_map, check_predicate_false, check_slice_not_empty, check_valid_string, FAILED,
+use nautilus_core::{
+    correctness::{
+        check_key_not_in_map, check_predicate_false, check_slice_not_empty, check_valid_string,
+        FAILED,
+    },
+    nanos::UnixNanos,
+    uuid::UUID4,
 };
 use nautilus_model::{
     accounts::AccountAny,
-    data::{Bar, BarType, QuoteTick, TradeTick},
+    data::{
+        Bar, BarType, DataType, FundingRateUpdate, IndexPriceUpdate, MarkPriceUpdate,
+        OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick,
+    },
     enums::{AggregationSource, OmsType, OrderSide, PositionSide, PriceType, TriggerType},
     identifiers::{
         AccountId, ClientId, ClientOrderId, ComponentId, ExecAlgorithmId, InstrumentId,
@@ -46,14 +54,15 @@ use nautilus_model::{
     orderbook::OrderBook,
     orders::{OrderAny, OrderList},
     position::Position,
-    types::{Currency, Price, Quantity},
+    types::{Currency, Money, Price, Quantity},
 };
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use crate::{
-    enums::SerializationEncoding, msgbus::database::DatabaseConfig, xrate::get_exchange_rate,
+    custom::CustomData, enums::SerializationEncoding, msgbus::database::DatabaseConfig,
+    xrate::get_exchange_rate,
 };
 
 /// Configuration for `Cache` instances.
@@ -80,6 +89,8 @@ pub struct CacheConfig {
     pub tick_capacity: usize,
     /// The maximum length for internal bar deques.
     pub bar_capacity: usize,
+    /// The maximum length for internal custom data deques (per data type).
+    pub custom_data_capacity: usize,
     /// If market data should be persisted to disk.
     pub save_market_data: bool,
 }
@@ -98,6 +109,7 @@ impl Default for CacheConfig {
             drop_instruments_on_reset: true,
             tick_capacity: 10_000,
             bar_capacity: 10_000,
+            custom_data_capacity: 10_000,
             save_market_data: false,
         }
     }
@@ -118,6 +130,7 @@ impl CacheConfig {
         drop_instruments_on_reset: bool,
         tick_capacity: usize,
         bar_capacity: usize,
+        custom_data_capacity: usize,
         save_market_data: bool,
     ) -> Self {
         Self {
@@ -131,6 +144,7 @@ impl CacheConfig {
             drop_instruments_on_reset,
             tick_capacity,
             bar_capacity,
+            custom_data_capacity,
             save_market_data,
         }
     }
@@ -143,6 +157,7 @@ pub struct CacheIndex {
     venue_positions: HashMap<Venue, HashSet<PositionId>>,
     venue_order_ids: HashMap<VenueOrderId, ClientOrderId>,
     client_order_ids: HashMap<ClientOrderId, VenueOrderId>,
+    venue_position_ids: HashMap<PositionId, PositionId>,
     order_position: HashMap<ClientOrderId, PositionId>,
     order_strategy: HashMap<ClientOrderId, StrategyId>,
     order_client: HashMap<ClientOrderId, ClientId>,
@@ -176,6 +191,7 @@ impl CacheIndex {
         self.venue_positions.clear();
         self.venue_order_ids.clear();
         self.client_order_ids.clear();
+        self.venue_position_ids.clear();
         self.order_position.clear();
         self.order_strategy.clear();
         self.order_client.clear();
@@ -211,7 +227,12 @@ pub struct Cache {
     quotes: HashMap<InstrumentId, VecDeque<QuoteTick>>,
     trades: HashMap<InstrumentId, VecDeque<TradeTick>>,
     books: HashMap<InstrumentId, OrderBook>,
+    depth10s: HashMap<InstrumentId, VecDeque<OrderBookDepth10>>,
+    funding_rates: HashMap<InstrumentId, VecDeque<FundingRateUpdate>>,
+    mark_prices: HashMap<InstrumentId, VecDeque<MarkPriceUpdate>>,
+    index_prices: HashMap<InstrumentId, VecDeque<IndexPriceUpdate>>,
     bars: HashMap<BarType, VecDeque<Bar>>,
+    custom_data: HashMap<DataType, VecDeque<CustomData>>,
     currencies: HashMap<Ustr, Currency>,
     instruments: HashMap<InstrumentId, InstrumentAny>,
     synthetics: HashMap<InstrumentId, SyntheticInstrument>,
@@ -219,7 +240,7 @@ pub struct Cache {
     orders: HashMap<ClientOrderId, OrderAny>,
     order_lists: HashMap<OrderListId, OrderList>,
     pub positions: HashMap<PositionId, Position>,
-    position_snapshots: HashMap<PositionId, Bytes>,
+    position_snapshots: HashMap<PositionId, Vec<Position>>,
 }
 
 // SAFETY: Cache is not meant to be passed between threads
@@ -246,6 +267,7 @@ impl Cache {
             venue_positions: HashMap::new(),
             venue_order_ids: HashMap::new(),
             client_order_ids: HashMap::new(),
+            venue_position_ids: HashMap::new(),
             order_position: HashMap::new(),
             order_strategy: HashMap::new(),
             order_client: HashMap::new(),
@@ -279,7 +301,12 @@ impl Cache {
             quotes: HashMap::new(),
             trades: HashMap::new(),
             books: HashMap::new(),
+            depth10s: HashMap::new(),
+            funding_rates: HashMap::new(),
+            mark_prices: HashMap::new(),
+            index_prices: HashMap::new(),
             bars: HashMap::new(),
+            custom_data: HashMap::new(),
             currencies: HashMap::new(),
             instruments: HashMap::new(),
             synthetics: HashMap::new(),
@@ -375,11 +402,12 @@ impl Cache {
         Ok(())
     }
 
-    /// Clears the current positions cache and loads positions from the cache database.
+    /// Clears the current positions cache and loads positions (and their snapshots) from the
+    /// cache database.
     pub fn cache_positions(&mut self) -> anyhow::Result<()> {
-        self.positions = match &mut self.database {
-            Some(db) => db.load_positions()?,
-            None => HashMap::new(),
+        (self.positions, self.position_snapshots) = match &mut self.database {
+            Some(db) => (db.load_positions()?, db.load_position_snapshots()?),
+            None => (HashMap::new(), HashMap::new()),
         };
 
         log::info!("Cached {} positions from database", self.general.len());
@@ -929,7 +957,12 @@ impl Cache {
         self.quotes.clear();
         self.trades.clear();
         self.books.clear();
+        self.depth10s.clear();
+        self.funding_rates.clear();
+        self.mark_prices.clear();
+        self.index_prices.clear();
         self.bars.clear();
+        self.custom_data.clear();
         self.currencies.clear();
         self.instruments.clear();
         self.synthetics.clear();
@@ -989,6 +1022,41 @@ impl Cache {
         Ok(())
     }
 
+    /// Applies the given order book `delta` to the cached order book for its instrument.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no order book exists for the deltas instrument.
+    pub fn update_order_book(&mut self, delta: &OrderBookDelta) -> anyhow::Result<()> {
+        let book = self.books.get_mut(&delta.instrument_id).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Cannot update order book: no book for {}",
+                delta.instrument_id
+            )
+        })?;
+        book.apply_delta(delta);
+        Ok(())
+    }
+
+    /// Applies the given order book `deltas` to the cached order book for their instrument.
+    ///
+    /// For L3 (MBO) books this maintains the exact order-level state, allowing order-level
+    /// queries such as [`OrderBook::queue_position`] against the cached book.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no order book exists for the deltas instrument.
+    pub fn update_order_book_deltas(&mut self, deltas: &OrderBookDeltas) -> anyhow::Result<()> {
+        let book = self.books.get_mut(&deltas.instrument_id).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Cannot update order book: no book for {}",
+                deltas.instrument_id
+            )
+        })?;
+        book.apply_deltas(deltas);
+        Ok(())
+    }
+
     /// Adds the given `quote` tick to the cache.
     pub fn add_quote(&mut self, quote: QuoteTick) -> anyhow::Result<()> {
         log::debug!("Adding `QuoteTick` {}", quote.instrument_id);
@@ -1077,6 +1145,70 @@ impl Cache {
         Ok(())
     }
 
+    /// Adds the given order book `depth` snapshot to the cache.
+    ///
+    /// Only the most recent `tick_capacity` snapshots are retained per instrument.
+    pub fn add_depth10(&mut self, depth: OrderBookDepth10) -> anyhow::Result<()> {
+        log::debug!("Adding `OrderBookDepth10` {}", depth.instrument_id);
+
+        let capacity = self.config.tick_capacity;
+        let depth_deque = self
+            .depth10s
+            .entry(depth.instrument_id)
+            .or_insert_with(|| VecDeque::with_capacity(capacity));
+        depth_deque.push_front(depth);
+        depth_deque.truncate(capacity);
+        Ok(())
+    }
+
+    /// Adds the given funding rate `update` to the cache.
+    ///
+    /// Only the most recent `tick_capacity` updates are retained per instrument.
+    pub fn add_funding_rate(&mut self, update: FundingRateUpdate) -> anyhow::Result<()> {
+        log::debug!("Adding `FundingRateUpdate` {}", update.instrument_id);
+
+        let capacity = self.config.tick_capacity;
+        let funding_deque = self
+            .funding_rates
+            .entry(update.instrument_id)
+            .or_insert_with(|| VecDeque::with_capacity(capacity));
+        funding_deque.push_front(update);
+        funding_deque.truncate(capacity);
+        Ok(())
+    }
+
+    /// Adds the given mark price `update` to the cache.
+    ///
+    /// Only the most recent `tick_capacity` updates are retained per instrument.
+    pub fn add_mark_price(&mut self, update: MarkPriceUpdate) -> anyhow::Result<()> {
+        log::debug!("Adding `MarkPriceUpdate` {}", update.instrument_id);
+
+        let capacity = self.config.tick_capacity;
+        let mark_deque = self
+            .mark_prices
+            .entry(update.instrument_id)
+            .or_insert_with(|| VecDeque::with_capacity(capacity));
+        mark_deque.push_front(update);
+        mark_deque.truncate(capacity);
+        Ok(())
+    }
+
+    /// Adds the given index price `update` to the cache.
+    ///
+    /// Only the most recent `tick_capacity` updates are retained per instrument.
+    pub fn add_index_price(&mut self, update: IndexPriceUpdate) -> anyhow::Result<()> {
+        log::debug!("Adding `IndexPriceUpdate` {}", update.instrument_id);
+
+        let capacity = self.config.tick_capacity;
+        let index_deque = self
+            .index_prices
+            .entry(update.instrument_id)
+            .or_insert_with(|| VecDeque::with_capacity(capacity));
+        index_deque.push_front(update);
+        index_deque.truncate(capacity);
+        Ok(())
+    }
+
     /// Adds the given `bar` to the cache.
     pub fn add_bar(&mut self, bar: Bar) -> anyhow::Result<()> {
         log::debug!("Adding `Bar` {}", bar.bar_type);
@@ -1121,6 +1253,28 @@ impl Cache {
         Ok(())
     }
 
+    /// Adds the given custom `data` to the cache.
+    ///
+    /// Only the most recent `custom_data_capacity` items are retained per data type.
+    pub fn add_custom_data(&mut self, data: CustomData) -> anyhow::Result<()> {
+        log::debug!("Adding `CustomData` {}", data.data_type);
+
+        if self.config.save_market_data {
+            if let Some(database) = &mut self.database {
+                database.add_custom_data(&data)?;
+            }
+        }
+
+        let capacity = self.config.custom_data_capacity;
+        let data_deque = self
+            .custom_data
+            .entry(data.data_type.clone())
+            .or_insert_with(|| VecDeque::with_capacity(capacity));
+        data_deque.push_front(data);
+        data_deque.truncate(capacity);
+        Ok(())
+    }
+
     /// Adds the given `currency` to the cache.
     pub fn add_currency(&mut self, currency: Currency) -> anyhow::Result<()> {
         log::debug!("Adding `Currency` {}", currency.code);
@@ -1308,10 +1462,10 @@ impl Cache {
 
         // Update emulation index
         match order.emulation_trigger() {
-            Some(_) => {
+            Some(TriggerType::NoTrigger) | None => {
                 self.index.orders_emulated.remove(&client_order_id);
             }
-            None => {
+            Some(_) => {
                 self.index.orders_emulated.insert(client_order_id);
             }
         }
@@ -1346,7 +1500,7 @@ impl Cache {
     }
 
     /// Indexes the given `position_id` with the other given IDs.
-    fn add_position_id(
+    pub fn add_position_id(
         &mut self,
         position_id: &PositionId,
         venue: &Venue,
@@ -1432,6 +1586,8 @@ impl Cache {
         if let Some(database) = &mut self.database {
             database.update_account(&account)?;
         }
+
+        self.accounts.insert(account.id(), account);
         Ok(())
     }
 
@@ -1466,13 +1622,13 @@ impl Cache {
             self.index.orders_closed.insert(client_order_id);
         }
 
-        // Update emulation
-        if let Some(emulation_trigger) = order.emulation_trigger() {
-            match emulation_trigger {
-                TriggerType::NoTrigger => self.index.orders_emulated.remove(&client_order_id),
-                _ => self.index.orders_emulated.insert(client_order_id),
-            };
-        }
+        // Update emulation (the trigger is cleared when an emulated order is released)
+        match order.emulation_trigger() {
+            Some(TriggerType::NoTrigger) | None => {
+                self.index.orders_emulated.remove(&client_order_id)
+            }
+            Some(_) => self.index.orders_emulated.insert(client_order_id),
+        };
 
         if let Some(database) = &mut self.database {
             database.update_order(order.last_event())?;
@@ -1482,9 +1638,64 @@ impl Cache {
             // }
         }
 
+        self.orders.insert(client_order_id, order.clone());
+
         Ok(())
     }
 
+    /// Updates the given `order` after it has been routed to a different venue, moving it to
+    /// its new venue and instrument ID in the cache indexes, along with the routed `client_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order does not exist in the cache, or if updating it fails.
+    pub fn update_order_routing(
+        &mut self,
+        order: &OrderAny,
+        client_id: Option<ClientId>,
+    ) -> anyhow::Result<()> {
+        let client_order_id = order.client_order_id();
+        let Some(previous) = self.orders.get(&client_order_id) else {
+            anyhow::bail!("Order {client_order_id} not found in cache");
+        };
+
+        let previous_instrument_id = previous.instrument_id();
+        let instrument_id = order.instrument_id();
+        if previous_instrument_id != instrument_id {
+            if let Some(client_order_ids) = self
+                .index
+                .venue_orders
+                .get_mut(&previous_instrument_id.venue)
+            {
+                client_order_ids.remove(&client_order_id);
+            }
+            if let Some(client_order_ids) = self
+                .index
+                .instrument_orders
+                .get_mut(&previous_instrument_id)
+            {
+                client_order_ids.remove(&client_order_id);
+            }
+
+            self.index
+                .venue_orders
+                .entry(instrument_id.venue)
+                .or_default()
+                .insert(client_order_id);
+            self.index
+                .instrument_orders
+                .entry(instrument_id)
+                .or_default()
+                .insert(client_order_id);
+        }
+
+        if let Some(client_id) = client_id {
+            self.index.order_client.insert(client_order_id, client_id);
+        }
+
+        self.update_order(order)
+    }
+
     /// Updates the given `order` as pending cancel locally.
     pub fn update_order_pending_cancel_local(&mut self, order: &OrderAny) {
         self.index
@@ -1510,6 +1721,87 @@ impl Cache {
             //     database.snapshot_order_state(order)?;
             // }
         }
+
+        self.positions.insert(position.id, position.clone());
+
+        Ok(())
+    }
+
+    /// Snapshots the given `position` state at `ts_snapshot`.
+    ///
+    /// The snapshot is a copy of the position assigned a unique ID of the form
+    /// `{position_id}-{uuid}`, retained against the original position ID. With a NETTING OMS a
+    /// closed position is reopened under the same position ID, so the snapshots retain the
+    /// history (and realized PnL) of each previous position cycle.
+    ///
+    /// If a database is configured the snapshot is also persisted through the adapter.
+    pub fn snapshot_position(
+        &mut self,
+        position: &Position,
+        ts_snapshot: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let mut copied_position = position.clone();
+        copied_position.id = PositionId::new(format!("{}-{}", position.id, UUID4::new()));
+
+        if let Some(database) = &self.database {
+            database.snapshot_position(&position.id, &copied_position)?;
+            database.snapshot_position_state(&copied_position, ts_snapshot, None)?;
+        }
+
+        log::debug!("Snapshot {copied_position}");
+        self.position_snapshots
+            .entry(position.id)
+            .or_default()
+            .push(copied_position);
+        Ok(())
+    }
+
+    /// Persists the current state of the given `position` at `ts_snapshot` through the
+    /// database adapter (if configured).
+    ///
+    /// If `open_only` is true then the state is only persisted while the position is open.
+    pub fn snapshot_position_state(
+        &self,
+        position: &Position,
+        ts_snapshot: UnixNanos,
+        unrealized_pnl: Option<Money>,
+        open_only: bool,
+    ) -> anyhow::Result<()> {
+        if open_only && !position.is_open() {
+            return Ok(());
+        }
+
+        if let Some(database) = &self.database {
+            database.snapshot_position_state(position, ts_snapshot, unrealized_pnl)?;
+        } else {
+            log::debug!("Cannot snapshot {position} state: no database configured");
+        }
+        Ok(())
+    }
+
+    /// Indexes the given `venue_position_id` as an alias of the given `position_id`.
+    ///
+    /// Used with a HEDGING OMS where the venue assigns its own position IDs, so that
+    /// subsequent fills carrying the venue position ID resolve to the same position.
+    pub fn add_venue_position_id(
+        &mut self,
+        position_id: &PositionId,
+        venue_position_id: &PositionId,
+        overwrite: bool,
+    ) -> anyhow::Result<()> {
+        if let Some(existing) = self.index.venue_position_ids.get(venue_position_id) {
+            if !overwrite && existing != position_id {
+                anyhow::bail!(
+                    "Existing {existing} for venue {venue_position_id} did not match the given {position_id}"
+                );
+            }
+        }
+
+        self.index
+            .venue_position_ids
+            .insert(*venue_position_id, *position_id);
+
+        log::debug!("Indexed venue {venue_position_id} for {position_id}");
         Ok(())
     }
 
@@ -2213,6 +2505,36 @@ impl Cache {
         self.index.order_position.get(client_order_id)
     }
 
+    /// Returns a reference to the position ID indexed for the given `venue_position_id` (if found).
+    #[must_use]
+    pub fn position_id_for_venue(&self, venue_position_id: &PositionId) -> Option<&PositionId> {
+        self.index.venue_position_ids.get(venue_position_id)
+    }
+
+    /// Returns the snapshots taken for the given `position_id`, or for all positions if `None`.
+    ///
+    /// Snapshots for each position are returned in the order they were taken.
+    pub fn position_snapshots(
+        &self,
+        position_id: Option<&PositionId>,
+    ) -> anyhow::Result<Vec<Position>> {
+        let snapshots = match position_id {
+            Some(position_id) => self
+                .position_snapshots
+                .get(position_id)
+                .cloned()
+                .unwrap_or_default(),
+            None => self
+                .position_snapshots
+                .values()
+                .flatten()
+                .cloned()
+                .collect(),
+        };
+
+        Ok(snapshots)
+    }
+
     /// Returns a reference to all positions matching the given optional filter parameters.
     #[must_use]
     pub fn positions(
@@ -2385,6 +2707,46 @@ impl Cache {
             .map(|bars| bars.iter().copied().collect())
     }
 
+    /// Gets all order book depth snapshots for the given `instrument_id`.
+    #[must_use]
+    pub fn depth10s(&self, instrument_id: &InstrumentId) -> Option<Vec<OrderBookDepth10>> {
+        self.depth10s
+            .get(instrument_id)
+            .map(|depths| depths.iter().copied().collect())
+    }
+
+    /// Gets all funding rate updates for the given `instrument_id` (most recent first).
+    #[must_use]
+    pub fn funding_rates(&self, instrument_id: &InstrumentId) -> Option<Vec<FundingRateUpdate>> {
+        self.funding_rates
+            .get(instrument_id)
+            .map(|updates| updates.iter().copied().collect())
+    }
+
+    /// Gets all mark price updates for the given `instrument_id` (most recent first).
+    #[must_use]
+    pub fn mark_prices(&self, instrument_id: &InstrumentId) -> Option<Vec<MarkPriceUpdate>> {
+        self.mark_prices
+            .get(instrument_id)
+            .map(|updates| updates.iter().copied().collect())
+    }
+
+    /// Gets all index price updates for the given `instrument_id` (most recent first).
+    #[must_use]
+    pub fn index_prices(&self, instrument_id: &InstrumentId) -> Option<Vec<IndexPriceUpdate>> {
+        self.index_prices
+            .get(instrument_id)
+            .map(|updates| updates.iter().copied().collect())
+    }
+
+    /// Gets all custom data for the given `data_type`.
+    #[must_use]
+    pub fn custom_data(&self, data_type: &DataType) -> Option<Vec<CustomData>> {
+        self.custom_data
+            .get(data_type)
+            .map(|data| data.iter().cloned().collect())
+    }
+
     /// Gets a reference to the order book for the given `instrument_id`.
     #[must_use]
     pub fn order_book(&self, instrument_id: &InstrumentId) -> Option<&OrderBook> {
@@ -2419,6 +2781,46 @@ impl Cache {
         self.bars.get(bar_type).and_then(|bars| bars.front())
     }
 
+    /// Gets a reference to the latest order book depth snapshot for the given `instrument_id`.
+    #[must_use]
+    pub fn depth10(&self, instrument_id: &InstrumentId) -> Option<&OrderBookDepth10> {
+        self.depth10s
+            .get(instrument_id)
+            .and_then(|depths| depths.front())
+    }
+
+    /// Gets a reference to the latest funding rate update for the given `instrument_id`.
+    #[must_use]
+    pub fn funding_rate(&self, instrument_id: &InstrumentId) -> Option<&FundingRateUpdate> {
+        self.funding_rates
+            .get(instrument_id)
+            .and_then(|updates| updates.front())
+    }
+
+    /// Gets a reference to the latest mark price update for the given `instrument_id`.
+    #[must_use]
+    pub fn mark_price(&self, instrument_id: &InstrumentId) -> Option<&MarkPriceUpdate> {
+        self.mark_prices
+            .get(instrument_id)
+            .and_then(|updates| updates.front())
+    }
+
+    /// Gets a reference to the latest index price update for the given `instrument_id`.
+    #[must_use]
+    pub fn index_price(&self, instrument_id: &InstrumentId) -> Option<&IndexPriceUpdate> {
+        self.index_prices
+            .get(instrument_id)
+            .and_then(|updates| updates.front())
+    }
+
+    /// Gets a reference to the latest custom data for the given `data_type`.
+    #[must_use]
+    pub fn custom_data_latest(&self, data_type: &DataType) -> Option<&CustomData> {
+        self.custom_data
+            .get(data_type)
+            .and_then(|data| data.front())
+    }
+
     /// Gets the order book update count for the given `instrument_id`.
     #[must_use]
     pub fn book_update_count(&self, instrument_id: &InstrumentId) -> usize {
@@ -2449,6 +2851,46 @@ impl Cache {
             .map_or(0, std::collections::VecDeque::len)
     }
 
+    /// Gets the order book depth snapshot count for the given `instrument_id`.
+    #[must_use]
+    pub fn depth10_count(&self, instrument_id: &InstrumentId) -> usize {
+        self.depth10s
+            .get(instrument_id)
+            .map_or(0, std::collections::VecDeque::len)
+    }
+
+    /// Gets the funding rate update count for the given `instrument_id`.
+    #[must_use]
+    pub fn funding_rate_count(&self, instrument_id: &InstrumentId) -> usize {
+        self.funding_rates
+            .get(instrument_id)
+            .map_or(0, std::collections::VecDeque::len)
+    }
+
+    /// Gets the mark price update count for the given `instrument_id`.
+    #[must_use]
+    pub fn mark_price_count(&self, instrument_id: &InstrumentId) -> usize {
+        self.mark_prices
+            .get(instrument_id)
+            .map_or(0, std::collections::VecDeque::len)
+    }
+
+    /// Gets the index price update count for the given `instrument_id`.
+    #[must_use]
+    pub fn index_price_count(&self, instrument_id: &InstrumentId) -> usize {
+        self.index_prices
+            .get(instrument_id)
+            .map_or(0, std::collections::VecDeque::len)
+    }
+
+    /// Gets the custom data count for the given `data_type`.
+    #[must_use]
+    pub fn custom_data_count(&self, data_type: &DataType) -> usize {
+        self.custom_data
+            .get(data_type)
+            .map_or(0, std::collections::VecDeque::len)
+    }
+
     /// Returns whether the cache contains an order book for the given `instrument_id`.
     #[must_use]
     pub fn has_order_book(&self, instrument_id: &InstrumentId) -> bool {
@@ -2467,6 +2909,36 @@ impl Cache {
         self.trade_count(instrument_id) > 0
     }
 
+    /// Returns whether the cache contains order book depth snapshots for the given `instrument_id`.
+    #[must_use]
+    pub fn has_depth10s(&self, instrument_id: &InstrumentId) -> bool {
+        self.depth10_count(instrument_id) > 0
+    }
+
+    /// Returns whether the cache contains funding rate updates for the given `instrument_id`.
+    #[must_use]
+    pub fn has_funding_rates(&self, instrument_id: &InstrumentId) -> bool {
+        self.funding_rate_count(instrument_id) > 0
+    }
+
+    /// Returns whether the cache contains mark price updates for the given `instrument_id`.
+    #[must_use]
+    pub fn has_mark_prices(&self, instrument_id: &InstrumentId) -> bool {
+        self.mark_price_count(instrument_id) > 0
+    }
+
+    /// Returns whether the cache contains index price updates for the given `instrument_id`.
+    #[must_use]
+    pub fn has_index_prices(&self, instrument_id: &InstrumentId) -> bool {
+        self.index_price_count(instrument_id) > 0
+    }
+
+    /// Returns whether the cache contains custom data for the given `data_type`.
+    #[must_use]
+    pub fn has_custom_data(&self, data_type: &DataType) -> bool {
+        self.custom_data_count(data_type) > 0
+    }
+
     /// Returns whether the cache contains bars for the given `bar_type`.
     #[must_use]
     pub fn has_bars(&self, bar_type: &BarType) -> bool {
@@ -18,10 +18,15 @@
 use bytes::Bytes;
 use nautilus_model::{
     accounts::AccountAny,
-    data::{Bar, QuoteTick, TradeTick},
-    enums::{BookType, OmsType, OrderSide, OrderStatus, OrderType},
+    data::{
+        stubs::{
+            stub_depth10, stub_funding_rate_update, stub_index_price_update, stub_mark_price_update,
+        },
+        Bar, BookOrder, DataType, OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick,
+    },
+    enums::{BookAction, BookType, OmsType, OrderSide, OrderStatus, OrderType},
     events::{OrderAccepted, OrderEventAny, OrderRejected, OrderSubmitted},
-    identifiers::{AccountId, ClientOrderId, PositionId, Venue},
+    identifiers::{AccountId, ClientId, ClientOrderId, InstrumentId, PositionId, Venue},
     instruments::{stubs::*, CurrencyPair, InstrumentAny, SyntheticInstrument},
     orderbook::OrderBook,
     orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
@@ -30,7 +35,8 @@ use nautilus_model::{
 };
 use rstest::{fixture, rstest};
 
-use super::Cache;
+use super::{Cache, CacheConfig};
+use crate::custom::CustomData;
 
 #[fixture]
 fn cache() -> Cache {
@@ -164,6 +170,51 @@ fn test_order_when_submitted(mut cache: Cache, audusd_sim: CurrencyPair) {
     assert_eq!(cache.venue_order_id(&order.client_order_id()), None);
 }
 
+#[rstest]
+fn test_update_order_routing_moves_order_indexes(mut cache: Cache, audusd_sim: CurrencyPair) {
+    let mut order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(audusd_sim.id)
+        .side(OrderSide::Buy)
+        .price(Price::from("1.00000"))
+        .quantity(Quantity::from(100_000))
+        .build();
+    cache.add_order(order.clone(), None, None, false).unwrap();
+
+    let venue = Venue::from("OTHER");
+    let instrument_id = InstrumentId::new(audusd_sim.id.symbol, venue);
+    let client_id = ClientId::from("OTHER");
+    order.set_instrument_id(instrument_id);
+    cache.update_order_routing(&order, Some(client_id)).unwrap();
+
+    assert_eq!(cache.order(&order.client_order_id()), Some(&order));
+    assert_eq!(cache.client_id(&order.client_order_id()), Some(&client_id));
+    assert_eq!(cache.orders(Some(&venue), None, None, None), vec![&order]);
+    assert_eq!(
+        cache.orders(None, Some(&instrument_id), None, None),
+        vec![&order]
+    );
+    assert!(cache
+        .orders(Some(&audusd_sim.id.venue), None, None, None)
+        .is_empty());
+    assert!(cache
+        .orders(None, Some(&audusd_sim.id), None, None)
+        .is_empty());
+}
+
+#[rstest]
+fn test_update_order_routing_when_order_not_cached_returns_error(
+    mut cache: Cache,
+    audusd_sim: CurrencyPair,
+) {
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(audusd_sim.id)
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(100_000))
+        .build();
+
+    assert!(cache.update_order_routing(&order, None).is_err());
+}
+
 #[rstest]
 fn test_order_when_rejected(mut cache: Cache, audusd_sim: CurrencyPair) {
     let mut order = OrderTestBuilder::new(OrderType::Market)
@@ -502,6 +553,87 @@ fn test_order_book_mut_when_some(mut cache: Cache, audusd_sim: CurrencyPair) {
     assert_eq!(result, Some(&mut book));
 }
 
+#[rstest]
+fn test_update_order_book_when_no_book_returns_error(mut cache: Cache, audusd_sim: CurrencyPair) {
+    let order = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("1.00000"),
+        Quantity::from(100_000),
+        1,
+    );
+    let delta = OrderBookDelta::new(
+        audusd_sim.id,
+        BookAction::Add,
+        order,
+        0,
+        1,
+        1.into(),
+        1.into(),
+    );
+
+    let result = cache.update_order_book(&delta);
+
+    assert!(result.is_err());
+}
+
+#[rstest]
+fn test_update_order_book_deltas_maintains_l3_book(mut cache: Cache, audusd_sim: CurrencyPair) {
+    let book = OrderBook::new(audusd_sim.id, BookType::L3_MBO);
+    cache.add_order_book(book).unwrap();
+
+    let order1 = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("1.00000"),
+        Quantity::from(100_000),
+        1,
+    );
+    let order2 = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("1.00000"),
+        Quantity::from(200_000),
+        2,
+    );
+    let deltas = OrderBookDeltas::new(
+        audusd_sim.id,
+        vec![
+            OrderBookDelta::new(
+                audusd_sim.id,
+                BookAction::Add,
+                order1,
+                0,
+                1,
+                1.into(),
+                1.into(),
+            ),
+            OrderBookDelta::new(
+                audusd_sim.id,
+                BookAction::Add,
+                order2,
+                0,
+                2,
+                2.into(),
+                2.into(),
+            ),
+            OrderBookDelta::new(
+                audusd_sim.id,
+                BookAction::Delete,
+                order1,
+                0,
+                3,
+                3.into(),
+                3.into(),
+            ),
+        ],
+    );
+
+    cache.update_order_book_deltas(&deltas).unwrap();
+
+    let book = cache.order_book(&audusd_sim.id).unwrap();
+    assert!(book.get_order(1).is_none());
+    assert_eq!(book.queue_position(2), Some(0));
+    assert_eq!(cache.book_update_count(&audusd_sim.id), 3);
+}
+
 #[rstest]
 fn test_quote_tick_when_empty(cache: Cache, audusd_sim: CurrencyPair) {
     let result = cache.quote(&audusd_sim.id);
@@ -534,6 +666,128 @@ fn test_quote_ticks_when_some(mut cache: Cache) {
     assert_eq!(result, Some(quotes));
 }
 
+#[rstest]
+fn test_depth10_when_empty(cache: Cache, audusd_sim: CurrencyPair) {
+    assert!(cache.depth10(&audusd_sim.id).is_none());
+    assert!(cache.depth10s(&audusd_sim.id).is_none());
+    assert_eq!(cache.depth10_count(&audusd_sim.id), 0);
+    assert!(!cache.has_depth10s(&audusd_sim.id));
+}
+
+#[rstest]
+fn test_depth10_when_some(mut cache: Cache) {
+    let depth1 = stub_depth10();
+    let mut depth2 = stub_depth10();
+    depth2.ts_event = depth1.ts_event + 1;
+    cache.add_depth10(depth1).unwrap();
+    cache.add_depth10(depth2).unwrap();
+
+    let instrument_id = depth1.instrument_id;
+    assert_eq!(cache.depth10(&instrument_id), Some(&depth2));
+    assert_eq!(cache.depth10s(&instrument_id), Some(vec![depth2, depth1]));
+    assert_eq!(cache.depth10_count(&instrument_id), 2);
+    assert!(cache.has_depth10s(&instrument_id));
+}
+
+#[rstest]
+fn test_funding_rate_when_empty(cache: Cache) {
+    let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+    assert!(cache.funding_rate(&instrument_id).is_none());
+    assert!(cache.funding_rates(&instrument_id).is_none());
+    assert_eq!(cache.funding_rate_count(&instrument_id), 0);
+    assert!(!cache.has_funding_rates(&instrument_id));
+}
+
+#[rstest]
+fn test_funding_rate_when_some(mut cache: Cache) {
+    let update1 = stub_funding_rate_update();
+    let mut update2 = stub_funding_rate_update();
+    update2.ts_event = update1.ts_event + 1;
+    cache.add_funding_rate(update1).unwrap();
+    cache.add_funding_rate(update2).unwrap();
+
+    let instrument_id = update1.instrument_id;
+    assert_eq!(cache.funding_rate(&instrument_id), Some(&update2));
+    assert_eq!(
+        cache.funding_rates(&instrument_id),
+        Some(vec![update2, update1])
+    );
+    assert_eq!(cache.funding_rate_count(&instrument_id), 2);
+    assert!(cache.has_funding_rates(&instrument_id));
+}
+
+#[rstest]
+fn test_mark_price_when_empty(cache: Cache) {
+    let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+    assert!(cache.mark_price(&instrument_id).is_none());
+    assert!(cache.mark_prices(&instrument_id).is_none());
+    assert_eq!(cache.mark_price_count(&instrument_id), 0);
+    assert!(!cache.has_mark_prices(&instrument_id));
+}
+
+#[rstest]
+fn test_mark_price_when_some(mut cache: Cache) {
+    let update1 = stub_mark_price_update();
+    let mut update2 = stub_mark_price_update();
+    update2.value = Price::from("100001.00");
+    cache.add_mark_price(update1).unwrap();
+    cache.add_mark_price(update2).unwrap();
+
+    let instrument_id = update1.instrument_id;
+    assert_eq!(cache.mark_price(&instrument_id), Some(&update2));
+    assert_eq!(
+        cache.mark_prices(&instrument_id),
+        Some(vec![update2, update1])
+    );
+    assert_eq!(cache.mark_price_count(&instrument_id), 2);
+    assert!(cache.has_mark_prices(&instrument_id));
+}
+
+#[rstest]
+fn test_index_price_when_some(mut cache: Cache) {
+    let update = stub_index_price_update();
+    cache.add_index_price(update).unwrap();
+
+    let instrument_id = update.instrument_id;
+    assert_eq!(cache.index_price(&instrument_id), Some(&update));
+    assert_eq!(cache.index_prices(&instrument_id), Some(vec![update]));
+    assert_eq!(cache.index_price_count(&instrument_id), 1);
+    assert!(cache.has_index_prices(&instrument_id));
+    assert!(!cache.has_mark_prices(&instrument_id));
+}
+
+#[rstest]
+fn test_custom_data_when_empty(cache: Cache) {
+    let data_type = DataType::new("NewsEvent", None);
+    assert!(cache.custom_data_latest(&data_type).is_none());
+    assert!(cache.custom_data(&data_type).is_none());
+    assert_eq!(cache.custom_data_count(&data_type), 0);
+    assert!(!cache.has_custom_data(&data_type));
+}
+
+#[rstest]
+fn test_custom_data_when_some() {
+    let config = CacheConfig {
+        custom_data_capacity: 2,
+        ..Default::default()
+    };
+    let mut cache = Cache::new(Some(config), None);
+    let data_type = DataType::new("NewsEvent", None);
+    let other_type = DataType::new("Signal", None);
+    let data1 = CustomData::new(data_type.clone(), Bytes::from("a"), 1.into(), 1.into());
+    let data2 = CustomData::new(data_type.clone(), Bytes::from("b"), 2.into(), 2.into());
+    let data3 = CustomData::new(data_type.clone(), Bytes::from("c"), 3.into(), 3.into());
+    cache.add_custom_data(data1).unwrap();
+    cache.add_custom_data(data2.clone()).unwrap();
+    cache.add_custom_data(data3.clone()).unwrap();
+
+    assert_eq!(cache.custom_data_latest(&data_type), Some(&data3));
+    assert_eq!(cache.custom_data(&data_type), Some(vec![data3, data2]));
+    assert_eq!(cache.custom_data_count(&data_type), 2);
+    assert!(cache.has_custom_data(&data_type));
+    assert!(!cache.has_custom_data(&other_type));
+}
+
 #[rstest]
 fn test_trade_tick_when_empty(cache: Cache, audusd_sim: CurrencyPair) {
     let result = cache.trade(&audusd_sim.id);
@@ -612,6 +866,18 @@ fn test_cache_add_account(mut cache: Cache) {
     assert_eq!(*result.unwrap(), account);
 }
 
+#[rstest]
+fn test_cache_update_account(mut cache: Cache) {
+    let mut account = AccountAny::default();
+    cache.add_account(account.clone()).unwrap();
+
+    account.apply(account.last_event().unwrap());
+    cache.update_account(account.clone()).unwrap();
+
+    let result = cache.account(&account.id()).unwrap();
+    assert_eq!(result.events().len(), 2);
+}
+
 #[rstest]
 fn test_cache_accounts_when_no_accounts_returns_empty(cache: Cache) {
     let result = cache.accounts(&AccountId::default());
@@ -634,3 +900,98 @@ fn test_cache_account_for_venue_return_correct(mut cache: Cache) {
     assert!(result.is_some());
     assert_eq!(*result.unwrap(), account);
 }
+
+#[rstest]
+fn test_snapshot_position(mut cache: Cache, audusd_sim: CurrencyPair) {
+    let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(audusd_sim.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(100_000))
+        .build();
+    let fill = TestOrderEventStubs::order_filled(
+        &order,
+        &audusd_sim,
+        None,
+        Some(PositionId::new("P-123456")),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let position = Position::new(&audusd_sim, fill.into());
+
+    cache.snapshot_position(&position, 1.into()).unwrap();
+    cache.snapshot_position(&position, 2.into()).unwrap();
+
+    let snapshots = cache.position_snapshots(Some(&position.id)).unwrap();
+    assert_eq!(snapshots.len(), 2);
+    assert_ne!(snapshots[0].id, snapshots[1].id);
+    assert!(snapshots[0].id.as_str().starts_with("P-123456-"));
+    assert_eq!(snapshots[0].quantity, position.quantity);
+    assert_eq!(cache.position_snapshots(None).unwrap().len(), 2);
+    assert!(cache
+        .position_snapshots(Some(&PositionId::from("P-2")))
+        .unwrap()
+        .is_empty());
+}
+
+#[rstest]
+fn test_snapshot_position_state_when_no_database(cache: Cache, audusd_sim: CurrencyPair) {
+    let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(audusd_sim.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(100_000))
+        .build();
+    let fill = TestOrderEventStubs::order_filled(
+        &order,
+        &audusd_sim,
+        None,
+        Some(PositionId::new("P-123456")),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let position = Position::new(&audusd_sim, fill.into());
+
+    assert!(cache
+        .snapshot_position_state(&position, 1.into(), None, true)
+        .is_ok());
+}
+
+#[rstest]
+fn test_add_venue_position_id(mut cache: Cache) {
+    let position_id = PositionId::from("P-1");
+    let venue_position_id = PositionId::from("SIM-1-001");
+
+    cache
+        .add_venue_position_id(&position_id, &venue_position_id, false)
+        .unwrap();
+
+    assert_eq!(
+        cache.position_id_for_venue(&venue_position_id),
+        Some(&position_id)
+    );
+    assert!(cache
+        .add_venue_position_id(&PositionId::from("P-2"), &venue_position_id, false)
+        .is_err());
+}
+
+#[rstest]
+fn test_reset_clears_venue_position_ids(mut cache: Cache) {
+    let position_id = PositionId::from("P-1");
+    let venue_position_id = PositionId::from("SIM-1-001");
+    cache
+        .add_venue_position_id(&position_id, &venue_position_id, false)
+        .unwrap();
+
+    cache.reset();
+
+    assert_eq!(cache.position_id_for_venue(&venue_position_id), None);
+}
@@ -26,15 +26,21 @@ use std::{
 use chrono::{DateTime, Utc};
 use futures::Stream;
 use nautilus_core::{
-    correctness::{check_positive_u64, check_predicate_true, check_valid_string},
+    correctness::{
+        check_positive_u64, check_predicate_false, check_predicate_true, check_valid_string,
+    },
     nanos::UnixNanos,
     time::{get_atomic_clock_realtime, AtomicTime},
 };
 use tokio::sync::Mutex;
 use ustr::Ustr;
 
-use crate::timer::{
-    create_valid_interval, LiveTimer, TestTimer, TimeEvent, TimeEventCallback, TimeEventHandlerV2,
+use crate::{
+    schedule::CronSchedule,
+    timer::{
+        create_valid_interval, LiveCronTimer, LiveTimer, TestCronTimer, TestTimer, TimeEvent,
+        TimeEventCallback, TimeEventHandlerV2,
+    },
 };
 
 /// Represents a type of clock.
@@ -75,8 +81,12 @@ pub trait Clock {
     /// Note: Panics if the event does not have an associated handler
     fn get_handler(&self, event: TimeEvent) -> TimeEventHandlerV2;
 
-    /// Set a `Timer` to alert at a particular time. Optional
+    /// Set a `Timer` to alert once at a particular time. Optional
     /// callback gets used to handle generated events.
+    ///
+    /// Any existing timer with the same `name` is canceled and replaced.
+    /// If `alert_time_ns` is in the past or at current time, then an immediate
+    /// time event will be generated.
     fn set_time_alert_ns(
         &mut self,
         name: &str,
@@ -84,9 +94,31 @@ pub trait Clock {
         callback: Option<TimeEventCallback>,
     ) -> anyhow::Result<()>;
 
+    /// Set a `Timer` to alert once at a particular time. Optional
+    /// callback gets used to handle generated events.
+    ///
+    /// If `allow_override` is true then an existing active timer with the same
+    /// `name` is canceled and replaced, otherwise the `name` must be unique.
+    fn set_time_alert(
+        &mut self,
+        name: &str,
+        alert_time: DateTime<Utc>,
+        callback: Option<TimeEventCallback>,
+        allow_override: bool,
+    ) -> anyhow::Result<()> {
+        check_predicate_false(
+            !allow_override && self.timer_names().contains(&name),
+            &format!("Timer '{name}' already exists"),
+        )?;
+
+        self.set_time_alert_ns(name, alert_time.into(), callback)
+    }
+
     /// Set a `Timer` to start alerting at every interval
     /// between start and stop time. Optional callback gets
     /// used to handle generated event.
+    ///
+    /// Replaces any existing timer with the same `name`.
     fn set_timer_ns(
         &mut self,
         name: &str,
@@ -96,6 +128,19 @@ pub trait Clock {
         callback: Option<TimeEventCallback>,
     ) -> anyhow::Result<()>;
 
+    /// Set a `Timer` to alert at every time matching the calendar `schedule`
+    /// (starting from now) until the optional stop time. Optional callback gets
+    /// used to handle generated events.
+    ///
+    /// Replaces any existing timer with the same `name`.
+    fn set_cron_timer(
+        &mut self,
+        name: &str,
+        schedule: CronSchedule,
+        stop_time_ns: Option<UnixNanos>,
+        callback: Option<TimeEventCallback>,
+    ) -> anyhow::Result<()>;
+
     /// Returns the time interval in which the timer `name` is triggered.
     ///
     /// If the timer doesn't exist 0 is returned.
@@ -112,6 +157,7 @@ pub struct TestClock {
     // use btree map to ensure stable ordering when scanning for timers
     // in `advance_time`
     timers: BTreeMap<Ustr, TestTimer>,
+    cron_timers: BTreeMap<Ustr, TestCronTimer>,
     default_callback: Option<TimeEventCallback>,
     callbacks: HashMap<Ustr, TimeEventCallback>,
     heap: BinaryHeap<TimeEvent>,
@@ -124,6 +170,7 @@ impl TestClock {
         Self {
             time: AtomicTime::new(false, UnixNanos::default()),
             timers: BTreeMap::new(),
+            cron_timers: BTreeMap::new(),
             default_callback: None,
             callbacks: HashMap::new(),
             heap: BinaryHeap::new(),
@@ -136,6 +183,12 @@ impl TestClock {
         &self.timers
     }
 
+    /// Returns a reference to the internal calendar scheduled timers for the clock.
+    #[must_use]
+    pub const fn get_cron_timers(&self) -> &BTreeMap<Ustr, TestCronTimer> {
+        &self.cron_timers
+    }
+
     /// Advances the internal clock to the specified `to_time_ns` and optionally sets the clock to that time.
     ///
     /// This function ensures that the clock behaves in a non-decreasing manner. If `set_time` is `true`,
@@ -166,6 +219,11 @@ impl TestClock {
 
             !timer.is_expired()
         });
+        self.cron_timers.retain(|_, timer| {
+            events.extend(timer.advance(to_time_ns));
+
+            !timer.is_expired()
+        });
 
         events.sort_by(|a, b| a.ts_event.cmp(&b.ts_event));
         events
@@ -193,6 +251,11 @@ impl TestClock {
                 self.heap.push(event);
             });
 
+            !timer.is_expired()
+        });
+        self.cron_timers.retain(|_, timer| {
+            self.heap.extend(timer.advance(to_time_ns));
+
             !timer.is_expired()
         });
     }
@@ -265,6 +328,12 @@ impl Clock for TestClock {
             .iter()
             .filter(|(_, timer)| !timer.is_expired())
             .map(|(k, _)| k.as_str())
+            .chain(
+                self.cron_timers
+                    .iter()
+                    .filter(|(_, timer)| !timer.is_expired())
+                    .map(|(k, _)| k.as_str()),
+            )
             .collect()
     }
 
@@ -273,6 +342,11 @@ impl Clock for TestClock {
             .iter()
             .filter(|(_, timer)| !timer.is_expired())
             .count()
+            + self
+                .cron_timers
+                .iter()
+                .filter(|(_, timer)| !timer.is_expired())
+                .count()
     }
 
     fn register_default_handler(&mut self, callback: TimeEventCallback) {
@@ -313,6 +387,7 @@ impl Clock for TestClock {
         // TODO: For now we accommodate immediate alerts in the past, consider an `allow_past` flag
         let interval_ns = create_valid_interval(std::cmp::max((alert_time_ns - ts_now).into(), 1));
         let timer = TestTimer::new(name, interval_ns, ts_now, Some(alert_time_ns));
+        self.cancel_timer(name); // Replace any existing timer with the same name
         self.timers.insert(name_ustr, timer);
 
         Ok(())
@@ -341,21 +416,56 @@ impl Clock for TestClock {
 
         let interval_ns = create_valid_interval(interval_ns);
         let timer = TestTimer::new(name, interval_ns, start_time_ns, stop_time_ns);
+        self.cancel_timer(name); // Replace any existing timer with the same name
         self.timers.insert(name_ustr, timer);
 
         Ok(())
     }
 
+    fn set_cron_timer(
+        &mut self,
+        name: &str,
+        schedule: CronSchedule,
+        stop_time_ns: Option<UnixNanos>,
+        callback: Option<TimeEventCallback>,
+    ) -> anyhow::Result<()> {
+        check_valid_string(name, stringify!(name))?;
+        check_predicate_true(
+            callback.is_some() | self.default_callback.is_some(),
+            "No callbacks provided",
+        )?;
+
+        let name_ustr = Ustr::from(name);
+        match callback {
+            Some(callback_py) => self.callbacks.insert(name_ustr, callback_py),
+            None => None,
+        };
+
+        let ts_now = self.time.get_time_ns();
+        let timer = TestCronTimer::new(name, schedule, ts_now, stop_time_ns);
+        self.timers.remove(&name_ustr);
+        self.cron_timers.insert(name_ustr, timer);
+
+        Ok(())
+    }
+
     fn next_time_ns(&self, name: &str) -> UnixNanos {
-        let timer = self.timers.get(&Ustr::from(name));
-        match timer {
+        let name = Ustr::from(name);
+        if let Some(timer) = self.cron_timers.get(&name) {
+            return timer.next_time_ns();
+        }
+        match self.timers.get(&name) {
             None => 0.into(),
             Some(timer) => timer.next_time_ns(),
         }
     }
 
     fn cancel_timer(&mut self, name: &str) {
-        let timer = self.timers.remove(&Ustr::from(name));
+        let name = Ustr::from(name);
+        if let Some(mut timer) = self.cron_timers.remove(&name) {
+            timer.cancel();
+        }
+        let timer = self.timers.remove(&name);
         match timer {
             None => {}
             Some(mut timer) => timer.cancel(),
@@ -366,7 +476,11 @@ impl Clock for TestClock {
         for timer in &mut self.timers.values_mut() {
             timer.cancel();
         }
+        for timer in &mut self.cron_timers.values_mut() {
+            timer.cancel();
+        }
         self.timers = BTreeMap::new();
+        self.cron_timers = BTreeMap::new();
     }
 }
 
@@ -376,6 +490,7 @@ impl Clock for TestClock {
 pub struct LiveClock {
     time: &'static AtomicTime,
     timers: HashMap<Ustr, LiveTimer>,
+    cron_timers: HashMap<Ustr, LiveCronTimer>,
     default_callback: Option<TimeEventCallback>,
     pub heap: Arc<Mutex<BinaryHeap<TimeEvent>>>,
     #[allow(dead_code)]
@@ -389,6 +504,7 @@ impl LiveClock {
         Self {
             time: get_atomic_clock_realtime(),
             timers: HashMap::new(),
+            cron_timers: HashMap::new(),
             default_callback: None,
             heap: Arc::new(Mutex::new(BinaryHeap::new())),
             callbacks: HashMap::new(),
@@ -405,9 +521,15 @@ impl LiveClock {
         &self.timers
     }
 
+    #[must_use]
+    pub const fn get_cron_timers(&self) -> &HashMap<Ustr, LiveCronTimer> {
+        &self.cron_timers
+    }
+
     // Clean up expired timers. Retain only live ones
     fn clear_expired_timers(&mut self) {
         self.timers.retain(|_, timer| !timer.is_expired());
+        self.cron_timers.retain(|_, timer| !timer.is_expired());
     }
 }
 
@@ -448,6 +570,12 @@ impl Clock for LiveClock {
             .iter()
             .filter(|(_, timer)| !timer.is_expired())
             .map(|(k, _)| k.as_str())
+            .chain(
+                self.cron_timers
+                    .iter()
+                    .filter(|(_, timer)| !timer.is_expired())
+                    .map(|(k, _)| k.as_str()),
+            )
             .collect()
     }
 
@@ -456,6 +584,11 @@ impl Clock for LiveClock {
             .iter()
             .filter(|(_, timer)| !timer.is_expired())
             .count()
+            + self
+                .cron_timers
+                .iter()
+                .filter(|(_, timer)| !timer.is_expired())
+                .count()
     }
 
     fn register_default_handler(&mut self, handler: TimeEventCallback) {
@@ -523,6 +656,7 @@ impl Clock for LiveClock {
 
         timer.start();
 
+        self.cancel_timer(name); // Replace any existing timer with the same name
         self.clear_expired_timers();
         self.timers.insert(Ustr::from(name), timer);
 
@@ -571,14 +705,69 @@ impl Clock for LiveClock {
         );
         timer.start();
 
+        self.cancel_timer(name); // Replace any existing timer with the same name
         self.clear_expired_timers();
         self.timers.insert(Ustr::from(name), timer);
 
         Ok(())
     }
 
+    fn set_cron_timer(
+        &mut self,
+        name: &str,
+        schedule: CronSchedule,
+        stop_time_ns: Option<UnixNanos>,
+        callback: Option<TimeEventCallback>,
+    ) -> anyhow::Result<()> {
+        check_valid_string(name, stringify!(name))?;
+        check_predicate_true(
+            callback.is_some() | self.default_callback.is_some(),
+            "No callbacks provided",
+        )?;
+
+        let callback = match callback {
+            Some(callback) => callback,
+            None => self.default_callback.clone().unwrap(),
+        };
+
+        #[cfg(feature = "clock_v2")]
+        {
+            let name = Ustr::from(name);
+            self.callbacks.insert(name, callback.clone());
+        }
+
+        let ts_now = self.get_time_ns();
+
+        #[cfg(not(feature = "clock_v2"))]
+        let mut timer = LiveCronTimer::new(name, schedule, ts_now, stop_time_ns, callback);
+        #[cfg(feature = "clock_v2")]
+        let mut timer = LiveCronTimer::new(
+            name,
+            schedule,
+            ts_now,
+            stop_time_ns,
+            callback,
+            self.heap.clone(),
+        );
+        timer.start();
+
+        self.clear_expired_timers();
+        if let Some(mut existing) = self.timers.remove(&Ustr::from(name)) {
+            existing.cancel();
+        }
+        if let Some(mut existing) = self.cron_timers.insert(Ustr::from(name), timer) {
+            existing.cancel();
+        }
+
+        Ok(())
+    }
+
     fn next_time_ns(&self, name: &str) -> UnixNanos {
-        let timer = self.timers.get(&Ustr::from(name));
+        let name = Ustr::from(name);
+        if let Some(timer) = self.cron_timers.get(&name) {
+            return timer.next_time_ns();
+        }
+        let timer = self.timers.get(&name);
         match timer {
             None => 0.into(),
             Some(timer) => timer.next_time_ns(),
@@ -586,7 +775,11 @@ impl Clock for LiveClock {
     }
 
     fn cancel_timer(&mut self, name: &str) {
-        let timer = self.timers.remove(&Ustr::from(name));
+        let name = Ustr::from(name);
+        if let Some(mut timer) = self.cron_timers.remove(&name) {
+            timer.cancel();
+        }
+        let timer = self.timers.remove(&name);
         match timer {
             None => {}
             Some(mut timer) => {
@@ -599,7 +792,11 @@ impl Clock for LiveClock {
         for timer in &mut self.timers.values_mut() {
             timer.cancel();
         }
+        for timer in &mut self.cron_timers.values_mut() {
+            timer.cancel();
+        }
         self.timers.clear();
+        self.cron_timers.clear();
     }
 }
 
@@ -777,4 +974,241 @@ mod tests {
         assert_eq!(events[1].name.as_str(), "timer1");
         assert_eq!(events[2].name.as_str(), "timer2");
     }
+
+    #[rstest]
+    fn test_next_time_ns(mut test_clock: TestClock) {
+        let start_time = test_clock.timestamp_ns();
+        test_clock
+            .set_timer_ns("timer1", 1000, start_time, None, None)
+            .unwrap();
+        assert_eq!(*test_clock.next_time_ns("timer1"), *start_time + 1000);
+        assert_eq!(*test_clock.next_time_ns("unknown"), 0);
+
+        test_clock.advance_time((*start_time + 1000).into(), true);
+        assert_eq!(*test_clock.next_time_ns("timer1"), *start_time + 2000);
+    }
+
+    #[rstest]
+    fn test_cancel_timers(mut test_clock: TestClock) {
+        let start_time = test_clock.timestamp_ns();
+        test_clock
+            .set_timer_ns("timer1", 1000, start_time, None, None)
+            .unwrap();
+        test_clock
+            .set_timer_ns("timer2", 2000, start_time, None, None)
+            .unwrap();
+
+        test_clock.cancel_timers();
+
+        assert_eq!(test_clock.timer_count(), 0);
+        assert!(test_clock
+            .advance_time((*start_time + 5000).into(), true)
+            .is_empty());
+    }
+
+    #[rstest]
+    fn test_set_timer_ns_with_zero_interval_errors(mut test_clock: TestClock) {
+        let start_time = test_clock.timestamp_ns();
+        let result = test_clock.set_timer_ns("timer1", 0, start_time, None, None);
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_timer_with_stop_time_expires(mut test_clock: TestClock) {
+        let start_time = test_clock.timestamp_ns();
+        test_clock
+            .set_timer_ns(
+                "timer1",
+                1000,
+                start_time,
+                Some((*start_time + 2000).into()),
+                None,
+            )
+            .unwrap();
+        let events = test_clock.advance_time((*start_time + 5000).into(), true);
+        assert_eq!(events.len(), 2);
+        assert_eq!(test_clock.timer_count(), 0);
+    }
+
+    #[rstest]
+    fn test_time_alert_fires_once(mut test_clock: TestClock) {
+        test_clock
+            .set_time_alert_ns("alert", 1_000.into(), None)
+            .unwrap();
+
+        let events = test_clock.advance_time(5_000.into(), true);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].name.as_str(), "alert");
+        assert_eq!(*events[0].ts_event, 1_000);
+        assert_eq!(test_clock.timer_count(), 0);
+        assert!(test_clock.advance_time(10_000.into(), true).is_empty());
+    }
+
+    #[rstest]
+    fn test_set_time_alert_ns_with_existing_name_replaces(mut test_clock: TestClock) {
+        test_clock
+            .set_time_alert_ns("alert", 1_000.into(), None)
+            .unwrap();
+        test_clock
+            .set_time_alert_ns("alert", 2_000.into(), None)
+            .unwrap();
+
+        assert_eq!(test_clock.timer_count(), 1);
+        let events = test_clock.advance_time(5_000.into(), true);
+        assert_eq!(events.len(), 1);
+        assert_eq!(*events[0].ts_event, 2_000);
+    }
+
+    #[rstest]
+    fn test_set_time_alert_ns_reuses_name_once_fired(mut test_clock: TestClock) {
+        test_clock
+            .set_time_alert_ns("alert", 1_000.into(), None)
+            .unwrap();
+        test_clock.advance_time(1_000.into(), true);
+
+        test_clock
+            .set_time_alert_ns("alert", 2_000.into(), None)
+            .unwrap();
+        assert_eq!(*test_clock.next_time_ns("alert"), 2_000);
+    }
+
+    #[rstest]
+    fn test_set_time_alert_with_override_replaces_existing(mut test_clock: TestClock) {
+        test_clock
+            .set_time_alert("alert", DateTime::from_timestamp_nanos(1_000), None, false)
+            .unwrap();
+        assert!(test_clock
+            .set_time_alert("alert", DateTime::from_timestamp_nanos(2_000), None, false)
+            .is_err());
+
+        test_clock
+            .set_time_alert("alert", DateTime::from_timestamp_nanos(2_000), None, true)
+            .unwrap();
+
+        let events = test_clock.advance_time(5_000.into(), true);
+        assert_eq!(events.len(), 1);
+        assert_eq!(*events[0].ts_event, 2_000);
+    }
+
+    #[rstest]
+    fn test_cancel_time_alert_before_fire(mut test_clock: TestClock) {
+        test_clock
+            .set_time_alert_ns("alert", 1_000.into(), None)
+            .unwrap();
+        test_clock.cancel_timer("alert");
+        assert!(test_clock.advance_time(5_000.into(), true).is_empty());
+    }
+
+    #[rstest]
+    fn test_cron_timer(mut test_clock: TestClock) {
+        // Every day at 16:00 UTC, starting from the UNIX epoch
+        let schedule = CronSchedule::utc("0 16 * * *").unwrap();
+        test_clock
+            .set_cron_timer("daily_close", schedule, None, None)
+            .unwrap();
+
+        let hour_ns = 3_600_000_000_000;
+        assert_eq!(test_clock.timer_count(), 1);
+        assert_eq!(test_clock.timer_names(), vec!["daily_close"]);
+        assert_eq!(*test_clock.next_time_ns("daily_close"), 16 * hour_ns);
+
+        let events = test_clock.advance_time((48 * hour_ns).into(), true);
+        assert_eq!(events.len(), 2);
+        assert_eq!(*events[0].ts_event, 16 * hour_ns);
+        assert_eq!(*events[1].ts_event, 40 * hour_ns);
+        assert_eq!(*test_clock.next_time_ns("daily_close"), 64 * hour_ns);
+    }
+
+    #[rstest]
+    fn test_cron_timer_with_stop_time_expires(mut test_clock: TestClock) {
+        let schedule = CronSchedule::utc("0 * * * *").unwrap();
+        let hour_ns: u64 = 3_600_000_000_000;
+        test_clock
+            .set_cron_timer("hourly", schedule, Some((2 * hour_ns).into()), None)
+            .unwrap();
+
+        let events = test_clock.advance_time((5 * hour_ns).into(), true);
+        assert_eq!(events.len(), 2);
+        assert_eq!(test_clock.timer_count(), 0);
+    }
+
+    #[rstest]
+    fn test_cron_timer_events_interleave_with_interval_timers(mut test_clock: TestClock) {
+        let minute_ns: u64 = 60_000_000_000;
+        test_clock
+            .set_timer_ns("interval", 90 * minute_ns, 0.into(), None, None)
+            .unwrap();
+        test_clock
+            .set_cron_timer(
+                "hourly",
+                CronSchedule::utc("0 * * * *").unwrap(),
+                None,
+                None,
+            )
+            .unwrap();
+
+        let events = test_clock.advance_time((180 * minute_ns).into(), true);
+        let names: Vec<&str> = events.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["hourly", "interval", "hourly", "interval", "hourly"]
+        );
+    }
+
+    #[rstest]
+    fn test_set_timer_ns_replaces_cron_timer_with_same_name(mut test_clock: TestClock) {
+        let hour_ns: u64 = 3_600_000_000_000;
+        test_clock
+            .set_cron_timer("timer", CronSchedule::utc("0 * * * *").unwrap(), None, None)
+            .unwrap();
+        test_clock
+            .set_timer_ns("timer", 2 * hour_ns, 0.into(), None, None)
+            .unwrap();
+
+        assert_eq!(test_clock.timer_count(), 1);
+        assert_eq!(*test_clock.next_time_ns("timer"), 2 * hour_ns);
+        let events = test_clock.advance_time((4 * hour_ns).into(), true);
+        assert_eq!(events.len(), 2);
+    }
+
+    #[rstest]
+    fn test_cancel_cron_timer(mut test_clock: TestClock) {
+        test_clock
+            .set_cron_timer(
+                "hourly",
+                CronSchedule::utc("0 * * * *").unwrap(),
+                None,
+                None,
+            )
+            .unwrap();
+        test_clock.cancel_timer("hourly");
+        assert_eq!(test_clock.timer_count(), 0);
+        assert!(test_clock
+            .advance_time(3_600_000_000_000.into(), true)
+            .is_empty());
+    }
+
+    #[rstest]
+    fn test_live_clock_timestamps_are_monotonic() {
+        let clock = LiveClock::new();
+        let ts1 = clock.timestamp_ns();
+        let ts2 = clock.timestamp_ns();
+        assert!(ts2 > ts1);
+    }
+
+    #[rstest]
+    fn test_live_clock_set_and_cancel_timer() {
+        let mut clock = LiveClock::new();
+        clock.register_default_handler(TestCallback::default().into());
+        let start_time = clock.timestamp_ns();
+
+        clock
+            .set_timer_ns("timer1", 60_000_000_000, start_time, None, None)
+            .unwrap();
+        assert_eq!(clock.timer_count(), 1);
+        assert_eq!(clock.timer_names(), vec!["timer1"]);
+
+        clock.cancel_timer("timer1");
+        assert_eq!(clock.timer_count(), 0);
+    }
 }
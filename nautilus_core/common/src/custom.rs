@@ -17,7 +17,7 @@
 
 use bytes::Bytes;
 use nautilus_core::nanos::UnixNanos;
-use nautilus_model::data::DataType;
+use nautilus_model::data::{DataType, GetTsInit};
 use serde::{Deserialize, Serialize};
 
 /// Represents a custom data.
@@ -50,3 +50,9 @@ impl CustomData {
         }
     }
 }
+
+impl GetTsInit for CustomData {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
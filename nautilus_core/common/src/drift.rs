@@ -0,0 +1,401 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Monitoring of local clock drift and feed latency against venue timestamps.
+//!
+//! Each inbound data point carries the venue event timestamp (`ts_event`) and the local
+//! receive timestamp (`ts_init`). Their difference is the one-way latency *plus* any offset
+//! between the local and venue clocks. The minimum over a rolling window approximates the
+//! clock offset (plus the irreducible network latency), so a sustained large or negative
+//! minimum indicates the local clock has drifted (e.g. NTP problems).
+
+use std::{cell::RefCell, collections::VecDeque, fmt::Display, rc::Rc, time::Instant};
+
+use indexmap::IndexMap;
+use nautilus_core::{
+    correctness::{check_in_range_inclusive_usize, check_positive_u64},
+    nanos::UnixNanos,
+    time::get_atomic_clock_realtime,
+};
+use nautilus_model::{data::Data, identifiers::Venue};
+
+use crate::{
+    clock::Clock,
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// Configuration for a [`ClockDriftMonitor`].
+#[derive(Clone, Debug)]
+pub struct ClockDriftConfig {
+    /// The number of most recent samples retained per venue.
+    pub window_size: usize,
+    /// The minimum number of samples for a venue before an estimate is made.
+    pub min_samples: usize,
+    /// The absolute estimated offset (nanoseconds) above which a warning is emitted.
+    pub warn_threshold_ns: u64,
+}
+
+impl Default for ClockDriftConfig {
+    /// Creates a new default [`ClockDriftConfig`] instance.
+    fn default() -> Self {
+        Self {
+            window_size: 1_000,
+            min_samples: 10,
+            warn_threshold_ns: 100_000_000, // 100ms
+        }
+    }
+}
+
+/// Represents an estimate of the clock offset and feed latency for a venue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DriftEstimate {
+    /// The venue for the estimate.
+    pub venue: Venue,
+    /// The estimated local clock offset versus the venue (nanoseconds), the minimum of
+    /// `ts_init - ts_event` over the window. Positive means the local clock is ahead
+    /// (or includes the minimum network latency), negative means the local clock is behind.
+    pub offset_ns: i64,
+    /// The median of `ts_init - ts_event` over the window (nanoseconds).
+    pub latency_median_ns: i64,
+    /// The maximum of `ts_init - ts_event` over the window (nanoseconds).
+    pub latency_max_ns: i64,
+    /// The number of samples the estimate is based on.
+    pub samples: usize,
+}
+
+impl Display for DriftEstimate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(venue={}, offset_ns={}, latency_median_ns={}, latency_max_ns={}, samples={})",
+            stringify!(DriftEstimate),
+            self.venue,
+            self.offset_ns,
+            self.latency_median_ns,
+            self.latency_max_ns,
+            self.samples,
+        )
+    }
+}
+
+/// Provides monitoring of the local clock drift against venue timestamps observed on inbound data.
+///
+/// Also tracks the divergence between the local wall clock and the monotonic clock since the
+/// monitor was created, which detects wall clock steps (e.g. NTP corrections) directly.
+#[derive(Debug)]
+pub struct ClockDriftMonitor {
+    config: ClockDriftConfig,
+    samples: IndexMap<Venue, VecDeque<i64>>,
+    anchor_wall_ns: UnixNanos,
+    anchor_mono: Instant,
+}
+
+impl ClockDriftMonitor {
+    /// Creates a new [`ClockDriftMonitor`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `config.window_size` is not positive (> 0).
+    /// - If `config.min_samples` is not in the range [1, `config.window_size`].
+    /// - If `config.warn_threshold_ns` is not positive (> 0).
+    pub fn new(config: ClockDriftConfig) -> anyhow::Result<Self> {
+        check_positive_u64(config.window_size as u64, "config.window_size")?;
+        check_in_range_inclusive_usize(
+            config.min_samples,
+            1,
+            config.window_size,
+            "config.min_samples",
+        )?;
+        check_positive_u64(config.warn_threshold_ns, "config.warn_threshold_ns")?;
+
+        Ok(Self {
+            config,
+            samples: IndexMap::new(),
+            anchor_wall_ns: get_atomic_clock_realtime().get_time_ns(),
+            anchor_mono: Instant::now(),
+        })
+    }
+
+    /// Returns the configuration for the monitor.
+    #[must_use]
+    pub const fn config(&self) -> &ClockDriftConfig {
+        &self.config
+    }
+
+    /// Records a sample for the given `venue` from its event and local receive timestamps.
+    ///
+    /// Samples with a zero `ts_event` (venue timestamp unavailable) are ignored.
+    pub fn record(&mut self, venue: Venue, ts_event: UnixNanos, ts_init: UnixNanos) {
+        if ts_event.as_u64() == 0 {
+            return;
+        }
+
+        let delta = ts_init.as_i64() - ts_event.as_i64();
+        let window = self.samples.entry(venue).or_default();
+        if window.len() == self.config.window_size {
+            window.pop_front();
+        }
+        window.push_back(delta);
+    }
+
+    /// Records a sample from the given inbound `data`.
+    ///
+    /// Bars are ignored as their event timestamp is the bar close rather than a venue timestamp.
+    pub fn handle_data(&mut self, data: &Data) {
+        let ts_event = match data {
+            Data::Delta(delta) => delta.ts_event,
+            Data::Deltas(deltas) => deltas.ts_event,
+            Data::Depth10(depth) => depth.ts_event,
+            Data::Quote(quote) => quote.ts_event,
+            Data::Trade(trade) => trade.ts_event,
+            Data::Bar(_) => return,
+        };
+        let ts_init = match data {
+            Data::Delta(delta) => delta.ts_init,
+            Data::Deltas(deltas) => deltas.ts_init,
+            Data::Depth10(depth) => depth.ts_init,
+            Data::Quote(quote) => quote.ts_init,
+            Data::Trade(trade) => trade.ts_init,
+            Data::Bar(_) => return,
+        };
+
+        self.record(data.instrument_id().venue, ts_event, ts_init);
+    }
+
+    /// Returns the current drift estimate for the given `venue` (if enough samples).
+    #[must_use]
+    pub fn estimate(&self, venue: &Venue) -> Option<DriftEstimate> {
+        let window = self.samples.get(venue)?;
+        if window.len() < self.config.min_samples {
+            return None;
+        }
+
+        let mut sorted: Vec<i64> = window.iter().copied().collect();
+        sorted.sort_unstable();
+
+        Some(DriftEstimate {
+            venue: *venue,
+            offset_ns: sorted[0],
+            latency_median_ns: sorted[sorted.len() / 2],
+            latency_max_ns: sorted[sorted.len() - 1],
+            samples: sorted.len(),
+        })
+    }
+
+    /// Returns the current drift estimates for all venues with enough samples.
+    #[must_use]
+    pub fn estimates(&self) -> Vec<DriftEstimate> {
+        self.samples
+            .keys()
+            .filter_map(|venue| self.estimate(venue))
+            .collect()
+    }
+
+    /// Returns the divergence (nanoseconds) of the wall clock from the monotonic clock
+    /// since the monitor was created. Positive means the wall clock has stepped forward.
+    #[must_use]
+    pub fn wall_clock_drift_ns(&self) -> i64 {
+        let wall_elapsed =
+            get_atomic_clock_realtime().get_time_ns().as_i64() - self.anchor_wall_ns.as_i64();
+        let mono_elapsed = i64::try_from(self.anchor_mono.elapsed().as_nanos()).unwrap_or(i64::MAX);
+        wall_elapsed - mono_elapsed
+    }
+
+    /// Checks the current estimates, logging a warning for each which exceeds the threshold.
+    ///
+    /// Returns the estimates which exceeded the threshold.
+    pub fn check(&self) -> Vec<DriftEstimate> {
+        let threshold = self.config.warn_threshold_ns;
+
+        let wall_drift = self.wall_clock_drift_ns();
+        if wall_drift.unsigned_abs() > threshold {
+            log::warn!("Wall clock has drifted {wall_drift}ns from the monotonic clock");
+        }
+
+        let exceeded: Vec<DriftEstimate> = self
+            .estimates()
+            .into_iter()
+            .filter(|estimate| estimate.offset_ns.unsigned_abs() > threshold)
+            .collect();
+
+        for estimate in &exceeded {
+            log::warn!("Clock drift exceeds threshold of {threshold}ns: {estimate}");
+        }
+
+        exceeded
+    }
+
+    /// Clears all samples for the monitor.
+    pub fn reset(&mut self) {
+        self.samples.clear();
+    }
+}
+
+/// Sets a timer on the given `clock` which periodically runs [`ClockDriftMonitor::check`].
+///
+/// # Errors
+///
+/// This function returns an error if the timer cannot be set.
+pub fn set_drift_check_timer(
+    monitor: Rc<RefCell<ClockDriftMonitor>>,
+    clock: &mut dyn Clock,
+    name: &str,
+    interval_ns: u64,
+) -> anyhow::Result<()> {
+    let callback = TimeEventCallback::Rust(Rc::new(move |_event: TimeEvent| {
+        monitor.borrow().check();
+    }));
+    let start_time_ns = clock.timestamp_ns();
+    clock.set_timer_ns(name, interval_ns, start_time_ns, None, Some(callback))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::stubs::{quote_audusd, stub_bar};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::clock::TestClock;
+
+    fn monitor(min_samples: usize) -> ClockDriftMonitor {
+        ClockDriftMonitor::new(ClockDriftConfig {
+            window_size: 5,
+            min_samples,
+            warn_threshold_ns: 1_000,
+        })
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_new_with_invalid_config_errors() {
+        let config = ClockDriftConfig {
+            window_size: 0,
+            ..Default::default()
+        };
+        assert!(ClockDriftMonitor::new(config).is_err());
+    }
+
+    #[rstest]
+    fn test_estimate_requires_min_samples() {
+        let mut monitor = monitor(3);
+        let venue = Venue::from("SIM");
+        monitor.record(venue, 1_000.into(), 1_100.into());
+        monitor.record(venue, 2_000.into(), 2_100.into());
+
+        assert!(monitor.estimate(&venue).is_none());
+        assert!(monitor.estimates().is_empty());
+    }
+
+    #[rstest]
+    fn test_estimate_offset_and_latency() {
+        let mut monitor = monitor(3);
+        let venue = Venue::from("SIM");
+        monitor.record(venue, 1_000.into(), 1_300.into());
+        monitor.record(venue, 2_000.into(), 2_100.into());
+        monitor.record(venue, 3_000.into(), 3_200.into());
+
+        let estimate = monitor.estimate(&venue).unwrap();
+        assert_eq!(estimate.offset_ns, 100);
+        assert_eq!(estimate.latency_median_ns, 200);
+        assert_eq!(estimate.latency_max_ns, 300);
+        assert_eq!(estimate.samples, 3);
+    }
+
+    #[rstest]
+    fn test_local_clock_behind_gives_negative_offset() {
+        let mut monitor = monitor(1);
+        let venue = Venue::from("SIM");
+        monitor.record(venue, 10_000.into(), 5_000.into());
+
+        assert_eq!(monitor.estimate(&venue).unwrap().offset_ns, -5_000);
+    }
+
+    #[rstest]
+    fn test_window_is_bounded() {
+        let mut monitor = monitor(1);
+        let venue = Venue::from("SIM");
+        monitor.record(venue, 1_000.into(), 1_001.into()); // Rolls out of the window
+        for i in 1..=5 {
+            monitor.record(venue, (i * 1_000).into(), (i * 1_000 + 50).into());
+        }
+
+        let estimate = monitor.estimate(&venue).unwrap();
+        assert_eq!(estimate.samples, 5);
+        assert_eq!(estimate.offset_ns, 50);
+    }
+
+    #[rstest]
+    fn test_zero_ts_event_is_ignored() {
+        let mut monitor = monitor(1);
+        let venue = Venue::from("SIM");
+        monitor.record(venue, 0.into(), 1_000.into());
+
+        assert!(monitor.estimate(&venue).is_none());
+    }
+
+    #[rstest]
+    fn test_handle_data_records_quotes_and_ignores_bars() {
+        let mut monitor = monitor(1);
+        let mut quote = quote_audusd();
+        quote.ts_event = 1_000.into();
+        quote.ts_init = 1_250.into();
+
+        monitor.handle_data(&Data::Quote(quote));
+        monitor.handle_data(&Data::Bar(stub_bar()));
+
+        let estimates = monitor.estimates();
+        assert_eq!(estimates.len(), 1);
+        assert_eq!(estimates[0].venue, Venue::from("SIM"));
+        assert_eq!(estimates[0].offset_ns, 250);
+    }
+
+    #[rstest]
+    fn test_check_returns_estimates_exceeding_threshold() {
+        let mut monitor = monitor(1);
+        let sim = Venue::from("SIM");
+        let binance = Venue::from("BINANCE");
+        monitor.record(sim, 1_000.into(), 1_500.into());
+        monitor.record(binance, 10_000.into(), 15_000.into());
+
+        let exceeded = monitor.check();
+        assert_eq!(exceeded.len(), 1);
+        assert_eq!(exceeded[0].venue, binance);
+    }
+
+    #[rstest]
+    fn test_wall_clock_drift_is_small() {
+        let monitor = monitor(1);
+        assert!(monitor.wall_clock_drift_ns().unsigned_abs() < 1_000_000_000);
+    }
+
+    #[rstest]
+    fn test_set_drift_check_timer() {
+        let monitor = Rc::new(RefCell::new(monitor(1)));
+        let mut clock = TestClock::new();
+
+        set_drift_check_timer(monitor, &mut clock, "drift-check", 1_000).unwrap();
+
+        assert_eq!(clock.timer_names(), vec!["drift-check"]);
+        let events = clock.advance_time(2_000.into(), true);
+        assert_eq!(events.len(), 2);
+        for handler in clock.match_handlers(events) {
+            handler.run();
+        }
+    }
+}
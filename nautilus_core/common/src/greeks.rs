@@ -0,0 +1,497 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Option Greeks and implied volatility for cached option instruments.
+//!
+//! Market prices for the option and its underlying are read from the [`Cache`], the volatility
+//! implied by the option price is solved for, and the Greeks are computed with the Black-76
+//! model for options on futures or the Black-Scholes model otherwise. The Greeks of open
+//! positions can then be aggregated per underlying to give a portfolio-level view.
+
+use std::{cell::RefCell, fmt::Display, rc::Rc};
+
+use indexmap::IndexMap;
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::greeks::{black76_greeks, black_scholes_greeks, imply_vol, BlackScholesGreeksResult},
+    enums::{OptionKind, PriceType},
+    identifiers::{InstrumentId, StrategyId, Symbol, Venue},
+    instruments::{InstrumentAny, OptionsContract},
+};
+
+use crate::cache::Cache;
+
+const NANOSECONDS_IN_YEAR: f64 = 365.25 * 86_400.0 * 1_000_000_000.0;
+
+/// Represents the implied volatility and Greeks of a single option contract.
+///
+/// The price and Greeks are per contract (scaled by the instrument multiplier), with vega and
+/// rho per 1% change in volatility and interest rate, and theta per calendar day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OptionGreeks {
+    /// The option instrument ID.
+    pub instrument_id: InstrumentId,
+    /// The underlying instrument ID.
+    pub underlying_id: InstrumentId,
+    /// If the option is a call (otherwise a put).
+    pub is_call: bool,
+    /// The option strike price.
+    pub strike: f64,
+    /// The time to expiry (years).
+    pub expiry_in_years: f64,
+    /// The underlying market price.
+    pub underlying_price: f64,
+    /// The interest rate used for the calculation.
+    pub interest_rate: f64,
+    /// The implied volatility.
+    pub vol: f64,
+    /// The model price of the contract.
+    pub price: f64,
+    /// The sensitivity to the underlying price.
+    pub delta: f64,
+    /// The sensitivity of delta to the underlying price.
+    pub gamma: f64,
+    /// The sensitivity to a 1% change in volatility.
+    pub vega: f64,
+    /// The change in price per calendar day.
+    pub theta: f64,
+    /// The sensitivity to a 1% change in the interest rate.
+    pub rho: f64,
+    /// UNIX timestamp (nanoseconds) at which the Greeks were calculated.
+    pub ts_event: UnixNanos,
+}
+
+impl Display for OptionGreeks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(instrument_id={}, vol={:.4}, price={:.4}, delta={:.4}, gamma={:.4}, vega={:.4}, theta={:.4}, rho={:.4})",
+            stringify!(OptionGreeks),
+            self.instrument_id,
+            self.vol,
+            self.price,
+            self.delta,
+            self.gamma,
+            self.vega,
+            self.theta,
+            self.rho,
+        )
+    }
+}
+
+/// Represents the aggregated Greeks of the open positions on a single underlying.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PortfolioGreeks {
+    /// The net sensitivity to the underlying price (in units of the underlying).
+    pub delta: f64,
+    /// The net sensitivity of delta to the underlying price.
+    pub gamma: f64,
+    /// The net sensitivity to a 1% change in volatility.
+    pub vega: f64,
+    /// The net change in value per calendar day.
+    pub theta: f64,
+    /// The net sensitivity to a 1% change in the interest rate.
+    pub rho: f64,
+}
+
+impl PortfolioGreeks {
+    /// Adds the `greeks` of an option position with the given signed `quantity`.
+    pub fn add_option(&mut self, greeks: &OptionGreeks, quantity: f64) {
+        self.delta += greeks.delta * quantity;
+        self.gamma += greeks.gamma * quantity;
+        self.vega += greeks.vega * quantity;
+        self.theta += greeks.theta * quantity;
+        self.rho += greeks.rho * quantity;
+    }
+
+    /// Adds the delta of a linear position with the given signed `quantity` and `multiplier`.
+    pub fn add_linear(&mut self, quantity: f64, multiplier: f64) {
+        self.delta += quantity * multiplier;
+    }
+}
+
+impl Display for PortfolioGreeks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(delta={:.4}, gamma={:.4}, vega={:.4}, theta={:.4}, rho={:.4})",
+            stringify!(PortfolioGreeks),
+            self.delta,
+            self.gamma,
+            self.vega,
+            self.theta,
+            self.rho,
+        )
+    }
+}
+
+/// Provides Greeks calculations for the option instruments and positions held in a [`Cache`].
+pub struct GreeksCalculator {
+    cache: Rc<RefCell<Cache>>,
+}
+
+impl GreeksCalculator {
+    /// Creates a new [`GreeksCalculator`] instance.
+    #[must_use]
+    pub const fn new(cache: Rc<RefCell<Cache>>) -> Self {
+        Self { cache }
+    }
+
+    /// Calculates the implied volatility and Greeks for the option with the given
+    /// `instrument_id` at `ts_now`.
+    ///
+    /// The underlying is the instrument with the option's underlying symbol, preferring the
+    /// option's venue. Options on futures use the Black-76 model, otherwise the Black-Scholes
+    /// model is used with a cost of carry of `interest_rate - dividend_yield`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the instrument is not found in the cache or is not an options contract.
+    /// - If no market price is cached for the option or its underlying.
+    /// - If the option has expired at `ts_now`.
+    /// - If the option price is outside the no-arbitrage bounds (no implied volatility).
+    pub fn instrument_greeks(
+        &self,
+        instrument_id: &InstrumentId,
+        interest_rate: f64,
+        dividend_yield: f64,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<OptionGreeks> {
+        let cache = self.cache.borrow();
+        let option = match cache.instrument(instrument_id) {
+            Some(InstrumentAny::OptionsContract(option)) => option,
+            Some(_) => anyhow::bail!("Instrument {instrument_id} is not an options contract"),
+            None => anyhow::bail!("Instrument {instrument_id} not found in cache"),
+        };
+
+        let underlying_id = underlying_instrument_id(&cache, option);
+        let underlying_price = market_price(&cache, &underlying_id)
+            .ok_or_else(|| anyhow::anyhow!("No market price for underlying {underlying_id}"))?;
+        let option_price = market_price(&cache, instrument_id)
+            .ok_or_else(|| anyhow::anyhow!("No market price for {instrument_id}"))?;
+
+        let expiry_in_years = option
+            .expiration_ns
+            .as_u64()
+            .saturating_sub(ts_now.as_u64()) as f64
+            / NANOSECONDS_IN_YEAR;
+        if expiry_in_years <= 0.0 {
+            anyhow::bail!("Option {instrument_id} has expired");
+        }
+
+        let is_call = option.option_kind == OptionKind::Call;
+        let strike = option.strike_price.as_f64();
+        let multiplier = option.multiplier.as_f64();
+        let is_future_underlying = matches!(
+            cache.instrument(&underlying_id),
+            Some(InstrumentAny::FuturesContract(_) | InstrumentAny::CryptoFuture(_))
+        );
+        let cost_of_carry = if is_future_underlying {
+            0.0
+        } else {
+            interest_rate - dividend_yield
+        };
+
+        let vol = imply_vol(
+            underlying_price,
+            interest_rate,
+            cost_of_carry,
+            is_call,
+            strike,
+            expiry_in_years,
+            option_price,
+        );
+        if !vol.is_finite() {
+            anyhow::bail!(
+                "Cannot imply volatility for {instrument_id}: price {option_price} outside no-arbitrage bounds"
+            );
+        }
+
+        let BlackScholesGreeksResult {
+            price,
+            delta,
+            gamma,
+            vega,
+            theta,
+            rho,
+        } = if is_future_underlying {
+            black76_greeks(
+                underlying_price,
+                interest_rate,
+                vol,
+                is_call,
+                strike,
+                expiry_in_years,
+                multiplier,
+            )
+        } else {
+            black_scholes_greeks(
+                underlying_price,
+                interest_rate,
+                cost_of_carry,
+                vol,
+                is_call,
+                strike,
+                expiry_in_years,
+                multiplier,
+            )
+        };
+
+        Ok(OptionGreeks {
+            instrument_id: *instrument_id,
+            underlying_id,
+            is_call,
+            strike,
+            expiry_in_years,
+            underlying_price,
+            interest_rate,
+            vol,
+            price,
+            delta,
+            gamma,
+            vega,
+            theta,
+            rho,
+            ts_event: ts_now,
+        })
+    }
+
+    /// Aggregates the Greeks of the open positions (filtered by the optional `venue` and
+    /// `strategy_id`) at `ts_now`, keyed by underlying instrument ID.
+    ///
+    /// Positions in the underlying (or any other non-option instrument) contribute their delta
+    /// under their own instrument ID. Options whose Greeks cannot be calculated are logged and
+    /// excluded.
+    #[must_use]
+    pub fn portfolio_greeks(
+        &self,
+        venue: Option<&Venue>,
+        strategy_id: Option<&StrategyId>,
+        interest_rate: f64,
+        dividend_yield: f64,
+        ts_now: UnixNanos,
+    ) -> IndexMap<InstrumentId, PortfolioGreeks> {
+        let cache = self.cache.borrow();
+        let mut output: IndexMap<InstrumentId, PortfolioGreeks> = IndexMap::new();
+
+        for position in cache.positions_open(venue, None, strategy_id, None) {
+            let is_option = matches!(
+                cache.instrument(&position.instrument_id),
+                Some(InstrumentAny::OptionsContract(_))
+            );
+            if !is_option {
+                output
+                    .entry(position.instrument_id)
+                    .or_default()
+                    .add_linear(position.signed_qty, position.multiplier.as_f64());
+                continue;
+            }
+
+            match self.instrument_greeks(
+                &position.instrument_id,
+                interest_rate,
+                dividend_yield,
+                ts_now,
+            ) {
+                Ok(greeks) => output
+                    .entry(greeks.underlying_id)
+                    .or_default()
+                    .add_option(&greeks, position.signed_qty),
+                Err(e) => log::warn!("Excluding position {} from Greeks: {e}", position.id),
+            }
+        }
+
+        output
+    }
+}
+
+fn underlying_instrument_id(cache: &Cache, option: &OptionsContract) -> InstrumentId {
+    let symbol = Symbol::new(option.underlying);
+    let same_venue_id = InstrumentId::new(symbol, option.id.venue);
+    if cache.instrument(&same_venue_id).is_some() {
+        return same_venue_id;
+    }
+
+    cache
+        .instrument_ids(None)
+        .into_iter()
+        .find(|id| id.symbol == symbol)
+        .copied()
+        .unwrap_or(same_venue_id)
+}
+
+fn market_price(cache: &Cache, instrument_id: &InstrumentId) -> Option<f64> {
+    cache
+        .price(instrument_id, PriceType::Mid)
+        .or_else(|| cache.price(instrument_id, PriceType::Last))
+        .map(|price| price.as_f64())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::QuoteTick,
+        enums::{OmsType, OrderSide, OrderType},
+        identifiers::PositionId,
+        instruments::stubs::{equity_aapl, options_contract_appl},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        position::Position,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    const RATE: f64 = 0.05;
+    const VOL: f64 = 0.3;
+
+    fn add_quote(cache: &mut Cache, instrument_id: InstrumentId, price: Price) {
+        let quote = QuoteTick::new(
+            instrument_id,
+            price,
+            price,
+            Quantity::from(100),
+            Quantity::from(100),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        cache.add_quote(quote).unwrap();
+    }
+
+    fn add_position(
+        cache: &mut Cache,
+        instrument: &InstrumentAny,
+        side: OrderSide,
+        quantity: u64,
+        position_id: &str,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .build();
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::new(position_id)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(instrument, fill.into());
+        cache.add_position(position, OmsType::Netting).unwrap();
+    }
+
+    fn setup() -> (GreeksCalculator, OptionsContract, UnixNanos) {
+        let option = options_contract_appl();
+        let ts_now = option.activation_ns;
+        let expiry_in_years =
+            (option.expiration_ns.as_u64() - ts_now.as_u64()) as f64 / NANOSECONDS_IN_YEAR;
+        let option_price = black_scholes_greeks(
+            150.0,
+            RATE,
+            RATE,
+            VOL,
+            true,
+            option.strike_price.as_f64(),
+            expiry_in_years,
+            1.0,
+        )
+        .price;
+
+        let mut cache = Cache::default();
+        let equity = equity_aapl();
+        cache
+            .add_instrument(InstrumentAny::Equity(equity.clone()))
+            .unwrap();
+        cache
+            .add_instrument(InstrumentAny::OptionsContract(option.clone()))
+            .unwrap();
+        add_quote(&mut cache, equity.id, Price::from("150.00"));
+        add_quote(&mut cache, option.id, Price::new(option_price, 2));
+
+        let calculator = GreeksCalculator::new(Rc::new(RefCell::new(cache)));
+        (calculator, option, ts_now)
+    }
+
+    #[rstest]
+    fn test_instrument_greeks() {
+        let (calculator, option, ts_now) = setup();
+
+        let greeks = calculator
+            .instrument_greeks(&option.id, RATE, 0.0, ts_now)
+            .unwrap();
+
+        assert_eq!(greeks.underlying_id, InstrumentId::from("AAPL.XNAS"));
+        assert!(greeks.is_call);
+        assert_eq!(greeks.underlying_price, 150.0);
+        assert!((greeks.vol - VOL).abs() < 1e-3);
+        assert!(greeks.delta > 0.5 && greeks.delta < 1.0);
+        assert!(greeks.gamma > 0.0);
+        assert!(greeks.vega > 0.0);
+        assert!(greeks.theta < 0.0);
+        assert!(greeks.rho > 0.0);
+    }
+
+    #[rstest]
+    fn test_instrument_greeks_when_expired_errors() {
+        let (calculator, option, _) = setup();
+
+        let result = calculator.instrument_greeks(&option.id, RATE, 0.0, option.expiration_ns);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_instrument_greeks_when_not_an_option_errors() {
+        let (calculator, _, ts_now) = setup();
+
+        let result =
+            calculator.instrument_greeks(&InstrumentId::from("AAPL.XNAS"), RATE, 0.0, ts_now);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_portfolio_greeks_aggregates_by_underlying() {
+        let (calculator, option, ts_now) = setup();
+        {
+            let mut cache = calculator.cache.borrow_mut();
+            let option_any = InstrumentAny::OptionsContract(option.clone());
+            let equity_any = InstrumentAny::Equity(equity_aapl());
+            add_position(&mut cache, &option_any, OrderSide::Buy, 2, "P-1");
+            add_position(&mut cache, &equity_any, OrderSide::Sell, 1, "P-2");
+        }
+
+        let greeks = calculator
+            .instrument_greeks(&option.id, RATE, 0.0, ts_now)
+            .unwrap();
+        let portfolio_greeks = calculator.portfolio_greeks(None, None, RATE, 0.0, ts_now);
+
+        assert_eq!(portfolio_greeks.len(), 1);
+        let aapl = portfolio_greeks[&InstrumentId::from("AAPL.XNAS")];
+        assert!((aapl.delta - (2.0 * greeks.delta - 1.0)).abs() < 1e-9);
+        assert!((aapl.gamma - 2.0 * greeks.gamma).abs() < 1e-9);
+        assert!((aapl.vega - 2.0 * greeks.vega).abs() < 1e-9);
+    }
+}
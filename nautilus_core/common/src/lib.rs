@@ -33,17 +33,22 @@ pub mod cache;
 pub mod clock;
 pub mod component;
 pub mod custom;
+pub mod drift;
 pub mod enums;
 pub mod factories;
 pub mod generators;
+pub mod greeks;
 pub mod logging;
 pub mod messages;
 pub mod msgbus;
+pub mod profiling;
 pub mod runtime;
+pub mod schedule;
 pub mod signal;
 pub mod testing;
 pub mod throttler;
 pub mod timer;
+pub mod watchdog;
 pub mod xrate;
 
 #[cfg(feature = "ffi")]
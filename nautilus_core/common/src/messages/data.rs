@@ -13,40 +13,252 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{any::Any, collections::HashMap, sync::Arc};
+use std::{
+    any::Any,
+    collections::HashMap,
+    num::{NonZeroU64, NonZeroUsize},
+    str::FromStr,
+    sync::Arc,
+};
 
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use indexmap::IndexMap;
+use nautilus_core::{correctness::check_predicate_true, nanos::UnixNanos, uuid::UUID4};
 use nautilus_model::{
-    data::{Data, DataType},
-    identifiers::{ClientId, Venue},
+    data::{Bar, BarType, Data, DataType, QuoteTick, TradeTick},
+    enums::BookType,
+    identifiers::{ClientId, InstrumentId, Venue},
+    instruments::InstrumentAny,
+    orderbook::OrderBook,
 };
+use serde::{Deserialize, Serialize};
+
+use crate::custom::CustomData;
 
 // TODO: redesign data messages for a tighter model
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct DataRequest {
     pub correlation_id: UUID4,
     pub client_id: ClientId,
     pub venue: Venue,
     pub data_type: DataType,
+    /// UNIX timestamp (nanoseconds) when the request was initialized.
     pub ts_init: UnixNanos,
+    /// Adapter-specific options for the request (e.g. "depth" -> "50").
     pub params: Option<HashMap<String, String>>,
 }
 
+impl DataRequest {
+    /// Creates a new [`DataRequest`] instance.
+    #[must_use]
+    pub const fn new(
+        correlation_id: UUID4,
+        client_id: ClientId,
+        venue: Venue,
+        data_type: DataType,
+        ts_init: UnixNanos,
+        params: Option<HashMap<String, String>>,
+    ) -> Self {
+        Self {
+            correlation_id,
+            client_id,
+            venue,
+            data_type,
+            ts_init,
+            params,
+        }
+    }
+
+    /// Returns the value of the adapter-specific parameter with the given `key` (if set).
+    #[must_use]
+    pub fn param(&self, key: &str) -> Option<&str> {
+        get_param(self.params.as_ref(), key)
+    }
+
+    /// Sets the adapter-specific parameter `key` to `value`, returning the request.
+    #[must_use]
+    pub fn with_param(mut self, key: &str, value: &str) -> Self {
+        set_param(&mut self.params, key, value);
+        self
+    }
+}
+
+fn get_param<'a>(params: Option<&'a HashMap<String, String>>, key: &str) -> Option<&'a str> {
+    params
+        .and_then(|params| params.get(key))
+        .map(String::as_str)
+}
+
+fn set_param(params: &mut Option<HashMap<String, String>>, key: &str, value: &str) {
+    params
+        .get_or_insert_with(HashMap::new)
+        .insert(key.to_string(), value.to_string());
+}
+
 pub type Payload = Arc<dyn Any + Send + Sync>;
 
-#[derive(Debug)]
+/// Represents the typed payload of a [`DataResponse`].
+///
+/// Built-in data is carried with its concrete type, the `Custom` variant is the
+/// escape hatch for custom data types which must be downcast by the consumer.
+///
+/// The `Book` and `Custom` variants are process-local and cannot be serialized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DataResponsePayload {
+    Instrument(Box<InstrumentAny>),
+    Instruments(Vec<InstrumentAny>),
+    #[serde(skip)]
+    Book(Box<OrderBook>),
+    Quotes(Vec<QuoteTick>),
+    Trades(Vec<TradeTick>),
+    Bars(Vec<Bar>),
+    #[serde(skip)]
+    Custom(Payload),
+}
+
+impl DataResponsePayload {
+    /// Creates a new custom payload from the given `data`.
+    #[must_use]
+    pub fn custom<T: Any + Send + Sync>(data: T) -> Self {
+        Self::Custom(Arc::new(data))
+    }
+
+    /// Returns the instruments carried by the payload (if an instrument payload).
+    #[must_use]
+    pub fn as_instruments(&self) -> Option<&[InstrumentAny]> {
+        match self {
+            Self::Instrument(instrument) => Some(std::slice::from_ref(instrument.as_ref())),
+            Self::Instruments(instruments) => Some(instruments),
+            _ => None,
+        }
+    }
+
+    /// Returns the order book carried by the payload (if a book payload).
+    #[must_use]
+    pub fn as_book(&self) -> Option<&OrderBook> {
+        match self {
+            Self::Book(book) => Some(book),
+            _ => None,
+        }
+    }
+
+    /// Returns the quotes carried by the payload (if a quotes payload).
+    #[must_use]
+    pub fn as_quotes(&self) -> Option<&[QuoteTick]> {
+        match self {
+            Self::Quotes(quotes) => Some(quotes),
+            _ => None,
+        }
+    }
+
+    /// Returns the trades carried by the payload (if a trades payload).
+    #[must_use]
+    pub fn as_trades(&self) -> Option<&[TradeTick]> {
+        match self {
+            Self::Trades(trades) => Some(trades),
+            _ => None,
+        }
+    }
+
+    /// Returns the bars carried by the payload (if a bars payload).
+    #[must_use]
+    pub fn as_bars(&self) -> Option<&[Bar]> {
+        match self {
+            Self::Bars(bars) => Some(bars),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the custom data of type `T` (if a custom payload of that type).
+    #[must_use]
+    pub fn downcast_custom<T: Any + Send + Sync>(&self) -> Option<&T> {
+        match self {
+            Self::Custom(data) => data.downcast_ref::<T>(),
+            _ => None,
+        }
+    }
+}
+
+impl From<InstrumentAny> for DataResponsePayload {
+    fn from(value: InstrumentAny) -> Self {
+        Self::Instrument(Box::new(value))
+    }
+}
+
+impl From<Vec<InstrumentAny>> for DataResponsePayload {
+    fn from(value: Vec<InstrumentAny>) -> Self {
+        Self::Instruments(value)
+    }
+}
+
+impl From<OrderBook> for DataResponsePayload {
+    fn from(value: OrderBook) -> Self {
+        Self::Book(Box::new(value))
+    }
+}
+
+impl From<Vec<QuoteTick>> for DataResponsePayload {
+    fn from(value: Vec<QuoteTick>) -> Self {
+        Self::Quotes(value)
+    }
+}
+
+impl From<Vec<TradeTick>> for DataResponsePayload {
+    fn from(value: Vec<TradeTick>) -> Self {
+        Self::Trades(value)
+    }
+}
+
+impl From<Vec<Bar>> for DataResponsePayload {
+    fn from(value: Vec<Bar>) -> Self {
+        Self::Bars(value)
+    }
+}
+
+impl From<Vec<CustomData>> for DataResponsePayload {
+    fn from(value: Vec<CustomData>) -> Self {
+        Self::custom(value)
+    }
+}
+
+/// Represents the failure of a [`DataRequest`], delivered to the requester as the custom
+/// payload of a [`DataResponse`] in place of the requested data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataRequestFailure {
+    pub reason: String,
+}
+
+impl DataRequestFailure {
+    /// Creates a new [`DataRequestFailure`] instance.
+    #[must_use]
+    pub fn new(reason: &str) -> Self {
+        Self {
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl From<DataRequestFailure> for DataResponsePayload {
+    fn from(value: DataRequestFailure) -> Self {
+        Self::custom(value)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct DataResponse {
     pub correlation_id: UUID4,
     pub client_id: ClientId,
     pub venue: Venue,
     pub data_type: DataType,
-    pub data: Payload,
+    pub data: DataResponsePayload,
+    /// UNIX timestamp (nanoseconds) when the response was initialized.
     pub ts_init: UnixNanos,
+    /// Adapter-specific metadata for the response.
     pub params: Option<HashMap<String, String>>,
 }
 
 impl DataResponse {
-    pub fn new<T: Any + Send + Sync>(
+    /// Creates a new [`DataResponse`] instance.
+    pub fn new<T: Into<DataResponsePayload>>(
         correlation_id: UUID4,
         client_id: ClientId,
         venue: Venue,
@@ -60,20 +272,39 @@ impl DataResponse {
             client_id,
             venue,
             data_type,
-            data: Arc::new(data),
+            data: data.into(),
             ts_init,
             params,
         }
     }
+
+    /// Returns the failure of the request (if the response reports a failed request).
+    #[must_use]
+    pub fn failure(&self) -> Option<&DataRequestFailure> {
+        self.data.downcast_custom::<DataRequestFailure>()
+    }
+
+    /// Returns the value of the adapter-specific parameter with the given `key` (if set).
+    #[must_use]
+    pub fn param(&self, key: &str) -> Option<&str> {
+        get_param(self.params.as_ref(), key)
+    }
+
+    /// Sets the adapter-specific parameter `key` to `value`, returning the response.
+    #[must_use]
+    pub fn with_param(mut self, key: &str, value: &str) -> Self {
+        set_param(&mut self.params, key, value);
+        self
+    }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum Action {
     Subscribe,
     Unsubscribe,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubscriptionCommand {
     pub client_id: ClientId,
     pub venue: Venue,
@@ -108,6 +339,258 @@ impl SubscriptionCommand {
     }
 }
 
+/// Represents the data stream targeted by a [`SubscribeCommand`] or [`UnsubscribeCommand`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubscriptionKind {
+    /// Custom data of the given data type.
+    Data(DataType),
+    /// All instrument updates for a venue.
+    Instruments(Venue),
+    /// Instrument updates for a single instrument.
+    Instrument(InstrumentId),
+    /// Order book deltas for an instrument.
+    BookDeltas {
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        depth: Option<NonZeroUsize>,
+        managed: bool,
+    },
+    /// Order book snapshots for an instrument, published at the given interval.
+    BookSnapshots {
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        depth: Option<NonZeroUsize>,
+        interval_ms: NonZeroU64,
+    },
+    /// Quote ticks for an instrument.
+    Quotes(InstrumentId),
+    /// Trade ticks for an instrument.
+    Trades(InstrumentId),
+    /// Bars of the given bar type.
+    Bars(BarType),
+    /// Instrument status updates for an instrument.
+    InstrumentStatus(InstrumentId),
+    /// Instrument close prices for an instrument.
+    InstrumentClose(InstrumentId),
+    /// Funding rate updates for a perpetual swap instrument.
+    FundingRates(InstrumentId),
+    /// Mark price updates for an instrument.
+    MarkPrices(InstrumentId),
+    /// Index price updates for an instrument.
+    IndexPrices(InstrumentId),
+}
+
+impl SubscriptionKind {
+    /// Returns the instrument ID targeted by the subscription (if any).
+    #[must_use]
+    pub fn instrument_id(&self) -> Option<InstrumentId> {
+        match self {
+            Self::Data(data_type) => data_type
+                .metadata()
+                .and_then(|metadata| metadata.get("instrument_id"))
+                .and_then(|value| InstrumentId::from_str(value).ok()),
+            Self::Instruments(_) => None,
+            Self::Instrument(instrument_id)
+            | Self::BookDeltas { instrument_id, .. }
+            | Self::BookSnapshots { instrument_id, .. }
+            | Self::Quotes(instrument_id)
+            | Self::Trades(instrument_id)
+            | Self::InstrumentStatus(instrument_id)
+            | Self::InstrumentClose(instrument_id)
+            | Self::FundingRates(instrument_id)
+            | Self::MarkPrices(instrument_id)
+            | Self::IndexPrices(instrument_id) => Some(*instrument_id),
+            Self::Bars(bar_type) => Some(bar_type.instrument_id()),
+        }
+    }
+
+    /// Returns the equivalent [`DataType`] for the subscription, including its metadata.
+    #[must_use]
+    pub fn data_type(&self) -> DataType {
+        let instrument_metadata = |instrument_id: &InstrumentId| {
+            IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())])
+        };
+
+        match self {
+            Self::Data(data_type) => data_type.clone(),
+            Self::Instruments(venue) => DataType::new(
+                stringify!(InstrumentAny),
+                Some(IndexMap::from([("venue".to_string(), venue.to_string())])),
+            ),
+            Self::Instrument(instrument_id) => DataType::new(
+                stringify!(InstrumentAny),
+                Some(instrument_metadata(instrument_id)),
+            ),
+            Self::BookDeltas {
+                instrument_id,
+                book_type,
+                depth,
+                managed,
+            } => {
+                let mut metadata = instrument_metadata(instrument_id);
+                metadata.insert("book_type".to_string(), book_type.to_string());
+                if let Some(depth) = depth {
+                    metadata.insert("depth".to_string(), depth.to_string());
+                }
+                metadata.insert("managed".to_string(), managed.to_string());
+                DataType::new(stringify!(OrderBookDelta), Some(metadata))
+            }
+            Self::BookSnapshots {
+                instrument_id,
+                book_type,
+                depth,
+                interval_ms,
+            } => {
+                let mut metadata = instrument_metadata(instrument_id);
+                metadata.insert("book_type".to_string(), book_type.to_string());
+                if let Some(depth) = depth {
+                    metadata.insert("depth".to_string(), depth.to_string());
+                }
+                metadata.insert("interval_ms".to_string(), interval_ms.to_string());
+                metadata.insert("managed".to_string(), true.to_string());
+                DataType::new(stringify!(OrderBook), Some(metadata))
+            }
+            Self::Quotes(instrument_id) => DataType::new(
+                stringify!(QuoteTick),
+                Some(instrument_metadata(instrument_id)),
+            ),
+            Self::Trades(instrument_id) => DataType::new(
+                stringify!(TradeTick),
+                Some(instrument_metadata(instrument_id)),
+            ),
+            Self::Bars(bar_type) => DataType::new(
+                stringify!(Bar),
+                Some(IndexMap::from([(
+                    "bar_type".to_string(),
+                    bar_type.to_string(),
+                )])),
+            ),
+            Self::InstrumentStatus(instrument_id) => DataType::new(
+                stringify!(InstrumentStatus),
+                Some(instrument_metadata(instrument_id)),
+            ),
+            Self::InstrumentClose(instrument_id) => DataType::new(
+                stringify!(InstrumentClose),
+                Some(instrument_metadata(instrument_id)),
+            ),
+            Self::FundingRates(instrument_id) => DataType::new(
+                stringify!(FundingRateUpdate),
+                Some(instrument_metadata(instrument_id)),
+            ),
+            Self::MarkPrices(instrument_id) => DataType::new(
+                stringify!(MarkPriceUpdate),
+                Some(instrument_metadata(instrument_id)),
+            ),
+            Self::IndexPrices(instrument_id) => DataType::new(
+                stringify!(IndexPriceUpdate),
+                Some(instrument_metadata(instrument_id)),
+            ),
+        }
+    }
+}
+
+/// Represents a command to subscribe to a data stream.
+///
+/// The command is routed to the data client with `client_id` if specified,
+/// otherwise to the client registered for the `venue`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubscribeCommand {
+    pub client_id: Option<ClientId>,
+    pub venue: Option<Venue>,
+    pub kind: SubscriptionKind,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+    pub params: Option<HashMap<String, String>>,
+}
+
+impl SubscribeCommand {
+    /// Creates a new [`SubscribeCommand`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if both `client_id` and `venue` are `None`.
+    pub fn new(
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        kind: SubscriptionKind,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+        params: Option<HashMap<String, String>>,
+    ) -> anyhow::Result<Self> {
+        check_routing(client_id, venue)?;
+
+        Ok(Self {
+            client_id,
+            venue,
+            kind,
+            command_id,
+            ts_init,
+            params,
+        })
+    }
+
+    /// Returns the equivalent [`DataType`] for the subscription.
+    #[must_use]
+    pub fn data_type(&self) -> DataType {
+        self.kind.data_type()
+    }
+}
+
+/// Represents a command to unsubscribe from a data stream.
+///
+/// The command is routed to the data client with `client_id` if specified,
+/// otherwise to the client registered for the `venue`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnsubscribeCommand {
+    pub client_id: Option<ClientId>,
+    pub venue: Option<Venue>,
+    pub kind: SubscriptionKind,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+    pub params: Option<HashMap<String, String>>,
+}
+
+impl UnsubscribeCommand {
+    /// Creates a new [`UnsubscribeCommand`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if both `client_id` and `venue` are `None`.
+    pub fn new(
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        kind: SubscriptionKind,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+        params: Option<HashMap<String, String>>,
+    ) -> anyhow::Result<Self> {
+        check_routing(client_id, venue)?;
+
+        Ok(Self {
+            client_id,
+            venue,
+            kind,
+            command_id,
+            ts_init,
+            params,
+        })
+    }
+
+    /// Returns the equivalent [`DataType`] for the subscription.
+    #[must_use]
+    pub fn data_type(&self) -> DataType {
+        self.kind.data_type()
+    }
+}
+
+fn check_routing(client_id: Option<ClientId>, venue: Option<Venue>) -> anyhow::Result<()> {
+    check_predicate_true(
+        client_id.is_some() || venue.is_some(),
+        "Both `client_id` and `venue` were None",
+    )
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub enum DataCommand {
     Request(DataRequest),
     Subscribe(SubscriptionCommand),
@@ -115,7 +598,212 @@ pub enum DataCommand {
 
 // TODO: Refine this to reduce disparity between enum sizes
 #[allow(clippy::large_enum_variant)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum DataEvent {
     Response(DataResponse),
     Data(Data),
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::stubs::{quote_ethusdt_binance, stub_bar},
+        identifiers::stubs::instrument_id_aud_usd_sim,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_payload_from_quotes() {
+        let quote = quote_ethusdt_binance();
+        let payload = DataResponsePayload::from(vec![quote]);
+
+        assert_eq!(payload.as_quotes(), Some([quote].as_slice()));
+        assert!(payload.as_trades().is_none());
+        assert!(payload.as_bars().is_none());
+    }
+
+    #[rstest]
+    fn test_payload_from_bars() {
+        let bar = stub_bar();
+        let payload = DataResponsePayload::from(vec![bar]);
+
+        assert_eq!(payload.as_bars(), Some([bar].as_slice()));
+        assert!(payload.as_quotes().is_none());
+    }
+
+    #[rstest]
+    fn test_payload_custom_downcast() {
+        let payload = DataResponsePayload::custom(42_u64);
+
+        assert_eq!(payload.downcast_custom::<u64>(), Some(&42));
+        assert!(payload.downcast_custom::<String>().is_none());
+        assert!(payload.as_instruments().is_none());
+    }
+
+    #[rstest]
+    fn test_response_new_converts_payload() {
+        let quote = quote_ethusdt_binance();
+        let response = DataResponse::new(
+            UUID4::new(),
+            ClientId::from("BINANCE"),
+            Venue::from("BINANCE"),
+            DataType::new(stringify!(QuoteTick), None),
+            vec![quote],
+            UnixNanos::default(),
+            None,
+        );
+
+        assert!(matches!(response.data, DataResponsePayload::Quotes(_)));
+        assert!(response.failure().is_none());
+    }
+
+    #[rstest]
+    fn test_response_failure() {
+        let response = DataResponse::new(
+            UUID4::new(),
+            ClientId::from("BINANCE"),
+            Venue::from("BINANCE"),
+            DataType::new(stringify!(QuoteTick), None),
+            DataRequestFailure::new("Request timed out"),
+            UnixNanos::default(),
+            None,
+        );
+
+        assert_eq!(
+            response.failure(),
+            Some(&DataRequestFailure::new("Request timed out"))
+        );
+    }
+
+    #[rstest]
+    fn test_request_params() {
+        let request = DataRequest::new(
+            UUID4::new(),
+            ClientId::from("BINANCE"),
+            Venue::from("BINANCE"),
+            DataType::new(stringify!(OrderBook), None),
+            UnixNanos::from(1),
+            None,
+        );
+
+        assert!(request.param("depth").is_none());
+
+        let request = request.with_param("depth", "50");
+
+        assert_eq!(request.param("depth"), Some("50"));
+        assert_eq!(request.ts_init, UnixNanos::from(1));
+    }
+
+    #[rstest]
+    fn test_response_params() {
+        let response = DataResponse::new(
+            UUID4::new(),
+            ClientId::from("BINANCE"),
+            Venue::from("BINANCE"),
+            DataType::new(stringify!(QuoteTick), None),
+            Vec::<QuoteTick>::new(),
+            UnixNanos::default(),
+            Some(HashMap::from([("source".to_string(), "rest".to_string())])),
+        )
+        .with_param("page", "2");
+
+        assert_eq!(response.param("source"), Some("rest"));
+        assert_eq!(response.param("page"), Some("2"));
+    }
+
+    #[rstest]
+    fn test_subscribe_command_requires_routing() {
+        let result = SubscribeCommand::new(
+            None,
+            None,
+            SubscriptionKind::Quotes(instrument_id_aud_usd_sim()),
+            UUID4::new(),
+            UnixNanos::default(),
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_subscribe_book_snapshots_data_type() {
+        let instrument_id = instrument_id_aud_usd_sim();
+        let cmd = SubscribeCommand::new(
+            None,
+            Some(instrument_id.venue),
+            SubscriptionKind::BookSnapshots {
+                instrument_id,
+                book_type: BookType::L2_MBP,
+                depth: NonZeroUsize::new(10),
+                interval_ms: NonZeroU64::new(1_000).unwrap(),
+            },
+            UUID4::new(),
+            UnixNanos::default(),
+            None,
+        )
+        .unwrap();
+
+        let data_type = cmd.data_type();
+        assert_eq!(data_type.type_name(), "OrderBook");
+        assert_eq!(data_type.instrument_id(), Some(instrument_id));
+        assert_eq!(data_type.book_type(), BookType::L2_MBP);
+        assert_eq!(data_type.depth(), Some(10));
+        assert_eq!(data_type.interval_ms().get(), 1_000);
+        assert_eq!(cmd.kind.instrument_id(), Some(instrument_id));
+    }
+
+    #[rstest]
+    fn test_unsubscribe_bars_data_type() {
+        let bar = stub_bar();
+        let cmd = UnsubscribeCommand::new(
+            Some(ClientId::from("SIM")),
+            None,
+            SubscriptionKind::Bars(bar.bar_type),
+            UUID4::new(),
+            UnixNanos::default(),
+            None,
+        )
+        .unwrap();
+
+        let data_type = cmd.data_type();
+        assert_eq!(data_type.type_name(), "Bar");
+        assert_eq!(data_type.bar_type(), bar.bar_type);
+        assert_eq!(cmd.kind.instrument_id(), Some(bar.bar_type.instrument_id()));
+    }
+
+    #[rstest]
+    fn test_subscribe_instruments_has_no_instrument_id() {
+        let kind = SubscriptionKind::Instruments(Venue::from("SIM"));
+
+        assert!(kind.instrument_id().is_none());
+        assert_eq!(kind.data_type().venue(), Some(Venue::from("SIM")));
+    }
+
+    #[rstest]
+    fn test_subscribe_funding_rates_data_type() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let kind = SubscriptionKind::FundingRates(instrument_id);
+
+        let data_type = kind.data_type();
+        assert_eq!(data_type.type_name(), "FundingRateUpdate");
+        assert_eq!(data_type.instrument_id(), Some(instrument_id));
+        assert_eq!(kind.instrument_id(), Some(instrument_id));
+    }
+
+    #[rstest]
+    fn test_subscribe_mark_and_index_prices_data_types() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+
+        let mark = SubscriptionKind::MarkPrices(instrument_id).data_type();
+        let index = SubscriptionKind::IndexPrices(instrument_id).data_type();
+        assert_eq!(mark.type_name(), "MarkPriceUpdate");
+        assert_eq!(index.type_name(), "IndexPriceUpdate");
+        assert_eq!(mark.instrument_id(), Some(instrument_id));
+        assert_eq!(index.instrument_id(), Some(instrument_id));
+    }
+}
@@ -14,6 +14,9 @@
 // -------------------------------------------------------------------------------------------------
 
 //! Execution specific messages such as order commands.
+//!
+//! These commands form the shared vocabulary between the execution engine, risk engine
+//! and execution clients.
 
 pub mod cancel;
 pub mod cancel_all;
@@ -23,18 +26,25 @@ pub mod query;
 pub mod submit;
 pub mod submit_list;
 
+use nautilus_core::nanos::UnixNanos;
 use nautilus_model::identifiers::{ClientId, InstrumentId};
+use serde::{Deserialize, Serialize};
 use strum::Display;
 
 // Re-exports
 pub use self::{
-    cancel::CancelOrder, cancel_all::CancelAllOrders, cancel_batch::BatchCancelOrders,
-    modify::ModifyOrder, query::QueryOrder, submit::SubmitOrder, submit_list::SubmitOrderList,
+    cancel::CancelOrder,
+    cancel_all::CancelAllOrders,
+    cancel_batch::BatchCancelOrders,
+    modify::ModifyOrder,
+    query::QueryOrder,
+    submit::{RoutingInstructions, SubmitOrder},
+    submit_list::SubmitOrderList,
 };
 
 // TODO
 #[allow(clippy::large_enum_variant)]
-#[derive(Clone, Debug, Display)]
+#[derive(Clone, Debug, Display, Serialize, Deserialize)]
 pub enum TradingCommand {
     SubmitOrder(SubmitOrder),
     SubmitOrderList(SubmitOrderList),
@@ -71,4 +81,17 @@ impl TradingCommand {
             Self::QueryOrder(command) => command.instrument_id,
         }
     }
+
+    #[must_use]
+    pub const fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::SubmitOrder(command) => command.ts_init,
+            Self::SubmitOrderList(command) => command.ts_init,
+            Self::ModifyOrder(command) => command.ts_init,
+            Self::CancelOrder(command) => command.ts_init,
+            Self::CancelAllOrders(command) => command.ts_init,
+            Self::BatchCancelOrders(command) => command.ts_init,
+            Self::QueryOrder(command) => command.ts_init,
+        }
+    }
 }
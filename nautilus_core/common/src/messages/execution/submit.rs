@@ -19,12 +19,41 @@ use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 use nautilus_model::{
     identifiers::{
         ClientId, ClientOrderId, ExecAlgorithmId, InstrumentId, PositionId, StrategyId, TraderId,
-        VenueOrderId,
+        Venue, VenueOrderId,
     },
     orders::OrderAny,
 };
 use serde::{Deserialize, Serialize};
 
+/// Per-order routing instructions which override the execution engines routing policy.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct RoutingInstructions {
+    /// The explicit destination venue for the order (takes precedence over any policy).
+    pub venue: Option<Venue>,
+    /// The venues which should not be considered when routing the order.
+    pub excluded_venues: Vec<Venue>,
+}
+
+impl RoutingInstructions {
+    /// Creates new [`RoutingInstructions`] which route directly to the given `venue`.
+    #[must_use]
+    pub const fn to_venue(venue: Venue) -> Self {
+        Self {
+            venue: Some(venue),
+            excluded_venues: Vec::new(),
+        }
+    }
+
+    /// Creates new [`RoutingInstructions`] which exclude the given `venues` from routing.
+    #[must_use]
+    pub const fn excluding(venues: Vec<Venue>) -> Self {
+        Self {
+            venue: None,
+            excluded_venues: venues,
+        }
+    }
+}
+
 // Fix: equality and default and builder
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 // #[builder(default)]
@@ -39,6 +68,7 @@ pub struct SubmitOrder {
     pub order: OrderAny,
     pub exec_algorith_id: Option<ExecAlgorithmId>,
     pub position_id: Option<PositionId>,
+    pub routing: Option<RoutingInstructions>,
     pub command_id: UUID4,
     pub ts_init: UnixNanos,
 }
@@ -69,10 +99,18 @@ impl SubmitOrder {
             order,
             exec_algorith_id,
             position_id,
+            routing: None,
             command_id,
             ts_init,
         })
     }
+
+    /// Sets the routing instructions for the command.
+    #[must_use]
+    pub fn with_routing(mut self, routing: RoutingInstructions) -> Self {
+        self.routing = Some(routing);
+        self
+    }
 }
 
 impl Display for SubmitOrder {
@@ -14,3 +14,11 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod data;
+pub mod execution;
+pub mod risk;
+pub mod split;
+pub mod tracker;
+pub mod wire;
+
+// Re-exports
+pub use nautilus_model::events::{OrderEventAny, OrderEventType};
@@ -0,0 +1,68 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Risk specific messages such as trading state commands.
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{enums::TradingState, identifiers::TraderId};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+/// Command to change the trading state of the risk engine at runtime.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub struct SetTradingState {
+    pub trader_id: TraderId,
+    pub trading_state: TradingState,
+    /// If all open orders should be canceled when the trading state is set to `HALTED`.
+    pub cancel_open_orders: bool,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+impl SetTradingState {
+    /// Creates a new [`SetTradingState`] instance.
+    #[must_use]
+    pub const fn new(
+        trader_id: TraderId,
+        trading_state: TradingState,
+        cancel_open_orders: bool,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            trader_id,
+            trading_state,
+            cancel_open_orders,
+            command_id,
+            ts_init,
+        }
+    }
+}
+
+impl std::fmt::Display for SetTradingState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SetTradingState(trading_state={}, cancel_open_orders={})",
+            self.trading_state, self.cancel_open_orders,
+        )
+    }
+}
+
+#[derive(Clone, Debug, Display, Serialize, Deserialize)]
+pub enum RiskCommand {
+    SetTradingState(SetTradingState),
+}
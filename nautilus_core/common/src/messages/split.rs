@@ -0,0 +1,606 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Splitting of large data requests into sub-requests.
+//!
+//! Venue APIs commonly cap the size of a single historical data response, so a request
+//! spanning a large time range must be chunked. A [`RequestSplitter`] divides a
+//! [`DataRequest`] into child requests by time window (or row limit for time bars), tracks
+//! the child correlation IDs, and reassembles the child responses in time order into a
+//! single aggregate [`DataResponse`] for the parent request.
+
+use std::{
+    collections::HashMap,
+    num::{NonZeroU64, NonZeroUsize},
+};
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::data::{bar::get_bar_interval_ns, DataType};
+
+use super::data::{DataRequest, DataResponse, DataResponsePayload};
+
+/// The strategy for splitting a data request into sub-requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitStrategy {
+    /// Split into consecutive time windows of the given duration (nanoseconds).
+    TimeWindow(NonZeroU64),
+    /// Split so that each sub-request returns at most the given number of rows.
+    ///
+    /// Only supported for time aggregated bar requests, where the row count for a time
+    /// window is known ahead of time.
+    RowLimit(NonZeroUsize),
+}
+
+impl SplitStrategy {
+    fn window_ns(&self, data_type: &DataType) -> anyhow::Result<u64> {
+        match self {
+            Self::TimeWindow(window_ns) => Ok(window_ns.get()),
+            Self::RowLimit(rows) => {
+                if data_type.type_name() != stringify!(Bar) {
+                    anyhow::bail!(
+                        "Cannot split {} request by row limit, only time bars are supported",
+                        data_type.type_name()
+                    );
+                }
+                let bar_type = data_type.bar_type();
+                if !bar_type.spec().is_time_aggregated() {
+                    anyhow::bail!("Cannot split {bar_type} request by row limit, not time bars");
+                }
+                get_bar_interval_ns(&bar_type)
+                    .as_u64()
+                    .checked_mul(rows.get() as u64)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Cannot split {bar_type} request by row limit {rows}, window overflows"
+                        )
+                    })
+            }
+        }
+    }
+}
+
+/// Represents a parent data request which was split into child requests.
+#[derive(Debug)]
+pub struct SplitRequest {
+    /// The correlation ID of the parent request.
+    pub correlation_id: UUID4,
+    /// The data type of the parent request.
+    pub data_type: DataType,
+    children: Vec<UUID4>,
+    responses: HashMap<UUID4, DataResponse>,
+}
+
+impl SplitRequest {
+    /// Returns the child correlation IDs in time order.
+    #[must_use]
+    pub fn child_ids(&self) -> &[UUID4] {
+        &self.children
+    }
+
+    /// Returns whether responses for all child requests have been received.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.responses.len() == self.children.len()
+    }
+
+    fn assemble(mut self) -> anyhow::Result<DataResponse> {
+        let mut data: Option<DataResponsePayload> = None;
+        let mut last: Option<DataResponse> = None;
+
+        for child_id in &self.children {
+            let response = self
+                .responses
+                .remove(child_id)
+                .ok_or_else(|| anyhow::anyhow!("No response for child request {child_id}"))?;
+            let payload = response.data.clone();
+            data = Some(match data {
+                None => payload,
+                Some(acc) => concat_payloads(acc, payload)?,
+            });
+            last = Some(response);
+        }
+
+        let last = last.ok_or_else(|| anyhow::anyhow!("No child requests"))?;
+        Ok(DataResponse::new(
+            self.correlation_id,
+            last.client_id,
+            last.venue,
+            self.data_type,
+            data.expect("Payload was assembled"),
+            last.ts_init,
+            last.params,
+        ))
+    }
+}
+
+fn concat_payloads(
+    acc: DataResponsePayload,
+    next: DataResponsePayload,
+) -> anyhow::Result<DataResponsePayload> {
+    use DataResponsePayload::{Bars, Instrument, Instruments, Quotes, Trades};
+
+    Ok(match (acc, next) {
+        (Quotes(mut acc), Quotes(next)) => {
+            acc.extend(next);
+            Quotes(acc)
+        }
+        (Trades(mut acc), Trades(next)) => {
+            acc.extend(next);
+            Trades(acc)
+        }
+        (Bars(mut acc), Bars(next)) => {
+            acc.extend(next);
+            Bars(acc)
+        }
+        (Instrument(acc), Instrument(next)) => Instruments(vec![*acc, *next]),
+        (Instrument(acc), Instruments(next)) => {
+            Instruments(std::iter::once(*acc).chain(next).collect())
+        }
+        (Instruments(mut acc), Instrument(next)) => {
+            acc.push(*next);
+            Instruments(acc)
+        }
+        (Instruments(mut acc), Instruments(next)) => {
+            acc.extend(next);
+            Instruments(acc)
+        }
+        _ => anyhow::bail!("Cannot concatenate mismatched or process-local payloads"),
+    })
+}
+
+/// Splits data requests into child requests and reassembles their responses.
+#[derive(Debug)]
+pub struct RequestSplitter {
+    max_children: NonZeroUsize,
+    splits: HashMap<UUID4, SplitRequest>,
+    child_to_parent: HashMap<UUID4, UUID4>,
+}
+
+impl RequestSplitter {
+    /// Creates a new [`RequestSplitter`] instance, which splits a request into at most
+    /// `max_children` child requests.
+    #[must_use]
+    pub fn new(max_children: NonZeroUsize) -> Self {
+        Self {
+            max_children,
+            splits: HashMap::new(),
+            child_to_parent: HashMap::new(),
+        }
+    }
+
+    /// Splits the given `request` into child requests according to the `strategy`, and
+    /// tracks the children for reassembly.
+    ///
+    /// The child requests cover consecutive non-overlapping time windows from the parent
+    /// `start` to `end` (inclusive), and are returned in time order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The request does not have both a `start` and `end` in its data type metadata.
+    /// - The `start` is after the `end`.
+    /// - The request is already being split.
+    /// - The `strategy` is not supported for the requested data type.
+    /// - The request would be split into more than the maximum number of child requests.
+    pub fn split(
+        &mut self,
+        request: &DataRequest,
+        strategy: SplitStrategy,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Vec<DataRequest>> {
+        let data_type = &request.data_type;
+        let (Some(start), Some(end)) = (data_type.start(), data_type.end()) else {
+            anyhow::bail!("Cannot split request without both 'start' and 'end' in metadata");
+        };
+        if start > end {
+            anyhow::bail!("Cannot split request with 'start' {start} after 'end' {end}");
+        }
+        if self.splits.contains_key(&request.correlation_id) {
+            anyhow::bail!(
+                "Request with correlation ID {} already being split",
+                request.correlation_id
+            );
+        }
+
+        let window_ns = strategy.window_ns(data_type)?;
+        let child_count = (end.as_u64() - start.as_u64()) / window_ns + 1;
+        if child_count > self.max_children.get() as u64 {
+            anyhow::bail!(
+                "Cannot split request into {child_count} sub-requests, maximum is {}",
+                self.max_children
+            );
+        }
+        let metadata = data_type.metadata().cloned().unwrap_or_default();
+
+        let mut children = Vec::with_capacity(child_count as usize);
+        let mut window_start = start.as_u64();
+        loop {
+            let window_end = window_start.saturating_add(window_ns - 1).min(end.as_u64());
+
+            let mut child_metadata = metadata.clone();
+            child_metadata.insert("start".to_string(), window_start.to_string());
+            child_metadata.insert("end".to_string(), window_end.to_string());
+
+            children.push(DataRequest {
+                correlation_id: UUID4::new(),
+                client_id: request.client_id,
+                venue: request.venue,
+                data_type: DataType::new(data_type.type_name(), Some(child_metadata)),
+                ts_init,
+                params: request.params.clone(),
+            });
+
+            if window_end >= end.as_u64() {
+                break;
+            }
+            window_start = window_end + 1;
+        }
+
+        let child_ids: Vec<UUID4> = children.iter().map(|child| child.correlation_id).collect();
+        for child_id in &child_ids {
+            self.child_to_parent
+                .insert(*child_id, request.correlation_id);
+        }
+        self.splits.insert(
+            request.correlation_id,
+            SplitRequest {
+                correlation_id: request.correlation_id,
+                data_type: data_type.clone(),
+                children: child_ids,
+                responses: HashMap::new(),
+            },
+        );
+
+        Ok(children)
+    }
+
+    /// Returns whether the given `correlation_id` is for a tracked child request.
+    #[must_use]
+    pub fn is_child(&self, correlation_id: &UUID4) -> bool {
+        self.child_to_parent.contains_key(correlation_id)
+    }
+
+    /// Returns the parent correlation ID for the given child `correlation_id` (if tracked).
+    #[must_use]
+    pub fn parent_id(&self, correlation_id: &UUID4) -> Option<UUID4> {
+        self.child_to_parent.get(correlation_id).copied()
+    }
+
+    /// Returns the split request for the given parent `correlation_id` (if found).
+    #[must_use]
+    pub fn get(&self, correlation_id: &UUID4) -> Option<&SplitRequest> {
+        self.splits.get(correlation_id)
+    }
+
+    /// Handles the given child `response`.
+    ///
+    /// Returns the aggregate response for the parent request once responses for all
+    /// children have been received, otherwise `None`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The response is not for a tracked child request.
+    /// - The child payloads cannot be concatenated (mismatched or process-local payloads),
+    ///   in which case the parent request is discarded.
+    pub fn handle_response(
+        &mut self,
+        response: DataResponse,
+    ) -> anyhow::Result<Option<DataResponse>> {
+        let parent_id = self
+            .child_to_parent
+            .remove(&response.correlation_id)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "No split request for child correlation ID {}",
+                    response.correlation_id
+                )
+            })?;
+
+        let split = self
+            .splits
+            .get_mut(&parent_id)
+            .expect("Split request should exist for child");
+        split.responses.insert(response.correlation_id, response);
+
+        if !split.is_complete() {
+            return Ok(None);
+        }
+
+        let split = self
+            .splits
+            .remove(&parent_id)
+            .expect("Split request exists");
+        split.assemble().map(Some)
+    }
+
+    /// Discards the split request for the given parent `correlation_id`, along with any
+    /// responses received so far.
+    ///
+    /// Returns the discarded split request (if found).
+    pub fn discard(&mut self, correlation_id: &UUID4) -> Option<SplitRequest> {
+        let split = self.splits.remove(correlation_id)?;
+        for child_id in &split.children {
+            self.child_to_parent.remove(child_id);
+        }
+        Some(split)
+    }
+
+    /// Returns the number of parent requests awaiting child responses.
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.splits.len()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+    use nautilus_model::{
+        data::{stubs::quote_audusd, QuoteTick},
+        identifiers::{ClientId, Venue},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    const MINUTE_NS: u64 = 60_000_000_000;
+
+    fn request(type_name: &str, key: &str, value: &str, start: u64, end: u64) -> DataRequest {
+        let metadata = IndexMap::from([
+            (key.to_string(), value.to_string()),
+            ("start".to_string(), start.to_string()),
+            ("end".to_string(), end.to_string()),
+        ]);
+        DataRequest {
+            correlation_id: UUID4::new(),
+            client_id: ClientId::new("SIM"),
+            venue: Venue::new("SIM"),
+            data_type: DataType::new(type_name, Some(metadata)),
+            ts_init: UnixNanos::default(),
+            params: None,
+        }
+    }
+
+    fn quotes_request(start: u64, end: u64) -> DataRequest {
+        request("QuoteTick", "instrument_id", "AUD/USD.SIM", start, end)
+    }
+
+    fn quotes_response(child: &DataRequest, ts: u64) -> DataResponse {
+        let mut quote = quote_audusd();
+        quote.ts_event = UnixNanos::from(ts);
+        quote.ts_init = UnixNanos::from(ts);
+        DataResponse::new(
+            child.correlation_id,
+            child.client_id,
+            child.venue,
+            child.data_type.clone(),
+            vec![quote],
+            UnixNanos::from(ts),
+            None,
+        )
+    }
+
+    #[fixture]
+    fn splitter() -> RequestSplitter {
+        RequestSplitter::new(NonZeroUsize::new(100).unwrap())
+    }
+
+    #[rstest]
+    fn test_split_by_time_window(mut splitter: RequestSplitter) {
+        let parent = quotes_request(0, 249);
+        let strategy = SplitStrategy::TimeWindow(NonZeroU64::new(100).unwrap());
+
+        let children = splitter
+            .split(&parent, strategy, UnixNanos::default())
+            .unwrap();
+
+        let windows: Vec<(u64, u64)> = children
+            .iter()
+            .map(|c| {
+                (
+                    c.data_type.start().unwrap().as_u64(),
+                    c.data_type.end().unwrap().as_u64(),
+                )
+            })
+            .collect();
+        assert_eq!(windows, vec![(0, 99), (100, 199), (200, 249)]);
+        assert_eq!(
+            children[0].data_type.instrument_id(),
+            parent.data_type.instrument_id()
+        );
+        assert!(children
+            .iter()
+            .all(|c| splitter.is_child(&c.correlation_id)));
+        assert_eq!(
+            splitter
+                .get(&parent.correlation_id)
+                .unwrap()
+                .child_ids()
+                .len(),
+            3
+        );
+    }
+
+    #[rstest]
+    fn test_split_range_within_window_gives_single_child(mut splitter: RequestSplitter) {
+        let parent = quotes_request(10, 20);
+        let strategy = SplitStrategy::TimeWindow(NonZeroU64::new(100).unwrap());
+
+        let children = splitter
+            .split(&parent, strategy, UnixNanos::default())
+            .unwrap();
+
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].data_type.start(), Some(UnixNanos::from(10)));
+        assert_eq!(children[0].data_type.end(), Some(UnixNanos::from(20)));
+    }
+
+    #[rstest]
+    fn test_split_by_row_limit_for_bars(mut splitter: RequestSplitter) {
+        let parent = request(
+            "Bar",
+            "bar_type",
+            "AUD/USD.SIM-1-MINUTE-BID-EXTERNAL",
+            0,
+            25 * MINUTE_NS - 1,
+        );
+        let strategy = SplitStrategy::RowLimit(NonZeroUsize::new(10).unwrap());
+
+        let children = splitter
+            .split(&parent, strategy, UnixNanos::default())
+            .unwrap();
+
+        assert_eq!(children.len(), 3);
+        assert_eq!(
+            children[1].data_type.start(),
+            Some(UnixNanos::from(10 * MINUTE_NS))
+        );
+    }
+
+    #[rstest]
+    fn test_split_by_row_limit_for_quotes_errors(mut splitter: RequestSplitter) {
+        let parent = quotes_request(0, 100);
+        let strategy = SplitStrategy::RowLimit(NonZeroUsize::new(10).unwrap());
+
+        let result = splitter.split(&parent, strategy, UnixNanos::default());
+
+        assert!(result.is_err());
+        assert_eq!(splitter.pending_count(), 0);
+    }
+
+    #[rstest]
+    fn test_split_by_row_limit_with_overflowing_window_errors(mut splitter: RequestSplitter) {
+        let parent = request(
+            "Bar",
+            "bar_type",
+            "AUD/USD.SIM-1-MINUTE-BID-EXTERNAL",
+            0,
+            MINUTE_NS,
+        );
+        let strategy = SplitStrategy::RowLimit(NonZeroUsize::new(usize::MAX).unwrap());
+
+        let result = splitter.split(&parent, strategy, UnixNanos::default());
+
+        assert!(result.is_err());
+        assert_eq!(splitter.pending_count(), 0);
+    }
+
+    #[rstest]
+    fn test_split_exceeding_max_children_errors(mut splitter: RequestSplitter) {
+        let parent = quotes_request(0, 100 * 100);
+        let strategy = SplitStrategy::TimeWindow(NonZeroU64::new(100).unwrap());
+
+        let result = splitter.split(&parent, strategy, UnixNanos::default());
+
+        assert!(result.is_err());
+        assert_eq!(splitter.pending_count(), 0);
+    }
+
+    #[rstest]
+    fn test_split_at_max_children(mut splitter: RequestSplitter) {
+        let parent = quotes_request(0, 100 * 100 - 1);
+        let strategy = SplitStrategy::TimeWindow(NonZeroU64::new(100).unwrap());
+
+        let children = splitter
+            .split(&parent, strategy, UnixNanos::default())
+            .unwrap();
+
+        assert_eq!(children.len(), 100);
+    }
+
+    #[rstest]
+    fn test_split_without_range_errors(mut splitter: RequestSplitter) {
+        let mut parent = quotes_request(0, 100);
+        parent.data_type = DataType::new(
+            "QuoteTick",
+            Some(IndexMap::from([(
+                "instrument_id".to_string(),
+                "AUD/USD.SIM".to_string(),
+            )])),
+        );
+        let strategy = SplitStrategy::TimeWindow(NonZeroU64::new(100).unwrap());
+
+        assert!(splitter
+            .split(&parent, strategy, UnixNanos::default())
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_reassembles_responses_in_order(mut splitter: RequestSplitter) {
+        let parent = quotes_request(0, 299);
+        let strategy = SplitStrategy::TimeWindow(NonZeroU64::new(100).unwrap());
+        let children = splitter
+            .split(&parent, strategy, UnixNanos::default())
+            .unwrap();
+
+        // Responses arrive out of order
+        let r1 = splitter
+            .handle_response(quotes_response(&children[2], 200))
+            .unwrap();
+        let r2 = splitter
+            .handle_response(quotes_response(&children[0], 0))
+            .unwrap();
+        let aggregate = splitter
+            .handle_response(quotes_response(&children[1], 100))
+            .unwrap()
+            .unwrap();
+
+        assert!(r1.is_none());
+        assert!(r2.is_none());
+        assert_eq!(aggregate.correlation_id, parent.correlation_id);
+        assert_eq!(aggregate.data_type, parent.data_type);
+        let ts: Vec<u64> = aggregate
+            .data
+            .as_quotes()
+            .unwrap()
+            .iter()
+            .map(|q: &QuoteTick| q.ts_event.as_u64())
+            .collect();
+        assert_eq!(ts, vec![0, 100, 200]);
+        assert_eq!(splitter.pending_count(), 0);
+        assert!(!splitter.is_child(&children[0].correlation_id));
+    }
+
+    #[rstest]
+    fn test_handle_response_for_unknown_child_errors(mut splitter: RequestSplitter) {
+        let request = quotes_request(0, 10);
+
+        let result = splitter.handle_response(quotes_response(&request, 0));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_discard(mut splitter: RequestSplitter) {
+        let parent = quotes_request(0, 199);
+        let strategy = SplitStrategy::TimeWindow(NonZeroU64::new(100).unwrap());
+        let children = splitter
+            .split(&parent, strategy, UnixNanos::default())
+            .unwrap();
+
+        assert_eq!(
+            splitter.parent_id(&children[1].correlation_id),
+            Some(parent.correlation_id)
+        );
+        assert!(splitter.discard(&parent.correlation_id).is_some());
+        assert!(splitter.discard(&parent.correlation_id).is_none());
+        assert!(!splitter.is_child(&children[0].correlation_id));
+        assert_eq!(splitter.pending_count(), 0);
+    }
+}
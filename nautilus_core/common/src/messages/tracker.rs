@@ -0,0 +1,358 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tracking of outstanding requests by correlation ID.
+//!
+//! A [`RequestTracker`] registers pending requests with a deadline, matches incoming
+//! responses by correlation ID, and fires timeout callbacks for requests which were not
+//! responded to in time.
+
+use std::{collections::HashMap, fmt::Debug, rc::Rc};
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+
+use super::data::DataResponse;
+
+pub type RequestTimeoutCallback = dyn Fn(UUID4);
+
+/// Represents a request awaiting a response.
+#[derive(Clone)]
+pub struct PendingRequest {
+    /// The correlation ID for the request.
+    pub correlation_id: UUID4,
+    /// UNIX timestamp (nanoseconds) when the request was registered.
+    pub ts_registered: UnixNanos,
+    /// UNIX timestamp (nanoseconds) after which the request times out.
+    pub deadline: UnixNanos,
+    callback: Option<Rc<RequestTimeoutCallback>>,
+}
+
+impl Debug for PendingRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(PendingRequest))
+            .field("correlation_id", &self.correlation_id)
+            .field("ts_registered", &self.ts_registered)
+            .field("deadline", &self.deadline)
+            .field("has_callback", &self.callback.is_some())
+            .finish()
+    }
+}
+
+/// Tracks outstanding requests by correlation ID until responded to, canceled or timed out.
+#[derive(Debug, Default)]
+pub struct RequestTracker {
+    pending: HashMap<UUID4, PendingRequest>,
+}
+
+impl RequestTracker {
+    /// Creates a new [`RequestTracker`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a pending request with the given `deadline`.
+    ///
+    /// The optional `callback` is called with the correlation ID if the request times out.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - A request with the same `correlation_id` is already pending.
+    /// - The `deadline` is before `ts_now`.
+    pub fn register(
+        &mut self,
+        correlation_id: UUID4,
+        ts_now: UnixNanos,
+        deadline: UnixNanos,
+        callback: Option<Rc<RequestTimeoutCallback>>,
+    ) -> anyhow::Result<()> {
+        if self.pending.contains_key(&correlation_id) {
+            anyhow::bail!("Request with correlation ID {correlation_id} already pending");
+        }
+        if deadline < ts_now {
+            anyhow::bail!("Request deadline {deadline} was before the current time {ts_now}");
+        }
+
+        self.pending.insert(
+            correlation_id,
+            PendingRequest {
+                correlation_id,
+                ts_registered: ts_now,
+                deadline,
+                callback,
+            },
+        );
+        Ok(())
+    }
+
+    /// Resolves the pending request with the given `correlation_id`.
+    ///
+    /// Returns the resolved request, or `None` if no such request was pending (e.g. it
+    /// already timed out or was canceled).
+    pub fn resolve(&mut self, correlation_id: &UUID4) -> Option<PendingRequest> {
+        self.pending.remove(correlation_id)
+    }
+
+    /// Resolves the pending request matching the correlation ID of the `response`.
+    pub fn match_response(&mut self, response: &DataResponse) -> Option<PendingRequest> {
+        self.resolve(&response.correlation_id)
+    }
+
+    /// Cancels the pending request with the given `correlation_id` without firing its
+    /// timeout callback.
+    ///
+    /// Returns whether a pending request was canceled.
+    pub fn cancel(&mut self, correlation_id: &UUID4) -> bool {
+        self.pending.remove(correlation_id).is_some()
+    }
+
+    /// Cancels all pending requests without firing their timeout callbacks.
+    pub fn cancel_all(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Removes all requests whose deadline has passed as of `ts_now`, calling their timeout
+    /// callbacks in deadline order.
+    ///
+    /// Returns the correlation IDs of the timed out requests.
+    pub fn check_timeouts(&mut self, ts_now: UnixNanos) -> Vec<UUID4> {
+        let mut expired: Vec<PendingRequest> = Vec::new();
+        self.pending.retain(|_, request| {
+            if request.deadline <= ts_now {
+                expired.push(request.clone());
+                false
+            } else {
+                true
+            }
+        });
+        expired.sort_by_key(|request| (request.deadline, request.ts_registered));
+
+        expired
+            .into_iter()
+            .map(|request| {
+                log::warn!(
+                    "Request {} timed out at {ts_now} (deadline {})",
+                    request.correlation_id,
+                    request.deadline,
+                );
+                if let Some(callback) = request.callback {
+                    callback(request.correlation_id);
+                }
+                request.correlation_id
+            })
+            .collect()
+    }
+
+    /// Returns whether a request with the given `correlation_id` is pending.
+    #[must_use]
+    pub fn is_pending(&self, correlation_id: &UUID4) -> bool {
+        self.pending.contains_key(correlation_id)
+    }
+
+    /// Returns the number of pending requests.
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns the earliest deadline of all pending requests (if any).
+    #[must_use]
+    pub fn next_deadline(&self) -> Option<UnixNanos> {
+        self.pending.values().map(|request| request.deadline).min()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use nautilus_model::{
+        data::{DataType, QuoteTick},
+        identifiers::{ClientId, Venue},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn tracker() -> RequestTracker {
+        RequestTracker::new()
+    }
+
+    #[rstest]
+    fn test_register_and_resolve(mut tracker: RequestTracker) {
+        let correlation_id = UUID4::new();
+        tracker
+            .register(
+                correlation_id,
+                UnixNanos::from(1),
+                UnixNanos::from(10),
+                None,
+            )
+            .unwrap();
+
+        assert!(tracker.is_pending(&correlation_id));
+        assert_eq!(tracker.next_deadline(), Some(UnixNanos::from(10)));
+
+        let resolved = tracker.resolve(&correlation_id).unwrap();
+
+        assert_eq!(resolved.correlation_id, correlation_id);
+        assert_eq!(resolved.ts_registered, UnixNanos::from(1));
+        assert!(!tracker.is_pending(&correlation_id));
+        assert!(tracker.resolve(&correlation_id).is_none());
+    }
+
+    #[rstest]
+    fn test_register_duplicate_errors(mut tracker: RequestTracker) {
+        let correlation_id = UUID4::new();
+        tracker
+            .register(
+                correlation_id,
+                UnixNanos::default(),
+                UnixNanos::from(10),
+                None,
+            )
+            .unwrap();
+
+        let result = tracker.register(
+            correlation_id,
+            UnixNanos::default(),
+            UnixNanos::from(20),
+            None,
+        );
+
+        assert!(result.is_err());
+        assert_eq!(tracker.pending_count(), 1);
+    }
+
+    #[rstest]
+    fn test_register_deadline_in_past_errors(mut tracker: RequestTracker) {
+        let result = tracker.register(UUID4::new(), UnixNanos::from(10), UnixNanos::from(5), None);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_match_response(mut tracker: RequestTracker) {
+        let correlation_id = UUID4::new();
+        tracker
+            .register(
+                correlation_id,
+                UnixNanos::default(),
+                UnixNanos::from(10),
+                None,
+            )
+            .unwrap();
+        let response = DataResponse::new(
+            correlation_id,
+            ClientId::new("TEST"),
+            Venue::new("TEST"),
+            DataType::new("QuoteTick", None),
+            Vec::<QuoteTick>::new(),
+            UnixNanos::from(5),
+            None,
+        );
+
+        assert!(tracker.match_response(&response).is_some());
+        assert_eq!(tracker.pending_count(), 0);
+    }
+
+    #[rstest]
+    fn test_check_timeouts_fires_callbacks_in_deadline_order(mut tracker: RequestTracker) {
+        let fired = Rc::new(RefCell::new(Vec::new()));
+        let fired_clone = fired.clone();
+        let callback: Rc<RequestTimeoutCallback> =
+            Rc::new(move |correlation_id| fired_clone.borrow_mut().push(correlation_id));
+
+        let id1 = UUID4::new();
+        let id2 = UUID4::new();
+        let id3 = UUID4::new();
+        tracker
+            .register(
+                id1,
+                UnixNanos::default(),
+                UnixNanos::from(20),
+                Some(callback.clone()),
+            )
+            .unwrap();
+        tracker
+            .register(
+                id2,
+                UnixNanos::default(),
+                UnixNanos::from(10),
+                Some(callback),
+            )
+            .unwrap();
+        tracker
+            .register(id3, UnixNanos::default(), UnixNanos::from(30), None)
+            .unwrap();
+
+        let timed_out = tracker.check_timeouts(UnixNanos::from(25));
+
+        assert_eq!(timed_out, vec![id2, id1]);
+        assert_eq!(*fired.borrow(), vec![id2, id1]);
+        assert!(tracker.is_pending(&id3));
+        assert_eq!(tracker.next_deadline(), Some(UnixNanos::from(30)));
+    }
+
+    #[rstest]
+    fn test_cancel_does_not_fire_callback(mut tracker: RequestTracker) {
+        let fired = Rc::new(RefCell::new(false));
+        let fired_clone = fired.clone();
+        let correlation_id = UUID4::new();
+        tracker
+            .register(
+                correlation_id,
+                UnixNanos::default(),
+                UnixNanos::from(10),
+                Some(Rc::new(move |_| *fired_clone.borrow_mut() = true)),
+            )
+            .unwrap();
+
+        assert!(tracker.cancel(&correlation_id));
+        assert!(!tracker.cancel(&correlation_id));
+        assert!(tracker.check_timeouts(UnixNanos::from(100)).is_empty());
+        assert!(!*fired.borrow());
+    }
+
+    #[rstest]
+    fn test_cancel_all(mut tracker: RequestTracker) {
+        tracker
+            .register(
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::from(10),
+                None,
+            )
+            .unwrap();
+        tracker
+            .register(
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::from(20),
+                None,
+            )
+            .unwrap();
+
+        tracker.cancel_all();
+
+        assert_eq!(tracker.pending_count(), 0);
+        assert_eq!(tracker.next_deadline(), None);
+    }
+}
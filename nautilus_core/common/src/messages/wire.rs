@@ -0,0 +1,321 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Versioned wire format for messages which cross process boundaries.
+//!
+//! Messages are wrapped in a [`WireEnvelope`] carrying the schema version, and can be
+//! encoded as either JSON or `MsgPack`. Decoding rejects envelopes with a newer schema
+//! version than this build understands.
+
+use bytes::Bytes;
+use nautilus_core::serialization::Serializable;
+use nautilus_model::{data::Data, events::OrderEventAny};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    data::{DataRequest, DataResponse, SubscribeCommand, SubscriptionCommand, UnsubscribeCommand},
+    execution::TradingCommand,
+};
+
+/// The current wire schema version.
+///
+/// Increment this when making a breaking change to any message layout.
+pub const WIRE_SCHEMA_VERSION: u16 = 1;
+
+/// Represents a wire encoding format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WireFormat {
+    Json,
+    MsgPack,
+}
+
+/// Represents any message which can be sent over the wire.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", content = "message")]
+pub enum WireMessage {
+    DataRequest(DataRequest),
+    DataResponse(DataResponse),
+    Subscribe(SubscribeCommand),
+    Unsubscribe(UnsubscribeCommand),
+    Subscription(SubscriptionCommand),
+    TradingCommand(TradingCommand),
+    OrderEvent(OrderEventAny),
+    Data(Data),
+}
+
+macro_rules! impl_from_wire_message {
+    ($($ty:ty => $variant:ident),+ $(,)?) => {
+        $(
+            impl From<$ty> for WireMessage {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_wire_message!(
+    DataRequest => DataRequest,
+    DataResponse => DataResponse,
+    SubscribeCommand => Subscribe,
+    UnsubscribeCommand => Unsubscribe,
+    SubscriptionCommand => Subscription,
+    TradingCommand => TradingCommand,
+    OrderEventAny => OrderEvent,
+    Data => Data,
+);
+
+/// Wraps a [`WireMessage`] with its schema version.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WireEnvelope {
+    pub version: u16,
+    pub message: WireMessage,
+}
+
+impl Serializable for WireEnvelope {}
+
+impl WireEnvelope {
+    /// Creates a new [`WireEnvelope`] instance at the current schema version.
+    pub fn new<T: Into<WireMessage>>(message: T) -> Self {
+        Self {
+            version: WIRE_SCHEMA_VERSION,
+            message: message.into(),
+        }
+    }
+
+    /// Encodes the envelope in the given `format`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the message contains a process-local payload
+    /// which cannot be serialized.
+    pub fn encode(&self, format: WireFormat) -> anyhow::Result<Bytes> {
+        let bytes = match format {
+            WireFormat::Json => self.as_json_bytes()?,
+            WireFormat::MsgPack => self.as_msgpack_bytes()?,
+        };
+        Ok(bytes)
+    }
+
+    /// Decodes an envelope from `data` in the given `format`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The data cannot be decoded.
+    /// - The envelope schema version is not supported.
+    pub fn decode(data: &[u8], format: WireFormat) -> anyhow::Result<Self> {
+        let envelope = match format {
+            WireFormat::Json => Self::from_json_bytes(data)?,
+            WireFormat::MsgPack => Self::from_msgpack_bytes(data)?,
+        };
+
+        if envelope.version == 0 || envelope.version > WIRE_SCHEMA_VERSION {
+            anyhow::bail!(
+                "Unsupported wire schema version {}, expected <= {WIRE_SCHEMA_VERSION}",
+                envelope.version,
+            );
+        }
+
+        Ok(envelope)
+    }
+}
+
+/// Encodes the `message` at the current schema version in the given `format`.
+///
+/// # Errors
+///
+/// This function returns an error if the message cannot be serialized.
+pub fn encode<T: Into<WireMessage>>(message: T, format: WireFormat) -> anyhow::Result<Bytes> {
+    WireEnvelope::new(message).encode(format)
+}
+
+/// Decodes a message from `data` in the given `format`.
+///
+/// # Errors
+///
+/// This function returns an error if the data cannot be decoded, or the schema version
+/// is not supported.
+pub fn decode(data: &[u8], format: WireFormat) -> anyhow::Result<WireMessage> {
+    WireEnvelope::decode(data, format).map(|envelope| envelope.message)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use nautilus_model::{
+        data::{stubs::quote_ethusdt_binance, DataType},
+        events::{order::stubs::order_filled, OrderFilled},
+        identifiers::{
+            stubs::{
+                client_order_id, instrument_id_btc_usdt, strategy_id_ema_cross, trader_id, uuid4,
+            },
+            ClientId, ClientOrderId, InstrumentId, StrategyId, TraderId, Venue, VenueOrderId,
+        },
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::messages::{
+        data::{DataResponsePayload, SubscriptionKind},
+        execution::CancelOrder,
+    };
+
+    fn fill() -> OrderFilled {
+        order_filled(
+            trader_id(),
+            strategy_id_ema_cross(),
+            instrument_id_btc_usdt(),
+            client_order_id(),
+            uuid4(),
+        )
+    }
+
+    #[rstest]
+    #[case(WireFormat::Json)]
+    #[case(WireFormat::MsgPack)]
+    fn test_roundtrip_subscribe_command(#[case] format: WireFormat) {
+        let command = SubscribeCommand::new(
+            Some(ClientId::new("BINANCE")),
+            None,
+            SubscriptionKind::Quotes(InstrumentId::from("ETHUSDT.BINANCE")),
+            UUID4::new(),
+            UnixNanos::from(1),
+            None,
+        )
+        .unwrap();
+
+        let bytes = encode(command.clone(), format).unwrap();
+        let decoded = decode(&bytes, format).unwrap();
+
+        match decoded {
+            WireMessage::Subscribe(decoded) => {
+                assert_eq!(decoded.client_id, command.client_id);
+                assert_eq!(decoded.kind, command.kind);
+                assert_eq!(decoded.command_id, command.command_id);
+                assert_eq!(decoded.ts_init, command.ts_init);
+            }
+            other => panic!("Unexpected message {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case(WireFormat::Json)]
+    #[case(WireFormat::MsgPack)]
+    fn test_roundtrip_data_response(#[case] format: WireFormat) {
+        let quote = quote_ethusdt_binance();
+        let response = DataResponse::new(
+            UUID4::new(),
+            ClientId::new("BINANCE"),
+            Venue::new("BINANCE"),
+            DataType::new("QuoteTick", None),
+            vec![quote],
+            UnixNanos::default(),
+            None,
+        );
+
+        let bytes = encode(response, format).unwrap();
+        let decoded = decode(&bytes, format).unwrap();
+
+        match decoded {
+            WireMessage::DataResponse(decoded) => {
+                assert_eq!(decoded.data.as_quotes(), Some([quote].as_slice()));
+            }
+            other => panic!("Unexpected message {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case(WireFormat::Json)]
+    #[case(WireFormat::MsgPack)]
+    fn test_roundtrip_trading_command(#[case] format: WireFormat) {
+        let command = CancelOrder::new(
+            TraderId::new("TRADER-001"),
+            ClientId::new("BINANCE"),
+            StrategyId::new("S-001"),
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            ClientOrderId::new("O-001"),
+            VenueOrderId::new("001"),
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        let bytes = encode(TradingCommand::CancelOrder(command.clone()), format).unwrap();
+        let decoded = decode(&bytes, format).unwrap();
+
+        match decoded {
+            WireMessage::TradingCommand(TradingCommand::CancelOrder(decoded)) => {
+                assert_eq!(decoded, command);
+            }
+            other => panic!("Unexpected message {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case(WireFormat::Json)]
+    #[case(WireFormat::MsgPack)]
+    fn test_roundtrip_order_event(#[case] format: WireFormat) {
+        let event = OrderEventAny::Filled(fill());
+
+        let bytes = encode(event.clone(), format).unwrap();
+        let decoded = decode(&bytes, format).unwrap();
+
+        match decoded {
+            WireMessage::OrderEvent(decoded) => assert_eq!(decoded, event),
+            other => panic!("Unexpected message {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_encode_custom_payload_fails() {
+        let response = DataResponse::new(
+            UUID4::new(),
+            ClientId::new("TEST"),
+            Venue::new("TEST"),
+            DataType::new("CustomData", None),
+            DataResponsePayload::custom(1_u64),
+            UnixNanos::default(),
+            None,
+        );
+
+        assert!(encode(response, WireFormat::Json).is_err());
+    }
+
+    #[rstest]
+    fn test_decode_unsupported_version() {
+        let mut envelope = WireEnvelope::new(OrderEventAny::Filled(fill()));
+        envelope.version = WIRE_SCHEMA_VERSION + 1;
+        let bytes = envelope.encode(WireFormat::Json).unwrap();
+
+        let result = decode(&bytes, WireFormat::Json);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_json_envelope_layout() {
+        let bytes = encode(OrderEventAny::Filled(fill()), WireFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+
+        assert_eq!(value["version"], WIRE_SCHEMA_VERSION);
+        assert_eq!(value["message"]["type"], "OrderEvent");
+    }
+}
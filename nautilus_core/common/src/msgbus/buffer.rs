@@ -0,0 +1,449 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Bounded buffering for message bus subscriptions.
+//!
+//! A [`BufferedMessageHandler`] decouples the publishing of [`Data`] from a potentially
+//! slow subscriber, holding at most `capacity` messages until the buffer is drained.
+//! What happens when the buffer is full is determined by the [`OverflowPolicy`].
+//!
+//! The buffers are drained through [`MessageBus::drain_buffers`](super::MessageBus::drain_buffers)
+//! by the live data runner once it has caught up with incoming data, and by the backtest
+//! engine after each data point.
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    mem::Discriminant,
+    num::NonZeroUsize,
+};
+
+use nautilus_model::{
+    data::{
+        Bar, Data, OrderBookDelta, OrderBookDeltas, OrderBookDeltas_API, OrderBookDepth10,
+        QuoteTick, TradeTick,
+    },
+    identifiers::InstrumentId,
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::handler::{MessageHandler, ShareableMessageHandler};
+use crate::messages::data::DataResponse;
+
+/// The policy applied when a message is published to a full subscription buffer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OverflowPolicy {
+    /// The publisher blocks until the subscriber has handled the oldest buffered message,
+    /// so no messages are dropped.
+    ///
+    /// As the message bus is single-threaded, nothing else can make room in the buffer while
+    /// the publisher waits. The oldest buffered message is therefore delivered to the
+    /// subscriber within the publishing call, before the new message is buffered.
+    #[default]
+    Block,
+    /// The oldest buffered message is dropped to make room for the new message.
+    DropOldest,
+    /// The new message is dropped.
+    DropNewest,
+    /// A buffered message with the same data type and instrument is replaced by the new
+    /// message, otherwise the oldest buffered message is dropped when full.
+    ConflateByKey,
+}
+
+type ConflationKey = (Discriminant<Data>, InstrumentId);
+
+fn conflation_key(data: &Data) -> ConflationKey {
+    (std::mem::discriminant(data), data.instrument_id())
+}
+
+/// Returns the given published `message` as [`Data`], if it is a market data type.
+fn message_as_data(message: &dyn Any) -> Option<Data> {
+    if let Some(quote) = message.downcast_ref::<QuoteTick>() {
+        Some(Data::Quote(*quote))
+    } else if let Some(trade) = message.downcast_ref::<TradeTick>() {
+        Some(Data::Trade(*trade))
+    } else if let Some(bar) = message.downcast_ref::<Bar>() {
+        Some(Data::Bar(*bar))
+    } else if let Some(delta) = message.downcast_ref::<OrderBookDelta>() {
+        Some(Data::Delta(*delta))
+    } else if let Some(deltas) = message.downcast_ref::<OrderBookDeltas>() {
+        Some(Data::Deltas(OrderBookDeltas_API::new(deltas.clone())))
+    } else {
+        message
+            .downcast_ref::<OrderBookDepth10>()
+            .map(|depth| Data::Depth10(depth.clone()))
+    }
+}
+
+/// A buffered message, retaining whether it was published as [`Data`] or as a message.
+#[derive(Debug)]
+struct BufferedData {
+    data: Data,
+    is_message: bool,
+}
+
+/// Provides a point-in-time view of a subscription buffer for monitoring.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BufferStats {
+    /// The subscription topic for the buffer.
+    pub topic: Ustr,
+    /// The handler ID for the subscription.
+    pub handler_id: Ustr,
+    /// The number of messages currently buffered.
+    pub len: usize,
+    /// The maximum number of messages which can be buffered.
+    pub capacity: usize,
+    /// The overflow policy for the buffer.
+    pub policy: OverflowPolicy,
+    /// The total number of messages dropped (or replaced by conflation).
+    pub dropped: u64,
+}
+
+/// A message handler which buffers market data for an inner handler, up to a bounded capacity.
+///
+/// Buffered data is delivered to the inner handler when [`BufferedMessageHandler::drain`]
+/// is called, in the same form as it was published (either as [`Data`] or as a market data
+/// message such as a [`QuoteTick`]). Other messages and responses are passed straight
+/// through to the inner handler.
+pub struct BufferedMessageHandler {
+    inner: ShareableMessageHandler,
+    capacity: usize,
+    policy: OverflowPolicy,
+    buffer: RefCell<VecDeque<BufferedData>>,
+    dropped: Cell<u64>,
+}
+
+impl BufferedMessageHandler {
+    /// Creates a new [`BufferedMessageHandler`] instance.
+    #[must_use]
+    pub fn new(
+        inner: ShareableMessageHandler,
+        capacity: NonZeroUsize,
+        policy: OverflowPolicy,
+    ) -> Self {
+        let capacity = capacity.get();
+        Self {
+            inner,
+            capacity,
+            policy,
+            buffer: RefCell::new(VecDeque::with_capacity(capacity)),
+            dropped: Cell::new(0),
+        }
+    }
+
+    /// Returns the maximum number of messages which can be buffered.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the overflow policy for the buffer.
+    #[must_use]
+    pub const fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    /// Returns the number of messages currently buffered.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.buffer.borrow().len()
+    }
+
+    /// Returns whether there are no messages currently buffered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.borrow().is_empty()
+    }
+
+    /// Returns the total number of messages dropped (or replaced by conflation).
+    #[must_use]
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped.get()
+    }
+
+    /// Delivers all buffered messages to the inner handler in the order they were
+    /// buffered, returning the number of messages delivered.
+    ///
+    /// Messages buffered by the inner handler while draining are also delivered.
+    pub fn drain(&self) -> usize {
+        let mut count = 0;
+        // Pop one at a time so the buffer is not borrowed while the inner handler runs
+        loop {
+            let next = self.buffer.borrow_mut().pop_front();
+            match next {
+                Some(buffered) => self.deliver(buffered),
+                None => return count,
+            }
+            count += 1;
+        }
+    }
+
+    fn deliver(&self, buffered: BufferedData) {
+        if buffered.is_message {
+            self.deliver_message(buffered.data);
+        } else {
+            self.inner.0.handle_data(buffered.data);
+        }
+    }
+
+    fn deliver_message(&self, data: Data) {
+        let handler = &self.inner.0;
+        match data {
+            Data::Delta(delta) => handler.handle(&delta),
+            Data::Deltas(deltas) => handler.handle(&*deltas),
+            Data::Depth10(depth) => handler.handle(&depth),
+            Data::Quote(quote) => handler.handle(&quote),
+            Data::Trade(trade) => handler.handle(&trade),
+            Data::Bar(bar) => handler.handle(&bar),
+        }
+    }
+
+    fn record_dropped(&self) {
+        self.dropped.set(self.dropped.get() + 1);
+    }
+
+    fn push(&self, data: Data, is_message: bool) {
+        let data = BufferedData { data, is_message };
+        if self.policy == OverflowPolicy::ConflateByKey {
+            let key = conflation_key(&data.data);
+            let mut buffer = self.buffer.borrow_mut();
+            if let Some(existing) = buffer.iter_mut().find(|d| conflation_key(&d.data) == key) {
+                *existing = data;
+                drop(buffer);
+                self.record_dropped();
+                return;
+            }
+        }
+
+        if self.len() >= self.capacity {
+            match self.policy {
+                OverflowPolicy::Block => {
+                    let oldest = self.buffer.borrow_mut().pop_front();
+                    if let Some(oldest) = oldest {
+                        self.deliver(oldest);
+                    }
+                }
+                OverflowPolicy::DropOldest | OverflowPolicy::ConflateByKey => {
+                    self.buffer.borrow_mut().pop_front();
+                    self.record_dropped();
+                }
+                OverflowPolicy::DropNewest => {
+                    self.record_dropped();
+                    return;
+                }
+            }
+        }
+
+        self.buffer.borrow_mut().push_back(data);
+    }
+}
+
+impl MessageHandler for BufferedMessageHandler {
+    fn id(&self) -> Ustr {
+        self.inner.0.id()
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        match message_as_data(message) {
+            Some(data) => self.push(data, true),
+            None => self.inner.0.handle(message),
+        }
+    }
+
+    fn handle_response(&self, resp: DataResponse) {
+        self.inner.0.handle_response(resp);
+    }
+
+    fn handle_data(&self, data: Data) {
+        self.push(data, false);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::data::{QuoteTick, TradeTick};
+    use rstest::*;
+
+    use super::*;
+    use crate::msgbus::stubs::{
+        get_data_saving_handler, get_message_saving_handler, get_saved_data, get_saved_messages,
+    };
+
+    fn quote(instrument_id: &str, ts_event: u64) -> Data {
+        Data::Quote(QuoteTick {
+            instrument_id: InstrumentId::from(instrument_id),
+            ts_event: UnixNanos::from(ts_event),
+            ..Default::default()
+        })
+    }
+
+    fn ts_events(data: &[Data]) -> Vec<u64> {
+        data.iter()
+            .map(|d| match d {
+                Data::Quote(quote) => quote.ts_event.as_u64(),
+                Data::Trade(trade) => trade.ts_event.as_u64(),
+                _ => panic!("Unexpected data {d:?}"),
+            })
+            .collect()
+    }
+
+    fn buffered(
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> (BufferedMessageHandler, ShareableMessageHandler) {
+        let inner = get_data_saving_handler(None);
+        (
+            BufferedMessageHandler::new(
+                inner.clone(),
+                NonZeroUsize::new(capacity).unwrap(),
+                policy,
+            ),
+            inner,
+        )
+    }
+
+    #[rstest]
+    fn test_buffers_until_drained() {
+        let (handler, inner) = buffered(3, OverflowPolicy::DropOldest);
+
+        handler.handle_data(quote("AUDUSD.SIM", 1));
+        handler.handle_data(quote("AUDUSD.SIM", 2));
+
+        assert_eq!(handler.len(), 2);
+        assert!(get_saved_data(inner.clone()).is_empty());
+
+        assert_eq!(handler.drain(), 2);
+        assert!(handler.is_empty());
+        assert_eq!(ts_events(&get_saved_data(inner)), vec![1, 2]);
+        assert_eq!(handler.dropped_count(), 0);
+    }
+
+    #[rstest]
+    fn test_block_delivers_oldest_message_when_full() {
+        let (handler, inner) = buffered(2, OverflowPolicy::Block);
+
+        for ts in 1..=3 {
+            handler.handle_data(quote("AUDUSD.SIM", ts));
+        }
+
+        assert_eq!(ts_events(&get_saved_data(inner.clone())), vec![1]);
+        assert_eq!(handler.len(), 2);
+
+        handler.drain();
+        assert_eq!(ts_events(&get_saved_data(inner)), vec![1, 2, 3]);
+        assert_eq!(handler.dropped_count(), 0);
+    }
+
+    #[rstest]
+    fn test_drop_oldest_when_full() {
+        let (handler, inner) = buffered(2, OverflowPolicy::DropOldest);
+
+        for ts in 1..=4 {
+            handler.handle_data(quote("AUDUSD.SIM", ts));
+        }
+        handler.drain();
+
+        assert_eq!(ts_events(&get_saved_data(inner)), vec![3, 4]);
+        assert_eq!(handler.dropped_count(), 2);
+    }
+
+    #[rstest]
+    fn test_drop_newest_when_full() {
+        let (handler, inner) = buffered(2, OverflowPolicy::DropNewest);
+
+        for ts in 1..=4 {
+            handler.handle_data(quote("AUDUSD.SIM", ts));
+        }
+        handler.drain();
+
+        assert_eq!(ts_events(&get_saved_data(inner)), vec![1, 2]);
+        assert_eq!(handler.dropped_count(), 2);
+    }
+
+    #[rstest]
+    fn test_conflate_by_key_replaces_buffered_message_in_place() {
+        let (handler, inner) = buffered(3, OverflowPolicy::ConflateByKey);
+
+        handler.handle_data(quote("AUDUSD.SIM", 1));
+        handler.handle_data(quote("EURUSD.SIM", 2));
+        handler.handle_data(Data::Trade(TradeTick {
+            ts_event: UnixNanos::from(3),
+            ..Default::default()
+        }));
+        handler.handle_data(quote("AUDUSD.SIM", 4));
+        handler.drain();
+
+        // The trade has a different key to the AUDUSD quote, so is not conflated
+        assert_eq!(ts_events(&get_saved_data(inner)), vec![4, 2, 3]);
+        assert_eq!(handler.dropped_count(), 1);
+    }
+
+    #[rstest]
+    fn test_conflate_by_key_drops_oldest_when_full_of_distinct_keys() {
+        let (handler, inner) = buffered(2, OverflowPolicy::ConflateByKey);
+
+        handler.handle_data(quote("AUDUSD.SIM", 1));
+        handler.handle_data(quote("EURUSD.SIM", 2));
+        handler.handle_data(quote("GBPUSD.SIM", 3));
+        handler.drain();
+
+        assert_eq!(ts_events(&get_saved_data(inner)), vec![2, 3]);
+        assert_eq!(handler.dropped_count(), 1);
+    }
+
+    #[rstest]
+    fn test_buffers_market_data_messages_and_delivers_as_messages() {
+        let inner = get_message_saving_handler::<QuoteTick>(None);
+        let handler = BufferedMessageHandler::new(
+            inner.clone(),
+            NonZeroUsize::new(2).unwrap(),
+            OverflowPolicy::DropOldest,
+        );
+        let quote = QuoteTick {
+            ts_event: UnixNanos::from(1),
+            ..Default::default()
+        };
+
+        handler.handle(&quote);
+
+        assert_eq!(handler.len(), 1);
+        assert!(get_saved_messages::<QuoteTick>(inner.clone()).is_empty());
+
+        handler.drain();
+        assert_eq!(get_saved_messages::<QuoteTick>(inner), vec![quote]);
+    }
+
+    #[rstest]
+    fn test_id_is_inner_handler_id() {
+        let inner = get_data_saving_handler(Some(Ustr::from("inner")));
+        let handler = BufferedMessageHandler::new(
+            inner,
+            NonZeroUsize::new(1).unwrap(),
+            OverflowPolicy::Block,
+        );
+
+        assert_eq!(handler.id(), Ustr::from("inner"));
+    }
+}
@@ -118,6 +118,15 @@ pub struct MessageBusConfig {
     pub stream_per_topic: bool,
     /// The external stream keys the message bus will listen to for publishing deserialized message payloads internally.
     pub external_streams: Option<Vec<String>>,
+    /// If external streams should be replayed from their beginning on startup (rather than
+    /// from the current time), before continuing to stream new messages.
+    pub replay_external_streams: bool,
+    /// A list of topic patterns (which may contain `*` and `?` wildcards) to publish externally.
+    /// If `None`, then all publishable topics will be published.
+    pub topics_filter: Option<Vec<String>>,
+    /// The approximate maximum number of entries to retain per stream.
+    /// Streams are trimmed on each write using `MAXLEN ~`, independently of `autotrim_mins`.
+    pub stream_maxlen: Option<usize>,
     /// A list of serializable types **not** to publish externally.
     pub types_filter: Option<Vec<String>>,
     /// The heartbeat interval (seconds).
@@ -139,6 +148,9 @@ impl Default for MessageBusConfig {
             streams_prefix: "stream".to_string(),
             stream_per_topic: true,
             external_streams: None,
+            replay_external_streams: false,
+            topics_filter: None,
+            stream_maxlen: None,
             types_filter: None,
             heartbeat_interval_secs: None,
         }
@@ -220,6 +232,9 @@ mod tests {
         assert_eq!(config.streams_prefix, "stream");
         assert!(config.stream_per_topic);
         assert_eq!(config.external_streams, None);
+        assert!(!config.replay_external_streams);
+        assert_eq!(config.topics_filter, None);
+        assert_eq!(config.stream_maxlen, None);
         assert_eq!(config.types_filter, None);
     }
 
@@ -245,6 +260,9 @@ mod tests {
             "streams_prefix": "data_streams",
             "stream_per_topic": false,
             "external_streams": ["stream1", "stream2"],
+            "replay_external_streams": true,
+            "topics_filter": ["data.quotes.*"],
+            "stream_maxlen": 10000,
             "types_filter": ["type1", "type2"]
         });
         let config: MessageBusConfig = serde_json::from_value(config_json).unwrap();
@@ -261,6 +279,12 @@ mod tests {
             config.external_streams,
             Some(vec!["stream1".to_string(), "stream2".to_string()])
         );
+        assert!(config.replay_external_streams);
+        assert_eq!(
+            config.topics_filter,
+            Some(vec!["data.quotes.*".to_string()])
+        );
+        assert_eq!(config.stream_maxlen, Some(10_000));
         assert_eq!(
             config.types_filter,
             Some(vec!["type1".to_string(), "type2".to_string()])
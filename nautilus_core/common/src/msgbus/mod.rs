@@ -15,6 +15,7 @@
 
 //! A common in-memory `MessageBus` for loosely coupled message passing patterns.
 
+pub mod buffer;
 pub mod database;
 pub mod handler;
 pub mod stubs;
@@ -22,22 +23,75 @@ pub mod switchboard;
 
 use std::{
     any::Any,
-    collections::HashMap,
+    cell::{Cell, RefCell},
+    collections::{HashMap, VecDeque},
     fmt::Debug,
     hash::{Hash, Hasher},
+    num::NonZeroUsize,
+    rc::Rc,
 };
 
+use buffer::{BufferStats, BufferedMessageHandler, OverflowPolicy};
 use handler::ShareableMessageHandler;
 use indexmap::IndexMap;
 use nautilus_core::uuid::UUID4;
 use nautilus_model::{data::Data, identifiers::TraderId};
+use serde::{Deserialize, Serialize};
 use switchboard::MessagingSwitchboard;
 use ustr::Ustr;
 
-use crate::messages::data::DataResponse;
+use crate::{
+    messages::data::{DataRequest, DataResponse},
+    timer::{TimeEvent, TimeEventCallback},
+};
 
 pub const CLOSE_TOPIC: &str = "CLOSE";
 
+/// The mode in which published messages are dispatched to subscribed handlers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DispatchMode {
+    /// Messages are delivered to handlers as soon as they are published, so messages
+    /// published from within a handler are delivered before the current message has
+    /// reached all of its handlers.
+    #[default]
+    Immediate,
+    /// Messages are assigned a monotonic sequence number and processed one at a time
+    /// from a single ordered queue, for exactly reproducible dispatch (e.g. backtesting).
+    ///
+    /// Messages published from within a handler are queued behind the current message.
+    Sequenced,
+}
+
+enum QueuedPayload {
+    Data(Data),
+    Message(Box<dyn Any>),
+}
+
+/// Restores a dispatch state cell to its previous value when dropped, so that the state is
+/// reset even if a handler panics.
+struct RestoreOnDrop<'a, T: Copy> {
+    cell: &'a Cell<T>,
+    previous: T,
+}
+
+impl<'a, T: Copy> RestoreOnDrop<'a, T> {
+    fn replace(cell: &'a Cell<T>, value: T) -> Self {
+        let previous = cell.replace(value);
+        Self { cell, previous }
+    }
+}
+
+impl<T: Copy> Drop for RestoreOnDrop<'_, T> {
+    fn drop(&mut self) {
+        self.cell.set(self.previous);
+    }
+}
+
+struct QueuedMessage {
+    topic: Ustr,
+    payload: QueuedPayload,
+}
+
 /// Represents a subscription to a particular topic.
 ///
 /// This is an internal class intended to be used by the message bus to organize
@@ -141,6 +195,9 @@ impl Hash for Subscription {
 /// A question mark matches a single character once. For example, `c?mp` matches
 /// `camp` and `comp`. The question mark can also be used more than once.
 /// For example, `c??p` would match both of the above examples and `coop`.
+///
+/// Handlers for a published topic are invoked in a deterministic order: by descending
+/// subscription priority, then in the order the subscriptions were made.
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.common")
@@ -156,16 +213,30 @@ pub struct MessageBus {
     pub has_backing: bool,
     /// The switchboard for built-in endpoints.
     pub switchboard: MessagingSwitchboard,
-    /// Mapping from topic to the corresponding handler
-    /// a topic can be a string with wildcards
+    /// Mapping from subscription to the cached published topics it matched when subscribed,
+    /// a subscription topic can be a string with wildcards
     /// * '?' - any character
     /// * '*' - any number of any characters
     subscriptions: IndexMap<Subscription, Vec<Ustr>>,
-    /// Maps a pattern to all the handlers registered for it
-    /// this is updated whenever a new subscription is created.
-    patterns: IndexMap<Ustr, Vec<Subscription>>,
+    /// Caches the matching subscriptions (in handler invocation order) for each published
+    /// topic, this is updated whenever a subscription is created or removed.
+    patterns: RefCell<IndexMap<Ustr, Vec<Subscription>>>,
     /// Handles a message or a request destined for a specific endpoint.
     endpoints: IndexMap<Ustr, ShareableMessageHandler>,
+    /// Maps the correlation ID of each pending request to the handler for its response.
+    correlation_index: RefCell<IndexMap<UUID4, ShareableMessageHandler>>,
+    /// The bounded buffers for subscriptions made with `subscribe_buffered`.
+    buffers: IndexMap<Subscription, Rc<BufferedMessageHandler>>,
+    /// The mode in which published messages are dispatched.
+    dispatch_mode: DispatchMode,
+    /// The sequence number of the last message dispatched (in `Sequenced` mode).
+    sequence: Cell<u64>,
+    /// The sequence number of the message currently being dispatched (in `Sequenced` mode).
+    current_sequence: Cell<Option<u64>>,
+    /// If a message is currently being dispatched (in `Sequenced` mode).
+    dispatching: Cell<bool>,
+    /// The queue of messages waiting to be dispatched (in `Sequenced` mode).
+    queue: RefCell<VecDeque<QueuedMessage>>,
 }
 
 // SAFETY: Message bus is not meant to be passed between threads
@@ -180,6 +251,7 @@ impl MessageBus {
         instance_id: UUID4,
         name: Option<String>,
         _config: Option<HashMap<String, serde_json::Value>>,
+        dispatch_mode: Option<DispatchMode>,
     ) -> Self {
         Self {
             trader_id,
@@ -187,8 +259,15 @@ impl MessageBus {
             name: name.unwrap_or(stringify!(MessageBus).to_owned()),
             switchboard: MessagingSwitchboard::default(),
             subscriptions: IndexMap::new(),
-            patterns: IndexMap::new(),
+            patterns: RefCell::new(IndexMap::new()),
             endpoints: IndexMap::new(),
+            correlation_index: RefCell::new(IndexMap::new()),
+            buffers: IndexMap::new(),
+            dispatch_mode: dispatch_mode.unwrap_or_default(),
+            sequence: Cell::new(0),
+            current_sequence: Cell::new(None),
+            dispatching: Cell::new(false),
+            queue: RefCell::new(VecDeque::new()),
             has_backing: false,
         }
     }
@@ -199,6 +278,32 @@ impl MessageBus {
         format!("{:?}", std::ptr::from_ref(self))
     }
 
+    /// Returns the mode in which published messages are dispatched.
+    #[must_use]
+    pub const fn dispatch_mode(&self) -> DispatchMode {
+        self.dispatch_mode
+    }
+
+    /// Returns the sequence number of the last message dispatched (zero if none, or not
+    /// in `Sequenced` mode).
+    #[must_use]
+    pub fn sequence(&self) -> u64 {
+        self.sequence.get()
+    }
+
+    /// Returns the sequence number of the message currently being dispatched (if any, and
+    /// in `Sequenced` mode).
+    #[must_use]
+    pub fn current_sequence(&self) -> Option<u64> {
+        self.current_sequence.get()
+    }
+
+    /// Returns the count of messages waiting to be dispatched.
+    #[must_use]
+    pub fn queued_count(&self) -> usize {
+        self.queue.borrow().len()
+    }
+
     /// Returns the registered endpoint addresses.
     #[must_use]
     pub fn endpoints(&self) -> Vec<&str> {
@@ -225,8 +330,11 @@ impl MessageBus {
     /// Returns the count of subscribers for the given `pattern`.
     #[must_use]
     pub fn subscriptions_count<T: AsRef<str>>(&self, pattern: T) -> usize {
-        self.matching_subscriptions(&Ustr::from(pattern.as_ref()))
-            .len()
+        let topic = Ustr::from(pattern.as_ref());
+        self.subscriptions
+            .keys()
+            .filter(|sub| is_matching(&topic, &sub.topic))
+            .count()
     }
 
     /// Returns whether there are subscribers for the given `pattern`.
@@ -304,22 +412,74 @@ impl MessageBus {
             return;
         }
 
-        // Find existing patterns which match this topic
+        // Add to the cached published topics which match the new subscription
         let mut matches = Vec::new();
-        for (pattern, subs) in &mut self.patterns {
-            if is_matching(&Ustr::from(topic.as_ref()), pattern) {
+        for (published, subs) in self.patterns.get_mut() {
+            if is_matching(published, &sub.topic) {
                 subs.push(sub.clone());
-                subs.sort();
-                // subs.sort_by(|a, b| a.priority.cmp(&b.priority).then_with(|| a.cmp(b)));
-                matches.push(*pattern);
+                subs.sort(); // Stable, so subscription order is retained within a priority
+                matches.push(*published);
             }
         }
 
-        matches.sort();
-
         self.subscriptions.insert(sub, matches);
     }
 
+    /// Subscribes the given `handler` to the `topic`, with published [`Data`] held in a
+    /// bounded buffer of `capacity` messages until [`MessageBus::drain_buffers`] is called.
+    ///
+    /// The `policy` determines what happens when data is published to a full buffer.
+    pub fn subscribe_buffered<T: AsRef<str>>(
+        &mut self,
+        topic: T,
+        handler: ShareableMessageHandler,
+        priority: Option<u8>,
+        capacity: NonZeroUsize,
+        policy: OverflowPolicy,
+    ) {
+        let buffered = Rc::new(BufferedMessageHandler::new(handler, capacity, policy));
+        let shareable = ShareableMessageHandler(buffered.clone());
+        let sub = Subscription::new(topic.as_ref(), shareable.clone(), priority);
+        if self.subscriptions.contains_key(&sub) {
+            log::error!("{sub:?} already exists.");
+            return;
+        }
+
+        self.subscribe(topic, shareable, priority);
+        self.buffers.insert(sub, buffered);
+    }
+
+    /// Delivers all buffered data to the subscribed handlers, returning the number of
+    /// messages delivered.
+    pub fn drain_buffers(&self) -> usize {
+        self.buffers.values().map(|buffer| buffer.drain()).sum()
+    }
+
+    /// Returns the current statistics for each subscription buffer, for monitoring.
+    #[must_use]
+    pub fn buffer_stats(&self) -> Vec<BufferStats> {
+        self.buffers
+            .iter()
+            .map(|(sub, buffer)| BufferStats {
+                topic: sub.topic,
+                handler_id: sub.handler_id,
+                len: buffer.len(),
+                capacity: buffer.capacity(),
+                policy: buffer.policy(),
+                dropped: buffer.dropped_count(),
+            })
+            .collect()
+    }
+
+    /// Returns the total number of messages dropped across all subscription buffers.
+    #[must_use]
+    pub fn dropped_count(&self) -> u64 {
+        self.buffers
+            .values()
+            .map(|buffer| buffer.dropped_count())
+            .sum()
+    }
+
     /// Unsubscribes the given `handler` from the `topic`.
     pub fn unsubscribe<T: AsRef<str>>(&mut self, topic: T, handler: ShareableMessageHandler) {
         log::debug!(
@@ -328,7 +488,15 @@ impl MessageBus {
             self.memory_address(),
         );
         let sub = Subscription::new(topic, handler, None);
-        self.subscriptions.shift_remove(&sub);
+        // Any data still buffered for the subscription is discarded
+        self.buffers.shift_remove(&sub);
+        if self.subscriptions.shift_remove(&sub).is_some() {
+            // Topics cached on publish are not tracked per subscription, so remove the
+            // subscription from every cached topic
+            for subs in self.patterns.get_mut().values_mut() {
+                subs.retain(|s| s != &sub);
+            }
+        }
     }
 
     /// Returns the handler for the given `endpoint`.
@@ -337,33 +505,29 @@ impl MessageBus {
         self.endpoints.get(&Ustr::from(endpoint.as_ref()))
     }
 
+    /// Returns the subscriptions whose topic patterns match the published `topic`, in
+    /// handler invocation order.
+    ///
+    /// The result is cached per topic until the subscriptions change.
     #[must_use]
-    pub fn matching_subscriptions<'a>(&'a self, pattern: &'a Ustr) -> Vec<&'a Subscription> {
-        let mut matching_subs: Vec<&'a Subscription> = Vec::new();
-
-        // Collect matching subscriptions from direct subscriptions
-        matching_subs.extend(self.subscriptions.iter().filter_map(|(sub, _)| {
-            if is_matching(&sub.topic, pattern) {
-                Some(sub)
-            } else {
-                None
-            }
-        }));
-
-        // Collect matching subscriptions from pattern-based subscriptions
-        // TODO: Improve efficiency of this
-        for subs in self.patterns.values() {
-            let filtered_subs: Vec<&Subscription> = subs
-                .iter()
-                // .filter(|sub| is_matching(&sub.topic, pattern))
-                // .filter(|sub| !matching_subs.contains(sub) && is_matching(&sub.topic, pattern))
-                .collect();
-
-            matching_subs.extend(filtered_subs);
+    pub fn matching_subscriptions(&self, topic: &Ustr) -> Vec<Subscription> {
+        if let Some(subs) = self.patterns.borrow().get(topic) {
+            return subs.clone();
         }
 
-        // Sort into priority order
+        let mut matching_subs: Vec<Subscription> = self
+            .subscriptions
+            .keys()
+            .filter(|sub| is_matching(topic, &sub.topic))
+            .cloned()
+            .collect();
+
+        // Sort into priority order (stable, so subscription order is retained)
         matching_subs.sort();
+
+        self.patterns
+            .borrow_mut()
+            .insert(*topic, matching_subs.clone());
         matching_subs
     }
 
@@ -388,7 +552,72 @@ impl MessageBus {
     }
 
     /// Publish a message to a topic.
-    pub fn publish(&self, topic: &Ustr, message: &dyn Any) {
+    ///
+    /// In `Sequenced` mode a message published from within a handler is cloned and queued
+    /// behind the message currently being dispatched.
+    pub fn publish<T: Any + Clone>(&self, topic: &Ustr, message: &T) {
+        match self.dispatch_mode {
+            DispatchMode::Immediate => self.dispatch(topic, message),
+            DispatchMode::Sequenced if self.dispatching.get() => self.enqueue(QueuedMessage {
+                topic: *topic,
+                payload: QueuedPayload::Message(Box::new(message.clone())),
+            }),
+            DispatchMode::Sequenced => {
+                {
+                    let _dispatching = RestoreOnDrop::replace(&self.dispatching, true);
+                    self.dispatch_sequenced(|| self.dispatch(topic, message));
+                }
+                self.drain_queue();
+            }
+        }
+    }
+
+    /// Publish an owned message to a topic.
+    ///
+    /// In `Sequenced` mode the message is queued behind any message currently being
+    /// dispatched.
+    pub fn publish_owned<T: Any>(&self, topic: &Ustr, message: T) {
+        match self.dispatch_mode {
+            DispatchMode::Immediate => self.dispatch(topic, &message),
+            DispatchMode::Sequenced => self.enqueue(QueuedMessage {
+                topic: *topic,
+                payload: QueuedPayload::Message(Box::new(message)),
+            }),
+        }
+    }
+
+    fn enqueue(&self, message: QueuedMessage) {
+        self.queue.borrow_mut().push_back(message);
+        self.drain_queue();
+    }
+
+    fn drain_queue(&self) {
+        if self.dispatching.get() {
+            return; // Queued messages will be dispatched by the outer call
+        }
+        let _dispatching = RestoreOnDrop::replace(&self.dispatching, true);
+
+        loop {
+            // Pop one at a time so the queue is not borrowed while handlers run
+            let next = self.queue.borrow_mut().pop_front();
+            let Some(message) = next else {
+                break;
+            };
+            self.dispatch_sequenced(|| match message.payload {
+                QueuedPayload::Data(data) => self.dispatch_data(&message.topic, data),
+                QueuedPayload::Message(boxed) => self.dispatch(&message.topic, &*boxed),
+            });
+        }
+    }
+
+    fn dispatch_sequenced(&self, dispatch: impl FnOnce()) {
+        let sequence = self.sequence.get() + 1;
+        self.sequence.set(sequence);
+        let _current_sequence = RestoreOnDrop::replace(&self.current_sequence, Some(sequence));
+        dispatch();
+    }
+
+    fn dispatch(&self, topic: &Ustr, message: &dyn Any) {
         log::trace!(
             "Publishing topic '{topic}' {message:?} {}",
             self.memory_address()
@@ -421,6 +650,84 @@ impl MessageBus {
     //     }
     // }
 
+    /// Sends the `request` to the handler registered at the `endpoint`, with the `callback`
+    /// handler receiving the [`DataResponse`] correlated with the request.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - No handler is registered at the `endpoint`.
+    /// - A request with the same correlation ID is already pending a response.
+    pub fn request(
+        &self,
+        endpoint: &Ustr,
+        request: DataRequest,
+        callback: ShareableMessageHandler,
+    ) -> anyhow::Result<()> {
+        let handler = self
+            .get_endpoint(endpoint)
+            .ok_or_else(|| anyhow::anyhow!("No handler registered at endpoint '{endpoint}'"))?
+            .clone();
+
+        {
+            let mut correlation_index = self.correlation_index.borrow_mut();
+            if correlation_index.contains_key(&request.correlation_id) {
+                anyhow::bail!(
+                    "Request with correlation ID {} already pending a response",
+                    request.correlation_id
+                );
+            }
+            correlation_index.insert(request.correlation_id, callback);
+        }
+
+        handler.0.handle(&request);
+        Ok(())
+    }
+
+    /// Sends the `response` to the callback handler of the request with the same correlation ID.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no request is pending for the response correlation ID.
+    pub fn response(&self, response: DataResponse) -> anyhow::Result<()> {
+        let callback = self
+            .correlation_index
+            .borrow_mut()
+            .shift_remove(&response.correlation_id)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "No pending request for response with correlation ID {}",
+                    response.correlation_id
+                )
+            })?;
+
+        callback.0.handle_response(response);
+        Ok(())
+    }
+
+    /// Cancels the request with the given `correlation_id`, removing its callback handler
+    /// without a response (e.g. when the request has timed out).
+    ///
+    /// Returns whether a request was pending a response.
+    pub fn cancel_request(&self, correlation_id: &UUID4) -> bool {
+        self.correlation_index
+            .borrow_mut()
+            .shift_remove(correlation_id)
+            .is_some()
+    }
+
+    /// Returns whether a request with the given `correlation_id` is pending a response.
+    #[must_use]
+    pub fn is_pending_response(&self, correlation_id: &UUID4) -> bool {
+        self.correlation_index.borrow().contains_key(correlation_id)
+    }
+
+    /// Returns the number of requests pending a response.
+    #[must_use]
+    pub fn pending_responses_count(&self) -> usize {
+        self.correlation_index.borrow().len()
+    }
+
     /// Send a [`DataResponse`] to an endpoint that must be an actor.
     pub fn send_response(&self, message: DataResponse) {
         if let Some(handler) = self.get_endpoint(message.client_id.inner()) {
@@ -429,7 +736,20 @@ impl MessageBus {
     }
 
     /// Publish [`Data`] to a topic.
+    ///
+    /// In `Sequenced` mode the data is queued behind any message currently being
+    /// dispatched.
     pub fn publish_data(&self, topic: &Ustr, message: Data) {
+        match self.dispatch_mode {
+            DispatchMode::Immediate => self.dispatch_data(topic, message),
+            DispatchMode::Sequenced => self.enqueue(QueuedMessage {
+                topic: *topic,
+                payload: QueuedPayload::Data(message),
+            }),
+        }
+    }
+
+    fn dispatch_data(&self, topic: &Ustr, message: Data) {
         let matching_subs = self.matching_subscriptions(topic);
 
         for sub in matching_subs {
@@ -443,61 +763,181 @@ impl MessageBus {
 /// '*' - match 0 or more characters after this
 /// '?' - match any character once
 /// 'a-z' - match the specific character
+///
+/// Matching is performed on bytes, so '?' matches a single ASCII character.
 #[must_use]
 pub fn is_matching(topic: &Ustr, pattern: &Ustr) -> bool {
-    let mut table = [[false; 256]; 256];
-    table[0][0] = true;
-
-    let m = pattern.len();
-    let n = topic.len();
-
-    pattern.chars().enumerate().for_each(|(j, c)| {
-        if c == '*' {
-            table[0][j + 1] = table[0][j];
+    let topic = topic.as_bytes();
+    let pattern = pattern.as_bytes();
+
+    let (mut t, mut p) = (0, 0);
+    let mut last_star: Option<usize> = None;
+    let mut star_match = 0;
+
+    while t < topic.len() {
+        if p < pattern.len() && (pattern[p] == b'?' || pattern[p] == topic[t]) {
+            t += 1;
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == b'*' {
+            last_star = Some(p);
+            star_match = t;
+            p += 1;
+        } else if let Some(star) = last_star {
+            // Backtrack: let the last '*' consume one more character
+            p = star + 1;
+            star_match += 1;
+            t = star_match;
+        } else {
+            return false;
         }
-    });
-
-    topic.chars().enumerate().for_each(|(i, tc)| {
-        pattern.chars().enumerate().for_each(|(j, pc)| {
-            if pc == '*' {
-                table[i + 1][j + 1] = table[i][j + 1] || table[i + 1][j];
-            } else if pc == '?' || tc == pc {
-                table[i + 1][j + 1] = table[i][j];
-            }
-        });
-    });
+    }
 
-    table[n][m]
+    // Any remaining pattern must be trailing '*'
+    pattern[p..].iter().all(|&c| c == b'*')
 }
 
 impl Default for MessageBus {
     /// Creates a new default [`MessageBus`] instance.
     fn default() -> Self {
-        Self::new(TraderId::from("TRADER-001"), UUID4::new(), None, None)
+        Self::new(TraderId::from("TRADER-001"), UUID4::new(), None, None, None)
     }
 }
 
+/// Creates a [`TimeEventCallback`] which publishes each [`TimeEvent`] on the given `topic`.
+///
+/// Allows time alerts and timers to be delivered through the message bus
+/// rather than to a directly supplied handler.
+#[must_use]
+pub fn time_event_publisher(msgbus: Rc<RefCell<MessageBus>>, topic: &str) -> TimeEventCallback {
+    let topic = Ustr::from(topic);
+    TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+        msgbus.borrow().publish(&topic, &event);
+    }))
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
 
-    use nautilus_core::uuid::UUID4;
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use nautilus_model::{
+        data::{DataType, QuoteTick},
+        identifiers::{ClientId, Venue},
+    };
     use rstest::*;
     use stubs::check_handler_was_called;
 
     use super::*;
-    use crate::msgbus::stubs::{get_call_check_shareable_handler, get_stub_shareable_handler};
+    use crate::{
+        clock::{Clock, TestClock},
+        msgbus::stubs::{
+            get_call_check_shareable_handler, get_data_saving_handler, get_message_saving_handler,
+            get_response_saving_handler, get_saved_data, get_saved_messages,
+            get_saved_response_ids, get_stub_shareable_handler,
+        },
+    };
 
     fn stub_msgbus() -> MessageBus {
-        MessageBus::new(TraderId::from("trader-001"), UUID4::new(), None, None)
+        MessageBus::new(TraderId::from("trader-001"), UUID4::new(), None, None, None)
+    }
+
+    type DispatchLog = Rc<RefCell<Vec<(Ustr, String, Option<u64>)>>>;
+
+    // Records the messages it receives, republishing (borrowed) the first as "<message>-reply"
+    struct RepublishingHandler {
+        id: Ustr,
+        msgbus: Rc<RefCell<MessageBus>>,
+        reply_topic: Option<Ustr>,
+        log: DispatchLog,
+    }
+
+    impl handler::MessageHandler for RepublishingHandler {
+        fn id(&self) -> Ustr {
+            self.id
+        }
+
+        fn handle(&self, message: &dyn Any) {
+            let message = message.downcast_ref::<String>().unwrap();
+            let msgbus = self.msgbus.borrow();
+            self.log
+                .borrow_mut()
+                .push((self.id, message.clone(), msgbus.current_sequence()));
+            if let Some(topic) = self.reply_topic {
+                if !message.ends_with("-reply") {
+                    msgbus.publish(&topic, &format!("{message}-reply"));
+                }
+            }
+        }
+
+        fn handle_response(&self, _resp: DataResponse) {}
+
+        fn handle_data(&self, _data: Data) {}
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    fn republishing_msgbus(dispatch_mode: DispatchMode) -> (Rc<RefCell<MessageBus>>, DispatchLog) {
+        let msgbus = Rc::new(RefCell::new(MessageBus::new(
+            TraderId::from("trader-001"),
+            UUID4::new(),
+            None,
+            None,
+            Some(dispatch_mode),
+        )));
+        let log: DispatchLog = Rc::new(RefCell::new(Vec::new()));
+        let topic = Ustr::from("events.test");
+        for (id, reply_topic) in [("handler-1", Some(topic)), ("handler-2", None)] {
+            let handler = RepublishingHandler {
+                id: Ustr::from(id),
+                msgbus: msgbus.clone(),
+                reply_topic,
+                log: log.clone(),
+            };
+            msgbus
+                .borrow_mut()
+                .subscribe(topic, ShareableMessageHandler(Rc::new(handler)), None);
+        }
+        (msgbus, log)
+    }
+
+    fn dispatch_order(log: &DispatchLog) -> Vec<(String, String)> {
+        log.borrow()
+            .iter()
+            .map(|(id, message, _)| (id.to_string(), message.clone()))
+            .collect()
+    }
+
+    fn stub_request(correlation_id: UUID4) -> DataRequest {
+        DataRequest::new(
+            correlation_id,
+            ClientId::from("BINANCE"),
+            Venue::from("BINANCE"),
+            DataType::new("QuoteTick", None),
+            UnixNanos::default(),
+            None,
+        )
+    }
+
+    fn stub_response(correlation_id: UUID4) -> DataResponse {
+        DataResponse::new(
+            correlation_id,
+            ClientId::from("BINANCE"),
+            Venue::from("BINANCE"),
+            DataType::new("QuoteTick", None),
+            Vec::<QuoteTick>::new(),
+            UnixNanos::default(),
+            None,
+        )
     }
 
     #[rstest]
     fn test_new() {
         let trader_id = TraderId::from("trader-001");
-        let msgbus = MessageBus::new(trader_id, UUID4::new(), None, None);
+        let msgbus = MessageBus::new(trader_id, UUID4::new(), None, None, None);
 
         assert_eq!(msgbus.trader_id, trader_id);
         assert_eq!(msgbus.name, stringify!(MessageBus));
@@ -628,6 +1068,435 @@ mod tests {
         assert_eq!(subs[3].handler_id, handler_id2);
     }
 
+    #[rstest]
+    fn test_publish_to_wildcard_subscriptions() {
+        let mut msgbus = stub_msgbus();
+        let handler1 = get_call_check_shareable_handler(None);
+        let handler2 = get_call_check_shareable_handler(None);
+        let handler3 = get_call_check_shareable_handler(None);
+
+        msgbus.subscribe("data.quotes.*", handler1.clone(), None);
+        msgbus.subscribe("data.???es.BINANCE", handler2.clone(), None);
+        msgbus.subscribe("data.trades.*", handler3.clone(), None);
+
+        msgbus.publish(&Ustr::from("data.quotes.BINANCE"), &"Test Message");
+
+        assert!(check_handler_was_called(handler1));
+        assert!(!check_handler_was_called(handler2)); // Only matches five character types
+        assert!(!check_handler_was_called(handler3));
+        assert_eq!(msgbus.subscriptions_count("data.quotes.BINANCE"), 1);
+    }
+
+    #[rstest]
+    fn test_matching_subscriptions_updated_on_subscribe_and_unsubscribe() {
+        let mut msgbus = stub_msgbus();
+        let topic = Ustr::from("data.quotes.BINANCE");
+        let handler_id1 = Ustr::from("1");
+        let handler1 = get_stub_shareable_handler(Some(handler_id1));
+        let handler_id2 = Ustr::from("2");
+        let handler2 = get_stub_shareable_handler(Some(handler_id2));
+
+        msgbus.subscribe("data.*", handler1.clone(), None);
+        assert_eq!(msgbus.matching_subscriptions(&topic).len(), 1); // Cached
+
+        msgbus.subscribe("data.quotes.*", handler2.clone(), Some(1));
+        let subs = msgbus.matching_subscriptions(&topic);
+        assert_eq!(subs.len(), 2);
+        assert_eq!(subs[0].handler_id, handler_id2);
+        assert_eq!(subs[1].handler_id, handler_id1);
+
+        msgbus.unsubscribe("data.quotes.*", handler2);
+        let subs = msgbus.matching_subscriptions(&topic);
+        assert_eq!(subs.len(), 1);
+        assert_eq!(subs[0].handler_id, handler_id1);
+
+        msgbus.unsubscribe("data.*", handler1);
+        assert!(msgbus.matching_subscriptions(&topic).is_empty());
+    }
+
+    #[rstest]
+    fn test_unsubscribe_removes_subscription_cached_on_publish() {
+        let mut msgbus = stub_msgbus();
+        let topic = Ustr::from("data.quotes.BINANCE");
+        let handler = get_call_check_shareable_handler(None);
+
+        msgbus.subscribe("data.*", handler.clone(), None);
+        msgbus.publish(&topic, &"Test Message"); // Caches the topic on a miss
+        assert!(check_handler_was_called(handler.clone()));
+
+        msgbus.unsubscribe("data.*", handler);
+
+        assert!(msgbus.matching_subscriptions(&topic).is_empty());
+    }
+
+    #[rstest]
+    fn test_subscriptions_count_does_not_cache_pattern() {
+        let mut msgbus = stub_msgbus();
+        let handler = get_stub_shareable_handler(None);
+        msgbus.subscribe("data.*", handler, None);
+
+        assert_eq!(msgbus.subscriptions_count("data.quotes.*"), 1);
+        assert!(msgbus.patterns.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_matching_subscriptions_order_is_deterministic() {
+        let mut msgbus = stub_msgbus();
+        let topic = Ustr::from("data.quotes.BINANCE");
+        let ids: Vec<Ustr> = (0..5).map(|i| Ustr::from(&i.to_string())).collect();
+
+        // Subscribe after the topic is cached, and mixed with pattern subscriptions
+        let _ = msgbus.matching_subscriptions(&topic);
+        for (i, id) in ids.iter().enumerate() {
+            let pattern = if i % 2 == 0 {
+                "data.*"
+            } else {
+                "data.quotes.BINANCE"
+            };
+            msgbus.subscribe(pattern, get_stub_shareable_handler(Some(*id)), None);
+        }
+
+        let subs = msgbus.matching_subscriptions(&topic);
+        let handler_ids: Vec<Ustr> = subs.iter().map(|s| s.handler_id).collect();
+        assert_eq!(handler_ids, ids);
+    }
+
+    #[rstest]
+    fn test_matching_subscriptions_priority_order_is_stable() {
+        let mut msgbus = stub_msgbus();
+        let topic = Ustr::from("events.order.SCALPER-001");
+        let subscriptions = [
+            ("strategy-1", "events.order.*", None),
+            ("risk", "events.*", Some(10)),
+            ("strategy-2", "events.order.SCALPER-001", None),
+            ("portfolio-1", "events.*", Some(5)),
+            ("portfolio-2", "events.order.*", Some(5)),
+        ];
+
+        for (i, (id, pattern, priority)) in subscriptions.iter().enumerate() {
+            // Cache the matching subscriptions partway through subscribing
+            if i == 2 {
+                let _ = msgbus.matching_subscriptions(&topic);
+            }
+            let handler = get_stub_shareable_handler(Some(Ustr::from(id)));
+            msgbus.subscribe(*pattern, handler, *priority);
+        }
+
+        let subs = msgbus.matching_subscriptions(&topic);
+        let handler_ids: Vec<&str> = subs.iter().map(|s| s.handler_id.as_str()).collect();
+        assert_eq!(
+            handler_ids,
+            vec![
+                "risk",
+                "portfolio-1",
+                "portfolio-2",
+                "strategy-1",
+                "strategy-2"
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_subscribe_buffered_holds_data_until_drained() {
+        let mut msgbus = stub_msgbus();
+        let topic = Ustr::from("data.quotes.SIM.AUDUSD");
+        let handler = get_data_saving_handler(None);
+        msgbus.subscribe_buffered(
+            topic,
+            handler.clone(),
+            None,
+            NonZeroUsize::new(2).unwrap(),
+            OverflowPolicy::DropOldest,
+        );
+
+        for _ in 0..3 {
+            msgbus.publish_data(&topic, Data::Quote(QuoteTick::default()));
+        }
+
+        assert!(get_saved_data(handler.clone()).is_empty());
+        assert_eq!(msgbus.dropped_count(), 1);
+
+        let stats = msgbus.buffer_stats();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].topic, topic);
+        assert_eq!(stats[0].len, 2);
+        assert_eq!(stats[0].capacity, 2);
+        assert_eq!(stats[0].policy, OverflowPolicy::DropOldest);
+        assert_eq!(stats[0].dropped, 1);
+
+        assert_eq!(msgbus.drain_buffers(), 2);
+        assert_eq!(get_saved_data(handler).len(), 2);
+        assert_eq!(msgbus.buffer_stats()[0].len, 0);
+    }
+
+    #[rstest]
+    fn test_subscribe_buffered_does_not_delay_unbuffered_subscribers() {
+        let mut msgbus = stub_msgbus();
+        let topic = Ustr::from("data.quotes.SIM.AUDUSD");
+        let slow_handler = get_data_saving_handler(None);
+        let fast_handler = get_data_saving_handler(None);
+        msgbus.subscribe_buffered(
+            topic,
+            slow_handler.clone(),
+            None,
+            NonZeroUsize::new(10).unwrap(),
+            OverflowPolicy::Block,
+        );
+        msgbus.subscribe(topic, fast_handler.clone(), None);
+
+        msgbus.publish_data(&topic, Data::Quote(QuoteTick::default()));
+
+        assert!(get_saved_data(slow_handler).is_empty());
+        assert_eq!(get_saved_data(fast_handler).len(), 1);
+    }
+
+    #[rstest]
+    fn test_unsubscribe_buffered_removes_buffer() {
+        let mut msgbus = stub_msgbus();
+        let topic = Ustr::from("data.quotes.SIM.AUDUSD");
+        let handler = get_data_saving_handler(None);
+        msgbus.subscribe_buffered(
+            topic,
+            handler.clone(),
+            None,
+            NonZeroUsize::new(10).unwrap(),
+            OverflowPolicy::Block,
+        );
+        msgbus.publish_data(&topic, Data::Quote(QuoteTick::default()));
+
+        msgbus.unsubscribe(topic, handler.clone());
+
+        assert!(msgbus.subscriptions().is_empty());
+        assert!(msgbus.buffer_stats().is_empty());
+        assert_eq!(msgbus.drain_buffers(), 0);
+        assert!(get_saved_data(handler).is_empty());
+    }
+
+    #[rstest]
+    fn test_dispatch_mode_defaults_to_immediate() {
+        let msgbus = stub_msgbus();
+        assert_eq!(msgbus.dispatch_mode(), DispatchMode::Immediate);
+        assert_eq!(msgbus.sequence(), 0);
+        assert_eq!(msgbus.current_sequence(), None);
+    }
+
+    #[rstest]
+    fn test_immediate_dispatch_delivers_nested_messages_first() {
+        let (msgbus, log) = republishing_msgbus(DispatchMode::Immediate);
+
+        msgbus
+            .borrow()
+            .publish_owned(&Ustr::from("events.test"), "a".to_string());
+
+        let pairs = |v: &[(&str, &str)]| -> Vec<(String, String)> {
+            v.iter()
+                .map(|(a, b)| ((*a).to_string(), (*b).to_string()))
+                .collect()
+        };
+        assert_eq!(
+            dispatch_order(&log),
+            pairs(&[
+                ("handler-1", "a"),
+                ("handler-1", "a-reply"),
+                ("handler-2", "a-reply"),
+                ("handler-2", "a"),
+            ])
+        );
+        assert_eq!(msgbus.borrow().sequence(), 0);
+    }
+
+    #[rstest]
+    fn test_sequenced_dispatch_delivers_messages_in_publish_order() {
+        let (msgbus, log) = republishing_msgbus(DispatchMode::Sequenced);
+        let topic = Ustr::from("events.test");
+
+        msgbus.borrow().publish_owned(&topic, "a".to_string());
+        msgbus.borrow().publish(&topic, &"b".to_string());
+
+        let expected: Vec<(Ustr, String, Option<u64>)> = [
+            ("handler-1", "a", 1),
+            ("handler-2", "a", 1),
+            ("handler-1", "a-reply", 2),
+            ("handler-2", "a-reply", 2),
+            ("handler-1", "b", 3),
+            ("handler-2", "b", 3),
+            ("handler-1", "b-reply", 4),
+            ("handler-2", "b-reply", 4),
+        ]
+        .iter()
+        .map(|(id, message, sequence)| (Ustr::from(id), (*message).to_string(), Some(*sequence)))
+        .collect();
+        assert_eq!(*log.borrow(), expected);
+
+        let msgbus = msgbus.borrow();
+        assert_eq!(msgbus.sequence(), 4);
+        assert_eq!(msgbus.current_sequence(), None);
+        assert_eq!(msgbus.queued_count(), 0);
+    }
+
+    #[rstest]
+    fn test_sequenced_dispatch_is_reproducible() {
+        let run = || {
+            let (msgbus, log) = republishing_msgbus(DispatchMode::Sequenced);
+            let topic = Ustr::from("events.test");
+            for i in 0..10 {
+                msgbus.borrow().publish_owned(&topic, i.to_string());
+            }
+            log.take()
+        };
+
+        assert_eq!(run(), run());
+    }
+
+    #[rstest]
+    fn test_sequenced_dispatch_recovers_after_handler_panic() {
+        let mut msgbus = MessageBus::new(
+            TraderId::from("trader-001"),
+            UUID4::new(),
+            None,
+            None,
+            Some(DispatchMode::Sequenced),
+        );
+        let topic = Ustr::from("events.test");
+        let handler = get_message_saving_handler::<String>(None);
+        msgbus.subscribe(topic, handler.clone(), None);
+
+        // The handler panics on a message type mismatch
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            msgbus.publish(&topic, &1_u64);
+        }));
+        assert!(result.is_err());
+        assert_eq!(msgbus.current_sequence(), None);
+
+        msgbus.publish(&topic, &"a".to_string());
+
+        assert_eq!(get_saved_messages::<String>(handler), vec!["a".to_string()]);
+        assert_eq!(msgbus.sequence(), 2);
+        assert_eq!(msgbus.queued_count(), 0);
+    }
+
+    #[rstest]
+    fn test_sequenced_publish_data() {
+        let mut msgbus = MessageBus::new(
+            TraderId::from("trader-001"),
+            UUID4::new(),
+            None,
+            None,
+            Some(DispatchMode::Sequenced),
+        );
+        let topic = Ustr::from("data.quotes.SIM.AUDUSD");
+        let handler = get_data_saving_handler(None);
+        msgbus.subscribe(topic, handler.clone(), None);
+
+        msgbus.publish_data(&topic, Data::Quote(QuoteTick::default()));
+        msgbus.publish_data(&topic, Data::Quote(QuoteTick::default()));
+
+        assert_eq!(get_saved_data(handler).len(), 2);
+        assert_eq!(msgbus.sequence(), 2);
+    }
+
+    #[rstest]
+    fn test_request_response() {
+        let mut msgbus = stub_msgbus();
+        let endpoint = Ustr::from("DataEngine.request");
+        let endpoint_handler = get_call_check_shareable_handler(None);
+        msgbus.register(endpoint, endpoint_handler.clone());
+
+        let correlation_id = UUID4::new();
+        let callback = get_response_saving_handler(None);
+
+        msgbus
+            .request(&endpoint, stub_request(correlation_id), callback.clone())
+            .unwrap();
+
+        assert!(check_handler_was_called(endpoint_handler));
+        assert!(msgbus.is_pending_response(&correlation_id));
+        assert_eq!(msgbus.pending_responses_count(), 1);
+
+        msgbus.response(stub_response(correlation_id)).unwrap();
+
+        assert_eq!(get_saved_response_ids(callback), vec![correlation_id]);
+        assert!(!msgbus.is_pending_response(&correlation_id));
+        assert_eq!(msgbus.pending_responses_count(), 0);
+    }
+
+    #[rstest]
+    fn test_request_when_no_endpoint_registered() {
+        let msgbus = stub_msgbus();
+        let endpoint = Ustr::from("DataEngine.request");
+        let correlation_id = UUID4::new();
+
+        let result = msgbus.request(
+            &endpoint,
+            stub_request(correlation_id),
+            get_response_saving_handler(None),
+        );
+
+        assert!(result.is_err());
+        assert!(!msgbus.is_pending_response(&correlation_id));
+    }
+
+    #[rstest]
+    fn test_request_with_duplicate_correlation_id() {
+        let mut msgbus = stub_msgbus();
+        let endpoint = Ustr::from("DataEngine.request");
+        msgbus.register(endpoint, get_call_check_shareable_handler(None));
+
+        let correlation_id = UUID4::new();
+        msgbus
+            .request(
+                &endpoint,
+                stub_request(correlation_id),
+                get_response_saving_handler(None),
+            )
+            .unwrap();
+
+        let result = msgbus.request(
+            &endpoint,
+            stub_request(correlation_id),
+            get_response_saving_handler(None),
+        );
+
+        assert!(result.is_err());
+        assert_eq!(msgbus.pending_responses_count(), 1);
+    }
+
+    #[rstest]
+    fn test_cancel_request() {
+        let mut msgbus = stub_msgbus();
+        let endpoint = Ustr::from("DataEngine.request");
+        msgbus.register(endpoint, get_call_check_shareable_handler(None));
+
+        let correlation_id = UUID4::new();
+        let callback = get_response_saving_handler(None);
+        msgbus
+            .request(&endpoint, stub_request(correlation_id), callback.clone())
+            .unwrap();
+
+        assert!(msgbus.cancel_request(&correlation_id));
+        assert!(!msgbus.cancel_request(&correlation_id));
+        assert_eq!(msgbus.pending_responses_count(), 0);
+        assert!(msgbus.response(stub_response(correlation_id)).is_err());
+        assert!(get_saved_response_ids(callback).is_empty());
+    }
+
+    #[rstest]
+    fn test_response_when_no_pending_request() {
+        let msgbus = stub_msgbus();
+
+        let result = msgbus.response(stub_response(UUID4::new()));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_is_matching_long_topic() {
+        let topic = Ustr::from(&format!("data.{}", "x".repeat(500)));
+
+        assert!(is_matching(&topic, &Ustr::from("data.*")));
+        assert!(!is_matching(&topic, &Ustr::from("data.*y")));
+    }
+
     #[rstest]
     #[case("*", "*", true)]
     #[case("a", "*", true)]
@@ -638,10 +1507,41 @@ mod tests {
     #[case("data.quotes.BINANCE", "data.*.BINANCE", true)]
     #[case("data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.*", true)]
     #[case("data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.ETH*", true)]
+    #[case("data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.BTC*", false)]
+    #[case("comp", "c?mp", true)]
+    #[case("coop", "c??p", true)]
+    #[case("camp", "c?p", false)]
+    #[case("", "*", true)]
+    #[case("", "?", false)]
     fn test_is_matching(#[case] topic: &str, #[case] pattern: &str, #[case] expected: bool) {
         assert_eq!(
             is_matching(&Ustr::from(topic), &Ustr::from(pattern)),
             expected
         );
     }
+
+    #[rstest]
+    fn test_time_event_publisher_delivers_time_alert_once() {
+        let msgbus = Rc::new(RefCell::new(stub_msgbus()));
+        let handler = get_message_saving_handler::<TimeEvent>(None);
+        msgbus
+            .borrow_mut()
+            .subscribe("events.time.alert", handler.clone(), None);
+
+        let mut clock = TestClock::new();
+        let callback = time_event_publisher(msgbus.clone(), "events.time.alert");
+        clock
+            .set_time_alert_ns("alert", 1_000.into(), Some(callback))
+            .unwrap();
+
+        let events = clock.advance_time(5_000.into(), true);
+        for handler in clock.match_handlers(events) {
+            handler.run();
+        }
+
+        let messages = get_saved_messages::<TimeEvent>(handler);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].name.as_str(), "alert");
+        assert_eq!(messages[0].ts_event, 1_000);
+    }
 }
@@ -23,7 +23,7 @@ use std::{
     },
 };
 
-use nautilus_core::message::Message;
+use nautilus_core::{message::Message, uuid::UUID4};
 use nautilus_model::data::Data;
 use ustr::Ustr;
 use uuid::Uuid;
@@ -184,3 +184,107 @@ pub fn get_saved_messages<T: Clone + 'static>(handler: ShareableMessageHandler)
         .unwrap()
         .get_messages()
 }
+
+// Handler which saves the correlation IDs of the responses it receives
+#[derive(Debug, Clone)]
+pub struct ResponseSavingHandler {
+    id: Ustr,
+    correlation_ids: Rc<RefCell<Vec<UUID4>>>,
+}
+
+impl ResponseSavingHandler {
+    #[must_use]
+    pub fn get_correlation_ids(&self) -> Vec<UUID4> {
+        self.correlation_ids.borrow().clone()
+    }
+}
+
+impl MessageHandler for ResponseSavingHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, _message: &dyn Any) {}
+
+    fn handle_response(&self, resp: DataResponse) {
+        self.correlation_ids.borrow_mut().push(resp.correlation_id);
+    }
+
+    fn handle_data(&self, _data: Data) {}
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[must_use]
+pub fn get_response_saving_handler(id: Option<Ustr>) -> ShareableMessageHandler {
+    let unique_id = id.unwrap_or_else(|| Ustr::from(&Uuid::new_v4().to_string()));
+    ShareableMessageHandler(Rc::new(ResponseSavingHandler {
+        id: unique_id,
+        correlation_ids: Rc::new(RefCell::new(Vec::new())),
+    }))
+}
+
+#[must_use]
+pub fn get_saved_response_ids(handler: ShareableMessageHandler) -> Vec<UUID4> {
+    handler
+        .0
+        .as_ref()
+        .as_any()
+        .downcast_ref::<ResponseSavingHandler>()
+        .unwrap()
+        .get_correlation_ids()
+}
+
+// Handler which saves the data it receives
+#[derive(Debug, Clone)]
+pub struct DataSavingHandler {
+    id: Ustr,
+    data: Rc<RefCell<Vec<Data>>>,
+}
+
+impl DataSavingHandler {
+    #[must_use]
+    pub fn get_data(&self) -> Vec<Data> {
+        self.data.borrow().clone()
+    }
+}
+
+impl MessageHandler for DataSavingHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, _message: &dyn Any) {}
+
+    fn handle_response(&self, _resp: DataResponse) {}
+
+    fn handle_data(&self, data: Data) {
+        self.data.borrow_mut().push(data);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[must_use]
+pub fn get_data_saving_handler(id: Option<Ustr>) -> ShareableMessageHandler {
+    let unique_id = id.unwrap_or_else(|| Ustr::from(&Uuid::new_v4().to_string()));
+    ShareableMessageHandler(Rc::new(DataSavingHandler {
+        id: unique_id,
+        data: Rc::new(RefCell::new(Vec::new())),
+    }))
+}
+
+#[must_use]
+pub fn get_saved_data(handler: ShareableMessageHandler) -> Vec<Data> {
+    handler
+        .0
+        .as_ref()
+        .as_any()
+        .downcast_ref::<DataSavingHandler>()
+        .unwrap()
+        .get_data()
+}
@@ -28,6 +28,9 @@ pub struct MessagingSwitchboard {
     pub data_engine_process: Ustr,
     pub exec_engine_execute: Ustr,
     pub exec_engine_process: Ustr,
+    pub exec_engine_reconcile: Ustr,
+    pub order_emulator_execute: Ustr,
+    pub risk_engine_execute: Ustr,
     custom_topics: HashMap<DataType, Ustr>,
     instrument_topics: HashMap<InstrumentId, Ustr>,
     deltas_topics: HashMap<InstrumentId, Ustr>,
@@ -35,6 +38,9 @@ pub struct MessagingSwitchboard {
     depth_topics: HashMap<InstrumentId, Ustr>,
     quote_topics: HashMap<InstrumentId, Ustr>,
     trade_topics: HashMap<InstrumentId, Ustr>,
+    funding_rate_topics: HashMap<InstrumentId, Ustr>,
+    mark_price_topics: HashMap<InstrumentId, Ustr>,
+    index_price_topics: HashMap<InstrumentId, Ustr>,
     bar_topics: HashMap<BarType, Ustr>,
     order_snapshots_topics: HashMap<ClientOrderId, Ustr>,
 }
@@ -47,6 +53,9 @@ impl Default for MessagingSwitchboard {
             data_engine_process: Ustr::from("DataEngine.process"),
             exec_engine_execute: Ustr::from("ExecEngine.execute"),
             exec_engine_process: Ustr::from("ExecEngine.process"),
+            exec_engine_reconcile: Ustr::from("ExecEngine.reconcile_mass_status"),
+            order_emulator_execute: Ustr::from("OrderEmulator.execute"),
+            risk_engine_execute: Ustr::from("RiskEngine.execute"),
             custom_topics: HashMap::new(),
             instrument_topics: HashMap::new(),
             deltas_topics: HashMap::new(),
@@ -54,6 +63,9 @@ impl Default for MessagingSwitchboard {
             depth_topics: HashMap::new(),
             quote_topics: HashMap::new(),
             trade_topics: HashMap::new(),
+            funding_rate_topics: HashMap::new(),
+            mark_price_topics: HashMap::new(),
+            index_price_topics: HashMap::new(),
             bar_topics: HashMap::new(),
             order_snapshots_topics: HashMap::new(),
         }
@@ -135,6 +147,45 @@ impl MessagingSwitchboard {
         })
     }
 
+    #[must_use]
+    pub fn get_funding_rates_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .funding_rate_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.funding.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_mark_prices_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .mark_price_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.mark_prices.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_index_prices_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .index_price_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.index_prices.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
     #[must_use]
     pub fn get_bars_topic(&mut self, bar_type: BarType) -> Ustr {
         *self
@@ -240,6 +291,39 @@ mod tests {
         assert!(switchboard.trade_topics.contains_key(&instrument_id));
     }
 
+    #[rstest]
+    fn test_get_funding_rates_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.funding.XCME.ESZ24");
+        let result = switchboard.get_funding_rates_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.funding_rate_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_mark_prices_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.mark_prices.XCME.ESZ24");
+        let result = switchboard.get_mark_prices_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.mark_price_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_index_prices_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.index_prices.XCME.ESZ24");
+        let result = switchboard.get_index_prices_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.index_price_topics.contains_key(&instrument_id));
+    }
+
     #[rstest]
     fn test_get_bars_topic(mut switchboard: MessagingSwitchboard) {
         let bar_type = BarType::from("ESZ24.XCME-1-MINUTE-LAST-INTERNAL");
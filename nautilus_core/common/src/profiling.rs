@@ -0,0 +1,658 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Optional lightweight self-profiling of memory allocations and CPU time per component.
+//!
+//! CPU time is measured around each profiled call as the thread CPU time spent inside the
+//! component (exclusive of any nested profiled component). Allocation tracking requires
+//! installing the [`CountingAllocator`] as the global allocator of the final binary:
+//!
+//! ```ignore
+//! use nautilus_common::profiling::CountingAllocator;
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator = CountingAllocator;
+//! ```
+//!
+//! Without the counting allocator installed, allocation counts are reported as zero.
+//!
+//! Allocation counters are shared process-wide and assigned to components by name, so
+//! profilers which register the same component name share its allocation counts.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt::Display,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, PoisonError,
+    },
+};
+
+use indexmap::IndexMap;
+use nautilus_model::data::Data;
+use ustr::Ustr;
+
+use crate::{
+    clock::Clock,
+    messages::data::DataResponse,
+    msgbus::handler::{MessageHandler, ShareableMessageHandler},
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// The maximum number of components which can have allocations attributed to them,
+/// across all profilers in the process.
+pub const MAX_PROFILED_COMPONENTS: usize = 64;
+
+// Slot 0 holds allocations made outside of any profiled component
+const SLOTS: usize = MAX_PROFILED_COMPONENTS + 1;
+
+static ALLOC_COUNTS: [AtomicU64; SLOTS] = [const { AtomicU64::new(0) }; SLOTS];
+static ALLOC_BYTES: [AtomicU64; SLOTS] = [const { AtomicU64::new(0) }; SLOTS];
+static DEALLOC_BYTES: AtomicU64 = AtomicU64::new(0);
+
+// The component assigned to each slot (offset by one), shared by all profilers
+static SLOT_COMPONENTS: Mutex<Vec<Ustr>> = Mutex::new(Vec::new());
+
+thread_local! {
+    static CURRENT_SLOT: Cell<usize> = const { Cell::new(0) };
+}
+
+fn current_slot() -> usize {
+    // Thread locals may already be destroyed during thread teardown
+    CURRENT_SLOT.try_with(Cell::get).unwrap_or(0)
+}
+
+fn set_current_slot(slot: usize) {
+    let _ = CURRENT_SLOT.try_with(|current| current.set(slot));
+}
+
+/// Returns the allocation slot for the given `component`, assigning the next free slot on
+/// first use, or `None` if all slots are assigned.
+fn component_slot(component: Ustr) -> Option<usize> {
+    let mut components = SLOT_COMPONENTS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some(index) = components.iter().position(|c| *c == component) {
+        return Some(index + 1);
+    }
+    if components.len() >= MAX_PROFILED_COMPONENTS {
+        return None;
+    }
+    components.push(component);
+    Some(components.len())
+}
+
+fn record_alloc(size: usize) {
+    let slot = current_slot();
+    ALLOC_COUNTS[slot].fetch_add(1, Ordering::Relaxed);
+    ALLOC_BYTES[slot].fetch_add(size as u64, Ordering::Relaxed);
+}
+
+fn record_dealloc(size: usize) {
+    DEALLOC_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+}
+
+fn alloc_counters(slot: usize) -> (u64, u64) {
+    if slot == 0 {
+        return (0, 0);
+    }
+    (
+        ALLOC_COUNTS[slot].load(Ordering::Relaxed),
+        ALLOC_BYTES[slot].load(Ordering::Relaxed),
+    )
+}
+
+fn unattributed_alloc_counters() -> (u64, u64) {
+    (
+        ALLOC_COUNTS[0].load(Ordering::Relaxed),
+        ALLOC_BYTES[0].load(Ordering::Relaxed),
+    )
+}
+
+/// Returns the number of bytes currently allocated through the [`CountingAllocator`].
+#[must_use]
+pub fn allocated_bytes_in_use() -> u64 {
+    let allocated: u64 = ALLOC_BYTES
+        .iter()
+        .map(|bytes| bytes.load(Ordering::Relaxed))
+        .sum();
+    allocated.saturating_sub(DEALLOC_BYTES.load(Ordering::Relaxed))
+}
+
+/// Provides a global allocator which wraps the system allocator, counting allocations
+/// and attributing them to the currently profiled component (if any).
+///
+/// A reallocation is counted as a new allocation of the new size.
+#[derive(Debug, Default)]
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Returns the CPU time (nanoseconds) consumed by the current thread.
+#[cfg(unix)]
+#[must_use]
+pub fn thread_cpu_time_ns() -> u64 {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `ts` is a valid pointer to a `timespec` for the duration of the call
+    unsafe {
+        libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts);
+    }
+    ts.tv_sec as u64 * 1_000_000_000 + ts.tv_nsec as u64
+}
+
+/// Returns the monotonic elapsed time (nanoseconds) as an approximation of the CPU time
+/// consumed by the current thread, on platforms without a thread CPU clock.
+#[cfg(not(unix))]
+#[must_use]
+pub fn thread_cpu_time_ns() -> u64 {
+    use std::{sync::OnceLock, time::Instant};
+
+    static ANCHOR: OnceLock<Instant> = OnceLock::new();
+    let elapsed = ANCHOR.get_or_init(Instant::now).elapsed().as_nanos();
+    u64::try_from(elapsed).unwrap_or(u64::MAX)
+}
+
+/// Represents the resources used by a single component over a report interval.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ComponentResourceUsage {
+    /// The component name.
+    pub component: Ustr,
+    /// The number of profiled calls into the component.
+    pub calls: u64,
+    /// The CPU time (nanoseconds) spent in the component, excluding nested components.
+    pub cpu_time_ns: u64,
+    /// The number of allocations made by the component.
+    pub alloc_count: u64,
+    /// The number of bytes allocated by the component.
+    pub alloc_bytes: u64,
+}
+
+impl Display for ComponentResourceUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(component={}, calls={}, cpu_time_ns={}, alloc_count={}, alloc_bytes={})",
+            stringify!(ComponentResourceUsage),
+            self.component,
+            self.calls,
+            self.cpu_time_ns,
+            self.alloc_count,
+            self.alloc_bytes,
+        )
+    }
+}
+
+/// Represents a periodic resource report aggregated across all profiled components.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResourceReport {
+    /// The per-component usage, sorted by CPU time descending.
+    pub components: Vec<ComponentResourceUsage>,
+    /// The number of allocations made outside of any profiled component.
+    pub unattributed_alloc_count: u64,
+    /// The number of bytes allocated outside of any profiled component.
+    pub unattributed_alloc_bytes: u64,
+    /// The number of bytes currently allocated (requires the [`CountingAllocator`]).
+    pub bytes_in_use: u64,
+}
+
+impl Display for ResourceReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Resource report (bytes_in_use={}, unattributed_alloc_count={}, unattributed_alloc_bytes={}):",
+            self.bytes_in_use, self.unattributed_alloc_count, self.unattributed_alloc_bytes,
+        )?;
+        for usage in &self.components {
+            writeln!(f, "  {usage}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ComponentEntry {
+    // Zero when no allocation slot was free, in which case allocations are unattributed
+    slot: usize,
+    calls: u64,
+    cpu_time_ns: u64,
+    alloc_baseline: (u64, u64),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ActiveScope {
+    component: Ustr,
+    slot: usize,
+    start_cpu_ns: u64,
+    nested_cpu_ns: u64,
+}
+
+/// Provides per-component CPU time and allocation profiling, aggregated into periodic
+/// [`ResourceReport`]s.
+///
+/// Profiling is disabled by default, in which case entering and exiting components are no-ops.
+#[derive(Debug, Default)]
+pub struct ResourceProfiler {
+    enabled: bool,
+    components: IndexMap<Ustr, ComponentEntry>,
+    stack: Vec<ActiveScope>,
+    unattributed_baseline: (u64, u64),
+}
+
+impl ResourceProfiler {
+    /// Creates a new [`ResourceProfiler`] instance.
+    #[must_use]
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            unattributed_baseline: unattributed_alloc_counters(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns whether profiling is enabled.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Sets whether profiling is enabled.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Returns the names of the registered components.
+    #[must_use]
+    pub fn components(&self) -> Vec<Ustr> {
+        self.components.keys().copied().collect()
+    }
+
+    /// Registers the given `component` for profiling (no-op if already registered).
+    ///
+    /// If [`MAX_PROFILED_COMPONENTS`] already have allocation counters then the CPU time of
+    /// the component is still profiled, but its allocations are reported as unattributed.
+    pub fn register(&mut self, component: Ustr) {
+        if self.components.contains_key(&component) {
+            return;
+        }
+
+        let slot = component_slot(component).unwrap_or_else(|| {
+            log::warn!(
+                "Cannot attribute allocations to {component}: maximum of {MAX_PROFILED_COMPONENTS} profiled components reached"
+            );
+            0
+        });
+        self.components.insert(
+            component,
+            ComponentEntry {
+                slot,
+                calls: 0,
+                cpu_time_ns: 0,
+                alloc_baseline: alloc_counters(slot),
+            },
+        );
+    }
+
+    /// Enters the given `component`, attributing CPU time and allocations to it until
+    /// the matching call to [`ResourceProfiler::exit`].
+    ///
+    /// Unregistered components are registered on first entry.
+    pub fn enter(&mut self, component: Ustr) {
+        if !self.enabled {
+            return;
+        }
+
+        self.register(component);
+
+        let now = thread_cpu_time_ns();
+        let entry = self
+            .components
+            .get_mut(&component)
+            .expect("component was registered");
+        entry.calls += 1;
+        let slot = entry.slot;
+
+        self.stack.push(ActiveScope {
+            component,
+            slot,
+            start_cpu_ns: now,
+            nested_cpu_ns: 0,
+        });
+        set_current_slot(slot);
+    }
+
+    /// Exits the most recently entered component.
+    pub fn exit(&mut self) {
+        let Some(scope) = self.stack.pop() else {
+            return;
+        };
+
+        let elapsed = thread_cpu_time_ns().saturating_sub(scope.start_cpu_ns);
+        if let Some(entry) = self.components.get_mut(&scope.component) {
+            entry.cpu_time_ns += elapsed.saturating_sub(scope.nested_cpu_ns);
+        }
+
+        let parent_slot = match self.stack.last_mut() {
+            Some(parent) => {
+                parent.nested_cpu_ns += elapsed;
+                parent.slot
+            }
+            None => 0,
+        };
+        set_current_slot(parent_slot);
+    }
+
+    /// Returns the resource report for the interval since the last report, and begins a
+    /// new interval.
+    pub fn report(&mut self) -> ResourceReport {
+        let mut components: Vec<ComponentResourceUsage> = self
+            .components
+            .iter_mut()
+            .map(|(component, entry)| {
+                let (count, bytes) = alloc_counters(entry.slot);
+                let usage = ComponentResourceUsage {
+                    component: *component,
+                    calls: entry.calls,
+                    cpu_time_ns: entry.cpu_time_ns,
+                    alloc_count: count - entry.alloc_baseline.0,
+                    alloc_bytes: bytes - entry.alloc_baseline.1,
+                };
+                entry.calls = 0;
+                entry.cpu_time_ns = 0;
+                entry.alloc_baseline = (count, bytes);
+                usage
+            })
+            .collect();
+        components.sort_by_key(|usage| std::cmp::Reverse(usage.cpu_time_ns));
+
+        let (count, bytes) = unattributed_alloc_counters();
+        let report = ResourceReport {
+            components,
+            unattributed_alloc_count: count - self.unattributed_baseline.0,
+            unattributed_alloc_bytes: bytes - self.unattributed_baseline.1,
+            bytes_in_use: allocated_bytes_in_use(),
+        };
+        self.unattributed_baseline = (count, bytes);
+
+        report
+    }
+}
+
+/// Exits the entered component when dropped, including when unwinding from a panic.
+struct ProfileScopeGuard<'a> {
+    profiler: &'a Rc<RefCell<ResourceProfiler>>,
+}
+
+impl Drop for ProfileScopeGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut profiler) = self.profiler.try_borrow_mut() {
+            profiler.exit();
+        }
+    }
+}
+
+/// Runs `f` within the given `component` of the shared `profiler`.
+///
+/// The profiler is only borrowed while entering and exiting, so `f` may itself run
+/// other profiled components. The component is exited even if `f` panics.
+pub fn profile<R>(
+    profiler: &Rc<RefCell<ResourceProfiler>>,
+    component: Ustr,
+    f: impl FnOnce() -> R,
+) -> R {
+    profiler.borrow_mut().enter(component);
+    let _guard = ProfileScopeGuard { profiler };
+    f()
+}
+
+/// Provides a message handler which profiles an inner handler as the given component.
+pub struct ProfiledMessageHandler {
+    component: Ustr,
+    inner: ShareableMessageHandler,
+    profiler: Rc<RefCell<ResourceProfiler>>,
+}
+
+impl ProfiledMessageHandler {
+    /// Creates a new [`ProfiledMessageHandler`] instance.
+    #[must_use]
+    pub fn new(
+        component: Ustr,
+        inner: ShareableMessageHandler,
+        profiler: Rc<RefCell<ResourceProfiler>>,
+    ) -> Self {
+        Self {
+            component,
+            inner,
+            profiler,
+        }
+    }
+}
+
+impl MessageHandler for ProfiledMessageHandler {
+    fn id(&self) -> Ustr {
+        self.inner.0.id()
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        profile(&self.profiler, self.component, || {
+            self.inner.0.handle(message)
+        });
+    }
+
+    fn handle_response(&self, resp: DataResponse) {
+        profile(&self.profiler, self.component, || {
+            self.inner.0.handle_response(resp);
+        });
+    }
+
+    fn handle_data(&self, data: Data) {
+        profile(&self.profiler, self.component, || {
+            self.inner.0.handle_data(data)
+        });
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Sets a timer on the given `clock` which periodically logs a [`ResourceReport`].
+///
+/// # Errors
+///
+/// This function returns an error if the timer cannot be set.
+pub fn set_resource_report_timer(
+    profiler: Rc<RefCell<ResourceProfiler>>,
+    clock: &mut dyn Clock,
+    name: &str,
+    interval_ns: u64,
+) -> anyhow::Result<()> {
+    let callback = TimeEventCallback::Rust(Rc::new(move |_event: TimeEvent| {
+        let mut profiler = profiler.borrow_mut();
+        if profiler.is_enabled() {
+            log::info!("{}", profiler.report());
+        }
+    }));
+    let start_time_ns = clock.timestamp_ns();
+    clock.set_timer_ns(name, interval_ns, start_time_ns, None, Some(callback))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::clock::TestClock;
+
+    fn busy_work() -> u64 {
+        (0..200_000u64).fold(0, |acc, x| acc.wrapping_add(x * x))
+    }
+
+    #[rstest]
+    fn test_disabled_profiler_records_nothing() {
+        let mut profiler = ResourceProfiler::new(false);
+        let component = Ustr::from("Strategy-001");
+
+        profiler.enter(component);
+        profiler.exit();
+
+        assert!(profiler.components().is_empty());
+        assert!(profiler.report().components.is_empty());
+    }
+
+    #[rstest]
+    fn test_enter_registers_and_counts_calls() {
+        let mut profiler = ResourceProfiler::new(true);
+        let component = Ustr::from("Strategy-001");
+
+        for _ in 0..3 {
+            profiler.enter(component);
+            std::hint::black_box(busy_work());
+            profiler.exit();
+        }
+
+        let report = profiler.report();
+        assert_eq!(profiler.components(), vec![component]);
+        assert_eq!(report.components.len(), 1);
+        assert_eq!(report.components[0].component, component);
+        assert_eq!(report.components[0].calls, 3);
+    }
+
+    #[rstest]
+    fn test_report_resets_interval() {
+        let mut profiler = ResourceProfiler::new(true);
+        let component = Ustr::from("DataEngine");
+
+        profiler.enter(component);
+        profiler.exit();
+        let _ = profiler.report();
+
+        let report = profiler.report();
+        assert_eq!(report.components[0].calls, 0);
+        assert_eq!(report.components[0].cpu_time_ns, 0);
+    }
+
+    #[rstest]
+    fn test_nested_components_are_exclusive() {
+        let profiler = Rc::new(RefCell::new(ResourceProfiler::new(true)));
+        let outer = Ustr::from("RiskEngine");
+        let inner = Ustr::from("ExecEngine");
+
+        profile(&profiler, outer, || {
+            profile(&profiler, inner, || std::hint::black_box(busy_work()));
+        });
+
+        let report = profiler.borrow_mut().report();
+        let outer_usage = report.components.iter().find(|u| u.component == outer);
+        let inner_usage = report.components.iter().find(|u| u.component == inner);
+        assert_eq!(outer_usage.unwrap().calls, 1);
+        assert_eq!(inner_usage.unwrap().calls, 1);
+        assert!(outer_usage.unwrap().cpu_time_ns <= inner_usage.unwrap().cpu_time_ns);
+        assert_eq!(current_slot(), 0);
+    }
+
+    #[rstest]
+    fn test_exit_without_enter_is_noop() {
+        let mut profiler = ResourceProfiler::new(true);
+        profiler.exit();
+
+        assert_eq!(current_slot(), 0);
+    }
+
+    #[rstest]
+    fn test_profilers_share_slots_by_component_name() {
+        let mut profiler1 = ResourceProfiler::new(true);
+        let mut profiler2 = ResourceProfiler::new(true);
+        let component1 = Ustr::from("SlotStrategy-001");
+        let component2 = Ustr::from("SlotStrategy-002");
+
+        profiler1.register(component1);
+        profiler2.register(component2);
+        profiler2.register(component1);
+
+        let slot1 = profiler1.components[&component1].slot;
+        assert_ne!(slot1, 0);
+        assert_ne!(profiler2.components[&component2].slot, slot1);
+        assert_eq!(profiler2.components[&component1].slot, slot1);
+    }
+
+    #[rstest]
+    fn test_profile_exits_component_on_panic() {
+        let profiler = Rc::new(RefCell::new(ResourceProfiler::new(true)));
+        let component = Ustr::from("PanickingActor");
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            profile(&profiler, component, || panic!("handler failed"));
+        }));
+
+        assert!(result.is_err());
+        assert!(profiler.borrow().stack.is_empty());
+        assert_eq!(current_slot(), 0);
+        assert_eq!(profiler.borrow_mut().report().components[0].calls, 1);
+    }
+
+    #[rstest]
+    fn test_set_resource_report_timer() {
+        let profiler = Rc::new(RefCell::new(ResourceProfiler::new(true)));
+        let mut clock = TestClock::new();
+
+        set_resource_report_timer(profiler, &mut clock, "resource-report", 1_000).unwrap();
+
+        assert_eq!(clock.timer_names(), vec!["resource-report"]);
+        let events = clock.advance_time(2_000.into(), true);
+        assert_eq!(events.len(), 2);
+        for handler in clock.match_handlers(events) {
+            handler.run();
+        }
+    }
+}
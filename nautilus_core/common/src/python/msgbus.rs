@@ -49,12 +49,7 @@ impl MessageBus {
     /// Publish a message to a topic.
     #[pyo3(name = "publish")]
     pub fn publish_py(&self, topic: &str, message: PyObject) {
-        let topic = Ustr::from(topic);
-        let matching_subs = self.matching_subscriptions(&topic);
-
-        for sub in matching_subs {
-            sub.handler.0.handle(&message);
-        }
+        self.publish_owned(&Ustr::from(topic), message);
     }
 
     /// Registers the given `handler` for the `endpoint` address.
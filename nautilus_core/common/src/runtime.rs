@@ -17,7 +17,15 @@
 
 use std::sync::OnceLock;
 
-use tokio::runtime::Runtime;
+use futures::future::BoxFuture;
+use nautilus_core::time::get_atomic_clock_realtime;
+use tokio::{
+    runtime::Runtime,
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+};
+
+use crate::watchdog::ClientActivity;
 
 static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
 
@@ -35,3 +43,186 @@ pub fn get_runtime() -> &'static tokio::runtime::Runtime {
     // Using default configuration values for now
     RUNTIME.get_or_init(|| Runtime::new().expect("Failed to create tokio runtime"))
 }
+
+/// A command run against the client owned by a [`ClientTask`].
+type ClientCommand<C> = Box<dyn for<'a> FnOnce(&'a mut C) -> BoxFuture<'a, ()> + Send>;
+
+/// Runs an async venue client on the shared runtime.
+///
+/// The task forwards the events streamed by the client to a channel, while synchronous
+/// callers (such as the data and execution engines) run commands against the client with
+/// [`ClientTask::call`]. Streaming pauses once the client returns no event (e.g. when
+/// disconnected), and resumes after the next command.
+pub struct ClientTask<C> {
+    cmd_tx: mpsc::UnboundedSender<ClientCommand<C>>,
+    handle: JoinHandle<()>,
+    activity: ClientActivity,
+}
+
+impl<C: Send + 'static> ClientTask<C> {
+    /// Spawns a new [`ClientTask`] owning the `client`, sending each event returned by
+    /// `next_event` to the `event_tx` channel.
+    ///
+    /// The `next_event` future is dropped when a command arrives, so must be cancel safe.
+    pub fn spawn<E, N>(mut client: C, next_event: N, event_tx: mpsc::UnboundedSender<E>) -> Self
+    where
+        E: Send + 'static,
+        N: for<'a> Fn(&'a mut C) -> BoxFuture<'a, Option<E>> + Send + 'static,
+    {
+        let (cmd_tx, mut cmd_rx) = mpsc::unbounded_channel::<ClientCommand<C>>();
+        let activity = ClientActivity::default();
+        let task_activity = activity.clone();
+
+        let handle = get_runtime().spawn(async move {
+            let mut is_streaming = true;
+            loop {
+                tokio::select! {
+                    biased;
+                    cmd = cmd_rx.recv() => match cmd {
+                        Some(cmd) => {
+                            cmd(&mut client).await;
+                            is_streaming = true;
+                        }
+                        None => break, // Handle dropped
+                    },
+                    event = next_event(&mut client), if is_streaming => match event {
+                        Some(event) => {
+                            task_activity.record(get_atomic_clock_realtime().get_time_ns());
+                            if event_tx.send(event).is_err() {
+                                break; // Receiver dropped
+                            }
+                        }
+                        None => is_streaming = false,
+                    },
+                }
+            }
+        });
+
+        Self {
+            cmd_tx,
+            handle,
+            activity,
+        }
+    }
+
+    /// Runs `f` against the client, blocking until it completes and returning its result.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the task has stopped, or `f` returns an error.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if called from within an async context.
+    pub fn call<T, F>(&self, f: F) -> anyhow::Result<T>
+    where
+        T: Send + 'static,
+        F: for<'a> FnOnce(&'a mut C) -> BoxFuture<'a, anyhow::Result<T>> + Send + 'static,
+    {
+        let (result_tx, result_rx) = oneshot::channel();
+        let command = client_command(move |client: &mut C| {
+            Box::pin(async move {
+                let _ = result_tx.send(f(client).await);
+            })
+        });
+
+        if self.cmd_tx.send(command).is_err() {
+            anyhow::bail!("Client task has stopped");
+        }
+        get_runtime()
+            .block_on(result_rx)
+            .map_err(|_| anyhow::anyhow!("Client task has stopped"))?
+    }
+
+    /// Returns the activity of the client, recorded as each event is received, for
+    /// monitoring by a [`ConnectionWatchdog`](crate::watchdog::ConnectionWatchdog).
+    #[must_use]
+    pub fn activity(&self) -> ClientActivity {
+        self.activity.clone()
+    }
+
+    /// Returns whether the task is still running.
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        !self.handle.is_finished()
+    }
+}
+
+impl<C> Drop for ClientTask<C> {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+fn client_command<C, F>(f: F) -> ClientCommand<C>
+where
+    F: for<'a> FnOnce(&'a mut C) -> BoxFuture<'a, ()> + Send + 'static,
+{
+    Box::new(f)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    struct CountingClient {
+        remaining: u32,
+    }
+
+    impl CountingClient {
+        async fn next(&mut self) -> Option<u32> {
+            if self.remaining == 0 {
+                return None;
+            }
+            self.remaining -= 1;
+            Some(self.remaining)
+        }
+    }
+
+    #[rstest]
+    fn test_client_task_streams_events_and_resumes_after_command() {
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let task = ClientTask::spawn(
+            CountingClient { remaining: 2 },
+            |client| Box::pin(client.next()),
+            event_tx,
+        );
+
+        assert_eq!(get_runtime().block_on(event_rx.recv()), Some(1));
+        assert_eq!(get_runtime().block_on(event_rx.recv()), Some(0));
+        assert!(task.activity().last_received().as_u64() > 0);
+
+        let remaining = task
+            .call(|client| {
+                Box::pin(async move {
+                    client.remaining = 1;
+                    Ok(client.remaining)
+                })
+            })
+            .unwrap();
+
+        assert_eq!(remaining, 1);
+        assert_eq!(get_runtime().block_on(event_rx.recv()), Some(0));
+        assert!(task.is_running());
+    }
+
+    #[rstest]
+    fn test_client_task_call_returns_error() {
+        let (event_tx, _event_rx) = mpsc::unbounded_channel::<u32>();
+        let task = ClientTask::spawn(
+            CountingClient { remaining: 0 },
+            |client| Box::pin(client.next()),
+            event_tx,
+        );
+
+        let result: anyhow::Result<()> =
+            task.call(|_| Box::pin(async { anyhow::bail!("Request failed") }));
+
+        assert!(result.is_err());
+    }
+}
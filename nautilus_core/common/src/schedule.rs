@@ -0,0 +1,420 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Calendar-aware schedules for use with `Clock` timers.
+//!
+//! A [`CronSchedule`] is parsed from a standard five-field cron expression and is evaluated
+//! in a specific timezone, so schedules such as "every weekday at 16:00 exchange time"
+//! remain correct across daylight saving transitions.
+
+use std::fmt::Display;
+
+use chrono::{DateTime, Datelike, LocalResult, NaiveDate, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+use nautilus_core::{correctness::check_valid_string, nanos::UnixNanos};
+
+/// The maximum number of days searched forward for the next matching time.
+///
+/// Covers a full leap year cycle so expressions such as "29 Feb" resolve.
+const MAX_SEARCH_DAYS: u32 = 366 * 4 + 1;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// Represents a calendar schedule defined by a cron expression in a given timezone.
+///
+/// The expression has five whitespace separated fields:
+///
+/// | Field        | Values          | Names     |
+/// |--------------|-----------------|-----------|
+/// | minute       | 0-59            |           |
+/// | hour         | 0-23            |           |
+/// | day of month | 1-31            |           |
+/// | month        | 1-12            | JAN-DEC   |
+/// | day of week  | 0-7 (0 or 7 is Sunday) | SUN-SAT |
+///
+/// Each field accepts `*`, single values, ranges (`a-b`), steps (`*/n`, `a-b/n`, `a/n`)
+/// and comma separated lists of these. As with standard cron, when both the day of month
+/// and day of week fields are restricted a day matches if *either* field matches.
+///
+/// Times are interpreted as wall clock times in the schedule's timezone:
+/// - Local times which do not exist (skipped by a DST transition) do not fire.
+/// - Local times which occur twice (repeated by a DST transition) fire once, at the earliest instant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronSchedule {
+    expression: String,
+    tz: Tz,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    dom_restricted: bool,
+    dow_restricted: bool,
+}
+
+impl CronSchedule {
+    /// Creates a new [`CronSchedule`] instance from the given cron `expression` and timezone.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `expression` is not a valid string.
+    /// - If `expression` does not have exactly five fields.
+    /// - If any field contains an invalid or out of range value.
+    pub fn new(expression: &str, tz: Tz) -> anyhow::Result<Self> {
+        check_valid_string(expression, stringify!(expression))?;
+
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        if fields.len() != 5 {
+            anyhow::bail!(
+                "Invalid cron expression '{expression}': expected 5 fields, was {}",
+                fields.len()
+            );
+        }
+
+        let minutes = parse_field(fields[0], 0, 59, None)?;
+        let hours = parse_field(fields[1], 0, 23, None)?;
+        let days_of_month = parse_field(fields[2], 1, 31, None)?;
+        let months = parse_field(fields[3], 1, 12, Some((MONTH_NAMES.as_slice(), 1)))?;
+        let mut days_of_week = parse_field(fields[4], 0, 7, Some((WEEKDAY_NAMES.as_slice(), 0)))?;
+
+        // Normalize Sunday as 7 to Sunday as 0
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+
+        Ok(Self {
+            expression: expression.to_string(),
+            tz,
+            minutes,
+            hours,
+            days_of_month,
+            months,
+            days_of_week,
+            dom_restricted: fields[2] != "*",
+            dow_restricted: fields[4] != "*",
+        })
+    }
+
+    /// Creates a new [`CronSchedule`] instance evaluated in UTC.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `expression` is invalid (see [`CronSchedule::new`]).
+    pub fn utc(expression: &str) -> anyhow::Result<Self> {
+        Self::new(expression, Tz::UTC)
+    }
+
+    /// Returns the cron expression for the schedule.
+    #[must_use]
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// Returns the timezone the schedule is evaluated in.
+    #[must_use]
+    pub const fn tz(&self) -> Tz {
+        self.tz
+    }
+
+    /// Returns the next time (UNIX nanoseconds) matching the schedule strictly after `after_ns`.
+    ///
+    /// Returns `None` if no matching time exists within the search horizon (around four years),
+    /// e.g. for an expression such as "0 0 31 2 *".
+    #[must_use]
+    pub fn next_after(&self, after_ns: UnixNanos) -> Option<UnixNanos> {
+        let after = DateTime::<Utc>::from_timestamp_nanos(after_ns.as_i64());
+        let local = after.with_timezone(&self.tz).naive_local();
+
+        let mut date = local.date();
+        // Minute of day strictly after the local wall time of `after_ns`
+        let mut min_minute_of_day = local.hour() * 60 + local.minute() + 1;
+
+        for _ in 0..MAX_SEARCH_DAYS {
+            if self.matches_date(date) {
+                if let Some(ts) = self.next_on_date(date, min_minute_of_day, after_ns) {
+                    return Some(ts);
+                }
+            }
+            date = date.succ_opt()?;
+            min_minute_of_day = 0;
+        }
+
+        None
+    }
+
+    fn next_on_date(
+        &self,
+        date: NaiveDate,
+        min_minute_of_day: u32,
+        after_ns: UnixNanos,
+    ) -> Option<UnixNanos> {
+        for hour in (0..24).filter(|h| self.hours & (1 << h) != 0) {
+            for minute in (0..60).filter(|m| self.minutes & (1 << m) != 0) {
+                if hour * 60 + minute < min_minute_of_day {
+                    continue;
+                }
+
+                let naive = date.and_hms_opt(hour, minute, 0)?;
+                let dt = match self.tz.from_local_datetime(&naive) {
+                    LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => dt,
+                    LocalResult::None => continue, // Skipped by DST transition
+                };
+
+                let ts = UnixNanos::from(u64::try_from(dt.timestamp_nanos_opt()?).ok()?);
+                if ts > after_ns {
+                    return Some(ts);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+
+        let dom_match = self.days_of_month & (1 << date.day()) != 0;
+        let dow_match = self.days_of_week & (1 << date.weekday().num_days_from_sunday()) != 0;
+
+        match (self.dom_restricted, self.dow_restricted) {
+            (true, true) => dom_match || dow_match,
+            _ => dom_match && dow_match,
+        }
+    }
+}
+
+impl Display for CronSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.expression, self.tz)
+    }
+}
+
+/// Parses a single cron `field` into a bitmask of the allowed values within `min..=max`.
+fn parse_field(
+    field: &str,
+    min: u32,
+    max: u32,
+    names: Option<(&[&str], u32)>,
+) -> anyhow::Result<u64> {
+    let mut mask = 0_u64;
+
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step.parse().map_err(|_| {
+                    anyhow::anyhow!("Invalid step '{step}' in cron field '{field}'")
+                })?;
+                if step == 0 {
+                    anyhow::bail!("Invalid step of zero in cron field '{field}'");
+                }
+                (range, step)
+            }
+            None => (item, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (
+                parse_value(start, field, names)?,
+                parse_value(end, field, names)?,
+            )
+        } else {
+            let start = parse_value(range, field, names)?;
+            // A single value with a step runs to the end of the range, e.g. `5/15`
+            let end = if item.contains('/') { max } else { start };
+            (start, end)
+        };
+
+        if start < min || end > max || start > end {
+            anyhow::bail!("Invalid range {start}-{end} in cron field '{field}', valid {min}-{max}");
+        }
+
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+
+    Ok(mask)
+}
+
+fn parse_value(value: &str, field: &str, names: Option<(&[&str], u32)>) -> anyhow::Result<u32> {
+    if let Ok(value) = value.parse::<u32>() {
+        return Ok(value);
+    }
+
+    if let Some((names, offset)) = names {
+        let upper = value.to_ascii_uppercase();
+        if let Some(index) = names.iter().position(|name| *name == upper) {
+            return Ok(index as u32 + offset);
+        }
+    }
+
+    anyhow::bail!("Invalid value '{value}' in cron field '{field}'")
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn utc_ns(s: &str) -> UnixNanos {
+        let naive = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+        UnixNanos::from(naive.and_utc().timestamp_nanos_opt().unwrap() as u64)
+    }
+
+    #[rstest]
+    #[case("* * * *")]
+    #[case("* * * * * *")]
+    #[case("60 * * * *")]
+    #[case("* 24 * * *")]
+    #[case("* * 0 * *")]
+    #[case("* * * 13 *")]
+    #[case("* * * * 8")]
+    #[case("*/0 * * * *")]
+    #[case("5-1 * * * *")]
+    #[case("* * * FOO *")]
+    fn test_invalid_expressions(#[case] expression: &str) {
+        assert!(CronSchedule::utc(expression).is_err());
+    }
+
+    #[rstest]
+    fn test_every_minute() {
+        let schedule = CronSchedule::utc("* * * * *").unwrap();
+        assert_eq!(
+            schedule.next_after(utc_ns("2024-01-01 00:00:00")),
+            Some(utc_ns("2024-01-01 00:01:00"))
+        );
+        assert_eq!(
+            schedule.next_after(utc_ns("2024-01-01 00:00:30")),
+            Some(utc_ns("2024-01-01 00:01:00"))
+        );
+    }
+
+    #[rstest]
+    fn test_steps_and_lists() {
+        let schedule = CronSchedule::utc("5/15 9,17 * * *").unwrap();
+        assert_eq!(
+            schedule.next_after(utc_ns("2024-01-01 09:05:00")),
+            Some(utc_ns("2024-01-01 09:20:00"))
+        );
+        assert_eq!(
+            schedule.next_after(utc_ns("2024-01-01 09:50:00")),
+            Some(utc_ns("2024-01-01 17:05:00"))
+        );
+    }
+
+    #[rstest]
+    fn test_weekdays_skips_weekend() {
+        // 2024-01-05 is a Friday
+        let schedule = CronSchedule::utc("0 16 * * MON-FRI").unwrap();
+        assert_eq!(
+            schedule.next_after(utc_ns("2024-01-05 16:00:00")),
+            Some(utc_ns("2024-01-08 16:00:00"))
+        );
+    }
+
+    #[rstest]
+    fn test_sunday_as_seven() {
+        // 2024-01-07 is a Sunday
+        let schedule = CronSchedule::utc("0 0 * * 7").unwrap();
+        assert_eq!(
+            schedule.next_after(utc_ns("2024-01-01 00:00:00")),
+            Some(utc_ns("2024-01-07 00:00:00"))
+        );
+    }
+
+    #[rstest]
+    fn test_day_of_month_or_day_of_week() {
+        // 2024-01-03 is a Wednesday
+        let schedule = CronSchedule::utc("0 0 15 * WED").unwrap();
+        assert_eq!(
+            schedule.next_after(utc_ns("2024-01-01 00:00:00")),
+            Some(utc_ns("2024-01-03 00:00:00"))
+        );
+        assert_eq!(
+            schedule.next_after(utc_ns("2024-01-10 00:00:00")),
+            Some(utc_ns("2024-01-15 00:00:00"))
+        );
+    }
+
+    #[rstest]
+    fn test_leap_day() {
+        let schedule = CronSchedule::utc("0 0 29 2 *").unwrap();
+        assert_eq!(
+            schedule.next_after(utc_ns("2024-03-01 00:00:00")),
+            Some(utc_ns("2028-02-29 00:00:00"))
+        );
+    }
+
+    #[rstest]
+    fn test_impossible_date_returns_none() {
+        let schedule = CronSchedule::utc("0 0 31 2 *").unwrap();
+        assert_eq!(schedule.next_after(utc_ns("2024-01-01 00:00:00")), None);
+    }
+
+    #[rstest]
+    fn test_timezone_across_dst_transition() {
+        // New York switches from EST (UTC-5) to EDT (UTC-4) on 2024-03-10
+        let schedule = CronSchedule::new("0 16 * * *", Tz::America__New_York).unwrap();
+        assert_eq!(
+            schedule.next_after(utc_ns("2024-03-08 22:00:00")),
+            Some(utc_ns("2024-03-09 21:00:00"))
+        );
+        assert_eq!(
+            schedule.next_after(utc_ns("2024-03-09 21:00:00")),
+            Some(utc_ns("2024-03-10 20:00:00"))
+        );
+    }
+
+    #[rstest]
+    fn test_local_time_in_dst_gap_is_skipped() {
+        // 02:30 does not exist in New York on 2024-03-10
+        let schedule = CronSchedule::new("30 2 * * *", Tz::America__New_York).unwrap();
+        assert_eq!(
+            schedule.next_after(utc_ns("2024-03-09 08:00:00")),
+            Some(utc_ns("2024-03-11 06:30:00"))
+        );
+    }
+
+    #[rstest]
+    fn test_repeated_local_time_fires_once() {
+        // 01:30 occurs twice in New York on 2024-11-03 (EDT then EST)
+        let schedule = CronSchedule::new("30 1 * * *", Tz::America__New_York).unwrap();
+        let first = schedule.next_after(utc_ns("2024-11-03 04:00:00")).unwrap();
+        assert_eq!(first, utc_ns("2024-11-03 05:30:00"));
+        assert_eq!(
+            schedule.next_after(first),
+            Some(utc_ns("2024-11-04 06:30:00"))
+        );
+    }
+
+    #[rstest]
+    fn test_display() {
+        let schedule = CronSchedule::new("0 16 * * MON-FRI", Tz::America__New_York).unwrap();
+        assert_eq!(schedule.to_string(), "0 16 * * MON-FRI (America/New_York)");
+    }
+}
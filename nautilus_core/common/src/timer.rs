@@ -49,7 +49,7 @@ use tokio::{
 };
 use ustr::Ustr;
 
-use crate::runtime::get_runtime;
+use crate::{runtime::get_runtime, schedule::CronSchedule};
 
 /// Creates a valid nanoseconds interval that is guaranteed to be positive.
 ///
@@ -344,6 +344,107 @@ impl Iterator for TestTimer {
     }
 }
 
+/// A test timer which fires according to a calendar [`CronSchedule`], for use with a `TestClock`.
+///
+/// Unlike a [`TestTimer`] the interval between events is not fixed, the next event time
+/// is evaluated from the schedule each time the timer fires.
+#[derive(Clone, Debug)]
+pub struct TestCronTimer {
+    /// The name of the timer.
+    pub name: Ustr,
+    /// The calendar schedule for the timer.
+    pub schedule: CronSchedule,
+    /// The optional stop time of the timer in UNIX nanoseconds.
+    pub stop_time_ns: Option<UnixNanos>,
+    next_time_ns: Option<UnixNanos>,
+    is_expired: bool,
+}
+
+impl TestCronTimer {
+    /// Creates a new [`TestCronTimer`] instance.
+    ///
+    /// The first event is the first time matching `schedule` strictly after `start_time_ns`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `name` is not a valid string.
+    #[must_use]
+    pub fn new(
+        name: &str,
+        schedule: CronSchedule,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+    ) -> Self {
+        check_valid_string(name, stringify!(name)).expect(FAILED);
+
+        let next_time_ns = schedule.next_after(start_time_ns);
+        let is_expired = is_schedule_exhausted(next_time_ns, stop_time_ns);
+
+        Self {
+            name: Ustr::from(name),
+            schedule,
+            stop_time_ns,
+            next_time_ns,
+            is_expired,
+        }
+    }
+
+    /// Returns the next time in UNIX nanoseconds when the timer will fire.
+    ///
+    /// Returns zero if the timer has no further scheduled events.
+    #[must_use]
+    pub fn next_time_ns(&self) -> UnixNanos {
+        self.next_time_ns.unwrap_or_default()
+    }
+
+    /// Returns whether the timer is expired.
+    #[must_use]
+    pub const fn is_expired(&self) -> bool {
+        self.is_expired
+    }
+
+    /// Advance the test timer forward to the given time, generating a sequence
+    /// of events. A [`TimeEvent`] is appended for each scheduled time <= the given `to_time_ns`.
+    pub fn advance(&mut self, to_time_ns: UnixNanos) -> Vec<TimeEvent> {
+        let mut events = Vec::new();
+
+        while !self.is_expired {
+            let Some(next_time_ns) = self.next_time_ns else {
+                break;
+            };
+            if next_time_ns > to_time_ns {
+                break;
+            }
+
+            events.push(TimeEvent::new(
+                self.name,
+                UUID4::new(),
+                next_time_ns,
+                next_time_ns,
+            ));
+
+            self.next_time_ns = self.schedule.next_after(next_time_ns);
+            self.is_expired = is_schedule_exhausted(self.next_time_ns, self.stop_time_ns);
+        }
+
+        events
+    }
+
+    /// Cancels the timer (the timer will not generate an event).
+    pub fn cancel(&mut self) {
+        self.is_expired = true;
+    }
+}
+
+fn is_schedule_exhausted(next_time_ns: Option<UnixNanos>, stop_time_ns: Option<UnixNanos>) -> bool {
+    match (next_time_ns, stop_time_ns) {
+        (None, _) => true,
+        (Some(next_time_ns), Some(stop_time_ns)) => next_time_ns > stop_time_ns,
+        (Some(_), None) => false,
+    }
+}
+
 /// A live timer for use with a `LiveClock`.
 ///
 /// `LiveTimer` triggers events at specified intervals in a real-time environment,
@@ -531,6 +632,177 @@ impl LiveTimer {
     }
 }
 
+/// A live timer which fires according to a calendar [`CronSchedule`], for use with a `LiveClock`.
+///
+/// `LiveCronTimer` sleeps until each scheduled time in a real-time environment,
+/// using Tokio's async runtime to handle scheduling and execution.
+pub struct LiveCronTimer {
+    /// The name of the timer.
+    pub name: Ustr,
+    /// The calendar schedule for the timer.
+    pub schedule: CronSchedule,
+    /// The optional stop time of the timer in UNIX nanoseconds.
+    pub stop_time_ns: Option<UnixNanos>,
+    next_time_ns: Arc<AtomicU64>,
+    callback: TimeEventCallback,
+    task_handle: Option<JoinHandle<()>>,
+    #[cfg(feature = "clock_v2")]
+    heap: Arc<Mutex<BinaryHeap<TimeEvent>>>,
+}
+
+impl LiveCronTimer {
+    /// Creates a new [`LiveCronTimer`] instance.
+    ///
+    /// The first event is the first time matching `schedule` strictly after `start_time_ns`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `name` is not a valid string.
+    #[must_use]
+    #[cfg(not(feature = "clock_v2"))]
+    pub fn new(
+        name: &str,
+        schedule: CronSchedule,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+        callback: TimeEventCallback,
+    ) -> Self {
+        check_valid_string(name, stringify!(name)).expect(FAILED);
+
+        log::debug!("Creating cron timer '{name}' for {schedule}");
+        let next_time_ns = schedule.next_after(start_time_ns).unwrap_or_default();
+        Self {
+            name: Ustr::from(name),
+            schedule,
+            stop_time_ns,
+            next_time_ns: Arc::new(AtomicU64::new(next_time_ns.as_u64())),
+            callback,
+            task_handle: None,
+        }
+    }
+
+    /// Creates a new [`LiveCronTimer`] instance.
+    ///
+    /// The first event is the first time matching `schedule` strictly after `start_time_ns`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `name` is not a valid string.
+    #[must_use]
+    #[cfg(feature = "clock_v2")]
+    pub fn new(
+        name: &str,
+        schedule: CronSchedule,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+        callback: TimeEventCallback,
+        heap: Arc<Mutex<BinaryHeap<TimeEvent>>>,
+    ) -> Self {
+        check_valid_string(name, stringify!(name)).expect(FAILED);
+
+        log::debug!("Creating cron timer '{name}' for {schedule}");
+        let next_time_ns = schedule.next_after(start_time_ns).unwrap_or_default();
+        Self {
+            name: Ustr::from(name),
+            schedule,
+            stop_time_ns,
+            next_time_ns: Arc::new(AtomicU64::new(next_time_ns.as_u64())),
+            callback,
+            heap,
+            task_handle: None,
+        }
+    }
+
+    /// Returns the next time in UNIX nanoseconds when the timer will fire.
+    ///
+    /// Returns zero if the timer has no further scheduled events.
+    #[must_use]
+    pub fn next_time_ns(&self) -> UnixNanos {
+        UnixNanos::from(self.next_time_ns.load(atomic::Ordering::SeqCst))
+    }
+
+    /// Returns whether the timer is expired.
+    ///
+    /// An expired timer will not trigger any further events.
+    /// A timer that has not been started is not expired.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.task_handle
+            .as_ref()
+            .is_some_and(tokio::task::JoinHandle::is_finished)
+    }
+
+    /// Starts the timer.
+    ///
+    /// Time events will begin triggering at the scheduled times.
+    /// The generated events are handled by the provided callback function.
+    pub fn start(&mut self) {
+        let event_name = self.name;
+        let schedule = self.schedule.clone();
+        let stop_time_ns = self.stop_time_ns;
+        let next_time_atomic = self.next_time_ns.clone();
+        let mut next_time_ns = UnixNanos::from(next_time_atomic.load(atomic::Ordering::SeqCst));
+
+        #[cfg(feature = "clock_v2")]
+        let heap = self.heap.clone();
+
+        let callback = self.callback.clone();
+        let rt = get_runtime();
+
+        let handle = rt.spawn(async move {
+            let clock = get_atomic_clock_realtime();
+
+            loop {
+                if next_time_ns == 0 || stop_time_ns.is_some_and(|stop| next_time_ns > stop) {
+                    break; // Schedule exhausted
+                }
+
+                let now_ns = clock.get_time_ns();
+                if next_time_ns > now_ns {
+                    let diff: u64 = (next_time_ns - now_ns).into();
+                    tokio::time::sleep(Duration::from_nanos(diff)).await;
+                }
+                let now_ns = clock.get_time_ns();
+
+                #[cfg(feature = "python")]
+                {
+                    match callback {
+                        TimeEventCallback::Python(ref callback) => {
+                            call_python_with_time_event(event_name, next_time_ns, now_ns, callback);
+                        }
+                        // Note: Clock v1 style path should not be called with Rust callback
+                        TimeEventCallback::Rust(_) => {}
+                    };
+                }
+
+                #[cfg(feature = "clock_v2")]
+                {
+                    let event = TimeEvent::new(event_name, UUID4::new(), next_time_ns, now_ns);
+                    heap.lock().await.push(event);
+                }
+
+                // Prepare next scheduled time
+                next_time_ns = schedule.next_after(next_time_ns).unwrap_or_default();
+                next_time_atomic.store(next_time_ns.as_u64(), atomic::Ordering::SeqCst);
+            }
+        });
+
+        self.task_handle = Some(handle);
+    }
+
+    /// Cancels the timer.
+    ///
+    /// The timer will not generate a final event.
+    pub fn cancel(&mut self) {
+        log::debug!("Cancel cron timer '{}'", self.name);
+        if let Some(ref handle) = self.task_handle {
+            handle.abort();
+        }
+    }
+}
+
 #[cfg(feature = "python")]
 fn call_python_with_time_event(
     name: Ustr,
@@ -564,7 +836,8 @@ mod tests {
     use nautilus_core::nanos::UnixNanos;
     use rstest::*;
 
-    use super::{TestTimer, TimeEvent};
+    use super::{TestCronTimer, TestTimer, TimeEvent};
+    use crate::schedule::CronSchedule;
 
     #[rstest]
     fn test_test_timer_pop_event() {
@@ -644,4 +917,29 @@ mod tests {
         assert_eq!(timer.advance(UnixNanos::from(10)).count(), 5);
         assert!(timer.is_expired);
     }
+
+    #[rstest]
+    fn test_test_cron_timer_advance() {
+        let minute_ns: u64 = 60_000_000_000;
+        let schedule = CronSchedule::utc("*/15 * * * *").unwrap();
+        let mut timer = TestCronTimer::new("test_timer", schedule, UnixNanos::default(), None);
+
+        assert_eq!(timer.next_time_ns(), 15 * minute_ns);
+        assert_eq!(timer.advance(UnixNanos::from(14 * minute_ns)).len(), 0);
+        assert_eq!(timer.advance(UnixNanos::from(45 * minute_ns)).len(), 3);
+        assert_eq!(timer.next_time_ns(), 60 * minute_ns);
+        assert!(!timer.is_expired());
+    }
+
+    #[rstest]
+    fn test_test_cron_timer_with_stop_time_before_first_event_is_expired() {
+        let schedule = CronSchedule::utc("0 * * * *").unwrap();
+        let timer = TestCronTimer::new(
+            "test_timer",
+            schedule,
+            UnixNanos::default(),
+            Some(UnixNanos::from(1)),
+        );
+        assert!(timer.is_expired());
+    }
 }
@@ -0,0 +1,535 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Heartbeat monitoring of live data and execution client connections.
+//!
+//! Clients report each message received from their venue (either directly, or through a shared
+//! [`ClientActivity`] updated from the client's async task), and a periodic check compares the
+//! time since the last message against the configured thresholds. A silent connection is first
+//! pinged (where the client supports protocol-level pings), then marked degraded, and finally
+//! stale, at which point a reconnect can be triggered automatically. Each change of health is
+//! raised as a [`ConnectionHealthEvent`].
+
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{
+    correctness::{check_positive_u64, check_predicate_true},
+    nanos::UnixNanos,
+};
+use nautilus_model::identifiers::ClientId;
+use strum::Display;
+use ustr::Ustr;
+
+use crate::{
+    clock::Clock,
+    msgbus::MessageBus,
+    timer::{TimeEvent, TimeEventCallback},
+};
+
+/// A callback invoked by the watchdog for a client, e.g. to send a ping or trigger a reconnect.
+///
+/// Callbacks are invoked while the watchdog is borrowed, so must not call back into it
+/// synchronously (async work such as sending a ping should be spawned).
+pub type WatchdogCallback = Rc<dyn Fn(&ClientId)>;
+
+/// Provides the time of the last message received by a client, which can be shared with
+/// the async task receiving the messages.
+#[derive(Clone, Debug, Default)]
+pub struct ClientActivity(Arc<AtomicU64>);
+
+impl ClientActivity {
+    /// Records a message received at `ts_received`.
+    pub fn record(&self, ts_received: UnixNanos) {
+        self.0.fetch_max(ts_received.as_u64(), Ordering::Relaxed);
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) of the last message received (zero if none).
+    #[must_use]
+    pub fn last_received(&self) -> UnixNanos {
+        UnixNanos::from(self.0.load(Ordering::Relaxed))
+    }
+}
+
+/// The health of a monitored client connection.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConnectionHealth {
+    /// Messages are being received within the degraded threshold.
+    Healthy,
+    /// No message has been received for longer than the degraded threshold.
+    Degraded,
+    /// No message has been received for longer than the stale threshold.
+    Stale,
+}
+
+/// Configuration for a [`ConnectionWatchdog`].
+#[derive(Clone, Debug)]
+pub struct WatchdogConfig {
+    /// The silence (nanoseconds) after which a ping is sent, repeated at the same interval.
+    pub ping_after_ns: u64,
+    /// The silence (nanoseconds) after which a connection is degraded.
+    pub degraded_threshold_ns: u64,
+    /// The silence (nanoseconds) after which a connection is stale.
+    pub stale_threshold_ns: u64,
+    /// If a reconnect is triggered for stale connections, repeated each stale threshold.
+    pub auto_reconnect: bool,
+}
+
+impl Default for WatchdogConfig {
+    /// Creates a new default [`WatchdogConfig`] instance.
+    fn default() -> Self {
+        Self {
+            ping_after_ns: 5_000_000_000,          // 5s
+            degraded_threshold_ns: 10_000_000_000, // 10s
+            stale_threshold_ns: 30_000_000_000,    // 30s
+            auto_reconnect: false,
+        }
+    }
+}
+
+/// Represents a change in the health of a monitored client connection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConnectionHealthEvent {
+    /// The client ID for the connection.
+    pub client_id: ClientId,
+    /// The current health of the connection.
+    pub health: ConnectionHealth,
+    /// The previous health of the connection.
+    pub previous: ConnectionHealth,
+    /// The time (nanoseconds) since the last message was received.
+    pub silent_ns: u64,
+    /// If a reconnect was triggered for the connection.
+    pub reconnect_triggered: bool,
+    /// UNIX timestamp (nanoseconds) when the change was detected.
+    pub ts_event: UnixNanos,
+}
+
+impl Display for ConnectionHealthEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(client_id={}, health={}, previous={}, silent_ns={}, reconnect_triggered={}, ts_event={})",
+            stringify!(ConnectionHealthEvent),
+            self.client_id,
+            self.health,
+            self.previous,
+            self.silent_ns,
+            self.reconnect_triggered,
+            self.ts_event,
+        )
+    }
+}
+
+struct WatchedClient {
+    last_received: UnixNanos,
+    last_ping: UnixNanos,
+    last_reconnect: Option<UnixNanos>,
+    health: ConnectionHealth,
+    ping: Option<WatchdogCallback>,
+    reconnect: Option<WatchdogCallback>,
+    activity: Option<ClientActivity>,
+}
+
+/// Provides heartbeat monitoring of live client connections.
+pub struct ConnectionWatchdog {
+    config: WatchdogConfig,
+    clients: IndexMap<ClientId, WatchedClient>,
+}
+
+impl std::fmt::Debug for ConnectionWatchdog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(ConnectionWatchdog))
+            .field("config", &self.config)
+            .field("clients", &self.clients.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl ConnectionWatchdog {
+    /// Creates a new [`ConnectionWatchdog`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `config.ping_after_ns` is not positive (> 0).
+    /// - If `config.degraded_threshold_ns` is not positive (> 0).
+    /// - If `config.stale_threshold_ns` is not greater than `config.degraded_threshold_ns`.
+    pub fn new(config: WatchdogConfig) -> anyhow::Result<Self> {
+        check_positive_u64(config.ping_after_ns, "config.ping_after_ns")?;
+        check_positive_u64(config.degraded_threshold_ns, "config.degraded_threshold_ns")?;
+        check_predicate_true(
+            config.stale_threshold_ns > config.degraded_threshold_ns,
+            "`config.stale_threshold_ns` was not greater than `config.degraded_threshold_ns`",
+        )?;
+
+        Ok(Self {
+            config,
+            clients: IndexMap::new(),
+        })
+    }
+
+    /// Returns the configuration for the watchdog.
+    #[must_use]
+    pub const fn config(&self) -> &WatchdogConfig {
+        &self.config
+    }
+
+    /// Registers the client with the given `client_id` for monitoring from `ts_now`.
+    ///
+    /// The optional `ping` callback sends a protocol-level ping, and the optional `reconnect`
+    /// callback is triggered for stale connections when auto-reconnect is enabled.
+    /// Registering an already monitored client replaces its callbacks and resets its health.
+    pub fn register(
+        &mut self,
+        client_id: ClientId,
+        ts_now: UnixNanos,
+        ping: Option<WatchdogCallback>,
+        reconnect: Option<WatchdogCallback>,
+    ) {
+        self.clients.insert(
+            client_id,
+            WatchedClient {
+                last_received: ts_now,
+                last_ping: ts_now,
+                last_reconnect: None,
+                health: ConnectionHealth::Healthy,
+                ping,
+                reconnect,
+                activity: None,
+            },
+        );
+    }
+
+    /// Deregisters the client with the given `client_id` from monitoring.
+    pub fn deregister(&mut self, client_id: &ClientId) {
+        self.clients.shift_remove(client_id);
+    }
+
+    /// Records a message received by the client with the given `client_id` at `ts_received`.
+    ///
+    /// Messages for unregistered clients are ignored.
+    pub fn record_message(&mut self, client_id: &ClientId, ts_received: UnixNanos) {
+        if let Some(client) = self.clients.get_mut(client_id) {
+            client.last_received = client.last_received.max(ts_received);
+        }
+    }
+
+    /// Sets the shared `activity` from which messages received by the client with the given
+    /// `client_id` are recorded at each check.
+    ///
+    /// Activity for unregistered clients is ignored.
+    pub fn set_activity(&mut self, client_id: &ClientId, activity: ClientActivity) {
+        if let Some(client) = self.clients.get_mut(client_id) {
+            client.activity = Some(activity);
+        }
+    }
+
+    /// Returns the current health of the client with the given `client_id` (if registered).
+    #[must_use]
+    pub fn health(&self, client_id: &ClientId) -> Option<ConnectionHealth> {
+        self.clients.get(client_id).map(|client| client.health)
+    }
+
+    /// Returns the client IDs of all monitored clients.
+    #[must_use]
+    pub fn client_ids(&self) -> Vec<ClientId> {
+        self.clients.keys().copied().collect()
+    }
+
+    /// Checks every monitored client at `ts_now`, sending pings and triggering reconnects as
+    /// configured. Returns an event for each client whose health changed.
+    pub fn check(&mut self, ts_now: UnixNanos) -> Vec<ConnectionHealthEvent> {
+        let mut events = Vec::new();
+
+        for (client_id, client) in &mut self.clients {
+            if let Some(activity) = &client.activity {
+                client.last_received = client.last_received.max(activity.last_received());
+            }
+            let silent_ns = ts_now
+                .as_u64()
+                .saturating_sub(client.last_received.as_u64());
+            let health = if silent_ns > self.config.stale_threshold_ns {
+                ConnectionHealth::Stale
+            } else if silent_ns > self.config.degraded_threshold_ns {
+                ConnectionHealth::Degraded
+            } else {
+                ConnectionHealth::Healthy
+            };
+
+            if let Some(ping) = &client.ping {
+                let since_ping = ts_now.as_u64().saturating_sub(client.last_ping.as_u64());
+                if silent_ns >= self.config.ping_after_ns && since_ping >= self.config.ping_after_ns
+                {
+                    log::debug!("Pinging {client_id} after {silent_ns}ns of silence");
+                    client.last_ping = ts_now;
+                    ping(client_id);
+                }
+            }
+
+            let mut reconnect_triggered = false;
+            if health == ConnectionHealth::Stale && self.config.auto_reconnect {
+                if let Some(reconnect) = &client.reconnect {
+                    let due = client.last_reconnect.map_or(true, |last| {
+                        ts_now.as_u64().saturating_sub(last.as_u64())
+                            > self.config.stale_threshold_ns
+                    });
+                    if due {
+                        log::warn!("Triggering reconnect for stale connection {client_id}");
+                        client.last_reconnect = Some(ts_now);
+                        reconnect(client_id);
+                        reconnect_triggered = true;
+                    }
+                }
+            }
+
+            if health == client.health && !reconnect_triggered {
+                continue;
+            }
+
+            let event = ConnectionHealthEvent {
+                client_id: *client_id,
+                health,
+                previous: client.health,
+                silent_ns,
+                reconnect_triggered,
+                ts_event: ts_now,
+            };
+            match health {
+                ConnectionHealth::Healthy => log::info!("Connection recovered: {event}"),
+                ConnectionHealth::Degraded | ConnectionHealth::Stale => {
+                    log::warn!("Connection unhealthy: {event}");
+                }
+            }
+
+            client.health = health;
+            events.push(event);
+        }
+
+        events
+    }
+}
+
+/// Returns the message bus topic on which health events for `client_id` are published.
+#[must_use]
+pub fn connection_health_topic(client_id: &ClientId) -> Ustr {
+    Ustr::from(format!("events.connection.{client_id}").as_str())
+}
+
+/// Sets a timer on the given `clock` which periodically runs [`ConnectionWatchdog::check`].
+///
+/// If a `msgbus` is given, each [`ConnectionHealthEvent`] is published on the topic
+/// returned by [`connection_health_topic`].
+///
+/// # Errors
+///
+/// This function returns an error if the timer cannot be set.
+pub fn set_watchdog_timer(
+    watchdog: Rc<RefCell<ConnectionWatchdog>>,
+    msgbus: Option<Rc<RefCell<MessageBus>>>,
+    clock: &mut dyn Clock,
+    name: &str,
+    interval_ns: u64,
+) -> anyhow::Result<()> {
+    let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+        let events = watchdog.borrow_mut().check(event.ts_event);
+        if let Some(msgbus) = &msgbus {
+            for health_event in events {
+                let topic = connection_health_topic(&health_event.client_id);
+                msgbus.borrow().publish(&topic, &health_event);
+            }
+        }
+    }));
+    let start_time_ns = clock.timestamp_ns();
+    clock.set_timer_ns(name, interval_ns, start_time_ns, None, Some(callback))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        clock::TestClock,
+        msgbus::stubs::{get_message_saving_handler, get_saved_messages},
+    };
+
+    fn watchdog(auto_reconnect: bool) -> ConnectionWatchdog {
+        ConnectionWatchdog::new(WatchdogConfig {
+            ping_after_ns: 100,
+            degraded_threshold_ns: 200,
+            stale_threshold_ns: 500,
+            auto_reconnect,
+        })
+        .unwrap()
+    }
+
+    fn counter() -> (Rc<Cell<usize>>, WatchdogCallback) {
+        let count = Rc::new(Cell::new(0));
+        let callback_count = count.clone();
+        let callback: WatchdogCallback =
+            Rc::new(move |_client_id: &ClientId| callback_count.set(callback_count.get() + 1));
+        (count, callback)
+    }
+
+    #[rstest]
+    fn test_new_with_invalid_config_errors() {
+        let config = WatchdogConfig {
+            degraded_threshold_ns: 500,
+            stale_threshold_ns: 500,
+            ..Default::default()
+        };
+        assert!(ConnectionWatchdog::new(config).is_err());
+    }
+
+    #[rstest]
+    fn test_health_transitions_and_recovery() {
+        let mut watchdog = watchdog(false);
+        let client_id = ClientId::from("BINANCE");
+        watchdog.register(client_id, 0.into(), None, None);
+
+        assert!(watchdog.check(150.into()).is_empty());
+        assert_eq!(watchdog.health(&client_id), Some(ConnectionHealth::Healthy));
+
+        let events = watchdog.check(300.into());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].health, ConnectionHealth::Degraded);
+        assert_eq!(events[0].previous, ConnectionHealth::Healthy);
+        assert_eq!(events[0].silent_ns, 300);
+
+        assert!(watchdog.check(400.into()).is_empty()); // No change
+        let events = watchdog.check(600.into());
+        assert_eq!(events[0].health, ConnectionHealth::Stale);
+        assert!(!events[0].reconnect_triggered);
+
+        watchdog.record_message(&client_id, 650.into());
+        let events = watchdog.check(700.into());
+        assert_eq!(events[0].health, ConnectionHealth::Healthy);
+        assert_eq!(events[0].previous, ConnectionHealth::Stale);
+    }
+
+    #[rstest]
+    fn test_shared_activity_recorded_at_check() {
+        let mut watchdog = watchdog(false);
+        let client_id = ClientId::from("BINANCE");
+        let activity = ClientActivity::default();
+        watchdog.register(client_id, 0.into(), None, None);
+        watchdog.set_activity(&client_id, activity.clone());
+
+        let events = watchdog.check(300.into());
+        assert_eq!(events[0].health, ConnectionHealth::Degraded);
+
+        activity.record(250.into());
+        let events = watchdog.check(350.into());
+        assert_eq!(events[0].health, ConnectionHealth::Healthy);
+        assert_eq!(activity.last_received(), UnixNanos::from(250));
+    }
+
+    #[rstest]
+    fn test_pings_sent_while_silent() {
+        let mut watchdog = watchdog(false);
+        let client_id = ClientId::from("BINANCE");
+        let (pings, ping) = counter();
+        watchdog.register(client_id, 0.into(), Some(ping), None);
+
+        watchdog.check(50.into());
+        assert_eq!(pings.get(), 0);
+        watchdog.check(100.into());
+        assert_eq!(pings.get(), 1);
+        watchdog.check(150.into()); // Within ping interval
+        assert_eq!(pings.get(), 1);
+        watchdog.check(200.into());
+        assert_eq!(pings.get(), 2);
+
+        watchdog.record_message(&client_id, 250.into());
+        watchdog.check(300.into());
+        assert_eq!(pings.get(), 2);
+    }
+
+    #[rstest]
+    fn test_auto_reconnect_triggered_for_stale_connection() {
+        let mut watchdog = watchdog(true);
+        let client_id = ClientId::from("BINANCE");
+        let (reconnects, reconnect) = counter();
+        watchdog.register(client_id, 0.into(), None, Some(reconnect));
+
+        let events = watchdog.check(600.into());
+        assert_eq!(reconnects.get(), 1);
+        assert!(events[0].reconnect_triggered);
+
+        assert!(watchdog.check(700.into()).is_empty()); // Reconnect not yet due again
+        let events = watchdog.check(1_200.into());
+        assert_eq!(reconnects.get(), 2);
+        assert_eq!(events[0].previous, ConnectionHealth::Stale);
+        assert!(events[0].reconnect_triggered);
+    }
+
+    #[rstest]
+    fn test_record_message_for_unregistered_client_is_ignored() {
+        let mut watchdog = watchdog(false);
+        let client_id = ClientId::from("BINANCE");
+        watchdog.record_message(&client_id, 100.into());
+
+        assert!(watchdog.health(&client_id).is_none());
+        assert!(watchdog.client_ids().is_empty());
+    }
+
+    #[rstest]
+    fn test_set_watchdog_timer_publishes_events() {
+        let client_id = ClientId::from("BINANCE");
+        let mut watchdog = watchdog(false);
+        watchdog.register(client_id, 0.into(), None, None);
+        let watchdog = Rc::new(RefCell::new(watchdog));
+
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let handler = get_message_saving_handler::<ConnectionHealthEvent>(None);
+        msgbus
+            .borrow_mut()
+            .subscribe("events.connection.*", handler.clone(), None);
+
+        let mut clock = TestClock::new();
+        set_watchdog_timer(watchdog.clone(), Some(msgbus), &mut clock, "watchdog", 300).unwrap();
+        let events = clock.advance_time(600.into(), true);
+        for handler in clock.match_handlers(events) {
+            handler.run();
+        }
+
+        let messages = get_saved_messages::<ConnectionHealthEvent>(handler);
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].health, ConnectionHealth::Degraded);
+        assert_eq!(messages[1].health, ConnectionHealth::Stale);
+        assert_eq!(
+            watchdog.borrow().health(&client_id),
+            Some(ConnectionHealth::Stale)
+        );
+        assert_eq!(
+            connection_health_topic(&client_id),
+            Ustr::from("events.connection.BINANCE")
+        );
+    }
+}
@@ -0,0 +1,48 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{cell::RefCell, rc::Rc};
+
+use nautilus_common::profiling::{profile, CountingAllocator, ResourceProfiler};
+use rstest::*;
+use ustr::Ustr;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[rstest]
+pub fn test_counting_allocator_attributes_allocations_to_components() {
+    let profiler1 = Rc::new(RefCell::new(ResourceProfiler::new(true)));
+    let profiler2 = Rc::new(RefCell::new(ResourceProfiler::new(true)));
+    let component1 = Ustr::from("AllocatingStrategy-001");
+    let component2 = Ustr::from("IdleStrategy-002");
+    profiler1.borrow_mut().register(component1);
+    profiler2.borrow_mut().register(component2);
+
+    profile(&profiler1, component1, || {
+        std::hint::black_box(vec![0u8; 4096]);
+    });
+    profile(&profiler2, component2, || {});
+
+    let report1 = profiler1.borrow_mut().report();
+    let report2 = profiler2.borrow_mut().report();
+    assert_eq!(report1.components[0].component, component1);
+    assert!(report1.components[0].alloc_count >= 1);
+    assert!(report1.components[0].alloc_bytes >= 4096);
+    assert_eq!(report2.components[0].component, component2);
+    assert_eq!(report2.components[0].alloc_count, 0);
+    assert_eq!(report2.components[0].alloc_bytes, 0);
+    assert!(report1.bytes_in_use > 0);
+}
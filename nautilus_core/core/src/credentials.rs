@@ -0,0 +1,380 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Credential resolution for adapters, with redaction of secret values.
+//!
+//! Credentials are described by a [`CredentialSource`] (an environment variable, a file, or
+//! a key held by an external secret manager) and resolved into a [`Secret`] by a
+//! [`CredentialResolver`]. External secret managers are integrated by implementing the
+//! [`SecretProvider`] trait and registering the provider with the resolver.
+
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    path::PathBuf,
+    sync::Arc,
+};
+
+use serde::{Deserialize, Deserializer};
+
+const REDACTED: &str = "****";
+
+/// Returns a redacted representation of the given secret `value`, safe for logging.
+///
+/// Values longer than eight characters retain their first and last two characters,
+/// all other values are fully masked.
+#[must_use]
+pub fn redact(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    if chars.len() > 8 {
+        let head: String = chars[..2].iter().collect();
+        let tail: String = chars[chars.len() - 2..].iter().collect();
+        format!("{head}...{tail}")
+    } else {
+        REDACTED.to_string()
+    }
+}
+
+/// A secret credential value which is redacted when formatted for display or debugging.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Creates a new [`Secret`] instance, validating the given `value`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `value` is empty or contains whitespace.
+    pub fn new(value: impl Into<String>) -> anyhow::Result<Self> {
+        let value = value.into();
+        if value.is_empty() {
+            anyhow::bail!("Invalid secret: value was empty");
+        }
+        if value.chars().any(char::is_whitespace) {
+            anyhow::bail!("Invalid secret: value contained whitespace");
+        }
+        Ok(Self(value))
+    }
+
+    /// Returns the underlying secret value.
+    ///
+    /// Care should be taken to never log the returned value.
+    #[must_use]
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns a redacted representation of the secret, safe for logging.
+    #[must_use]
+    pub fn redacted(&self) -> String {
+        redact(&self.0)
+    }
+}
+
+impl Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", stringify!(Secret), self.redacted())
+    }
+}
+
+impl Display for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.redacted())
+    }
+}
+
+impl<'de> Deserialize<'de> for Secret {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Provides secret values held by an external secret manager.
+pub trait SecretProvider: Send + Sync {
+    /// Returns the name the provider is registered under.
+    fn name(&self) -> &str;
+
+    /// Returns the secret value for the given `key`, or `None` if no such secret exists.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the secret manager cannot be queried.
+    fn get_secret(&self, key: &str) -> anyhow::Result<Option<String>>;
+}
+
+/// The source from which a credential is resolved.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum CredentialSource {
+    /// The credential is held in the environment variable `var`.
+    Env { var: String },
+    /// The credential is the content of the file at `path` (surrounding whitespace is trimmed).
+    File { path: PathBuf },
+    /// The credential is held under `key` by the secret manager registered as `provider`.
+    SecretManager { provider: String, key: String },
+}
+
+/// Resolves credentials from their [`CredentialSource`].
+#[derive(Clone, Default)]
+pub struct CredentialResolver {
+    providers: HashMap<String, Arc<dyn SecretProvider>>,
+}
+
+impl Debug for CredentialResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut providers: Vec<&String> = self.providers.keys().collect();
+        providers.sort();
+        f.debug_struct(stringify!(CredentialResolver))
+            .field("providers", &providers)
+            .finish()
+    }
+}
+
+impl CredentialResolver {
+    /// Creates a new [`CredentialResolver`] instance with no secret providers.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the given secret `provider`, replacing any provider with the same name.
+    pub fn register_provider(&mut self, provider: Arc<dyn SecretProvider>) {
+        self.providers.insert(provider.name().to_string(), provider);
+    }
+
+    /// Resolves the credential from the given `source`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The credential cannot be found at the source.
+    /// - The secret provider is not registered or fails.
+    /// - The resolved value is not a valid [`Secret`].
+    pub fn resolve(&self, source: &CredentialSource) -> anyhow::Result<Secret> {
+        let value = match source {
+            CredentialSource::Env { var } => std::env::var(var)
+                .map_err(|_| anyhow::anyhow!("Environment variable '{var}' not set"))?,
+            CredentialSource::File { path } => std::fs::read_to_string(path)
+                .map_err(|e| {
+                    anyhow::anyhow!("Error reading credential file '{}': {e}", path.display())
+                })?
+                .trim()
+                .to_string(),
+            CredentialSource::SecretManager { provider, key } => self
+                .providers
+                .get(provider)
+                .ok_or_else(|| anyhow::anyhow!("Secret provider '{provider}' not registered"))?
+                .get_secret(key)?
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Secret '{key}' not found with provider '{provider}'")
+                })?,
+        };
+
+        Secret::new(value)
+    }
+}
+
+/// Resolves an API key from the given `value` if provided, otherwise from the
+/// environment variable `env_var`.
+///
+/// # Errors
+///
+/// This function returns an error if no valid key is provided or set in the environment.
+pub fn resolve_api_key(value: Option<&str>, env_var: &str) -> anyhow::Result<Secret> {
+    match value {
+        Some(value) => Secret::new(value),
+        None => CredentialResolver::new()
+            .resolve(&CredentialSource::Env {
+                var: env_var.to_string(),
+            })
+            .map_err(|_| {
+                anyhow::anyhow!(
+                    "API key must be provided or set in the '{env_var}' environment variable"
+                )
+            }),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    struct StubSecretProvider;
+
+    impl SecretProvider for StubSecretProvider {
+        fn name(&self) -> &str {
+            "stub"
+        }
+
+        fn get_secret(&self, key: &str) -> anyhow::Result<Option<String>> {
+            match key {
+                "binance/api_key" => Ok(Some("abcdef123456".to_string())),
+                "broken" => anyhow::bail!("Secret manager unavailable"),
+                _ => Ok(None),
+            }
+        }
+    }
+
+    #[rstest]
+    #[case("", "****")]
+    #[case("abcd", "****")]
+    #[case("abcdefgh", "****")]
+    #[case("abcdefghi", "ab...hi")]
+    fn test_redact(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(redact(value), expected);
+    }
+
+    #[rstest]
+    fn test_secret_is_redacted_when_formatted() {
+        let secret = Secret::new("abcdef123456").unwrap();
+
+        assert_eq!(secret.expose(), "abcdef123456");
+        assert_eq!(secret.to_string(), "ab...56");
+        assert_eq!(format!("{secret:?}"), "Secret(ab...56)");
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("abc def")]
+    #[case("abcdef\n")]
+    fn test_secret_invalid_values(#[case] value: &str) {
+        assert!(Secret::new(value).is_err());
+    }
+
+    #[rstest]
+    fn test_secret_deserialize() {
+        let secret: Secret = serde_json::from_str("\"abcdef123456\"").unwrap();
+        assert_eq!(secret.expose(), "abcdef123456");
+
+        let result: Result<Secret, _> = serde_json::from_str("\"\"");
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_credential_source_deserialize() {
+        let source: CredentialSource =
+            serde_json::from_str(r#"{"source": "env", "var": "MY_API_KEY"}"#).unwrap();
+        assert_eq!(
+            source,
+            CredentialSource::Env {
+                var: "MY_API_KEY".to_string()
+            }
+        );
+
+        let source: CredentialSource = serde_json::from_str(
+            r#"{"source": "secret_manager", "provider": "vault", "key": "api_key"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            source,
+            CredentialSource::SecretManager {
+                provider: "vault".to_string(),
+                key: "api_key".to_string(),
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_resolve_env_when_not_set() {
+        let resolver = CredentialResolver::new();
+        let source = CredentialSource::Env {
+            var: "NAUTILUS_TEST_CREDENTIAL_NOT_SET".to_string(),
+        };
+
+        let result = resolver.resolve(&source);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Environment variable 'NAUTILUS_TEST_CREDENTIAL_NOT_SET' not set"
+        );
+    }
+
+    #[rstest]
+    fn test_resolve_file() {
+        let path = std::env::temp_dir().join(format!("nautilus-credential-{}", std::process::id()));
+        let mut file = std::fs::File::create(&path).unwrap();
+        writeln!(file, "abcdef123456").unwrap();
+
+        let resolver = CredentialResolver::new();
+        let secret = resolver
+            .resolve(&CredentialSource::File { path: path.clone() })
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(secret.expose(), "abcdef123456");
+    }
+
+    #[rstest]
+    fn test_resolve_secret_manager() {
+        let mut resolver = CredentialResolver::new();
+        resolver.register_provider(Arc::new(StubSecretProvider));
+
+        let source = CredentialSource::SecretManager {
+            provider: "stub".to_string(),
+            key: "binance/api_key".to_string(),
+        };
+        let secret = resolver.resolve(&source).unwrap();
+
+        assert_eq!(secret.expose(), "abcdef123456");
+    }
+
+    #[rstest]
+    #[case("stub", "missing", "Secret 'missing' not found with provider 'stub'")]
+    #[case("stub", "broken", "Secret manager unavailable")]
+    #[case("vault", "api_key", "Secret provider 'vault' not registered")]
+    fn test_resolve_secret_manager_errors(
+        #[case] provider: &str,
+        #[case] key: &str,
+        #[case] expected: &str,
+    ) {
+        let mut resolver = CredentialResolver::new();
+        resolver.register_provider(Arc::new(StubSecretProvider));
+
+        let source = CredentialSource::SecretManager {
+            provider: provider.to_string(),
+            key: key.to_string(),
+        };
+        let result = resolver.resolve(&source);
+
+        assert_eq!(result.unwrap_err().to_string(), expected);
+    }
+
+    #[rstest]
+    fn test_resolve_api_key_with_value() {
+        let secret = resolve_api_key(Some("abcdef123456"), "NAUTILUS_TEST_API_KEY").unwrap();
+        assert_eq!(secret.expose(), "abcdef123456");
+    }
+
+    #[rstest]
+    fn test_resolve_api_key_when_not_provided_or_set() {
+        let result = resolve_api_key(None, "NAUTILUS_TEST_API_KEY_NOT_SET");
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "API key must be provided or set in the 'NAUTILUS_TEST_API_KEY_NOT_SET' environment variable"
+        );
+    }
+}
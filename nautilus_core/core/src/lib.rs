@@ -28,6 +28,7 @@
 //! - `python`: Enables Python bindings from `pyo3`.
 
 pub mod correctness;
+pub mod credentials;
 pub mod datetime;
 pub mod message;
 pub mod nanos;
@@ -60,6 +60,31 @@ pub trait BarAggregator {
     }
 }
 
+/// Allows a shared aggregator (such as a [`TimeBarAggregator`] also referenced by its
+/// timer callback) to be driven through the same interface as an owned aggregator.
+impl<A> BarAggregator for Rc<RefCell<A>>
+where
+    A: BarAggregator,
+{
+    fn bar_type(&self) -> BarType {
+        self.borrow().bar_type()
+    }
+
+    fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
+        self.borrow_mut().update(price, size, ts_event);
+    }
+}
+
+/// The type of interval used for time bar aggregation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BarIntervalType {
+    /// The start time is excluded and the end time is included (default).
+    #[default]
+    LeftOpen,
+    /// The start time is included and the end time is excluded.
+    RightOpen,
+}
+
 /// Provides a generic bar builder for aggregation.
 pub struct BarBuilder {
     bar_type: BarType,
@@ -478,20 +503,20 @@ where
 /// Provides a means of building time bars aggregated from quote and trades.
 ///
 /// At each aggregation time interval, a bar is created and sent to the handler.
-pub struct TimeBarAggregator<C, H>
+///
+/// The aggregator does not own a clock, the interval timer is set on the clock passed
+/// to [`TimeBarAggregator::start`], so it can be driven by any component clock.
+pub struct TimeBarAggregator<H>
 where
-    C: Clock,
     H: FnMut(Bar),
 {
     core: BarAggregatorCore<H>,
-    clock: C,
     build_with_no_updates: bool,
     timestamp_on_close: bool,
     is_left_open: bool,
     build_on_next_tick: bool,
     stored_open_ns: UnixNanos,
     stored_close_ns: UnixNanos,
-    cached_update: Option<(Price, Quantity, u64)>,
     timer_name: String,
     interval: TimeDelta,
     interval_ns: UnixNanos,
@@ -499,27 +524,26 @@ where
 }
 
 #[derive(Clone)]
-pub struct NewBarCallback<C: Clock, H: FnMut(Bar)> {
-    aggregator: Rc<RefCell<TimeBarAggregator<C, H>>>,
+pub struct NewBarCallback<H: FnMut(Bar)> {
+    aggregator: Rc<RefCell<TimeBarAggregator<H>>>,
 }
 
-impl<C: Clock, H: FnMut(Bar)> NewBarCallback<C, H> {
-    pub const fn new(aggregator: Rc<RefCell<TimeBarAggregator<C, H>>>) -> Self {
+impl<H: FnMut(Bar)> NewBarCallback<H> {
+    pub const fn new(aggregator: Rc<RefCell<TimeBarAggregator<H>>>) -> Self {
         Self { aggregator }
     }
 }
 
-impl<C: Clock + 'static, H: FnMut(Bar) + 'static> From<NewBarCallback<C, H>> for TimeEventCallback {
-    fn from(value: NewBarCallback<C, H>) -> Self {
+impl<H: FnMut(Bar) + 'static> From<NewBarCallback<H>> for TimeEventCallback {
+    fn from(value: NewBarCallback<H>) -> Self {
         Self::Rust(Rc::new(move |event: TimeEvent| {
             value.aggregator.borrow_mut().build_bar(event);
         }))
     }
 }
 
-impl<C, H> TimeBarAggregator<C, H>
+impl<H> TimeBarAggregator<H>
 where
-    C: Clock + 'static,
     H: FnMut(Bar) + 'static,
 {
     /// Creates a new [`TimeBarAggregator`] instance.
@@ -529,27 +553,24 @@ where
     /// This function panics:
     /// - If `instrument.id` is not equal to the `bar_type.instrument_id`.
     /// - If `bar_type.aggregation_source` is not equal to `AggregationSource::Internal`.
-    #[allow(clippy::too_many_arguments)]
+    /// - If the aggregation method of the given `bar_type` is not time based.
     pub fn new(
         instrument: &InstrumentAny,
         bar_type: BarType,
         handler: H,
         await_partial: bool,
-        clock: C,
         build_with_no_updates: bool,
         timestamp_on_close: bool,
-        interval_type: &str, // TODO: Make this an enum
+        interval_type: BarIntervalType,
     ) -> Self {
         Self {
             core: BarAggregatorCore::new(instrument, bar_type, handler, await_partial),
-            clock,
             build_with_no_updates,
             timestamp_on_close,
-            is_left_open: false,
+            is_left_open: interval_type == BarIntervalType::LeftOpen,
             build_on_next_tick: false,
             stored_open_ns: UnixNanos::default(),
             stored_close_ns: UnixNanos::default(),
-            cached_update: None,
             timer_name: bar_type.to_string(),
             interval: get_bar_interval(&bar_type),
             interval_ns: get_bar_interval_ns(&bar_type),
@@ -557,62 +578,85 @@ where
         }
     }
 
+    /// Returns the name of the aggregators interval timer.
+    #[must_use]
+    pub fn timer_name(&self) -> &str {
+        &self.timer_name
+    }
+
+    /// Returns the close time of the bar currently being aggregated.
+    #[must_use]
+    pub const fn next_close_ns(&self) -> UnixNanos {
+        self.next_close_ns
+    }
+
     /// Starts the time bar aggregator.
-    pub fn start(&mut self, callback: NewBarCallback<C, H>) -> anyhow::Result<()> {
-        let now = self.clock.utc_now();
+    ///
+    /// The interval timer is aligned to the start of the current bar, so that bars
+    /// close on interval boundaries (e.g. on the minute for 1-MINUTE bars).
+    pub fn start(
+        &mut self,
+        clock: &mut dyn Clock,
+        callback: NewBarCallback<H>,
+    ) -> anyhow::Result<()> {
+        let now = clock.utc_now();
         let start_time = get_time_bar_start(now, &self.bar_type());
         let start_time_ns = UnixNanos::from(start_time.timestamp_nanos_opt().unwrap() as u64);
 
-        self.clock
-            .set_timer_ns(
-                &self.timer_name,
-                self.interval_ns.as_u64(),
-                start_time_ns,
-                None,
-                Some(callback.into()),
-            )
-            .expect(FAILED);
+        clock.set_timer_ns(
+            &self.timer_name,
+            self.interval_ns.as_u64(),
+            start_time_ns,
+            None,
+            Some(callback.into()),
+        )?;
+
+        self.stored_open_ns = start_time_ns;
+        self.next_close_ns = start_time_ns + self.interval_ns;
 
         log::debug!("Started timer {}", self.timer_name);
         Ok(())
     }
 
     /// Stops the time bar aggregator.
-    pub fn stop(&mut self) {
-        self.clock.cancel_timer(&self.timer_name);
+    pub fn stop(&mut self, clock: &mut dyn Clock) {
+        clock.cancel_timer(&self.timer_name);
     }
 
     fn build_bar(&mut self, event: TimeEvent) {
         if !self.core.builder.initialized {
+            // Build on the next update with the stored close time, this avoids a race
+            // between a data update and the time event from the timer
             self.build_on_next_tick = true;
             self.stored_close_ns = self.next_close_ns;
+            self.next_close_ns = event.ts_event + self.interval_ns;
             return;
         }
 
         if !self.build_with_no_updates && self.core.builder.count == 0 {
+            // Do not build and emit bar, the close time still becomes the next open time
+            self.stored_open_ns = event.ts_event;
+            self.next_close_ns = event.ts_event + self.interval_ns;
             return;
         }
 
         let ts_init = event.ts_event;
-        let ts_event = if self.is_left_open {
-            if self.timestamp_on_close {
-                event.ts_event
-            } else {
-                self.stored_open_ns
-            }
+        let ts_event = if self.is_left_open && self.timestamp_on_close {
+            event.ts_event
         } else {
             self.stored_open_ns
         };
 
         self.core.build_and_send(ts_event, ts_init);
+
+        // Close time becomes the next open time
         self.stored_open_ns = event.ts_event;
-        self.next_close_ns = self.clock.next_time_ns(&self.timer_name);
+        self.next_close_ns = event.ts_event + self.interval_ns;
     }
 }
 
-impl<C, H> BarAggregator for TimeBarAggregator<C, H>
+impl<H> BarAggregator for TimeBarAggregator<H>
 where
-    C: Clock,
     H: FnMut(Bar),
 {
     fn bar_type(&self) -> BarType {
@@ -621,20 +665,21 @@ where
 
     fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
         self.core.apply_update(price, size, ts_event);
-        if self.build_on_next_tick {
-            let ts_init = ts_event;
 
-            let ts_event = if self.is_left_open {
-                if self.timestamp_on_close {
+        if self.build_on_next_tick {
+            if ts_event <= self.stored_close_ns {
+                let ts_init = ts_event;
+                let ts_event = if self.is_left_open && self.timestamp_on_close {
                     self.stored_close_ns
                 } else {
                     self.stored_open_ns
-                }
-            } else {
-                self.stored_open_ns
-            };
+                };
 
-            self.core.build_and_send(ts_event, ts_init);
+                self.core.build_and_send(ts_event, ts_init);
+            }
+
+            // The stored close time becomes the next open time
+            self.stored_open_ns = self.stored_close_ns;
             self.build_on_next_tick = false;
             self.stored_close_ns = UnixNanos::default();
         }
@@ -648,9 +693,11 @@ where
 mod tests {
     use std::sync::{Arc, Mutex};
 
+    use nautilus_common::clock::TestClock;
     use nautilus_model::{
         data::{BarSpecification, BarType},
         enums::{AggregationSource, BarAggregation, PriceType},
+        identifiers::InstrumentId,
         instruments::{stubs::*, Equity, InstrumentAny},
         types::{Price, Quantity},
     };
@@ -975,4 +1022,144 @@ mod tests {
         assert_eq!(bar.ts_event, trade.ts_event);
         assert_eq!(bar.ts_init, trade.ts_init);
     }
+
+    fn time_bar_aggregator(
+        instrument: &InstrumentAny,
+        bar_type: BarType,
+        build_with_no_updates: bool,
+    ) -> (
+        Rc<RefCell<TimeBarAggregator<impl FnMut(Bar) + 'static>>>,
+        Rc<RefCell<Vec<Bar>>>,
+    ) {
+        let bars = Rc::new(RefCell::new(Vec::new()));
+        let bars_clone = bars.clone();
+        let aggregator = TimeBarAggregator::new(
+            instrument,
+            bar_type,
+            move |bar: Bar| bars_clone.borrow_mut().push(bar),
+            false,
+            build_with_no_updates,
+            true,
+            BarIntervalType::LeftOpen,
+        );
+        (Rc::new(RefCell::new(aggregator)), bars)
+    }
+
+    fn trade_at(instrument_id: InstrumentId, price: &str, ts: u64) -> TradeTick {
+        TradeTick {
+            instrument_id,
+            price: Price::from(price),
+            ts_event: ts.into(),
+            ts_init: ts.into(),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_start_aligns_to_interval(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let bar_type = BarType::from("AAPL.XNAS-1-SECOND-LAST-INTERNAL");
+        let (aggregator, _) = time_bar_aggregator(&instrument, bar_type, true);
+        let mut clock = TestClock::new();
+        clock.set_time(1_500_000_000.into());
+
+        let callback = NewBarCallback::new(aggregator.clone());
+        aggregator.borrow_mut().start(&mut clock, callback).unwrap();
+
+        assert_eq!(aggregator.borrow().next_close_ns(), 2_000_000_000);
+        assert_eq!(aggregator.borrow().stored_open_ns, 1_000_000_000);
+        assert_eq!(
+            clock.next_time_ns(aggregator.borrow().timer_name()),
+            2_000_000_000
+        );
+
+        aggregator.borrow_mut().stop(&mut clock);
+        assert_eq!(clock.timer_count(), 0);
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_builds_bar_on_interval_close(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let instrument_id = instrument.id();
+        let bar_type = BarType::from("AAPL.XNAS-1-SECOND-LAST-INTERNAL");
+        let (aggregator, bars) = time_bar_aggregator(&instrument, bar_type, true);
+        let mut clock = TestClock::new();
+        clock.set_time(1_500_000_000.into());
+        let callback = NewBarCallback::new(aggregator.clone());
+        aggregator.borrow_mut().start(&mut clock, callback).unwrap();
+
+        aggregator
+            .borrow_mut()
+            .handle_trade(trade_at(instrument_id, "100.00", 1_600_000_000));
+        aggregator
+            .borrow_mut()
+            .handle_trade(trade_at(instrument_id, "101.00", 1_700_000_000));
+        aggregator
+            .borrow_mut()
+            .handle_trade(trade_at(instrument_id, "99.00", 1_800_000_000));
+
+        for event in clock.advance_time(2_000_000_000.into(), true) {
+            aggregator.borrow_mut().build_bar(event);
+        }
+
+        let bars = bars.borrow();
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].open, Price::from("100.00"));
+        assert_eq!(bars[0].high, Price::from("101.00"));
+        assert_eq!(bars[0].low, Price::from("99.00"));
+        assert_eq!(bars[0].close, Price::from("99.00"));
+        assert_eq!(bars[0].ts_event, 2_000_000_000); // Timestamp on close
+        assert_eq!(bars[0].ts_init, 2_000_000_000);
+        assert_eq!(aggregator.borrow().next_close_ns(), 3_000_000_000);
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_with_no_updates_when_disabled_does_not_build(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let instrument_id = instrument.id();
+        let bar_type = BarType::from("AAPL.XNAS-1-SECOND-LAST-INTERNAL");
+        let (aggregator, bars) = time_bar_aggregator(&instrument, bar_type, false);
+        let mut clock = TestClock::new();
+        let callback = NewBarCallback::new(aggregator.clone());
+        aggregator.borrow_mut().start(&mut clock, callback).unwrap();
+
+        aggregator
+            .borrow_mut()
+            .handle_trade(trade_at(instrument_id, "100.00", 500_000_000));
+
+        for event in clock.advance_time(3_000_000_000.into(), true) {
+            aggregator.borrow_mut().build_bar(event);
+        }
+
+        // Only the first interval received an update
+        assert_eq!(bars.borrow().len(), 1);
+        assert_eq!(bars.borrow()[0].ts_event, 1_000_000_000);
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_builds_on_next_tick_when_not_initialized(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let instrument_id = instrument.id();
+        let bar_type = BarType::from("AAPL.XNAS-1-SECOND-LAST-INTERNAL");
+        let (aggregator, bars) = time_bar_aggregator(&instrument, bar_type, true);
+        let mut clock = TestClock::new();
+        let callback = NewBarCallback::new(aggregator.clone());
+        aggregator.borrow_mut().start(&mut clock, callback).unwrap();
+
+        // Timer fires before the first update has been received
+        for event in clock.advance_time(1_000_000_000.into(), true) {
+            aggregator.borrow_mut().build_bar(event);
+        }
+        assert!(bars.borrow().is_empty());
+
+        // A late update for the closed interval builds the bar
+        aggregator
+            .borrow_mut()
+            .handle_trade(trade_at(instrument_id, "100.00", 1_000_000_000));
+
+        let bars = bars.borrow();
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].close, Price::from("100.00"));
+        assert_eq!(bars[0].ts_event, 1_000_000_000);
+    }
 }
@@ -23,13 +23,14 @@ use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
     ops::{Deref, DerefMut},
-    sync::Arc,
 };
 
 use indexmap::IndexMap;
 use nautilus_common::{
     clock::Clock,
-    messages::data::{Action, DataRequest, DataResponse, Payload, SubscriptionCommand},
+    messages::data::{
+        Action, DataRequest, DataRequestFailure, DataResponse, Payload, SubscriptionCommand,
+    },
 };
 use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 use nautilus_model::{
@@ -111,6 +112,21 @@ pub trait DataClient {
         instrument_id: &InstrumentId,
         params: &Option<HashMap<String, String>>,
     ) -> anyhow::Result<()>;
+    fn subscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()>;
+    fn subscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()>;
+    fn subscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()>;
     fn unsubscribe(
         &mut self,
         data_type: &DataType,
@@ -161,6 +177,21 @@ pub trait DataClient {
         instrument_id: &InstrumentId,
         params: &Option<HashMap<String, String>>,
     ) -> anyhow::Result<()>;
+    fn unsubscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()>;
+    fn unsubscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()>;
+    fn unsubscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()>;
 
     // -- DATA REQUEST HANDLERS -------------------------------------------------------------------
 
@@ -172,7 +203,7 @@ pub trait DataClient {
         start: Option<UnixNanos>,
         end: Option<UnixNanos>,
         params: &Option<HashMap<String, String>>,
-    ) -> Vec<InstrumentAny>;
+    ) -> anyhow::Result<Vec<InstrumentAny>>;
     fn request_instrument(
         &self,
         correlation_id: UUID4,
@@ -180,7 +211,7 @@ pub trait DataClient {
         start: Option<UnixNanos>,
         end: Option<UnixNanos>,
         params: &Option<HashMap<String, String>>,
-    ) -> InstrumentAny;
+    ) -> anyhow::Result<InstrumentAny>;
     // TODO: figure out where to call this and it's return type
     fn request_order_book_snapshot(
         &self,
@@ -188,7 +219,7 @@ pub trait DataClient {
         instrument_id: InstrumentId,
         depth: Option<usize>,
         params: &Option<HashMap<String, String>>,
-    ) -> Payload;
+    ) -> anyhow::Result<Payload>;
     fn request_quote_ticks(
         &self,
         correlation_id: UUID4,
@@ -197,7 +228,7 @@ pub trait DataClient {
         end: Option<UnixNanos>,
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
-    ) -> Vec<QuoteTick>;
+    ) -> anyhow::Result<Vec<QuoteTick>>;
     fn request_trade_ticks(
         &self,
         correlation_id: UUID4,
@@ -206,7 +237,7 @@ pub trait DataClient {
         end: Option<UnixNanos>,
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
-    ) -> Vec<TradeTick>;
+    ) -> anyhow::Result<Vec<TradeTick>>;
     fn request_bars(
         &self,
         correlation_id: UUID4,
@@ -215,7 +246,7 @@ pub trait DataClient {
         end: Option<UnixNanos>,
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
-    ) -> Vec<Bar>;
+    ) -> anyhow::Result<Vec<Bar>>;
 }
 
 pub struct DataClientAdapter {
@@ -233,6 +264,9 @@ pub struct DataClientAdapter {
     pub subscriptions_bar: HashSet<BarType>,
     pub subscriptions_instrument_status: HashSet<InstrumentId>,
     pub subscriptions_instrument_close: HashSet<InstrumentId>,
+    pub subscriptions_funding_rate: HashSet<InstrumentId>,
+    pub subscriptions_mark_price: HashSet<InstrumentId>,
+    pub subscriptions_index_price: HashSet<InstrumentId>,
     pub subscriptions_instrument: HashSet<InstrumentId>,
     pub subscriptions_instrument_venue: HashSet<Venue>,
 }
@@ -281,6 +315,12 @@ impl Debug for DataClientAdapter {
                 "subscriptions_instrument_close",
                 &self.subscriptions_instrument_close,
             )
+            .field(
+                "subscriptions_funding_rate",
+                &self.subscriptions_funding_rate,
+            )
+            .field("subscriptions_mark_price", &self.subscriptions_mark_price)
+            .field("subscriptions_index_price", &self.subscriptions_index_price)
             .field("subscriptions_instrument", &self.subscriptions_instrument)
             .field(
                 "subscriptions_instrument_venue",
@@ -316,6 +356,9 @@ impl DataClientAdapter {
             subscriptions_bar: HashSet::new(),
             subscriptions_instrument_status: HashSet::new(),
             subscriptions_instrument_close: HashSet::new(),
+            subscriptions_funding_rate: HashSet::new(),
+            subscriptions_mark_price: HashSet::new(),
+            subscriptions_index_price: HashSet::new(),
             subscriptions_instrument: HashSet::new(),
             subscriptions_instrument_venue: HashSet::new(),
         }
@@ -336,12 +379,15 @@ impl DataClientAdapter {
         match command.data_type.type_name() {
             stringify!(InstrumentAny) => Self::subscribe_instrument(self, command),
             stringify!(OrderBookDelta) => Self::subscribe_order_book_deltas(self, command),
-            stringify!(OrderBookDeltas) | stringify!(OrderBookDepth10) => {
+            stringify!(OrderBook) | stringify!(OrderBookDeltas) | stringify!(OrderBookDepth10) => {
                 Self::subscribe_snapshots(self, command);
             }
             stringify!(QuoteTick) => Self::subscribe_quote_ticks(self, command),
             stringify!(TradeTick) => Self::subscribe_trade_ticks(self, command),
             stringify!(Bar) => Self::subscribe_bars(self, command),
+            stringify!(FundingRateUpdate) => Self::subscribe_funding_rates(self, command),
+            stringify!(MarkPriceUpdate) => Self::subscribe_mark_prices(self, command),
+            stringify!(IndexPriceUpdate) => Self::subscribe_index_prices(self, command),
             _ => Self::subscribe(self, command),
         }
     }
@@ -351,12 +397,15 @@ impl DataClientAdapter {
         match command.data_type.type_name() {
             stringify!(InstrumentAny) => Self::unsubscribe_instrument(self, command),
             stringify!(OrderBookDelta) => Self::unsubscribe_order_book_deltas(self, command),
-            stringify!(OrderBookDeltas) | stringify!(OrderBookDepth10) => {
+            stringify!(OrderBook) | stringify!(OrderBookDeltas) | stringify!(OrderBookDepth10) => {
                 Self::unsubscribe_snapshots(self, command);
             }
             stringify!(QuoteTick) => Self::unsubscribe_quote_ticks(self, command),
             stringify!(TradeTick) => Self::unsubscribe_trade_ticks(self, command),
             stringify!(Bar) => Self::unsubscribe_bars(self, command),
+            stringify!(FundingRateUpdate) => Self::unsubscribe_funding_rates(self, command),
+            stringify!(MarkPriceUpdate) => Self::unsubscribe_mark_prices(self, command),
+            stringify!(IndexPriceUpdate) => Self::unsubscribe_index_prices(self, command),
             _ => Self::unsubscribe(self, command),
         }
     }
@@ -564,6 +613,90 @@ impl DataClientAdapter {
         self.subscriptions_bar.remove(&bar_type);
     }
 
+    fn subscribe_funding_rates(&mut self, command: SubscriptionCommand) {
+        let instrument_id = command
+            .data_type
+            .instrument_id()
+            .expect("Error on subscribe: no 'instrument_id' in metadata");
+
+        if !self.subscriptions_funding_rate.contains(&instrument_id) {
+            self.client
+                .subscribe_funding_rates(&instrument_id, &command.params)
+                .expect("Error on subscribe");
+        }
+        self.subscriptions_funding_rate.insert(instrument_id);
+    }
+
+    fn unsubscribe_funding_rates(&mut self, command: SubscriptionCommand) {
+        let instrument_id = command
+            .data_type
+            .instrument_id()
+            .expect("Error on subscribe: no 'instrument_id' in metadata");
+
+        if self.subscriptions_funding_rate.contains(&instrument_id) {
+            self.client
+                .unsubscribe_funding_rates(&instrument_id, &command.params)
+                .expect("Error on subscribe");
+        }
+        self.subscriptions_funding_rate.remove(&instrument_id);
+    }
+
+    fn subscribe_mark_prices(&mut self, command: SubscriptionCommand) {
+        let instrument_id = command
+            .data_type
+            .instrument_id()
+            .expect("Error on subscribe: no 'instrument_id' in metadata");
+
+        if !self.subscriptions_mark_price.contains(&instrument_id) {
+            self.client
+                .subscribe_mark_prices(&instrument_id, &command.params)
+                .expect("Error on subscribe");
+        }
+        self.subscriptions_mark_price.insert(instrument_id);
+    }
+
+    fn unsubscribe_mark_prices(&mut self, command: SubscriptionCommand) {
+        let instrument_id = command
+            .data_type
+            .instrument_id()
+            .expect("Error on subscribe: no 'instrument_id' in metadata");
+
+        if self.subscriptions_mark_price.contains(&instrument_id) {
+            self.client
+                .unsubscribe_mark_prices(&instrument_id, &command.params)
+                .expect("Error on subscribe");
+        }
+        self.subscriptions_mark_price.remove(&instrument_id);
+    }
+
+    fn subscribe_index_prices(&mut self, command: SubscriptionCommand) {
+        let instrument_id = command
+            .data_type
+            .instrument_id()
+            .expect("Error on subscribe: no 'instrument_id' in metadata");
+
+        if !self.subscriptions_index_price.contains(&instrument_id) {
+            self.client
+                .subscribe_index_prices(&instrument_id, &command.params)
+                .expect("Error on subscribe");
+        }
+        self.subscriptions_index_price.insert(instrument_id);
+    }
+
+    fn unsubscribe_index_prices(&mut self, command: SubscriptionCommand) {
+        let instrument_id = command
+            .data_type
+            .instrument_id()
+            .expect("Error on subscribe: no 'instrument_id' in metadata");
+
+        if self.subscriptions_index_price.contains(&instrument_id) {
+            self.client
+                .unsubscribe_index_prices(&instrument_id, &command.params)
+                .expect("Error on subscribe");
+        }
+        self.subscriptions_index_price.remove(&instrument_id);
+    }
+
     pub fn subscribe(&mut self, command: SubscriptionCommand) {
         let data_type = command.data_type;
         if !self.subscriptions_generic.contains(&data_type) {
@@ -594,6 +727,8 @@ impl DataClientAdapter {
         self.client.request_data(req);
     }
 
+    /// Handles the `req` with the client, returning a failed response if the client
+    /// cannot fulfil the request.
     #[must_use]
     pub fn request(&self, req: DataRequest) -> DataResponse {
         let instrument_id = req.data_type.instrument_id();
@@ -602,28 +737,18 @@ impl DataClientAdapter {
         let end = req.data_type.end();
         let limit = req.data_type.limit();
 
-        match req.data_type.type_name() {
+        let result = match req.data_type.type_name() {
             stringify!(InstrumentAny) => match (instrument_id, venue) {
-                (None, Some(venue)) => {
-                    let instruments = self.client.request_instruments(
-                        req.correlation_id,
-                        venue,
-                        start,
-                        end,
-                        &req.params,
-                    );
-                    self.handle_instruments(venue, instruments, req.correlation_id)
-                }
-                (Some(instrument_id), None) => {
-                    let instrument = self.client.request_instrument(
-                        req.correlation_id,
-                        instrument_id,
-                        start,
-                        end,
-                        &req.params,
-                    );
-                    self.handle_instrument(instrument, req.correlation_id)
-                }
+                (None, Some(venue)) => self
+                    .client
+                    .request_instruments(req.correlation_id, venue, start, end, &req.params)
+                    .map(|instruments| {
+                        self.handle_instruments(venue, instruments, req.correlation_id)
+                    }),
+                (Some(instrument_id), None) => self
+                    .client
+                    .request_instrument(req.correlation_id, instrument_id, start, end, &req.params)
+                    .map(|instrument| self.handle_instrument(instrument, req.correlation_id)),
                 _ => {
                     todo!()
                 }
@@ -631,45 +756,63 @@ impl DataClientAdapter {
             stringify!(QuoteTick) => {
                 let instrument_id =
                     instrument_id.expect("Error on request: no 'instrument_id' found in metadata");
-                let quotes = self.client.request_quote_ticks(
-                    req.correlation_id,
-                    instrument_id,
-                    start,
-                    end,
-                    limit,
-                    &req.params,
-                );
-                self.handle_quote_ticks(&instrument_id, quotes, req.correlation_id)
+                self.client
+                    .request_quote_ticks(
+                        req.correlation_id,
+                        instrument_id,
+                        start,
+                        end,
+                        limit,
+                        &req.params,
+                    )
+                    .map(|quotes| {
+                        self.handle_quote_ticks(&instrument_id, quotes, req.correlation_id)
+                    })
             }
             stringify!(TradeTick) => {
                 let instrument_id =
                     instrument_id.expect("Error on request: no 'instrument_id' found in metadata");
-                let trades = self.client.request_trade_ticks(
-                    req.correlation_id,
-                    instrument_id,
-                    start,
-                    end,
-                    limit,
-                    &req.params,
-                );
-                self.handle_trade_ticks(&instrument_id, trades, req.correlation_id)
+                self.client
+                    .request_trade_ticks(
+                        req.correlation_id,
+                        instrument_id,
+                        start,
+                        end,
+                        limit,
+                        &req.params,
+                    )
+                    .map(|trades| {
+                        self.handle_trade_ticks(&instrument_id, trades, req.correlation_id)
+                    })
             }
             stringify!(Bar) => {
                 let bar_type = req.data_type.bar_type();
-                let bars = self.client.request_bars(
-                    req.correlation_id,
-                    bar_type,
-                    start,
-                    end,
-                    limit,
-                    &req.params,
-                );
-                self.handle_bars(&bar_type, bars, req.correlation_id)
+                self.client
+                    .request_bars(req.correlation_id, bar_type, start, end, limit, &req.params)
+                    .map(|bars| self.handle_bars(&bar_type, bars, req.correlation_id))
             }
             _ => {
                 todo!()
             }
-        }
+        };
+
+        result.unwrap_or_else(|e| {
+            log::error!("Error on request {}: {e}", req.correlation_id);
+            self.handle_request_failure(&req, &e)
+        })
+    }
+
+    #[must_use]
+    pub fn handle_request_failure(&self, req: &DataRequest, error: &anyhow::Error) -> DataResponse {
+        DataResponse::new(
+            req.correlation_id,
+            self.client_id,
+            req.venue,
+            req.data_type.clone(),
+            DataRequestFailure::new(&error.to_string()),
+            self.clock.timestamp_ns(),
+            None,
+        )
     }
 
     #[must_use]
@@ -681,14 +824,13 @@ impl DataClientAdapter {
         let instrument_id = instrument.id();
         let metadata = IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())]);
         let data_type = DataType::new(stringify!(InstrumentAny), Some(metadata));
-        let data = Arc::new(instrument);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             instrument_id.venue,
             data_type,
-            data,
+            instrument,
             self.clock.timestamp_ns(),
             None,
         )
@@ -703,14 +845,13 @@ impl DataClientAdapter {
     ) -> DataResponse {
         let metadata = IndexMap::from([("venue".to_string(), venue.to_string())]);
         let data_type = DataType::new(stringify!(InstrumentAny), Some(metadata));
-        let data = Arc::new(instruments);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             venue,
             data_type,
-            data,
+            instruments,
             self.clock.timestamp_ns(),
             None,
         )
@@ -725,14 +866,13 @@ impl DataClientAdapter {
     ) -> DataResponse {
         let metadata = IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())]);
         let data_type = DataType::new(stringify!(QuoteTick), Some(metadata));
-        let data = Arc::new(quotes);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             instrument_id.venue,
             data_type,
-            data,
+            quotes,
             self.clock.timestamp_ns(),
             None,
         )
@@ -747,14 +887,13 @@ impl DataClientAdapter {
     ) -> DataResponse {
         let metadata = IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())]);
         let data_type = DataType::new(stringify!(TradeTick), Some(metadata));
-        let data = Arc::new(trades);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             instrument_id.venue,
             data_type,
-            data,
+            trades,
             self.clock.timestamp_ns(),
             None,
         )
@@ -769,14 +908,13 @@ impl DataClientAdapter {
     ) -> DataResponse {
         let metadata = IndexMap::from([("bar_type".to_string(), bar_type.to_string())]);
         let data_type = DataType::new(stringify!(Bar), Some(metadata));
-        let data = Arc::new(bars);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             bar_type.instrument_id().venue,
             data_type,
-            data,
+            bars,
             self.clock.timestamp_ns(),
             None,
         )
@@ -16,6 +16,7 @@
 use std::{
     any::Any,
     cell::{Ref, RefCell},
+    collections::HashMap,
     num::NonZeroU64,
     rc::Rc,
 };
@@ -41,6 +42,7 @@ pub struct BookSnapshotInfo {
     pub root: Ustr,
     pub topic: Ustr,
     pub interval_ms: NonZeroU64,
+    pub depth: Option<usize>,
 }
 
 pub struct BookUpdater {
@@ -86,12 +88,17 @@ impl MessageHandler for BookUpdater {
     }
 }
 
+/// Publishes conflated order book snapshots at a fixed interval.
+///
+/// All updates applied to a book within an interval are conflated into at most one snapshot
+/// (the latest book state), and no snapshot is published for an interval without updates.
 pub struct BookSnapshotter {
     pub id: Ustr,
     pub timer_name: Ustr,
     pub snap_info: BookSnapshotInfo,
     pub cache: Rc<RefCell<Cache>>,
     pub msgbus: Rc<RefCell<MessageBus>>,
+    published_counts: RefCell<HashMap<InstrumentId, u64>>,
 }
 
 impl BookSnapshotter {
@@ -117,6 +124,7 @@ impl BookSnapshotter {
             snap_info,
             cache,
             msgbus,
+            published_counts: RefCell::new(HashMap::new()),
         }
     }
 
@@ -156,6 +164,16 @@ impl BookSnapshotter {
             return;
         }
 
-        msgbus.publish(topic, book as &dyn Any);
+        let mut published_counts = self.published_counts.borrow_mut();
+        if published_counts.get(instrument_id) == Some(&book.count) {
+            log::debug!("OrderBook for {instrument_id} not updated since last snapshot");
+            return;
+        }
+        published_counts.insert(*instrument_id, book.count);
+
+        match self.snap_info.depth {
+            Some(depth) if depth > 0 => msgbus.publish(topic, &book.clone_to_depth(depth)),
+            _ => msgbus.publish(topic, book),
+        }
     }
 }
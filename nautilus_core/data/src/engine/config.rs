@@ -13,16 +13,24 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::num::NonZeroUsize;
+
 use nautilus_model::identifiers::ClientId;
 
+use crate::aggregation::BarIntervalType;
+
 /// Configuration for `DataEngine` instances.
 pub struct DataEngineConfig {
     pub time_bars_build_with_no_updates: bool,
     pub time_bars_timestamp_on_close: bool,
-    pub time_bars_interval_type: String, // Make this an enum `BarIntervalType`
+    pub time_bars_interval_type: BarIntervalType,
     pub validate_data_sequence: bool,
     pub buffer_deltas: bool,
     pub external_clients: Option<Vec<ClientId>>,
+    /// The timeout (milliseconds) after which a pending data request is considered failed.
+    pub request_timeout_ms: u64,
+    /// The maximum number of sub-requests a split data request may be divided into.
+    pub request_split_max_children: NonZeroUsize,
     pub debug: bool,
 }
 
@@ -32,10 +40,12 @@ impl Default for DataEngineConfig {
         Self {
             time_bars_build_with_no_updates: true,
             time_bars_timestamp_on_close: true,
-            time_bars_interval_type: "left_open".to_string(), // Make this an enum `BarIntervalType`
+            time_bars_interval_type: BarIntervalType::default(),
             validate_data_sequence: false,
             buffer_deltas: false,
             external_clients: None,
+            request_timeout_ms: 60_000,
+            request_split_max_children: NonZeroUsize::new(1_000).unwrap(),
             debug: false,
         }
     }
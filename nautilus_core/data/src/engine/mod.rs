@@ -42,11 +42,10 @@ mod tests;
 
 use std::{
     any::Any,
-    cell::{Ref, RefCell},
+    cell::{Cell, Ref, RefCell},
     collections::{HashMap, HashSet, VecDeque},
     num::NonZeroU64,
     rc::Rc,
-    sync::Arc,
 };
 
 use book::{BookSnapshotInfo, BookSnapshotter, BookUpdater};
@@ -55,8 +54,16 @@ use indexmap::IndexMap;
 use nautilus_common::{
     cache::Cache,
     clock::Clock,
+    custom::CustomData,
     logging::{RECV, RES},
-    messages::data::{Action, DataRequest, DataResponse, SubscriptionCommand},
+    messages::{
+        data::{
+            Action, DataRequest, DataRequestFailure, DataResponse, DataResponsePayload,
+            SubscribeCommand, SubscriptionCommand, UnsubscribeCommand,
+        },
+        split::{RequestSplitter, SplitStrategy},
+        tracker::{RequestTimeoutCallback, RequestTracker},
+    },
     msgbus::{
         handler::{MessageHandler, ShareableMessageHandler},
         MessageBus,
@@ -66,24 +73,32 @@ use nautilus_common::{
 use nautilus_core::{
     correctness::{check_key_in_index_map, check_key_not_in_index_map, FAILED},
     datetime::{millis_to_nanos, NANOSECONDS_IN_MILLISECOND, NANOSECONDS_IN_SECOND},
+    nanos::UnixNanos,
+    uuid::UUID4,
 };
 use nautilus_model::{
     data::{
-        Bar, BarType, Data, DataType, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick,
-        TradeTick,
+        Bar, BarType, Data, DataType, FundingRateUpdate, IndexPriceUpdate, MarkPriceUpdate,
+        OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick,
     },
-    enums::{AggregationSource, BookType, PriceType, RecordFlag},
+    enums::{AggregationSource, BarAggregation, BookType, PriceType, RecordFlag},
     identifiers::{ClientId, InstrumentId, Venue},
     instruments::{InstrumentAny, SyntheticInstrument},
     orderbook::OrderBook,
 };
 use ustr::Ustr;
 
-use crate::{aggregation::BarAggregator, client::DataClientAdapter};
+use crate::{
+    aggregation::{
+        BarAggregator, NewBarCallback, TickBarAggregator, TimeBarAggregator, ValueBarAggregator,
+        VolumeBarAggregator,
+    },
+    client::DataClientAdapter,
+};
 
 /// Provides a high-performance `DataEngine` for all environments.
 pub struct DataEngine {
-    clock: Box<dyn Clock>,
+    clock: Rc<RefCell<dyn Clock>>,
     cache: Rc<RefCell<Cache>>,
     msgbus: Rc<RefCell<MessageBus>>,
     clients: IndexMap<ClientId, DataClientAdapter>,
@@ -99,6 +114,8 @@ pub struct DataEngine {
     buffered_deltas_map: HashMap<InstrumentId, Vec<OrderBookDelta>>, // TODO: Use OrderBookDeltas?
     msgbus_priority: u8,
     command_queue: VecDeque<SubscriptionCommand>,
+    request_tracker: RequestTracker,
+    request_splitter: RequestSplitter,
     config: DataEngineConfig,
 }
 
@@ -106,11 +123,12 @@ impl DataEngine {
     /// Creates a new [`DataEngine`] instance.
     #[must_use]
     pub fn new(
-        clock: Box<dyn Clock>,
+        clock: Rc<RefCell<dyn Clock>>,
         cache: Rc<RefCell<Cache>>,
         msgbus: Rc<RefCell<MessageBus>>,
         config: Option<DataEngineConfig>,
     ) -> Self {
+        let config = config.unwrap_or_default();
         Self {
             clock,
             cache,
@@ -128,7 +146,9 @@ impl DataEngine {
             buffered_deltas_map: HashMap::new(),
             msgbus_priority: 10, // High-priority for built-in component
             command_queue: VecDeque::new(),
-            config: config.unwrap_or_default(),
+            request_tracker: RequestTracker::new(),
+            request_splitter: RequestSplitter::new(config.request_split_max_children),
+            config,
         }
     }
 
@@ -167,7 +187,7 @@ impl DataEngine {
 
     pub fn dispose(mut self) {
         self.clients.values().for_each(|client| client.dispose());
-        self.clock.cancel_timers();
+        self.clock.borrow_mut().cancel_timers();
     }
 
     pub fn connect(&self) {
@@ -201,7 +221,7 @@ impl DataEngine {
         T: Clone,
     {
         let mut subs = Vec::new();
-        for client in self.clients.values() {
+        for client in self.clients.values().chain(self.default_client.iter()) {
             subs.extend(get_subs(client).iter().cloned());
         }
         subs
@@ -213,7 +233,8 @@ impl DataEngine {
             None => self
                 .routing_map
                 .get(venue)
-                .and_then(|client_id: &ClientId| self.clients.get(client_id)),
+                .and_then(|client_id: &ClientId| self.clients.get(client_id))
+                .or(self.default_client.as_ref()),
         }
     }
 
@@ -232,7 +253,27 @@ impl DataEngine {
             return self.clients.get_mut(mapped_client_id);
         }
 
-        None
+        // Otherwise fall back to the default client (if registered)
+        self.default_client.as_mut()
+    }
+
+    /// Resolves the client ID and venue to route a message to, trying the `client_id` first,
+    /// then the routing for the `venue`, and finally the default client.
+    fn resolve_routing(
+        &self,
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+    ) -> Option<(ClientId, Venue)> {
+        let client = client_id
+            .and_then(|client_id| self.clients.get(&client_id))
+            .or_else(|| {
+                venue
+                    .and_then(|venue| self.routing_map.get(&venue))
+                    .and_then(|client_id| self.clients.get(client_id))
+            })
+            .or(self.default_client.as_ref())?;
+
+        Some((client.client_id, venue.unwrap_or(client.venue)))
     }
 
     #[must_use]
@@ -280,6 +321,21 @@ impl DataEngine {
         self.collect_subscriptions(|client| &client.subscriptions_instrument_close)
     }
 
+    #[must_use]
+    pub fn subscribed_funding_rates(&self) -> Vec<InstrumentId> {
+        self.collect_subscriptions(|client| &client.subscriptions_funding_rate)
+    }
+
+    #[must_use]
+    pub fn subscribed_mark_prices(&self) -> Vec<InstrumentId> {
+        self.collect_subscriptions(|client| &client.subscriptions_mark_price)
+    }
+
+    #[must_use]
+    pub fn subscribed_index_prices(&self) -> Vec<InstrumentId> {
+        self.collect_subscriptions(|client| &client.subscriptions_index_price)
+    }
+
     pub fn on_start(self) {
         todo!()
     }
@@ -330,11 +386,82 @@ impl DataEngine {
     pub fn enqueue(&mut self, cmd: &dyn Any) {
         if let Some(cmd) = cmd.downcast_ref::<SubscriptionCommand>() {
             self.command_queue.push_back(cmd.clone());
+        } else if let Some(cmd) = cmd.downcast_ref::<SubscribeCommand>() {
+            if let Some(cmd) = self.route_subscribe(cmd.clone()) {
+                self.command_queue.push_back(cmd);
+            }
+        } else if let Some(cmd) = cmd.downcast_ref::<UnsubscribeCommand>() {
+            if let Some(cmd) = self.route_unsubscribe(cmd.clone()) {
+                self.command_queue.push_back(cmd);
+            }
         } else {
             log::error!("Invalid message type received: {cmd:?}");
         }
     }
 
+    /// Executes the given [`SubscribeCommand`], routing it to the data client for the
+    /// commands `client_id` or `venue`, otherwise to the default client.
+    pub fn subscribe(&mut self, cmd: SubscribeCommand) {
+        if let Some(cmd) = self.route_subscribe(cmd) {
+            self.execute(cmd);
+        }
+    }
+
+    /// Executes the given [`UnsubscribeCommand`], routing it to the data client for the
+    /// commands `client_id` or `venue`, otherwise to the default client.
+    pub fn unsubscribe(&mut self, cmd: UnsubscribeCommand) {
+        if let Some(cmd) = self.route_unsubscribe(cmd) {
+            self.execute(cmd);
+        }
+    }
+
+    fn route_subscribe(&self, cmd: SubscribeCommand) -> Option<SubscriptionCommand> {
+        let data_type = cmd.data_type();
+        self.route_command(
+            cmd.client_id,
+            cmd.venue,
+            data_type,
+            Action::Subscribe,
+            cmd.command_id,
+            cmd.ts_init,
+            cmd.params,
+        )
+    }
+
+    fn route_unsubscribe(&self, cmd: UnsubscribeCommand) -> Option<SubscriptionCommand> {
+        let data_type = cmd.data_type();
+        self.route_command(
+            cmd.client_id,
+            cmd.venue,
+            data_type,
+            Action::Unsubscribe,
+            cmd.command_id,
+            cmd.ts_init,
+            cmd.params,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn route_command(
+        &self,
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        data_type: DataType,
+        action: Action,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+        params: Option<HashMap<String, String>>,
+    ) -> Option<SubscriptionCommand> {
+        let Some((client_id, venue)) = self.resolve_routing(client_id, venue) else {
+            log::error!("Cannot handle command: no client found for {data_type}");
+            return None;
+        };
+
+        Some(SubscriptionCommand::new(
+            client_id, venue, data_type, action, command_id, ts_init, params,
+        ))
+    }
+
     pub fn execute(&mut self, cmd: SubscriptionCommand) {
         let result = match cmd.action {
             Action::Subscribe => match cmd.data_type.type_name() {
@@ -367,20 +494,162 @@ impl DataEngine {
     }
 
     /// Sends a [`DataRequest`] to an endpoint that must be a data client implementation.
-    pub fn request(&self, req: DataRequest) {
+    ///
+    /// The request is tracked by its correlation ID until a response is received, or it
+    /// times out after the configured `request_timeout_ms`.
+    pub fn request(&mut self, req: DataRequest) {
+        let callback = self.request_timeout_callback(&req);
+        self.send_request(req, callback);
+    }
+
+    /// Sends and tracks the given request, returning whether a client was found for it.
+    fn send_request(&mut self, req: DataRequest, callback: Rc<RequestTimeoutCallback>) -> bool {
+        let correlation_id = req.correlation_id;
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let deadline = ts_now + self.config.request_timeout_ms * NANOSECONDS_IN_MILLISECOND;
+        if let Err(e) =
+            self.request_tracker
+                .register(correlation_id, ts_now, deadline, Some(callback))
+        {
+            log::error!("Cannot track request: {e}");
+        }
+
         if let Some(client) = self.get_client(&req.client_id, &req.venue) {
             client.through_request(req);
+            true
         } else {
             log::error!(
                 "Cannot handle request: no client found for {}",
                 req.client_id
             );
+            self.request_tracker.cancel(&correlation_id);
+            false
+        }
+    }
+
+    /// Returns a timeout callback which sends a failed [`DataResponse`] for the `req` to
+    /// the requester.
+    ///
+    /// The callback may be shared by the sub-requests of a split request, in which case the
+    /// failure is sent once for the original request.
+    fn request_timeout_callback(&self, req: &DataRequest) -> Rc<RequestTimeoutCallback> {
+        let correlation_id = req.correlation_id;
+        let client_id = req.client_id;
+        let venue = req.venue;
+        let data_type = req.data_type.clone();
+        let clock = self.clock.clone();
+        let msgbus = self.msgbus.clone();
+        let failed = Cell::new(false);
+
+        Rc::new(move |_| {
+            if failed.replace(true) {
+                return;
+            }
+            let resp = DataResponse::new(
+                correlation_id,
+                client_id,
+                venue,
+                data_type.clone(),
+                DataRequestFailure::new("Request timed out"),
+                clock.borrow().timestamp_ns(),
+                None,
+            );
+            send_response(&msgbus.borrow(), resp);
+        })
+    }
+
+    /// Splits the given request into sub-requests according to the `strategy`, sending each
+    /// to the data client.
+    ///
+    /// The child responses are reassembled and handled as a single response for the
+    /// original request correlation ID.
+    pub fn request_split(&mut self, req: DataRequest, strategy: SplitStrategy) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let children = match self.request_splitter.split(&req, strategy, ts_now) {
+            Ok(children) => children,
+            Err(e) => {
+                log::error!("Cannot split request {}: {e}", req.correlation_id);
+                return;
+            }
+        };
+
+        log::debug!(
+            "Split request {} into {} sub-requests",
+            req.correlation_id,
+            children.len()
+        );
+
+        let callback = self.request_timeout_callback(&req);
+        for child in children {
+            if !self.send_request(child, callback.clone()) {
+                // The split request cannot complete without all of its sub-requests
+                if let Some(split) = self.request_splitter.discard(&req.correlation_id) {
+                    log::error!("Discarding split request {}", req.correlation_id);
+                    for child_id in split.child_ids() {
+                        self.request_tracker.cancel(child_id);
+                    }
+                }
+                return;
+            }
+        }
+    }
+
+    /// Delivers all data held in message bus subscription buffers, returning the number
+    /// of messages delivered.
+    pub fn drain_buffers(&self) -> usize {
+        self.msgbus.as_ref().borrow().drain_buffers()
+    }
+
+    /// Checks for pending requests which have passed their deadline, returning the
+    /// correlation IDs of the timed out requests.
+    pub fn check_request_timeouts(&mut self) -> Vec<UUID4> {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let timed_out = self.request_tracker.check_timeouts(ts_now);
+        let msgbus = self.msgbus.as_ref().borrow();
+
+        for correlation_id in &timed_out {
+            // No response will be sent for the request
+            msgbus.cancel_request(correlation_id);
+
+            // A split request cannot complete once any of its sub-requests times out
+            let Some(parent_id) = self.request_splitter.parent_id(correlation_id) else {
+                continue;
+            };
+            if let Some(split) = self.request_splitter.discard(&parent_id) {
+                log::warn!("Discarding split request {parent_id}: sub-request timed out");
+                for child_id in split.child_ids() {
+                    self.request_tracker.cancel(child_id);
+                }
+                msgbus.cancel_request(&parent_id);
+            }
         }
+
+        timed_out
+    }
+
+    /// Returns the number of requests awaiting a response.
+    #[must_use]
+    pub fn pending_requests_count(&self) -> usize {
+        self.request_tracker.pending_count()
+    }
+
+    /// Returns the number of split requests awaiting sub-request responses.
+    #[must_use]
+    pub fn pending_split_requests_count(&self) -> usize {
+        self.request_splitter.pending_count()
     }
 
     pub fn process(&mut self, data: &dyn Any) {
         if let Some(instrument) = data.downcast_ref::<InstrumentAny>() {
             self.handle_instrument(instrument.clone());
+        } else if let Some(custom) = data.downcast_ref::<CustomData>() {
+            self.handle_custom_data(custom.clone());
+        } else if let Some(update) = data.downcast_ref::<FundingRateUpdate>() {
+            self.handle_funding_rate(*update);
+        } else if let Some(update) = data.downcast_ref::<MarkPriceUpdate>() {
+            self.handle_mark_price(*update);
+        } else if let Some(update) = data.downcast_ref::<IndexPriceUpdate>() {
+            self.handle_index_price(*update);
         } else {
             log::error!("Cannot process data {data:?}, type is unrecognized");
         }
@@ -397,34 +666,47 @@ impl DataEngine {
         }
     }
 
-    pub fn response(&self, resp: DataResponse) {
+    pub fn response(&mut self, resp: DataResponse) {
         log::debug!("{}", format!("{RECV}{RES} {resp:?}"));
 
-        match resp.data_type.type_name() {
-            stringify!(InstrumentAny) => {
-                let instruments = Arc::downcast::<Vec<InstrumentAny>>(resp.data.clone())
-                    .expect("Invalid response data");
-                self.handle_instruments(instruments);
-            }
-            stringify!(QuoteTick) => {
-                let quotes = Arc::downcast::<Vec<QuoteTick>>(resp.data.clone())
-                    .expect("Invalid response data");
-                self.handle_quotes(quotes);
+        if self.request_tracker.match_response(&resp).is_none() {
+            log::warn!(
+                "Received response with no pending request for correlation ID {}",
+                resp.correlation_id
+            );
+        }
+
+        let resp = if self.request_splitter.is_child(&resp.correlation_id) {
+            match self.request_splitter.handle_response(resp) {
+                Ok(Some(aggregate)) => aggregate,
+                Ok(None) => return, // Awaiting remaining sub-responses
+                Err(e) => {
+                    log::error!("Cannot reassemble split response: {e}");
+                    return;
+                }
             }
-            stringify!(TradeTick) => {
-                let trades = Arc::downcast::<Vec<TradeTick>>(resp.data.clone())
-                    .expect("Invalid response data");
-                self.handle_trades(trades);
+        } else {
+            resp
+        };
+
+        match &resp.data {
+            DataResponsePayload::Instrument(instrument) => {
+                self.handle_instruments(std::slice::from_ref(instrument.as_ref()));
             }
-            stringify!(Bar) => {
-                let bars =
-                    Arc::downcast::<Vec<Bar>>(resp.data.clone()).expect("Invalid response data");
-                self.handle_bars(bars);
+            DataResponsePayload::Instruments(instruments) => self.handle_instruments(instruments),
+            DataResponsePayload::Quotes(quotes) => self.handle_quotes(quotes),
+            DataResponsePayload::Trades(trades) => self.handle_trades(trades),
+            DataResponsePayload::Bars(bars) => self.handle_bars(bars),
+            DataResponsePayload::Book(book) => self.handle_book(book),
+            DataResponsePayload::Custom(_) => {
+                // Other custom payloads are forwarded only
+                if let Some(data) = resp.data.downcast_custom::<Vec<CustomData>>() {
+                    self.handle_custom_data_list(data);
+                }
             }
-            type_name => log::error!("Cannot handle request, type {type_name} is unrecognized"),
         }
 
-        self.msgbus.as_ref().borrow().send_response(resp);
+        send_response(&self.msgbus.as_ref().borrow(), resp);
     }
 
     // -- DATA HANDLERS ---------------------------------------------------------------------------
@@ -441,7 +723,64 @@ impl DataEngine {
 
         let mut msgbus = self.msgbus.borrow_mut();
         let topic = msgbus.switchboard.get_instrument_topic(instrument.id());
-        msgbus.publish(&topic, &instrument as &dyn Any); // TODO: Optimize
+        msgbus.publish(&topic, &instrument); // TODO: Optimize
+    }
+
+    fn handle_custom_data(&mut self, data: CustomData) {
+        if let Err(e) = self
+            .cache
+            .as_ref()
+            .borrow_mut()
+            .add_custom_data(data.clone())
+        {
+            log::error!("Error on cache insert: {e}");
+        }
+
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_custom_topic(&data.data_type);
+        msgbus.publish(&topic, &data); // TODO: Optimize
+    }
+
+    fn handle_funding_rate(&mut self, update: FundingRateUpdate) {
+        if let Err(e) = self.cache.as_ref().borrow_mut().add_funding_rate(update) {
+            log::error!("Error on cache insert: {e}");
+        }
+
+        // Release the mutable borrow before publishing, as subscribers may send messages
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_funding_rates_topic(update.instrument_id);
+        self.msgbus.borrow().publish(&topic, &update);
+    }
+
+    fn handle_mark_price(&mut self, update: MarkPriceUpdate) {
+        if let Err(e) = self.cache.as_ref().borrow_mut().add_mark_price(update) {
+            log::error!("Error on cache insert: {e}");
+        }
+
+        // Release the mutable borrow before publishing, as subscribers may send messages
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_mark_prices_topic(update.instrument_id);
+        self.msgbus.borrow().publish(&topic, &update);
+    }
+
+    fn handle_index_price(&mut self, update: IndexPriceUpdate) {
+        if let Err(e) = self.cache.as_ref().borrow_mut().add_index_price(update) {
+            log::error!("Error on cache insert: {e}");
+        }
+
+        // Release the mutable borrow before publishing, as subscribers may send messages
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_index_prices_topic(update.instrument_id);
+        self.msgbus.borrow().publish(&topic, &update);
     }
 
     fn handle_delta(&mut self, delta: OrderBookDelta) {
@@ -466,49 +805,57 @@ impl DataEngine {
             OrderBookDeltas::new(delta.instrument_id, vec![delta])
         };
 
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus.switchboard.get_deltas_topic(deltas.instrument_id);
-        msgbus.publish(&topic, &deltas as &dyn Any);
+        self.publish_deltas(&deltas);
     }
 
     fn handle_deltas(&mut self, deltas: OrderBookDeltas) {
-        let deltas = if self.config.buffer_deltas {
-            let buffer_deltas = self
-                .buffered_deltas_map
-                .entry(deltas.instrument_id)
-                .or_default();
-            buffer_deltas.extend(deltas.deltas);
+        if !self.config.buffer_deltas {
+            self.publish_deltas(&deltas);
+            return;
+        }
 
-            let mut is_last_delta = false;
-            for delta in buffer_deltas.iter_mut() {
-                if RecordFlag::F_LAST.matches(delta.flags) {
-                    is_last_delta = true;
+        let instrument_id = deltas.instrument_id;
+        let mut completed = Vec::new();
+        {
+            let buffer_deltas = self.buffered_deltas_map.entry(instrument_id).or_default();
+
+            // Publish one event per `F_LAST` boundary, any trailing deltas
+            // remain buffered until their event is completed
+            for delta in deltas.deltas {
+                let is_last = RecordFlag::F_LAST.matches(delta.flags);
+                buffer_deltas.push(delta);
+                if is_last {
+                    completed.push(OrderBookDeltas::new(
+                        instrument_id,
+                        std::mem::take(buffer_deltas),
+                    ));
                 }
             }
 
-            if !is_last_delta {
-                return;
+            if buffer_deltas.is_empty() {
+                self.buffered_deltas_map.remove(&instrument_id);
             }
+        }
 
-            // SAFETY: We know the deltas exists already
-            let buffer_deltas = self
-                .buffered_deltas_map
-                .remove(&deltas.instrument_id)
-                .unwrap();
-            OrderBookDeltas::new(deltas.instrument_id, buffer_deltas)
-        } else {
-            deltas
-        };
+        for deltas in &completed {
+            self.publish_deltas(deltas);
+        }
+    }
 
+    fn publish_deltas(&self, deltas: &OrderBookDeltas) {
         let mut msgbus = self.msgbus.borrow_mut();
         let topic = msgbus.switchboard.get_deltas_topic(deltas.instrument_id);
-        msgbus.publish(&topic, &deltas as &dyn Any); // TODO: Optimize
+        msgbus.publish(&topic, deltas); // TODO: Optimize
     }
 
     fn handle_depth10(&mut self, depth: OrderBookDepth10) {
+        if let Err(e) = self.cache.as_ref().borrow_mut().add_depth10(depth) {
+            log::error!("Error on cache insert: {e}");
+        }
+
         let mut msgbus = self.msgbus.borrow_mut();
         let topic = msgbus.switchboard.get_depth_topic(depth.instrument_id);
-        msgbus.publish(&topic, &depth as &dyn Any); // TODO: Optimize
+        msgbus.publish(&topic, &depth); // TODO: Optimize
     }
 
     fn handle_quote(&mut self, quote: QuoteTick) {
@@ -518,9 +865,23 @@ impl DataEngine {
 
         // TODO: Handle synthetics
 
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus.switchboard.get_quotes_topic(quote.instrument_id);
-        msgbus.publish(&topic, &quote as &dyn Any); // TODO: Optimize
+        // Release the mutable borrow before publishing, as subscribers may send messages
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_quotes_topic(quote.instrument_id);
+        self.msgbus.borrow().publish(&topic, &quote); // TODO: Optimize
+
+        // Quotes are the price source for bid, ask and mid bars
+        for aggregator in self.bar_aggregators.values_mut() {
+            let bar_type = aggregator.bar_type();
+            if bar_type.instrument_id() == quote.instrument_id
+                && bar_type.spec().price_type != PriceType::Last
+            {
+                aggregator.handle_quote(quote);
+            }
+        }
     }
 
     fn handle_trade(&mut self, trade: TradeTick) {
@@ -530,9 +891,22 @@ impl DataEngine {
 
         // TODO: Handle synthetics
 
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus.switchboard.get_trades_topic(trade.instrument_id);
-        msgbus.publish(&topic, &trade as &dyn Any); // TODO: Optimize
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_trades_topic(trade.instrument_id);
+        self.msgbus.borrow().publish(&topic, &trade); // TODO: Optimize
+
+        // Trades are the price source for last bars
+        for aggregator in self.bar_aggregators.values_mut() {
+            let bar_type = aggregator.bar_type();
+            if bar_type.instrument_id() == trade.instrument_id
+                && bar_type.spec().price_type == PriceType::Last
+            {
+                aggregator.handle_trade(trade);
+            }
+        }
     }
 
     fn handle_bar(&mut self, bar: Bar) {
@@ -563,7 +937,7 @@ impl DataEngine {
 
         let mut msgbus = self.msgbus.borrow_mut();
         let topic = msgbus.switchboard.get_bars_topic(bar.bar_type);
-        msgbus.publish(&topic, &bar as &dyn Any); // TODO: Optimize
+        msgbus.publish(&topic, &bar); // TODO: Optimize
     }
 
     // -- SUBSCRIPTION HANDLERS -------------------------------------------------------------------
@@ -583,10 +957,6 @@ impl DataEngine {
             anyhow::bail!("Cannot subscribe for synthetic instrument `OrderBookDelta` data");
         }
 
-        if !self.subscribed_order_book_deltas().contains(&instrument_id) {
-            return Ok(());
-        }
-
         let data_type = command.data_type.clone();
         let book_type = data_type.book_type();
         let depth = data_type.depth();
@@ -635,9 +1005,10 @@ impl DataEngine {
                     root: Ustr::from(instrument_id.symbol.root()),
                     topic,
                     interval_ms,
+                    depth,
                 };
 
-                let now_ns = self.clock.timestamp_ns().as_u64();
+                let now_ns = self.clock.borrow().timestamp_ns().as_u64();
                 let mut start_time_ns = now_ns - (now_ns % interval_ns);
 
                 if start_time_ns - NANOSECONDS_IN_MILLISECOND <= now_ns {
@@ -657,6 +1028,7 @@ impl DataEngine {
                     TimeEventCallback::Rust(Rc::new(move |event| snapshotter.snapshot(event)));
 
                 self.clock
+                    .borrow_mut()
                     .set_timer_ns(
                         &timer_name,
                         interval_ns,
@@ -757,8 +1129,23 @@ impl DataEngine {
     }
 
     fn handle_unsubscribe_bars(&mut self, command: &SubscriptionCommand) -> anyhow::Result<()> {
-        // TODO: Handle aggregators
-        Ok(())
+        let bar_type = command.data_type.bar_type();
+        if !self.bar_aggregators.contains_key(&bar_type.standard()) {
+            return Ok(());
+        }
+
+        // Stop the aggregator only once the last subscriber to its bars has unsubscribed
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_bars_topic(bar_type);
+        if self.msgbus.borrow().subscriptions_count(topic) > 0 {
+            log::debug!("Not stopping bar aggregator for {bar_type}: bars still subscribed");
+            return Ok(());
+        }
+
+        self.stop_bar_aggregator(bar_type)
     }
 
     fn maintain_book_updater(&mut self, instrument_id: &InstrumentId, topics: &[Ustr]) {
@@ -797,8 +1184,9 @@ impl DataEngine {
             if msgbus.subscriptions_count(topic) == 0 {
                 let timer_name = snapshotter.timer_name;
                 self.book_snapshotters.remove(instrument_id);
-                if self.clock.timer_names().contains(&timer_name.as_str()) {
-                    self.clock.cancel_timer(&timer_name);
+                let mut clock = self.clock.borrow_mut();
+                if clock.timer_names().contains(&timer_name.as_str()) {
+                    clock.cancel_timer(&timer_name);
                 }
                 log::debug!("Removed BookSnapshotter for instrument ID {instrument_id}");
             }
@@ -807,30 +1195,49 @@ impl DataEngine {
 
     // -- RESPONSE HANDLERS -----------------------------------------------------------------------
 
-    fn handle_instruments(&self, instruments: Arc<Vec<InstrumentAny>>) {
+    fn handle_instruments(&mut self, instruments: &[InstrumentAny]) {
         // TODO improve by adding bulk update methods to cache and database
+        for instrument in instruments {
+            self.handle_instrument(instrument.clone());
+        }
+    }
+
+    fn handle_book(&self, book: &OrderBook) {
         let mut cache = self.cache.as_ref().borrow_mut();
-        for instrument in instruments.iter() {
-            if let Err(e) = cache.add_instrument(instrument.clone()) {
-                log::error!("Error on cache insert: {e}");
-            }
+
+        // A managed book is maintained from the live updates, so is not replaced
+        if cache.has_order_book(&book.instrument_id) {
+            return;
+        }
+
+        if let Err(e) = cache.add_order_book(book.clone()) {
+            log::error!("Error on cache insert: {e}");
         }
     }
 
-    fn handle_quotes(&self, quotes: Arc<Vec<QuoteTick>>) {
-        if let Err(e) = self.cache.as_ref().borrow_mut().add_quotes(&quotes) {
+    fn handle_quotes(&self, quotes: &[QuoteTick]) {
+        if let Err(e) = self.cache.as_ref().borrow_mut().add_quotes(quotes) {
             log::error!("Error on cache insert: {e}");
         }
     }
 
-    fn handle_trades(&self, trades: Arc<Vec<TradeTick>>) {
-        if let Err(e) = self.cache.as_ref().borrow_mut().add_trades(&trades) {
+    fn handle_trades(&self, trades: &[TradeTick]) {
+        if let Err(e) = self.cache.as_ref().borrow_mut().add_trades(trades) {
             log::error!("Error on cache insert: {e}");
         }
     }
 
-    fn handle_bars(&self, bars: Arc<Vec<Bar>>) {
-        if let Err(e) = self.cache.as_ref().borrow_mut().add_bars(&bars) {
+    fn handle_custom_data_list(&self, data: &[CustomData]) {
+        let mut cache = self.cache.as_ref().borrow_mut();
+        for item in data {
+            if let Err(e) = cache.add_custom_data(item.clone()) {
+                log::error!("Error on cache insert: {e}");
+            }
+        }
+    }
+
+    fn handle_bars(&self, bars: &[Bar]) {
+        if let Err(e) = self.cache.as_ref().borrow_mut().add_bars(bars) {
             log::error!("Error on cache insert: {e}");
         }
     }
@@ -879,6 +1286,7 @@ impl DataEngine {
             .cache
             .borrow()
             .instrument(&bar_type.instrument_id())
+            .cloned()
             .ok_or_else(|| {
                 anyhow::anyhow!(
                     "Cannot start bar aggregation: no instrument found for {}",
@@ -886,55 +1294,100 @@ impl DataEngine {
                 )
             })?;
 
-        // Create aggregator
-        // TODO: Determine how to handle generic Clock vs dyn Clock
-        // let aggregator = if bar_type.spec().is_time_aggregated() {
-        //     TimeBarAggregator::new(
-        //         instrument,
-        //         bar_type,
-        //         |b| self.handle_bar(b),
-        //         false,
-        //         self.clock,
-        //         self.config.time_bars_build_with_no_updates,
-        //         self.config.time_bars_timestamp_on_close,
-        //         &self.config.time_bars_interval_type,
-        //     )
-        // };
+        // Aggregated bars are cached and published in the same way as bars from a client
+        let cache = self.cache.clone();
+        let msgbus = self.msgbus.clone();
+        let handler = move |bar: Bar| {
+            if let Err(e) = cache.as_ref().borrow_mut().add_bar(bar) {
+                log::error!("Error on cache insert: {e}");
+            }
+
+            let mut msgbus = msgbus.borrow_mut();
+            let topic = msgbus.switchboard.get_bars_topic(bar.bar_type);
+            msgbus.publish(&topic, &bar); // TODO: Optimize
+        };
+
+        let bar_type = bar_type.standard();
+        let aggregator: Box<dyn BarAggregator> = match bar_type.spec().aggregation {
+            BarAggregation::Tick => Box::new(TickBarAggregator::new(
+                &instrument,
+                bar_type,
+                handler,
+                false,
+            )),
+            BarAggregation::Volume => Box::new(VolumeBarAggregator::new(
+                &instrument,
+                bar_type,
+                handler,
+                false,
+            )),
+            BarAggregation::Value => Box::new(ValueBarAggregator::new(
+                &instrument,
+                bar_type,
+                handler,
+                false,
+            )),
+            BarAggregation::Millisecond
+            | BarAggregation::Second
+            | BarAggregation::Minute
+            | BarAggregation::Hour
+            | BarAggregation::Day => {
+                // The aggregator is shared with its timer callback, which builds each bar
+                let aggregator = Rc::new(RefCell::new(TimeBarAggregator::new(
+                    &instrument,
+                    bar_type,
+                    handler,
+                    false,
+                    self.config.time_bars_build_with_no_updates,
+                    self.config.time_bars_timestamp_on_close,
+                    self.config.time_bars_interval_type,
+                )));
+                let callback = NewBarCallback::new(aggregator.clone());
+                aggregator
+                    .borrow_mut()
+                    .start(&mut *self.clock.borrow_mut(), callback)?;
+                Box::new(aggregator)
+            }
+            aggregation => {
+                log::warn!("Cannot start bar aggregation: {aggregation} not yet supported");
+                return Ok(());
+            }
+        };
+
+        log::debug!("Starting bar aggregator for {bar_type}");
+        self.bar_aggregators.insert(bar_type, aggregator);
 
         Ok(())
     }
 
     fn stop_bar_aggregator(&mut self, bar_type: BarType) -> anyhow::Result<()> {
-        let aggregator = self
-            .bar_aggregators
-            .remove(&bar_type.standard())
-            .ok_or_else(|| {
-                anyhow::anyhow!("Cannot stop bar aggregator: no aggregator to stop for {bar_type}")
-            })?;
+        let bar_type = bar_type.standard();
+        self.bar_aggregators.remove(&bar_type).ok_or_else(|| {
+            anyhow::anyhow!("Cannot stop bar aggregator: no aggregator to stop for {bar_type}")
+        })?;
 
-        // TODO: If its a `TimeBarAggregator` then call `.stop()`
-        // if let Some(aggregator) = (aggregator as &dyn BarAggregator)
-        //     .as_any()
-        //     .downcast_ref::<TimeBarAggregator<_, _>>()
-        // {
-        //     aggregator.stop();
-        // };
-
-        if bar_type.is_composite() {
-            let composite_bar_type = bar_type.composite();
-            // TODO: Unsubscribe the `aggregator.handle_bar`
-        } else if bar_type.spec().price_type == PriceType::Last {
-            // TODO: Unsubscribe `aggregator.handle_trade_tick`
-            todo!()
-        } else {
-            // TODO: Unsubscribe `aggregator.handle_quote_tick`
-            todo!()
-        };
+        // Time bar aggregators are built by a timer named for their bar type
+        if bar_type.spec().is_time_aggregated() {
+            self.clock.borrow_mut().cancel_timer(&bar_type.to_string());
+        }
 
+        log::debug!("Stopped bar aggregator for {bar_type}");
         Ok(())
     }
 }
 
+/// Sends the `resp` to the callback handler of a request made through the message bus,
+/// otherwise to the endpoint of the requesting client.
+fn send_response(msgbus: &MessageBus, resp: DataResponse) {
+    if msgbus.is_pending_response(&resp.correlation_id) {
+        if let Err(e) = msgbus.response(resp) {
+            log::error!("Cannot send response: {e}");
+        }
+    } else {
+        msgbus.send_response(resp);
+    }
+}
+
 pub struct SubscriptionCommandHandler {
     pub id: Ustr,
     pub engine_ref: Rc<RefCell<DataEngine>>,
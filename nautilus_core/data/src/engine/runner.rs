@@ -22,9 +22,12 @@ use std::{
 use futures::StreamExt;
 use nautilus_common::{
     clock::{Clock, LiveClock, TestClock},
+    drift::{set_drift_check_timer, ClockDriftMonitor},
     messages::data::{DataEvent, DataResponse, SubscriptionCommand},
+    msgbus::MessageBus,
     runtime::get_runtime,
     timer::{TimeEvent, TimeEventHandlerV2},
+    watchdog::{set_watchdog_timer, ConnectionWatchdog},
 };
 use nautilus_model::data::GetTsInit;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
@@ -158,6 +161,7 @@ impl Runner for BacktestRunner {
 
                     // Execute all handlers before processing the data
                     handlers.into_iter().for_each(TimeEventHandlerV2::run);
+                    engine.check_request_timeouts();
 
                     engine.process_data(data);
                 }
@@ -167,19 +171,77 @@ impl Runner for BacktestRunner {
 }
 
 pub struct LiveRunner {
+    resp_tx: UnboundedSender<DataEvent>,
     resp_rx: UnboundedReceiver<DataEvent>,
     pub clock: Rc<RefCell<LiveClock>>,
+    drift_monitor: Option<Rc<RefCell<ClockDriftMonitor>>>,
+}
+
+impl LiveRunner {
+    /// Returns a sender for publishing data events to the engine from async tasks,
+    /// such as live data client streams and REST pollers.
+    #[must_use]
+    pub fn data_sender(&self) -> UnboundedSender<DataEvent> {
+        self.resp_tx.clone()
+    }
+
+    /// Sets the `monitor` which records the clock drift of each data point received,
+    /// checked every `interval_ns`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the check timer cannot be set.
+    pub fn set_drift_monitor(
+        &mut self,
+        monitor: Rc<RefCell<ClockDriftMonitor>>,
+        interval_ns: u64,
+    ) -> anyhow::Result<()> {
+        set_drift_check_timer(
+            monitor.clone(),
+            &mut *self.clock.borrow_mut(),
+            "ClockDriftMonitor",
+            interval_ns,
+        )?;
+        self.drift_monitor = Some(monitor);
+        Ok(())
+    }
+
+    /// Sets the `watchdog` monitoring the live client connections, checked every
+    /// `interval_ns` with health events published on the `msgbus` (if given).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the check timer cannot be set.
+    pub fn set_watchdog(
+        &mut self,
+        watchdog: Rc<RefCell<ConnectionWatchdog>>,
+        msgbus: Option<Rc<RefCell<MessageBus>>>,
+        interval_ns: u64,
+    ) -> anyhow::Result<()> {
+        set_watchdog_timer(
+            watchdog,
+            msgbus,
+            &mut *self.clock.borrow_mut(),
+            "ConnectionWatchdog",
+            interval_ns,
+        )
+    }
 }
 
 impl Runner for LiveRunner {
     fn new() -> Self {
         let (resp_tx, resp_rx) = tokio::sync::mpsc::unbounded_channel::<DataEvent>();
-        set_data_queue(Rc::new(RefCell::new(AsyncDataQueue(resp_tx))));
+        set_data_queue(Rc::new(RefCell::new(AsyncDataQueue(resp_tx.clone()))));
 
         let clock = Rc::new(RefCell::new(LiveClock::new()));
         set_clock(clock.clone());
 
-        Self { resp_rx, clock }
+        Self {
+            resp_tx,
+            resp_rx,
+            clock,
+            drift_monitor: None,
+        }
     }
 
     fn run(&mut self, engine: &mut DataEngine) {
@@ -204,11 +266,23 @@ impl Runner for LiveRunner {
             match next_event {
                 Some(RunnerEvent::Data(resp)) => match resp {
                     DataEvent::Response(resp) => engine.response(resp),
-                    DataEvent::Data(data) => engine.process_data(data),
+                    DataEvent::Data(data) => {
+                        if let Some(monitor) = &self.drift_monitor {
+                            monitor.borrow_mut().handle_data(&data);
+                        }
+                        engine.process_data(data);
+                    }
                 },
                 Some(RunnerEvent::Timer(event)) => self.clock.borrow().get_handler(event).run(),
                 None => break,
             }
+
+            engine.check_request_timeouts();
+
+            // Buffered subscribers receive data once the engine has caught up
+            if self.resp_rx.is_empty() {
+                engine.drain_buffers();
+            }
         }
     }
 }
@@ -18,38 +18,66 @@
 use std::{
     any::Any,
     cell::{OnceCell, RefCell},
+    num::NonZeroU64,
     rc::Rc,
 };
 
+use bytes::Bytes;
 use indexmap::indexmap;
 use nautilus_common::{
     cache::Cache,
-    clock::TestClock,
-    messages::data::{Action, SubscriptionCommand},
+    clock::{Clock, TestClock},
+    custom::CustomData,
+    messages::{
+        data::{
+            Action, DataRequest, DataRequestFailure, DataResponse, SubscribeCommand,
+            SubscriptionCommand, SubscriptionKind, UnsubscribeCommand,
+        },
+        split::SplitStrategy,
+    },
     msgbus::{
-        handler::ShareableMessageHandler,
-        stubs::{get_message_saving_handler, get_saved_messages},
+        handler::{MessageHandler, ShareableMessageHandler},
+        stubs::{
+            get_call_check_shareable_handler, get_message_saving_handler,
+            get_response_saving_handler, get_saved_messages, get_saved_response_ids,
+        },
         switchboard::MessagingSwitchboard,
         MessageBus,
     },
     testing::init_logger_for_testing,
+    timer::TimeEvent,
 };
 use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 use nautilus_model::{
+    accounts::{AccountAny, MarginAccount},
     data::{
-        stubs::{stub_delta, stub_deltas, stub_depth10},
-        Bar, BarType, Data, DataType, OrderBookDeltas, OrderBookDeltas_API, OrderBookDepth10,
-        QuoteTick, TradeTick,
+        stubs::{
+            stub_delta, stub_deltas, stub_depth10, stub_funding_rate_update,
+            stub_index_price_update, stub_mark_price_update,
+        },
+        Bar, BarType, Data, DataType, FundingRateUpdate, IndexPriceUpdate, MarkPriceUpdate,
+        OrderBookDeltas, OrderBookDeltas_API, OrderBookDepth10, QuoteTick, TradeTick,
     },
-    enums::BookType,
-    identifiers::{ClientId, TraderId, Venue},
+    enums::{BookType, OmsType, OrderSide, OrderType, RecordFlag},
+    events::account::stubs::margin_account_state,
+    identifiers::{ClientId, PositionId, TraderId, Venue},
     instruments::{stubs::audusd_sim, CurrencyPair, InstrumentAny},
+    orderbook::OrderBook,
+    orders::{stubs::TestOrderEventStubs, OrderTestBuilder},
+    position::Position,
+    types::{Money, Price, Quantity},
 };
+use nautilus_portfolio::{config::PortfolioConfig, portfolio::Portfolio};
 use rstest::*;
+use ustr::Ustr;
 
 use crate::{
     client::DataClientAdapter,
-    engine::{DataEngine, SubscriptionCommandHandler},
+    engine::{
+        book::{BookSnapshotInfo, BookSnapshotter},
+        config::DataEngineConfig,
+        DataEngine, SubscriptionCommandHandler,
+    },
     mocks::MockDataClient,
 };
 
@@ -96,6 +124,7 @@ fn msgbus(trader_id: TraderId) -> Rc<RefCell<MessageBus>> {
                 UUID4::new(),
                 None,
                 None,
+                None,
             )))
         })
         .clone()
@@ -113,7 +142,7 @@ fn data_engine(
     cache: Rc<RefCell<Cache>>,
     msgbus: Rc<RefCell<MessageBus>>,
 ) -> Rc<RefCell<DataEngine>> {
-    let data_engine = DataEngine::new(clock, cache, msgbus, None);
+    let data_engine = DataEngine::new(Rc::new(RefCell::new(*clock)), cache, msgbus, None);
     Rc::new(RefCell::new(data_engine))
 }
 
@@ -697,6 +726,55 @@ fn test_process_order_book_deltas(
     assert!(messages.contains(&deltas));
 }
 
+#[rstest]
+fn test_process_order_book_deltas_buffered_publishes_complete_events(
+    clock: Box<TestClock>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let config = DataEngineConfig {
+        buffer_deltas: true,
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(
+        Rc::new(RefCell::new(*clock)),
+        cache,
+        msgbus.clone(),
+        Some(config),
+    );
+
+    let last_flags = RecordFlag::F_LAST as u8 | RecordFlag::F_SNAPSHOT as u8;
+    let mut deltas = stub_deltas();
+    let instrument_id = deltas.instrument_id;
+    deltas.deltas[1].flags = last_flags;
+    let trailing = deltas.deltas[2..].to_vec();
+
+    let handler = get_message_saving_handler::<OrderBookDeltas>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_deltas_topic(instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    // TODO: Using FFI API wrapper temporarily until Cython gone
+    data_engine.process_data(Data::Deltas(OrderBookDeltas_API::new(deltas.clone())));
+
+    let messages = get_saved_messages::<OrderBookDeltas>(handler.clone());
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].deltas, deltas.deltas[..2].to_vec());
+
+    let mut last_delta = stub_delta();
+    last_delta.instrument_id = instrument_id;
+    last_delta.flags = RecordFlag::F_LAST as u8;
+    data_engine.process_data(Data::Delta(last_delta));
+
+    let messages = get_saved_messages::<OrderBookDeltas>(handler);
+    let mut expected = trailing;
+    expected.push(last_delta);
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[1].deltas, expected);
+}
+
 #[rstest]
 fn test_process_order_book_depth10(
     audusd_sim: CurrencyPair,
@@ -743,11 +821,12 @@ fn test_process_order_book_depth10(
 
     let mut data_engine = data_engine.borrow_mut();
     data_engine.process_data(Data::Depth10(depth));
-    let _cache = &data_engine.get_cache();
+    let cache = &data_engine.get_cache();
     let messages = get_saved_messages::<OrderBookDepth10>(handler);
 
     assert_eq!(messages.len(), 1);
     assert!(messages.contains(&depth));
+    assert_eq!(cache.depth10(&depth.instrument_id), Some(&depth));
 }
 
 #[rstest]
@@ -904,3 +983,1039 @@ fn test_process_bar(
     assert_eq!(messages.len(), 1);
     assert!(messages.contains(&bar));
 }
+
+fn subscribe_internal_bars(
+    bar_type: BarType,
+    instrument: CurrencyPair,
+    msgbus: &Rc<RefCell<MessageBus>>,
+    switchboard: &MessagingSwitchboard,
+    data_engine: &Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) -> ShareableMessageHandler {
+    let endpoint = switchboard.data_engine_execute;
+    let handler = ShareableMessageHandler(Rc::new(SubscriptionCommandHandler {
+        id: endpoint,
+        engine_ref: data_engine.clone(),
+    }));
+    msgbus.borrow_mut().register(endpoint, handler);
+
+    let instrument = InstrumentAny::CurrencyPair(instrument);
+    data_engine.borrow_mut().process(&instrument as &dyn Any);
+
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    data_engine.borrow_mut().register_client(data_client, None);
+
+    let metadata = indexmap! {
+        "bar_type".to_string() => bar_type.to_string(),
+    };
+    let cmd = SubscriptionCommand::new(
+        client_id,
+        venue,
+        DataType::new(stringify!(Bar), Some(metadata)),
+        Action::Subscribe,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    );
+    msgbus.borrow().send(&endpoint, &cmd as &dyn Any);
+    data_engine.borrow_mut().run();
+
+    let handler = get_message_saving_handler::<Bar>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_bars_topic(bar_type);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+    handler
+}
+
+#[rstest]
+fn test_internal_mid_bars_aggregated_from_quotes(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    switchboard: MessagingSwitchboard,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let instrument_id = audusd_sim.id;
+    let bar_type = BarType::from("AUD/USD.SIM-2-TICK-MID-INTERNAL");
+    let handler = subscribe_internal_bars(
+        bar_type,
+        audusd_sim,
+        &msgbus,
+        &switchboard,
+        &data_engine,
+        data_client,
+    );
+
+    let quote = |bid: &str, ask: &str| QuoteTick {
+        instrument_id,
+        bid_price: Price::from(bid),
+        ask_price: Price::from(ask),
+        ..Default::default()
+    };
+    let trade = TradeTick {
+        instrument_id,
+        ..Default::default()
+    };
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process_data(Data::Quote(quote("1.00000", "1.00002")));
+    data_engine.process_data(Data::Trade(trade)); // Not a price source for mid bars
+    data_engine.process_data(Data::Quote(quote("1.00004", "1.00006")));
+
+    let bars = get_saved_messages::<Bar>(handler);
+    assert_eq!(bars.len(), 1);
+    assert_eq!(bars[0].bar_type, bar_type);
+    assert_eq!(bars[0].open, Price::from("1.000010"));
+    assert_eq!(bars[0].high, Price::from("1.000050"));
+    assert_eq!(bars[0].low, Price::from("1.000010"));
+    assert_eq!(bars[0].close, Price::from("1.000050"));
+    assert_eq!(data_engine.get_cache().bar(&bar_type), Some(&bars[0]));
+}
+
+#[rstest]
+fn test_internal_last_bars_aggregated_from_trades(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    switchboard: MessagingSwitchboard,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let instrument_id = audusd_sim.id;
+    let bar_type = BarType::from("AUD/USD.SIM-2-TICK-LAST-INTERNAL");
+    let handler = subscribe_internal_bars(
+        bar_type,
+        audusd_sim,
+        &msgbus,
+        &switchboard,
+        &data_engine,
+        data_client,
+    );
+
+    let trade = |price: &str| TradeTick {
+        instrument_id,
+        price: Price::from(price),
+        ..Default::default()
+    };
+    let quote = QuoteTick {
+        instrument_id,
+        ..Default::default()
+    };
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process_data(Data::Trade(trade("1.00001")));
+    data_engine.process_data(Data::Quote(quote)); // Not a price source for last bars
+    data_engine.process_data(Data::Trade(trade("1.00003")));
+
+    let bars = get_saved_messages::<Bar>(handler);
+    assert_eq!(bars.len(), 1);
+    assert_eq!(bars[0].open, Price::from("1.00001"));
+    assert_eq!(bars[0].close, Price::from("1.00003"));
+}
+
+#[rstest]
+fn test_unsubscribe_internal_bars_stops_aggregator_when_last_subscriber_leaves(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    switchboard: MessagingSwitchboard,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let instrument_id = audusd_sim.id;
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    let bar_type = BarType::from("AUD/USD.SIM-2-TICK-LAST-INTERNAL");
+    let handler1 = subscribe_internal_bars(
+        bar_type,
+        audusd_sim,
+        &msgbus,
+        &switchboard,
+        &data_engine,
+        data_client,
+    );
+    let handler2 = get_message_saving_handler::<Bar>(None);
+    let topic = msgbus.borrow_mut().switchboard.get_bars_topic(bar_type);
+    msgbus.borrow_mut().subscribe(topic, handler2.clone(), None);
+
+    let endpoint = switchboard.data_engine_execute;
+    let metadata = indexmap! {
+        "bar_type".to_string() => bar_type.to_string(),
+    };
+    let cmd = SubscriptionCommand::new(
+        client_id,
+        venue,
+        DataType::new(stringify!(Bar), Some(metadata)),
+        Action::Unsubscribe,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    );
+    let trade = |price: &str| TradeTick {
+        instrument_id,
+        price: Price::from(price),
+        ..Default::default()
+    };
+
+    msgbus.borrow_mut().unsubscribe(topic, handler1);
+    msgbus.borrow().send(&endpoint, &cmd as &dyn Any);
+    data_engine.borrow_mut().run();
+    data_engine
+        .borrow_mut()
+        .process_data(Data::Trade(trade("1.00001")));
+    data_engine
+        .borrow_mut()
+        .process_data(Data::Trade(trade("1.00003")));
+
+    assert_eq!(data_engine.borrow().bar_aggregators.len(), 1);
+    assert_eq!(get_saved_messages::<Bar>(handler2.clone()).len(), 1);
+
+    msgbus.borrow_mut().unsubscribe(topic, handler2);
+    msgbus.borrow().send(&endpoint, &cmd as &dyn Any);
+    data_engine.borrow_mut().run();
+
+    assert!(data_engine.borrow().bar_aggregators.is_empty());
+}
+
+#[rstest]
+fn test_subscribe_command_routes_by_venue(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    switchboard: MessagingSwitchboard,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let venue = data_client.venue;
+    data_engine
+        .borrow_mut()
+        .register_client(data_client, Some(venue));
+
+    let endpoint = switchboard.data_engine_execute;
+    let handler = ShareableMessageHandler(Rc::new(SubscriptionCommandHandler {
+        id: endpoint,
+        engine_ref: data_engine.clone(),
+    }));
+    msgbus.borrow_mut().register(endpoint, handler);
+
+    let kind = SubscriptionKind::Quotes(audusd_sim.id);
+    let cmd = SubscribeCommand::new(
+        None,
+        Some(venue),
+        kind.clone(),
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    )
+    .unwrap();
+    msgbus.borrow().send(&endpoint, &cmd as &dyn Any);
+    data_engine.borrow_mut().run();
+
+    assert!(data_engine
+        .borrow()
+        .subscribed_quote_ticks()
+        .contains(&audusd_sim.id));
+
+    let cmd = UnsubscribeCommand::new(
+        None,
+        Some(venue),
+        kind,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    )
+    .unwrap();
+    msgbus.borrow().send(&endpoint, &cmd as &dyn Any);
+    data_engine.borrow_mut().run();
+
+    assert!(!data_engine
+        .borrow()
+        .subscribed_quote_ticks()
+        .contains(&audusd_sim.id));
+}
+
+#[rstest]
+fn test_subscribe_command_routes_to_default_client(
+    audusd_sim: CurrencyPair,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    data_engine
+        .borrow_mut()
+        .register_default_client(data_client);
+
+    let cmd = SubscribeCommand::new(
+        Some(ClientId::new("UNKNOWN")),
+        None,
+        SubscriptionKind::Trades(audusd_sim.id),
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    )
+    .unwrap();
+    data_engine.borrow_mut().subscribe(cmd);
+
+    assert_eq!(
+        data_engine.borrow().subscribed_trade_ticks(),
+        vec![audusd_sim.id]
+    );
+}
+
+#[rstest]
+fn test_subscribe_command_with_no_client_is_dropped(
+    audusd_sim: CurrencyPair,
+    data_engine: Rc<RefCell<DataEngine>>,
+) {
+    let cmd = SubscribeCommand::new(
+        None,
+        Some(audusd_sim.id.venue),
+        SubscriptionKind::Trades(audusd_sim.id),
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    )
+    .unwrap();
+    data_engine.borrow_mut().subscribe(cmd);
+
+    assert!(data_engine.borrow().subscribed_trade_ticks().is_empty());
+}
+
+#[rstest]
+fn test_response_instruments_are_cached_and_published(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+) {
+    let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+    let handler = get_message_saving_handler::<InstrumentAny>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_instrument_topic(audusd_sim.id());
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let resp = DataResponse::new(
+        UUID4::new(),
+        ClientId::new("SIM"),
+        audusd_sim.id().venue,
+        DataType::new(stringify!(InstrumentAny), None),
+        vec![audusd_sim.clone()],
+        UnixNanos::default(),
+        None,
+    );
+    data_engine.borrow_mut().response(resp);
+
+    assert_eq!(
+        data_engine
+            .borrow()
+            .get_cache()
+            .instrument(&audusd_sim.id()),
+        Some(&audusd_sim)
+    );
+    assert_eq!(
+        get_saved_messages::<InstrumentAny>(handler),
+        vec![audusd_sim]
+    );
+}
+
+#[rstest]
+fn test_request_with_no_client_is_not_tracked(data_engine: Rc<RefCell<DataEngine>>) {
+    let req = DataRequest {
+        correlation_id: UUID4::new(),
+        client_id: ClientId::new("UNKNOWN"),
+        venue: Venue::new("UNKNOWN"),
+        data_type: DataType::new(stringify!(QuoteTick), None),
+        ts_init: UnixNanos::default(),
+        params: None,
+    };
+
+    data_engine.borrow_mut().request(req);
+
+    assert_eq!(data_engine.borrow().pending_requests_count(), 0);
+    assert!(data_engine.borrow_mut().check_request_timeouts().is_empty());
+}
+
+#[rstest]
+fn test_response_is_sent_to_message_bus_request_callback(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+) {
+    let endpoint = Ustr::from("DataEngine.request");
+    msgbus
+        .borrow_mut()
+        .register(endpoint, get_call_check_shareable_handler(None));
+
+    let correlation_id = UUID4::new();
+    let req = DataRequest {
+        correlation_id,
+        client_id: ClientId::new("SIM"),
+        venue: Venue::new("SIM"),
+        data_type: DataType::new(stringify!(QuoteTick), None),
+        ts_init: UnixNanos::default(),
+        params: None,
+    };
+    let callback = get_response_saving_handler(None);
+    msgbus
+        .borrow()
+        .request(&endpoint, req, callback.clone())
+        .unwrap();
+
+    let resp = DataResponse::new(
+        correlation_id,
+        ClientId::new("SIM"),
+        Venue::new("SIM"),
+        DataType::new(stringify!(QuoteTick), None),
+        Vec::<QuoteTick>::new(),
+        UnixNanos::default(),
+        None,
+    );
+    data_engine.borrow_mut().response(resp);
+
+    assert_eq!(get_saved_response_ids(callback), vec![correlation_id]);
+    assert_eq!(msgbus.borrow().pending_responses_count(), 0);
+}
+
+#[rstest]
+fn test_request_timeout_cancels_message_bus_request(
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_client: DataClientAdapter,
+) {
+    let config = DataEngineConfig {
+        request_timeout_ms: 0,
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(
+        Rc::new(RefCell::new(TestClock::new())),
+        cache,
+        msgbus.clone(),
+        Some(config),
+    );
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    data_engine.register_client(data_client, Some(venue));
+
+    let endpoint = Ustr::from("DataEngine.request");
+    msgbus
+        .borrow_mut()
+        .register(endpoint, get_call_check_shareable_handler(None));
+
+    let correlation_id = UUID4::new();
+    let req = || DataRequest {
+        correlation_id,
+        client_id,
+        venue,
+        data_type: DataType::new(stringify!(QuoteTick), None),
+        ts_init: UnixNanos::default(),
+        params: None,
+    };
+    msgbus
+        .borrow()
+        .request(&endpoint, req(), get_response_saving_handler(None))
+        .unwrap();
+    data_engine.request(req());
+
+    assert_eq!(data_engine.check_request_timeouts(), vec![correlation_id]);
+    assert!(!msgbus.borrow().is_pending_response(&correlation_id));
+}
+
+/// Handler which saves the request failures of the responses it receives.
+struct FailureSavingHandler {
+    id: Ustr,
+    failures: RefCell<Vec<(UUID4, DataRequestFailure)>>,
+}
+
+impl MessageHandler for FailureSavingHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, _message: &dyn Any) {}
+
+    fn handle_response(&self, resp: DataResponse) {
+        if let Some(failure) = resp.failure() {
+            self.failures
+                .borrow_mut()
+                .push((resp.correlation_id, failure.clone()));
+        }
+    }
+
+    fn handle_data(&self, _data: Data) {}
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn get_saved_failures(handler: &ShareableMessageHandler) -> Vec<(UUID4, DataRequestFailure)> {
+    handler
+        .0
+        .as_any()
+        .downcast_ref::<FailureSavingHandler>()
+        .unwrap()
+        .failures
+        .borrow()
+        .clone()
+}
+
+fn timeout_data_engine(
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_client: DataClientAdapter,
+) -> DataEngine {
+    let config = DataEngineConfig {
+        request_timeout_ms: 0,
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(
+        Rc::new(RefCell::new(TestClock::new())),
+        cache,
+        msgbus,
+        Some(config),
+    );
+    let venue = data_client.venue;
+    data_engine.register_client(data_client, Some(venue));
+    data_engine
+}
+
+#[rstest]
+fn test_request_timeout_sends_failure_response(
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    let mut data_engine = timeout_data_engine(cache, msgbus.clone(), data_client);
+    let endpoint = Ustr::from("DataEngine.request");
+    msgbus
+        .borrow_mut()
+        .register(endpoint, get_call_check_shareable_handler(None));
+
+    let correlation_id = UUID4::new();
+    let req = || DataRequest {
+        correlation_id,
+        client_id,
+        venue,
+        data_type: DataType::new(stringify!(QuoteTick), None),
+        ts_init: UnixNanos::default(),
+        params: None,
+    };
+    let callback = ShareableMessageHandler(Rc::new(FailureSavingHandler {
+        id: Ustr::from("failure-saving"),
+        failures: RefCell::new(Vec::new()),
+    }));
+    msgbus
+        .borrow()
+        .request(&endpoint, req(), callback.clone())
+        .unwrap();
+    data_engine.request(req());
+    data_engine.check_request_timeouts();
+
+    assert_eq!(
+        get_saved_failures(&callback),
+        vec![(correlation_id, DataRequestFailure::new("Request timed out"))]
+    );
+}
+
+#[rstest]
+fn test_split_request_timeout_sends_single_failure_response(
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    let mut data_engine = timeout_data_engine(cache, msgbus.clone(), data_client);
+    let endpoint = Ustr::from("DataEngine.request");
+    msgbus
+        .borrow_mut()
+        .register(endpoint, get_call_check_shareable_handler(None));
+
+    let metadata = indexmap! {
+        "start".to_string() => "0".to_string(),
+        "end".to_string() => "2999".to_string(),
+    };
+    let correlation_id = UUID4::new();
+    let req = || DataRequest {
+        correlation_id,
+        client_id,
+        venue,
+        data_type: DataType::new(stringify!(QuoteTick), Some(metadata.clone())),
+        ts_init: UnixNanos::default(),
+        params: None,
+    };
+    let callback = ShareableMessageHandler(Rc::new(FailureSavingHandler {
+        id: Ustr::from("failure-saving"),
+        failures: RefCell::new(Vec::new()),
+    }));
+    msgbus
+        .borrow()
+        .request(&endpoint, req(), callback.clone())
+        .unwrap();
+    data_engine.request_split(
+        req(),
+        SplitStrategy::TimeWindow(NonZeroU64::new(1_000).unwrap()),
+    );
+
+    assert_eq!(data_engine.check_request_timeouts().len(), 3);
+    assert_eq!(data_engine.pending_split_requests_count(), 0);
+    assert_eq!(
+        get_saved_failures(&callback),
+        vec![(correlation_id, DataRequestFailure::new("Request timed out"))]
+    );
+}
+
+#[rstest]
+fn test_request_split_with_no_client_is_discarded(data_engine: Rc<RefCell<DataEngine>>) {
+    let metadata = indexmap! {
+        "start".to_string() => "0".to_string(),
+        "end".to_string() => "2999".to_string(),
+    };
+    let req = DataRequest {
+        correlation_id: UUID4::new(),
+        client_id: ClientId::new("UNKNOWN"),
+        venue: Venue::new("UNKNOWN"),
+        data_type: DataType::new(stringify!(QuoteTick), Some(metadata)),
+        ts_init: UnixNanos::default(),
+        params: None,
+    };
+
+    data_engine.borrow_mut().request_split(
+        req,
+        SplitStrategy::TimeWindow(NonZeroU64::new(1_000).unwrap()),
+    );
+
+    assert_eq!(data_engine.borrow().pending_split_requests_count(), 0);
+    assert_eq!(data_engine.borrow().pending_requests_count(), 0);
+}
+
+#[rstest]
+fn test_book_snapshotter_conflates_updates(
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let depth = stub_depth10();
+    let instrument_id = depth.instrument_id;
+    cache
+        .borrow_mut()
+        .add_order_book(OrderBook::new(instrument_id, BookType::L2_MBP))
+        .unwrap();
+
+    let handler = get_message_saving_handler::<OrderBook>(None);
+    let topic = {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_book_snapshots_topic(instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+        topic
+    };
+
+    let snap_info = BookSnapshotInfo {
+        instrument_id,
+        venue: instrument_id.venue,
+        is_composite: false,
+        root: instrument_id.symbol.inner(),
+        topic,
+        interval_ms: NonZeroU64::new(1_000).unwrap(),
+        depth: Some(5),
+    };
+    let snapshotter = BookSnapshotter::new(snap_info, cache.clone(), msgbus.clone());
+    let event = || TimeEvent::new(snapshotter.timer_name, UUID4::new(), 0.into(), 0.into());
+
+    // Book not yet updated
+    snapshotter.snapshot(event());
+    assert!(get_saved_messages::<OrderBook>(handler.clone()).is_empty());
+
+    // Multiple updates within an interval are conflated into one snapshot
+    {
+        let mut cache = cache.borrow_mut();
+        let book = cache.order_book_mut(&instrument_id).unwrap();
+        book.apply_depth(&depth);
+        book.apply_depth(&depth);
+    }
+    snapshotter.snapshot(event());
+
+    // No snapshot when the book was not updated during the interval
+    snapshotter.snapshot(event());
+
+    let snapshots = get_saved_messages::<OrderBook>(handler.clone());
+    assert_eq!(snapshots.len(), 1);
+    assert_eq!(snapshots[0].bids(None).count(), 5);
+    assert_eq!(snapshots[0].asks(None).count(), 5);
+
+    cache
+        .borrow_mut()
+        .order_book_mut(&instrument_id)
+        .unwrap()
+        .apply_depth(&depth);
+    snapshotter.snapshot(event());
+
+    assert_eq!(get_saved_messages::<OrderBook>(handler).len(), 2);
+}
+
+#[rstest]
+fn test_process_custom_data(
+    msgbus: Rc<RefCell<MessageBus>>,
+    switchboard: MessagingSwitchboard,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let venue = data_client.venue;
+    data_engine
+        .borrow_mut()
+        .register_client(data_client, Some(venue));
+
+    let endpoint = switchboard.data_engine_execute;
+    let handler = ShareableMessageHandler(Rc::new(SubscriptionCommandHandler {
+        id: endpoint,
+        engine_ref: data_engine.clone(),
+    }));
+    msgbus.borrow_mut().register(endpoint, handler);
+
+    let data_type = DataType::new("NewsEvent", None);
+    let cmd = SubscribeCommand::new(
+        None,
+        Some(venue),
+        SubscriptionKind::Data(data_type.clone()),
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    )
+    .unwrap();
+    msgbus.borrow().send(&endpoint, &cmd as &dyn Any);
+    data_engine.borrow_mut().run();
+
+    assert!(data_engine
+        .borrow()
+        .subscribed_custom_data()
+        .contains(&data_type));
+
+    let data = CustomData::new(
+        data_type.clone(),
+        Bytes::from_static(b"headline"),
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    );
+    let handler = get_message_saving_handler::<CustomData>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_custom_topic(&data_type);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process(&data as &dyn Any);
+    let cache = &data_engine.get_cache();
+    let messages = get_saved_messages::<CustomData>(handler);
+
+    assert_eq!(cache.custom_data_latest(&data_type), Some(&data));
+    assert_eq!(messages.len(), 1);
+    assert!(messages.contains(&data));
+}
+
+#[rstest]
+fn test_process_funding_rate(
+    msgbus: Rc<RefCell<MessageBus>>,
+    switchboard: MessagingSwitchboard,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let venue = data_client.venue;
+    data_engine
+        .borrow_mut()
+        .register_client(data_client, Some(venue));
+
+    let endpoint = switchboard.data_engine_execute;
+    let handler = ShareableMessageHandler(Rc::new(SubscriptionCommandHandler {
+        id: endpoint,
+        engine_ref: data_engine.clone(),
+    }));
+    msgbus.borrow_mut().register(endpoint, handler);
+
+    let update = stub_funding_rate_update();
+    let cmd = SubscribeCommand::new(
+        None,
+        Some(venue),
+        SubscriptionKind::FundingRates(update.instrument_id),
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    )
+    .unwrap();
+    msgbus.borrow().send(&endpoint, &cmd as &dyn Any);
+    data_engine.borrow_mut().run();
+
+    assert!(data_engine
+        .borrow()
+        .subscribed_funding_rates()
+        .contains(&update.instrument_id));
+
+    let handler = get_message_saving_handler::<FundingRateUpdate>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_funding_rates_topic(update.instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process(&update as &dyn Any);
+    let cache = &data_engine.get_cache();
+    let messages = get_saved_messages::<FundingRateUpdate>(handler);
+
+    assert_eq!(cache.funding_rate(&update.instrument_id), Some(&update));
+    assert_eq!(messages.len(), 1);
+    assert!(messages.contains(&update));
+}
+
+#[rstest]
+fn test_process_mark_and_index_prices(
+    msgbus: Rc<RefCell<MessageBus>>,
+    switchboard: MessagingSwitchboard,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let venue = data_client.venue;
+    data_engine
+        .borrow_mut()
+        .register_client(data_client, Some(venue));
+
+    let endpoint = switchboard.data_engine_execute;
+    let handler = ShareableMessageHandler(Rc::new(SubscriptionCommandHandler {
+        id: endpoint,
+        engine_ref: data_engine.clone(),
+    }));
+    msgbus.borrow_mut().register(endpoint, handler);
+
+    let mark = stub_mark_price_update();
+    let index = stub_index_price_update();
+    for kind in [
+        SubscriptionKind::MarkPrices(mark.instrument_id),
+        SubscriptionKind::IndexPrices(index.instrument_id),
+    ] {
+        let cmd = SubscribeCommand::new(
+            None,
+            Some(venue),
+            kind,
+            UUID4::new(),
+            UnixNanos::default(),
+            None,
+        )
+        .unwrap();
+        msgbus.borrow().send(&endpoint, &cmd as &dyn Any);
+    }
+    data_engine.borrow_mut().run();
+
+    assert!(data_engine
+        .borrow()
+        .subscribed_mark_prices()
+        .contains(&mark.instrument_id));
+    assert!(data_engine
+        .borrow()
+        .subscribed_index_prices()
+        .contains(&index.instrument_id));
+
+    let mark_handler = get_message_saving_handler::<MarkPriceUpdate>(None);
+    let index_handler = get_message_saving_handler::<IndexPriceUpdate>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_mark_prices_topic(mark.instrument_id);
+        msgbus.subscribe(topic, mark_handler.clone(), None);
+        let topic = msgbus
+            .switchboard
+            .get_index_prices_topic(index.instrument_id);
+        msgbus.subscribe(topic, index_handler.clone(), None);
+    }
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process(&mark as &dyn Any);
+    data_engine.process(&index as &dyn Any);
+    let cache = &data_engine.get_cache();
+
+    assert_eq!(cache.mark_price(&mark.instrument_id), Some(&mark));
+    assert_eq!(cache.index_price(&index.instrument_id), Some(&index));
+    assert_eq!(
+        get_saved_messages::<MarkPriceUpdate>(mark_handler),
+        vec![mark]
+    );
+    assert_eq!(
+        get_saved_messages::<IndexPriceUpdate>(index_handler),
+        vec![index]
+    );
+}
+
+/// Returns a data engine sharing its message bus and cache with a subscribed [`Portfolio`],
+/// holding an open long position for the `instrument` in a calculated margin account.
+fn data_engine_with_portfolio(
+    instrument: &InstrumentAny,
+    config: PortfolioConfig,
+) -> (DataEngine, Portfolio, Position) {
+    let clock: Rc<RefCell<dyn Clock>> = Rc::new(RefCell::new(TestClock::new()));
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(100_000))
+        .build();
+    let fill = TestOrderEventStubs::order_filled(
+        &order,
+        instrument,
+        None,
+        Some(PositionId::new("P-1")),
+        Some(Price::from("1.00000")),
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let position = Position::new(instrument, fill.into());
+    {
+        let mut cache = cache.borrow_mut();
+        cache.add_instrument(instrument.clone()).unwrap();
+        cache
+            .add_account(AccountAny::Margin(MarginAccount::new(
+                margin_account_state(),
+                true,
+            )))
+            .unwrap();
+        cache
+            .add_position(position.clone(), OmsType::Netting)
+            .unwrap();
+    }
+
+    let mut portfolio = Portfolio::new(msgbus.clone(), cache.clone(), clock.clone(), Some(config));
+    portfolio.initialize_positions();
+    let data_engine = DataEngine::new(clock, cache, msgbus, None);
+
+    (data_engine, portfolio, position)
+}
+
+#[rstest]
+fn test_process_funding_rate_settles_through_subscribed_portfolio(audusd_sim: CurrencyPair) {
+    let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+    let (mut data_engine, _portfolio, position) =
+        data_engine_with_portfolio(&instrument, PortfolioConfig::default());
+
+    let quote = QuoteTick::new(
+        instrument.id(),
+        Price::from("1.00010"),
+        Price::from("1.00020"),
+        Quantity::from(100_000),
+        Quantity::from(100_000),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    );
+    data_engine.process_data(Data::Quote(quote));
+
+    // The second update reaches the announced funding time, so the portfolio settles the
+    // period and publishes account and portfolio updates from within the handler
+    let announced = FundingRateUpdate {
+        instrument_id: instrument.id(),
+        next_funding_ns: Some(UnixNanos::from(100)),
+        ts_event: UnixNanos::default(),
+        ts_init: UnixNanos::default(),
+        ..stub_funding_rate_update()
+    };
+    let reached = FundingRateUpdate {
+        next_funding_ns: Some(UnixNanos::from(200)),
+        ts_event: UnixNanos::from(100),
+        ts_init: UnixNanos::from(100),
+        ..announced
+    };
+    data_engine.process(&announced as &dyn Any);
+    data_engine.process(&reached as &dyn Any);
+
+    let cache = data_engine.get_cache();
+    assert_eq!(cache.funding_rate(&instrument.id()), Some(&reached));
+    assert!(cache
+        .position(&position.id)
+        .unwrap()
+        .funding_payments
+        .is_some());
+}
+
+#[rstest]
+fn test_process_mark_price_marks_through_subscribed_portfolio(audusd_sim: CurrencyPair) {
+    let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+    let config = PortfolioConfig {
+        use_mark_prices: true,
+        ..Default::default()
+    };
+    let (mut data_engine, mut portfolio, _position) =
+        data_engine_with_portfolio(&instrument, config);
+
+    // The portfolio re-marks the position and publishes updates from within the handler
+    let mark = MarkPriceUpdate::new(
+        instrument.id(),
+        Price::from("1.00100"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    );
+    data_engine.process(&mark as &dyn Any);
+
+    assert_eq!(
+        data_engine.get_cache().mark_price(&instrument.id()),
+        Some(&mark)
+    );
+    assert_eq!(
+        portfolio.unrealized_pnl(&instrument.id()),
+        Some(Money::from("100.00 USD"))
+    );
+}
+
+/// Handler which republishes the index price updates it receives, re-entering the bus.
+struct RepublishingHandler {
+    id: Ustr,
+    topic: Ustr,
+    msgbus: Rc<RefCell<MessageBus>>,
+}
+
+impl MessageHandler for RepublishingHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        let update = message.downcast_ref::<IndexPriceUpdate>().unwrap();
+        self.msgbus.borrow().publish(&self.topic, update);
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+
+    fn handle_data(&self, _data: Data) {}
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[rstest]
+fn test_process_index_price_with_reentrant_subscriber(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+) {
+    let index = stub_index_price_update();
+    let republished_topic = Ustr::from("test.index_prices.republished");
+    let handler = get_message_saving_handler::<IndexPriceUpdate>(None);
+    {
+        let mut msgbus_mut = msgbus.borrow_mut();
+        let topic = msgbus_mut
+            .switchboard
+            .get_index_prices_topic(index.instrument_id);
+        let republisher = ShareableMessageHandler(Rc::new(RepublishingHandler {
+            id: Ustr::from("RepublishingHandler"),
+            topic: republished_topic,
+            msgbus: msgbus.clone(),
+        }));
+        msgbus_mut.subscribe(topic, republisher, None);
+        msgbus_mut.subscribe(republished_topic, handler.clone(), None);
+    }
+
+    data_engine.borrow_mut().process(&index as &dyn Any);
+
+    assert_eq!(get_saved_messages::<IndexPriceUpdate>(handler), vec![index]);
+}
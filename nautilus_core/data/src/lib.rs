@@ -31,3 +31,4 @@ pub mod aggregation;
 pub mod client;
 pub mod engine;
 pub mod mocks;
+pub mod polling;
@@ -164,6 +164,30 @@ impl DataClient for MockDataClient {
         Ok(())
     }
 
+    fn subscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn subscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn subscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn unsubscribe(
         &mut self,
         data_type: &DataType,
@@ -244,10 +268,34 @@ impl DataClient for MockDataClient {
         Ok(())
     }
 
+    fn unsubscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     // -- DATA REQUEST HANDLERS ---------------------------------------------------------------------------
 
     fn request_data(&self, request: DataRequest) {
-        todo!()
+        // Requests are left pending, the mock never responds
     }
 
     fn request_instruments(
@@ -257,7 +305,7 @@ impl DataClient for MockDataClient {
         start: Option<UnixNanos>,
         end: Option<UnixNanos>,
         params: &Option<HashMap<String, String>>,
-    ) -> Vec<InstrumentAny> {
+    ) -> anyhow::Result<Vec<InstrumentAny>> {
         todo!()
     }
 
@@ -268,7 +316,7 @@ impl DataClient for MockDataClient {
         start: Option<UnixNanos>,
         end: Option<UnixNanos>,
         params: &Option<HashMap<String, String>>,
-    ) -> InstrumentAny {
+    ) -> anyhow::Result<InstrumentAny> {
         todo!()
     }
 
@@ -279,7 +327,7 @@ impl DataClient for MockDataClient {
         instrument_id: InstrumentId,
         depth: Option<usize>,
         params: &Option<HashMap<String, String>>,
-    ) -> Payload {
+    ) -> anyhow::Result<Payload> {
         todo!()
     }
 
@@ -291,7 +339,7 @@ impl DataClient for MockDataClient {
         end: Option<UnixNanos>,
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
-    ) -> Vec<QuoteTick> {
+    ) -> anyhow::Result<Vec<QuoteTick>> {
         todo!()
     }
 
@@ -303,7 +351,7 @@ impl DataClient for MockDataClient {
         end: Option<UnixNanos>,
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
-    ) -> Vec<TradeTick> {
+    ) -> anyhow::Result<Vec<TradeTick>> {
         todo!()
     }
 
@@ -315,7 +363,7 @@ impl DataClient for MockDataClient {
         end: Option<UnixNanos>,
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
-    ) -> Vec<Bar> {
+    ) -> anyhow::Result<Vec<Bar>> {
         todo!()
     }
 }
@@ -0,0 +1,590 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A reusable REST polling client for venues which only expose market data over HTTP.
+//!
+//! Each [`PollingEndpoint`] is requested on its own interval (with random jitter so many
+//! clients do not poll in lockstep). Conditional requests are made with the `ETag` and
+//! `Last-Modified` validators returned by the server, so unchanged resources cost a
+//! `304 Not Modified` rather than a full response. Responses are converted to Nautilus data
+//! by a venue specific [`PollingParser`], and data already seen in a previous response is
+//! dropped by a [`PollingDeduplicator`] before being published as [`DataEvent`]s.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    hash::{Hash, Hasher},
+    time::Duration,
+};
+
+use nautilus_common::messages::data::DataEvent;
+use nautilus_core::{nanos::UnixNanos, time::get_atomic_clock_realtime};
+use nautilus_model::data::Data;
+use nautilus_network::{
+    http::{HttpClient, HttpResponse},
+    ratelimiter::quota::Quota,
+};
+use rand::Rng;
+use reqwest::{Method, StatusCode};
+use tokio::{
+    sync::mpsc::UnboundedSender,
+    task::JoinHandle,
+    time::{sleep_until, Instant},
+};
+
+const ETAG_HEADER: &str = "etag";
+const LAST_MODIFIED_HEADER: &str = "last-modified";
+const IF_NONE_MATCH_HEADER: &str = "if-none-match";
+const IF_MODIFIED_SINCE_HEADER: &str = "if-modified-since";
+
+/// Configuration for a polled REST endpoint.
+#[derive(Clone, Debug)]
+pub struct PollingEndpoint {
+    /// The name identifying the endpoint to the parser and in logs.
+    pub name: String,
+    /// The full URL requested, including any query string.
+    pub url: String,
+    /// The interval between requests.
+    pub interval: Duration,
+    /// The maximum random jitter added to each interval.
+    pub jitter: Duration,
+    /// Additional headers sent with each request.
+    pub headers: HashMap<String, String>,
+    /// The keys used for rate limiting requests to the endpoint.
+    pub rate_limit_keys: Option<Vec<String>>,
+    /// The timeout for each request in seconds.
+    pub timeout_secs: Option<u64>,
+}
+
+impl PollingEndpoint {
+    /// Creates a new [`PollingEndpoint`] instance with no jitter, headers or rate limit keys.
+    #[must_use]
+    pub fn new(name: &str, url: &str, interval: Duration) -> Self {
+        Self {
+            name: name.to_string(),
+            url: url.to_string(),
+            interval,
+            jitter: Duration::ZERO,
+            headers: HashMap::new(),
+            rate_limit_keys: None,
+            timeout_secs: None,
+        }
+    }
+
+    /// Returns the delay until the next request, the interval plus a random jitter.
+    #[must_use]
+    pub fn next_delay(&self) -> Duration {
+        if self.jitter.is_zero() {
+            return self.interval;
+        }
+        let jitter_ms = rand::thread_rng().gen_range(0..=self.jitter.as_millis() as u64);
+        self.interval + Duration::from_millis(jitter_ms)
+    }
+}
+
+/// Converts the responses of polled endpoints into Nautilus data.
+pub trait PollingParser: Send + 'static {
+    /// Parses the response `body` from the given `endpoint` into data initialized at `ts_init`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the body cannot be parsed.
+    fn parse(
+        &mut self,
+        endpoint: &PollingEndpoint,
+        body: &[u8],
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Vec<Data>>;
+}
+
+impl<F> PollingParser for F
+where
+    F: FnMut(&PollingEndpoint, &[u8], UnixNanos) -> anyhow::Result<Vec<Data>> + Send + 'static,
+{
+    fn parse(
+        &mut self,
+        endpoint: &PollingEndpoint,
+        body: &[u8],
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Vec<Data>> {
+        self(endpoint, body, ts_init)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Watermark {
+    ts_event: UnixNanos,
+    keys: HashSet<u64>,
+}
+
+/// Drops polled data which was already published from a previous response.
+///
+/// A watermark of the latest event timestamp is kept per instrument (or bar type). Data
+/// older than the watermark is dropped, and data at the watermark is dropped if its
+/// sequence (or trade ID for trades) was already seen at that timestamp.
+#[derive(Debug, Default)]
+pub struct PollingDeduplicator {
+    watermarks: HashMap<String, Watermark>,
+}
+
+impl PollingDeduplicator {
+    /// Creates a new [`PollingDeduplicator`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the new items of `data` in ascending order of event timestamp.
+    pub fn filter(&mut self, mut data: Vec<Data>) -> Vec<Data> {
+        // Venues commonly return the most recent items first
+        data.sort_by_key(ts_event);
+
+        data.into_iter()
+            .filter(|item| {
+                let watermark = self.watermarks.entry(stream_key(item)).or_default();
+                let item_ts = ts_event(item);
+                let key = dedup_key(item);
+
+                if item_ts < watermark.ts_event {
+                    false
+                } else if item_ts == watermark.ts_event {
+                    watermark.keys.insert(key)
+                } else {
+                    watermark.ts_event = item_ts;
+                    watermark.keys.clear();
+                    watermark.keys.insert(key);
+                    true
+                }
+            })
+            .collect()
+    }
+
+    /// Clears all watermarks, so the next response is published in full.
+    pub fn reset(&mut self) {
+        self.watermarks.clear();
+    }
+}
+
+fn ts_event(data: &Data) -> UnixNanos {
+    match data {
+        Data::Delta(d) => d.ts_event,
+        Data::Deltas(d) => d.ts_event,
+        Data::Depth10(d) => d.ts_event,
+        Data::Quote(q) => q.ts_event,
+        Data::Trade(t) => t.ts_event,
+        Data::Bar(b) => b.ts_event,
+    }
+}
+
+fn stream_key(data: &Data) -> String {
+    match data {
+        Data::Bar(bar) => bar.bar_type.to_string(),
+        _ => data.instrument_id().to_string(),
+    }
+}
+
+fn dedup_key(data: &Data) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    match data {
+        Data::Delta(d) => {
+            d.sequence.hash(&mut hasher);
+            d.action.hash(&mut hasher);
+            d.order.side.hash(&mut hasher);
+            d.order.price.raw.hash(&mut hasher);
+            d.order.size.raw.hash(&mut hasher);
+        }
+        Data::Deltas(d) => d.sequence.hash(&mut hasher),
+        Data::Depth10(d) => d.sequence.hash(&mut hasher),
+        Data::Trade(t) => t.trade_id.hash(&mut hasher),
+        // At most one quote or bar per timestamp
+        Data::Quote(_) | Data::Bar(_) => {}
+    }
+    hasher.finish()
+}
+
+/// The state of a single polled endpoint.
+#[derive(Debug)]
+struct EndpointState {
+    endpoint: PollingEndpoint,
+    next_poll: Instant,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl EndpointState {
+    fn new(endpoint: PollingEndpoint) -> Self {
+        Self {
+            endpoint,
+            next_poll: Instant::now(),
+            etag: None,
+            last_modified: None,
+        }
+    }
+
+    /// Returns the request headers, including the conditional request validators.
+    fn request_headers(&self) -> HashMap<String, String> {
+        let mut headers = self.endpoint.headers.clone();
+        if let Some(etag) = &self.etag {
+            headers.insert(IF_NONE_MATCH_HEADER.to_string(), etag.clone());
+        }
+        if let Some(last_modified) = &self.last_modified {
+            headers.insert(IF_MODIFIED_SINCE_HEADER.to_string(), last_modified.clone());
+        }
+        headers
+    }
+
+    /// Retains the validators of the `response` for the next conditional request.
+    fn update_validators(&mut self, response: &HttpResponse) {
+        if let Some(etag) = find_header(response, ETAG_HEADER) {
+            self.etag = Some(etag.to_string());
+        }
+        if let Some(last_modified) = find_header(response, LAST_MODIFIED_HEADER) {
+            self.last_modified = Some(last_modified.to_string());
+        }
+    }
+}
+
+fn find_header<'a>(response: &'a HttpResponse, name: &str) -> Option<&'a str> {
+    response
+        .headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Polls REST endpoints and publishes the parsed data as [`DataEvent`]s.
+///
+/// All endpoints are polled from a single task which owns the parser, so parsers need no
+/// synchronization. Failed requests are logged and retried on the next interval.
+#[derive(Debug)]
+pub struct RestPoller {
+    task: JoinHandle<()>,
+}
+
+impl RestPoller {
+    /// Creates an [`HttpClient`] which retains the validator headers needed for conditional
+    /// requests, with the given default `headers` and optional global `quota`.
+    #[must_use]
+    pub fn http_client(headers: HashMap<String, String>, quota: Option<Quota>) -> HttpClient {
+        HttpClient::new(
+            headers,
+            vec![ETAG_HEADER.to_string(), LAST_MODIFIED_HEADER.to_string()],
+            vec![],
+            None,
+            quota,
+            false,
+            3,
+        )
+    }
+
+    /// Starts polling the `endpoints` with the `client`, publishing data parsed by the
+    /// `parser` to `sender`.
+    ///
+    /// The `client` should retain the `ETag` and `Last-Modified` response headers (see
+    /// [`RestPoller::http_client`]), otherwise every request is unconditional.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if not called within a Tokio runtime.
+    #[must_use]
+    pub fn start<P: PollingParser>(
+        client: HttpClient,
+        endpoints: Vec<PollingEndpoint>,
+        parser: P,
+        sender: UnboundedSender<DataEvent>,
+    ) -> Self {
+        let task = tokio::spawn(run_poller(client, endpoints, parser, sender));
+        Self { task }
+    }
+
+    /// Returns whether the poller is still running.
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        !self.task.is_finished()
+    }
+
+    /// Stops polling.
+    pub fn stop(&self) {
+        self.task.abort();
+    }
+}
+
+impl Drop for RestPoller {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn run_poller<P: PollingParser>(
+    client: HttpClient,
+    endpoints: Vec<PollingEndpoint>,
+    mut parser: P,
+    sender: UnboundedSender<DataEvent>,
+) {
+    let mut states: Vec<EndpointState> = endpoints.into_iter().map(EndpointState::new).collect();
+    let mut deduplicator = PollingDeduplicator::new();
+
+    loop {
+        let Some(state) = states.iter_mut().min_by_key(|state| state.next_poll) else {
+            log::warn!("No endpoints to poll");
+            return;
+        };
+
+        sleep_until(state.next_poll).await;
+        state.next_poll = Instant::now() + state.endpoint.next_delay();
+
+        match poll_endpoint(&client, state, &mut parser).await {
+            Ok(data) => {
+                for item in deduplicator.filter(data) {
+                    if sender.send(DataEvent::Data(item)).is_err() {
+                        log::debug!("Data receiver dropped, stopping poller");
+                        return;
+                    }
+                }
+            }
+            Err(e) => log::error!("Error polling '{}': {e}", state.endpoint.name),
+        }
+    }
+}
+
+/// Requests the endpoint of the given `state` once, returning the parsed data.
+///
+/// Returns no data if the server responds that the resource is not modified.
+async fn poll_endpoint<P: PollingParser>(
+    client: &HttpClient,
+    state: &mut EndpointState,
+    parser: &mut P,
+) -> anyhow::Result<Vec<Data>> {
+    let response = client
+        .request(
+            Method::GET,
+            state.endpoint.url.clone(),
+            Some(state.request_headers()),
+            None,
+            state.endpoint.rate_limit_keys.clone(),
+            state.endpoint.timeout_secs,
+            None,
+        )
+        .await?;
+
+    if response.status == StatusCode::NOT_MODIFIED.as_u16() {
+        return Ok(Vec::new());
+    }
+    if !(200..300).contains(&response.status) {
+        anyhow::bail!(
+            "HTTP {}: {}",
+            response.status,
+            String::from_utf8_lossy(&response.body)
+        );
+    }
+
+    state.update_validators(&response);
+    let ts_init = get_atomic_clock_realtime().get_time_ns();
+    parser.parse(&state.endpoint, &response.body, ts_init)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::SocketAddr,
+        sync::{
+            atomic::{AtomicBool, AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    use axum::{
+        http::{HeaderMap, StatusCode as AxumStatusCode},
+        response::IntoResponse,
+        routing::get,
+        serve, Router,
+    };
+    use nautilus_model::{
+        data::{QuoteTick, TradeTick},
+        enums::AggressorSide,
+        identifiers::{InstrumentId, TradeId},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+    use tokio::{sync::mpsc::unbounded_channel, time::timeout};
+
+    use super::*;
+
+    fn trade(id: &str, ts_event: u64) -> Data {
+        Data::Trade(TradeTick::new(
+            InstrumentId::from("BTCUSD.REST"),
+            Price::from("100.0"),
+            Quantity::from(1),
+            AggressorSide::Buyer,
+            TradeId::new(id),
+            ts_event.into(),
+            ts_event.into(),
+        ))
+    }
+
+    fn quote(ts_event: u64) -> Data {
+        Data::Quote(QuoteTick::new(
+            InstrumentId::from("BTCUSD.REST"),
+            Price::from("99.0"),
+            Price::from("101.0"),
+            Quantity::from(1),
+            Quantity::from(1),
+            ts_event.into(),
+            ts_event.into(),
+        ))
+    }
+
+    fn trade_ids(data: &[Data]) -> Vec<String> {
+        data.iter()
+            .map(|item| match item {
+                Data::Trade(trade) => trade.trade_id.to_string(),
+                other => panic!("Unexpected data {other:?}"),
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_next_delay_with_jitter() {
+        let mut endpoint =
+            PollingEndpoint::new("trades", "http://localhost", Duration::from_millis(100));
+        assert_eq!(endpoint.next_delay(), Duration::from_millis(100));
+
+        endpoint.jitter = Duration::from_millis(50);
+        for _ in 0..100 {
+            let delay = endpoint.next_delay();
+            assert!(delay >= Duration::from_millis(100));
+            assert!(delay <= Duration::from_millis(150));
+        }
+    }
+
+    #[rstest]
+    fn test_deduplicator_sorts_and_drops_seen_trades() {
+        let mut deduplicator = PollingDeduplicator::new();
+
+        let first = deduplicator.filter(vec![trade("2", 2), trade("1", 1)]);
+        let second = deduplicator.filter(vec![trade("3", 2), trade("2", 2), trade("1", 1)]);
+        let third = deduplicator.filter(vec![trade("4", 3), trade("3", 2)]);
+
+        assert_eq!(trade_ids(&first), vec!["1", "2"]);
+        assert_eq!(trade_ids(&second), vec!["3"]);
+        assert_eq!(trade_ids(&third), vec!["4"]);
+    }
+
+    #[rstest]
+    fn test_deduplicator_drops_quote_at_same_timestamp() {
+        let mut deduplicator = PollingDeduplicator::new();
+
+        assert_eq!(deduplicator.filter(vec![quote(1)]).len(), 1);
+        assert_eq!(deduplicator.filter(vec![quote(1)]).len(), 0);
+        assert_eq!(deduplicator.filter(vec![quote(2)]).len(), 1);
+
+        deduplicator.reset();
+        assert_eq!(deduplicator.filter(vec![quote(2)]).len(), 1);
+    }
+
+    async fn start_test_server(
+        calls: Arc<AtomicUsize>,
+        conditional: Arc<AtomicBool>,
+    ) -> SocketAddr {
+        let router = Router::new().route(
+            "/trades",
+            get(move |headers: HeaderMap| {
+                let calls = calls.clone();
+                let conditional = conditional.clone();
+                async move {
+                    match calls.fetch_add(1, Ordering::SeqCst) {
+                        0 => ([(ETAG_HEADER, "v1")], "1:1,2:2").into_response(),
+                        1 => {
+                            let etag = headers.get(IF_NONE_MATCH_HEADER);
+                            conditional
+                                .store(etag.is_some_and(|etag| etag == "v1"), Ordering::SeqCst);
+                            AxumStatusCode::NOT_MODIFIED.into_response()
+                        }
+                        _ => ([(ETAG_HEADER, "v2")], "2:2,3:3").into_response(),
+                    }
+                }
+            }),
+        );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            serve(listener, router).await.unwrap();
+        });
+        addr
+    }
+
+    /// Parses a body of comma separated `id:ts_event` trades.
+    fn parse_trades(
+        _endpoint: &PollingEndpoint,
+        body: &[u8],
+        _ts_init: UnixNanos,
+    ) -> anyhow::Result<Vec<Data>> {
+        std::str::from_utf8(body)?
+            .split(',')
+            .map(|item| {
+                let (id, ts) = item
+                    .split_once(':')
+                    .ok_or_else(|| anyhow::anyhow!("Invalid trade {item}"))?;
+                Ok(trade(id, ts.parse()?))
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_poller_publishes_new_data() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let conditional = Arc::new(AtomicBool::new(false));
+        let addr = start_test_server(calls.clone(), conditional.clone()).await;
+
+        let endpoint = PollingEndpoint::new(
+            "trades",
+            &format!("http://{addr}/trades"),
+            Duration::from_millis(10),
+        );
+        let (tx, mut rx) = unbounded_channel();
+        let poller = RestPoller::start(
+            RestPoller::http_client(HashMap::new(), None),
+            vec![endpoint],
+            parse_trades,
+            tx,
+        );
+
+        let mut received = Vec::new();
+        while received.len() < 3 {
+            let event = timeout(Duration::from_secs(5), rx.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            match event {
+                DataEvent::Data(data) => received.push(data),
+                DataEvent::Response(_) => panic!("Unexpected response"),
+            }
+        }
+
+        // Trade 2 is in both full responses but only published once
+        assert!(timeout(Duration::from_millis(100), rx.recv())
+            .await
+            .is_err());
+        assert_eq!(trade_ids(&received), vec!["1", "2", "3"]);
+        assert!(conditional.load(Ordering::SeqCst));
+        assert!(calls.load(Ordering::SeqCst) >= 3);
+        assert!(poller.is_running());
+
+        poller.stop();
+    }
+}
@@ -0,0 +1,251 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Iceberg execution algorithm.
+
+use std::collections::HashMap;
+
+use nautilus_model::{
+    events::OrderEventAny, identifiers::ClientOrderId, orders::OrderAny, types::Quantity,
+};
+
+use super::{get_param, ExecAlgorithm, ExecAlgorithmCore};
+
+/// Executes a limit primary order by only ever showing a display quantity to the market.
+///
+/// Parameters are read from the primary order `exec_algorithm_params`:
+/// - `display_qty`: the quantity of each displayed child order.
+///
+/// A child limit order for the display quantity is spawned at the primary price, and the next
+/// child is reposted each time the displayed child is completely filled. The primary order itself
+/// is submitted once its remaining quantity no longer exceeds the display quantity. Execution
+/// stops if a displayed child is canceled, expired or rejected.
+pub struct IcebergExecAlgorithm {
+    core: ExecAlgorithmCore,
+    display_qtys: HashMap<ClientOrderId, Quantity>,
+    // Displayed child order -> primary order
+    displayed: HashMap<ClientOrderId, ClientOrderId>,
+}
+
+impl IcebergExecAlgorithm {
+    /// Creates a new [`IcebergExecAlgorithm`] instance.
+    #[must_use]
+    pub fn new(core: ExecAlgorithmCore) -> Self {
+        Self {
+            core,
+            display_qtys: HashMap::new(),
+            displayed: HashMap::new(),
+        }
+    }
+
+    /// Returns the currently displayed child order ID for the given `primary_id` (if executing).
+    #[must_use]
+    pub fn displayed_order_id(&self, primary_id: &ClientOrderId) -> Option<ClientOrderId> {
+        self.displayed
+            .iter()
+            .find(|(_, id)| *id == primary_id)
+            .map(|(child_id, _)| *child_id)
+    }
+
+    fn display(&mut self, mut primary: OrderAny, display_qty: Quantity) -> anyhow::Result<()> {
+        let primary_id = primary.client_order_id();
+        if primary.quantity() <= display_qty {
+            self.display_qtys.remove(&primary_id);
+            return self.core.submit_order(primary);
+        }
+
+        let spawned = self.core.spawn_slice(&mut primary, display_qty)?;
+        self.displayed.insert(spawned.client_order_id(), primary_id);
+        self.core.submit_order(spawned)
+    }
+
+    fn repost(&mut self, primary_id: ClientOrderId) -> anyhow::Result<()> {
+        let display_qty = *self
+            .display_qtys
+            .get(&primary_id)
+            .ok_or_else(|| anyhow::anyhow!("No display quantity for {primary_id}"))?;
+        let primary = self
+            .core
+            .order(&primary_id)
+            .ok_or_else(|| anyhow::anyhow!("Primary order {primary_id} not found in cache"))?;
+        if primary.is_closed() {
+            log::warn!("Primary order {primary_id} closed, stopping execution");
+            self.display_qtys.remove(&primary_id);
+            return Ok(());
+        }
+        self.display(primary, display_qty)
+    }
+}
+
+impl ExecAlgorithm for IcebergExecAlgorithm {
+    fn core(&self) -> &ExecAlgorithmCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut ExecAlgorithmCore {
+        &mut self.core
+    }
+
+    fn on_order(&mut self, order: OrderAny) -> anyhow::Result<()> {
+        let primary_id = order.client_order_id();
+        anyhow::ensure!(
+            order.price().is_some(),
+            "Iceberg primary order {primary_id} must have a limit price"
+        );
+        let display_qty: f64 = get_param(&order, "display_qty")?
+            .ok_or_else(|| anyhow::anyhow!("Missing `display_qty` parameter for {primary_id}"))?;
+        let instrument = self.core.instrument(&order.instrument_id())?;
+        let display_qty = instrument.make_qty(display_qty);
+        anyhow::ensure!(
+            display_qty.is_positive(),
+            "Invalid display quantity {display_qty} for {primary_id}"
+        );
+
+        self.display_qtys.insert(primary_id, display_qty);
+        self.display(order, display_qty)
+    }
+
+    fn on_order_event(&mut self, event: &OrderEventAny) {
+        let client_order_id = event.client_order_id();
+        let Some(primary_id) = self.displayed.get(&client_order_id).copied() else {
+            return;
+        };
+
+        match event {
+            OrderEventAny::Filled(_) => {
+                self.displayed.remove(&client_order_id);
+                if let Err(e) = self.repost(primary_id) {
+                    log::error!("Error reposting for {primary_id}: {e}");
+                    self.display_qtys.remove(&primary_id);
+                }
+            }
+            OrderEventAny::Canceled(_) | OrderEventAny::Expired(_) | OrderEventAny::Rejected(_) => {
+                log::warn!("Displayed order {client_order_id} closed, stopping {primary_id}");
+                self.displayed.remove(&client_order_id);
+                self.display_qtys.remove(&primary_id);
+            }
+            _ => {}
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        events::OrderCanceled,
+        identifiers::{AccountId, InstrumentId},
+        orders::stubs::TestOrderEventStubs,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::algorithm::stubs::{primary_order, submit_order, TestContext};
+
+    const PARAMS: &[(&str, &str)] = &[("display_qty", "4")];
+
+    fn fill(context: &TestContext, order: &OrderAny) -> OrderEventAny {
+        let instrument = context
+            .cache
+            .borrow()
+            .instrument(&InstrumentId::from("AUD/USD.SIM"))
+            .cloned()
+            .unwrap();
+        TestOrderEventStubs::order_filled(
+            order,
+            &instrument,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[rstest]
+    fn test_iceberg_reposts_display_quantity_until_complete() {
+        let context = TestContext::new();
+        let mut algorithm = IcebergExecAlgorithm::new(context.core("ICEBERG"));
+        let primary = primary_order("ICEBERG", 10, Some("0.80000"), PARAMS);
+        let primary_id = primary.client_order_id();
+
+        algorithm.execute(submit_order(&primary)).unwrap();
+
+        let submitted = context.submitted_orders();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].quantity(), Quantity::from(4));
+        assert_eq!(submitted[0].price(), primary.price());
+        assert_eq!(
+            algorithm.displayed_order_id(&primary_id),
+            Some(submitted[0].client_order_id())
+        );
+
+        algorithm.on_order_event(&fill(&context, &submitted[0]));
+        let submitted = context.submitted_orders();
+        algorithm.on_order_event(&fill(&context, &submitted[1]));
+
+        let submitted = context.submitted_orders();
+        let quantities: Vec<Quantity> = submitted.iter().map(OrderAny::quantity).collect();
+        assert_eq!(
+            quantities,
+            vec![Quantity::from(4), Quantity::from(4), Quantity::from(2)]
+        );
+        assert_eq!(submitted[2].client_order_id(), primary_id);
+        assert_eq!(algorithm.displayed_order_id(&primary_id), None);
+    }
+
+    #[rstest]
+    fn test_iceberg_stops_when_displayed_order_canceled() {
+        let context = TestContext::new();
+        let mut algorithm = IcebergExecAlgorithm::new(context.core("ICEBERG"));
+        let primary = primary_order("ICEBERG", 10, Some("0.80000"), PARAMS);
+        let primary_id = primary.client_order_id();
+
+        algorithm.execute(submit_order(&primary)).unwrap();
+        let displayed = context.submitted_orders().remove(0);
+        let canceled = OrderCanceled::new(
+            displayed.trader_id(),
+            displayed.strategy_id(),
+            displayed.instrument_id(),
+            displayed.client_order_id(),
+            UUID4::new(),
+            0.into(),
+            0.into(),
+            false,
+            None,
+            Some(AccountId::from("SIM-001")),
+        );
+        algorithm.on_order_event(&OrderEventAny::Canceled(canceled));
+
+        assert_eq!(algorithm.displayed_order_id(&primary_id), None);
+        assert_eq!(context.submitted_orders().len(), 1);
+    }
+
+    #[rstest]
+    fn test_iceberg_requires_limit_primary() {
+        let context = TestContext::new();
+        let mut algorithm = IcebergExecAlgorithm::new(context.core("ICEBERG"));
+        let primary = primary_order("ICEBERG", 10, None, PARAMS);
+
+        assert!(algorithm.execute(submit_order(&primary)).is_err());
+        assert!(context.submitted_orders().is_empty());
+    }
+}
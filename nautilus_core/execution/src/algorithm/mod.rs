@@ -0,0 +1,668 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Execution algorithms which work a primary order by spawning child orders.
+//!
+//! A strategy submits a primary order carrying an `exec_algorithm_id` to the
+//! `{exec_algorithm_id}.execute` endpoint. The algorithm then spawns child orders with the
+//! primary client order ID as their `exec_spawn_id` (indexed by the cache), reducing the primary
+//! order quantity by each spawned quantity, and finally submits the primary order itself for any
+//! remaining quantity. All orders are submitted through the risk engine.
+
+pub mod iceberg;
+pub mod twap;
+pub mod vwap;
+
+#[cfg(test)]
+mod stubs;
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+    rc::Rc,
+    str::FromStr,
+};
+
+use nautilus_common::{
+    cache::Cache,
+    clock::Clock,
+    messages::{
+        data::DataResponse,
+        execution::{CancelOrder, SubmitOrder, TradingCommand},
+    },
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+    timer::{TimeEvent, TimeEventCallback},
+};
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    data::{Data, TradeTick},
+    enums::TimeInForce,
+    events::{OrderEventAny, OrderUpdated},
+    identifiers::{ClientId, ClientOrderId, ExecAlgorithmId, InstrumentId, PositionId},
+    instruments::InstrumentAny,
+    orders::{LimitOrder, MarketOrder, OrderAny},
+    types::{Price, Quantity},
+};
+use ustr::Ustr;
+
+/// Provides the interface for an execution algorithm.
+///
+/// Implementations hold an [`ExecAlgorithmCore`] for spawning and submitting orders, and receive
+/// messages through an [`ExecAlgorithmHandler`] registered with [`register_exec_algorithm`].
+pub trait ExecAlgorithm {
+    /// Returns the core used for spawning and submitting orders.
+    fn core(&self) -> &ExecAlgorithmCore;
+
+    /// Returns a mutable reference to the core used for spawning and submitting orders.
+    fn core_mut(&mut self) -> &mut ExecAlgorithmCore;
+
+    /// Handles a primary `order` received by the algorithm.
+    fn on_order(&mut self, order: OrderAny) -> anyhow::Result<()>;
+
+    /// Handles an order `event`, which may be for an order of another algorithm.
+    fn on_order_event(&mut self, _event: &OrderEventAny) {}
+
+    /// Handles a `trade` tick, which may be for an instrument the algorithm is not executing.
+    fn on_trade(&mut self, _trade: &TradeTick) {}
+
+    /// Handles a time `event` from a timer set by the algorithm.
+    fn on_time_event(&mut self, _event: &TimeEvent) {}
+
+    /// Returns the algorithm ID.
+    fn id(&self) -> ExecAlgorithmId {
+        self.core().id
+    }
+
+    /// Executes the given `command` for a primary order.
+    fn execute(&mut self, command: SubmitOrder) -> anyhow::Result<()> {
+        let order = self.core_mut().handle_submit_order(command)?;
+        self.on_order(order)
+    }
+}
+
+/// Common state for an execution algorithm, providing the spawning of child orders and the
+/// submission of orders through the risk engine.
+pub struct ExecAlgorithmCore {
+    pub id: ExecAlgorithmId,
+    pub clock: Rc<RefCell<dyn Clock>>,
+    pub cache: Rc<RefCell<Cache>>,
+    pub msgbus: Rc<RefCell<MessageBus>>,
+    client_ids: HashMap<ClientOrderId, ClientId>,
+    position_ids: HashMap<ClientOrderId, PositionId>,
+    spawn_sequence: HashMap<ClientOrderId, u32>,
+}
+
+impl ExecAlgorithmCore {
+    /// Creates a new [`ExecAlgorithmCore`] instance.
+    pub fn new(
+        id: ExecAlgorithmId,
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Self {
+        Self {
+            id,
+            clock,
+            cache,
+            msgbus,
+            client_ids: HashMap::new(),
+            position_ids: HashMap::new(),
+            spawn_sequence: HashMap::new(),
+        }
+    }
+
+    /// Returns the endpoint on which the algorithm receives primary orders.
+    #[must_use]
+    pub fn execute_endpoint(&self) -> Ustr {
+        Ustr::from(&format!("{}.execute", self.id))
+    }
+
+    /// Returns the endpoint on which the algorithm receives its timer events.
+    #[must_use]
+    pub fn time_event_endpoint(&self) -> Ustr {
+        Ustr::from(&format!("{}.on_time_event", self.id))
+    }
+
+    /// Returns the instrument for the given `instrument_id` from the cache.
+    pub fn instrument(&self, instrument_id: &InstrumentId) -> anyhow::Result<InstrumentAny> {
+        self.cache
+            .borrow()
+            .instrument(instrument_id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Instrument {instrument_id} not found in cache"))
+    }
+
+    /// Returns the cached order for the given `client_order_id` (if found).
+    #[must_use]
+    pub fn order(&self, client_order_id: &ClientOrderId) -> Option<OrderAny> {
+        self.cache.borrow().order(client_order_id).cloned()
+    }
+
+    /// Returns the primary order of the given spawned `order` (if found), only orders spawned
+    /// by this algorithm are considered.
+    #[must_use]
+    pub fn primary_order(&self, order: &OrderAny) -> Option<OrderAny> {
+        if order.exec_algorithm_id() != Some(self.id) {
+            return None;
+        }
+        order
+            .exec_spawn_id()
+            .and_then(|exec_spawn_id| self.order(&exec_spawn_id))
+    }
+
+    /// Sets a timer with the given `name`, delivering its events to the algorithm.
+    pub fn set_timer(
+        &self,
+        name: &str,
+        interval_ns: u64,
+        stop_time_ns: Option<UnixNanos>,
+    ) -> anyhow::Result<()> {
+        let msgbus = self.msgbus.clone();
+        let endpoint = self.time_event_endpoint();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            msgbus.borrow().send(&endpoint, &event as &dyn Any);
+        }));
+
+        let mut clock = self.clock.borrow_mut();
+        let start_time_ns = clock.timestamp_ns();
+        clock.set_timer_ns(
+            name,
+            interval_ns,
+            start_time_ns,
+            stop_time_ns,
+            Some(callback),
+        )
+    }
+
+    /// Cancels the timer with the given `name`.
+    pub fn cancel_timer(&self, name: &str) {
+        self.clock.borrow_mut().cancel_timer(name);
+    }
+
+    /// Spawns a market order from the `primary` order for the given `quantity`.
+    ///
+    /// If `reduce_primary` then the primary order quantity is reduced by the spawned quantity.
+    pub fn spawn_market(
+        &mut self,
+        primary: &mut OrderAny,
+        quantity: Quantity,
+        time_in_force: TimeInForce,
+        reduce_primary: bool,
+    ) -> anyhow::Result<OrderAny> {
+        self.check_spawn_quantity(primary, quantity, reduce_primary)?;
+
+        let order = MarketOrder::new(
+            primary.trader_id(),
+            primary.strategy_id(),
+            primary.instrument_id(),
+            self.next_spawn_id(primary),
+            primary.order_side(),
+            quantity,
+            time_in_force,
+            UUID4::new(),
+            self.clock.borrow().timestamp_ns(),
+            primary.is_reduce_only(),
+            primary.is_quote_quantity(),
+            None,
+            None,
+            None,
+            None,
+            Some(self.id),
+            primary.exec_algorithm_params(),
+            Some(primary.client_order_id()),
+            None,
+        );
+
+        if reduce_primary {
+            self.reduce_primary_order(primary, quantity)?;
+        }
+        Ok(OrderAny::Market(order))
+    }
+
+    /// Spawns a limit order from the `primary` order for the given `quantity` and `price`.
+    ///
+    /// If `reduce_primary` then the primary order quantity is reduced by the spawned quantity.
+    pub fn spawn_limit(
+        &mut self,
+        primary: &mut OrderAny,
+        quantity: Quantity,
+        price: Price,
+        time_in_force: TimeInForce,
+        reduce_primary: bool,
+    ) -> anyhow::Result<OrderAny> {
+        self.check_spawn_quantity(primary, quantity, reduce_primary)?;
+
+        let order = LimitOrder::new(
+            primary.trader_id(),
+            primary.strategy_id(),
+            primary.instrument_id(),
+            self.next_spawn_id(primary),
+            primary.order_side(),
+            quantity,
+            price,
+            time_in_force,
+            primary.expire_time(),
+            primary.is_post_only(),
+            primary.is_reduce_only(),
+            primary.is_quote_quantity(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(self.id),
+            primary.exec_algorithm_params(),
+            Some(primary.client_order_id()),
+            None,
+            UUID4::new(),
+            self.clock.borrow().timestamp_ns(),
+        )?;
+
+        if reduce_primary {
+            self.reduce_primary_order(primary, quantity)?;
+        }
+        Ok(OrderAny::Limit(order))
+    }
+
+    /// Spawns a child of the same type as the `primary` order (market or limit) for the given
+    /// `quantity`, reducing the primary order quantity.
+    pub fn spawn_slice(
+        &mut self,
+        primary: &mut OrderAny,
+        quantity: Quantity,
+    ) -> anyhow::Result<OrderAny> {
+        let time_in_force = primary.time_in_force();
+        match primary.price() {
+            Some(price) => self.spawn_limit(primary, quantity, price, time_in_force, true),
+            None => self.spawn_market(primary, quantity, time_in_force, true),
+        }
+    }
+
+    /// Submits the given `order` to the risk engine, adding it to the cache if new.
+    ///
+    /// Spawned orders are submitted with the client and position of their primary order.
+    pub fn submit_order(&self, order: OrderAny) -> anyhow::Result<()> {
+        let client_order_id = order.client_order_id();
+        let primary_id = order.exec_spawn_id().unwrap_or(client_order_id);
+        let client_id = self.client_ids.get(&primary_id).copied().ok_or_else(|| {
+            anyhow::anyhow!("No client ID for {primary_id}, was the primary order executed?")
+        })?;
+        let position_id = self.position_ids.get(&primary_id).copied();
+
+        {
+            let mut cache = self.cache.borrow_mut();
+            if cache.order_exists(&client_order_id) {
+                cache.update_order(&order)?;
+            } else {
+                cache.add_order(order.clone(), position_id, Some(client_id), false)?;
+            }
+        }
+
+        log::info!("Submitting {client_order_id} for {primary_id}");
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            client_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            order.venue_order_id().unwrap_or_default(),
+            order.clone(),
+            Some(self.id),
+            position_id,
+            UUID4::new(),
+            self.clock.borrow().timestamp_ns(),
+        )?;
+        self.send_command(TradingCommand::SubmitOrder(command));
+        Ok(())
+    }
+
+    /// Cancels the given open `order` through the risk engine.
+    pub fn cancel_order(&self, order: &OrderAny) -> anyhow::Result<()> {
+        let client_order_id = order.client_order_id();
+        let primary_id = order.exec_spawn_id().unwrap_or(client_order_id);
+        let client_id = self.client_ids.get(&primary_id).copied().ok_or_else(|| {
+            anyhow::anyhow!("No client ID for {primary_id}, was the primary order executed?")
+        })?;
+
+        let command = CancelOrder::new(
+            order.trader_id(),
+            client_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            order.venue_order_id().unwrap_or_default(),
+            UUID4::new(),
+            self.clock.borrow().timestamp_ns(),
+        )?;
+        self.send_command(TradingCommand::CancelOrder(command));
+        Ok(())
+    }
+
+    /// Handles the `command` for a primary order, caching the order and returning it.
+    pub fn handle_submit_order(&mut self, command: SubmitOrder) -> anyhow::Result<OrderAny> {
+        let order = command.order;
+        let client_order_id = order.client_order_id();
+        anyhow::ensure!(
+            order.exec_algorithm_id() == Some(self.id),
+            "Order {client_order_id} is not for {}",
+            self.id
+        );
+
+        {
+            let mut cache = self.cache.borrow_mut();
+            if !cache.order_exists(&client_order_id) {
+                cache.add_order(
+                    order.clone(),
+                    command.position_id,
+                    Some(command.client_id),
+                    false,
+                )?;
+            }
+        }
+
+        self.client_ids.insert(client_order_id, command.client_id);
+        if let Some(position_id) = command.position_id {
+            self.position_ids.insert(client_order_id, position_id);
+        }
+
+        log::info!("Received primary order {client_order_id}");
+        Ok(order)
+    }
+
+    fn check_spawn_quantity(
+        &self,
+        primary: &OrderAny,
+        quantity: Quantity,
+        reduce_primary: bool,
+    ) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            quantity.is_positive(),
+            "Spawned quantity for {} must be positive",
+            primary.client_order_id()
+        );
+        if reduce_primary {
+            anyhow::ensure!(
+                quantity < primary.quantity(),
+                "Spawned quantity {quantity} must be less than the primary {} quantity {}",
+                primary.client_order_id(),
+                primary.quantity()
+            );
+        }
+        Ok(())
+    }
+
+    fn next_spawn_id(&mut self, primary: &OrderAny) -> ClientOrderId {
+        let sequence = self
+            .spawn_sequence
+            .entry(primary.client_order_id())
+            .or_insert(0);
+        *sequence += 1;
+        ClientOrderId::new(format!("{}-E{sequence}", primary.client_order_id()))
+    }
+
+    fn reduce_primary_order(
+        &self,
+        primary: &mut OrderAny,
+        spawn_qty: Quantity,
+    ) -> anyhow::Result<()> {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let updated = OrderUpdated::new(
+            primary.trader_id(),
+            primary.strategy_id(),
+            primary.instrument_id(),
+            primary.client_order_id(),
+            primary.quantity() - spawn_qty,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            primary.venue_order_id(),
+            primary.account_id(),
+            None,
+            None,
+        );
+        primary.apply(OrderEventAny::Updated(updated))?;
+        self.cache.borrow_mut().update_order(primary)
+    }
+
+    /// Sends the `command` to the risk engine, which checks spawned orders before passing
+    /// them on to the execution engine.
+    fn send_command(&self, command: TradingCommand) {
+        // Release the message bus before dispatching, as the risk engine sends on through it
+        let handler = {
+            let msgbus = self.msgbus.borrow();
+            msgbus
+                .get_endpoint(msgbus.switchboard.risk_engine_execute)
+                .cloned()
+        };
+        match handler {
+            Some(handler) => handler.0.handle(&command as &dyn Any),
+            None => log::error!("Cannot send command: risk engine endpoint not registered"),
+        }
+    }
+}
+
+/// Parses the execution algorithm parameter `key` of the given `order` (if found).
+pub fn get_param<T>(order: &OrderAny, key: &str) -> anyhow::Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let params = order.exec_algorithm_params().unwrap_or_default();
+    params
+        .get(&Ustr::from(key))
+        .map(|value| {
+            value.parse::<T>().map_err(|e| {
+                anyhow::anyhow!(
+                    "Invalid `{key}` parameter '{value}' for {}: {e}",
+                    order.client_order_id()
+                )
+            })
+        })
+        .transpose()
+}
+
+enum AlgorithmMessage {
+    Command(SubmitOrder),
+    Event(OrderEventAny),
+    Trade(TradeTick),
+    Time(TimeEvent),
+}
+
+/// Dispatches primary orders, order events, trades and timer events from the message bus to an
+/// execution algorithm.
+///
+/// Messages received while the algorithm is busy (e.g. events generated synchronously by its
+/// own submissions) are queued and dispatched once it returns.
+pub struct ExecAlgorithmHandler {
+    id: Ustr,
+    algorithm: Rc<RefCell<dyn ExecAlgorithm>>,
+    pending: RefCell<VecDeque<AlgorithmMessage>>,
+}
+
+impl ExecAlgorithmHandler {
+    /// Creates a new [`ExecAlgorithmHandler`] instance.
+    pub fn new(algorithm: Rc<RefCell<dyn ExecAlgorithm>>) -> Self {
+        let id = Ustr::from(algorithm.borrow().id().as_str());
+        Self {
+            id,
+            algorithm,
+            pending: RefCell::new(VecDeque::new()),
+        }
+    }
+
+    fn dispatch(&self, message: AlgorithmMessage) {
+        self.pending.borrow_mut().push_back(message);
+
+        let Ok(mut algorithm) = self.algorithm.try_borrow_mut() else {
+            return; // Dispatched by the outer call
+        };
+
+        loop {
+            let message = self.pending.borrow_mut().pop_front();
+            match message {
+                Some(AlgorithmMessage::Command(command)) => {
+                    let client_order_id = command.client_order_id;
+                    if let Err(e) = algorithm.execute(command) {
+                        log::error!("Error executing {client_order_id}: {e}");
+                    }
+                }
+                Some(AlgorithmMessage::Event(event)) => algorithm.on_order_event(&event),
+                Some(AlgorithmMessage::Trade(trade)) => algorithm.on_trade(&trade),
+                Some(AlgorithmMessage::Time(event)) => algorithm.on_time_event(&event),
+                None => break,
+            }
+        }
+    }
+}
+
+impl MessageHandler for ExecAlgorithmHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        if let Some(command) = message.downcast_ref::<TradingCommand>() {
+            match command {
+                TradingCommand::SubmitOrder(command) => {
+                    self.dispatch(AlgorithmMessage::Command(command.clone()));
+                }
+                command => log::error!("Cannot handle command {command:?} for {}", self.id),
+            }
+        } else if let Some(event) = message.downcast_ref::<OrderEventAny>() {
+            self.dispatch(AlgorithmMessage::Event(event.clone()));
+        } else if let Some(trade) = message.downcast_ref::<TradeTick>() {
+            self.dispatch(AlgorithmMessage::Trade(*trade));
+        } else if let Some(event) = message.downcast_ref::<TimeEvent>() {
+            self.dispatch(AlgorithmMessage::Time(event.clone()));
+        }
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, _data: Data) {}
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Registers the `algorithm` with the message bus, to receive primary orders on its execute
+/// endpoint, its timer events, all order events and all trades.
+pub fn register_exec_algorithm(
+    algorithm: Rc<RefCell<dyn ExecAlgorithm>>,
+    msgbus: &mut MessageBus,
+) -> ShareableMessageHandler {
+    let (execute_endpoint, time_event_endpoint) = {
+        let algorithm = algorithm.borrow();
+        (
+            algorithm.core().execute_endpoint(),
+            algorithm.core().time_event_endpoint(),
+        )
+    };
+    let handler = ShareableMessageHandler(Rc::new(ExecAlgorithmHandler::new(algorithm)));
+
+    msgbus.register(execute_endpoint, handler.clone());
+    msgbus.register(time_event_endpoint, handler.clone());
+    msgbus.subscribe("events.order.*", handler.clone(), None);
+    msgbus.subscribe("data.trades.*", handler.clone(), None);
+    handler
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{
+        stubs::{primary_order, submit_order, TestContext},
+        *,
+    };
+
+    #[rstest]
+    fn test_get_param() {
+        let order = primary_order("TWAP", 100, None, &[("horizon_secs", "60"), ("bad", "x")]);
+
+        assert_eq!(
+            get_param::<f64>(&order, "horizon_secs").unwrap(),
+            Some(60.0)
+        );
+        assert_eq!(get_param::<f64>(&order, "interval_secs").unwrap(), None);
+        assert!(get_param::<f64>(&order, "bad").is_err());
+    }
+
+    #[rstest]
+    fn test_handle_submit_order_rejects_other_algorithm() {
+        let context = TestContext::new();
+        let mut core = context.core("TWAP");
+        let order = primary_order("VWAP", 100, None, &[]);
+
+        assert!(core.handle_submit_order(submit_order(&order)).is_err());
+    }
+
+    #[rstest]
+    fn test_spawn_market_reduces_primary_and_submits() {
+        let context = TestContext::new();
+        let mut core = context.core("TWAP");
+        let mut primary = core
+            .handle_submit_order(submit_order(&primary_order("TWAP", 100, None, &[])))
+            .unwrap();
+
+        let spawned = core
+            .spawn_market(&mut primary, Quantity::from(40), TimeInForce::Gtc, true)
+            .unwrap();
+        core.submit_order(spawned.clone()).unwrap();
+
+        let primary_id = primary.client_order_id();
+        assert_eq!(spawned.client_order_id(), ClientOrderId::from("O-1-E1"));
+        assert_eq!(spawned.exec_spawn_id(), Some(primary_id));
+        assert_eq!(spawned.quantity(), Quantity::from(40));
+        assert_eq!(
+            core.order(&primary_id).unwrap().quantity(),
+            Quantity::from(60)
+        );
+        assert_eq!(core.primary_order(&spawned).unwrap(), primary);
+        assert_eq!(
+            context
+                .cache
+                .borrow()
+                .orders_for_exec_spawn(&primary_id)
+                .len(),
+            2
+        );
+        assert_eq!(context.submitted_orders(), vec![spawned]);
+    }
+
+    #[rstest]
+    fn test_spawn_quantity_must_be_less_than_primary() {
+        let context = TestContext::new();
+        let mut core = context.core("TWAP");
+        let mut primary = core
+            .handle_submit_order(submit_order(&primary_order("TWAP", 100, None, &[])))
+            .unwrap();
+
+        let result = core.spawn_market(&mut primary, Quantity::from(100), TimeInForce::Gtc, true);
+
+        assert!(result.is_err());
+        assert_eq!(primary.quantity(), Quantity::from(100));
+    }
+}
@@ -0,0 +1,135 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use nautilus_common::{
+    cache::Cache,
+    clock::TestClock,
+    messages::execution::{SubmitOrder, TradingCommand},
+    msgbus::{
+        handler::ShareableMessageHandler,
+        stubs::{get_message_saving_handler, get_saved_messages},
+        MessageBus,
+    },
+};
+use nautilus_core::uuid::UUID4;
+use nautilus_model::{
+    enums::{OrderSide, OrderType},
+    identifiers::{ClientId, ClientOrderId, ExecAlgorithmId, InstrumentId},
+    instruments::{stubs::audusd_sim, InstrumentAny},
+    orders::{OrderAny, OrderTestBuilder},
+    types::{Price, Quantity},
+};
+use ustr::Ustr;
+
+use super::ExecAlgorithmCore;
+
+pub struct TestContext {
+    pub clock: Rc<RefCell<TestClock>>,
+    pub cache: Rc<RefCell<Cache>>,
+    pub msgbus: Rc<RefCell<MessageBus>>,
+    pub command_handler: ShareableMessageHandler,
+}
+
+impl TestContext {
+    pub fn new() -> Self {
+        let mut cache = Cache::default();
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim()))
+            .unwrap();
+        let msgbus = MessageBus::default();
+        let command_handler = get_message_saving_handler::<TradingCommand>(None);
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        {
+            let mut msgbus = msgbus.borrow_mut();
+            let endpoint = msgbus.switchboard.risk_engine_execute;
+            msgbus.register(endpoint, command_handler.clone());
+        }
+        Self {
+            clock: Rc::new(RefCell::new(TestClock::new())),
+            cache: Rc::new(RefCell::new(cache)),
+            msgbus,
+            command_handler,
+        }
+    }
+
+    pub fn core(&self, exec_algorithm_id: &str) -> ExecAlgorithmCore {
+        ExecAlgorithmCore::new(
+            ExecAlgorithmId::from(exec_algorithm_id),
+            self.clock.clone(),
+            self.cache.clone(),
+            self.msgbus.clone(),
+        )
+    }
+
+    pub fn submitted_orders(&self) -> Vec<OrderAny> {
+        get_saved_messages::<TradingCommand>(self.command_handler.clone())
+            .into_iter()
+            .filter_map(|command| match command {
+                TradingCommand::SubmitOrder(command) => Some(command.order),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+pub fn primary_order(
+    exec_algorithm_id: &str,
+    quantity: i64,
+    price: Option<&str>,
+    params: &[(&str, &str)],
+) -> OrderAny {
+    let client_order_id = ClientOrderId::from("O-1");
+    let mut builder = OrderTestBuilder::new(if price.is_some() {
+        OrderType::Limit
+    } else {
+        OrderType::Market
+    });
+    builder
+        .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+        .client_order_id(client_order_id)
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(quantity))
+        .exec_algorithm_id(ExecAlgorithmId::from(exec_algorithm_id))
+        .exec_algorithm_params(
+            params
+                .iter()
+                .map(|(key, value)| (Ustr::from(key), Ustr::from(value)))
+                .collect::<HashMap<_, _>>(),
+        )
+        .exec_spawn_id(client_order_id);
+    if let Some(price) = price {
+        builder.price(Price::from(price));
+    }
+    builder.build()
+}
+
+pub fn submit_order(order: &OrderAny) -> SubmitOrder {
+    SubmitOrder::new(
+        order.trader_id(),
+        ClientId::from("SIM"),
+        order.strategy_id(),
+        order.instrument_id(),
+        order.client_order_id(),
+        order.venue_order_id().unwrap_or_default(),
+        order.clone(),
+        order.exec_algorithm_id(),
+        None,
+        UUID4::new(),
+        0.into(),
+    )
+    .unwrap()
+}
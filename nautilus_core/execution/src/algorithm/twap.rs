@@ -0,0 +1,338 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Time-weighted average price (TWAP) execution algorithm.
+
+use std::collections::{HashMap, VecDeque};
+
+use nautilus_common::timer::TimeEvent;
+use nautilus_model::{identifiers::ClientOrderId, orders::OrderAny, types::Quantity};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use ustr::Ustr;
+
+use super::{get_param, ExecAlgorithm, ExecAlgorithmCore};
+
+const NANOSECONDS_IN_SECOND: f64 = 1_000_000_000.0;
+
+#[derive(Debug)]
+struct TwapSchedule {
+    primary_id: ClientOrderId,
+    slices: VecDeque<Quantity>,
+}
+
+/// Executes a primary order by splitting it into slices spread evenly over a time horizon.
+///
+/// Parameters are read from the primary order `exec_algorithm_params`:
+/// - `horizon_secs`: the total execution horizon in seconds.
+/// - `interval_secs`: the interval between slices in seconds.
+/// - `randomness` (optional): the fraction in [0, 1) by which slice sizes are randomized
+///   around the even split, defaults to zero.
+///
+/// The first slice is spawned immediately, the remaining slices on each interval, and the primary
+/// order itself is submitted for the final slice. Slices are spawned as the same order type as
+/// the primary order (market or limit).
+pub struct TwapExecAlgorithm {
+    core: ExecAlgorithmCore,
+    rng: StdRng,
+    schedules: HashMap<Ustr, TwapSchedule>,
+}
+
+impl TwapExecAlgorithm {
+    /// Creates a new [`TwapExecAlgorithm`] instance.
+    ///
+    /// The optional `seed` makes the randomized slice sizes reproducible.
+    #[must_use]
+    pub fn new(core: ExecAlgorithmCore, seed: Option<u64>) -> Self {
+        let rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+        Self {
+            core,
+            rng,
+            schedules: HashMap::new(),
+        }
+    }
+
+    /// Returns whether the given `primary_id` is currently being executed.
+    #[must_use]
+    pub fn is_executing(&self, primary_id: &ClientOrderId) -> bool {
+        self.schedules
+            .values()
+            .any(|schedule| schedule.primary_id == *primary_id)
+    }
+
+    fn timer_name(&self, primary_id: &ClientOrderId) -> Ustr {
+        Ustr::from(&format!("{}-{primary_id}", self.core.id))
+    }
+
+    /// Splits `quantity` into `count` slices of whole `increment` units, each randomized by up to
+    /// `randomness` around the even split. The last slice takes any remainder.
+    fn make_slices(
+        &mut self,
+        quantity: Quantity,
+        increment: Quantity,
+        count: u64,
+        randomness: f64,
+    ) -> Vec<Quantity> {
+        let units = quantity.raw / increment.raw;
+        let count = count.clamp(1, units.max(1));
+        let weights: Vec<f64> = (0..count)
+            .map(|_| 1.0 + randomness * self.rng.gen_range(-1.0..=1.0))
+            .collect();
+        let total_weight: f64 = weights.iter().sum();
+
+        // Every slice gets at least one unit, with the surplus allocated by weight
+        let surplus = units.saturating_sub(count) as f64;
+        let mut slices = Vec::with_capacity(count as usize);
+        let mut allocated = 0;
+        for weight in &weights[..weights.len() - 1] {
+            let slice_units = 1 + (surplus * weight / total_weight).floor() as u64;
+            allocated += slice_units * increment.raw;
+            slices.push(Quantity::from_raw(
+                slice_units * increment.raw,
+                quantity.precision,
+            ));
+        }
+        slices.push(Quantity::from_raw(
+            quantity.raw - allocated,
+            quantity.precision,
+        ));
+        slices
+    }
+
+    fn finish(&mut self, timer_name: Ustr) {
+        if self.schedules.remove(&timer_name).is_some() {
+            self.core.cancel_timer(&timer_name);
+        }
+    }
+}
+
+impl ExecAlgorithm for TwapExecAlgorithm {
+    fn core(&self) -> &ExecAlgorithmCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut ExecAlgorithmCore {
+        &mut self.core
+    }
+
+    fn on_order(&mut self, mut order: OrderAny) -> anyhow::Result<()> {
+        let primary_id = order.client_order_id();
+        let horizon_secs: f64 = get_param(&order, "horizon_secs")?
+            .ok_or_else(|| anyhow::anyhow!("Missing `horizon_secs` parameter for {primary_id}"))?;
+        let interval_secs: f64 = get_param(&order, "interval_secs")?
+            .ok_or_else(|| anyhow::anyhow!("Missing `interval_secs` parameter for {primary_id}"))?;
+        let randomness: f64 = get_param(&order, "randomness")?.unwrap_or(0.0);
+        anyhow::ensure!(
+            interval_secs > 0.0 && horizon_secs >= interval_secs,
+            "Invalid TWAP horizon {horizon_secs}s with interval {interval_secs}s for {primary_id}"
+        );
+        anyhow::ensure!(
+            (0.0..1.0).contains(&randomness),
+            "Invalid TWAP randomness {randomness} for {primary_id}, must be in [0, 1)"
+        );
+
+        let instrument = self.core.instrument(&order.instrument_id())?;
+        let count = (horizon_secs / interval_secs).floor() as u64;
+        let mut slices: VecDeque<Quantity> = self
+            .make_slices(
+                order.quantity(),
+                instrument.size_increment(),
+                count,
+                randomness,
+            )
+            .into();
+        // The final slice is the remaining primary order quantity
+        slices.pop_back();
+
+        let Some(first_qty) = slices.pop_front() else {
+            log::info!("Submitting {primary_id} in a single slice");
+            return self.core.submit_order(order);
+        };
+
+        let spawned = self.core.spawn_slice(&mut order, first_qty)?;
+        self.core.submit_order(spawned)?;
+
+        let timer_name = self.timer_name(&primary_id);
+        let interval_ns = (interval_secs * NANOSECONDS_IN_SECOND) as u64;
+        self.core.set_timer(&timer_name, interval_ns, None)?;
+        self.schedules
+            .insert(timer_name, TwapSchedule { primary_id, slices });
+        Ok(())
+    }
+
+    fn on_time_event(&mut self, event: &TimeEvent) {
+        let Some(schedule) = self.schedules.get_mut(&event.name) else {
+            return;
+        };
+        let primary_id = schedule.primary_id;
+        let next_qty = schedule.slices.pop_front();
+
+        let Some(mut primary) = self.core.order(&primary_id) else {
+            log::error!("Primary order {primary_id} not found in cache");
+            self.finish(event.name);
+            return;
+        };
+        if primary.is_closed() {
+            log::warn!("Primary order {primary_id} closed, stopping execution");
+            self.finish(event.name);
+            return;
+        }
+
+        let result = match next_qty {
+            Some(quantity) => self
+                .core
+                .spawn_slice(&mut primary, quantity)
+                .and_then(|spawned| self.core.submit_order(spawned)),
+            None => {
+                self.finish(event.name);
+                self.core.submit_order(primary)
+            }
+        };
+        if let Err(e) = result {
+            log::error!("Error executing slice for {primary_id}: {e}");
+            self.finish(event.name);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{any::Any, cell::RefCell, rc::Rc};
+
+    use nautilus_common::messages::execution::TradingCommand;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::algorithm::{
+        register_exec_algorithm,
+        stubs::{primary_order, submit_order, TestContext},
+    };
+
+    const PARAMS: &[(&str, &str)] = &[("horizon_secs", "4"), ("interval_secs", "1")];
+
+    fn advance(context: &TestContext, algorithm: &mut TwapExecAlgorithm, secs: u64) {
+        let events = context
+            .clock
+            .borrow_mut()
+            .advance_time((secs * 1_000_000_000).into(), true);
+        for event in &events {
+            algorithm.on_time_event(event);
+        }
+    }
+
+    #[rstest]
+    fn test_twap_slices_primary_over_horizon() {
+        let context = TestContext::new();
+        let mut algorithm = TwapExecAlgorithm::new(context.core("TWAP"), Some(42));
+        let primary = primary_order("TWAP", 100, None, PARAMS);
+        let primary_id = primary.client_order_id();
+
+        algorithm.execute(submit_order(&primary)).unwrap();
+
+        let submitted = context.submitted_orders();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].quantity(), Quantity::from(25));
+        assert!(algorithm.is_executing(&primary_id));
+
+        for secs in 1..=3 {
+            advance(&context, &mut algorithm, secs);
+        }
+
+        let submitted = context.submitted_orders();
+        let quantities: Vec<Quantity> = submitted.iter().map(OrderAny::quantity).collect();
+        assert_eq!(quantities, vec![Quantity::from(25); 4]);
+        assert_eq!(submitted[3].client_order_id(), primary_id);
+        assert!(!algorithm.is_executing(&primary_id));
+        assert_eq!(
+            context
+                .cache
+                .borrow()
+                .orders_for_exec_spawn(&primary_id)
+                .len(),
+            4
+        );
+    }
+
+    #[rstest]
+    fn test_twap_single_slice_submits_primary() {
+        let context = TestContext::new();
+        let mut algorithm = TwapExecAlgorithm::new(context.core("TWAP"), None);
+        let primary = primary_order(
+            "TWAP",
+            100,
+            None,
+            &[("horizon_secs", "1"), ("interval_secs", "1")],
+        );
+
+        algorithm.execute(submit_order(&primary)).unwrap();
+
+        assert_eq!(context.submitted_orders(), vec![primary]);
+    }
+
+    #[rstest]
+    fn test_twap_invalid_params() {
+        let context = TestContext::new();
+        let mut algorithm = TwapExecAlgorithm::new(context.core("TWAP"), None);
+        let primary = primary_order(
+            "TWAP",
+            100,
+            None,
+            &[("horizon_secs", "1"), ("interval_secs", "2")],
+        );
+
+        assert!(algorithm.execute(submit_order(&primary)).is_err());
+        assert!(context.submitted_orders().is_empty());
+    }
+
+    #[rstest]
+    fn test_twap_randomized_slices_sum_to_quantity() {
+        let context = TestContext::new();
+        let mut algorithm = TwapExecAlgorithm::new(context.core("TWAP"), Some(1));
+
+        let slices = algorithm.make_slices(Quantity::from(1000), Quantity::from(1), 7, 0.5);
+
+        assert_eq!(slices.len(), 7);
+        assert!(slices
+            .iter()
+            .all(|slice| slice.raw >= Quantity::from(1).raw));
+        assert!(slices.iter().any(|slice| *slice != slices[0]));
+        assert_eq!(
+            slices.iter().map(|slice| slice.raw).sum::<u64>(),
+            Quantity::from(1000).raw
+        );
+    }
+
+    #[rstest]
+    fn test_twap_executes_from_message_bus() {
+        let context = TestContext::new();
+        let algorithm = Rc::new(RefCell::new(TwapExecAlgorithm::new(
+            context.core("TWAP"),
+            Some(42),
+        )));
+        register_exec_algorithm(algorithm.clone(), &mut context.msgbus.borrow_mut());
+        let primary = primary_order("TWAP", 100, None, PARAMS);
+
+        let command = TradingCommand::SubmitOrder(submit_order(&primary));
+        context
+            .msgbus
+            .borrow()
+            .send(&Ustr::from("TWAP.execute"), &command as &dyn Any);
+
+        assert_eq!(context.submitted_orders().len(), 1);
+        assert!(algorithm.borrow().is_executing(&primary.client_order_id()));
+    }
+}
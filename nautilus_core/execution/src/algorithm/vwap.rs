@@ -0,0 +1,221 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Participation-rate volume-weighted average price (VWAP) execution algorithm.
+
+use std::collections::HashMap;
+
+use nautilus_model::{
+    data::TradeTick, identifiers::ClientOrderId, orders::OrderAny, types::Quantity,
+};
+
+use super::{get_param, ExecAlgorithm, ExecAlgorithmCore};
+
+#[derive(Debug)]
+struct Participation {
+    rate: f64,
+    increment: Quantity,
+    accumulated_raw: u64,
+}
+
+/// Executes a primary order by participating in a fixed fraction of the market traded volume,
+/// so that the average execution price tracks the market VWAP over the execution period.
+///
+/// Parameters are read from the primary order `exec_algorithm_params`:
+/// - `participation_rate`: the fraction in (0, 1] of traded volume to execute.
+///
+/// Each trade for the instrument accrues its size multiplied by the participation rate. Once a
+/// whole size increment has accrued a child order is spawned for it, and the primary order
+/// itself is submitted once the accrued quantity covers its remaining quantity.
+pub struct VwapExecAlgorithm {
+    core: ExecAlgorithmCore,
+    participations: HashMap<ClientOrderId, Participation>,
+}
+
+impl VwapExecAlgorithm {
+    /// Creates a new [`VwapExecAlgorithm`] instance.
+    #[must_use]
+    pub fn new(core: ExecAlgorithmCore) -> Self {
+        Self {
+            core,
+            participations: HashMap::new(),
+        }
+    }
+
+    /// Returns whether the given `primary_id` is currently being executed.
+    #[must_use]
+    pub fn is_executing(&self, primary_id: &ClientOrderId) -> bool {
+        self.participations.contains_key(primary_id)
+    }
+
+    fn participate(&mut self, primary_id: ClientOrderId, trade: &TradeTick) -> anyhow::Result<()> {
+        let Some(mut primary) = self.core.order(&primary_id) else {
+            self.participations.remove(&primary_id);
+            anyhow::bail!("Primary order {primary_id} not found in cache");
+        };
+        if primary.is_closed() {
+            log::warn!("Primary order {primary_id} closed, stopping execution");
+            self.participations.remove(&primary_id);
+            return Ok(());
+        }
+
+        let participation = self
+            .participations
+            .get_mut(&primary_id)
+            .expect("Participation should exist");
+        participation.accumulated_raw += (trade.size.raw as f64 * participation.rate) as u64;
+        let increment_raw = participation.increment.raw;
+        let spawn_raw = participation.accumulated_raw / increment_raw * increment_raw;
+        if spawn_raw == 0 {
+            return Ok(());
+        }
+
+        if spawn_raw >= primary.quantity().raw {
+            self.participations.remove(&primary_id);
+            return self.core.submit_order(primary);
+        }
+
+        participation.accumulated_raw -= spawn_raw;
+        let quantity = Quantity::from_raw(spawn_raw, primary.quantity().precision);
+        let spawned = self.core.spawn_slice(&mut primary, quantity)?;
+        self.core.submit_order(spawned)
+    }
+}
+
+impl ExecAlgorithm for VwapExecAlgorithm {
+    fn core(&self) -> &ExecAlgorithmCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut ExecAlgorithmCore {
+        &mut self.core
+    }
+
+    fn on_order(&mut self, order: OrderAny) -> anyhow::Result<()> {
+        let primary_id = order.client_order_id();
+        let rate: f64 = get_param(&order, "participation_rate")?.ok_or_else(|| {
+            anyhow::anyhow!("Missing `participation_rate` parameter for {primary_id}")
+        })?;
+        anyhow::ensure!(
+            rate > 0.0 && rate <= 1.0,
+            "Invalid participation rate {rate} for {primary_id}, must be in (0, 1]"
+        );
+
+        let instrument = self.core.instrument(&order.instrument_id())?;
+        self.participations.insert(
+            primary_id,
+            Participation {
+                rate,
+                increment: instrument.size_increment(),
+                accumulated_raw: 0,
+            },
+        );
+        Ok(())
+    }
+
+    fn on_trade(&mut self, trade: &TradeTick) {
+        let primary_ids: Vec<ClientOrderId> = self
+            .participations
+            .keys()
+            .copied()
+            .filter(|primary_id| {
+                self.core
+                    .order(primary_id)
+                    .is_some_and(|order| order.instrument_id() == trade.instrument_id)
+            })
+            .collect();
+
+        for primary_id in primary_ids {
+            if let Err(e) = self.participate(primary_id, trade) {
+                log::error!("Error participating for {primary_id}: {e}");
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::AggressorSide,
+        identifiers::{InstrumentId, TradeId},
+        types::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::algorithm::stubs::{primary_order, submit_order, TestContext};
+
+    fn trade(instrument_id: &str, size: i64) -> TradeTick {
+        TradeTick::new(
+            InstrumentId::from(instrument_id),
+            Price::from("0.80000"),
+            Quantity::from(size),
+            AggressorSide::Buyer,
+            TradeId::from("T-1"),
+            0.into(),
+            0.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_vwap_participates_in_traded_volume() {
+        let context = TestContext::new();
+        let mut algorithm = VwapExecAlgorithm::new(context.core("VWAP"));
+        let primary = primary_order("VWAP", 10, None, &[("participation_rate", "0.5")]);
+        let primary_id = primary.client_order_id();
+
+        algorithm.execute(submit_order(&primary)).unwrap();
+        algorithm.on_trade(&trade("AUD/USD.SIM", 1));
+        assert!(context.submitted_orders().is_empty());
+
+        algorithm.on_trade(&trade("AUD/USD.SIM", 9));
+        algorithm.on_trade(&trade("AUD/USD.SIM", 6));
+        algorithm.on_trade(&trade("AUD/USD.SIM", 4));
+
+        let submitted = context.submitted_orders();
+        let quantities: Vec<Quantity> = submitted.iter().map(OrderAny::quantity).collect();
+        assert_eq!(
+            quantities,
+            vec![Quantity::from(5), Quantity::from(3), Quantity::from(2)]
+        );
+        assert_eq!(submitted[2].client_order_id(), primary_id);
+        assert!(!algorithm.is_executing(&primary_id));
+    }
+
+    #[rstest]
+    fn test_vwap_ignores_other_instruments() {
+        let context = TestContext::new();
+        let mut algorithm = VwapExecAlgorithm::new(context.core("VWAP"));
+        let primary = primary_order("VWAP", 10, None, &[("participation_rate", "0.5")]);
+
+        algorithm.execute(submit_order(&primary)).unwrap();
+        algorithm.on_trade(&trade("USD/JPY.SIM", 100));
+
+        assert!(context.submitted_orders().is_empty());
+        assert!(algorithm.is_executing(&primary.client_order_id()));
+    }
+
+    #[rstest]
+    fn test_vwap_invalid_participation_rate() {
+        let context = TestContext::new();
+        let mut algorithm = VwapExecAlgorithm::new(context.core("VWAP"));
+        let primary = primary_order("VWAP", 10, None, &[("participation_rate", "1.5")]);
+
+        assert!(algorithm.execute(submit_order(&primary)).is_err());
+    }
+}
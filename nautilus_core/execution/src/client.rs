@@ -19,12 +19,26 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use std::{cell::RefCell, rc::Rc};
-
-use nautilus_common::{cache::Cache, msgbus::MessageBus};
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use nautilus_common::{
+    cache::Cache,
+    messages::{
+        data::DataResponse,
+        execution::{
+            BatchCancelOrders, CancelAllOrders, CancelOrder, ModifyOrder, QueryOrder, SubmitOrder,
+            SubmitOrderList, TradingCommand,
+        },
+    },
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+};
 use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
 use nautilus_model::{
     accounts::AccountAny,
+    data::Data,
     enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType},
     events::{
         AccountState, OrderAccepted, OrderCancelRejected, OrderCanceled, OrderEventAny,
@@ -37,10 +51,106 @@ use nautilus_model::{
     },
     types::{AccountBalance, Currency, MarginBalance, Money, Price, Quantity},
 };
+use ustr::Ustr;
 
-use crate::messages::{
-    BatchCancelOrders, CancelOrder, ModifyOrder, QueryOrder, SubmitOrder, SubmitOrderList,
-};
+use crate::reports::mass_status::ExecutionMassStatus;
+
+/// Represents a request to a venue adapter for an [`ExecutionMassStatus`] of its venue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MassStatusRequest {
+    /// The client ID the mass status is requested for.
+    pub client_id: ClientId,
+    /// The maximum lookback (minutes) of the reported orders and fills.
+    pub lookback_mins: Option<u32>,
+    /// UNIX timestamp (nanoseconds) when the request was initialized.
+    pub ts_init: UnixNanos,
+}
+
+/// Returns the message bus endpoint the venue adapter for `client_id` handles trading
+/// commands on.
+#[must_use]
+pub fn execute_endpoint(client_id: &ClientId) -> Ustr {
+    Ustr::from(&format!("{client_id}.execute"))
+}
+
+/// Handles the [`TradingCommand`]s sent to the execute endpoint of an [`ExecutionClient`]
+/// by the venue adapter for a live venue.
+///
+/// Implementations emit the resulting order events for the execution engine to process.
+pub trait LiveExecutionClient {
+    fn client_id(&self) -> ClientId;
+    fn venue(&self) -> Venue;
+    fn account_id(&self) -> AccountId;
+    fn start(&mut self) -> anyhow::Result<()>;
+    fn stop(&mut self) -> anyhow::Result<()>;
+    fn is_connected(&self) -> bool;
+
+    // -- COMMAND HANDLERS ----------------------------------------------------
+
+    fn submit_order(&mut self, command: &SubmitOrder) -> anyhow::Result<()>;
+    fn submit_order_list(&mut self, command: &SubmitOrderList) -> anyhow::Result<()>;
+    fn modify_order(&mut self, command: &ModifyOrder) -> anyhow::Result<()>;
+    fn cancel_order(&mut self, command: &CancelOrder) -> anyhow::Result<()>;
+    fn cancel_all_orders(&mut self, command: &CancelAllOrders) -> anyhow::Result<()>;
+    fn batch_cancel_orders(&mut self, command: &BatchCancelOrders) -> anyhow::Result<()>;
+    fn query_order(&mut self, command: &QueryOrder) -> anyhow::Result<()>;
+
+    /// Handles the `command` with its specific handler.
+    fn execute(&mut self, command: &TradingCommand) -> anyhow::Result<()> {
+        match command {
+            TradingCommand::SubmitOrder(command) => self.submit_order(command),
+            TradingCommand::SubmitOrderList(command) => self.submit_order_list(command),
+            TradingCommand::ModifyOrder(command) => self.modify_order(command),
+            TradingCommand::CancelOrder(command) => self.cancel_order(command),
+            TradingCommand::CancelAllOrders(command) => self.cancel_all_orders(command),
+            TradingCommand::BatchCancelOrders(command) => self.batch_cancel_orders(command),
+            TradingCommand::QueryOrder(command) => self.query_order(command),
+        }
+    }
+}
+
+/// Handles the trading commands on the execute endpoint of a [`LiveExecutionClient`].
+pub struct LiveExecutionClientHandler {
+    id: Ustr,
+    client: Rc<RefCell<dyn LiveExecutionClient>>,
+}
+
+impl MessageHandler for LiveExecutionClientHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        let Some(command) = message.downcast_ref::<TradingCommand>() else {
+            log::error!("Invalid message type for {}", self.id);
+            return;
+        };
+        if let Err(e) = self.client.borrow_mut().execute(command) {
+            log::error!("Error executing {command} on {}: {e}", self.id);
+        }
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, _data: Data) {}
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Registers the live `client` with the message bus, to handle the trading commands on
+/// the execute endpoint for its client ID.
+pub fn register_live_exec_client(
+    client: Rc<RefCell<dyn LiveExecutionClient>>,
+    msgbus: &mut MessageBus,
+) -> ShareableMessageHandler {
+    let endpoint = execute_endpoint(&client.borrow().client_id());
+    let handler = ShareableMessageHandler(Rc::new(LiveExecutionClientHandler {
+        id: endpoint,
+        client,
+    }));
+    msgbus.register(endpoint, handler.clone());
+    handler
+}
 
 pub struct ExecutionClient {
     pub trader_id: TraderId,
@@ -57,6 +167,49 @@ pub struct ExecutionClient {
 }
 
 impl ExecutionClient {
+    /// Creates a new [`ExecutionClient`] instance.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        trader_id: TraderId,
+        client_id: ClientId,
+        venue: Venue,
+        oms_type: OmsType,
+        account_id: AccountId,
+        account_type: AccountType,
+        base_currency: Option<Currency>,
+        clock: &'static AtomicTime,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Self {
+        Self {
+            trader_id,
+            client_id,
+            venue,
+            oms_type,
+            account_id,
+            account_type,
+            base_currency,
+            is_connected: false,
+            clock,
+            cache,
+            msgbus,
+        }
+    }
+
+    /// Returns the message bus endpoint the venue adapter for this client handles
+    /// trading commands on.
+    #[must_use]
+    pub fn execute_endpoint(&self) -> Ustr {
+        execute_endpoint(&self.client_id)
+    }
+
+    /// Returns the message bus endpoint the venue adapter for this client handles
+    /// mass status requests on.
+    #[must_use]
+    pub fn mass_status_endpoint(&self) -> Ustr {
+        Ustr::from(&format!("{}.mass_status", self.client_id))
+    }
+
     #[must_use]
     pub fn get_account(&self) -> AccountAny {
         let cache = self.cache.as_ref().borrow();
@@ -66,27 +219,62 @@ impl ExecutionClient {
     // -- COMMAND HANDLERS ----------------------------------------------------
 
     pub fn submit_order(&self, command: SubmitOrder) -> anyhow::Result<()> {
-        todo!();
+        self.send_command(TradingCommand::SubmitOrder(command))
     }
 
     pub fn submit_order_list(&self, command: SubmitOrderList) -> anyhow::Result<()> {
-        todo!();
+        self.send_command(TradingCommand::SubmitOrderList(command))
     }
 
     pub fn modify_order(&self, command: ModifyOrder) -> anyhow::Result<()> {
-        todo!();
+        self.send_command(TradingCommand::ModifyOrder(command))
     }
 
     pub fn cancel_order(&self, command: CancelOrder) -> anyhow::Result<()> {
-        todo!();
+        self.send_command(TradingCommand::CancelOrder(command))
+    }
+
+    pub fn cancel_all_orders(&self, command: CancelAllOrders) -> anyhow::Result<()> {
+        self.send_command(TradingCommand::CancelAllOrders(command))
     }
 
     pub fn batch_cancel_orders(&self, command: BatchCancelOrders) -> anyhow::Result<()> {
-        todo!();
+        self.send_command(TradingCommand::BatchCancelOrders(command))
     }
 
     pub fn query_order(&self, command: QueryOrder) -> anyhow::Result<()> {
-        todo!();
+        self.send_command(TradingCommand::QueryOrder(command))
+    }
+
+    /// Requests an [`ExecutionMassStatus`] for the venue from the venue adapter, which
+    /// responds through [`ExecutionClient::send_mass_status`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no venue adapter handles mass status requests for this client.
+    pub fn request_mass_status(&self, lookback_mins: Option<u32>) -> anyhow::Result<()> {
+        let endpoint = self.mass_status_endpoint();
+        let msgbus = self.msgbus.borrow();
+        if !msgbus.is_registered(endpoint) {
+            anyhow::bail!("No handler registered for {endpoint}");
+        }
+        let request = MassStatusRequest {
+            client_id: self.client_id,
+            lookback_mins,
+            ts_init: self.clock.get_time_ns(),
+        };
+        msgbus.send(&endpoint, &request as &dyn Any);
+        Ok(())
+    }
+
+    fn send_command(&self, command: TradingCommand) -> anyhow::Result<()> {
+        let endpoint = self.execute_endpoint();
+        let msgbus = self.msgbus.borrow();
+        if !msgbus.is_registered(endpoint) {
+            anyhow::bail!("No handler registered for {endpoint}");
+        }
+        msgbus.send(&endpoint, &command as &dyn Any);
+        Ok(())
     }
 
     pub fn generate_account_state(
@@ -390,15 +578,28 @@ impl ExecutionClient {
     }
 
     fn send_account_state(&self, account_state: AccountState) -> anyhow::Result<()> {
-        todo!()
+        let endpoint = Ustr::from("Portfolio.update_account");
+        let msgbus = self.msgbus.borrow();
+        if !msgbus.is_registered(endpoint) {
+            anyhow::bail!("No handler registered for {endpoint}");
+        }
+        msgbus.send(&endpoint, &account_state as &dyn Any);
+        Ok(())
     }
 
     fn send_order_event(&self, event: OrderEventAny) {
-        todo!()
+        let msgbus = self.msgbus.borrow();
+        msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
     }
 
-    // TODO: Implement execution reports
-    // fn send_mass_status_report(&self, report)
+    /// Sends the `mass_status` of the venue to the execution engine for reconciliation.
+    pub fn send_mass_status(&self, mass_status: ExecutionMassStatus) {
+        let msgbus = self.msgbus.borrow();
+        msgbus.send(
+            &msgbus.switchboard.exec_engine_reconcile,
+            &mass_status as &dyn Any,
+        );
+    }
 
     // TODO: Implement execution reports
     // fn send_order_status_report(&self, report)
@@ -0,0 +1,498 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Client-side management of contingent order lists for venues without native support.
+
+use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
+
+use nautilus_common::{
+    cache::Cache,
+    clock::Clock,
+    messages::execution::{CancelOrder, ModifyOrder, SubmitOrder, TradingCommand},
+    msgbus::MessageBus,
+};
+use nautilus_core::uuid::UUID4;
+use nautilus_model::{
+    enums::ContingencyType,
+    events::{OrderCanceled, OrderEventAny},
+    identifiers::{ClientId, ClientOrderId},
+    orders::OrderAny,
+    types::Quantity,
+};
+
+/// Enforces OTO, OCO and OUO contingencies between orders linked by an order list,
+/// for execution clients whose venue does not support contingent orders natively.
+///
+/// - OTO (one-triggers-other): child orders are held until their parent is (partially) filled.
+/// - OCO (one-cancels-other): a fill or close of any order cancels its linked orders.
+/// - OUO (one-updates-other): a fill or quantity change reduces its linked orders to the same
+///   leaves quantity, a close cancels them.
+///
+/// Commands are sent to the execution engine, and local cancels of held orders to its
+/// event processing endpoint.
+pub struct ContingencyManager {
+    client_id: ClientId,
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    held_orders: HashMap<ClientOrderId, OrderAny>,
+}
+
+impl ContingencyManager {
+    /// Creates a new [`ContingencyManager`] instance.
+    pub fn new(
+        client_id: ClientId,
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Self {
+        Self {
+            client_id,
+            clock,
+            cache,
+            msgbus,
+            held_orders: HashMap::new(),
+        }
+    }
+
+    /// Returns whether the order with the given `client_order_id` is held by the manager.
+    #[must_use]
+    pub fn is_held(&self, client_order_id: &ClientOrderId) -> bool {
+        self.held_orders.contains_key(client_order_id)
+    }
+
+    /// Holds the given `order` if it is the child of an OTO parent which has not yet filled,
+    /// returning whether the order should be withheld from the venue.
+    ///
+    /// A child of a parent which closed without any fill is canceled locally.
+    pub fn hold_order(&mut self, order: &OrderAny) -> bool {
+        let Some(parent_order_id) = order.parent_order_id() else {
+            return false;
+        };
+        let (is_oto, has_fills, is_closed) = {
+            let cache = self.cache.borrow();
+            let Some(parent_order) = cache.order(&parent_order_id) else {
+                log::warn!(
+                    "Cannot find OTO parent {parent_order_id} for {}",
+                    order.client_order_id()
+                );
+                return false;
+            };
+            (
+                parent_order.contingency_type() == Some(ContingencyType::Oto),
+                parent_order.filled_qty().is_positive(),
+                parent_order.is_closed(),
+            )
+        };
+        if !is_oto || has_fills {
+            return false;
+        }
+
+        if is_closed {
+            self.cancel_locally(order);
+        } else {
+            log::info!(
+                "Holding OTO order {} until {parent_order_id} fills",
+                order.client_order_id()
+            );
+            self.held_orders
+                .insert(order.client_order_id(), order.clone());
+        }
+        true
+    }
+
+    /// Handles the given order `event`, which must already be applied to the cached order.
+    pub fn handle_event(&mut self, event: &OrderEventAny) {
+        let order = match self.cache.borrow().order(&event.client_order_id()) {
+            Some(order) => order.clone(),
+            None => {
+                log::warn!(
+                    "Cannot handle contingencies for {}: order not found in cache",
+                    event.client_order_id()
+                );
+                return;
+            }
+        };
+
+        match (event, order.contingency_type()) {
+            (OrderEventAny::Filled(_), Some(ContingencyType::Oto)) => self.release_children(&order),
+            (OrderEventAny::Filled(_), Some(ContingencyType::Oco)) => self.cancel_linked(&order),
+            (OrderEventAny::Filled(_) | OrderEventAny::Updated(_), Some(ContingencyType::Ouo))
+                if !order.is_closed() =>
+            {
+                self.update_linked(&order);
+            }
+            (
+                OrderEventAny::Filled(_)
+                | OrderEventAny::Canceled(_)
+                | OrderEventAny::Expired(_)
+                | OrderEventAny::Rejected(_),
+                Some(ContingencyType::Oco | ContingencyType::Ouo | ContingencyType::Oto),
+            ) if order.is_closed() => self.cancel_linked(&order),
+            _ => {}
+        }
+    }
+
+    fn release_children(&mut self, order: &OrderAny) {
+        for client_order_id in order.linked_order_ids().unwrap_or_default() {
+            if let Some(child_order) = self.held_orders.remove(&client_order_id) {
+                log::info!("Releasing OTO order {client_order_id}");
+                self.send_command(TradingCommand::SubmitOrder(
+                    self.submit_command(child_order),
+                ));
+            }
+        }
+    }
+
+    /// Cancels the orders linked to the given closed (or filled OCO) `order`.
+    ///
+    /// Children of an OTO parent are only canceled while still held, once released
+    /// they work independently of the parent.
+    fn cancel_linked(&mut self, order: &OrderAny) {
+        let is_oto = order.contingency_type() == Some(ContingencyType::Oto);
+        for client_order_id in order.linked_order_ids().unwrap_or_default() {
+            if let Some(held_order) = self.held_orders.remove(&client_order_id) {
+                self.cancel_locally(&held_order);
+                continue;
+            }
+            if is_oto {
+                continue;
+            }
+
+            let linked_order = self.cache.borrow().order(&client_order_id).cloned();
+            match linked_order {
+                Some(linked_order) if !linked_order.is_closed() => {
+                    let command = CancelOrder::new(
+                        linked_order.trader_id(),
+                        self.client_id,
+                        linked_order.strategy_id(),
+                        linked_order.instrument_id(),
+                        client_order_id,
+                        linked_order.venue_order_id().unwrap_or_default(),
+                        UUID4::new(),
+                        self.clock.borrow().timestamp_ns(),
+                    )
+                    .expect("Failed to create `CancelOrder` command");
+                    self.send_command(TradingCommand::CancelOrder(command));
+                }
+                Some(_) => {}
+                None => log::warn!("Cannot find contingent order {client_order_id}"),
+            }
+        }
+    }
+
+    fn update_linked(&mut self, order: &OrderAny) {
+        for client_order_id in order.linked_order_ids().unwrap_or_default() {
+            let linked_order = self.cache.borrow().order(&client_order_id).cloned();
+            let Some(linked_order) = linked_order else {
+                log::warn!("Cannot find contingent order {client_order_id}");
+                continue;
+            };
+            if linked_order.is_closed() || linked_order.leaves_qty() == order.leaves_qty() {
+                continue;
+            }
+
+            let quantity: Quantity = linked_order.filled_qty() + order.leaves_qty();
+            let command = ModifyOrder::new(
+                linked_order.trader_id(),
+                self.client_id,
+                linked_order.strategy_id(),
+                linked_order.instrument_id(),
+                client_order_id,
+                linked_order.venue_order_id().unwrap_or_default(),
+                Some(quantity),
+                None,
+                None,
+                UUID4::new(),
+                self.clock.borrow().timestamp_ns(),
+            )
+            .expect("Failed to create `ModifyOrder` command");
+            self.send_command(TradingCommand::ModifyOrder(command));
+        }
+    }
+
+    fn submit_command(&self, order: OrderAny) -> SubmitOrder {
+        SubmitOrder::new(
+            order.trader_id(),
+            self.client_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.venue_order_id().unwrap_or_default(),
+            order.clone(),
+            order.exec_algorithm_id(),
+            order.position_id(),
+            UUID4::new(),
+            self.clock.borrow().timestamp_ns(),
+        )
+        .expect("Failed to create `SubmitOrder` command")
+    }
+
+    fn cancel_locally(&self, order: &OrderAny) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let event = OrderEventAny::Canceled(OrderCanceled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order.venue_order_id(),
+            order.account_id(),
+        ));
+        let msgbus = self.msgbus.borrow();
+        msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+    }
+
+    fn send_command(&self, command: TradingCommand) {
+        let msgbus = self.msgbus.borrow();
+        msgbus.send(
+            &msgbus.switchboard.exec_engine_execute,
+            &command as &dyn Any,
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::{
+        clock::TestClock,
+        msgbus::{
+            stubs::{get_message_saving_handler, get_saved_messages},
+            ShareableMessageHandler,
+        },
+    };
+    use nautilus_model::{
+        enums::{LiquiditySide, OrderSide, OrderType},
+        events::OrderRejected,
+        identifiers::InstrumentId,
+        instruments::{stubs::audusd_sim, CurrencyPair, InstrumentAny},
+        orders::{
+            stubs::{TestOrderEventStubs, TestOrderStubs},
+            OrderTestBuilder,
+        },
+        types::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    struct TestContext {
+        manager: ContingencyManager,
+        cache: Rc<RefCell<Cache>>,
+        command_handler: ShareableMessageHandler,
+        event_handler: ShareableMessageHandler,
+    }
+
+    fn get_context() -> TestContext {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut msgbus = MessageBus::default();
+        let command_handler = get_message_saving_handler::<TradingCommand>(None);
+        let event_handler = get_message_saving_handler::<OrderEventAny>(None);
+        msgbus.register(
+            msgbus.switchboard.exec_engine_execute,
+            command_handler.clone(),
+        );
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            event_handler.clone(),
+        );
+        let manager = ContingencyManager::new(
+            ClientId::from("SIM"),
+            Rc::new(RefCell::new(TestClock::new())),
+            cache.clone(),
+            Rc::new(RefCell::new(msgbus)),
+        );
+        TestContext {
+            manager,
+            cache,
+            command_handler,
+            event_handler,
+        }
+    }
+
+    fn limit_order(
+        client_order_id: &str,
+        side: OrderSide,
+        price: &str,
+        contingency_type: Option<ContingencyType>,
+        linked_order_id: Option<&str>,
+        parent_order_id: Option<&str>,
+    ) -> OrderAny {
+        let mut builder = OrderTestBuilder::new(OrderType::Limit);
+        builder
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(side)
+            .price(Price::from(price))
+            .quantity(Quantity::from(100_000));
+        if let Some(contingency_type) = contingency_type {
+            builder.contingency_type(contingency_type);
+        }
+        if let Some(linked_order_id) = linked_order_id {
+            builder.linked_order_ids(vec![ClientOrderId::from(linked_order_id)]);
+        }
+        if let Some(parent_order_id) = parent_order_id {
+            builder.parent_order_id(ClientOrderId::from(parent_order_id));
+        }
+        builder.build()
+    }
+
+    fn add_order(cache: &Rc<RefCell<Cache>>, order: &OrderAny) {
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn test_oto_child_held_until_parent_filled(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut context = get_context();
+        let parent_order = limit_order(
+            "O-1",
+            OrderSide::Buy,
+            "0.80000",
+            Some(ContingencyType::Oto),
+            Some("O-2"),
+            None,
+        );
+        let child_order = limit_order("O-2", OrderSide::Sell, "0.81000", None, None, Some("O-1"));
+        add_order(&context.cache, &parent_order);
+        add_order(&context.cache, &child_order);
+
+        assert!(context.manager.hold_order(&child_order));
+        assert!(context.manager.is_held(&child_order.client_order_id()));
+
+        let filled_order =
+            TestOrderStubs::make_filled_order(&parent_order, &instrument, LiquiditySide::Taker);
+        context
+            .cache
+            .borrow_mut()
+            .update_order(&filled_order)
+            .unwrap();
+        let fill = TestOrderEventStubs::order_filled(
+            &filled_order,
+            &instrument,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        context.manager.handle_event(&fill);
+
+        let commands = get_saved_messages::<TradingCommand>(context.command_handler);
+        assert_eq!(commands.len(), 1);
+        let TradingCommand::SubmitOrder(command) = &commands[0] else {
+            panic!("Expected submit order, was {:?}", commands[0]);
+        };
+        assert_eq!(command.client_order_id, child_order.client_order_id());
+        assert!(!context.manager.is_held(&child_order.client_order_id()));
+    }
+
+    #[rstest]
+    fn test_oto_child_canceled_when_parent_rejected() {
+        let mut context = get_context();
+        let mut parent_order = limit_order(
+            "O-1",
+            OrderSide::Buy,
+            "0.80000",
+            Some(ContingencyType::Oto),
+            Some("O-2"),
+            None,
+        );
+        parent_order
+            .apply(OrderEventAny::Rejected(OrderRejected::default()))
+            .unwrap();
+        let child_order = limit_order("O-2", OrderSide::Sell, "0.81000", None, None, Some("O-1"));
+        add_order(&context.cache, &parent_order);
+        add_order(&context.cache, &child_order);
+
+        assert!(context.manager.hold_order(&child_order));
+        assert!(!context.manager.is_held(&child_order.client_order_id()));
+
+        let events = get_saved_messages::<OrderEventAny>(context.event_handler);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].client_order_id(), child_order.client_order_id());
+        assert!(matches!(events[0], OrderEventAny::Canceled(_)));
+    }
+
+    #[rstest]
+    fn test_oco_fill_cancels_linked_order(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut context = get_context();
+        let take_profit = limit_order(
+            "O-1",
+            OrderSide::Sell,
+            "0.81000",
+            Some(ContingencyType::Oco),
+            Some("O-2"),
+            None,
+        );
+        let stop_loss = limit_order(
+            "O-2",
+            OrderSide::Buy,
+            "0.79000",
+            Some(ContingencyType::Oco),
+            Some("O-1"),
+            None,
+        );
+        add_order(
+            &context.cache,
+            &TestOrderStubs::make_accepted_order(&take_profit),
+        );
+        add_order(
+            &context.cache,
+            &TestOrderStubs::make_accepted_order(&stop_loss),
+        );
+
+        let filled_order =
+            TestOrderStubs::make_filled_order(&take_profit, &instrument, LiquiditySide::Maker);
+        context
+            .cache
+            .borrow_mut()
+            .update_order(&filled_order)
+            .unwrap();
+        let fill = TestOrderEventStubs::order_filled(
+            &filled_order,
+            &instrument,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        context.manager.handle_event(&fill);
+
+        let commands = get_saved_messages::<TradingCommand>(context.command_handler);
+        assert_eq!(commands.len(), 1);
+        let TradingCommand::CancelOrder(command) = &commands[0] else {
+            panic!("Expected cancel order, was {:?}", commands[0]);
+        };
+        assert_eq!(command.client_order_id, stop_loss.client_order_id());
+    }
+}
@@ -0,0 +1,1078 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An `OrderEmulator` which holds orders locally until their trigger conditions are met.
+//!
+//! Orders submitted with an `emulation_trigger` are held by the emulator (rather than being sent
+//! to the venue) and monitored against the quotes or trades of their trigger instrument, which
+//! may be a synthetic instrument. Once triggered an order is released to the execution engine
+//! (or its execution algorithm) transformed into a market or limit order:
+//! - Stop market, market-if-touched and trailing stop market orders are released as market orders.
+//! - Stop limit, limit-if-touched and trailing stop limit orders are released as limit orders.
+//! - Limit orders are released as market orders once their limit price is crossed.
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use nautilus_common::{
+    cache::Cache,
+    clock::Clock,
+    messages::{
+        data::DataResponse,
+        execution::{CancelAllOrders, CancelOrder, ModifyOrder, SubmitOrder, TradingCommand},
+    },
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+};
+use nautilus_core::uuid::UUID4;
+use nautilus_model::{
+    data::{Data, QuoteTick, TradeTick},
+    enums::{OrderSide, OrderSideSpecified, OrderStatus, OrderType, TimeInForce, TriggerType},
+    events::{OrderCanceled, OrderEmulated, OrderEventAny, OrderReleased, OrderUpdated},
+    identifiers::{ClientOrderId, InstrumentId},
+    orders::{LimitOrder, LimitOrderAny, MarketOrder, OrderAny, StopOrderAny},
+    types::Price,
+};
+use ustr::Ustr;
+
+use crate::{matching_core::OrderMatchingCore, trailing::trailing_stop_calculate};
+
+/// Provides order emulation for order types and trigger instruments not supported by a venue.
+pub struct OrderEmulator {
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    matching_cores: HashMap<InstrumentId, OrderMatchingCore>,
+    quote_triggered: HashSet<InstrumentId>,
+    commands_submit_order: HashMap<ClientOrderId, SubmitOrder>,
+}
+
+impl OrderEmulator {
+    /// Creates a new [`OrderEmulator`] instance.
+    pub fn new(
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Self {
+        Self {
+            clock,
+            cache,
+            msgbus,
+            matching_cores: HashMap::new(),
+            quote_triggered: HashSet::new(),
+            commands_submit_order: HashMap::new(),
+        }
+    }
+
+    // -- QUERIES ---------------------------------------------------------------------------------
+
+    /// Returns the instrument IDs of all trigger instruments being monitored.
+    #[must_use]
+    pub fn trigger_instrument_ids(&self) -> Vec<InstrumentId> {
+        self.matching_cores.keys().copied().collect()
+    }
+
+    /// Returns the matching core for the given trigger `instrument_id` (if found).
+    #[must_use]
+    pub fn matching_core(&self, instrument_id: &InstrumentId) -> Option<&OrderMatchingCore> {
+        self.matching_cores.get(instrument_id)
+    }
+
+    /// Returns the submit order commands for all orders currently being emulated.
+    #[must_use]
+    pub fn get_submit_order_commands(&self) -> &HashMap<ClientOrderId, SubmitOrder> {
+        &self.commands_submit_order
+    }
+
+    /// Returns whether the order with the given `client_order_id` is being emulated.
+    #[must_use]
+    pub fn is_emulating(&self, client_order_id: &ClientOrderId) -> bool {
+        self.commands_submit_order.contains_key(client_order_id)
+    }
+
+    // -- COMMANDS --------------------------------------------------------------------------------
+
+    /// Executes the given trading `command` for an emulated order.
+    pub fn execute(&mut self, command: TradingCommand) {
+        match command {
+            TradingCommand::SubmitOrder(command) => self.handle_submit_order(command),
+            TradingCommand::ModifyOrder(command) => self.handle_modify_order(&command),
+            TradingCommand::CancelOrder(command) => self.handle_cancel_order(&command),
+            TradingCommand::CancelAllOrders(command) => self.handle_cancel_all_orders(&command),
+            command => log::error!("Cannot handle command: unsupported {command:?}"),
+        }
+    }
+
+    /// Handles the given `quote`, updating the trigger instrument prices and checking its
+    /// emulated orders.
+    pub fn on_quote_tick(&mut self, quote: &QuoteTick) {
+        let Some(matching_core) = self.matching_cores.get_mut(&quote.instrument_id) else {
+            return;
+        };
+        matching_core.set_bid_raw(quote.bid_price);
+        matching_core.set_ask_raw(quote.ask_price);
+
+        self.iterate_orders(quote.instrument_id);
+    }
+
+    /// Handles the given `trade`, updating the trigger instrument prices and checking its
+    /// emulated orders.
+    pub fn on_trade_tick(&mut self, trade: &TradeTick) {
+        let Some(matching_core) = self.matching_cores.get_mut(&trade.instrument_id) else {
+            return;
+        };
+        matching_core.set_last_raw(trade.price);
+        if !self.quote_triggered.contains(&trade.instrument_id) {
+            // Trades are the only prices for the trigger instrument
+            matching_core.set_bid_raw(trade.price);
+            matching_core.set_ask_raw(trade.price);
+        }
+
+        self.iterate_orders(trade.instrument_id);
+    }
+
+    fn handle_submit_order(&mut self, command: SubmitOrder) {
+        let mut order = command.order.clone();
+        let client_order_id = order.client_order_id();
+
+        let emulation_trigger = match order.emulation_trigger() {
+            Some(TriggerType::NoTrigger) | None => {
+                log::error!("Cannot emulate order {client_order_id}: no emulation trigger");
+                return;
+            }
+            Some(trigger) => trigger,
+        };
+        if !matches!(
+            emulation_trigger,
+            TriggerType::Default | TriggerType::BidAsk | TriggerType::LastPrice
+        ) {
+            log::error!(
+                "Cannot emulate order {client_order_id}: `TriggerType` {emulation_trigger} not supported"
+            );
+            self.cancel_order(&order);
+            return;
+        }
+        if matches!(
+            order.order_type(),
+            OrderType::Market | OrderType::MarketToLimit
+        ) {
+            log::error!(
+                "Cannot emulate order {client_order_id}: `OrderType` {} not supported",
+                order.order_type()
+            );
+            self.cancel_order(&order);
+            return;
+        }
+
+        let trigger_instrument_id = order
+            .trigger_instrument_id()
+            .unwrap_or_else(|| order.instrument_id());
+        if !self.matching_cores.contains_key(&trigger_instrument_id) {
+            let price_increment = {
+                let cache = self.cache.borrow();
+                cache
+                    .instrument(&trigger_instrument_id)
+                    .map(|instrument| instrument.price_increment())
+                    .or_else(|| {
+                        cache
+                            .synthetic(&trigger_instrument_id)
+                            .map(|synthetic| synthetic.price_increment)
+                    })
+            };
+            let Some(price_increment) = price_increment else {
+                log::error!(
+                    "Cannot emulate order {client_order_id}: trigger instrument {trigger_instrument_id} not found"
+                );
+                self.cancel_order(&order);
+                return;
+            };
+            self.matching_cores.insert(
+                trigger_instrument_id,
+                OrderMatchingCore::new(trigger_instrument_id, price_increment, None, None, None),
+            );
+        }
+        if emulation_trigger != TriggerType::LastPrice {
+            self.quote_triggered.insert(trigger_instrument_id);
+        }
+
+        if !self.cache.borrow().order_exists(&client_order_id) {
+            if let Err(e) = self.cache.borrow_mut().add_order(
+                order.clone(),
+                command.position_id,
+                Some(command.client_id),
+                false,
+            ) {
+                log::error!("Error adding order {client_order_id} to cache: {e}");
+                return;
+            }
+        }
+
+        if order.status() == OrderStatus::Initialized {
+            let ts_now = self.clock.borrow().timestamp_ns();
+            let event = OrderEventAny::Emulated(OrderEmulated::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                client_order_id,
+                UUID4::new(),
+                ts_now,
+                ts_now,
+            ));
+            if let Err(e) = self.apply_event(&mut order, event) {
+                log::error!("Error emulating order {client_order_id}: {e}");
+                return;
+            }
+        }
+
+        let matching_core = self
+            .matching_cores
+            .get_mut(&trigger_instrument_id)
+            .expect("Matching core should exist");
+        if let Err(e) = matching_core.add_order(order.clone().into()) {
+            log::error!("Error adding order {client_order_id} to matching core: {e}");
+            return;
+        }
+        self.commands_submit_order.insert(client_order_id, command);
+        log::info!(
+            "Emulating {client_order_id} triggered by {trigger_instrument_id} {emulation_trigger}"
+        );
+
+        // The order may be immediately triggered
+        self.check_order(trigger_instrument_id, client_order_id);
+    }
+
+    fn handle_modify_order(&mut self, command: &ModifyOrder) {
+        let client_order_id = command.client_order_id;
+        let Some(mut order) = self.emulated_order(&client_order_id) else {
+            log::error!("Cannot modify order {client_order_id}: not being emulated");
+            return;
+        };
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let event = OrderEventAny::Updated(OrderUpdated::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            command.quantity.unwrap_or_else(|| order.quantity()),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            None,
+            order.account_id(),
+            command.price.filter(|_| order.price().is_some()),
+            command
+                .trigger_price
+                .filter(|_| order.trigger_price().is_some()),
+        ));
+        if let Err(e) = self.apply_event(&mut order, event) {
+            log::error!("Error modifying order {client_order_id}: {e}");
+            return;
+        }
+
+        if let Some(trigger_instrument_id) = self.trigger_instrument_id(&client_order_id) {
+            self.check_order(trigger_instrument_id, client_order_id);
+        }
+    }
+
+    fn handle_cancel_order(&mut self, command: &CancelOrder) {
+        let client_order_id = command.client_order_id;
+        match self.emulated_order(&client_order_id) {
+            Some(order) => self.cancel_order(&order),
+            None => log::warn!("Cannot cancel order {client_order_id}: not being emulated"),
+        }
+    }
+
+    fn handle_cancel_all_orders(&mut self, command: &CancelAllOrders) {
+        let orders: Vec<OrderAny> = self
+            .commands_submit_order
+            .keys()
+            .filter_map(|client_order_id| self.emulated_order(client_order_id))
+            .filter(|order| {
+                order.instrument_id() == command.instrument_id
+                    && order.strategy_id() == command.strategy_id
+                    && (command.order_side == OrderSide::NoOrderSide
+                        || order.order_side() == command.order_side)
+            })
+            .collect();
+
+        for order in &orders {
+            self.cancel_order(order);
+        }
+    }
+
+    // -- TRIGGERING ------------------------------------------------------------------------------
+
+    fn iterate_orders(&mut self, trigger_instrument_id: InstrumentId) {
+        let Some(matching_core) = self.matching_cores.get(&trigger_instrument_id) else {
+            return;
+        };
+        let client_order_ids: Vec<ClientOrderId> = matching_core
+            .get_orders_bid()
+            .iter()
+            .chain(matching_core.get_orders_ask())
+            .map(|order| order.client_order_id())
+            .collect();
+
+        for client_order_id in client_order_ids {
+            self.check_order(trigger_instrument_id, client_order_id);
+        }
+    }
+
+    fn check_order(&mut self, trigger_instrument_id: InstrumentId, client_order_id: ClientOrderId) {
+        let Some(mut order) = self.emulated_order(&client_order_id) else {
+            // Closed or released elsewhere
+            self.remove_order(&client_order_id);
+            return;
+        };
+
+        if matches!(
+            order.order_type(),
+            OrderType::TrailingStopMarket | OrderType::TrailingStopLimit
+        ) {
+            self.update_trailing_stop(trigger_instrument_id, &mut order);
+        }
+
+        let matching_core = &self.matching_cores[&trigger_instrument_id];
+        let release = match order.order_type() {
+            OrderType::Limit => matching_core
+                .is_limit_matched(&LimitOrderAny::from(order.clone()))
+                .then_some(OrderType::Market),
+            OrderType::StopMarket | OrderType::MarketIfTouched | OrderType::TrailingStopMarket => {
+                matching_core
+                    .is_stop_matched(&StopOrderAny::from(order.clone()))
+                    .then_some(OrderType::Market)
+            }
+            OrderType::StopLimit | OrderType::LimitIfTouched | OrderType::TrailingStopLimit => {
+                matching_core
+                    .is_stop_matched(&StopOrderAny::from(order.clone()))
+                    .then_some(OrderType::Limit)
+            }
+            _ => None,
+        };
+
+        if let Some(order_type) = release {
+            let released_price = match order.order_side_specified() {
+                OrderSideSpecified::Buy => matching_core.ask,
+                OrderSideSpecified::Sell => matching_core.bid,
+            }
+            .or(matching_core.last);
+            if let Err(e) = self.release_order(order, order_type, released_price) {
+                log::error!("Error releasing order {client_order_id}: {e}");
+            }
+        }
+    }
+
+    fn update_trailing_stop(&mut self, trigger_instrument_id: InstrumentId, order: &mut OrderAny) {
+        let matching_core = &self.matching_cores[&trigger_instrument_id];
+        let (trigger_price, price) = match trailing_stop_calculate(
+            matching_core.price_increment,
+            order,
+            matching_core.bid,
+            matching_core.ask,
+            matching_core.last,
+        ) {
+            Ok(prices) => prices,
+            Err(e) => {
+                log::debug!(
+                    "Cannot calculate trailing stop for {}: {e}",
+                    order.client_order_id()
+                );
+                return;
+            }
+        };
+        if trigger_price.is_none() && price.is_none() {
+            return;
+        }
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let event = OrderEventAny::Updated(OrderUpdated::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.quantity(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            None,
+            order.account_id(),
+            price,
+            trigger_price,
+        ));
+        if let Err(e) = self.apply_event(order, event) {
+            log::error!(
+                "Error updating trailing stop {}: {e}",
+                order.client_order_id()
+            );
+        }
+    }
+
+    fn release_order(
+        &mut self,
+        order: OrderAny,
+        order_type: OrderType,
+        released_price: Option<Price>,
+    ) -> anyhow::Result<()> {
+        let client_order_id = order.client_order_id();
+        let released_price = released_price
+            .or_else(|| order.trigger_price())
+            .or_else(|| order.price())
+            .ok_or_else(|| anyhow::anyhow!("No released price for {client_order_id}"))?;
+        let command = self
+            .commands_submit_order
+            .get(&client_order_id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No submit order command for {client_order_id}"))?;
+
+        let mut transformed = match order_type {
+            OrderType::Market => transform_to_market_order(&order)?,
+            _ => transform_to_limit_order(&order)?,
+        };
+        // Carry over the emulation history of the original order
+        for event in order.events() {
+            if let OrderEventAny::Emulated(_) = event {
+                transformed.apply(event.clone())?;
+            }
+        }
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let released = OrderEventAny::Released(OrderReleased::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            released_price,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        ));
+        transformed.apply(released.clone())?;
+        self.cache.borrow_mut().add_order(
+            transformed.clone(),
+            command.position_id,
+            Some(command.client_id),
+            true,
+        )?;
+        self.remove_order(&client_order_id);
+        self.publish_event(&released);
+
+        log::info!("Releasing {client_order_id} as {order_type} at {released_price}");
+        let submit = TradingCommand::SubmitOrder(SubmitOrder::new(
+            command.trader_id,
+            command.client_id,
+            command.strategy_id,
+            command.instrument_id,
+            client_order_id,
+            command.venue_order_id,
+            transformed.clone(),
+            transformed.exec_algorithm_id(),
+            command.position_id,
+            UUID4::new(),
+            ts_now,
+        )?);
+
+        let msgbus = self.msgbus.borrow();
+        let endpoint = match transformed.exec_algorithm_id() {
+            Some(exec_algorithm_id) => Ustr::from(&format!("{exec_algorithm_id}.execute")),
+            None => msgbus.switchboard.exec_engine_execute,
+        };
+        msgbus.send(&endpoint, &submit as &dyn Any);
+        Ok(())
+    }
+
+    fn cancel_order(&mut self, order: &OrderAny) {
+        let client_order_id = order.client_order_id();
+        self.remove_order(&client_order_id);
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let event = OrderEventAny::Canceled(OrderCanceled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            None,
+            order.account_id(),
+        ));
+        let mut order = order.clone();
+        if let Err(e) = self.apply_event(&mut order, event) {
+            log::error!("Error canceling order {client_order_id}: {e}");
+        }
+    }
+
+    // -- HELPERS ---------------------------------------------------------------------------------
+
+    /// Returns the cached order for `client_order_id` if it is currently emulated.
+    fn emulated_order(&self, client_order_id: &ClientOrderId) -> Option<OrderAny> {
+        self.cache
+            .borrow()
+            .order(client_order_id)
+            .filter(|order| order.status() == OrderStatus::Emulated)
+            .cloned()
+    }
+
+    fn trigger_instrument_id(&self, client_order_id: &ClientOrderId) -> Option<InstrumentId> {
+        self.matching_cores
+            .iter()
+            .find(|(_, matching_core)| matching_core.order_exists(*client_order_id))
+            .map(|(instrument_id, _)| *instrument_id)
+    }
+
+    fn remove_order(&mut self, client_order_id: &ClientOrderId) {
+        self.commands_submit_order.remove(client_order_id);
+        for matching_core in self.matching_cores.values_mut() {
+            let order = matching_core
+                .get_orders_bid()
+                .iter()
+                .chain(matching_core.get_orders_ask())
+                .find(|order| order.client_order_id() == *client_order_id)
+                .cloned();
+            if let Some(order) = order {
+                let _ = matching_core.delete_order(&order);
+            }
+        }
+    }
+
+    fn apply_event(&self, order: &mut OrderAny, event: OrderEventAny) -> anyhow::Result<()> {
+        order.apply(event.clone())?;
+        self.cache.borrow_mut().update_order(order)?;
+        self.publish_event(&event);
+        Ok(())
+    }
+
+    fn publish_event(&self, event: &OrderEventAny) {
+        let topic = Ustr::from(&format!("events.order.{}", event.strategy_id()));
+        self.msgbus.borrow().publish(&topic, event);
+    }
+}
+
+/// Transforms the given emulated `order` into a market order with the same identity.
+fn transform_to_market_order(order: &OrderAny) -> anyhow::Result<OrderAny> {
+    let time_in_force = match order.time_in_force() {
+        TimeInForce::Gtd => TimeInForce::Gtc,
+        time_in_force => time_in_force,
+    };
+    let market = MarketOrder::new_checked(
+        order.trader_id(),
+        order.strategy_id(),
+        order.instrument_id(),
+        order.client_order_id(),
+        order.order_side(),
+        order.quantity(),
+        time_in_force,
+        order.init_id(),
+        order.ts_init(),
+        order.is_reduce_only(),
+        order.is_quote_quantity(),
+        order.contingency_type(),
+        order.order_list_id(),
+        order.linked_order_ids(),
+        order.parent_order_id(),
+        order.exec_algorithm_id(),
+        order.exec_algorithm_params(),
+        order.exec_spawn_id(),
+        order.tags(),
+    )?;
+    Ok(OrderAny::Market(market))
+}
+
+/// Transforms the given emulated `order` into a limit order at its limit price with the same
+/// identity.
+fn transform_to_limit_order(order: &OrderAny) -> anyhow::Result<OrderAny> {
+    let price = order
+        .price()
+        .ok_or_else(|| anyhow::anyhow!("No limit price for {}", order.client_order_id()))?;
+    let limit = LimitOrder::new(
+        order.trader_id(),
+        order.strategy_id(),
+        order.instrument_id(),
+        order.client_order_id(),
+        order.order_side(),
+        order.quantity(),
+        price,
+        order.time_in_force(),
+        order.expire_time(),
+        order.is_post_only(),
+        order.is_reduce_only(),
+        order.is_quote_quantity(),
+        None,
+        None,
+        None,
+        order.contingency_type(),
+        order.order_list_id(),
+        order.linked_order_ids(),
+        order.parent_order_id(),
+        order.exec_algorithm_id(),
+        order.exec_algorithm_params(),
+        order.exec_spawn_id(),
+        order.tags(),
+        order.init_id(),
+        order.ts_init(),
+    )?;
+    Ok(OrderAny::Limit(limit))
+}
+
+/// Dispatches commands, quotes and trades from the message bus to an [`OrderEmulator`].
+pub struct OrderEmulatorHandler {
+    id: Ustr,
+    emulator: Rc<RefCell<OrderEmulator>>,
+}
+
+impl OrderEmulatorHandler {
+    /// Creates a new [`OrderEmulatorHandler`] instance.
+    pub fn new(emulator: Rc<RefCell<OrderEmulator>>) -> Self {
+        Self {
+            id: Ustr::from("OrderEmulator"),
+            emulator,
+        }
+    }
+}
+
+impl MessageHandler for OrderEmulatorHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        if let Some(command) = message.downcast_ref::<TradingCommand>() {
+            self.emulator.borrow_mut().execute(command.clone());
+        } else if let Some(quote) = message.downcast_ref::<QuoteTick>() {
+            self.emulator.borrow_mut().on_quote_tick(quote);
+        } else if let Some(trade) = message.downcast_ref::<TradeTick>() {
+            self.emulator.borrow_mut().on_trade_tick(trade);
+        }
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, _data: Data) {}
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Registers the `emulator` with the message bus, to receive commands on the order emulator
+/// endpoint along with all quotes and trades.
+pub fn register_order_emulator(
+    emulator: Rc<RefCell<OrderEmulator>>,
+    msgbus: &mut MessageBus,
+) -> ShareableMessageHandler {
+    let handler = ShareableMessageHandler(Rc::new(OrderEmulatorHandler::new(emulator)));
+    msgbus.register(msgbus.switchboard.order_emulator_execute, handler.clone());
+    msgbus.subscribe("data.quotes.*", handler.clone(), None);
+    msgbus.subscribe("data.trades.*", handler.clone(), None);
+    handler
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::{
+        clock::TestClock,
+        msgbus::stubs::{get_message_saving_handler, get_saved_messages},
+    };
+    use nautilus_model::{
+        enums::{AggressorSide, TrailingOffsetType},
+        identifiers::{ClientId, TradeId},
+        instruments::{stubs::audusd_sim, InstrumentAny},
+        orders::OrderTestBuilder,
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    struct TestContext {
+        emulator: OrderEmulator,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        command_handler: ShareableMessageHandler,
+        event_handler: ShareableMessageHandler,
+    }
+
+    fn get_context() -> TestContext {
+        let mut cache = Cache::default();
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim()))
+            .unwrap();
+        let cache = Rc::new(RefCell::new(cache));
+        let mut msgbus = MessageBus::default();
+        let command_handler = get_message_saving_handler::<TradingCommand>(None);
+        let event_handler = get_message_saving_handler::<OrderEventAny>(None);
+        msgbus.register(
+            msgbus.switchboard.exec_engine_execute,
+            command_handler.clone(),
+        );
+        msgbus.subscribe("events.order.*", event_handler.clone(), None);
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let emulator = OrderEmulator::new(
+            Rc::new(RefCell::new(TestClock::new())),
+            cache.clone(),
+            msgbus.clone(),
+        );
+        TestContext {
+            emulator,
+            cache,
+            msgbus,
+            command_handler,
+            event_handler,
+        }
+    }
+
+    fn emulated_order(
+        order_type: OrderType,
+        side: OrderSide,
+        price: Option<&str>,
+        trigger_price: Option<&str>,
+        emulation_trigger: TriggerType,
+    ) -> OrderAny {
+        let mut builder = OrderTestBuilder::new(order_type);
+        builder
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .client_order_id(ClientOrderId::from("O-1"))
+            .side(side)
+            .quantity(Quantity::from(100_000))
+            .emulation_trigger(emulation_trigger);
+        if let Some(price) = price {
+            builder.price(Price::from(price));
+        }
+        if let Some(trigger_price) = trigger_price {
+            builder.trigger_price(Price::from(trigger_price));
+        }
+        builder.build()
+    }
+
+    fn submit(order: &OrderAny) -> TradingCommand {
+        TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                order.trader_id(),
+                ClientId::from("SIM"),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                order.venue_order_id().unwrap_or_default(),
+                order.clone(),
+                None,
+                None,
+                UUID4::new(),
+                0.into(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn quote(bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from("AUD/USD.SIM"),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            0.into(),
+            0.into(),
+        )
+    }
+
+    fn released_orders(context: &TestContext) -> Vec<OrderAny> {
+        get_saved_messages::<TradingCommand>(context.command_handler.clone())
+            .into_iter()
+            .filter_map(|command| match command {
+                TradingCommand::SubmitOrder(command) => Some(command.order),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_submit_order_holds_order_locally() {
+        let mut context = get_context();
+        let order = emulated_order(
+            OrderType::StopMarket,
+            OrderSide::Buy,
+            None,
+            Some("0.80010"),
+            TriggerType::BidAsk,
+        );
+        let client_order_id = order.client_order_id();
+
+        context.emulator.execute(submit(&order));
+
+        let cache = context.cache.borrow();
+        assert!(context.emulator.is_emulating(&client_order_id));
+        assert!(cache.is_order_emulated(&client_order_id));
+        assert_eq!(
+            cache.order(&client_order_id).unwrap().status(),
+            OrderStatus::Emulated
+        );
+        let events = get_saved_messages::<OrderEventAny>(context.event_handler);
+        assert!(matches!(events[..], [OrderEventAny::Emulated(_)]));
+        assert!(released_orders(&context).is_empty());
+    }
+
+    #[rstest]
+    fn test_stop_market_released_as_market_order_when_triggered() {
+        let mut context = get_context();
+        let order = emulated_order(
+            OrderType::StopMarket,
+            OrderSide::Buy,
+            None,
+            Some("0.80010"),
+            TriggerType::BidAsk,
+        );
+        let client_order_id = order.client_order_id();
+        context.emulator.execute(submit(&order));
+
+        context.emulator.on_quote_tick(&quote("0.80000", "0.80005"));
+        assert!(released_orders(&context).is_empty());
+
+        context.emulator.on_quote_tick(&quote("0.80008", "0.80012"));
+
+        let released = released_orders(&context);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].order_type(), OrderType::Market);
+        assert_eq!(released[0].client_order_id(), client_order_id);
+        assert_eq!(released[0].status(), OrderStatus::Released);
+        assert!(!context.emulator.is_emulating(&client_order_id));
+        assert!(!context.cache.borrow().is_order_emulated(&client_order_id));
+        let events = get_saved_messages::<OrderEventAny>(context.event_handler);
+        let OrderEventAny::Released(released) = events.last().unwrap() else {
+            panic!("Expected released event, was {:?}", events.last());
+        };
+        assert_eq!(released.released_price, Price::from("0.80012"));
+    }
+
+    #[rstest]
+    fn test_stop_limit_released_as_limit_order_when_triggered() {
+        let mut context = get_context();
+        let order = emulated_order(
+            OrderType::StopLimit,
+            OrderSide::Sell,
+            Some("0.79990"),
+            Some("0.80000"),
+            TriggerType::BidAsk,
+        );
+        context.emulator.execute(submit(&order));
+
+        context.emulator.on_quote_tick(&quote("0.79995", "0.80000"));
+
+        let released = released_orders(&context);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].order_type(), OrderType::Limit);
+        assert_eq!(released[0].price(), Some(Price::from("0.79990")));
+        assert_eq!(released[0].emulation_trigger(), None);
+    }
+
+    #[rstest]
+    fn test_limit_released_as_market_order_when_crossed() {
+        let mut context = get_context();
+        let order = emulated_order(
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("0.80000"),
+            None,
+            TriggerType::BidAsk,
+        );
+        context.emulator.execute(submit(&order));
+
+        context.emulator.on_quote_tick(&quote("0.79995", "0.80000"));
+
+        let released = released_orders(&context);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].order_type(), OrderType::Market);
+    }
+
+    #[rstest]
+    fn test_last_price_trigger_released_on_trade() {
+        let mut context = get_context();
+        let order = emulated_order(
+            OrderType::StopMarket,
+            OrderSide::Sell,
+            None,
+            Some("0.80000"),
+            TriggerType::LastPrice,
+        );
+        context.emulator.execute(submit(&order));
+
+        let trade = TradeTick::new(
+            InstrumentId::from("AUD/USD.SIM"),
+            Price::from("0.79999"),
+            Quantity::from(1_000),
+            AggressorSide::Seller,
+            TradeId::from("T-1"),
+            0.into(),
+            0.into(),
+        );
+        context.emulator.on_trade_tick(&trade);
+
+        assert_eq!(released_orders(&context).len(), 1);
+    }
+
+    #[rstest]
+    fn test_cancel_order_cancels_emulated_order() {
+        let mut context = get_context();
+        let order = emulated_order(
+            OrderType::StopMarket,
+            OrderSide::Buy,
+            None,
+            Some("0.80010"),
+            TriggerType::BidAsk,
+        );
+        let client_order_id = order.client_order_id();
+        context.emulator.execute(submit(&order));
+
+        let cancel = CancelOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            order.venue_order_id().unwrap_or_default(),
+            UUID4::new(),
+            0.into(),
+        )
+        .unwrap();
+        context
+            .emulator
+            .execute(TradingCommand::CancelOrder(cancel));
+        context.emulator.on_quote_tick(&quote("0.80008", "0.80012"));
+
+        assert!(!context.emulator.is_emulating(&client_order_id));
+        assert_eq!(
+            context
+                .cache
+                .borrow()
+                .order(&client_order_id)
+                .unwrap()
+                .status(),
+            OrderStatus::Canceled
+        );
+        assert!(released_orders(&context).is_empty());
+    }
+
+    #[rstest]
+    fn test_modify_order_updates_trigger_price() {
+        let mut context = get_context();
+        let order = emulated_order(
+            OrderType::StopMarket,
+            OrderSide::Buy,
+            None,
+            Some("0.80010"),
+            TriggerType::BidAsk,
+        );
+        let client_order_id = order.client_order_id();
+        context.emulator.execute(submit(&order));
+        context.emulator.on_quote_tick(&quote("0.80000", "0.80005"));
+
+        let modify = ModifyOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            order.venue_order_id().unwrap_or_default(),
+            None,
+            None,
+            Some(Price::from("0.80005")),
+            UUID4::new(),
+            0.into(),
+        )
+        .unwrap();
+        context
+            .emulator
+            .execute(TradingCommand::ModifyOrder(modify));
+
+        // Modified trigger price is immediately triggered by the last quote
+        let released = released_orders(&context);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].client_order_id(), client_order_id);
+    }
+
+    #[rstest]
+    fn test_trailing_stop_market_trails_quotes_then_releases() {
+        let mut context = get_context();
+        let order = OrderTestBuilder::new(OrderType::TrailingStopMarket)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .client_order_id(ClientOrderId::from("O-1"))
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from(100_000))
+            .trigger_price(Price::from("0.79900"))
+            .trigger_type(TriggerType::BidAsk)
+            .trailing_offset(Price::from("0.00050"))
+            .trailing_offset_type(TrailingOffsetType::Price)
+            .emulation_trigger(TriggerType::BidAsk)
+            .build();
+        let client_order_id = order.client_order_id();
+        context.emulator.execute(submit(&order));
+
+        context.emulator.on_quote_tick(&quote("0.80000", "0.80005"));
+
+        assert_eq!(
+            context
+                .cache
+                .borrow()
+                .order(&client_order_id)
+                .unwrap()
+                .trigger_price(),
+            Some(Price::from("0.79950"))
+        );
+        assert!(released_orders(&context).is_empty());
+
+        context.emulator.on_quote_tick(&quote("0.79950", "0.79955"));
+
+        let released = released_orders(&context);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].order_type(), OrderType::Market);
+    }
+
+    #[rstest]
+    fn test_registered_emulator_receives_commands_and_quotes() {
+        let context = get_context();
+        let msgbus = context.msgbus.clone();
+        let emulator = Rc::new(RefCell::new(context.emulator));
+        register_order_emulator(emulator.clone(), &mut msgbus.borrow_mut());
+        let order = emulated_order(
+            OrderType::StopMarket,
+            OrderSide::Buy,
+            None,
+            Some("0.80010"),
+            TriggerType::Default,
+        );
+
+        let endpoint = msgbus.borrow().switchboard.order_emulator_execute;
+        msgbus.borrow().send(&endpoint, &submit(&order) as &dyn Any);
+        assert!(emulator.borrow().is_emulating(&order.client_order_id()));
+
+        let topic = Ustr::from("data.quotes.SIM.AUD/USD");
+        msgbus
+            .borrow()
+            .publish(&topic, &quote("0.80008", "0.80012"));
+
+        let commands = get_saved_messages::<TradingCommand>(context.command_handler);
+        assert_eq!(commands.len(), 1);
+    }
+}
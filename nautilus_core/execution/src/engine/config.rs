@@ -40,12 +40,45 @@ pub struct ExecutionEngineConfig {
     /// If debug mode is active (will provide extra debug logging)
     #[serde(default)]
     pub debug: bool,
+
+    /// If order and position state should be reconciled with the venue mass status
+    /// reports on startup
+    #[serde(default = "default_true")]
+    pub reconciliation: bool,
+
+    /// The maximum lookback (minutes) of the execution mass status requested for
+    /// reconciliation. If None then all available history is requested
+    #[serde(default)]
+    pub reconciliation_lookback_mins: Option<u32>,
+
+    /// If contingent order lists (OTO, OCO, OUO) are managed by the engine, for venues
+    /// which do not support contingencies natively
+    #[serde(default)]
+    pub manage_contingent_orders: bool,
+
+    /// The threshold (milliseconds) beyond which an order command without a venue response
+    /// is considered in flight too long, and the order status is queried from the venue
+    #[serde(default = "default_inflight_check_threshold_ms")]
+    pub inflight_check_threshold_ms: u64,
+
+    /// The interval (milliseconds) at which order commands in flight are checked against the
+    /// threshold once the engine is started. If zero then no checks are scheduled
+    #[serde(default = "default_inflight_check_interval_ms")]
+    pub inflight_check_interval_ms: u64,
 }
 
 const fn default_true() -> bool {
     true
 }
 
+const fn default_inflight_check_threshold_ms() -> u64 {
+    5_000
+}
+
+const fn default_inflight_check_interval_ms() -> u64 {
+    2_000
+}
+
 impl Default for ExecutionEngineConfig {
     fn default() -> Self {
         Self {
@@ -54,6 +87,11 @@ impl Default for ExecutionEngineConfig {
             snapshot_positions: false,
             snapshot_positions_interval_secs: None,
             debug: false,
+            reconciliation: true,
+            reconciliation_lookback_mins: None,
+            manage_contingent_orders: false,
+            inflight_check_threshold_ms: default_inflight_check_threshold_ms(),
+            inflight_check_interval_ms: default_inflight_check_interval_ms(),
         }
     }
 }
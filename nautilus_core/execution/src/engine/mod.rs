@@ -25,8 +25,13 @@
 #![allow(unused_variables)]
 
 pub mod config;
+pub mod routing;
+
+#[cfg(test)]
+mod tests;
 
 use std::{
+    any::Any,
     cell::RefCell,
     collections::{HashMap, HashSet},
     rc::Rc,
@@ -34,26 +39,55 @@ use std::{
 
 use config::ExecutionEngineConfig;
 use nautilus_common::{
-    cache::Cache, clock::Clock, generators::position_id::PositionIdGenerator, msgbus::MessageBus,
+    cache::Cache,
+    clock::Clock,
+    generators::position_id::PositionIdGenerator,
+    messages::{
+        execution::{
+            BatchCancelOrders, CancelAllOrders, CancelOrder, ModifyOrder, QueryOrder,
+            RoutingInstructions, SubmitOrder, SubmitOrderList, TradingCommand,
+        },
+        tracker::{RequestTimeoutCallback, RequestTracker},
+    },
+    msgbus::MessageBus,
+    timer::{TimeEvent, TimeEventCallback},
 };
+use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, nanos::UnixNanos, uuid::UUID4};
 use nautilus_model::{
-    enums::{OmsType, OrderSide},
-    events::{OrderEvent, OrderEventAny, OrderFilled},
-    identifiers::{ClientId, InstrumentId, PositionId, StrategyId, Venue},
+    enums::{
+        LiquiditySide, OmsType, OrderSide, OrderStatus, OrderType, PositionSide, PriceType,
+        TimeInForce, TriggerType,
+    },
+    events::{
+        OrderAccepted, OrderCanceled, OrderDenied, OrderEvent, OrderEventAny, OrderExpired,
+        OrderFilled, OrderRejected, OrderTriggered, OrderUpdated,
+    },
+    identifiers::{
+        ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, TradeId, Venue, VenueOrderId,
+    },
     instruments::InstrumentAny,
-    orders::OrderAny,
+    orders::{
+        LimitOrderBuilder, MarketOrderBuilder, OrderAny, StopLimitOrderBuilder,
+        StopMarketOrderBuilder,
+    },
     position::Position,
-    types::{Price, Quantity},
+    types::{Money, Price, Quantity},
 };
+use routing::RoutingPolicy;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use ustr::Ustr;
 
 use crate::{
     client::ExecutionClient,
-    messages::{
-        BatchCancelOrders, CancelAllOrders, CancelOrder, ModifyOrder, QueryOrder, SubmitOrder,
-        SubmitOrderList, TradingCommand,
+    contingency::ContingencyManager,
+    reports::{
+        fill::FillReport, mass_status::ExecutionMassStatus, order::OrderStatusReport,
+        position::PositionStatusReport,
     },
 };
 
+const INFLIGHT_CHECK_TIMER: &str = "ExecEngine_CHECK_INFLIGHT_ORDERS";
+
 pub struct ExecutionEngine {
     clock: Rc<RefCell<dyn Clock>>,
     cache: Rc<RefCell<Cache>>,
@@ -61,9 +95,15 @@ pub struct ExecutionEngine {
     clients: HashMap<ClientId, ExecutionClient>,
     default_client: Option<ExecutionClient>,
     routing_map: HashMap<Venue, ClientId>,
+    routing_policy: Option<Box<dyn RoutingPolicy>>,
     oms_overrides: HashMap<StrategyId, OmsType>,
     external_order_claims: HashMap<InstrumentId, StrategyId>,
     pos_id_generator: PositionIdGenerator,
+    contingency_managers: HashMap<ClientId, RefCell<ContingencyManager>>,
+    pending_mass_statuses: HashSet<ClientId>,
+    mass_statuses: Vec<ExecutionMassStatus>,
+    inflight_tracker: Rc<RefCell<RequestTracker>>,
+    inflight_orders: Rc<RefCell<HashMap<ClientOrderId, UUID4>>>,
     config: ExecutionEngineConfig,
 }
 
@@ -82,9 +122,15 @@ impl ExecutionEngine {
             clients: HashMap::new(),
             default_client: None,
             routing_map: HashMap::new(),
+            routing_policy: None,
             oms_overrides: HashMap::new(),
             external_order_claims: HashMap::new(),
             pos_id_generator: PositionIdGenerator::new(trader_id, clock),
+            contingency_managers: HashMap::new(),
+            pending_mass_statuses: HashSet::new(),
+            mass_statuses: Vec::new(),
+            inflight_tracker: Rc::new(RefCell::new(RequestTracker::new())),
+            inflight_orders: Rc::new(RefCell::new(HashMap::new())),
             config,
         }
     }
@@ -130,15 +176,32 @@ impl ExecutionEngine {
         self.routing_map.insert(client.venue, client.client_id);
 
         log::info!("Registered client {}", client.client_id);
+        self.register_contingency_manager(client.client_id);
         self.clients.insert(client.client_id, client);
         Ok(())
     }
 
     pub fn register_default_client(&mut self, client: ExecutionClient) {
         log::info!("Registered default client {}", client.client_id);
+        self.register_contingency_manager(client.client_id);
         self.default_client = Some(client);
     }
 
+    fn register_contingency_manager(&mut self, client_id: ClientId) {
+        if !self.config.manage_contingent_orders {
+            return;
+        }
+
+        let manager = ContingencyManager::new(
+            client_id,
+            self.clock.clone(),
+            self.cache.clone(),
+            self.msgbus.clone(),
+        );
+        self.contingency_managers
+            .insert(client_id, RefCell::new(manager));
+    }
+
     pub fn register_venue_routing(
         &mut self,
         client_id: ClientId,
@@ -153,6 +216,20 @@ impl ExecutionEngine {
         Ok(())
     }
 
+    /// Registers an OMS type override for the positions of the given `strategy_id`.
+    ///
+    /// An `OmsType::Unspecified` override defers to the OMS type of the venue client.
+    pub fn register_oms_type(&mut self, strategy_id: StrategyId, oms_type: OmsType) {
+        self.oms_overrides.insert(strategy_id, oms_type);
+        log::info!("Registered OMS::{oms_type} for {strategy_id}");
+    }
+
+    /// Sets the policy used to route orders for instruments tradable on multiple venues.
+    pub fn set_routing_policy(&mut self, policy: Box<dyn RoutingPolicy>) {
+        log::info!("Set routing policy {}", policy.name());
+        self.routing_policy = Some(policy);
+    }
+
     // TODO: Implement `Strategy`
     // pub fn register_external_order_claims(&mut self, strategy: Strategy) -> anyhow::Result<()> {
     //     todo!();
@@ -160,6 +237,7 @@ impl ExecutionEngine {
 
     pub fn deregister_client(&mut self, client_id: ClientId) -> anyhow::Result<()> {
         if self.clients.remove(&client_id).is_some() {
+            self.contingency_managers.remove(&client_id);
             // Remove from routing map if present
             self.routing_map
                 .retain(|_, mapped_id| mapped_id != &client_id);
@@ -180,8 +258,710 @@ impl ExecutionEngine {
         todo!();
     }
 
-    pub fn process(&self, event: &OrderEventAny) {
-        todo!()
+    /// Executes the given trading `command` by routing it to the relevant execution client.
+    pub fn execute(&self, command: TradingCommand) {
+        self.execute_command(command);
+    }
+
+    /// Processes the given order `event`, applying it to the cached order (and any position
+    /// for fills) before publishing it to the owning strategy.
+    pub fn process(&mut self, event: &OrderEventAny) {
+        self.handle_event(event.clone());
+    }
+
+    /// Checks for order commands which have been in flight beyond the configured threshold
+    /// without a venue response, querying the status of each order from its client.
+    ///
+    /// Returns the correlation IDs of the timed out inflight commands.
+    pub fn check_inflight_orders(&self) -> Vec<UUID4> {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        check_inflight_timeouts(&self.inflight_tracker, &self.inflight_orders, ts_now)
+    }
+
+    /// Returns the number of orders with a command in flight awaiting a venue response.
+    #[must_use]
+    pub fn inflight_orders_count(&self) -> usize {
+        self.inflight_orders.borrow().len()
+    }
+
+    // -- RECONCILIATION ------------------------------------------------------
+
+    /// Starts the engine, requesting an execution mass status from each registered client so
+    /// the cached state can be reconciled with the venues before trading.
+    ///
+    /// Clients respond through the `ExecEngine.reconcile_mass_status` endpoint, which must
+    /// be handled by calling [`ExecutionEngine::handle_mass_status`].
+    pub fn start(&mut self) {
+        if self.config.inflight_check_interval_ms > 0 {
+            self.set_inflight_check_timer();
+        }
+
+        if !self.config.reconciliation {
+            log::warn!("Reconciliation deactivated");
+            return;
+        }
+
+        let default_client = self
+            .default_client
+            .as_ref()
+            .filter(|client| !self.clients.contains_key(&client.client_id));
+        for client in self.clients.values().chain(default_client) {
+            match client.request_mass_status(self.config.reconciliation_lookback_mins) {
+                Ok(()) => {
+                    self.pending_mass_statuses.insert(client.client_id);
+                }
+                Err(e) => log::error!("Cannot request mass status from {}: {e}", client.client_id),
+            }
+        }
+    }
+
+    /// Sets a timer on the engine clock which checks the inflight order commands at the
+    /// configured interval.
+    fn set_inflight_check_timer(&self) {
+        let tracker = self.inflight_tracker.clone();
+        let orders = self.inflight_orders.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            check_inflight_timeouts(&tracker, &orders, event.ts_event);
+        }));
+
+        let mut clock = self.clock.borrow_mut();
+        let interval_ns = self.config.inflight_check_interval_ms * NANOSECONDS_IN_MILLISECOND;
+        let start_time_ns = clock.timestamp_ns();
+        if let Err(e) = clock.set_timer_ns(
+            INFLIGHT_CHECK_TIMER,
+            interval_ns,
+            start_time_ns,
+            None,
+            Some(callback),
+        ) {
+            log::error!("Cannot set inflight check timer: {e}");
+        }
+    }
+
+    /// Handles the `mass_status` reported by an execution client, reconciling the state with
+    /// all requested mass statuses once every client has responded.
+    pub fn handle_mass_status(&mut self, mass_status: ExecutionMassStatus) {
+        if !self.pending_mass_statuses.remove(&mass_status.client_id) {
+            // Not requested on startup, so reconcile on its own
+            self.reconcile_mass_status(&mass_status);
+            return;
+        }
+
+        self.mass_statuses.push(mass_status);
+        if self.pending_mass_statuses.is_empty() {
+            let mass_statuses = std::mem::take(&mut self.mass_statuses);
+            self.reconcile_state(&mass_statuses);
+        }
+    }
+
+    /// Reconciles the cached order and position state with the given venue `mass_statuses`,
+    /// as reported by the execution clients on startup.
+    ///
+    /// Returns whether all reports were reconciled (always `true` if reconciliation is disabled).
+    pub fn reconcile_state(&mut self, mass_statuses: &[ExecutionMassStatus]) -> bool {
+        if !self.config.reconciliation {
+            log::warn!("Reconciliation deactivated");
+            return true;
+        }
+
+        mass_statuses.iter().fold(true, |reconciled, mass_status| {
+            self.reconcile_mass_status(mass_status) && reconciled
+        })
+    }
+
+    /// Reconciles the cached order and position state with the given `mass_status`, generating
+    /// the events required for the cached orders to converge to their reported state.
+    ///
+    /// Returns whether all reports were reconciled.
+    pub fn reconcile_mass_status(&mut self, mass_status: &ExecutionMassStatus) -> bool {
+        log::info!(
+            "Reconciling state for {} with {} order report(s), {} position report(s)",
+            mass_status.venue,
+            mass_status.order_reports().len(),
+            mass_status.position_reports().len(),
+        );
+
+        // Reconcile in acceptance order so dependent events are generated deterministically
+        let mut order_reports: Vec<&OrderStatusReport> =
+            mass_status.order_reports().values().collect();
+        order_reports.sort_by_key(|report| (report.ts_accepted, report.ts_last));
+
+        let mut reconciled = true;
+        for report in order_reports {
+            let fills = mass_status
+                .fill_reports()
+                .get(&report.venue_order_id)
+                .map_or(&[][..], Vec::as_slice);
+            reconciled &= self.reconcile_order_report(report, fills);
+        }
+
+        for report in mass_status.position_reports().values().flatten() {
+            reconciled &= self.reconcile_position_report(report);
+        }
+
+        if reconciled {
+            log::info!("Reconciled state for {}", mass_status.venue);
+        } else {
+            log::error!("Failed to reconcile state for {}", mass_status.venue);
+        }
+        reconciled
+    }
+
+    /// Reconciles the cached order for the given `report` with its reported state, applying
+    /// the reported `fills` and inferring a fill for any remaining filled quantity gap.
+    ///
+    /// An external order is generated for a venue order which is not known to the system.
+    pub fn reconcile_order_report(
+        &mut self,
+        report: &OrderStatusReport,
+        fills: &[FillReport],
+    ) -> bool {
+        let Some(instrument) = self
+            .cache
+            .borrow()
+            .instrument(&report.instrument_id)
+            .cloned()
+        else {
+            log::error!(
+                "Cannot reconcile {}: no instrument found for {}",
+                report.venue_order_id,
+                report.instrument_id
+            );
+            return false;
+        };
+        let mut order = match self.order_for_report(report) {
+            Some(order) => order,
+            None => match self.generate_external_order(report) {
+                Ok(order) => order,
+                Err(e) => {
+                    log::error!(
+                        "Cannot reconcile external order {}: {e}",
+                        report.venue_order_id
+                    );
+                    return false;
+                }
+            },
+        };
+
+        if order.status() == report.order_status && order.filled_qty() == report.filled_qty {
+            return true; // Already reconciled
+        }
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+
+        if report.order_status == OrderStatus::Rejected {
+            if order.status() != OrderStatus::Rejected {
+                let reason = report.cancel_reason.as_deref().unwrap_or("UNKNOWN");
+                self.process(&OrderEventAny::Rejected(OrderRejected::new(
+                    order.trader_id(),
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    order.client_order_id(),
+                    report.account_id,
+                    Ustr::from(reason),
+                    UUID4::new(),
+                    report.ts_last,
+                    ts_now,
+                    true,
+                )));
+            }
+            return true;
+        }
+
+        if matches!(
+            order.status(),
+            OrderStatus::Initialized | OrderStatus::Submitted
+        ) {
+            self.process(&OrderEventAny::Accepted(OrderAccepted::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                report.venue_order_id,
+                report.account_id,
+                UUID4::new(),
+                report.ts_accepted,
+                ts_now,
+                true,
+            )));
+            order = self.refresh_order(order);
+        }
+
+        if report.order_status == OrderStatus::Triggered && order.is_triggered() == Some(false) {
+            self.process(&OrderEventAny::Triggered(OrderTriggered::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                UUID4::new(),
+                report.ts_triggered.unwrap_or(report.ts_last),
+                ts_now,
+                true,
+                Some(report.venue_order_id),
+                Some(report.account_id),
+            )));
+            order = self.refresh_order(order);
+        }
+
+        // Apply reported fills not yet applied to the order
+        let mut fills: Vec<&FillReport> = fills.iter().collect();
+        fills.sort_by_key(|fill| fill.ts_event);
+        for fill in fills {
+            if order.trade_ids().contains(&&fill.trade_id) {
+                continue;
+            }
+            if fill.last_qty > order.leaves_qty() {
+                log::error!(
+                    "Cannot apply fill {} to {}: quantity {} exceeds leaves quantity {}",
+                    fill.trade_id,
+                    order.client_order_id(),
+                    fill.last_qty,
+                    order.leaves_qty()
+                );
+                return false;
+            }
+            self.process(&OrderEventAny::Filled(OrderFilled::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                report.venue_order_id,
+                report.account_id,
+                fill.trade_id,
+                order.order_side(),
+                order.order_type(),
+                fill.last_qty,
+                fill.last_px,
+                instrument.quote_currency(),
+                fill.liquidity_side,
+                UUID4::new(),
+                fill.ts_event,
+                ts_now,
+                true,
+                None,
+                Some(fill.commission),
+            )));
+            order = self.refresh_order(order);
+        }
+
+        // Infer a fill for any filled quantity without a fill report
+        if report.filled_qty > order.filled_qty() {
+            let Some(fill) = self.generate_inferred_fill(&order, report, &instrument, ts_now)
+            else {
+                return false;
+            };
+            self.process(&OrderEventAny::Filled(fill));
+            order = self.refresh_order(order);
+        } else if report.filled_qty < order.filled_qty() {
+            log::error!(
+                "Cannot reconcile {}: reported filled quantity {} is less than cached {}",
+                order.client_order_id(),
+                report.filled_qty,
+                order.filled_qty()
+            );
+            return false;
+        }
+
+        if order.is_open()
+            && (report.quantity != order.quantity()
+                || report
+                    .price
+                    .is_some_and(|price| Some(price) != order.price())
+                || report
+                    .trigger_price
+                    .is_some_and(|trigger_price| Some(trigger_price) != order.trigger_price()))
+        {
+            self.process(&OrderEventAny::Updated(OrderUpdated::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                report.quantity,
+                UUID4::new(),
+                report.ts_last,
+                ts_now,
+                true,
+                Some(report.venue_order_id),
+                Some(report.account_id),
+                report.price,
+                report.trigger_price,
+            )));
+            order = self.refresh_order(order);
+        }
+
+        if order.is_open() {
+            match report.order_status {
+                OrderStatus::Canceled => {
+                    self.process(&OrderEventAny::Canceled(OrderCanceled::new(
+                        order.trader_id(),
+                        order.strategy_id(),
+                        order.instrument_id(),
+                        order.client_order_id(),
+                        UUID4::new(),
+                        report.ts_last,
+                        ts_now,
+                        true,
+                        Some(report.venue_order_id),
+                        Some(report.account_id),
+                    )));
+                }
+                OrderStatus::Expired => {
+                    self.process(&OrderEventAny::Expired(OrderExpired::new(
+                        order.trader_id(),
+                        order.strategy_id(),
+                        order.instrument_id(),
+                        order.client_order_id(),
+                        UUID4::new(),
+                        report.ts_last,
+                        ts_now,
+                        true,
+                        Some(report.venue_order_id),
+                        Some(report.account_id),
+                    )));
+                }
+                _ => {}
+            }
+        }
+
+        true
+    }
+
+    /// Reconciles the cached position state for the given `report`, inferring a fill for an
+    /// external market order to close any gap with the reported signed quantity.
+    pub fn reconcile_position_report(&mut self, report: &PositionStatusReport) -> bool {
+        let cached_qty: Decimal = {
+            let cache = self.cache.borrow();
+            match report.venue_position_id {
+                // Hedging positions are reconciled individually
+                Some(position_id) => cache
+                    .position(&position_id)
+                    .map_or(Decimal::ZERO, signed_decimal_qty),
+                None => cache
+                    .positions_open(None, Some(&report.instrument_id), None, None)
+                    .into_iter()
+                    .map(signed_decimal_qty)
+                    .sum(),
+            }
+        };
+
+        let gap = report.signed_decimal_qty - cached_qty;
+        if gap.is_zero() {
+            return true;
+        }
+
+        log::warn!(
+            "Reconciling {} position: cached signed quantity {cached_qty} did not match reported {}",
+            report.instrument_id,
+            report.signed_decimal_qty
+        );
+        self.generate_position_gap_fill(report, gap)
+    }
+
+    /// Generates an external market order filled for the `gap` between the cached and reported
+    /// position quantity, priced from the latest cached market price for the instrument.
+    fn generate_position_gap_fill(&mut self, report: &PositionStatusReport, gap: Decimal) -> bool {
+        let Some(instrument) = self
+            .cache
+            .borrow()
+            .instrument(&report.instrument_id)
+            .cloned()
+        else {
+            log::error!(
+                "Cannot reconcile {} position: instrument not found",
+                report.instrument_id
+            );
+            return false;
+        };
+        let last_px = {
+            let cache = self.cache.borrow();
+            cache
+                .price(&report.instrument_id, PriceType::Last)
+                .or_else(|| cache.price(&report.instrument_id, PriceType::Mid))
+        };
+        let Some(last_px) = last_px else {
+            log::error!(
+                "Cannot reconcile {} position: no market price to infer a fill",
+                report.instrument_id
+            );
+            return false;
+        };
+
+        let order_side = if gap.is_sign_positive() {
+            OrderSide::Buy
+        } else {
+            OrderSide::Sell
+        };
+        let last_qty = Quantity::new(
+            gap.abs().to_f64().unwrap_or_default(),
+            instrument.size_precision(),
+        );
+        let venue_order_id = VenueOrderId::new(UUID4::new().to_string());
+        let order_report = OrderStatusReport::new(
+            report.account_id,
+            report.instrument_id,
+            venue_order_id,
+            order_side,
+            OrderType::Market,
+            TimeInForce::Gtc,
+            OrderStatus::Accepted,
+            last_qty,
+            Quantity::zero(instrument.size_precision()),
+            UUID4::new(),
+            report.ts_last,
+            report.ts_last,
+            report.ts_init,
+        );
+        let mut order = match self.generate_external_order(&order_report) {
+            Ok(order) => order,
+            Err(e) => {
+                log::error!("Cannot reconcile {} position: {e}", report.instrument_id);
+                return false;
+            }
+        };
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        self.process(&OrderEventAny::Accepted(OrderAccepted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            venue_order_id,
+            report.account_id,
+            UUID4::new(),
+            report.ts_last,
+            ts_now,
+            true,
+        )));
+        order = self.refresh_order(order);
+
+        let liquidity_side = LiquiditySide::Taker;
+        self.process(&OrderEventAny::Filled(OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            venue_order_id,
+            report.account_id,
+            TradeId::new(UUID4::new().to_string()),
+            order_side,
+            OrderType::Market,
+            last_qty,
+            last_px,
+            instrument.quote_currency(),
+            liquidity_side,
+            UUID4::new(),
+            report.ts_last,
+            ts_now,
+            true,
+            report.venue_position_id,
+            Some(inferred_commission(
+                &instrument,
+                last_qty,
+                last_px,
+                liquidity_side,
+            )),
+        )));
+        true
+    }
+
+    /// Generates and caches an order for the venue order in the `report` which is not known
+    /// to the system, owned by the strategy claiming its instrument (otherwise `EXTERNAL`).
+    fn generate_external_order(&self, report: &OrderStatusReport) -> anyhow::Result<OrderAny> {
+        let trader_id = self.msgbus.borrow().trader_id;
+        let strategy_id = self
+            .external_order_claims
+            .get(&report.instrument_id)
+            .copied()
+            .unwrap_or_else(StrategyId::external);
+        let client_order_id = report
+            .client_order_id
+            .unwrap_or_else(|| ClientOrderId::from(report.venue_order_id.as_str()));
+        let ts_init = self.clock.borrow().timestamp_ns();
+        let missing =
+            |field: &str| anyhow::anyhow!("no {field} reported for {} order", report.order_type);
+        let expire_time = report
+            .expire_time
+            .filter(|_| report.time_in_force == TimeInForce::Gtd);
+
+        let order = match report.order_type {
+            OrderType::Market => OrderAny::Market(
+                MarketOrderBuilder::new(
+                    trader_id,
+                    strategy_id,
+                    report.instrument_id,
+                    client_order_id,
+                    report.order_side,
+                    report.quantity,
+                )
+                .time_in_force(report.time_in_force)
+                .reduce_only(report.reduce_only)
+                .ts_init(ts_init)
+                .build()?,
+            ),
+            OrderType::Limit => {
+                let mut builder = LimitOrderBuilder::new(
+                    trader_id,
+                    strategy_id,
+                    report.instrument_id,
+                    client_order_id,
+                    report.order_side,
+                    report.quantity,
+                )
+                .price(report.price.ok_or_else(|| missing("price"))?)
+                .time_in_force(report.time_in_force)
+                .post_only(report.post_only)
+                .reduce_only(report.reduce_only)
+                .ts_init(ts_init);
+                if let Some(expire_time) = expire_time {
+                    builder = builder.expire_time(expire_time);
+                }
+                if let Some(display_qty) = report.display_qty {
+                    builder = builder.display_qty(display_qty);
+                }
+                OrderAny::Limit(builder.build()?)
+            }
+            OrderType::StopMarket => {
+                let mut builder = StopMarketOrderBuilder::new(
+                    trader_id,
+                    strategy_id,
+                    report.instrument_id,
+                    client_order_id,
+                    report.order_side,
+                    report.quantity,
+                )
+                .trigger_price(
+                    report
+                        .trigger_price
+                        .ok_or_else(|| missing("trigger price"))?,
+                )
+                .trigger_type(report.trigger_type.unwrap_or(TriggerType::Default))
+                .time_in_force(report.time_in_force)
+                .reduce_only(report.reduce_only)
+                .ts_init(ts_init);
+                if let Some(expire_time) = expire_time {
+                    builder = builder.expire_time(expire_time);
+                }
+                OrderAny::StopMarket(builder.build()?)
+            }
+            OrderType::StopLimit => {
+                let mut builder = StopLimitOrderBuilder::new(
+                    trader_id,
+                    strategy_id,
+                    report.instrument_id,
+                    client_order_id,
+                    report.order_side,
+                    report.quantity,
+                )
+                .price(report.price.ok_or_else(|| missing("price"))?)
+                .trigger_price(
+                    report
+                        .trigger_price
+                        .ok_or_else(|| missing("trigger price"))?,
+                )
+                .trigger_type(report.trigger_type.unwrap_or(TriggerType::Default))
+                .time_in_force(report.time_in_force)
+                .post_only(report.post_only)
+                .reduce_only(report.reduce_only)
+                .ts_init(ts_init);
+                if let Some(expire_time) = expire_time {
+                    builder = builder.expire_time(expire_time);
+                }
+                if let Some(display_qty) = report.display_qty {
+                    builder = builder.display_qty(display_qty);
+                }
+                OrderAny::StopLimit(builder.build()?)
+            }
+            order_type => anyhow::bail!("external {order_type} orders are not supported"),
+        };
+
+        self.cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)?;
+        log::info!(
+            "Generated external order {client_order_id} for {} ({strategy_id})",
+            report.venue_order_id
+        );
+        Ok(order)
+    }
+
+    fn order_for_report(&self, report: &OrderStatusReport) -> Option<OrderAny> {
+        let cache = self.cache.borrow();
+        let client_order_id = report
+            .client_order_id
+            .or_else(|| cache.client_order_id(&report.venue_order_id).copied())?;
+        cache.order(&client_order_id).cloned()
+    }
+
+    fn refresh_order(&self, order: OrderAny) -> OrderAny {
+        self.cache
+            .borrow()
+            .order(&order.client_order_id())
+            .cloned()
+            .unwrap_or(order)
+    }
+
+    /// Generates a fill for the filled quantity gap between the `order` and its `report`,
+    /// pricing it from the reported average price where available.
+    fn generate_inferred_fill(
+        &self,
+        order: &OrderAny,
+        report: &OrderStatusReport,
+        instrument: &InstrumentAny,
+        ts_now: UnixNanos,
+    ) -> Option<OrderFilled> {
+        let last_qty = report.filled_qty - order.filled_qty();
+        let last_px = match (
+            report.avg_px.and_then(|avg_px| avg_px.to_f64()),
+            order.avg_px(),
+        ) {
+            (Some(report_avg_px), Some(order_avg_px)) => {
+                let value = report_avg_px.mul_add(
+                    report.filled_qty.as_f64(),
+                    -order_avg_px * order.filled_qty().as_f64(),
+                ) / last_qty.as_f64();
+                Some(Price::new(value, instrument.price_precision()))
+            }
+            (Some(report_avg_px), None) => {
+                Some(Price::new(report_avg_px, instrument.price_precision()))
+            }
+            (None, _) => report.price.or(order.price()),
+        };
+        let Some(last_px) = last_px else {
+            log::error!(
+                "Cannot infer fill for {}: no average or limit price reported",
+                order.client_order_id()
+            );
+            return None;
+        };
+
+        let liquidity_side = inferred_liquidity_side(report);
+        let commission = inferred_commission(instrument, last_qty, last_px, liquidity_side);
+        log::warn!(
+            "Inferred fill for {}: {last_qty} @ {last_px}",
+            order.client_order_id()
+        );
+
+        Some(OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.venue_order_id,
+            report.account_id,
+            TradeId::new(UUID4::new().to_string()),
+            order.order_side(),
+            order.order_type(),
+            last_qty,
+            last_px,
+            instrument.quote_currency(),
+            liquidity_side,
+            UUID4::new(),
+            report.ts_last,
+            ts_now,
+            true,
+            None,
+            Some(commission),
+        ))
     }
 
     // -- COMMAND HANDLERS ----------------------------------------------------
@@ -189,6 +969,14 @@ impl ExecutionEngine {
     fn execute_command(&self, command: TradingCommand) {
         log::debug!("<--[CMD] {command:?}"); // TODO: Log constants
 
+        let command = match command {
+            TradingCommand::SubmitOrder(cmd) => match self.route_submit_order(cmd) {
+                Some(cmd) => TradingCommand::SubmitOrder(cmd),
+                None => return, // Order denied
+            },
+            command => command,
+        };
+
         let client = self
             .clients
             .get(&command.client_id())
@@ -211,6 +999,132 @@ impl ExecutionEngine {
         }
     }
 
+    /// Returns the candidate venues on which the symbol of `instrument_id` is tradable
+    /// through a registered execution client, sorted for deterministic routing.
+    fn routing_venues(&self, instrument_id: &InstrumentId) -> Vec<Venue> {
+        let cache = self.cache.borrow();
+        let mut venues: Vec<Venue> = self
+            .routing_map
+            .keys()
+            .filter(|venue| {
+                cache
+                    .instrument(&InstrumentId::new(instrument_id.symbol, **venue))
+                    .is_some()
+            })
+            .copied()
+            .collect();
+        venues.sort();
+        venues
+    }
+
+    /// Applies the routing decision for the submit `command`, directing it to the client for
+    /// the selected venue with the order instrument ID rewritten for that venue.
+    ///
+    /// Returns `None` if the order could not be routed, in which case it is denied.
+    fn route_submit_order(&self, mut command: SubmitOrder) -> Option<SubmitOrder> {
+        let venue = match self.route_order(&command.order, command.routing.as_ref()) {
+            Ok(Some(venue)) => venue,
+            Ok(None) => return Some(command),
+            Err(reason) => {
+                self.deny_order(&command.order, &reason);
+                return None;
+            }
+        };
+
+        let Some(client_id) = self.routing_map.get(&venue).copied() else {
+            self.deny_order(
+                &command.order,
+                &format!("no execution client registered for venue {venue}"),
+            );
+            return None;
+        };
+
+        let instrument_id = InstrumentId::new(command.instrument_id.symbol, venue);
+        if instrument_id != command.instrument_id {
+            if self.cache.borrow().instrument(&instrument_id).is_none() {
+                self.deny_order(
+                    &command.order,
+                    &format!("no instrument {instrument_id} to route to"),
+                );
+                return None;
+            }
+
+            command.instrument_id = instrument_id;
+            command.order.set_instrument_id(instrument_id);
+
+            // The order is re-indexed under the routed venue and instrument if already cached
+            let mut cache = self.cache.borrow_mut();
+            if cache.order_exists(&command.client_order_id) {
+                if let Err(e) = cache.update_order_routing(&command.order, Some(client_id)) {
+                    log::error!("Error updating routed order in cache: {e}");
+                }
+            }
+        }
+
+        command.client_id = client_id;
+        Some(command)
+    }
+
+    /// Returns the venue to route the `order` to, if determined by the given `instructions`
+    /// or the engines routing policy, otherwise `None` when no routing decision is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns the denial reason if the instructions cannot be satisfied, such as an explicit
+    /// venue without a registered execution client, or every candidate venue being excluded.
+    fn route_order(
+        &self,
+        order: &OrderAny,
+        instructions: Option<&RoutingInstructions>,
+    ) -> Result<Option<Venue>, String> {
+        if let Some(venue) = instructions.and_then(|i| i.venue) {
+            if !self.routing_map.contains_key(&venue) {
+                return Err(format!("no execution client registered for venue {venue}"));
+            }
+            return Ok(Some(venue));
+        }
+
+        let excluded = instructions.map_or(&[][..], |i| i.excluded_venues.as_slice());
+
+        let Some(policy) = self.routing_policy.as_ref() else {
+            let venue = order.instrument_id().venue;
+            if excluded.contains(&venue) {
+                return Err(format!("venue {venue} excluded with no routing policy"));
+            }
+            return Ok(None);
+        };
+
+        let mut venues = self.routing_venues(&order.instrument_id());
+        venues.retain(|venue| !excluded.contains(venue));
+
+        if venues.len() < 2 && excluded.is_empty() {
+            return Ok(None); // No routing decision required
+        }
+
+        match policy.select_venue(order, &venues, &self.cache.borrow()) {
+            // The candidates are registered and not excluded, so any other selection is invalid
+            Some(venue) if !venues.contains(&venue) => Err(format!(
+                "venue {venue} selected by {} is not a routing candidate for {}",
+                policy.name(),
+                order.instrument_id()
+            )),
+            Some(venue) => {
+                log::debug!(
+                    "Routed {} to {venue} using {}",
+                    order.client_order_id(),
+                    policy.name()
+                );
+                Ok(Some(venue))
+            }
+            None if excluded.is_empty() => Ok(None),
+            None => Err(format!(
+                "no venue for {} selected by {} after exclusions",
+                order.instrument_id(),
+                policy.name()
+            )),
+        }
+    }
+
     fn handle_submit_order(&self, client: &ExecutionClient, command: SubmitOrder) {
         let order = &command.order;
 
@@ -231,17 +1145,19 @@ impl ExecutionEngine {
             }
         }
 
-        let instrument =
-            if let Some(instrument) = self.cache.borrow().instrument(&order.instrument_id()) {
-                instrument
-            } else {
-                log::error!(
-                    "Cannot handle submit order: no instrument found for {}, {}",
-                    order.instrument_id(),
-                    &command
-                );
-                return;
-            };
+        if self
+            .cache
+            .borrow()
+            .instrument(&order.instrument_id())
+            .is_none()
+        {
+            log::error!(
+                "Cannot handle submit order: no instrument found for {}, {}",
+                order.instrument_id(),
+                &command
+            );
+            return;
+        }
 
         // Handle quote quantity conversion
         // TODO: implemnent is_quote_quantity
@@ -261,16 +1177,26 @@ impl ExecutionEngine {
         //     self.set_order_base_qty(&order, base_qty);
         // }
 
-        // // Send to execution client
-        // client.submit_order(command);
+        // Hold OTO children until their parent fills
+        if let Some(manager) = self.contingency_managers.get(&client.client_id) {
+            if manager.borrow_mut().hold_order(order) {
+                return;
+            }
+        }
+
+        // Send to execution client
+        let client_order_id = command.client_order_id;
+        self.track_inflight(client, client_order_id);
+        if let Err(e) = client.submit_order(command) {
+            self.untrack_inflight(&client_order_id);
+            log::error!("Error submitting order to client: {e}");
+        }
     }
 
     pub fn handle_submit_order_list(&self, client: &ExecutionClient, command: SubmitOrderList) {
-        let mut cache = self.cache.borrow_mut();
-
         for order in &command.order_list.orders {
-            if !cache.order_exists(&order.client_order_id()) {
-                if let Err(e) = cache.add_order(
+            if !self.cache.borrow().order_exists(&order.client_order_id()) {
+                if let Err(e) = self.cache.borrow_mut().add_order(
                     order.clone(),
                     command.position_id,
                     Some(command.client_id),
@@ -285,32 +1211,168 @@ impl ExecutionEngine {
             }
         }
 
+        // Submit the orders individually, holding OTO children until their parent fills
+        if let Some(manager) = self.contingency_managers.get(&client.client_id) {
+            for order in command.order_list.orders {
+                if manager.borrow_mut().hold_order(&order) {
+                    continue;
+                }
+                let submit = SubmitOrder::new(
+                    command.trader_id,
+                    command.client_id,
+                    command.strategy_id,
+                    order.instrument_id(),
+                    order.client_order_id(),
+                    order.venue_order_id().unwrap_or_default(),
+                    order.clone(),
+                    command.exec_algorith_id,
+                    command.position_id,
+                    UUID4::new(),
+                    command.ts_init,
+                );
+                match submit {
+                    Ok(submit) => {
+                        self.track_inflight(client, order.client_order_id());
+                        if let Err(e) = client.submit_order(submit) {
+                            self.untrack_inflight(&order.client_order_id());
+                            log::error!("Error submitting order to client: {e}");
+                        }
+                    }
+                    Err(e) => log::error!("Cannot submit {}: {e}", order.client_order_id()),
+                }
+            }
+            return;
+        }
+
         // Send to execution client
-        client.submit_order_list(command).unwrap();
+        let client_order_ids: Vec<ClientOrderId> = command
+            .order_list
+            .orders
+            .iter()
+            .map(OrderAny::client_order_id)
+            .collect();
+        for client_order_id in &client_order_ids {
+            self.track_inflight(client, *client_order_id);
+        }
+        if let Err(e) = client.submit_order_list(command) {
+            for client_order_id in &client_order_ids {
+                self.untrack_inflight(client_order_id);
+            }
+            log::error!("Error submitting order list to client: {e}");
+        }
     }
 
     fn handle_modify_order(&self, client: &ExecutionClient, command: ModifyOrder) {
-        todo!();
+        let client_order_id = command.client_order_id;
+        self.track_inflight(client, client_order_id);
+        if let Err(e) = client.modify_order(command) {
+            self.untrack_inflight(&client_order_id);
+            log::error!("Error modifying order: {e}");
+        }
     }
 
     fn handle_cancel_order(&self, client: &ExecutionClient, command: CancelOrder) {
-        todo!();
+        let client_order_id = command.client_order_id;
+        self.track_inflight(client, client_order_id);
+        if let Err(e) = client.cancel_order(command) {
+            self.untrack_inflight(&client_order_id);
+            log::error!("Error canceling order: {e}");
+        }
     }
 
-    pub const fn handle_cancel_all_orders(
-        &self,
-        client: &ExecutionClient,
-        command: CancelAllOrders,
-    ) {
-        // TODO
-        // client.cancel_all_orders(command);
+    fn handle_cancel_all_orders(&self, client: &ExecutionClient, command: CancelAllOrders) {
+        if let Err(e) = client.cancel_all_orders(command) {
+            log::error!("Error canceling all orders: {e}");
+        }
     }
+
     fn handle_batch_cancel_orders(&self, client: &ExecutionClient, command: BatchCancelOrders) {
-        todo!();
+        if let Err(e) = client.batch_cancel_orders(command) {
+            log::error!("Error batch canceling orders: {e}");
+        }
     }
 
     fn handle_query_order(&self, client: &ExecutionClient, command: QueryOrder) {
-        todo!();
+        if let Err(e) = client.query_order(command) {
+            log::error!("Error querying order: {e}");
+        }
+    }
+
+    /// Tracks the command sent to the `client` for the order with `client_order_id` until
+    /// the order is no longer in flight, querying the order status if the command times out.
+    ///
+    /// Tracking starts before the command is sent, as the client may respond synchronously.
+    fn track_inflight(&self, client: &ExecutionClient, client_order_id: ClientOrderId) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let deadline =
+            ts_now + self.config.inflight_check_threshold_ms * NANOSECONDS_IN_MILLISECOND;
+        let correlation_id = UUID4::new();
+
+        let mut tracker = self.inflight_tracker.borrow_mut();
+        if let Some(previous) = self
+            .inflight_orders
+            .borrow_mut()
+            .insert(client_order_id, correlation_id)
+        {
+            tracker.cancel(&previous);
+        }
+        let callback = self.query_order_callback(client, client_order_id);
+        if let Err(e) = tracker.register(correlation_id, ts_now, deadline, Some(callback)) {
+            log::error!("Cannot track inflight command for {client_order_id}: {e}");
+        }
+    }
+
+    /// Returns a timeout callback which sends a [`QueryOrder`] for the order with
+    /// `client_order_id` to the `client`.
+    fn query_order_callback(
+        &self,
+        client: &ExecutionClient,
+        client_order_id: ClientOrderId,
+    ) -> Rc<RequestTimeoutCallback> {
+        let trader_id = client.trader_id;
+        let client_id = client.client_id;
+        let endpoint = client.execute_endpoint();
+        let clock = self.clock.clone();
+        let cache = self.cache.clone();
+        let msgbus = self.msgbus.clone();
+
+        Rc::new(move |_| {
+            let Some(order) = cache.borrow().order(&client_order_id).cloned() else {
+                return;
+            };
+            log::warn!("Querying {client_order_id}, no venue response within inflight threshold");
+            let query = QueryOrder::new(
+                trader_id,
+                client_id,
+                order.strategy_id(),
+                order.instrument_id(),
+                client_order_id,
+                order.venue_order_id().unwrap_or_default(),
+                UUID4::new(),
+                clock.borrow().timestamp_ns(),
+            );
+            match query {
+                Ok(query) => {
+                    let command = TradingCommand::QueryOrder(query);
+                    msgbus.borrow().send(&endpoint, &command as &dyn Any);
+                }
+                Err(e) => log::error!("Cannot query {client_order_id}: {e}"),
+            }
+        })
+    }
+
+    /// Stops tracking the inflight command for the `order` once it is no longer in flight.
+    fn resolve_inflight(&self, order: &OrderAny) {
+        if !order.is_inflight() {
+            self.untrack_inflight(&order.client_order_id());
+        }
+    }
+
+    fn untrack_inflight(&self, client_order_id: &ClientOrderId) {
+        let correlation_id = self.inflight_orders.borrow_mut().remove(client_order_id);
+        if let Some(correlation_id) = correlation_id {
+            self.inflight_tracker.borrow_mut().cancel(&correlation_id);
+        }
     }
 
     fn create_order_state_snapshot(&self, order: &OrderAny) {
@@ -321,16 +1383,118 @@ impl ExecutionEngine {
         msgbus.publish(&topic, order);
     }
 
+    fn create_position_state_snapshot(&self, position: &Position) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        if let Err(e) = self
+            .cache
+            .borrow()
+            .snapshot_position_state(position, ts_now, None, false)
+        {
+            log::error!("Error snapshotting position {} state: {e}", position.id);
+        }
+    }
+
     // -- EVENT HANDLERS ----------------------------------------------------
 
-    fn handle_event(&self, event: OrderEventAny) {
-        todo!()
+    fn handle_event(&mut self, event: OrderEventAny) {
+        log::debug!("<--[EVT] {event:?}"); // TODO: Log constants
+
+        let Some(mut order) = self.order_for_event(&event) else {
+            return;
+        };
+
+        match event {
+            OrderEventAny::Filled(mut fill) => {
+                if order.trade_ids().contains(&&fill.trade_id) {
+                    log::warn!(
+                        "Duplicate {} for {}, did not apply {fill}",
+                        fill.trade_id,
+                        order.client_order_id()
+                    );
+                    return;
+                }
+
+                let oms_type = self.determine_oms_type(&fill);
+                let position_id = self.determine_position_id(fill, oms_type);
+                fill.position_id = Some(position_id);
+
+                if let Err(e) = self.cache.borrow_mut().add_position_id(
+                    &position_id,
+                    &fill.instrument_id.venue,
+                    &fill.client_order_id,
+                    &fill.strategy_id,
+                ) {
+                    log::error!(
+                        "Error indexing {position_id} for {}: {e}",
+                        fill.client_order_id
+                    );
+                }
+
+                if self.apply_event_to_order(&mut order, OrderEventAny::Filled(fill)) {
+                    self.handle_order_fill(&order, fill, oms_type);
+                    self.handle_contingencies(&OrderEventAny::Filled(fill));
+                }
+            }
+            event => {
+                if self.apply_event_to_order(&mut order, event.clone()) {
+                    self.handle_contingencies(&event);
+                }
+            }
+        }
+    }
+
+    /// Handles the contingencies of the order for the applied `event`, if managed for the
+    /// execution client of the order.
+    fn handle_contingencies(&self, event: &OrderEventAny) {
+        if self.contingency_managers.is_empty() {
+            return;
+        }
+
+        let client_id = self
+            .cache
+            .borrow()
+            .client_id(&event.client_order_id())
+            .copied();
+        if let Some(manager) = client_id.and_then(|id| self.contingency_managers.get(&id)) {
+            manager.borrow_mut().handle_event(event);
+        }
+    }
+
+    fn order_for_event(&self, event: &OrderEventAny) -> Option<OrderAny> {
+        let cache = self.cache.borrow();
+        let client_order_id = event.client_order_id();
+        if let Some(order) = cache.order(&client_order_id) {
+            return Some(order.clone());
+        }
+
+        log::warn!("Order with {client_order_id} not found in the cache to apply {event}");
+
+        // Search by venue order ID for orders which were modified by cancel+replace
+        let order = event
+            .venue_order_id()
+            .and_then(|venue_order_id| cache.client_order_id(&venue_order_id))
+            .and_then(|client_order_id| cache.order(client_order_id));
+        match order {
+            Some(order) => {
+                log::info!(
+                    "Order with {} was found in the cache",
+                    order.client_order_id()
+                );
+                Some(order.clone())
+            }
+            None => {
+                log::error!("Cannot apply event to any order: {client_order_id} not found in the cache with no venue order ID");
+                None
+            }
+        }
     }
 
     fn determine_oms_type(&self, fill: &OrderFilled) -> OmsType {
         // Check for strategy OMS override
         if let Some(oms_type) = self.oms_overrides.get(&fill.strategy_id) {
-            return *oms_type;
+            if *oms_type != OmsType::Unspecified {
+                return *oms_type;
+            }
         }
 
         // Use native venue OMS
@@ -356,65 +1520,160 @@ impl ExecutionEngine {
     }
 
     fn determine_hedging_position_id(&mut self, fill: OrderFilled) -> PositionId {
-        // Check if position ID already exists
-        if let Some(position_id) = fill.position_id {
+        // Check if position ID already exists (assigned by the venue)
+        if let Some(venue_position_id) = fill.position_id {
+            return self.resolve_venue_position_id(&fill, venue_position_id);
+        }
+
+        let cache = self.cache.borrow();
+
+        // Check for a position already indexed for the order (partial fills, or
+        // an order submitted against an existing position)
+        if let Some(position_id) = cache.position_id(&fill.client_order_id) {
             if self.config.debug {
-                log::debug!("Already had a position ID of: {}", position_id);
+                log::debug!("Found {} for {}", position_id, fill.client_order_id);
             }
-            return position_id;
+            return *position_id;
         }
 
         // Check for order
-        let cache = self.cache.borrow();
-        let order = match cache.order(&fill.client_order_id()) {
+        let order = match cache.order(&fill.client_order_id) {
             Some(o) => o,
             None => {
                 panic!(
                     "Order for {} not found to determine position ID",
-                    fill.client_order_id()
+                    fill.client_order_id
                 );
             }
         };
 
         // Check execution spawn orders
         if let Some(spawn_id) = order.exec_spawn_id() {
-            let cache = self.cache.borrow();
             let spawn_orders = cache.orders_for_exec_spawn(&spawn_id);
             for spawned_order in spawn_orders {
                 if let Some(pos_id) = spawned_order.position_id() {
                     if self.config.debug {
-                        log::debug!("Found spawned {} for {}", pos_id, fill.client_order_id());
+                        log::debug!("Found spawned {} for {}", pos_id, fill.client_order_id);
                     }
                     return pos_id;
                 }
             }
         }
+        drop(cache);
 
         // Generate new position ID
         let position_id = self.pos_id_generator.generate(fill.strategy_id, false);
         if self.config.debug {
-            log::debug!("Generated {} for {}", position_id, fill.client_order_id());
+            log::debug!("Generated {} for {}", position_id, fill.client_order_id);
         }
         position_id
     }
 
-    fn determine_netting_position_id(&self, fill: OrderFilled) -> PositionId {
-        PositionId::new(format!("{}-{}", fill.instrument_id, fill.strategy_id))
-    }
+    /// Resolves the position ID assigned by the venue to the system position ID.
+    ///
+    /// When the order was already indexed against a different position, the venue
+    /// position ID is mapped to it so later fills resolve to the same position.
+    fn resolve_venue_position_id(
+        &self,
+        fill: &OrderFilled,
+        venue_position_id: PositionId,
+    ) -> PositionId {
+        let mut cache = self.cache.borrow_mut();
+        if let Some(position_id) = cache.position_id_for_venue(&venue_position_id) {
+            return *position_id;
+        }
 
-    fn apply_event_to_order(&self, order: &mut OrderAny, event: OrderEventAny) {
-        match order.apply(event.clone()) {
-            Ok(()) => {
-                self.cache.borrow_mut().update_order(order).unwrap();
+        let position_id = match cache.position_id(&fill.client_order_id) {
+            Some(position_id) if *position_id != venue_position_id => *position_id,
+            _ => {
+                if self.config.debug {
+                    log::debug!("Already had a position ID of: {}", venue_position_id);
+                }
+                return venue_position_id;
             }
-            Err(e) => {
-                log::error!("Error applying event: {}, did not apply {}", e, event);
+        };
+
+        if let Err(e) = cache.add_venue_position_id(&position_id, &venue_position_id, false) {
+            log::error!("Error indexing venue {venue_position_id}: {e}");
+        }
+        position_id
+    }
+
+    fn determine_netting_position_id(&self, fill: OrderFilled) -> PositionId {
+        let position_id = PositionId::new(format!("{}-{}", fill.instrument_id, fill.strategy_id));
+
+        // Retain any venue assigned position ID so it resolves to the netted position
+        if let Some(venue_position_id) = fill.position_id {
+            if venue_position_id != position_id {
+                if let Err(e) = self.cache.borrow_mut().add_venue_position_id(
+                    &position_id,
+                    &venue_position_id,
+                    true,
+                ) {
+                    log::error!("Error indexing venue {venue_position_id}: {e}");
+                }
             }
         }
+        position_id
     }
 
-    fn handle_order_fill(&self, order: &OrderAny, fill: OrderFilled, oms_type: OmsType) {
-        todo!();
+    /// Applies the `event` to the `order` and updates the cache, then publishes the event
+    /// to the owning strategy.
+    ///
+    /// Returns whether the event was applied.
+    fn apply_event_to_order(&self, order: &mut OrderAny, event: OrderEventAny) -> bool {
+        if let Err(e) = order.apply(event.clone()) {
+            log::error!("Error applying event: {e}, did not apply {event}");
+            return false;
+        }
+
+        if let Err(e) = self.cache.borrow_mut().update_order(order) {
+            log::error!(
+                "Error updating order {} in cache: {e}",
+                order.client_order_id()
+            );
+        }
+
+        self.resolve_inflight(order);
+
+        let topic = Ustr::from(&format!("events.order.{}", event.strategy_id()));
+        self.msgbus.borrow().publish(&topic, &event);
+
+        if self.config.snapshot_orders {
+            self.create_order_state_snapshot(order);
+        }
+        true
+    }
+
+    fn handle_order_fill(&mut self, order: &OrderAny, fill: OrderFilled, oms_type: OmsType) {
+        let Some(instrument) = self.cache.borrow().instrument(&fill.instrument_id).cloned() else {
+            log::error!(
+                "Cannot handle order fill: no instrument found for {}, {fill}",
+                fill.instrument_id
+            );
+            return;
+        };
+
+        let position_id = fill.position_id.expect("Fill should have a position ID");
+        let position = self.cache.borrow().position(&position_id).cloned();
+        match position {
+            Some(mut position) if position.is_open() => {
+                if self.will_flip_position(&position, fill) {
+                    self.flip_position(instrument, &mut position, fill, oms_type);
+                } else {
+                    self.update_position(instrument, &mut position, fill, oms_type);
+                }
+            }
+            // Reopen the closed position (netting), its previous cycle was snapshot on close
+            Some(mut position) => {
+                self.update_position(instrument, &mut position, fill, oms_type);
+            }
+            None => {
+                if let Err(e) = self.open_position(instrument, position_id, fill, oms_type) {
+                    log::error!("Error opening position {position_id}: {e}");
+                }
+            }
+        }
     }
 
     fn open_position(
@@ -425,7 +1684,14 @@ impl ExecutionEngine {
         oms_type: OmsType,
     ) -> anyhow::Result<()> {
         let position = Position::new(&instrument, fill);
-        self.cache.borrow_mut().add_position(position, oms_type)
+        self.cache
+            .borrow_mut()
+            .add_position(position.clone(), oms_type)?;
+
+        if self.config.snapshot_positions {
+            self.create_position_state_snapshot(&position);
+        }
+        Ok(())
     }
 
     fn update_position(
@@ -436,20 +1702,70 @@ impl ExecutionEngine {
         oms_type: OmsType,
     ) {
         position.apply(&fill);
+        if let Err(e) = self.cache.borrow_mut().update_position(position) {
+            log::error!("Error updating position {} in cache: {e}", position.id);
+        }
+
+        if position.is_closed() {
+            let ts_now = self.clock.borrow().timestamp_ns();
+            if let Err(e) = self.cache.borrow_mut().snapshot_position(position, ts_now) {
+                log::error!("Error snapshotting position {}: {e}", position.id);
+            }
+        }
+
+        if self.config.snapshot_positions {
+            self.create_position_state_snapshot(position);
+        }
     }
 
-    fn will_flip_position(&self, position: &Position, fill: OrderFilled) {
-        todo!();
+    fn will_flip_position(&self, position: &Position, fill: OrderFilled) -> bool {
+        position.is_opposite_side(fill.order_side) && fill.last_qty.raw > position.quantity.raw
     }
 
+    /// Closes the `position` with the part of the `fill` matching its quantity, then opens
+    /// a position in the opposite direction with the remainder.
     fn flip_position(
-        &self,
+        &mut self,
         instrument: InstrumentAny,
         position: &mut Position,
         fill: OrderFilled,
         oms_type: OmsType,
     ) {
-        todo!();
+        let difference = fill.last_qty - position.quantity;
+
+        // Split commission between the two positions pro rata
+        let (commission1, commission2) = match fill.commission {
+            Some(commission) => {
+                let fill_percent = position.quantity.as_f64() / fill.last_qty.as_f64();
+                let commission1 =
+                    Money::new(commission.as_f64() * fill_percent, commission.currency);
+                (Some(commission1), Some(commission - commission1))
+            }
+            None => (None, None),
+        };
+
+        let mut fill_split1 = fill;
+        fill_split1.last_qty = position.quantity;
+        fill_split1.commission = commission1;
+        self.update_position(instrument.clone(), position, fill_split1, oms_type);
+
+        if difference.raw == 0 {
+            log::warn!("Zero fill size during position flip calculation, this could be caused by a mismatch between instrument `size_precision` and a quantity `size_precision`");
+            return;
+        }
+
+        let position_id_flip = match oms_type {
+            OmsType::Hedging => self.pos_id_generator.generate(fill.strategy_id, true),
+            _ => position.id,
+        };
+
+        let mut fill_split2 = fill;
+        fill_split2.position_id = Some(position_id_flip);
+        fill_split2.last_qty = difference;
+        fill_split2.commission = commission2;
+        if let Err(e) = self.open_position(instrument, position_id_flip, fill_split2, oms_type) {
+            log::error!("Error opening flipped position {position_id_flip}: {e}");
+        }
     }
 
     fn publish_order_snapshot(&self, order: &OrderAny) {
@@ -505,6 +1821,90 @@ impl ExecutionEngine {
             "Order denied: {reason}, order ID: {}",
             order.client_order_id()
         );
-        todo!()
+
+        let mut order = order.clone();
+        if !self.cache.borrow().order_exists(&order.client_order_id()) {
+            if let Err(e) = self
+                .cache
+                .borrow_mut()
+                .add_order(order.clone(), None, None, false)
+            {
+                log::error!("Error adding denied order to cache: {e}");
+                return;
+            }
+        }
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let denied = OrderEventAny::Denied(OrderDenied::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            reason.into(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        ));
+        self.apply_event_to_order(&mut order, denied);
     }
 }
+
+/// Fires the callbacks of the inflight commands timed out at `ts_now`, no longer tracking
+/// their orders as in flight.
+///
+/// Returns the correlation IDs of the timed out inflight commands.
+fn check_inflight_timeouts(
+    tracker: &RefCell<RequestTracker>,
+    orders: &RefCell<HashMap<ClientOrderId, UUID4>>,
+    ts_now: UnixNanos,
+) -> Vec<UUID4> {
+    let timed_out = tracker.borrow_mut().check_timeouts(ts_now);
+    if !timed_out.is_empty() {
+        orders
+            .borrow_mut()
+            .retain(|_, correlation_id| !timed_out.contains(correlation_id));
+    }
+    timed_out
+}
+
+/// Returns the signed quantity of the `position` as a decimal (negative when short).
+fn signed_decimal_qty(position: &Position) -> Decimal {
+    match position.side {
+        PositionSide::Short => -position.quantity.as_decimal(),
+        _ => position.quantity.as_decimal(),
+    }
+}
+
+/// Returns the liquidity side for a fill inferred from the `report`, which is only known for
+/// orders which must take (market orders) or provide (post-only orders) liquidity.
+fn inferred_liquidity_side(report: &OrderStatusReport) -> LiquiditySide {
+    match report.order_type {
+        OrderType::Market
+        | OrderType::StopMarket
+        | OrderType::MarketIfTouched
+        | OrderType::TrailingStopMarket
+        | OrderType::MarketToLimit => LiquiditySide::Taker,
+        _ if report.post_only => LiquiditySide::Maker,
+        _ => LiquiditySide::NoLiquiditySide,
+    }
+}
+
+/// Returns the commission for an inferred fill in the instruments settlement currency,
+/// charged at the fee rate for the `liquidity_side` (no commission if unknown).
+fn inferred_commission(
+    instrument: &InstrumentAny,
+    last_qty: Quantity,
+    last_px: Price,
+    liquidity_side: LiquiditySide,
+) -> Money {
+    let fee_rate = match liquidity_side {
+        LiquiditySide::Maker => instrument.maker_fee(),
+        LiquiditySide::Taker => instrument.taker_fee(),
+        LiquiditySide::NoLiquiditySide => Decimal::ZERO,
+    };
+    let notional = instrument.calculate_notional_value(last_qty, last_px, None);
+    Money::new(
+        notional.as_f64() * fee_rate.to_f64().unwrap_or_default(),
+        instrument.settlement_currency(),
+    )
+}
@@ -0,0 +1,262 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Order routing policies for instruments which trade on multiple connected venues.
+//!
+//! When the same symbol is available on more than one venue with a registered execution
+//! client, the `ExecutionEngine` consults its [`RoutingPolicy`] to select the destination.
+//! Individual orders can override the policy via `RoutingInstructions` on the submit command.
+
+use nautilus_common::cache::Cache;
+use nautilus_model::{
+    enums::{OrderSide, OrderType},
+    identifiers::{InstrumentId, Venue},
+    orders::OrderAny,
+    types::Price,
+};
+use rust_decimal::Decimal;
+
+/// Selects a destination venue for an order from a set of candidate venues.
+pub trait RoutingPolicy {
+    /// Returns the name of the routing policy.
+    fn name(&self) -> &'static str;
+
+    /// Returns the venue to route the `order` to, or `None` if no candidate is suitable.
+    ///
+    /// The `venues` are the candidate venues on which the orders symbol is tradable, with a
+    /// connected execution client registered for each.
+    fn select_venue(&self, order: &OrderAny, venues: &[Venue], cache: &Cache) -> Option<Venue>;
+}
+
+/// Routes orders to the first candidate venue in a static order of preference.
+#[derive(Clone, Debug, Default)]
+pub struct StaticPreferenceRouting {
+    pub preferences: Vec<Venue>,
+}
+
+impl StaticPreferenceRouting {
+    /// Creates a new [`StaticPreferenceRouting`] instance.
+    #[must_use]
+    pub const fn new(preferences: Vec<Venue>) -> Self {
+        Self { preferences }
+    }
+}
+
+impl RoutingPolicy for StaticPreferenceRouting {
+    fn name(&self) -> &'static str {
+        stringify!(StaticPreferenceRouting)
+    }
+
+    fn select_venue(&self, _order: &OrderAny, venues: &[Venue], _cache: &Cache) -> Option<Venue> {
+        self.preferences
+            .iter()
+            .find(|venue| venues.contains(venue))
+            .copied()
+    }
+}
+
+/// Routes orders to the venue with the best top-of-book price for the orders side.
+///
+/// Buy orders are routed to the lowest ask, and sell orders to the highest bid. Venues
+/// without a cached quote are not considered.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BestTopOfBookRouting;
+
+impl RoutingPolicy for BestTopOfBookRouting {
+    fn name(&self) -> &'static str {
+        stringify!(BestTopOfBookRouting)
+    }
+
+    fn select_venue(&self, order: &OrderAny, venues: &[Venue], cache: &Cache) -> Option<Venue> {
+        let symbol = order.instrument_id().symbol;
+        let side = order.order_side();
+
+        let prices = venues.iter().filter_map(|venue| {
+            let quote = cache.quote(&InstrumentId::new(symbol, *venue))?;
+            let price: Price = match side {
+                OrderSide::Buy => quote.ask_price,
+                OrderSide::Sell => quote.bid_price,
+                OrderSide::NoOrderSide => return None,
+            };
+            Some((*venue, price))
+        });
+
+        match side {
+            OrderSide::Buy => prices.min_by_key(|(_, price)| *price),
+            OrderSide::Sell => prices.max_by_key(|(_, price)| *price),
+            OrderSide::NoOrderSide => None,
+        }
+        .map(|(venue, _)| venue)
+    }
+}
+
+/// Routes orders to the venue with the lowest fee rate for the instrument.
+///
+/// Orders which are expected to take liquidity are compared on taker fees, all other
+/// orders on maker fees. Venues without a cached instrument are not considered.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LowestFeeRouting;
+
+impl LowestFeeRouting {
+    const fn is_aggressive(order_type: OrderType) -> bool {
+        matches!(
+            order_type,
+            OrderType::Market
+                | OrderType::MarketToLimit
+                | OrderType::StopMarket
+                | OrderType::MarketIfTouched
+                | OrderType::TrailingStopMarket
+        )
+    }
+}
+
+impl RoutingPolicy for LowestFeeRouting {
+    fn name(&self) -> &'static str {
+        stringify!(LowestFeeRouting)
+    }
+
+    fn select_venue(&self, order: &OrderAny, venues: &[Venue], cache: &Cache) -> Option<Venue> {
+        let symbol = order.instrument_id().symbol;
+        let is_aggressive = Self::is_aggressive(order.order_type());
+
+        venues
+            .iter()
+            .filter_map(|venue| {
+                let instrument = cache.instrument(&InstrumentId::new(symbol, *venue))?;
+                let fee: Decimal = if is_aggressive {
+                    instrument.taker_fee()
+                } else {
+                    instrument.maker_fee()
+                };
+                Some((*venue, fee))
+            })
+            .min_by_key(|(_, fee)| *fee)
+            .map(|(venue, _)| venue)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::{
+        data::QuoteTick,
+        identifiers::Symbol,
+        instruments::{stubs::default_fx_ccy, InstrumentAny},
+        orders::OrderTestBuilder,
+        types::Quantity,
+    };
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn venues() -> Vec<Venue> {
+        vec![Venue::from("SIM-A"), Venue::from("SIM-B")]
+    }
+
+    fn order(order_type: OrderType, side: OrderSide) -> OrderAny {
+        let mut builder = OrderTestBuilder::new(order_type);
+        builder
+            .instrument_id(InstrumentId::from("AUD/USD.SIM-A"))
+            .side(side)
+            .quantity(Quantity::from(100_000));
+        if order_type == OrderType::Limit {
+            builder.price(Price::from("0.80000"));
+        }
+        builder.build()
+    }
+
+    fn add_quote(cache: &mut Cache, venue: &str, bid: &str, ask: &str) {
+        let quote = QuoteTick::new(
+            InstrumentId::from(format!("AUD/USD.{venue}").as_str()),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        cache.add_quote(quote).unwrap();
+    }
+
+    fn add_instrument(cache: &mut Cache, venue: &str, maker_fee: Decimal, taker_fee: Decimal) {
+        let mut instrument = default_fx_ccy(Symbol::from("AUD/USD"), Some(Venue::from(venue)));
+        instrument.maker_fee = maker_fee;
+        instrument.taker_fee = taker_fee;
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(instrument))
+            .unwrap();
+    }
+
+    #[rstest]
+    fn test_static_preference_selects_first_available() {
+        let policy = StaticPreferenceRouting::new(vec![Venue::from("SIM-C"), Venue::from("SIM-B")]);
+        let order = order(OrderType::Market, OrderSide::Buy);
+
+        let venue = policy.select_venue(&order, &venues(), &Cache::default());
+
+        assert_eq!(venue, Some(Venue::from("SIM-B")));
+    }
+
+    #[rstest]
+    fn test_static_preference_when_no_preferred_venue_available() {
+        let policy = StaticPreferenceRouting::new(vec![Venue::from("SIM-C")]);
+        let order = order(OrderType::Market, OrderSide::Buy);
+
+        let venue = policy.select_venue(&order, &venues(), &Cache::default());
+
+        assert_eq!(venue, None);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "SIM-B")]
+    #[case(OrderSide::Sell, "SIM-A")]
+    fn test_best_top_of_book(#[case] side: OrderSide, #[case] expected: &str) {
+        let mut cache = Cache::default();
+        add_quote(&mut cache, "SIM-A", "0.80002", "0.80006");
+        add_quote(&mut cache, "SIM-B", "0.80000", "0.80004");
+        let order = order(OrderType::Market, side);
+
+        let venue = BestTopOfBookRouting.select_venue(&order, &venues(), &cache);
+
+        assert_eq!(venue, Some(Venue::from(expected)));
+    }
+
+    #[rstest]
+    fn test_best_top_of_book_when_no_quotes() {
+        let order = order(OrderType::Market, OrderSide::Buy);
+
+        let venue = BestTopOfBookRouting.select_venue(&order, &venues(), &Cache::default());
+
+        assert_eq!(venue, None);
+    }
+
+    #[rstest]
+    #[case(OrderType::Market, "SIM-B")]
+    #[case(OrderType::Limit, "SIM-A")]
+    fn test_lowest_fee(#[case] order_type: OrderType, #[case] expected: &str) {
+        let mut cache = Cache::default();
+        add_instrument(&mut cache, "SIM-A", dec!(-0.0001), dec!(0.0005));
+        add_instrument(&mut cache, "SIM-B", dec!(0.0002), dec!(0.0003));
+        let order = order(order_type, OrderSide::Buy);
+
+        let venue = LowestFeeRouting.select_venue(&order, &venues(), &cache);
+
+        assert_eq!(venue, Some(Venue::from(expected)));
+    }
+}
@@ -14,3 +14,1108 @@
 // -------------------------------------------------------------------------------------------------
 
 //! Tests module for `ExecutionEngine`.
+
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use nautilus_common::{
+    cache::Cache,
+    clock::{Clock, TestClock},
+    messages::execution::{RoutingInstructions, SubmitOrder, TradingCommand},
+    msgbus::{
+        handler::ShareableMessageHandler,
+        stubs::{get_message_saving_handler, get_saved_messages},
+        MessageBus,
+    },
+};
+use nautilus_core::{nanos::UnixNanos, time::get_atomic_clock_static, uuid::UUID4};
+use nautilus_model::{
+    data::QuoteTick,
+    enums::{
+        AccountType, ContingencyType, LiquiditySide, OmsType, OrderSide, OrderStatus, OrderType,
+        PositionSide, TimeInForce,
+    },
+    events::OrderEventAny,
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, Symbol, TradeId,
+        TraderId, Venue, VenueOrderId,
+    },
+    instruments::{
+        stubs::{audusd_sim, default_fx_ccy},
+        CurrencyPair, InstrumentAny,
+    },
+    orders::{
+        stubs::{TestOrderEventStubs, TestOrderStubs},
+        OrderAny, OrderTestBuilder,
+    },
+    types::{Price, Quantity},
+};
+use rstest::{fixture, rstest};
+use rust_decimal_macros::dec;
+
+use super::{
+    config::ExecutionEngineConfig,
+    routing::{RoutingPolicy, StaticPreferenceRouting},
+    ExecutionEngine,
+};
+use crate::{
+    client::{ExecutionClient, MassStatusRequest},
+    reports::{
+        mass_status::ExecutionMassStatus, order::OrderStatusReport, position::PositionStatusReport,
+    },
+};
+
+#[fixture]
+fn instrument(audusd_sim: CurrencyPair) -> InstrumentAny {
+    InstrumentAny::CurrencyPair(audusd_sim)
+}
+
+fn get_engine(instrument: &InstrumentAny, config: ExecutionEngineConfig) -> ExecutionEngine {
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    cache
+        .borrow_mut()
+        .add_instrument(instrument.clone())
+        .unwrap();
+    let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+    let mut engine = ExecutionEngine::new(
+        Rc::new(RefCell::new(TestClock::new())),
+        cache.clone(),
+        msgbus.clone(),
+        config,
+    );
+    let client = ExecutionClient::new(
+        TraderId::default(),
+        ClientId::from("SIM"),
+        Venue::from("SIM"),
+        OmsType::Netting,
+        AccountId::from("SIM-001"),
+        AccountType::Margin,
+        None,
+        get_atomic_clock_static(),
+        cache,
+        msgbus,
+    );
+    engine.register_client(client).unwrap();
+    engine
+}
+
+fn market_order(instrument: &InstrumentAny, client_order_id: &str, side: OrderSide) -> OrderAny {
+    OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument.id())
+        .client_order_id(ClientOrderId::from(client_order_id))
+        .side(side)
+        .quantity(Quantity::from(100_000))
+        .build()
+}
+
+fn add_accepted_order(engine: &ExecutionEngine, order: &OrderAny) -> OrderAny {
+    let order = TestOrderStubs::make_accepted_order(order);
+    engine
+        .cache
+        .borrow_mut()
+        .add_order(order.clone(), None, None, false)
+        .unwrap();
+    order
+}
+
+fn add_quote(engine: &ExecutionEngine, instrument: &InstrumentAny) {
+    engine
+        .cache
+        .borrow_mut()
+        .add_quote(QuoteTick::new(
+            instrument.id(),
+            Price::from("0.79990"),
+            Price::from("0.80010"),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        ))
+        .unwrap();
+}
+
+fn fill_event(
+    order: &OrderAny,
+    instrument: &InstrumentAny,
+    trade_id: &str,
+    last_qty: Quantity,
+    position_id: Option<PositionId>,
+) -> OrderEventAny {
+    let OrderEventAny::Filled(mut fill) = TestOrderEventStubs::order_filled(
+        order,
+        instrument,
+        Some(TradeId::from(trade_id)),
+        None,
+        Some(Price::from("0.80000")),
+        Some(last_qty),
+        None,
+        None,
+        None,
+        None,
+    ) else {
+        panic!("Expected `OrderFilled` event");
+    };
+    fill.position_id = position_id;
+    OrderEventAny::Filled(fill)
+}
+
+fn order_status_report(
+    instrument: &InstrumentAny,
+    order: &OrderAny,
+    order_status: OrderStatus,
+    filled_qty: Quantity,
+) -> OrderStatusReport {
+    OrderStatusReport::new(
+        AccountId::from("SIM-001"),
+        instrument.id(),
+        VenueOrderId::from("V-001"),
+        order.order_side(),
+        order.order_type(),
+        TimeInForce::Gtc,
+        order_status,
+        order.quantity(),
+        filled_qty,
+        UUID4::new(),
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+        UnixNanos::from(3),
+    )
+    .with_client_order_id(order.client_order_id())
+}
+
+#[rstest]
+fn test_execute_submit_order_routes_to_client(instrument: InstrumentAny) {
+    let engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    let handler = get_message_saving_handler::<TradingCommand>(None);
+    engine
+        .msgbus
+        .borrow_mut()
+        .register("SIM.execute", handler.clone());
+    let order = market_order(&instrument, "O-1", OrderSide::Buy);
+
+    engine.execute(TradingCommand::SubmitOrder(
+        SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            instrument.id(),
+            order.client_order_id(),
+            VenueOrderId::default(),
+            order.clone(),
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap(),
+    ));
+
+    let commands = get_saved_messages::<TradingCommand>(handler);
+    assert_eq!(commands.len(), 1);
+    assert!(engine.cache.borrow().order_exists(&order.client_order_id()));
+}
+
+fn add_routed_venue(engine: &mut ExecutionEngine, venue: &str) {
+    let venue = Venue::from(venue);
+    let instrument = default_fx_ccy(Symbol::from("AUD/USD"), Some(venue));
+    engine
+        .cache
+        .borrow_mut()
+        .add_instrument(InstrumentAny::CurrencyPair(instrument))
+        .unwrap();
+    let client = ExecutionClient::new(
+        TraderId::default(),
+        ClientId::from(venue.as_str()),
+        venue,
+        OmsType::Netting,
+        AccountId::from(format!("{venue}-001").as_str()),
+        AccountType::Margin,
+        None,
+        get_atomic_clock_static(),
+        engine.cache.clone(),
+        engine.msgbus.clone(),
+    );
+    engine.register_client(client).unwrap();
+}
+
+fn submit_order(order: &OrderAny, routing: RoutingInstructions) -> TradingCommand {
+    TradingCommand::SubmitOrder(
+        SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from(order.instrument_id().venue.as_str()),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::default(),
+            order.clone(),
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap()
+        .with_routing(routing),
+    )
+}
+
+#[rstest]
+fn test_execute_submit_order_routes_to_policy_venue(instrument: InstrumentAny) {
+    let mut engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    add_routed_venue(&mut engine, "SIM-A");
+    add_routed_venue(&mut engine, "SIM-B");
+    engine.set_routing_policy(Box::new(StaticPreferenceRouting::new(vec![Venue::from(
+        "SIM-B",
+    )])));
+    let handler = get_message_saving_handler::<TradingCommand>(None);
+    engine
+        .msgbus
+        .borrow_mut()
+        .register("SIM-B.execute", handler.clone());
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(InstrumentId::from("AUD/USD.SIM-A"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(100_000))
+        .build();
+
+    engine.execute(submit_order(&order, RoutingInstructions::default()));
+
+    let routed_id = InstrumentId::from("AUD/USD.SIM-B");
+    let commands = get_saved_messages::<TradingCommand>(handler);
+    assert_eq!(commands.len(), 1);
+    let TradingCommand::SubmitOrder(command) = &commands[0] else {
+        panic!("Expected `SubmitOrder`, was {:?}", commands[0]);
+    };
+    assert_eq!(command.client_id, ClientId::from("SIM-B"));
+    assert_eq!(command.instrument_id, routed_id);
+    assert_eq!(command.order.instrument_id(), routed_id);
+    let cache = engine.cache.borrow();
+    let cached = cache.order(&order.client_order_id()).unwrap();
+    assert_eq!(cached.instrument_id(), routed_id);
+}
+
+#[rstest]
+fn test_execute_submit_order_reindexes_cached_order_under_routed_venue(instrument: InstrumentAny) {
+    let mut engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    add_routed_venue(&mut engine, "SIM-A");
+    add_routed_venue(&mut engine, "SIM-B");
+    engine.set_routing_policy(Box::new(StaticPreferenceRouting::new(vec![Venue::from(
+        "SIM-B",
+    )])));
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(InstrumentId::from("AUD/USD.SIM-A"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(100_000))
+        .build();
+    engine
+        .cache
+        .borrow_mut()
+        .add_order(order.clone(), None, Some(ClientId::from("SIM-A")), false)
+        .unwrap();
+
+    engine.execute(submit_order(&order, RoutingInstructions::default()));
+
+    let venue = Venue::from("SIM-B");
+    let routed_id = InstrumentId::from("AUD/USD.SIM-B");
+    let cache = engine.cache.borrow();
+    let cached = cache.order(&order.client_order_id()).unwrap();
+    assert_eq!(cache.orders(Some(&venue), None, None, None), vec![cached]);
+    assert_eq!(
+        cache.orders(None, Some(&routed_id), None, None),
+        vec![cached]
+    );
+    assert!(cache
+        .orders(Some(&Venue::from("SIM-A")), None, None, None)
+        .is_empty());
+    assert_eq!(
+        cache.client_id(&order.client_order_id()),
+        Some(&ClientId::from("SIM-B"))
+    );
+}
+
+/// Routing policy which always selects the same venue, regardless of the candidates.
+struct FixedVenueRouting(Venue);
+
+impl RoutingPolicy for FixedVenueRouting {
+    fn name(&self) -> &'static str {
+        stringify!(FixedVenueRouting)
+    }
+
+    fn select_venue(&self, _order: &OrderAny, _venues: &[Venue], _cache: &Cache) -> Option<Venue> {
+        Some(self.0)
+    }
+}
+
+#[rstest]
+#[case::unregistered("SIM-X", None)]
+#[case::excluded("SIM-B", Some("SIM-B"))]
+fn test_execute_submit_order_with_invalid_policy_venue_denies_order(
+    instrument: InstrumentAny,
+    #[case] selected: &str,
+    #[case] excluded: Option<&str>,
+) {
+    let mut engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    add_routed_venue(&mut engine, "SIM-A");
+    add_routed_venue(&mut engine, "SIM-B");
+    add_routed_venue(&mut engine, "SIM-C");
+    engine.set_routing_policy(Box::new(FixedVenueRouting(Venue::from(selected))));
+    let handler = get_message_saving_handler::<TradingCommand>(None);
+    for endpoint in ["SIM-A.execute", "SIM-B.execute", "SIM-C.execute"] {
+        engine
+            .msgbus
+            .borrow_mut()
+            .register(endpoint, handler.clone());
+    }
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(InstrumentId::from("AUD/USD.SIM-A"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(100_000))
+        .build();
+    let routing = RoutingInstructions {
+        excluded_venues: excluded.map(Venue::from).into_iter().collect(),
+        ..Default::default()
+    };
+
+    engine.execute(submit_order(&order, routing));
+
+    assert!(get_saved_messages::<TradingCommand>(handler).is_empty());
+    let cache = engine.cache.borrow();
+    let cached = cache.order(&order.client_order_id()).unwrap();
+    assert_eq!(cached.status(), OrderStatus::Denied);
+}
+
+#[rstest]
+fn test_execute_submit_order_with_unregistered_routing_venue_denies_order(
+    instrument: InstrumentAny,
+) {
+    let engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    let handler = get_message_saving_handler::<TradingCommand>(None);
+    engine
+        .msgbus
+        .borrow_mut()
+        .register("SIM.execute", handler.clone());
+    let order = market_order(&instrument, "O-1", OrderSide::Buy);
+
+    engine.execute(submit_order(
+        &order,
+        RoutingInstructions::to_venue(Venue::from("SIM-X")),
+    ));
+
+    assert!(get_saved_messages::<TradingCommand>(handler).is_empty());
+    let cache = engine.cache.borrow();
+    let cached = cache.order(&order.client_order_id()).unwrap();
+    assert_eq!(cached.status(), OrderStatus::Denied);
+}
+
+#[rstest]
+fn test_process_fill_updates_order_and_opens_position(instrument: InstrumentAny) {
+    let mut engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    let order = add_accepted_order(&engine, &market_order(&instrument, "O-1", OrderSide::Buy));
+    let handler = get_message_saving_handler::<OrderEventAny>(None);
+    engine.msgbus.borrow_mut().subscribe(
+        format!("events.order.{}", order.strategy_id()),
+        handler.clone(),
+        None,
+    );
+    let fill = TestOrderEventStubs::order_filled(
+        &order,
+        &instrument,
+        None,
+        None,
+        Some(Price::from("0.80000")),
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+
+    engine.process(&fill);
+
+    let cache = engine.cache.borrow();
+    let order = cache.order(&order.client_order_id()).unwrap();
+    let positions = cache.positions_open(None, Some(&instrument.id()), None, None);
+    assert_eq!(order.status(), OrderStatus::Filled);
+    assert_eq!(positions.len(), 1);
+    assert_eq!(positions[0].side, PositionSide::Long);
+    assert_eq!(positions[0].quantity, Quantity::from(100_000));
+    assert_eq!(order.position_id(), Some(positions[0].id));
+    assert_eq!(get_saved_messages::<OrderEventAny>(handler).len(), 1);
+}
+
+#[rstest]
+fn test_process_fill_flips_netting_position(instrument: InstrumentAny) {
+    let mut engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    let buy = add_accepted_order(&engine, &market_order(&instrument, "O-1", OrderSide::Buy));
+    let sell = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument.id())
+        .client_order_id(ClientOrderId::from("O-2"))
+        .side(OrderSide::Sell)
+        .quantity(Quantity::from(150_000))
+        .build();
+    let sell = add_accepted_order(&engine, &sell);
+
+    for (order, trade_id) in [(&buy, "E-1"), (&sell, "E-2")] {
+        engine.process(&TestOrderEventStubs::order_filled(
+            order,
+            &instrument,
+            Some(TradeId::from(trade_id)),
+            None,
+            Some(Price::from("0.80000")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ));
+    }
+
+    let cache = engine.cache.borrow();
+    let positions = cache.positions_open(None, Some(&instrument.id()), None, None);
+    assert_eq!(positions.len(), 1);
+    assert_eq!(positions[0].side, PositionSide::Short);
+    assert_eq!(positions[0].quantity, Quantity::from(50_000));
+}
+
+#[rstest]
+fn test_reconcile_order_report_generates_inferred_fill(instrument: InstrumentAny) {
+    let mut engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    let order = add_accepted_order(&engine, &market_order(&instrument, "O-1", OrderSide::Buy));
+    let report = order_status_report(
+        &instrument,
+        &order,
+        OrderStatus::Filled,
+        Quantity::from(100_000),
+    )
+    .with_avg_px(dec!(0.80000));
+
+    let reconciled = engine.reconcile_order_report(&report, &[]);
+
+    let cache = engine.cache.borrow();
+    let order = cache.order(&order.client_order_id()).unwrap();
+    assert!(reconciled);
+    assert_eq!(order.status(), OrderStatus::Filled);
+    assert_eq!(order.avg_px(), Some(0.8));
+    assert_eq!(order.liquidity_side(), Some(LiquiditySide::Taker));
+    assert_eq!(
+        cache
+            .positions_open(None, Some(&instrument.id()), None, None)
+            .len(),
+        1
+    );
+}
+
+#[rstest]
+fn test_reconcile_order_report_cancels_order(instrument: InstrumentAny) {
+    let mut engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    let order = add_accepted_order(&engine, &market_order(&instrument, "O-1", OrderSide::Buy));
+    let report = order_status_report(
+        &instrument,
+        &order,
+        OrderStatus::Canceled,
+        Quantity::from(0),
+    );
+
+    assert!(engine.reconcile_order_report(&report, &[]));
+    assert_eq!(
+        engine
+            .cache
+            .borrow()
+            .order(&order.client_order_id())
+            .unwrap()
+            .status(),
+        OrderStatus::Canceled
+    );
+}
+
+#[rstest]
+fn test_reconcile_mass_status_generates_external_order(instrument: InstrumentAny) {
+    let mut engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    let order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument.id())
+        .client_order_id(ClientOrderId::from("O-1"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(100_000))
+        .price(Price::from("0.80000"))
+        .build();
+    let mut mass_status = ExecutionMassStatus::new(
+        ClientId::from("SIM"),
+        AccountId::from("SIM-001"),
+        Venue::from("SIM"),
+        UnixNanos::default(),
+        None,
+    );
+    mass_status.add_order_reports(vec![order_status_report(
+        &instrument,
+        &order,
+        OrderStatus::Accepted,
+        Quantity::from(0),
+    )
+    .with_price(Price::from("0.80000"))]);
+
+    assert!(engine.reconcile_state(&[mass_status]));
+
+    let cache = engine.cache.borrow();
+    let external = cache.order(&order.client_order_id()).unwrap();
+    assert_eq!(external.status(), OrderStatus::Accepted);
+    assert_eq!(external.strategy_id(), StrategyId::external());
+    assert_eq!(external.price(), Some(Price::from("0.80000")));
+    assert_eq!(external.venue_order_id(), Some(VenueOrderId::from("V-001")));
+}
+
+#[rstest]
+fn test_reconcile_state_when_deactivated_skips_reports(instrument: InstrumentAny) {
+    let mut engine = get_engine(
+        &instrument,
+        ExecutionEngineConfig {
+            reconciliation: false,
+            ..Default::default()
+        },
+    );
+    let order = market_order(&instrument, "O-1", OrderSide::Buy);
+    let mut mass_status = ExecutionMassStatus::new(
+        ClientId::from("SIM"),
+        AccountId::from("SIM-001"),
+        Venue::from("SIM"),
+        UnixNanos::default(),
+        None,
+    );
+    mass_status.add_order_reports(vec![order_status_report(
+        &instrument,
+        &order,
+        OrderStatus::Accepted,
+        Quantity::from(0),
+    )]);
+
+    assert!(engine.reconcile_state(&[mass_status]));
+    assert!(engine
+        .cache
+        .borrow()
+        .order(&order.client_order_id())
+        .is_none());
+}
+
+#[rstest]
+fn test_start_requests_mass_status_and_reconciles_response(instrument: InstrumentAny) {
+    let mut engine = get_engine(
+        &instrument,
+        ExecutionEngineConfig {
+            reconciliation_lookback_mins: Some(60),
+            ..Default::default()
+        },
+    );
+    let handler = get_message_saving_handler::<MassStatusRequest>(None);
+    engine
+        .msgbus
+        .borrow_mut()
+        .register("SIM.mass_status", handler.clone());
+
+    engine.start();
+
+    let requests = get_saved_messages::<MassStatusRequest>(handler);
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].client_id, ClientId::from("SIM"));
+    assert_eq!(requests[0].lookback_mins, Some(60));
+
+    let mut mass_status = ExecutionMassStatus::new(
+        ClientId::from("SIM"),
+        AccountId::from("SIM-001"),
+        Venue::from("SIM"),
+        UnixNanos::default(),
+        None,
+    );
+    mass_status.add_position_reports(vec![PositionStatusReport::new(
+        AccountId::from("SIM-001"),
+        instrument.id(),
+        PositionSide::Long,
+        Quantity::from(100_000),
+        None,
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    )]);
+    add_quote(&engine, &instrument);
+
+    engine.handle_mass_status(mass_status);
+
+    let cache = engine.cache.borrow();
+    let positions = cache.positions_open(None, Some(&instrument.id()), None, None);
+    assert_eq!(positions.len(), 1);
+    assert_eq!(positions[0].quantity, Quantity::from(100_000));
+}
+
+#[rstest]
+fn test_reconcile_position_report_infers_fill_to_close_gap(instrument: InstrumentAny) {
+    let mut engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    let buy = add_accepted_order(&engine, &market_order(&instrument, "O-1", OrderSide::Buy));
+    engine.process(&fill_event(
+        &buy,
+        &instrument,
+        "E-1",
+        Quantity::from(100_000),
+        None,
+    ));
+    add_quote(&engine, &instrument);
+    let report = PositionStatusReport::new(
+        AccountId::from("SIM-001"),
+        instrument.id(),
+        PositionSide::Long,
+        Quantity::from(150_000),
+        None,
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    );
+
+    assert!(engine.reconcile_position_report(&report));
+    assert!(engine.reconcile_position_report(&report));
+
+    let cache = engine.cache.borrow();
+    let external = StrategyId::external();
+    let gap_orders = cache.orders(None, Some(&instrument.id()), Some(&external), None);
+    let gap_positions = cache.positions_open(None, Some(&instrument.id()), Some(&external), None);
+    assert_eq!(gap_orders.len(), 1);
+    assert_eq!(gap_orders[0].order_side(), OrderSide::Buy);
+    assert_eq!(gap_orders[0].filled_qty(), Quantity::from(50_000));
+    assert_eq!(gap_orders[0].avg_px(), Some(0.8));
+    assert_eq!(gap_positions.len(), 1);
+    assert_eq!(gap_positions[0].side, PositionSide::Long);
+    assert_eq!(gap_positions[0].quantity, Quantity::from(50_000));
+}
+
+#[rstest]
+fn test_process_fills_with_hedging_oms_opens_position_per_order(instrument: InstrumentAny) {
+    let mut engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    let buy1 = add_accepted_order(&engine, &market_order(&instrument, "O-1", OrderSide::Buy));
+    let buy2 = add_accepted_order(&engine, &market_order(&instrument, "O-2", OrderSide::Buy));
+    engine.register_oms_type(buy1.strategy_id(), OmsType::Hedging);
+
+    // Partial fills of the same order resolve to the same position
+    engine.process(&fill_event(
+        &buy1,
+        &instrument,
+        "E-1",
+        Quantity::from(50_000),
+        None,
+    ));
+    engine.process(&fill_event(
+        &buy1,
+        &instrument,
+        "E-2",
+        Quantity::from(50_000),
+        None,
+    ));
+    engine.process(&fill_event(
+        &buy2,
+        &instrument,
+        "E-3",
+        Quantity::from(100_000),
+        None,
+    ));
+
+    let cache = engine.cache.borrow();
+    let positions = cache.positions_open(None, Some(&instrument.id()), None, None);
+    assert_eq!(positions.len(), 2);
+    assert!(positions
+        .iter()
+        .all(|position| position.quantity == Quantity::from(100_000)));
+    assert_ne!(
+        cache.position_id(&buy1.client_order_id()),
+        cache.position_id(&buy2.client_order_id())
+    );
+}
+
+#[rstest]
+fn test_process_fills_with_netting_oms_flattens_into_single_position(instrument: InstrumentAny) {
+    let mut engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    let buy1 = add_accepted_order(&engine, &market_order(&instrument, "O-1", OrderSide::Buy));
+    let buy2 = add_accepted_order(&engine, &market_order(&instrument, "O-2", OrderSide::Buy));
+
+    engine.process(&fill_event(
+        &buy1,
+        &instrument,
+        "E-1",
+        Quantity::from(100_000),
+        None,
+    ));
+    engine.process(&fill_event(
+        &buy2,
+        &instrument,
+        "E-2",
+        Quantity::from(100_000),
+        None,
+    ));
+
+    let cache = engine.cache.borrow();
+    let positions = cache.positions_open(None, Some(&instrument.id()), None, None);
+    assert_eq!(positions.len(), 1);
+    assert_eq!(positions[0].quantity, Quantity::from(200_000));
+    assert_eq!(
+        positions[0].id,
+        PositionId::from(format!("{}-{}", instrument.id(), buy1.strategy_id()).as_str())
+    );
+}
+
+#[rstest]
+fn test_process_fill_with_netting_oms_reopens_closed_position_with_snapshot(
+    instrument: InstrumentAny,
+) {
+    let mut engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    let buy1 = add_accepted_order(&engine, &market_order(&instrument, "O-1", OrderSide::Buy));
+    let sell = add_accepted_order(&engine, &market_order(&instrument, "O-2", OrderSide::Sell));
+    let buy2 = add_accepted_order(&engine, &market_order(&instrument, "O-3", OrderSide::Buy));
+
+    engine.process(&fill_event(
+        &buy1,
+        &instrument,
+        "E-1",
+        Quantity::from(100_000),
+        None,
+    ));
+    engine.process(&fill_event(
+        &sell,
+        &instrument,
+        "E-2",
+        Quantity::from(100_000),
+        None,
+    ));
+    engine.process(&fill_event(
+        &buy2,
+        &instrument,
+        "E-3",
+        Quantity::from(100_000),
+        None,
+    ));
+
+    let cache = engine.cache.borrow();
+    let positions = cache.positions_open(None, Some(&instrument.id()), None, None);
+    let snapshots = cache.position_snapshots(Some(&positions[0].id)).unwrap();
+    assert_eq!(positions.len(), 1);
+    assert_eq!(positions[0].side, PositionSide::Long);
+    assert_eq!(snapshots.len(), 1);
+    assert!(snapshots[0].is_closed());
+}
+
+#[rstest]
+fn test_process_fill_with_hedging_oms_maps_venue_position_id(instrument: InstrumentAny) {
+    let mut engine = get_engine(&instrument, ExecutionEngineConfig::default());
+    let buy = add_accepted_order(&engine, &market_order(&instrument, "O-1", OrderSide::Buy));
+    let sell = add_accepted_order(&engine, &market_order(&instrument, "O-2", OrderSide::Sell));
+    engine.register_oms_type(buy.strategy_id(), OmsType::Hedging);
+
+    // Venue assigned position ID is used directly when nothing else is indexed
+    let venue_position_id = PositionId::from("SIM-1-001");
+    engine.process(&fill_event(
+        &buy,
+        &instrument,
+        "E-1",
+        Quantity::from(100_000),
+        Some(venue_position_id),
+    ));
+
+    // A reducing order submitted against a system position ID maps the venue ID onto it
+    let position_id = PositionId::from("P-CLOSE");
+    engine
+        .cache
+        .borrow_mut()
+        .add_order(sell.clone(), Some(position_id), None, true)
+        .unwrap();
+    engine.process(&fill_event(
+        &sell,
+        &instrument,
+        "E-2",
+        Quantity::from(50_000),
+        Some(PositionId::from("SIM-1-002")),
+    ));
+
+    let cache = engine.cache.borrow();
+    assert!(cache.position(&venue_position_id).is_some());
+    assert!(cache.position(&position_id).is_some());
+    assert_eq!(
+        cache.position_id_for_venue(&PositionId::from("SIM-1-002")),
+        Some(&position_id)
+    );
+}
+
+fn contingent_order(
+    instrument: &InstrumentAny,
+    client_order_id: &str,
+    contingency_type: Option<ContingencyType>,
+    linked_order_id: Option<&str>,
+    parent_order_id: Option<&str>,
+) -> OrderAny {
+    let mut builder = OrderTestBuilder::new(OrderType::Limit);
+    builder
+        .instrument_id(instrument.id())
+        .client_order_id(ClientOrderId::from(client_order_id))
+        .side(OrderSide::Buy)
+        .price(Price::from("0.80000"))
+        .quantity(Quantity::from(100_000));
+    if let Some(contingency_type) = contingency_type {
+        builder.contingency_type(contingency_type);
+    }
+    if let Some(linked_order_id) = linked_order_id {
+        builder.linked_order_ids(vec![ClientOrderId::from(linked_order_id)]);
+    }
+    if let Some(parent_order_id) = parent_order_id {
+        builder.parent_order_id(ClientOrderId::from(parent_order_id));
+    }
+    builder.build()
+}
+
+fn get_contingency_engine(
+    instrument: &InstrumentAny,
+) -> (
+    ExecutionEngine,
+    ShareableMessageHandler,
+    ShareableMessageHandler,
+) {
+    let engine = get_engine(
+        instrument,
+        ExecutionEngineConfig {
+            manage_contingent_orders: true,
+            ..Default::default()
+        },
+    );
+    let client_handler = get_message_saving_handler::<TradingCommand>(None);
+    let engine_handler = get_message_saving_handler::<TradingCommand>(None);
+    {
+        let mut msgbus = engine.msgbus.borrow_mut();
+        msgbus.register("SIM.execute", client_handler.clone());
+        let endpoint = msgbus.switchboard.exec_engine_execute;
+        msgbus.register(endpoint, engine_handler.clone());
+    }
+    (engine, client_handler, engine_handler)
+}
+
+fn submit_and_accept(engine: &mut ExecutionEngine, order: &OrderAny, venue_order_id: &str) {
+    engine.execute(submit_order(order, RoutingInstructions::default()));
+    engine.process(&TestOrderEventStubs::order_submitted(
+        order,
+        AccountId::from("SIM-001"),
+    ));
+    engine.process(&TestOrderEventStubs::order_accepted(
+        order,
+        AccountId::from("SIM-001"),
+        VenueOrderId::from(venue_order_id),
+    ));
+}
+
+#[rstest]
+fn test_oco_fill_cancels_linked_order(instrument: InstrumentAny) {
+    let (mut engine, _, engine_handler) = get_contingency_engine(&instrument);
+    let order1 = contingent_order(
+        &instrument,
+        "O-1",
+        Some(ContingencyType::Oco),
+        Some("O-2"),
+        None,
+    );
+    let order2 = contingent_order(
+        &instrument,
+        "O-2",
+        Some(ContingencyType::Oco),
+        Some("O-1"),
+        None,
+    );
+    submit_and_accept(&mut engine, &order1, "V-1");
+    submit_and_accept(&mut engine, &order2, "V-2");
+
+    engine.process(&fill_event(
+        &order1,
+        &instrument,
+        "E-1",
+        Quantity::from(100_000),
+        None,
+    ));
+
+    let commands = get_saved_messages::<TradingCommand>(engine_handler);
+    assert_eq!(commands.len(), 1);
+    let TradingCommand::CancelOrder(cancel) = &commands[0] else {
+        panic!("Expected `CancelOrder`, was {}", commands[0]);
+    };
+    assert_eq!(cancel.client_order_id, order2.client_order_id());
+    assert_eq!(cancel.venue_order_id, VenueOrderId::from("V-2"));
+}
+
+#[rstest]
+fn test_ouo_partial_fill_reduces_linked_order(instrument: InstrumentAny) {
+    let (mut engine, _, engine_handler) = get_contingency_engine(&instrument);
+    let order1 = contingent_order(
+        &instrument,
+        "O-1",
+        Some(ContingencyType::Ouo),
+        Some("O-2"),
+        None,
+    );
+    let order2 = contingent_order(
+        &instrument,
+        "O-2",
+        Some(ContingencyType::Ouo),
+        Some("O-1"),
+        None,
+    );
+    submit_and_accept(&mut engine, &order1, "V-1");
+    submit_and_accept(&mut engine, &order2, "V-2");
+
+    engine.process(&fill_event(
+        &order1,
+        &instrument,
+        "E-1",
+        Quantity::from(40_000),
+        None,
+    ));
+
+    let commands = get_saved_messages::<TradingCommand>(engine_handler);
+    assert_eq!(commands.len(), 1);
+    let TradingCommand::ModifyOrder(modify) = &commands[0] else {
+        panic!("Expected `ModifyOrder`, was {}", commands[0]);
+    };
+    assert_eq!(modify.client_order_id, order2.client_order_id());
+    assert_eq!(modify.quantity, Some(Quantity::from(60_000)));
+}
+
+#[rstest]
+fn test_oto_child_held_until_parent_filled(instrument: InstrumentAny) {
+    let (mut engine, client_handler, engine_handler) = get_contingency_engine(&instrument);
+    let parent = contingent_order(
+        &instrument,
+        "O-1",
+        Some(ContingencyType::Oto),
+        Some("O-2"),
+        None,
+    );
+    let child = contingent_order(&instrument, "O-2", None, None, Some("O-1"));
+    submit_and_accept(&mut engine, &parent, "V-1");
+    engine.execute(submit_order(&child, RoutingInstructions::default()));
+
+    let submitted = get_saved_messages::<TradingCommand>(client_handler);
+    assert_eq!(submitted.len(), 1);
+    let TradingCommand::SubmitOrder(submit) = &submitted[0] else {
+        panic!("Expected `SubmitOrder`, was {}", submitted[0]);
+    };
+    assert_eq!(submit.client_order_id, parent.client_order_id());
+
+    engine.process(&fill_event(
+        &parent,
+        &instrument,
+        "E-1",
+        Quantity::from(100_000),
+        None,
+    ));
+
+    let released = get_saved_messages::<TradingCommand>(engine_handler);
+    assert_eq!(released.len(), 1);
+    let TradingCommand::SubmitOrder(submit) = &released[0] else {
+        panic!("Expected `SubmitOrder`, was {}", released[0]);
+    };
+    assert_eq!(submit.client_order_id, child.client_order_id());
+}
+
+fn get_inflight_engine(
+    instrument: &InstrumentAny,
+) -> (
+    ExecutionEngine,
+    Rc<RefCell<TestClock>>,
+    ShareableMessageHandler,
+) {
+    let mut engine = get_engine(
+        instrument,
+        ExecutionEngineConfig {
+            inflight_check_threshold_ms: 1_000,
+            ..Default::default()
+        },
+    );
+    let clock = Rc::new(RefCell::new(TestClock::new()));
+    engine.clock = clock.clone() as Rc<RefCell<dyn Clock>>;
+    let client_handler = get_message_saving_handler::<TradingCommand>(None);
+    engine
+        .msgbus
+        .borrow_mut()
+        .register("SIM.execute", client_handler.clone());
+    (engine, clock, client_handler)
+}
+
+#[rstest]
+fn test_inflight_order_queried_after_threshold(instrument: InstrumentAny) {
+    let (mut engine, clock, client_handler) = get_inflight_engine(&instrument);
+    let order = market_order(&instrument, "O-1", OrderSide::Buy);
+    engine.execute(submit_order(&order, RoutingInstructions::default()));
+    engine.process(&TestOrderEventStubs::order_submitted(
+        &order,
+        AccountId::from("SIM-001"),
+    ));
+
+    clock
+        .borrow_mut()
+        .advance_time(UnixNanos::from(999_999_999), true);
+    assert!(engine.check_inflight_orders().is_empty());
+
+    clock
+        .borrow_mut()
+        .advance_time(UnixNanos::from(1_000_000_000), true);
+    let timed_out = engine.check_inflight_orders();
+
+    let commands = get_saved_messages::<TradingCommand>(client_handler);
+    assert_eq!(timed_out.len(), 1);
+    assert_eq!(engine.inflight_orders_count(), 0);
+    assert_eq!(commands.len(), 2);
+    let TradingCommand::QueryOrder(query) = &commands[1] else {
+        panic!("Expected `QueryOrder`, was {}", commands[1]);
+    };
+    assert_eq!(query.client_order_id, order.client_order_id());
+    assert_eq!(query.ts_init, UnixNanos::from(1_000_000_000));
+}
+
+#[rstest]
+fn test_inflight_order_queried_by_check_timer_when_started(instrument: InstrumentAny) {
+    let (mut engine, clock, client_handler) = get_inflight_engine(&instrument);
+    engine.config.reconciliation = false;
+    engine.start();
+    let order = market_order(&instrument, "O-1", OrderSide::Buy);
+    engine.execute(submit_order(&order, RoutingInstructions::default()));
+    engine.process(&TestOrderEventStubs::order_submitted(
+        &order,
+        AccountId::from("SIM-001"),
+    ));
+
+    let events = clock
+        .borrow_mut()
+        .advance_time(UnixNanos::from(2_000_000_000), true);
+    let handlers = clock.borrow().match_handlers(events);
+    for handler in handlers {
+        handler.run();
+    }
+
+    let commands = get_saved_messages::<TradingCommand>(client_handler);
+    assert_eq!(engine.inflight_orders_count(), 0);
+    assert_eq!(commands.len(), 2);
+    let TradingCommand::QueryOrder(query) = &commands[1] else {
+        panic!("Expected `QueryOrder`, was {}", commands[1]);
+    };
+    assert_eq!(query.client_order_id, order.client_order_id());
+}
+
+#[rstest]
+fn test_inflight_order_resolved_when_accepted(instrument: InstrumentAny) {
+    let (mut engine, clock, client_handler) = get_inflight_engine(&instrument);
+    let order = market_order(&instrument, "O-1", OrderSide::Buy);
+    engine.execute(submit_order(&order, RoutingInstructions::default()));
+    assert_eq!(engine.inflight_orders_count(), 1);
+
+    engine.process(&TestOrderEventStubs::order_submitted(
+        &order,
+        AccountId::from("SIM-001"),
+    ));
+    engine.process(&TestOrderEventStubs::order_accepted(
+        &order,
+        AccountId::from("SIM-001"),
+        VenueOrderId::from("V-1"),
+    ));
+    clock
+        .borrow_mut()
+        .advance_time(UnixNanos::from(2_000_000_000), true);
+
+    assert_eq!(engine.inflight_orders_count(), 0);
+    assert!(engine.check_inflight_orders().is_empty());
+    assert_eq!(
+        get_saved_messages::<TradingCommand>(client_handler).len(),
+        1
+    );
+}
@@ -27,11 +27,18 @@
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`.
 //! - `python`: Enables Python bindings from `pyo3`.
 
+pub mod algorithm;
 pub mod client;
+pub mod contingency;
+pub mod emulator;
 pub mod engine;
 pub mod matching_core;
-pub mod messages;
 pub mod reports;
+pub mod trailing;
+
+/// Trading command messages, which live in `nautilus_common` so that other crates can send them
+/// without depending on the execution crate.
+pub use nautilus_common::messages::execution as messages;
 
 #[cfg(feature = "python")]
 pub mod python;
@@ -210,17 +210,44 @@ impl OrderMatchingCore {
 
     #[must_use]
     pub fn is_limit_matched(&self, order: &LimitOrderAny) -> bool {
-        match order.order_side_specified() {
-            OrderSideSpecified::Buy => self.ask.is_some_and(|a| a <= order.limit_px()),
-            OrderSideSpecified::Sell => self.bid.is_some_and(|b| b >= order.limit_px()),
-        }
+        self.is_limit_price_matched(order.order_side_specified(), order.limit_px())
     }
 
     #[must_use]
     pub fn is_stop_matched(&self, order: &StopOrderAny) -> bool {
-        match order.order_side_specified() {
-            OrderSideSpecified::Buy => self.ask.is_some_and(|a| a >= order.stop_px()),
-            OrderSideSpecified::Sell => self.bid.is_some_and(|b| b <= order.stop_px()),
+        match order {
+            StopOrderAny::MarketIfTouched(_) | StopOrderAny::LimitIfTouched(_) => {
+                self.is_touch_triggered(order.order_side_specified(), order.stop_px())
+            }
+            _ => self.is_stop_triggered(order.order_side_specified(), order.stop_px()),
+        }
+    }
+
+    /// Returns whether a limit order on the given `side` at `price` would match the market.
+    #[must_use]
+    pub fn is_limit_price_matched(&self, side: OrderSideSpecified, price: Price) -> bool {
+        match side {
+            OrderSideSpecified::Buy => self.ask.is_some_and(|a| a <= price),
+            OrderSideSpecified::Sell => self.bid.is_some_and(|b| b >= price),
+        }
+    }
+
+    /// Returns whether a stop order on the given `side` would be triggered at `trigger_price`.
+    #[must_use]
+    pub fn is_stop_triggered(&self, side: OrderSideSpecified, trigger_price: Price) -> bool {
+        match side {
+            OrderSideSpecified::Buy => self.ask.is_some_and(|a| a >= trigger_price),
+            OrderSideSpecified::Sell => self.bid.is_some_and(|b| b <= trigger_price),
+        }
+    }
+
+    /// Returns whether an if-touched order on the given `side` would be triggered at
+    /// `trigger_price`.
+    #[must_use]
+    pub fn is_touch_triggered(&self, side: OrderSideSpecified, trigger_price: Price) -> bool {
+        match side {
+            OrderSideSpecified::Buy => self.ask.is_some_and(|a| a <= trigger_price),
+            OrderSideSpecified::Sell => self.bid.is_some_and(|b| b >= trigger_price),
         }
     }
 }
@@ -497,6 +524,32 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    #[case(Price::from("101.00"), OrderSide::Buy, true)] // Trigger at ask
+    #[case(Price::from("100.50"), OrderSide::Buy, false)] // Trigger below ask
+    #[case(Price::from("100.00"), OrderSide::Sell, true)] // Trigger at bid
+    #[case(Price::from("100.50"), OrderSide::Sell, false)] // Trigger above bid
+    fn test_is_stop_matched_for_market_if_touched(
+        #[case] trigger_price: Price,
+        #[case] order_side: OrderSide,
+        #[case] expected: bool,
+    ) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut matching_core = create_matching_core(instrument_id, Price::from("0.01"));
+        matching_core.bid = Some(Price::from("100.00"));
+        matching_core.ask = Some(Price::from("101.00"));
+
+        let order = OrderTestBuilder::new(OrderType::MarketIfTouched)
+            .instrument_id(instrument_id)
+            .side(order_side)
+            .trigger_price(trigger_price)
+            .quantity(Quantity::from("100"))
+            .build();
+
+        let result = matching_core.is_stop_matched(&order.into());
+        assert_eq!(result, expected);
+    }
+
     #[rstest]
     #[case(OrderSide::Buy)]
     #[case(OrderSide::Sell)]
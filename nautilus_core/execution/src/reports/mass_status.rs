@@ -0,0 +1,104 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::identifiers::{AccountId, ClientId, InstrumentId, Venue, VenueOrderId};
+use serde::{Deserialize, Serialize};
+
+use super::{fill::FillReport, order::OrderStatusReport, position::PositionStatusReport};
+
+/// Represents an execution mass status report for an execution client, including the
+/// status of all orders, the fills for those orders, and all open positions.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionMassStatus {
+    pub client_id: ClientId,
+    pub account_id: AccountId,
+    pub venue: Venue,
+    pub report_id: UUID4,
+    pub ts_init: UnixNanos,
+    order_reports: HashMap<VenueOrderId, OrderStatusReport>,
+    fill_reports: HashMap<VenueOrderId, Vec<FillReport>>,
+    position_reports: HashMap<InstrumentId, Vec<PositionStatusReport>>,
+}
+
+impl ExecutionMassStatus {
+    /// Creates a new [`ExecutionMassStatus`] instance.
+    #[must_use]
+    pub fn new(
+        client_id: ClientId,
+        account_id: AccountId,
+        venue: Venue,
+        ts_init: UnixNanos,
+        report_id: Option<UUID4>,
+    ) -> Self {
+        Self {
+            client_id,
+            account_id,
+            venue,
+            report_id: report_id.unwrap_or_default(),
+            ts_init,
+            order_reports: HashMap::new(),
+            fill_reports: HashMap::new(),
+            position_reports: HashMap::new(),
+        }
+    }
+
+    /// Returns the order status reports keyed by venue order ID.
+    #[must_use]
+    pub const fn order_reports(&self) -> &HashMap<VenueOrderId, OrderStatusReport> {
+        &self.order_reports
+    }
+
+    /// Returns the fill reports keyed by venue order ID.
+    #[must_use]
+    pub const fn fill_reports(&self) -> &HashMap<VenueOrderId, Vec<FillReport>> {
+        &self.fill_reports
+    }
+
+    /// Returns the position status reports keyed by instrument ID.
+    #[must_use]
+    pub const fn position_reports(&self) -> &HashMap<InstrumentId, Vec<PositionStatusReport>> {
+        &self.position_reports
+    }
+
+    /// Adds the given order status `reports`, replacing any existing report for the same order.
+    pub fn add_order_reports(&mut self, reports: Vec<OrderStatusReport>) {
+        for report in reports {
+            self.order_reports.insert(report.venue_order_id, report);
+        }
+    }
+
+    /// Adds the given fill `reports`.
+    pub fn add_fill_reports(&mut self, reports: Vec<FillReport>) {
+        for report in reports {
+            self.fill_reports
+                .entry(report.venue_order_id)
+                .or_default()
+                .push(report);
+        }
+    }
+
+    /// Adds the given position status `reports`.
+    pub fn add_position_reports(&mut self, reports: Vec<PositionStatusReport>) {
+        for report in reports {
+            self.position_reports
+                .entry(report.instrument_id)
+                .or_default()
+                .push(report);
+        }
+    }
+}
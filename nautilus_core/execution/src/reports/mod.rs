@@ -14,5 +14,6 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod fill;
+pub mod mass_status;
 pub mod order;
 pub mod position;
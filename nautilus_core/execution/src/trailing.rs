@@ -0,0 +1,295 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trailing stop price calculations.
+
+use anyhow::Context;
+use nautilus_model::{
+    enums::{OrderSideSpecified, TrailingOffsetType, TriggerType},
+    orders::OrderAny,
+    types::Price,
+};
+
+/// Calculates the new trigger price (and limit price for trailing stop limit orders) for the
+/// given trailing stop `order` from the current market prices.
+///
+/// Each returned price is `None` unless it would tighten the order's current price, as a
+/// trailing stop only ever trails towards the market.
+///
+/// # Errors
+///
+/// This function returns an error if:
+/// - The `order` is not a trailing stop order.
+/// - The market price required by the order's trigger type is not available.
+/// - The order's trigger type or trailing offset type is not supported.
+pub fn trailing_stop_calculate(
+    price_increment: Price,
+    order: &OrderAny,
+    bid: Option<Price>,
+    ask: Option<Price>,
+    last: Option<Price>,
+) -> anyhow::Result<(Option<Price>, Option<Price>)> {
+    let (trigger_type, trailing_offset, trailing_offset_type, limit_offset) = match order {
+        OrderAny::TrailingStopMarket(order) => (
+            order.trigger_type,
+            order.trailing_offset,
+            order.trailing_offset_type,
+            None,
+        ),
+        OrderAny::TrailingStopLimit(order) => (
+            order.trigger_type,
+            order.trailing_offset,
+            order.trailing_offset_type,
+            Some(order.limit_offset),
+        ),
+        _ => anyhow::bail!(
+            "Invalid order type for trailing stop calculation, was {}",
+            order.order_type()
+        ),
+    };
+
+    let side = order.order_side_specified();
+    let basis_prices = match trigger_type {
+        TriggerType::Default | TriggerType::LastPrice | TriggerType::MarkPrice => {
+            vec![last.context("No last price for trailing stop calculation")?]
+        }
+        TriggerType::BidAsk => vec![bid_ask_basis(&side, bid, ask)?],
+        TriggerType::LastOrBidAsk => vec![
+            last.context("No last price for trailing stop calculation")?,
+            bid_ask_basis(&side, bid, ask)?,
+        ],
+        TriggerType::MidPoint => {
+            let bid = bid.context("No bid price for trailing stop calculation")?;
+            let ask = ask.context("No ask price for trailing stop calculation")?;
+            vec![Price::new(
+                (bid.as_f64() + ask.as_f64()) / 2.0,
+                price_increment.precision,
+            )]
+        }
+        _ => anyhow::bail!("Unsupported trigger type {trigger_type} for trailing stop"),
+    };
+
+    // The tightest basis is the one closest to the market on the stop side
+    let basis = match side {
+        OrderSideSpecified::Buy => basis_prices.into_iter().min(),
+        OrderSideSpecified::Sell => basis_prices.into_iter().max(),
+    }
+    .expect("Basis prices should not be empty");
+
+    let new_trigger_price = offset_price(
+        basis,
+        trailing_offset,
+        trailing_offset_type,
+        price_increment,
+        &side,
+    )?;
+    let new_trigger_price =
+        is_tighter(&side, new_trigger_price, order.trigger_price()).then_some(new_trigger_price);
+
+    let new_price = match limit_offset {
+        Some(limit_offset) => {
+            let price = offset_price(
+                basis,
+                limit_offset,
+                trailing_offset_type,
+                price_increment,
+                &side,
+            )?;
+            is_tighter(&side, price, order.price()).then_some(price)
+        }
+        None => None,
+    };
+
+    Ok((new_trigger_price, new_price))
+}
+
+fn bid_ask_basis(
+    side: &OrderSideSpecified,
+    bid: Option<Price>,
+    ask: Option<Price>,
+) -> anyhow::Result<Price> {
+    match side {
+        OrderSideSpecified::Buy => ask.context("No ask price for trailing stop calculation"),
+        OrderSideSpecified::Sell => bid.context("No bid price for trailing stop calculation"),
+    }
+}
+
+fn offset_price(
+    basis: Price,
+    offset: Price,
+    offset_type: TrailingOffsetType,
+    price_increment: Price,
+    side: &OrderSideSpecified,
+) -> anyhow::Result<Price> {
+    let offset = match offset_type {
+        TrailingOffsetType::Price => offset.as_f64(),
+        TrailingOffsetType::BasisPoints => basis.as_f64() * offset.as_f64() / 10_000.0,
+        TrailingOffsetType::Ticks => offset.as_f64() * price_increment.as_f64(),
+        _ => anyhow::bail!("Unsupported trailing offset type {offset_type}"),
+    };
+    let value = match side {
+        OrderSideSpecified::Buy => basis.as_f64() + offset,
+        OrderSideSpecified::Sell => basis.as_f64() - offset,
+    };
+    Ok(Price::new(value, price_increment.precision))
+}
+
+fn is_tighter(side: &OrderSideSpecified, price: Price, current: Option<Price>) -> bool {
+    match (side, current) {
+        (_, None) => true,
+        (OrderSideSpecified::Buy, Some(current)) => price < current,
+        (OrderSideSpecified::Sell, Some(current)) => price > current,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        identifiers::InstrumentId,
+        orders::builder::OrderTestBuilder,
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn trailing_stop_market(
+        side: OrderSide,
+        trigger_price: &str,
+        trigger_type: TriggerType,
+        trailing_offset: &str,
+        trailing_offset_type: TrailingOffsetType,
+    ) -> OrderAny {
+        OrderTestBuilder::new(OrderType::TrailingStopMarket)
+            .instrument_id(InstrumentId::from("ETHUSDT-PERP.BINANCE"))
+            .side(side)
+            .trigger_price(Price::from(trigger_price))
+            .trigger_type(trigger_type)
+            .trailing_offset(Price::from(trailing_offset))
+            .trailing_offset_type(trailing_offset_type)
+            .quantity(Quantity::from("1.000"))
+            .build()
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, "1480.00", Some("1490.00"))]
+    #[case(OrderSide::Sell, "1495.00", None)]
+    #[case(OrderSide::Buy, "1520.00", Some("1510.00"))]
+    #[case(OrderSide::Buy, "1505.00", None)]
+    fn test_trailing_stop_market_last_price(
+        #[case] side: OrderSide,
+        #[case] trigger_price: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let order = trailing_stop_market(
+            side,
+            trigger_price,
+            TriggerType::LastPrice,
+            "10.00",
+            TrailingOffsetType::Price,
+        );
+
+        let (new_trigger_price, new_price) = trailing_stop_calculate(
+            Price::from("0.01"),
+            &order,
+            None,
+            None,
+            Some(Price::from("1500.00")),
+        )
+        .unwrap();
+
+        assert_eq!(new_trigger_price, expected.map(Price::from));
+        assert_eq!(new_price, None);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, TriggerType::BidAsk, "1485.00")]
+    #[case(OrderSide::Buy, TriggerType::BidAsk, "1517.02")]
+    #[case(OrderSide::Sell, TriggerType::MidPoint, "1485.99")]
+    fn test_trailing_stop_market_basis_points(
+        #[case] side: OrderSide,
+        #[case] trigger_type: TriggerType,
+        #[case] expected: &str,
+    ) {
+        let trigger_price = match side {
+            OrderSide::Buy => "1600.00",
+            _ => "1400.00",
+        };
+        let order = trailing_stop_market(
+            side,
+            trigger_price,
+            trigger_type,
+            "100",
+            TrailingOffsetType::BasisPoints,
+        );
+
+        let (new_trigger_price, _) = trailing_stop_calculate(
+            Price::from("0.01"),
+            &order,
+            Some(Price::from("1500.00")),
+            Some(Price::from("1502.00")),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(new_trigger_price, Some(Price::from(expected)));
+    }
+
+    #[rstest]
+    fn test_trailing_stop_limit_ticks() {
+        let order = OrderTestBuilder::new(OrderType::TrailingStopLimit)
+            .instrument_id(InstrumentId::from("ETHUSDT-PERP.BINANCE"))
+            .side(OrderSide::Sell)
+            .price(Price::from("1470.00"))
+            .trigger_price(Price::from("1480.00"))
+            .trigger_type(TriggerType::BidAsk)
+            .limit_offset(Price::from("20"))
+            .trailing_offset(Price::from("10"))
+            .trailing_offset_type(TrailingOffsetType::Ticks)
+            .quantity(Quantity::from("1.000"))
+            .build();
+
+        let (new_trigger_price, new_price) = trailing_stop_calculate(
+            Price::from("0.50"),
+            &order,
+            Some(Price::from("1500.00")),
+            Some(Price::from("1500.50")),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(new_trigger_price, Some(Price::from("1495.00")));
+        assert_eq!(new_price, Some(Price::from("1490.00")));
+    }
+
+    #[rstest]
+    fn test_trailing_stop_without_market_price_errors() {
+        let order = trailing_stop_market(
+            OrderSide::Sell,
+            "1480.00",
+            TriggerType::LastPrice,
+            "10.00",
+            TrailingOffsetType::Price,
+        );
+
+        let result = trailing_stop_calculate(Price::from("0.01"), &order, None, None, None);
+
+        assert!(result.is_err());
+    }
+}
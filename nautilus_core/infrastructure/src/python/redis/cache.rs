@@ -63,6 +63,26 @@ impl RedisCacheDatabase {
         }
     }
 
+    #[pyo3(name = "read_range")]
+    fn py_read_range(
+        &mut self,
+        py: Python,
+        key: &str,
+        min: u64,
+        max: u64,
+    ) -> PyResult<Vec<PyObject>> {
+        match self.read_range(key, min, max) {
+            Ok(result) => {
+                let vec_py_bytes = result
+                    .into_iter()
+                    .map(|r| PyBytes::new_bound(py, r.as_ref()).into())
+                    .collect::<Vec<PyObject>>();
+                Ok(vec_py_bytes)
+            }
+            Err(e) => Err(to_pyruntime_err(e)),
+        }
+    }
+
     #[pyo3(name = "insert")]
     fn py_insert(&mut self, key: String, payload: Vec<Vec<u8>>) -> PyResult<()> {
         let payload: Vec<Bytes> = payload.into_iter().map(Bytes::from).collect();
@@ -19,7 +19,7 @@ use bytes::Bytes;
 use nautilus_common::{
     cache::database::CacheDatabaseAdapter, custom::CustomData, runtime::get_runtime, signal::Signal,
 };
-use nautilus_core::python::to_pyruntime_err;
+use nautilus_core::{nanos::UnixNanos, python::to_pyruntime_err};
 use nautilus_model::{
     data::{Bar, DataType, QuoteTick, TradeTick},
     events::PositionSnapshot,
@@ -138,6 +138,28 @@ impl PostgresCacheDatabase {
         })
     }
 
+    #[pyo3(name = "load_fills")]
+    #[pyo3(signature = (instrument_id, start=None, end=None))]
+    fn py_load_fills(
+        &self,
+        py: Python,
+        instrument_id: InstrumentId,
+        start: Option<u64>,
+        end: Option<u64>,
+    ) -> PyResult<Vec<PyObject>> {
+        get_runtime().block_on(async {
+            let result = DatabaseQueries::load_fills(
+                &self.pool,
+                &instrument_id,
+                start.map(UnixNanos::from),
+                end.map(UnixNanos::from),
+            )
+            .await
+            .map_err(to_pyruntime_err)?;
+            Ok(result.into_iter().map(|fill| fill.into_py(py)).collect())
+        })
+    }
+
     #[pyo3(name = "load_account")]
     fn py_load_account(&self, py: Python, account_id: AccountId) -> PyResult<Option<PyObject>> {
         get_runtime().block_on(async {
@@ -41,9 +41,10 @@ use nautilus_model::{
     orderbook::OrderBook,
     orders::OrderAny,
     position::Position,
-    types::Currency,
+    types::{Currency, Money},
 };
 use redis::{Commands, Connection, Pipeline, RedisError};
+use serde::{de::DeserializeOwned, Serialize};
 use ustr::Ustr;
 
 use super::{REDIS_DELIMITER, REDIS_FLUSHDB};
@@ -65,9 +66,11 @@ const SYNTHETICS: &str = "synthetics";
 const ACCOUNTS: &str = "accounts";
 const ORDERS: &str = "orders";
 const POSITIONS: &str = "positions";
+const FILLS: &str = "fills";
 const ACTORS: &str = "actors";
 const STRATEGIES: &str = "strategies";
 const SNAPSHOTS: &str = "snapshots";
+const POSITION_STATES: &str = "position_states";
 const HEALTH: &str = "health";
 
 // Index keys
@@ -213,12 +216,41 @@ impl RedisCacheDatabase {
             ACCOUNTS => read_list(&mut self.con, &key),
             ORDERS => read_list(&mut self.con, &key),
             POSITIONS => read_list(&mut self.con, &key),
+            FILLS => read_sorted_set(&mut self.con, &key, 0, u64::MAX),
             ACTORS => read_string(&mut self.con, &key),
             STRATEGIES => read_string(&mut self.con, &key),
+            SNAPSHOTS => read_list(&mut self.con, &key),
             _ => anyhow::bail!("Unsupported operation: `read` for collection '{collection}'"),
         }
     }
 
+    /// Reads the members of a sorted collection with scores between `min` and `max` (inclusive).
+    ///
+    /// Scores are stored as `f64`, so callers requiring nanosecond precision at the range
+    /// boundaries should filter the returned members on their exact timestamps.
+    pub fn read_range(&mut self, key: &str, min: u64, max: u64) -> anyhow::Result<Vec<Bytes>> {
+        let collection = get_collection_key(key)?;
+        let key = format!("{}{REDIS_DELIMITER}{}", self.trader_key, key);
+
+        match collection {
+            FILLS => read_sorted_set(&mut self.con, &key, min, max),
+            _ => anyhow::bail!("Unsupported operation: `read_range` for collection '{collection}'"),
+        }
+    }
+
+    fn send(
+        &self,
+        op_type: DatabaseOperation,
+        key: String,
+        payload: Vec<Bytes>,
+    ) -> anyhow::Result<()> {
+        let op = DatabaseCommand::new(op_type, key, Some(payload));
+        match self.tx.send(op) {
+            Ok(_) => Ok(()),
+            Err(e) => anyhow::bail!("{FAILED_TX_CHANNEL}: {e}"),
+        }
+    }
+
     pub fn insert(&mut self, key: String, payload: Option<Vec<Bytes>>) -> anyhow::Result<()> {
         let op = DatabaseCommand::new(DatabaseOperation::Insert, key, payload);
         match self.tx.send(op) {
@@ -387,6 +419,16 @@ fn read_list(conn: &mut Connection, key: &str) -> anyhow::Result<Vec<Bytes>> {
     Ok(result)
 }
 
+fn read_sorted_set(
+    conn: &mut Connection,
+    key: &str,
+    min: u64,
+    max: u64,
+) -> anyhow::Result<Vec<Bytes>> {
+    let result: Vec<Bytes> = conn.zrangebyscore(key, min as f64, max as f64)?;
+    Ok(result)
+}
+
 fn insert(
     pipe: &mut Pipeline,
     collection: &str,
@@ -425,6 +467,11 @@ fn insert(
             insert_list(pipe, key, value[0].as_ref());
             Ok(())
         }
+        FILLS => {
+            let score = parse_score(value.get(1))?;
+            insert_sorted_set(pipe, key, value[0].as_ref(), score);
+            Ok(())
+        }
         ACTORS => {
             insert_string(pipe, key, value[0].as_ref());
             Ok(())
@@ -512,6 +559,16 @@ fn insert_list(pipe: &mut Pipeline, key: &str, value: &[u8]) {
     pipe.rpush(key, value);
 }
 
+fn insert_sorted_set(pipe: &mut Pipeline, key: &str, value: &[u8], score: f64) {
+    pipe.zadd(key, value, score);
+}
+
+fn parse_score(value: Option<&Bytes>) -> anyhow::Result<f64> {
+    let value = value.ok_or_else(|| anyhow::anyhow!("Missing score in `payload`"))?;
+    let score: u64 = std::str::from_utf8(value)?.parse()?;
+    Ok(score as f64)
+}
+
 fn update(
     pipe: &mut Pipeline,
     collection: &str,
@@ -660,6 +717,35 @@ fn deserialize_payload(
     }
 }
 
+fn serialize_value<T: Serialize>(
+    encoding: SerializationEncoding,
+    value: &T,
+) -> anyhow::Result<Bytes> {
+    let bytes = match encoding {
+        SerializationEncoding::MsgPack => rmp_serde::to_vec_named(value)?,
+        SerializationEncoding::Json => serde_json::to_vec(value)?,
+    };
+    Ok(Bytes::from(bytes))
+}
+
+fn deserialize_value<T: DeserializeOwned>(
+    encoding: SerializationEncoding,
+    bytes: &[u8],
+) -> anyhow::Result<T> {
+    match encoding {
+        SerializationEncoding::MsgPack => Ok(rmp_serde::from_slice(bytes)?),
+        SerializationEncoding::Json => Ok(serde_json::from_slice(bytes)?),
+    }
+}
+
+fn position_snapshots_key(position_id: &PositionId) -> String {
+    format!("{SNAPSHOTS}{REDIS_DELIMITER}{POSITIONS}{REDIS_DELIMITER}{position_id}")
+}
+
+fn position_state_snapshots_key(position_id: &PositionId) -> String {
+    format!("{SNAPSHOTS}{REDIS_DELIMITER}{POSITION_STATES}{REDIS_DELIMITER}{position_id}")
+}
+
 #[allow(dead_code)] // Under development
 pub struct RedisCacheDatabaseAdapter {
     pub encoding: SerializationEncoding,
@@ -794,6 +880,25 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
         Ok(positions)
     }
 
+    fn load_position_snapshots(&mut self) -> anyhow::Result<HashMap<PositionId, Vec<Position>>> {
+        let mut snapshots = HashMap::new();
+        let prefix = format!(
+            "{}{REDIS_DELIMITER}{SNAPSHOTS}{REDIS_DELIMITER}{POSITIONS}{REDIS_DELIMITER}",
+            self.database.trader_key
+        );
+
+        for key in scan_keys(&mut self.database.con, format!("{prefix}*"))? {
+            let position_id = PositionId::from(&key[prefix.len()..]);
+            let positions = read_list(&mut self.database.con, &key)?
+                .iter()
+                .map(|bytes| deserialize_value(self.encoding, bytes))
+                .collect::<anyhow::Result<Vec<Position>>>()?;
+            snapshots.insert(position_id, positions);
+        }
+
+        Ok(snapshots)
+    }
+
     fn load_index_order_position(&self) -> anyhow::Result<HashMap<ClientOrderId, Position>> {
         todo!()
     }
@@ -961,8 +1066,32 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
         todo!()
     }
 
-    fn snapshot_position_state(&self, position: &Position) -> anyhow::Result<()> {
-        todo!()
+    fn snapshot_position(
+        &self,
+        position_id: &PositionId,
+        snapshot: &Position,
+    ) -> anyhow::Result<()> {
+        let payload = serialize_value(self.encoding, snapshot)?;
+        self.database.send(
+            DatabaseOperation::Insert,
+            position_snapshots_key(position_id),
+            vec![payload],
+        )
+    }
+
+    fn snapshot_position_state(
+        &self,
+        position: &Position,
+        ts_snapshot: UnixNanos,
+        unrealized_pnl: Option<Money>,
+    ) -> anyhow::Result<()> {
+        let snapshot = PositionSnapshot::new(position, unrealized_pnl, ts_snapshot);
+        let payload = serialize_value(self.encoding, &snapshot)?;
+        self.database.send(
+            DatabaseOperation::Insert,
+            position_state_snapshots_key(&position.id),
+            vec![payload],
+        )
     }
 
     fn heartbeat(&self, timestamp: UnixNanos) -> anyhow::Result<()> {
@@ -991,6 +1120,21 @@ mod tests {
         assert!(key.ends_with(&instance_id.to_string()));
     }
 
+    #[rstest]
+    fn test_parse_score() {
+        let value = Bytes::from("1700000000000000000");
+        assert_eq!(
+            parse_score(Some(&value)).unwrap(),
+            1_700_000_000_000_000_000.0
+        );
+    }
+
+    #[rstest]
+    fn test_parse_score_when_missing_or_invalid() {
+        assert!(parse_score(None).is_err());
+        assert!(parse_score(Some(&Bytes::from("abc"))).is_err());
+    }
+
     #[rstest]
     fn test_get_collection_key_valid() {
         let key = "collection:123";
@@ -27,7 +27,7 @@ use futures::stream::Stream;
 use nautilus_common::{
     msgbus::{
         database::{BusMessage, DatabaseConfig, MessageBusConfig, MessageBusDatabaseAdapter},
-        CLOSE_TOPIC,
+        is_matching, CLOSE_TOPIC,
     },
     runtime::get_runtime,
 };
@@ -38,7 +38,8 @@ use nautilus_core::{
 use nautilus_cryptography::providers::install_cryptographic_provider;
 use nautilus_model::identifiers::TraderId;
 use redis::*;
-use streams::StreamReadOptions;
+use streams::{StreamMaxlen, StreamReadOptions};
+use ustr::Ustr;
 
 use super::{await_handle, REDIS_MINID, REDIS_XTRIM};
 use crate::redis::{create_redis_connection, get_stream_key};
@@ -60,6 +61,7 @@ pub struct RedisMessageBusDatabase {
     pub trader_id: TraderId,
     /// The instance ID for this message bus database.
     pub instance_id: UUID4,
+    topics_filter: Option<Vec<Ustr>>,
     pub_tx: tokio::sync::mpsc::UnboundedSender<BusMessage>,
     pub_handle: Option<tokio::task::JoinHandle<()>>,
     stream_rx: Option<tokio::sync::mpsc::Receiver<BusMessage>>,
@@ -81,6 +83,10 @@ impl MessageBusDatabaseAdapter for RedisMessageBusDatabase {
         install_cryptographic_provider();
 
         let config_clone = config.clone();
+        let topics_filter = config
+            .topics_filter
+            .as_ref()
+            .map(|patterns| patterns.iter().map(Ustr::from).collect());
         let db_config = config
             .database
             .clone()
@@ -97,6 +103,7 @@ impl MessageBusDatabaseAdapter for RedisMessageBusDatabase {
 
         // Conditionally create stream task and channel if external streams configured
         let external_streams = config.external_streams.clone().unwrap_or_default();
+        let replay = config.replay_external_streams;
         let stream_signal = Arc::new(AtomicBool::new(false));
         let (stream_rx, stream_handle) = if !external_streams.is_empty() {
             let stream_signal_clone = stream_signal.clone();
@@ -104,9 +111,15 @@ impl MessageBusDatabaseAdapter for RedisMessageBusDatabase {
             (
                 Some(stream_rx),
                 Some(get_runtime().spawn(async move {
-                    stream_messages(stream_tx, db_config, external_streams, stream_signal_clone)
-                        .await
-                        .expect("Error spawning task '{MSGBUS_STREAM}'}");
+                    stream_messages(
+                        stream_tx,
+                        db_config,
+                        external_streams,
+                        replay,
+                        stream_signal_clone,
+                    )
+                    .await
+                    .expect("Error spawning task '{MSGBUS_STREAM}'}");
                 })),
             )
         } else {
@@ -130,6 +143,7 @@ impl MessageBusDatabaseAdapter for RedisMessageBusDatabase {
         Ok(Self {
             trader_id,
             instance_id,
+            topics_filter,
             pub_tx,
             pub_handle,
             stream_rx,
@@ -147,7 +161,13 @@ impl MessageBusDatabaseAdapter for RedisMessageBusDatabase {
     }
 
     /// Publishes a message with the given `topic` and `payload`.
+    ///
+    /// Messages with topics not matching any configured `topics_filter` pattern are dropped.
     fn publish(&self, topic: String, payload: Bytes) {
+        if !self.is_publishable(&topic) {
+            return;
+        }
+
         let msg = BusMessage { topic, payload };
         if let Err(e) = self.pub_tx.send(msg) {
             log::error!("Failed to send message: {e}");
@@ -183,6 +203,12 @@ impl MessageBusDatabaseAdapter for RedisMessageBusDatabase {
 }
 
 impl RedisMessageBusDatabase {
+    /// Returns whether messages on the given `topic` should be published externally.
+    #[must_use]
+    pub fn is_publishable(&self, topic: &str) -> bool {
+        is_topic_publishable(self.topics_filter.as_deref(), topic)
+    }
+
     /// Gets the stream receiver for this instance.
     pub fn get_stream_receiver(
         &mut self,
@@ -231,6 +257,7 @@ pub async fn publish_messages(
         .filter(|&mins| mins > 0)
         .map(|mins| Duration::from_secs(mins as u64 * 60));
     let mut last_trim_index: HashMap<String, usize> = HashMap::new();
+    let stream_maxlen = config.stream_maxlen.map(StreamMaxlen::Approx);
 
     // Buffering
     let mut buffer: VecDeque<BusMessage> = VecDeque::new();
@@ -243,6 +270,7 @@ pub async fn publish_messages(
                 &mut con,
                 &stream_key,
                 config.stream_per_topic,
+                stream_maxlen,
                 autotrim_duration,
                 &mut last_trim_index,
                 &mut buffer,
@@ -272,6 +300,7 @@ pub async fn publish_messages(
             &mut con,
             &stream_key,
             config.stream_per_topic,
+            stream_maxlen,
             autotrim_duration,
             &mut last_trim_index,
             &mut buffer,
@@ -286,6 +315,7 @@ fn drain_buffer(
     conn: &mut Connection,
     stream_key: &str,
     stream_per_topic: bool,
+    stream_maxlen: Option<StreamMaxlen>,
     autotrim_duration: Option<Duration>,
     last_trim_index: &mut HashMap<String, usize>,
     buffer: &mut VecDeque<BusMessage>,
@@ -302,7 +332,10 @@ fn drain_buffer(
             true => format!("{stream_key}:{}", &msg.topic),
             false => stream_key.to_string(),
         };
-        pipe.xadd(&stream_key, "*", &items);
+        match stream_maxlen {
+            Some(maxlen) => pipe.xadd_maxlen(&stream_key, maxlen, "*", &items),
+            None => pipe.xadd(&stream_key, "*", &items),
+        };
 
         if autotrim_duration.is_none() {
             continue; // Nothing else to do
@@ -341,6 +374,7 @@ pub async fn stream_messages(
     tx: tokio::sync::mpsc::Sender<BusMessage>,
     config: DatabaseConfig,
     stream_keys: Vec<String>,
+    replay: bool,
     stream_signal: Arc<AtomicBool>,
 ) -> anyhow::Result<()> {
     tracing::info!("Starting message streaming");
@@ -353,10 +387,15 @@ pub async fn stream_messages(
 
     tracing::debug!("Listening to streams: [{}]", stream_keys.join(", "));
 
-    // Start streaming from current timestamp
-    let clock = get_atomic_clock_realtime();
-    let timestamp_ms = clock.get_time_ms();
-    let mut last_id = timestamp_ms.to_string();
+    // Start streaming from the beginning of the streams when replaying,
+    // otherwise from the current timestamp.
+    let mut last_id = if replay {
+        tracing::info!("Replaying streams from start");
+        "0".to_string()
+    } else {
+        let clock = get_atomic_clock_realtime();
+        clock.get_time_ms().to_string()
+    };
 
     let opts = StreamReadOptions::default().block(100);
 
@@ -406,6 +445,16 @@ pub async fn stream_messages(
     Ok(())
 }
 
+fn is_topic_publishable(topics_filter: Option<&[Ustr]>, topic: &str) -> bool {
+    match topics_filter {
+        Some(patterns) => {
+            let topic = Ustr::from(topic);
+            patterns.iter().any(|pattern| is_matching(&topic, pattern))
+        }
+        None => true,
+    }
+}
+
 fn decode_bus_message(stream_msg: &redis::Value) -> anyhow::Result<BusMessage> {
     if let redis::Value::Array(stream_msg) = stream_msg {
         if stream_msg.len() < 4 {
@@ -488,6 +537,29 @@ mod tests {
 
     use super::*;
 
+    #[rstest]
+    fn test_is_topic_publishable_without_filter() {
+        assert!(is_topic_publishable(None, "data.quotes.BINANCE.ETHUSDT"));
+    }
+
+    #[rstest]
+    #[case("data.quotes.BINANCE.ETHUSDT", true)]
+    #[case("events.order.S-001", true)]
+    #[case("data.trades.BINANCE.ETHUSDT", false)]
+    #[case("events.position.S-001", false)]
+    fn test_is_topic_publishable_with_filter(#[case] topic: &str, #[case] expected: bool) {
+        let filter = vec![Ustr::from("data.quotes.*"), Ustr::from("events.order.*")];
+        assert_eq!(is_topic_publishable(Some(&filter), topic), expected);
+    }
+
+    #[rstest]
+    fn test_is_topic_publishable_with_empty_filter() {
+        assert!(!is_topic_publishable(
+            Some(&[]),
+            "data.quotes.BINANCE.ETHUSDT"
+        ));
+    }
+
     #[rstest]
     fn test_decode_bus_message_valid() {
         let stream_msg = Value::Array(vec![
@@ -606,6 +678,7 @@ mod serial_tests {
                 tx,
                 DatabaseConfig::default(),
                 external_streams,
+                false,
                 stream_signal_clone,
             )
             .await
@@ -660,6 +733,7 @@ mod serial_tests {
                 tx,
                 DatabaseConfig::default(),
                 external_streams,
+                false,
                 stream_signal_clone,
             )
             .await
@@ -707,6 +781,7 @@ mod serial_tests {
                 tx,
                 DatabaseConfig::default(),
                 external_streams,
+                false,
                 stream_signal_clone,
             )
             .await
@@ -725,6 +800,66 @@ mod serial_tests {
         flush_redis(&mut con).unwrap()
     }
 
+    #[rstest]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_stream_messages_replay(redis_connection: redis::Connection) {
+        let mut con = redis_connection;
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<BusMessage>(100);
+
+        let trader_id = TraderId::from("tester-001");
+        let instance_id = UUID4::new();
+        let mut config = MessageBusConfig::default();
+        config.database = Some(DatabaseConfig::default());
+
+        let stream_key = get_stream_key(trader_id, instance_id, &config);
+        let external_streams = vec![stream_key.clone()];
+        let stream_signal = Arc::new(AtomicBool::new(false));
+        let stream_signal_clone = stream_signal.clone();
+
+        // Publish test messages in the past, which are only received when replaying
+        let _: () = con
+            .xadd(
+                &stream_key,
+                "1-0",
+                &[("topic", "topic1"), ("payload", "data1")],
+            )
+            .unwrap();
+        let _: () = con
+            .xadd(
+                &stream_key,
+                "2-0",
+                &[("topic", "topic2"), ("payload", "data2")],
+            )
+            .unwrap();
+
+        // Start the message streaming task
+        let handle = tokio::spawn(async move {
+            stream_messages(
+                tx,
+                DatabaseConfig::default(),
+                external_streams,
+                true,
+                stream_signal_clone,
+            )
+            .await
+            .unwrap();
+        });
+
+        // Receive and verify the messages in stream order
+        let msg1 = rx.recv().await.unwrap();
+        let msg2 = rx.recv().await.unwrap();
+        assert_eq!(msg1.topic, "topic1");
+        assert_eq!(msg1.payload, Bytes::from("data1"));
+        assert_eq!(msg2.topic, "topic2");
+        assert_eq!(msg2.payload, Bytes::from("data2"));
+
+        // Shutdown and cleanup
+        rx.close();
+        stream_signal.store(true, Ordering::Relaxed);
+        handle.await.unwrap();
+        flush_redis(&mut con).unwrap()
+    }
+
     #[rstest]
     #[tokio::test(flavor = "multi_thread")]
     async fn test_publish_messages(redis_connection: redis::Connection) {
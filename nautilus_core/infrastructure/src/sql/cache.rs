@@ -35,7 +35,7 @@ use nautilus_model::{
     orderbook::OrderBook,
     orders::OrderAny,
     position::Position,
-    types::Currency,
+    types::{Currency, Money},
 };
 use sqlx::{postgres::PgConnectOptions, PgPool};
 use ustr::Ustr;
@@ -65,6 +65,7 @@ pub enum DatabaseQuery {
     AddInstrument(InstrumentAny),
     AddOrder(OrderAny, Option<ClientId>, bool),
     AddPositionSnapshot(PositionSnapshot),
+    AddPositionCycleSnapshot(PositionId, Position),
     AddAccount(AccountAny, bool),
     AddSignal(Signal),
     AddCustom(CustomData),
@@ -336,6 +337,28 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
         todo!()
     }
 
+    fn load_position_snapshots(&mut self) -> anyhow::Result<HashMap<PositionId, Vec<Position>>> {
+        let pool = self.pool.clone();
+        let (tx, rx) = std::sync::mpsc::channel();
+        tokio::spawn(async move {
+            let result = DatabaseQueries::load_position_snapshots(&pool).await;
+            match result {
+                Ok(snapshots) => {
+                    if let Err(e) = tx.send(snapshots) {
+                        log::error!("Failed to send position snapshots: {e:?}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to load position snapshots: {e:?}");
+                    if let Err(e) = tx.send(HashMap::new()) {
+                        log::error!("Failed to send empty position snapshots: {e:?}");
+                    }
+                }
+            }
+        });
+        Ok(rx.recv()?)
+    }
+
     fn load_index_order_position(&self) -> anyhow::Result<HashMap<ClientOrderId, Position>> {
         todo!()
     }
@@ -737,8 +760,27 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
         todo!()
     }
 
-    fn snapshot_position_state(&self, position: &Position) -> anyhow::Result<()> {
-        todo!()
+    fn snapshot_position(
+        &self,
+        position_id: &PositionId,
+        snapshot: &Position,
+    ) -> anyhow::Result<()> {
+        let query = DatabaseQuery::AddPositionCycleSnapshot(*position_id, snapshot.clone());
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to send query snapshot_position to database message handler: {e}"
+            )
+        })
+    }
+
+    fn snapshot_position_state(
+        &self,
+        position: &Position,
+        ts_snapshot: UnixNanos,
+        unrealized_pnl: Option<Money>,
+    ) -> anyhow::Result<()> {
+        let snapshot = PositionSnapshot::new(position, unrealized_pnl, ts_snapshot);
+        self.add_position_snapshot(&snapshot)
     }
 
     fn heartbeat(&self, timestamp: UnixNanos) -> anyhow::Result<()> {
@@ -879,6 +921,9 @@ async fn drain_buffer(pool: &PgPool, buffer: &mut VecDeque<DatabaseQuery>) {
             DatabaseQuery::AddPositionSnapshot(snapshot) => {
                 DatabaseQueries::add_position_snapshot(pool, snapshot).await
             }
+            DatabaseQuery::AddPositionCycleSnapshot(position_id, snapshot) => {
+                DatabaseQueries::add_position_cycle_snapshot(pool, position_id, snapshot).await
+            }
             DatabaseQuery::AddAccount(account_any, updated) => match account_any {
                 AccountAny::Cash(account) => {
                     DatabaseQueries::add_account(pool, "CASH", updated, Box::new(account)).await
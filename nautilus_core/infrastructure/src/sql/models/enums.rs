@@ -235,6 +235,8 @@ impl sqlx::Encode<'_, sqlx::Postgres> for BarAggregationModel {
             BarAggregation::Day => "DAY",
             BarAggregation::Week => "WEEK",
             BarAggregation::Month => "MONTH",
+            BarAggregation::Renko => "RENKO",
+            BarAggregation::Range => "RANGE",
         };
         <&str as sqlx::Encode<sqlx::Postgres>>::encode(bar_aggregation_str, buf)
     }
@@ -16,13 +16,17 @@
 use std::collections::HashMap;
 
 use nautilus_common::{custom::CustomData, signal::Signal};
+use nautilus_core::nanos::UnixNanos;
 use nautilus_model::{
     accounts::{any::AccountAny, base::Account},
     data::{Bar, DataType, QuoteTick, TradeTick},
-    events::{position::snapshot::PositionSnapshot, AccountState, OrderEvent, OrderEventAny},
-    identifiers::{AccountId, ClientId, ClientOrderId, InstrumentId},
+    events::{
+        position::snapshot::PositionSnapshot, AccountState, OrderEvent, OrderEventAny, OrderFilled,
+    },
+    identifiers::{AccountId, ClientId, ClientOrderId, InstrumentId, PositionId},
     instruments::{Instrument, InstrumentAny},
     orders::{Order, OrderAny},
+    position::Position,
     types::{AccountBalance, Currency, MarginBalance},
 };
 use sqlx::{PgPool, Row};
@@ -341,6 +345,46 @@ impl DatabaseQueries {
             .map_err(|e| anyhow::anyhow!("Failed to commit transaction: {e}"))
     }
 
+    pub async fn add_position_cycle_snapshot(
+        pool: &PgPool,
+        position_id: PositionId,
+        snapshot: Position,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO "position_snapshot" (id, position_id, value, created_at)
+            VALUES ($1, $2, $3, CURRENT_TIMESTAMP)
+            ON CONFLICT (id) DO NOTHING
+        "#,
+        )
+        .bind(snapshot.id.to_string())
+        .bind(position_id.to_string())
+        .bind(serde_json::to_value(&snapshot)?)
+        .execute(pool)
+        .await
+        .map(|_| ())
+        .map_err(|e| anyhow::anyhow!("Failed to insert into position_snapshot table: {e}"))
+    }
+
+    pub async fn load_position_snapshots(
+        pool: &PgPool,
+    ) -> anyhow::Result<HashMap<PositionId, Vec<Position>>> {
+        let rows = sqlx::query(
+            r#"SELECT position_id, value FROM "position_snapshot" ORDER BY created_at ASC"#,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to load position snapshots: {e}"))?;
+
+        let mut snapshots: HashMap<PositionId, Vec<Position>> = HashMap::new();
+        for row in rows {
+            let position_id = PositionId::from(row.get::<&str, _>("position_id"));
+            let snapshot: Position = serde_json::from_value(row.get("value"))?;
+            snapshots.entry(position_id).or_default().push(snapshot);
+        }
+        Ok(snapshots)
+    }
+
     pub async fn check_if_order_initialized_exists(
         pool: &PgPool,
         client_order_id: ClientOrderId,
@@ -492,6 +536,39 @@ impl DatabaseQueries {
         .map_err(|e| anyhow::anyhow!("Failed to load order events: {e}"))
     }
 
+    pub async fn load_fills(
+        pool: &PgPool,
+        instrument_id: &InstrumentId,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<Vec<OrderFilled>> {
+        let start = start.map_or(0, |ts| ts.as_u64());
+        let end = end.map_or(u64::MAX, |ts| ts.as_u64());
+        sqlx::query_as::<_, OrderEventAnyModel>(
+            r#"
+            SELECT * FROM "order_event" event
+            WHERE event.kind = 'OrderFilled'
+            AND event.instrument_id = $1
+            AND event.ts_event::NUMERIC BETWEEN $2::NUMERIC AND $3::NUMERIC
+            ORDER BY event.ts_event::NUMERIC ASC
+        "#,
+        )
+        .bind(instrument_id.to_string())
+        .bind(start.to_string())
+        .bind(end.to_string())
+        .fetch_all(pool)
+        .await
+        .map(|rows| {
+            rows.into_iter()
+                .filter_map(|row| match row.0 {
+                    OrderEventAny::Filled(fill) => Some(fill),
+                    _ => None,
+                })
+                .collect()
+        })
+        .map_err(|e| anyhow::anyhow!("Failed to load fills: {e}"))
+    }
+
     pub async fn load_order(
         pool: &PgPool,
         client_order_id: &ClientOrderId,
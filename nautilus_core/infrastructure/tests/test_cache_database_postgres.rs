@@ -35,8 +35,8 @@ mod serial_tests {
         enums::{CurrencyType, OrderSide, OrderStatus, OrderType},
         events::account::stubs::cash_account_state_million_usd,
         identifiers::{
-            stubs::account_id, AccountId, ClientId, ClientOrderId, InstrumentId, TradeId,
-            VenueOrderId,
+            stubs::account_id, AccountId, ClientId, ClientOrderId, InstrumentId, PositionId,
+            TradeId, VenueOrderId,
         },
         instruments::{
             stubs::{
@@ -46,6 +46,7 @@ mod serial_tests {
             Instrument, InstrumentAny,
         },
         orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        position::Position,
         types::{Currency, Price, Quantity},
     };
     use serde::Serialize;
@@ -590,6 +591,60 @@ mod serial_tests {
         pg_cache.close().unwrap();
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_postgres_cache_database_snapshot_position() {
+        let mut pg_cache = get_pg_cache_database().await.unwrap();
+
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            Some(PositionId::new("P-123456")),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(&instrument, filled.into());
+        let mut snapshot1 = position.clone();
+        snapshot1.id = PositionId::new("P-123456-1");
+        let mut snapshot2 = position.clone();
+        snapshot2.id = PositionId::new("P-123456-2");
+
+        pg_cache
+            .snapshot_position(&position.id, &snapshot1)
+            .unwrap();
+        pg_cache
+            .snapshot_position(&position.id, &snapshot2)
+            .unwrap();
+
+        wait_until(
+            || {
+                pg_cache
+                    .load_position_snapshots()
+                    .unwrap()
+                    .get(&position.id)
+                    .is_some_and(|snapshots| snapshots.len() == 2)
+            },
+            Duration::from_secs(2),
+        );
+
+        let snapshots = pg_cache.load_position_snapshots().unwrap();
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[&position.id], vec![snapshot1, snapshot2]);
+
+        pg_cache.flush().unwrap();
+        pg_cache.close().unwrap();
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_postgres_cache_database_add_custom_data() {
         let mut pg_cache = get_pg_cache_database().await.unwrap();
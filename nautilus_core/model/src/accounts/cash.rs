@@ -35,6 +35,31 @@ use crate::{
     types::{AccountBalance, Currency, Money, Price, Quantity},
 };
 
+/// Represents a drift between a cached balance and the balance reported by the venue.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BalanceDrift {
+    /// The balance currency.
+    pub currency: Currency,
+    /// The cached balance (zero if no balance was cached).
+    pub cached: AccountBalance,
+    /// The venue-reported balance (zero if no balance was reported).
+    pub reported: AccountBalance,
+}
+
+impl BalanceDrift {
+    /// Returns the reported total balance less the cached total balance.
+    #[must_use]
+    pub fn total_drift(&self) -> Money {
+        self.reported.total - self.cached.total
+    }
+
+    /// Returns the reported free balance less the cached free balance.
+    #[must_use]
+    pub fn free_drift(&self) -> Money {
+        self.reported.free - self.cached.free
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(
     feature = "python",
@@ -95,6 +120,159 @@ impl CashAccount {
 
         self.balances.insert(currency, new_balance);
     }
+
+    /// Adjusts the total and free balances by the given `amount` in its native currency,
+    /// creating the currency balance if none exists.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the adjustment would result in a negative total balance.
+    pub fn adjust_balance(&mut self, amount: Money) -> anyhow::Result<()> {
+        self.adjust_balances(&[amount])
+    }
+
+    /// Adjusts the total and free balances by each of the given `amounts` in their native
+    /// currencies, creating any currency balances which do not exist.
+    ///
+    /// Amounts are netted per currency and applied atomically, so on error no balances are
+    /// changed.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If any adjustment would result in a negative total balance.
+    pub fn adjust_balances(&mut self, amounts: &[Money]) -> anyhow::Result<()> {
+        let mut net_amounts: HashMap<Currency, Money> = HashMap::new();
+        for amount in amounts {
+            net_amounts
+                .entry(amount.currency)
+                .and_modify(|net| *net += *amount)
+                .or_insert(*amount);
+        }
+
+        let mut balances = self.balances.clone();
+
+        for (currency, amount) in net_amounts {
+            if amount.is_zero() {
+                continue;
+            }
+
+            let (total, locked, free) = match balances.get(&currency) {
+                Some(balance) => (
+                    balance.total + amount,
+                    balance.locked,
+                    balance.free + amount,
+                ),
+                None => (amount, Money::new(0.0, currency), amount),
+            };
+
+            if total.raw < 0 {
+                anyhow::bail!(
+                    "Cannot adjust {currency} balance by {amount}: total balance would be negative"
+                );
+            }
+
+            balances.insert(currency, AccountBalance::new(total, locked, free));
+        }
+
+        self.balances = balances;
+        Ok(())
+    }
+
+    /// Applies the realized PnLs and commission of the given `fill` to the balances in their
+    /// native currencies.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the PnLs for the fill cannot be calculated.
+    /// - If any balance total would become negative (no balances are changed).
+    pub fn apply_fill(
+        &mut self,
+        instrument: InstrumentAny,
+        fill: OrderFilled,
+        position: Option<Position>,
+    ) -> anyhow::Result<()> {
+        let mut amounts = self.calculate_pnls(instrument, fill, position)?;
+        if let Some(commission) = fill.commission {
+            amounts.push(-commission);
+        }
+
+        self.adjust_balances(&amounts)?;
+
+        if let Some(commission) = fill.commission {
+            self.update_commissions(commission);
+        }
+        Ok(())
+    }
+
+    /// Applies the given funding `payment` to the balance in its native currency.
+    ///
+    /// A positive payment is received and a negative payment is paid.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the payment would result in a negative total balance.
+    pub fn apply_funding_payment(&mut self, payment: Money) -> anyhow::Result<()> {
+        self.adjust_balance(payment)
+    }
+
+    /// Reconciles the cached balances against the given venue-`reported` balances, returning
+    /// the drift for each currency where the total or free balance differs by more than the
+    /// given `tolerance`.
+    ///
+    /// A currency missing from either side is compared against a zero balance. The drifts
+    /// are returned sorted by currency code.
+    #[must_use]
+    pub fn reconcile_balances(
+        &self,
+        reported: &[AccountBalance],
+        tolerance: Decimal,
+    ) -> Vec<BalanceDrift> {
+        let zero_balance = |currency: Currency| {
+            AccountBalance::new(
+                Money::new(0.0, currency),
+                Money::new(0.0, currency),
+                Money::new(0.0, currency),
+            )
+        };
+
+        let reported: HashMap<Currency, AccountBalance> = reported
+            .iter()
+            .map(|balance| (balance.currency, *balance))
+            .collect();
+
+        let mut currencies: Vec<Currency> = self
+            .balances
+            .keys()
+            .chain(reported.keys())
+            .copied()
+            .collect();
+        currencies.sort_by(|a, b| a.code.cmp(&b.code));
+        currencies.dedup();
+
+        currencies
+            .into_iter()
+            .map(|currency| BalanceDrift {
+                currency,
+                cached: self
+                    .balances
+                    .get(&currency)
+                    .copied()
+                    .unwrap_or_else(|| zero_balance(currency)),
+                reported: reported
+                    .get(&currency)
+                    .copied()
+                    .unwrap_or_else(|| zero_balance(currency)),
+            })
+            .filter(|drift| {
+                drift.total_drift().as_decimal().abs() > tolerance
+                    || drift.free_drift().as_decimal().abs() > tolerance
+            })
+            .collect()
+    }
 }
 
 impl Account for CashAccount {
@@ -119,7 +297,7 @@ impl Account for CashAccount {
     }
 
     fn calculated_account_state(&self) -> bool {
-        false // TODO (implement this logic)
+        self.calculate_account_state
     }
 
     fn balance_total(&self, currency: Option<Currency>) -> Option<Money> {
@@ -280,6 +458,7 @@ mod tests {
     use std::collections::{HashMap, HashSet};
 
     use rstest::rstest;
+    use rust_decimal::Decimal;
 
     use crate::{
         accounts::{base::Account, cash::CashAccount, stubs::*},
@@ -289,7 +468,7 @@ mod tests {
         instruments::{stubs::*, CryptoPerpetual, CurrencyPair, Equity, Instrument, InstrumentAny},
         orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
         position::Position,
-        types::{Currency, Money, Price, Quantity},
+        types::{AccountBalance, Currency, Money, Price, Quantity},
     };
 
     #[rstest]
@@ -586,6 +765,151 @@ mod tests {
         assert_eq!(result2_set, result2_expected);
     }
 
+    #[rstest]
+    fn test_apply_fill_for_multi_currency_cash_account(
+        mut cash_account_multi: CashAccount,
+        currency_pair_btcusdt: CurrencyPair,
+    ) {
+        let btcusdt = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(currency_pair_btcusdt.id)
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from("0.5"))
+            .build();
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &btcusdt,
+            None,
+            Some(PositionId::new("P-123456")),
+            Some(Price::from("45500.00")),
+            None,
+            None,
+            Some(Money::from("10 USDT")),
+            None,
+            Some(AccountId::from("SIM-001")),
+        );
+
+        cash_account_multi
+            .apply_fill(btcusdt, fill.into(), None)
+            .unwrap();
+
+        assert_eq!(
+            cash_account_multi.balance_total(Some(Currency::BTC())),
+            Some(Money::from("9.5 BTC"))
+        );
+        assert_eq!(
+            cash_account_multi.balance_free(Some(Currency::USDT())),
+            Some(Money::from("22740 USDT"))
+        );
+        assert_eq!(
+            cash_account_multi.balance_total(Some(Currency::ETH())),
+            Some(Money::from("20 ETH"))
+        );
+        assert_eq!(
+            cash_account_multi.commissions.get(&Currency::USDT()),
+            Some(&10.0)
+        );
+    }
+
+    #[rstest]
+    fn test_apply_fill_when_balance_would_be_negative_leaves_balances_unchanged(
+        mut cash_account_multi: CashAccount,
+        currency_pair_btcusdt: CurrencyPair,
+    ) {
+        let btcusdt = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(currency_pair_btcusdt.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("0.5"))
+            .build();
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &btcusdt,
+            None,
+            Some(PositionId::new("P-123456")),
+            Some(Price::from("45500.00")),
+            None,
+            None,
+            None,
+            None,
+            Some(AccountId::from("SIM-001")),
+        );
+        let balances = cash_account_multi.balances();
+
+        let result = cash_account_multi.apply_fill(btcusdt, fill.into(), None);
+
+        assert!(result.is_err());
+        assert_eq!(cash_account_multi.balances(), balances);
+    }
+
+    #[rstest]
+    fn test_apply_funding_payment(mut cash_account_multi: CashAccount) {
+        cash_account_multi
+            .apply_funding_payment(Money::from("-0.1 BTC"))
+            .unwrap();
+        cash_account_multi
+            .apply_funding_payment(Money::from("5 USDT"))
+            .unwrap();
+
+        assert_eq!(
+            cash_account_multi.balance_total(Some(Currency::BTC())),
+            Some(Money::from("9.9 BTC"))
+        );
+        assert_eq!(
+            cash_account_multi.balance_free(Some(Currency::BTC())),
+            Some(Money::from("9.9 BTC"))
+        );
+        assert_eq!(
+            cash_account_multi.balance_total(Some(Currency::USDT())),
+            Some(Money::from("5 USDT"))
+        );
+        assert!(cash_account_multi
+            .apply_funding_payment(Money::from("-1 ETH"))
+            .is_ok());
+        assert!(cash_account_multi
+            .apply_funding_payment(Money::from("-100 ETH"))
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_reconcile_balances_reports_drift_above_tolerance(cash_account_multi: CashAccount) {
+        let reported = vec![
+            AccountBalance::new(
+                Money::from("9.5 BTC"),
+                Money::from("0 BTC"),
+                Money::from("9.5 BTC"),
+            ),
+            AccountBalance::new(
+                Money::from("20.0001 ETH"),
+                Money::from("0 ETH"),
+                Money::from("20.0001 ETH"),
+            ),
+            AccountBalance::new(
+                Money::from("100 USDT"),
+                Money::from("0 USDT"),
+                Money::from("100 USDT"),
+            ),
+        ];
+
+        let drifts = cash_account_multi.reconcile_balances(&reported, Decimal::new(1, 3));
+
+        assert_eq!(drifts.len(), 2);
+        assert_eq!(drifts[0].currency, Currency::BTC());
+        assert_eq!(drifts[0].total_drift(), Money::from("-0.5 BTC"));
+        assert_eq!(drifts[1].currency, Currency::USDT());
+        assert_eq!(drifts[1].cached.total, Money::from("0 USDT"));
+        assert_eq!(drifts[1].free_drift(), Money::from("100 USDT"));
+    }
+
+    #[rstest]
+    fn test_reconcile_balances_when_matching_returns_empty(cash_account_multi: CashAccount) {
+        let reported: Vec<AccountBalance> = cash_account_multi.balances().into_values().collect();
+
+        let drifts = cash_account_multi.reconcile_balances(&reported, Decimal::ZERO);
+
+        assert!(drifts.is_empty());
+    }
+
     #[rstest]
     #[case(false, Money::from("-0.00218331 BTC"))]
     #[case(true, Money::from("-25.0 USD"))]
@@ -222,6 +222,36 @@ impl MarginAccount {
         }
     }
 
+    /// Applies the given funding `payment` to the balance in its native currency.
+    ///
+    /// A positive payment is received and a negative payment is paid. Any margin locked against
+    /// the balance is unchanged, so the payment adjusts the total and free balances.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If there is no balance for the payment currency.
+    /// - If the payment would result in a negative total balance.
+    pub fn apply_funding_payment(&mut self, payment: Money) -> anyhow::Result<()> {
+        let currency = payment.currency;
+        let Some(balance) = self.balances.get(&currency).copied() else {
+            anyhow::bail!("Cannot apply funding payment {payment}: no {currency} balance");
+        };
+
+        let total = balance.total + payment;
+        if total.raw < 0 {
+            anyhow::bail!(
+                "Cannot apply funding payment {payment}: total {currency} balance would be negative"
+            );
+        }
+
+        self.balances.insert(
+            currency,
+            AccountBalance::new(total, balance.locked, balance.free + payment),
+        );
+        Ok(())
+    }
+
     pub fn recalculate_balance(&mut self, currency: Currency) {
         let current_balance = match self.balances.get(&currency) {
             Some(balance) => balance,
@@ -287,7 +317,7 @@ impl Account for MarginAccount {
     }
 
     fn calculated_account_state(&self) -> bool {
-        false // TODO (implement this logic)
+        self.calculate_account_state
     }
 
     fn balance_total(&self, currency: Option<Currency>) -> Option<Money> {
@@ -329,6 +359,9 @@ impl Account for MarginAccount {
         self.balances.clone()
     }
     fn apply(&mut self, event: AccountState) {
+        for margin in &event.margins {
+            self.margins.insert(margin.instrument_id, *margin);
+        }
         self.base_apply(event);
     }
     fn calculate_balance_locked(
@@ -347,7 +380,19 @@ impl Account for MarginAccount {
         fill: OrderFilled,
         position: Option<Position>,
     ) -> anyhow::Result<Vec<Money>> {
-        self.base_calculate_pnls(instrument, fill, position)
+        // Only the realized PnL of a position reduced by the fill is settled on a margin
+        // account, the notional value of the fill is not exchanged
+        let mut pnls = Vec::new();
+        if let Some(position) = position {
+            if position.entry != fill.order_side {
+                pnls.push(position.calculate_pnl(
+                    position.avg_px_open,
+                    fill.last_px.as_f64(),
+                    position.quantity.min(fill.last_qty),
+                ));
+            }
+        }
+        Ok(pnls)
     }
     fn calculate_commission(
         &self,
@@ -421,6 +466,37 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_apply_funding_payment(mut margin_account: MarginAccount) {
+        margin_account
+            .apply_funding_payment(Money::from("-1000 USD"))
+            .unwrap();
+
+        assert_eq!(
+            margin_account.balance_total(None),
+            Some(Money::from("1524000 USD"))
+        );
+        assert_eq!(
+            margin_account.balance_free(None),
+            Some(Money::from("1499000 USD"))
+        );
+        assert_eq!(
+            margin_account.balance_locked(None),
+            Some(Money::from("25000 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_apply_funding_payment_when_no_balance_for_currency(mut margin_account: MarginAccount) {
+        assert!(margin_account
+            .apply_funding_payment(Money::from("-1 USDT"))
+            .is_err());
+        assert_eq!(
+            margin_account.balance_total(None),
+            Some(Money::from("1525000 USD"))
+        );
+    }
+
     #[rstest]
     fn test_base_account_properties(
         margin_account: MarginAccount,
@@ -550,6 +626,50 @@ mod tests {
         assert_eq!(margins, vec![margin]);
     }
 
+    #[rstest]
+    fn test_update_margins_recalculates_locked_and_free_balance(
+        mut margin_account: MarginAccount,
+        instrument_id_aud_usd_sim: InstrumentId,
+    ) {
+        margin_account.update_initial_margin(instrument_id_aud_usd_sim, Money::from("10000 USD"));
+        margin_account
+            .update_maintenance_margin(instrument_id_aud_usd_sim, Money::from("5000 USD"));
+
+        assert_eq!(
+            margin_account.balance_total(None),
+            Some(Money::from("1525000 USD"))
+        );
+        assert_eq!(
+            margin_account.balance_locked(None),
+            Some(Money::from("15000 USD"))
+        );
+        assert_eq!(
+            margin_account.balance_free(None),
+            Some(Money::from("1510000 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_apply_updates_margins(
+        mut margin_account: MarginAccount,
+        margin_account_state: AccountState,
+    ) {
+        assert!(margin_account.margins.is_empty());
+
+        margin_account.apply(margin_account_state.clone());
+
+        let margin = margin_account_state.margins[0];
+        assert_eq!(margin_account.event_count(), 2);
+        assert_eq!(
+            margin_account.initial_margin(margin.instrument_id),
+            margin.initial
+        );
+        assert_eq!(
+            margin_account.maintenance_margin(margin.instrument_id),
+            margin.maintenance
+        );
+    }
+
     #[rstest]
     fn test_calculate_margin_init_with_leverage(
         mut margin_account: MarginAccount,
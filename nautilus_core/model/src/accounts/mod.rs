@@ -27,6 +27,6 @@ pub mod stubs;
 pub use crate::accounts::{
     any::AccountAny,
     base::{Account, BaseAccount},
-    cash::CashAccount,
+    cash::{BalanceDrift, CashAccount},
     margin::MarginAccount,
 };
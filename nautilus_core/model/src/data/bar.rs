@@ -22,7 +22,7 @@ use std::{
     str::FromStr,
 };
 
-use chrono::{DateTime, Datelike, Duration, TimeDelta, Timelike, Utc};
+use chrono::{DateTime, Duration, DurationRound, TimeDelta, Utc};
 use derive_builder::Builder;
 use indexmap::IndexMap;
 use nautilus_core::{correctness::FAILED, nanos::UnixNanos, serialization::Serializable};
@@ -68,43 +68,36 @@ pub fn get_bar_interval_ns(bar_type: &BarType) -> UnixNanos {
 }
 
 /// Returns the time bar start as a timezone-aware `DateTime<Utc>`.
+///
+/// Bars are aligned to multiples of the step within the next larger time unit, e.g.
+/// 15-MINUTE bars start at :00, :15, :30 and :45 past each hour.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the aggregation method of the given `bar_type` is not time based.
 pub fn get_time_bar_start(now: DateTime<Utc>, bar_type: &BarType) -> DateTime<Utc> {
     let spec = bar_type.spec();
-    let step = spec.step;
+    let step = spec.step as i64;
 
-    match spec.aggregation {
-        BarAggregation::Millisecond => {
-            let diff_milliseconds = now.timestamp_subsec_millis() as usize % step;
-            now - TimeDelta::milliseconds(diff_milliseconds as i64)
-        }
-        BarAggregation::Second => {
-            let diff_seconds = now.timestamp() % step as i64;
-            now - TimeDelta::seconds(diff_seconds)
-        }
-        BarAggregation::Minute => {
-            let diff_minutes = now.time().minute() as usize % step;
-            now - TimeDelta::minutes(diff_minutes as i64)
-                - TimeDelta::seconds(now.time().second() as i64)
-        }
-        BarAggregation::Hour => {
-            let diff_hours = now.time().hour() as usize % step;
-            let diff_days = if diff_hours == 0 { 0 } else { (step / 24) - 1 };
-            now - TimeDelta::days(diff_days as i64)
-                - TimeDelta::hours(diff_hours as i64)
-                - TimeDelta::minutes(now.minute() as i64)
-                - TimeDelta::seconds(now.second() as i64)
-        }
+    let (period, unit) = match spec.aggregation {
+        BarAggregation::Millisecond => (TimeDelta::seconds(1), TimeDelta::milliseconds(1)),
+        BarAggregation::Second => (TimeDelta::minutes(1), TimeDelta::seconds(1)),
+        BarAggregation::Minute => (TimeDelta::hours(1), TimeDelta::minutes(1)),
+        BarAggregation::Hour => (TimeDelta::days(1), TimeDelta::hours(1)),
         BarAggregation::Day => {
-            now - TimeDelta::days(now.day() as i64 % step as i64)
-                - TimeDelta::hours(now.hour() as i64)
-                - TimeDelta::minutes(now.minute() as i64)
-                - TimeDelta::seconds(now.second() as i64)
+            return now.duration_trunc(TimeDelta::days(1)).expect(FAILED);
         }
         _ => panic!(
             "Aggregation type {} not supported for time bars",
             spec.aggregation
         ),
-    }
+    };
+
+    let period_start = now.duration_trunc(period).expect(FAILED);
+    let unit_ns = unit.num_nanoseconds().expect(FAILED);
+    let elapsed_units = (now - period_start).num_nanoseconds().expect(FAILED) / unit_ns;
+    period_start + TimeDelta::nanoseconds((elapsed_units / step) * step * unit_ns)
 }
 
 /// Represents a bar aggregation specification including a step, aggregation
@@ -176,6 +169,8 @@ impl BarSpecification {
     ///  - [`BarAggregation::VolumeImbalance`]
     ///  - [`BarAggregation::Value`]
     ///  - [`BarAggregation::ValueImbalance`]
+    ///  - [`BarAggregation::Renko`]
+    ///  - [`BarAggregation::Range`]
     pub fn is_threshold_aggregated(&self) -> bool {
         matches!(
             self.aggregation,
@@ -185,6 +180,8 @@ impl BarSpecification {
                 | BarAggregation::VolumeImbalance
                 | BarAggregation::Value
                 | BarAggregation::ValueImbalance
+                | BarAggregation::Renko
+                | BarAggregation::Range
         )
     }
 
@@ -783,6 +780,30 @@ mod tests {
     1,
     Utc.with_ymd_and_hms(2024, 7, 21, 0, 0, 0).unwrap()
     )]
+    #[case::second_with_subsec(
+    Utc.with_ymd_and_hms(2024, 7, 21, 12, 34, 56).unwrap() + TimeDelta::milliseconds(789),
+    BarAggregation::Second,
+    5,
+    Utc.with_ymd_and_hms(2024, 7, 21, 12, 34, 55).unwrap()
+    )]
+    #[case::minute_with_subsec(
+    Utc.with_ymd_and_hms(2024, 7, 21, 12, 34, 56).unwrap() + TimeDelta::milliseconds(789),
+    BarAggregation::Minute,
+    15,
+    Utc.with_ymd_and_hms(2024, 7, 21, 12, 30, 0).unwrap()
+    )]
+    #[case::millisecond_with_subsec(
+    Utc.with_ymd_and_hms(2024, 7, 21, 12, 34, 56).unwrap() + TimeDelta::microseconds(123_456),
+    BarAggregation::Millisecond,
+    100,
+    Utc.with_ymd_and_hms(2024, 7, 21, 12, 34, 56).unwrap() + TimeDelta::milliseconds(100)
+    )]
+    #[case::hour_with_step(
+    Utc.with_ymd_and_hms(2024, 7, 21, 12, 34, 56).unwrap(),
+    BarAggregation::Hour,
+    4,
+    Utc.with_ymd_and_hms(2024, 7, 21, 12, 0, 0).unwrap()
+    )]
     fn test_get_time_bar_start(
         #[case] now: DateTime<Utc>,
         #[case] aggregation: BarAggregation,
@@ -27,10 +27,21 @@ pub struct BlackScholesGreeksResult {
     pub gamma: f64,
     pub vega: f64,
     pub theta: f64,
+    pub rho: f64,
 }
 
+/// The tolerance of the repricing check on an implied volatility (relative to the price).
+const IMPLY_VOL_TOLERANCE: f64 = 1e-9;
+
+/// The upper bound of the volatility search interval for the bisection fallback.
+const IMPLY_VOL_MAX: f64 = 10.0;
+
+const IMPLY_VOL_MAX_ITERATIONS: usize = 200;
+
 // dS_t = S_t * (b * dt + sigma * dW_t) (stock)
 // dC_t = r * C_t * dt (cash numeraire)
+//
+// Rho is the sensitivity to the interest rate with the cost of carry moving with it (b = r - q).
 #[allow(clippy::too_many_arguments)]
 pub fn black_scholes_greeks(
     s: f64,
@@ -61,6 +72,7 @@ pub fn black_scholes_greeks(
         * (s_t * (-dist_d1 * sigma / (2.0 * t.sqrt()) - phi * (b - r) * cdf_phi_d1)
             - phi * r * k_t * cdf_phi_d2)
         * 0.0027378507871321013; // 1 / 365.25 in change per calendar day
+    let rho = multiplier * phi * t * k_t * cdf_phi_d2 * 0.01; // in absolute percent change
 
     BlackScholesGreeksResult {
         price,
@@ -68,14 +80,83 @@ pub fn black_scholes_greeks(
         gamma,
         vega,
         theta,
+        rho,
     }
 }
 
+// Black-76 model for options on a forward or futures price `f` (zero cost of carry), where
+// rho is the sensitivity to the discount rate only.
+pub fn black76_greeks(
+    f: f64,
+    r: f64,
+    sigma: f64,
+    is_call: bool,
+    k: f64,
+    t: f64,
+    multiplier: f64,
+) -> BlackScholesGreeksResult {
+    let mut greeks = black_scholes_greeks(f, r, 0.0, sigma, is_call, k, t, multiplier);
+    greeks.rho = -t * greeks.price * 0.01; // in absolute percent change
+    greeks
+}
+
+// Undiscounted Black price of an option on the forward `f`.
+fn black_forward_price(f: f64, k: f64, t: f64, sigma: f64, is_call: bool) -> f64 {
+    let phi = if is_call { 1.0 } else { -1.0 };
+    let scaled_vol = sigma * t.sqrt();
+    let d1 = ((f / k).ln() + 0.5 * scaled_vol * scaled_vol) / scaled_vol;
+    let d2 = d1 - scaled_vol;
+
+    phi * (f * norm_cdf(phi * d1) - k * norm_cdf(phi * d2))
+}
+
+// Returns the implied volatility, or NaN if the price lies outside the no-arbitrage bounds.
+//
+// The rational approximation of `implied_black_volatility` is checked by repricing, falling
+// back to bisection when it fails to reproduce the price (e.g. very deep in or out of the money).
 pub fn imply_vol(s: f64, r: f64, b: f64, is_call: bool, k: f64, t: f64, price: f64) -> f64 {
-    let forward = s * b.exp();
+    let forward = s * (b * t).exp();
     let forward_price = price * (r * t).exp();
 
-    implied_black_volatility(forward_price, forward, k, t, is_call)
+    let (intrinsic, upper_bound) = if is_call {
+        ((forward - k).max(0.0), forward)
+    } else {
+        ((k - forward).max(0.0), k)
+    };
+    if !forward_price.is_finite()
+        || t <= 0.0
+        || forward_price < intrinsic
+        || forward_price >= upper_bound
+    {
+        return f64::NAN;
+    }
+
+    let tolerance = IMPLY_VOL_TOLERANCE * forward_price.max(1.0);
+    let vol = implied_black_volatility(forward_price, forward, k, t, is_call);
+    if vol.is_finite()
+        && vol >= 0.0
+        && (black_forward_price(forward, k, t, vol, is_call) - forward_price).abs() <= tolerance
+    {
+        return vol;
+    }
+
+    // The forward price is increasing in volatility, so bisect on the bracketing interval
+    let mut low = 0.0;
+    let mut high = IMPLY_VOL_MAX;
+    for _ in 0..IMPLY_VOL_MAX_ITERATIONS {
+        let mid = 0.5 * (low + high);
+        let diff = black_forward_price(forward, k, t, mid, is_call) - forward_price;
+        if diff.abs() <= tolerance {
+            return mid;
+        }
+        if diff > 0.0 {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+
+    0.5 * (low + high)
 }
 
 #[repr(C)]
@@ -91,6 +172,7 @@ pub struct ImplyVolAndGreeksResult {
     pub gamma: f64,
     pub vega: f64,
     pub theta: f64,
+    pub rho: f64,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -114,6 +196,7 @@ pub fn imply_vol_and_greeks(
         gamma: greeks.gamma,
         vega: greeks.vega,
         theta: greeks.theta,
+        rho: greeks.rho,
     }
 }
 
@@ -290,3 +373,81 @@ fn test_imply_vol_and_greeks_accuracy_put() {
         "Theta difference exceeds tolerance"
     );
 }
+
+#[test]
+fn test_rho_accuracy() {
+    let s = 100.0;
+    let k = 100.1;
+    let t = 1.0;
+    let q = 0.005;
+    let r = 0.01;
+    let sigma = 0.2;
+    let eps = 1e-4;
+
+    for is_call in [true, false] {
+        let greeks = black_scholes_greeks(s, r, r - q, sigma, is_call, k, t, 1.0);
+        let rho_bnr = (black_scholes_greeks(s, r + eps, r + eps - q, sigma, is_call, k, t, 1.0)
+            .price
+            - black_scholes_greeks(s, r - eps, r - eps - q, sigma, is_call, k, t, 1.0).price)
+            / (2.0 * eps)
+            / 100.0;
+
+        assert!(
+            (greeks.rho - rho_bnr).abs() < 1e-5,
+            "Rho difference exceeds tolerance"
+        );
+    }
+}
+
+#[test]
+fn test_black76_rho_accuracy() {
+    let f = 100.0;
+    let k = 95.0;
+    let t = 0.5;
+    let r = 0.03;
+    let sigma = 0.25;
+    let eps = 1e-4;
+
+    let greeks = black76_greeks(f, r, sigma, false, k, t, 1.0);
+    let rho_bnr = (black76_greeks(f, r + eps, sigma, false, k, t, 1.0).price
+        - black76_greeks(f, r - eps, sigma, false, k, t, 1.0).price)
+        / (2.0 * eps)
+        / 100.0;
+
+    assert!(
+        (greeks.rho - rho_bnr).abs() < 1e-5,
+        "Rho difference exceeds tolerance"
+    );
+    assert_eq!(
+        greeks.price,
+        black_scholes_greeks(f, r, 0.0, sigma, false, k, t, 1.0).price
+    );
+}
+
+#[test]
+fn test_imply_vol_with_carry_and_short_expiry() {
+    let s = 100.0;
+    let k = 110.0;
+    let t = 0.25;
+    let r = 0.05;
+    let b = 0.03;
+    let sigma = 0.35;
+
+    for is_call in [true, false] {
+        let price = black_scholes_greeks(s, r, b, sigma, is_call, k, t, 1.0).price;
+        let vol = imply_vol(s, r, b, is_call, k, t, price);
+
+        assert!(
+            (vol - sigma).abs() < 1e-6,
+            "Vol difference exceeds tolerance"
+        );
+    }
+}
+
+#[test]
+fn test_imply_vol_outside_arbitrage_bounds_returns_nan() {
+    // Call worth less than intrinsic value, and call worth more than the underlying
+    assert!(imply_vol(100.0, 0.0, 0.0, true, 80.0, 1.0, 10.0).is_nan());
+    assert!(imply_vol(100.0, 0.0, 0.0, true, 80.0, 1.0, 101.0).is_nan());
+    assert!(imply_vol(100.0, 0.0, 0.0, true, 80.0, 0.0, 25.0).is_nan());
+}
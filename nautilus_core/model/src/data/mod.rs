@@ -19,8 +19,10 @@ pub mod bar;
 pub mod delta;
 pub mod deltas;
 pub mod depth;
+pub mod funding;
 pub mod greeks;
 pub mod order;
+pub mod prices;
 pub mod quote;
 pub mod status;
 pub mod trade;
@@ -46,8 +48,10 @@ pub use bar::{Bar, BarSpecification, BarType};
 pub use delta::OrderBookDelta;
 pub use deltas::{OrderBookDeltas, OrderBookDeltas_API};
 pub use depth::{OrderBookDepth10, DEPTH10_LEN};
-pub use greeks::{black_scholes_greeks, BlackScholesGreeksResult};
+pub use funding::FundingRateUpdate;
+pub use greeks::{black76_greeks, black_scholes_greeks, BlackScholesGreeksResult};
 pub use order::{BookOrder, NULL_ORDER};
+pub use prices::{IndexPriceUpdate, MarkPriceUpdate};
 pub use quote::QuoteTick;
 pub use status::InstrumentStatus;
 pub use trade::TradeTick;
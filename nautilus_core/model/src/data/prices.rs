@@ -0,0 +1,190 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! `MarkPriceUpdate` and `IndexPriceUpdate` data types published by derivatives venues.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable};
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::{identifiers::InstrumentId, types::Price};
+
+/// Represents a mark price update for an instrument.
+///
+/// Derivatives venues use the mark price to value positions for unrealized PnL and margin.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub struct MarkPriceUpdate {
+    /// The instrument ID for the mark price.
+    pub instrument_id: InstrumentId,
+    /// The mark price.
+    pub value: Price,
+    /// UNIX timestamp (nanoseconds) when the price event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl MarkPriceUpdate {
+    /// Creates a new [`MarkPriceUpdate`] instance.
+    #[must_use]
+    pub const fn new(
+        instrument_id: InstrumentId,
+        value: Price,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            value,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(instrument_id: &InstrumentId) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata
+    }
+}
+
+impl Display for MarkPriceUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.instrument_id, self.value, self.ts_event, self.ts_init,
+        )
+    }
+}
+
+impl Serializable for MarkPriceUpdate {}
+
+impl GetTsInit for MarkPriceUpdate {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// Represents an index price update for an instrument.
+///
+/// The index price is the reference (typically spot basket) price a derivative tracks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub struct IndexPriceUpdate {
+    /// The instrument ID for the index price.
+    pub instrument_id: InstrumentId,
+    /// The index price.
+    pub value: Price,
+    /// UNIX timestamp (nanoseconds) when the price event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl IndexPriceUpdate {
+    /// Creates a new [`IndexPriceUpdate`] instance.
+    #[must_use]
+    pub const fn new(
+        instrument_id: InstrumentId,
+        value: Price,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            value,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(instrument_id: &InstrumentId) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata
+    }
+}
+
+impl Display for IndexPriceUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.instrument_id, self.value, self.ts_event, self.ts_init,
+        )
+    }
+}
+
+impl Serializable for IndexPriceUpdate {}
+
+impl GetTsInit for IndexPriceUpdate {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::stubs::{stub_index_price_update, stub_mark_price_update};
+
+    #[rstest]
+    fn test_mark_price_to_string(stub_mark_price_update: MarkPriceUpdate) {
+        assert_eq!(
+            stub_mark_price_update.to_string(),
+            "BTCUSDT-PERP.BINANCE,100000.50,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_index_price_to_string(stub_index_price_update: IndexPriceUpdate) {
+        assert_eq!(
+            stub_index_price_update.to_string(),
+            "BTCUSDT-PERP.BINANCE,99990.25,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_mark_price_json_serialization(stub_mark_price_update: MarkPriceUpdate) {
+        let serialized = stub_mark_price_update.as_json_bytes().unwrap();
+        let deserialized = MarkPriceUpdate::from_json_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, stub_mark_price_update);
+    }
+
+    #[rstest]
+    fn test_index_price_msgpack_serialization(stub_index_price_update: IndexPriceUpdate) {
+        let serialized = stub_index_price_update.as_msgpack_bytes().unwrap();
+        let deserialized = IndexPriceUpdate::from_msgpack_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, stub_index_price_update);
+    }
+}
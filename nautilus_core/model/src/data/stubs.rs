@@ -17,10 +17,12 @@
 
 use nautilus_core::nanos::UnixNanos;
 use rstest::fixture;
+use rust_decimal_macros::dec;
 
 use super::{
-    Bar, BarSpecification, BarType, InstrumentStatus, OrderBookDelta, OrderBookDeltas,
-    OrderBookDepth10, QuoteTick, TradeTick, DEPTH10_LEN,
+    Bar, BarSpecification, BarType, FundingRateUpdate, IndexPriceUpdate, InstrumentStatus,
+    MarkPriceUpdate, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick,
+    DEPTH10_LEN,
 };
 use crate::{
     data::order::BookOrder,
@@ -363,3 +365,34 @@ pub fn stub_instrument_status() -> InstrumentStatus {
         None,
     )
 }
+
+#[fixture]
+pub fn stub_funding_rate_update() -> FundingRateUpdate {
+    FundingRateUpdate::new(
+        InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+        dec!(0.0001),
+        Some(UnixNanos::from(28_800_000_000_000)),
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    )
+}
+
+#[fixture]
+pub fn stub_mark_price_update() -> MarkPriceUpdate {
+    MarkPriceUpdate::new(
+        InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+        Price::from("100000.50"),
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    )
+}
+
+#[fixture]
+pub fn stub_index_price_update() -> IndexPriceUpdate {
+    IndexPriceUpdate::new(
+        InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+        Price::from("99990.25"),
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    )
+}
@@ -303,6 +303,10 @@ pub enum BarAggregation {
     Week = 15,
     /// Based on time intervals with month granularity.
     Month = 16,
+    /// Based on fixed price movements (bricks) of the instrument.
+    Renko = 17,
+    /// Based on a fixed high-low price range of the instrument.
+    Range = 18,
 }
 
 /// The type of order book action for an order book event.
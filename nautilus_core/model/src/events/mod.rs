@@ -18,6 +18,7 @@
 pub mod account;
 pub mod order;
 pub mod position;
+pub mod risk;
 
 // Re-exports
 pub use crate::events::{
@@ -35,4 +36,5 @@ pub use crate::events::{
         changed::PositionChanged, closed::PositionClosed, opened::PositionOpened,
         snapshot::PositionSnapshot, PositionEvent,
     },
+    risk::trading_state::TradingStateChanged,
 };
@@ -26,7 +26,7 @@ use crate::{
         OrderPendingUpdate, OrderRejected, OrderReleased, OrderSubmitted, OrderTriggered,
         OrderUpdated,
     },
-    identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TraderId},
+    identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TraderId, VenueOrderId},
 };
 
 /// Wraps an `OrderEvent` allowing polymorphism.
@@ -236,6 +236,90 @@ impl OrderEventAny {
         }
     }
 
+    #[must_use]
+    pub fn venue_order_id(&self) -> Option<VenueOrderId> {
+        match self {
+            Self::Initialized(event) => event.venue_order_id(),
+            Self::Denied(event) => event.venue_order_id(),
+            Self::Emulated(event) => event.venue_order_id(),
+            Self::Released(event) => event.venue_order_id(),
+            Self::Submitted(event) => event.venue_order_id(),
+            Self::Accepted(event) => event.venue_order_id(),
+            Self::Rejected(event) => event.venue_order_id(),
+            Self::Canceled(event) => event.venue_order_id(),
+            Self::Expired(event) => event.venue_order_id(),
+            Self::Triggered(event) => event.venue_order_id(),
+            Self::PendingUpdate(event) => event.venue_order_id(),
+            Self::PendingCancel(event) => event.venue_order_id(),
+            Self::ModifyRejected(event) => event.venue_order_id(),
+            Self::CancelRejected(event) => event.venue_order_id(),
+            Self::Updated(event) => event.venue_order_id(),
+            Self::PartiallyFilled(event) => event.venue_order_id(),
+            Self::Filled(event) => event.venue_order_id(),
+        }
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::Initialized(event) => event.ts_init,
+            Self::Denied(event) => event.ts_init,
+            Self::Emulated(event) => event.ts_init,
+            Self::Released(event) => event.ts_init,
+            Self::Submitted(event) => event.ts_init,
+            Self::Accepted(event) => event.ts_init,
+            Self::Rejected(event) => event.ts_init,
+            Self::Canceled(event) => event.ts_init,
+            Self::Expired(event) => event.ts_init,
+            Self::Triggered(event) => event.ts_init,
+            Self::PendingUpdate(event) => event.ts_init,
+            Self::PendingCancel(event) => event.ts_init,
+            Self::ModifyRejected(event) => event.ts_init,
+            Self::CancelRejected(event) => event.ts_init,
+            Self::Updated(event) => event.ts_init,
+            Self::PartiallyFilled(event) => event.ts_init,
+            Self::Filled(event) => event.ts_init,
+        }
+    }
+
+    /// Returns whether the event transitions an order to a terminal (closed) state.
+    #[must_use]
+    pub const fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Denied(_)
+                | Self::Rejected(_)
+                | Self::Canceled(_)
+                | Self::Expired(_)
+                | Self::Filled(_)
+        )
+    }
+
+    /// Returns whether the event is a (partial or complete) fill.
+    #[must_use]
+    pub const fn is_fill(&self) -> bool {
+        matches!(self, Self::PartiallyFilled(_) | Self::Filled(_))
+    }
+
+    /// Returns a reference to the fill event, if the event is a (partial or complete) fill.
+    #[must_use]
+    pub const fn as_fill(&self) -> Option<&OrderFilled> {
+        match self {
+            Self::PartiallyFilled(event) | Self::Filled(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    /// Wraps the given fill `event`, as either a partial or complete fill of the order.
+    #[must_use]
+    pub const fn from_fill(event: OrderFilled, is_last_fill: bool) -> Self {
+        if is_last_fill {
+            Self::Filled(event)
+        } else {
+            Self::PartiallyFilled(event)
+        }
+    }
+
     pub fn message(&self) -> Option<Ustr> {
         match self {
             Self::Initialized(_) => None,
@@ -259,6 +343,38 @@ impl OrderEventAny {
     }
 }
 
+macro_rules! impl_from_order_event {
+    ($($event:ident => $variant:ident),+ $(,)?) => {
+        $(
+            impl From<$event> for OrderEventAny {
+                fn from(event: $event) -> Self {
+                    Self::$variant(event)
+                }
+            }
+        )+
+    };
+}
+
+// `OrderFilled` is deliberately excluded as it may represent either a partial or complete
+// fill, use `OrderEventAny::from_fill` instead.
+impl_from_order_event!(
+    OrderInitialized => Initialized,
+    OrderDenied => Denied,
+    OrderEmulated => Emulated,
+    OrderReleased => Released,
+    OrderSubmitted => Submitted,
+    OrderAccepted => Accepted,
+    OrderRejected => Rejected,
+    OrderCanceled => Canceled,
+    OrderExpired => Expired,
+    OrderTriggered => Triggered,
+    OrderPendingUpdate => PendingUpdate,
+    OrderPendingCancel => PendingCancel,
+    OrderModifyRejected => ModifyRejected,
+    OrderCancelRejected => CancelRejected,
+    OrderUpdated => Updated,
+);
+
 impl From<OrderEventAny> for OrderFilled {
     fn from(event: OrderEventAny) -> OrderFilled {
         match event {
@@ -267,3 +383,55 @@ impl From<OrderEventAny> for OrderFilled {
         }
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::events::order::stubs::*;
+
+    #[rstest]
+    fn test_from_order_event(order_accepted: OrderAccepted) {
+        let event = OrderEventAny::from(order_accepted);
+
+        assert_eq!(event.event_type(), OrderEventType::Accepted);
+        assert_eq!(event.client_order_id(), order_accepted.client_order_id);
+        assert_eq!(event.venue_order_id(), Some(order_accepted.venue_order_id));
+        assert_eq!(event.ts_init(), order_accepted.ts_init);
+        assert!(!event.is_terminal());
+        assert!(!event.is_fill());
+        assert!(event.as_fill().is_none());
+    }
+
+    #[rstest]
+    fn test_from_rejected_is_terminal(order_rejected_insufficient_margin: OrderRejected) {
+        let event: OrderEventAny = order_rejected_insufficient_margin.into();
+
+        assert!(event.is_terminal());
+        assert_eq!(
+            event.message(),
+            Some(order_rejected_insufficient_margin.reason)
+        );
+    }
+
+    #[rstest]
+    #[case(false, OrderEventType::PartiallyFilled, false)]
+    #[case(true, OrderEventType::Filled, true)]
+    fn test_from_fill(
+        order_filled: OrderFilled,
+        #[case] is_last_fill: bool,
+        #[case] expected_type: OrderEventType,
+        #[case] expected_terminal: bool,
+    ) {
+        let event = OrderEventAny::from_fill(order_filled, is_last_fill);
+
+        assert_eq!(event.event_type(), expected_type);
+        assert_eq!(event.is_terminal(), expected_terminal);
+        assert!(event.is_fill());
+        assert_eq!(event.as_fill(), Some(&order_filled));
+    }
+}
@@ -19,6 +19,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     enums::{OrderSide, PositionSide},
     identifiers::{AccountId, ClientOrderId, InstrumentId, PositionId, StrategyId, TraderId},
+    position::Position,
     types::{Currency, Money, Quantity},
 };
 
@@ -87,3 +88,40 @@ pub struct PositionSnapshot {
     /// UNIX timestamp (nanoseconds) when the snapshot was initialized.
     pub ts_init: UnixNanos,
 }
+
+impl PositionSnapshot {
+    /// Creates a new [`PositionSnapshot`] of the given `position` state.
+    #[must_use]
+    pub fn new(position: &Position, unrealized_pnl: Option<Money>, ts_init: UnixNanos) -> Self {
+        Self {
+            trader_id: position.trader_id,
+            strategy_id: position.strategy_id,
+            instrument_id: position.instrument_id,
+            position_id: position.id,
+            account_id: position.account_id,
+            opening_order_id: position.opening_order_id,
+            closing_order_id: position.closing_order_id,
+            entry: position.entry,
+            side: position.side,
+            signed_qty: position.signed_qty,
+            quantity: position.quantity,
+            peak_qty: position.peak_qty,
+            quote_currency: position.quote_currency,
+            base_currency: position.base_currency,
+            settlement_currency: position.settlement_currency,
+            avg_px_open: position.avg_px_open,
+            avg_px_close: position.avg_px_close,
+            realized_return: Some(position.realized_return),
+            realized_pnl: position
+                .realized_pnl
+                .unwrap_or_else(|| Money::new(0.0, position.settlement_currency)),
+            unrealized_pnl,
+            commissions: position.commissions(),
+            duration_ns: position.ts_closed.map(|_| position.duration_ns),
+            ts_opened: position.ts_opened,
+            ts_closed: position.ts_closed,
+            ts_last: position.ts_last,
+            ts_init,
+        }
+    }
+}
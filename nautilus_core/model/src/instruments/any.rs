@@ -15,6 +15,7 @@
 
 use nautilus_core::nanos::UnixNanos;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{
@@ -24,12 +25,13 @@ use super::{
     options_contract::OptionsContract, options_spread::OptionsSpread, Instrument,
 };
 use crate::{
+    data::GetTsInit,
     enums::InstrumentClass,
     identifiers::{InstrumentId, Symbol, Venue},
     types::{Currency, Money, Price, Quantity},
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum InstrumentAny {
     Betting(BettingInstrument),
     BinaryOption(BinaryOption),
@@ -464,6 +466,38 @@ impl InstrumentAny {
         }
     }
 
+    #[must_use]
+    pub fn margin_init(&self) -> Decimal {
+        match self {
+            Self::Betting(inst) => inst.margin_init(),
+            Self::BinaryOption(inst) => inst.margin_init(),
+            Self::CryptoFuture(inst) => inst.margin_init(),
+            Self::CryptoPerpetual(inst) => inst.margin_init(),
+            Self::CurrencyPair(inst) => inst.margin_init(),
+            Self::Equity(inst) => inst.margin_init(),
+            Self::FuturesContract(inst) => inst.margin_init(),
+            Self::FuturesSpread(inst) => inst.margin_init(),
+            Self::OptionsContract(inst) => inst.margin_init(),
+            Self::OptionsSpread(inst) => inst.margin_init(),
+        }
+    }
+
+    #[must_use]
+    pub fn margin_maint(&self) -> Decimal {
+        match self {
+            Self::Betting(inst) => inst.margin_maint(),
+            Self::BinaryOption(inst) => inst.margin_maint(),
+            Self::CryptoFuture(inst) => inst.margin_maint(),
+            Self::CryptoPerpetual(inst) => inst.margin_maint(),
+            Self::CurrencyPair(inst) => inst.margin_maint(),
+            Self::Equity(inst) => inst.margin_maint(),
+            Self::FuturesContract(inst) => inst.margin_maint(),
+            Self::FuturesSpread(inst) => inst.margin_maint(),
+            Self::OptionsContract(inst) => inst.margin_maint(),
+            Self::OptionsSpread(inst) => inst.margin_maint(),
+        }
+    }
+
     // #[deprecated(since = "0.21.0", note = "Will be removed in a future version")]
     #[must_use]
     pub fn maker_fee(&self) -> Decimal {
@@ -498,6 +532,22 @@ impl InstrumentAny {
         }
     }
 
+    #[must_use]
+    pub fn ts_event(&self) -> UnixNanos {
+        match self {
+            Self::Betting(inst) => inst.ts_event(),
+            Self::BinaryOption(inst) => inst.ts_event(),
+            Self::CryptoFuture(inst) => inst.ts_event(),
+            Self::CryptoPerpetual(inst) => inst.ts_event(),
+            Self::CurrencyPair(inst) => inst.ts_event(),
+            Self::Equity(inst) => inst.ts_event(),
+            Self::FuturesContract(inst) => inst.ts_event(),
+            Self::FuturesSpread(inst) => inst.ts_event(),
+            Self::OptionsContract(inst) => inst.ts_event(),
+            Self::OptionsSpread(inst) => inst.ts_event(),
+        }
+    }
+
     pub fn get_base_quantity(&self, quantity: Quantity, last_px: Price) -> Quantity {
         match self {
             Self::Betting(inst) => inst.calculate_base_quantity(quantity, last_px),
@@ -519,3 +569,20 @@ impl PartialEq for InstrumentAny {
         self.id() == other.id()
     }
 }
+
+impl GetTsInit for InstrumentAny {
+    fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::Betting(inst) => inst.ts_init(),
+            Self::BinaryOption(inst) => inst.ts_init(),
+            Self::CryptoFuture(inst) => inst.ts_init(),
+            Self::CryptoPerpetual(inst) => inst.ts_init(),
+            Self::CurrencyPair(inst) => inst.ts_init(),
+            Self::Equity(inst) => inst.ts_init(),
+            Self::FuturesContract(inst) => inst.ts_init(),
+            Self::FuturesSpread(inst) => inst.ts_init(),
+            Self::OptionsContract(inst) => inst.ts_init(),
+            Self::OptionsSpread(inst) => inst.ts_init(),
+        }
+    }
+}
@@ -0,0 +1,244 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Inverse (coin-margined) contract math.
+//!
+//! Inverse contracts are quoted in the quote currency (e.g. USD) but margined and settled in the
+//! base currency (e.g. BTC), with each contract having a fixed face value in the quote currency
+//! given by the instrument multiplier. The functions in this module are the single source of
+//! truth for inverse notional, PnL and liquidation math used by positions, accounts and the
+//! simulated venue.
+
+use crate::enums::PositionSide;
+
+/// Returns the notional value of an inverse position in the base (settlement) currency.
+///
+/// Computed as `quantity * multiplier / price`.
+#[must_use]
+pub fn notional_value(quantity: f64, multiplier: f64, price: f64) -> f64 {
+    quantity * multiplier * (1.0 / price)
+}
+
+/// Returns the face value of an inverse position in the quote currency.
+///
+/// Computed as `quantity * multiplier`, which is independent of price.
+#[must_use]
+pub fn face_value(quantity: f64, multiplier: f64) -> f64 {
+    quantity * multiplier
+}
+
+/// Returns the PnL points (per unit of face value) in the base currency for the `side`.
+///
+/// A long position gains as price rises, since the base currency value of the fixed face value
+/// falls: `1 / avg_px_open - 1 / avg_px_close`. Returns zero when `side` is flat.
+#[must_use]
+pub fn points(side: PositionSide, avg_px_open: f64, avg_px_close: f64) -> f64 {
+    let inverse_open = 1.0 / avg_px_open;
+    let inverse_close = 1.0 / avg_px_close;
+    match side {
+        PositionSide::Long => inverse_open - inverse_close,
+        PositionSide::Short => inverse_close - inverse_open,
+        _ => 0.0, // FLAT
+    }
+}
+
+/// Returns the PnL of an inverse position in the base (settlement) currency.
+#[must_use]
+pub fn pnl(
+    side: PositionSide,
+    quantity: f64,
+    multiplier: f64,
+    avg_px_open: f64,
+    avg_px_close: f64,
+) -> f64 {
+    quantity * multiplier * points(side, avg_px_open, avg_px_close)
+}
+
+/// Returns the PnL of an inverse position converted to the quote currency at `price`.
+#[must_use]
+pub fn pnl_in_quote(
+    side: PositionSide,
+    quantity: f64,
+    multiplier: f64,
+    avg_px_open: f64,
+    price: f64,
+) -> f64 {
+    pnl(side, quantity, multiplier, avg_px_open, price) * price
+}
+
+/// Returns the estimated liquidation price of an isolated inverse position.
+///
+/// The initial margin is taken as `1 / leverage` of the entry notional (in base currency), and
+/// the position is liquidated when the remaining margin equals `maint_margin_rate` of the
+/// notional at the liquidation price. Fees and funding are not considered.
+///
+/// Returns `None` when the position cannot be liquidated (e.g. a short with leverage of one or
+/// less), or the inputs are not positive.
+#[must_use]
+pub fn liquidation_price(
+    side: PositionSide,
+    avg_px_open: f64,
+    leverage: f64,
+    maint_margin_rate: f64,
+) -> Option<f64> {
+    if avg_px_open <= 0.0 || leverage <= 0.0 || maint_margin_rate < 0.0 {
+        return None;
+    }
+
+    let margin_rate = 1.0 / leverage;
+    let price = match side {
+        // 1/E * (1 + 1/L) = (1 + mmr) / P
+        PositionSide::Long => avg_px_open * (1.0 + maint_margin_rate) / (1.0 + margin_rate),
+        // 1/E * (1/L - 1) = (mmr - 1) / P
+        PositionSide::Short => {
+            let denominator = 1.0 - margin_rate;
+            if denominator <= 0.0 || maint_margin_rate >= 1.0 {
+                return None;
+            }
+            avg_px_open * (1.0 - maint_margin_rate) / denominator
+        }
+        _ => return None, // FLAT
+    };
+
+    Some(price)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use float_cmp::approx_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_notional_value() {
+        // 10,000 contracts of $1 at $50,000 is 0.2 BTC
+        assert!(approx_eq!(
+            f64,
+            notional_value(10_000.0, 1.0, 50_000.0),
+            0.2,
+            epsilon = 1e-12
+        ));
+    }
+
+    #[rstest]
+    fn test_face_value() {
+        assert!(approx_eq!(
+            f64,
+            face_value(10_000.0, 1.0),
+            10_000.0,
+            epsilon = 1e-12
+        ));
+        assert!(approx_eq!(
+            f64,
+            face_value(100.0, 10.0),
+            1_000.0,
+            epsilon = 1e-12
+        ));
+    }
+
+    #[rstest]
+    #[case(PositionSide::Long, 0.000_01)]
+    #[case(PositionSide::Short, -0.000_01)]
+    #[case(PositionSide::Flat, 0.0)]
+    fn test_points(#[case] side: PositionSide, #[case] expected: f64) {
+        // 1/50,000 - 1/100,000 = 0.00001
+        let result = points(side, 50_000.0, 100_000.0);
+        assert!(approx_eq!(f64, result, expected, epsilon = 1e-12));
+    }
+
+    #[rstest]
+    #[case(PositionSide::Long, 0.1)]
+    #[case(PositionSide::Short, -0.1)]
+    fn test_pnl_price_doubles(#[case] side: PositionSide, #[case] expected: f64) {
+        // 10,000 contracts opened at $50,000 (0.2 BTC), closed at $100,000 (0.1 BTC)
+        let result = pnl(side, 10_000.0, 1.0, 50_000.0, 100_000.0);
+        assert!(approx_eq!(f64, result, expected, epsilon = 1e-12));
+    }
+
+    #[rstest]
+    fn test_pnl_is_asymmetric_for_long() {
+        // A long inverse position gains less on the way up than it loses on the way down
+        let gain = pnl(PositionSide::Long, 10_000.0, 1.0, 50_000.0, 60_000.0);
+        let loss = pnl(PositionSide::Long, 10_000.0, 1.0, 50_000.0, 40_000.0);
+        assert!(gain > 0.0);
+        assert!(loss < 0.0);
+        assert!(gain < loss.abs());
+    }
+
+    #[rstest]
+    fn test_pnl_with_multiplier() {
+        let result = pnl(PositionSide::Long, 100.0, 100.0, 50_000.0, 100_000.0);
+        assert!(approx_eq!(f64, result, 0.1, epsilon = 1e-12));
+    }
+
+    #[rstest]
+    fn test_pnl_in_quote() {
+        // 0.1 BTC gain converted at $100,000
+        let result = pnl_in_quote(PositionSide::Long, 10_000.0, 1.0, 50_000.0, 100_000.0);
+        assert!(approx_eq!(f64, result, 10_000.0, epsilon = 1e-6));
+    }
+
+    #[rstest]
+    fn test_liquidation_price_long() {
+        // 10x long with no maintenance margin liquidates when the 10% initial margin is lost
+        let price = liquidation_price(PositionSide::Long, 50_000.0, 10.0, 0.0).unwrap();
+        assert!(approx_eq!(f64, price, 50_000.0 / 1.1, epsilon = 1e-6));
+
+        // Margin plus PnL at the liquidation price is exactly zero
+        let margin = notional_value(1.0, 1.0, 50_000.0) / 10.0;
+        let loss = pnl(PositionSide::Long, 1.0, 1.0, 50_000.0, price);
+        assert!(approx_eq!(f64, margin + loss, 0.0, epsilon = 1e-15));
+    }
+
+    #[rstest]
+    fn test_liquidation_price_long_with_maintenance_margin() {
+        let price = liquidation_price(PositionSide::Long, 50_000.0, 10.0, 0.005).unwrap();
+        let margin = notional_value(1.0, 1.0, 50_000.0) / 10.0;
+        let loss = pnl(PositionSide::Long, 1.0, 1.0, 50_000.0, price);
+        let maintenance = 0.005 * notional_value(1.0, 1.0, price);
+        assert!(approx_eq!(f64, margin + loss, maintenance, epsilon = 1e-15));
+        assert!(price > 50_000.0 / 1.1);
+    }
+
+    #[rstest]
+    fn test_liquidation_price_short() {
+        let price = liquidation_price(PositionSide::Short, 50_000.0, 10.0, 0.005).unwrap();
+        let margin = notional_value(1.0, 1.0, 50_000.0) / 10.0;
+        let loss = pnl(PositionSide::Short, 1.0, 1.0, 50_000.0, price);
+        let maintenance = 0.005 * notional_value(1.0, 1.0, price);
+        assert!(approx_eq!(f64, margin + loss, maintenance, epsilon = 1e-15));
+        assert!(price > 50_000.0);
+    }
+
+    #[rstest]
+    #[case(PositionSide::Short, 50_000.0, 1.0, 0.0)]
+    #[case(PositionSide::Short, 50_000.0, 0.5, 0.0)]
+    #[case(PositionSide::Flat, 50_000.0, 10.0, 0.0)]
+    #[case(PositionSide::Long, 0.0, 10.0, 0.0)]
+    #[case(PositionSide::Long, 50_000.0, 0.0, 0.0)]
+    #[case(PositionSide::Long, 50_000.0, 10.0, -0.1)]
+    fn test_liquidation_price_none(
+        #[case] side: PositionSide,
+        #[case] avg_px_open: f64,
+        #[case] leverage: f64,
+        #[case] maint_margin_rate: f64,
+    ) {
+        assert!(liquidation_price(side, avg_px_open, leverage, maint_margin_rate).is_none());
+    }
+}
@@ -24,6 +24,7 @@ pub mod currency_pair;
 pub mod equity;
 pub mod futures_contract;
 pub mod futures_spread;
+pub mod inverse;
 pub mod options_contract;
 pub mod options_spread;
 pub mod synthetic;
@@ -131,8 +132,11 @@ pub trait Instrument: 'static + Send {
             if use_quote_for_inverse {
                 (quantity.as_f64(), self.quote_currency())
             } else {
-                let amount =
-                    quantity.as_f64() * self.multiplier().as_f64() * (1.0 / price.as_f64());
+                let amount = inverse::notional_value(
+                    quantity.as_f64(),
+                    self.multiplier().as_f64(),
+                    price.as_f64(),
+                );
                 let currency = self
                     .base_currency()
                     .expect("Error: no base currency for notional calculation");
@@ -21,9 +21,26 @@ use super::{BookLevel, BookPrice, OrderBook};
 use crate::{
     enums::{BookType, OrderSide},
     orderbook::BookIntegrityError,
-    types::{Price, Quantity},
+    types::{fixed::FIXED_SCALAR, Price, Quantity},
 };
 
+/// Represents the estimated cost of sweeping a quantity through one side of an order book.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SweepCost {
+    /// The quantity which can be filled from the available liquidity.
+    pub filled_qty: f64,
+    /// The average fill price.
+    pub avg_px: f64,
+    /// The price of the last (worst) level reached.
+    pub worst_px: f64,
+    /// The total notional value (price * size) of the fills.
+    pub notional: f64,
+    /// The absolute difference between the average fill price and the best price.
+    pub slippage: f64,
+    /// The slippage relative to the best price in basis points.
+    pub slippage_bps: f64,
+}
+
 /// Calculates the estimated fill quantity for a specified price from a set of
 /// order book levels and order side.
 #[must_use]
@@ -117,7 +134,104 @@ pub fn get_avg_px_qty_for_exposure(
     }
 }
 
+/// Calculates the total size of the top `depth` levels from a set of order book levels.
+#[must_use]
+pub fn get_depth_volume(levels: &BTreeMap<BookPrice, BookLevel>, depth: usize) -> f64 {
+    levels.values().take(depth).map(BookLevel::size).sum()
+}
+
+/// Calculates the volume-weighted average price of the top `depth` levels from a set
+/// of order book levels, returning `None` if there is no volume.
+#[must_use]
+pub fn get_depth_vwap(levels: &BTreeMap<BookPrice, BookLevel>, depth: usize) -> Option<f64> {
+    let mut volume = 0.0;
+    let mut value = 0.0;
+
+    for (book_price, level) in levels.iter().take(depth) {
+        let size = level.size();
+        volume += size;
+        value += book_price.value.as_f64() * size;
+    }
+
+    if volume == 0.0 {
+        None
+    } else {
+        Some(value / volume)
+    }
+}
+
+/// Calculates the cumulative size available within each of the given price `offsets`
+/// (absolute price distances) from the best price of a set of order book levels.
+#[must_use]
+pub fn get_cumulative_depth(levels: &BTreeMap<BookPrice, BookLevel>, offsets: &[f64]) -> Vec<f64> {
+    let Some(best_px) = levels.keys().next().map(|p| p.value.as_f64()) else {
+        return vec![0.0; offsets.len()];
+    };
+
+    offsets
+        .iter()
+        .map(|offset| {
+            levels
+                .iter()
+                .take_while(|(book_price, _)| {
+                    (book_price.value.as_f64() - best_px).abs() <= *offset
+                })
+                .map(|(_, level)| level.size())
+                .sum()
+        })
+        .collect()
+}
+
+/// Calculates the estimated cost of sweeping the specified quantity through a set of
+/// order book levels, returning `None` if there is no liquidity.
+///
+/// If the available liquidity is less than `qty`, the cost reflects the partial fill.
+#[must_use]
+pub fn get_sweep_cost(qty: Quantity, levels: &BTreeMap<BookPrice, BookLevel>) -> Option<SweepCost> {
+    let best_px = levels.keys().next()?.value.as_f64();
+
+    let mut cumulative_size_raw = 0u64;
+    let mut cumulative_value = 0.0;
+    let mut worst_px = best_px;
+
+    for (book_price, level) in levels {
+        if cumulative_size_raw >= qty.raw {
+            break;
+        }
+
+        let size_this_level = level.size_raw().min(qty.raw - cumulative_size_raw);
+        if size_this_level == 0 {
+            continue;
+        }
+
+        worst_px = book_price.value.as_f64();
+        cumulative_size_raw += size_this_level;
+        cumulative_value += worst_px * size_this_level as f64;
+    }
+
+    if cumulative_size_raw == 0 {
+        return None;
+    }
+
+    let avg_px = cumulative_value / cumulative_size_raw as f64;
+    let filled_qty = cumulative_size_raw as f64 / FIXED_SCALAR;
+    let slippage = (avg_px - best_px).abs();
+
+    Some(SweepCost {
+        filled_qty,
+        avg_px,
+        worst_px,
+        notional: avg_px * filled_qty,
+        slippage,
+        slippage_bps: slippage / best_px * 10_000.0,
+    })
+}
+
 pub fn book_check_integrity(book: &OrderBook) -> Result<(), BookIntegrityError> {
+    if let Some((expected, received)) = book.sequence_gap {
+        return Err(BookIntegrityError::SequenceGap(expected, received));
+    }
+
     match book.book_type {
         BookType::L1_MBP => {
             if book.bids.len() > 1 {
@@ -166,5 +280,15 @@ pub fn book_check_integrity(book: &OrderBook) -> Result<(), BookIntegrityError>
         }
     }
 
+    for level in book.bids.levels.values().chain(book.asks.levels.values()) {
+        if let Some(order) = level.orders.values().find(|order| order.size.raw == 0) {
+            return Err(BookIntegrityError::NonPositiveSize(
+                level.price.side,
+                level.price,
+                order.order_id,
+            ));
+        }
+    }
+
     Ok(())
 }
@@ -21,15 +21,40 @@ use indexmap::IndexMap;
 use nautilus_core::nanos::UnixNanos;
 use rust_decimal::Decimal;
 
-use super::{aggregation::pre_process_order, analysis, display::pprint_book, level::BookLevel};
+use super::{
+    aggregation::pre_process_order,
+    analysis,
+    display::{pprint_book, pprint_book_with_options, BookDisplayOptions},
+    level::BookLevel,
+};
 use crate::{
-    data::{BookOrder, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick},
+    data::{
+        order::OrderId, BookOrder, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick,
+        TradeTick,
+    },
     enums::{BookAction, BookType, OrderSide, OrderSideSpecified},
     identifiers::InstrumentId,
-    orderbook::{ladder::BookLadder, InvalidBookOperation},
+    orderbook::{
+        analysis::{book_check_integrity, SweepCost},
+        ladder::BookLadder,
+        BookIntegrityError, InvalidBookOperation,
+    },
     types::{Price, Quantity},
 };
 
+/// Strategy for automatically resolving order book integrity violations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BookIntegrityResolution {
+    /// Only report violations, leaving the book unchanged.
+    #[default]
+    None,
+    /// Drop crossing levels from the least recently updated (stale) side of the book,
+    /// along with any zero-size orders.
+    DropStaleLevels,
+    /// Clear the book so it can be rebuilt from a fresh snapshot (e.g. by resubscribing).
+    RequestSnapshot,
+}
+
 /// Provides a high-performance, versatile order book.
 ///
 /// Maintains buy (bid) and sell (ask) orders in price-time priority, supporting multiple
@@ -55,6 +80,10 @@ pub struct OrderBook {
     pub count: u64,
     pub(crate) bids: BookLadder,
     pub(crate) asks: BookLadder,
+    pub(crate) bids_ts_last: UnixNanos,
+    pub(crate) asks_ts_last: UnixNanos,
+    pub(crate) check_sequence: bool,
+    pub(crate) sequence_gap: Option<(u64, u64)>,
 }
 
 impl PartialEq for OrderBook {
@@ -89,9 +118,21 @@ impl OrderBook {
             count: 0,
             bids: BookLadder::new(OrderSide::Buy),
             asks: BookLadder::new(OrderSide::Sell),
+            bids_ts_last: UnixNanos::default(),
+            asks_ts_last: UnixNanos::default(),
+            check_sequence: false,
+            sequence_gap: None,
         }
     }
 
+    /// Sets whether the book should track gaps in the sequence numbers of applied events.
+    ///
+    /// Only enable this for venues which provide contiguous sequence numbers per instrument.
+    pub fn set_sequence_checks(&mut self, enabled: bool) {
+        self.check_sequence = enabled;
+        self.sequence_gap = None;
+    }
+
     /// Resets the order book to its initial empty state.
     pub fn reset(&mut self) {
         self.bids.clear();
@@ -99,6 +140,9 @@ impl OrderBook {
         self.sequence = 0;
         self.ts_last = UnixNanos::default();
         self.count = 0;
+        self.bids_ts_last = UnixNanos::default();
+        self.asks_ts_last = UnixNanos::default();
+        self.sequence_gap = None;
     }
 
     /// Adds an order to the book after preprocessing based on book type.
@@ -109,6 +153,7 @@ impl OrderBook {
             OrderSideSpecified::Sell => self.asks.add(order),
         }
 
+        self.touch(order.side, ts_event);
         self.increment(sequence, ts_event);
     }
 
@@ -120,6 +165,7 @@ impl OrderBook {
             OrderSideSpecified::Sell => self.asks.update(order),
         }
 
+        self.touch(order.side, ts_event);
         self.increment(sequence, ts_event);
     }
 
@@ -131,25 +177,54 @@ impl OrderBook {
             OrderSideSpecified::Sell => self.asks.delete(order, sequence, ts_event),
         }
 
+        self.touch(order.side, ts_event);
+        self.increment(sequence, ts_event);
+    }
+
+    /// Removes the order with the given `order_id` from whichever side of the book holds it.
+    ///
+    /// This supports L3 (MBO) feeds which only provide the venue order ID for deletes.
+    /// Returns `true` if the order was found and removed.
+    pub fn remove_order(&mut self, order_id: OrderId, sequence: u64, ts_event: UnixNanos) -> bool {
+        let removed = if self.bids.contains(order_id) {
+            self.bids.remove(order_id, sequence, ts_event);
+            self.touch(OrderSide::Buy, ts_event);
+            true
+        } else if self.asks.contains(order_id) {
+            self.asks.remove(order_id, sequence, ts_event);
+            self.touch(OrderSide::Sell, ts_event);
+            true
+        } else {
+            false
+        };
+
         self.increment(sequence, ts_event);
+        removed
     }
 
     /// Clears all orders from both sides of the book.
+    ///
+    /// A clear starts a new snapshot, so any previously detected sequence gap is resolved.
     pub fn clear(&mut self, sequence: u64, ts_event: UnixNanos) {
         self.bids.clear();
         self.asks.clear();
+        self.touch(OrderSide::Buy, ts_event);
+        self.touch(OrderSide::Sell, ts_event);
         self.increment(sequence, ts_event);
+        self.sequence_gap = None;
     }
 
     /// Clears all bid orders from the book.
     pub fn clear_bids(&mut self, sequence: u64, ts_event: UnixNanos) {
         self.bids.clear();
+        self.touch(OrderSide::Buy, ts_event);
         self.increment(sequence, ts_event);
     }
 
     /// Clears all ask orders from the book.
     pub fn clear_asks(&mut self, sequence: u64, ts_event: UnixNanos) {
         self.asks.clear();
+        self.touch(OrderSide::Sell, ts_event);
         self.increment(sequence, ts_event);
     }
 
@@ -186,6 +261,18 @@ impl OrderBook {
         for order in depth.asks {
             self.add(order, depth.flags, depth.sequence, depth.ts_event);
         }
+
+        // A depth snapshot replaces the book state entirely
+        self.sequence_gap = None;
+    }
+
+    /// Returns a copy of the order book retaining only the top `depth` price levels of each side.
+    #[must_use]
+    pub fn clone_to_depth(&self, depth: usize) -> Self {
+        let mut book = self.clone();
+        book.bids.truncate(depth);
+        book.asks.truncate(depth);
+        book
     }
 
     /// Returns an iterator over bid price levels.
@@ -263,6 +350,111 @@ impl OrderBook {
         levels
     }
 
+    /// Returns the order with the given `order_id` from either side of the book (if found).
+    #[must_use]
+    pub fn get_order(&self, order_id: OrderId) -> Option<&BookOrder> {
+        self.bids
+            .get_order(order_id)
+            .or_else(|| self.asks.get_order(order_id))
+    }
+
+    /// Returns all orders resting at the given `price` on the given `side` in FIFO order.
+    #[must_use]
+    pub fn orders_at_price(&self, side: OrderSide, price: Price) -> Vec<BookOrder> {
+        let ladder = match side.as_specified() {
+            OrderSideSpecified::Buy => &self.bids,
+            OrderSideSpecified::Sell => &self.asks,
+        };
+        ladder
+            .get_level(price)
+            .map(BookLevel::get_orders)
+            .unwrap_or_default()
+    }
+
+    /// Returns the zero-based FIFO queue position of the order with the given `order_id`
+    /// at its price level (if found).
+    #[must_use]
+    pub fn queue_position(&self, order_id: OrderId) -> Option<usize> {
+        self.bids
+            .level_for_order(order_id)
+            .or_else(|| self.asks.level_for_order(order_id))
+            .and_then(|level| level.queue_position(order_id))
+    }
+
+    /// Returns the total size queued ahead of the order with the given `order_id`
+    /// at its price level (if found).
+    #[must_use]
+    pub fn size_ahead(&self, order_id: OrderId) -> Option<Quantity> {
+        self.bids
+            .level_for_order(order_id)
+            .or_else(|| self.asks.level_for_order(order_id))
+            .and_then(|level| level.size_ahead(order_id))
+    }
+
+    /// Checks the book for integrity violations (crossed book, non-positive sizes,
+    /// sequence gaps, and level/order counts inconsistent with the book type).
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`BookIntegrityError`] found.
+    pub fn check_integrity(&self) -> Result<(), BookIntegrityError> {
+        book_check_integrity(self)
+    }
+
+    /// Checks the book for integrity violations and attempts to resolve them using
+    /// the given `resolution` strategy.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`BookIntegrityError`] which could not be resolved. For the
+    /// [`BookIntegrityResolution::RequestSnapshot`] strategy the book is cleared and the
+    /// original violation returned, so the caller can request a fresh snapshot.
+    pub fn resolve_integrity(
+        &mut self,
+        resolution: BookIntegrityResolution,
+    ) -> Result<(), BookIntegrityError> {
+        let error = match self.check_integrity() {
+            Ok(()) => return Ok(()),
+            Err(e) => e,
+        };
+
+        match resolution {
+            BookIntegrityResolution::None => Err(error),
+            BookIntegrityResolution::DropStaleLevels => {
+                self.bids.remove_empty_orders();
+                self.asks.remove_empty_orders();
+                self.drop_stale_crossed_levels();
+                self.check_integrity()
+            }
+            BookIntegrityResolution::RequestSnapshot => {
+                if error.requires_snapshot() {
+                    self.bids.clear();
+                    self.asks.clear();
+                    self.sequence_gap = None;
+                }
+                Err(error)
+            }
+        }
+    }
+
+    fn drop_stale_crossed_levels(&mut self) {
+        let (Some(best_bid), Some(best_ask)) = (self.best_bid_price(), self.best_ask_price())
+        else {
+            return;
+        };
+
+        if best_bid < best_ask {
+            return; // Not crossed
+        }
+
+        // When both sides were last updated at the same time the stale side is ambiguous
+        if self.bids_ts_last < self.asks_ts_last {
+            self.bids.remove_levels_crossing(best_ask);
+        } else if self.asks_ts_last < self.bids_ts_last {
+            self.asks.remove_levels_crossing(best_bid);
+        }
+    }
+
     /// Returns true if the book has any bid orders.
     #[must_use]
     pub fn has_bid(&self) -> bool {
@@ -358,6 +550,67 @@ impl OrderBook {
         analysis::get_quantity_for_price(price, order_side, levels)
     }
 
+    /// Returns the order book imbalance `(bid_volume - ask_volume) / (bid_volume + ask_volume)`
+    /// over the top `depth` levels of each side (all levels if `None`).
+    ///
+    /// Returns `None` if both sides are empty.
+    #[must_use]
+    pub fn imbalance(&self, depth: Option<usize>) -> Option<f64> {
+        let depth = depth.unwrap_or(usize::MAX);
+        let bid_volume = analysis::get_depth_volume(&self.bids.levels, depth);
+        let ask_volume = analysis::get_depth_volume(&self.asks.levels, depth);
+        let total_volume = bid_volume + ask_volume;
+
+        if total_volume == 0.0 {
+            None
+        } else {
+            Some((bid_volume - ask_volume) / total_volume)
+        }
+    }
+
+    /// Returns the volume-weighted midpoint over the top `depth` levels of each side
+    /// (all levels if `None`).
+    ///
+    /// Each side's volume-weighted price is weighted by the opposing side's volume, so
+    /// the result leans towards the side with less liquidity (as with a micro-price).
+    /// Returns `None` if either side is empty.
+    #[must_use]
+    pub fn volume_weighted_midpoint(&self, depth: Option<usize>) -> Option<f64> {
+        let depth = depth.unwrap_or(usize::MAX);
+        let bid_vwap = analysis::get_depth_vwap(&self.bids.levels, depth)?;
+        let ask_vwap = analysis::get_depth_vwap(&self.asks.levels, depth)?;
+        let bid_volume = analysis::get_depth_volume(&self.bids.levels, depth);
+        let ask_volume = analysis::get_depth_volume(&self.asks.levels, depth);
+
+        Some((bid_vwap * ask_volume + ask_vwap * bid_volume) / (bid_volume + ask_volume))
+    }
+
+    /// Returns the cumulative size available on the given book side within each of the
+    /// price `offsets` (absolute price distances) from that side's best price.
+    ///
+    /// A `side` of [`OrderSide::Buy`] returns the bid depth, [`OrderSide::Sell`] the ask depth.
+    #[must_use]
+    pub fn cumulative_depth(&self, side: OrderSide, offsets: &[f64]) -> Vec<f64> {
+        let levels = match side.as_specified() {
+            OrderSideSpecified::Buy => &self.bids.levels,
+            OrderSideSpecified::Sell => &self.asks.levels,
+        };
+
+        analysis::get_cumulative_depth(levels, offsets)
+    }
+
+    /// Estimates the cost of sweeping the specified quantity with an aggressive order on
+    /// the given `order_side`, returning `None` if there is no opposing liquidity.
+    #[must_use]
+    pub fn sweep_cost(&self, qty: Quantity, order_side: OrderSide) -> Option<SweepCost> {
+        let levels = match order_side.as_specified() {
+            OrderSideSpecified::Buy => &self.asks.levels,
+            OrderSideSpecified::Sell => &self.bids.levels,
+        };
+
+        analysis::get_sweep_cost(qty, levels)
+    }
+
     /// Simulates fills for an order, returning list of (price, quantity) tuples.
     #[must_use]
     pub fn simulate_fills(&self, order: &BookOrder) -> Vec<(Price, Quantity)> {
@@ -373,12 +626,35 @@ impl OrderBook {
         pprint_book(&self.bids, &self.asks, num_levels)
     }
 
+    /// Return a formatted string representation of the order book, rendered with the
+    /// given `options` (e.g. own order markers and ANSI colours).
+    #[must_use]
+    pub fn pprint_with_options(&self, num_levels: usize, options: &BookDisplayOptions) -> String {
+        pprint_book_with_options(&self.bids, &self.asks, num_levels, options)
+    }
+
     fn increment(&mut self, sequence: u64, ts_event: UnixNanos) {
+        if self.check_sequence
+            && self.sequence_gap.is_none()
+            && self.sequence > 0
+            && sequence > self.sequence + 1
+        {
+            self.sequence_gap = Some((self.sequence + 1, sequence));
+        }
+
         self.sequence = sequence;
         self.ts_last = ts_event;
         self.count += 1;
     }
 
+    fn touch(&mut self, side: OrderSide, ts_event: UnixNanos) {
+        match side {
+            OrderSide::Buy => self.bids_ts_last = ts_event,
+            OrderSide::Sell => self.asks_ts_last = ts_event,
+            OrderSide::NoOrderSide => {}
+        }
+    }
+
     /// Updates L1 book state from a quote tick. Only valid for L1_MBP book type.
     pub fn update_quote_tick(&mut self, quote: &QuoteTick) -> Result<(), InvalidBookOperation> {
         if self.book_type != BookType::L1_MBP {
@@ -438,6 +714,7 @@ impl OrderBook {
             }
         }
         self.bids.add(order);
+        self.touch(OrderSide::Buy, ts_event);
     }
 
     fn update_book_ask(&mut self, order: BookOrder, ts_event: UnixNanos) {
@@ -447,6 +724,7 @@ impl OrderBook {
             }
         }
         self.asks.add(order);
+        self.touch(OrderSide::Sell, ts_event);
     }
 }
 
@@ -455,6 +733,8 @@ impl OrderBook {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use rstest::rstest;
     use rust_decimal_macros::dec;
 
@@ -462,7 +742,10 @@ mod tests {
         data::{depth::OrderBookDepth10, order::BookOrder, stubs::*, QuoteTick, TradeTick},
         enums::{AggressorSide, BookType, OrderSide},
         identifiers::{InstrumentId, TradeId},
-        orderbook::{analysis::book_check_integrity, BookIntegrityError, BookPrice, OrderBook},
+        orderbook::{
+            analysis::book_check_integrity, display::BookDisplayOptions, BookIntegrityError,
+            BookIntegrityResolution, BookPrice, OrderBook,
+        },
         types::{Price, Quantity},
     };
 
@@ -543,6 +826,199 @@ mod tests {
         assert_eq!(book.best_bid_size().unwrap().as_f64() as i64, quantity);
     }
 
+    #[rstest]
+    fn test_book_integrity_zero_size_order() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+
+        let bid = BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(0), 1);
+        book.add(bid, 0, 1, 1.into());
+
+        let result = book.check_integrity();
+
+        assert_eq!(
+            result,
+            Err(BookIntegrityError::NonPositiveSize(
+                OrderSide::Buy,
+                BookPrice::new(Price::from("100.00"), OrderSide::Buy),
+                1,
+            ))
+        );
+        assert!(result.unwrap_err().requires_snapshot());
+    }
+
+    #[rstest]
+    fn test_book_integrity_sequence_gap_ignored_by_default() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+
+        let bid = BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(10), 1);
+        book.add(bid, 0, 1, 1.into());
+        book.update(bid, 0, 5, 2.into());
+
+        assert!(book.check_integrity().is_ok());
+    }
+
+    #[rstest]
+    fn test_book_integrity_sequence_gap_detected_and_cleared_by_snapshot() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        book.set_sequence_checks(true);
+
+        let bid = BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(10), 1);
+        book.add(bid, 0, 1, 1.into());
+        book.update(bid, 0, 2, 2.into());
+        book.update(bid, 0, 2, 2.into()); // Repeated sequence is not a gap
+        assert!(book.check_integrity().is_ok());
+
+        book.update(bid, 0, 5, 3.into());
+        assert_eq!(
+            book.check_integrity(),
+            Err(BookIntegrityError::SequenceGap(3, 5))
+        );
+
+        book.clear(6, 4.into());
+        assert!(book.check_integrity().is_ok());
+    }
+
+    #[rstest]
+    fn test_resolve_integrity_when_valid() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+
+        let result = book.resolve_integrity(BookIntegrityResolution::DropStaleLevels);
+
+        assert!(result.is_ok());
+    }
+
+    #[rstest]
+    fn test_resolve_integrity_none_leaves_book_unchanged() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let bid = BookOrder::new(OrderSide::Buy, Price::from("101.00"), Quantity::from(10), 1);
+        let ask = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("100.00"),
+            Quantity::from(10),
+            2,
+        );
+        book.add(bid, 0, 1, 1.into());
+        book.add(ask, 0, 2, 2.into());
+
+        let result = book.resolve_integrity(BookIntegrityResolution::None);
+
+        assert!(matches!(result, Err(BookIntegrityError::OrdersCrossed(..))));
+        assert_eq!(book.best_bid_price(), Some(Price::from("101.00")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("100.00")));
+    }
+
+    #[rstest]
+    fn test_resolve_integrity_drops_stale_bid_levels() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let bid1 = BookOrder::new(OrderSide::Buy, Price::from("101.00"), Quantity::from(10), 1);
+        let bid2 = BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(10), 2);
+        let bid3 = BookOrder::new(OrderSide::Buy, Price::from("99.00"), Quantity::from(10), 3);
+        let ask = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("100.00"),
+            Quantity::from(10),
+            4,
+        );
+        book.add(bid1, 0, 1, 1.into());
+        book.add(bid2, 0, 2, 2.into());
+        book.add(bid3, 0, 3, 3.into());
+        book.add(ask, 0, 4, 4.into()); // Asks updated more recently
+
+        let result = book.resolve_integrity(BookIntegrityResolution::DropStaleLevels);
+
+        assert!(result.is_ok());
+        assert_eq!(book.best_bid_price(), Some(Price::from("99.00")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("100.00")));
+        assert_eq!(book.bids(None).count(), 1);
+    }
+
+    #[rstest]
+    fn test_resolve_integrity_drops_stale_ask_levels() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let ask1 = BookOrder::new(OrderSide::Sell, Price::from("99.00"), Quantity::from(10), 1);
+        let ask2 = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("102.00"),
+            Quantity::from(10),
+            2,
+        );
+        let bid = BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(10), 3);
+        book.add(ask1, 0, 1, 1.into());
+        book.add(ask2, 0, 2, 2.into());
+        book.add(bid, 0, 3, 3.into()); // Bids updated more recently
+
+        let result = book.resolve_integrity(BookIntegrityResolution::DropStaleLevels);
+
+        assert!(result.is_ok());
+        assert_eq!(book.best_bid_price(), Some(Price::from("100.00")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("102.00")));
+    }
+
+    #[rstest]
+    fn test_resolve_integrity_drops_zero_size_orders() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+        let bid1 = BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(0), 1);
+        let bid2 = BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(10), 2);
+        book.add(bid1, 0, 1, 1.into());
+        book.add(bid2, 0, 2, 2.into());
+
+        let result = book.resolve_integrity(BookIntegrityResolution::DropStaleLevels);
+
+        assert!(result.is_ok());
+        assert!(book.get_order(1).is_none());
+        assert_eq!(book.best_bid_size(), Some(Quantity::from(10)));
+    }
+
+    #[rstest]
+    fn test_resolve_integrity_drop_stale_levels_when_ambiguous() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let bid = BookOrder::new(OrderSide::Buy, Price::from("101.00"), Quantity::from(10), 1);
+        let ask = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("100.00"),
+            Quantity::from(10),
+            2,
+        );
+        book.add(bid, 0, 1, 1.into());
+        book.add(ask, 0, 2, 1.into());
+
+        let result = book.resolve_integrity(BookIntegrityResolution::DropStaleLevels);
+
+        assert!(matches!(result, Err(BookIntegrityError::OrdersCrossed(..))));
+    }
+
+    #[rstest]
+    fn test_resolve_integrity_request_snapshot_clears_book() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let bid = BookOrder::new(OrderSide::Buy, Price::from("101.00"), Quantity::from(10), 1);
+        let ask = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("100.00"),
+            Quantity::from(10),
+            2,
+        );
+        book.add(bid, 0, 1, 1.into());
+        book.add(ask, 0, 2, 2.into());
+
+        let result = book.resolve_integrity(BookIntegrityResolution::RequestSnapshot);
+
+        let error = result.unwrap_err();
+        assert!(error.requires_snapshot());
+        assert!(!book.has_bid());
+        assert!(!book.has_ask());
+        assert!(book.check_integrity().is_ok());
+    }
+
     #[rstest]
     fn test_display() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -785,6 +1261,114 @@ mod tests {
         );
     }
 
+    fn analytics_book() -> OrderBook {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let orders = [
+            (OrderSide::Buy, "99.00", "3.0"),
+            (OrderSide::Buy, "98.00", "1.0"),
+            (OrderSide::Sell, "101.00", "1.0"),
+            (OrderSide::Sell, "102.00", "2.0"),
+            (OrderSide::Sell, "103.00", "1.0"),
+        ];
+        for (i, (side, price, size)) in orders.into_iter().enumerate() {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+            book.add(order, 0, i as u64 + 1, (i as u64 + 1).into());
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_analytics_empty_book() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let book = OrderBook::new(instrument_id, BookType::L2_MBP);
+
+        assert_eq!(book.imbalance(None), None);
+        assert_eq!(book.volume_weighted_midpoint(None), None);
+        assert_eq!(book.cumulative_depth(OrderSide::Buy, &[1.0]), vec![0.0]);
+        assert_eq!(book.sweep_cost(Quantity::from("1.0"), OrderSide::Buy), None);
+    }
+
+    #[rstest]
+    fn test_imbalance() {
+        let book = analytics_book();
+
+        assert_eq!(book.imbalance(None), Some(0.0));
+        assert_eq!(book.imbalance(Some(1)), Some(0.5));
+        assert_eq!(book.imbalance(Some(2)), Some(1.0 / 7.0));
+    }
+
+    #[rstest]
+    fn test_volume_weighted_midpoint() {
+        let book = analytics_book();
+
+        // Top level: bid 99.00 x 3, ask 101.00 x 1
+        assert_eq!(book.volume_weighted_midpoint(Some(1)), Some(100.5));
+        // Full book: bid vwap 98.75 (vol 4), ask vwap 102.00 (vol 4)
+        assert_eq!(book.volume_weighted_midpoint(None), Some(100.375));
+    }
+
+    #[rstest]
+    fn test_volume_weighted_midpoint_one_sided() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let bid = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("99.00"),
+            Quantity::from("1.0"),
+            0,
+        );
+        book.add(bid, 0, 1, 1.into());
+
+        assert_eq!(book.volume_weighted_midpoint(None), None);
+        assert_eq!(book.imbalance(None), Some(1.0));
+    }
+
+    #[rstest]
+    fn test_cumulative_depth() {
+        let book = analytics_book();
+
+        assert_eq!(
+            book.cumulative_depth(OrderSide::Buy, &[0.0, 0.5, 1.0, 5.0]),
+            vec![3.0, 3.0, 4.0, 4.0]
+        );
+        assert_eq!(
+            book.cumulative_depth(OrderSide::Sell, &[0.0, 1.0, 2.0]),
+            vec![1.0, 3.0, 4.0]
+        );
+    }
+
+    #[rstest]
+    fn test_sweep_cost_buy() {
+        let book = analytics_book();
+
+        let cost = book
+            .sweep_cost(Quantity::from("2.0"), OrderSide::Buy)
+            .unwrap();
+
+        assert_eq!(cost.filled_qty, 2.0);
+        assert_eq!(cost.avg_px, 101.5);
+        assert_eq!(cost.worst_px, 102.0);
+        assert_eq!(cost.notional, 203.0);
+        assert_eq!(cost.slippage, 0.5);
+        assert!((cost.slippage_bps - 49.504_950_495).abs() < 1e-6);
+    }
+
+    #[rstest]
+    fn test_sweep_cost_sell_exceeding_liquidity() {
+        let book = analytics_book();
+
+        let cost = book
+            .sweep_cost(Quantity::from("10.0"), OrderSide::Sell)
+            .unwrap();
+
+        assert_eq!(cost.filled_qty, 4.0);
+        assert_eq!(cost.avg_px, 98.75);
+        assert_eq!(cost.worst_px, 98.0);
+        assert_eq!(cost.notional, 395.0);
+        assert_eq!(cost.slippage, 0.25);
+    }
+
     #[rstest]
     fn test_get_price_for_exposure_no_market() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -820,6 +1404,23 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_clone_to_depth(stub_depth10: OrderBookDepth10) {
+        let depth = stub_depth10;
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        book.apply_depth(&depth);
+
+        let snapshot = book.clone_to_depth(3);
+
+        assert_eq!(snapshot.bids(None).count(), 3);
+        assert_eq!(snapshot.asks(None).count(), 3);
+        assert_eq!(snapshot.best_bid_price(), book.best_bid_price());
+        assert_eq!(snapshot.best_ask_price(), book.best_ask_price());
+        assert_eq!(snapshot.count, book.count);
+        assert_eq!(book.bids(None).count(), 10);
+    }
+
     #[rstest]
     fn test_apply_depth(stub_depth10: OrderBookDepth10) {
         let depth = stub_depth10;
@@ -975,6 +1576,50 @@ mod tests {
         assert_eq!(pprint_output, expected_output);
     }
 
+    #[rstest]
+    fn test_pprint_with_options() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+
+        let bid = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.000"),
+            Quantity::from("1.0"),
+            1,
+        );
+        let own_bid = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.000"),
+            Quantity::from("2.0"),
+            2,
+        );
+        let ask = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("2.000"),
+            Quantity::from("3.0"),
+            3,
+        );
+        book.add(bid, 0, 1, 100.into());
+        book.add(own_bid, 0, 2, 200.into());
+        book.add(ask, 0, 3, 300.into());
+
+        let options = BookDisplayOptions {
+            own_order_ids: HashSet::from([2]),
+            colored: true,
+        };
+        let pprint_output = book.pprint_with_options(3, &options);
+        let lines: Vec<&str> = pprint_output.lines().collect();
+
+        assert_eq!(lines.len(), 6);
+        assert!(lines[1].contains("bids"));
+        assert!(!lines[1].starts_with('\x1b'));
+        assert!(lines[3].starts_with("\x1b[31m"));
+        assert!(lines[3].contains("[3.0]"));
+        assert!(lines[4].starts_with("\x1b[32m"));
+        assert!(lines[4].contains("[1.0, 2.0*]"));
+        assert!(lines[5].starts_with('╰'));
+    }
+
     #[rstest]
     fn test_group_empty_book() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -1099,4 +1744,110 @@ mod tests {
         assert_eq!(grouped_asks.get(&dec!(102.0)), Some(&dec!(3000))); // 1000 + 2000 grouped
         assert_eq!(grouped_asks.get(&dec!(104.0)), Some(&dec!(3000)));
     }
+
+    fn l3_book_with_queue() -> OrderBook {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+        for (order_id, size) in [(1, "1.0"), (2, "2.0"), (3, "3.0")] {
+            let order = BookOrder::new(
+                OrderSide::Buy,
+                Price::from("100.00"),
+                Quantity::from(size),
+                order_id,
+            );
+            book.add(order, 0, order_id, order_id.into());
+        }
+        let ask = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("101.00"),
+            Quantity::from("5.0"),
+            4,
+        );
+        book.add(ask, 0, 4, 4.into());
+        book
+    }
+
+    #[rstest]
+    fn test_l3_get_order() {
+        let book = l3_book_with_queue();
+
+        assert_eq!(book.get_order(2).unwrap().size, Quantity::from("2.0"));
+        assert_eq!(book.get_order(4).unwrap().side, OrderSide::Sell);
+        assert!(book.get_order(99).is_none());
+    }
+
+    #[rstest]
+    fn test_l3_orders_at_price() {
+        let book = l3_book_with_queue();
+
+        let orders = book.orders_at_price(OrderSide::Buy, Price::from("100.00"));
+        let order_ids: Vec<u64> = orders.iter().map(|o| o.order_id).collect();
+
+        assert_eq!(order_ids, vec![1, 2, 3]);
+        assert!(book
+            .orders_at_price(OrderSide::Sell, Price::from("100.00"))
+            .is_empty());
+    }
+
+    #[rstest]
+    fn test_l3_queue_position_and_size_ahead() {
+        let book = l3_book_with_queue();
+
+        assert_eq!(book.queue_position(1), Some(0));
+        assert_eq!(book.queue_position(3), Some(2));
+        assert_eq!(book.queue_position(4), Some(0));
+        assert_eq!(book.queue_position(99), None);
+        assert_eq!(book.size_ahead(1), Some(Quantity::from("0.0")));
+        assert_eq!(book.size_ahead(3), Some(Quantity::from("3.0")));
+        assert_eq!(book.size_ahead(99), None);
+    }
+
+    #[rstest]
+    fn test_l3_remove_order_by_id() {
+        let mut book = l3_book_with_queue();
+
+        assert!(book.remove_order(2, 5, 5.into()));
+        assert!(!book.remove_order(2, 6, 6.into()));
+        assert!(book.remove_order(4, 7, 7.into()));
+
+        assert!(book.get_order(2).is_none());
+        assert_eq!(book.queue_position(3), Some(1));
+        assert_eq!(book.size_ahead(3), Some(Quantity::from("1.0")));
+        assert!(!book.has_ask());
+        assert_eq!(book.sequence, 7);
+        assert_eq!(book.count, 7);
+    }
+
+    #[rstest]
+    fn test_l3_update_to_zero_size_removes_order_and_level() {
+        let mut book = l3_book_with_queue();
+        let order = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("101.00"),
+            Quantity::from("0.0"),
+            4,
+        );
+
+        book.update(order, 0, 5, 5.into());
+
+        assert!(book.get_order(4).is_none());
+        assert!(!book.has_ask());
+        assert_eq!(book.best_ask_price(), None);
+    }
+
+    #[rstest]
+    fn test_l3_update_size_keeps_queue_position() {
+        let mut book = l3_book_with_queue();
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from("0.5"),
+            1,
+        );
+
+        book.update(order, 0, 5, 5.into());
+
+        assert_eq!(book.queue_position(1), Some(0));
+        assert_eq!(book.size_ahead(2), Some(Quantity::from("0.5")));
+    }
 }
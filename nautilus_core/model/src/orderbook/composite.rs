@@ -0,0 +1,533 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A composite order book which aggregates the books of the same underlying across venues.
+
+use std::{cmp::Reverse, collections::BTreeMap};
+
+use indexmap::IndexMap;
+use nautilus_core::nanos::UnixNanos;
+
+use crate::{
+    enums::{OrderSide, OrderSideSpecified},
+    identifiers::InstrumentId,
+    orderbook::{BookLevel, BookPrice, OrderBook},
+    types::{Price, Quantity},
+};
+
+/// Represents an aggregated price level of a [`CompositeOrderBook`], tracking the size
+/// contributed by each constituent venue.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompositeLevel {
+    pub price: BookPrice,
+    pub venues: IndexMap<InstrumentId, Quantity>,
+}
+
+impl CompositeLevel {
+    /// Creates a new [`CompositeLevel`] instance.
+    #[must_use]
+    pub fn new(price: BookPrice) -> Self {
+        Self {
+            price,
+            venues: IndexMap::new(),
+        }
+    }
+
+    /// Returns the number of venues contributing to this level.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.venues.len()
+    }
+
+    /// Returns true if no venues contribute to this level.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.venues.is_empty()
+    }
+
+    /// Returns the total size across all venues at this price level as a float.
+    #[must_use]
+    pub fn size(&self) -> f64 {
+        self.venues.values().map(Quantity::as_f64).sum()
+    }
+
+    /// Returns the total size across all venues at this price level as raw integer units.
+    #[must_use]
+    pub fn size_raw(&self) -> u64 {
+        self.venues.values().map(|q| q.raw).sum()
+    }
+
+    /// Returns the venue with the largest size at this price level (the earliest
+    /// contributing venue wins ties).
+    #[must_use]
+    pub fn best_venue(&self) -> Option<(InstrumentId, Quantity)> {
+        self.venues.iter().fold(
+            None,
+            |best: Option<(InstrumentId, Quantity)>, (id, size)| match best {
+                Some((_, best_size)) if best_size.raw >= size.raw => best,
+                _ => Some((*id, *size)),
+            },
+        )
+    }
+
+    /// Returns the venues contributing to this level, sorted by size descending.
+    #[must_use]
+    pub fn venues_by_size(&self) -> Vec<(InstrumentId, Quantity)> {
+        let mut venues: Vec<(InstrumentId, Quantity)> =
+            self.venues.iter().map(|(id, size)| (*id, *size)).collect();
+        venues.sort_by_key(|(_, size)| Reverse(size.raw));
+        venues
+    }
+}
+
+/// The price levels contributed by a single constituent book.
+#[derive(Clone, Debug, Default)]
+struct ConstituentLevels {
+    bids: BTreeMap<BookPrice, Quantity>,
+    asks: BTreeMap<BookPrice, Quantity>,
+}
+
+/// Provides a synthetic order book which merges the books of the same underlying from
+/// multiple venues into a single ladder, tracking venue provenance per price level.
+///
+/// Constituent books are registered with [`CompositeOrderBook::add_constituent`] and the
+/// composite is updated incrementally by passing each constituent book to
+/// [`CompositeOrderBook::update`] whenever it changes; only the price levels which changed
+/// since the previous update of that constituent are touched.
+#[derive(Clone, Debug)]
+pub struct CompositeOrderBook {
+    /// The synthetic instrument ID for the composite order book.
+    pub instrument_id: InstrumentId,
+    /// The timestamp of the last constituent update applied to the composite book.
+    pub ts_last: UnixNanos,
+    /// The current count of constituent updates applied to the composite book.
+    pub count: u64,
+    bids: BTreeMap<BookPrice, CompositeLevel>,
+    asks: BTreeMap<BookPrice, CompositeLevel>,
+    constituents: IndexMap<InstrumentId, ConstituentLevels>,
+}
+
+impl CompositeOrderBook {
+    /// Creates a new [`CompositeOrderBook`] instance.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId) -> Self {
+        Self {
+            instrument_id,
+            ts_last: UnixNanos::default(),
+            count: 0,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            constituents: IndexMap::new(),
+        }
+    }
+
+    /// Returns the instrument IDs of the constituent books.
+    #[must_use]
+    pub fn constituents(&self) -> Vec<InstrumentId> {
+        self.constituents.keys().copied().collect()
+    }
+
+    /// Returns true if the given instrument ID is a constituent of the composite book.
+    #[must_use]
+    pub fn has_constituent(&self, instrument_id: &InstrumentId) -> bool {
+        self.constituents.contains_key(instrument_id)
+    }
+
+    /// Registers a constituent book for the given `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument ID is already a constituent.
+    pub fn add_constituent(&mut self, instrument_id: InstrumentId) -> anyhow::Result<()> {
+        if self.constituents.contains_key(&instrument_id) {
+            anyhow::bail!("Constituent {instrument_id} already registered");
+        }
+        self.constituents
+            .insert(instrument_id, ConstituentLevels::default());
+        Ok(())
+    }
+
+    /// Removes the constituent book for the given `instrument_id`, along with all of its
+    /// contributed price levels.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the instrument ID is not a constituent.
+    pub fn remove_constituent(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        let Some(previous) = self.constituents.shift_remove(instrument_id) else {
+            anyhow::bail!("Constituent {instrument_id} not registered");
+        };
+        sync_side(
+            &mut self.bids,
+            *instrument_id,
+            &previous.bids,
+            &BTreeMap::new(),
+        );
+        sync_side(
+            &mut self.asks,
+            *instrument_id,
+            &previous.asks,
+            &BTreeMap::new(),
+        );
+        Ok(())
+    }
+
+    /// Updates the composite book from the current state of a constituent `book`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the book's instrument ID is not a constituent.
+    pub fn update(&mut self, book: &OrderBook) -> anyhow::Result<()> {
+        let instrument_id = book.instrument_id;
+        let Some(previous) = self.constituents.get_mut(&instrument_id) else {
+            anyhow::bail!("Constituent {instrument_id} not registered");
+        };
+
+        let current = ConstituentLevels {
+            bids: snapshot_side(book, OrderSide::Buy),
+            asks: snapshot_side(book, OrderSide::Sell),
+        };
+
+        sync_side(&mut self.bids, instrument_id, &previous.bids, &current.bids);
+        sync_side(&mut self.asks, instrument_id, &previous.asks, &current.asks);
+        *previous = current;
+
+        self.ts_last = self.ts_last.max(book.ts_last);
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Returns an iterator over the composite bid price levels.
+    pub fn bids(&self, depth: Option<usize>) -> impl Iterator<Item = &CompositeLevel> {
+        self.bids.values().take(depth.unwrap_or(usize::MAX))
+    }
+
+    /// Returns an iterator over the composite ask price levels.
+    pub fn asks(&self, depth: Option<usize>) -> impl Iterator<Item = &CompositeLevel> {
+        self.asks.values().take(depth.unwrap_or(usize::MAX))
+    }
+
+    /// Returns true if the composite book has any bid orders.
+    #[must_use]
+    pub fn has_bid(&self) -> bool {
+        !self.bids.is_empty()
+    }
+
+    /// Returns true if the composite book has any ask orders.
+    #[must_use]
+    pub fn has_ask(&self) -> bool {
+        !self.asks.is_empty()
+    }
+
+    /// Returns the best bid price across all constituents if available.
+    #[must_use]
+    pub fn best_bid_price(&self) -> Option<Price> {
+        self.bids.keys().next().map(|p| p.value)
+    }
+
+    /// Returns the best ask price across all constituents if available.
+    #[must_use]
+    pub fn best_ask_price(&self) -> Option<Price> {
+        self.asks.keys().next().map(|p| p.value)
+    }
+
+    /// Returns the total size at the best bid price across all constituents if available.
+    #[must_use]
+    pub fn best_bid_size(&self) -> Option<f64> {
+        self.bids.values().next().map(CompositeLevel::size)
+    }
+
+    /// Returns the total size at the best ask price across all constituents if available.
+    #[must_use]
+    pub fn best_ask_size(&self) -> Option<f64> {
+        self.asks.values().next().map(CompositeLevel::size)
+    }
+
+    /// Returns the spread between best ask and bid prices if both exist.
+    ///
+    /// The spread may be negative when venues are crossed against each other.
+    #[must_use]
+    pub fn spread(&self) -> Option<f64> {
+        match (self.best_ask_price(), self.best_bid_price()) {
+            (Some(ask), Some(bid)) => Some(ask.as_f64() - bid.as_f64()),
+            _ => None,
+        }
+    }
+
+    /// Returns the midpoint between best ask and bid prices if both exist.
+    #[must_use]
+    pub fn midpoint(&self) -> Option<f64> {
+        match (self.best_ask_price(), self.best_bid_price()) {
+            (Some(ask), Some(bid)) => Some((ask.as_f64() + bid.as_f64()) / 2.0),
+            _ => None,
+        }
+    }
+
+    /// Returns the composite level at the given book `side` and `price` if it exists.
+    #[must_use]
+    pub fn level_at_price(&self, side: OrderSide, price: Price) -> Option<&CompositeLevel> {
+        let book_price = BookPrice::new(price, side);
+        match side.as_specified() {
+            OrderSideSpecified::Buy => self.bids.get(&book_price),
+            OrderSideSpecified::Sell => self.asks.get(&book_price),
+        }
+    }
+
+    /// Returns the venues quoting at the given book `side` and `price`, sorted by size descending.
+    #[must_use]
+    pub fn venues_at_price(&self, side: OrderSide, price: Price) -> Vec<(InstrumentId, Quantity)> {
+        self.level_at_price(side, price)
+            .map(CompositeLevel::venues_by_size)
+            .unwrap_or_default()
+    }
+
+    /// Returns the venue with the largest size at the given book `side` and `price`.
+    #[must_use]
+    pub fn best_venue_at_price(
+        &self,
+        side: OrderSide,
+        price: Price,
+    ) -> Option<(InstrumentId, Quantity)> {
+        self.level_at_price(side, price)
+            .and_then(CompositeLevel::best_venue)
+    }
+
+    /// Returns the venue with the largest size at the best bid price.
+    #[must_use]
+    pub fn best_bid_venue(&self) -> Option<(InstrumentId, Quantity)> {
+        self.bids
+            .values()
+            .next()
+            .and_then(CompositeLevel::best_venue)
+    }
+
+    /// Returns the venue with the largest size at the best ask price.
+    #[must_use]
+    pub fn best_ask_venue(&self) -> Option<(InstrumentId, Quantity)> {
+        self.asks
+            .values()
+            .next()
+            .and_then(CompositeLevel::best_venue)
+    }
+}
+
+fn snapshot_side(book: &OrderBook, side: OrderSide) -> BTreeMap<BookPrice, Quantity> {
+    match side.as_specified() {
+        OrderSideSpecified::Buy => snapshot_levels(book.bids(None)),
+        OrderSideSpecified::Sell => snapshot_levels(book.asks(None)),
+    }
+}
+
+fn snapshot_levels<'a>(
+    levels: impl Iterator<Item = &'a BookLevel>,
+) -> BTreeMap<BookPrice, Quantity> {
+    levels
+        .filter_map(|level| {
+            let precision = level.first()?.size.precision;
+            let size_raw = level.size_raw();
+            if size_raw == 0 {
+                return None;
+            }
+            Some((level.price, Quantity::from_raw(size_raw, precision)))
+        })
+        .collect()
+}
+
+fn sync_side(
+    levels: &mut BTreeMap<BookPrice, CompositeLevel>,
+    instrument_id: InstrumentId,
+    previous: &BTreeMap<BookPrice, Quantity>,
+    current: &BTreeMap<BookPrice, Quantity>,
+) {
+    for book_price in previous.keys() {
+        if current.contains_key(book_price) {
+            continue;
+        }
+        if let Some(level) = levels.get_mut(book_price) {
+            level.venues.shift_remove(&instrument_id);
+            if level.is_empty() {
+                levels.remove(book_price);
+            }
+        }
+    }
+
+    for (book_price, size) in current {
+        if previous.get(book_price) == Some(size) {
+            continue;
+        }
+        levels
+            .entry(*book_price)
+            .or_insert_with(|| CompositeLevel::new(*book_price))
+            .venues
+            .insert(instrument_id, *size);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{data::order::BookOrder, enums::BookType};
+
+    fn add_order(book: &mut OrderBook, side: OrderSide, price: &str, size: &str) {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+        let sequence = book.sequence + 1;
+        book.add(order, 0, sequence, sequence.into());
+    }
+
+    fn books() -> (OrderBook, OrderBook) {
+        let mut book1 = OrderBook::new(InstrumentId::from("BTCUSDT.BINANCE"), BookType::L2_MBP);
+        add_order(&mut book1, OrderSide::Buy, "100.00", "1.0");
+        add_order(&mut book1, OrderSide::Buy, "99.00", "2.0");
+        add_order(&mut book1, OrderSide::Sell, "101.00", "1.0");
+
+        let mut book2 = OrderBook::new(InstrumentId::from("BTCUSDT.BYBIT"), BookType::L2_MBP);
+        add_order(&mut book2, OrderSide::Buy, "100.00", "3.0");
+        add_order(&mut book2, OrderSide::Sell, "100.50", "2.0");
+        add_order(&mut book2, OrderSide::Sell, "101.00", "4.0");
+
+        (book1, book2)
+    }
+
+    fn composite(book1: &OrderBook, book2: &OrderBook) -> CompositeOrderBook {
+        let mut composite = CompositeOrderBook::new(InstrumentId::from("BTCUSDT.COMPOSITE"));
+        composite.add_constituent(book1.instrument_id).unwrap();
+        composite.add_constituent(book2.instrument_id).unwrap();
+        composite.update(book1).unwrap();
+        composite.update(book2).unwrap();
+        composite
+    }
+
+    #[rstest]
+    fn test_empty_composite() {
+        let composite = CompositeOrderBook::new(InstrumentId::from("BTCUSDT.COMPOSITE"));
+
+        assert!(!composite.has_bid());
+        assert!(!composite.has_ask());
+        assert_eq!(composite.best_bid_price(), None);
+        assert_eq!(composite.midpoint(), None);
+        assert_eq!(composite.best_bid_venue(), None);
+        assert!(composite.constituents().is_empty());
+    }
+
+    #[rstest]
+    fn test_add_constituent_twice_errors() {
+        let mut composite = CompositeOrderBook::new(InstrumentId::from("BTCUSDT.COMPOSITE"));
+        let instrument_id = InstrumentId::from("BTCUSDT.BINANCE");
+        composite.add_constituent(instrument_id).unwrap();
+
+        assert!(composite.add_constituent(instrument_id).is_err());
+    }
+
+    #[rstest]
+    fn test_update_unregistered_constituent_errors() {
+        let (book1, _) = books();
+        let mut composite = CompositeOrderBook::new(InstrumentId::from("BTCUSDT.COMPOSITE"));
+
+        assert!(composite.update(&book1).is_err());
+    }
+
+    #[rstest]
+    fn test_merged_ladder() {
+        let (book1, book2) = books();
+        let composite = composite(&book1, &book2);
+
+        assert_eq!(composite.bids(None).count(), 2);
+        assert_eq!(composite.asks(None).count(), 2);
+        assert_eq!(composite.best_bid_price(), Some(Price::from("100.00")));
+        assert_eq!(composite.best_ask_price(), Some(Price::from("100.50")));
+        assert_eq!(composite.best_bid_size(), Some(4.0));
+        assert_eq!(composite.best_ask_size(), Some(2.0));
+        assert_eq!(composite.spread(), Some(0.5));
+        assert_eq!(composite.midpoint(), Some(100.25));
+        assert_eq!(composite.count, 2);
+    }
+
+    #[rstest]
+    fn test_best_venue_queries() {
+        let (book1, book2) = books();
+        let composite = composite(&book1, &book2);
+
+        assert_eq!(
+            composite.best_bid_venue(),
+            Some((book2.instrument_id, Quantity::from("3.0")))
+        );
+        assert_eq!(
+            composite.best_ask_venue(),
+            Some((book2.instrument_id, Quantity::from("2.0")))
+        );
+        assert_eq!(
+            composite.best_venue_at_price(OrderSide::Sell, Price::from("101.00")),
+            Some((book2.instrument_id, Quantity::from("4.0")))
+        );
+        assert_eq!(
+            composite.venues_at_price(OrderSide::Sell, Price::from("101.00")),
+            vec![
+                (book2.instrument_id, Quantity::from("4.0")),
+                (book1.instrument_id, Quantity::from("1.0")),
+            ]
+        );
+        assert_eq!(
+            composite.best_venue_at_price(OrderSide::Buy, Price::from("98.00")),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_incremental_update() {
+        let (mut book1, book2) = books();
+        let mut composite = composite(&book1, &book2);
+
+        // Remove the 99.00 bid and grow the 100.00 bid on the first venue
+        let bid_99 = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("99.00"),
+            Quantity::from("2.0"),
+            0,
+        );
+        book1.delete(bid_99, 0, 10, 10.into());
+        add_order(&mut book1, OrderSide::Buy, "100.00", "5.0");
+        composite.update(&book1).unwrap();
+
+        assert_eq!(composite.bids(None).count(), 1);
+        assert_eq!(composite.best_bid_size(), Some(8.0));
+        assert_eq!(
+            composite.best_bid_venue(),
+            Some((book1.instrument_id, Quantity::from("5.0")))
+        );
+        assert_eq!(composite.ts_last, book1.ts_last);
+    }
+
+    #[rstest]
+    fn test_remove_constituent() {
+        let (book1, book2) = books();
+        let mut composite = composite(&book1, &book2);
+
+        composite.remove_constituent(&book2.instrument_id).unwrap();
+
+        assert_eq!(composite.constituents(), vec![book1.instrument_id]);
+        assert_eq!(composite.best_bid_size(), Some(1.0));
+        assert_eq!(composite.best_ask_price(), Some(Price::from("101.00")));
+        assert_eq!(
+            composite.venues_at_price(OrderSide::Sell, Price::from("101.00")),
+            vec![(book1.instrument_id, Quantity::from("1.0"))]
+        );
+        assert!(composite.remove_constituent(&book2.instrument_id).is_err());
+    }
+}
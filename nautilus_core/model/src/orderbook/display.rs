@@ -15,10 +15,28 @@
 
 //! Functions related to order book display.
 
+use std::collections::HashSet;
+
 use tabled::{settings::Style, Table, Tabled};
 
 use super::{BookLevel, BookPrice};
-use crate::orderbook::ladder::BookLadder;
+use crate::{data::order::OrderId, orderbook::ladder::BookLadder};
+
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// The marker appended to the size of own orders when rendering an order book.
+pub const OWN_ORDER_MARKER: char = '*';
+
+/// Options for rendering an order book as a text table.
+#[derive(Clone, Debug, Default)]
+pub struct BookDisplayOptions {
+    /// The order IDs of own orders, whose sizes are marked with [`OWN_ORDER_MARKER`].
+    pub own_order_ids: HashSet<OrderId>,
+    /// If ANSI colour codes are applied to the rows (bids green, asks red).
+    pub colored: bool,
+}
 
 #[derive(Tabled)]
 struct BookLevelDisplay {
@@ -30,46 +48,77 @@ struct BookLevelDisplay {
 /// Return a [`String`] representation of the order book in a human-readable table format.
 #[must_use]
 pub(crate) fn pprint_book(bids: &BookLadder, asks: &BookLadder, num_levels: usize) -> String {
+    pprint_book_with_options(bids, asks, num_levels, &BookDisplayOptions::default())
+}
+
+/// Return a [`String`] representation of the order book in a human-readable table format,
+/// rendered with the given `options`.
+#[must_use]
+pub(crate) fn pprint_book_with_options(
+    bids: &BookLadder,
+    asks: &BookLadder,
+    num_levels: usize,
+    options: &BookDisplayOptions,
+) -> String {
     let ask_levels: Vec<(&BookPrice, &BookLevel)> =
         asks.levels.iter().take(num_levels).rev().collect();
     let bid_levels: Vec<(&BookPrice, &BookLevel)> = bids.levels.iter().take(num_levels).collect();
+    let num_ask_rows = ask_levels.len();
     let levels: Vec<(&BookPrice, &BookLevel)> = ask_levels.into_iter().chain(bid_levels).collect();
 
+    let format_sizes = |level: &BookLevel| -> String {
+        let sizes: Vec<String> = level
+            .orders
+            .values()
+            .map(|order| {
+                if options.own_order_ids.contains(&order.order_id) {
+                    format!("{}{OWN_ORDER_MARKER}", order.size)
+                } else {
+                    format!("{}", order.size)
+                }
+            })
+            .collect();
+        format!("[{}]", sizes.join(", "))
+    };
+
     let data: Vec<BookLevelDisplay> = levels
         .iter()
         .map(|(book_price, level)| {
             let is_bid_level = bids.levels.contains_key(book_price);
             let is_ask_level = asks.levels.contains_key(book_price);
 
-            let bid_sizes: Vec<String> = level
-                .orders
-                .iter()
-                .filter(|_| is_bid_level)
-                .map(|order| format!("{}", order.1.size))
-                .collect();
-
-            let ask_sizes: Vec<String> = level
-                .orders
-                .iter()
-                .filter(|_| is_ask_level)
-                .map(|order| format!("{}", order.1.size))
-                .collect();
-
             BookLevelDisplay {
-                bids: if bid_sizes.is_empty() {
-                    String::new()
+                bids: if is_bid_level && !level.is_empty() {
+                    format_sizes(level)
                 } else {
-                    format!("[{}]", bid_sizes.join(", "))
+                    String::new()
                 },
                 price: format!("{}", level.price),
-                asks: if ask_sizes.is_empty() {
-                    String::new()
+                asks: if is_ask_level && !level.is_empty() {
+                    format_sizes(level)
                 } else {
-                    format!("[{}]", ask_sizes.join(", "))
+                    String::new()
                 },
             }
         })
         .collect();
 
-    Table::new(data).with(Style::rounded()).to_string()
+    let table = Table::new(data).with(Style::rounded()).to_string();
+    if !options.colored {
+        return table;
+    }
+
+    // Colour whole rows after rendering so ANSI codes do not affect column widths,
+    // the first three lines are the top border, header and header separator.
+    const HEADER_LINES: usize = 3;
+    table
+        .lines()
+        .enumerate()
+        .map(|(i, line)| match i.checked_sub(HEADER_LINES) {
+            Some(row) if row < num_ask_rows => format!("{ANSI_RED}{line}{ANSI_RESET}"),
+            Some(row) if row < levels.len() => format!("{ANSI_GREEN}{line}{ANSI_RESET}"),
+            _ => line.to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
 }
@@ -42,4 +42,23 @@ pub enum BookIntegrityError {
     TooManyOrders(OrderSide, usize),
     #[error("Integrity error: number of {0} levels > 1 for L1_MBP book, was {1}")]
     TooManyLevels(OrderSide, usize),
+    #[error("Integrity error: non-positive {0} order size at {1}, order_id={2}")]
+    NonPositiveSize(OrderSide, BookPrice, u64),
+    #[error("Integrity error: sequence gap, expected {0} received {1}")]
+    SequenceGap(u64, u64),
+}
+
+impl BookIntegrityError {
+    /// Returns whether the violation leaves the book out of sync with the venue,
+    /// such that it should be rebuilt from a fresh snapshot (e.g. by resubscribing).
+    #[must_use]
+    pub fn requires_snapshot(&self) -> bool {
+        matches!(
+            self,
+            Self::OrderNotFound(..)
+                | Self::OrdersCrossed(..)
+                | Self::NonPositiveSize(..)
+                | Self::SequenceGap(..)
+        )
+    }
 }
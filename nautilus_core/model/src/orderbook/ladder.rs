@@ -145,8 +145,14 @@ impl BookLadder {
         if let Some(price) = price {
             if let Some(level) = self.levels.get_mut(&price) {
                 if order.price == level.price.value {
-                    // Update at current price level
+                    // Update at current price level (a zero size removes the order)
                     level.update(order);
+                    if order.size.raw == 0 {
+                        self.cache.remove(&order.order_id);
+                        if level.is_empty() {
+                            self.levels.remove(&price);
+                        }
+                    }
                     return;
                 }
 
@@ -159,7 +165,9 @@ impl BookLadder {
             }
         }
 
-        self.add(order);
+        if order.size.raw > 0 {
+            self.add(order);
+        }
     }
 
     /// Deletes an order from the ladder.
@@ -179,6 +187,90 @@ impl BookLadder {
         }
     }
 
+    /// Removes all price levels at or through the given `price` (from the opposite side),
+    /// returning the number of levels removed.
+    pub fn remove_levels_crossing(&mut self, price: Price) -> usize {
+        let side = self.side;
+        let crossing: Vec<BookPrice> = self
+            .levels
+            .keys()
+            .take_while(|book_price| match side {
+                OrderSide::Buy => book_price.value >= price,
+                _ => book_price.value <= price,
+            })
+            .copied()
+            .collect();
+
+        for book_price in &crossing {
+            if let Some(level) = self.levels.remove(book_price) {
+                for order_id in level.orders.keys() {
+                    self.cache.remove(order_id);
+                }
+            }
+        }
+
+        crossing.len()
+    }
+
+    /// Removes all price levels beyond the top `depth` levels, returning the number of levels removed.
+    pub fn truncate(&mut self, depth: usize) -> usize {
+        let excess: Vec<BookPrice> = self.levels.keys().skip(depth).copied().collect();
+
+        for book_price in &excess {
+            if let Some(level) = self.levels.remove(book_price) {
+                for order_id in level.orders.keys() {
+                    self.cache.remove(order_id);
+                }
+            }
+        }
+
+        excess.len()
+    }
+
+    /// Removes all orders with a zero size from the ladder, returning the number of orders removed.
+    pub fn remove_empty_orders(&mut self) -> usize {
+        let empty: Vec<OrderId> = self
+            .levels
+            .values()
+            .flat_map(|level| level.orders.values())
+            .filter(|order| order.size.raw == 0)
+            .map(|order| order.order_id)
+            .collect();
+
+        for order_id in &empty {
+            self.remove(*order_id, 0, UnixNanos::default());
+        }
+
+        empty.len()
+    }
+
+    /// Returns true if the ladder contains an order with the given `order_id`.
+    #[must_use]
+    pub fn contains(&self, order_id: OrderId) -> bool {
+        self.cache.contains_key(&order_id)
+    }
+
+    /// Returns the order with the given `order_id` in the ladder (if found).
+    #[must_use]
+    pub fn get_order(&self, order_id: OrderId) -> Option<&BookOrder> {
+        self.level_for_order(order_id)
+            .and_then(|level| level.get_order(order_id))
+    }
+
+    /// Returns the price level at the given `price` (if found).
+    #[must_use]
+    pub fn get_level(&self, price: Price) -> Option<&BookLevel> {
+        self.levels.get(&BookPrice::new(price, self.side))
+    }
+
+    /// Returns the price level holding the order with the given `order_id` (if found).
+    #[must_use]
+    pub fn level_for_order(&self, order_id: OrderId) -> Option<&BookLevel> {
+        self.cache
+            .get(&order_id)
+            .and_then(|price| self.levels.get(price))
+    }
+
     /// Returns the total size of all orders in the ladder.
     #[must_use]
     pub fn sizes(&self) -> f64 {
@@ -23,7 +23,7 @@ use rust_decimal::Decimal;
 use crate::{
     data::order::{BookOrder, OrderId},
     orderbook::{BookIntegrityError, BookPrice},
-    types::fixed::FIXED_SCALAR,
+    types::{fixed::FIXED_SCALAR, Quantity},
 };
 
 /// Represents a discrete price level in an order book.
@@ -94,6 +94,41 @@ impl BookLevel {
             .collect()
     }
 
+    /// Returns a reference to the order with the given `order_id` at this price level (if found).
+    #[must_use]
+    pub fn get_order(&self, order_id: OrderId) -> Option<&BookOrder> {
+        self.orders.get(&order_id)
+    }
+
+    /// Returns the zero-based FIFO queue position of the order with the given `order_id` (if found).
+    #[must_use]
+    pub fn queue_position(&self, order_id: OrderId) -> Option<usize> {
+        if !self.orders.contains_key(&order_id) {
+            return None;
+        }
+
+        self.insertion_order
+            .iter()
+            .filter(|id| self.orders.contains_key(id))
+            .position(|&id| id == order_id)
+    }
+
+    /// Returns the total size of all orders queued ahead of the order with the given `order_id` (if found).
+    #[must_use]
+    pub fn size_ahead(&self, order_id: OrderId) -> Option<Quantity> {
+        let position = self.queue_position(order_id)?;
+        let precision = self.orders[&order_id].size.precision;
+        let raw = self
+            .insertion_order
+            .iter()
+            .filter_map(|id| self.orders.get(id))
+            .take(position)
+            .map(|o| o.size.raw)
+            .sum();
+
+        Some(Quantity::from_raw(raw, precision))
+    }
+
     /// Returns the total size of all orders at this price level as a float.
     #[must_use]
     pub fn size(&self) -> f64 {
@@ -18,6 +18,7 @@
 pub mod aggregation;
 pub mod analysis;
 pub mod book;
+pub mod composite;
 pub mod display;
 pub mod error;
 pub mod ladder;
@@ -25,7 +26,9 @@ pub mod level;
 
 // Re-exports
 pub use crate::orderbook::{
-    book::OrderBook,
+    analysis::SweepCost,
+    book::{BookIntegrityResolution, OrderBook},
+    composite::{CompositeLevel, CompositeOrderBook},
     error::{BookIntegrityError, InvalidBookOperation},
     ladder::BookPrice,
     level::BookLevel,
@@ -13,10 +13,11 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
 
-use nautilus_core::nanos::UnixNanos;
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 use serde::{Deserialize, Serialize};
+use ustr::Ustr;
 
 use super::{
     base::{Order, OrderError},
@@ -33,12 +34,12 @@ use super::{
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderSideSpecified, OrderStatus, OrderType,
-        PositionSide, TriggerType,
+        PositionSide, TimeInForce, TriggerType,
     },
     events::OrderEventAny,
     identifiers::{
-        AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, PositionId, StrategyId, TraderId,
-        VenueOrderId,
+        AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, PositionId,
+        StrategyId, TradeId, TraderId, VenueOrderId,
     },
     types::{Price, Quantity},
 };
@@ -207,6 +208,21 @@ impl OrderAny {
         }
     }
 
+    /// Sets the instrument ID of the order, including on its initialization event.
+    pub fn set_instrument_id(&mut self, instrument_id: InstrumentId) {
+        match self {
+            Self::Limit(order) => order.set_instrument_id(instrument_id),
+            Self::LimitIfTouched(order) => order.set_instrument_id(instrument_id),
+            Self::Market(order) => order.set_instrument_id(instrument_id),
+            Self::MarketIfTouched(order) => order.set_instrument_id(instrument_id),
+            Self::MarketToLimit(order) => order.set_instrument_id(instrument_id),
+            Self::StopLimit(order) => order.set_instrument_id(instrument_id),
+            Self::StopMarket(order) => order.set_instrument_id(instrument_id),
+            Self::TrailingStopLimit(order) => order.set_instrument_id(instrument_id),
+            Self::TrailingStopMarket(order) => order.set_instrument_id(instrument_id),
+        }
+    }
+
     #[must_use]
     pub fn client_order_id(&self) -> ClientOrderId {
         match self {
@@ -282,6 +298,21 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn exec_algorithm_params(&self) -> Option<HashMap<Ustr, Ustr>> {
+        match self {
+            Self::Limit(order) => order.exec_algorithm_params.clone(),
+            Self::LimitIfTouched(order) => order.exec_algorithm_params.clone(),
+            Self::Market(order) => order.exec_algorithm_params.clone(),
+            Self::MarketIfTouched(order) => order.exec_algorithm_params.clone(),
+            Self::MarketToLimit(order) => order.exec_algorithm_params.clone(),
+            Self::StopLimit(order) => order.exec_algorithm_params.clone(),
+            Self::StopMarket(order) => order.exec_algorithm_params.clone(),
+            Self::TrailingStopLimit(order) => order.exec_algorithm_params.clone(),
+            Self::TrailingStopMarket(order) => order.exec_algorithm_params.clone(),
+        }
+    }
+
     #[must_use]
     pub fn exec_spawn_id(&self) -> Option<ClientOrderId> {
         match self {
@@ -387,6 +418,36 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn avg_px(&self) -> Option<f64> {
+        match self {
+            Self::Limit(order) => order.avg_px(),
+            Self::LimitIfTouched(order) => order.avg_px(),
+            Self::Market(order) => order.avg_px(),
+            Self::MarketIfTouched(order) => order.avg_px(),
+            Self::MarketToLimit(order) => order.avg_px(),
+            Self::StopLimit(order) => order.avg_px(),
+            Self::StopMarket(order) => order.avg_px(),
+            Self::TrailingStopLimit(order) => order.avg_px(),
+            Self::TrailingStopMarket(order) => order.avg_px(),
+        }
+    }
+
+    #[must_use]
+    pub fn trade_ids(&self) -> Vec<&TradeId> {
+        match self {
+            Self::Limit(order) => order.trade_ids(),
+            Self::LimitIfTouched(order) => order.trade_ids(),
+            Self::Market(order) => order.trade_ids(),
+            Self::MarketIfTouched(order) => order.trade_ids(),
+            Self::MarketToLimit(order) => order.trade_ids(),
+            Self::StopLimit(order) => order.trade_ids(),
+            Self::StopMarket(order) => order.trade_ids(),
+            Self::TrailingStopLimit(order) => order.trade_ids(),
+            Self::TrailingStopMarket(order) => order.trade_ids(),
+        }
+    }
+
     #[must_use]
     pub fn order_side_specified(&self) -> OrderSideSpecified {
         match self {
@@ -522,6 +583,21 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn is_quote_quantity(&self) -> bool {
+        match self {
+            Self::Limit(order) => order.is_quote_quantity,
+            Self::LimitIfTouched(order) => order.is_quote_quantity,
+            Self::Market(order) => order.is_quote_quantity,
+            Self::MarketIfTouched(order) => order.is_quote_quantity,
+            Self::MarketToLimit(order) => order.is_quote_quantity,
+            Self::StopLimit(order) => order.is_quote_quantity,
+            Self::StopMarket(order) => order.is_quote_quantity,
+            Self::TrailingStopLimit(order) => order.is_quote_quantity,
+            Self::TrailingStopMarket(order) => order.is_quote_quantity,
+        }
+    }
+
     #[must_use]
     pub fn is_reduce_only(&self) -> bool {
         match self {
@@ -537,6 +613,67 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn time_in_force(&self) -> TimeInForce {
+        match self {
+            Self::Limit(order) => order.time_in_force(),
+            Self::LimitIfTouched(order) => order.time_in_force(),
+            Self::Market(order) => order.time_in_force(),
+            Self::MarketIfTouched(order) => order.time_in_force(),
+            Self::MarketToLimit(order) => order.time_in_force(),
+            Self::StopLimit(order) => order.time_in_force(),
+            Self::StopMarket(order) => order.time_in_force(),
+            Self::TrailingStopLimit(order) => order.time_in_force(),
+            Self::TrailingStopMarket(order) => order.time_in_force(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_post_only(&self) -> bool {
+        match self {
+            Self::Limit(order) => order.is_post_only(),
+            Self::LimitIfTouched(order) => order.is_post_only(),
+            Self::Market(order) => order.is_post_only(),
+            Self::MarketIfTouched(order) => order.is_post_only(),
+            Self::MarketToLimit(order) => order.is_post_only(),
+            Self::StopLimit(order) => order.is_post_only(),
+            Self::StopMarket(order) => order.is_post_only(),
+            Self::TrailingStopLimit(order) => order.is_post_only(),
+            Self::TrailingStopMarket(order) => order.is_post_only(),
+        }
+    }
+
+    #[must_use]
+    pub fn expire_time(&self) -> Option<UnixNanos> {
+        match self {
+            Self::Limit(order) => order.expire_time(),
+            Self::LimitIfTouched(order) => order.expire_time(),
+            Self::Market(order) => order.expire_time(),
+            Self::MarketIfTouched(order) => order.expire_time(),
+            Self::MarketToLimit(order) => order.expire_time(),
+            Self::StopLimit(order) => order.expire_time(),
+            Self::StopMarket(order) => order.expire_time(),
+            Self::TrailingStopLimit(order) => order.expire_time(),
+            Self::TrailingStopMarket(order) => order.expire_time(),
+        }
+    }
+
+    /// Returns whether the order has been triggered, or `None` for orders without a trigger.
+    #[must_use]
+    pub fn is_triggered(&self) -> Option<bool> {
+        match self {
+            Self::Limit(_) => None,
+            Self::LimitIfTouched(order) => Some(order.is_triggered),
+            Self::Market(_) => None,
+            Self::MarketIfTouched(order) => Some(order.is_triggered),
+            Self::MarketToLimit(_) => None,
+            Self::StopLimit(order) => Some(order.is_triggered),
+            Self::StopMarket(order) => Some(order.is_triggered),
+            Self::TrailingStopLimit(order) => Some(order.is_triggered),
+            Self::TrailingStopMarket(order) => Some(order.is_triggered),
+        }
+    }
+
     #[must_use]
     pub fn is_buy(&self) -> bool {
         match self {
@@ -611,6 +748,96 @@ impl OrderAny {
             Self::TrailingStopMarket(order) => order.linked_order_ids.clone(),
         }
     }
+
+    #[must_use]
+    pub fn trigger_instrument_id(&self) -> Option<InstrumentId> {
+        match self {
+            Self::Limit(order) => order.trigger_instrument_id(),
+            Self::LimitIfTouched(order) => order.trigger_instrument_id(),
+            Self::Market(order) => order.trigger_instrument_id(),
+            Self::MarketIfTouched(order) => order.trigger_instrument_id(),
+            Self::MarketToLimit(order) => order.trigger_instrument_id(),
+            Self::StopLimit(order) => order.trigger_instrument_id(),
+            Self::StopMarket(order) => order.trigger_instrument_id(),
+            Self::TrailingStopLimit(order) => order.trigger_instrument_id(),
+            Self::TrailingStopMarket(order) => order.trigger_instrument_id(),
+        }
+    }
+
+    #[must_use]
+    pub fn order_list_id(&self) -> Option<OrderListId> {
+        match self {
+            Self::Limit(order) => order.order_list_id(),
+            Self::LimitIfTouched(order) => order.order_list_id(),
+            Self::Market(order) => order.order_list_id(),
+            Self::MarketIfTouched(order) => order.order_list_id(),
+            Self::MarketToLimit(order) => order.order_list_id(),
+            Self::StopLimit(order) => order.order_list_id(),
+            Self::StopMarket(order) => order.order_list_id(),
+            Self::TrailingStopLimit(order) => order.order_list_id(),
+            Self::TrailingStopMarket(order) => order.order_list_id(),
+        }
+    }
+
+    #[must_use]
+    pub fn tags(&self) -> Option<Vec<Ustr>> {
+        match self {
+            Self::Limit(order) => order.tags().map(<[Ustr]>::to_vec),
+            Self::LimitIfTouched(order) => order.tags().map(<[Ustr]>::to_vec),
+            Self::Market(order) => order.tags().map(<[Ustr]>::to_vec),
+            Self::MarketIfTouched(order) => order.tags().map(<[Ustr]>::to_vec),
+            Self::MarketToLimit(order) => order.tags().map(<[Ustr]>::to_vec),
+            Self::StopLimit(order) => order.tags().map(<[Ustr]>::to_vec),
+            Self::StopMarket(order) => order.tags().map(<[Ustr]>::to_vec),
+            Self::TrailingStopLimit(order) => order.tags().map(<[Ustr]>::to_vec),
+            Self::TrailingStopMarket(order) => order.tags().map(<[Ustr]>::to_vec),
+        }
+    }
+
+    #[must_use]
+    pub fn init_id(&self) -> UUID4 {
+        match self {
+            Self::Limit(order) => order.init_id(),
+            Self::LimitIfTouched(order) => order.init_id(),
+            Self::Market(order) => order.init_id(),
+            Self::MarketIfTouched(order) => order.init_id(),
+            Self::MarketToLimit(order) => order.init_id(),
+            Self::StopLimit(order) => order.init_id(),
+            Self::StopMarket(order) => order.init_id(),
+            Self::TrailingStopLimit(order) => order.init_id(),
+            Self::TrailingStopMarket(order) => order.init_id(),
+        }
+    }
+
+    #[must_use]
+    pub fn events(&self) -> Vec<&OrderEventAny> {
+        match self {
+            Self::Limit(order) => order.events(),
+            Self::LimitIfTouched(order) => order.events(),
+            Self::Market(order) => order.events(),
+            Self::MarketIfTouched(order) => order.events(),
+            Self::MarketToLimit(order) => order.events(),
+            Self::StopLimit(order) => order.events(),
+            Self::StopMarket(order) => order.events(),
+            Self::TrailingStopLimit(order) => order.events(),
+            Self::TrailingStopMarket(order) => order.events(),
+        }
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::Limit(order) => order.ts_init(),
+            Self::LimitIfTouched(order) => order.ts_init(),
+            Self::Market(order) => order.ts_init(),
+            Self::MarketIfTouched(order) => order.ts_init(),
+            Self::MarketToLimit(order) => order.ts_init(),
+            Self::StopLimit(order) => order.ts_init(),
+            Self::StopMarket(order) => order.ts_init(),
+            Self::TrailingStopLimit(order) => order.ts_init(),
+            Self::TrailingStopMarket(order) => order.ts_init(),
+        }
+    }
 }
 
 impl PartialEq for OrderAny {
@@ -646,6 +873,7 @@ impl From<OrderAny> for PassiveOrderAny {
     fn from(order: OrderAny) -> PassiveOrderAny {
         match order {
             OrderAny::Limit(_) => PassiveOrderAny::Limit(order.into()),
+            OrderAny::MarketToLimit(_) => PassiveOrderAny::Limit(order.into()),
             OrderAny::LimitIfTouched(_) => PassiveOrderAny::Stop(order.into()),
             OrderAny::MarketIfTouched(_) => PassiveOrderAny::Stop(order.into()),
             OrderAny::StopLimit(_) => PassiveOrderAny::Stop(order.into()),
@@ -102,9 +102,11 @@ impl OrderStatus {
             (Self::Initialized, OrderEventAny::Canceled(_)) => Self::Canceled,  // External orders
             (Self::Initialized, OrderEventAny::Expired(_)) => Self::Expired,  // External orders
             (Self::Initialized, OrderEventAny::Triggered(_)) => Self::Triggered, // External orders
+            (Self::Initialized, OrderEventAny::Updated(_)) => Self::Initialized,  // Execution algo
             (Self::Emulated, OrderEventAny::Canceled(_)) => Self::Canceled,  // Emulated orders
             (Self::Emulated, OrderEventAny::Expired(_)) => Self::Expired,  // Emulated orders
             (Self::Emulated, OrderEventAny::Released(_)) => Self::Released,  // Emulated orders
+            (Self::Emulated, OrderEventAny::Updated(_)) => Self::Emulated,  // Emulated orders
             (Self::Released, OrderEventAny::Submitted(_)) => Self::Submitted,  // Emulated orders
             (Self::Released, OrderEventAny::Denied(_)) => Self::Denied,  // Emulated orders
             (Self::Released, OrderEventAny::Canceled(_)) => Self::Canceled,  // Execution algo
@@ -675,6 +677,17 @@ impl OrderCore {
     pub fn init_event(&self) -> Option<OrderEventAny> {
         self.events.first().cloned()
     }
+
+    /// Sets the instrument ID of the order, including on its initialization event.
+    ///
+    /// This is only valid before the order is submitted, such as when routing the order to
+    /// another venue listing the same symbol.
+    pub fn set_instrument_id(&mut self, instrument_id: InstrumentId) {
+        self.instrument_id = instrument_id;
+        if let Some(OrderEventAny::Initialized(init)) = self.events.first_mut() {
+            init.instrument_id = instrument_id;
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -351,9 +351,18 @@ impl Order for LimitIfTouchedOrder {
             self.update(event);
         };
         let is_order_filled = matches!(event, OrderEventAny::Filled(_));
+        let ts_triggered = match event {
+            OrderEventAny::Triggered(ref event) => Some(event.ts_event),
+            _ => None,
+        };
 
         self.core.apply(event)?;
 
+        if ts_triggered.is_some() {
+            self.is_triggered = true;
+            self.ts_triggered = ts_triggered;
+        }
+
         if is_order_filled {
             self.core.set_slippage(self.price);
         };
@@ -31,6 +31,7 @@ pub mod stop_limit;
 pub mod stop_market;
 pub mod trailing_stop_limit;
 pub mod trailing_stop_market;
+pub mod typed_builder;
 
 #[cfg(feature = "stubs")]
 pub mod stubs;
@@ -50,4 +51,9 @@ pub use crate::orders::{
     stop_market::StopMarketOrder,
     trailing_stop_limit::TrailingStopLimitOrder,
     trailing_stop_market::TrailingStopMarketOrder,
+    typed_builder::{
+        LimitIfTouchedOrderBuilder, LimitOrderBuilder, MarketIfTouchedOrderBuilder,
+        MarketOrderBuilder, MarketToLimitOrderBuilder, StopLimitOrderBuilder,
+        StopMarketOrderBuilder, TrailingStopLimitOrderBuilder, TrailingStopMarketOrderBuilder,
+    },
 };
@@ -0,0 +1,1378 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Typed builders for constructing orders.
+//!
+//! Each order type has a builder which takes the order identity, side and quantity on
+//! construction. Prices and offsets required by the order type are tracked as typestate,
+//! so a builder only provides `build` once they have been set, and setters are only
+//! provided for parameters the order type supports. Flag combinations which cannot be
+//! expressed through the type system are validated when the order is built.
+
+use std::collections::HashMap;
+
+use nautilus_core::{
+    correctness::{check_predicate_false, check_predicate_true},
+    nanos::UnixNanos,
+    uuid::UUID4,
+};
+use ustr::Ustr;
+
+use crate::{
+    enums::{ContingencyType, OrderSide, TimeInForce, TrailingOffsetType, TriggerType},
+    identifiers::{
+        ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, StrategyId, TraderId,
+    },
+    orders::{
+        limit::LimitOrder, limit_if_touched::LimitIfTouchedOrder, market::MarketOrder,
+        market_if_touched::MarketIfTouchedOrder, market_to_limit::MarketToLimitOrder,
+        stop_limit::StopLimitOrder, stop_market::StopMarketOrder,
+        trailing_stop_limit::TrailingStopLimitOrder, trailing_stop_market::TrailingStopMarketOrder,
+    },
+    types::{quantity::check_quantity_positive, Price, Quantity},
+};
+
+/// Typestate for a required builder parameter which has not been set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Unset;
+
+/// The trailing offset and its type, as required by trailing stop orders.
+pub type TrailingOffset = (Price, TrailingOffsetType);
+
+#[derive(Clone, Debug)]
+struct OrderParams {
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    order_side: OrderSide,
+    quantity: Quantity,
+    time_in_force: TimeInForce,
+    expire_time: Option<UnixNanos>,
+    trigger_type: TriggerType,
+    post_only: bool,
+    reduce_only: bool,
+    quote_quantity: bool,
+    display_qty: Option<Quantity>,
+    emulation_trigger: Option<TriggerType>,
+    trigger_instrument_id: Option<InstrumentId>,
+    contingency_type: Option<ContingencyType>,
+    order_list_id: Option<OrderListId>,
+    linked_order_ids: Option<Vec<ClientOrderId>>,
+    parent_order_id: Option<ClientOrderId>,
+    exec_algorithm_id: Option<ExecAlgorithmId>,
+    exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+    exec_spawn_id: Option<ClientOrderId>,
+    tags: Option<Vec<Ustr>>,
+    init_id: UUID4,
+    ts_init: UnixNanos,
+}
+
+impl OrderParams {
+    fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            time_in_force: TimeInForce::Gtc,
+            expire_time: None,
+            trigger_type: TriggerType::Default,
+            post_only: false,
+            reduce_only: false,
+            quote_quantity: false,
+            display_qty: None,
+            emulation_trigger: None,
+            trigger_instrument_id: None,
+            contingency_type: None,
+            order_list_id: None,
+            linked_order_ids: None,
+            parent_order_id: None,
+            exec_algorithm_id: None,
+            exec_algorithm_params: None,
+            exec_spawn_id: None,
+            tags: None,
+            init_id: UUID4::new(),
+            ts_init: UnixNanos::default(),
+        }
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        check_quantity_positive(self.quantity)?;
+        if self.time_in_force == TimeInForce::Gtd {
+            check_predicate_true(
+                self.expire_time.is_some_and(|t| t.as_u64() > 0),
+                "`expire_time` is required for `GTD` order",
+            )?;
+        }
+        check_predicate_false(
+            self.post_only && matches!(self.time_in_force, TimeInForce::Ioc | TimeInForce::Fok),
+            "`post_only` is not valid with `IOC` or `FOK` time in force",
+        )?;
+        if let Some(display_qty) = self.display_qty {
+            check_predicate_true(
+                display_qty <= self.quantity,
+                "`display_qty` must not exceed `quantity`",
+            )?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_common_setters {
+    ($builder:ident $(<$($state:ident),+>)?) => {
+        impl$(<$($state),+>)? $builder$(<$($state),+>)? {
+            /// Sets the time in force for the order (`GTD` requires an expire time).
+            #[must_use]
+            pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+                self.params.time_in_force = time_in_force;
+                self
+            }
+
+            /// Sets whether the order may only reduce an open position.
+            #[must_use]
+            pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+                self.params.reduce_only = reduce_only;
+                self
+            }
+
+            /// Sets whether the order quantity is denominated in the quote currency.
+            #[must_use]
+            pub fn quote_quantity(mut self, quote_quantity: bool) -> Self {
+                self.params.quote_quantity = quote_quantity;
+                self
+            }
+
+            /// Sets the contingency type and linked order IDs for the order.
+            #[must_use]
+            pub fn contingency(
+                mut self,
+                contingency_type: ContingencyType,
+                linked_order_ids: Vec<ClientOrderId>,
+            ) -> Self {
+                self.params.contingency_type = Some(contingency_type);
+                self.params.linked_order_ids = Some(linked_order_ids);
+                self
+            }
+
+            /// Sets the order list ID for the order.
+            #[must_use]
+            pub fn order_list_id(mut self, order_list_id: OrderListId) -> Self {
+                self.params.order_list_id = Some(order_list_id);
+                self
+            }
+
+            /// Sets the parent order ID for the order.
+            #[must_use]
+            pub fn parent_order_id(mut self, parent_order_id: ClientOrderId) -> Self {
+                self.params.parent_order_id = Some(parent_order_id);
+                self
+            }
+
+            /// Sets the execution algorithm and its parameters for the order.
+            #[must_use]
+            pub fn exec_algorithm(
+                mut self,
+                exec_algorithm_id: ExecAlgorithmId,
+                exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+            ) -> Self {
+                self.params.exec_algorithm_id = Some(exec_algorithm_id);
+                self.params.exec_algorithm_params = exec_algorithm_params;
+                self
+            }
+
+            /// Sets the execution spawn ID for the order.
+            #[must_use]
+            pub fn exec_spawn_id(mut self, exec_spawn_id: ClientOrderId) -> Self {
+                self.params.exec_spawn_id = Some(exec_spawn_id);
+                self
+            }
+
+            /// Sets the custom tags for the order.
+            #[must_use]
+            pub fn tags(mut self, tags: Vec<Ustr>) -> Self {
+                self.params.tags = Some(tags);
+                self
+            }
+
+            /// Sets the initialization event ID for the order.
+            #[must_use]
+            pub fn init_id(mut self, init_id: UUID4) -> Self {
+                self.params.init_id = init_id;
+                self
+            }
+
+            /// Sets the UNIX timestamp (nanoseconds) when the order was initialized.
+            #[must_use]
+            pub fn ts_init(mut self, ts_init: UnixNanos) -> Self {
+                self.params.ts_init = ts_init;
+                self
+            }
+        }
+    };
+}
+
+macro_rules! impl_resting_setters {
+    ($builder:ident $(<$($state:ident),+>)?) => {
+        impl$(<$($state),+>)? $builder$(<$($state),+>)? {
+            /// Sets the order to expire at `expire_time` (sets the time in force to `GTD`).
+            #[must_use]
+            pub fn expire_time(mut self, expire_time: UnixNanos) -> Self {
+                self.params.time_in_force = TimeInForce::Gtd;
+                self.params.expire_time = Some(expire_time);
+                self
+            }
+
+            /// Sets the displayed quantity for the order (iceberg orders).
+            #[must_use]
+            pub fn display_qty(mut self, display_qty: Quantity) -> Self {
+                self.params.display_qty = Some(display_qty);
+                self
+            }
+        }
+    };
+}
+
+macro_rules! impl_post_only_setter {
+    ($builder:ident $(<$($state:ident),+>)?) => {
+        impl$(<$($state),+>)? $builder$(<$($state),+>)? {
+            /// Sets whether the order will only provide liquidity (`IOC` and `FOK` are invalid).
+            #[must_use]
+            pub fn post_only(mut self, post_only: bool) -> Self {
+                self.params.post_only = post_only;
+                self
+            }
+        }
+    };
+}
+
+macro_rules! impl_emulation_setters {
+    ($builder:ident $(<$($state:ident),+>)?) => {
+        impl$(<$($state),+>)? $builder$(<$($state),+>)? {
+            /// Sets the emulation trigger for the order, optionally triggering from the
+            /// prices of another instrument.
+            #[must_use]
+            pub fn emulation_trigger(
+                mut self,
+                emulation_trigger: TriggerType,
+                trigger_instrument_id: Option<InstrumentId>,
+            ) -> Self {
+                self.params.emulation_trigger = Some(emulation_trigger);
+                self.params.trigger_instrument_id = trigger_instrument_id;
+                self
+            }
+        }
+    };
+}
+
+macro_rules! impl_trigger_type_setter {
+    ($builder:ident $(<$($state:ident),+>)?) => {
+        impl$(<$($state),+>)? $builder$(<$($state),+>)? {
+            /// Sets the trigger type for the order.
+            #[must_use]
+            pub fn trigger_type(mut self, trigger_type: TriggerType) -> Self {
+                self.params.trigger_type = trigger_type;
+                self
+            }
+        }
+    };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// MarketOrderBuilder
+////////////////////////////////////////////////////////////////////////////////
+
+/// Builder for [`MarketOrder`] instances.
+#[derive(Clone, Debug)]
+pub struct MarketOrderBuilder {
+    params: OrderParams,
+}
+
+impl MarketOrderBuilder {
+    /// Creates a new [`MarketOrderBuilder`] instance.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            params: OrderParams::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                order_side,
+                quantity,
+            ),
+        }
+    }
+
+    /// Builds the [`MarketOrder`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order parameters are invalid.
+    pub fn build(self) -> anyhow::Result<MarketOrder> {
+        self.params.validate()?;
+        let p = self.params;
+        MarketOrder::new_checked(
+            p.trader_id,
+            p.strategy_id,
+            p.instrument_id,
+            p.client_order_id,
+            p.order_side,
+            p.quantity,
+            p.time_in_force,
+            p.init_id,
+            p.ts_init,
+            p.reduce_only,
+            p.quote_quantity,
+            p.contingency_type,
+            p.order_list_id,
+            p.linked_order_ids,
+            p.parent_order_id,
+            p.exec_algorithm_id,
+            p.exec_algorithm_params,
+            p.exec_spawn_id,
+            p.tags,
+        )
+    }
+}
+
+impl_common_setters!(MarketOrderBuilder);
+
+////////////////////////////////////////////////////////////////////////////////
+// LimitOrderBuilder
+////////////////////////////////////////////////////////////////////////////////
+
+/// Builder for [`LimitOrder`] instances, requiring a limit `price`.
+#[derive(Clone, Debug)]
+pub struct LimitOrderBuilder<P = Unset> {
+    params: OrderParams,
+    price: P,
+}
+
+impl LimitOrderBuilder {
+    /// Creates a new [`LimitOrderBuilder`] instance.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            params: OrderParams::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                order_side,
+                quantity,
+            ),
+            price: Unset,
+        }
+    }
+}
+
+impl<P> LimitOrderBuilder<P> {
+    /// Sets the limit price for the order.
+    #[must_use]
+    pub fn price(self, price: Price) -> LimitOrderBuilder<Price> {
+        LimitOrderBuilder {
+            params: self.params,
+            price,
+        }
+    }
+}
+
+impl LimitOrderBuilder<Price> {
+    /// Builds the [`LimitOrder`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order parameters are invalid.
+    pub fn build(self) -> anyhow::Result<LimitOrder> {
+        self.params.validate()?;
+        let p = self.params;
+        LimitOrder::new(
+            p.trader_id,
+            p.strategy_id,
+            p.instrument_id,
+            p.client_order_id,
+            p.order_side,
+            p.quantity,
+            self.price,
+            p.time_in_force,
+            p.expire_time,
+            p.post_only,
+            p.reduce_only,
+            p.quote_quantity,
+            p.display_qty,
+            p.emulation_trigger,
+            p.trigger_instrument_id,
+            p.contingency_type,
+            p.order_list_id,
+            p.linked_order_ids,
+            p.parent_order_id,
+            p.exec_algorithm_id,
+            p.exec_algorithm_params,
+            p.exec_spawn_id,
+            p.tags,
+            p.init_id,
+            p.ts_init,
+        )
+    }
+}
+
+impl_common_setters!(LimitOrderBuilder<P>);
+impl_resting_setters!(LimitOrderBuilder<P>);
+impl_post_only_setter!(LimitOrderBuilder<P>);
+impl_emulation_setters!(LimitOrderBuilder<P>);
+
+////////////////////////////////////////////////////////////////////////////////
+// StopMarketOrderBuilder
+////////////////////////////////////////////////////////////////////////////////
+
+/// Builder for [`StopMarketOrder`] instances, requiring a `trigger_price`.
+#[derive(Clone, Debug)]
+pub struct StopMarketOrderBuilder<T = Unset> {
+    params: OrderParams,
+    trigger_price: T,
+}
+
+impl StopMarketOrderBuilder {
+    /// Creates a new [`StopMarketOrderBuilder`] instance.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            params: OrderParams::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                order_side,
+                quantity,
+            ),
+            trigger_price: Unset,
+        }
+    }
+}
+
+impl<T> StopMarketOrderBuilder<T> {
+    /// Sets the trigger price for the order.
+    #[must_use]
+    pub fn trigger_price(self, trigger_price: Price) -> StopMarketOrderBuilder<Price> {
+        StopMarketOrderBuilder {
+            params: self.params,
+            trigger_price,
+        }
+    }
+}
+
+impl StopMarketOrderBuilder<Price> {
+    /// Builds the [`StopMarketOrder`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order parameters are invalid.
+    pub fn build(self) -> anyhow::Result<StopMarketOrder> {
+        self.params.validate()?;
+        let p = self.params;
+        Ok(StopMarketOrder::new(
+            p.trader_id,
+            p.strategy_id,
+            p.instrument_id,
+            p.client_order_id,
+            p.order_side,
+            p.quantity,
+            self.trigger_price,
+            p.trigger_type,
+            p.time_in_force,
+            p.expire_time,
+            p.reduce_only,
+            p.quote_quantity,
+            p.display_qty,
+            p.emulation_trigger,
+            p.trigger_instrument_id,
+            p.contingency_type,
+            p.order_list_id,
+            p.linked_order_ids,
+            p.parent_order_id,
+            p.exec_algorithm_id,
+            p.exec_algorithm_params,
+            p.exec_spawn_id,
+            p.tags,
+            p.init_id,
+            p.ts_init,
+        ))
+    }
+}
+
+impl_common_setters!(StopMarketOrderBuilder<T>);
+impl_resting_setters!(StopMarketOrderBuilder<T>);
+impl_emulation_setters!(StopMarketOrderBuilder<T>);
+impl_trigger_type_setter!(StopMarketOrderBuilder<T>);
+
+////////////////////////////////////////////////////////////////////////////////
+// StopLimitOrderBuilder
+////////////////////////////////////////////////////////////////////////////////
+
+/// Builder for [`StopLimitOrder`] instances, requiring a limit `price` and `trigger_price`.
+#[derive(Clone, Debug)]
+pub struct StopLimitOrderBuilder<P = Unset, T = Unset> {
+    params: OrderParams,
+    price: P,
+    trigger_price: T,
+}
+
+impl StopLimitOrderBuilder {
+    /// Creates a new [`StopLimitOrderBuilder`] instance.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            params: OrderParams::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                order_side,
+                quantity,
+            ),
+            price: Unset,
+            trigger_price: Unset,
+        }
+    }
+}
+
+impl<P, T> StopLimitOrderBuilder<P, T> {
+    /// Sets the limit price for the order.
+    #[must_use]
+    pub fn price(self, price: Price) -> StopLimitOrderBuilder<Price, T> {
+        StopLimitOrderBuilder {
+            params: self.params,
+            price,
+            trigger_price: self.trigger_price,
+        }
+    }
+
+    /// Sets the trigger price for the order.
+    #[must_use]
+    pub fn trigger_price(self, trigger_price: Price) -> StopLimitOrderBuilder<P, Price> {
+        StopLimitOrderBuilder {
+            params: self.params,
+            price: self.price,
+            trigger_price,
+        }
+    }
+}
+
+impl StopLimitOrderBuilder<Price, Price> {
+    /// Builds the [`StopLimitOrder`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order parameters are invalid.
+    pub fn build(self) -> anyhow::Result<StopLimitOrder> {
+        self.params.validate()?;
+        let p = self.params;
+        Ok(StopLimitOrder::new(
+            p.trader_id,
+            p.strategy_id,
+            p.instrument_id,
+            p.client_order_id,
+            p.order_side,
+            p.quantity,
+            self.price,
+            self.trigger_price,
+            p.trigger_type,
+            p.time_in_force,
+            p.expire_time,
+            p.post_only,
+            p.reduce_only,
+            p.quote_quantity,
+            p.display_qty,
+            p.emulation_trigger,
+            p.trigger_instrument_id,
+            p.contingency_type,
+            p.order_list_id,
+            p.linked_order_ids,
+            p.parent_order_id,
+            p.exec_algorithm_id,
+            p.exec_algorithm_params,
+            p.exec_spawn_id,
+            p.tags,
+            p.init_id,
+            p.ts_init,
+        ))
+    }
+}
+
+impl_common_setters!(StopLimitOrderBuilder<P, T>);
+impl_resting_setters!(StopLimitOrderBuilder<P, T>);
+impl_post_only_setter!(StopLimitOrderBuilder<P, T>);
+impl_emulation_setters!(StopLimitOrderBuilder<P, T>);
+impl_trigger_type_setter!(StopLimitOrderBuilder<P, T>);
+
+////////////////////////////////////////////////////////////////////////////////
+// MarketIfTouchedOrderBuilder
+////////////////////////////////////////////////////////////////////////////////
+
+/// Builder for [`MarketIfTouchedOrder`] instances, requiring a `trigger_price`.
+#[derive(Clone, Debug)]
+pub struct MarketIfTouchedOrderBuilder<T = Unset> {
+    params: OrderParams,
+    trigger_price: T,
+}
+
+impl MarketIfTouchedOrderBuilder {
+    /// Creates a new [`MarketIfTouchedOrderBuilder`] instance.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            params: OrderParams::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                order_side,
+                quantity,
+            ),
+            trigger_price: Unset,
+        }
+    }
+}
+
+impl<T> MarketIfTouchedOrderBuilder<T> {
+    /// Sets the trigger price for the order.
+    #[must_use]
+    pub fn trigger_price(self, trigger_price: Price) -> MarketIfTouchedOrderBuilder<Price> {
+        MarketIfTouchedOrderBuilder {
+            params: self.params,
+            trigger_price,
+        }
+    }
+}
+
+impl MarketIfTouchedOrderBuilder<Price> {
+    /// Builds the [`MarketIfTouchedOrder`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order parameters are invalid.
+    pub fn build(self) -> anyhow::Result<MarketIfTouchedOrder> {
+        self.params.validate()?;
+        let p = self.params;
+        Ok(MarketIfTouchedOrder::new(
+            p.trader_id,
+            p.strategy_id,
+            p.instrument_id,
+            p.client_order_id,
+            p.order_side,
+            p.quantity,
+            self.trigger_price,
+            p.trigger_type,
+            p.time_in_force,
+            p.expire_time,
+            p.reduce_only,
+            p.quote_quantity,
+            p.display_qty,
+            p.emulation_trigger,
+            p.trigger_instrument_id,
+            p.contingency_type,
+            p.order_list_id,
+            p.linked_order_ids,
+            p.parent_order_id,
+            p.exec_algorithm_id,
+            p.exec_algorithm_params,
+            p.exec_spawn_id,
+            p.tags,
+            p.init_id,
+            p.ts_init,
+        ))
+    }
+}
+
+impl_common_setters!(MarketIfTouchedOrderBuilder<T>);
+impl_resting_setters!(MarketIfTouchedOrderBuilder<T>);
+impl_emulation_setters!(MarketIfTouchedOrderBuilder<T>);
+impl_trigger_type_setter!(MarketIfTouchedOrderBuilder<T>);
+
+////////////////////////////////////////////////////////////////////////////////
+// LimitIfTouchedOrderBuilder
+////////////////////////////////////////////////////////////////////////////////
+
+/// Builder for [`LimitIfTouchedOrder`] instances, requiring a limit `price` and `trigger_price`.
+#[derive(Clone, Debug)]
+pub struct LimitIfTouchedOrderBuilder<P = Unset, T = Unset> {
+    params: OrderParams,
+    price: P,
+    trigger_price: T,
+}
+
+impl LimitIfTouchedOrderBuilder {
+    /// Creates a new [`LimitIfTouchedOrderBuilder`] instance.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            params: OrderParams::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                order_side,
+                quantity,
+            ),
+            price: Unset,
+            trigger_price: Unset,
+        }
+    }
+}
+
+impl<P, T> LimitIfTouchedOrderBuilder<P, T> {
+    /// Sets the limit price for the order.
+    #[must_use]
+    pub fn price(self, price: Price) -> LimitIfTouchedOrderBuilder<Price, T> {
+        LimitIfTouchedOrderBuilder {
+            params: self.params,
+            price,
+            trigger_price: self.trigger_price,
+        }
+    }
+
+    /// Sets the trigger price for the order.
+    #[must_use]
+    pub fn trigger_price(self, trigger_price: Price) -> LimitIfTouchedOrderBuilder<P, Price> {
+        LimitIfTouchedOrderBuilder {
+            params: self.params,
+            price: self.price,
+            trigger_price,
+        }
+    }
+}
+
+impl LimitIfTouchedOrderBuilder<Price, Price> {
+    /// Builds the [`LimitIfTouchedOrder`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order parameters are invalid.
+    pub fn build(self) -> anyhow::Result<LimitIfTouchedOrder> {
+        self.params.validate()?;
+        let p = self.params;
+        Ok(LimitIfTouchedOrder::new(
+            p.trader_id,
+            p.strategy_id,
+            p.instrument_id,
+            p.client_order_id,
+            p.order_side,
+            p.quantity,
+            self.price,
+            self.trigger_price,
+            p.trigger_type,
+            p.time_in_force,
+            p.expire_time,
+            p.post_only,
+            p.reduce_only,
+            p.quote_quantity,
+            p.display_qty,
+            p.emulation_trigger,
+            p.trigger_instrument_id,
+            p.contingency_type,
+            p.order_list_id,
+            p.linked_order_ids,
+            p.parent_order_id,
+            p.exec_algorithm_id,
+            p.exec_algorithm_params,
+            p.exec_spawn_id,
+            p.tags,
+            p.init_id,
+            p.ts_init,
+        ))
+    }
+}
+
+impl_common_setters!(LimitIfTouchedOrderBuilder<P, T>);
+impl_resting_setters!(LimitIfTouchedOrderBuilder<P, T>);
+impl_post_only_setter!(LimitIfTouchedOrderBuilder<P, T>);
+impl_emulation_setters!(LimitIfTouchedOrderBuilder<P, T>);
+impl_trigger_type_setter!(LimitIfTouchedOrderBuilder<P, T>);
+
+////////////////////////////////////////////////////////////////////////////////
+// MarketToLimitOrderBuilder
+////////////////////////////////////////////////////////////////////////////////
+
+/// Builder for [`MarketToLimitOrder`] instances.
+#[derive(Clone, Debug)]
+pub struct MarketToLimitOrderBuilder {
+    params: OrderParams,
+}
+
+impl MarketToLimitOrderBuilder {
+    /// Creates a new [`MarketToLimitOrderBuilder`] instance.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            params: OrderParams::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                order_side,
+                quantity,
+            ),
+        }
+    }
+
+    /// Builds the [`MarketToLimitOrder`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order parameters are invalid.
+    pub fn build(self) -> anyhow::Result<MarketToLimitOrder> {
+        self.params.validate()?;
+        let p = self.params;
+        Ok(MarketToLimitOrder::new(
+            p.trader_id,
+            p.strategy_id,
+            p.instrument_id,
+            p.client_order_id,
+            p.order_side,
+            p.quantity,
+            p.time_in_force,
+            p.expire_time,
+            p.post_only,
+            p.reduce_only,
+            p.quote_quantity,
+            p.display_qty,
+            p.contingency_type,
+            p.order_list_id,
+            p.linked_order_ids,
+            p.parent_order_id,
+            p.exec_algorithm_id,
+            p.exec_algorithm_params,
+            p.exec_spawn_id,
+            p.tags,
+            p.init_id,
+            p.ts_init,
+        ))
+    }
+}
+
+impl_common_setters!(MarketToLimitOrderBuilder);
+impl_resting_setters!(MarketToLimitOrderBuilder);
+impl_post_only_setter!(MarketToLimitOrderBuilder);
+
+////////////////////////////////////////////////////////////////////////////////
+// TrailingStopMarketOrderBuilder
+////////////////////////////////////////////////////////////////////////////////
+
+/// Builder for [`TrailingStopMarketOrder`] instances, requiring a `trigger_price` and
+/// `trailing_offset`.
+#[derive(Clone, Debug)]
+pub struct TrailingStopMarketOrderBuilder<T = Unset, O = Unset> {
+    params: OrderParams,
+    trigger_price: T,
+    trailing_offset: O,
+}
+
+impl TrailingStopMarketOrderBuilder {
+    /// Creates a new [`TrailingStopMarketOrderBuilder`] instance.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            params: OrderParams::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                order_side,
+                quantity,
+            ),
+            trigger_price: Unset,
+            trailing_offset: Unset,
+        }
+    }
+}
+
+impl<T, O> TrailingStopMarketOrderBuilder<T, O> {
+    /// Sets the trigger price for the order.
+    #[must_use]
+    pub fn trigger_price(self, trigger_price: Price) -> TrailingStopMarketOrderBuilder<Price, O> {
+        TrailingStopMarketOrderBuilder {
+            params: self.params,
+            trigger_price,
+            trailing_offset: self.trailing_offset,
+        }
+    }
+
+    /// Sets the trailing offset and its type for the order.
+    #[must_use]
+    pub fn trailing_offset(
+        self,
+        trailing_offset: Price,
+        trailing_offset_type: TrailingOffsetType,
+    ) -> TrailingStopMarketOrderBuilder<T, TrailingOffset> {
+        TrailingStopMarketOrderBuilder {
+            params: self.params,
+            trigger_price: self.trigger_price,
+            trailing_offset: (trailing_offset, trailing_offset_type),
+        }
+    }
+}
+
+impl TrailingStopMarketOrderBuilder<Price, TrailingOffset> {
+    /// Builds the [`TrailingStopMarketOrder`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order parameters are invalid.
+    pub fn build(self) -> anyhow::Result<TrailingStopMarketOrder> {
+        self.params.validate()?;
+        let p = self.params;
+        let (trailing_offset, trailing_offset_type) = self.trailing_offset;
+        Ok(TrailingStopMarketOrder::new(
+            p.trader_id,
+            p.strategy_id,
+            p.instrument_id,
+            p.client_order_id,
+            p.order_side,
+            p.quantity,
+            self.trigger_price,
+            p.trigger_type,
+            trailing_offset,
+            trailing_offset_type,
+            p.time_in_force,
+            p.expire_time,
+            p.reduce_only,
+            p.quote_quantity,
+            p.display_qty,
+            p.emulation_trigger,
+            p.trigger_instrument_id,
+            p.contingency_type,
+            p.order_list_id,
+            p.linked_order_ids,
+            p.parent_order_id,
+            p.exec_algorithm_id,
+            p.exec_algorithm_params,
+            p.exec_spawn_id,
+            p.tags,
+            p.init_id,
+            p.ts_init,
+        ))
+    }
+}
+
+impl_common_setters!(TrailingStopMarketOrderBuilder<T, O>);
+impl_resting_setters!(TrailingStopMarketOrderBuilder<T, O>);
+impl_emulation_setters!(TrailingStopMarketOrderBuilder<T, O>);
+impl_trigger_type_setter!(TrailingStopMarketOrderBuilder<T, O>);
+
+////////////////////////////////////////////////////////////////////////////////
+// TrailingStopLimitOrderBuilder
+////////////////////////////////////////////////////////////////////////////////
+
+/// Builder for [`TrailingStopLimitOrder`] instances, requiring a limit `price`,
+/// `trigger_price`, `limit_offset` and `trailing_offset`.
+#[derive(Clone, Debug)]
+pub struct TrailingStopLimitOrderBuilder<P = Unset, T = Unset, L = Unset, O = Unset> {
+    params: OrderParams,
+    price: P,
+    trigger_price: T,
+    limit_offset: L,
+    trailing_offset: O,
+}
+
+impl TrailingStopLimitOrderBuilder {
+    /// Creates a new [`TrailingStopLimitOrderBuilder`] instance.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            params: OrderParams::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                order_side,
+                quantity,
+            ),
+            price: Unset,
+            trigger_price: Unset,
+            limit_offset: Unset,
+            trailing_offset: Unset,
+        }
+    }
+}
+
+impl<P, T, L, O> TrailingStopLimitOrderBuilder<P, T, L, O> {
+    /// Sets the limit price for the order.
+    #[must_use]
+    pub fn price(self, price: Price) -> TrailingStopLimitOrderBuilder<Price, T, L, O> {
+        TrailingStopLimitOrderBuilder {
+            params: self.params,
+            price,
+            trigger_price: self.trigger_price,
+            limit_offset: self.limit_offset,
+            trailing_offset: self.trailing_offset,
+        }
+    }
+
+    /// Sets the trigger price for the order.
+    #[must_use]
+    pub fn trigger_price(
+        self,
+        trigger_price: Price,
+    ) -> TrailingStopLimitOrderBuilder<P, Price, L, O> {
+        TrailingStopLimitOrderBuilder {
+            params: self.params,
+            price: self.price,
+            trigger_price,
+            limit_offset: self.limit_offset,
+            trailing_offset: self.trailing_offset,
+        }
+    }
+
+    /// Sets the limit offset for the order.
+    #[must_use]
+    pub fn limit_offset(
+        self,
+        limit_offset: Price,
+    ) -> TrailingStopLimitOrderBuilder<P, T, Price, O> {
+        TrailingStopLimitOrderBuilder {
+            params: self.params,
+            price: self.price,
+            trigger_price: self.trigger_price,
+            limit_offset,
+            trailing_offset: self.trailing_offset,
+        }
+    }
+
+    /// Sets the trailing offset and its type for the order.
+    #[must_use]
+    pub fn trailing_offset(
+        self,
+        trailing_offset: Price,
+        trailing_offset_type: TrailingOffsetType,
+    ) -> TrailingStopLimitOrderBuilder<P, T, L, TrailingOffset> {
+        TrailingStopLimitOrderBuilder {
+            params: self.params,
+            price: self.price,
+            trigger_price: self.trigger_price,
+            limit_offset: self.limit_offset,
+            trailing_offset: (trailing_offset, trailing_offset_type),
+        }
+    }
+}
+
+impl TrailingStopLimitOrderBuilder<Price, Price, Price, TrailingOffset> {
+    /// Builds the [`TrailingStopLimitOrder`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order parameters are invalid.
+    pub fn build(self) -> anyhow::Result<TrailingStopLimitOrder> {
+        self.params.validate()?;
+        let p = self.params;
+        let (trailing_offset, trailing_offset_type) = self.trailing_offset;
+        Ok(TrailingStopLimitOrder::new(
+            p.trader_id,
+            p.strategy_id,
+            p.instrument_id,
+            p.client_order_id,
+            p.order_side,
+            p.quantity,
+            self.price,
+            self.trigger_price,
+            p.trigger_type,
+            self.limit_offset,
+            trailing_offset,
+            trailing_offset_type,
+            p.time_in_force,
+            p.expire_time,
+            p.post_only,
+            p.reduce_only,
+            p.quote_quantity,
+            p.display_qty,
+            p.emulation_trigger,
+            p.trigger_instrument_id,
+            p.contingency_type,
+            p.order_list_id,
+            p.linked_order_ids,
+            p.parent_order_id,
+            p.exec_algorithm_id,
+            p.exec_algorithm_params,
+            p.exec_spawn_id,
+            p.tags,
+            p.init_id,
+            p.ts_init,
+        ))
+    }
+}
+
+impl_common_setters!(TrailingStopLimitOrderBuilder<P, T, L, O>);
+impl_resting_setters!(TrailingStopLimitOrderBuilder<P, T, L, O>);
+impl_post_only_setter!(TrailingStopLimitOrderBuilder<P, T, L, O>);
+impl_emulation_setters!(TrailingStopLimitOrderBuilder<P, T, L, O>);
+impl_trigger_type_setter!(TrailingStopLimitOrderBuilder<P, T, L, O>);
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{enums::OrderType, orders::base::Order};
+
+    fn limit_builder(quantity: Quantity) -> LimitOrderBuilder {
+        LimitOrderBuilder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            ClientOrderId::default(),
+            OrderSide::Buy,
+            quantity,
+        )
+    }
+
+    #[rstest]
+    fn test_market_order_builder() {
+        let order = MarketOrderBuilder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            ClientOrderId::default(),
+            OrderSide::Sell,
+            Quantity::from(1),
+        )
+        .time_in_force(TimeInForce::Ioc)
+        .reduce_only(true)
+        .build()
+        .unwrap();
+
+        assert_eq!(order.order_type(), OrderType::Market);
+        assert_eq!(order.order_side(), OrderSide::Sell);
+        assert_eq!(order.time_in_force(), TimeInForce::Ioc);
+        assert!(order.is_reduce_only());
+    }
+
+    #[rstest]
+    fn test_market_order_builder_gtd_is_invalid() {
+        let result = MarketOrderBuilder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            ClientOrderId::default(),
+            OrderSide::Buy,
+            Quantity::from(1),
+        )
+        .time_in_force(TimeInForce::Gtd)
+        .build();
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_limit_order_builder() {
+        let order = limit_builder(Quantity::from(10))
+            .price(Price::from("100.50"))
+            .post_only(true)
+            .display_qty(Quantity::from(2))
+            .expire_time(UnixNanos::from(1_000))
+            .build()
+            .unwrap();
+
+        assert_eq!(order.order_type(), OrderType::Limit);
+        assert_eq!(order.price(), Some(Price::from("100.50")));
+        assert_eq!(order.time_in_force(), TimeInForce::Gtd);
+        assert_eq!(order.expire_time(), Some(UnixNanos::from(1_000)));
+        assert_eq!(order.display_qty(), Some(Quantity::from(2)));
+        assert!(order.is_post_only());
+    }
+
+    #[rstest]
+    fn test_limit_order_builder_price_can_be_replaced() {
+        let order = limit_builder(Quantity::from(10))
+            .price(Price::from("100.00"))
+            .price(Price::from("101.00"))
+            .build()
+            .unwrap();
+
+        assert_eq!(order.price(), Some(Price::from("101.00")));
+    }
+
+    #[rstest]
+    #[case(TimeInForce::Ioc)]
+    #[case(TimeInForce::Fok)]
+    fn test_limit_order_builder_post_only_with_immediate_tif(#[case] time_in_force: TimeInForce) {
+        let result = limit_builder(Quantity::from(10))
+            .price(Price::from("100.00"))
+            .post_only(true)
+            .time_in_force(time_in_force)
+            .build();
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "`post_only` is not valid with `IOC` or `FOK` time in force"
+        );
+    }
+
+    #[rstest]
+    fn test_limit_order_builder_display_qty_exceeds_quantity() {
+        let result = limit_builder(Quantity::from(10))
+            .price(Price::from("100.00"))
+            .display_qty(Quantity::from(11))
+            .build();
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "`display_qty` must not exceed `quantity`"
+        );
+    }
+
+    #[rstest]
+    fn test_limit_order_builder_gtd_without_expire_time() {
+        let result = limit_builder(Quantity::from(10))
+            .price(Price::from("100.00"))
+            .time_in_force(TimeInForce::Gtd)
+            .build();
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "`expire_time` is required for `GTD` order"
+        );
+    }
+
+    #[rstest]
+    fn test_limit_order_builder_zero_quantity() {
+        let result = limit_builder(Quantity::from(0))
+            .price(Price::from("100.00"))
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_stop_limit_order_builder_in_any_order() {
+        let order = StopLimitOrderBuilder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            ClientOrderId::default(),
+            OrderSide::Buy,
+            Quantity::from(1),
+        )
+        .trigger_price(Price::from("101.00"))
+        .trigger_type(TriggerType::LastPrice)
+        .price(Price::from("102.00"))
+        .build()
+        .unwrap();
+
+        assert_eq!(order.order_type(), OrderType::StopLimit);
+        assert_eq!(order.price(), Some(Price::from("102.00")));
+        assert_eq!(order.trigger_price(), Some(Price::from("101.00")));
+        assert_eq!(order.trigger_type(), Some(TriggerType::LastPrice));
+    }
+
+    #[rstest]
+    fn test_trailing_stop_limit_order_builder() {
+        let order = TrailingStopLimitOrderBuilder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            ClientOrderId::default(),
+            OrderSide::Sell,
+            Quantity::from(1),
+        )
+        .price(Price::from("99.00"))
+        .trigger_price(Price::from("100.00"))
+        .limit_offset(Price::from("1.00"))
+        .trailing_offset(Price::from("5"), TrailingOffsetType::BasisPoints)
+        .build()
+        .unwrap();
+
+        assert_eq!(order.order_type(), OrderType::TrailingStopLimit);
+        assert_eq!(order.trailing_offset(), Some(Price::from("5")));
+        assert_eq!(
+            order.trailing_offset_type(),
+            Some(TrailingOffsetType::BasisPoints)
+        );
+    }
+}
@@ -22,6 +22,7 @@ use std::{
 };
 
 use nautilus_core::nanos::UnixNanos;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -31,7 +32,7 @@ use crate::{
         AccountId, ClientOrderId, InstrumentId, PositionId, StrategyId, Symbol, TradeId, TraderId,
         Venue, VenueOrderId,
     },
-    instruments::InstrumentAny,
+    instruments::{inverse, InstrumentAny},
     types::{Currency, Money, Price, Quantity},
 };
 
@@ -79,6 +80,8 @@ pub struct Position {
     pub buy_qty: Quantity,
     pub sell_qty: Quantity,
     pub commissions: HashMap<Currency, Money>,
+    #[serde(default)]
+    pub funding_payments: Option<Money>,
 }
 
 impl Position {
@@ -123,6 +126,7 @@ impl Position {
             avg_px_close: None,
             realized_return: 0.0,
             realized_pnl: None,
+            funding_payments: None,
         };
         item.apply(&fill);
         item
@@ -152,6 +156,7 @@ impl Position {
             self.avg_px_close = None;
             self.realized_return = 0.0;
             self.realized_pnl = None;
+            self.funding_payments = None;
         }
 
         self.events.push(*fill);
@@ -312,6 +317,34 @@ impl Position {
         )
     }
 
+    /// Applies a funding payment for the given funding `rate` at the mark `price`, adding it
+    /// to the realized PnL. Returns the payment, which is negative when paid by the position.
+    ///
+    /// A positive rate is paid by long positions to short positions.
+    pub fn apply_funding(&mut self, rate: Decimal, price: Price) -> Money {
+        if self.side == PositionSide::Flat {
+            return Money::new(0.0, self.settlement_currency);
+        }
+
+        let direction = if self.side == PositionSide::Long {
+            -1.0
+        } else {
+            1.0
+        };
+        let notional = self.notional_value(price).as_f64();
+        let payment = Money::new(
+            direction * notional * rate.to_f64().unwrap_or_default(),
+            self.settlement_currency,
+        );
+
+        self.realized_pnl = Some(self.realized_pnl.map_or(payment, |pnl| pnl + payment));
+        self.funding_payments = Some(
+            self.funding_payments
+                .map_or(payment, |funding| funding + payment),
+        );
+        payment
+    }
+
     #[must_use]
     pub fn total_pnl(&self, last: Price) -> Money {
         let realized_pnl = self.realized_pnl.map_or(0.0, |pnl| pnl.as_f64());
@@ -329,16 +362,6 @@ impl Position {
         }
     }
 
-    fn calculate_points_inverse(&self, avg_px_open: f64, avg_px_close: f64) -> f64 {
-        let inverse_open = 1.0 / avg_px_open;
-        let inverse_close = 1.0 / avg_px_close;
-        match self.side {
-            PositionSide::Long => inverse_open - inverse_close,
-            PositionSide::Short => inverse_close - inverse_open,
-            _ => 0.0, // FLAT
-        }
-    }
-
     #[must_use]
     pub fn calculate_pnl(&self, avg_px_open: f64, avg_px_close: f64, quantity: Quantity) -> Money {
         let pnl_raw = self.calculate_pnl_raw(avg_px_open, avg_px_close, quantity.as_f64());
@@ -366,9 +389,13 @@ impl Position {
     fn calculate_pnl_raw(&self, avg_px_open: f64, avg_px_close: f64, quantity: f64) -> f64 {
         let quantity = quantity.min(self.signed_qty.abs());
         if self.is_inverse {
-            quantity
-                * self.multiplier.as_f64()
-                * self.calculate_points_inverse(avg_px_open, avg_px_close)
+            inverse::pnl(
+                self.side,
+                quantity,
+                self.multiplier.as_f64(),
+                avg_px_open,
+                avg_px_close,
+            )
         } else {
             quantity * self.multiplier.as_f64() * self.calculate_points(avg_px_open, avg_px_close)
         }
@@ -439,7 +466,11 @@ impl Position {
     pub fn notional_value(&self, last: Price) -> Money {
         if self.is_inverse {
             Money::new(
-                self.quantity.as_f64() * self.multiplier.as_f64() * (1.0 / last.as_f64()),
+                inverse::notional_value(
+                    self.quantity.as_f64(),
+                    self.multiplier.as_f64(),
+                    last.as_f64(),
+                ),
                 self.base_currency.unwrap(),
             )
         } else {
@@ -450,6 +481,36 @@ impl Position {
         }
     }
 
+    /// Returns the estimated liquidation price of the position if margined in isolation
+    /// with the given `leverage` and `maint_margin_rate`.
+    ///
+    /// Fees and funding are not considered. Returns `None` when the position is flat or
+    /// cannot be liquidated.
+    #[must_use]
+    pub fn liquidation_price(&self, leverage: f64, maint_margin_rate: f64) -> Option<Price> {
+        let avg_px_open = self.avg_px_open;
+        let price = if self.is_inverse {
+            inverse::liquidation_price(self.side, avg_px_open, leverage, maint_margin_rate)?
+        } else {
+            if avg_px_open <= 0.0 || leverage <= 0.0 || !(0.0..1.0).contains(&maint_margin_rate) {
+                return None;
+            }
+            let margin_rate = 1.0 / leverage;
+            match self.side {
+                // E/L + (P - E) = mmr * P
+                PositionSide::Long if margin_rate < 1.0 => {
+                    avg_px_open * (1.0 - margin_rate) / (1.0 - maint_margin_rate)
+                }
+                // E/L + (E - P) = mmr * P
+                PositionSide::Short => {
+                    avg_px_open * (1.0 + margin_rate) / (1.0 + maint_margin_rate)
+                }
+                _ => return None,
+            }
+        };
+        Price::new_checked(price, self.price_precision).ok()
+    }
+
     #[must_use]
     pub fn last_event(&self) -> OrderFilled {
         *self
@@ -527,6 +588,7 @@ mod tests {
 
     use nautilus_core::nanos::UnixNanos;
     use rstest::rstest;
+    use rust_decimal_macros::dec;
 
     use crate::{
         enums::{LiquiditySide, OrderSide, OrderType, PositionSide},
@@ -1891,6 +1953,70 @@ mod tests {
         assert_eq!(position.commissions(), vec![Money::from("0.06048387 BTC")]);
     }
 
+    #[rstest]
+    fn test_liquidation_price_for_long_inverse(xbtusd_bitmex: CryptoPerpetual) {
+        let xbtusd_bitmex = InstrumentAny::CryptoPerpetual(xbtusd_bitmex);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(xbtusd_bitmex.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("100000"))
+            .build();
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &xbtusd_bitmex,
+            Some(TradeId::new("1")),
+            Some(PositionId::new("P-123456")),
+            Some(Price::from("11000.0")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(&xbtusd_bitmex, fill.into());
+
+        // 11,000 * (1 + 0) / (1 + 1/10)
+        assert_eq!(
+            position.liquidation_price(10.0, 0.0),
+            Some(Price::new(10_000.0, position.price_precision))
+        );
+        assert_eq!(position.liquidation_price(0.0, 0.0), None);
+    }
+
+    #[rstest]
+    fn test_liquidation_price_for_linear(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id())
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &audusd_sim,
+            None,
+            None,
+            Some(Price::from("1.00000")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(&audusd_sim, fill.into());
+
+        // 1.0 * (1 + 1/4) / (1 + 0)
+        assert_eq!(
+            position.liquidation_price(4.0, 0.0),
+            Some(Price::from("1.25000"))
+        );
+        // A short with leverage of one is liquidated at double the entry price
+        assert_eq!(
+            position.liquidation_price(1.0, 0.0),
+            Some(Price::from("2.00000"))
+        );
+    }
+
     #[rstest]
     #[case(OrderSide::Buy, 25, 25.0)]
     #[case(OrderSide::Sell,25,-25.0)]
@@ -1945,4 +2071,39 @@ mod tests {
         let position = Position::new(&audusd_sim, fill);
         assert_eq!(position.realized_pnl, Some(Money::from("0 USD")));
     }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "-0.40000000 USDT")]
+    #[case(OrderSide::Sell, "0.40000000 USDT")]
+    fn test_apply_funding(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] side: OrderSide,
+        #[case] expected: &str,
+    ) {
+        let ethusdt = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(ethusdt.id())
+            .side(side)
+            .quantity(Quantity::from("2.000"))
+            .build();
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &ethusdt,
+            None,
+            Some(PositionId::new("P-123456")),
+            Some(Price::from("2000.00")),
+            None,
+            None,
+            Some(Money::from("0 USDT")),
+            None,
+            None,
+        );
+        let mut position = Position::new(&ethusdt, fill.into());
+
+        let payment = position.apply_funding(dec!(0.0001), Price::from("2000.00"));
+
+        assert_eq!(payment, Money::from(expected));
+        assert_eq!(position.funding_payments, Some(payment));
+        assert_eq!(position.realized_pnl, Some(payment));
+    }
 }
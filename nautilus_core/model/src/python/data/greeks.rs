@@ -16,15 +16,23 @@
 use pyo3::prelude::*;
 
 use crate::data::greeks::{
-    black_scholes_greeks, imply_vol, imply_vol_and_greeks, BlackScholesGreeksResult,
-    ImplyVolAndGreeksResult,
+    black76_greeks, black_scholes_greeks, imply_vol, imply_vol_and_greeks,
+    BlackScholesGreeksResult, ImplyVolAndGreeksResult,
 };
 
 #[pymethods]
 impl ImplyVolAndGreeksResult {
     /// Creates a new [`ImplyVolAndGreeksResult`] instance.
     #[new]
-    fn py_new(vol: f64, price: f64, delta: f64, gamma: f64, theta: f64, vega: f64) -> Self {
+    fn py_new(
+        vol: f64,
+        price: f64,
+        delta: f64,
+        gamma: f64,
+        theta: f64,
+        vega: f64,
+        rho: f64,
+    ) -> Self {
         Self {
             vol,
             price,
@@ -32,6 +40,7 @@ impl ImplyVolAndGreeksResult {
             gamma,
             theta,
             vega,
+            rho,
         }
     }
 
@@ -71,6 +80,12 @@ impl ImplyVolAndGreeksResult {
         self.theta
     }
 
+    #[getter]
+    #[pyo3(name = "rho")]
+    fn py_rho(&self) -> f64 {
+        self.rho
+    }
+
     fn __repr__(&self) -> String {
         format!("{self:?}")
     }
@@ -80,13 +95,14 @@ impl ImplyVolAndGreeksResult {
 impl BlackScholesGreeksResult {
     /// Creates a new [`BlackScholesGreeksResult`] instance.
     #[new]
-    fn py_new(price: f64, delta: f64, gamma: f64, theta: f64, vega: f64) -> Self {
+    fn py_new(price: f64, delta: f64, gamma: f64, theta: f64, vega: f64, rho: f64) -> Self {
         Self {
             price,
             delta,
             gamma,
             theta,
             vega,
+            rho,
         }
     }
 
@@ -120,6 +136,12 @@ impl BlackScholesGreeksResult {
         self.theta
     }
 
+    #[getter]
+    #[pyo3(name = "rho")]
+    fn py_rho(&self) -> f64 {
+        self.rho
+    }
+
     fn __repr__(&self) -> String {
         format!("{self:?}")
     }
@@ -142,6 +164,21 @@ pub fn py_black_scholes_greeks(
     Ok(result)
 }
 
+#[pyfunction]
+#[pyo3(name = "black76_greeks")]
+pub fn py_black76_greeks(
+    f: f64,
+    r: f64,
+    sigma: f64,
+    is_call: bool,
+    k: f64,
+    t: f64,
+    multiplier: f64,
+) -> PyResult<BlackScholesGreeksResult> {
+    let result = black76_greeks(f, r, sigma, is_call, k, t, multiplier);
+    Ok(result)
+}
+
 #[pyfunction]
 #[pyo3(name = "imply_vol")]
 pub fn py_imply_vol(
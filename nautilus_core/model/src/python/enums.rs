@@ -574,6 +574,18 @@ impl BarAggregation {
     fn py_month() -> Self {
         Self::Month
     }
+
+    #[classattr]
+    #[pyo3(name = "RENKO")]
+    fn py_renko() -> Self {
+        Self::Renko
+    }
+
+    #[classattr]
+    #[pyo3(name = "RANGE")]
+    fn py_range() -> Self {
+        Self::Range
+    }
 }
 
 #[pymethods]
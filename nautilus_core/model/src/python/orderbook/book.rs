@@ -107,6 +107,12 @@ impl OrderBook {
         self.delete(order, flags, sequence, ts_event.into());
     }
 
+    #[pyo3(signature = (order_id, sequence, ts_event))]
+    #[pyo3(name = "remove_order")]
+    fn py_remove_order(&mut self, order_id: u64, sequence: u64, ts_event: u64) -> bool {
+        self.remove_order(order_id, sequence, ts_event.into())
+    }
+
     #[pyo3(signature = (sequence, ts_event))]
     #[pyo3(name = "clear")]
     fn py_clear(&mut self, sequence: u64, ts_event: u64) {
@@ -145,6 +151,11 @@ impl OrderBook {
         book_check_integrity(self).map_err(to_pyruntime_err)
     }
 
+    #[pyo3(name = "set_sequence_checks")]
+    fn py_set_sequence_checks(&mut self, enabled: bool) {
+        self.set_sequence_checks(enabled);
+    }
+
     #[pyo3(signature = (depth=None))]
     #[pyo3(name = "bids")]
     fn py_bids(&self, depth: Option<usize>) -> Vec<BookLevel> {
@@ -193,6 +204,26 @@ impl OrderBook {
         self.group_asks(group_size, depth)
     }
 
+    #[pyo3(name = "get_order")]
+    fn py_get_order(&self, order_id: u64) -> Option<BookOrder> {
+        self.get_order(order_id).copied()
+    }
+
+    #[pyo3(name = "orders_at_price")]
+    fn py_orders_at_price(&self, side: OrderSide, price: Price) -> Vec<BookOrder> {
+        self.orders_at_price(side, price)
+    }
+
+    #[pyo3(name = "queue_position")]
+    fn py_queue_position(&self, order_id: u64) -> Option<usize> {
+        self.queue_position(order_id)
+    }
+
+    #[pyo3(name = "size_ahead")]
+    fn py_size_ahead(&self, order_id: u64) -> Option<Quantity> {
+        self.size_ahead(order_id)
+    }
+
     #[pyo3(name = "best_bid_price")]
     fn py_best_bid_price(&self) -> Option<Price> {
         self.best_bid_price()
@@ -242,6 +273,42 @@ impl OrderBook {
         self.get_quantity_for_price(price, order_side)
     }
 
+    #[pyo3(name = "imbalance")]
+    #[pyo3(signature = (depth=None))]
+    fn py_imbalance(&self, depth: Option<usize>) -> Option<f64> {
+        self.imbalance(depth)
+    }
+
+    #[pyo3(name = "volume_weighted_midpoint")]
+    #[pyo3(signature = (depth=None))]
+    fn py_volume_weighted_midpoint(&self, depth: Option<usize>) -> Option<f64> {
+        self.volume_weighted_midpoint(depth)
+    }
+
+    #[pyo3(name = "cumulative_depth")]
+    fn py_cumulative_depth(&self, side: OrderSide, offsets: Vec<f64>) -> Vec<f64> {
+        self.cumulative_depth(side, &offsets)
+    }
+
+    /// Returns (filled_qty, avg_px, worst_px, notional, slippage, slippage_bps).
+    #[pyo3(name = "sweep_cost")]
+    fn py_sweep_cost(
+        &self,
+        qty: Quantity,
+        order_side: OrderSide,
+    ) -> Option<(f64, f64, f64, f64, f64, f64)> {
+        self.sweep_cost(qty, order_side).map(|cost| {
+            (
+                cost.filled_qty,
+                cost.avg_px,
+                cost.worst_px,
+                cost.notional,
+                cost.slippage,
+                cost.slippage_bps,
+            )
+        })
+    }
+
     #[pyo3(name = "simulate_fills")]
     fn py_simulate_fills(&self, order: &BookOrder) -> Vec<(Price, Quantity)> {
         self.simulate_fills(order)
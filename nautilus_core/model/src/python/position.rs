@@ -226,6 +226,12 @@ impl Position {
         self.realized_pnl
     }
 
+    #[getter]
+    #[pyo3(name = "funding_payments")]
+    fn py_funding_payments(&self) -> Option<Money> {
+        self.funding_payments
+    }
+
     #[getter]
     #[pyo3(name = "events")]
     fn py_events(&self) -> Vec<OrderFilled> {
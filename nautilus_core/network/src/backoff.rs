@@ -0,0 +1,212 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides an exponential backoff mechanism with jitter for reconnection attempts.
+
+use std::time::Duration;
+
+use nautilus_core::correctness::{check_in_range_inclusive_f64, check_predicate_true};
+use rand::Rng;
+
+const DEFAULT_DELAY_INITIAL_MS: u64 = 1_000;
+const DEFAULT_DELAY_MAX_MS: u64 = 30_000;
+const DEFAULT_FACTOR: f64 = 2.0;
+const DEFAULT_JITTER_MS: u64 = 250;
+
+/// An exponential backoff with an upper bound and random jitter.
+///
+/// Each call to [`ExponentialBackoff::next_duration`] returns the current delay plus a
+/// random jitter in `[0, jitter_ms]`, then grows the delay by `factor` up to `delay_max`.
+#[derive(Clone, Debug)]
+pub struct ExponentialBackoff {
+    delay_initial: Duration,
+    delay_max: Duration,
+    delay_current: Duration,
+    factor: f64,
+    jitter_ms: u64,
+}
+
+impl ExponentialBackoff {
+    /// Creates a new [`ExponentialBackoff`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `delay_initial` is zero.
+    /// - If `delay_initial` is greater than `delay_max`.
+    /// - If `factor` is not in the range [1.0, 100.0].
+    pub fn new(
+        delay_initial: Duration,
+        delay_max: Duration,
+        factor: f64,
+        jitter_ms: u64,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(!delay_initial.is_zero(), "`delay_initial` must be non-zero")?;
+        check_predicate_true(
+            delay_initial <= delay_max,
+            "`delay_initial` must not exceed `delay_max`",
+        )?;
+        check_in_range_inclusive_f64(factor, 1.0, 100.0, "factor")?;
+
+        Ok(Self {
+            delay_initial,
+            delay_max,
+            delay_current: delay_initial,
+            factor,
+            jitter_ms,
+        })
+    }
+
+    /// Creates a new [`ExponentialBackoff`] instance from optional parameters (milliseconds).
+    ///
+    /// Unset parameters take their defaults, and the defaults are used throughout
+    /// if the given parameters are invalid.
+    #[must_use]
+    pub fn from_optional(
+        delay_initial_ms: Option<u64>,
+        delay_max_ms: Option<u64>,
+        factor: Option<f64>,
+        jitter_ms: Option<u64>,
+    ) -> Self {
+        Self::new(
+            Duration::from_millis(delay_initial_ms.unwrap_or(DEFAULT_DELAY_INITIAL_MS)),
+            Duration::from_millis(delay_max_ms.unwrap_or(DEFAULT_DELAY_MAX_MS)),
+            factor.unwrap_or(DEFAULT_FACTOR),
+            jitter_ms.unwrap_or(DEFAULT_JITTER_MS),
+        )
+        .unwrap_or_else(|e| {
+            tracing::warn!("Invalid backoff parameters ({e}), using defaults");
+            Self::default()
+        })
+    }
+
+    /// Returns the next delay to wait, including jitter, and advances the backoff.
+    pub fn next_duration(&mut self) -> Duration {
+        let jitter = if self.jitter_ms > 0 {
+            rand::thread_rng().gen_range(0..=self.jitter_ms)
+        } else {
+            0
+        };
+        let delay = self.delay_current + Duration::from_millis(jitter);
+
+        let next_ms = (self.delay_current.as_millis() as f64 * self.factor) as u64;
+        self.delay_current = Duration::from_millis(next_ms).min(self.delay_max);
+
+        delay
+    }
+
+    /// Resets the backoff to its initial delay.
+    pub fn reset(&mut self) {
+        self.delay_current = self.delay_initial;
+    }
+
+    /// Returns the current delay (excluding jitter).
+    #[must_use]
+    pub const fn current_delay(&self) -> Duration {
+        self.delay_current
+    }
+}
+
+impl Default for ExponentialBackoff {
+    /// Creates a new default [`ExponentialBackoff`] instance.
+    fn default() -> Self {
+        Self::new(
+            Duration::from_millis(DEFAULT_DELAY_INITIAL_MS),
+            Duration::from_millis(DEFAULT_DELAY_MAX_MS),
+            DEFAULT_FACTOR,
+            DEFAULT_JITTER_MS,
+        )
+        .expect("Default backoff parameters should be valid")
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_next_duration_grows_exponentially_up_to_max() {
+        let mut backoff = ExponentialBackoff::new(
+            Duration::from_millis(100),
+            Duration::from_millis(500),
+            2.0,
+            0,
+        )
+        .unwrap();
+
+        let delays: Vec<u128> = (0..5)
+            .map(|_| backoff.next_duration().as_millis())
+            .collect();
+
+        assert_eq!(delays, vec![100, 200, 400, 500, 500]);
+    }
+
+    #[rstest]
+    fn test_next_duration_applies_bounded_jitter() {
+        let mut backoff =
+            ExponentialBackoff::new(Duration::from_millis(100), Duration::from_secs(1), 1.0, 50)
+                .unwrap();
+
+        for _ in 0..100 {
+            let delay = backoff.next_duration();
+            assert!(delay >= Duration::from_millis(100));
+            assert!(delay <= Duration::from_millis(150));
+        }
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut backoff = ExponentialBackoff::new(
+            Duration::from_millis(100),
+            Duration::from_millis(500),
+            2.0,
+            0,
+        )
+        .unwrap();
+        backoff.next_duration();
+        backoff.next_duration();
+
+        backoff.reset();
+
+        assert_eq!(backoff.current_delay(), Duration::from_millis(100));
+    }
+
+    #[rstest]
+    fn test_from_optional_with_invalid_params_uses_defaults() {
+        let backoff = ExponentialBackoff::from_optional(Some(5_000), Some(1_000), None, None);
+
+        assert_eq!(
+            backoff.current_delay(),
+            Duration::from_millis(DEFAULT_DELAY_INITIAL_MS)
+        );
+    }
+
+    #[rstest]
+    #[case(Duration::ZERO, Duration::from_secs(1), 2.0)]
+    #[case(Duration::from_secs(2), Duration::from_secs(1), 2.0)]
+    #[case(Duration::from_secs(1), Duration::from_secs(2), 0.5)]
+    fn test_new_with_invalid_args(
+        #[case] delay_initial: Duration,
+        #[case] delay_max: Duration,
+        #[case] factor: f64,
+    ) {
+        assert!(ExponentialBackoff::new(delay_initial, delay_max, factor, 0).is_err());
+    }
+}
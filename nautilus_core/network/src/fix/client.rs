@@ -0,0 +1,404 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A FIX initiator client which runs a [`FixSession`] over a TCP (optionally TLS) connection.
+
+use std::time::Duration;
+
+use bytes::BytesMut;
+use nautilus_core::{nanos::UnixNanos, time::get_atomic_clock_realtime};
+use nautilus_cryptography::providers::install_cryptographic_provider;
+use tokio::{
+    io::{split, AsyncReadExt, AsyncWriteExt, ReadHalf, WriteHalf},
+    net::TcpStream,
+    sync::{mpsc, oneshot},
+    task,
+};
+use tokio_tungstenite::{
+    tungstenite::{client::IntoClientRequest, stream::Mode},
+    MaybeTlsStream,
+};
+
+use super::{
+    message::{split_frame, FixMessage},
+    session::{FixSession, FixSessionConfig, FixSessionEvent},
+    store::FixMessageStore,
+};
+use crate::tls::tcp_tls;
+
+type FixReader = ReadHalf<MaybeTlsStream<TcpStream>>;
+type FixWriter = WriteHalf<MaybeTlsStream<TcpStream>>;
+
+/// The interval at which the session timer is driven.
+const TIMER_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The configuration for a [`FixClient`].
+#[derive(Clone, Debug)]
+pub struct FixClientConfig {
+    /// The `host:port` address of the FIX acceptor.
+    pub url: String,
+    /// The connection mode {Plain, TLS}.
+    pub mode: Mode,
+    /// The FIX session configuration.
+    pub session: FixSessionConfig,
+    /// The maximum time to wait for the logon response (seconds).
+    pub logon_timeout_secs: u64,
+}
+
+enum FixCommand {
+    Send(FixMessage, oneshot::Sender<anyhow::Result<()>>),
+    Logout(Option<String>),
+}
+
+/// Provides a FIX initiator client.
+///
+/// The connection is serviced by a background task which drives the [`FixSession`] from
+/// socket reads, a one second timer and commands from the client. All session events other
+/// than outgoing messages are forwarded on the channel returned from [`FixClient::connect`].
+#[derive(Debug)]
+pub struct FixClient {
+    cmd_tx: mpsc::UnboundedSender<FixCommand>,
+    task: task::JoinHandle<()>,
+}
+
+impl std::fmt::Debug for FixCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Send(message, _) => write!(f, "Send({message})"),
+            Self::Logout(text) => write!(f, "Logout({text:?})"),
+        }
+    }
+}
+
+impl FixClient {
+    /// Connects to the acceptor and logs on, returning the client and its event receiver.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the connection cannot be established.
+    /// - If the logon is rejected or not answered within the logon timeout.
+    pub async fn connect<S: FixMessageStore + 'static>(
+        config: FixClientConfig,
+        store: S,
+    ) -> anyhow::Result<(Self, mpsc::UnboundedReceiver<FixSessionEvent>)> {
+        let (reader, mut writer) = Self::connect_stream(&config.url, config.mode).await?;
+
+        let mut session = FixSession::new(config.session, store);
+        for event in session.logon(now())? {
+            if let FixSessionEvent::Send(bytes) = event {
+                writer.write_all(&bytes).await?;
+            }
+        }
+
+        let (cmd_tx, cmd_rx) = mpsc::unbounded_channel();
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let task = task::spawn(run_session(session, reader, writer, cmd_rx, event_tx));
+
+        let logon_timeout = Duration::from_secs(config.logon_timeout_secs);
+        let logged_on = tokio::time::timeout(logon_timeout, async {
+            while let Some(event) = event_rx.recv().await {
+                match event {
+                    FixSessionEvent::LoggedOn => return Ok(()),
+                    FixSessionEvent::Disconnect(reason) => {
+                        anyhow::bail!("FIX logon failed: {reason}")
+                    }
+                    event => tracing::debug!("Received {event:?} during FIX logon"),
+                }
+            }
+            anyhow::bail!("FIX session closed during logon")
+        })
+        .await;
+
+        match logged_on {
+            Ok(Ok(())) => Ok((Self { cmd_tx, task }, event_rx)),
+            Ok(Err(e)) => {
+                task.abort();
+                Err(e)
+            }
+            Err(_) => {
+                task.abort();
+                anyhow::bail!("FIX logon timed out after {logon_timeout:?}")
+            }
+        }
+    }
+
+    async fn connect_stream(url: &str, mode: Mode) -> anyhow::Result<(FixReader, FixWriter)> {
+        tracing::debug!("Connecting to FIX acceptor {url}");
+        let stream = TcpStream::connect(url).await?;
+        let stream = match mode {
+            Mode::Plain => MaybeTlsStream::Plain(stream),
+            Mode::Tls => {
+                install_cryptographic_provider();
+                let request = url.into_client_request()?;
+                tcp_tls(&request, mode, stream, None).await?
+            }
+        };
+        Ok(split(stream))
+    }
+
+    /// Sends the given application `message` on the session.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the session task has stopped.
+    /// - If the session rejects the message (e.g. when not logged on).
+    pub async fn send(&self, message: FixMessage) -> anyhow::Result<()> {
+        let (tx, rx) = oneshot::channel();
+        self.cmd_tx
+            .send(FixCommand::Send(message, tx))
+            .map_err(|_| anyhow::anyhow!("FIX session task has stopped"))?;
+        rx.await?
+    }
+
+    /// Initiates a logout of the session, after which the connection is closed.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the session task has stopped.
+    pub fn logout(&self, text: Option<&str>) -> anyhow::Result<()> {
+        self.cmd_tx
+            .send(FixCommand::Logout(text.map(ToString::to_string)))
+            .map_err(|_| anyhow::anyhow!("FIX session task has stopped"))
+    }
+
+    /// Returns whether the connection has been closed.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+impl Drop for FixClient {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn now() -> UnixNanos {
+    get_atomic_clock_realtime().get_time_ns()
+}
+
+async fn run_session<S: FixMessageStore>(
+    mut session: FixSession<S>,
+    mut reader: FixReader,
+    mut writer: FixWriter,
+    mut cmd_rx: mpsc::UnboundedReceiver<FixCommand>,
+    event_tx: mpsc::UnboundedSender<FixSessionEvent>,
+) {
+    let mut buf = BytesMut::with_capacity(8192);
+    let mut timer = tokio::time::interval(TIMER_INTERVAL);
+
+    loop {
+        let result = tokio::select! {
+            read = reader.read_buf(&mut buf) => match read {
+                Ok(0) => Err(anyhow::anyhow!("Connection closed by acceptor")),
+                Ok(_) => on_read(&mut session, &mut buf),
+                Err(e) => Err(e.into()),
+            },
+            _ = timer.tick() => session.on_timer(now()),
+            Some(cmd) = cmd_rx.recv() => match cmd {
+                FixCommand::Send(message, tx) => {
+                    let result = session.send(&message, now());
+                    match result {
+                        Ok(bytes) => {
+                            let _ = tx.send(Ok(()));
+                            Ok(vec![FixSessionEvent::Send(bytes)])
+                        }
+                        Err(e) => {
+                            let _ = tx.send(Err(e));
+                            Ok(Vec::new())
+                        }
+                    }
+                }
+                FixCommand::Logout(text) => session.logout(text.as_deref(), now()),
+            },
+        };
+
+        let events = match result {
+            Ok(events) => events,
+            Err(e) => vec![FixSessionEvent::Disconnect(e.to_string())],
+        };
+
+        let mut disconnect = false;
+        for event in events {
+            match event {
+                FixSessionEvent::Send(bytes) => {
+                    if let Err(e) = writer.write_all(&bytes).await {
+                        tracing::error!("Failed to write FIX message: {e}");
+                        disconnect = true;
+                        let _ = event_tx.send(FixSessionEvent::Disconnect(e.to_string()));
+                    }
+                }
+                FixSessionEvent::Disconnect(reason) => {
+                    disconnect = true;
+                    let _ = event_tx.send(FixSessionEvent::Disconnect(reason));
+                }
+                event => {
+                    let _ = event_tx.send(event);
+                }
+            }
+        }
+
+        if disconnect {
+            break;
+        }
+    }
+
+    session.on_disconnected(now());
+    if let Err(e) = writer.shutdown().await {
+        tracing::debug!("Error shutting down FIX connection: {e}");
+    }
+}
+
+fn on_read<S: FixMessageStore>(
+    session: &mut FixSession<S>,
+    buf: &mut BytesMut,
+) -> anyhow::Result<Vec<FixSessionEvent>> {
+    let mut events = Vec::new();
+    loop {
+        match split_frame(buf) {
+            Ok(Some(frame)) => match FixMessage::decode(&frame) {
+                Ok(message) => {
+                    tracing::trace!("Received FIX message {message}");
+                    events.extend(session.on_message(message, now())?);
+                }
+                // Garbled messages are ignored, the gap is recovered by a resend request
+                Err(e) => tracing::warn!("Ignoring garbled FIX message: {e}"),
+            },
+            Ok(None) => break,
+            Err(e) => tracing::warn!("Discarding invalid FIX frame: {e}"),
+        }
+    }
+    Ok(events)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::fix::{
+        store::MemoryMessageStore,
+        tags::{msg_type, CL_ORD_ID, MSG_SEQ_NUM, SENDER_COMP_ID, TARGET_COMP_ID, TEXT},
+    };
+
+    async fn read_message(stream: &mut TcpStream, buf: &mut BytesMut) -> FixMessage {
+        loop {
+            if let Some(frame) = split_frame(buf).unwrap() {
+                return FixMessage::decode(&frame).unwrap();
+            }
+            stream.read_buf(buf).await.unwrap();
+        }
+    }
+
+    fn venue_message(msg_type: &str, seq_num: u64) -> Vec<u8> {
+        FixMessage::new(msg_type)
+            .with(SENDER_COMP_ID, "VENUE")
+            .with(TARGET_COMP_ID, "CLIENT")
+            .with(MSG_SEQ_NUM, seq_num)
+            .encode("FIX.4.4")
+    }
+
+    fn config(url: String) -> FixClientConfig {
+        FixClientConfig {
+            url,
+            mode: Mode::Plain,
+            session: FixSessionConfig::new("CLIENT", "VENUE"),
+            logon_timeout_secs: 5,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connect_send_and_receive() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = listener.local_addr().unwrap().to_string();
+
+        let venue = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = BytesMut::new();
+
+            let logon = read_message(&mut stream, &mut buf).await;
+            assert_eq!(logon.msg_type(), msg_type::LOGON);
+            stream
+                .write_all(&venue_message(msg_type::LOGON, 1))
+                .await
+                .unwrap();
+
+            let order = read_message(&mut stream, &mut buf).await;
+            assert_eq!(order.get(CL_ORD_ID), Some("O-1"));
+            assert_eq!(order.seq_num().unwrap(), 2);
+            stream
+                .write_all(&venue_message(msg_type::EXECUTION_REPORT, 2))
+                .await
+                .unwrap();
+
+            let logout = read_message(&mut stream, &mut buf).await;
+            assert_eq!(logout.msg_type(), msg_type::LOGOUT);
+            let response = FixMessage::new(msg_type::LOGOUT)
+                .with(SENDER_COMP_ID, "VENUE")
+                .with(TARGET_COMP_ID, "CLIENT")
+                .with(MSG_SEQ_NUM, 3)
+                .with(TEXT, "Bye");
+            stream.write_all(&response.encode("FIX.4.4")).await.unwrap();
+        });
+
+        let (client, mut events) = FixClient::connect(config(url), MemoryMessageStore::new())
+            .await
+            .unwrap();
+        client
+            .send(FixMessage::new(msg_type::NEW_ORDER_SINGLE).with(CL_ORD_ID, "O-1"))
+            .await
+            .unwrap();
+
+        match events.recv().await.unwrap() {
+            FixSessionEvent::Application(message) => {
+                assert_eq!(message.msg_type(), msg_type::EXECUTION_REPORT);
+            }
+            event => panic!("Unexpected event {event:?}"),
+        }
+
+        client.logout(None).unwrap();
+        assert_eq!(
+            events.recv().await.unwrap(),
+            FixSessionEvent::LoggedOut(Some("Bye".to_string()))
+        );
+        venue.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_connect_logon_rejected() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = listener.local_addr().unwrap().to_string();
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = BytesMut::new();
+            read_message(&mut stream, &mut buf).await;
+            stream
+                .write_all(&venue_message(msg_type::LOGOUT, 1))
+                .await
+                .unwrap();
+        });
+
+        let result = FixClient::connect(config(url), MemoryMessageStore::new()).await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,533 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The FIX tag-value message representation and codec.
+
+use std::{fmt::Display, str::FromStr};
+
+use bytes::{Buf, BytesMut};
+use chrono::{DateTime, NaiveDateTime};
+use nautilus_core::nanos::UnixNanos;
+
+use super::tags::{BEGIN_STRING, BODY_LENGTH, CHECKSUM, MSG_SEQ_NUM, MSG_TYPE, POSS_DUP_FLAG};
+
+/// The FIX field delimiter (Start of Heading).
+pub const SOH: u8 = 0x01;
+
+/// The length of the trailing checksum field `10=NNN<SOH>`.
+const CHECKSUM_FIELD_LEN: usize = 7;
+
+const UTC_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H:%M:%S%.3f";
+const UTC_TIMESTAMP_PARSE_FORMAT: &str = "%Y%m%d-%H:%M:%S%.f";
+
+/// Represents errors that can occur when encoding, decoding or handling FIX messages.
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum FixError {
+    #[error("Malformed FIX message: {0}")]
+    Malformed(String),
+
+    #[error("Invalid FIX body length: declared {declared}, actual {actual}")]
+    BodyLength { declared: usize, actual: usize },
+
+    #[error("Invalid FIX checksum: declared {declared}, computed {computed}")]
+    Checksum { declared: u8, computed: u8 },
+
+    #[error("Missing required FIX tag {0}")]
+    MissingTag(u32),
+
+    #[error("Invalid value for FIX tag {tag}: '{value}'")]
+    InvalidValue { tag: u32, value: String },
+}
+
+/// Represents a single FIX message as its `MsgType` and an ordered list of tag-value fields.
+///
+/// The `BeginString`, `BodyLength`, `MsgType` and `CheckSum` fields are not held in the field
+/// list, these are written and validated by the codec.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixMessage {
+    msg_type: String,
+    fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    /// Creates a new [`FixMessage`] instance.
+    #[must_use]
+    pub fn new(msg_type: &str) -> Self {
+        Self {
+            msg_type: msg_type.to_string(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Returns the message with the given field appended.
+    #[must_use]
+    pub fn with(mut self, tag: u32, value: impl Display) -> Self {
+        self.push(tag, value);
+        self
+    }
+
+    /// Appends the given field, retaining any existing fields with the same tag.
+    pub fn push(&mut self, tag: u32, value: impl Display) {
+        self.fields.push((tag, value.to_string()));
+    }
+
+    /// Sets the value of the first field with the given tag, appending it if absent.
+    pub fn set(&mut self, tag: u32, value: impl Display) {
+        match self.fields.iter_mut().find(|(t, _)| *t == tag) {
+            Some(field) => field.1 = value.to_string(),
+            None => self.push(tag, value),
+        }
+    }
+
+    /// Removes all fields with the given tag.
+    pub fn remove(&mut self, tag: u32) {
+        self.fields.retain(|(t, _)| *t != tag);
+    }
+
+    #[must_use]
+    pub fn msg_type(&self) -> &str {
+        &self.msg_type
+    }
+
+    #[must_use]
+    pub fn fields(&self) -> &[(u32, String)] {
+        &self.fields
+    }
+
+    /// Returns the value of the first field with the given tag (if found).
+    #[must_use]
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(t, _)| *t == tag)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Returns the value of the first field with the given tag.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the tag is not present.
+    pub fn require(&self, tag: u32) -> Result<&str, FixError> {
+        self.get(tag).ok_or(FixError::MissingTag(tag))
+    }
+
+    /// Returns the parsed value of the first field with the given tag (if found).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the value cannot be parsed as `T`.
+    pub fn get_parsed<T: FromStr>(&self, tag: u32) -> Result<Option<T>, FixError> {
+        self.get(tag)
+            .map(|value| {
+                value.parse().map_err(|_| FixError::InvalidValue {
+                    tag,
+                    value: value.to_string(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Returns the parsed value of the first field with the given tag.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the tag is not present.
+    /// - If the value cannot be parsed as `T`.
+    pub fn require_parsed<T: FromStr>(&self, tag: u32) -> Result<T, FixError> {
+        self.get_parsed(tag)?.ok_or(FixError::MissingTag(tag))
+    }
+
+    /// Returns the `MsgSeqNum` (34) of the message.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the sequence number is missing or invalid.
+    pub fn seq_num(&self) -> Result<u64, FixError> {
+        self.require_parsed(MSG_SEQ_NUM)
+    }
+
+    /// Returns whether the `PossDupFlag` (43) of the message is set.
+    #[must_use]
+    pub fn is_poss_dup(&self) -> bool {
+        self.get(POSS_DUP_FLAG) == Some("Y")
+    }
+
+    /// Encodes the message to its wire format with the given `begin_string`.
+    #[must_use]
+    pub fn encode(&self, begin_string: &str) -> Vec<u8> {
+        let mut body = Vec::with_capacity(64 + self.fields.len() * 16);
+        write_field(&mut body, MSG_TYPE, &self.msg_type);
+        for (tag, value) in &self.fields {
+            write_field(&mut body, *tag, value);
+        }
+
+        let mut buf = Vec::with_capacity(body.len() + 32);
+        write_field(&mut buf, BEGIN_STRING, begin_string);
+        write_field(&mut buf, BODY_LENGTH, &body.len().to_string());
+        buf.extend_from_slice(&body);
+
+        let checksum = checksum(&buf);
+        write_field(&mut buf, CHECKSUM, &format!("{checksum:03}"));
+        buf
+    }
+
+    /// Decodes a single complete message from its wire format.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the message does not start with `BeginString`, `BodyLength` and `MsgType`.
+    /// - If the declared body length does not match the actual body length.
+    /// - If the declared checksum does not match the computed checksum.
+    pub fn decode(bytes: &[u8]) -> Result<Self, FixError> {
+        let raw = std::str::from_utf8(bytes)
+            .map_err(|e| FixError::Malformed(format!("Invalid UTF-8: {e}")))?;
+        if !raw.ends_with(SOH as char) {
+            return Err(FixError::Malformed(
+                "missing trailing delimiter".to_string(),
+            ));
+        }
+
+        let mut fields = Vec::new();
+        let mut offset = 0;
+        let mut body_start = 0;
+        let mut checksum_start = None;
+        for field in raw[..raw.len() - 1].split(SOH as char) {
+            let (tag, value) = field
+                .split_once('=')
+                .ok_or_else(|| FixError::Malformed(format!("invalid field '{field}'")))?;
+            let tag: u32 = tag
+                .parse()
+                .map_err(|_| FixError::Malformed(format!("invalid tag '{tag}'")))?;
+
+            if tag == BODY_LENGTH {
+                body_start = offset + field.len() + 1;
+            }
+            if tag == CHECKSUM {
+                checksum_start = Some(offset);
+            }
+            offset += field.len() + 1;
+            fields.push((tag, value.to_string()));
+        }
+
+        if fields.len() < 4
+            || fields[0].0 != BEGIN_STRING
+            || fields[1].0 != BODY_LENGTH
+            || fields[2].0 != MSG_TYPE
+        {
+            return Err(FixError::Malformed(
+                "message must start with tags 8, 9 and 35".to_string(),
+            ));
+        }
+
+        let checksum_start = match checksum_start {
+            Some(start) if fields.last().map(|(t, _)| *t) == Some(CHECKSUM) => start,
+            _ => {
+                return Err(FixError::Malformed(
+                    "message must end with tag 10".to_string(),
+                ))
+            }
+        };
+
+        let declared: usize = fields[1].1.parse().map_err(|_| FixError::InvalidValue {
+            tag: BODY_LENGTH,
+            value: fields[1].1.clone(),
+        })?;
+        let actual = checksum_start - body_start;
+        if declared != actual {
+            return Err(FixError::BodyLength { declared, actual });
+        }
+
+        let (_, declared_checksum) = fields.pop().expect("checked above");
+        let declared: u8 = declared_checksum
+            .parse()
+            .map_err(|_| FixError::InvalidValue {
+                tag: CHECKSUM,
+                value: declared_checksum.clone(),
+            })?;
+        let computed = checksum(&bytes[..checksum_start]);
+        if declared != computed {
+            return Err(FixError::Checksum { declared, computed });
+        }
+
+        let mut fields = fields.split_off(2);
+        let (_, msg_type) = fields.remove(0);
+
+        Ok(Self { msg_type, fields })
+    }
+}
+
+impl Display for FixMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{MSG_TYPE}={}", self.msg_type)?;
+        for (tag, value) in &self.fields {
+            write!(f, "|{tag}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+fn write_field(buf: &mut Vec<u8>, tag: u32, value: &str) {
+    buf.extend_from_slice(tag.to_string().as_bytes());
+    buf.push(b'=');
+    buf.extend_from_slice(value.as_bytes());
+    buf.push(SOH);
+}
+
+/// Computes the FIX checksum of the given bytes (the byte sum modulo 256).
+#[must_use]
+pub fn checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |acc, b| acc.wrapping_add(*b))
+}
+
+/// Splits the next complete message frame from the front of the given buffer.
+///
+/// Any leading bytes which are not the start of a message are discarded. Returns `None` if the
+/// buffer does not yet hold a complete frame.
+///
+/// # Errors
+///
+/// This function returns an error if the `BodyLength` of the next frame is invalid.
+pub fn split_frame(buf: &mut BytesMut) -> Result<Option<BytesMut>, FixError> {
+    let prefix = [b'8', b'='];
+    match buf.windows(2).position(|w| w == prefix) {
+        Some(0) => {}
+        Some(start) => {
+            buf.advance(start);
+        }
+        None => {
+            // Retain a possible partial prefix at the end of the buffer
+            let keep = usize::from(buf.last() == Some(&b'8'));
+            buf.advance(buf.len() - keep);
+            return Ok(None);
+        }
+    }
+
+    // Locate the end of the `BeginString` and `BodyLength` fields
+    let Some(begin_end) = buf.iter().position(|b| *b == SOH) else {
+        return Ok(None);
+    };
+    let Some(length_end) = buf[begin_end + 1..]
+        .iter()
+        .position(|b| *b == SOH)
+        .map(|i| begin_end + 1 + i)
+    else {
+        return Ok(None);
+    };
+
+    let length_field = &buf[begin_end + 1..length_end];
+    let body_length = std::str::from_utf8(length_field)
+        .ok()
+        .and_then(|field| field.strip_prefix("9="))
+        .and_then(|value| value.parse::<usize>().ok());
+    let Some(body_length) = body_length else {
+        let value = String::from_utf8_lossy(length_field).to_string();
+        // Discard the invalid prefix so the stream can resynchronize on the next message
+        buf.advance(length_end + 1);
+        return Err(FixError::InvalidValue {
+            tag: BODY_LENGTH,
+            value,
+        });
+    };
+
+    let frame_len = length_end + 1 + body_length + CHECKSUM_FIELD_LEN;
+    if buf.len() < frame_len {
+        return Ok(None);
+    }
+
+    Ok(Some(buf.split_to(frame_len)))
+}
+
+/// Formats the given UNIX nanoseconds as a FIX `UTCTimestamp` with millisecond precision.
+#[must_use]
+pub fn format_utc_timestamp(timestamp: UnixNanos) -> String {
+    DateTime::from_timestamp_nanos(timestamp.as_i64())
+        .format(UTC_TIMESTAMP_FORMAT)
+        .to_string()
+}
+
+/// Parses a FIX `UTCTimestamp` (with optional fractional seconds) to UNIX nanoseconds.
+///
+/// # Errors
+///
+/// This function returns an error if the value is not a valid `UTCTimestamp`.
+pub fn parse_utc_timestamp(tag: u32, value: &str) -> Result<UnixNanos, FixError> {
+    NaiveDateTime::parse_from_str(value, UTC_TIMESTAMP_PARSE_FORMAT)
+        .map(|dt| UnixNanos::from(dt.and_utc()))
+        .map_err(|_| FixError::InvalidValue {
+            tag,
+            value: value.to_string(),
+        })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::fix::tags::{SENDER_COMP_ID, SENDING_TIME, TARGET_COMP_ID, TEST_REQ_ID};
+
+    fn wire(raw: &str) -> Vec<u8> {
+        raw.replace('|', "\x01").into_bytes()
+    }
+
+    fn heartbeat() -> FixMessage {
+        FixMessage::new("0")
+            .with(SENDER_COMP_ID, "CLIENT")
+            .with(TARGET_COMP_ID, "VENUE")
+            .with(MSG_SEQ_NUM, 2)
+            .with(SENDING_TIME, "20240101-00:00:00.000")
+    }
+
+    #[rstest]
+    fn test_encode() {
+        let bytes = heartbeat().encode("FIX.4.4");
+
+        assert_eq!(
+            bytes,
+            wire("8=FIX.4.4|9=54|35=0|49=CLIENT|56=VENUE|34=2|52=20240101-00:00:00.000|10=242|")
+        );
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let message = heartbeat().with(TEST_REQ_ID, "TEST");
+
+        let decoded = FixMessage::decode(&message.encode("FIX.4.4")).unwrap();
+
+        assert_eq!(decoded, message);
+        assert_eq!(decoded.seq_num().unwrap(), 2);
+        assert_eq!(decoded.get(TEST_REQ_ID), Some("TEST"));
+    }
+
+    #[rstest]
+    fn test_decode_invalid_checksum() {
+        let bytes =
+            wire("8=FIX.4.4|9=54|35=0|49=CLIENT|56=VENUE|34=2|52=20240101-00:00:00.000|10=000|");
+
+        let result = FixMessage::decode(&bytes);
+
+        assert_eq!(
+            result,
+            Err(FixError::Checksum {
+                declared: 0,
+                computed: 242
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_decode_invalid_body_length() {
+        let bytes =
+            wire("8=FIX.4.4|9=50|35=0|49=CLIENT|56=VENUE|34=2|52=20240101-00:00:00.000|10=242|");
+
+        let result = FixMessage::decode(&bytes);
+
+        assert_eq!(
+            result,
+            Err(FixError::BodyLength {
+                declared: 50,
+                actual: 54
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_decode_missing_header() {
+        let result = FixMessage::decode(&wire("35=0|34=2|10=000|"));
+
+        assert!(matches!(result, Err(FixError::Malformed(_))));
+    }
+
+    #[rstest]
+    fn test_set_replaces_existing_field() {
+        let mut message = heartbeat();
+        message.set(MSG_SEQ_NUM, 5);
+        message.set(POSS_DUP_FLAG, "Y");
+
+        assert_eq!(message.seq_num().unwrap(), 5);
+        assert!(message.is_poss_dup());
+        assert_eq!(message.fields().len(), 5);
+    }
+
+    #[rstest]
+    fn test_require_parsed_invalid_value() {
+        let message = FixMessage::new("0").with(MSG_SEQ_NUM, "abc");
+
+        assert_eq!(
+            message.seq_num(),
+            Err(FixError::InvalidValue {
+                tag: MSG_SEQ_NUM,
+                value: "abc".to_string()
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_split_frame() {
+        let first = heartbeat().encode("FIX.4.4");
+        let second = heartbeat().with(TEST_REQ_ID, "1").encode("FIX.4.4");
+        let mut buf = BytesMut::from(&b"garbage"[..]);
+        buf.extend_from_slice(&first);
+        buf.extend_from_slice(&second[..10]);
+
+        let frame = split_frame(&mut buf).unwrap().unwrap();
+        assert_eq!(frame.as_ref(), first.as_slice());
+        assert!(split_frame(&mut buf).unwrap().is_none());
+
+        buf.extend_from_slice(&second[10..]);
+        let frame = split_frame(&mut buf).unwrap().unwrap();
+        assert_eq!(frame.as_ref(), second.as_slice());
+        assert!(buf.is_empty());
+    }
+
+    #[rstest]
+    fn test_split_frame_invalid_body_length() {
+        let mut buf = BytesMut::from(&wire("8=FIX.4.4|9=abc|35=0|")[..]);
+
+        let result = split_frame(&mut buf);
+
+        assert!(matches!(
+            result,
+            Err(FixError::InvalidValue {
+                tag: BODY_LENGTH,
+                ..
+            })
+        ));
+    }
+
+    #[rstest]
+    fn test_format_parse_utc_timestamp() {
+        let timestamp = UnixNanos::from(1_704_067_200_123_000_000);
+
+        let formatted = format_utc_timestamp(timestamp);
+
+        assert_eq!(formatted, "20240101-00:00:00.123");
+        assert_eq!(
+            parse_utc_timestamp(SENDING_TIME, &formatted).unwrap(),
+            timestamp
+        );
+        assert_eq!(
+            parse_utc_timestamp(SENDING_TIME, "20240101-00:00:00").unwrap(),
+            UnixNanos::from(1_704_067_200_000_000_000)
+        );
+        assert!(parse_utc_timestamp(SENDING_TIME, "2024-01-01").is_err());
+    }
+}
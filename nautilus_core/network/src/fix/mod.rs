@@ -0,0 +1,31 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A FIX 4.4 engine for connecting to venues which offer only a FIX API.
+//!
+//! The engine is composed of:
+//! - A tag-value codec ([`message`]).
+//! - A session layer handling logon, heartbeats, sequence numbers and resend requests
+//!   ([`session`]), backed by a persistent message store ([`store`]).
+//! - A tokio client which runs a session over TCP or TLS ([`client`]).
+//!
+//! The mapping of application messages to Nautilus execution commands and order events is
+//! provided by the `nautilus-fix` adapter crate.
+
+pub mod client;
+pub mod message;
+pub mod session;
+pub mod store;
+pub mod tags;
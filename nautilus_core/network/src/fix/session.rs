@@ -0,0 +1,915 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The FIX session layer, implemented as a state machine independent of any I/O.
+//!
+//! The [`FixSession`] is driven by passing it decoded inbound messages and periodic timer ticks
+//! (each with the current time), and returns the [`FixSessionEvent`]s to action, such as the
+//! encoded messages to write to the transport.
+
+use std::collections::BTreeMap;
+
+use nautilus_core::{credentials::Secret, datetime::NANOSECONDS_IN_SECOND, nanos::UnixNanos};
+
+use super::{
+    message::{format_utc_timestamp, FixMessage},
+    store::FixMessageStore,
+    tags::{
+        msg_type, BEGIN_SEQ_NO, ENCRYPT_METHOD, END_SEQ_NO, GAP_FILL_FLAG, HEART_BT_INT,
+        MSG_SEQ_NUM, NEW_SEQ_NO, ORIG_SENDING_TIME, PASSWORD, POSS_DUP_FLAG, RESET_SEQ_NUM_FLAG,
+        SENDER_COMP_ID, SENDING_TIME, STANDARD_HEADER_TAGS, TARGET_COMP_ID, TEST_REQ_ID, TEXT,
+        USERNAME,
+    },
+};
+
+/// The configuration for a FIX initiator session.
+#[derive(Clone, Debug)]
+pub struct FixSessionConfig {
+    /// The `BeginString` (8) of the session.
+    pub begin_string: String,
+    /// The `SenderCompID` (49) identifying this side of the session.
+    pub sender_comp_id: String,
+    /// The `TargetCompID` (56) identifying the counterparty.
+    pub target_comp_id: String,
+    /// The heartbeat interval (seconds) requested on logon.
+    pub heartbeat_interval_secs: u64,
+    /// If the sequence numbers should be reset to one on every logon.
+    pub reset_on_logon: bool,
+    /// The optional `Username` (553) sent on logon.
+    pub username: Option<String>,
+    /// The optional `Password` (554) sent on logon.
+    pub password: Option<Secret>,
+}
+
+impl FixSessionConfig {
+    /// Creates a new FIX 4.4 [`FixSessionConfig`] instance with a 30 second heartbeat interval.
+    #[must_use]
+    pub fn new(sender_comp_id: &str, target_comp_id: &str) -> Self {
+        Self {
+            begin_string: "FIX.4.4".to_string(),
+            sender_comp_id: sender_comp_id.to_string(),
+            target_comp_id: target_comp_id.to_string(),
+            heartbeat_interval_secs: 30,
+            reset_on_logon: false,
+            username: None,
+            password: None,
+        }
+    }
+
+    /// Returns the identifier of the session, suitable for naming its message store.
+    #[must_use]
+    pub fn session_id(&self) -> String {
+        format!(
+            "{}-{}-{}",
+            self.begin_string, self.sender_comp_id, self.target_comp_id
+        )
+    }
+}
+
+/// The state of a FIX session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FixSessionState {
+    /// The session is not logged on.
+    Disconnected,
+    /// A logon has been sent and the response is awaited.
+    LogonSent,
+    /// The session is logged on.
+    Active,
+    /// A logout has been sent and the response is awaited.
+    LogoutSent,
+}
+
+/// An action or notification produced by a [`FixSession`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FixSessionEvent {
+    /// The encoded message should be written to the transport.
+    Send(Vec<u8>),
+    /// The session was logged on by the counterparty.
+    LoggedOn,
+    /// An in-sequence application message was received.
+    Application(FixMessage),
+    /// A session-level reject was received for a previously sent message.
+    Reject(FixMessage),
+    /// The session was logged out, with the optional text from the counterparty.
+    LoggedOut(Option<String>),
+    /// The transport should be closed for the given reason.
+    Disconnect(String),
+}
+
+/// Provides a FIX initiator session layer over a [`FixMessageStore`].
+///
+/// Handles logon and logout, heartbeats and test requests, sequence number management, gap
+/// detection with resend requests, and servicing of resend requests from the counterparty by
+/// replaying stored application messages and gap-filling session messages.
+#[derive(Debug)]
+pub struct FixSession<S: FixMessageStore> {
+    config: FixSessionConfig,
+    store: S,
+    state: FixSessionState,
+    state_ts: UnixNanos,
+    last_sent: UnixNanos,
+    last_received: UnixNanos,
+    test_request_sent: Option<UnixNanos>,
+    test_request_count: u64,
+    resend_requested: Option<u64>,
+}
+
+impl<S: FixMessageStore> FixSession<S> {
+    /// Creates a new [`FixSession`] instance.
+    #[must_use]
+    pub fn new(config: FixSessionConfig, store: S) -> Self {
+        Self {
+            config,
+            store,
+            state: FixSessionState::Disconnected,
+            state_ts: UnixNanos::default(),
+            last_sent: UnixNanos::default(),
+            last_received: UnixNanos::default(),
+            test_request_sent: None,
+            test_request_count: 0,
+            resend_requested: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn config(&self) -> &FixSessionConfig {
+        &self.config
+    }
+
+    #[must_use]
+    pub const fn state(&self) -> FixSessionState {
+        self.state
+    }
+
+    #[must_use]
+    pub const fn store(&self) -> &S {
+        &self.store
+    }
+
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.state == FixSessionState::Active
+    }
+
+    /// Initiates the session by producing a `Logon` message.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the session is not disconnected.
+    /// - If the message store fails.
+    pub fn logon(&mut self, now: UnixNanos) -> anyhow::Result<Vec<FixSessionEvent>> {
+        if self.state != FixSessionState::Disconnected {
+            anyhow::bail!("Cannot logon from state {:?}", self.state);
+        }
+
+        if self.config.reset_on_logon {
+            self.store.reset()?;
+        }
+
+        let mut logon = FixMessage::new(msg_type::LOGON)
+            .with(ENCRYPT_METHOD, 0)
+            .with(HEART_BT_INT, self.config.heartbeat_interval_secs);
+        if self.config.reset_on_logon {
+            logon.push(RESET_SEQ_NUM_FLAG, "Y");
+        }
+        if let Some(username) = &self.config.username {
+            logon.push(USERNAME, username);
+        }
+        if let Some(password) = &self.config.password {
+            logon.push(PASSWORD, password.expose());
+        }
+
+        let mut events = Vec::new();
+        self.send_admin(logon, now, &mut events)?;
+        self.last_received = now;
+        self.test_request_sent = None;
+        self.resend_requested = None;
+        self.set_state(FixSessionState::LogonSent, now);
+        Ok(events)
+    }
+
+    /// Initiates the termination of the session by producing a `Logout` message.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the session is not active.
+    /// - If the message store fails.
+    pub fn logout(
+        &mut self,
+        text: Option<&str>,
+        now: UnixNanos,
+    ) -> anyhow::Result<Vec<FixSessionEvent>> {
+        if self.state != FixSessionState::Active {
+            anyhow::bail!("Cannot logout from state {:?}", self.state);
+        }
+
+        let mut logout = FixMessage::new(msg_type::LOGOUT);
+        if let Some(text) = text {
+            logout.push(TEXT, text);
+        }
+
+        let mut events = Vec::new();
+        self.send_admin(logout, now, &mut events)?;
+        self.set_state(FixSessionState::LogoutSent, now);
+        Ok(events)
+    }
+
+    /// Stamps the standard header on the given application `message`, stores it for any
+    /// subsequent resend request, and returns its encoded bytes.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the session is not active.
+    /// - If the message is a session-level message.
+    /// - If the message store fails.
+    pub fn send(&mut self, message: &FixMessage, now: UnixNanos) -> anyhow::Result<Vec<u8>> {
+        if self.state != FixSessionState::Active {
+            anyhow::bail!("Cannot send application message in state {:?}", self.state);
+        }
+        if msg_type::is_admin(message.msg_type()) {
+            anyhow::bail!(
+                "Cannot send session message type '{}' as application message",
+                message.msg_type()
+            );
+        }
+
+        let seq_num = self.store.next_sender_seq_num();
+        let bytes = self
+            .stamp(message, seq_num, now, None)
+            .encode(&self.config.begin_string);
+        self.store.store(seq_num, &bytes)?;
+        self.store.set_next_sender_seq_num(seq_num + 1)?;
+        self.last_sent = now;
+        Ok(bytes)
+    }
+
+    /// Notifies the session that the transport was closed.
+    pub fn on_disconnected(&mut self, now: UnixNanos) {
+        self.set_state(FixSessionState::Disconnected, now);
+    }
+
+    /// Handles the given decoded inbound `message`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the message store fails.
+    pub fn on_message(
+        &mut self,
+        message: FixMessage,
+        now: UnixNanos,
+    ) -> anyhow::Result<Vec<FixSessionEvent>> {
+        let mut events = Vec::new();
+        if self.state == FixSessionState::Disconnected {
+            tracing::warn!("Ignoring FIX message while disconnected: {message}");
+            return Ok(events);
+        }
+
+        self.last_received = now;
+        self.test_request_sent = None;
+
+        if message.get(SENDER_COMP_ID) != Some(self.config.target_comp_id.as_str())
+            || message.get(TARGET_COMP_ID) != Some(self.config.sender_comp_id.as_str())
+        {
+            self.terminate("CompID problem", now, &mut events)?;
+            return Ok(events);
+        }
+
+        let seq_num = match message.seq_num() {
+            Ok(seq_num) => seq_num,
+            Err(e) => {
+                self.terminate(&e.to_string(), now, &mut events)?;
+                return Ok(events);
+            }
+        };
+
+        // A `SequenceReset` in reset mode applies regardless of its sequence number
+        if message.msg_type() == msg_type::SEQUENCE_RESET && message.get(GAP_FILL_FLAG) != Some("Y")
+        {
+            self.on_sequence_reset(&message)?;
+            return Ok(events);
+        }
+
+        if message.msg_type() == msg_type::LOGON && message.get(RESET_SEQ_NUM_FLAG) == Some("Y") {
+            self.store.set_next_target_seq_num(seq_num)?;
+        }
+
+        let expected = self.store.next_target_seq_num();
+        if seq_num > expected {
+            match message.msg_type() {
+                msg_type::LOGON => self.on_logon(now, &mut events),
+                msg_type::LOGOUT => {
+                    self.on_logout(&message, now, &mut events)?;
+                    return Ok(events);
+                }
+                _ => {}
+            }
+
+            if self.resend_requested.is_none() {
+                tracing::warn!("FIX sequence gap: expected {expected}, received {seq_num}");
+                let resend = FixMessage::new(msg_type::RESEND_REQUEST)
+                    .with(BEGIN_SEQ_NO, expected)
+                    .with(END_SEQ_NO, 0);
+                self.send_admin(resend, now, &mut events)?;
+                self.resend_requested = Some(seq_num);
+            }
+            return Ok(events);
+        }
+
+        if seq_num < expected {
+            if !message.is_poss_dup() {
+                let reason =
+                    format!("MsgSeqNum too low, expected {expected} but received {seq_num}");
+                self.terminate(&reason, now, &mut events)?;
+            }
+            return Ok(events);
+        }
+
+        self.store.set_next_target_seq_num(expected + 1)?;
+        if self.resend_requested.is_some_and(|until| seq_num >= until) {
+            self.resend_requested = None;
+        }
+
+        match message.msg_type() {
+            msg_type::HEARTBEAT => {}
+            msg_type::TEST_REQUEST => {
+                let mut heartbeat = FixMessage::new(msg_type::HEARTBEAT);
+                if let Some(test_req_id) = message.get(TEST_REQ_ID) {
+                    heartbeat.push(TEST_REQ_ID, test_req_id);
+                }
+                self.send_admin(heartbeat, now, &mut events)?;
+            }
+            msg_type::RESEND_REQUEST => self.on_resend_request(&message, now, &mut events)?,
+            msg_type::REJECT => events.push(FixSessionEvent::Reject(message)),
+            msg_type::SEQUENCE_RESET => self.on_sequence_reset(&message)?,
+            msg_type::LOGON => self.on_logon(now, &mut events),
+            msg_type::LOGOUT => self.on_logout(&message, now, &mut events)?,
+            _ => {
+                if self.state == FixSessionState::Active {
+                    events.push(FixSessionEvent::Application(message));
+                } else {
+                    tracing::warn!(
+                        "Ignoring FIX application message in state {:?}: {message}",
+                        self.state
+                    );
+                }
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Handles a periodic timer tick, producing heartbeats and test requests and detecting
+    /// an unresponsive counterparty.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the message store fails.
+    pub fn on_timer(&mut self, now: UnixNanos) -> anyhow::Result<Vec<FixSessionEvent>> {
+        let mut events = Vec::new();
+        let interval = self.config.heartbeat_interval_secs * NANOSECONDS_IN_SECOND;
+
+        match self.state {
+            FixSessionState::Disconnected => {}
+            FixSessionState::LogonSent | FixSessionState::LogoutSent => {
+                if elapsed(self.state_ts, now) >= interval {
+                    let reason = format!("Timed out in state {:?}", self.state);
+                    self.set_state(FixSessionState::Disconnected, now);
+                    events.push(FixSessionEvent::Disconnect(reason));
+                }
+            }
+            FixSessionState::Active => {
+                if let Some(sent) = self.test_request_sent {
+                    if elapsed(sent, now) >= interval {
+                        self.set_state(FixSessionState::Disconnected, now);
+                        events.push(FixSessionEvent::Disconnect("Heartbeat timeout".to_string()));
+                        return Ok(events);
+                    }
+                } else if elapsed(self.last_received, now) >= interval + interval / 5 {
+                    self.test_request_count += 1;
+                    let test_request = FixMessage::new(msg_type::TEST_REQUEST)
+                        .with(TEST_REQ_ID, format!("TEST-{}", self.test_request_count));
+                    self.send_admin(test_request, now, &mut events)?;
+                    self.test_request_sent = Some(now);
+                }
+
+                if elapsed(self.last_sent, now) >= interval {
+                    self.send_admin(FixMessage::new(msg_type::HEARTBEAT), now, &mut events)?;
+                }
+            }
+        }
+
+        Ok(events)
+    }
+
+    fn set_state(&mut self, state: FixSessionState, now: UnixNanos) {
+        self.state = state;
+        self.state_ts = now;
+    }
+
+    fn stamp(
+        &self,
+        message: &FixMessage,
+        seq_num: u64,
+        now: UnixNanos,
+        orig_sending_time: Option<&str>,
+    ) -> FixMessage {
+        let mut stamped = FixMessage::new(message.msg_type())
+            .with(SENDER_COMP_ID, &self.config.sender_comp_id)
+            .with(TARGET_COMP_ID, &self.config.target_comp_id)
+            .with(MSG_SEQ_NUM, seq_num)
+            .with(SENDING_TIME, format_utc_timestamp(now));
+        if let Some(orig_sending_time) = orig_sending_time {
+            stamped.push(POSS_DUP_FLAG, "Y");
+            stamped.push(ORIG_SENDING_TIME, orig_sending_time);
+        }
+
+        for (tag, value) in message.fields() {
+            if !STANDARD_HEADER_TAGS.contains(tag) {
+                stamped.push(*tag, value);
+            }
+        }
+        stamped
+    }
+
+    fn send_admin(
+        &mut self,
+        message: FixMessage,
+        now: UnixNanos,
+        events: &mut Vec<FixSessionEvent>,
+    ) -> anyhow::Result<()> {
+        let seq_num = self.store.next_sender_seq_num();
+        let bytes = self
+            .stamp(&message, seq_num, now, None)
+            .encode(&self.config.begin_string);
+        self.store.set_next_sender_seq_num(seq_num + 1)?;
+        self.last_sent = now;
+        events.push(FixSessionEvent::Send(bytes));
+        Ok(())
+    }
+
+    fn terminate(
+        &mut self,
+        reason: &str,
+        now: UnixNanos,
+        events: &mut Vec<FixSessionEvent>,
+    ) -> anyhow::Result<()> {
+        tracing::error!("Terminating FIX session: {reason}");
+        let logout = FixMessage::new(msg_type::LOGOUT).with(TEXT, reason);
+        self.send_admin(logout, now, events)?;
+        self.set_state(FixSessionState::Disconnected, now);
+        events.push(FixSessionEvent::Disconnect(reason.to_string()));
+        Ok(())
+    }
+
+    fn on_logon(&mut self, now: UnixNanos, events: &mut Vec<FixSessionEvent>) {
+        if self.state == FixSessionState::LogonSent {
+            self.set_state(FixSessionState::Active, now);
+            events.push(FixSessionEvent::LoggedOn);
+        } else {
+            tracing::warn!("Ignoring unexpected FIX logon in state {:?}", self.state);
+        }
+    }
+
+    fn on_logout(
+        &mut self,
+        message: &FixMessage,
+        now: UnixNanos,
+        events: &mut Vec<FixSessionEvent>,
+    ) -> anyhow::Result<()> {
+        if self.state != FixSessionState::LogoutSent {
+            // Confirm a logout initiated by the counterparty
+            self.send_admin(FixMessage::new(msg_type::LOGOUT), now, events)?;
+        }
+        self.set_state(FixSessionState::Disconnected, now);
+        events.push(FixSessionEvent::LoggedOut(
+            message.get(TEXT).map(ToString::to_string),
+        ));
+        events.push(FixSessionEvent::Disconnect("Logged out".to_string()));
+        Ok(())
+    }
+
+    fn on_sequence_reset(&mut self, message: &FixMessage) -> anyhow::Result<()> {
+        let new_seq_num: u64 = message.require_parsed(NEW_SEQ_NO)?;
+        let expected = self.store.next_target_seq_num();
+        if new_seq_num > expected {
+            self.store.set_next_target_seq_num(new_seq_num)?;
+        } else if new_seq_num < expected {
+            tracing::warn!(
+                "Ignoring FIX sequence reset to {new_seq_num} lower than expected {expected}"
+            );
+        }
+        if self
+            .resend_requested
+            .is_some_and(|until| self.store.next_target_seq_num() > until)
+        {
+            self.resend_requested = None;
+        }
+        Ok(())
+    }
+
+    fn on_resend_request(
+        &mut self,
+        message: &FixMessage,
+        now: UnixNanos,
+        events: &mut Vec<FixSessionEvent>,
+    ) -> anyhow::Result<()> {
+        let begin: u64 = message.require_parsed(BEGIN_SEQ_NO)?;
+        let end: u64 = message.require_parsed(END_SEQ_NO)?;
+        let last_sent = self.store.next_sender_seq_num().saturating_sub(1);
+        let end = if end == 0 || end > last_sent {
+            last_sent
+        } else {
+            end
+        };
+        if begin == 0 || begin > end {
+            return Ok(());
+        }
+
+        tracing::info!("Servicing FIX resend request {begin}..={end}");
+        let stored: BTreeMap<u64, Vec<u8>> = self.store.messages(begin, end)?.into_iter().collect();
+
+        let mut gap_start: Option<u64> = None;
+        for seq_num in begin..=end {
+            let Some(bytes) = stored.get(&seq_num) else {
+                if gap_start.is_none() {
+                    gap_start = Some(seq_num);
+                }
+                continue;
+            };
+
+            if let Some(start) = gap_start.take() {
+                events.push(self.gap_fill(start, seq_num, now));
+            }
+
+            match FixMessage::decode(bytes) {
+                Ok(original) => {
+                    let orig_sending_time = original.get(SENDING_TIME).map(ToString::to_string);
+                    let replay = self.stamp(&original, seq_num, now, orig_sending_time.as_deref());
+                    events.push(FixSessionEvent::Send(
+                        replay.encode(&self.config.begin_string),
+                    ));
+                }
+                Err(e) => {
+                    tracing::error!("Gap-filling corrupt stored FIX message {seq_num}: {e}");
+                    events.push(self.gap_fill(seq_num, seq_num + 1, now));
+                }
+            }
+        }
+
+        if let Some(start) = gap_start {
+            events.push(self.gap_fill(start, end + 1, now));
+        }
+
+        self.last_sent = now;
+        Ok(())
+    }
+
+    fn gap_fill(&self, seq_num: u64, new_seq_num: u64, now: UnixNanos) -> FixSessionEvent {
+        let gap_fill = FixMessage::new(msg_type::SEQUENCE_RESET)
+            .with(GAP_FILL_FLAG, "Y")
+            .with(NEW_SEQ_NO, new_seq_num);
+        let sending_time = format_utc_timestamp(now);
+        let stamped = self.stamp(&gap_fill, seq_num, now, Some(&sending_time));
+        FixSessionEvent::Send(stamped.encode(&self.config.begin_string))
+    }
+}
+
+fn elapsed(since: UnixNanos, now: UnixNanos) -> u64 {
+    now.as_u64().saturating_sub(since.as_u64())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::fix::{store::MemoryMessageStore, tags::CL_ORD_ID};
+
+    const SECOND: u64 = NANOSECONDS_IN_SECOND;
+
+    fn session() -> FixSession<MemoryMessageStore> {
+        let mut config = FixSessionConfig::new("CLIENT", "VENUE");
+        config.username = Some("user".to_string());
+        config.password = Some(Secret::new("pass").unwrap());
+        FixSession::new(config, MemoryMessageStore::new())
+    }
+
+    fn inbound(msg_type: &str, seq_num: u64) -> FixMessage {
+        FixMessage::new(msg_type)
+            .with(SENDER_COMP_ID, "VENUE")
+            .with(TARGET_COMP_ID, "CLIENT")
+            .with(MSG_SEQ_NUM, seq_num)
+            .with(SENDING_TIME, "20240101-00:00:00.000")
+    }
+
+    fn sent(events: &[FixSessionEvent]) -> Vec<FixMessage> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                FixSessionEvent::Send(bytes) => Some(FixMessage::decode(bytes).unwrap()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn active_session() -> FixSession<MemoryMessageStore> {
+        let mut session = session();
+        session.logon(UnixNanos::default()).unwrap();
+        session
+            .on_message(inbound(msg_type::LOGON, 1), UnixNanos::default())
+            .unwrap();
+        session
+    }
+
+    #[rstest]
+    fn test_logon() {
+        let mut session = session();
+
+        let events = session.logon(UnixNanos::default()).unwrap();
+        let messages = sent(&events);
+
+        assert_eq!(session.state(), FixSessionState::LogonSent);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].msg_type(), msg_type::LOGON);
+        assert_eq!(messages[0].seq_num().unwrap(), 1);
+        assert_eq!(messages[0].get(HEART_BT_INT), Some("30"));
+        assert_eq!(messages[0].get(USERNAME), Some("user"));
+        assert_eq!(messages[0].get(PASSWORD), Some("pass"));
+    }
+
+    #[rstest]
+    fn test_logon_response_activates_session() {
+        let mut session = session();
+        session.logon(UnixNanos::default()).unwrap();
+
+        let events = session
+            .on_message(inbound(msg_type::LOGON, 1), UnixNanos::default())
+            .unwrap();
+
+        assert_eq!(events, vec![FixSessionEvent::LoggedOn]);
+        assert!(session.is_active());
+        assert_eq!(session.store().next_target_seq_num(), 2);
+    }
+
+    #[rstest]
+    fn test_logon_with_reset_flag() {
+        let mut session = session();
+        session.config.reset_on_logon = true;
+        session.store.set_next_sender_seq_num(10).unwrap();
+
+        let events = session.logon(UnixNanos::default()).unwrap();
+        let messages = sent(&events);
+
+        assert_eq!(messages[0].seq_num().unwrap(), 1);
+        assert_eq!(messages[0].get(RESET_SEQ_NUM_FLAG), Some("Y"));
+    }
+
+    #[rstest]
+    fn test_send_application_message_stamps_and_stores() {
+        let mut session = active_session();
+        let message = FixMessage::new(msg_type::NEW_ORDER_SINGLE).with(CL_ORD_ID, "O-1");
+
+        let bytes = session.send(&message, UnixNanos::default()).unwrap();
+        let decoded = FixMessage::decode(&bytes).unwrap();
+
+        assert_eq!(decoded.seq_num().unwrap(), 2);
+        assert_eq!(decoded.get(SENDER_COMP_ID), Some("CLIENT"));
+        assert_eq!(decoded.get(TARGET_COMP_ID), Some("VENUE"));
+        assert_eq!(decoded.get(CL_ORD_ID), Some("O-1"));
+        assert_eq!(session.store().messages(2, 2).unwrap(), vec![(2, bytes)]);
+        assert_eq!(session.store().next_sender_seq_num(), 3);
+    }
+
+    #[rstest]
+    fn test_send_when_not_active_fails() {
+        let mut session = session();
+        let message = FixMessage::new(msg_type::NEW_ORDER_SINGLE);
+
+        assert!(session.send(&message, UnixNanos::default()).is_err());
+    }
+
+    #[rstest]
+    fn test_application_message_forwarded() {
+        let mut session = active_session();
+        let message = inbound(msg_type::EXECUTION_REPORT, 2);
+
+        let events = session
+            .on_message(message.clone(), UnixNanos::default())
+            .unwrap();
+
+        assert_eq!(events, vec![FixSessionEvent::Application(message)]);
+    }
+
+    #[rstest]
+    fn test_test_request_answered_with_heartbeat() {
+        let mut session = active_session();
+        let test_request = inbound(msg_type::TEST_REQUEST, 2).with(TEST_REQ_ID, "T1");
+
+        let events = session
+            .on_message(test_request, UnixNanos::default())
+            .unwrap();
+        let messages = sent(&events);
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].msg_type(), msg_type::HEARTBEAT);
+        assert_eq!(messages[0].get(TEST_REQ_ID), Some("T1"));
+    }
+
+    #[rstest]
+    fn test_sequence_gap_sends_single_resend_request() {
+        let mut session = active_session();
+
+        let events = session
+            .on_message(inbound(msg_type::EXECUTION_REPORT, 5), UnixNanos::default())
+            .unwrap();
+        let repeat = session
+            .on_message(inbound(msg_type::EXECUTION_REPORT, 6), UnixNanos::default())
+            .unwrap();
+        let messages = sent(&events);
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].msg_type(), msg_type::RESEND_REQUEST);
+        assert_eq!(messages[0].get(BEGIN_SEQ_NO), Some("2"));
+        assert_eq!(messages[0].get(END_SEQ_NO), Some("0"));
+        assert!(repeat.is_empty());
+        assert_eq!(session.store().next_target_seq_num(), 2);
+    }
+
+    #[rstest]
+    fn test_sequence_too_low_terminates_session() {
+        let mut session = active_session();
+
+        let events = session
+            .on_message(inbound(msg_type::HEARTBEAT, 1), UnixNanos::default())
+            .unwrap();
+        let messages = sent(&events);
+
+        assert_eq!(messages[0].msg_type(), msg_type::LOGOUT);
+        assert!(matches!(
+            events.last(),
+            Some(FixSessionEvent::Disconnect(_))
+        ));
+        assert_eq!(session.state(), FixSessionState::Disconnected);
+    }
+
+    #[rstest]
+    fn test_poss_dup_with_low_sequence_ignored() {
+        let mut session = active_session();
+        let message = inbound(msg_type::EXECUTION_REPORT, 1).with(POSS_DUP_FLAG, "Y");
+
+        let events = session.on_message(message, UnixNanos::default()).unwrap();
+
+        assert!(events.is_empty());
+        assert!(session.is_active());
+    }
+
+    #[rstest]
+    fn test_invalid_comp_id_terminates_session() {
+        let mut session = active_session();
+        let mut message = inbound(msg_type::HEARTBEAT, 2);
+        message.set(SENDER_COMP_ID, "OTHER");
+
+        let events = session.on_message(message, UnixNanos::default()).unwrap();
+
+        assert!(matches!(
+            events.last(),
+            Some(FixSessionEvent::Disconnect(_))
+        ));
+    }
+
+    #[rstest]
+    fn test_gap_fill_advances_target_sequence() {
+        let mut session = active_session();
+        let gap_fill = inbound(msg_type::SEQUENCE_RESET, 2)
+            .with(GAP_FILL_FLAG, "Y")
+            .with(NEW_SEQ_NO, 10);
+
+        session.on_message(gap_fill, UnixNanos::default()).unwrap();
+
+        assert_eq!(session.store().next_target_seq_num(), 10);
+    }
+
+    #[rstest]
+    fn test_sequence_reset_ignores_sequence_number() {
+        let mut session = active_session();
+        let reset = inbound(msg_type::SEQUENCE_RESET, 99).with(NEW_SEQ_NO, 50);
+
+        let events = session.on_message(reset, UnixNanos::default()).unwrap();
+
+        assert!(events.is_empty());
+        assert_eq!(session.store().next_target_seq_num(), 50);
+    }
+
+    #[rstest]
+    fn test_resend_request_replays_and_gap_fills() {
+        let mut session = active_session();
+        let order = FixMessage::new(msg_type::NEW_ORDER_SINGLE).with(CL_ORD_ID, "O-1");
+        session.send(&order, UnixNanos::default()).unwrap(); // Seq 2
+        session.on_timer(UnixNanos::from(31 * SECOND)).unwrap(); // Heartbeat seq 3
+        let resend = inbound(msg_type::RESEND_REQUEST, 2)
+            .with(BEGIN_SEQ_NO, 1)
+            .with(END_SEQ_NO, 0);
+
+        let events = session
+            .on_message(resend, UnixNanos::from(32 * SECOND))
+            .unwrap();
+        let messages = sent(&events);
+
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[0].msg_type(), msg_type::SEQUENCE_RESET);
+        assert_eq!(messages[0].seq_num().unwrap(), 1);
+        assert_eq!(messages[0].get(NEW_SEQ_NO), Some("2"));
+        assert_eq!(messages[1].msg_type(), msg_type::NEW_ORDER_SINGLE);
+        assert_eq!(messages[1].seq_num().unwrap(), 2);
+        assert!(messages[1].is_poss_dup());
+        assert_eq!(
+            messages[1].get(ORIG_SENDING_TIME),
+            Some("19700101-00:00:00.000")
+        );
+        assert_eq!(messages[1].get(CL_ORD_ID), Some("O-1"));
+        assert_eq!(messages[2].msg_type(), msg_type::SEQUENCE_RESET);
+        assert_eq!(messages[2].seq_num().unwrap(), 3);
+        assert_eq!(messages[2].get(NEW_SEQ_NO), Some("4"));
+        assert_eq!(session.store().next_sender_seq_num(), 4);
+    }
+
+    #[rstest]
+    fn test_timer_sends_heartbeat_then_test_request_then_disconnects() {
+        let mut session = active_session();
+
+        let heartbeat = sent(&session.on_timer(UnixNanos::from(30 * SECOND)).unwrap());
+        let test_request = sent(&session.on_timer(UnixNanos::from(36 * SECOND)).unwrap());
+        let timeout = session.on_timer(UnixNanos::from(66 * SECOND)).unwrap();
+
+        assert_eq!(heartbeat.len(), 1);
+        assert_eq!(heartbeat[0].msg_type(), msg_type::HEARTBEAT);
+        assert_eq!(test_request.len(), 1);
+        assert_eq!(test_request[0].msg_type(), msg_type::TEST_REQUEST);
+        assert_eq!(test_request[0].get(TEST_REQ_ID), Some("TEST-1"));
+        assert_eq!(
+            timeout,
+            vec![FixSessionEvent::Disconnect("Heartbeat timeout".to_string())]
+        );
+    }
+
+    #[rstest]
+    fn test_logon_timeout() {
+        let mut session = session();
+        session.logon(UnixNanos::default()).unwrap();
+
+        let events = session.on_timer(UnixNanos::from(30 * SECOND)).unwrap();
+
+        assert!(matches!(events[..], [FixSessionEvent::Disconnect(_)]));
+        assert_eq!(session.state(), FixSessionState::Disconnected);
+    }
+
+    #[rstest]
+    fn test_logout_initiated_by_counterparty() {
+        let mut session = active_session();
+        let logout = inbound(msg_type::LOGOUT, 2).with(TEXT, "End of day");
+
+        let events = session.on_message(logout, UnixNanos::default()).unwrap();
+        let messages = sent(&events);
+
+        assert_eq!(messages[0].msg_type(), msg_type::LOGOUT);
+        assert!(events.contains(&FixSessionEvent::LoggedOut(Some("End of day".to_string()))));
+        assert_eq!(session.state(), FixSessionState::Disconnected);
+    }
+
+    #[rstest]
+    fn test_logout_confirmed() {
+        let mut session = active_session();
+        session.logout(None, UnixNanos::default()).unwrap();
+
+        let events = session
+            .on_message(inbound(msg_type::LOGOUT, 2), UnixNanos::default())
+            .unwrap();
+
+        assert!(sent(&events).is_empty());
+        assert_eq!(
+            events,
+            vec![
+                FixSessionEvent::LoggedOut(None),
+                FixSessionEvent::Disconnect("Logged out".to_string())
+            ]
+        );
+    }
+}
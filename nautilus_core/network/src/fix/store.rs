@@ -0,0 +1,334 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Persistent storage of FIX session sequence numbers and sent messages.
+
+use std::{
+    collections::BTreeMap,
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Read, Write},
+    path::{Path, PathBuf},
+};
+
+/// Provides storage of the session sequence numbers and the sent application messages,
+/// so that a session can resume after a restart and service resend requests.
+pub trait FixMessageStore: Send {
+    /// Returns the sequence number of the next outgoing message.
+    fn next_sender_seq_num(&self) -> u64;
+    /// Returns the expected sequence number of the next incoming message.
+    fn next_target_seq_num(&self) -> u64;
+    /// Sets the sequence number of the next outgoing message.
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()>;
+    /// Sets the expected sequence number of the next incoming message.
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()>;
+    /// Stores the encoded outgoing `message` with the given `seq_num`.
+    fn store(&mut self, seq_num: u64, message: &[u8]) -> anyhow::Result<()>;
+    /// Returns the stored messages in the inclusive range `begin..=end`, where an `end`
+    /// of zero means infinity.
+    fn messages(&self, begin: u64, end: u64) -> anyhow::Result<Vec<(u64, Vec<u8>)>>;
+    /// Resets both sequence numbers to one and clears all stored messages.
+    fn reset(&mut self) -> anyhow::Result<()>;
+}
+
+/// Provides an in-memory [`FixMessageStore`], which does not survive a restart.
+#[derive(Debug)]
+pub struct MemoryMessageStore {
+    next_sender_seq_num: u64,
+    next_target_seq_num: u64,
+    messages: BTreeMap<u64, Vec<u8>>,
+}
+
+impl Default for MemoryMessageStore {
+    fn default() -> Self {
+        Self {
+            next_sender_seq_num: 1,
+            next_target_seq_num: 1,
+            messages: BTreeMap::new(),
+        }
+    }
+}
+
+impl MemoryMessageStore {
+    /// Creates a new [`MemoryMessageStore`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl FixMessageStore for MemoryMessageStore {
+    fn next_sender_seq_num(&self) -> u64 {
+        self.next_sender_seq_num
+    }
+
+    fn next_target_seq_num(&self) -> u64 {
+        self.next_target_seq_num
+    }
+
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.next_sender_seq_num = seq_num;
+        Ok(())
+    }
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.next_target_seq_num = seq_num;
+        Ok(())
+    }
+
+    fn store(&mut self, seq_num: u64, message: &[u8]) -> anyhow::Result<()> {
+        self.messages.insert(seq_num, message.to_vec());
+        Ok(())
+    }
+
+    fn messages(&self, begin: u64, end: u64) -> anyhow::Result<Vec<(u64, Vec<u8>)>> {
+        let end = if end == 0 { u64::MAX } else { end };
+        if begin > end {
+            return Ok(Vec::new());
+        }
+
+        Ok(self
+            .messages
+            .range(begin..=end)
+            .map(|(seq_num, message)| (*seq_num, message.clone()))
+            .collect())
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        *self = Self::default();
+        Ok(())
+    }
+}
+
+/// Provides a file backed [`FixMessageStore`].
+///
+/// The sequence numbers are held in a `<session_id>.seqnums` file which is rewritten on every
+/// change, and sent messages are appended to a `<session_id>.body` log as records of
+/// `<seq_num> <len>\n<message>\n`. The log is indexed in memory when the store is opened.
+#[derive(Debug)]
+pub struct FileMessageStore {
+    seqnums_path: PathBuf,
+    body_path: PathBuf,
+    body: File,
+    cache: MemoryMessageStore,
+}
+
+impl FileMessageStore {
+    /// Opens (or creates) the store for the given `session_id` in the directory `dir`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the directory or files cannot be created or read.
+    /// - If the existing files are corrupt.
+    pub fn open<P: AsRef<Path>>(dir: P, session_id: &str) -> anyhow::Result<Self> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+
+        let seqnums_path = dir.join(format!("{session_id}.seqnums"));
+        let body_path = dir.join(format!("{session_id}.body"));
+
+        let mut cache = MemoryMessageStore::new();
+        if seqnums_path.exists() {
+            let contents = std::fs::read_to_string(&seqnums_path)?;
+            let (sender, target) = contents
+                .trim()
+                .split_once(' ')
+                .ok_or_else(|| anyhow::anyhow!("Invalid seqnums file '{contents}'"))?;
+            cache.next_sender_seq_num = sender.parse()?;
+            cache.next_target_seq_num = target.parse()?;
+        }
+        if body_path.exists() {
+            cache.messages = read_body_log(&body_path)?;
+        }
+
+        let body = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&body_path)?;
+
+        let mut store = Self {
+            seqnums_path,
+            body_path,
+            body,
+            cache,
+        };
+        store.write_seqnums()?;
+        Ok(store)
+    }
+
+    fn write_seqnums(&mut self) -> anyhow::Result<()> {
+        let contents = format!(
+            "{} {}\n",
+            self.cache.next_sender_seq_num, self.cache.next_target_seq_num
+        );
+        // Write to a temporary file first so a crash cannot leave a truncated file
+        let tmp_path = self.seqnums_path.with_extension("seqnums.tmp");
+        std::fs::write(&tmp_path, contents)?;
+        std::fs::rename(&tmp_path, &self.seqnums_path)?;
+        Ok(())
+    }
+}
+
+fn read_body_log(path: &Path) -> anyhow::Result<BTreeMap<u64, Vec<u8>>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut messages = BTreeMap::new();
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+
+        let (seq_num, len) = line
+            .trim_end()
+            .split_once(' ')
+            .ok_or_else(|| anyhow::anyhow!("Invalid body log record header '{line}'"))?;
+        let seq_num: u64 = seq_num.parse()?;
+        let len: usize = len.parse()?;
+
+        // Read the message and its trailing newline
+        let mut message = vec![0; len + 1];
+        reader.read_exact(&mut message)?;
+        message.truncate(len);
+        messages.insert(seq_num, message);
+    }
+
+    Ok(messages)
+}
+
+impl FixMessageStore for FileMessageStore {
+    fn next_sender_seq_num(&self) -> u64 {
+        self.cache.next_sender_seq_num()
+    }
+
+    fn next_target_seq_num(&self) -> u64 {
+        self.cache.next_target_seq_num()
+    }
+
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.cache.set_next_sender_seq_num(seq_num)?;
+        self.write_seqnums()
+    }
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.cache.set_next_target_seq_num(seq_num)?;
+        self.write_seqnums()
+    }
+
+    fn store(&mut self, seq_num: u64, message: &[u8]) -> anyhow::Result<()> {
+        let mut record = format!("{seq_num} {}\n", message.len()).into_bytes();
+        record.extend_from_slice(message);
+        record.push(b'\n');
+        self.body.write_all(&record)?;
+        self.body.flush()?;
+        self.cache.store(seq_num, message)
+    }
+
+    fn messages(&self, begin: u64, end: u64) -> anyhow::Result<Vec<(u64, Vec<u8>)>> {
+        self.cache.messages(begin, end)
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        self.cache.reset()?;
+        self.body = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.body_path)?;
+        self.write_seqnums()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[rstest]
+    fn test_memory_store_messages_range() {
+        let mut store = MemoryMessageStore::new();
+        for seq_num in 1..=5 {
+            store
+                .store(seq_num, format!("msg{seq_num}").as_bytes())
+                .unwrap();
+        }
+
+        let messages = store.messages(2, 3).unwrap();
+        let open_ended = store.messages(4, 0).unwrap();
+
+        assert_eq!(messages, vec![(2, b"msg2".to_vec()), (3, b"msg3".to_vec())]);
+        assert_eq!(open_ended.len(), 2);
+        assert!(store.messages(4, 2).unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn test_memory_store_reset() {
+        let mut store = MemoryMessageStore::new();
+        store.set_next_sender_seq_num(10).unwrap();
+        store.set_next_target_seq_num(20).unwrap();
+        store.store(9, b"msg").unwrap();
+
+        store.reset().unwrap();
+
+        assert_eq!(store.next_sender_seq_num(), 1);
+        assert_eq!(store.next_target_seq_num(), 1);
+        assert!(store.messages(1, 0).unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn test_file_store_reopen_restores_state() {
+        let dir = tempdir().unwrap();
+        {
+            let mut store = FileMessageStore::open(dir.path(), "CLIENT-VENUE").unwrap();
+            store.set_next_sender_seq_num(3).unwrap();
+            store.set_next_target_seq_num(7).unwrap();
+            store.store(1, b"8=FIX.4.4\x0135=D\x01").unwrap();
+            store.store(2, b"multi\nline").unwrap();
+        }
+
+        let store = FileMessageStore::open(dir.path(), "CLIENT-VENUE").unwrap();
+
+        assert_eq!(store.next_sender_seq_num(), 3);
+        assert_eq!(store.next_target_seq_num(), 7);
+        assert_eq!(
+            store.messages(1, 0).unwrap(),
+            vec![
+                (1, b"8=FIX.4.4\x0135=D\x01".to_vec()),
+                (2, b"multi\nline".to_vec())
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_file_store_reset() {
+        let dir = tempdir().unwrap();
+        let mut store = FileMessageStore::open(dir.path(), "CLIENT-VENUE").unwrap();
+        store.set_next_sender_seq_num(3).unwrap();
+        store.store(2, b"msg").unwrap();
+
+        store.reset().unwrap();
+        drop(store);
+        let store = FileMessageStore::open(dir.path(), "CLIENT-VENUE").unwrap();
+
+        assert_eq!(store.next_sender_seq_num(), 1);
+        assert!(store.messages(1, 0).unwrap().is_empty());
+    }
+}
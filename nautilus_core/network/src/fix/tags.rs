@@ -0,0 +1,114 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tag numbers and message types of the FIX 4.4 protocol used by the engine.
+
+/// The standard header tags, which are stamped by the session on outgoing messages.
+pub const STANDARD_HEADER_TAGS: [u32; 9] = [
+    BEGIN_STRING,
+    BODY_LENGTH,
+    MSG_TYPE,
+    MSG_SEQ_NUM,
+    SENDER_COMP_ID,
+    TARGET_COMP_ID,
+    SENDING_TIME,
+    POSS_DUP_FLAG,
+    ORIG_SENDING_TIME,
+];
+
+// Session tags
+pub const BEGIN_SEQ_NO: u32 = 7;
+pub const BEGIN_STRING: u32 = 8;
+pub const BODY_LENGTH: u32 = 9;
+pub const CHECKSUM: u32 = 10;
+pub const END_SEQ_NO: u32 = 16;
+pub const MSG_SEQ_NUM: u32 = 34;
+pub const MSG_TYPE: u32 = 35;
+pub const NEW_SEQ_NO: u32 = 36;
+pub const POSS_DUP_FLAG: u32 = 43;
+pub const REF_SEQ_NUM: u32 = 45;
+pub const SENDER_COMP_ID: u32 = 49;
+pub const SENDING_TIME: u32 = 52;
+pub const TARGET_COMP_ID: u32 = 56;
+pub const TEXT: u32 = 58;
+pub const ENCRYPT_METHOD: u32 = 98;
+pub const HEART_BT_INT: u32 = 108;
+pub const TEST_REQ_ID: u32 = 112;
+pub const ORIG_SENDING_TIME: u32 = 122;
+pub const GAP_FILL_FLAG: u32 = 123;
+pub const RESET_SEQ_NUM_FLAG: u32 = 141;
+pub const REF_TAG_ID: u32 = 371;
+pub const REF_MSG_TYPE: u32 = 372;
+pub const SESSION_REJECT_REASON: u32 = 373;
+pub const USERNAME: u32 = 553;
+pub const PASSWORD: u32 = 554;
+
+// Application tags
+pub const ACCOUNT: u32 = 1;
+pub const AVG_PX: u32 = 6;
+pub const CL_ORD_ID: u32 = 11;
+pub const COMMISSION: u32 = 12;
+pub const COMM_TYPE: u32 = 13;
+pub const CUM_QTY: u32 = 14;
+pub const CURRENCY: u32 = 15;
+pub const EXEC_ID: u32 = 17;
+pub const EXEC_INST: u32 = 18;
+pub const HANDL_INST: u32 = 21;
+pub const LAST_PX: u32 = 31;
+pub const LAST_QTY: u32 = 32;
+pub const ORDER_ID: u32 = 37;
+pub const ORDER_QTY: u32 = 38;
+pub const ORD_STATUS: u32 = 39;
+pub const ORD_TYPE: u32 = 40;
+pub const ORIG_CL_ORD_ID: u32 = 41;
+pub const PRICE: u32 = 44;
+pub const SIDE: u32 = 54;
+pub const SYMBOL: u32 = 55;
+pub const TIME_IN_FORCE: u32 = 59;
+pub const TRANSACT_TIME: u32 = 60;
+pub const STOP_PX: u32 = 99;
+pub const CXL_REJ_REASON: u32 = 102;
+pub const ORD_REJ_REASON: u32 = 103;
+pub const EXPIRE_TIME: u32 = 126;
+pub const EXEC_TYPE: u32 = 150;
+pub const LEAVES_QTY: u32 = 151;
+pub const CXL_REJ_RESPONSE_TO: u32 = 434;
+pub const LAST_LIQUIDITY_IND: u32 = 851;
+
+/// The values of the `MsgType` (35) field.
+pub mod msg_type {
+    pub const HEARTBEAT: &str = "0";
+    pub const TEST_REQUEST: &str = "1";
+    pub const RESEND_REQUEST: &str = "2";
+    pub const REJECT: &str = "3";
+    pub const SEQUENCE_RESET: &str = "4";
+    pub const LOGOUT: &str = "5";
+    pub const EXECUTION_REPORT: &str = "8";
+    pub const ORDER_CANCEL_REJECT: &str = "9";
+    pub const LOGON: &str = "A";
+    pub const NEW_ORDER_SINGLE: &str = "D";
+    pub const ORDER_CANCEL_REQUEST: &str = "F";
+    pub const ORDER_CANCEL_REPLACE_REQUEST: &str = "G";
+    pub const BUSINESS_MESSAGE_REJECT: &str = "j";
+
+    /// Returns whether the given `msg_type` is a session-level (admin) message.
+    #[must_use]
+    pub fn is_admin(msg_type: &str) -> bool {
+        matches!(
+            msg_type,
+            HEARTBEAT | TEST_REQUEST | RESEND_REQUEST | REJECT | SEQUENCE_RESET | LOGOUT | LOGON
+        )
+    }
+}
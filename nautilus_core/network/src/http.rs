@@ -15,16 +15,30 @@
 
 //! A high-performance HTTP client implementation.
 
-use std::{collections::HashMap, hash::Hash, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    num::NonZeroU32,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use bytes::Bytes;
 use http::HeaderValue;
 use reqwest::{
     header::{HeaderMap, HeaderName},
-    Method, Response, Url,
+    Method, Response, StatusCode, Url,
+};
+use tokio::time::{sleep, Instant};
+
+use crate::{
+    backoff::ExponentialBackoff,
+    ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimitError, RateLimiter},
 };
 
-use crate::ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter};
+/// The response header a server uses to indicate how long to wait before retrying.
+const RETRY_AFTER_HEADER: &str = "retry-after";
 
 /// Represents the HTTP methods supported by the `HttpClient`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -82,6 +96,9 @@ pub enum HttpClientError {
 
     #[error("HTTP request timed out: {0}")]
     TimeoutError(String),
+
+    #[error("HTTP request rate limited: {0}")]
+    RateLimitError(String),
 }
 
 impl From<reqwest::Error> for HttpClientError {
@@ -94,6 +111,12 @@ impl From<reqwest::Error> for HttpClientError {
     }
 }
 
+impl From<RateLimitError> for HttpClientError {
+    fn from(source: RateLimitError) -> Self {
+        Self::RateLimitError(source.to_string())
+    }
+}
+
 impl From<String> for HttpClientError {
     fn from(value: String) -> Self {
         Self::Error(value)
@@ -106,6 +129,18 @@ impl From<String> for HttpClientError {
 /// support for rate limiting, timeouts, and custom headers. The client is
 /// built on top of `reqwest` and can be used for both synchronous and
 /// asynchronous HTTP requests.
+///
+/// Requests are checked against an optional global quota and the quotas for
+/// their keys, with a weight per request. When a quota is exhausted the request
+/// is either queued until it conforms, or rejected with a rate limit error.
+///
+/// When the server responds with `429 Too Many Requests` all requests are paused for the
+/// `Retry-After` duration (or an exponential backoff if not given). Idempotent requests are
+/// then retried up to `max_retries` times, as are those answered with `503 Service Unavailable`.
+///
+/// Non-idempotent requests (such as `POST`) are never retried by the client, as the server may
+/// have acted on them. Requests which are signed with a timestamp should also be retried by the
+/// caller (with `max_retries` of zero), so that each attempt is signed afresh.
 #[derive(Clone)]
 #[cfg_attr(
     feature = "python",
@@ -116,16 +151,30 @@ pub struct HttpClient {
     pub(crate) client: InnerHttpClient,
     /// The rate limiter to control the request rate.
     pub(crate) rate_limiter: Arc<RateLimiter<String, MonotonicClock>>,
+    /// If requests are rejected (rather than queued) when rate limited.
+    pub(crate) reject_when_limited: bool,
+    /// The maximum number of retries for an idempotent request rate limited by the server.
+    pub(crate) max_retries: u32,
+    /// The instant until which the server has asked for requests to be paused.
+    pub(crate) paused_until: Arc<Mutex<Option<Instant>>>,
 }
 
 impl HttpClient {
     /// Creates a new [`HttpClient`] instance.
+    ///
+    /// - `global_quota`: The quota every request is checked against, regardless of its keys.
+    /// - `reject_when_limited`: If requests are rejected rather than queued when rate limited.
+    /// - `max_retries`: The maximum number of retries for an idempotent request rate limited
+    ///   by the server.
     #[must_use]
     pub fn new(
         headers: HashMap<String, String>,
-        header_keys: Vec<String>,
+        mut header_keys: Vec<String>,
         keyed_quotas: Vec<(String, Quota)>,
         default_quota: Option<Quota>,
+        global_quota: Option<Quota>,
+        reject_when_limited: bool,
+        max_retries: u32,
     ) -> Self {
         // Build default headers
         let mut header_map = HeaderMap::new();
@@ -140,15 +189,27 @@ impl HttpClient {
             .build()
             .expect("Failed to build reqwest client");
 
+        // Always retain the retry header so server rate limits can be respected
+        if !header_keys
+            .iter()
+            .any(|key| key.eq_ignore_ascii_case(RETRY_AFTER_HEADER))
+        {
+            header_keys.push(RETRY_AFTER_HEADER.to_string());
+        }
+
         let client = InnerHttpClient {
             client,
             header_keys: Arc::new(header_keys),
         };
-        let rate_limiter = Arc::new(RateLimiter::new_with_quota(default_quota, keyed_quotas));
+        let mut rate_limiter = RateLimiter::new_with_quota(default_quota, keyed_quotas);
+        rate_limiter.set_global_quota(global_quota);
 
         Self {
             client,
-            rate_limiter,
+            rate_limiter: Arc::new(rate_limiter),
+            reject_when_limited,
+            max_retries,
+            paused_until: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -159,6 +220,14 @@ impl HttpClient {
     /// `headers`: The header key value pairs in the request.
     /// `body`: The bytes sent in the body of request.
     /// `keys`: The keys used for rate limiting the request.
+    /// `weight`: The number of quota cells the request consumes (defaults to 1).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `weight` is zero or exceeds the burst capacity of a quota.
+    /// - If the client rejects requests when rate limited and a quota is exhausted.
+    /// - If sending the request fails.
     ///
     /// # Example
     ///
@@ -174,16 +243,115 @@ impl HttpClient {
         body: Option<Vec<u8>>,
         keys: Option<Vec<String>>,
         timeout_secs: Option<u64>,
+        weight: Option<u32>,
     ) -> Result<HttpResponse, HttpClientError> {
-        let rate_limiter = self.rate_limiter.clone();
+        let weight = NonZeroU32::new(weight.unwrap_or(1))
+            .ok_or_else(|| HttpClientError::from("Request weight must be positive".to_string()))?;
+        let mut backoff = ExponentialBackoff::new(
+            Duration::from_millis(500),
+            Duration::from_secs(60),
+            2.0,
+            250,
+        )
+        .expect("Backoff parameters should be valid");
+        let mut retries = 0;
+        let idempotent = method.is_idempotent();
+
+        loop {
+            self.acquire(keys.clone(), weight).await?;
+
+            let response = self
+                .client
+                .send_request(
+                    method.clone(),
+                    url.clone(),
+                    headers.clone(),
+                    body.clone(),
+                    timeout_secs,
+                )
+                .await?;
+
+            if !is_rate_limited(response.status, idempotent) {
+                return Ok(response);
+            }
+
+            let delay = parse_retry_after(&response).unwrap_or_else(|| backoff.next_duration());
+            self.pause_for(delay);
+
+            if !idempotent || self.reject_when_limited || retries >= self.max_retries {
+                tracing::warn!("Rate limited by server ({}), not retrying", response.status);
+                return Ok(response);
+            }
+
+            retries += 1;
+            tracing::warn!(
+                "Rate limited by server ({}), retry {retries}/{} in {delay:?}",
+                response.status,
+                self.max_retries,
+            );
+        }
+    }
 
-        rate_limiter.await_keys_ready(keys).await;
-        self.client
-            .send_request(method, url, headers, body, timeout_secs)
-            .await
+    /// Acquires `weight` cells from the global and keyed quotas, waiting out any pause
+    /// requested by the server, or rejecting the request if configured to do so.
+    async fn acquire(
+        &self,
+        keys: Option<Vec<String>>,
+        weight: NonZeroU32,
+    ) -> Result<(), HttpClientError> {
+        let paused_until = *self.paused_until.lock().expect("Pause lock poisoned");
+        if let Some(paused_until) = paused_until {
+            let now = Instant::now();
+            if paused_until > now {
+                let wait = paused_until - now;
+                if self.reject_when_limited {
+                    return Err(RateLimitError::Exhausted(wait).into());
+                }
+                sleep(wait).await;
+            }
+        }
+
+        if self.reject_when_limited {
+            self.rate_limiter
+                .check_keys_n(&keys.unwrap_or_default(), weight)?;
+        } else {
+            self.rate_limiter.await_keys_ready_n(keys, weight).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Pauses all requests from the client for the given `delay`.
+    fn pause_for(&self, delay: Duration) {
+        let until = Instant::now() + delay;
+        let mut paused_until = self.paused_until.lock().expect("Pause lock poisoned");
+        if paused_until.map_or(true, |current| current < until) {
+            *paused_until = Some(until);
+        }
     }
 }
 
+/// Returns whether the `status` indicates the server is rate limiting requests.
+///
+/// A `503 Service Unavailable` is only treated as such for `idempotent` requests, otherwise
+/// the outcome of the request is unknown and the response is returned to the caller.
+fn is_rate_limited(status: u16, idempotent: bool) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS.as_u16()
+        || (idempotent && status == StatusCode::SERVICE_UNAVAILABLE.as_u16())
+}
+
+/// Parses the `Retry-After` header of the `response` given in delta-seconds.
+///
+/// HTTP-date values are not supported and return `None`.
+fn parse_retry_after(response: &HttpResponse) -> Option<Duration> {
+    response
+        .headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(RETRY_AFTER_HEADER))
+        .and_then(|(_, value)| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
 /// A high-performance `HttpClient` for HTTP requests.
 ///
 /// The client is backed by a hyper Client which keeps connections alive and
@@ -295,13 +463,18 @@ impl Default for InnerHttpClient {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::net::{SocketAddr, TcpListener};
+    use std::{
+        net::{SocketAddr, TcpListener},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
 
     use axum::{
+        response::IntoResponse,
         routing::{delete, get, patch, post},
         serve, Router,
     };
     use http::status::StatusCode;
+    use rstest::rstest;
 
     use super::*;
 
@@ -318,11 +491,32 @@ mod tests {
     }
 
     fn create_router() -> Router {
+        let rate_limited_calls = Arc::new(AtomicUsize::new(0));
+        let rate_limited = move || {
+            let calls = rate_limited_calls.clone();
+            async move {
+                // Rate limit the first request only
+                if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                    (StatusCode::TOO_MANY_REQUESTS, [("retry-after", "0")]).into_response()
+                } else {
+                    "hello-world!".into_response()
+                }
+            }
+        };
+
         Router::new()
             .route("/get", get(|| async { "hello-world!" }))
             .route("/post", post(|| async { StatusCode::OK }))
             .route("/patch", patch(|| async { StatusCode::OK }))
             .route("/delete", delete(|| async { StatusCode::OK }))
+            .route(
+                "/rate-limited",
+                get(rate_limited.clone()).post(rate_limited),
+            )
+            .route(
+                "/unavailable",
+                post(|| async { StatusCode::SERVICE_UNAVAILABLE }),
+            )
     }
 
     async fn start_test_server() -> Result<SocketAddr, Box<dyn std::error::Error + Send + Sync>> {
@@ -447,4 +641,123 @@ mod tests {
 
         assert_eq!(response.status, StatusCode::OK);
     }
+
+    #[tokio::test]
+    async fn test_request_retries_when_rate_limited_by_server() {
+        let addr = start_test_server().await.unwrap();
+        let url = format!("http://{addr}");
+
+        let client = HttpClient::new(HashMap::new(), vec![], vec![], None, None, false, 3);
+        let response = client
+            .request(
+                reqwest::Method::GET,
+                format!("{url}/rate-limited"),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(String::from_utf8_lossy(&response.body), "hello-world!");
+    }
+
+    #[rstest]
+    #[case::rate_limited("/rate-limited", StatusCode::TOO_MANY_REQUESTS)]
+    #[case::unavailable("/unavailable", StatusCode::SERVICE_UNAVAILABLE)]
+    #[tokio::test]
+    async fn test_request_does_not_retry_non_idempotent_request(
+        #[case] path: &str,
+        #[case] expected: StatusCode,
+    ) {
+        let addr = start_test_server().await.unwrap();
+        let url = format!("http://{addr}");
+
+        let client = HttpClient::new(HashMap::new(), vec![], vec![], None, None, false, 3);
+        let response = client
+            .request(
+                reqwest::Method::POST,
+                format!("{url}{path}"),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, expected);
+    }
+
+    #[tokio::test]
+    async fn test_request_rejected_when_global_quota_exhausted() {
+        let addr = start_test_server().await.unwrap();
+        let url = format!("http://{addr}");
+
+        let global_quota = Quota::per_second(NonZeroU32::new(1).unwrap());
+        let client = HttpClient::new(
+            HashMap::new(),
+            vec![],
+            vec![],
+            None,
+            Some(global_quota),
+            true,
+            0,
+        );
+        let first = client
+            .request(
+                reqwest::Method::GET,
+                format!("{url}/get"),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+        let second = client
+            .request(
+                reqwest::Method::GET,
+                format!("{url}/get"),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+        assert!(first.is_ok());
+        assert!(matches!(second, Err(HttpClientError::RateLimitError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_request_with_weight_exceeding_capacity() {
+        let client = HttpClient::new(
+            HashMap::new(),
+            vec![],
+            vec![],
+            None,
+            Some(Quota::per_second(NonZeroU32::new(2).unwrap())),
+            false,
+            0,
+        );
+        let result = client
+            .request(
+                reqwest::Method::GET,
+                "http://127.0.0.1:1/get".to_string(),
+                None,
+                None,
+                None,
+                None,
+                Some(3),
+            )
+            .await;
+
+        assert!(matches!(result, Err(HttpClientError::RateLimitError(_))));
+    }
 }
@@ -26,12 +26,14 @@
 //!
 //! - `python`: Enables Python bindings from `pyo3`.
 
+pub mod backoff;
+pub mod fix;
 pub mod http;
+#[allow(dead_code)]
+pub mod ratelimiter;
 pub mod socket;
 pub mod websocket;
 
-#[allow(dead_code)]
-mod ratelimiter;
 mod tls;
 
 #[cfg(feature = "python")]
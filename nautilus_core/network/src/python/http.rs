@@ -32,12 +32,16 @@ create_exception!(network, HttpError, PyException);
 // Python exception class for generic HTTP timeout errors.
 create_exception!(network, HttpTimeoutError, PyException);
 
+// Python exception class for HTTP rate limit errors.
+create_exception!(network, HttpRateLimitError, PyException);
+
 impl HttpClientError {
     #[must_use]
     pub fn into_py_err(self) -> PyErr {
         match self {
             Self::Error(e) => PyErr::new::<HttpError, _>(e),
             Self::TimeoutError(e) => PyErr::new::<HttpTimeoutError, _>(e),
+            Self::RateLimitError(e) => PyErr::new::<HttpRateLimitError, _>(e),
         }
     }
 }
@@ -91,6 +95,10 @@ impl HttpClient {
     /// `keyed_quota`: A list of string quota pairs that gives quota for specific key values.
     /// `default_quota`: The default rate limiting quota for any request.
     /// Default quota is optional and no quota is passthrough.
+    /// `global_quota`: The quota every request is checked against, regardless of its keys.
+    /// `reject_when_limited`: If requests are rejected rather than queued when rate limited.
+    /// `max_retries`: The maximum number of retries for an idempotent request rate limited by
+    /// the server (defaults to none, leaving retries to the caller).
     ///
     /// Rate limiting can be configured on a per-endpoint basis by passing
     /// key-value pairs of endpoint URLs and their respective quotas.
@@ -107,15 +115,26 @@ impl HttpClient {
     ///
     /// For request /foo/bar, should pass keys ["foo/bar", "foo"] for rate limiting.
     #[new]
-    #[pyo3(signature = (default_headers = HashMap::new(), header_keys = Vec::new(), keyed_quotas = Vec::new(), default_quota = None))]
+    #[pyo3(signature = (default_headers = HashMap::new(), header_keys = Vec::new(), keyed_quotas = Vec::new(), default_quota = None, global_quota = None, reject_when_limited = false, max_retries = 0))]
     #[must_use]
     pub fn py_new(
         default_headers: HashMap<String, String>,
         header_keys: Vec<String>,
         keyed_quotas: Vec<(String, Quota)>,
         default_quota: Option<Quota>,
+        global_quota: Option<Quota>,
+        reject_when_limited: bool,
+        max_retries: u32,
     ) -> Self {
-        Self::new(default_headers, header_keys, keyed_quotas, default_quota)
+        Self::new(
+            default_headers,
+            header_keys,
+            keyed_quotas,
+            default_quota,
+            global_quota,
+            reject_when_limited,
+            max_retries,
+        )
     }
 
     /// Send an HTTP request.
@@ -125,6 +144,7 @@ impl HttpClient {
     /// `headers`: The header key value pairs in the request.
     /// `body`: The bytes sent in the body of request.
     /// `keys`: The keys used for rate limiting the request.
+    /// `weight`: The number of quota cells the request consumes (defaults to 1).
     ///
     /// # Example
     ///
@@ -133,7 +153,7 @@ impl HttpClient {
     /// For request /foo/bar, should pass keys ["foo/bar", "foo"] for rate limiting.
     #[pyo3(name = "request")]
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (method, url, headers=None, body=None, keys=None, timeout_secs=None))]
+    #[pyo3(signature = (method, url, headers=None, body=None, keys=None, timeout_secs=None, weight=None))]
     fn py_request<'py>(
         &self,
         method: HttpMethod,
@@ -142,15 +162,22 @@ impl HttpClient {
         body: Option<Vec<u8>>,
         keys: Option<Vec<String>>,
         timeout_secs: Option<u64>,
+        weight: Option<u32>,
         py: Python<'py>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.client.clone();
-        let rate_limiter = self.rate_limiter.clone();
+        let client = self.clone();
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            rate_limiter.await_keys_ready(keys).await;
             client
-                .send_request(method.into(), url, headers, body, timeout_secs)
+                .request(
+                    method.into(),
+                    url,
+                    headers,
+                    body,
+                    keys,
+                    timeout_secs,
+                    weight,
+                )
                 .await
                 .map_err(HttpClientError::into_py_err)
         })
@@ -22,7 +22,7 @@ pub mod websocket;
 use pyo3::{prelude::*, PyTypeCheck};
 
 use crate::python::{
-    http::{HttpError, HttpTimeoutError},
+    http::{HttpError, HttpRateLimitError, HttpTimeoutError},
     websocket::WebSocketClientError,
 };
 
@@ -37,6 +37,10 @@ pub fn network(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::websocket::WebSocketConfig>()?;
     m.add_class::<crate::socket::SocketClient>()?;
     m.add_class::<crate::socket::SocketConfig>()?;
+    m.add(
+        stringify!(UNLIMITED_RECONNECTION_TRIES),
+        crate::websocket::UNLIMITED_RECONNECTION_TRIES,
+    )?;
 
     // Add error classes
     m.add(
@@ -51,6 +55,10 @@ pub fn network(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         <HttpTimeoutError as PyTypeCheck>::NAME,
         m.py().get_type_bound::<HttpTimeoutError>(),
     )?;
+    m.add(
+        <HttpRateLimitError as PyTypeCheck>::NAME,
+        m.py().get_type_bound::<HttpRateLimitError>(),
+    )?;
 
     Ok(())
 }
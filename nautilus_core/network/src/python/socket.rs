@@ -15,32 +15,52 @@
 
 use std::sync::{atomic::Ordering, Arc};
 
-use nautilus_core::python::to_pyruntime_err;
+use nautilus_core::python::{to_pyruntime_err, to_pyvalue_err};
 use pyo3::prelude::*;
 use tokio::io::AsyncWriteExt;
 use tokio_tungstenite::tungstenite::stream::Mode;
 
-use crate::socket::{SocketClient, SocketConfig};
+use crate::socket::{MessageFraming, SocketClient, SocketConfig};
 
 #[pymethods]
 impl SocketConfig {
+    /// Creates a new socket config.
+    ///
+    /// Messages are terminated by `suffix`, unless `length_prefix_width` is given in
+    /// which case messages are prefixed with their big-endian length of that many bytes.
     #[new]
-    #[pyo3(signature = (url, ssl, suffix, handler, heartbeat=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (url, ssl, suffix, handler, heartbeat=None, length_prefix_width=None, max_reconnection_tries=3, reconnect_delay_initial_ms=None, reconnect_delay_max_ms=None, reconnect_backoff_factor=None, reconnect_jitter_ms=None))]
     fn py_new(
         url: String,
         ssl: bool,
         suffix: Vec<u8>,
         handler: PyObject,
         heartbeat: Option<(u64, Vec<u8>)>,
-    ) -> Self {
+        length_prefix_width: Option<u8>,
+        max_reconnection_tries: Option<u64>,
+        reconnect_delay_initial_ms: Option<u64>,
+        reconnect_delay_max_ms: Option<u64>,
+        reconnect_backoff_factor: Option<f64>,
+        reconnect_jitter_ms: Option<u64>,
+    ) -> PyResult<Self> {
         let mode = if ssl { Mode::Tls } else { Mode::Plain };
-        Self {
+        let framing = match length_prefix_width {
+            Some(width) => MessageFraming::length_prefixed(width).map_err(to_pyvalue_err)?,
+            None => MessageFraming::Delimited(suffix),
+        };
+        Ok(Self {
             url,
             mode,
-            suffix,
+            framing,
             handler: Arc::new(handler),
             heartbeat,
-        }
+            max_reconnection_tries,
+            reconnect_delay_initial_ms,
+            reconnect_delay_max_ms,
+            reconnect_backoff_factor,
+            reconnect_jitter_ms,
+        })
     }
 }
 
@@ -114,11 +134,11 @@ impl SocketClient {
     #[pyo3(name = "send")]
     fn py_send<'py>(
         slf: PyRef<'_, Self>,
-        mut data: Vec<u8>,
+        data: Vec<u8>,
         py: Python<'py>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let writer = slf.writer.clone();
-        data.extend(&slf.suffix);
+        let data = slf.framing.encode(&data).map_err(to_pyvalue_err)?;
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let mut writer = writer.lock().await;
@@ -146,7 +166,7 @@ mod tests {
     use tokio_tungstenite::tungstenite::stream::Mode;
     use tracing_test::traced_test;
 
-    use crate::socket::{SocketClient, SocketConfig};
+    use crate::socket::{MessageFraming, SocketClient, SocketConfig};
 
     struct TestServer {
         task: JoinHandle<()>,
@@ -254,8 +274,13 @@ counter = Counter()",
             url: format!("127.0.0.1:{}", server.port),
             handler: Arc::new(handler),
             mode: Mode::Plain,
-            suffix: b"\r\n".to_vec(),
+            framing: MessageFraming::Delimited(b"\r\n".to_vec()),
             heartbeat: None,
+            max_reconnection_tries: Some(3),
+            reconnect_delay_initial_ms: None,
+            reconnect_delay_max_ms: None,
+            reconnect_backoff_factor: None,
+            reconnect_jitter_ms: None,
         };
         let client: SocketClient = SocketClient::connect(config, None, None, None)
             .await
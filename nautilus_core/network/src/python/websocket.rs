@@ -35,7 +35,8 @@ fn to_websocket_pyerr(e: tokio_tungstenite::tungstenite::Error) -> PyErr {
 #[pymethods]
 impl WebSocketConfig {
     #[new]
-    #[pyo3(signature = (url, handler, headers, heartbeat=None, heartbeat_msg=None, ping_handler=None, max_reconnection_tries=3))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (url, handler, headers, heartbeat=None, heartbeat_msg=None, ping_handler=None, max_reconnection_tries=3, reconnect_delay_initial_ms=None, reconnect_delay_max_ms=None, reconnect_backoff_factor=None, reconnect_jitter_ms=None, state_handler=None))]
     fn py_new(
         url: String,
         handler: PyObject,
@@ -44,6 +45,11 @@ impl WebSocketConfig {
         heartbeat_msg: Option<String>,
         ping_handler: Option<PyObject>,
         max_reconnection_tries: Option<u64>,
+        reconnect_delay_initial_ms: Option<u64>,
+        reconnect_delay_max_ms: Option<u64>,
+        reconnect_backoff_factor: Option<f64>,
+        reconnect_jitter_ms: Option<u64>,
+        state_handler: Option<PyObject>,
     ) -> Self {
         Self {
             url,
@@ -53,6 +59,11 @@ impl WebSocketConfig {
             heartbeat_msg,
             ping_handler: ping_handler.map(Arc::new),
             max_reconnection_tries,
+            reconnect_delay_initial_ms,
+            reconnect_delay_max_ms,
+            reconnect_backoff_factor,
+            reconnect_jitter_ms,
+            state_handler: state_handler.map(Arc::new),
         }
     }
 }
@@ -122,6 +133,57 @@ impl WebSocketClient {
         !slf.controller_task.is_finished()
     }
 
+    /// Returns the current connection state name of the client.
+    #[pyo3(name = "connection_state")]
+    fn py_connection_state(slf: PyRef<'_, Self>) -> &'static str {
+        slf.connection_state().as_str()
+    }
+
+    /// Returns the subscription messages which will be replayed on reconnection.
+    #[pyo3(name = "subscriptions")]
+    fn py_subscriptions(slf: PyRef<'_, Self>) -> Vec<String> {
+        slf.subscriptions()
+    }
+
+    /// Send UTF-8 encoded bytes as a text subscription message, recording it for
+    /// replay when the client reconnects.
+    ///
+    /// # Errors
+    ///
+    /// - Raises `PyRuntimeError` if unable to send the data.
+    #[pyo3(name = "send_subscription")]
+    #[pyo3(signature = (data, keys=None))]
+    fn py_send_subscription<'py>(
+        slf: PyRef<'_, Self>,
+        data: Vec<u8>,
+        py: Python<'py>,
+        keys: Option<Vec<String>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let data = String::from_utf8(data).map_err(to_pyvalue_err)?;
+        slf.record_subscription(data.clone());
+        let writer = slf.writer.clone();
+        let rate_limiter = slf.rate_limiter.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            rate_limiter.await_keys_ready(keys).await;
+            tracing::trace!("Sending subscription: {data}");
+
+            let mut guard = writer.lock().await;
+            guard
+                .send(Message::Text(data))
+                .await
+                .map_err(to_websocket_pyerr)
+        })
+    }
+
+    /// Remove a recorded subscription message so it is no longer replayed on reconnection.
+    #[pyo3(name = "remove_subscription")]
+    fn py_remove_subscription(slf: PyRef<'_, Self>, data: Vec<u8>) -> PyResult<()> {
+        let data = String::from_utf8(data).map_err(to_pyvalue_err)?;
+        slf.remove_subscription(&data);
+        Ok(())
+    }
+
     /// Send bytes data to the server.
     ///
     /// # Errors
@@ -233,7 +295,9 @@ mod tests {
     };
     use tracing_test::traced_test;
 
-    use crate::websocket::{WebSocketClient, WebSocketConfig};
+    use crate::websocket::{
+        ConnectionState, WebSocketClient, WebSocketConfig, UNLIMITED_RECONNECTION_TRIES,
+    };
 
     struct TestServer {
         task: JoinHandle<()>,
@@ -359,6 +423,11 @@ counter = Counter()",
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         );
         let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None)
             .await
@@ -464,6 +533,11 @@ checker = Checker()",
             Some("heartbeat message".to_string()),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
         );
         let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None)
             .await
@@ -486,4 +560,87 @@ checker = Checker()",
         client.disconnect().await;
         assert!(client.is_disconnected());
     }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_reconnect_replays_subscriptions() {
+        prepare_freethreaded_python();
+
+        let header_key = "hello-custom-key".to_string();
+        let header_value = "hello-custom-value".to_string();
+
+        // Create counter class and handler that counts echoed subscription messages
+        let (counter, handler) = Python::with_gil(|py| {
+            let pymod = PyModule::from_code_bound(
+                py,
+                r"
+class Counter:
+    def __init__(self):
+        self.count = 0
+
+    def handler(self, bytes):
+        if bytes.decode() == 'subscribe':
+            self.count = self.count + 1
+
+    def get_count(self):
+        return self.count
+
+counter = Counter()",
+                "",
+                "",
+            )
+            .unwrap();
+
+            let counter = pymod.getattr("counter").unwrap().into_py(py);
+            let handler = counter.getattr(py, "handler").unwrap().into_py(py);
+
+            (counter, handler)
+        });
+
+        let server = TestServer::setup(header_key.clone(), header_value.clone()).await;
+        let config = WebSocketConfig::py_new(
+            format!("ws://127.0.0.1:{}", server.port),
+            Python::with_gil(|py| handler.clone_ref(py)),
+            vec![(header_key, header_value)],
+            None,
+            None,
+            None,
+            Some(UNLIMITED_RECONNECTION_TRIES),
+            Some(100),
+            Some(1_000),
+            None,
+            Some(0),
+            None,
+        );
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None)
+            .await
+            .unwrap();
+
+        client
+            .send_subscription("subscribe".to_string())
+            .await
+            .unwrap();
+        assert_eq!(client.subscriptions(), vec!["subscribe".to_string()]);
+
+        // Close the connection, the client should reconnect and replay the subscription
+        client.send_close_message().await;
+        sleep(Duration::from_secs(2)).await;
+
+        let count_value: usize = Python::with_gil(|py| {
+            counter
+                .getattr(py, "get_count")
+                .unwrap()
+                .call0(py)
+                .unwrap()
+                .extract(py)
+                .unwrap()
+        });
+        assert_eq!(count_value, 2);
+        assert_eq!(client.connection_state(), ConnectionState::Connected);
+
+        // Shutdown client
+        client.disconnect().await;
+        assert!(client.is_disconnected());
+        assert_eq!(client.connection_state(), ConnectionState::Disconnected);
+    }
 }
@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{cmp, fmt::Display, time::Duration};
+use std::{cmp, fmt::Display, num::NonZeroU32, time::Duration};
 
 use super::{clock, nanos::Nanos, quota::Quota, StateStore};
 
@@ -115,6 +115,22 @@ impl<P: clock::Reference> Display for NotUntil<P> {
     }
 }
 
+/// Error indicating that the number of cells tested exceeds the burst capacity of the quota.
+///
+/// The wrapped value is the maximum number of cells which can be let through at once.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InsufficientCapacity(pub u32);
+
+impl Display for InsufficientCapacity {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "required number of cells exceeds burst capacity of {}",
+            self.0
+        )
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Gcra {
     /// The "weight" of a single packet in units of time.
@@ -161,4 +177,40 @@ impl Gcra {
             }
         })
     }
+
+    /// Tests `n` cells at once against the rate limiter state and updates it at the given key.
+    ///
+    /// Either all `n` cells conform and the state is updated for all of them, or none do.
+    pub(crate) fn test_n_all_and_update<K, S: StateStore<Key = K>, P: clock::Reference>(
+        &self,
+        start: P,
+        key: &K,
+        n: NonZeroU32,
+        state: &S,
+        t0: P,
+    ) -> Result<Result<(), NotUntil<P>>, InsufficientCapacity> {
+        let t0 = t0.duration_since(start);
+        let tau = self.tau;
+        let t = self.t;
+        let additional_weight = t * (u64::from(n.get()) - 1);
+
+        // Check that the bucket can ever let this many cells through
+        if additional_weight + t > tau {
+            return Err(InsufficientCapacity((tau.as_u64() / t.as_u64()) as u32));
+        }
+
+        Ok(state.measure_and_replace(key, |tat| {
+            let tat = tat.unwrap_or_else(|| self.starting_state(t0));
+            let earliest_time = (tat + additional_weight).saturating_sub(tau);
+            if t0 < earliest_time {
+                Err(NotUntil::new(
+                    StateSnapshot::new(self.t, self.tau, earliest_time, earliest_time),
+                    start,
+                ))
+            } else {
+                let next = cmp::max(tat, t0) + t + additional_weight;
+                Ok(((), next))
+            }
+        }))
+    }
 }
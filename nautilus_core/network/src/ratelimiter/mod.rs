@@ -23,7 +23,7 @@ pub mod quota;
 
 use std::{
     hash::Hash,
-    num::NonZeroU64,
+    num::{NonZeroU32, NonZeroU64},
     sync::atomic::{AtomicU64, Ordering},
     time::Duration,
 };
@@ -34,7 +34,7 @@ use tokio::time::sleep;
 
 use self::{
     clock::{Clock, FakeRelativeClock, MonotonicClock},
-    gcra::{Gcra, NotUntil},
+    gcra::{Gcra, InsufficientCapacity, NotUntil},
     nanos::Nanos,
     quota::Quota,
 };
@@ -126,10 +126,35 @@ impl<K: Hash + Eq + Clone> StateStore for DashMapStateStore<K> {
     }
 }
 
+/// A direct (not keyed) state store, used to track a single global rate limit.
+impl StateStore for InMemoryState {
+    type Key = ();
+
+    fn measure_and_replace<T, F, E>(&self, _key: &Self::Key, f: F) -> Result<T, E>
+    where
+        F: Fn(Option<Nanos>) -> Result<(T, Nanos), E>,
+    {
+        self.measure_and_replace_one(f)
+    }
+}
+
+/// Represents a negative rate limiting decision.
+#[derive(thiserror::Error, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RateLimitError {
+    /// The request weight exceeds the burst capacity of the quota, so can never be let through.
+    #[error("Request weight {weight} exceeds burst capacity {capacity}")]
+    InsufficientCapacity { weight: u32, capacity: u32 },
+    /// The quota is currently exhausted, the wait until it may conform is given.
+    #[error("Rate limit exhausted, retry after {0:?}")]
+    Exhausted(Duration),
+}
+
 pub struct RateLimiter<K, C>
 where
     C: Clock,
 {
+    global_gcra: Option<Gcra>,
+    global_state: InMemoryState,
     default_gcra: Option<Gcra>,
     state: DashMapStateStore<K>,
     gcra: DashMap<K, Gcra>,
@@ -146,6 +171,8 @@ where
         let start = MonotonicClock::now(&clock);
         let gcra = DashMap::from_iter(keyed_quotas.into_iter().map(|(k, q)| (k, Gcra::new(q))));
         Self {
+            global_gcra: None,
+            global_state: InMemoryState::default(),
             default_gcra: base_quota.map(Gcra::new),
             state: DashMapStateStore::new(),
             gcra,
@@ -182,26 +209,140 @@ where
         }
     }
 
+    /// Sets the global quota, which every request is checked against in addition to its keys.
+    pub fn set_global_quota(&mut self, quota: Option<Quota>) {
+        self.global_gcra = quota.map(Gcra::new);
+    }
+
+    /// Checks `weight` cells against the quota for `key` (or the default quota if none is set).
+    pub fn check_key_n(&self, key: &K, weight: NonZeroU32) -> Result<(), RateLimitError> {
+        let decision = match self.gcra.get(key) {
+            Some(gcra) => {
+                gcra.test_n_all_and_update(self.start, key, weight, &self.state, self.clock.now())
+            }
+            None => match self.default_gcra.as_ref() {
+                Some(gcra) => gcra.test_n_all_and_update(
+                    self.start,
+                    key,
+                    weight,
+                    &self.state,
+                    self.clock.now(),
+                ),
+                None => return Ok(()),
+            },
+        };
+        self.to_rate_limit_result(decision, weight)
+    }
+
+    /// Checks `weight` cells against the global quota (passes if no global quota is set).
+    pub fn check_global_n(&self, weight: NonZeroU32) -> Result<(), RateLimitError> {
+        match self.global_gcra.as_ref() {
+            Some(gcra) => {
+                let decision = gcra.test_n_all_and_update(
+                    self.start,
+                    &(),
+                    weight,
+                    &self.global_state,
+                    self.clock.now(),
+                );
+                self.to_rate_limit_result(decision, weight)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Checks `weight` cells against the global quota and then each of the given `keys`,
+    /// without waiting.
+    ///
+    /// Cells are consumed for each quota checked before the first negative decision.
+    pub fn check_keys_n(&self, keys: &[K], weight: NonZeroU32) -> Result<(), RateLimitError> {
+        self.check_global_n(weight)?;
+        keys.iter()
+            .try_for_each(|key| self.check_key_n(key, weight))
+    }
+
+    fn to_rate_limit_result(
+        &self,
+        decision: Result<Result<(), NotUntil<C::Instant>>, InsufficientCapacity>,
+        weight: NonZeroU32,
+    ) -> Result<(), RateLimitError> {
+        match decision {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(not_until)) => Err(RateLimitError::Exhausted(
+                not_until.wait_time_from(self.clock.now()),
+            )),
+            Err(InsufficientCapacity(capacity)) => Err(RateLimitError::InsufficientCapacity {
+                weight: weight.get(),
+                capacity,
+            }),
+        }
+    }
+
     pub async fn until_key_ready(&self, key: &K) {
+        // A single cell always fits within the burst capacity of a quota
+        let _ = self.until_key_ready_n(key, NonZeroU32::MIN).await;
+    }
+
+    /// Waits until `weight` cells conform to the quota for `key`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `weight` exceeds the burst capacity of the quota.
+    pub async fn until_key_ready_n(
+        &self,
+        key: &K,
+        weight: NonZeroU32,
+    ) -> Result<(), RateLimitError> {
+        loop {
+            match self.check_key_n(key, weight) {
+                Ok(()) => return Ok(()),
+                Err(RateLimitError::Exhausted(wait)) => sleep(wait).await,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Waits until `weight` cells conform to the global quota.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `weight` exceeds the burst capacity of the quota.
+    pub async fn until_global_ready_n(&self, weight: NonZeroU32) -> Result<(), RateLimitError> {
         loop {
-            match self.check_key(key) {
-                Ok(x) => x,
-                Err(neg) => {
-                    sleep(neg.wait_time_from(self.clock.now())).await;
-                }
+            match self.check_global_n(weight) {
+                Ok(()) => return Ok(()),
+                Err(RateLimitError::Exhausted(wait)) => sleep(wait).await,
+                Err(e) => return Err(e),
             }
         }
     }
 
     pub async fn await_keys_ready(&self, keys: Option<Vec<K>>) {
+        // A single cell always fits within the burst capacity of a quota
+        let _ = self.await_keys_ready_n(keys, NonZeroU32::MIN).await;
+    }
+
+    /// Waits until `weight` cells conform to the global quota and the quotas for all `keys`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `weight` exceeds the burst capacity of any quota.
+    pub async fn await_keys_ready_n(
+        &self,
+        keys: Option<Vec<K>>,
+        weight: NonZeroU32,
+    ) -> Result<(), RateLimitError> {
+        self.until_global_ready_n(weight).await?;
+
         let keys = keys.unwrap_or_default();
-        let tasks = keys.iter().map(|key| self.until_key_ready(key));
+        let tasks = keys.iter().map(|key| self.until_key_ready_n(key, weight));
 
         futures::stream::iter(tasks)
-            .for_each_concurrent(None, |key_future| async move {
-                key_future.await;
-            })
-            .await;
+            .buffer_unordered(keys.len().max(1))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect()
     }
 }
 
@@ -218,7 +359,7 @@ mod tests {
         clock::{Clock, FakeRelativeClock},
         gcra::Gcra,
         quota::Quota,
-        DashMapStateStore, RateLimiter,
+        DashMapStateStore, InMemoryState, RateLimitError, RateLimiter,
     };
 
     fn initialize_mock_rate_limiter() -> RateLimiter<String, FakeRelativeClock> {
@@ -227,6 +368,8 @@ mod tests {
         let gcra = DashMap::new();
         let base_quota = Quota::per_second(NonZeroU32::new(2).unwrap());
         RateLimiter {
+            global_gcra: None,
+            global_state: InMemoryState::default(),
             default_gcra: Some(Gcra::new(base_quota)),
             state: DashMapStateStore::new(),
             gcra,
@@ -342,4 +485,72 @@ mod tests {
         assert!(mock_limiter.check_key(&"per_second".to_string()).is_ok());
         assert!(mock_limiter.check_key(&"per_minute".to_string()).is_err());
     }
+
+    #[test]
+    fn test_weighted_check() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        mock_limiter.add_quota_for_key(
+            "weighted".to_string(),
+            Quota::per_second(NonZeroU32::new(5).unwrap()),
+        );
+
+        // Consume 3 of 5 cells, a further weight of 3 must wait
+        assert!(mock_limiter
+            .check_key_n(&"weighted".to_string(), NonZeroU32::new(3).unwrap())
+            .is_ok());
+        assert!(matches!(
+            mock_limiter.check_key_n(&"weighted".to_string(), NonZeroU32::new(3).unwrap()),
+            Err(RateLimitError::Exhausted(_))
+        ));
+        assert!(mock_limiter
+            .check_key_n(&"weighted".to_string(), NonZeroU32::new(2).unwrap())
+            .is_ok());
+
+        // Replenish the full burst
+        mock_limiter.advance_clock(Duration::from_secs(1));
+        assert!(mock_limiter
+            .check_key_n(&"weighted".to_string(), NonZeroU32::new(5).unwrap())
+            .is_ok());
+    }
+
+    #[test]
+    fn test_weight_exceeding_capacity() {
+        let mock_limiter = initialize_mock_rate_limiter();
+
+        assert_eq!(
+            mock_limiter.check_key_n(&"default".to_string(), NonZeroU32::new(3).unwrap()),
+            Err(RateLimitError::InsufficientCapacity {
+                weight: 3,
+                capacity: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_global_quota_applies_across_keys() {
+        let mut mock_limiter = initialize_mock_rate_limiter();
+        mock_limiter.set_global_quota(Some(Quota::per_second(NonZeroU32::new(3).unwrap())));
+        let weight = NonZeroU32::new(1).unwrap();
+
+        assert!(mock_limiter
+            .check_keys_n(&["key1".to_string()], weight)
+            .is_ok());
+        assert!(mock_limiter
+            .check_keys_n(&["key2".to_string()], weight)
+            .is_ok());
+        assert!(mock_limiter
+            .check_keys_n(&["key3".to_string()], weight)
+            .is_ok());
+
+        // Global quota is exhausted even though each key has capacity
+        assert!(matches!(
+            mock_limiter.check_keys_n(&["key4".to_string()], weight),
+            Err(RateLimitError::Exhausted(_))
+        ));
+
+        mock_limiter.advance_clock(Duration::from_secs(1));
+        assert!(mock_limiter
+            .check_keys_n(&["key4".to_string()], weight)
+            .is_ok());
+    }
 }
@@ -37,12 +37,185 @@ use tokio_tungstenite::{
     MaybeTlsStream,
 };
 
-use crate::tls::tcp_tls;
+use crate::{backoff::ExponentialBackoff, tls::tcp_tls};
 
 type TcpWriter = WriteHalf<MaybeTlsStream<TcpStream>>;
 type SharedTcpWriter = Arc<Mutex<WriteHalf<MaybeTlsStream<TcpStream>>>>;
 type TcpReader = ReadHalf<MaybeTlsStream<TcpStream>>;
 
+/// The default maximum length of a length prefixed message (16 MiB).
+pub const DEFAULT_MAX_FRAME_LEN: usize = 16 * 1024 * 1024;
+
+/// Represents the width in bytes of a message length prefix, one of 1, 2, 4 or 8.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PrefixWidth(u8);
+
+impl PrefixWidth {
+    pub const ONE: Self = Self(1);
+    pub const TWO: Self = Self(2);
+    pub const FOUR: Self = Self(4);
+    pub const EIGHT: Self = Self(8);
+
+    /// Creates a new [`PrefixWidth`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `width` is not one of 1, 2, 4 or 8 bytes.
+    pub fn new(width: u8) -> anyhow::Result<Self> {
+        match width {
+            1 | 2 | 4 | 8 => Ok(Self(width)),
+            _ => anyhow::bail!("Invalid length prefix width {width}, expected 1, 2, 4 or 8"),
+        }
+    }
+
+    /// Returns the width in bytes.
+    #[must_use]
+    pub const fn get(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Represents how messages are framed on the raw byte stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MessageFraming {
+    /// Messages are terminated by the given delimiter bytes (e.g. `\r\n` or SOH).
+    ///
+    /// An empty delimiter passes data through as it is received.
+    Delimited(Vec<u8>),
+    /// Messages are prefixed with their length as a big-endian unsigned integer
+    /// of `width` bytes, and are at most `max_frame_len` bytes long.
+    LengthPrefixed {
+        width: PrefixWidth,
+        max_frame_len: usize,
+    },
+}
+
+impl MessageFraming {
+    /// Creates a framing for messages terminated by a newline.
+    #[must_use]
+    pub fn newline() -> Self {
+        Self::Delimited(b"\n".to_vec())
+    }
+
+    /// Creates a framing for messages terminated by the SOH (`0x01`) character.
+    #[must_use]
+    pub fn soh() -> Self {
+        Self::Delimited(vec![0x01])
+    }
+
+    /// Creates a framing for messages prefixed with their length, bounded by
+    /// [`DEFAULT_MAX_FRAME_LEN`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `width` is not one of 1, 2, 4 or 8 bytes.
+    pub fn length_prefixed(width: u8) -> anyhow::Result<Self> {
+        Ok(Self::LengthPrefixed {
+            width: PrefixWidth::new(width)?,
+            max_frame_len: DEFAULT_MAX_FRAME_LEN,
+        })
+    }
+
+    /// Encodes the message `data` into a frame to write to the stream.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `data` is too long for the length prefix width
+    /// or exceeds the maximum frame length.
+    pub fn encode(&self, data: &[u8]) -> Result<Vec<u8>, std::io::Error> {
+        match self {
+            Self::Delimited(delimiter) => {
+                let mut frame = Vec::with_capacity(data.len() + delimiter.len());
+                frame.extend_from_slice(data);
+                frame.extend_from_slice(delimiter);
+                Ok(frame)
+            }
+            Self::LengthPrefixed {
+                width,
+                max_frame_len,
+            } => {
+                let width = width.get();
+                if data.len() > *max_frame_len {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!(
+                            "Message length {} exceeds maximum frame length {max_frame_len}",
+                            data.len()
+                        ),
+                    ));
+                }
+                let len = data.len() as u64;
+                if width < 8 && len >> (width * 8) != 0 {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!("Message length {len} exceeds {width} byte length prefix"),
+                    ));
+                }
+                let mut frame = Vec::with_capacity(width + data.len());
+                frame.extend_from_slice(&len.to_be_bytes()[8 - width..]);
+                frame.extend_from_slice(data);
+                Ok(frame)
+            }
+        }
+    }
+
+    /// Removes and returns the next complete message from the front of `buf`,
+    /// or `None` if `buf` does not yet contain a complete frame.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the length prefix exceeds the maximum frame
+    /// length, after which the stream can no longer be framed.
+    pub fn decode(&self, buf: &mut Vec<u8>) -> Result<Option<Vec<u8>>, std::io::Error> {
+        match self {
+            Self::Delimited(delimiter) if delimiter.is_empty() => {
+                Ok((!buf.is_empty()).then(|| std::mem::take(buf)))
+            }
+            Self::Delimited(delimiter) => {
+                let Some(i) = buf
+                    .windows(delimiter.len())
+                    .position(|window| window == delimiter.as_slice())
+                else {
+                    return Ok(None);
+                };
+                let mut data: Vec<u8> = buf.drain(..i + delimiter.len()).collect();
+                data.truncate(i);
+                Ok(Some(data))
+            }
+            Self::LengthPrefixed {
+                width,
+                max_frame_len,
+            } => {
+                let width = width.get();
+                if buf.len() < width {
+                    return Ok(None);
+                }
+                let len = buf[..width]
+                    .iter()
+                    .fold(0u64, |acc, byte| (acc << 8) | u64::from(*byte));
+                let len = usize::try_from(len)
+                    .ok()
+                    .filter(|len| len <= max_frame_len)
+                    .ok_or_else(|| {
+                        std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            format!(
+                                "Frame length {len} exceeds maximum frame length {max_frame_len}"
+                            ),
+                        )
+                    })?;
+                let end = width + len;
+                if buf.len() < end {
+                    return Ok(None);
+                }
+                let data = buf[width..end].to_vec();
+                buf.drain(..end);
+                Ok(Some(data))
+            }
+        }
+    }
+}
+
 /// Configuration for TCP socket connection.
 #[derive(Debug, Clone)]
 #[cfg_attr(
@@ -54,12 +227,35 @@ pub struct SocketConfig {
     pub url: String,
     /// The connection mode {Plain, TLS}.
     pub mode: Mode,
-    /// The sequence of bytes which separates lines.
-    pub suffix: Vec<u8>,
+    /// The framing which separates messages on the byte stream.
+    pub framing: MessageFraming,
     /// The Python function to handle incoming messages.
     pub handler: Arc<PyObject>,
-    /// The optional heartbeat with period and beat message.
+    /// The optional heartbeat with period (seconds) and beat message.
     pub heartbeat: Option<(u64, Vec<u8>)>,
+    /// The maximum number of consecutive reconnection attempts (`None` retries indefinitely).
+    pub max_reconnection_tries: Option<u64>,
+    /// The initial reconnection delay (milliseconds).
+    pub reconnect_delay_initial_ms: Option<u64>,
+    /// The maximum reconnection delay (milliseconds).
+    pub reconnect_delay_max_ms: Option<u64>,
+    /// The factor applied to the reconnection delay after each failed attempt.
+    pub reconnect_backoff_factor: Option<f64>,
+    /// The maximum random jitter added to each reconnection delay (milliseconds).
+    pub reconnect_jitter_ms: Option<u64>,
+}
+
+impl SocketConfig {
+    /// Returns the reconnection backoff for the config.
+    #[must_use]
+    pub fn reconnect_backoff(&self) -> ExponentialBackoff {
+        ExponentialBackoff::from_optional(
+            self.reconnect_delay_initial_ms,
+            self.reconnect_delay_max_ms,
+            self.reconnect_backoff_factor,
+            self.reconnect_jitter_ms,
+        )
+    }
 }
 
 /// Creates a TcpStream with the server.
@@ -74,9 +270,9 @@ pub struct SocketConfig {
 /// The heartbeat is optional and can be configured with an interval and data to
 /// send.
 ///
-/// The client uses a suffix to separate messages on the byte stream. It is
-/// appended to all sent messages and heartbeats. It is also used the split
-/// the received byte stream.
+/// The client uses a [`MessageFraming`] to separate messages on the byte stream.
+/// All sent messages and heartbeats are encoded with it, and the received byte
+/// stream is split into messages with it.
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
@@ -96,19 +292,20 @@ impl SocketClientInner {
             url,
             mode,
             heartbeat,
-            suffix,
+            framing,
             handler,
+            ..
         } = &config;
         let (reader, writer) = Self::tls_connect_with_server(url, *mode).await?;
         let shared_writer = Arc::new(Mutex::new(writer));
 
         let handler1 = Python::with_gil(|py| handler.clone_ref(py));
         // Keep receiving messages from socket pass them as arguments to handler
-        let read_task = Self::spawn_read_task(reader, handler1, suffix.clone());
+        let read_task = Self::spawn_read_task(reader, handler1, framing.clone());
 
         // Optionally create heartbeat task
         let heartbeat_task =
-            Self::spawn_heartbeat_task(heartbeat.clone(), shared_writer.clone(), suffix.clone());
+            Self::spawn_heartbeat_task(heartbeat.clone(), shared_writer.clone(), framing.clone());
 
         Ok(Self {
             config,
@@ -133,7 +330,7 @@ impl SocketClientInner {
     pub fn spawn_read_task(
         mut reader: TcpReader,
         handler: PyObject,
-        suffix: Vec<u8>,
+        framing: MessageFraming,
     ) -> task::JoinHandle<()> {
         // Keep receiving messages from socket pass them as arguments to handler
         task::spawn(async move {
@@ -154,21 +351,24 @@ impl SocketClientInner {
                     Ok(bytes) => {
                         tracing::trace!("Received <binary> {bytes} bytes");
 
-                        // While received data has a line break
+                        // While received data has a complete frame
                         // drain it and pass it to the handler
-                        while let Some((i, _)) = &buf
-                            .windows(suffix.len())
-                            .enumerate()
-                            .find(|(_, pair)| pair.eq(&suffix))
-                        {
-                            let mut data: Vec<u8> = buf.drain(0..i + suffix.len()).collect();
-                            data.truncate(data.len() - suffix.len());
-
-                            if let Err(e) =
-                                Python::with_gil(|py| handler.call1(py, (data.as_slice(),)))
-                            {
-                                tracing::error!("Call to handler failed: {e}");
-                                break;
+                        loop {
+                            match framing.decode(&mut buf) {
+                                Ok(Some(data)) => {
+                                    if let Err(e) =
+                                        Python::with_gil(|py| handler.call1(py, (data.as_slice(),)))
+                                    {
+                                        tracing::error!("Call to handler failed: {e}");
+                                        break;
+                                    }
+                                }
+                                Ok(None) => break,
+                                Err(e) => {
+                                    // The stream cannot be re-synchronized past a bad frame
+                                    tracing::error!("Failed to decode frame: {e}");
+                                    return;
+                                }
                             }
                         }
                     }
@@ -181,12 +381,18 @@ impl SocketClientInner {
     pub fn spawn_heartbeat_task(
         heartbeat: Option<(u64, Vec<u8>)>,
         writer: SharedTcpWriter,
-        suffix: Vec<u8>,
+        framing: MessageFraming,
     ) -> Option<task::JoinHandle<()>> {
-        heartbeat.map(|(duration, mut message)| {
+        heartbeat.map(|(duration, message)| {
             task::spawn(async move {
                 let duration = Duration::from_secs(duration);
-                message.extend(suffix);
+                let message = match framing.encode(&message) {
+                    Ok(frame) => frame,
+                    Err(e) => {
+                        tracing::error!("Failed to encode heartbeat: {e}");
+                        return;
+                    }
+                };
                 loop {
                     sleep(duration).await;
                     tracing::debug!("Sending heartbeat");
@@ -236,8 +442,9 @@ impl SocketClientInner {
             url,
             mode,
             heartbeat,
-            suffix,
+            framing,
             handler,
+            ..
         } = &self.config;
         tracing::debug!("Reconnecting client");
         let (reader, new_writer) = Self::tls_connect_with_server(url, *mode).await?;
@@ -249,9 +456,9 @@ impl SocketClientInner {
 
         let handler1 = Python::with_gil(|py| handler.clone_ref(py));
         tracing::debug!("Recreate reader and heartbeat task");
-        self.read_task = Self::spawn_read_task(reader, handler1, suffix.clone());
+        self.read_task = Self::spawn_read_task(reader, handler1, framing.clone());
         self.heartbeat_task =
-            Self::spawn_heartbeat_task(heartbeat.clone(), self.writer.clone(), suffix.clone());
+            Self::spawn_heartbeat_task(heartbeat.clone(), self.writer.clone(), framing.clone());
         Ok(())
     }
 
@@ -291,7 +498,7 @@ pub struct SocketClient {
     pub(crate) writer: SharedTcpWriter,
     pub(crate) controller_task: task::JoinHandle<()>,
     pub(crate) disconnect_mode: Arc<AtomicBool>,
-    pub(crate) suffix: Vec<u8>,
+    pub(crate) framing: MessageFraming,
 }
 
 impl SocketClient {
@@ -301,7 +508,7 @@ impl SocketClient {
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
     ) -> Result<Self, Error> {
-        let suffix = config.suffix.clone();
+        let framing = config.framing.clone();
         let inner = SocketClientInner::connect_url(config).await?;
         let writer = inner.writer.clone();
         let disconnect_mode = Arc::new(AtomicBool::new(false));
@@ -324,7 +531,7 @@ impl SocketClient {
             writer,
             controller_task,
             disconnect_mode,
-            suffix,
+            framing,
         })
     }
 
@@ -351,10 +558,11 @@ impl SocketClient {
         }
     }
 
+    /// Sends the message `data` to the server, encoded with the configured framing.
     pub async fn send_bytes(&self, data: &[u8]) -> Result<(), std::io::Error> {
+        let frame = self.framing.encode(data)?;
         let mut writer = self.writer.lock().await;
-        writer.write_all(data).await?;
-        writer.write_all(&self.suffix).await
+        writer.write_all(&frame).await
     }
 
     #[must_use]
@@ -369,6 +577,10 @@ impl SocketClient {
         post_disconnection: Option<PyObject>,
     ) -> task::JoinHandle<()> {
         task::spawn(async move {
+            let max_reconnection_tries = inner.config.max_reconnection_tries;
+            let mut backoff = inner.config.reconnect_backoff();
+            let mut retry_counter: u64 = 0;
+
             loop {
                 sleep(Duration::from_millis(100)).await;
 
@@ -378,6 +590,9 @@ impl SocketClient {
                     (false, false) => match inner.reconnect().await {
                         Ok(()) => {
                             tracing::debug!("Reconnected successfully");
+                            retry_counter = 0;
+                            backoff.reset();
+
                             if let Some(ref handler) = post_reconnection {
                                 Python::with_gil(|py| match handler.call0(py) {
                                     Ok(_) => tracing::debug!("Called `post_reconnection` handler"),
@@ -390,8 +605,17 @@ impl SocketClient {
                             }
                         }
                         Err(e) => {
-                            tracing::error!("Reconnect failed {e}");
-                            break;
+                            if max_reconnection_tries.is_some_and(|max| retry_counter >= max) {
+                                tracing::error!("Reconnect failed {e}, max retries exhausted");
+                                break;
+                            }
+
+                            retry_counter += 1;
+                            let delay = backoff.next_duration();
+                            tracing::warn!(
+                                "Reconnect failed {e}. Retry {retry_counter} in {delay:?}"
+                            );
+                            sleep(delay).await;
                         }
                     },
                     (true, true) => {
@@ -420,3 +644,86 @@ impl SocketClient {
         })
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_delimited_framing_roundtrip() {
+        let framing = MessageFraming::soh();
+        let mut buf = framing.encode(b"8=FIX.4.4").unwrap();
+        buf.extend(framing.encode(b"35=A").unwrap());
+        buf.extend_from_slice(b"partial");
+
+        assert_eq!(
+            framing.decode(&mut buf).unwrap(),
+            Some(b"8=FIX.4.4".to_vec())
+        );
+        assert_eq!(framing.decode(&mut buf).unwrap(), Some(b"35=A".to_vec()));
+        assert_eq!(framing.decode(&mut buf).unwrap(), None);
+        assert_eq!(buf, b"partial".to_vec());
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(4)]
+    #[case(8)]
+    fn test_length_prefixed_framing_roundtrip(#[case] width: u8) {
+        let framing = MessageFraming::length_prefixed(width).unwrap();
+        let frame = framing.encode(b"hello").unwrap();
+        assert_eq!(frame.len(), width as usize + 5);
+
+        // Incomplete frame is retained until the rest arrives
+        let mut buf = frame[..frame.len() - 1].to_vec();
+        assert_eq!(framing.decode(&mut buf).unwrap(), None);
+
+        buf.push(*frame.last().unwrap());
+        assert_eq!(framing.decode(&mut buf).unwrap(), Some(b"hello".to_vec()));
+        assert!(buf.is_empty());
+    }
+
+    #[rstest]
+    fn test_length_prefixed_encode_when_message_too_long() {
+        let framing = MessageFraming::length_prefixed(1).unwrap();
+
+        assert!(framing.encode(&[0u8; 256]).is_err());
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(3)]
+    #[case(16)]
+    fn test_length_prefixed_with_invalid_width(#[case] width: u8) {
+        assert!(PrefixWidth::new(width).is_err());
+        assert!(MessageFraming::length_prefixed(width).is_err());
+    }
+
+    #[rstest]
+    fn test_length_prefixed_encode_when_exceeds_max_frame_len() {
+        let framing = MessageFraming::LengthPrefixed {
+            width: PrefixWidth::FOUR,
+            max_frame_len: 4,
+        };
+
+        assert!(framing.encode(b"1234").is_ok());
+        assert!(framing.encode(b"12345").is_err());
+    }
+
+    #[rstest]
+    fn test_length_prefixed_decode_when_exceeds_max_frame_len() {
+        let framing = MessageFraming::LengthPrefixed {
+            width: PrefixWidth::EIGHT,
+            max_frame_len: 4,
+        };
+        let mut buf = u64::MAX.to_be_bytes().to_vec();
+
+        assert!(framing.decode(&mut buf).is_err());
+    }
+}
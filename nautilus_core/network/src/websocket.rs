@@ -16,7 +16,7 @@
 //! A high-performance WebSocket client implementation.
 use std::{
     sync::{
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicU8, Ordering},
         Arc,
     },
     time::Duration,
@@ -35,12 +35,18 @@ use tokio_tungstenite::{
     MaybeTlsStream, WebSocketStream,
 };
 
-use crate::ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter};
+use crate::{
+    backoff::ExponentialBackoff,
+    ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter},
+};
 type MessageWriter = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
 type SharedMessageWriter =
     Arc<Mutex<SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>>>;
 pub type MessageReader = SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>;
 
+/// The `max_reconnection_tries` value which keeps reconnecting indefinitely.
+pub const UNLIMITED_RECONNECTION_TRIES: u64 = u64::MAX;
+
 #[derive(Debug, Clone)]
 #[cfg_attr(
     feature = "python",
@@ -53,7 +59,75 @@ pub struct WebSocketConfig {
     pub heartbeat: Option<u64>,
     pub heartbeat_msg: Option<String>,
     pub ping_handler: Option<Arc<PyObject>>,
+    /// The maximum number of consecutive reconnection attempts (`None` does not reconnect).
+    ///
+    /// Use [`UNLIMITED_RECONNECTION_TRIES`] to keep reconnecting indefinitely.
     pub max_reconnection_tries: Option<u64>,
+    /// The initial reconnection delay (milliseconds).
+    pub reconnect_delay_initial_ms: Option<u64>,
+    /// The maximum reconnection delay (milliseconds).
+    pub reconnect_delay_max_ms: Option<u64>,
+    /// The factor applied to the reconnection delay after each failed attempt.
+    pub reconnect_backoff_factor: Option<f64>,
+    /// The maximum random jitter added to each reconnection delay (milliseconds).
+    pub reconnect_jitter_ms: Option<u64>,
+    /// The handler called with the new [`ConnectionState`] name on each state change.
+    pub state_handler: Option<Arc<PyObject>>,
+}
+
+impl WebSocketConfig {
+    /// Returns the reconnection backoff for the config.
+    #[must_use]
+    pub fn reconnect_backoff(&self) -> ExponentialBackoff {
+        ExponentialBackoff::from_optional(
+            self.reconnect_delay_initial_ms,
+            self.reconnect_delay_max_ms,
+            self.reconnect_backoff_factor,
+            self.reconnect_jitter_ms,
+        )
+    }
+}
+
+/// Represents the connection state of a [`WebSocketClient`].
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionState {
+    /// The client is connected to the server.
+    Connected = 0,
+    /// The connection was lost and the client is attempting to reconnect.
+    Reconnecting = 1,
+    /// The client was explicitly disconnected.
+    Disconnected = 2,
+    /// The client stopped after exhausting its reconnection attempts.
+    Closed = 3,
+}
+
+impl ConnectionState {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Connected,
+            1 => Self::Reconnecting,
+            2 => Self::Disconnected,
+            _ => Self::Closed,
+        }
+    }
+
+    /// Returns the name of the state.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Connected => "CONNECTED",
+            Self::Reconnecting => "RECONNECTING",
+            Self::Disconnected => "DISCONNECTED",
+            Self::Closed => "CLOSED",
+        }
+    }
+}
+
+impl std::fmt::Display for ConnectionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 /// `WebSocketClient` connects to a websocket server to read and send messages.
@@ -83,7 +157,6 @@ impl WebSocketClientInner {
     pub async fn connect_url(config: WebSocketConfig) -> Result<Self, Error> {
         install_cryptographic_provider();
 
-        #[allow(unused_variables)]
         let WebSocketConfig {
             url,
             handler,
@@ -91,7 +164,7 @@ impl WebSocketClientInner {
             headers,
             heartbeat_msg,
             ping_handler,
-            max_reconnection_tries,
+            ..
         } = &config;
         let (writer, reader) = Self::connect_with_server(url, headers.clone()).await?;
         let writer = Arc::new(Mutex::new(writer));
@@ -332,6 +405,8 @@ pub struct WebSocketClient {
     pub(crate) controller_task: task::JoinHandle<()>,
     pub(crate) rate_limiter: Arc<RateLimiter<String, MonotonicClock>>,
     pub(crate) disconnect_mode: Arc<AtomicBool>,
+    pub(crate) state: Arc<AtomicU8>,
+    pub(crate) subscriptions: Arc<std::sync::Mutex<Vec<String>>>,
 }
 
 impl WebSocketClient {
@@ -360,19 +435,27 @@ impl WebSocketClient {
                 heartbeat_msg,
                 ping_handler: None,
                 max_reconnection_tries,
+                reconnect_delay_initial_ms: None,
+                reconnect_delay_max_ms: None,
+                reconnect_backoff_factor: None,
+                reconnect_jitter_ms: None,
+                state_handler: None,
             }
         };
 
         let disconnect_mode = Arc::new(AtomicBool::new(false));
+        let state = Arc::new(AtomicU8::new(ConnectionState::Connected as u8));
+        let subscriptions = Arc::new(std::sync::Mutex::new(Vec::new()));
         let rate_limiter = Arc::new(RateLimiter::new_with_quota(default_quota, keyed_quotas));
 
         let inner = WebSocketClientInner::connect_url(config).await?;
         let controller_task = Self::spawn_controller_task(
             inner,
             disconnect_mode.clone(),
+            state.clone(),
+            subscriptions.clone(),
             None, // no post_reconnection
             None, // no post_disconnection
-            max_reconnection_tries,
         );
 
         Ok((
@@ -382,6 +465,8 @@ impl WebSocketClient {
                 controller_task,
                 rate_limiter,
                 disconnect_mode,
+                state,
+                subscriptions,
             },
         ))
     }
@@ -402,13 +487,16 @@ impl WebSocketClient {
         let inner = WebSocketClientInner::connect_url(config.clone()).await?;
         let writer = inner.writer.clone();
         let disconnect_mode = Arc::new(AtomicBool::new(false));
+        let state = Arc::new(AtomicU8::new(ConnectionState::Connected as u8));
+        let subscriptions = Arc::new(std::sync::Mutex::new(Vec::new()));
 
         let controller_task = Self::spawn_controller_task(
             inner,
             disconnect_mode.clone(),
+            state.clone(),
+            subscriptions.clone(),
             post_reconnection,
             post_disconnection,
-            config.max_reconnection_tries,
         );
         let rate_limiter = Arc::new(RateLimiter::new_with_quota(default_quota, keyed_quotas));
 
@@ -424,6 +512,8 @@ impl WebSocketClient {
             controller_task,
             rate_limiter,
             disconnect_mode,
+            state,
+            subscriptions,
         })
     }
 
@@ -432,6 +522,53 @@ impl WebSocketClient {
         self.controller_task.is_finished()
     }
 
+    /// Returns the current connection state of the client.
+    #[must_use]
+    pub fn connection_state(&self) -> ConnectionState {
+        ConnectionState::from_u8(self.state.load(Ordering::SeqCst))
+    }
+
+    /// Returns the subscription messages which will be replayed on reconnection.
+    #[must_use]
+    pub fn subscriptions(&self) -> Vec<String> {
+        self.subscriptions
+            .lock()
+            .expect("Subscriptions lock poisoned")
+            .clone()
+    }
+
+    /// Records the subscription message `data` for replay on reconnection.
+    ///
+    /// Messages are replayed in the order they were first recorded, duplicates are ignored.
+    pub fn record_subscription(&self, data: String) {
+        let mut subscriptions = self
+            .subscriptions
+            .lock()
+            .expect("Subscriptions lock poisoned");
+        if !subscriptions.contains(&data) {
+            subscriptions.push(data);
+        }
+    }
+
+    /// Removes the subscription message `data` so it is no longer replayed on reconnection.
+    pub fn remove_subscription(&self, data: &str) {
+        self.subscriptions
+            .lock()
+            .expect("Subscriptions lock poisoned")
+            .retain(|msg| msg != data);
+    }
+
+    /// Sends the subscription message `data` as text and records it for replay on reconnection.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if sending the message fails (the message is still
+    /// recorded, so it will be sent once the client reconnects).
+    pub async fn send_subscription(&self, data: String) -> Result<(), Error> {
+        self.record_subscription(data.clone());
+        self.send_text(data).await
+    }
+
     /// Set disconnect mode to true.
     ///
     /// Controller task will periodically check the disconnect mode
@@ -479,56 +616,93 @@ impl WebSocketClient {
     fn spawn_controller_task(
         mut inner: WebSocketClientInner,
         disconnect_mode: Arc<AtomicBool>,
+        state: Arc<AtomicU8>,
+        subscriptions: Arc<std::sync::Mutex<Vec<String>>>,
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
-        max_reconnection_tries: Option<u64>,
     ) -> task::JoinHandle<()> {
         task::spawn(async move {
             let check_interval = Duration::from_millis(100);
-            let retry_interval = Duration::from_millis(1000);
+            let max_reconnection_tries = inner.config.max_reconnection_tries;
+            let state_handler = inner.config.state_handler.clone();
+            let mut backoff = inner.config.reconnect_backoff();
             let mut retry_counter: u64 = 0;
 
+            let set_state = |new_state: ConnectionState| {
+                let previous = state.swap(new_state as u8, Ordering::SeqCst);
+                if previous == new_state as u8 {
+                    return;
+                }
+                tracing::debug!("Connection state changed to {new_state}");
+
+                if let Some(ref handler) = state_handler {
+                    Python::with_gil(|py| match handler.call1(py, (new_state.as_str(),)) {
+                        Ok(_) => tracing::debug!("Called `state_handler` handler"),
+                        Err(e) => tracing::error!("Error calling `state_handler` handler: {e}"),
+                    });
+                }
+            };
+
             loop {
                 sleep(check_interval).await;
 
                 // Check if client needs to disconnect
                 let disconnect = disconnect_mode.load(Ordering::SeqCst);
                 match (disconnect, inner.is_alive()) {
-                    (false, false) => match inner.reconnect().await {
-                        Ok(()) => {
-                            tracing::debug!("Reconnected successfully");
-                            retry_counter = 0;
-
-                            if let Some(ref handler) = post_reconnection {
-                                Python::with_gil(|py| match handler.call0(py) {
-                                    Ok(_) => tracing::debug!("Called `post_reconnection` handler"),
-                                    Err(e) => {
-                                        tracing::error!(
-                                            "Error calling `post_reconnection` handler: {e}"
-                                        );
-                                    }
-                                });
+                    (false, false) => {
+                        set_state(ConnectionState::Reconnecting);
+
+                        match inner.reconnect().await {
+                            Ok(()) => {
+                                tracing::debug!("Reconnected successfully");
+                                retry_counter = 0;
+                                backoff.reset();
+
+                                Self::replay_subscriptions(&inner, &subscriptions).await;
+                                set_state(ConnectionState::Connected);
+
+                                if let Some(ref handler) = post_reconnection {
+                                    Python::with_gil(|py| match handler.call0(py) {
+                                        Ok(_) => {
+                                            tracing::debug!("Called `post_reconnection` handler");
+                                        }
+                                        Err(e) => {
+                                            tracing::error!(
+                                                "Error calling `post_reconnection` handler: {e}"
+                                            );
+                                        }
+                                    });
+                                }
                             }
-                        }
-                        Err(e) => {
-                            if let Some(max_reconnection_tries) = max_reconnection_tries {
-                                if retry_counter < max_reconnection_tries {
-                                    retry_counter += 1;
-                                    tracing::warn!("Reconnect failed {e}. Retry {retry_counter}/{max_reconnection_tries}");
-                                    sleep(retry_interval).await;
-                                } else {
-                                    tracing::error!("Reconnect failed {e}");
+                            Err(e) => {
+                                let Some(max) =
+                                    max_reconnection_tries.filter(|max| retry_counter < *max)
+                                else {
+                                    tracing::error!("Reconnect failed {e}, no retries remaining");
+                                    set_state(ConnectionState::Closed);
                                     break;
+                                };
+
+                                retry_counter += 1;
+                                let delay = backoff.next_duration();
+                                if max == UNLIMITED_RECONNECTION_TRIES {
+                                    tracing::warn!(
+                                        "Reconnect failed {e}. Retry {retry_counter} in {delay:?}"
+                                    );
+                                } else {
+                                    tracing::warn!(
+                                        "Reconnect failed {e}. Retry {retry_counter}/{max} in {delay:?}"
+                                    );
                                 }
-                            } else {
-                                tracing::error!("Reconnect failed {e}");
-                                break;
+                                sleep(delay).await;
                             }
                         }
-                    },
+                    }
                     (true, true) => {
                         tracing::debug!("Shutting down inner client");
                         inner.shutdown().await;
+                        set_state(ConnectionState::Disconnected);
+
                         if let Some(ref handler) = post_disconnection {
                             Python::with_gil(|py| match handler.call0(py) {
                                 Ok(_) => tracing::debug!("Called `post_disconnection` handler"),
@@ -546,10 +720,33 @@ impl WebSocketClient {
                         tracing::debug!("Inner client is disconnected");
                         tracing::debug!("Shutting down inner client to clean up running tasks");
                         inner.shutdown().await;
+                        set_state(ConnectionState::Disconnected);
                     }
                     _ => (),
                 }
             }
         })
     }
+
+    /// Replays the recorded subscription messages on the (re)connected `inner` client.
+    async fn replay_subscriptions(
+        inner: &WebSocketClientInner,
+        subscriptions: &std::sync::Mutex<Vec<String>>,
+    ) {
+        let messages = subscriptions
+            .lock()
+            .expect("Subscriptions lock poisoned")
+            .clone();
+        if messages.is_empty() {
+            return;
+        }
+
+        tracing::debug!("Replaying {} subscription message(s)", messages.len());
+        let mut guard = inner.writer.lock().await;
+        for msg in messages {
+            if let Err(e) = guard.send(Message::Text(msg.clone())).await {
+                tracing::error!("Error replaying subscription {msg}: {e}");
+            }
+        }
+    }
 }
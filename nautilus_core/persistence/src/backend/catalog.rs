@@ -13,28 +13,33 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::path::PathBuf;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 
 use datafusion::{arrow::record_batch::RecordBatch, error::Result};
 use heck::ToSnakeCase;
 use itertools::Itertools;
 use log::info;
-use nautilus_core::nanos::UnixNanos;
-use nautilus_model::data::{
-    Bar, Data, GetTsInit, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick,
+use nautilus_core::{datetime::unix_nanos_to_iso8601, nanos::UnixNanos};
+use nautilus_model::{
+    data::{Bar, Data, GetTsInit, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick},
+    instruments::InstrumentAny,
 };
 use nautilus_serialization::{
     arrow::{DecodeDataFromRecordBatch, EncodeToRecordBatch},
     parquet::write_batches_to_parquet,
 };
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 
-use super::session::{self, build_query, DataBackendSession, QueryResult};
+use super::session::{self, build_query, DataBackendSession, QueryResult, QueryResultBatches};
 
 pub struct ParquetDataCatalog {
     base_path: PathBuf,
     batch_size: usize,
     session: DataBackendSession,
+    table_count: usize,
 }
 
 impl ParquetDataCatalog {
@@ -45,6 +50,7 @@ impl ParquetDataCatalog {
             base_path,
             batch_size,
             session: session::DataBackendSession::new(batch_size),
+            table_count: 0,
         }
     }
 
@@ -93,9 +99,21 @@ impl ParquetDataCatalog {
         T: GetTsInit + Serialize,
     {
         let type_name = std::any::type_name::<T>().to_snake_case();
-        Self::check_ascending_timestamps(&data, &type_name);
+        self.write_custom_to_json(&type_name, data)
+    }
 
-        let path = self.make_path(&type_name, None);
+    /// Writes the given custom `data` to JSON under the directory for `type_name`.
+    ///
+    /// Custom data types share a single Rust type, so the `type_name` of the
+    /// data type is used to keep each stream separate in the catalog.
+    #[must_use]
+    pub fn write_custom_to_json<T>(&self, type_name: &str, data: Vec<T>) -> PathBuf
+    where
+        T: GetTsInit + Serialize,
+    {
+        Self::check_ascending_timestamps(&data, type_name);
+
+        let path = self.make_path(type_name, None);
         let json_path = path.with_extension("json");
 
         info!(
@@ -114,6 +132,32 @@ impl ParquetDataCatalog {
         json_path
     }
 
+    /// Reads the custom data previously written for `type_name`, optionally
+    /// filtered to the inclusive `start` and `end` range of `ts_init`.
+    pub fn read_custom_from_json<T>(
+        &self,
+        type_name: &str,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<Vec<T>>
+    where
+        T: GetTsInit + DeserializeOwned,
+    {
+        let json_path = self.make_path(type_name, None).with_extension("json");
+        if !json_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let file = std::fs::File::open(&json_path)?;
+        let data: Vec<T> = serde_json::from_reader(std::io::BufReader::new(file))?;
+
+        Ok(data
+            .into_iter()
+            .filter(|d| start.map_or(true, |start| d.ts_init() >= start))
+            .filter(|d| end.map_or(true, |end| d.ts_init() <= end))
+            .collect())
+    }
+
     pub fn write_to_parquet<T>(&self, data: Vec<T>)
     where
         T: GetTsInit + EncodeToRecordBatch,
@@ -140,7 +184,70 @@ impl ParquetDataCatalog {
         }
     }
 
-    /// Query data loaded in the catalog
+    /// Writes the given `data` to Parquet files partitioned by instrument and UTC date.
+    ///
+    /// Files are written to `{base_path}/data/{type_name}/{partition}/{date}.parquet`, where the
+    /// partition is the bar type for bars and the instrument ID otherwise. If a file already
+    /// exists for the same partition and date then the data is written to the next free
+    /// `{date}-{n}.parquet` file, so existing data is never overwritten. Returns the paths of
+    /// the files written.
+    pub fn write_to_partitioned_parquet<T>(
+        &self,
+        type_name: &str,
+        data: Vec<T>,
+    ) -> anyhow::Result<Vec<PathBuf>>
+    where
+        T: GetTsInit + EncodeToRecordBatch,
+    {
+        let mut partitions: BTreeMap<(String, String), Vec<T>> = BTreeMap::new();
+        for item in data {
+            let metadata = item.metadata();
+            let partition = metadata
+                .get("bar_type")
+                .or_else(|| metadata.get("instrument_id"))
+                .ok_or_else(|| anyhow::anyhow!("No partition key found in {type_name} metadata"))?
+                .replace('/', "");
+            let date = unix_nanos_to_iso8601(item.ts_init())[..10].to_string();
+            partitions.entry((partition, date)).or_default().push(item);
+        }
+
+        let mut paths = Vec::with_capacity(partitions.len());
+        for ((partition, date), mut items) in partitions {
+            items.sort_by_key(GetTsInit::ts_init);
+
+            let dir = self.base_path.join("data").join(type_name).join(partition);
+            let path = Self::next_free_path(&dir, &date);
+            let batches = self.data_to_record_batches(items);
+
+            info!(
+                "Writing {} batches of {} data to {:?}",
+                batches.len(),
+                type_name,
+                path
+            );
+            write_batches_to_parquet(&batches, &path, None, Some(self.batch_size))
+                .map_err(|e| anyhow::anyhow!("Failed to write {type_name} to parquet: {e}"))?;
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+
+    /// Writes the given `instruments` to Parquet files partitioned by instrument ID and UTC date.
+    pub fn write_instruments(
+        &self,
+        instruments: Vec<InstrumentAny>,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        self.write_to_partitioned_parquet("instrument", instruments)
+    }
+
+    /// Queries the catalog for data of type `T`, returning a result which yields
+    /// the data in ascending order of `ts_init`.
+    ///
+    /// Only the partitions for the given `instrument_ids` (or bar types) are read, all
+    /// partitions are read if none are given. Files for dates outside the `start` and
+    /// `end` range are skipped, and the time range and `where_clause` are pushed down
+    /// to the Parquet reader so row groups can be pruned using their statistics.
     pub fn query<T>(
         &mut self,
         // use instrument_ids or bar_types to query specific subset of the data
@@ -152,23 +259,131 @@ impl ParquetDataCatalog {
     where
         T: DecodeDataFromRecordBatch,
     {
-        let mut paths = Vec::new();
-        for instrument_id in &instrument_ids {
-            paths.push(self.make_path("TODO", Some(instrument_id)));
+        self.add_query::<T>(instrument_ids, start, end, where_clause)?;
+        Ok(self.get_query_result())
+    }
+
+    /// Registers a query for data of type `T` without consuming it, so that queries for
+    /// multiple data types can be merged into a single stream ordered by `ts_init`.
+    ///
+    /// The registered queries are consumed by [`Self::get_query_result`] or
+    /// [`Self::get_query_result_batches`].
+    pub fn add_query<T>(
+        &mut self,
+        instrument_ids: Vec<String>,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        where_clause: Option<&str>,
+    ) -> Result<()>
+    where
+        T: DecodeDataFromRecordBatch,
+    {
+        let type_name = Self::type_dir_name::<T>();
+        let paths = self.query_files(&type_name, &instrument_ids, start, end)?;
+
+        for path in &paths {
+            // Table names must be unique for the lifetime of the session
+            let table_name = format!("{type_name}_{}", self.table_count);
+            self.table_count += 1;
+
+            let query = build_query(&table_name, start, end, where_clause);
+            self.session
+                .add_file::<T>(&table_name, &path.to_string_lossy(), Some(&query))?;
         }
 
-        // If no specific instrument_id is selected query all files for the data type
-        if paths.is_empty() {
-            paths.push(self.make_path("TODO", None));
+        Ok(())
+    }
+
+    /// Consumes all registered queries, returning a result which yields the merged
+    /// data in ascending order of `ts_init`.
+    pub fn get_query_result(&mut self) -> QueryResult {
+        self.session.get_query_result()
+    }
+
+    /// Consumes all registered queries, returning an iterator over the merged data in
+    /// batches of at most the catalogs `batch_size`, in ascending order of `ts_init`.
+    pub fn get_query_result_batches(&mut self) -> QueryResultBatches {
+        QueryResultBatches::new(self.session.get_query_result(), self.batch_size)
+    }
+
+    /// Returns the Parquet files for `type_name` which may contain data for the
+    /// given `instrument_ids` within the `start` and `end` range.
+    fn query_files(
+        &self,
+        type_name: &str,
+        instrument_ids: &[String],
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> Result<Vec<PathBuf>> {
+        let type_dir = self.base_path.join("data").join(type_name);
+        if !type_dir.exists() {
+            return Ok(Vec::new());
         }
 
-        for path in &paths {
-            let path = path.to_str().unwrap();
-            let query = build_query(path, start, end, where_clause);
-            self.session.add_file::<T>(path, path, Some(&query))?;
+        let partition_dirs: Vec<PathBuf> = if instrument_ids.is_empty() {
+            std::fs::read_dir(&type_dir)?
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.is_dir())
+                .collect()
+        } else {
+            instrument_ids
+                .iter()
+                .map(|id| type_dir.join(id.replace('/', "")))
+                .filter(|path| path.is_dir())
+                .collect()
+        };
+
+        // File names are UTC dates, which order the same as their ISO 8601 strings
+        let start_date = start.map(|ts| unix_nanos_to_iso8601(ts)[..10].to_string());
+        let end_date = end.map(|ts| unix_nanos_to_iso8601(ts)[..10].to_string());
+
+        let mut files = Vec::new();
+        for dir in partition_dirs {
+            for entry in std::fs::read_dir(&dir)? {
+                let path = entry?.path();
+                if path.extension().and_then(|ext| ext.to_str()) != Some("parquet") {
+                    continue;
+                }
+                // Stems are either `{date}` or `{date}-{n}` for subsequent writes
+                let Some(date) = path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .and_then(|stem| stem.get(..10))
+                else {
+                    continue;
+                };
+                if start_date.as_deref().is_some_and(|start| date < start)
+                    || end_date.as_deref().is_some_and(|end| date > end)
+                {
+                    continue;
+                }
+                files.push(path);
+            }
+        }
+
+        files.sort();
+        Ok(files)
+    }
+
+    fn next_free_path(dir: &Path, date: &str) -> PathBuf {
+        let path = dir.join(format!("{date}.parquet"));
+        if !path.exists() {
+            return path;
         }
 
-        Ok(self.session.get_query_result())
+        (1..)
+            .map(|i| dir.join(format!("{date}-{i}.parquet")))
+            .find(|path| !path.exists())
+            .expect("Unbounded range always yields a free path")
+    }
+
+    fn type_dir_name<T>() -> String {
+        let type_name = std::any::type_name::<T>();
+        type_name
+            .rsplit("::")
+            .next()
+            .unwrap_or(type_name)
+            .to_snake_case()
     }
 
     pub fn write_data_enum(&self, data: Vec<Data>) {
@@ -205,4 +420,33 @@ impl ParquetDataCatalog {
         self.write_to_parquet(trade);
         self.write_to_parquet(bar);
     }
+
+    /// Writes the given `data` to Parquet files partitioned by instrument and UTC date,
+    /// with a directory per data type. Returns the paths of the files written.
+    pub fn write_data_enum_partitioned(&self, data: Vec<Data>) -> anyhow::Result<Vec<PathBuf>> {
+        let mut delta: Vec<OrderBookDelta> = Vec::new();
+        let mut depth10: Vec<OrderBookDepth10> = Vec::new();
+        let mut quote: Vec<QuoteTick> = Vec::new();
+        let mut trade: Vec<TradeTick> = Vec::new();
+        let mut bar: Vec<Bar> = Vec::new();
+
+        for d in data {
+            match d {
+                Data::Delta(d) => delta.push(d),
+                Data::Deltas(d) => delta.extend(d.deltas.iter().copied()),
+                Data::Depth10(d) => depth10.push(d),
+                Data::Quote(d) => quote.push(d),
+                Data::Trade(d) => trade.push(d),
+                Data::Bar(d) => bar.push(d),
+            }
+        }
+
+        let mut paths = Vec::new();
+        paths.extend(self.write_to_partitioned_parquet("order_book_delta", delta)?);
+        paths.extend(self.write_to_partitioned_parquet("order_book_depth10", depth10)?);
+        paths.extend(self.write_to_partitioned_parquet("quote_tick", quote)?);
+        paths.extend(self.write_to_partitioned_parquet("trade_tick", trade)?);
+        paths.extend(self.write_to_partitioned_parquet("bar", bar)?);
+        Ok(paths)
+    }
 }
@@ -0,0 +1,429 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Streaming of live data to rotating Arrow IPC (Feather) files.
+
+use std::{
+    any::Any, cell::RefCell, collections::BTreeMap, fs::File, io::BufWriter, path::PathBuf, rc::Rc,
+};
+
+use datafusion::arrow::{
+    compute::concat_batches, ipc::writer::StreamWriter, record_batch::RecordBatch,
+};
+use nautilus_common::{
+    clock::Clock,
+    custom::CustomData,
+    messages::data::DataResponse,
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+};
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::{Bar, Data, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick},
+    instruments::InstrumentAny,
+};
+use nautilus_serialization::arrow::EncodeToRecordBatch;
+use ustr::Ustr;
+
+/// Configuration for a [`FeatherWriter`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeatherWriterConfig {
+    /// The interval (milliseconds) between flushes of buffered data to the files.
+    pub flush_interval_ms: u64,
+    /// The file size (bytes) at which a file is closed and a new file started.
+    pub max_file_size: u64,
+}
+
+impl Default for FeatherWriterConfig {
+    /// Creates a new default [`FeatherWriterConfig`] instance.
+    fn default() -> Self {
+        Self {
+            flush_interval_ms: 1_000,
+            max_file_size: 1024 * 1024 * 1024,
+        }
+    }
+}
+
+type PartitionKey = (String, String);
+
+struct OpenFile {
+    path: PathBuf,
+    writer: StreamWriter<BufWriter<File>>,
+}
+
+/// Writes data to Arrow IPC stream (Feather) files, with a file per data type and partition.
+///
+/// Files are written to `{base_path}/{type_name}/{partition}_{timestamp}.feather`, where the
+/// partition is the bar type for bars, the instrument ID for other market data and instruments,
+/// and the topic for custom data. Data is buffered in memory and written to the files when the
+/// flush interval has elapsed, and a file is closed once it reaches the maximum file size so the
+/// next data for the partition starts a new file.
+pub struct FeatherWriter {
+    base_path: PathBuf,
+    config: FeatherWriterConfig,
+    clock: Rc<RefCell<dyn Clock>>,
+    buffers: BTreeMap<PartitionKey, Vec<RecordBatch>>,
+    files: BTreeMap<PartitionKey, OpenFile>,
+    closed_paths: Vec<PathBuf>,
+    last_flush_ns: UnixNanos,
+}
+
+impl FeatherWriter {
+    /// Creates a new [`FeatherWriter`] instance.
+    pub fn new(
+        base_path: PathBuf,
+        config: FeatherWriterConfig,
+        clock: Rc<RefCell<dyn Clock>>,
+    ) -> Self {
+        let last_flush_ns = clock.borrow().timestamp_ns();
+        Self {
+            base_path,
+            config,
+            clock,
+            buffers: BTreeMap::new(),
+            files: BTreeMap::new(),
+            closed_paths: Vec::new(),
+            last_flush_ns,
+        }
+    }
+
+    /// Returns the paths of all files written to so far, including any currently open.
+    #[must_use]
+    pub fn paths(&self) -> Vec<PathBuf> {
+        let mut paths = self.closed_paths.clone();
+        paths.extend(self.files.values().map(|file| file.path.clone()));
+        paths
+    }
+
+    /// Writes the given market `data`.
+    pub fn write_data(&mut self, data: Data) -> anyhow::Result<()> {
+        match data {
+            Data::Delta(delta) => self.write("order_book_delta", None, delta),
+            Data::Deltas(deltas) => self.write_deltas(&deltas),
+            Data::Depth10(depth) => self.write("order_book_depth10", None, depth),
+            Data::Quote(quote) => self.write("quote_tick", None, quote),
+            Data::Trade(trade) => self.write("trade_tick", None, trade),
+            Data::Bar(bar) => self.write("bar", None, bar),
+        }
+    }
+
+    /// Writes each delta of the given order book `deltas`.
+    pub fn write_deltas(&mut self, deltas: &OrderBookDeltas) -> anyhow::Result<()> {
+        for delta in &deltas.deltas {
+            self.write("order_book_delta", None, *delta)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the given `instrument`.
+    pub fn write_instrument(&mut self, instrument: InstrumentAny) -> anyhow::Result<()> {
+        self.write("instrument", None, instrument)
+    }
+
+    /// Writes the given custom `data`, partitioned by its data type topic.
+    pub fn write_custom(&mut self, data: CustomData) -> anyhow::Result<()> {
+        let partition = data.data_type.topic().replace('/', "");
+        self.write("custom", Some(partition), data)
+    }
+
+    fn write<T: EncodeToRecordBatch>(
+        &mut self,
+        type_name: &str,
+        partition: Option<String>,
+        item: T,
+    ) -> anyhow::Result<()> {
+        let metadata = item.metadata();
+        let partition = match partition {
+            Some(partition) => partition,
+            None => metadata
+                .get("bar_type")
+                .or_else(|| metadata.get("instrument_id"))
+                .ok_or_else(|| anyhow::anyhow!("No partition key found in {type_name} metadata"))?
+                .replace('/', ""),
+        };
+        let batch = T::encode_batch(&metadata, std::slice::from_ref(&item))?;
+        self.buffers
+            .entry((type_name.to_string(), partition))
+            .or_default()
+            .push(batch);
+
+        let now_ns = self.clock.borrow().timestamp_ns();
+        if now_ns.as_u64()
+            >= self.last_flush_ns.as_u64() + self.config.flush_interval_ms * 1_000_000
+        {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Writes all buffered data to the files, rotating any file which has reached the
+    /// maximum file size.
+    pub fn flush(&mut self) -> anyhow::Result<()> {
+        let now_ns = self.clock.borrow().timestamp_ns();
+        for (key, batches) in std::mem::take(&mut self.buffers) {
+            let Some(first) = batches.first() else {
+                continue;
+            };
+            let batch = concat_batches(&first.schema(), &batches)?;
+
+            if !self.files.contains_key(&key) {
+                let file = self.open_file(&key, &batch, now_ns)?;
+                self.files.insert(key.clone(), file);
+            }
+            let file = self.files.get_mut(&key).expect("File was opened");
+            file.writer.write(&batch)?;
+            file.writer.flush()?;
+
+            if std::fs::metadata(&file.path)?.len() >= self.config.max_file_size {
+                self.close_file(&key)?;
+            }
+        }
+
+        self.last_flush_ns = now_ns;
+        Ok(())
+    }
+
+    /// Flushes all buffered data and closes all open files.
+    pub fn close(&mut self) -> anyhow::Result<()> {
+        self.flush()?;
+        let keys: Vec<PartitionKey> = self.files.keys().cloned().collect();
+        for key in keys {
+            self.close_file(&key)?;
+        }
+        Ok(())
+    }
+
+    fn open_file(
+        &self,
+        key: &PartitionKey,
+        batch: &RecordBatch,
+        now_ns: UnixNanos,
+    ) -> anyhow::Result<OpenFile> {
+        let (type_name, partition) = key;
+        let dir = self.base_path.join(type_name);
+        std::fs::create_dir_all(&dir)?;
+
+        // Avoid overwriting a file when rotating more than once within a clock tick
+        let mut path = dir.join(format!("{partition}_{now_ns}.feather"));
+        let mut n = 1;
+        while path.exists() {
+            path = dir.join(format!("{partition}_{now_ns}_{n}.feather"));
+            n += 1;
+        }
+
+        log::info!("Opening {path:?} for streaming {type_name} data");
+        let writer = StreamWriter::try_new(BufWriter::new(File::create(&path)?), &batch.schema())?;
+        Ok(OpenFile { path, writer })
+    }
+
+    fn close_file(&mut self, key: &PartitionKey) -> anyhow::Result<()> {
+        if let Some(mut file) = self.files.remove(key) {
+            file.writer.finish()?;
+            log::info!("Closed {:?}", file.path);
+            self.closed_paths.push(file.path);
+        }
+        Ok(())
+    }
+}
+
+/// A message handler which records all data published on its subscribed topics with a
+/// [`FeatherWriter`].
+pub struct FeatherDataRecorder {
+    id: Ustr,
+    writer: RefCell<FeatherWriter>,
+}
+
+impl FeatherDataRecorder {
+    /// Creates a new [`FeatherDataRecorder`] instance.
+    #[must_use]
+    pub fn new(writer: FeatherWriter) -> Self {
+        Self {
+            id: Ustr::from("FeatherDataRecorder"),
+            writer: RefCell::new(writer),
+        }
+    }
+
+    /// Subscribes the given `recorder` to each of the `topics` on the `msgbus`.
+    ///
+    /// Topics may contain wildcards, e.g. `data.quotes.*` to record all quotes.
+    pub fn subscribe(recorder: &Rc<Self>, msgbus: &mut MessageBus, topics: &[&str]) {
+        let handler = ShareableMessageHandler(recorder.clone());
+        for topic in topics {
+            msgbus.subscribe(*topic, handler.clone(), None);
+        }
+    }
+
+    /// Flushes all buffered data to the files.
+    pub fn flush(&self) -> anyhow::Result<()> {
+        self.writer.borrow_mut().flush()
+    }
+
+    /// Flushes all buffered data and closes all open files.
+    pub fn close(&self) -> anyhow::Result<()> {
+        self.writer.borrow_mut().close()
+    }
+
+    /// Returns the paths of all files written to so far.
+    #[must_use]
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.writer.borrow().paths()
+    }
+
+    fn record(&self, message: &dyn Any) -> anyhow::Result<()> {
+        let mut writer = self.writer.borrow_mut();
+        if let Some(quote) = message.downcast_ref::<QuoteTick>() {
+            writer.write_data(Data::Quote(*quote))
+        } else if let Some(trade) = message.downcast_ref::<TradeTick>() {
+            writer.write_data(Data::Trade(*trade))
+        } else if let Some(bar) = message.downcast_ref::<Bar>() {
+            writer.write_data(Data::Bar(*bar))
+        } else if let Some(delta) = message.downcast_ref::<OrderBookDelta>() {
+            writer.write_data(Data::Delta(*delta))
+        } else if let Some(deltas) = message.downcast_ref::<OrderBookDeltas>() {
+            writer.write_deltas(deltas)
+        } else if let Some(depth) = message.downcast_ref::<OrderBookDepth10>() {
+            writer.write_data(Data::Depth10(*depth))
+        } else if let Some(instrument) = message.downcast_ref::<InstrumentAny>() {
+            writer.write_instrument(instrument.clone())
+        } else if let Some(data) = message.downcast_ref::<CustomData>() {
+            writer.write_custom(data.clone())
+        } else if let Some(data) = message.downcast_ref::<Data>() {
+            writer.write_data(data.clone())
+        } else {
+            Ok(()) // Not a data message
+        }
+    }
+}
+
+impl MessageHandler for FeatherDataRecorder {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        if let Err(e) = self.record(message) {
+            log::error!("Error recording data: {e}");
+        }
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+
+    fn handle_data(&self, data: Data) {
+        if let Err(e) = self.writer.borrow_mut().write_data(data) {
+            log::error!("Error recording data: {e}");
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use datafusion::arrow::ipc::reader::StreamReader;
+    use nautilus_common::clock::TestClock;
+    use nautilus_model::data::stubs::{quote_ethusdt_binance, stub_bar};
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn count_rows(path: &Path) -> usize {
+        let reader = StreamReader::try_new(File::open(path).unwrap(), None).unwrap();
+        reader.map(|batch| batch.unwrap().num_rows()).sum()
+    }
+
+    #[rstest]
+    fn test_write_flushes_after_interval(quote_ethusdt_binance: QuoteTick) {
+        let temp_dir = TempDir::new().unwrap();
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let mut writer = FeatherWriter::new(
+            temp_dir.path().to_path_buf(),
+            FeatherWriterConfig::default(),
+            clock.clone(),
+        );
+
+        writer
+            .write_data(Data::Quote(quote_ethusdt_binance))
+            .unwrap();
+        assert!(writer.paths().is_empty());
+
+        clock
+            .borrow_mut()
+            .advance_time(UnixNanos::from(1_000_000_000), true);
+        writer
+            .write_data(Data::Quote(quote_ethusdt_binance))
+            .unwrap();
+        writer.close().unwrap();
+
+        let paths = writer.paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].starts_with(temp_dir.path().join("quote_tick")));
+        assert_eq!(count_rows(&paths[0]), 2);
+    }
+
+    #[rstest]
+    fn test_write_rotates_at_max_file_size(stub_bar: Bar) {
+        let temp_dir = TempDir::new().unwrap();
+        let config = FeatherWriterConfig {
+            flush_interval_ms: 0,
+            max_file_size: 1,
+        };
+        let mut writer = FeatherWriter::new(
+            temp_dir.path().to_path_buf(),
+            config,
+            Rc::new(RefCell::new(TestClock::new())),
+        );
+
+        writer.write_data(Data::Bar(stub_bar)).unwrap();
+        writer.write_data(Data::Bar(stub_bar)).unwrap();
+        writer.close().unwrap();
+
+        let paths = writer.paths();
+        assert_eq!(paths.len(), 2);
+        assert_ne!(paths[0], paths[1]);
+        assert!(paths.iter().all(|path| count_rows(path) == 1));
+    }
+
+    #[rstest]
+    fn test_recorder_records_published_data(quote_ethusdt_binance: QuoteTick) {
+        let temp_dir = TempDir::new().unwrap();
+        let writer = FeatherWriter::new(
+            temp_dir.path().to_path_buf(),
+            FeatherWriterConfig::default(),
+            Rc::new(RefCell::new(TestClock::new())),
+        );
+        let recorder = Rc::new(FeatherDataRecorder::new(writer));
+        let mut msgbus = MessageBus::default();
+        FeatherDataRecorder::subscribe(&recorder, &mut msgbus, &["data.quotes.*"]);
+
+        let topic = Ustr::from("data.quotes.BINANCE.ETHUSDT");
+        msgbus.publish(&topic, &quote_ethusdt_binance);
+        msgbus.publish_data(&topic, Data::Quote(quote_ethusdt_binance));
+        recorder.close().unwrap();
+
+        let paths = recorder.paths();
+        assert_eq!(paths.len(), 1);
+        assert_eq!(count_rows(&paths[0]), 2);
+    }
+}
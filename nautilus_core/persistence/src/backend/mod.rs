@@ -16,5 +16,6 @@
 //! Provides an Apache Parquet backend powered by [DataFusion](https://arrow.apache.org/datafusion).
 
 pub mod catalog;
+pub mod feather;
 pub mod kmerge_batch;
 pub mod session;
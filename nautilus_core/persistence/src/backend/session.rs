@@ -73,7 +73,9 @@ impl DataBackendSession {
             .unwrap();
         let session_cfg = SessionConfig::new()
             .set_str("datafusion.optimizer.repartition_file_scans", "false")
-            .set_str("datafusion.optimizer.prefer_existing_sort", "true");
+            .set_str("datafusion.optimizer.prefer_existing_sort", "true")
+            .set_str("datafusion.execution.parquet.pushdown_filters", "true")
+            .set_str("datafusion.execution.parquet.reorder_filters", "true");
         let session_ctx = SessionContext::new_with_config(session_cfg);
         Self {
             session_ctx,
@@ -215,6 +217,41 @@ pub fn build_query(
     query
 }
 
+/// Iterates over a [`QueryResult`] in batches of at most `batch_size` elements.
+///
+/// Each underlying stream only buffers a bounded number of record batches ahead of
+/// the merge, so data larger than memory can be streamed through in `ts_init` order.
+pub struct QueryResultBatches {
+    result: QueryResult,
+    batch_size: usize,
+}
+
+impl QueryResultBatches {
+    /// Creates a new [`QueryResultBatches`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `batch_size` is zero.
+    #[must_use]
+    pub fn new(result: QueryResult, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "`batch_size` must be positive");
+        Self { result, batch_size }
+    }
+}
+
+impl Iterator for QueryResultBatches {
+    type Item = Vec<Data>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch: Vec<Data> = self.result.by_ref().take(self.batch_size).collect();
+        if batch.is_empty() {
+            None
+        } else {
+            Some(batch)
+        }
+    }
+}
+
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.persistence")
@@ -0,0 +1,677 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Loaders and writers for quote, trade and bar data in CSV (or TSV) format.
+
+use std::{
+    fs::File,
+    io::{Read, Write},
+    path::Path,
+    str::FromStr,
+};
+
+use chrono::{DateTime, NaiveDateTime, SecondsFormat};
+use csv::{ReaderBuilder, StringRecord, WriterBuilder};
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::{Bar, BarType, QuoteTick, TradeTick},
+    enums::AggressorSide,
+    identifiers::{InstrumentId, TradeId},
+    types::{Price, Quantity},
+};
+
+/// The format of timestamp values in a CSV file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// Integer UNIX nanoseconds.
+    #[default]
+    UnixNanos,
+    /// Integer UNIX microseconds.
+    UnixMicros,
+    /// Integer UNIX milliseconds.
+    UnixMillis,
+    /// UNIX seconds, which may have a fractional part.
+    UnixSeconds,
+    /// RFC 3339 (ISO 8601) datetime strings.
+    Rfc3339,
+    /// Datetime strings in the given `chrono` format, interpreted as UTC.
+    Custom(String),
+}
+
+impl TimestampFormat {
+    /// Parses the given timestamp `value` in this format.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the value cannot be parsed in this format.
+    /// - If the timestamp is before the UNIX epoch, or overflows UNIX nanoseconds.
+    pub fn parse(&self, value: &str) -> anyhow::Result<UnixNanos> {
+        let value = value.trim();
+        let out_of_range = || anyhow::anyhow!("Timestamp out of range: {value}");
+        let nanos = match self {
+            Self::UnixNanos => value.parse::<u64>()?,
+            Self::UnixMicros => value
+                .parse::<u64>()?
+                .checked_mul(1_000)
+                .ok_or_else(out_of_range)?,
+            Self::UnixMillis => value
+                .parse::<u64>()?
+                .checked_mul(1_000_000)
+                .ok_or_else(out_of_range)?,
+            Self::UnixSeconds => {
+                let (secs, frac) = value.split_once('.').unwrap_or((value, ""));
+                let frac = format!("{frac:0<9}");
+                let frac_nanos = frac.get(..9).unwrap_or(&frac).parse::<u64>()?;
+                secs.parse::<u64>()?
+                    .checked_mul(1_000_000_000)
+                    .and_then(|nanos| nanos.checked_add(frac_nanos))
+                    .ok_or_else(out_of_range)?
+            }
+            Self::Rfc3339 => DateTime::parse_from_rfc3339(value)?
+                .timestamp_nanos_opt()
+                .and_then(|nanos| u64::try_from(nanos).ok())
+                .ok_or_else(out_of_range)?,
+            Self::Custom(format) => NaiveDateTime::parse_from_str(value, format)?
+                .and_utc()
+                .timestamp_nanos_opt()
+                .and_then(|nanos| u64::try_from(nanos).ok())
+                .ok_or_else(out_of_range)?,
+        };
+        Ok(UnixNanos::from(nanos))
+    }
+
+    /// Formats the given `timestamp` in this format.
+    #[must_use]
+    pub fn format(&self, timestamp: UnixNanos) -> String {
+        let nanos = timestamp.as_u64();
+        match self {
+            Self::UnixNanos => nanos.to_string(),
+            Self::UnixMicros => (nanos / 1_000).to_string(),
+            Self::UnixMillis => (nanos / 1_000_000).to_string(),
+            Self::UnixSeconds => format!("{}.{:09}", nanos / 1_000_000_000, nanos % 1_000_000_000),
+            Self::Rfc3339 => DateTime::from_timestamp_nanos(nanos as i64)
+                .to_rfc3339_opts(SecondsFormat::Nanos, true),
+            Self::Custom(format) => DateTime::from_timestamp_nanos(nanos as i64)
+                .format(format)
+                .to_string(),
+        }
+    }
+}
+
+/// The column names used when reading and writing CSV data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvColumns {
+    pub ts_event: String,
+    /// If `None` then `ts_init` is set from `ts_event` when loading.
+    pub ts_init: Option<String>,
+    pub bid_price: String,
+    pub ask_price: String,
+    pub bid_size: String,
+    pub ask_size: String,
+    pub price: String,
+    pub size: String,
+    /// If the column is missing then trade IDs are generated from the row number when loading.
+    pub trade_id: String,
+    /// If the column is missing then trades are loaded with no aggressor side.
+    pub aggressor_side: String,
+    pub open: String,
+    pub high: String,
+    pub low: String,
+    pub close: String,
+    pub volume: String,
+}
+
+impl Default for CsvColumns {
+    /// Creates a new default [`CsvColumns`] instance, matching the Nautilus field names.
+    fn default() -> Self {
+        Self {
+            ts_event: "ts_event".to_string(),
+            ts_init: Some("ts_init".to_string()),
+            bid_price: "bid_price".to_string(),
+            ask_price: "ask_price".to_string(),
+            bid_size: "bid_size".to_string(),
+            ask_size: "ask_size".to_string(),
+            price: "price".to_string(),
+            size: "size".to_string(),
+            trade_id: "trade_id".to_string(),
+            aggressor_side: "aggressor_side".to_string(),
+            open: "open".to_string(),
+            high: "high".to_string(),
+            low: "low".to_string(),
+            close: "close".to_string(),
+            volume: "volume".to_string(),
+        }
+    }
+}
+
+/// Configuration for loading and writing CSV data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvConfig {
+    /// The field delimiter, use `b'\t'` for TSV.
+    pub delimiter: u8,
+    /// The column names for each field.
+    pub columns: CsvColumns,
+    /// The format of timestamp values.
+    pub timestamp_format: TimestampFormat,
+    /// The price precision, if `None` then inferred from the data when loading.
+    pub price_precision: Option<u8>,
+    /// The size precision, if `None` then inferred from the data when loading.
+    pub size_precision: Option<u8>,
+}
+
+impl Default for CsvConfig {
+    /// Creates a new default [`CsvConfig`] instance.
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            columns: CsvColumns::default(),
+            timestamp_format: TimestampFormat::default(),
+            price_precision: None,
+            size_precision: None,
+        }
+    }
+}
+
+/// Loads [`QuoteTick`]s for the given `instrument_id` from the CSV file at `filepath`.
+pub fn load_quotes<P: AsRef<Path>>(
+    filepath: P,
+    instrument_id: InstrumentId,
+    config: &CsvConfig,
+) -> anyhow::Result<Vec<QuoteTick>> {
+    read_quotes(File::open(filepath)?, instrument_id, config)
+}
+
+/// Loads [`TradeTick`]s for the given `instrument_id` from the CSV file at `filepath`.
+pub fn load_trades<P: AsRef<Path>>(
+    filepath: P,
+    instrument_id: InstrumentId,
+    config: &CsvConfig,
+) -> anyhow::Result<Vec<TradeTick>> {
+    read_trades(File::open(filepath)?, instrument_id, config)
+}
+
+/// Loads [`Bar`]s of the given `bar_type` from the CSV file at `filepath`.
+pub fn load_bars<P: AsRef<Path>>(
+    filepath: P,
+    bar_type: BarType,
+    config: &CsvConfig,
+) -> anyhow::Result<Vec<Bar>> {
+    read_bars(File::open(filepath)?, bar_type, config)
+}
+
+/// Reads [`QuoteTick`]s for the given `instrument_id` from the CSV `reader`.
+pub fn read_quotes<R: Read>(
+    reader: R,
+    instrument_id: InstrumentId,
+    config: &CsvConfig,
+) -> anyhow::Result<Vec<QuoteTick>> {
+    let cols = &config.columns;
+    let table = CsvTable::read(reader, config)?;
+    let bid_price = table.column(&cols.bid_price)?;
+    let ask_price = table.column(&cols.ask_price)?;
+    let bid_size = table.column(&cols.bid_size)?;
+    let ask_size = table.column(&cols.ask_size)?;
+    let price_precision = config
+        .price_precision
+        .unwrap_or_else(|| table.infer_precision(&[bid_price, ask_price]));
+    let size_precision = config
+        .size_precision
+        .unwrap_or_else(|| table.infer_precision(&[bid_size, ask_size]));
+
+    table
+        .records
+        .iter()
+        .map(|record| {
+            let (ts_event, ts_init) = table.timestamps(record, config)?;
+            QuoteTick::new_checked(
+                instrument_id,
+                Price::new_checked(parse_f64(record, bid_price)?, price_precision)?,
+                Price::new_checked(parse_f64(record, ask_price)?, price_precision)?,
+                Quantity::new_checked(parse_f64(record, bid_size)?, size_precision)?,
+                Quantity::new_checked(parse_f64(record, ask_size)?, size_precision)?,
+                ts_event,
+                ts_init,
+            )
+        })
+        .collect()
+}
+
+/// Reads [`TradeTick`]s for the given `instrument_id` from the CSV `reader`.
+pub fn read_trades<R: Read>(
+    reader: R,
+    instrument_id: InstrumentId,
+    config: &CsvConfig,
+) -> anyhow::Result<Vec<TradeTick>> {
+    let cols = &config.columns;
+    let table = CsvTable::read(reader, config)?;
+    let price = table.column(&cols.price)?;
+    let size = table.column(&cols.size)?;
+    let trade_id = table.optional_column(&cols.trade_id);
+    let aggressor_side = table.optional_column(&cols.aggressor_side);
+    let price_precision = config
+        .price_precision
+        .unwrap_or_else(|| table.infer_precision(&[price]));
+    let size_precision = config
+        .size_precision
+        .unwrap_or_else(|| table.infer_precision(&[size]));
+
+    table
+        .records
+        .iter()
+        .enumerate()
+        .map(|(i, record)| {
+            let (ts_event, ts_init) = table.timestamps(record, config)?;
+            let trade_id = match trade_id {
+                Some(index) => TradeId::new_checked(field(record, index)?)?,
+                None => TradeId::new((i + 1).to_string()),
+            };
+            let aggressor_side = match aggressor_side {
+                Some(index) => parse_aggressor_side(field(record, index)?)?,
+                None => AggressorSide::NoAggressor,
+            };
+            Ok(TradeTick::new(
+                instrument_id,
+                Price::new_checked(parse_f64(record, price)?, price_precision)?,
+                Quantity::new_checked(parse_f64(record, size)?, size_precision)?,
+                aggressor_side,
+                trade_id,
+                ts_event,
+                ts_init,
+            ))
+        })
+        .collect()
+}
+
+/// Reads [`Bar`]s of the given `bar_type` from the CSV `reader`.
+pub fn read_bars<R: Read>(
+    reader: R,
+    bar_type: BarType,
+    config: &CsvConfig,
+) -> anyhow::Result<Vec<Bar>> {
+    let cols = &config.columns;
+    let table = CsvTable::read(reader, config)?;
+    let open = table.column(&cols.open)?;
+    let high = table.column(&cols.high)?;
+    let low = table.column(&cols.low)?;
+    let close = table.column(&cols.close)?;
+    let volume = table.column(&cols.volume)?;
+    let price_precision = config
+        .price_precision
+        .unwrap_or_else(|| table.infer_precision(&[open, high, low, close]));
+    let size_precision = config
+        .size_precision
+        .unwrap_or_else(|| table.infer_precision(&[volume]));
+
+    table
+        .records
+        .iter()
+        .map(|record| {
+            let (ts_event, ts_init) = table.timestamps(record, config)?;
+            Ok(Bar::new(
+                bar_type,
+                Price::new_checked(parse_f64(record, open)?, price_precision)?,
+                Price::new_checked(parse_f64(record, high)?, price_precision)?,
+                Price::new_checked(parse_f64(record, low)?, price_precision)?,
+                Price::new_checked(parse_f64(record, close)?, price_precision)?,
+                Quantity::new_checked(parse_f64(record, volume)?, size_precision)?,
+                ts_event,
+                ts_init,
+            ))
+        })
+        .collect()
+}
+
+/// Writes the given `quotes` as CSV to the `writer`.
+pub fn write_quotes<W: Write>(
+    writer: W,
+    quotes: &[QuoteTick],
+    config: &CsvConfig,
+) -> anyhow::Result<()> {
+    let cols = &config.columns;
+    let mut csv_writer = WriterBuilder::new()
+        .delimiter(config.delimiter)
+        .from_writer(writer);
+    csv_writer.write_record(with_timestamps(
+        vec![
+            &cols.bid_price,
+            &cols.ask_price,
+            &cols.bid_size,
+            &cols.ask_size,
+        ],
+        cols,
+    ))?;
+
+    for quote in quotes {
+        csv_writer.write_record(with_timestamp_values(
+            vec![
+                quote.bid_price.to_string(),
+                quote.ask_price.to_string(),
+                quote.bid_size.to_string(),
+                quote.ask_size.to_string(),
+            ],
+            quote.ts_event,
+            quote.ts_init,
+            config,
+        ))?;
+    }
+
+    csv_writer.flush()?;
+    Ok(())
+}
+
+/// Writes the given `trades` as CSV to the `writer`.
+pub fn write_trades<W: Write>(
+    writer: W,
+    trades: &[TradeTick],
+    config: &CsvConfig,
+) -> anyhow::Result<()> {
+    let cols = &config.columns;
+    let mut csv_writer = WriterBuilder::new()
+        .delimiter(config.delimiter)
+        .from_writer(writer);
+    csv_writer.write_record(with_timestamps(
+        vec![
+            &cols.price,
+            &cols.size,
+            &cols.aggressor_side,
+            &cols.trade_id,
+        ],
+        cols,
+    ))?;
+
+    for trade in trades {
+        csv_writer.write_record(with_timestamp_values(
+            vec![
+                trade.price.to_string(),
+                trade.size.to_string(),
+                trade.aggressor_side.to_string(),
+                trade.trade_id.to_string(),
+            ],
+            trade.ts_event,
+            trade.ts_init,
+            config,
+        ))?;
+    }
+
+    csv_writer.flush()?;
+    Ok(())
+}
+
+/// Writes the given `bars` as CSV to the `writer`.
+pub fn write_bars<W: Write>(writer: W, bars: &[Bar], config: &CsvConfig) -> anyhow::Result<()> {
+    let cols = &config.columns;
+    let mut csv_writer = WriterBuilder::new()
+        .delimiter(config.delimiter)
+        .from_writer(writer);
+    csv_writer.write_record(with_timestamps(
+        vec![&cols.open, &cols.high, &cols.low, &cols.close, &cols.volume],
+        cols,
+    ))?;
+
+    for bar in bars {
+        csv_writer.write_record(with_timestamp_values(
+            vec![
+                bar.open.to_string(),
+                bar.high.to_string(),
+                bar.low.to_string(),
+                bar.close.to_string(),
+                bar.volume.to_string(),
+            ],
+            bar.ts_event,
+            bar.ts_init,
+            config,
+        ))?;
+    }
+
+    csv_writer.flush()?;
+    Ok(())
+}
+
+struct CsvTable {
+    headers: StringRecord,
+    records: Vec<StringRecord>,
+    ts_event: usize,
+    ts_init: Option<usize>,
+}
+
+impl CsvTable {
+    fn read<R: Read>(reader: R, config: &CsvConfig) -> anyhow::Result<Self> {
+        let mut csv_reader = ReaderBuilder::new()
+            .delimiter(config.delimiter)
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+        let headers = csv_reader.headers()?.clone();
+        let records = csv_reader.records().collect::<Result<Vec<_>, _>>()?;
+
+        let mut table = Self {
+            headers,
+            records,
+            ts_event: 0,
+            ts_init: None,
+        };
+        table.ts_event = table.column(&config.columns.ts_event)?;
+        table.ts_init = config
+            .columns
+            .ts_init
+            .as_ref()
+            .and_then(|name| table.optional_column(name));
+        Ok(table)
+    }
+
+    fn optional_column(&self, name: &str) -> Option<usize> {
+        self.headers.iter().position(|header| header == name)
+    }
+
+    fn column(&self, name: &str) -> anyhow::Result<usize> {
+        self.optional_column(name)
+            .ok_or_else(|| anyhow::anyhow!("Column '{name}' not found in CSV headers"))
+    }
+
+    fn timestamps(
+        &self,
+        record: &StringRecord,
+        config: &CsvConfig,
+    ) -> anyhow::Result<(UnixNanos, UnixNanos)> {
+        let format = &config.timestamp_format;
+        let ts_event = format.parse(field(record, self.ts_event)?)?;
+        let ts_init = match self.ts_init {
+            Some(index) => format.parse(field(record, index)?)?,
+            None => ts_event,
+        };
+        Ok((ts_event, ts_init))
+    }
+
+    /// Returns the maximum number of decimal places of the values in the given `columns`.
+    fn infer_precision(&self, columns: &[usize]) -> u8 {
+        self.records
+            .iter()
+            .flat_map(|record| columns.iter().filter_map(|&index| record.get(index)))
+            .map(|value| {
+                value
+                    .split_once('.')
+                    .map_or(0, |(_, decimals)| decimals.trim_end().len() as u8)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+fn field(record: &StringRecord, index: usize) -> anyhow::Result<&str> {
+    record
+        .get(index)
+        .ok_or_else(|| anyhow::anyhow!("Missing field at index {index} in {record:?}"))
+}
+
+fn parse_f64(record: &StringRecord, index: usize) -> anyhow::Result<f64> {
+    let value = field(record, index)?;
+    value
+        .parse::<f64>()
+        .map_err(|e| anyhow::anyhow!("Invalid number '{value}': {e}"))
+}
+
+fn parse_aggressor_side(value: &str) -> anyhow::Result<AggressorSide> {
+    match value.to_ascii_uppercase().as_str() {
+        "" | "0" => Ok(AggressorSide::NoAggressor),
+        "1" | "B" | "BUY" => Ok(AggressorSide::Buyer),
+        "2" | "S" | "SELL" => Ok(AggressorSide::Seller),
+        other => AggressorSide::from_str(other)
+            .map_err(|_| anyhow::anyhow!("Invalid aggressor side '{value}'")),
+    }
+}
+
+fn with_timestamps<'a>(mut names: Vec<&'a String>, cols: &'a CsvColumns) -> Vec<&'a String> {
+    names.push(&cols.ts_event);
+    if let Some(ts_init) = &cols.ts_init {
+        names.push(ts_init);
+    }
+    names
+}
+
+fn with_timestamp_values(
+    mut values: Vec<String>,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    config: &CsvConfig,
+) -> Vec<String> {
+    values.push(config.timestamp_format.format(ts_event));
+    if config.columns.ts_init.is_some() {
+        values.push(config.timestamp_format.format(ts_init));
+    }
+    values
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::stubs::stub_bar;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(TimestampFormat::UnixNanos, "1546383600000000000")]
+    #[case(TimestampFormat::UnixMillis, "1546383600000")]
+    #[case(TimestampFormat::UnixSeconds, "1546383600.0")]
+    #[case(TimestampFormat::Rfc3339, "2019-01-01T23:00:00Z")]
+    #[case(
+        TimestampFormat::Custom("%Y%m%d %H%M%S".to_string()),
+        "20190101 230000"
+    )]
+    fn test_parse_timestamp(#[case] format: TimestampFormat, #[case] value: &str) {
+        let result = format.parse(value).unwrap();
+
+        assert_eq!(result, UnixNanos::from(1_546_383_600_000_000_000));
+    }
+
+    #[rstest]
+    #[case(TimestampFormat::UnixMicros, "18446744073709552")]
+    #[case(TimestampFormat::UnixMillis, "18446744073710")]
+    #[case(TimestampFormat::UnixSeconds, "18446744073.709551616")]
+    #[case(TimestampFormat::Rfc3339, "1969-12-31T23:59:59Z")]
+    #[case(
+        TimestampFormat::Custom("%Y%m%d %H%M%S".to_string()),
+        "19691231 235959"
+    )]
+    fn test_parse_timestamp_out_of_range_returns_error(
+        #[case] format: TimestampFormat,
+        #[case] value: &str,
+    ) {
+        assert!(format.parse(value).is_err());
+    }
+
+    #[rstest]
+    fn test_read_quotes_infers_precision() {
+        let csv = "ts_event,bid_price,ask_price,bid_size,ask_size\n\
+                   1,1.0001,1.00025,100000,200000\n\
+                   2,1.00015,1.0003,150000,250000\n";
+        let config = CsvConfig {
+            columns: CsvColumns {
+                ts_init: None,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let quotes =
+            read_quotes(csv.as_bytes(), InstrumentId::from("AUD/USD.SIM"), &config).unwrap();
+
+        assert_eq!(quotes.len(), 2);
+        assert_eq!(quotes[0].bid_price, Price::from("1.00010"));
+        assert_eq!(quotes[0].ask_size, Quantity::from(200_000));
+        assert_eq!(quotes[1].ts_init, UnixNanos::from(2));
+    }
+
+    #[rstest]
+    fn test_read_trades_from_tsv_with_column_mapping() {
+        let tsv = "time\tpx\tqty\tside\n\
+                   2019-01-01T23:00:00Z\t100.5\t1.25\tbuy\n\
+                   2019-01-01T23:00:01Z\t100.25\t0.5\tsell\n";
+        let config = CsvConfig {
+            delimiter: b'\t',
+            columns: CsvColumns {
+                ts_event: "time".to_string(),
+                ts_init: None,
+                price: "px".to_string(),
+                size: "qty".to_string(),
+                aggressor_side: "side".to_string(),
+                ..Default::default()
+            },
+            timestamp_format: TimestampFormat::Rfc3339,
+            ..Default::default()
+        };
+
+        let trades = read_trades(
+            tsv.as_bytes(),
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            &config,
+        )
+        .unwrap();
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].price, Price::from("100.50"));
+        assert_eq!(trades[0].size, Quantity::from("1.25"));
+        assert_eq!(trades[0].aggressor_side, AggressorSide::Buyer);
+        assert_eq!(trades[1].aggressor_side, AggressorSide::Seller);
+        assert_eq!(trades[1].trade_id, TradeId::new("2"));
+    }
+
+    #[rstest]
+    fn test_read_quotes_with_missing_column_returns_error() {
+        let csv = "ts_event,bid_price\n1,1.0\n";
+
+        let result = read_quotes(
+            csv.as_bytes(),
+            InstrumentId::from("AUD/USD.SIM"),
+            &CsvConfig::default(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_write_and_read_bars_round_trip(stub_bar: Bar) {
+        let config = CsvConfig::default();
+        let bars = vec![stub_bar];
+        let mut buffer = Vec::new();
+
+        write_bars(&mut buffer, &bars, &config).unwrap();
+        let loaded = read_bars(buffer.as_slice(), stub_bar.bar_type, &config).unwrap();
+
+        assert_eq!(loaded, bars);
+    }
+}
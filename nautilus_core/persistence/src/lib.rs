@@ -28,6 +28,7 @@
 //! - `python`: Enables Python bindings from `pyo3`.
 
 pub mod backend;
+pub mod csv;
 
 #[cfg(feature = "python")]
 pub mod python;
@@ -358,6 +358,237 @@ fn test_catalog_serialization_json_round_trip() {
     }
 }
 
+#[rstest]
+fn test_custom_data_json_round_trip() {
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::data::GetTsInit;
+    use pretty_assertions::assert_eq;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct NewsEvent {
+        headline: String,
+        ts_init: UnixNanos,
+    }
+
+    impl GetTsInit for NewsEvent {
+        fn ts_init(&self) -> UnixNanos {
+            self.ts_init
+        }
+    }
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), Some(1000));
+    let events: Vec<NewsEvent> = (1..=3)
+        .map(|i| NewsEvent {
+            headline: format!("headline-{i}"),
+            ts_init: UnixNanos::from(i),
+        })
+        .collect();
+
+    catalog.write_custom_to_json("news_event", events.clone());
+
+    let loaded: Vec<NewsEvent> = catalog
+        .read_custom_from_json("news_event", None, None)
+        .unwrap();
+    let filtered: Vec<NewsEvent> = catalog
+        .read_custom_from_json("news_event", Some(UnixNanos::from(2)), None)
+        .unwrap();
+    let missing: Vec<NewsEvent> = catalog
+        .read_custom_from_json("other_event", None, None)
+        .unwrap();
+
+    assert_eq!(loaded, events);
+    assert_eq!(filtered, events[1..].to_vec());
+    assert!(missing.is_empty());
+}
+
+#[rstest]
+fn test_write_to_partitioned_parquet_round_trip() {
+    use pretty_assertions::assert_eq;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), Some(1000));
+
+    let file_path = get_test_data_file_path("nautilus/quotes.parquet");
+    let mut session = DataBackendSession::new(1000);
+    session
+        .add_file::<QuoteTick>("test_data", file_path.as_str(), None)
+        .unwrap();
+    let query_result: QueryResult = session.get_query_result();
+    let quote_ticks: Vec<QuoteTick> = to_variant(query_result.collect());
+
+    let paths = catalog
+        .write_to_partitioned_parquet("quote_tick", quote_ticks.clone())
+        .unwrap();
+
+    let mut session = DataBackendSession::new(1000);
+    for (i, path) in paths.iter().enumerate() {
+        let instrument_dir = path.parent().unwrap().file_name().unwrap();
+        assert_eq!(
+            instrument_dir.to_str().unwrap(),
+            quote_ticks[0].instrument_id.to_string().replace('/', "")
+        );
+        session
+            .add_file::<QuoteTick>(&format!("quotes_{i}"), path.to_str().unwrap(), None)
+            .unwrap();
+    }
+    let query_result: QueryResult = session.get_query_result();
+    let loaded: Vec<QuoteTick> = to_variant(query_result.collect());
+
+    assert!(!paths.is_empty());
+    assert_eq!(loaded, quote_ticks);
+}
+
+#[rstest]
+fn test_write_to_partitioned_parquet_twice_retains_existing_data() {
+    use pretty_assertions::assert_eq;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), Some(1000));
+
+    let file_path = get_test_data_file_path("nautilus/quotes.parquet");
+    let mut session = DataBackendSession::new(1000);
+    session
+        .add_file::<QuoteTick>("test_data", file_path.as_str(), None)
+        .unwrap();
+    let query_result: QueryResult = session.get_query_result();
+    let quote_ticks: Vec<QuoteTick> = to_variant(query_result.collect());
+    let (first, second) = quote_ticks.split_at(quote_ticks.len() / 2);
+
+    let first_paths = catalog
+        .write_to_partitioned_parquet("quote_tick", first.to_vec())
+        .unwrap();
+    let second_paths = catalog
+        .write_to_partitioned_parquet("quote_tick", second.to_vec())
+        .unwrap();
+
+    let loaded: Vec<QuoteTick> = to_variant(
+        catalog
+            .query::<QuoteTick>(vec![], None, None, None)
+            .unwrap()
+            .collect(),
+    );
+
+    assert!(first_paths.iter().all(|path| !second_paths.contains(path)));
+    assert_eq!(loaded, quote_ticks);
+}
+
+#[rstest]
+fn test_catalog_query_with_time_range_and_instrument_filters() {
+    use pretty_assertions::assert_eq;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), Some(1000));
+
+    let file_path = get_test_data_file_path("nautilus/quotes.parquet");
+    let mut session = DataBackendSession::new(1000);
+    session
+        .add_file::<QuoteTick>("test_data", file_path.as_str(), None)
+        .unwrap();
+    let query_result: QueryResult = session.get_query_result();
+    let quote_ticks: Vec<QuoteTick> = to_variant(query_result.collect());
+    catalog
+        .write_to_partitioned_parquet("quote_tick", quote_ticks.clone())
+        .unwrap();
+
+    let instrument_id = quote_ticks[0].instrument_id.to_string();
+    let start = quote_ticks[100].ts_init;
+    let end = quote_ticks[200].ts_init;
+    let expected: Vec<QuoteTick> = quote_ticks
+        .iter()
+        .filter(|q| q.ts_init >= start && q.ts_init <= end)
+        .copied()
+        .collect();
+
+    let all: Vec<QuoteTick> = to_variant(
+        catalog
+            .query::<QuoteTick>(vec![], None, None, None)
+            .unwrap()
+            .collect(),
+    );
+    let ranged: Vec<QuoteTick> = to_variant(
+        catalog
+            .query::<QuoteTick>(vec![instrument_id], Some(start), Some(end), None)
+            .unwrap()
+            .collect(),
+    );
+    let other: Vec<Data> = catalog
+        .query::<QuoteTick>(vec!["ETHUSDT.BINANCE".to_string()], None, None, None)
+        .unwrap()
+        .collect();
+
+    assert_eq!(all, quote_ticks);
+    assert_eq!(ranged, expected);
+    assert!(is_monotonically_increasing_by_init(&ranged));
+    assert!(other.is_empty());
+}
+
+#[rstest]
+fn test_catalog_merged_query_result_batches() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), Some(1000));
+
+    let mut session = DataBackendSession::new(1000);
+    session
+        .add_file::<QuoteTick>(
+            "quotes",
+            get_test_data_file_path("nautilus/quotes.parquet").as_str(),
+            None,
+        )
+        .unwrap();
+    session
+        .add_file::<TradeTick>(
+            "trades",
+            get_test_data_file_path("nautilus/trades.parquet").as_str(),
+            None,
+        )
+        .unwrap();
+    let data: Vec<Data> = session.get_query_result().collect();
+    catalog.write_data_enum_partitioned(data.clone()).unwrap();
+
+    catalog
+        .add_query::<QuoteTick>(vec![], None, None, None)
+        .unwrap();
+    catalog
+        .add_query::<TradeTick>(vec![], None, None, None)
+        .unwrap();
+    let batches: Vec<Vec<Data>> = catalog.get_query_result_batches().collect();
+    let merged: Vec<Data> = batches.iter().flatten().cloned().collect();
+
+    assert_eq!(batches.len(), data.len().div_ceil(1000));
+    assert!(batches.iter().all(|batch| batch.len() <= 1000));
+    assert_eq!(merged.len(), data.len());
+    assert!(is_monotonically_increasing_by_init(&merged));
+}
+
+#[rstest]
+fn test_write_instruments_to_partitioned_parquet() {
+    use datafusion::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use nautilus_model::instruments::{stubs::audusd_sim, InstrumentAny};
+    use nautilus_serialization::arrow::instrument::decode_instruments_batch;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+
+    let paths = catalog.write_instruments(vec![instrument.clone()]).unwrap();
+
+    assert_eq!(paths.len(), 1);
+    assert!(paths[0].ends_with("data/instrument/AUDUSD.SIM/1970-01-01.parquet"));
+
+    let file = std::fs::File::open(&paths[0]).unwrap();
+    let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+        .unwrap()
+        .build()
+        .unwrap();
+    let instruments: Vec<InstrumentAny> = reader
+        .flat_map(|batch| decode_instruments_batch(&batch.unwrap()).unwrap())
+        .collect();
+
+    assert_eq!(instruments, vec![instrument]);
+}
+
 #[rstest]
 fn test_datafusion_parquet_round_trip() {
     use std::collections::HashMap;
@@ -0,0 +1,29 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a configuration for `Portfolio` instances.
+
+use rust_decimal::Decimal;
+
+/// Configuration for `Portfolio` instances.
+#[derive(Clone, Debug, Default)]
+pub struct PortfolioConfig {
+    /// If positions are marked (for unrealized PnL and maintenance margin) from the venue
+    /// mark price when available, rather than from quotes and the last trade.
+    pub use_mark_prices: bool,
+    /// The difference in a total or free balance above which cached cash account balances
+    /// are reported as drifted from the venue-reported balances.
+    pub balance_drift_tolerance: Decimal,
+}
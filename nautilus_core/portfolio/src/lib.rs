@@ -15,8 +15,12 @@
 
 //! Provides a generic `Portfolio` for all environments.
 
+pub mod config;
 pub mod manager;
 pub mod portfolio;
+pub mod update;
 
 // Re-exports
+pub use config::PortfolioConfig;
 pub use portfolio::Portfolio;
+pub use update::PortfolioUpdate;
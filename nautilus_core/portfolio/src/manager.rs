@@ -26,7 +26,7 @@ use nautilus_model::{
     instruments::InstrumentAny,
     orders::OrderAny,
     position::Position,
-    types::{AccountBalance, Money},
+    types::{AccountBalance, Money, Price},
 };
 use rust_decimal::{prelude::ToPrimitive, Decimal};
 pub struct AccountsManager {
@@ -39,13 +39,17 @@ impl AccountsManager {
         Self { clock, cache }
     }
 
+    /// Applies the realized PnL and commission of the given `fill` to the `account` balances,
+    /// returning the updated account and its generated state.
+    ///
+    /// Cash accounts without a base currency apply the fill in its native currencies.
     #[must_use]
     pub fn update_balances(
         &self,
-        account: AccountAny,
+        mut account: AccountAny,
         instrument: InstrumentAny,
         fill: OrderFilled,
-    ) -> AccountState {
+    ) -> (AccountAny, AccountState) {
         let cache = self.cache.borrow();
         let position_id = if let Some(position_id) = fill.position_id {
             position_id
@@ -57,9 +61,19 @@ impl AccountsManager {
                 .id
         };
 
-        let position = cache.position(&position_id);
+        let position = cache.position(&position_id).cloned();
 
-        let pnls = account.calculate_pnls(instrument, fill, position.cloned());
+        if let AccountAny::Cash(cash_account) = &mut account {
+            if cash_account.base_currency().is_none() {
+                if let Err(e) = cash_account.apply_fill(instrument, fill, position) {
+                    log::error!("Cannot apply fill {} to account: {e}", fill.trade_id);
+                }
+                let account_state = self.generate_account_state(account.clone(), fill.ts_event);
+                return (account, account_state);
+            }
+        }
+
+        let pnls = account.calculate_pnls(instrument, fill, position);
 
         // Calculate final PnL including commissions
         match account.base_currency() {
@@ -74,17 +88,45 @@ impl AccountsManager {
                     },
                 );
 
-                self.update_balance_single_currency(account.clone(), &fill, pnl);
+                self.update_balance_single_currency(&mut account, &fill, pnl);
             }
             None => {
                 if let Ok(mut pnl_list) = pnls {
-                    self.update_balance_multi_currency(account.clone(), fill, &mut pnl_list);
+                    self.update_balance_multi_currency(&mut account, fill, &mut pnl_list);
                 }
             }
         }
 
         // Generate and return account state
-        self.generate_account_state(account, fill.ts_event)
+        let account_state = self.generate_account_state(account.clone(), fill.ts_event);
+        (account, account_state)
+    }
+
+    /// Applies the funding `payment` to the `account` balance in its native currency,
+    /// returning the updated account and its generated state.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the payment cannot be applied to the account balance.
+    pub fn apply_funding_payment(
+        &self,
+        account: AccountAny,
+        payment: Money,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<(AccountAny, AccountState)> {
+        let account = match account {
+            AccountAny::Cash(mut cash_account) => {
+                cash_account.apply_funding_payment(payment)?;
+                AccountAny::Cash(cash_account)
+            }
+            AccountAny::Margin(mut margin_account) => {
+                margin_account.apply_funding_payment(payment)?;
+                AccountAny::Margin(margin_account)
+            }
+        };
+
+        let account_state = self.generate_account_state(account.clone(), ts_event);
+        Ok((account, account_state))
     }
 
     #[must_use]
@@ -109,12 +151,15 @@ impl AccountsManager {
         }
     }
 
+    /// Updates the maintenance margin for the open `positions`, valued at the venue
+    /// `mark_price` when given, otherwise at each position's average open price.
     #[must_use]
     pub fn update_positions(
         &self,
         account: &MarginAccount,
         instrument: InstrumentAny,
         positions: Vec<&Position>,
+        mark_price: Option<Price>,
         ts_event: UnixNanos,
     ) -> Option<(MarginAccount, AccountState)> {
         let mut total_margin_maint = Decimal::ZERO;
@@ -134,67 +179,39 @@ impl AccountsManager {
                 continue;
             }
 
+            let price = mark_price.unwrap_or_else(|| instrument.make_price(position.avg_px_open));
+
             let margin_maint = match instrument {
-                InstrumentAny::Betting(i) => account.calculate_maintenance_margin(
-                    i,
-                    position.quantity,
-                    instrument.make_price(position.avg_px_open),
-                    None,
-                ),
-                InstrumentAny::BinaryOption(i) => account.calculate_maintenance_margin(
-                    i,
-                    position.quantity,
-                    instrument.make_price(position.avg_px_open),
-                    None,
-                ),
-                InstrumentAny::CryptoFuture(i) => account.calculate_maintenance_margin(
-                    i,
-                    position.quantity,
-                    instrument.make_price(position.avg_px_open),
-                    None,
-                ),
-                InstrumentAny::CryptoPerpetual(i) => account.calculate_maintenance_margin(
-                    i,
-                    position.quantity,
-                    instrument.make_price(position.avg_px_open),
-                    None,
-                ),
-                InstrumentAny::CurrencyPair(i) => account.calculate_maintenance_margin(
-                    i,
-                    position.quantity,
-                    instrument.make_price(position.avg_px_open),
-                    None,
-                ),
-                InstrumentAny::Equity(i) => account.calculate_maintenance_margin(
-                    i,
-                    position.quantity,
-                    instrument.make_price(position.avg_px_open),
-                    None,
-                ),
-                InstrumentAny::FuturesContract(i) => account.calculate_maintenance_margin(
-                    i,
-                    position.quantity,
-                    instrument.make_price(position.avg_px_open),
-                    None,
-                ),
-                InstrumentAny::FuturesSpread(i) => account.calculate_maintenance_margin(
-                    i,
-                    position.quantity,
-                    instrument.make_price(position.avg_px_open),
-                    None,
-                ),
-                InstrumentAny::OptionsContract(i) => account.calculate_maintenance_margin(
-                    i,
-                    position.quantity,
-                    instrument.make_price(position.avg_px_open),
-                    None,
-                ),
-                InstrumentAny::OptionsSpread(i) => account.calculate_maintenance_margin(
-                    i,
-                    position.quantity,
-                    instrument.make_price(position.avg_px_open),
-                    None,
-                ),
+                InstrumentAny::Betting(i) => {
+                    account.calculate_maintenance_margin(i, position.quantity, price, None)
+                }
+                InstrumentAny::BinaryOption(i) => {
+                    account.calculate_maintenance_margin(i, position.quantity, price, None)
+                }
+                InstrumentAny::CryptoFuture(i) => {
+                    account.calculate_maintenance_margin(i, position.quantity, price, None)
+                }
+                InstrumentAny::CryptoPerpetual(i) => {
+                    account.calculate_maintenance_margin(i, position.quantity, price, None)
+                }
+                InstrumentAny::CurrencyPair(i) => {
+                    account.calculate_maintenance_margin(i, position.quantity, price, None)
+                }
+                InstrumentAny::Equity(i) => {
+                    account.calculate_maintenance_margin(i, position.quantity, price, None)
+                }
+                InstrumentAny::FuturesContract(i) => {
+                    account.calculate_maintenance_margin(i, position.quantity, price, None)
+                }
+                InstrumentAny::FuturesSpread(i) => {
+                    account.calculate_maintenance_margin(i, position.quantity, price, None)
+                }
+                InstrumentAny::OptionsContract(i) => {
+                    account.calculate_maintenance_margin(i, position.quantity, price, None)
+                }
+                InstrumentAny::OptionsSpread(i) => {
+                    account.calculate_maintenance_margin(i, position.quantity, price, None)
+                }
             };
 
             let mut margin_maint = margin_maint.as_decimal();
@@ -436,7 +453,7 @@ impl AccountsManager {
 
     fn update_balance_single_currency(
         &self,
-        account: AccountAny,
+        account: &mut AccountAny,
         fill: &OrderFilled,
         mut pnl: Money,
     ) {
@@ -524,13 +541,13 @@ impl AccountsManager {
         balances.push(new_balance);
 
         match account {
-            AccountAny::Cash(mut cash) => {
+            AccountAny::Cash(cash) => {
                 cash.update_balances(balances);
                 if let Some(comm) = commission {
                     cash.update_commissions(comm);
                 }
             }
-            AccountAny::Margin(mut margin) => {
+            AccountAny::Margin(margin) => {
                 margin.update_balances(balances);
                 if let Some(comm) = commission {
                     margin.update_commissions(comm);
@@ -541,7 +558,7 @@ impl AccountsManager {
 
     fn update_balance_multi_currency(
         &self,
-        account: AccountAny,
+        account: &mut AccountAny,
         fill: OrderFilled,
         pnls: &mut [Money],
     ) {
@@ -638,13 +655,13 @@ impl AccountsManager {
         }
 
         match account {
-            AccountAny::Cash(mut cash) => {
+            AccountAny::Cash(cash) => {
                 cash.update_balances(new_balances);
                 if let Some(commission) = commission {
                     cash.update_commissions(commission);
                 }
             }
-            AccountAny::Margin(mut margin) => {
+            AccountAny::Margin(margin) => {
                 margin.update_balances(new_balances);
                 if let Some(commission) = commission {
                     margin.update_commissions(commission);
@@ -668,8 +685,8 @@ impl AccountsManager {
             ),
             AccountAny::Margin(margin_account) => AccountState::new(
                 margin_account.id,
-                AccountType::Cash,
-                vec![],
+                AccountType::Margin,
+                margin_account.balances.clone().into_values().collect(),
                 margin_account.margins.clone().into_values().collect(),
                 false,
                 uuid4_new(),
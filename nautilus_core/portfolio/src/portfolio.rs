@@ -16,12 +16,13 @@
 //! Provides a generic `Portfolio` for all environments.
 use std::{
     any::Any,
-    cell::RefCell,
+    cell::{RefCell, RefMut},
     collections::{HashMap, HashSet},
     rc::Rc,
     sync::Arc,
 };
 
+use indexmap::IndexMap;
 use nautilus_analysis::{
     analyzer::PortfolioAnalyzer,
     statistics::{
@@ -36,18 +37,20 @@ use nautilus_analysis::{
 use nautilus_common::{
     cache::Cache,
     clock::Clock,
+    greeks::{GreeksCalculator, PortfolioGreeks},
     messages::data::DataResponse,
     msgbus::{
         handler::{MessageHandler, ShareableMessageHandler},
         MessageBus,
     },
 };
+use nautilus_core::nanos::UnixNanos;
 use nautilus_model::{
     accounts::AccountAny,
-    data::{Data, QuoteTick},
+    data::{Data, FundingRateUpdate, MarkPriceUpdate, QuoteTick},
     enums::{OrderSide, OrderType, PositionSide, PriceType},
     events::{position::PositionEvent, AccountState, OrderEventAny},
-    identifiers::{InstrumentId, Venue},
+    identifiers::{AccountId, InstrumentId, Venue},
     instruments::InstrumentAny,
     orders::OrderAny,
     position::Position,
@@ -60,7 +63,7 @@ use rust_decimal::{
 use ustr::Ustr;
 use uuid::Uuid;
 
-use crate::manager::AccountsManager;
+use crate::{config::PortfolioConfig, manager::AccountsManager, update::PortfolioUpdate};
 
 struct UpdateQuoteTickHandler {
     id: Ustr,
@@ -82,6 +85,46 @@ impl MessageHandler for UpdateQuoteTickHandler {
     }
 }
 
+struct UpdateFundingRateHandler {
+    id: Ustr,
+    callback: Box<dyn Fn(&FundingRateUpdate)>,
+}
+
+impl MessageHandler for UpdateFundingRateHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, msg: &dyn Any) {
+        (self.callback)(msg.downcast_ref::<FundingRateUpdate>().unwrap());
+    }
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, _data: Data) {}
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+struct UpdateMarkPriceHandler {
+    id: Ustr,
+    callback: Box<dyn Fn(&MarkPriceUpdate)>,
+}
+
+impl MessageHandler for UpdateMarkPriceHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, msg: &dyn Any) {
+        (self.callback)(msg.downcast_ref::<MarkPriceUpdate>().unwrap());
+    }
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, _data: Data) {}
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 struct UpdateOrderHandler {
     id: Ustr,
     callback: Box<dyn Fn(&OrderEventAny)>,
@@ -133,7 +176,7 @@ impl MessageHandler for UpdateAccountHandler {
     }
 
     fn handle(&self, msg: &dyn Any) {
-        (self.callback)(msg.downcast_ref::<&AccountState>().unwrap());
+        (self.callback)(msg.downcast_ref::<AccountState>().unwrap());
     }
     fn handle_response(&self, _resp: DataResponse) {}
     fn handle_data(&self, _data: Data) {}
@@ -149,11 +192,18 @@ struct PortfolioState {
     realized_pnls: HashMap<InstrumentId, Money>,
     net_positions: HashMap<InstrumentId, Decimal>,
     pending_calcs: HashSet<InstrumentId>,
+    pending_funding: HashMap<InstrumentId, FundingRateUpdate>,
+    funding_settled: HashMap<InstrumentId, UnixNanos>,
     initialized: bool,
+    config: PortfolioConfig,
 }
 
 impl PortfolioState {
-    fn new(clock: Rc<RefCell<dyn Clock>>, cache: Rc<RefCell<Cache>>) -> Self {
+    fn new(
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+        config: PortfolioConfig,
+    ) -> Self {
         let mut analyzer = PortfolioAnalyzer::new();
         analyzer.register_statistic(Arc::new(MaxWinner {}));
         analyzer.register_statistic(Arc::new(AvgWinner {}));
@@ -179,8 +229,20 @@ impl PortfolioState {
             realized_pnls: HashMap::new(),
             net_positions: HashMap::new(),
             pending_calcs: HashSet::new(),
+            pending_funding: HashMap::new(),
+            funding_settled: HashMap::new(),
             initialized: false,
+            config,
+        }
+    }
+
+    /// Returns the venue mark price for the `instrument_id` when configured to mark from it.
+    fn mark_price(&self, cache: &Cache, instrument_id: &InstrumentId) -> Option<Price> {
+        if !self.config.use_mark_prices {
+            return None;
         }
+
+        cache.mark_price(instrument_id).map(|update| update.value)
     }
 
     fn reset(&mut self) {
@@ -189,6 +251,8 @@ impl PortfolioState {
         self.unrealized_pnls.clear();
         self.realized_pnls.clear();
         self.pending_calcs.clear();
+        self.pending_funding.clear();
+        self.funding_settled.clear();
         self.analyzer.reset();
         log::debug!("READY");
     }
@@ -206,10 +270,12 @@ impl Portfolio {
         msgbus: Rc<RefCell<MessageBus>>,
         cache: Rc<RefCell<Cache>>,
         clock: Rc<RefCell<dyn Clock>>,
+        config: Option<PortfolioConfig>,
     ) -> Self {
         let inner = Rc::new(RefCell::new(PortfolioState::new(
             clock.clone(),
             cache.clone(),
+            config.unwrap_or_default(),
         )));
 
         Self::register_message_handlers(
@@ -235,10 +301,12 @@ impl Portfolio {
     ) {
         let update_account_handler = {
             let cache = cache.clone();
+            let inner = inner.clone();
             ShareableMessageHandler(Rc::new(UpdateAccountHandler {
                 id: Ustr::from(&Uuid::new_v4().to_string()),
                 callback: Box::new(move |event: &AccountState| {
-                    update_account(cache.clone(), event);
+                    let tolerance = inner.borrow().config.balance_drift_tolerance;
+                    update_account(cache.clone(), event, tolerance);
                 }),
             }))
         };
@@ -281,6 +349,45 @@ impl Portfolio {
             }))
         };
 
+        let update_funding_rate_handler = {
+            let cache = cache.clone();
+            let msgbus = msgbus.clone();
+            let clock = clock.clone();
+            let inner = inner.clone();
+            ShareableMessageHandler(Rc::new(UpdateFundingRateHandler {
+                id: Ustr::from(&Uuid::new_v4().to_string()),
+                callback: Box::new(move |update: &FundingRateUpdate| {
+                    update_funding_rate(
+                        cache.clone(),
+                        msgbus.clone(),
+                        clock.clone(),
+                        inner.clone(),
+                        update,
+                    );
+                }),
+            }))
+        };
+
+        let update_mark_price_handler = {
+            let cache = cache.clone();
+            let msgbus = msgbus.clone();
+            let clock = clock.clone();
+            let inner = inner.clone();
+            ShareableMessageHandler(Rc::new(UpdateMarkPriceHandler {
+                id: Ustr::from(&Uuid::new_v4().to_string()),
+                callback: Box::new(move |update: &MarkPriceUpdate| {
+                    update_mark_price(
+                        cache.clone(),
+                        msgbus.clone(),
+                        clock.clone(),
+                        inner.clone(),
+                        update,
+                    );
+                }),
+            }))
+        };
+        let use_mark_prices = inner.borrow().config.use_mark_prices;
+
         let update_order_handler = {
             let cache = cache;
             let msgbus = msgbus.clone();
@@ -304,6 +411,10 @@ impl Portfolio {
         borrowed_msgbus.register("Portfolio.update_account", update_account_handler.clone());
 
         borrowed_msgbus.subscribe("data.quotes.*", update_quote_handler, Some(10));
+        borrowed_msgbus.subscribe("data.funding.*", update_funding_rate_handler, Some(10));
+        if use_mark_prices {
+            borrowed_msgbus.subscribe("data.mark_prices.*", update_mark_price_handler, Some(10));
+        }
         borrowed_msgbus.subscribe("events.order.*", update_order_handler, Some(10));
         borrowed_msgbus.subscribe("events.position.*", update_position_handler, Some(10));
         borrowed_msgbus.subscribe("events.account.*", update_account_handler, Some(10));
@@ -317,6 +428,12 @@ impl Portfolio {
 
     // -- QUERIES ---------------------------------------------------------------------------------
 
+    /// Returns the portfolio analyzer, for calculating performance statistics.
+    #[must_use]
+    pub fn analyzer(&self) -> RefMut<'_, PortfolioAnalyzer> {
+        RefMut::map(self.inner.borrow_mut(), |inner| &mut inner.analyzer)
+    }
+
     #[must_use]
     pub fn is_initialized(&self) -> bool {
         self.inner.borrow().initialized
@@ -452,6 +569,35 @@ impl Portfolio {
             .collect()
     }
 
+    #[must_use]
+    pub fn equity(&mut self, venue: &Venue) -> HashMap<Currency, Money> {
+        let balances = match self.cache.borrow().account_for_venue(venue) {
+            Some(account) => account.balances(),
+            None => {
+                log::error!(
+                    "Cannot calculate equity: no account registered for {}",
+                    venue
+                );
+                return HashMap::new();
+            }
+        };
+
+        let mut equity: HashMap<Currency, f64> = balances
+            .iter()
+            .map(|(currency, balance)| (*currency, balance.total.as_f64()))
+            .collect();
+
+        // Mark the account balances with the unrealized PnL of any open positions
+        for (currency, pnl) in self.unrealized_pnls(venue) {
+            *equity.entry(currency).or_insert(0.0) += pnl.as_f64();
+        }
+
+        equity
+            .into_iter()
+            .map(|(currency, amount)| (currency, Money::new(amount, currency)))
+            .collect()
+    }
+
     #[must_use]
     pub fn net_exposures(&self, venue: &Venue) -> Option<HashMap<Currency, Money>> {
         let borrowed_cache = self.cache.borrow();
@@ -680,6 +826,27 @@ impl Portfolio {
         true
     }
 
+    /// Returns the aggregated Greeks of the open positions (for the optional `venue`),
+    /// keyed by underlying instrument ID.
+    ///
+    /// See [`GreeksCalculator::portfolio_greeks`] for how positions are aggregated.
+    #[must_use]
+    pub fn greeks(
+        &self,
+        venue: Option<&Venue>,
+        interest_rate: f64,
+        dividend_yield: f64,
+    ) -> IndexMap<InstrumentId, PortfolioGreeks> {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        GreeksCalculator::new(self.cache.clone()).portfolio_greeks(
+            venue,
+            None,
+            interest_rate,
+            dividend_yield,
+            ts_now,
+        )
+    }
+
     // -- COMMANDS --------------------------------------------------------------------------------
 
     pub fn initialize_orders(&mut self) {
@@ -837,12 +1004,17 @@ impl Portfolio {
                 break;
             };
 
+            let mark_price = self
+                .inner
+                .borrow()
+                .mark_price(&borrowed_cache, &instrument_id);
             let result = self.inner.borrow_mut().accounts.update_positions(
                 account,
                 instrument.clone(),
                 self.cache
                     .borrow()
                     .positions_open(None, Some(&instrument_id), None, None),
+                mark_price,
                 self.clock.borrow().timestamp_ns(),
             );
 
@@ -877,8 +1049,29 @@ impl Portfolio {
         );
     }
 
+    pub fn update_mark_price(&mut self, update: &MarkPriceUpdate) {
+        update_mark_price(
+            self.cache.clone(),
+            self.msgbus.clone(),
+            self.clock.clone(),
+            self.inner.clone(),
+            update,
+        );
+    }
+
+    pub fn update_funding_rate(&mut self, update: &FundingRateUpdate) {
+        update_funding_rate(
+            self.cache.clone(),
+            self.msgbus.clone(),
+            self.clock.clone(),
+            self.inner.clone(),
+            update,
+        );
+    }
+
     pub fn update_account(&mut self, event: &AccountState) {
-        update_account(self.cache.clone(), event);
+        let tolerance = self.inner.borrow().config.balance_drift_tolerance;
+        update_account(self.cache.clone(), event, tolerance);
     }
 
     pub fn update_order(&mut self, event: &OrderEventAny) {
@@ -921,6 +1114,30 @@ impl Portfolio {
         }
     }
 
+    fn publish_update(&self, instrument_id: &InstrumentId) {
+        let (unrealized_pnl, realized_pnl) = {
+            let inner = self.inner.borrow();
+            (
+                inner.unrealized_pnls.get(instrument_id).copied(),
+                inner.realized_pnls.get(instrument_id).copied(),
+            )
+        };
+
+        let update = PortfolioUpdate::new(
+            *instrument_id,
+            self.net_position(instrument_id),
+            self.net_exposure(instrument_id),
+            unrealized_pnl,
+            realized_pnl,
+            self.clock.borrow().timestamp_ns(),
+        );
+
+        log::debug!("{}", update);
+        self.msgbus
+            .borrow()
+            .publish(&PortfolioUpdate::topic(instrument_id), &update);
+    }
+
     fn calculate_unrealized_pnl(&mut self, instrument_id: &InstrumentId) -> Option<Money> {
         let borrowed_cache = self.cache.borrow();
 
@@ -1083,14 +1300,22 @@ impl Portfolio {
     }
 
     fn get_last_price(&self, position: &Position) -> Option<Price> {
+        let borrowed_cache = self.cache.borrow();
+
+        if let Some(mark_price) = self
+            .inner
+            .borrow()
+            .mark_price(&borrowed_cache, &position.instrument_id)
+        {
+            return Some(mark_price);
+        }
+
         let price_type = match position.side {
             PositionSide::Long => PriceType::Bid,
             PositionSide::Short => PriceType::Ask,
             _ => panic!("invalid `PositionSide`, was {}", position.side),
         };
 
-        let borrowed_cache = self.cache.borrow();
-
         borrowed_cache
             .price(&position.instrument_id, price_type)
             .or_else(|| borrowed_cache.price(&position.instrument_id, PriceType::Last))
@@ -1142,12 +1367,24 @@ fn update_quote_tick(
     inner: Rc<RefCell<PortfolioState>>,
     quote: &QuoteTick,
 ) {
-    inner
+    let previous_pnl = inner
         .borrow_mut()
         .unrealized_pnls
         .remove(&quote.instrument_id);
 
-    if inner.borrow().initialized || !inner.borrow().pending_calcs.contains(&quote.instrument_id) {
+    if inner.borrow().initialized {
+        mark_to_market(
+            cache,
+            msgbus,
+            clock,
+            inner,
+            &quote.instrument_id,
+            previous_pnl,
+        );
+        return;
+    }
+
+    if !inner.borrow().pending_calcs.contains(&quote.instrument_id) {
         return;
     }
 
@@ -1199,10 +1436,14 @@ fn update_quote_tick(
         );
 
         if let AccountAny::Margin(margin_account) = account {
+            let mark_price = inner
+                .borrow()
+                .mark_price(&borrowed_cache, &quote.instrument_id);
             result_maint = inner.borrow().accounts.update_positions(
                 margin_account,
                 instrument,
                 positions_open.iter().collect(),
+                mark_price,
                 clock.borrow().timestamp_ns(),
             );
         }
@@ -1237,170 +1478,454 @@ fn update_quote_tick(
     }
 }
 
-fn update_order(
+/// Re-marks open positions for the instrument from the mark price `update`, including their
+/// maintenance margin when held in a margin account.
+fn update_mark_price(
     cache: Rc<RefCell<Cache>>,
     msgbus: Rc<RefCell<MessageBus>>,
     clock: Rc<RefCell<dyn Clock>>,
     inner: Rc<RefCell<PortfolioState>>,
-    event: &OrderEventAny,
+    update: &MarkPriceUpdate,
 ) {
-    let borrowed_cache = cache.borrow();
-    let account_id = match event.account_id() {
-        Some(account_id) => account_id,
-        None => {
-            return; // No Account Assigned
-        }
-    };
+    let instrument_id = update.instrument_id;
 
-    let account = if let Some(account) = borrowed_cache.account(&account_id) {
-        account
-    } else {
-        log::error!(
-            "Cannot update order: no account registered for {}",
-            account_id
-        );
-        return;
-    };
-
-    match account {
-        AccountAny::Cash(cash_account) => {
-            if !cash_account.base.calculate_account_state {
-                return;
-            }
-        }
-        AccountAny::Margin(margin_account) => {
-            if !margin_account.base.calculate_account_state {
-                return;
-            }
-        }
+    if !inner.borrow().initialized {
+        return; // Positions are marked on initialization
     }
 
-    match event {
-        OrderEventAny::Accepted(_)
-        | OrderEventAny::Canceled(_)
-        | OrderEventAny::Rejected(_)
-        | OrderEventAny::Updated(_)
-        | OrderEventAny::Filled(_) => {}
-        _ => {
-            return;
-        }
-    }
+    let previous_pnl = inner.borrow_mut().unrealized_pnls.remove(&instrument_id);
+    mark_to_market(
+        cache.clone(),
+        msgbus.clone(),
+        clock.clone(),
+        inner.clone(),
+        &instrument_id,
+        previous_pnl,
+    );
 
-    let borrowed_cache = cache.borrow();
-    let order = if let Some(order) = borrowed_cache.order(&event.client_order_id()) {
-        order
-    } else {
-        log::error!(
-            "Cannot update order: {} not found in the cache",
-            event.client_order_id()
-        );
-        return; // No Order Found
+    let (margin_account, instrument, positions_open) = {
+        let borrowed_cache = cache.borrow();
+        let Some(AccountAny::Margin(margin_account)) =
+            borrowed_cache.account_for_venue(&instrument_id.venue)
+        else {
+            return; // Only margin accounts hold maintenance margin
+        };
+        let Some(instrument) = borrowed_cache.instrument(&instrument_id) else {
+            return;
+        };
+        let positions_open: Vec<Position> = borrowed_cache
+            .positions_open(None, Some(&instrument_id), None, None)
+            .iter()
+            .map(|p| (*p).clone())
+            .collect();
+        (margin_account.clone(), instrument.clone(), positions_open)
     };
 
-    if matches!(event, OrderEventAny::Rejected(_)) && order.order_type() != OrderType::StopLimit {
-        return; // No change to account state
-    }
-
-    let instrument = if let Some(instrument_id) = borrowed_cache.instrument(&event.instrument_id())
-    {
-        instrument_id
-    } else {
-        log::error!(
-            "Cannot update order: no instrument found for {}",
-            event.instrument_id()
-        );
+    if positions_open.is_empty() || !margin_account.calculate_account_state {
         return;
-    };
-
-    if let OrderEventAny::Filled(order_filled) = event {
-        let _ = inner.borrow().accounts.update_balances(
-            account.clone(),
-            instrument.clone(),
-            *order_filled,
-        );
-
-        let mut portfolio_clone = Portfolio {
-            clock: clock.clone(),
-            cache: cache.clone(),
-            msgbus: msgbus.clone(),
-            inner: inner.clone(),
-        };
-
-        match portfolio_clone.calculate_unrealized_pnl(&order_filled.instrument_id) {
-            Some(unrealized_pnl) => {
-                inner
-                    .borrow_mut()
-                    .unrealized_pnls
-                    .insert(event.instrument_id(), unrealized_pnl);
-            }
-            None => {
-                log::error!(
-                    "Failed to calculate unrealized PnL for instrument {}",
-                    event.instrument_id()
-                );
-            }
-        }
     }
 
-    let orders_open = borrowed_cache.orders_open(None, Some(&event.instrument_id()), None, None);
-
-    let account_state = inner.borrow_mut().accounts.update_orders(
-        account,
-        instrument.clone(),
-        orders_open,
+    let result = inner.borrow().accounts.update_positions(
+        &margin_account,
+        instrument,
+        positions_open.iter().collect(),
+        Some(update.value),
         clock.borrow().timestamp_ns(),
     );
 
-    let mut borrowed_cache = cache.borrow_mut();
-    borrowed_cache.update_account(account.clone()).unwrap();
-
-    if let Some(account_state) = account_state {
+    if let Some((margin_account, account_state)) = result {
+        let account_id = margin_account.id;
+        if let Err(e) = cache
+            .borrow_mut()
+            .update_account(AccountAny::Margin(margin_account))
+        {
+            log::error!("Failed to update account: {e}");
+            return;
+        }
         msgbus.borrow().publish(
-            &Ustr::from(&format!("events.account.{}", account.id())),
+            &Ustr::from(&format!("events.account.{account_id}")),
             &account_state,
         );
-    } else {
-        log::debug!("Added pending calculation for {}", instrument.id());
-        inner.borrow_mut().pending_calcs.insert(instrument.id());
     }
-
-    log::debug!("Updated {}", event);
 }
 
-fn update_position(
+/// Records the funding `update` for the instrument, settling the funding of all open
+/// positions once the previously announced funding time has been reached.
+///
+/// Each funding period is settled once, at the last rate announced for the period and valued
+/// at the venue mark price (otherwise the mid, then last price). The payments are realized into
+/// the positions PnL and applied to the balances of calculated accounts.
+fn update_funding_rate(
     cache: Rc<RefCell<Cache>>,
     msgbus: Rc<RefCell<MessageBus>>,
     clock: Rc<RefCell<dyn Clock>>,
     inner: Rc<RefCell<PortfolioState>>,
-    event: &PositionEvent,
+    update: &FundingRateUpdate,
 ) {
-    let instrument_id = event.instrument_id();
+    let instrument_id = update.instrument_id;
+    let ts_now = clock.borrow().timestamp_ns().max(update.ts_event);
 
-    let positions_open: Vec<Position> = {
-        let borrowed_cache = cache.borrow();
+    let rates: Vec<Decimal> = {
+        let mut state = inner.borrow_mut();
+        let pending = state.pending_funding.insert(instrument_id, *update);
 
-        borrowed_cache
+        let mut rates = Vec::new();
+        for candidate in pending.iter().chain(std::iter::once(update)) {
+            let Some(funding_ns) = candidate.next_funding_ns else {
+                continue;
+            };
+            let already_settled = state
+                .funding_settled
+                .get(&instrument_id)
+                .is_some_and(|settled_ns| *settled_ns >= funding_ns);
+            if ts_now >= funding_ns && !already_settled {
+                state.funding_settled.insert(instrument_id, funding_ns);
+                rates.push(candidate.rate);
+            }
+        }
+        rates
+    };
+
+    if rates.is_empty() {
+        return; // No funding period has elapsed
+    }
+
+    let (positions_open, price) = {
+        let borrowed_cache = cache.borrow();
+        let positions_open: Vec<Position> = borrowed_cache
             .positions_open(None, Some(&instrument_id), None, None)
             .iter()
-            .map(|o| (*o).clone())
-            .collect()
+            .map(|p| (*p).clone())
+            .collect();
+        let price = borrowed_cache
+            .mark_price(&instrument_id)
+            .map(|mark| mark.value)
+            .or_else(|| borrowed_cache.price(&instrument_id, PriceType::Mid))
+            .or_else(|| borrowed_cache.price(&instrument_id, PriceType::Last));
+        (positions_open, price)
     };
 
-    log::debug!("postion fresh from cache -> {:?}", positions_open);
+    if positions_open.is_empty() {
+        return;
+    }
 
-    let mut portfolio_clone = Portfolio {
-        clock: clock.clone(),
-        cache: cache.clone(),
-        msgbus,
-        inner: inner.clone(),
+    let Some(price) = price else {
+        log::warn!("Cannot settle funding for {instrument_id}: no mark price");
+        return;
     };
 
-    portfolio_clone.update_net_position(&instrument_id, positions_open.clone());
+    let mut account_payments: IndexMap<AccountId, Vec<Money>> = IndexMap::new();
+    for mut position in positions_open {
+        for rate in &rates {
+            let payment = position.apply_funding(*rate, price);
+            log::debug!("Applied funding {payment} to position {}", position.id);
+            account_payments
+                .entry(position.account_id)
+                .or_default()
+                .push(payment);
+        }
 
-    let calculated_unrealized_pnl = portfolio_clone
-        .calculate_unrealized_pnl(&instrument_id)
-        .expect("Failed to calculate unrealized PnL");
-    let calculated_realized_pnl = portfolio_clone
+        if let Err(e) = cache.borrow_mut().update_position(&position) {
+            log::error!("Error updating position {}: {e}", position.id);
+        }
+    }
+
+    for (account_id, payments) in account_payments {
+        settle_funding_payments(&cache, &msgbus, &inner, &account_id, &payments, ts_now);
+    }
+
+    let mut portfolio_clone = Portfolio {
+        clock,
+        cache,
+        msgbus,
+        inner: inner.clone(),
+    };
+
+    // Funding settles into realized PnL, so the cached value is recalculated
+    match portfolio_clone.calculate_realized_pnl(&instrument_id) {
+        Some(realized_pnl) => {
+            inner
+                .borrow_mut()
+                .realized_pnls
+                .insert(instrument_id, realized_pnl);
+        }
+        None => {
+            inner.borrow_mut().realized_pnls.remove(&instrument_id);
+        }
+    }
+
+    portfolio_clone.publish_update(&instrument_id);
+}
+
+/// Applies the funding `payments` to the balances of the account (when calculated by the
+/// portfolio), publishing the resulting account state.
+fn settle_funding_payments(
+    cache: &Rc<RefCell<Cache>>,
+    msgbus: &Rc<RefCell<MessageBus>>,
+    inner: &Rc<RefCell<PortfolioState>>,
+    account_id: &AccountId,
+    payments: &[Money],
+    ts_event: UnixNanos,
+) {
+    let Some(mut account) = cache.borrow().account(account_id).cloned() else {
+        log::error!("Cannot settle funding: no account registered for {account_id}");
+        return;
+    };
+
+    let calculate_account_state = match &account {
+        AccountAny::Cash(cash_account) => cash_account.calculate_account_state,
+        AccountAny::Margin(margin_account) => margin_account.calculate_account_state,
+    };
+    if !calculate_account_state {
+        return; // Balances are reported by the venue
+    }
+
+    let mut account_state = None;
+    for payment in payments.iter().filter(|payment| !payment.is_zero()) {
+        match inner
+            .borrow()
+            .accounts
+            .apply_funding_payment(account, *payment, ts_event)
+        {
+            Ok((updated_account, state)) => {
+                account = updated_account;
+                account_state = Some(state);
+            }
+            Err(e) => {
+                log::error!("Cannot settle funding for {account_id}: {e}");
+                return;
+            }
+        }
+    }
+
+    let Some(account_state) = account_state else {
+        return;
+    };
+
+    if let Err(e) = cache.borrow_mut().update_account(account) {
+        log::error!("Failed to update account: {e}");
+        return;
+    }
+    msgbus.borrow().publish(
+        &Ustr::from(&format!("events.account.{account_id}")),
+        &account_state,
+    );
+}
+
+fn mark_to_market(
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    clock: Rc<RefCell<dyn Clock>>,
+    inner: Rc<RefCell<PortfolioState>>,
+    instrument_id: &InstrumentId,
+    previous_pnl: Option<Money>,
+) {
+    if cache
+        .borrow()
+        .positions_open(None, Some(instrument_id), None, None)
+        .is_empty()
+    {
+        return; // Nothing to mark
+    }
+
+    let mut portfolio_clone = Portfolio {
+        clock,
+        cache,
+        msgbus,
+        inner: inner.clone(),
+    };
+
+    let unrealized_pnl = match portfolio_clone.calculate_unrealized_pnl(instrument_id) {
+        Some(pnl) => pnl,
+        None => return, // Added to pending calculations
+    };
+
+    inner
+        .borrow_mut()
+        .unrealized_pnls
+        .insert(*instrument_id, unrealized_pnl);
+
+    if previous_pnl != Some(unrealized_pnl) {
+        portfolio_clone.publish_update(instrument_id);
+    }
+}
+
+fn update_order(
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    clock: Rc<RefCell<dyn Clock>>,
+    inner: Rc<RefCell<PortfolioState>>,
+    event: &OrderEventAny,
+) {
+    let account_id = match event.account_id() {
+        Some(account_id) => account_id,
+        None => {
+            return; // No Account Assigned
+        }
+    };
+
+    match event {
+        OrderEventAny::Accepted(_)
+        | OrderEventAny::Canceled(_)
+        | OrderEventAny::Rejected(_)
+        | OrderEventAny::Updated(_)
+        | OrderEventAny::Filled(_) => {}
+        _ => {
+            return;
+        }
+    }
+
+    let (mut account, instrument) = {
+        let borrowed_cache = cache.borrow();
+        let account = if let Some(account) = borrowed_cache.account(&account_id) {
+            account.clone()
+        } else {
+            log::error!(
+                "Cannot update order: no account registered for {}",
+                account_id
+            );
+            return;
+        };
+
+        let calculate_account_state = match &account {
+            AccountAny::Cash(cash_account) => cash_account.base.calculate_account_state,
+            AccountAny::Margin(margin_account) => margin_account.base.calculate_account_state,
+        };
+        if !calculate_account_state {
+            return;
+        }
+
+        let order = if let Some(order) = borrowed_cache.order(&event.client_order_id()) {
+            order
+        } else {
+            log::error!(
+                "Cannot update order: {} not found in the cache",
+                event.client_order_id()
+            );
+            return; // No Order Found
+        };
+
+        if matches!(event, OrderEventAny::Rejected(_)) && order.order_type() != OrderType::StopLimit
+        {
+            return; // No change to account state
+        }
+
+        let instrument = if let Some(instrument) = borrowed_cache.instrument(&event.instrument_id())
+        {
+            instrument.clone()
+        } else {
+            log::error!(
+                "Cannot update order: no instrument found for {}",
+                event.instrument_id()
+            );
+            return;
+        };
+
+        (account, instrument)
+    };
+
+    if let OrderEventAny::Filled(order_filled) = event {
+        let (updated_account, _) =
+            inner
+                .borrow()
+                .accounts
+                .update_balances(account, instrument.clone(), *order_filled);
+        account = updated_account;
+
+        let mut portfolio_clone = Portfolio {
+            clock: clock.clone(),
+            cache: cache.clone(),
+            msgbus: msgbus.clone(),
+            inner: inner.clone(),
+        };
+
+        match portfolio_clone.calculate_unrealized_pnl(&order_filled.instrument_id) {
+            Some(unrealized_pnl) => {
+                inner
+                    .borrow_mut()
+                    .unrealized_pnls
+                    .insert(event.instrument_id(), unrealized_pnl);
+            }
+            None => {
+                log::error!(
+                    "Failed to calculate unrealized PnL for instrument {}",
+                    event.instrument_id()
+                );
+            }
+        }
+    }
+
+    let orders_open: Vec<OrderAny> = cache
+        .borrow()
+        .orders_open(None, Some(&event.instrument_id()), None, None)
+        .into_iter()
+        .cloned()
+        .collect();
+
+    let result = inner.borrow().accounts.update_orders(
+        &account,
+        instrument.clone(),
+        orders_open.iter().collect(),
+        clock.borrow().timestamp_ns(),
+    );
+
+    match result {
+        Some((updated_account, account_state)) => {
+            if let Err(e) = cache.borrow_mut().update_account(updated_account) {
+                log::error!("Failed to update account: {e}");
+                return;
+            }
+            msgbus.borrow().publish(
+                &Ustr::from(&format!("events.account.{account_id}")),
+                &account_state,
+            );
+        }
+        None => {
+            if let Err(e) = cache.borrow_mut().update_account(account) {
+                log::error!("Failed to update account: {e}");
+            }
+            log::debug!("Added pending calculation for {}", instrument.id());
+            inner.borrow_mut().pending_calcs.insert(instrument.id());
+        }
+    }
+
+    log::debug!("Updated {}", event);
+}
+
+fn update_position(
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    clock: Rc<RefCell<dyn Clock>>,
+    inner: Rc<RefCell<PortfolioState>>,
+    event: &PositionEvent,
+) {
+    let instrument_id = event.instrument_id();
+
+    let positions_open: Vec<Position> = {
+        let borrowed_cache = cache.borrow();
+
+        borrowed_cache
+            .positions_open(None, Some(&instrument_id), None, None)
+            .iter()
+            .map(|o| (*o).clone())
+            .collect()
+    };
+
+    log::debug!("postion fresh from cache -> {:?}", positions_open);
+
+    let mut portfolio_clone = Portfolio {
+        clock: clock.clone(),
+        cache: cache.clone(),
+        msgbus: msgbus.clone(),
+        inner: inner.clone(),
+    };
+
+    portfolio_clone.update_net_position(&instrument_id, positions_open.clone());
+
+    let calculated_unrealized_pnl = portfolio_clone
+        .calculate_unrealized_pnl(&instrument_id)
+        .expect("Failed to calculate unrealized PnL");
+    let calculated_realized_pnl = portfolio_clone
         .calculate_realized_pnl(&instrument_id)
         .expect("Failed to calculate realized PnL");
 
@@ -1413,17 +1938,28 @@ fn update_position(
         .realized_pnls
         .insert(event.instrument_id(), calculated_realized_pnl);
 
-    let borrowed_cache = cache.borrow();
-    let account = borrowed_cache.account(&event.account_id());
+    portfolio_clone.publish_update(&instrument_id);
+
+    let (margin_account, instrument, mark_price) = {
+        let borrowed_cache = cache.borrow();
+        let margin_account = match borrowed_cache.account(&event.account_id()) {
+            Some(AccountAny::Margin(margin_account)) => margin_account.clone(),
+            Some(AccountAny::Cash(_)) => return,
+            None => {
+                log::error!(
+                    "Cannot update position: no account registered for {}",
+                    event.account_id()
+                );
+                return;
+            }
+        };
 
-    if let Some(AccountAny::Margin(margin_account)) = account {
         if !margin_account.calculate_account_state {
             return; // Nothing to calculate
         };
 
-        let borrowed_cache = cache.borrow();
         let instrument = if let Some(instrument) = borrowed_cache.instrument(&instrument_id) {
-            instrument
+            instrument.clone()
         } else {
             log::error!(
                 "Cannot update position: no instrument found for {}",
@@ -1432,30 +1968,56 @@ fn update_position(
             return;
         };
 
-        let result = inner.borrow_mut().accounts.update_positions(
-            margin_account,
-            instrument.clone(),
-            positions_open.iter().collect(),
-            clock.borrow().timestamp_ns(),
-        );
-        let mut borrowed_cache = cache.borrow_mut();
-        if let Some((margin_account, _)) = result {
-            borrowed_cache
-                .add_account(AccountAny::Margin(margin_account)) // Temp Fix to update the mutated account
-                .unwrap();
+        let mark_price = inner.borrow().mark_price(&borrowed_cache, &instrument_id);
+
+        (margin_account, instrument, mark_price)
+    };
+
+    let result = inner.borrow().accounts.update_positions(
+        &margin_account,
+        instrument,
+        positions_open.iter().collect(),
+        mark_price,
+        clock.borrow().timestamp_ns(),
+    );
+
+    if let Some((margin_account, account_state)) = result {
+        if let Err(e) = cache
+            .borrow_mut()
+            .update_account(AccountAny::Margin(margin_account))
+        {
+            log::error!("Failed to update account: {e}");
+            return;
         }
-    } else if account.is_none() {
-        log::error!(
-            "Cannot update position: no account registered for {}",
-            event.account_id()
+        msgbus.borrow().publish(
+            &Ustr::from(&format!("events.account.{}", event.account_id())),
+            &account_state,
         );
     }
 }
 
-pub fn update_account(cache: Rc<RefCell<Cache>>, event: &AccountState) {
+/// Applies the account state `event` to the cached account, creating the account if none
+/// exists.
+///
+/// For a venue-reported state of a cash account, any cached balances which have drifted
+/// from the reported balances by more than the `tolerance` are logged before the reported
+/// balances are applied.
+pub fn update_account(cache: Rc<RefCell<Cache>>, event: &AccountState, tolerance: Decimal) {
     let mut borrowed_cache = cache.borrow_mut();
 
     if let Some(existing) = borrowed_cache.account(&event.account_id) {
+        if let (AccountAny::Cash(cash_account), true) = (existing, event.is_reported) {
+            for drift in cash_account.reconcile_balances(&event.balances, tolerance) {
+                log::warn!(
+                    "{} {} balance drifted from venue: total_drift={}, free_drift={}",
+                    event.account_id,
+                    drift.currency,
+                    drift.total_drift(),
+                    drift.free_drift(),
+                );
+            }
+        }
+
         let mut account = existing.clone();
         account.apply(event.clone());
 
@@ -1488,10 +2050,18 @@ pub fn update_account(cache: Rc<RefCell<Cache>>, event: &AccountState) {
 mod tests {
     use std::{cell::RefCell, rc::Rc};
 
-    use nautilus_common::{cache::Cache, clock::TestClock, msgbus::MessageBus};
+    use nautilus_common::{
+        cache::Cache,
+        clock::TestClock,
+        msgbus::{
+            stubs::{get_message_saving_handler, get_saved_messages},
+            MessageBus,
+        },
+    };
     use nautilus_core::nanos::UnixNanos;
     use nautilus_model::{
-        data::QuoteTick,
+        accounts::{Account, AccountAny, MarginAccount},
+        data::{FundingRateUpdate, MarkPriceUpdate, QuoteTick},
         enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType},
         events::{
             account::stubs::cash_account_state,
@@ -1515,6 +2085,7 @@ mod tests {
     use rust_decimal::{prelude::FromPrimitive, Decimal};
 
     use super::Portfolio;
+    use crate::{config::PortfolioConfig, update::PortfolioUpdate};
 
     #[fixture]
     fn msgbus() -> MessageBus {
@@ -1578,6 +2149,7 @@ mod tests {
             Rc::new(RefCell::new(msgbus)),
             Rc::new(RefCell::new(simple_cache)),
             Rc::new(RefCell::new(clock)),
+            None,
         )
     }
 
@@ -2186,6 +2758,83 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_order_accept_with_calculated_margin_account_publishes_account_state(
+        mut portfolio: Portfolio,
+        instrument_btcusdt: InstrumentAny,
+    ) {
+        let handler = get_message_saving_handler::<AccountState>(None);
+        portfolio
+            .msgbus
+            .borrow_mut()
+            .subscribe("events.account.*", handler.clone(), None);
+
+        let account = MarginAccount::new(get_margin_account(None), true);
+        portfolio
+            .cache
+            .borrow_mut()
+            .add_account(AccountAny::Margin(account))
+            .unwrap();
+
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_btcusdt.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("100.0"))
+            .price(Price::new(5.0, 0))
+            .build();
+
+        portfolio
+            .cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, true)
+            .unwrap();
+
+        let order_submitted = submit_order(&order);
+        order
+            .apply(OrderEventAny::Submitted(order_submitted))
+            .unwrap();
+        portfolio.cache.borrow_mut().update_order(&order).unwrap();
+
+        let order_accepted = accept_order(&order);
+        order
+            .apply(OrderEventAny::Accepted(order_accepted))
+            .unwrap();
+        portfolio.cache.borrow_mut().update_order(&order).unwrap();
+
+        // Act
+        portfolio.update_order(&OrderEventAny::Accepted(order_accepted));
+
+        // Assert
+        let states = get_saved_messages::<AccountState>(handler);
+        assert_eq!(states.len(), 1);
+        assert_eq!(states[0].account_type, AccountType::Margin);
+        assert_eq!(states[0].margins.len(), 1);
+        assert_eq!(states[0].margins[0].initial.as_f64(), 1.5);
+
+        let cache = portfolio.cache.borrow();
+        let Some(AccountAny::Margin(account)) = cache.account(&account_id()) else {
+            panic!("Expected margin account in cache");
+        };
+        assert_eq!(
+            account.initial_margin(instrument_btcusdt.id()).as_f64(),
+            1.5
+        );
+        assert_eq!(
+            account
+                .balance_locked(Some(Currency::USDT()))
+                .unwrap()
+                .as_f64(),
+            1.5
+        );
+        assert_eq!(
+            account
+                .balance_free(Some(Currency::USDT()))
+                .unwrap()
+                .as_f64(),
+            99998.5
+        );
+    }
+
     #[rstest]
     fn test_update_positions(mut portfolio: Portfolio, instrument_audusd: InstrumentAny) {
         let account_state = get_cash_account(None);
@@ -3292,4 +3941,326 @@ mod tests {
         // FIX: TODO: should not be empty
         assert_eq!(portfolio.margins_maint(&Venue::from("SIM")), HashMap::new());
     }
+
+    #[rstest]
+    fn test_equity_marks_balances_with_unrealized_pnl(
+        mut portfolio: Portfolio,
+        instrument_audusd: InstrumentAny,
+    ) {
+        let account_state = get_margin_account(None);
+        portfolio.update_account(&account_state);
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("10.00"))
+            .build();
+
+        let mut fill = fill_order(&order);
+        fill.position_id = Some(PositionId::new("SSD"));
+
+        let last = get_quote_tick(&instrument_audusd, 10510.0, 10511.0, 1.0, 1.0);
+        portfolio.cache.borrow_mut().add_quote(last).unwrap();
+        portfolio.update_quote_tick(&last);
+
+        let position = Position::new(&instrument_audusd, fill);
+        portfolio
+            .cache
+            .borrow_mut()
+            .add_position(position.clone(), OmsType::Hedging)
+            .unwrap();
+        portfolio.update_position(&PositionEvent::PositionOpened(get_open_position(&position)));
+
+        let equity = portfolio.equity(&Venue::from("SIM"));
+
+        assert_eq!(equity.get(&Currency::USD()).unwrap().as_f64(), -6435.89);
+        assert_eq!(equity.get(&Currency::BTC()).unwrap().as_f64(), 10.0);
+    }
+
+    #[rstest]
+    fn test_equity_when_no_account_returns_empty(mut portfolio: Portfolio, venue: Venue) {
+        assert_eq!(portfolio.equity(&venue), HashMap::new());
+    }
+
+    #[rstest]
+    fn test_update_position_publishes_portfolio_update(
+        mut portfolio: Portfolio,
+        instrument_audusd: InstrumentAny,
+    ) {
+        let handler = get_message_saving_handler::<PortfolioUpdate>(None);
+        portfolio
+            .msgbus
+            .borrow_mut()
+            .subscribe("portfolio.updates.*", handler.clone(), None);
+
+        let account_state = get_margin_account(None);
+        portfolio.update_account(&account_state);
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("10.00"))
+            .build();
+
+        let mut fill = fill_order(&order);
+        fill.position_id = Some(PositionId::new("SSD"));
+
+        let last = get_quote_tick(&instrument_audusd, 10510.0, 10511.0, 1.0, 1.0);
+        portfolio.cache.borrow_mut().add_quote(last).unwrap();
+        portfolio.update_quote_tick(&last);
+
+        let position = Position::new(&instrument_audusd, fill);
+        portfolio
+            .cache
+            .borrow_mut()
+            .add_position(position.clone(), OmsType::Hedging)
+            .unwrap();
+        portfolio.update_position(&PositionEvent::PositionOpened(get_open_position(&position)));
+
+        let updates = get_saved_messages::<PortfolioUpdate>(handler);
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].instrument_id, instrument_audusd.id());
+        assert_eq!(updates[0].net_position, Decimal::new(561, 3));
+        assert_eq!(updates[0].net_exposure.unwrap().as_f64(), 10510.0);
+        assert_eq!(updates[0].unrealized_pnl.unwrap().as_f64(), -6445.89);
+        assert_eq!(updates[0].realized_pnl.unwrap().as_f64(), 0.0);
+    }
+
+    #[rstest]
+    fn test_quote_tick_marks_open_positions_when_initialized(
+        mut portfolio: Portfolio,
+        instrument_audusd: InstrumentAny,
+    ) {
+        let account_state = get_margin_account(None);
+        portfolio.update_account(&account_state);
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("10.00"))
+            .build();
+
+        let mut fill = fill_order(&order);
+        fill.position_id = Some(PositionId::new("SSD"));
+
+        let first = get_quote_tick(&instrument_audusd, 10510.0, 10511.0, 1.0, 1.0);
+        portfolio.cache.borrow_mut().add_quote(first).unwrap();
+
+        let position = Position::new(&instrument_audusd, fill);
+        portfolio
+            .cache
+            .borrow_mut()
+            .add_position(position, OmsType::Hedging)
+            .unwrap();
+        portfolio.initialize_positions();
+        assert!(portfolio.is_initialized());
+        let initial_pnl = portfolio.unrealized_pnl(&instrument_audusd.id()).unwrap();
+
+        let handler = get_message_saving_handler::<PortfolioUpdate>(None);
+        portfolio
+            .msgbus
+            .borrow_mut()
+            .subscribe("portfolio.updates.*", handler.clone(), None);
+
+        let second = get_quote_tick(&instrument_audusd, 10520.0, 10521.0, 1.0, 1.0);
+        portfolio.cache.borrow_mut().add_quote(second).unwrap();
+        portfolio.update_quote_tick(&second);
+
+        // Marking with an unchanged quote does not publish again
+        portfolio.update_quote_tick(&second);
+
+        let updates = get_saved_messages::<PortfolioUpdate>(handler);
+        let marked_pnl = portfolio.unrealized_pnl(&instrument_audusd.id()).unwrap();
+        assert!(marked_pnl > initial_pnl);
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].unrealized_pnl, Some(marked_pnl));
+    }
+
+    fn open_funding_position(portfolio: &mut Portfolio, instrument: &InstrumentAny) -> Position {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("10.00"))
+            .build();
+
+        let mut fill = fill_order(&order);
+        fill.position_id = Some(PositionId::new("SSD"));
+
+        let last = get_quote_tick(instrument, 10510.0, 10511.0, 1.0, 1.0);
+        portfolio.cache.borrow_mut().add_quote(last).unwrap();
+        portfolio.update_quote_tick(&last);
+
+        let position = Position::new(instrument, fill);
+        portfolio
+            .cache
+            .borrow_mut()
+            .add_position(position.clone(), OmsType::Hedging)
+            .unwrap();
+        portfolio.update_position(&PositionEvent::PositionOpened(get_open_position(&position)));
+        position
+    }
+
+    fn funding_rate(
+        instrument: &InstrumentAny,
+        rate: Decimal,
+        next_funding_ns: u64,
+        ts_event: u64,
+    ) -> FundingRateUpdate {
+        FundingRateUpdate::new(
+            instrument.id(),
+            rate,
+            Some(next_funding_ns.into()),
+            ts_event.into(),
+            ts_event.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_funding_rate_settles_into_realized_pnl_once_per_period(
+        mut portfolio: Portfolio,
+        instrument_audusd: InstrumentAny,
+    ) {
+        let account_state = get_margin_account(None);
+        portfolio.update_account(&account_state);
+        let position = open_funding_position(&mut portfolio, &instrument_audusd);
+        let realized_before = portfolio.realized_pnl(&instrument_audusd.id()).unwrap();
+
+        // Rate announced for the period ending at 100, then revised before settlement
+        let rate = Decimal::new(1, 4);
+        portfolio.update_funding_rate(&funding_rate(
+            &instrument_audusd,
+            rate * Decimal::TWO,
+            100,
+            0,
+        ));
+        portfolio.update_funding_rate(&funding_rate(&instrument_audusd, rate, 100, 50));
+        let pending = portfolio
+            .cache
+            .borrow()
+            .position(&position.id)
+            .unwrap()
+            .clone();
+        assert_eq!(pending.funding_payments, None);
+
+        // Funding time reached: the period settles once at the last announced rate
+        portfolio.update_funding_rate(&funding_rate(&instrument_audusd, rate, 200, 100));
+        portfolio.update_funding_rate(&funding_rate(&instrument_audusd, rate, 200, 150));
+
+        let cached = portfolio
+            .cache
+            .borrow()
+            .position(&position.id)
+            .unwrap()
+            .clone();
+        let funding = cached.funding_payments.unwrap();
+        let realized_after = portfolio.realized_pnl(&instrument_audusd.id()).unwrap();
+        let expected = cached.clone().apply_funding(rate, Price::new(10510.5, 5));
+        assert!(funding.as_f64() < 0.0); // Long pays a positive rate
+        assert_eq!(funding, expected);
+        assert_eq!(realized_after, realized_before + funding);
+    }
+
+    #[rstest]
+    fn test_funding_rate_settles_payment_into_account_balance_at_mark_price(
+        mut portfolio: Portfolio,
+        instrument_audusd: InstrumentAny,
+    ) {
+        let position = open_funding_position(&mut portfolio, &instrument_audusd);
+        let account = MarginAccount::new(get_margin_account(None), true);
+        portfolio
+            .cache
+            .borrow_mut()
+            .add_account(AccountAny::Margin(account))
+            .unwrap();
+
+        let mark = MarkPriceUpdate::new(
+            instrument_audusd.id(),
+            Price::new(10600.0, 5),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        portfolio.cache.borrow_mut().add_mark_price(mark).unwrap();
+
+        let rate = Decimal::new(1, 6);
+        portfolio.update_funding_rate(&funding_rate(&instrument_audusd, rate, 100, 0));
+        portfolio.update_funding_rate(&funding_rate(&instrument_audusd, rate, 200, 100));
+
+        let cached = portfolio
+            .cache
+            .borrow()
+            .position(&position.id)
+            .unwrap()
+            .clone();
+        let funding = cached.funding_payments.unwrap();
+        assert_eq!(
+            funding,
+            position.clone().apply_funding(rate, Price::new(10600.0, 5))
+        );
+
+        let borrowed_cache = portfolio.cache.borrow();
+        let account = borrowed_cache.account(&account_id()).unwrap();
+        assert_eq!(
+            account.balances()[&Currency::USD()].total,
+            Money::new(10.0, Currency::USD()) + funding
+        );
+    }
+
+    #[rstest]
+    fn test_mark_price_marks_open_positions_when_configured(
+        msgbus: MessageBus,
+        mut simple_cache: Cache,
+        clock: TestClock,
+        instrument_audusd: InstrumentAny,
+    ) {
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+        let config = PortfolioConfig {
+            use_mark_prices: true,
+            ..Default::default()
+        };
+        let mut portfolio = Portfolio::new(
+            Rc::new(RefCell::new(msgbus)),
+            Rc::new(RefCell::new(simple_cache)),
+            Rc::new(RefCell::new(clock)),
+            Some(config),
+        );
+
+        let account_state = get_margin_account(None);
+        portfolio.update_account(&account_state);
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("10.00"))
+            .build();
+
+        let mut fill = fill_order(&order);
+        fill.position_id = Some(PositionId::new("SSD"));
+
+        let quote = get_quote_tick(&instrument_audusd, 10510.0, 10511.0, 1.0, 1.0);
+        portfolio.cache.borrow_mut().add_quote(quote).unwrap();
+
+        let position = Position::new(&instrument_audusd, fill);
+        portfolio
+            .cache
+            .borrow_mut()
+            .add_position(position, OmsType::Hedging)
+            .unwrap();
+        portfolio.initialize_positions();
+        let initial_pnl = portfolio.unrealized_pnl(&instrument_audusd.id()).unwrap();
+
+        let mark = MarkPriceUpdate::new(
+            instrument_audusd.id(),
+            Price::new(10600.0, 5),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        portfolio.cache.borrow_mut().add_mark_price(mark).unwrap();
+        portfolio.update_mark_price(&mark);
+
+        // The long position is marked from the mark price rather than the bid
+        let marked_pnl = portfolio.unrealized_pnl(&instrument_audusd.id()).unwrap();
+        assert!(marked_pnl > initial_pnl);
+    }
 }
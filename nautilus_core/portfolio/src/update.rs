@@ -0,0 +1,122 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Snapshot of the portfolio's view of a single instrument, published on the message bus.
+
+use std::fmt::Display;
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{identifiers::InstrumentId, types::Money};
+use rust_decimal::Decimal;
+use ustr::Ustr;
+
+/// Represents the portfolio's view of a single instrument after a position or
+/// market data change.
+///
+/// Published on the `portfolio.updates.{instrument_id}` topic whenever the net
+/// position or unrealized/realized PnL for the instrument changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PortfolioUpdate {
+    /// The instrument ID for the update.
+    pub instrument_id: InstrumentId,
+    /// The net position quantity (positive for long, negative for short).
+    pub net_position: Decimal,
+    /// The net exposure marked at the latest price, if it can be calculated.
+    pub net_exposure: Option<Money>,
+    /// The unrealized PnL marked at the latest price, if it can be calculated.
+    pub unrealized_pnl: Option<Money>,
+    /// The realized PnL, if it can be calculated.
+    pub realized_pnl: Option<Money>,
+    /// UNIX timestamp (nanoseconds) when the update was generated.
+    pub ts_event: UnixNanos,
+}
+
+impl PortfolioUpdate {
+    /// Creates a new [`PortfolioUpdate`] instance.
+    #[must_use]
+    pub const fn new(
+        instrument_id: InstrumentId,
+        net_position: Decimal,
+        net_exposure: Option<Money>,
+        unrealized_pnl: Option<Money>,
+        realized_pnl: Option<Money>,
+        ts_event: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            net_position,
+            net_exposure,
+            unrealized_pnl,
+            realized_pnl,
+            ts_event,
+        }
+    }
+
+    /// Returns the message bus topic for updates of the given `instrument_id`.
+    #[must_use]
+    pub fn topic(instrument_id: &InstrumentId) -> Ustr {
+        Ustr::from(&format!("portfolio.updates.{instrument_id}"))
+    }
+}
+
+impl Display for PortfolioUpdate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fmt_money = |money: &Option<Money>| money.map_or("None".to_string(), |m| m.to_string());
+        write!(
+            f,
+            "{}(instrument_id={}, net_position={}, net_exposure={}, unrealized_pnl={}, realized_pnl={}, ts_event={})",
+            stringify!(PortfolioUpdate),
+            self.instrument_id,
+            self.net_position,
+            fmt_money(&self.net_exposure),
+            fmt_money(&self.unrealized_pnl),
+            fmt_money(&self.realized_pnl),
+            self.ts_event,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::types::Currency;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_topic_and_display() {
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let update = PortfolioUpdate::new(
+            instrument_id,
+            Decimal::ONE,
+            Some(Money::new(1.0, Currency::USD())),
+            None,
+            Some(Money::new(0.0, Currency::USD())),
+            1.into(),
+        );
+
+        assert_eq!(
+            PortfolioUpdate::topic(&instrument_id).as_str(),
+            "portfolio.updates.AUD/USD.SIM"
+        );
+        assert_eq!(
+            update.to_string(),
+            "PortfolioUpdate(instrument_id=AUD/USD.SIM, net_position=1, net_exposure=1.00 USD, unrealized_pnl=None, realized_pnl=0.00 USD, ts_event=1)"
+        );
+    }
+}
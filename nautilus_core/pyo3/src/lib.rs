@@ -49,6 +49,12 @@ fn nautilus_pyo3(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     sys_modules.set_item(format!("{module_name}.{n}"), m.getattr(n)?)?;
     re_export_module_attributes(m, n)?;
 
+    let n = "analysis";
+    let submodule = pyo3::wrap_pymodule!(nautilus_analysis::python::analysis);
+    m.add_wrapped(submodule)?;
+    sys_modules.set_item(format!("{module_name}.{n}"), m.getattr(n)?)?;
+    re_export_module_attributes(m, n)?;
+
     let n = "common";
     let submodule = pyo3::wrap_pymodule!(nautilus_common::python::common);
     m.add_wrapped(submodule)?;
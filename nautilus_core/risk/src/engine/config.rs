@@ -19,7 +19,7 @@ use std::collections::HashMap;
 
 use nautilus_common::throttler::RateLimit;
 use nautilus_core::datetime::NANOSECONDS_IN_SECOND;
-use nautilus_model::identifiers::InstrumentId;
+use nautilus_model::{identifiers::InstrumentId, types::Quantity};
 use rust_decimal::Decimal;
 
 #[derive(Debug)]
@@ -29,6 +29,14 @@ pub struct RiskEngineConfig {
     pub max_order_submit: RateLimit,
     pub max_order_modify: RateLimit,
     pub max_notional_per_order: HashMap<InstrumentId, Decimal>,
+    /// The maximum quantity per order for each instrument.
+    pub max_order_qty: HashMap<InstrumentId, Quantity>,
+    /// The maximum fractional deviation of an order price from the reference (last or mid) price.
+    pub max_price_deviation: Option<Decimal>,
+    /// The maximum number of open orders per instrument.
+    pub max_open_orders_per_instrument: Option<usize>,
+    /// The maximum order submission rate for each strategy.
+    pub max_order_submit_per_strategy: Option<RateLimit>,
     pub debug: bool,
 }
 
@@ -40,6 +48,10 @@ impl Default for RiskEngineConfig {
             max_order_submit: RateLimit::new(100, NANOSECONDS_IN_SECOND),
             max_order_modify: RateLimit::new(100, NANOSECONDS_IN_SECOND),
             max_notional_per_order: HashMap::new(),
+            max_order_qty: HashMap::new(),
+            max_price_deviation: None,
+            max_open_orders_per_instrument: None,
+            max_order_submit_per_strategy: None,
             debug: false,
         }
     }
@@ -15,23 +15,38 @@
 
 //! Provides a generic `ExecutionEngine` for all environments.
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    rc::Rc,
+    str::FromStr,
+};
 
+use bytes::Bytes;
 use config::RiskEngineConfig;
 use nautilus_common::{
     cache::Cache,
     clock::Clock,
     logging::{CMD, EVT, RECV},
-    msgbus::MessageBus,
+    messages::{
+        data::DataResponse,
+        execution::{CancelAllOrders, ModifyOrder, SubmitOrder, SubmitOrderList, TradingCommand},
+        risk::{RiskCommand, SetTradingState},
+    },
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
     throttler::Throttler,
 };
-use nautilus_core::uuid::UUID4;
-use nautilus_execution::messages::{ModifyOrder, SubmitOrder, SubmitOrderList, TradingCommand};
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 use nautilus_model::{
     accounts::{Account, AccountAny},
-    enums::{InstrumentClass, OrderSide, OrderStatus, TradingState},
-    events::{OrderDenied, OrderEventAny, OrderModifyRejected},
-    identifiers::InstrumentId,
+    data::Data,
+    enums::{InstrumentClass, OrderSide, OrderStatus, PositionSide, TradingState, TriggerType},
+    events::{OrderDenied, OrderEventAny, OrderModifyRejected, TradingStateChanged},
+    identifiers::{ClientId, InstrumentId, StrategyId},
     instruments::InstrumentAny,
     orders::{OrderAny, OrderList},
     types::{Currency, Money, Price, Quantity},
@@ -42,6 +57,9 @@ use ustr::Ustr;
 pub mod config;
 // pub mod tests;
 
+/// The cache key under which the current trading state is persisted.
+const TRADING_STATE_KEY: &str = "risk.trading_state";
+
 type SubmitOrderFn = Box<dyn Fn(SubmitOrder)>;
 type ModifyOrderFn = Box<dyn Fn(ModifyOrder)>;
 
@@ -55,6 +73,7 @@ pub struct RiskEngine {
     pub throttled_submit_order: Throttler<SubmitOrder, SubmitOrderFn>,
     pub throttled_modify_order: Throttler<ModifyOrder, ModifyOrderFn>,
     max_notional_per_order: HashMap<InstrumentId, Decimal>,
+    strategy_submit_timestamps: HashMap<StrategyId, VecDeque<UnixNanos>>,
     trading_state: TradingState,
     config: RiskEngineConfig,
 }
@@ -81,18 +100,39 @@ impl RiskEngine {
             msgbus.clone(),
         );
 
+        let trading_state = Self::load_trading_state(&cache);
+
         Self {
             clock,
             cache,
             msgbus,
             throttled_submit_order,
             throttled_modify_order,
-            max_notional_per_order: HashMap::new(),
-            trading_state: TradingState::Active,
+            max_notional_per_order: config.max_notional_per_order.clone(),
+            strategy_submit_timestamps: HashMap::new(),
+            trading_state,
             config,
         }
     }
 
+    fn load_trading_state(cache: &Rc<RefCell<Cache>>) -> TradingState {
+        let cache = cache.borrow();
+        let Ok(Some(value)) = cache.get(TRADING_STATE_KEY) else {
+            return TradingState::Active;
+        };
+
+        match std::str::from_utf8(value).map(TradingState::from_str) {
+            Ok(Ok(state)) => {
+                log::info!("Loaded trading state {state:?} from cache");
+                state
+            }
+            _ => {
+                log::error!("Invalid trading state in cache: {value:?}");
+                TradingState::Active
+            }
+        }
+    }
+
     fn create_submit_order_throttler(
         config: &RiskEngineConfig,
         clock: Rc<RefCell<dyn Clock>>,
@@ -102,7 +142,7 @@ impl RiskEngine {
         let success_handler = {
             let msgbus = msgbus.clone();
             Box::new(move |submit_order: SubmitOrder| {
-                msgbus.borrow_mut().send(
+                msgbus.borrow().send(
                     &Ustr::from("ExecEngine.execute"),
                     &TradingCommand::SubmitOrder(submit_order),
                 );
@@ -126,7 +166,7 @@ impl RiskEngine {
                 let denied = Self::create_order_denied(&submit_order, reason, &clock);
 
                 msgbus
-                    .borrow_mut()
+                    .borrow()
                     .send(&Ustr::from("ExecEngine.process"), &denied);
             }) as Box<dyn Fn(SubmitOrder)>
         };
@@ -149,7 +189,7 @@ impl RiskEngine {
         let success_handler = {
             let msgbus = msgbus.clone();
             Box::new(move |order: ModifyOrder| {
-                msgbus.borrow_mut().send(
+                msgbus.borrow().send(
                     &Ustr::from("ExecEngine.execute"),
                     &TradingCommand::ModifyOrder(order),
                 );
@@ -176,7 +216,7 @@ impl RiskEngine {
                 let rejected = Self::create_modify_rejected(&order, reason, &clock);
 
                 msgbus
-                    .borrow_mut()
+                    .borrow()
                     .send(&Ustr::from("ExecEngine.process"), &rejected);
             }) as Box<dyn Fn(ModifyOrder)>
         };
@@ -261,6 +301,16 @@ impl RiskEngine {
         self.handle_command(command);
     }
 
+    pub fn execute_risk(&mut self, command: RiskCommand) {
+        if self.config.debug {
+            log::debug!("{}{} {:?}", CMD, RECV, command);
+        }
+
+        match command {
+            RiskCommand::SetTradingState(command) => self.handle_set_trading_state(command),
+        }
+    }
+
     pub fn process(&mut self, event: OrderEventAny) {
         // This will extend to other events such as `RiskEvent`
         self.handle_event(event);
@@ -274,14 +324,22 @@ impl RiskEngine {
 
         self.trading_state = state;
 
-        let _ts_now = self.clock.borrow().timestamp_ns();
+        // Persist so the trading state survives a restart of the node
+        if let Err(e) = self
+            .cache
+            .borrow_mut()
+            .add(TRADING_STATE_KEY, Bytes::from(state.as_ref().to_string()))
+        {
+            log::error!("Cannot persist trading state: {e}");
+        }
 
-        // TODO: Create a new Event "TradingStateChanged" in OrderEventAny enum.
-        // let event = OrderEventAny::TradingStateChanged(TradingStateChanged::new(..,self.trading_state,..));
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let trader_id = self.msgbus.borrow().trader_id;
+        let event = TradingStateChanged::new(trader_id, state, UUID4::new(), ts_now, ts_now);
 
         self.msgbus
-            .borrow_mut()
-            .publish(&Ustr::from("events.risk"), &"message"); // TODO: Send the new Event here
+            .borrow()
+            .publish(&Ustr::from("events.risk"), &event);
 
         log::info!("Trading state set to {state:?}");
     }
@@ -295,6 +353,50 @@ impl RiskEngine {
 
     // -- COMMAND HANDLERS ------------------------------------------------------------------------
 
+    fn handle_set_trading_state(&mut self, command: SetTradingState) {
+        self.set_trading_state(command.trading_state);
+
+        if command.cancel_open_orders && self.trading_state == TradingState::Halted {
+            self.cancel_open_orders();
+        }
+    }
+
+    fn cancel_open_orders(&self) {
+        // Cancel per client, strategy and instrument, as open orders may span all three
+        let mut targets: Vec<(ClientId, StrategyId, InstrumentId)> = Vec::new();
+        {
+            let cache = self.cache.borrow();
+            for order in cache.orders_open(None, None, None, None) {
+                let instrument_id = order.instrument_id();
+                let client_id = cache
+                    .client_id(&order.client_order_id())
+                    .copied()
+                    .unwrap_or_else(|| ClientId::from(instrument_id.venue.as_str()));
+                let target = (client_id, order.strategy_id(), instrument_id);
+                if !targets.contains(&target) {
+                    targets.push(target);
+                }
+            }
+        }
+
+        let trader_id = self.msgbus.borrow().trader_id;
+        let ts_now = self.clock.borrow().timestamp_ns();
+        for (client_id, strategy_id, instrument_id) in targets {
+            let command = CancelAllOrders::new(
+                trader_id,
+                client_id,
+                strategy_id,
+                instrument_id,
+                OrderSide::NoOrderSide,
+                UUID4::new(),
+                ts_now,
+            )
+            .expect("Failed to create `CancelAllOrders` command");
+            log::warn!("Canceling open orders for {instrument_id} (TradingState::HALTED)");
+            self.send_to_execution(TradingCommand::CancelAllOrders(command));
+        }
+    }
+
     // Renamed from `execute_command`
     fn handle_command(&mut self, command: TradingCommand) {
         if self.config.debug {
@@ -313,7 +415,7 @@ impl RiskEngine {
         }
     }
 
-    fn handle_submit_order(&self, command: SubmitOrder) {
+    fn handle_submit_order(&mut self, command: SubmitOrder) {
         if self.config.bypass {
             self.send_to_execution(TradingCommand::SubmitOrder(command));
             return;
@@ -369,14 +471,24 @@ impl RiskEngine {
             return; // Denied
         }
 
+        if let Some(risk_msg) = self.check_open_orders(&instrument, 1) {
+            self.deny_order(order.clone(), &risk_msg);
+            return; // Denied
+        }
+
         if !self.check_orders_risk(instrument.clone(), Vec::from([order.clone()])) {
             return; // Denied
         }
 
+        if let Some(risk_msg) = self.check_strategy_submit_rate(command.strategy_id) {
+            self.deny_order(order.clone(), &risk_msg);
+            return; // Denied
+        }
+
         self.execution_gateway(instrument, TradingCommand::SubmitOrder(command.clone()));
     }
 
-    fn handle_submit_order_list(&self, command: SubmitOrderList) {
+    fn handle_submit_order_list(&mut self, command: SubmitOrderList) {
         if self.config.bypass {
             self.send_to_execution(TradingCommand::SubmitOrderList(command));
             return;
@@ -406,6 +518,12 @@ impl RiskEngine {
             }
         }
 
+        if let Some(risk_msg) = self.check_open_orders(&instrument, command.order_list.orders.len())
+        {
+            self.deny_order_list(command.order_list.clone(), &risk_msg);
+            return; // Denied
+        }
+
         if !self.check_orders_risk(instrument.clone(), command.order_list.clone().orders) {
             self.deny_order_list(
                 command.order_list.clone(),
@@ -414,6 +532,11 @@ impl RiskEngine {
             return; // Denied
         }
 
+        if let Some(risk_msg) = self.check_strategy_submit_rate(command.strategy_id) {
+            self.deny_order_list(command.order_list.clone(), &risk_msg);
+            return; // Denied
+        }
+
         self.execution_gateway(instrument, TradingCommand::SubmitOrderList(command));
     }
 
@@ -473,7 +596,9 @@ impl RiskEngine {
         };
 
         // Check Price
-        let mut risk_msg = self.check_price(&instrument, command.price);
+        let mut risk_msg = self
+            .check_price(&instrument, command.price)
+            .or_else(|| self.check_price_collar(&instrument, command.price));
         if let Some(risk_msg) = risk_msg {
             self.reject_modify_order(order, &risk_msg);
             return; // Denied
@@ -501,33 +626,29 @@ impl RiskEngine {
             }
             TradingState::Reducing => {
                 if let Some(quantity) = command.quantity {
-                    if quantity > order.quantity() {
-                        // TODO: Complete this after implementing portfolio
-                        // if order.is_buy() && self.portfolio.is_net_long(instrument.id()) {
-                        //     self.reject_modify_order(
-                        //         order.clone(),
-                        //         &format!(
-                        //             "TradingState is REDUCING and update will increase exposure {}",
-                        //             instrument.id()
-                        //         ),
-                        //     );
-                        //     return; // Denied
-                        // } else if order.is_sell() && self.portfolio.is_net_short(instrument.id()) {
-                        //     self.reject_modify_order(
-                        //         order.clone(),
-                        //         &format!(
-                        //             "TradingState is REDUCING and update will increase exposure {}",
-                        //             instrument.id()
-                        //         ),
-                        //     );
-                        //     return; // Denied
-                        // }
+                    if quantity > order.quantity()
+                        && ((order.is_buy() && self.is_net_long(&instrument.id()))
+                            || (order.is_sell() && self.is_net_short(&instrument.id())))
+                    {
+                        self.reject_modify_order(
+                            order,
+                            &format!(
+                                "TradingState is REDUCING and update will increase exposure {}",
+                                instrument.id()
+                            ),
+                        );
+                        return; // Denied
                     }
                 }
             }
             _ => {}
         }
 
+        if order.status() == OrderStatus::Emulated {
+            self.send_to_emulator(TradingCommand::ModifyOrder(command));
+            return;
+        }
+
         self.throttled_modify_order.send(command);
     }
 
@@ -551,7 +672,9 @@ impl RiskEngine {
         // CHECK PRICE
         ////////////////////////////////////////////////////////////////////////////////
         if order.price().is_some() {
-            let risk_msg = self.check_price(&instrument, order.price());
+            let risk_msg = self
+                .check_price(&instrument, order.price())
+                .or_else(|| self.check_price_collar(&instrument, order.price()));
             if let Some(risk_msg) = risk_msg {
                 self.deny_order(order, &risk_msg);
                 return false; // Denied
@@ -905,6 +1028,97 @@ impl RiskEngine {
             }
         }
 
+        // Check MAX quantity per order limit
+        if let Some(max_order_qty) = self.config.max_order_qty.get(&instrument.id()) {
+            if quantity_val > *max_order_qty {
+                return Some(format!(
+                    "QUANTITY_EXCEEDS_MAX_PER_ORDER: max_order_qty={max_order_qty}, quantity={quantity_val}"
+                ));
+            }
+        }
+
+        None
+    }
+
+    fn check_price_collar(
+        &self,
+        instrument: &InstrumentAny,
+        price: Option<Price>,
+    ) -> Option<String> {
+        let max_deviation = self.config.max_price_deviation?;
+        let price_val = price?;
+
+        // Reference is the last trade price, otherwise the last quote mid price
+        let reference = {
+            let borrowed_cache = self.cache.borrow();
+            if let Some(trade) = borrowed_cache.trade(&instrument.id()) {
+                trade.price.as_decimal()
+            } else if let Some(quote) = borrowed_cache.quote(&instrument.id()) {
+                (quote.bid_price.as_decimal() + quote.ask_price.as_decimal()) / Decimal::TWO
+            } else {
+                log::warn!(
+                    "Cannot check price collar: no prices for {}",
+                    instrument.id()
+                );
+                return None;
+            }
+        };
+
+        if reference <= Decimal::ZERO {
+            return None;
+        }
+
+        let deviation = (price_val.as_decimal() - reference).abs() / reference;
+        if deviation > max_deviation {
+            return Some(format!(
+                "PRICE_OUTSIDE_COLLAR: price={price_val}, reference={reference}, max_deviation={max_deviation}"
+            ));
+        }
+
+        None
+    }
+
+    fn check_open_orders(&self, instrument: &InstrumentAny, new_orders: usize) -> Option<String> {
+        let max_open_orders = self.config.max_open_orders_per_instrument?;
+        let open_orders = self
+            .cache
+            .borrow()
+            .orders_open(None, Some(&instrument.id()), None, None)
+            .len();
+
+        if open_orders + new_orders > max_open_orders {
+            return Some(format!(
+                "MAX_OPEN_ORDERS_EXCEEDED: max_open_orders={max_open_orders}, open_orders={open_orders}"
+            ));
+        }
+
+        None
+    }
+
+    fn check_strategy_submit_rate(&mut self, strategy_id: StrategyId) -> Option<String> {
+        let rate_limit = self.config.max_order_submit_per_strategy.clone()?;
+        let ts_now = self.clock.borrow().timestamp_ns();
+
+        // Drop submissions which have fallen outside the rate limit interval
+        let timestamps = self
+            .strategy_submit_timestamps
+            .entry(strategy_id)
+            .or_default();
+        while timestamps
+            .front()
+            .is_some_and(|ts| ts_now.as_u64().saturating_sub(ts.as_u64()) >= rate_limit.interval_ns)
+        {
+            timestamps.pop_front();
+        }
+
+        if timestamps.len() >= rate_limit.limit {
+            return Some(format!(
+                "MAX_ORDER_SUBMIT_RATE_EXCEEDED: strategy_id={strategy_id}, limit={}, interval_ns={}",
+                rate_limit.limit, rate_limit.interval_ns
+            ));
+        }
+
+        timestamps.push_back(ts_now);
         None
     }
 
@@ -957,7 +1171,7 @@ impl RiskEngine {
         ));
 
         self.msgbus
-            .borrow_mut()
+            .borrow()
             .send(&Ustr::from("ExecEngine.process"), &denied);
     }
 
@@ -986,7 +1200,7 @@ impl RiskEngine {
         ));
 
         self.msgbus
-            .borrow_mut()
+            .borrow()
             .send(&Ustr::from("ExecEngine.process"), &denied);
     }
 
@@ -1005,53 +1219,115 @@ impl RiskEngine {
             },
             TradingState::Reducing => match command {
                 TradingCommand::SubmitOrder(submit_order) => {
-                    let _order = submit_order.order;
-                    // if order.is_buy() && self.portfolio.is_net_long(instrument.id()) {
-                    //     self.deny_order(order, &format!("BUY when TradingState::REDUCING and LONG {}", instrument.id()));
-                    //     return;
-                    // }
-                    // else if order.is_sell() && self.portfolio.is_net_short(instrument.id()) {
-                    //     self.deny_order(order, &format!("SELL when TradingState::REDUCING and SHORT {}", instrument.id()));
-                    //     return;
-                    // }
-                    todo!("Pending portfolio implementation");
-                    // return;
+                    if let Some(reason) = self.check_reducing(&submit_order.order) {
+                        self.deny_order(submit_order.order, &reason);
+                        return; // Denied
+                    }
+                    self.send_submit_order(submit_order);
                 }
                 TradingCommand::SubmitOrderList(submit_order_list) => {
-                    let order_list = submit_order_list.order_list;
-                    for _order in order_list.orders {
-                        // if order.is_buy() && self.portfolio.is_net_long(instrument.id()) {
-                        //     self.deny_order_list(order_list, &format!("BUY when TradingState::REDUCING and LONG {}", instrument.id()));
-                        //     return;
-                        // }
-                        // else if order.is_sell() && self.portfolio.is_net_short(instrument.id()) {
-                        //     self.deny_order_list(order_list, &format!("SELL when TradingState::REDUCING and SHORT {}", instrument.id()));
-                        //     return;
-                        // }
-                        todo!("Pending portfolio implementation");
-                        // return;
+                    let reason = submit_order_list
+                        .order_list
+                        .orders
+                        .iter()
+                        .find_map(|order| self.check_reducing(order));
+                    if let Some(reason) = reason {
+                        self.deny_order_list(submit_order_list.order_list, &reason);
+                        return; // Denied
                     }
+                    self.send_to_execution(TradingCommand::SubmitOrderList(submit_order_list));
                 }
                 _ => {}
             },
             TradingState::Active => match command {
                 TradingCommand::SubmitOrder(submit_order) => {
-                    self.throttled_submit_order.send(submit_order);
+                    self.send_submit_order(submit_order);
                 }
-                TradingCommand::SubmitOrderList(_submit_order_list) => {
-                    todo!("NOT IMPLEMENTED");
+                TradingCommand::SubmitOrderList(submit_order_list) => {
+                    self.send_to_execution(TradingCommand::SubmitOrderList(submit_order_list));
                 }
                 _ => {}
             },
         }
     }
 
+    /// Returns the reason to deny the `order` if it would increase exposure while reducing.
+    fn check_reducing(&self, order: &OrderAny) -> Option<String> {
+        let instrument_id = order.instrument_id();
+        let net_qty = self.net_position_qty(&instrument_id);
+        let side = order.order_side();
+
+        let position = match net_qty.cmp(&Decimal::ZERO) {
+            std::cmp::Ordering::Greater => PositionSide::Long,
+            std::cmp::Ordering::Less => PositionSide::Short,
+            std::cmp::Ordering::Equal => PositionSide::Flat,
+        };
+        let reduces = matches!(
+            (side, position),
+            (OrderSide::Buy, PositionSide::Short) | (OrderSide::Sell, PositionSide::Long)
+        );
+
+        if !reduces {
+            return Some(format!(
+                "{side} when TradingState::REDUCING and {position} {instrument_id}"
+            ));
+        }
+
+        if order.quantity().as_decimal() > net_qty.abs() {
+            return Some(format!(
+                "{side} when TradingState::REDUCING would flip {position} {instrument_id}"
+            ));
+        }
+
+        None
+    }
+
+    fn is_net_long(&self, instrument_id: &InstrumentId) -> bool {
+        self.net_position_qty(instrument_id) > Decimal::ZERO
+    }
+
+    fn is_net_short(&self, instrument_id: &InstrumentId) -> bool {
+        self.net_position_qty(instrument_id) < Decimal::ZERO
+    }
+
+    /// Returns the signed net quantity of all open positions for the `instrument_id`.
+    fn net_position_qty(&self, instrument_id: &InstrumentId) -> Decimal {
+        self.cache
+            .borrow()
+            .positions_open(None, Some(instrument_id), None, None)
+            .iter()
+            .map(|position| match position.side {
+                PositionSide::Short => -position.quantity.as_decimal(),
+                _ => position.quantity.as_decimal(),
+            })
+            .sum()
+    }
+
     fn send_to_execution(&self, command: TradingCommand) {
         self.msgbus
-            .borrow_mut()
+            .borrow()
             .send(&Ustr::from("ExecEngine.execute"), &command);
     }
 
+    /// Sends the `command` to the order emulator if the order has an emulation trigger,
+    /// otherwise to the execution engine through the submit order throttler.
+    fn send_submit_order(&self, command: SubmitOrder) {
+        let is_emulated = command
+            .order
+            .emulation_trigger()
+            .is_some_and(|trigger| trigger != TriggerType::NoTrigger);
+        if is_emulated {
+            self.send_to_emulator(TradingCommand::SubmitOrder(command));
+        } else {
+            self.throttled_submit_order.send(command);
+        }
+    }
+
+    fn send_to_emulator(&self, command: TradingCommand) {
+        let endpoint = self.msgbus.borrow().switchboard.order_emulator_execute;
+        self.msgbus.borrow().send(&endpoint, &command);
+    }
+
     fn handle_event(&mut self, event: OrderEventAny) {
         // We intend to extend the risk engine to be able to handle additional events.
         // For now we just log.
@@ -1061,6 +1337,53 @@ impl RiskEngine {
     }
 }
 
+/// Dispatches trading commands from the message bus to a [`RiskEngine`].
+pub struct RiskEngineHandler {
+    id: Ustr,
+    engine: Rc<RefCell<RiskEngine>>,
+}
+
+impl RiskEngineHandler {
+    /// Creates a new [`RiskEngineHandler`] instance.
+    pub fn new(engine: Rc<RefCell<RiskEngine>>) -> Self {
+        Self {
+            id: Ustr::from("RiskEngine"),
+            engine,
+        }
+    }
+}
+
+impl MessageHandler for RiskEngineHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        if let Some(command) = message.downcast_ref::<TradingCommand>() {
+            self.engine.borrow_mut().execute(command.clone());
+        } else if let Some(command) = message.downcast_ref::<RiskCommand>() {
+            self.engine.borrow_mut().execute_risk(command.clone());
+        }
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, _data: Data) {}
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Registers the `engine` with the message bus, to receive commands on the risk engine
+/// endpoint.
+pub fn register_risk_engine(
+    engine: Rc<RefCell<RiskEngine>>,
+    msgbus: &mut MessageBus,
+) -> ShareableMessageHandler {
+    let handler = ShareableMessageHandler(Rc::new(RiskEngineHandler::new(engine)));
+    msgbus.register(msgbus.switchboard.risk_engine_execute, handler.clone());
+    handler
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -1068,9 +1391,14 @@ impl RiskEngine {
 mod tests {
     use std::{cell::RefCell, collections::HashMap, rc::Rc, str::FromStr};
 
+    use bytes::Bytes;
     use nautilus_common::{
         cache::Cache,
         clock::TestClock,
+        messages::{
+            execution::{ModifyOrder, SubmitOrder, SubmitOrderList, TradingCommand},
+            risk::{RiskCommand, SetTradingState},
+        },
         msgbus::{
             handler::ShareableMessageHandler,
             stubs::{get_message_saving_handler, get_saved_messages},
@@ -1079,17 +1407,16 @@ mod tests {
         throttler::RateLimit,
     };
     use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
-    use nautilus_execution::messages::{ModifyOrder, SubmitOrder, SubmitOrderList, TradingCommand};
     use nautilus_model::{
         accounts::{
             stubs::{cash_account, margin_account},
             AccountAny,
         },
         data::{stubs::quote_audusd, QuoteTick},
-        enums::{AccountType, OrderSide, OrderType, TradingState},
+        enums::{AccountType, OmsType, OrderSide, OrderType, TradingState, TriggerType},
         events::{
             account::stubs::cash_account_state_million_usd, AccountState, OrderDenied,
-            OrderEventAny, OrderEventType,
+            OrderEmulated, OrderEventAny, OrderEventType, TradingStateChanged,
         },
         identifiers::{
             stubs::{
@@ -1103,14 +1430,18 @@ mod tests {
             stubs::{audusd_sim, crypto_perpetual_ethusdt, xbtusd_bitmex},
             CryptoPerpetual, CurrencyPair, InstrumentAny,
         },
-        orders::{OrderAny, OrderList, OrderTestBuilder},
+        orders::{
+            stubs::{TestOrderEventStubs, TestOrderStubs},
+            OrderAny, OrderList, OrderTestBuilder,
+        },
+        position::Position,
         types::{AccountBalance, Money, Price, Quantity},
     };
     use rstest::{fixture, rstest};
     use rust_decimal::{prelude::FromPrimitive, Decimal};
     use ustr::Ustr;
 
-    use super::{config::RiskEngineConfig, RiskEngine};
+    use super::{config::RiskEngineConfig, register_risk_engine, RiskEngine};
 
     #[fixture]
     fn msgbus() -> MessageBus {
@@ -1209,6 +1540,10 @@ mod tests {
             max_order_submit,
             max_order_modify,
             max_notional_per_order,
+            max_order_qty: HashMap::new(),
+            max_price_deviation: None,
+            max_open_orders_per_instrument: None,
+            max_order_submit_per_strategy: None,
         }
     }
 
@@ -1279,6 +1614,10 @@ mod tests {
             max_order_submit: RateLimit::new(10, 1000),
             max_order_modify: RateLimit::new(5, 1000),
             max_notional_per_order: HashMap::new(),
+            max_order_qty: HashMap::new(),
+            max_price_deviation: None,
+            max_open_orders_per_instrument: None,
+            max_order_submit_per_strategy: None,
         });
         let clock = clock.unwrap_or(Rc::new(RefCell::new(TestClock::new())));
         RiskEngine::new(config, clock, cache, msgbus)
@@ -1722,6 +2061,78 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_submit_order_sent_to_risk_engine_endpoint_then_denies(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        instrument_audusd: InstrumentAny,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        execute_order_event_handler: ShareableMessageHandler,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+        msgbus.register(
+            msgbus.switchboard.exec_engine_execute,
+            execute_order_event_handler.clone(),
+        );
+
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let risk_engine = Rc::new(RefCell::new(get_risk_engine(
+            msgbus.clone(),
+            None,
+            None,
+            None,
+            false,
+        )));
+        register_risk_engine(risk_engine.clone(), &mut msgbus.borrow_mut());
+
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .price(Price::from_raw(100, 0))
+            .quantity(Quantity::from("1000"))
+            .build();
+
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            instrument_audusd.id(),
+            client_order_id,
+            venue_order_id,
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        // The sender holds the message bus while the risk engine handles the command
+        {
+            let msgbus = msgbus.borrow();
+            msgbus.send(
+                &msgbus.switchboard.risk_engine_execute,
+                &TradingCommand::SubmitOrder(submit_order),
+            );
+        }
+
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+        assert_eq!(
+            saved_process_messages.first().unwrap().event_type(),
+            OrderEventType::Denied
+        );
+        assert!(get_execute_order_event_handler_messages(execute_order_event_handler).is_empty());
+    }
+
     #[rstest]
     fn test_submit_order_when_invalid_price_precision_then_denies(
         mut msgbus: MessageBus,
@@ -2846,23 +3257,11 @@ mod tests {
     #[rstest]
     fn test_submit_order_list_sells_when_multi_currency_cash_account_over_cumulative_notional() {}
 
-    // TODO: After portfolio
-    #[rstest]
-    fn test_submit_order_when_reducing_and_buy_order_adds_then_denies() {}
-
-    // TODO: After portfolio
-    #[rstest]
-    fn test_submit_order_when_reducing_and_sell_order_adds_then_denies() {}
-
     #[rstest]
-    fn test_submit_order_when_trading_halted_then_denies_order(
+    fn test_submit_order_when_reducing_and_buy_order_adds_then_denies(
         mut msgbus: MessageBus,
         strategy_id_ema_cross: StrategyId,
-        client_id_binance: ClientId,
-        trader_id: TraderId,
-        client_order_id: ClientOrderId,
-        instrument_eth_usdt: InstrumentAny,
-        venue_order_id: VenueOrderId,
+        instrument_audusd: InstrumentAny,
         process_order_event_handler: ShareableMessageHandler,
         mut simple_cache: Cache,
     ) {
@@ -2870,10 +3269,10 @@ mod tests {
             msgbus.switchboard.exec_engine_process,
             process_order_event_handler.clone(),
         );
-
         simple_cache
-            .add_instrument(instrument_eth_usdt.clone())
+            .add_instrument(instrument_audusd.clone())
             .unwrap();
+        add_open_position(&mut simple_cache, &instrument_audusd, OrderSide::Buy);
 
         let mut risk_engine = get_risk_engine(
             Rc::new(RefCell::new(msgbus)),
@@ -2882,33 +3281,129 @@ mod tests {
             None,
             false,
         );
+        risk_engine.set_trading_state(TradingState::Reducing);
+
         let order = OrderTestBuilder::new(OrderType::Market)
-            .instrument_id(instrument_eth_usdt.id())
+            .instrument_id(instrument_audusd.id())
             .side(OrderSide::Buy)
-            .quantity(Quantity::from_str("100").unwrap())
+            .quantity(Quantity::from(100_000))
             .build();
+        risk_engine.execute(submit_order_command(order, strategy_id_ema_cross));
 
-        let submit_order = SubmitOrder::new(
-            trader_id,
-            client_id_binance,
-            strategy_id_ema_cross,
-            order.instrument_id(),
-            client_order_id,
-            venue_order_id,
-            order,
-            None,
-            None,
-            UUID4::new(),
-            risk_engine.clock.borrow().timestamp_ns(),
-        )
-        .unwrap();
-
-        risk_engine.set_trading_state(TradingState::Halted);
-
-        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
-
-        // Get messages and test
-        let saved_messages = get_process_order_event_handler_messages(process_order_event_handler);
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+        assert_eq!(
+            saved_process_messages.first().unwrap().event_type(),
+            OrderEventType::Denied
+        );
+        assert_eq!(
+            saved_process_messages.first().unwrap().message().unwrap(),
+            Ustr::from("BUY when TradingState::REDUCING and LONG AUD/USD.SIM")
+        );
+    }
+
+    #[rstest]
+    fn test_submit_order_when_reducing_and_sell_order_adds_then_denies(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        instrument_audusd: InstrumentAny,
+        process_order_event_handler: ShareableMessageHandler,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+        add_open_position(&mut simple_cache, &instrument_audusd, OrderSide::Sell);
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        risk_engine.set_trading_state(TradingState::Reducing);
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from(100_000))
+            .build();
+        risk_engine.execute(submit_order_command(order, strategy_id_ema_cross));
+
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+        assert_eq!(
+            saved_process_messages.first().unwrap().event_type(),
+            OrderEventType::Denied
+        );
+        assert_eq!(
+            saved_process_messages.first().unwrap().message().unwrap(),
+            Ustr::from("SELL when TradingState::REDUCING and SHORT AUD/USD.SIM")
+        );
+    }
+
+    #[rstest]
+    fn test_submit_order_when_trading_halted_then_denies_order(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        instrument_eth_usdt: InstrumentAny,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+
+        simple_cache
+            .add_instrument(instrument_eth_usdt.clone())
+            .unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from_str("100").unwrap())
+            .build();
+
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            order.instrument_id(),
+            client_order_id,
+            venue_order_id,
+            order,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.set_trading_state(TradingState::Halted);
+
+        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+        // Get messages and test
+        let saved_messages = get_process_order_event_handler_messages(process_order_event_handler);
         assert_eq!(saved_messages.len(), 1);
         let first_message = saved_messages.first().unwrap();
         assert_eq!(first_message.event_type(), OrderEventType::Denied);
@@ -3084,13 +3579,155 @@ mod tests {
         }
     }
 
-    // TODO: After portfolio
     #[rstest]
-    fn test_submit_order_list_buys_when_trading_reducing_then_denies_orders() {}
+    fn test_submit_order_list_buys_when_trading_reducing_then_denies_orders(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        instrument_audusd: InstrumentAny,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+        add_open_position(&mut simple_cache, &instrument_audusd, OrderSide::Buy);
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        let orders = ["O-1", "O-2"]
+            .map(|client_order_id| {
+                OrderTestBuilder::new(OrderType::Market)
+                    .instrument_id(instrument_audusd.id())
+                    .client_order_id(ClientOrderId::from(client_order_id))
+                    .side(OrderSide::Buy)
+                    .quantity(Quantity::from(100_000))
+                    .build()
+            })
+            .to_vec();
+        let order_list = OrderList::new(
+            OrderListId::new("1"),
+            instrument_audusd.id(),
+            strategy_id_ema_cross,
+            orders,
+            risk_engine.clock.borrow().timestamp_ns(),
+        );
+        let submit_order_list = SubmitOrderList::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            order_list.instrument_id,
+            client_order_id,
+            venue_order_id,
+            order_list,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.set_trading_state(TradingState::Reducing);
+        risk_engine.execute(TradingCommand::SubmitOrderList(submit_order_list));
+
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 2);
+        for event in &saved_process_messages {
+            assert_eq!(event.event_type(), OrderEventType::Denied);
+            assert_eq!(
+                event.message().unwrap(),
+                Ustr::from("BUY when TradingState::REDUCING and LONG AUD/USD.SIM")
+            );
+        }
+    }
 
-    // TODO: After portfolio
     #[rstest]
-    fn test_submit_order_list_sells_when_trading_reducing_then_denies_orders() {}
+    fn test_submit_order_list_sells_when_trading_reducing_then_denies_orders(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        instrument_audusd: InstrumentAny,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+        add_open_position(&mut simple_cache, &instrument_audusd, OrderSide::Sell);
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        let orders = ["O-1", "O-2"]
+            .map(|client_order_id| {
+                OrderTestBuilder::new(OrderType::Market)
+                    .instrument_id(instrument_audusd.id())
+                    .client_order_id(ClientOrderId::from(client_order_id))
+                    .side(OrderSide::Sell)
+                    .quantity(Quantity::from(100_000))
+                    .build()
+            })
+            .to_vec();
+        let order_list = OrderList::new(
+            OrderListId::new("1"),
+            instrument_audusd.id(),
+            strategy_id_ema_cross,
+            orders,
+            risk_engine.clock.borrow().timestamp_ns(),
+        );
+        let submit_order_list = SubmitOrderList::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            order_list.instrument_id,
+            client_order_id,
+            venue_order_id,
+            order_list,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.set_trading_state(TradingState::Reducing);
+        risk_engine.execute(TradingCommand::SubmitOrderList(submit_order_list));
+
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 2);
+        for event in &saved_process_messages {
+            assert_eq!(event.event_type(), OrderEventType::Denied);
+            assert_eq!(
+                event.message().unwrap(),
+                Ustr::from("SELL when TradingState::REDUCING and SHORT AUD/USD.SIM")
+            );
+        }
+    }
 
     // SUBMIT BRACKET ORDER TESTS
     #[rstest]
@@ -3274,11 +3911,7 @@ mod tests {
     }
 
     #[rstest]
-    fn test_submit_order_for_emulation_sends_command_to_emulator() {}
-
-    // MODIFY ORDER TESTS
-    #[rstest]
-    fn test_modify_order_when_no_order_found_logs_error(
+    fn test_submit_order_for_emulation_sends_command_to_emulator(
         mut msgbus: MessageBus,
         strategy_id_ema_cross: StrategyId,
         client_id_binance: ClientId,
@@ -3286,24 +3919,31 @@ mod tests {
         client_order_id: ClientOrderId,
         instrument_audusd: InstrumentAny,
         venue_order_id: VenueOrderId,
-        process_order_event_handler: ShareableMessageHandler,
+        execute_order_event_handler: ShareableMessageHandler,
         cash_account_state_million_usd: AccountState,
+        quote_audusd: QuoteTick,
         mut simple_cache: Cache,
     ) {
+        let emulator_handler =
+            get_message_saving_handler::<TradingCommand>(Some(Ustr::from("OrderEmulator.execute")));
         msgbus.register(
-            msgbus.switchboard.exec_engine_process,
-            process_order_event_handler.clone(),
+            msgbus.switchboard.exec_engine_execute,
+            execute_order_event_handler.clone(),
+        );
+        msgbus.register(
+            msgbus.switchboard.order_emulator_execute,
+            emulator_handler.clone(),
         );
-
-        simple_cache
-            .add_instrument(instrument_audusd.clone())
-            .unwrap();
 
         simple_cache
             .add_account(AccountAny::Cash(cash_account(
                 cash_account_state_million_usd,
             )))
             .unwrap();
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+        simple_cache.add_quote(quote_audusd).unwrap();
 
         let mut risk_engine = get_risk_engine(
             Rc::new(RefCell::new(msgbus)),
@@ -3312,14 +3952,22 @@ mod tests {
             None,
             false,
         );
-        let modify_order = ModifyOrder::new(
+        let order = OrderTestBuilder::new(OrderType::StopMarket)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1000"))
+            .trigger_price(Price::from_raw(100, 0))
+            .emulation_trigger(TriggerType::BidAsk)
+            .build();
+
+        let submit_order = SubmitOrder::new(
             trader_id,
             client_id_binance,
             strategy_id_ema_cross,
             instrument_audusd.id(),
             client_order_id,
             venue_order_id,
-            None,
+            order,
             None,
             None,
             UUID4::new(),
@@ -3327,15 +3975,77 @@ mod tests {
         )
         .unwrap();
 
-        risk_engine.execute(TradingCommand::ModifyOrder(modify_order));
+        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
 
-        let saved_process_messages =
-            get_process_order_event_handler_messages(process_order_event_handler);
-        assert_eq!(saved_process_messages.len(), 0);
+        let saved_emulator_messages = get_saved_messages::<TradingCommand>(emulator_handler);
+        assert_eq!(saved_emulator_messages.len(), 1);
+        assert!(matches!(
+            saved_emulator_messages[0],
+            TradingCommand::SubmitOrder(_)
+        ));
+        assert!(get_execute_order_event_handler_messages(execute_order_event_handler).is_empty());
     }
 
+    // MODIFY ORDER TESTS
     #[rstest]
-    fn test_modify_order_beyond_rate_limit_then_rejects(
+    fn test_modify_order_when_no_order_found_logs_error(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        instrument_audusd: InstrumentAny,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        cash_account_state_million_usd: AccountState,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+
+        simple_cache
+            .add_account(AccountAny::Cash(cash_account(
+                cash_account_state_million_usd,
+            )))
+            .unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        let modify_order = ModifyOrder::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            instrument_audusd.id(),
+            client_order_id,
+            venue_order_id,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.execute(TradingCommand::ModifyOrder(modify_order));
+
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 0);
+    }
+
+    #[rstest]
+    fn test_modify_order_beyond_rate_limit_then_rejects(
         mut msgbus: MessageBus,
         strategy_id_ema_cross: StrategyId,
         client_id_binance: ClientId,
@@ -3508,7 +4218,88 @@ mod tests {
     }
 
     #[rstest]
-    fn test_modify_order_for_emulated_order_then_sends_to_emulator() {}
+    fn test_modify_order_for_emulated_order_then_sends_to_emulator(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        instrument_audusd: InstrumentAny,
+        venue_order_id: VenueOrderId,
+        execute_order_event_handler: ShareableMessageHandler,
+        mut simple_cache: Cache,
+    ) {
+        let emulator_handler =
+            get_message_saving_handler::<TradingCommand>(Some(Ustr::from("OrderEmulator.execute")));
+        msgbus.register(
+            msgbus.switchboard.exec_engine_execute,
+            execute_order_event_handler.clone(),
+        );
+        msgbus.register(
+            msgbus.switchboard.order_emulator_execute,
+            emulator_handler.clone(),
+        );
+
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+
+        let mut order = OrderTestBuilder::new(OrderType::StopMarket)
+            .instrument_id(instrument_audusd.id())
+            .client_order_id(client_order_id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from_str("100").unwrap())
+            .trigger_price(Price::from_raw(10001, 4))
+            .emulation_trigger(TriggerType::BidAsk)
+            .build();
+        order
+            .apply(OrderEventAny::Emulated(OrderEmulated::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+            )))
+            .unwrap();
+        simple_cache
+            .add_order(order, None, Some(client_id_binance), true)
+            .unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        let modify_order = ModifyOrder::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            instrument_audusd.id(),
+            client_order_id,
+            venue_order_id,
+            None,
+            None,
+            Some(Price::from_raw(10002, 4)),
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.execute(TradingCommand::ModifyOrder(modify_order));
+
+        let saved_emulator_messages = get_saved_messages::<TradingCommand>(emulator_handler);
+        assert_eq!(saved_emulator_messages.len(), 1);
+        assert!(matches!(
+            saved_emulator_messages[0],
+            TradingCommand::ModifyOrder(_)
+        ));
+        assert!(get_execute_order_event_handler_messages(execute_order_event_handler).is_empty());
+        assert_eq!(risk_engine.throttled_modify_order.used(), 0.0);
+    }
 
     #[rstest]
     fn test_submit_order_when_market_order_and_over_free_balance_then_denies_with_betting_account(
@@ -3665,4 +4456,410 @@ mod tests {
 
     #[rstest]
     fn test_partial_fill_and_full_fill_account_balance_correct() {}
+
+    fn add_open_position(cache: &mut Cache, instrument: &InstrumentAny, side: OrderSide) {
+        let order = TestOrderStubs::make_accepted_order(
+            &OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(instrument.id())
+                .side(side)
+                .quantity(Quantity::from(100_000))
+                .build(),
+        );
+        let OrderEventAny::Filled(fill) = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::new("P-1")),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) else {
+            unreachable!()
+        };
+        cache
+            .add_position(Position::new(instrument, fill), OmsType::Netting)
+            .unwrap();
+    }
+
+    fn get_limited_risk_engine(
+        msgbus: MessageBus,
+        cache: Cache,
+        config: RiskEngineConfig,
+    ) -> RiskEngine {
+        get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(cache))),
+            Some(config),
+            None,
+            false,
+        )
+    }
+
+    fn submit_order_command(order: OrderAny, strategy_id: StrategyId) -> TradingCommand {
+        TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                order.trader_id(),
+                ClientId::from("SIM"),
+                strategy_id,
+                order.instrument_id(),
+                order.client_order_id(),
+                VenueOrderId::default(),
+                order,
+                None,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    #[rstest]
+    fn test_submit_order_when_quantity_exceeds_max_order_qty_then_denies(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        instrument_audusd: InstrumentAny,
+        process_order_event_handler: ShareableMessageHandler,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+        let config = RiskEngineConfig {
+            max_order_qty: HashMap::from([(instrument_audusd.id(), Quantity::from(100_000))]),
+            ..Default::default()
+        };
+        let mut risk_engine = get_limited_risk_engine(msgbus, simple_cache, config);
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(200_000))
+            .build();
+        risk_engine.execute(submit_order_command(order, strategy_id_ema_cross));
+
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+        assert_eq!(
+            saved_process_messages.first().unwrap().event_type(),
+            OrderEventType::Denied
+        );
+        assert_eq!(
+            saved_process_messages.first().unwrap().message().unwrap(),
+            Ustr::from("QUANTITY_EXCEEDS_MAX_PER_ORDER: max_order_qty=100000, quantity=200000")
+        );
+    }
+
+    #[rstest]
+    fn test_submit_order_when_price_outside_collar_then_denies(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        instrument_audusd: InstrumentAny,
+        process_order_event_handler: ShareableMessageHandler,
+        execute_order_event_handler: ShareableMessageHandler,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+        msgbus.register(
+            msgbus.switchboard.exec_engine_execute,
+            execute_order_event_handler.clone(),
+        );
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+        simple_cache
+            .add_quote(QuoteTick::new(
+                instrument_audusd.id(),
+                Price::from("0.80000"),
+                Price::from("0.80010"),
+                Quantity::from(1_000_000),
+                Quantity::from(1_000_000),
+                UnixNanos::default(),
+                UnixNanos::default(),
+            ))
+            .unwrap();
+        let config = RiskEngineConfig {
+            max_price_deviation: Some(Decimal::from_str("0.05").unwrap()),
+            ..Default::default()
+        };
+        let mut risk_engine = get_limited_risk_engine(msgbus, simple_cache, config);
+
+        for (client_order_id, price) in [("O-1", "0.90000"), ("O-2", "0.79000")] {
+            let order = OrderTestBuilder::new(OrderType::Limit)
+                .instrument_id(instrument_audusd.id())
+                .client_order_id(ClientOrderId::from(client_order_id))
+                .side(OrderSide::Buy)
+                .price(Price::from(price))
+                .quantity(Quantity::from(100_000))
+                .build();
+            risk_engine.execute(submit_order_command(order, strategy_id_ema_cross));
+        }
+
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        let saved_execute_messages =
+            get_execute_order_event_handler_messages(execute_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+        assert_eq!(
+            saved_process_messages.first().unwrap().client_order_id(),
+            ClientOrderId::from("O-1")
+        );
+        assert!(saved_process_messages
+            .first()
+            .unwrap()
+            .message()
+            .unwrap()
+            .starts_with("PRICE_OUTSIDE_COLLAR: price=0.90000"));
+        assert_eq!(saved_execute_messages.len(), 1);
+    }
+
+    #[rstest]
+    fn test_submit_order_when_max_open_orders_exceeded_then_denies(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        instrument_audusd: InstrumentAny,
+        process_order_event_handler: ShareableMessageHandler,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+        let open_order = TestOrderStubs::make_accepted_order(
+            &OrderTestBuilder::new(OrderType::Limit)
+                .instrument_id(instrument_audusd.id())
+                .client_order_id(ClientOrderId::from("O-1"))
+                .side(OrderSide::Buy)
+                .price(Price::from("0.80000"))
+                .quantity(Quantity::from(100_000))
+                .build(),
+        );
+        simple_cache
+            .add_order(open_order.clone(), None, None, false)
+            .unwrap();
+        simple_cache.update_order(&open_order).unwrap();
+        let config = RiskEngineConfig {
+            max_open_orders_per_instrument: Some(1),
+            ..Default::default()
+        };
+        let mut risk_engine = get_limited_risk_engine(msgbus, simple_cache, config);
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .client_order_id(ClientOrderId::from("O-2"))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        risk_engine.execute(submit_order_command(order, strategy_id_ema_cross));
+
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+        assert_eq!(
+            saved_process_messages.first().unwrap().message().unwrap(),
+            Ustr::from("MAX_OPEN_ORDERS_EXCEEDED: max_open_orders=1, open_orders=1")
+        );
+    }
+
+    #[rstest]
+    fn test_submit_order_beyond_strategy_rate_limit_then_denies(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        instrument_audusd: InstrumentAny,
+        process_order_event_handler: ShareableMessageHandler,
+        execute_order_event_handler: ShareableMessageHandler,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+        msgbus.register(
+            msgbus.switchboard.exec_engine_execute,
+            execute_order_event_handler.clone(),
+        );
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+        let config = RiskEngineConfig {
+            max_order_submit_per_strategy: Some(RateLimit::new(2, 1_000_000_000)),
+            ..Default::default()
+        };
+        let mut risk_engine = get_limited_risk_engine(msgbus, simple_cache, config);
+
+        for i in 0..3 {
+            let order = OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(instrument_audusd.id())
+                .client_order_id(ClientOrderId::from(format!("O-{i}").as_str()))
+                .side(OrderSide::Buy)
+                .quantity(Quantity::from(100_000))
+                .build();
+            risk_engine.execute(submit_order_command(order, strategy_id_ema_cross));
+        }
+
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+        assert_eq!(
+            saved_process_messages.first().unwrap().client_order_id(),
+            ClientOrderId::from("O-2")
+        );
+        assert!(saved_process_messages
+            .first()
+            .unwrap()
+            .message()
+            .unwrap()
+            .starts_with("MAX_ORDER_SUBMIT_RATE_EXCEEDED"));
+        assert_eq!(
+            get_execute_order_event_handler_messages(execute_order_event_handler).len(),
+            2
+        );
+    }
+
+    #[rstest]
+    fn test_submit_order_when_reducing_and_order_reduces_then_sends_to_execution(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        instrument_audusd: InstrumentAny,
+        process_order_event_handler: ShareableMessageHandler,
+        execute_order_event_handler: ShareableMessageHandler,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+        msgbus.register(
+            msgbus.switchboard.exec_engine_execute,
+            execute_order_event_handler.clone(),
+        );
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+        add_open_position(&mut simple_cache, &instrument_audusd, OrderSide::Buy);
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        risk_engine.set_trading_state(TradingState::Reducing);
+
+        for (client_order_id, quantity) in [("O-1", 100_000), ("O-2", 200_000)] {
+            let order = OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(instrument_audusd.id())
+                .client_order_id(ClientOrderId::from(client_order_id))
+                .side(OrderSide::Sell)
+                .quantity(Quantity::from(quantity))
+                .build();
+            risk_engine.execute(submit_order_command(order, strategy_id_ema_cross));
+        }
+
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        let saved_execute_messages =
+            get_execute_order_event_handler_messages(execute_order_event_handler);
+        assert_eq!(saved_execute_messages.len(), 1);
+        assert_eq!(saved_process_messages.len(), 1);
+        assert_eq!(
+            saved_process_messages.first().unwrap().message().unwrap(),
+            Ustr::from("SELL when TradingState::REDUCING would flip LONG AUD/USD.SIM")
+        );
+    }
+
+    #[rstest]
+    fn test_set_trading_state_persists_to_cache(msgbus: MessageBus) {
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let handler = get_message_saving_handler::<TradingStateChanged>(None);
+        msgbus
+            .borrow_mut()
+            .subscribe("events.risk", handler.clone(), None);
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut risk_engine =
+            get_risk_engine(msgbus.clone(), Some(cache.clone()), None, None, false);
+
+        risk_engine.set_trading_state(TradingState::Reducing);
+        let restarted_engine = get_risk_engine(msgbus, Some(cache.clone()), None, None, false);
+
+        let events = get_saved_messages::<TradingStateChanged>(handler);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].state, TradingState::Reducing);
+        assert_eq!(
+            cache.borrow().get("risk.trading_state").unwrap(),
+            Some(&Bytes::from("REDUCING"))
+        );
+        assert_eq!(restarted_engine.trading_state, TradingState::Reducing);
+    }
+
+    #[rstest]
+    fn test_execute_set_trading_state_halted_cancels_open_orders(
+        mut msgbus: MessageBus,
+        trader_id: TraderId,
+        instrument_audusd: InstrumentAny,
+        execute_order_event_handler: ShareableMessageHandler,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_execute,
+            execute_order_event_handler.clone(),
+        );
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+        let open_order = TestOrderStubs::make_accepted_order(
+            &OrderTestBuilder::new(OrderType::Limit)
+                .instrument_id(instrument_audusd.id())
+                .side(OrderSide::Buy)
+                .price(Price::from("0.80000"))
+                .quantity(Quantity::from(100_000))
+                .build(),
+        );
+        simple_cache
+            .add_order(open_order.clone(), None, Some(ClientId::from("SIM")), false)
+            .unwrap();
+        simple_cache.update_order(&open_order).unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        risk_engine.execute_risk(RiskCommand::SetTradingState(SetTradingState::new(
+            trader_id,
+            TradingState::Halted,
+            true,
+            UUID4::new(),
+            UnixNanos::default(),
+        )));
+
+        let saved_execute_messages =
+            get_execute_order_event_handler_messages(execute_order_event_handler);
+        assert_eq!(risk_engine.trading_state, TradingState::Halted);
+        assert_eq!(saved_execute_messages.len(), 1);
+        let TradingCommand::CancelAllOrders(command) = saved_execute_messages.first().unwrap()
+        else {
+            panic!("Expected `CancelAllOrders` command");
+        };
+        assert_eq!(command.client_id, ClientId::from("SIM"));
+        assert_eq!(command.instrument_id, instrument_audusd.id());
+        assert_eq!(command.strategy_id, open_order.strategy_id());
+    }
 }
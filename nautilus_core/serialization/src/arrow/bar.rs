@@ -312,4 +312,31 @@ mod tests {
         let decoded_data = Bar::decode_batch(&metadata, record_batch).unwrap();
         assert_eq!(decoded_data.len(), 2);
     }
+
+    #[rstest]
+    #[case("EUR/USD.SIM-1-MINUTE-BID-INTERNAL")]
+    #[case("EUR/USD.SIM-1-MINUTE-ASK-INTERNAL")]
+    #[case("EUR/USD.SIM-1-MINUTE-MID-INTERNAL")]
+    #[case("EUR/USD.SIM-1-MINUTE-LAST-INTERNAL")]
+    fn test_decode_batch_preserves_price_type(#[case] bar_type: &str) {
+        let bar_type = BarType::from_str(bar_type).unwrap();
+        let metadata = Bar::get_metadata(&bar_type, 5, 0);
+        let bar = Bar::new(
+            bar_type,
+            Price::from("1.10000"),
+            Price::from("1.10020"),
+            Price::from("1.09990"),
+            Price::from("1.10010"),
+            Quantity::from(1_000_000),
+            1.into(),
+            2.into(),
+        );
+
+        let record_batch = Bar::encode_batch(&metadata, &[bar]).unwrap();
+        let schema_metadata = record_batch.schema().metadata().clone();
+        let decoded_data = Bar::decode_batch(&schema_metadata, record_batch).unwrap();
+
+        assert_eq!(decoded_data, vec![bar]);
+        assert_eq!(decoded_data[0].bar_type.spec(), bar_type.spec());
+    }
 }
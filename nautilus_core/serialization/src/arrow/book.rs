@@ -0,0 +1,229 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Arrow export of order book snapshots.
+
+use std::{collections::HashMap, sync::Arc};
+
+use arrow::{
+    array::{Int64Array, UInt32Array, UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    enums::OrderSide,
+    orderbook::{BookLevel, OrderBook},
+};
+
+use super::{KEY_INSTRUMENT_ID, KEY_PRICE_PRECISION, KEY_SIZE_PRECISION};
+
+const KEY_SEQUENCE: &str = "sequence";
+const KEY_TS_LAST: &str = "ts_last";
+
+/// Returns the Arrow schema for an order book snapshot, with one row per price level.
+#[must_use]
+pub fn book_snapshot_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+    let fields = vec![
+        Field::new("side", DataType::UInt8, false),
+        Field::new("level", DataType::UInt32, false),
+        Field::new("price", DataType::Int64, false),
+        Field::new("size", DataType::UInt64, false),
+        Field::new("count", DataType::UInt32, false),
+    ];
+
+    match metadata {
+        Some(metadata) => Schema::new_with_metadata(fields, metadata),
+        None => Schema::new(fields),
+    }
+}
+
+/// Encodes a snapshot of the given `book` into an Arrow [`RecordBatch`].
+///
+/// Each row represents a price level, bids first (best to worst) then asks (best to
+/// worst), up to `depth` levels per side. Prices and sizes are encoded as raw fixed-point
+/// values, with their precisions and the book `sequence` and `ts_last` in the metadata.
+///
+/// # Errors
+///
+/// This function returns an error if the record batch cannot be constructed.
+pub fn book_snapshot_to_record_batch(
+    book: &OrderBook,
+    depth: Option<usize>,
+) -> Result<RecordBatch, ArrowError> {
+    let levels: Vec<(OrderSide, u32, &BookLevel)> = book
+        .bids(depth)
+        .enumerate()
+        .map(|(i, level)| (OrderSide::Buy, i as u32, level))
+        .chain(
+            book.asks(depth)
+                .enumerate()
+                .map(|(i, level)| (OrderSide::Sell, i as u32, level)),
+        )
+        .collect();
+
+    let (price_precision, size_precision) = levels
+        .iter()
+        .find_map(|(_, _, level)| level.first())
+        .map_or((0, 0), |order| {
+            (order.price.precision, order.size.precision)
+        });
+
+    let mut side_builder = UInt8Array::builder(levels.len());
+    let mut level_builder = UInt32Array::builder(levels.len());
+    let mut price_builder = Int64Array::builder(levels.len());
+    let mut size_builder = UInt64Array::builder(levels.len());
+    let mut count_builder = UInt32Array::builder(levels.len());
+
+    for (side, index, level) in &levels {
+        side_builder.append_value(*side as u8);
+        level_builder.append_value(*index);
+        price_builder.append_value(level.price.value.raw);
+        size_builder.append_value(level.size_raw());
+        count_builder.append_value(level.len() as u32);
+    }
+
+    let metadata = HashMap::from([
+        (
+            KEY_INSTRUMENT_ID.to_string(),
+            book.instrument_id.to_string(),
+        ),
+        (KEY_PRICE_PRECISION.to_string(), price_precision.to_string()),
+        (KEY_SIZE_PRECISION.to_string(), size_precision.to_string()),
+        (KEY_SEQUENCE.to_string(), book.sequence.to_string()),
+        (KEY_TS_LAST.to_string(), book.ts_last.to_string()),
+    ]);
+
+    RecordBatch::try_new(
+        book_snapshot_schema(Some(metadata)).into(),
+        vec![
+            Arc::new(side_builder.finish()),
+            Arc::new(level_builder.finish()),
+            Arc::new(price_builder.finish()),
+            Arc::new(size_builder.finish()),
+            Arc::new(count_builder.finish()),
+        ],
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use arrow::array::Array;
+    use nautilus_model::{
+        data::order::BookOrder,
+        enums::BookType,
+        identifiers::InstrumentId,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_book_snapshot_to_record_batch() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L3_MBO);
+        book.add(
+            BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(5), 1),
+            0,
+            1,
+            1.into(),
+        );
+        book.add(
+            BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(3), 2),
+            0,
+            2,
+            2.into(),
+        );
+        book.add(
+            BookOrder::new(OrderSide::Buy, Price::from("99.00"), Quantity::from(1), 3),
+            0,
+            3,
+            3.into(),
+        );
+        book.add(
+            BookOrder::new(OrderSide::Sell, Price::from("101.00"), Quantity::from(2), 4),
+            0,
+            4,
+            4.into(),
+        );
+
+        let batch = book_snapshot_to_record_batch(&book, Some(10)).unwrap();
+
+        assert_eq!(batch.num_rows(), 3);
+        let metadata = batch.schema().metadata().clone();
+        assert_eq!(metadata.get(KEY_INSTRUMENT_ID).unwrap(), "AAPL.XNAS");
+        assert_eq!(metadata.get(KEY_PRICE_PRECISION).unwrap(), "2");
+        assert_eq!(metadata.get(KEY_SEQUENCE).unwrap(), "4");
+
+        let sides = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<UInt8Array>()
+            .unwrap();
+        let prices = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        let sizes = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .unwrap();
+        let counts = batch
+            .column(4)
+            .as_any()
+            .downcast_ref::<UInt32Array>()
+            .unwrap();
+
+        assert_eq!(sides.value(0), OrderSide::Buy as u8);
+        assert_eq!(sides.value(2), OrderSide::Sell as u8);
+        assert_eq!(prices.value(0), Price::from("100.00").raw);
+        assert_eq!(prices.value(1), Price::from("99.00").raw);
+        assert_eq!(sizes.value(0), Quantity::from(8).raw);
+        assert_eq!(counts.value(0), 2);
+    }
+
+    #[rstest]
+    fn test_book_snapshot_to_record_batch_with_depth() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        for i in 0..5 {
+            let price = Price::new(100.0 - f64::from(i), 2);
+            book.add(
+                BookOrder::new(OrderSide::Buy, price, Quantity::from(1), 0),
+                0,
+                u64::from(i),
+                0.into(),
+            );
+        }
+
+        let batch = book_snapshot_to_record_batch(&book, Some(2)).unwrap();
+
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.column(1).len(), 2);
+    }
+
+    #[rstest]
+    fn test_empty_book_snapshot() {
+        let book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+
+        let batch = book_snapshot_to_record_batch(&book, None).unwrap();
+
+        assert_eq!(batch.num_rows(), 0);
+    }
+}
@@ -0,0 +1,165 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, sync::Arc};
+
+use arrow::{
+    array::{BinaryArray, BinaryBuilder, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use bytes::Bytes;
+use nautilus_common::custom::CustomData;
+
+use super::{extract_column, EncodingError, KEY_INSTRUMENT_ID};
+use crate::arrow::{ArrowSchemaProvider, EncodeToRecordBatch};
+
+const KEY_DATA_TYPE: &str = "data_type";
+const KEY_TYPE_NAME: &str = "type_name";
+
+impl ArrowSchemaProvider for CustomData {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("value", DataType::Binary, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for CustomData {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut value_builder = BinaryBuilder::new();
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for item in data {
+            value_builder.append_value(&item.value);
+            ts_event_builder.append_value(item.ts_event.as_u64());
+            ts_init_builder.append_value(item.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(value_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert(
+            KEY_TYPE_NAME.to_string(),
+            self.data_type.type_name().to_string(),
+        );
+        // The full data type is stored as JSON to retain the order of its metadata
+        metadata.insert(
+            KEY_DATA_TYPE.to_string(),
+            serde_json::to_string(&self.data_type).expect("Failed to serialize `DataType`"),
+        );
+        if let Some(instrument_id) = self
+            .data_type
+            .metadata()
+            .and_then(|metadata| metadata.get(KEY_INSTRUMENT_ID))
+        {
+            metadata.insert(KEY_INSTRUMENT_ID.to_string(), instrument_id.clone());
+        }
+        metadata
+    }
+}
+
+/// Decodes the custom data contained in the given `record_batch`.
+pub fn decode_custom_data_batch(
+    metadata: &HashMap<String, String>,
+    record_batch: &RecordBatch,
+) -> Result<Vec<CustomData>, EncodingError> {
+    let data_type_str = metadata
+        .get(KEY_DATA_TYPE)
+        .ok_or(EncodingError::MissingMetadata(KEY_DATA_TYPE))?;
+    let data_type: nautilus_model::data::DataType = serde_json::from_str(data_type_str)
+        .map_err(|e| EncodingError::ParseError(KEY_DATA_TYPE, e.to_string()))?;
+
+    let cols = record_batch.columns();
+    let value_values = extract_column::<BinaryArray>(cols, "value", 0, DataType::Binary)?;
+    let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 1, DataType::UInt64)?;
+    let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 2, DataType::UInt64)?;
+
+    Ok((0..record_batch.num_rows())
+        .map(|i| {
+            CustomData::new(
+                data_type.clone(),
+                Bytes::copy_from_slice(value_values.value(i)),
+                ts_event_values.value(i).into(),
+                ts_init_values.value(i).into(),
+            )
+        })
+        .collect())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema() {
+        let schema = CustomData::get_schema(None);
+
+        assert_eq!(schema.fields().len(), 3);
+        assert_eq!(schema.field(0).data_type(), &DataType::Binary);
+    }
+
+    #[rstest]
+    fn test_encode_decode_batch() {
+        let metadata = IndexMap::from([
+            ("instrument_id".to_string(), "AUD/USD.SIM".to_string()),
+            ("source".to_string(), "news".to_string()),
+        ]);
+        let data_type = nautilus_model::data::DataType::new("NewsEvent", Some(metadata));
+        let data = vec![
+            CustomData::new(data_type.clone(), Bytes::from("a"), 1.into(), 2.into()),
+            CustomData::new(data_type, Bytes::from("b"), 3.into(), 4.into()),
+        ];
+        let metadata = CustomData::chunk_metadata(&data);
+
+        let record_batch = CustomData::encode_batch(&metadata, &data).unwrap();
+        let decoded =
+            decode_custom_data_batch(record_batch.schema().metadata(), &record_batch).unwrap();
+
+        assert_eq!(
+            metadata.get(KEY_INSTRUMENT_ID),
+            Some(&"AUD/USD.SIM".to_string())
+        );
+        assert_eq!(decoded, data);
+        assert_eq!(decoded[0].data_type.topic(), data[0].data_type.topic());
+    }
+}
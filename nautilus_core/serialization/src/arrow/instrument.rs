@@ -0,0 +1,163 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, sync::Arc};
+
+use arrow::{
+    array::{StringArray, StringBuilder, UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{data::GetTsInit, instruments::InstrumentAny};
+
+use super::{extract_column, EncodingError, KEY_INSTRUMENT_ID};
+use crate::arrow::{ArrowSchemaProvider, EncodeToRecordBatch};
+
+const KEY_INSTRUMENT_CLASS: &str = "instrument_class";
+
+impl ArrowSchemaProvider for InstrumentAny {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("id", DataType::Utf8, false),
+            Field::new("raw_symbol", DataType::Utf8, false),
+            Field::new("price_precision", DataType::UInt8, false),
+            Field::new("size_precision", DataType::UInt8, false),
+            Field::new("price_increment", DataType::Utf8, false),
+            Field::new("size_increment", DataType::Utf8, false),
+            // Full JSON definition, so any instrument type can be decoded from the common schema
+            Field::new("definition", DataType::Utf8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for InstrumentAny {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut id_builder = StringBuilder::new();
+        let mut raw_symbol_builder = StringBuilder::new();
+        let mut price_precision_builder = UInt8Array::builder(data.len());
+        let mut size_precision_builder = UInt8Array::builder(data.len());
+        let mut price_increment_builder = StringBuilder::new();
+        let mut size_increment_builder = StringBuilder::new();
+        let mut definition_builder = StringBuilder::new();
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for instrument in data {
+            let definition = serde_json::to_string(instrument)
+                .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
+
+            id_builder.append_value(instrument.id().to_string());
+            raw_symbol_builder.append_value(instrument.raw_symbol().as_str());
+            price_precision_builder.append_value(instrument.price_precision());
+            size_precision_builder.append_value(instrument.size_precision());
+            price_increment_builder.append_value(instrument.price_increment().to_string());
+            size_increment_builder.append_value(instrument.size_increment().to_string());
+            definition_builder.append_value(definition);
+            ts_event_builder.append_value(instrument.ts_event().as_u64());
+            ts_init_builder.append_value(instrument.ts_init().as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(id_builder.finish()),
+                Arc::new(raw_symbol_builder.finish()),
+                Arc::new(price_precision_builder.finish()),
+                Arc::new(size_precision_builder.finish()),
+                Arc::new(price_increment_builder.finish()),
+                Arc::new(size_increment_builder.finish()),
+                Arc::new(definition_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert(KEY_INSTRUMENT_ID.to_string(), self.id().to_string());
+        metadata.insert(
+            KEY_INSTRUMENT_CLASS.to_string(),
+            self.instrument_class().as_ref().to_string(),
+        );
+        metadata
+    }
+}
+
+/// Decodes the instruments contained in the given `record_batch` from their JSON definitions.
+pub fn decode_instruments_batch(
+    record_batch: &RecordBatch,
+) -> Result<Vec<InstrumentAny>, EncodingError> {
+    let cols = record_batch.columns();
+    let definition_values = extract_column::<StringArray>(cols, "definition", 6, DataType::Utf8)?;
+
+    definition_values
+        .iter()
+        .map(|definition| {
+            let definition = definition.unwrap_or_default();
+            serde_json::from_str(definition)
+                .map_err(|e| EncodingError::ParseError("definition", e.to_string()))
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::instruments::{stubs::*, CurrencyPair};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema() {
+        let metadata = HashMap::from([(KEY_INSTRUMENT_ID.to_string(), "AUD/USD.SIM".to_string())]);
+        let schema = InstrumentAny::get_schema(Some(metadata.clone()));
+
+        assert_eq!(schema.fields().len(), 9);
+        assert_eq!(schema.field(6).name(), "definition");
+        assert_eq!(schema.metadata(), &metadata);
+    }
+
+    #[rstest]
+    fn test_encode_decode_batch(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let data = vec![instrument.clone()];
+        let metadata = InstrumentAny::chunk_metadata(&data);
+
+        let record_batch = InstrumentAny::encode_batch(&metadata, &data).unwrap();
+        let decoded = decode_instruments_batch(&record_batch).unwrap();
+
+        assert_eq!(record_batch.num_rows(), 1);
+        assert_eq!(
+            record_batch.schema().metadata().get(KEY_INSTRUMENT_ID),
+            Some(&"AUD/USD.SIM".to_string())
+        );
+        assert_eq!(decoded, data);
+    }
+}
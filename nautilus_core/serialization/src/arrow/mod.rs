@@ -16,8 +16,11 @@
 //! Defines the Apache Arrow schema for Nautilus types.
 
 pub mod bar;
+pub mod book;
+pub mod custom;
 pub mod delta;
 pub mod depth;
+pub mod instrument;
 pub mod quote;
 pub mod trade;
 
@@ -33,7 +36,11 @@ use arrow::{
     ipc::writer::StreamWriter,
     record_batch::RecordBatch,
 };
-use nautilus_model::data::{Bar, Data, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick};
+use nautilus_common::custom::CustomData;
+use nautilus_model::{
+    data::{Bar, Data, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick},
+    instruments::InstrumentAny,
+};
 use pyo3::prelude::*;
 
 // Define metadata key constants constants
@@ -222,3 +229,31 @@ pub fn bars_to_arrow_record_batch_bytes(data: Vec<Bar>) -> Result<RecordBatch, E
     let metadata = first.metadata();
     Bar::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
 }
+
+pub fn instruments_to_arrow_record_batch_bytes(
+    data: Vec<InstrumentAny>,
+) -> Result<RecordBatch, EncodingError> {
+    if data.is_empty() {
+        return Err(EncodingError::EmptyData);
+    }
+
+    // Take first element and extract metadata
+    // SAFETY: Unwrap safe as already checked that `data` not empty
+    let first = data.first().unwrap();
+    let metadata = first.metadata();
+    InstrumentAny::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
+}
+
+pub fn custom_data_to_arrow_record_batch_bytes(
+    data: Vec<CustomData>,
+) -> Result<RecordBatch, EncodingError> {
+    if data.is_empty() {
+        return Err(EncodingError::EmptyData);
+    }
+
+    // Take first element and extract metadata
+    // SAFETY: Unwrap safe as already checked that `data` not empty
+    let first = data.first().unwrap();
+    let metadata = first.metadata();
+    CustomData::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
+}
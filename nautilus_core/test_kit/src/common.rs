@@ -80,3 +80,29 @@ pub fn ensure_data_exists_tardis_bitmex_trades() -> PathBuf {
     let url = format!("{base_url}/v1/bitmex/trades/2020/03/01/XBTUSD.csv.gz");
     ensure_test_data_exists(filename, &url)
 }
+
+/// Returns the contents of the JSON test data file `file_name` under the `src/tests/data`
+/// directory of the crate at `manifest_dir`.
+///
+/// # Panics
+///
+/// This function panics if the file cannot be read.
+#[must_use]
+pub fn load_crate_test_json(manifest_dir: &str, file_name: &str) -> String {
+    let path = PathBuf::from(manifest_dir)
+        .join("src")
+        .join("tests")
+        .join("data")
+        .join(file_name);
+
+    std::fs::read_to_string(path).expect("Failed to read test JSON file")
+}
+
+/// Loads the JSON test data file with the given name from the `src/tests/data` directory
+/// of the calling crate.
+#[macro_export]
+macro_rules! load_test_json {
+    ($file_name:expr) => {
+        $crate::common::load_crate_test_json(env!("CARGO_MANIFEST_DIR"), $file_name)
+    };
+}
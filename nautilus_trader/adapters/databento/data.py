@@ -32,6 +32,7 @@ from nautilus_trader.adapters.databento.providers import DatabentoInstrumentProv
 from nautilus_trader.adapters.databento.types import DatabentoImbalance
 from nautilus_trader.adapters.databento.types import DatabentoStatistics
 from nautilus_trader.adapters.databento.types import Dataset
+from nautilus_trader.adapters.env import get_env_key
 from nautilus_trader.cache.cache import Cache
 from nautilus_trader.common.component import LiveClock
 from nautilus_trader.common.component import MessageBus
@@ -113,7 +114,7 @@ class DatabentoDataClient(LiveMarketDataClient):
         )
 
         # Configuration
-        self._live_api_key: str = config.api_key or http_client.key
+        self._live_api_key: str = config.api_key or get_env_key("DATABENTO_API_KEY")
         self._live_gateway: str | None = config.live_gateway
         self._use_exchange_as_venue: bool = config.use_exchange_as_venue
         self._timeout_initial_load: float | None = config.timeout_initial_load
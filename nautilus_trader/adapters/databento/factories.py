@@ -80,7 +80,7 @@ def get_cached_databento_instrument_provider(
         The clock for the instrument provider.
     live_api_key : str, optional
         The specific API secret key for Databento live clients.
-        If not provided then will use the historical HTTP client API key.
+        If not provided then will source the `DATABENTO_API_KEY` environment variable.
     live_gateway : str, optional
         The live gateway override for Databento live clients.
     loader : DatabentoDataLoader, optional
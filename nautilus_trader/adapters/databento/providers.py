@@ -25,6 +25,7 @@ from nautilus_trader.adapters.databento.constants import ALL_SYMBOLS
 from nautilus_trader.adapters.databento.constants import PUBLISHERS_FILEPATH
 from nautilus_trader.adapters.databento.enums import DatabentoSchema
 from nautilus_trader.adapters.databento.loaders import DatabentoDataLoader
+from nautilus_trader.adapters.env import get_env_key
 from nautilus_trader.common.component import LiveClock
 from nautilus_trader.common.enums import LogColor
 from nautilus_trader.common.providers import InstrumentProvider
@@ -48,7 +49,7 @@ class DatabentoInstrumentProvider(InstrumentProvider):
         The clock for the provider.
     live_api_key : str, optional
         The specific API secret key for Databento live clients.
-        If not provided then will use the historical HTTP client API key.
+        If not provided then will source the `DATABENTO_API_KEY` environment variable.
     live_gateway : str, optional
         The live gateway override for Databento live clients.
     loader : DatabentoDataLoader, optional
@@ -71,7 +72,7 @@ class DatabentoInstrumentProvider(InstrumentProvider):
 
         self._clock = clock
         self._config = config
-        self._live_api_key = live_api_key or http_client.key
+        self._live_api_key = live_api_key or get_env_key("DATABENTO_API_KEY")
         self._live_gateway = live_gateway
 
         self._http_client = http_client
@@ -15,6 +15,7 @@
 
 from decimal import Decimal
 
+from nautilus_trader.config import LiveDataClientConfig
 from nautilus_trader.config import LiveExecClientConfig
 
 
@@ -73,3 +74,44 @@ class SandboxExecutionClientConfig(LiveExecClientConfig, frozen=True, kw_only=Tr
     use_position_ids: bool = True
     use_random_ids: bool = False
     use_reduce_only: bool = True
+
+
+class SandboxReplayDataClientConfig(LiveDataClientConfig, frozen=True, kw_only=True):
+    """
+    Configuration for ``SandboxReplayDataClient`` instances.
+
+    Parameters
+    ----------
+    venue : str
+        The venue to replay data for.
+    catalog_path : str
+        The path to the data catalog holding the recorded data.
+    catalog_fs_protocol : str, optional
+        The `fsspec` filesystem protocol for the catalog.
+    catalog_fs_storage_options : dict, optional
+        The `fsspec` storage options for the catalog.
+    instrument_ids : list[str], optional
+        The instrument IDs to load from the catalog on connect.
+        If ``None`` then all instruments for the venue are loaded.
+    start_time : str or int, optional
+        The start time for the replay (inclusive). If ``None`` then replays from the first record.
+    end_time : str or int, optional
+        The end time for the replay (inclusive). If ``None`` then replays to the last record.
+    speed : float, default 1.0
+        The replay speed multiplier relative to the recorded timing (e.g. 10.0 replays at 10x).
+        If ``None`` then data is replayed as fast as possible.
+    replay_start_delay_secs : float, default 1.0
+        The delay (seconds) after the first subscription before the replay starts,
+        allowing the initial subscriptions to be collected so all streams replay together.
+
+    """
+
+    venue: str
+    catalog_path: str
+    catalog_fs_protocol: str | None = None
+    catalog_fs_storage_options: dict | None = None
+    instrument_ids: list[str] | None = None
+    start_time: str | int | None = None
+    end_time: str | int | None = None
+    speed: float | None = 1.0
+    replay_start_delay_secs: float = 1.0
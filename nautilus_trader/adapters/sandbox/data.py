@@ -0,0 +1,377 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+
+import asyncio
+import heapq
+import itertools
+from typing import Any
+
+from nautilus_trader.adapters.sandbox.config import SandboxReplayDataClientConfig
+from nautilus_trader.cache.cache import Cache
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.common.providers import InstrumentProvider
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.data import Data
+from nautilus_trader.core.datetime import dt_to_unix_nanos
+from nautilus_trader.core.datetime import time_object_to_dt
+from nautilus_trader.core.datetime import unix_nanos_to_str
+from nautilus_trader.live.data_client import LiveMarketDataClient
+from nautilus_trader.model.data import Bar
+from nautilus_trader.model.data import BarType
+from nautilus_trader.model.data import DataType
+from nautilus_trader.model.data import OrderBookDelta
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import BookType
+from nautilus_trader.model.identifiers import ClientId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.persistence.catalog.parquet import ParquetDataCatalog
+
+
+# The number of records to replay between yields to the event loop (as fast as possible)
+_YIELD_INTERVAL = 1_000
+
+
+class SandboxReplayDataClient(LiveMarketDataClient):
+    """
+    Provides a data client which replays recorded catalog data in pseudo-real-time.
+
+    Subscribed data streams are loaded from the catalog and merged in `ts_init` order,
+    then handled with the same relative timing as recorded (scaled by the configured
+    replay speed), so strategies can be paper-traded against historical sessions within
+    the live engine stack. Replayed data retains its recorded timestamps.
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    config : SandboxReplayDataClientConfig
+        The configuration for the client.
+    name : str, optional
+        The custom client ID.
+    catalog : ParquetDataCatalog, optional
+        The catalog to replay from. If ``None`` then one is created from the config.
+
+    Raises
+    ------
+    ValueError
+        If `config.speed` is not ``None`` and not positive.
+    ValueError
+        If `config.replay_start_delay_secs` is negative.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        config: SandboxReplayDataClientConfig,
+        name: str | None = None,
+        catalog: ParquetDataCatalog | None = None,
+    ) -> None:
+        if config.speed is not None:
+            PyCondition.positive(config.speed, "config.speed")
+        PyCondition.not_negative(config.replay_start_delay_secs, "config.replay_start_delay_secs")
+
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(name or config.venue),
+            venue=Venue(config.venue),
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=InstrumentProvider(),
+            config=config,
+        )
+
+        self._config = config
+        self._catalog = catalog or ParquetDataCatalog(
+            path=config.catalog_path,
+            fs_protocol=config.catalog_fs_protocol,
+            fs_storage_options=config.catalog_fs_storage_options,
+        )
+        self._speed: float | None = config.speed
+        self._start_ns: int | None = (
+            dt_to_unix_nanos(time_object_to_dt(config.start_time))
+            if config.start_time is not None
+            else None
+        )
+        self._end_ns: int | None = (
+            dt_to_unix_nanos(time_object_to_dt(config.end_time))
+            if config.end_time is not None
+            else None
+        )
+        speed_str = f"{self._speed}x" if self._speed is not None else "as fast as possible"
+        self._log.info(f"Replay speed {speed_str}", LogColor.BLUE)
+
+        # Replay state
+        self._streams: set[tuple[type, str]] = set()
+        self._heap: list[tuple[int, int, tuple[type, str], Data]] = []
+        self._seq = itertools.count()
+        self._replay_task: asyncio.Task | None = None
+        self._replay_origin_ns: int | None = None  # Recorded time at replay start
+        self._wall_origin_ns: int | None = None  # Wall clock time at replay start
+        self._cursor_ns: int | None = None  # Recorded time of last replayed record
+
+    @property
+    def replay_time_ns(self) -> int | None:
+        """
+        Return the recorded time (UNIX nanoseconds) of the last replayed record.
+
+        Returns
+        -------
+        int or ``None``
+
+        """
+        return self._cursor_ns
+
+    async def _connect(self) -> None:
+        instruments = self._catalog.instruments(instrument_ids=self._config.instrument_ids)
+        for instrument in instruments:
+            if instrument.id.venue != self.venue:
+                continue
+            self._handle_data(instrument)
+
+        self._log.info(f"Loaded {len(instruments)} instrument(s) from catalog", LogColor.BLUE)
+
+    async def _disconnect(self) -> None:
+        if self._replay_task:
+            self._log.debug("Canceling task 'replay'")
+            self._replay_task.cancel()
+            self._replay_task = None
+
+        self._streams.clear()
+        self._heap.clear()
+
+    # -- SUBSCRIPTIONS ----------------------------------------------------------------------------
+
+    async def _subscribe(self, data_type: DataType, params: dict[str, Any] | None = None) -> None:
+        self._log.error(f"Cannot subscribe to {data_type}: not supported for replay")
+
+    async def _subscribe_instruments(self, params: dict[str, Any] | None = None) -> None:
+        pass  # All instruments are loaded on connect
+
+    async def _subscribe_instrument(
+        self,
+        instrument_id: InstrumentId,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        pass  # All instruments are loaded on connect
+
+    async def _subscribe_order_book_deltas(
+        self,
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        depth: int | None = None,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        await self._add_stream(OrderBookDelta, instrument_id.value)
+
+    async def _subscribe_order_book_snapshots(
+        self,
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        depth: int | None = None,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        await self._add_stream(OrderBookDelta, instrument_id.value)
+
+    async def _subscribe_quote_ticks(
+        self,
+        instrument_id: InstrumentId,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        await self._add_stream(QuoteTick, instrument_id.value)
+
+    async def _subscribe_trade_ticks(
+        self,
+        instrument_id: InstrumentId,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        await self._add_stream(TradeTick, instrument_id.value)
+
+    async def _subscribe_bars(
+        self,
+        bar_type: BarType,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        await self._add_stream(Bar, str(bar_type))
+
+    async def _subscribe_instrument_status(
+        self,
+        instrument_id: InstrumentId,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        self._log.warning(f"Instrument status not supported for replay: {instrument_id}")
+
+    async def _subscribe_instrument_close(
+        self,
+        instrument_id: InstrumentId,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        self._log.warning(f"Instrument close not supported for replay: {instrument_id}")
+
+    async def _unsubscribe(self, data_type: DataType, params: dict[str, Any] | None = None) -> None:
+        self._log.error(f"Cannot unsubscribe from {data_type}: not supported for replay")
+
+    async def _unsubscribe_instruments(self, params: dict[str, Any] | None = None) -> None:
+        pass  # No subscription to remove
+
+    async def _unsubscribe_instrument(
+        self,
+        instrument_id: InstrumentId,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        pass  # No subscription to remove
+
+    async def _unsubscribe_order_book_deltas(
+        self,
+        instrument_id: InstrumentId,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        self._remove_stream(OrderBookDelta, instrument_id.value)
+
+    async def _unsubscribe_order_book_snapshots(
+        self,
+        instrument_id: InstrumentId,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        self._remove_stream(OrderBookDelta, instrument_id.value)
+
+    async def _unsubscribe_quote_ticks(
+        self,
+        instrument_id: InstrumentId,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        self._remove_stream(QuoteTick, instrument_id.value)
+
+    async def _unsubscribe_trade_ticks(
+        self,
+        instrument_id: InstrumentId,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        self._remove_stream(TradeTick, instrument_id.value)
+
+    async def _unsubscribe_bars(
+        self,
+        bar_type: BarType,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        self._remove_stream(Bar, str(bar_type))
+
+    async def _unsubscribe_instrument_status(
+        self,
+        instrument_id: InstrumentId,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        pass  # No subscription to remove
+
+    async def _unsubscribe_instrument_close(
+        self,
+        instrument_id: InstrumentId,
+        params: dict[str, Any] | None = None,
+    ) -> None:
+        pass  # No subscription to remove
+
+    # -- REPLAY -----------------------------------------------------------------------------------
+
+    async def _add_stream(self, data_cls: type, identifier: str) -> None:
+        key = (data_cls, identifier)
+        if key in self._streams:
+            return
+
+        self._streams.add(key)
+
+        # Streams added mid-replay continue from the current replay time
+        start_ns = self._start_ns
+        if self._cursor_ns is not None:
+            start_ns = max(start_ns or 0, self._cursor_ns + 1)
+
+        data = await self._loop.run_in_executor(None, self._load_stream, data_cls, identifier, start_ns)
+        for record in data:
+            heapq.heappush(self._heap, (record.ts_init, next(self._seq), key, record))
+
+        self._log.info(
+            f"Loaded {len(data):,} {data_cls.__name__} record(s) for {identifier}",
+            LogColor.BLUE,
+        )
+
+        if self._replay_task is None or self._replay_task.done():
+            self._replay_task = self.create_task(self._run_replay())
+
+    def _remove_stream(self, data_cls: type, identifier: str) -> None:
+        # Buffered records for the stream are discarded lazily during replay
+        self._streams.discard((data_cls, identifier))
+
+    def _load_stream(self, data_cls: type, identifier: str, start_ns: int | None) -> list[Data]:
+        kwargs: dict[str, Any] = {
+            "start": time_object_to_dt(start_ns) if start_ns is not None else None,
+            "end": time_object_to_dt(self._end_ns) if self._end_ns is not None else None,
+        }
+        if data_cls is Bar:
+            return self._catalog.query(data_cls=Bar, bar_types=[identifier], **kwargs)
+
+        return self._catalog.query(data_cls=data_cls, instrument_ids=[identifier], **kwargs)
+
+    async def _run_replay(self) -> None:
+        if self._wall_origin_ns is None:
+            # Allow the initial subscriptions to arrive so all streams replay together
+            await asyncio.sleep(self._config.replay_start_delay_secs)
+
+        count = 0
+        while self._heap:
+            ts_init, _, key, data = self._heap[0]
+
+            if self._wall_origin_ns is None:
+                self._replay_origin_ns = ts_init
+                self._wall_origin_ns = self._clock.timestamp_ns()
+                self._log.info(f"Replay started from {unix_nanos_to_str(ts_init)}", LogColor.BLUE)
+
+            if self._speed is not None:
+                elapsed_ns = int((ts_init - self._replay_origin_ns) / self._speed)
+                delay_ns = self._wall_origin_ns + elapsed_ns - self._clock.timestamp_ns()
+                if delay_ns > 0:
+                    await asyncio.sleep(delay_ns / 1_000_000_000)
+                    continue  # Streams may have been added while sleeping
+
+            heapq.heappop(self._heap)
+            self._cursor_ns = ts_init
+
+            if key not in self._streams:
+                continue  # Unsubscribed
+
+            self._handle_data(data)
+
+            count += 1
+            if self._speed is None and count % _YIELD_INTERVAL == 0:
+                await asyncio.sleep(0)
+
+        if self._cursor_ns is not None:
+            self._log.info(
+                f"Replay complete at {unix_nanos_to_str(self._cursor_ns)}",
+                LogColor.BLUE,
+            )
@@ -16,10 +16,13 @@
 import asyncio
 
 from nautilus_trader.adapters.sandbox.config import SandboxExecutionClientConfig
+from nautilus_trader.adapters.sandbox.config import SandboxReplayDataClientConfig
+from nautilus_trader.adapters.sandbox.data import SandboxReplayDataClient
 from nautilus_trader.adapters.sandbox.execution import SandboxExecutionClient
 from nautilus_trader.cache.cache import Cache
 from nautilus_trader.common.component import LiveClock
 from nautilus_trader.common.component import MessageBus
+from nautilus_trader.live.factories import LiveDataClientFactory
 from nautilus_trader.live.factories import LiveExecClientFactory
 from nautilus_trader.portfolio import PortfolioFacade
 
@@ -73,3 +76,50 @@ class SandboxLiveExecClientFactory(LiveExecClientFactory):
             config=config,
         )
         return exec_client
+
+
+class SandboxLiveDataClientFactory(LiveDataClientFactory):
+    """
+    Provides a `Sandbox` replay data client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: SandboxReplayDataClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> SandboxReplayDataClient:
+        """
+        Create a new Sandbox replay data client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : SandboxReplayDataClientConfig
+            The configuration for the client.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock : LiveClock
+            The clock for the client.
+
+        Returns
+        -------
+        SandboxReplayDataClient
+
+        """
+        return SandboxReplayDataClient(
+            loop=loop,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            config=config,
+            name=name,
+        )
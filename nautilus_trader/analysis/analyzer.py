@@ -21,7 +21,9 @@ import pandas as pd
 from numpy import float64
 
 from nautilus_trader.accounting.accounts.base import Account
+from nautilus_trader.analysis.attribution import PerformanceAttribution
 from nautilus_trader.analysis.statistic import PortfolioStatistic
+from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.core.correctness import PyCondition
 from nautilus_trader.core.datetime import unix_nanos_to_dt
 from nautilus_trader.model.identifiers import PositionId
@@ -375,6 +377,50 @@ class PortfolioAnalyzer:
 
         return output
 
+    def get_performance_stats_monte_carlo(
+        self,
+        currency: Currency | None = None,
+        num_samples: int = 1000,
+        confidence_level: float = 0.95,
+        random_seed: int | None = None,
+    ) -> dict[str, float]:
+        """
+        Return the Monte Carlo (bootstrap) robustness statistics for the realized PnLs.
+
+        The realized trade PnLs are resampled with replacement `num_samples` times, and
+        confidence intervals are estimated for the total PnL, maximum drawdown and
+        Sharpe ratio.
+
+        Parameters
+        ----------
+        currency : Currency, optional
+            The currency for the statistics.
+        num_samples : int, default 1000
+            The number of resampled trade sequences.
+        confidence_level : float, default 0.95
+            The confidence level of the intervals.
+        random_seed : int, optional
+            The random seed for reproducible resampling.
+
+        Returns
+        -------
+        dict[str, float]
+
+        """
+        PyCondition.positive_int(num_samples, "num_samples")
+        PyCondition.in_range(confidence_level, 0.0, 1.0, "confidence_level")
+
+        realized_pnls = self.realized_pnls(currency)
+        if realized_pnls is None or realized_pnls.empty:
+            return {}
+
+        resampler = nautilus_pyo3.MonteCarloResampler(
+            num_samples,
+            confidence_level,
+            random_seed,
+        )
+        return resampler.resample(realized_pnls.astype(float).tolist())
+
     def get_performance_stats_returns(self) -> dict[str, Any]:
         """
         Return the `return` performance statistics values.
@@ -416,6 +462,26 @@ class PortfolioAnalyzer:
 
         return output
 
+    def get_performance_attribution(
+        self,
+        currency: Currency | None = None,
+    ) -> PerformanceAttribution:
+        """
+        Return the performance attribution of realized PnL and hit rate by hour-of-day,
+        weekday, and instrument for the analyzed positions.
+
+        Parameters
+        ----------
+        currency : Currency, optional
+            The currency for the attribution.
+
+        Returns
+        -------
+        PerformanceAttribution
+
+        """
+        return PerformanceAttribution(self._positions, currency)
+
     def get_stats_pnls_formatted(
         self,
         currency: Currency | None = None,
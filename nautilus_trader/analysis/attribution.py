@@ -0,0 +1,221 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import calendar
+
+import pandas as pd
+import pyarrow as pa
+
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.position import Position
+
+
+ATTRIBUTION_HOUR = "hour"
+ATTRIBUTION_WEEKDAY = "weekday"
+ATTRIBUTION_INSTRUMENT = "instrument"
+
+ATTRIBUTION_DIMENSIONS = (ATTRIBUTION_HOUR, ATTRIBUTION_WEEKDAY, ATTRIBUTION_INSTRUMENT)
+
+_COLUMNS = ["trades", "winners", "losers", "realized_pnl", "avg_pnl", "hit_rate"]
+
+
+class PerformanceAttribution:
+    """
+    Provides breakdowns of realized PnL and hit rate by hour-of-day, weekday, and
+    instrument, computed from a list of closed positions (trades).
+
+    Trades are attributed to the hour and weekday (UTC) at which the position was closed.
+
+    Parameters
+    ----------
+    positions : list[Position]
+        The positions to attribute, only closed positions are included.
+    currency : Currency, optional
+        The currency to attribute realized PnLs for. If ``None`` then the positions
+        must all have realized PnLs in a single currency.
+
+    Raises
+    ------
+    ValueError
+        If `currency` is ``None`` and the closed positions realize PnL in multiple currencies.
+
+    """
+
+    def __init__(
+        self,
+        positions: list[Position],
+        currency: Currency | None = None,
+    ) -> None:
+        PyCondition.not_none(positions, "positions")
+
+        closed = [p for p in positions if p.is_closed and p.realized_pnl is not None]
+        if currency is None:
+            currencies = {p.realized_pnl.currency for p in closed}
+            if len(currencies) > 1:
+                raise ValueError(
+                    "`currency` must be specified for positions realizing PnL in multiple "
+                    f"currencies, was {sorted(c.code for c in currencies)}",
+                )
+            currency = next(iter(currencies), None)
+
+        self.currency: Currency | None = currency
+        self._trades = pd.DataFrame(
+            [
+                {
+                    "ts_closed": pd.Timestamp(p.ts_closed, unit="ns", tz="UTC"),
+                    "instrument": p.instrument_id.value,
+                    "realized_pnl": p.realized_pnl.as_double(),
+                }
+                for p in closed
+                if p.realized_pnl.currency == currency
+            ],
+            columns=["ts_closed", "instrument", "realized_pnl"],
+        )
+
+    @property
+    def trade_count(self) -> int:
+        """
+        Return the count of trades included in the attribution.
+
+        Returns
+        -------
+        int
+
+        """
+        return len(self._trades)
+
+    def by_hour(self) -> pd.DataFrame:
+        """
+        Return the attribution by hour-of-day (0-23, UTC) at position close.
+
+        Returns
+        -------
+        pd.DataFrame
+
+        """
+        return self._breakdown(self._trades["ts_closed"].dt.hour.rename(ATTRIBUTION_HOUR))
+
+    def by_weekday(self) -> pd.DataFrame:
+        """
+        Return the attribution by weekday (e.g. 'Monday', UTC) at position close.
+
+        Returns
+        -------
+        pd.DataFrame
+
+        """
+        df = self._breakdown(self._trades["ts_closed"].dt.dayofweek.rename(ATTRIBUTION_WEEKDAY))
+        df.index = df.index.map(lambda d: calendar.day_name[d])  # Ordered Monday to Sunday
+        return df
+
+    def by_instrument(self) -> pd.DataFrame:
+        """
+        Return the attribution by instrument ID.
+
+        Returns
+        -------
+        pd.DataFrame
+
+        """
+        return self._breakdown(self._trades["instrument"].rename(ATTRIBUTION_INSTRUMENT))
+
+    def breakdown(self, dimension: str) -> pd.DataFrame:
+        """
+        Return the attribution for the given dimension.
+
+        Parameters
+        ----------
+        dimension : str {'hour', 'weekday', 'instrument'}
+            The dimension to break down by.
+
+        Returns
+        -------
+        pd.DataFrame
+
+        Raises
+        ------
+        ValueError
+            If `dimension` is not a valid attribution dimension.
+
+        """
+        PyCondition.is_in(
+            dimension,
+            ATTRIBUTION_DIMENSIONS,
+            "dimension",
+            "ATTRIBUTION_DIMENSIONS",
+            ex_type=ValueError,
+        )
+
+        if dimension == ATTRIBUTION_HOUR:
+            return self.by_hour()
+        elif dimension == ATTRIBUTION_WEEKDAY:
+            return self.by_weekday()
+        else:
+            return self.by_instrument()
+
+    def to_dict(self) -> dict[str, dict[str, dict[str, float]]]:
+        """
+        Return all attribution breakdowns as nested dictionaries.
+
+        The outer key is the dimension, then the group (as a string), then the metric.
+
+        Returns
+        -------
+        dict[str, dict[str, dict[str, float]]]
+
+        """
+        return {
+            dimension: {
+                str(group): {k: float(v) for k, v in row.items()}
+                for group, row in self.breakdown(dimension).iterrows()
+            }
+            for dimension in ATTRIBUTION_DIMENSIONS
+        }
+
+    def to_arrow(self, dimension: str) -> pa.Table:
+        """
+        Return the attribution for the given dimension as an Arrow table.
+
+        Parameters
+        ----------
+        dimension : str {'hour', 'weekday', 'instrument'}
+            The dimension to break down by.
+
+        Returns
+        -------
+        pa.Table
+
+        """
+        return pa.Table.from_pandas(self.breakdown(dimension).reset_index(), preserve_index=False)
+
+    def _breakdown(self, keys: pd.Series) -> pd.DataFrame:
+        if self._trades.empty:
+            return pd.DataFrame(columns=_COLUMNS, index=pd.Index([], name=keys.name))
+
+        pnls = self._trades["realized_pnl"]
+        grouped = pnls.groupby(keys, sort=True)
+        df = pd.DataFrame(
+            {
+                "trades": grouped.count(),
+                "winners": grouped.apply(lambda x: (x > 0).sum()),
+                "losers": grouped.apply(lambda x: (x < 0).sum()),
+                "realized_pnl": grouped.sum(),
+                "avg_pnl": grouped.mean(),
+            },
+        )
+        df["hit_rate"] = df["winners"] / df["trades"]
+
+        return df[_COLUMNS]
@@ -24,6 +24,7 @@ from nautilus_trader.common import Environment
 from nautilus_trader.common.config import ActorConfig
 from nautilus_trader.common.config import ImportableActorConfig
 from nautilus_trader.common.config import NautilusConfig
+from nautilus_trader.common.config import PositiveInt
 from nautilus_trader.common.config import resolve_path
 from nautilus_trader.core.datetime import dt_to_unix_nanos
 from nautilus_trader.data.config import DataEngineConfig
@@ -288,6 +289,12 @@ class BacktestEngineConfig(NautilusKernelConfig, frozen=True):
         If logging should be bypassed.
     run_analysis : bool, default True
         If post backtest performance analysis should be run.
+    run_monte_carlo : bool, default False
+        If Monte Carlo robustness statistics should be included in the backtest result.
+    monte_carlo_num_samples : PositiveInt, default 1000
+        The number of resampled trade sequences for the Monte Carlo statistics.
+    monte_carlo_seed : int, optional
+        The random seed for the Monte Carlo resampling (for reproducible results).
 
     """
 
@@ -297,6 +304,9 @@ class BacktestEngineConfig(NautilusKernelConfig, frozen=True):
     risk_engine: RiskEngineConfig = RiskEngineConfig()
     exec_engine: ExecEngineConfig = ExecEngineConfig()
     run_analysis: bool = True
+    run_monte_carlo: bool = False
+    monte_carlo_num_samples: PositiveInt = 1000
+    monte_carlo_seed: int | None = None
 
 
 class BacktestRunConfig(NautilusConfig, frozen=True):
@@ -1005,9 +1005,18 @@ cdef class BacktestEngine:
 
         """
         stats_pnls: dict[str, dict[str, float]] = {}
+        stats_attribution: dict[str, dict[str, dict[str, dict[str, float]]]] = {}
+        stats_monte_carlo: dict[str, dict[str, float]] = {}
 
         for currency in self.kernel.portfolio.analyzer.currencies:
             stats_pnls[currency.code] = self.kernel.portfolio.analyzer.get_performance_stats_pnls(currency)
+            stats_attribution[currency.code] = self.kernel.portfolio.analyzer.get_performance_attribution(currency).to_dict()
+            if self._config.run_monte_carlo:
+                stats_monte_carlo[currency.code] = self.kernel.portfolio.analyzer.get_performance_stats_monte_carlo(
+                    currency,
+                    num_samples=self._config.monte_carlo_num_samples,
+                    random_seed=self._config.monte_carlo_seed,
+                )
 
         return BacktestResult(
             trader_id=self._kernel.trader_id.value,
@@ -1026,6 +1035,8 @@ cdef class BacktestEngine:
             total_positions=self._kernel.cache.positions_total_count(),
             stats_pnls=stats_pnls,
             stats_returns=self._kernel.portfolio.analyzer.get_performance_stats_returns(),
+            stats_attribution=stats_attribution,
+            stats_monte_carlo=stats_monte_carlo,
         )
 
     def _run(
@@ -14,6 +14,7 @@
 # -------------------------------------------------------------------------------------------------
 
 from dataclasses import dataclass
+from dataclasses import field
 
 
 @dataclass
@@ -38,6 +39,10 @@ class BacktestResult:
     total_positions: int
     stats_pnls: dict[str, dict[str, float]]
     stats_returns: dict[str, float]
+    stats_attribution: dict[str, dict[str, dict[str, dict[str, float]]]] = field(
+        default_factory=dict,
+    )
+    stats_monte_carlo: dict[str, dict[str, float]] = field(default_factory=dict)
 
     # account_balances: pd.DataFrame
     # fills_report: pd.DataFrame
@@ -20,6 +20,7 @@ from nautilus_trader.core.rust.model cimport PriceType
 from nautilus_trader.model.book cimport OrderBook
 from nautilus_trader.model.data cimport Bar
 from nautilus_trader.model.data cimport BarType
+from nautilus_trader.model.data cimport OrderBookDepth10
 from nautilus_trader.model.data cimport QuoteTick
 from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.identifiers cimport AccountId
@@ -54,19 +55,23 @@ cdef class CacheFacade:
     cpdef list quote_ticks(self, InstrumentId instrument_id)
     cpdef list trade_ticks(self, InstrumentId instrument_id)
     cpdef list bars(self, BarType bar_type)
+    cpdef list depth10s(self, InstrumentId instrument_id)
     cpdef Price price(self, InstrumentId instrument_id, PriceType price_type)
     cpdef OrderBook order_book(self, InstrumentId instrument_id)
     cpdef QuoteTick quote_tick(self, InstrumentId instrument_id, int index=*)
     cpdef TradeTick trade_tick(self, InstrumentId instrument_id, int index=*)
     cpdef Bar bar(self, BarType bar_type, int index=*)
+    cpdef OrderBookDepth10 depth10(self, InstrumentId instrument_id, int index=*)
     cpdef int book_update_count(self, InstrumentId instrument_id)
     cpdef int quote_tick_count(self, InstrumentId instrument_id)
     cpdef int trade_tick_count(self, InstrumentId instrument_id)
     cpdef int bar_count(self, BarType bar_type)
+    cpdef int depth10_count(self, InstrumentId instrument_id)
     cpdef bint has_order_book(self, InstrumentId instrument_id)
     cpdef bint has_quote_ticks(self, InstrumentId instrument_id)
     cpdef bint has_trade_ticks(self, InstrumentId instrument_id)
     cpdef bint has_bars(self, BarType bar_type)
+    cpdef bint has_depth10s(self, InstrumentId instrument_id)
 
     cpdef double get_xrate(
         self,
@@ -81,6 +86,8 @@ cdef class CacheFacade:
     cpdef Instrument instrument(self, InstrumentId instrument_id)
     cpdef list instrument_ids(self, Venue venue=*)
     cpdef list instruments(self, Venue venue=*, str underlying=*)
+    cpdef bint is_trading_enabled(self, InstrumentId instrument_id)
+    cpdef list trading_disabled_instrument_ids(self)
 
 # -- SYNTHETIC QUERIES ----------------------------------------------------------------------------
 
@@ -16,6 +16,7 @@
 from nautilus_trader.core.rust.model cimport PriceType
 from nautilus_trader.model.data cimport Bar
 from nautilus_trader.model.data cimport BarType
+from nautilus_trader.model.data cimport OrderBookDepth10
 from nautilus_trader.model.data cimport QuoteTick
 from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.identifiers cimport AccountId
@@ -63,6 +64,10 @@ cdef class CacheFacade:
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `bars` must be implemented in the subclass")  # pragma: no cover
 
+    cpdef list depth10s(self, InstrumentId instrument_id):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `depth10s` must be implemented in the subclass")  # pragma: no cover
+
     cpdef Price price(self, InstrumentId instrument_id, PriceType price_type):
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `price` must be implemented in the subclass")  # pragma: no cover
@@ -83,6 +88,10 @@ cdef class CacheFacade:
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `bar` must be implemented in the subclass")  # pragma: no cover
 
+    cpdef OrderBookDepth10 depth10(self, InstrumentId instrument_id, int index=0):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `depth10` must be implemented in the subclass")  # pragma: no cover
+
     cpdef int book_update_count(self, InstrumentId instrument_id):
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `book_update_count` must be implemented in the subclass")  # pragma: no cover
@@ -99,6 +108,10 @@ cdef class CacheFacade:
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `bar_count` must be implemented in the subclass")  # pragma: no cover
 
+    cpdef int depth10_count(self, InstrumentId instrument_id):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `depth10_count` must be implemented in the subclass")  # pragma: no cover
+
     cpdef bint has_order_book(self, InstrumentId instrument_id):
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `has_order_book` must be implemented in the subclass")  # pragma: no cover
@@ -115,6 +128,10 @@ cdef class CacheFacade:
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `has_bars` must be implemented in the subclass")  # pragma: no cover
 
+    cpdef bint has_depth10s(self, InstrumentId instrument_id):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `has_depth10s` must be implemented in the subclass")  # pragma: no cover
+
     cpdef double get_xrate(
         self,
         Venue venue,
@@ -139,6 +156,14 @@ cdef class CacheFacade:
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `instruments` must be implemented in the subclass")  # pragma: no cover
 
+    cpdef bint is_trading_enabled(self, InstrumentId instrument_id):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `is_trading_enabled` must be implemented in the subclass")  # pragma: no cover
+
+    cpdef list trading_disabled_instrument_ids(self):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `trading_disabled_instrument_ids` must be implemented in the subclass")  # pragma: no cover
+
 # -- SYNTHETIC QUERIES ----------------------------------------------------------------------------
 
     cpdef SyntheticInstrument synthetic(self, InstrumentId instrument_id):
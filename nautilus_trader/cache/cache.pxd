@@ -23,6 +23,7 @@ from nautilus_trader.cache.base cimport CacheFacade
 from nautilus_trader.cache.facade cimport CacheDatabaseFacade
 from nautilus_trader.common.actor cimport Actor
 from nautilus_trader.common.component cimport Logger
+from nautilus_trader.common.messages cimport InstrumentConflictResolved
 from nautilus_trader.core.rust.model cimport AggregationSource
 from nautilus_trader.core.rust.model cimport OmsType
 from nautilus_trader.core.rust.model cimport OrderSide
@@ -32,6 +33,7 @@ from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.model.book cimport OrderBook
 from nautilus_trader.model.data cimport Bar
 from nautilus_trader.model.data cimport BarType
+from nautilus_trader.model.data cimport OrderBookDepth10
 from nautilus_trader.model.data cimport QuoteTick
 from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.identifiers cimport AccountId
@@ -64,17 +66,20 @@ cdef class Cache(CacheFacade):
     cdef dict _quote_ticks
     cdef dict _trade_ticks
     cdef dict _order_books
+    cdef dict _depth10s
     cdef dict _bars
     cdef dict _bars_bid
     cdef dict _bars_ask
     cdef dict _currencies
     cdef dict _instruments
+    cdef dict _instrument_sources
     cdef dict _synthetics
     cdef dict _accounts
     cdef dict _orders
     cdef dict _order_lists
     cdef dict _positions
     cdef dict _position_snapshots
+    cdef set _trading_disabled
 
     cdef dict _index_venue_account
     cdef dict _index_venue_orders
@@ -105,6 +110,7 @@ cdef class Cache(CacheFacade):
     cdef set _index_strategies
     cdef set _index_exec_algorithms
     cdef bint _drop_instruments_on_reset
+    cdef str _instrument_conflict_policy
 
     cdef readonly bint has_backing
     """If the cache has a database backing.\n\n:returns: `bool`"""
@@ -146,6 +152,7 @@ cdef class Cache(CacheFacade):
     cpdef Account load_account(self, AccountId account_id)
     cpdef Order load_order(self, ClientOrderId order_id)
     cpdef Position load_position(self, PositionId position_id)
+    cpdef list load_fills(self, InstrumentId instrument_id, uint64_t start=*, uint64_t end=*)
     cpdef void load_actor(self, Actor actor)
     cpdef void load_strategy(self, Strategy strategy)
 
@@ -153,11 +160,12 @@ cdef class Cache(CacheFacade):
     cpdef void add_quote_tick(self, QuoteTick tick)
     cpdef void add_trade_tick(self, TradeTick tick)
     cpdef void add_bar(self, Bar bar)
+    cpdef void add_depth10(self, OrderBookDepth10 depth)
     cpdef void add_quote_ticks(self, list ticks)
     cpdef void add_trade_ticks(self, list ticks)
     cpdef void add_bars(self, list bars)
     cpdef void add_currency(self, Currency currency)
-    cpdef void add_instrument(self, Instrument instrument)
+    cpdef InstrumentConflictResolved add_instrument(self, Instrument instrument, ClientId client_id=*)
     cpdef void add_synthetic(self, SyntheticInstrument synthetic)
     cpdef void add_account(self, Account account)
     cpdef void add_venue_order_id(self, ClientOrderId client_order_id, VenueOrderId venue_order_id, bint overwrite=*)
@@ -173,6 +181,7 @@ cdef class Cache(CacheFacade):
     cpdef void update_order(self, Order order)
     cpdef void update_order_pending_cancel_local(self, Order order)
     cpdef void update_position(self, Position position)
+    cpdef void set_trading_enabled(self, InstrumentId instrument_id, bint enabled)
     cpdef void update_actor(self, Actor actor)
     cpdef void update_strategy(self, Strategy strategy)
     cpdef void delete_actor(self, Actor actor)
@@ -181,6 +190,7 @@ cdef class Cache(CacheFacade):
     cpdef void heartbeat(self, datetime timestamp)
 
     cdef timedelta _get_timedelta(self, BarType bar_type)
+    cdef bint _is_venue_client(self, ClientId client_id, InstrumentId instrument_id)
 
     cpdef list bar_types(
         self,
@@ -33,7 +33,9 @@ from nautilus_trader.accounting.calculators cimport ExchangeRateCalculator
 from nautilus_trader.cache.facade cimport CacheDatabaseFacade
 from nautilus_trader.common.component cimport LogColor
 from nautilus_trader.common.component cimport Logger
+from nautilus_trader.common.messages cimport InstrumentConflictResolved
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.core.rust.model cimport AggregationSource
 from nautilus_trader.core.rust.model cimport ContingencyType
 from nautilus_trader.core.rust.model cimport OmsType
@@ -46,6 +48,7 @@ from nautilus_trader.model.data cimport Bar
 from nautilus_trader.model.data cimport BarAggregation
 from nautilus_trader.model.data cimport BarSpecification
 from nautilus_trader.model.data cimport BarType
+from nautilus_trader.model.data cimport OrderBookDepth10
 from nautilus_trader.model.data cimport QuoteTick
 from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.events.order cimport OrderUpdated
@@ -73,6 +76,9 @@ from nautilus_trader.model.orders.list cimport OrderList
 from nautilus_trader.trading.strategy cimport Strategy
 
 
+cdef tuple _INSTRUMENT_CONFLICT_POLICIES = ("PREFER_MOST_RECENT", "PREFER_VENUE", "ERROR")
+
+
 cdef class Cache(CacheFacade):
     """
     Provides a common object cache for market and execution related data.
@@ -98,6 +104,12 @@ cdef class Cache(CacheFacade):
         if config is None:
             config = CacheConfig()
         Condition.type(config, CacheConfig, "config")
+        Condition.is_in(
+            config.instrument_conflict_policy,
+            _INSTRUMENT_CONFLICT_POLICIES,
+            "config.instrument_conflict_policy",
+            "_INSTRUMENT_CONFLICT_POLICIES",
+        )
 
         self._database = database
         self._log = Logger(name=type(self).__name__)
@@ -105,6 +117,7 @@ cdef class Cache(CacheFacade):
 
         # Configuration
         self._drop_instruments_on_reset = config.drop_instruments_on_reset
+        self._instrument_conflict_policy = config.instrument_conflict_policy
         self.has_backing = database is not None
         self.tick_capacity = config.tick_capacity
         self.bar_capacity = config.bar_capacity
@@ -115,17 +128,20 @@ cdef class Cache(CacheFacade):
         self._quote_ticks: dict[InstrumentId, deque[QuoteTick]] = {}
         self._trade_ticks: dict[InstrumentId, deque[TradeTick]] = {}
         self._order_books: dict[InstrumentId, OrderBook] = {}
+        self._depth10s: dict[InstrumentId, deque[OrderBookDepth10]] = {}
         self._bars: dict[BarType, deque[Bar]] = {}
         self._bars_bid: dict[InstrumentId, Bar] = {}
         self._bars_ask: dict[InstrumentId, Bar] = {}
         self._currencies: dict[str, Currency] = {}
         self._instruments: dict[InstrumentId, Instrument] = {}
+        self._instrument_sources: dict[InstrumentId, ClientId] = {}
         self._synthetics: dict[InstrumentId, SyntheticInstrument] = {}
         self._accounts: dict[AccountId, Account] = {}
         self._orders: dict[ClientOrderId, Order] = {}
         self._order_lists: dict[OrderListId, OrderList] = {}
         self._positions: dict[PositionId, Position] = {}
         self._position_snapshots: dict[PositionId, list[bytes]] = {}
+        self._trading_disabled: set[InstrumentId] = set()
 
         # Cache index
         self._index_venue_account: dict[Venue, AccountId] = {}
@@ -729,6 +745,7 @@ cdef class Cache(CacheFacade):
         self._quote_ticks.clear()
         self._trade_ticks.clear()
         self._order_books.clear()
+        self._depth10s.clear()
         self._bars.clear()
         self._bars_bid.clear()
         self._bars_ask.clear()
@@ -739,10 +756,12 @@ cdef class Cache(CacheFacade):
         self._order_lists.clear()
         self._positions.clear()
         self._position_snapshots.clear()
+        self._trading_disabled.clear()
         self.clear_index()
 
         if self._drop_instruments_on_reset:
             self._instruments.clear()
+            self._instrument_sources.clear()
 
         self._log.info(f"Reset")
 
@@ -1087,6 +1106,38 @@ cdef class Cache(CacheFacade):
 
         return self._positions.get(position_id)
 
+    cpdef list load_fills(self, InstrumentId instrument_id, uint64_t start = 0, uint64_t end = 0):
+        """
+        Load the fill events for the given instrument ID within the given time range
+        from the cache database.
+
+        The fills are loaded directly from the persistent store, without loading
+        the orders they belong to, so large histories can be paged through by time.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the fills to load.
+        start : uint64_t, default 0
+            UNIX timestamp (nanoseconds) of the start of the range (inclusive).
+        end : uint64_t, default 0
+            UNIX timestamp (nanoseconds) of the end of the range (inclusive).
+            If zero then the range is unbounded.
+
+        Returns
+        -------
+        list[OrderFilled]
+            The fills sorted by `ts_event` ascending (empty if no cache database).
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        if self._database is None:
+            self._log.warning("Cannot load fills: no cache database configured")
+            return []
+
+        return self._database.load_fills(instrument_id, start, end)
+
     cpdef void add(self, str key, bytes value):
         """
         Add the given general object `value` to the cache.
@@ -1169,6 +1220,27 @@ cdef class Cache(CacheFacade):
 
         ticks.appendleft(tick)
 
+    cpdef void add_depth10(self, OrderBookDepth10 depth):
+        """
+        Add the given order book depth snapshot to the cache.
+
+        Parameters
+        ----------
+        depth : OrderBookDepth10
+            The depth snapshot to add.
+
+        """
+        Condition.not_none(depth, "depth")
+
+        depths = self._depth10s.get(depth.instrument_id)
+
+        if not depths:
+            # The instrument_id was not registered
+            depths = deque(maxlen=self.tick_capacity)
+            self._depth10s[depth.instrument_id] = depths
+
+        depths.appendleft(depth)
+
     cpdef void add_bar(self, Bar bar):
         """
         Add the given bar to the cache.
@@ -1327,18 +1399,81 @@ cdef class Cache(CacheFacade):
         if self._database is not None:
             self._database.add_currency(currency)
 
-    cpdef void add_instrument(self, Instrument instrument):
+    cpdef InstrumentConflictResolved add_instrument(self, Instrument instrument, ClientId client_id = None):
         """
         Add the given instrument to the cache.
 
+        If a definition for the same instrument ID was previously added from a
+        different client, then the configured instrument conflict policy decides
+        which definition is kept. Definitions from unknown clients never conflict.
+
         Parameters
         ----------
         instrument : Instrument
             The instrument to add.
+        client_id : ClientId, optional
+            The client ID which provided the instrument definition (if known).
+
+        Returns
+        -------
+        InstrumentConflictResolved or ``None``
+            The event describing the resolution if the definition conflicted.
+
+        Raises
+        ------
+        ValueError
+            If the definition conflicts and the policy is 'ERROR'.
+
+        """
+        cdef InstrumentConflictResolved event = None
+        cdef Instrument existing = self._instruments.get(instrument.id)
+        cdef ClientId existing_client_id = self._instrument_sources.get(instrument.id)
+        cdef bint replace = True
+
+        if (
+            existing is not None
+            and existing_client_id is not None
+            and client_id is not None
+            and existing_client_id.to_str() != client_id.to_str()
+        ):
+            if self._instrument_conflict_policy == "ERROR":
+                raise ValueError(
+                    f"Conflicting definition for {instrument.id} from {client_id}, "
+                    f"was previously defined by {existing_client_id}",
+                )
+            elif self._instrument_conflict_policy == "PREFER_VENUE":
+                # Only keep the existing definition when it came from the venue itself
+                replace = not (
+                    self._is_venue_client(existing_client_id, instrument.id)
+                    and not self._is_venue_client(client_id, instrument.id)
+                )
+            else:  # PREFER_MOST_RECENT
+                replace = instrument.ts_init >= existing.ts_init
+
+            event = InstrumentConflictResolved(
+                instrument_id=instrument.id,
+                policy=self._instrument_conflict_policy,
+                resolution="REPLACED" if replace else "KEPT_EXISTING",
+                existing_client_id=existing_client_id,
+                incoming_client_id=client_id,
+                event_id=UUID4(),
+                ts_event=instrument.ts_init,
+                ts_init=instrument.ts_init,
+            )
+
+            self._log.warning(
+                f"Resolved conflicting definition for {instrument.id} "
+                f"from {client_id} (existing from {existing_client_id}): {event.resolution}",
+            )
+
+            if not replace:
+                return event
 
-        """
         self._instruments[instrument.id] = instrument
 
+        if client_id is not None:
+            self._instrument_sources[instrument.id] = client_id
+
         if isinstance(instrument, (CurrencyPair, CryptoPerpetual)):
             self._xrate_symbols[instrument.id] = (
                 f"{instrument.base_currency}/{instrument.quote_currency}"
@@ -1350,6 +1485,8 @@ cdef class Cache(CacheFacade):
         if self._database is not None:
             self._database.add_instrument(instrument)
 
+        return event
+
     cpdef void add_synthetic(self, SyntheticInstrument synthetic):
         """
         Add the given synthetic instrument to the cache.
@@ -1882,6 +2019,30 @@ cdef class Cache(CacheFacade):
         # Update database
         self._database.update_position(position)
 
+    cpdef void set_trading_enabled(self, InstrumentId instrument_id, bint enabled):
+        """
+        Set whether trading is enabled for the given instrument ID.
+
+        When trading is disabled the `RiskEngine` will deny new orders for the
+        instrument until trading is enabled again.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the setting.
+        enabled : bool
+            If trading should be enabled for the instrument.
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        if enabled:
+            self._trading_disabled.discard(instrument_id)
+        else:
+            self._trading_disabled.add(instrument_id)
+
+        self._log.debug(f"Set trading {'enabled' if enabled else 'disabled'} for {instrument_id}")
+
     cpdef void update_actor(self, Actor actor):
         """
         Update the given actor state in the cache.
@@ -2049,6 +2210,24 @@ cdef class Cache(CacheFacade):
 
         return list(self._bars.get(bar_type, []))
 
+    cpdef list depth10s(self, InstrumentId instrument_id):
+        """
+        Return the order book depth snapshots for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the depth snapshots to get.
+
+        Returns
+        -------
+        list[OrderBookDepth10]
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        return list(self._depth10s.get(instrument_id, []))
+
     cpdef Price price(self, InstrumentId instrument_id, PriceType price_type):
         """
         Return the price for the given instrument ID and price type.
@@ -2204,6 +2383,41 @@ cdef class Cache(CacheFacade):
         except IndexError:
             return None
 
+    cpdef OrderBookDepth10 depth10(self, InstrumentId instrument_id, int index = 0):
+        """
+        Return the order book depth snapshot for the given instrument ID at the
+        given index.
+
+        Last depth snapshot if no index specified.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the depth snapshot to get.
+        index : int, optional
+            The index for the depth snapshot to get.
+
+        Returns
+        -------
+        OrderBookDepth10 or ``None``
+            If no depth snapshots or no depth snapshot at index then returns ``None``.
+
+        Notes
+        -----
+        Reverse indexed (most recent depth snapshot at index 0).
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        depths = self._depth10s.get(instrument_id)
+        if not depths:
+            return None
+
+        try:
+            return depths[index]
+        except IndexError:
+            return None
+
     cpdef int book_update_count(self, InstrumentId instrument_id):
         """
         The count of order book updates for the given instrument ID.
@@ -2282,6 +2496,24 @@ cdef class Cache(CacheFacade):
 
         return len(self._bars.get(bar_type, []))
 
+    cpdef int depth10_count(self, InstrumentId instrument_id):
+        """
+        The count of order book depth snapshots for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the depth snapshots.
+
+        Returns
+        -------
+        int
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        return len(self._depth10s.get(instrument_id, []))
+
     cpdef bint has_order_book(self, InstrumentId instrument_id):
         """
         Return a value indicating whether the cache has an order book snapshot
@@ -2356,6 +2588,25 @@ cdef class Cache(CacheFacade):
 
         return self.bar_count(bar_type) > 0
 
+    cpdef bint has_depth10s(self, InstrumentId instrument_id):
+        """
+        Return a value indicating whether the cache has order book depth
+        snapshots for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the depth snapshots.
+
+        Returns
+        -------
+        bool
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        return self.depth10_count(instrument_id) > 0
+
     cpdef double get_xrate(
         self,
         Venue venue,
@@ -2496,6 +2747,37 @@ cdef class Cache(CacheFacade):
             (underlying is None or (hasattr(x, "underlying") and underlying == x.underlying))
         ]
 
+    cpdef bint is_trading_enabled(self, InstrumentId instrument_id):
+        """
+        Return a value indicating whether trading is enabled for the given instrument ID.
+
+        Trading is enabled for all instruments unless explicitly disabled.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID to check.
+
+        Returns
+        -------
+        bool
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        return instrument_id not in self._trading_disabled
+
+    cpdef list trading_disabled_instrument_ids(self):
+        """
+        Return all instrument IDs with trading currently disabled.
+
+        Returns
+        -------
+        list[InstrumentId]
+
+        """
+        return sorted(self._trading_disabled)
+
     cdef timedelta _get_timedelta(self, BarType bar_type):
         # Helper method to get the timedelta from a BarType
         cdef BarSpecification bar_spec = bar_type.spec
@@ -2503,6 +2785,10 @@ cdef class Cache(CacheFacade):
             return timedelta(days=bar_spec.step * 30)  # Reasonable value to fix sorting
         return bar_spec.timedelta
 
+    cdef bint _is_venue_client(self, ClientId client_id, InstrumentId instrument_id):
+        # Helper method to determine whether the client ID is for the instruments venue
+        return client_id is not None and client_id.to_str() == instrument_id.venue.to_str()
+
     cpdef list bar_types(
         self,
         InstrumentId instrument_id = None,
@@ -49,6 +49,12 @@ class CacheConfig(NautilusConfig, frozen=True):
         The maximum length for internal tick dequeues.
     bar_capacity : PositiveInt, default 10_000
         The maximum length for internal bar dequeues.
+    instrument_conflict_policy : str, {'PREFER_MOST_RECENT', 'PREFER_VENUE', 'ERROR'}, default 'PREFER_MOST_RECENT'
+        The policy for resolving conflicting definitions of the same instrument
+        received from different clients.
+        'PREFER_MOST_RECENT' keeps the definition with the latest `ts_init`.
+        'PREFER_VENUE' keeps the definition from the client for the instruments venue.
+        'ERROR' raises an error on any conflicting definition.
 
     """
 
@@ -62,3 +68,4 @@ class CacheConfig(NautilusConfig, frozen=True):
     drop_instruments_on_reset: bool = True
     tick_capacity: PositiveInt = 10_000
     bar_capacity: PositiveInt = 10_000
+    instrument_conflict_policy: str = "PREFER_MOST_RECENT"
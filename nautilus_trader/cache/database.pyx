@@ -21,6 +21,7 @@ from nautilus_trader.cache.config import CacheConfig
 from nautilus_trader.core import nautilus_pyo3
 
 from cpython.datetime cimport datetime
+from libc.stdint cimport UINT64_MAX
 from libc.stdint cimport uint64_t
 
 from nautilus_trader.accounting.accounts.base cimport Account
@@ -76,6 +77,7 @@ cdef str _ACCOUNTS = "accounts"
 cdef str _TRADER = "trader"
 cdef str _ORDERS = "orders"
 cdef str _POSITIONS = "positions"
+cdef str _FILLS = "fills"
 cdef str _ACTORS = "actors"
 cdef str _STRATEGIES = "strategies"
 
@@ -615,6 +617,47 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
 
         return order
 
+    cpdef list load_fills(self, InstrumentId instrument_id, uint64_t start = 0, uint64_t end = 0):
+        """
+        Load the fill events for the given instrument ID within the given time range.
+
+        Only the fill events are loaded, without loading the orders they belong to.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the fills to load.
+        start : uint64_t, default 0
+            UNIX timestamp (nanoseconds) of the start of the range (inclusive).
+        end : uint64_t, default 0
+            UNIX timestamp (nanoseconds) of the end of the range (inclusive).
+            If zero then the range is unbounded.
+
+        Returns
+        -------
+        list[OrderFilled]
+            The fills sorted by `ts_event` ascending.
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        if end == 0:
+            end = UINT64_MAX
+
+        cdef str key = f"{_FILLS}:{instrument_id.to_str()}"
+        cdef list result = self._backing.read_range(key, start, end)
+
+        cdef list fills = []
+        cdef bytes fill_bytes
+        cdef OrderFilled fill
+        for fill_bytes in result:
+            fill = self._serializer.deserialize(fill_bytes)
+            # Scores are stored as floats, so filter on the exact timestamps
+            if start <= fill.ts_event <= end:
+                fills.append(fill)
+
+        return fills
+
     cpdef Position load_position(self, PositionId position_id):
         """
         Load the position associated with the given ID (if found).
@@ -1011,9 +1054,18 @@ cdef class CacheDatabaseAdapter(CacheDatabaseFacade):
 
         cdef str client_order_id_str = order.client_order_id.to_str()
         cdef str key = f"{_ORDERS}:{client_order_id_str}"
-        cdef list payload = [self._serializer.serialize(order.last_event_c())]
+        cdef OrderEvent last_event = order.last_event_c()
+        cdef bytes last_event_bytes = self._serializer.serialize(last_event)
+        cdef list payload = [last_event_bytes]
         self._backing.update(key, payload)
 
+        if isinstance(last_event, OrderFilled):
+            # Index fills by instrument scored by `ts_event` for time range queries
+            self._backing.insert(
+                f"{_FILLS}:{order.instrument_id.to_str()}",
+                [last_event_bytes, str(last_event.ts_event).encode()],
+            )
+
         if order.venue_order_id is not None:
             # Assumes order_id does not change
             self.index_venue_order_id(order.client_order_id, order.venue_order_id)
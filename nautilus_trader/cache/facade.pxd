@@ -73,6 +73,7 @@ cdef class CacheDatabaseFacade:
     cpdef Account load_account(self, AccountId account_id)
     cpdef Order load_order(self, ClientOrderId order_id)
     cpdef Position load_position(self, PositionId position_id)
+    cpdef list load_fills(self, InstrumentId instrument_id, uint64_t start=*, uint64_t end=*)
     cpdef dict load_actor(self, ComponentId component_id)
     cpdef void delete_actor(self, ComponentId component_id)
     cpdef dict load_strategy(self, StrategyId strategy_id)
@@ -139,6 +139,10 @@ cdef class CacheDatabaseFacade:
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `load_position` must be implemented in the subclass")  # pragma: no cover
 
+    cpdef list load_fills(self, InstrumentId instrument_id, uint64_t start = 0, uint64_t end = 0):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `load_fills` must be implemented in the subclass")  # pragma: no cover
+
     cpdef dict load_actor(self, ComponentId component_id):
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `load_actor` must be implemented in the subclass")  # pragma: no cover
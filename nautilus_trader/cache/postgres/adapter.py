@@ -26,6 +26,7 @@ from nautilus_trader.cache.postgres.transformers import transform_custom_data_to
 from nautilus_trader.cache.postgres.transformers import transform_data_type_to_pyo3
 from nautilus_trader.cache.postgres.transformers import transform_instrument_from_pyo3
 from nautilus_trader.cache.postgres.transformers import transform_instrument_to_pyo3
+from nautilus_trader.cache.postgres.transformers import transform_order_event_from_pyo3
 from nautilus_trader.cache.postgres.transformers import transform_order_event_to_pyo3
 from nautilus_trader.cache.postgres.transformers import transform_order_from_pyo3
 from nautilus_trader.cache.postgres.transformers import transform_order_to_pyo3
@@ -43,6 +44,7 @@ from nautilus_trader.model.data import CustomData
 from nautilus_trader.model.data import DataType
 from nautilus_trader.model.data import QuoteTick
 from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.events import OrderFilled
 from nautilus_trader.model.identifiers import AccountId
 from nautilus_trader.model.identifiers import ClientOrderId
 from nautilus_trader.model.identifiers import InstrumentId
@@ -95,6 +97,16 @@ class CachePostgresAdapter(CacheDatabaseFacade):
             return transform_order_from_pyo3(order_pyo3)
         return None
 
+    def load_fills(
+        self,
+        instrument_id: InstrumentId,
+        start: int = 0,
+        end: int = 0,
+    ) -> list[OrderFilled]:
+        instrument_id_pyo3 = nautilus_pyo3.InstrumentId.from_str(str(instrument_id))
+        fills_pyo3 = self._backing.load_fills(instrument_id_pyo3, start, end or None)
+        return [transform_order_event_from_pyo3(fill) for fill in fills_pyo3]
+
     def load_orders(self):
         orders = self._backing.load_orders()
         return [transform_order_from_pyo3(order) for order in orders]
@@ -60,6 +60,8 @@ cdef class Actor(Component):
     cdef dict[InstrumentId, list[Indicator]] _indicators_for_quotes
     cdef dict[InstrumentId, list[Indicator]] _indicators_for_trades
     cdef dict[BarType, list[Indicator]] _indicators_for_bars
+    cdef dict[tuple, dict] _persisted_subscriptions
+    cdef set _requested_on_start
 
     cdef readonly PortfolioFacade portfolio
     """The read-only portfolio for the actor.\n\n:returns: `PortfolioFacade`"""
@@ -175,6 +177,16 @@ cdef class Actor(Component):
     cpdef void publish_data(self, DataType data_type, Data data)
     cpdef void publish_signal(self, str name, value, uint64_t ts_event=*)
     cpdef void subscribe_signal(self, str name=*)
+    cpdef void unsubscribe_all(self)
+    cpdef list persisted_subscriptions(self)
+    cpdef void resubscribe_persisted(self)
+    cdef bint _is_persisting_subscriptions(self)
+    cdef str _subscriptions_key(self)
+    cdef list _load_persisted_subscriptions(self)
+    cdef void _resubscribe(self, list specs, set skip)
+    cdef void _record_subscription(self, str kind, str identifier, ClientId client_id, dict extra=*)
+    cdef void _remove_subscription(self, str kind, str identifier)
+    cdef void _persist_subscriptions(self)
 
 # -- REQUESTS -------------------------------------------------------------------------------------
 
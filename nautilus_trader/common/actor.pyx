@@ -28,9 +28,11 @@ import asyncio
 from concurrent.futures import Executor
 
 import cython
+import msgspec
 
 from nautilus_trader.common.config import ActorConfig
 from nautilus_trader.common.config import ImportableActorConfig
+from nautilus_trader.common.config import resolve_path
 from nautilus_trader.common.executor import ActorExecutor
 from nautilus_trader.common.executor import TaskId
 from nautilus_trader.common.signal import generate_signal_class
@@ -129,6 +131,10 @@ cdef class Actor(Component):
         self._indicators_for_trades: dict[InstrumentId, list[Indicator]] = {}
         self._indicators_for_bars: dict[BarType, list[Indicator]] = {}
 
+        # Persisted subscriptions (kind, identifier) -> spec
+        self._persisted_subscriptions: dict[tuple[str, str], dict] = {}
+        self._requested_on_start: set[tuple[str, str]] | None = None
+
         # Configuration
         self.config = config
 
@@ -1058,11 +1064,24 @@ cdef class Actor(Component):
 # -- ACTION IMPLEMENTATIONS -----------------------------------------------------------------------
 
     cpdef void _start(self):
+        if not self._is_persisting_subscriptions():
+            self.on_start()
+            return
+
+        # Persisted subscriptions are restored after `on_start`, other than those it requests again
+        cdef list persisted = self._load_persisted_subscriptions()
+        self._requested_on_start = set()
         self.on_start()
+        cdef set requested = self._requested_on_start
+        self._requested_on_start = None
+        self._resubscribe(persisted, requested)
 
     cpdef void _stop(self):
         self.on_stop()
 
+        if self.trader_id is not None:
+            self.unsubscribe_all()
+
         # Clean up clock
         cdef list timer_names = self._clock.timer_names
         self._clock.cancel_timers()
@@ -1127,6 +1146,19 @@ cdef class Actor(Component):
             handler=self.handle_data,
         )
 
+        self._record_subscription(
+            "data",
+            data_type.topic,
+            client_id,
+            {
+                "type": f"{data_type.type.__module__}:{data_type.type.__qualname__}",
+                "metadata": {
+                    k: v if v is None or isinstance(v, (str, int, float, bool)) else str(v)
+                    for k, v in data_type.metadata.items()
+                },
+            },
+        )
+
         if client_id is None:
             return
 
@@ -1137,6 +1169,7 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
         self._send_data_cmd(command)
@@ -1176,8 +1209,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._record_subscription("instruments", venue.value, client_id)
+
         self._send_data_cmd(command)
 
     cpdef void subscribe_instrument(
@@ -1217,8 +1253,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._record_subscription("instrument", instrument_id.value, client_id)
+
         self._send_data_cmd(command)
 
     cpdef void subscribe_order_book_deltas(
@@ -1282,6 +1321,14 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
+        )
+
+        self._record_subscription(
+            "order_book_deltas",
+            instrument_id.value,
+            client_id,
+            {"book_type": <int>book_type, "depth": depth, "managed": managed},
         )
 
         self._send_data_cmd(command)
@@ -1368,6 +1415,20 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
+        )
+
+        self._record_subscription(
+            "order_book_at_interval",
+            f"{instrument_id.value}@{interval_ms}",
+            client_id,
+            {
+                "instrument_id": instrument_id.value,
+                "book_type": <int>book_type,
+                "depth": depth,
+                "interval_ms": interval_ms,
+                "managed": managed,
+            },
         )
 
         self._send_data_cmd(command)
@@ -1409,8 +1470,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._record_subscription("quote_ticks", instrument_id.value, client_id)
+
         self._send_data_cmd(command)
 
     cpdef void subscribe_trade_ticks(
@@ -1450,8 +1514,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._record_subscription("trade_ticks", instrument_id.value, client_id)
+
         self._send_data_cmd(command)
 
     cpdef void subscribe_bars(
@@ -1496,8 +1563,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._record_subscription("bars", str(bar_type), client_id, {"await_partial": await_partial})
+
         self._send_data_cmd(command)
 
     cpdef void subscribe_instrument_status(
@@ -1535,8 +1605,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._record_subscription("instrument_status", instrument_id.value, client_id)
+
         self._send_data_cmd(command)
         self._log.info(f"Subscribed to {instrument_id} InstrumentStatus")
 
@@ -1575,8 +1648,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._record_subscription("instrument_close", instrument_id.value, client_id)
+
         self._send_data_cmd(command)
 
     cpdef void unsubscribe_data(
@@ -1607,6 +1683,8 @@ cdef class Actor(Component):
             handler=self.handle_data,
         )
 
+        self._remove_subscription("data", data_type.topic)
+
         if client_id is None:
             return
 
@@ -1617,6 +1695,7 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
         self._send_data_cmd(command)
@@ -1656,8 +1735,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._remove_subscription("instruments", venue.value)
+
         self._send_data_cmd(command)
 
     cpdef void unsubscribe_instrument(
@@ -1697,8 +1779,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._remove_subscription("instrument", instrument_id.value)
+
         self._send_data_cmd(command)
 
     cpdef void unsubscribe_order_book_deltas(
@@ -1738,8 +1823,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._remove_subscription("order_book_deltas", instrument_id.value)
+
         self._send_data_cmd(command)
 
     cpdef void unsubscribe_order_book_at_interval(
@@ -1788,8 +1876,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._remove_subscription("order_book_at_interval", f"{instrument_id.value}@{interval_ms}")
+
         self._send_data_cmd(command)
 
     cpdef void unsubscribe_quote_ticks(
@@ -1829,8 +1920,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._remove_subscription("quote_ticks", instrument_id.value)
+
         self._send_data_cmd(command)
 
     cpdef void unsubscribe_trade_ticks(
@@ -1870,8 +1964,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._remove_subscription("trade_ticks", instrument_id.value)
+
         self._send_data_cmd(command)
 
     cpdef void unsubscribe_bars(
@@ -1911,8 +2008,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._remove_subscription("bars", str(bar_type))
+
         self._send_data_cmd(command)
         self._log.info(f"Unsubscribed from {standard_bar_type} bar data")
 
@@ -1951,8 +2051,11 @@ cdef class Actor(Component):
             command_id=UUID4(),
             ts_init=self._clock.timestamp_ns(),
             params=params,
+            component_id=self.id,
         )
 
+        self._remove_subscription("instrument_status", instrument_id.value)
+
         self._send_data_cmd(command)
         self._log.info(f"Unsubscribed from {instrument_id} InstrumentStatus")
 
@@ -2032,6 +2135,167 @@ cdef class Actor(Component):
             handler=self.handle_signal,
         )
 
+    cpdef void unsubscribe_all(self):
+        """
+        Unsubscribe from all data subscriptions made by the actor.
+
+        The actor's data handlers are removed from the message bus, then the
+        `DataEngine` unsubscribes from any data no longer required by other components.
+
+        This is called automatically when the actor is stopped.
+        Persisted subscriptions are retained, so they are resubscribed on the next start.
+
+        """
+        Condition.is_true(self.trader_id is not None, "The actor has not been registered")
+
+        for sub in self._msgbus.subscriptions("data.*"):
+            if getattr(sub.handler, "__self__", None) is self:
+                self._msgbus.unsubscribe(topic=sub.topic, handler=sub.handler)
+
+        if "DataEngine.unsubscribe_all" in self._msgbus.endpoints():
+            self._msgbus.send(endpoint="DataEngine.unsubscribe_all", msg=self.id)
+
+    cpdef list persisted_subscriptions(self):
+        """
+        Return the persisted data subscriptions for the actor.
+
+        Subscriptions are only persisted when the `persist_subscriptions`
+        configuration option is enabled.
+
+        Returns
+        -------
+        list[dict[str, Any]]
+
+        """
+        return list(self._persisted_subscriptions.values())
+
+    cpdef void resubscribe_persisted(self):
+        """
+        Resubscribe to the data subscriptions persisted in the cache for the actor.
+
+        This is called automatically when the actor is started (after `on_start`),
+        if the `persist_subscriptions` configuration option is enabled. On start,
+        any subscriptions requested again by `on_start` are not resubscribed.
+
+        Warnings
+        --------
+        Any `params` passed with the original subscriptions are not persisted,
+        and custom data type metadata values are persisted as strings.
+
+        """
+        if not self._is_persisting_subscriptions():
+            return
+
+        self._resubscribe(self._load_persisted_subscriptions(), None)
+
+    cdef list _load_persisted_subscriptions(self):
+        cdef bytes value = self.cache.get(self._subscriptions_key())
+        if value is None:
+            return []
+
+        return msgspec.json.decode(value)
+
+    cdef void _resubscribe(self, list specs, set skip):
+        if skip:
+            specs = [spec for spec in specs if (spec["kind"], spec["id"]) not in skip]
+        if not specs:
+            return
+
+        cdef:
+            dict spec
+            str kind
+            ClientId client_id
+        for spec in specs:
+            kind = spec["kind"]
+            client_id = ClientId(spec["client_id"]) if spec.get("client_id") else None
+            if kind == "data":
+                self.subscribe_data(
+                    DataType(resolve_path(spec["type"]), metadata=spec["metadata"]),
+                    client_id,
+                )
+            elif kind == "instruments":
+                self.subscribe_instruments(Venue(spec["id"]), client_id)
+            elif kind == "instrument":
+                self.subscribe_instrument(InstrumentId.from_str_c(spec["id"]), client_id)
+            elif kind == "order_book_deltas":
+                self.subscribe_order_book_deltas(
+                    instrument_id=InstrumentId.from_str_c(spec["id"]),
+                    book_type=spec["book_type"],
+                    depth=spec["depth"],
+                    client_id=client_id,
+                    managed=spec["managed"],
+                )
+            elif kind == "order_book_at_interval":
+                self.subscribe_order_book_at_interval(
+                    instrument_id=InstrumentId.from_str_c(spec["instrument_id"]),
+                    book_type=spec["book_type"],
+                    depth=spec["depth"],
+                    interval_ms=spec["interval_ms"],
+                    client_id=client_id,
+                    managed=spec["managed"],
+                )
+            elif kind == "quote_ticks":
+                self.subscribe_quote_ticks(InstrumentId.from_str_c(spec["id"]), client_id)
+            elif kind == "trade_ticks":
+                self.subscribe_trade_ticks(InstrumentId.from_str_c(spec["id"]), client_id)
+            elif kind == "bars":
+                self.subscribe_bars(
+                    BarType.from_str(spec["id"]),
+                    client_id,
+                    await_partial=spec["await_partial"],
+                )
+            elif kind == "instrument_status":
+                self.subscribe_instrument_status(InstrumentId.from_str_c(spec["id"]), client_id)
+            elif kind == "instrument_close":
+                self.subscribe_instrument_close(InstrumentId.from_str_c(spec["id"]), client_id)
+            else:
+                self._log.error(f"Cannot resubscribe: unrecognized persisted subscription {spec}")
+
+        self._log.info(f"Resubscribed to {len(specs)} persisted subscription(s)", LogColor.BLUE)
+
+    cdef bint _is_persisting_subscriptions(self):
+        return getattr(self.config, "persist_subscriptions", False) and self.cache is not None
+
+    cdef str _subscriptions_key(self):
+        return f"subscriptions:{self.id.value}"
+
+    cdef void _record_subscription(
+        self,
+        str kind,
+        str identifier,
+        ClientId client_id,
+        dict extra = None,
+    ):
+        if not self._is_persisting_subscriptions():
+            return
+
+        cdef dict spec = {
+            "kind": kind,
+            "id": identifier,
+            "client_id": client_id.value if client_id is not None else None,
+        }
+        if extra:
+            spec.update(extra)
+
+        self._persisted_subscriptions[(kind, identifier)] = spec
+        self._persist_subscriptions()
+
+        if self._requested_on_start is not None:
+            self._requested_on_start.add((kind, identifier))
+
+    cdef void _remove_subscription(self, str kind, str identifier):
+        if not self._is_persisting_subscriptions():
+            return
+
+        if self._persisted_subscriptions.pop((kind, identifier), None) is not None:
+            self._persist_subscriptions()
+
+    cdef void _persist_subscriptions(self):
+        self.cache.add(
+            self._subscriptions_key(),
+            msgspec.json.encode(list(self._persisted_subscriptions.values())),
+        )
+
 # -- REQUESTS -------------------------------------------------------------------------------------
 
     cpdef UUID4 request_data(
@@ -13,9 +13,11 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from nautilus_trader.common.messages import SetInstrumentTrading
 from nautilus_trader.common.messages import ShutdownSystem
 
 
 __all__ = [
+    "SetInstrumentTrading",
     "ShutdownSystem",
 ]
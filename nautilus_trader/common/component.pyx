@@ -2084,6 +2084,8 @@ cdef class MessageBus:
         self._log.info(f"{config.use_trader_id=}", LogColor.BLUE)
         self._log.info(f"{config.use_instance_id=}", LogColor.BLUE)
         self._log.info(f"{config.streams_prefix=}", LogColor.BLUE)
+        self._log.info(f"{config.topics_filter=}", LogColor.BLUE)
+        self._log.info(f"{config.stream_maxlen=}", LogColor.BLUE)
         self._log.info(f"{config.types_filter=}", LogColor.BLUE)
 
         # Copy and clear `types_filter` before passing down to the core MessageBus
@@ -2421,6 +2423,8 @@ cdef class MessageBus:
             The priority for the subscription. Determines the ordering of
             handlers receiving messages being processed, higher priority
             handlers will receive messages prior to lower priority handlers.
+            Handlers with equal priority receive messages in the order they
+            were subscribed.
 
         Raises
         ------
@@ -2460,9 +2464,10 @@ cdef class MessageBus:
         cdef str pattern
         cdef list subs
         for pattern in patterns:
-            if is_matching(topic, pattern):
+            if is_matching(pattern, topic):
                 subs = list(self._patterns[pattern])
                 subs.append(sub)
+                # Stable sort, so subscription order is retained within a priority
                 subs = sorted(subs, reverse=True)
                 self._patterns[pattern] = np.ascontiguousarray(subs, dtype=Subscription)
                 matches.append(pattern)
@@ -2523,7 +2528,7 @@ cdef class MessageBus:
         Publish the given message for the given `topic`.
 
         Subscription handlers will receive the message in priority order
-        (highest first).
+        (highest first), then in the order they were subscribed.
 
         Parameters
         ----------
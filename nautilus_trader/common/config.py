@@ -330,6 +330,15 @@ class MessageBusConfig(NautilusConfig, frozen=True):
     external_streams : list[str], optional
         The external stream keys the node will listen to for publishing deserialized message
         payloads on the internal message bus.
+    replay_external_streams : bool, default False
+        If external streams should be replayed from their beginning on startup (rather than
+        from the current time), before continuing to stream new messages.
+    topics_filter : list[str], optional
+        A list of topic patterns (which may contain '*' and '?' wildcards) to publish externally.
+        If None, then all publishable topics will be published.
+    stream_maxlen : PositiveInt, optional
+        The approximate maximum number of entries to retain per stream.
+        Streams are trimmed on each write, independently of `autotrim_mins`.
     types_filter : list[type], optional
         A list of serializable types **not** to publish externally.
     heartbeat_interval_secs : PositiveInt, optional
@@ -348,6 +357,9 @@ class MessageBusConfig(NautilusConfig, frozen=True):
     streams_prefix: str = "stream"
     stream_per_topic: bool = True
     external_streams: list[str] | None = None
+    replay_external_streams: bool = False
+    topics_filter: list[str] | None = None
+    stream_maxlen: PositiveInt | None = None
     types_filter: list[type] | None = None
     heartbeat_interval_secs: PositiveInt | None = None
 
@@ -413,10 +425,14 @@ class ActorConfig(NautilusConfig, kw_only=True, frozen=True):
     component_id : ComponentId, optional
         The component ID. If ``None`` then the identifier will be taken from
         `type(self).__name__`.
+    persist_subscriptions : bool, default False
+        If the actor's data subscriptions should be persisted in the cache, and
+        automatically resubscribed on start (prior to `on_start`).
 
     """
 
     component_id: ComponentId | None = None
+    persist_subscriptions: bool = False
 
 
 class ImportableActorConfig(NautilusConfig, frozen=True):
@@ -14,13 +14,17 @@
 # -------------------------------------------------------------------------------------------------
 
 from nautilus_trader.common.component import TimeEvent
+from nautilus_trader.common.messages import CommandExpired
 from nautilus_trader.common.messages import ComponentStateChanged
+from nautilus_trader.common.messages import InstrumentConflictResolved
 from nautilus_trader.common.messages import RiskEvent
 from nautilus_trader.common.messages import TradingStateChanged
 
 
 __all__ = [
+    "CommandExpired",
     "ComponentStateChanged",
+    "InstrumentConflictResolved",
     "RiskEvent",
     "TimeEvent",
     "TradingStateChanged",
@@ -20,8 +20,11 @@ from nautilus_trader.core.message cimport Event
 from nautilus_trader.core.rust.common cimport ComponentState
 from nautilus_trader.core.rust.model cimport TradingState
 from nautilus_trader.core.uuid cimport UUID4
+from nautilus_trader.model.identifiers cimport ClientId
 from nautilus_trader.model.identifiers cimport ComponentId
 from nautilus_trader.model.identifiers cimport Identifier
+from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.identifiers cimport TraderId
 
 
@@ -43,6 +46,28 @@ cdef class ShutdownSystem(Command):
     cdef dict to_dict_c(ShutdownSystem obj)
 
 
+cdef class SetInstrumentTrading(Command):
+    cdef UUID4 _command_id
+    cdef uint64_t _ts_init
+
+    cdef readonly TraderId trader_id
+    """The trader ID associated with the command.\n\n:returns: `TraderId`"""
+    cdef readonly InstrumentId instrument_id
+    """The instrument ID for the command.\n\n:returns: `InstrumentId`"""
+    cdef readonly bint enabled
+    """If trading should be enabled for the instrument.\n\n:returns: `bool`"""
+    cdef readonly bint cancel_orders
+    """If open orders for the instrument should be canceled when disabling trading.\n\n:returns: `bool`"""
+    cdef readonly str reason
+    """The reason for the command.\n\n:returns: `str` or ``None``"""
+
+    @staticmethod
+    cdef SetInstrumentTrading from_dict_c(dict values)
+
+    @staticmethod
+    cdef dict to_dict_c(SetInstrumentTrading obj)
+
+
 cdef class ComponentStateChanged(Event):
     cdef UUID4 _event_id
     cdef uint64_t _ts_event
@@ -86,3 +111,51 @@ cdef class TradingStateChanged(RiskEvent):
 
     @staticmethod
     cdef dict to_dict_c(TradingStateChanged obj)
+
+
+cdef class InstrumentConflictResolved(Event):
+    cdef UUID4 _event_id
+    cdef uint64_t _ts_event
+    cdef uint64_t _ts_init
+
+    cdef readonly InstrumentId instrument_id
+    """The instrument ID for the conflicting definitions.\n\n:returns: `InstrumentId`"""
+    cdef readonly str policy
+    """The instrument conflict policy which was applied.\n\n:returns: `str`"""
+    cdef readonly str resolution
+    """The resolution chosen by the policy.\n\n:returns: `str`"""
+    cdef readonly ClientId existing_client_id
+    """The client ID which provided the existing definition.\n\n:returns: `ClientId` or ``None``"""
+    cdef readonly ClientId incoming_client_id
+    """The client ID which provided the incoming definition.\n\n:returns: `ClientId` or ``None``"""
+
+    @staticmethod
+    cdef InstrumentConflictResolved from_dict_c(dict values)
+
+    @staticmethod
+    cdef dict to_dict_c(InstrumentConflictResolved obj)
+
+
+cdef class CommandExpired(Event):
+    cdef UUID4 _event_id
+    cdef uint64_t _ts_event
+    cdef uint64_t _ts_init
+
+    cdef readonly TraderId trader_id
+    """The trader ID associated with the event.\n\n:returns: `TraderId`"""
+    cdef readonly StrategyId strategy_id
+    """The strategy ID which sent the expired command.\n\n:returns: `StrategyId`"""
+    cdef readonly InstrumentId instrument_id
+    """The instrument ID for the expired command.\n\n:returns: `InstrumentId`"""
+    cdef readonly str command_type
+    """The type name of the expired command.\n\n:returns: `str`"""
+    cdef readonly UUID4 command_id
+    """The ID of the expired command.\n\n:returns: `UUID4`"""
+    cdef readonly str reason
+    """The reason the command was aborted.\n\n:returns: `str`"""
+
+    @staticmethod
+    cdef CommandExpired from_dict_c(dict values)
+
+    @staticmethod
+    cdef dict to_dict_c(CommandExpired obj)
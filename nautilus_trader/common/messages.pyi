@@ -20,7 +20,9 @@ from nautilus_trader.core.message import Command
 from nautilus_trader.core.message import Event
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.model.enums import TradingState
+from nautilus_trader.model.identifiers import ClientId
 from nautilus_trader.model.identifiers import ComponentId
+from nautilus_trader.model.identifiers import InstrumentId
 from nautilus_trader.model.identifiers import TraderId
 
 class ShutdownSystem(Command):
@@ -86,3 +88,25 @@ class TradingStateChanged(RiskEvent):
     def from_dict(values: dict[str, Any]) -> TradingStateChanged: ...
     @staticmethod
     def to_dict(obj: TradingStateChanged) -> dict[str, Any]: ...
+
+class InstrumentConflictResolved(Event):
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def policy(self) -> str: ...
+    @property
+    def resolution(self) -> str: ...
+    @property
+    def existing_client_id(self) -> ClientId | None: ...
+    @property
+    def incoming_client_id(self) -> ClientId | None: ...
+    @property
+    def id(self) -> UUID4: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @staticmethod
+    def from_dict(values: dict[str, Any]) -> InstrumentConflictResolved: ...
+    @staticmethod
+    def to_dict(obj: InstrumentConflictResolved) -> dict[str, Any]: ...
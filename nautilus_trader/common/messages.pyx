@@ -28,8 +28,11 @@ from nautilus_trader.core.rust.model cimport TradingState
 from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.model.functions cimport trading_state_from_str
 from nautilus_trader.model.functions cimport trading_state_to_str
+from nautilus_trader.model.identifiers cimport ClientId
 from nautilus_trader.model.identifiers cimport ComponentId
 from nautilus_trader.model.identifiers cimport Identifier
+from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.identifiers cimport TraderId
 
 
@@ -144,6 +147,133 @@ cdef class ShutdownSystem(Command):
         return ShutdownSystem.to_dict_c(obj)
 
 
+cdef class SetInstrumentTrading(Command):
+    """
+    Represents a command to enable or disable trading for an instrument at runtime.
+
+    Parameters
+    ----------
+    trader_id : TraderId
+        The trader ID associated with the command.
+    instrument_id : InstrumentId
+        The instrument ID for the command.
+    enabled : bool
+        If trading should be enabled for the instrument.
+    command_id : UUID4
+        The command ID.
+    ts_init : uint64_t
+        UNIX timestamp (nanoseconds) when the object was initialized.
+    cancel_orders : bool, default False
+        If open orders for the instrument should be canceled when disabling trading.
+    reason : str, optional
+        The reason for the command (can be None).
+    """
+
+    def __init__(
+        self,
+        TraderId trader_id not None,
+        InstrumentId instrument_id not None,
+        bint enabled,
+        UUID4 command_id not None,
+        uint64_t ts_init,
+        bint cancel_orders = False,
+        str reason = None,
+    ) -> None:
+        super().__init__(command_id, ts_init)
+        self.trader_id = trader_id
+        self.instrument_id = instrument_id
+        self.enabled = enabled
+        self.cancel_orders = cancel_orders
+        self.reason = reason
+        self._command_id = command_id
+        self._ts_init = ts_init
+
+    def __eq__(self, Command other) -> bool:
+        return self._command_id == other.id
+
+    def __hash__(self) -> int:
+        return hash(self._command_id)
+
+    def __str__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"trader_id={self.trader_id.to_str()}, "
+            f"instrument_id={self.instrument_id.to_str()}, "
+            f"enabled={self.enabled}, "
+            f"cancel_orders={self.cancel_orders}, "
+            f"reason='{self.reason}', "
+            f"command_id={self._command_id.to_str()})"
+        )
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"trader_id={self.trader_id.to_str()}, "
+            f"instrument_id={self.instrument_id.to_str()}, "
+            f"enabled={self.enabled}, "
+            f"cancel_orders={self.cancel_orders}, "
+            f"reason='{self.reason}', "
+            f"command_id={self._command_id.to_str()}, "
+            f"ts_init={self._ts_init})"
+        )
+
+    @staticmethod
+    cdef SetInstrumentTrading from_dict_c(dict values):
+        Condition.not_none(values, "values")
+        return SetInstrumentTrading(
+            trader_id=TraderId(values["trader_id"]),
+            instrument_id=InstrumentId.from_str_c(values["instrument_id"]),
+            enabled=values["enabled"],
+            cancel_orders=values["cancel_orders"],
+            reason=values["reason"],
+            command_id=UUID4(values["command_id"]),
+            ts_init=values["ts_init"],
+        )
+
+    @staticmethod
+    cdef dict to_dict_c(SetInstrumentTrading obj):
+        Condition.not_none(obj, "obj")
+        return {
+            "type": "SetInstrumentTrading",
+            "trader_id": obj.trader_id.to_str(),
+            "instrument_id": obj.instrument_id.to_str(),
+            "enabled": obj.enabled,
+            "cancel_orders": obj.cancel_orders,
+            "reason": obj.reason,
+            "command_id": obj._command_id.to_str(),
+            "ts_init": obj._ts_init,
+        }
+
+    @staticmethod
+    def from_dict(dict values) -> SetInstrumentTrading:
+        """
+        Return a set instrument trading command from the given dict values.
+
+        Parameters
+        ----------
+        values : dict[str, object]
+            The values for initialization.
+
+        Returns
+        -------
+        SetInstrumentTrading
+
+        """
+        return SetInstrumentTrading.from_dict_c(values)
+
+    @staticmethod
+    def to_dict(SetInstrumentTrading obj):
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        return SetInstrumentTrading.to_dict_c(obj)
+
+
 
 cdef class ComponentStateChanged(Event):
     """
@@ -529,3 +659,351 @@ cdef class TradingStateChanged(RiskEvent):
 
         """
         return TradingStateChanged.to_dict_c(obj)
+
+
+cdef class InstrumentConflictResolved(Event):
+    """
+    Represents an event where conflicting definitions for the same instrument
+    were received from different clients, and were resolved by the `Cache`.
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID for the conflicting definitions.
+    policy : str
+        The instrument conflict policy which was applied.
+    resolution : str {'REPLACED', 'KEPT_EXISTING'}
+        The resolution chosen by the policy.
+    existing_client_id : ClientId, optional
+        The client ID which provided the existing definition (if known).
+    incoming_client_id : ClientId, optional
+        The client ID which provided the incoming definition (if known).
+    event_id : UUID4
+        The event ID.
+    ts_event : uint64_t
+        UNIX timestamp (nanoseconds) when the conflict resolution occurred.
+    ts_init : uint64_t
+        UNIX timestamp (nanoseconds) when the object was initialized.
+    """
+
+    def __init__(
+        self,
+        InstrumentId instrument_id not None,
+        str policy not None,
+        str resolution not None,
+        ClientId existing_client_id: ClientId | None,
+        ClientId incoming_client_id: ClientId | None,
+        UUID4 event_id not None,
+        uint64_t ts_event,
+        uint64_t ts_init,
+    ) -> None:
+        self.instrument_id = instrument_id
+        self.policy = policy
+        self.resolution = resolution
+        self.existing_client_id = existing_client_id
+        self.incoming_client_id = incoming_client_id
+        self._event_id = event_id
+        self._ts_event = ts_event
+        self._ts_init = ts_init
+
+    def __eq__(self, Event other) -> bool:
+        return self._event_id == other.id
+
+    def __hash__(self) -> int:
+        return hash(self._event_id)
+
+    def __str__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id.to_str()}, "
+            f"policy={self.policy}, "
+            f"resolution={self.resolution}, "
+            f"existing_client_id={self.existing_client_id}, "
+            f"incoming_client_id={self.incoming_client_id}, "
+            f"event_id={self._event_id.to_str()})"
+        )
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id.to_str()}, "
+            f"policy={self.policy}, "
+            f"resolution={self.resolution}, "
+            f"existing_client_id={self.existing_client_id}, "
+            f"incoming_client_id={self.incoming_client_id}, "
+            f"event_id={self._event_id.to_str()}, "
+            f"ts_init={self._ts_init})"
+        )
+
+    @property
+    def id(self) -> UUID4:
+        """
+        The event message identifier.
+
+        Returns
+        -------
+        UUID4
+
+        """
+        return self._event_id
+
+    @property
+    def ts_event(self) -> int:
+        """
+        UNIX timestamp (nanoseconds) when the event occurred.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._ts_event
+
+    @property
+    def ts_init(self) -> int:
+        """
+        UNIX timestamp (nanoseconds) when the object was initialized.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._ts_init
+
+    @staticmethod
+    cdef InstrumentConflictResolved from_dict_c(dict values):
+        Condition.not_none(values, "values")
+        cdef str existing_client_id = values["existing_client_id"]
+        cdef str incoming_client_id = values["incoming_client_id"]
+        return InstrumentConflictResolved(
+            instrument_id=InstrumentId.from_str_c(values["instrument_id"]),
+            policy=values["policy"],
+            resolution=values["resolution"],
+            existing_client_id=ClientId(existing_client_id) if existing_client_id is not None else None,
+            incoming_client_id=ClientId(incoming_client_id) if incoming_client_id is not None else None,
+            event_id=UUID4(values["event_id"]),
+            ts_event=values["ts_event"],
+            ts_init=values["ts_init"],
+        )
+
+    @staticmethod
+    cdef dict to_dict_c(InstrumentConflictResolved obj):
+        Condition.not_none(obj, "obj")
+        return {
+            "type": "InstrumentConflictResolved",
+            "instrument_id": obj.instrument_id.to_str(),
+            "policy": obj.policy,
+            "resolution": obj.resolution,
+            "existing_client_id": obj.existing_client_id.to_str() if obj.existing_client_id is not None else None,
+            "incoming_client_id": obj.incoming_client_id.to_str() if obj.incoming_client_id is not None else None,
+            "event_id": obj._event_id.to_str(),
+            "ts_event": obj._ts_event,
+            "ts_init": obj._ts_init,
+        }
+
+    @staticmethod
+    def from_dict(dict values) -> InstrumentConflictResolved:
+        """
+        Return an instrument conflict resolved event from the given dict values.
+
+        Parameters
+        ----------
+        values : dict[str, object]
+            The values for initialization.
+
+        Returns
+        -------
+        InstrumentConflictResolved
+
+        """
+        return InstrumentConflictResolved.from_dict_c(values)
+
+    @staticmethod
+    def to_dict(InstrumentConflictResolved obj):
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        return InstrumentConflictResolved.to_dict_c(obj)
+
+
+cdef class CommandExpired(Event):
+    """
+    Represents an event where a trading command passed its deadline before it
+    could be sent to the venue, and was aborted by the `ExecutionEngine`.
+
+    Only emitted for commands which have no order event to report the failure.
+
+    Parameters
+    ----------
+    trader_id : TraderId
+        The trader ID for the event.
+    strategy_id : StrategyId
+        The strategy ID which sent the expired command.
+    instrument_id : InstrumentId
+        The instrument ID for the expired command.
+    command_type : str
+        The type name of the expired command.
+    command_id : UUID4
+        The ID of the expired command.
+    reason : str
+        The reason the command was aborted.
+    event_id : UUID4
+        The event ID.
+    ts_event : uint64_t
+        UNIX timestamp (nanoseconds) when the command was aborted.
+    ts_init : uint64_t
+        UNIX timestamp (nanoseconds) when the object was initialized.
+    """
+
+    def __init__(
+        self,
+        TraderId trader_id not None,
+        StrategyId strategy_id not None,
+        InstrumentId instrument_id not None,
+        str command_type not None,
+        UUID4 command_id not None,
+        str reason not None,
+        UUID4 event_id not None,
+        uint64_t ts_event,
+        uint64_t ts_init,
+    ) -> None:
+        self.trader_id = trader_id
+        self.strategy_id = strategy_id
+        self.instrument_id = instrument_id
+        self.command_type = command_type
+        self.command_id = command_id
+        self.reason = reason
+        self._event_id = event_id
+        self._ts_event = ts_event
+        self._ts_init = ts_init
+
+    def __eq__(self, Event other) -> bool:
+        return self._event_id == other.id
+
+    def __hash__(self) -> int:
+        return hash(self._event_id)
+
+    def __str__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id.to_str()}, "
+            f"command_type={self.command_type}, "
+            f"command_id={self.command_id.to_str()}, "
+            f"reason='{self.reason}', "
+            f"event_id={self._event_id.to_str()})"
+        )
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"trader_id={self.trader_id.to_str()}, "
+            f"strategy_id={self.strategy_id.to_str()}, "
+            f"instrument_id={self.instrument_id.to_str()}, "
+            f"command_type={self.command_type}, "
+            f"command_id={self.command_id.to_str()}, "
+            f"reason='{self.reason}', "
+            f"event_id={self._event_id.to_str()}, "
+            f"ts_init={self._ts_init})"
+        )
+
+    @property
+    def id(self) -> UUID4:
+        """
+        The event message identifier.
+
+        Returns
+        -------
+        UUID4
+
+        """
+        return self._event_id
+
+    @property
+    def ts_event(self) -> int:
+        """
+        UNIX timestamp (nanoseconds) when the event occurred.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._ts_event
+
+    @property
+    def ts_init(self) -> int:
+        """
+        UNIX timestamp (nanoseconds) when the object was initialized.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._ts_init
+
+    @staticmethod
+    cdef CommandExpired from_dict_c(dict values):
+        Condition.not_none(values, "values")
+        return CommandExpired(
+            trader_id=TraderId(values["trader_id"]),
+            strategy_id=StrategyId(values["strategy_id"]),
+            instrument_id=InstrumentId.from_str_c(values["instrument_id"]),
+            command_type=values["command_type"],
+            command_id=UUID4(values["command_id"]),
+            reason=values["reason"],
+            event_id=UUID4(values["event_id"]),
+            ts_event=values["ts_event"],
+            ts_init=values["ts_init"],
+        )
+
+    @staticmethod
+    cdef dict to_dict_c(CommandExpired obj):
+        Condition.not_none(obj, "obj")
+        return {
+            "type": "CommandExpired",
+            "trader_id": obj.trader_id.to_str(),
+            "strategy_id": obj.strategy_id.to_str(),
+            "instrument_id": obj.instrument_id.to_str(),
+            "command_type": obj.command_type,
+            "command_id": obj.command_id.to_str(),
+            "reason": obj.reason,
+            "event_id": obj._event_id.to_str(),
+            "ts_event": obj._ts_event,
+            "ts_init": obj._ts_init,
+        }
+
+    @staticmethod
+    def from_dict(dict values) -> CommandExpired:
+        """
+        Return a command expired event from the given dict values.
+
+        Parameters
+        ----------
+        values : dict[str, object]
+            The values for initialization.
+
+        Returns
+        -------
+        CommandExpired
+
+        """
+        return CommandExpired.from_dict_c(values)
+
+    @staticmethod
+    def to_dict(CommandExpired obj):
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        return CommandExpired.to_dict_c(obj)
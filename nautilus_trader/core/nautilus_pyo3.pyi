@@ -151,6 +151,23 @@ class CustomData:
     @property
     def ts_init(self) -> int: ...
 
+###################################################################################################
+# Analysis
+###################################################################################################
+
+class MonteCarloResampler:
+    def __init__(
+        self,
+        num_samples: int,
+        confidence_level: float,
+        random_seed: int | None = None,
+    ) -> None: ...
+    @property
+    def num_samples(self) -> int: ...
+    @property
+    def confidence_level(self) -> float: ...
+    def resample(self, pnls: list[float]) -> dict[str, float]: ...
+
 ###################################################################################################
 # Cryptography
 ###################################################################################################
@@ -761,6 +778,8 @@ class BarAggregation(Enum):
     DAY = "DAY"
     WEEK = "WEEK"
     MONTH = "MONTH"
+    RENKO = "RENKO"
+    RANGE = "RANGE"
 
 class BookAction(Enum):
     ADD = "ADD"
@@ -2839,6 +2858,7 @@ class OrderBook:
     def add(self, order: BookOrder, flags: int, sequence: int, ts_event: int) -> None: ...
     def update(self, order: BookOrder, flags: int, sequence: int, ts_event: int) -> None: ...
     def delete(self, order: BookOrder, flags: int, sequence: int, ts_event: int) -> None: ...
+    def remove_order(self, order_id: int, sequence: int, ts_event: int) -> bool: ...
     def clear(self, sequence: int, ts_event: int) -> None: ...
     def clear_bids(self, sequence: int, ts_event: int) -> None: ...
     def clear_asks(self, sequence: int, ts_event: int) -> None: ...
@@ -2851,6 +2871,12 @@ class OrderBook:
     def asks_to_dict(self, depth: int | None = None) -> dict[Decimal, Decimal]: ...
     def group_bids(self, group_size: Decimal, depth: int | None = None) -> dict[Decimal, Decimal]: ...
     def group_asks(self, group_size: Decimal, depth: int | None = None) -> dict[Decimal, Decimal]: ...
+    def get_order(self, order_id: int) -> BookOrder | None: ...
+    def orders_at_price(self, side: OrderSide, price: Price) -> list[BookOrder]: ...
+    def queue_position(self, order_id: int) -> int | None: ...
+    def size_ahead(self, order_id: int) -> Quantity | None: ...
+    def check_integrity(self) -> None: ...
+    def set_sequence_checks(self, enabled: bool) -> None: ...
     def best_bid_price(self) -> Price | None: ...
     def best_ask_price(self) -> Price | None: ...
     def best_bid_size(self) -> Quantity | None: ...
@@ -2859,6 +2885,10 @@ class OrderBook:
     def midpoint(self) -> float | None: ...
     def get_avg_px_for_quantity(self, qty: Quantity, order_side: OrderSide) -> float: ...
     def get_quantity_for_price(self, price: Price, order_side: OrderSide) -> float: ...
+    def imbalance(self, depth: int | None = None) -> float | None: ...
+    def volume_weighted_midpoint(self, depth: int | None = None) -> float | None: ...
+    def cumulative_depth(self, side: OrderSide, offsets: list[float]) -> list[float]: ...
+    def sweep_cost(self, qty: Quantity, order_side: OrderSide) -> tuple[float, float, float, float, float, float] | None: ...
     def simulate_fills(self, order: BookOrder) -> list[tuple[Price, Quantity]]: ...
     def pprint(self, num_levels: int) -> str: ...
 
@@ -2892,6 +2922,8 @@ class RedisCacheDatabase:
         instance_id: UUID4,
         config: dict[str, Any],
     ) -> None: ...
+    def read(self, key: str) -> list[bytes]: ...
+    def read_range(self, key: str, min: int, max: int) -> list[bytes]: ...
 
 class PostgresCacheDatabase:
     @classmethod
@@ -2911,6 +2943,7 @@ class PostgresCacheDatabase:
     def load_instrument(self, instrument_id: InstrumentId) -> Instrument | None: ...
     def load_instruments(self) -> list[Instrument]: ...
     def load_order(self, client_order_id: ClientOrderId) -> Order | None: ...
+    def load_fills(self, instrument_id: InstrumentId, start: int | None = None, end: int | None = None) -> list[OrderFilled]: ...
     def load_account(self, account_id: AccountId) -> Account | None: ...
     def load_trades(self, instrument_id: InstrumentId) -> list[TradeTick]: ...
     def load_quotes(self, instrument_id: InstrumentId) -> list[QuoteTick]: ...
@@ -2941,6 +2974,9 @@ class HttpError(Exception):
 class HttpTimeoutError(Exception):
     ...
 
+class HttpRateLimitError(Exception):
+    ...
+
 class HttpClient:
     def __init__(
         self,
@@ -2948,6 +2984,9 @@ class HttpClient:
         header_keys: list[str] | None = None,
         keyed_quotas: list[tuple[str, Quota]] | None = None,
         default_quota: Quota | None = None,
+        global_quota: Quota | None = None,
+        reject_when_limited: bool = False,
+        max_retries: int = 0,
     ) -> None: ...
     async def request(
         self,
@@ -2957,6 +2996,7 @@ class HttpClient:
         body: bytes | None = None,
         keys: list[str] | None = None,
         timeout_secs: int | None = None,
+        weight: int | None = None,
     ) -> HttpResponse: ...
 
 class HttpMethod(Enum):
@@ -2982,6 +3022,8 @@ class Quota:
     @classmethod
     def rate_per_hour(cls, max_burst: int) -> Quota: ...
 
+UNLIMITED_RECONNECTION_TRIES: Final[int]
+
 class WebSocketClientError(Exception):
     ...
 
@@ -2994,7 +3036,12 @@ class WebSocketConfig:
         heartbeat: int | None = None,
         heartbeat_msg: str | None = None,
         ping_handler: Callable[..., Any] | None = None,
-        max_reconnection_tries: int | None = None,
+        max_reconnection_tries: int | None = 3,
+        reconnect_delay_initial_ms: int | None = None,
+        reconnect_delay_max_ms: int | None = None,
+        reconnect_backoff_factor: float | None = None,
+        reconnect_jitter_ms: int | None = None,
+        state_handler: Callable[[str], None] | None = None,
     ) -> None: ...
 
 class WebSocketClient:
@@ -3010,8 +3057,12 @@ class WebSocketClient:
     ) -> Awaitable[WebSocketClient]: ...
     def disconnect(self) -> Awaitable[None]: ...
     def is_alive(self) -> bool: ...
+    def connection_state(self) -> str: ...
+    def subscriptions(self) -> list[str]: ...
     def send(self, data: bytes, keys: list[str] | None = None) -> Awaitable[None]: ...
     def send_text(self, data: bytes, keys: list[str] | None = None) -> Awaitable[None]: ...
+    def send_subscription(self, data: bytes, keys: list[str] | None = None) -> Awaitable[None]: ...
+    def remove_subscription(self, data: bytes) -> None: ...
     def send_pong(self, data: bytes) -> Awaitable[None]: ...
 
 class SocketClient:
@@ -3035,6 +3086,12 @@ class SocketConfig:
         suffix: bytes,
         handler: Callable[..., Any],
         heartbeat: tuple[int, list[int]] | None = None,
+        length_prefix_width: int | None = None,
+        max_reconnection_tries: int | None = 3,
+        reconnect_delay_initial_ms: int | None = None,
+        reconnect_delay_max_ms: int | None = None,
+        reconnect_backoff_factor: float | None = None,
+        reconnect_jitter_ms: int | None = None,
     ) -> None: ...
 
 ###################################################################################################
@@ -4310,6 +4367,7 @@ class DatabentoLiveClient:
         start: int | None = None,
         snapshot: bool | None = False,
     ) -> dict[str, str]: ...
+    def subscriptions(self, schema: str) -> list[str]: ...
     def start(
         self,
         callback: Callable,
@@ -4394,6 +4452,7 @@ class BlackScholesGreeksResult:
     gamma: float
     vega: float
     theta: float
+    rho: float
 
 class ImplyVolAndGreeksResult:
     vol: float
@@ -4402,6 +4461,43 @@ class ImplyVolAndGreeksResult:
     gamma: float
     vega: float
     theta: float
+    rho: float
+
+
+def black76_greeks(
+    f: float,
+    r: float,
+    sigma: float,
+    is_call: bool,
+    k: float,
+    t: float,
+    multiplier: float,
+) -> BlackScholesGreeksResult:
+    """
+    Calculate the Black-76 Greeks for an option on a futures or forward contract.
+
+    Parameters
+    ----------
+    f : float
+        The current price of the underlying futures or forward contract.
+    r : float
+        The risk-free interest rate.
+    sigma : float
+        The volatility of the underlying asset.
+    is_call : bool
+        Whether the option is a call (True) or a put (False).
+    k : float
+        The strike price of the option.
+    t : float
+        The time to expiration of the option in years.
+    multiplier : float
+        The multiplier for the option contract.
+
+    Returns
+    -------
+    BlackScholesGreeksResult
+        A named tuple containing the calculated option price, delta, gamma, vega, theta, and rho.
+    """
 
 
 def black_scholes_greeks(
@@ -4439,7 +4535,7 @@ def black_scholes_greeks(
     Returns
     -------
     BlackScholesGreeksResult
-        A named tuple containing the calculated option price, delta, gamma, vega, and theta.
+        A named tuple containing the calculated option price, delta, gamma, vega, theta, and rho.
     """
 
 
@@ -4477,7 +4573,7 @@ def imply_vol(
     Returns
     -------
     float
-        An implied volatility value.
+        An implied volatility value, or NaN if the price is outside the no-arbitrage bounds.
     """
 
 
@@ -4516,7 +4612,7 @@ def imply_vol_and_greeks(
     Returns
     -------
     ImplyVolAndGreeksResult
-        A named tuple containing the calculated implied volatility, option price, delta, gamma, vega, and theta
+        A named tuple containing the calculated implied volatility, option price, delta, gamma, vega, theta, and rho
     """
 
 
@@ -15,12 +15,15 @@
 
 from cpython.datetime cimport datetime
 from cpython.datetime cimport timedelta
+from libc.stdint cimport int64_t
 from libc.stdint cimport uint8_t
 from libc.stdint cimport uint64_t
 
 from nautilus_trader.common.component cimport Clock
 from nautilus_trader.common.component cimport Logger
 from nautilus_trader.common.component cimport TimeEvent
+from nautilus_trader.core.rust.model cimport AggressorSide
+from nautilus_trader.model.book cimport OrderBook
 from nautilus_trader.model.data cimport Bar
 from nautilus_trader.model.data cimport BarType
 from nautilus_trader.model.data cimport QuoteTick
@@ -72,6 +75,7 @@ cdef class BarAggregator:
 
     cpdef void handle_quote_tick(self, QuoteTick tick)
     cpdef void handle_trade_tick(self, TradeTick tick)
+    cpdef void handle_order_book(self, OrderBook book)
     cpdef void handle_bar(self, Bar bar)
     cpdef void set_partial(self, Bar partial_bar)
     cdef void _apply_update(self, Price price, Quantity size, uint64_t ts_event)
@@ -94,6 +98,45 @@ cdef class ValueBarAggregator(BarAggregator):
     cpdef object get_cumulative_value(self)
 
 
+cdef class RenkoBarAggregator(BarAggregator):
+    cdef uint8_t _price_precision
+    cdef int64_t _brick_size_raw
+    cdef int64_t _brick_close_raw
+    cdef int _direction
+    cdef bint _initialized
+
+    cdef void _check_bricks(self, int64_t price_raw, uint64_t ts_event)
+    cdef void _send_brick(self, int64_t open_raw, int64_t close_raw, uint64_t ts_event)
+
+
+cdef class RangeBarAggregator(BarAggregator):
+    cdef int64_t _range_raw
+
+
+cdef class InformationBarAggregator(BarAggregator):
+    cdef AggressorSide _aggressor_side
+    cdef int64_t _last_price_raw
+    cdef int _last_sign
+
+    cdef int _get_sign(self, int64_t price_raw)
+    cdef double _get_measure(self, Price price, Quantity size)
+    cdef void _apply_signed_update(self, int sign, double measure)
+
+
+cdef class ImbalanceBarAggregator(InformationBarAggregator):
+    cdef double _imbalance
+
+    cpdef double get_imbalance(self)
+
+
+cdef class RunsBarAggregator(InformationBarAggregator):
+    cdef double _buy_run
+    cdef double _sell_run
+
+    cpdef double get_buy_run(self)
+    cpdef double get_sell_run(self)
+
+
 cdef class TimeBarAggregator(BarAggregator):
     cdef Clock _clock
     cdef bint _build_on_next_tick
@@ -20,6 +20,7 @@ import pandas as pd
 
 from cpython.datetime cimport datetime
 from cpython.datetime cimport timedelta
+from libc.stdint cimport int64_t
 from libc.stdint cimport uint64_t
 
 from nautilus_trader.core.datetime import unix_nanos_to_dt
@@ -30,6 +31,9 @@ from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.datetime cimport dt_to_unix_nanos
 from nautilus_trader.core.rust.core cimport millis_to_nanos
 from nautilus_trader.core.rust.core cimport secs_to_nanos
+from nautilus_trader.core.rust.model cimport AggressorSide
+from nautilus_trader.core.rust.model cimport PriceType
+from nautilus_trader.model.book cimport OrderBook
 from nautilus_trader.model.data cimport Bar
 from nautilus_trader.model.data cimport BarAggregation
 from nautilus_trader.model.data cimport BarType
@@ -354,6 +358,55 @@ cdef class BarAggregator:
                 ts_event=tick.ts_event,
             )
 
+    cpdef void handle_order_book(self, OrderBook book):
+        """
+        Update the aggregator with the top-of-book of the given order book.
+
+        The price and size are sourced from the best bid, best ask or mid
+        according to the price type of the bar specification. If the
+        required side(s) of the book are empty then the update is ignored.
+
+        Parameters
+        ----------
+        book : OrderBook
+            The order book for the update.
+
+        Raises
+        ------
+        ValueError
+            If the bar specification price type is ``LAST``.
+
+        """
+        Condition.not_none(book, "book")
+        Condition.not_equal(self.bar_type.spec.price_type, PriceType.LAST, "price_type", "LAST")
+
+        if self._await_partial:
+            return
+
+        cdef PriceType price_type = self.bar_type.spec.price_type
+        cdef Price bid_price = book.best_bid_price() if price_type != PriceType.ASK else None
+        cdef Price ask_price = book.best_ask_price() if price_type != PriceType.BID else None
+        cdef Quantity bid_size
+        cdef Quantity ask_size
+        if price_type == PriceType.BID:
+            if bid_price is None:
+                return
+            self._apply_update(bid_price, book.best_bid_size(), book.ts_last)
+        elif price_type == PriceType.ASK:
+            if ask_price is None:
+                return
+            self._apply_update(ask_price, book.best_ask_size(), book.ts_last)
+        else:
+            if bid_price is None or ask_price is None:
+                return
+            bid_size = book.best_bid_size()
+            ask_size = book.best_ask_size()
+            self._apply_update(
+                Price.from_raw_c((bid_price._mem.raw + ask_price._mem.raw) / 2, bid_price._mem.precision + 1),
+                Quantity.from_raw_c((bid_size._mem.raw + ask_size._mem.raw) / 2, bid_size._mem.precision + 1),
+                book.ts_last,
+            )
+
     cpdef void handle_bar(self, Bar bar):
         """
         Update the aggregator with the given bar.
@@ -658,6 +711,382 @@ cdef class ValueBarAggregator(BarAggregator):
             assert volume_update >= 0
 
 
+cdef class RenkoBarAggregator(BarAggregator):
+    """
+    Provides a means of building Renko bars from ticks.
+
+    A brick is formed each time the price moves a full brick size beyond the
+    close of the previous brick, where the brick size is the step of the bar
+    specification multiplied by the instruments price increment. A reversal
+    requires the price to move two brick sizes from the previous brick close.
+
+    The open and close of each bar are the brick boundaries, and the volume
+    accumulated since the last brick is assigned to the first brick formed.
+
+    Parameters
+    ----------
+    instrument : Instrument
+        The instrument for the aggregator.
+    bar_type : BarType
+        The bar type for the aggregator.
+    handler : Callable[[Bar], None]
+        The bar handler for the aggregator.
+
+    Raises
+    ------
+    ValueError
+        If `instrument.id` != `bar_type.instrument_id`.
+    """
+
+    def __init__(
+        self,
+        Instrument instrument not None,
+        BarType bar_type not None,
+        handler not None: Callable[[Bar], None],
+    ):
+        super().__init__(
+            instrument=instrument,
+            bar_type=bar_type.standard(),
+            handler=handler,
+        )
+
+        self._price_precision = instrument.price_precision
+        self._brick_size_raw = instrument.price_increment._mem.raw * self.bar_type.spec.step
+        self._brick_close_raw = 0
+        self._direction = 0
+        self._initialized = False
+
+    cdef void _apply_update(self, Price price, Quantity size, uint64_t ts_event):
+        self._builder.update(price, size, ts_event)
+        self._check_bricks(price._mem.raw, ts_event)
+
+    cdef void _apply_update_bar(self, Bar bar, Quantity volume, uint64_t ts_init):
+        self._builder.update_bar(bar, volume, ts_init)
+        self._check_bricks(bar._mem.close.raw, ts_init)
+
+    cdef void _check_bricks(self, int64_t price_raw, uint64_t ts_event):
+        cdef int64_t brick = self._brick_size_raw
+        cdef int64_t open_raw
+        if not self._initialized:
+            self._brick_close_raw = price_raw
+            self._initialized = True
+            return
+
+        while True:
+            if self._direction >= 0 and price_raw >= self._brick_close_raw + brick:
+                open_raw = self._brick_close_raw
+                self._direction = 1
+            elif self._direction < 0 and price_raw >= self._brick_close_raw + 2 * brick:
+                open_raw = self._brick_close_raw + brick
+                self._direction = 1
+            elif self._direction <= 0 and price_raw <= self._brick_close_raw - brick:
+                open_raw = self._brick_close_raw
+                self._direction = -1
+            elif self._direction > 0 and price_raw <= self._brick_close_raw - 2 * brick:
+                open_raw = self._brick_close_raw - brick
+                self._direction = -1
+            else:
+                break
+
+            self._brick_close_raw = open_raw + self._direction * brick
+            self._send_brick(open_raw, self._brick_close_raw, ts_event)
+
+    cdef void _send_brick(self, int64_t open_raw, int64_t close_raw, uint64_t ts_event):
+        cdef Bar bar = Bar(
+            bar_type=self.bar_type,
+            open=Price.from_raw_c(open_raw, self._price_precision),
+            high=Price.from_raw_c(max(open_raw, close_raw), self._price_precision),
+            low=Price.from_raw_c(min(open_raw, close_raw), self._price_precision),
+            close=Price.from_raw_c(close_raw, self._price_precision),
+            volume=self._builder.volume,
+            ts_event=ts_event,
+            ts_init=ts_event,
+        )
+        self._builder.reset()
+        self._handler(bar)
+
+
+cdef class RangeBarAggregator(BarAggregator):
+    """
+    Provides a means of building range bars from ticks.
+
+    When the high-low range of the bar reaches the step of the bar specification
+    multiplied by the instruments price increment, then a bar is created and
+    sent to the handler.
+
+    Parameters
+    ----------
+    instrument : Instrument
+        The instrument for the aggregator.
+    bar_type : BarType
+        The bar type for the aggregator.
+    handler : Callable[[Bar], None]
+        The bar handler for the aggregator.
+
+    Raises
+    ------
+    ValueError
+        If `instrument.id` != `bar_type.instrument_id`.
+    """
+
+    def __init__(
+        self,
+        Instrument instrument not None,
+        BarType bar_type not None,
+        handler not None: Callable[[Bar], None],
+    ):
+        super().__init__(
+            instrument=instrument,
+            bar_type=bar_type.standard(),
+            handler=handler,
+        )
+
+        self._range_raw = instrument.price_increment._mem.raw * self.bar_type.spec.step
+
+    cdef void _apply_update(self, Price price, Quantity size, uint64_t ts_event):
+        self._builder.update(price, size, ts_event)
+
+        if self._builder._high._mem.raw - self._builder._low._mem.raw >= self._range_raw:
+            self._build_now_and_send()
+
+    cdef void _apply_update_bar(self, Bar bar, Quantity volume, uint64_t ts_init):
+        self._builder.update_bar(bar, volume, ts_init)
+
+        if self._builder._high._mem.raw - self._builder._low._mem.raw >= self._range_raw:
+            self._build_now_and_send()
+
+
+cdef class InformationBarAggregator(BarAggregator):
+    """
+    Provides a base for building information-driven bars from signed ticks.
+
+    Each update is signed using the aggressor side of trades where available,
+    otherwise the tick rule is applied (an uptick is a buy, a downtick is a sell,
+    and an unchanged price carries the previous sign).
+
+    The measure of each update is one for ``TICK_*`` aggregations, the size for
+    ``VOLUME_*`` aggregations, and the notional value for ``VALUE_*`` aggregations.
+
+    Parameters
+    ----------
+    instrument : Instrument
+        The instrument for the aggregator.
+    bar_type : BarType
+        The bar type for the aggregator.
+    handler : Callable[[Bar], None]
+        The bar handler for the aggregator.
+
+    Raises
+    ------
+    ValueError
+        If `instrument.id` != `bar_type.instrument_id`.
+    """
+
+    def __init__(
+        self,
+        Instrument instrument not None,
+        BarType bar_type not None,
+        handler not None: Callable[[Bar], None],
+    ):
+        super().__init__(
+            instrument=instrument,
+            bar_type=bar_type.standard(),
+            handler=handler,
+        )
+
+        self._aggressor_side = AggressorSide.NO_AGGRESSOR
+        self._last_price_raw = 0
+        self._last_sign = 0
+
+    cpdef void handle_trade_tick(self, TradeTick tick):
+        """
+        Update the aggregator with the given tick.
+
+        Parameters
+        ----------
+        tick : TradeTick
+            The tick for the update.
+
+        """
+        Condition.not_none(tick, "tick")
+
+        if not self._await_partial:
+            self._aggressor_side = tick.aggressor_side
+            self._apply_update(
+                price=tick.price,
+                size=tick.size,
+                ts_event=tick.ts_event,
+            )
+            self._aggressor_side = AggressorSide.NO_AGGRESSOR
+
+    cdef void _apply_update(self, Price price, Quantity size, uint64_t ts_event):
+        cdef int sign = self._get_sign(price._mem.raw)
+        self._builder.update(price, size, ts_event)
+        self._apply_signed_update(sign, self._get_measure(price, size))
+
+    cdef void _apply_update_bar(self, Bar bar, Quantity volume, uint64_t ts_init):
+        cdef int sign = self._get_sign(bar._mem.close.raw)
+        self._builder.update_bar(bar, volume, ts_init)
+        self._apply_signed_update(sign, self._get_measure(bar.close, volume))
+
+    cdef int _get_sign(self, int64_t price_raw):
+        if self._aggressor_side == AggressorSide.BUYER:
+            self._last_sign = 1
+        elif self._aggressor_side == AggressorSide.SELLER:
+            self._last_sign = -1
+        elif self._last_price_raw != 0 and price_raw > self._last_price_raw:
+            self._last_sign = 1
+        elif self._last_price_raw != 0 and price_raw < self._last_price_raw:
+            self._last_sign = -1
+
+        self._last_price_raw = price_raw
+        return self._last_sign
+
+    cdef double _get_measure(self, Price price, Quantity size):
+        cdef BarAggregation aggregation = self.bar_type.spec.aggregation
+        if aggregation == BarAggregation.TICK_IMBALANCE or aggregation == BarAggregation.TICK_RUNS:
+            return 1.0
+        elif aggregation == BarAggregation.VOLUME_IMBALANCE or aggregation == BarAggregation.VOLUME_RUNS:
+            return size.as_f64_c()
+        else:
+            return size.as_f64_c() * price.as_f64_c()
+
+    cdef void _apply_signed_update(self, int sign, double measure):
+        raise NotImplementedError("method `_apply_signed_update` must be implemented in the subclass")  # pragma: no cover
+
+
+cdef class ImbalanceBarAggregator(InformationBarAggregator):
+    """
+    Provides a means of building imbalance bars from ticks.
+
+    When the absolute value of the signed cumulative measure (the buy/sell
+    imbalance) reaches the step threshold of the bar specification, then a bar
+    is created and sent to the handler.
+
+    Supports ``TICK_IMBALANCE``, ``VOLUME_IMBALANCE`` and ``VALUE_IMBALANCE``.
+
+    Parameters
+    ----------
+    instrument : Instrument
+        The instrument for the aggregator.
+    bar_type : BarType
+        The bar type for the aggregator.
+    handler : Callable[[Bar], None]
+        The bar handler for the aggregator.
+
+    Raises
+    ------
+    ValueError
+        If `instrument.id` != `bar_type.instrument_id`.
+    """
+
+    def __init__(
+        self,
+        Instrument instrument not None,
+        BarType bar_type not None,
+        handler not None: Callable[[Bar], None],
+    ):
+        super().__init__(
+            instrument=instrument,
+            bar_type=bar_type,
+            handler=handler,
+        )
+
+        self._imbalance = 0.0
+
+    cpdef double get_imbalance(self):
+        """
+        Return the current signed imbalance of the aggregator.
+
+        Returns
+        -------
+        double
+
+        """
+        return self._imbalance
+
+    cdef void _apply_signed_update(self, int sign, double measure):
+        self._imbalance += sign * measure
+
+        if abs(self._imbalance) >= self.bar_type.spec.step:
+            self._build_now_and_send()
+            self._imbalance = 0.0
+
+
+cdef class RunsBarAggregator(InformationBarAggregator):
+    """
+    Provides a means of building runs bars from ticks.
+
+    When the larger of the cumulative buy measure and cumulative sell measure
+    reaches the step threshold of the bar specification, then a bar is created
+    and sent to the handler.
+
+    Supports ``TICK_RUNS``, ``VOLUME_RUNS`` and ``VALUE_RUNS``.
+
+    Parameters
+    ----------
+    instrument : Instrument
+        The instrument for the aggregator.
+    bar_type : BarType
+        The bar type for the aggregator.
+    handler : Callable[[Bar], None]
+        The bar handler for the aggregator.
+
+    Raises
+    ------
+    ValueError
+        If `instrument.id` != `bar_type.instrument_id`.
+    """
+
+    def __init__(
+        self,
+        Instrument instrument not None,
+        BarType bar_type not None,
+        handler not None: Callable[[Bar], None],
+    ):
+        super().__init__(
+            instrument=instrument,
+            bar_type=bar_type,
+            handler=handler,
+        )
+
+        self._buy_run = 0.0
+        self._sell_run = 0.0
+
+    cpdef double get_buy_run(self):
+        """
+        Return the current cumulative buy measure of the aggregator.
+
+        Returns
+        -------
+        double
+
+        """
+        return self._buy_run
+
+    cpdef double get_sell_run(self):
+        """
+        Return the current cumulative sell measure of the aggregator.
+
+        Returns
+        -------
+        double
+
+        """
+        return self._sell_run
+
+    cdef void _apply_signed_update(self, int sign, double measure):
+        if sign > 0:
+            self._buy_run += measure
+        elif sign < 0:
+            self._sell_run += measure
+
+        if max(self._buy_run, self._sell_run) >= self.bar_type.spec.step:
+            self._build_now_and_send()
+            self._buy_run = 0.0
+            self._sell_run = 0.0
+
+
 cdef class TimeBarAggregator(BarAggregator):
     """
     Provides a means of building time bars from ticks with an internal timer.
@@ -180,7 +180,11 @@ cdef class DataClient(Component):
 # -- DATA HANDLERS --------------------------------------------------------------------------------
 
     cpdef void _handle_data(self, Data data):
-        self._msgbus.send(endpoint="DataEngine.process", msg=data)
+        if isinstance(data, Instrument):
+            # Instrument definitions are attributed to this client for conflict resolution
+            self._msgbus.send(endpoint=f"DataEngine.process.{self.id}", msg=data)
+        else:
+            self._msgbus.send(endpoint="DataEngine.process", msg=data)
 
     cpdef void _handle_data_response(self, DataType data_type, data, UUID4 correlation_id, dict[str, object] params):
         cdef DataResponse response = DataResponse(
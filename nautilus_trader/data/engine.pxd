@@ -45,6 +45,7 @@ from nautilus_trader.model.data cimport OrderBookDepth10
 from nautilus_trader.model.data cimport QuoteTick
 from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.identifiers cimport ClientId
+from nautilus_trader.model.identifiers cimport Identifier
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport Venue
 from nautilus_trader.model.instruments.base cimport Instrument
@@ -59,8 +60,10 @@ cdef class DataEngine(Component):
 
     cdef readonly dict[ClientId, DataClient] _clients
     cdef readonly dict[Venue, DataClient] _routing_map
+    cdef readonly dict[ClientId, object] _client_process_handlers
     cdef readonly dict _order_book_intervals
     cdef readonly dict[BarType, BarAggregator] _bar_aggregators
+    cdef readonly dict[InstrumentId, list[BarAggregator]] _book_bar_aggregators
     cdef readonly dict[InstrumentId, list[SyntheticInstrument]] _synthetic_quote_feeds
     cdef readonly dict[InstrumentId, list[SyntheticInstrument]] _synthetic_trade_feeds
     cdef readonly list[InstrumentId] _subscribed_synthetic_quotes
@@ -69,6 +72,7 @@ cdef class DataEngine(Component):
     cdef readonly dict[str, SnapshotInfo] _snapshot_info
     cdef readonly dict[UUID4, int] _query_group_n_components
     cdef readonly dict[UUID4, list] _query_group_components
+    cdef readonly dict[Identifier, list[Subscribe]] _component_subscriptions
 
     cdef readonly bint _time_bars_build_with_no_updates
     cdef readonly bint _time_bars_timestamp_on_close
@@ -116,18 +120,21 @@ cdef class DataEngine(Component):
     cpdef list subscribed_instrument_close(self)
     cpdef list subscribed_synthetic_quotes(self)
     cpdef list subscribed_synthetic_trades(self)
+    cpdef list component_subscriptions(self, Identifier component_id)
 
 # -- COMMANDS -------------------------------------------------------------------------------------
 
     cpdef void stop_clients(self)
     cpdef void execute(self, DataCommand command)
-    cpdef void process(self, Data data)
+    cpdef void process(self, Data data, ClientId client_id=*)
     cpdef void request(self, DataRequest request)
     cpdef void response(self, DataResponse response)
+    cpdef void unsubscribe_all(self, Identifier component_id)
 
 # -- COMMAND HANDLERS -----------------------------------------------------------------------------
 
     cpdef void _execute_command(self, DataCommand command)
+    cpdef void _track_subscription(self, DataCommand command)
     cpdef void _handle_subscribe(self, DataClient client, Subscribe command)
     cpdef void _handle_unsubscribe(self, DataClient client, Unsubscribe command)
     cpdef void _handle_subscribe_instrument(self, MarketDataClient client, InstrumentId instrument_id, dict params)
@@ -166,8 +173,8 @@ cdef class DataEngine(Component):
 
 # -- DATA HANDLERS --------------------------------------------------------------------------------
 
-    cpdef void _handle_data(self, Data data)
-    cpdef void _handle_instrument(self, Instrument instrument, bint update_catalog=*)
+    cpdef void _handle_data(self, Data data, ClientId client_id=*)
+    cpdef void _handle_instrument(self, Instrument instrument, bint update_catalog=*, ClientId client_id=*)
     cpdef void _handle_order_book_delta(self, OrderBookDelta delta)
     cpdef void _handle_order_book_deltas(self, OrderBookDeltas deltas)
     cpdef void _handle_order_book_depth(self, OrderBookDepth10 depth)
@@ -181,7 +188,7 @@ cdef class DataEngine(Component):
 # -- RESPONSE HANDLERS ----------------------------------------------------------------------------
 
     cpdef void _handle_response(self, DataResponse response)
-    cpdef void _handle_instruments(self, list instruments, bint update_catalog=*)
+    cpdef void _handle_instruments(self, list instruments, bint update_catalog=*, ClientId client_id=*)
     cpdef void _update_catalog(self, list ticks, bint is_instrument=*)
     cpdef void _new_query_group(self, UUID4 correlation_id, int n_components)
     cpdef object _handle_query_group(self, UUID4 correlation_id, list ticks)
@@ -194,7 +201,7 @@ cdef class DataEngine(Component):
 
 # -- INTERNAL -------------------------------------------------------------------------------------
 
-    cpdef void _internal_update_instruments(self, list instruments)
+    cpdef void _internal_update_instruments(self, list instruments, ClientId client_id=*)
     cpdef void _update_order_book(self, Data data)
     cpdef void _snapshot_order_book(self, TimeEvent snap_event)
     cpdef void _publish_order_book(self, InstrumentId instrument_id, str topic)
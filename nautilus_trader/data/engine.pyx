@@ -29,6 +29,7 @@ Alternative implementations can be written on top of the generic engine - which
 just need to override the `execute`, `process`, `send` and `receive` methods.
 """
 
+from functools import partial
 from typing import Callable
 
 from nautilus_trader.common.enums import LogColor
@@ -49,6 +50,7 @@ from nautilus_trader.common.component cimport Clock
 from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.component cimport MessageBus
 from nautilus_trader.common.component cimport TestClock
+from nautilus_trader.common.messages cimport InstrumentConflictResolved
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
 from nautilus_trader.core.datetime cimport dt_to_unix_nanos
@@ -61,6 +63,10 @@ from nautilus_trader.core.rust.model cimport BookType
 from nautilus_trader.core.rust.model cimport PriceType
 from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.data.aggregation cimport BarAggregator
+from nautilus_trader.data.aggregation cimport ImbalanceBarAggregator
+from nautilus_trader.data.aggregation cimport RangeBarAggregator
+from nautilus_trader.data.aggregation cimport RenkoBarAggregator
+from nautilus_trader.data.aggregation cimport RunsBarAggregator
 from nautilus_trader.data.aggregation cimport TickBarAggregator
 from nautilus_trader.data.aggregation cimport TimeBarAggregator
 from nautilus_trader.data.aggregation cimport ValueBarAggregator
@@ -131,11 +137,13 @@ cdef class DataEngine(Component):
 
         self._clients: dict[ClientId, DataClient] = {}
         self._routing_map: dict[Venue, DataClient] = {}
+        self._client_process_handlers: dict[ClientId, Callable[[Data], None]] = {}
         self._default_client: DataClient | None = None
         self._external_clients: set[ClientId] = set()
         self._catalogs: dict[str, ParquetDataCatalog] = {}
         self._order_book_intervals: dict[tuple[InstrumentId, int], list[Callable[[OrderBook], None]]] = {}
         self._bar_aggregators: dict[BarType, BarAggregator] = {}
+        self._book_bar_aggregators: dict[InstrumentId, list[BarAggregator]] = {}
         self._synthetic_quote_feeds: dict[InstrumentId, list[SyntheticInstrument]] = {}
         self._synthetic_trade_feeds: dict[InstrumentId, list[SyntheticInstrument]] = {}
         self._subscribed_synthetic_quotes: list[InstrumentId] = []
@@ -144,6 +152,7 @@ cdef class DataEngine(Component):
         self._snapshot_info: dict[str, SnapshotInfo] = {}
         self._query_group_n_components: dict[UUID4, int] = {}
         self._query_group_components: dict[UUID4, list] = {}
+        self._component_subscriptions: dict[Identifier, list[Subscribe]] = {}
 
         # Settings
         self.debug = config.debug
@@ -168,6 +177,7 @@ cdef class DataEngine(Component):
         self._msgbus.register(endpoint="DataEngine.process", handler=self.process)
         self._msgbus.register(endpoint="DataEngine.request", handler=self.request)
         self._msgbus.register(endpoint="DataEngine.response", handler=self.response)
+        self._msgbus.register(endpoint="DataEngine.unsubscribe_all", handler=self.unsubscribe_all)
 
     @property
     def registered_clients(self) -> list[ClientId]:
@@ -277,6 +287,11 @@ cdef class DataEngine(Component):
 
         self._clients[client.id] = client
 
+        # Data processed through the client endpoint is attributed to the client
+        handler = partial(self.process, client_id=client.id)
+        self._client_process_handlers[client.id] = handler
+        self._msgbus.register(endpoint=f"DataEngine.process.{client.id}", handler=handler)
+
         routing_log = ""
         if client.venue is None:
             if self._default_client is None:
@@ -345,6 +360,11 @@ cdef class DataEngine(Component):
         Condition.is_in(client.id, self._clients, "client.id", "self._clients")
 
         del self._clients[client.id]
+
+        handler = self._client_process_handlers.pop(client.id, None)
+        if handler is not None:
+            self._msgbus.deregister(endpoint=f"DataEngine.process.{client.id}", handler=handler)
+
         self._log.info(f"Deregistered {client}")
 
 # -- SUBSCRIPTIONS --------------------------------------------------------------------------------
@@ -506,6 +526,24 @@ cdef class DataEngine(Component):
         """
         return self._subscribed_synthetic_trades.copy()
 
+    cpdef list component_subscriptions(self, Identifier component_id):
+        """
+        Return the active subscriptions made by the given component.
+
+        Parameters
+        ----------
+        component_id : Identifier
+            The component ID for the subscriptions.
+
+        Returns
+        -------
+        list[Subscribe]
+
+        """
+        Condition.not_none(component_id, "component_id")
+
+        return self._component_subscriptions.get(component_id, []).copy()
+
 # -- ABSTRACT METHODS -----------------------------------------------------------------------------
 
     cpdef void _on_start(self):
@@ -540,12 +578,14 @@ cdef class DataEngine(Component):
 
         self._order_book_intervals.clear()
         self._bar_aggregators.clear()
+        self._book_bar_aggregators.clear()
         self._synthetic_quote_feeds.clear()
         self._synthetic_trade_feeds.clear()
         self._subscribed_synthetic_quotes.clear()
         self._subscribed_synthetic_trades.clear()
         self._buffered_deltas_map.clear()
         self._snapshot_info.clear()
+        self._component_subscriptions.clear()
 
         self._clock.cancel_timers()
         self.command_count = 0
@@ -584,7 +624,7 @@ cdef class DataEngine(Component):
 
         self._execute_command(command)
 
-    cpdef void process(self, Data data):
+    cpdef void process(self, Data data, ClientId client_id = None):
         """
         Process the given data.
 
@@ -592,11 +632,13 @@ cdef class DataEngine(Component):
         ----------
         data : Data
             The data to process.
+        client_id : ClientId, optional
+            The client ID which provided the data (if known).
 
         """
         Condition.not_none(data, "data")
 
-        self._handle_data(data)
+        self._handle_data(data, client_id)
 
     cpdef void request(self, DataRequest request):
         """
@@ -626,6 +668,39 @@ cdef class DataEngine(Component):
 
         self._handle_response(response)
 
+    cpdef void unsubscribe_all(self, Identifier component_id):
+        """
+        Unsubscribe from all data subscriptions made by the given component.
+
+        Data clients are only unsubscribed where no other subscribers remain for the data.
+
+        Parameters
+        ----------
+        component_id : Identifier
+            The component ID to unsubscribe.
+
+        """
+        Condition.not_none(component_id, "component_id")
+
+        cdef list subscriptions = self._component_subscriptions.pop(component_id, [])
+        if not subscriptions:
+            return
+
+        self._log.info(f"Unsubscribing {len(subscriptions)} subscription(s) for {component_id}")
+
+        cdef Subscribe command
+        for command in subscriptions:
+            self._execute_command(
+                Unsubscribe(
+                    client_id=command.client_id,
+                    venue=command.venue,
+                    data_type=command.data_type,
+                    command_id=UUID4(),
+                    ts_init=self._clock.timestamp_ns(),
+                    params=command.params,
+                ),
+            )
+
 # -- COMMAND HANDLERS -----------------------------------------------------------------------------
 
     cpdef void _execute_command(self, DataCommand command):
@@ -655,6 +730,9 @@ cdef class DataEngine(Component):
                 )
                 return  # No client to handle command
 
+        if command.component_id is not None:
+            self._track_subscription(command)
+
         if isinstance(command, Subscribe):
             self._handle_subscribe(client, command)
         elif isinstance(command, Unsubscribe):
@@ -662,6 +740,20 @@ cdef class DataEngine(Component):
         else:
             self._log.error(f"Cannot handle command: unrecognized {command}")
 
+    cpdef void _track_subscription(self, DataCommand command):
+        cdef list subscriptions = self._component_subscriptions.setdefault(command.component_id, [])
+        cdef Subscribe existing
+        if isinstance(command, Subscribe):
+            for existing in subscriptions:
+                if existing.data_type == command.data_type:
+                    return  # Already tracked
+            subscriptions.append(command)
+        elif isinstance(command, Unsubscribe):
+            subscriptions[:] = [s for s in subscriptions if not s.data_type == command.data_type]
+
+        if not subscriptions:
+            self._component_subscriptions.pop(command.component_id, None)
+
     cpdef void _handle_subscribe(self, DataClient client, Subscribe command):
         if command.data_type.type == Instrument:
             self._handle_subscribe_instrument(
@@ -1745,7 +1837,7 @@ cdef class DataEngine(Component):
 
 # -- DATA HANDLERS --------------------------------------------------------------------------------
 
-    cpdef void _handle_data(self, Data data):
+    cpdef void _handle_data(self, Data data, ClientId client_id = None):
         self.data_count += 1
 
         if isinstance(data, OrderBookDelta):
@@ -1761,7 +1853,7 @@ cdef class DataEngine(Component):
         elif isinstance(data, Bar):
             self._handle_bar(data)
         elif isinstance(data, Instrument):
-            self._handle_instrument(data)
+            self._handle_instrument(data, client_id=client_id)
         elif isinstance(data, InstrumentStatus):
             self._handle_instrument_status(data)
         elif isinstance(data, InstrumentClose):
@@ -1771,8 +1863,29 @@ cdef class DataEngine(Component):
         else:
             self._log.error(f"Cannot handle data: unrecognized type {type(data)} {data}")
 
-    cpdef void _handle_instrument(self, Instrument instrument, bint update_catalog = False):
-        self._cache.add_instrument(instrument)
+    cpdef void _handle_instrument(
+        self,
+        Instrument instrument,
+        bint update_catalog = False,
+        ClientId client_id = None,
+    ):
+        cdef InstrumentConflictResolved conflict
+        try:
+            conflict = self._cache.add_instrument(instrument, client_id)
+        except ValueError as e:
+            self._log.error(f"Cannot handle instrument: {e}")
+            return
+
+        if conflict is not None:
+            self._msgbus.publish_c(
+                topic=f"events.instrument_conflict"
+                      f".{instrument.id.venue}"
+                      f".{instrument.id.symbol}",
+                msg=conflict,
+            )
+
+            if conflict.resolution == "KEPT_EXISTING":
+                return  # Incoming definition was discarded
 
         if update_catalog:
             self._update_catalog([instrument], is_instrument=True)
@@ -1797,7 +1910,7 @@ cdef class DataEngine(Component):
                 self._buffered_deltas_map[delta.instrument_id] = buffer_deltas
 
             buffer_deltas.append(delta)
-            is_last_delta = delta.flags == RecordFlag.F_LAST
+            is_last_delta = (delta.flags & RecordFlag.F_LAST) != 0
 
             if is_last_delta:
                 deltas = OrderBookDeltas(
@@ -1810,7 +1923,7 @@ cdef class DataEngine(Component):
                         f".{deltas.instrument_id.symbol}",
                     msg=deltas,
                 )
-                buffer_deltas.clear()
+                self._buffered_deltas_map.pop(delta.instrument_id, None)
         else:
             deltas = OrderBookDeltas(
                 instrument_id=delta.instrument_id,
@@ -1837,7 +1950,7 @@ cdef class DataEngine(Component):
 
             for delta in deltas.deltas:
                 buffer_deltas.append(delta)
-                is_last_delta = delta.flags == RecordFlag.F_LAST
+                is_last_delta = (delta.flags & RecordFlag.F_LAST) != 0
 
                 if is_last_delta:
                     deltas_to_publish = OrderBookDeltas(
@@ -1850,7 +1963,9 @@ cdef class DataEngine(Component):
                             f".{deltas.instrument_id.symbol}",
                         msg=deltas_to_publish,
                     )
-                    buffer_deltas.clear()
+                    # Start a new event buffer (trailing deltas await their own `F_LAST`)
+                    buffer_deltas = []
+                    self._buffered_deltas_map[deltas.instrument_id] = buffer_deltas
         else:
             self._msgbus.publish_c(
                 topic=f"data.book.deltas"
@@ -1860,6 +1975,8 @@ cdef class DataEngine(Component):
             )
 
     cpdef void _handle_order_book_depth(self, OrderBookDepth10 depth):
+        self._cache.add_depth10(depth)
+
         self._msgbus.publish_c(
             topic=f"data.book.depth"
                   f".{depth.instrument_id.venue}"
@@ -1979,9 +2096,9 @@ cdef class DataEngine(Component):
 
         if response.data_type.type == Instrument:
             if isinstance(response.data, list):
-                self._handle_instruments(response.data, update_catalog)
+                self._handle_instruments(response.data, update_catalog, response.client_id)
             else:
-                self._handle_instrument(response.data, update_catalog)
+                self._handle_instrument(response.data, update_catalog, response.client_id)
         elif response.data_type.type == QuoteTick:
             self._handle_quote_ticks(response.data)
         elif response.data_type.type == TradeTick:
@@ -2066,10 +2183,15 @@ cdef class DataEngine(Component):
 
         return result
 
-    cpdef void _handle_instruments(self, list instruments, bint update_catalog = False):
+    cpdef void _handle_instruments(
+        self,
+        list instruments,
+        bint update_catalog = False,
+        ClientId client_id = None,
+    ):
         cdef Instrument instrument
         for instrument in instruments:
-            self._handle_instrument(instrument, update_catalog)
+            self._handle_instrument(instrument, update_catalog, client_id)
 
     cpdef void _handle_quote_ticks(self, list ticks):
         self._cache.add_quote_ticks(ticks)
@@ -2167,6 +2289,30 @@ cdef class DataEngine(Component):
                         bar_type=bar_type,
                         handler=handler,
                     )
+                elif bar_type.spec.aggregation == BarAggregation.RENKO:
+                    aggregator = RenkoBarAggregator(
+                        instrument=instrument,
+                        bar_type=bar_type,
+                        handler=handler,
+                    )
+                elif bar_type.spec.aggregation == BarAggregation.RANGE:
+                    aggregator = RangeBarAggregator(
+                        instrument=instrument,
+                        bar_type=bar_type,
+                        handler=handler,
+                    )
+                elif bar_type.spec.is_information_aggregated():
+                    aggregator = RunsBarAggregator(
+                        instrument=instrument,
+                        bar_type=bar_type,
+                        handler=handler,
+                    )
+                elif bar_type.spec.is_threshold_aggregated():
+                    aggregator = ImbalanceBarAggregator(
+                        instrument=instrument,
+                        bar_type=bar_type,
+                        handler=handler,
+                    )
 
             if metadata["bars_market_data_type"] == "quote_ticks" and not bar_type.is_composite():
                 aggregator.start_batch_update(handler, ticks[0].ts_event)
@@ -2205,12 +2351,12 @@ cdef class DataEngine(Component):
 # -- INTERNAL -------------------------------------------------------------------------------------
 
     # Python wrapper to enable callbacks
-    cpdef void _internal_update_instruments(self, list instruments: [Instrument]):
+    cpdef void _internal_update_instruments(self, list instruments: [Instrument], ClientId client_id = None):
         # Handle all instruments individually
         cdef Instrument instrument
 
         for instrument in instruments:
-            self._handle_instrument(instrument)
+            self._handle_instrument(instrument, client_id=client_id)
 
     cpdef void _update_order_book(self, Data data):
         cdef OrderBook order_book = self._cache.order_book(data.instrument_id)
@@ -2219,6 +2365,14 @@ cdef class DataEngine(Component):
 
         order_book.apply(data)
 
+        cdef list book_aggregators = self._book_bar_aggregators.get(data.instrument_id)
+        if not book_aggregators:
+            return
+
+        cdef BarAggregator aggregator
+        for aggregator in book_aggregators:
+            aggregator.handle_order_book(order_book)
+
     cpdef void _snapshot_order_book(self, TimeEvent snap_event):
         if self.debug:
             self._log.debug(f"Received snapshot event for {snap_event}", LogColor.MAGENTA)
@@ -2300,6 +2454,30 @@ cdef class DataEngine(Component):
                 bar_type=bar_type,
                 handler=self.process,
             )
+        elif bar_type.spec.aggregation == BarAggregation.RENKO:
+            aggregator = RenkoBarAggregator(
+                instrument=instrument,
+                bar_type=bar_type,
+                handler=self.process,
+            )
+        elif bar_type.spec.aggregation == BarAggregation.RANGE:
+            aggregator = RangeBarAggregator(
+                instrument=instrument,
+                bar_type=bar_type,
+                handler=self.process,
+            )
+        elif bar_type.spec.is_information_aggregated():
+            aggregator = RunsBarAggregator(
+                instrument=instrument,
+                bar_type=bar_type,
+                handler=self.process,
+            )
+        elif bar_type.spec.is_threshold_aggregated():
+            aggregator = ImbalanceBarAggregator(
+                instrument=instrument,
+                bar_type=bar_type,
+                handler=self.process,
+            )
         else:
             raise RuntimeError(  # pragma: no cover (design-time error)
                 f"Cannot start aggregator: "  # pragma: no cover (design-time error)
@@ -2323,6 +2501,17 @@ cdef class DataEngine(Component):
                 handler=aggregator.handle_bar,
             )
             self._handle_subscribe_bars(client, composite_bar_type, False, params)
+        elif bar_type.spec.price_type != PriceType.LAST and params.get("book_type") is not None:
+            # Source prices from the top of the managed order book
+            self._book_bar_aggregators.setdefault(bar_type.instrument_id, []).append(aggregator)
+            self._handle_subscribe_order_book_deltas(
+                client,
+                bar_type.instrument_id,
+                params["book_type"],
+                0,
+                True,
+                params,
+            )
         elif bar_type.spec.price_type == PriceType.LAST:
             self._msgbus.subscribe(
                 topic=f"data.trades"
@@ -2363,6 +2552,12 @@ cdef class DataEngine(Component):
                 handler=aggregator.handle_bar,
             )
             self._handle_unsubscribe_bars(client, composite_bar_type, params)
+        elif aggregator in self._book_bar_aggregators.get(bar_type.instrument_id, []):
+            book_aggregators = self._book_bar_aggregators[bar_type.instrument_id]
+            book_aggregators.remove(aggregator)
+            if not book_aggregators:
+                del self._book_bar_aggregators[bar_type.instrument_id]
+                self._handle_unsubscribe_order_book_deltas(client, bar_type.instrument_id, params)
         elif bar_type.spec.price_type == PriceType.LAST:
             self._msgbus.unsubscribe(
                 topic=f"data.trades"
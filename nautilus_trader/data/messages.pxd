@@ -18,6 +18,7 @@ from nautilus_trader.core.message cimport Request
 from nautilus_trader.core.message cimport Response
 from nautilus_trader.model.data cimport DataType
 from nautilus_trader.model.identifiers cimport ClientId
+from nautilus_trader.model.identifiers cimport Identifier
 from nautilus_trader.model.identifiers cimport Venue
 
 
@@ -30,6 +31,8 @@ cdef class DataCommand(Command):
     """The command data type.\n\n:returns: `type`"""
     cdef readonly dict[str, object] params
     """Additional specific parameters for the command.\n\n:returns: `dict[str, object]` or ``None``"""
+    cdef readonly Identifier component_id
+    """The component ID which sent the command.\n\n:returns: `Identifier` or ``None``"""
 
 
 cdef class Subscribe(DataCommand):
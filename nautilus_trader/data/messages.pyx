@@ -41,6 +41,8 @@ cdef class DataCommand(Command):
         UNIX timestamp (nanoseconds) when the object was initialized.
     params : dict[str, object], optional
         Additional parameters for the command.
+    component_id : Identifier, optional
+        The component ID which sent the command.
 
     Raises
     ------
@@ -60,6 +62,7 @@ cdef class DataCommand(Command):
         UUID4 command_id not None,
         uint64_t ts_init,
         dict[str, object] params: dict | None = None,
+        Identifier component_id: Identifier | None = None,
     ):
         Condition.is_true(client_id or venue, "Both `client_id` and `venue` were None")
         super().__init__(command_id, ts_init)
@@ -68,6 +71,7 @@ cdef class DataCommand(Command):
         self.venue = venue
         self.data_type = data_type
         self.params = params or {}
+        self.component_id = component_id
 
     def __str__(self) -> str:
         return (
@@ -105,6 +109,8 @@ cdef class Subscribe(DataCommand):
         UNIX timestamp (nanoseconds) when the object was initialized.
     params : dict[str, object], optional
         Additional parameters for the subscription.
+    component_id : Identifier, optional
+        The component ID which sent the command.
 
     Raises
     ------
@@ -121,6 +127,7 @@ cdef class Subscribe(DataCommand):
         UUID4 command_id not None,
         uint64_t ts_init,
         dict[str, object] params: dict | None = None,
+        Identifier component_id: Identifier | None = None,
     ):
         super().__init__(
             client_id,
@@ -129,6 +136,7 @@ cdef class Subscribe(DataCommand):
             command_id,
             ts_init,
             params,
+            component_id,
         )
 
 
@@ -150,6 +158,8 @@ cdef class Unsubscribe(DataCommand):
         UNIX timestamp (nanoseconds) when the object was initialized.
     params : dict[str, object], optional
         Additional parameters for the subscription.
+    component_id : Identifier, optional
+        The component ID which sent the command.
 
     Raises
     ------
@@ -166,6 +176,7 @@ cdef class Unsubscribe(DataCommand):
         UUID4 command_id not None,
         uint64_t ts_init,
         dict[str, object] params: dict | None = None,
+        Identifier component_id: Identifier | None = None,
     ):
         super().__init__(
             client_id,
@@ -174,6 +185,7 @@ cdef class Unsubscribe(DataCommand):
             command_id,
             ts_init,
             params,
+            component_id,
         )
 
 
@@ -0,0 +1,347 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+"""
+Provides utilities for resampling stored tick and bar series to other granularities.
+
+The input series can be taken from either the `Cache` or a `ParquetDataCatalog`,
+and need not be sorted. Resampled bars are timestamped on the close of each interval,
+with each interval including data timestamped in the range (open, close].
+
+"""
+
+from __future__ import annotations
+
+from collections.abc import Iterable
+from datetime import timedelta
+from typing import NamedTuple
+
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.model.data import Bar
+from nautilus_trader.model.data import BarType
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import PriceType
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+class _Point(NamedTuple):
+    ts: int
+    open: Price
+    high: Price
+    low: Price
+    close: Price
+    volume: Quantity
+
+
+def resample_bars(
+    bars: Iterable[Bar],
+    bar_type: BarType,
+    drop_partial: bool = True,
+    fill_gaps: bool = False,
+) -> list[Bar]:
+    """
+    Resample the given time bars to the coarser interval of the given bar type.
+
+    Parameters
+    ----------
+    bars : Iterable[Bar]
+        The time bars to resample (all of the same bar type).
+    bar_type : BarType
+        The bar type to resample to.
+    drop_partial : bool, default True
+        If leading and trailing intervals which are only partially covered by
+        the source bars should be dropped.
+    fill_gaps : bool, default False
+        If intervals with no source bars should be filled with a flat bar at the
+        previous close with zero volume, otherwise no bar is created for the interval.
+
+    Returns
+    -------
+    list[Bar]
+        The resampled bars sorted by `ts_event` ascending.
+
+    Raises
+    ------
+    ValueError
+        If the source bars are not time bars of a single bar type.
+    ValueError
+        If `bar_type` is not for the same instrument and price type as the source bars.
+    ValueError
+        If the `bar_type` interval is not a multiple of the source bar interval.
+
+    """
+    bars = sorted(bars, key=lambda b: b.ts_event)
+    interval_ns = _interval_ns(bar_type)
+    if not bars:
+        return []
+
+    source_type = bars[0].bar_type
+    PyCondition.is_true(
+        all(b.bar_type == source_type for b in bars),
+        "source bars were not all of the same bar type",
+    )
+    PyCondition.equal(
+        bar_type.instrument_id,
+        source_type.instrument_id,
+        "bar_type.instrument_id",
+        "source_type.instrument_id",
+    )
+    PyCondition.equal(
+        bar_type.spec.price_type,
+        source_type.spec.price_type,
+        "bar_type.spec.price_type",
+        "source_type.spec.price_type",
+    )
+
+    source_interval_ns = _interval_ns(source_type)
+    PyCondition.is_true(
+        interval_ns % source_interval_ns == 0,
+        f"interval of {bar_type} was not a multiple of the source interval of {source_type}",
+    )
+
+    points = [_Point(b.ts_event, b.open, b.high, b.low, b.close, b.volume) for b in bars]
+
+    return _aggregate(
+        points=points,
+        bar_type=bar_type,
+        interval_ns=interval_ns,
+        start_ns=bars[0].ts_event - source_interval_ns,
+        end_ns=bars[-1].ts_event,
+        drop_partial=drop_partial,
+        fill_gaps=fill_gaps,
+    )
+
+
+def resample_trades(
+    trades: Iterable[TradeTick],
+    bar_type: BarType,
+    start_ns: int | None = None,
+    end_ns: int | None = None,
+    drop_partial: bool = True,
+    fill_gaps: bool = False,
+) -> list[Bar]:
+    """
+    Resample the given trades to time bars of the given bar type.
+
+    Parameters
+    ----------
+    trades : Iterable[TradeTick]
+        The trades to resample.
+    bar_type : BarType
+        The bar type to resample to (must have a price type of ``LAST``).
+    start_ns : int, optional
+        UNIX timestamp (nanoseconds) of the start of the period covered by the trades.
+        If ``None`` then will use the first trade timestamp.
+    end_ns : int, optional
+        UNIX timestamp (nanoseconds) of the end of the period covered by the trades.
+        If ``None`` then will use the last trade timestamp.
+    drop_partial : bool, default True
+        If leading and trailing intervals which are only partially covered by the
+        period should be dropped.
+    fill_gaps : bool, default False
+        If intervals with no trades should be filled with a flat bar at the
+        previous close with zero volume, otherwise no bar is created for the interval.
+
+    Returns
+    -------
+    list[Bar]
+        The resampled bars sorted by `ts_event` ascending.
+
+    Raises
+    ------
+    ValueError
+        If `bar_type` does not have a price type of ``LAST``.
+
+    """
+    PyCondition.equal(
+        bar_type.spec.price_type,
+        PriceType.LAST,
+        "bar_type.spec.price_type",
+        "PriceType.LAST",
+    )
+
+    trades = sorted(trades, key=lambda t: t.ts_event)
+    points = [
+        _Point(t.ts_event, t.price, t.price, t.price, t.price, t.size)
+        for t in trades
+        if t.instrument_id == bar_type.instrument_id
+    ]
+
+    return _aggregate_ticks(points, bar_type, start_ns, end_ns, drop_partial, fill_gaps)
+
+
+def resample_quotes(
+    quotes: Iterable[QuoteTick],
+    bar_type: BarType,
+    start_ns: int | None = None,
+    end_ns: int | None = None,
+    drop_partial: bool = True,
+    fill_gaps: bool = False,
+) -> list[Bar]:
+    """
+    Resample the given quotes to time bars of the given bar type.
+
+    The bar prices and volumes are extracted from the quotes for the price type
+    of the bar type.
+
+    Parameters
+    ----------
+    quotes : Iterable[QuoteTick]
+        The quotes to resample.
+    bar_type : BarType
+        The bar type to resample to (must have a price type of ``BID``, ``ASK`` or ``MID``).
+    start_ns : int, optional
+        UNIX timestamp (nanoseconds) of the start of the period covered by the quotes.
+        If ``None`` then will use the first quote timestamp.
+    end_ns : int, optional
+        UNIX timestamp (nanoseconds) of the end of the period covered by the quotes.
+        If ``None`` then will use the last quote timestamp.
+    drop_partial : bool, default True
+        If leading and trailing intervals which are only partially covered by the
+        period should be dropped.
+    fill_gaps : bool, default False
+        If intervals with no quotes should be filled with a flat bar at the
+        previous close with zero volume, otherwise no bar is created for the interval.
+
+    Returns
+    -------
+    list[Bar]
+        The resampled bars sorted by `ts_event` ascending.
+
+    Raises
+    ------
+    ValueError
+        If `bar_type` has a price type of ``LAST``.
+
+    """
+    price_type = bar_type.spec.price_type
+    PyCondition.not_equal(
+        price_type,
+        PriceType.LAST,
+        "bar_type.spec.price_type",
+        "PriceType.LAST",
+    )
+
+    quotes = sorted(quotes, key=lambda q: q.ts_event)
+    points = []
+    for quote in quotes:
+        if quote.instrument_id != bar_type.instrument_id:
+            continue
+        price = quote.extract_price(price_type)
+        points.append(
+            _Point(quote.ts_event, price, price, price, price, quote.extract_size(price_type)),
+        )
+
+    return _aggregate_ticks(points, bar_type, start_ns, end_ns, drop_partial, fill_gaps)
+
+
+def _aggregate_ticks(
+    points: list[_Point],
+    bar_type: BarType,
+    start_ns: int | None,
+    end_ns: int | None,
+    drop_partial: bool,
+    fill_gaps: bool,
+) -> list[Bar]:
+    interval_ns = _interval_ns(bar_type)
+    if not points:
+        return []
+
+    return _aggregate(
+        points=points,
+        bar_type=bar_type,
+        interval_ns=interval_ns,
+        start_ns=points[0].ts if start_ns is None else start_ns,
+        end_ns=points[-1].ts if end_ns is None else end_ns,
+        drop_partial=drop_partial,
+        fill_gaps=fill_gaps,
+    )
+
+
+def _interval_ns(bar_type: BarType) -> int:
+    PyCondition.is_true(
+        bar_type.spec.is_time_aggregated(),
+        f"{bar_type} was not time aggregated",
+    )
+    return bar_type.spec.timedelta // timedelta(microseconds=1) * 1_000
+
+
+def _close_time_ns(ts: int, interval_ns: int) -> int:
+    # Intervals include the close time, so a timestamp on a boundary closes the interval
+    return -(-ts // interval_ns) * interval_ns
+
+
+def _aggregate(
+    points: list[_Point],
+    bar_type: BarType,
+    interval_ns: int,
+    start_ns: int,
+    end_ns: int,
+    drop_partial: bool,
+    fill_gaps: bool,
+) -> list[Bar]:
+    # Assumes points are sorted by timestamp
+    buckets: dict[int, list[_Point]] = {}
+    for point in points:
+        buckets.setdefault(_close_time_ns(point.ts, interval_ns), []).append(point)
+
+    first_close = min(buckets)
+    last_close = max(buckets)
+
+    if drop_partial:
+        if start_ns > first_close - interval_ns:
+            first_close += interval_ns  # Leading interval only partially covered
+        if end_ns < last_close:
+            last_close -= interval_ns  # Trailing interval only partially covered
+
+    bars: list[Bar] = []
+    last_bar: Bar | None = None
+
+    for close_ns in range(first_close, last_close + 1, interval_ns):
+        bucket = buckets.get(close_ns)
+        if bucket is not None:
+            last_bar = _build_bar(bar_type, bucket, close_ns)
+            bars.append(last_bar)
+        elif fill_gaps and last_bar is not None:
+            last_bar = Bar(
+                bar_type=bar_type,
+                open=last_bar.close,
+                high=last_bar.close,
+                low=last_bar.close,
+                close=last_bar.close,
+                volume=Quantity.zero(last_bar.volume.precision),
+                ts_event=close_ns,
+                ts_init=close_ns,
+            )
+            bars.append(last_bar)
+
+    return bars
+
+
+def _build_bar(bar_type: BarType, bucket: list[_Point], close_ns: int) -> Bar:
+    return Bar(
+        bar_type=bar_type,
+        open=bucket[0].open,
+        high=max(p.high for p in bucket),
+        low=min(p.low for p in bucket),
+        close=bucket[-1].close,
+        volume=Quantity.from_raw(
+            sum(p.volume.raw for p in bucket),
+            bucket[0].volume.precision,
+        ),
+        ts_event=close_ns,
+        ts_init=close_ns,
+    )
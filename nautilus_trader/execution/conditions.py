@@ -0,0 +1,239 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+
+import ast
+import operator
+from collections.abc import Callable
+from typing import Any
+
+from nautilus_trader.core.correctness import PyCondition
+
+
+CONDITION_TAG_PREFIX = "CONDITION:"
+
+CONDITION_VARIABLES = frozenset(
+    {
+        "bid",
+        "ask",
+        "mid",
+        "last",
+        "spread",
+        "spread_ticks",
+        "bid_size",
+        "ask_size",
+        "imbalance",
+    },
+)
+
+_COMPARE_OPS: dict[type, Callable[[Any, Any], bool]] = {
+    ast.Gt: operator.gt,
+    ast.GtE: operator.ge,
+    ast.Lt: operator.lt,
+    ast.LtE: operator.le,
+    ast.Eq: operator.eq,
+    ast.NotEq: operator.ne,
+}
+
+_BINARY_OPS: dict[type, Callable[[Any, Any], Any]] = {
+    ast.Add: operator.add,
+    ast.Sub: operator.sub,
+    ast.Mult: operator.mul,
+    ast.Div: operator.truediv,
+}
+
+
+class TriggerCondition:
+    """
+    Represents a user-defined trigger condition for an emulated order.
+
+    The condition is a small boolean expression over the market state of the
+    orders trigger instrument, for example ``"imbalance > 0.3 and spread_ticks <= 2"``.
+    Expressions support comparisons, ``and``/``or``/``not``, arithmetic
+    (``+ - * /``), numeric literals and the following variables:
+
+    - ``bid``, ``ask``, ``mid``, ``last``: prices as floats.
+    - ``spread``: the top-of-book spread as a float.
+    - ``spread_ticks``: the top-of-book spread in price increments.
+    - ``bid_size``, ``ask_size``: the top-of-book sizes as floats.
+    - ``imbalance``: ``(bid_size - ask_size) / (bid_size + ask_size)`` in [-1, 1].
+
+    A condition evaluates ``False`` while any variable it references is unavailable.
+
+    Parameters
+    ----------
+    expression : str
+        The trigger expression.
+
+    Raises
+    ------
+    ValueError
+        If `expression` is not a valid string.
+    ValueError
+        If `expression` cannot be parsed, or contains unsupported syntax or variables.
+
+    """
+
+    def __init__(self, expression: str) -> None:
+        PyCondition.valid_string(expression, "expression")
+
+        try:
+            tree = ast.parse(expression.strip(), mode="eval")
+        except SyntaxError as e:
+            raise ValueError(f"invalid trigger expression {expression!r}: {e.msg}") from e
+
+        self._variables: set[str] = set()
+        self._validate(tree.body, expression)
+
+        self.expression = expression.strip()
+        self._tree = tree.body
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, TriggerCondition):
+            return False
+        return self.expression == other.expression
+
+    def __hash__(self) -> int:
+        return hash(self.expression)
+
+    def __repr__(self) -> str:
+        return f"{type(self).__name__}({self.expression!r})"
+
+    @property
+    def variables(self) -> frozenset[str]:
+        """
+        Return the market state variables referenced by the condition.
+
+        Returns
+        -------
+        frozenset[str]
+
+        """
+        return frozenset(self._variables)
+
+    @staticmethod
+    def from_tags(tags: list[str] | None) -> "TriggerCondition | None":
+        """
+        Return the trigger condition parsed from the given order tags (if found).
+
+        The condition is taken from the first tag starting with ``"CONDITION:"``.
+
+        Parameters
+        ----------
+        tags : list[str], optional
+            The order tags.
+
+        Returns
+        -------
+        TriggerCondition or ``None``
+
+        Raises
+        ------
+        ValueError
+            If the condition tag expression is invalid.
+
+        """
+        if not tags:
+            return None
+
+        for tag in tags:
+            if tag.startswith(CONDITION_TAG_PREFIX):
+                return TriggerCondition(tag[len(CONDITION_TAG_PREFIX) :])
+
+        return None
+
+    def evaluate(self, state: dict[str, float | None]) -> bool:
+        """
+        Evaluate the condition against the given market state.
+
+        Parameters
+        ----------
+        state : dict[str, float | None]
+            The market state variables, with ``None`` for unavailable values.
+
+        Returns
+        -------
+        bool
+
+        """
+        for name in self._variables:
+            if state.get(name) is None:
+                return False
+
+        try:
+            return bool(self._eval(self._tree, state))
+        except ZeroDivisionError:
+            return False
+
+    def _validate(self, node: ast.AST, expression: str) -> None:
+        if isinstance(node, ast.BoolOp):
+            for value in node.values:
+                self._validate(value, expression)
+        elif isinstance(node, ast.UnaryOp) and isinstance(node.op, (ast.Not, ast.USub)):
+            self._validate(node.operand, expression)
+        elif isinstance(node, ast.Compare):
+            for op in node.ops:
+                if type(op) not in _COMPARE_OPS:
+                    raise ValueError(
+                        f"invalid trigger expression {expression!r}: "
+                        f"unsupported operator {type(op).__name__}",
+                    )
+            self._validate(node.left, expression)
+            for comparator in node.comparators:
+                self._validate(comparator, expression)
+        elif isinstance(node, ast.BinOp) and type(node.op) in _BINARY_OPS:
+            self._validate(node.left, expression)
+            self._validate(node.right, expression)
+        elif isinstance(node, ast.Name):
+            if node.id not in CONDITION_VARIABLES:
+                raise ValueError(
+                    f"invalid trigger expression {expression!r}: unknown variable {node.id!r}, "
+                    f"expected one of {sorted(CONDITION_VARIABLES)}",
+                )
+            self._variables.add(node.id)
+        elif isinstance(node, ast.Constant) and type(node.value) in (int, float):
+            return
+        else:
+            raise ValueError(
+                f"invalid trigger expression {expression!r}: "
+                f"unsupported syntax {type(node).__name__}",
+            )
+
+    def _eval(self, node: ast.AST, state: dict[str, float | None]) -> Any:
+        if isinstance(node, ast.BoolOp):
+            if isinstance(node.op, ast.And):
+                return all(self._eval(v, state) for v in node.values)
+            return any(self._eval(v, state) for v in node.values)
+        elif isinstance(node, ast.UnaryOp):
+            if isinstance(node.op, ast.Not):
+                return not self._eval(node.operand, state)
+            return -self._eval(node.operand, state)
+        elif isinstance(node, ast.Compare):
+            left = self._eval(node.left, state)
+            for op, comparator in zip(node.ops, node.comparators):
+                right = self._eval(comparator, state)
+                if not _COMPARE_OPS[type(op)](left, right):
+                    return False
+                left = right
+            return True
+        elif isinstance(node, ast.BinOp):
+            return _BINARY_OPS[type(node.op)](
+                self._eval(node.left, state),
+                self._eval(node.right, state),
+            )
+        elif isinstance(node, ast.Name):
+            return state[node.id]
+        else:
+            return node.value  # type: ignore[attr-defined]
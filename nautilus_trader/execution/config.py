@@ -21,6 +21,7 @@ import msgspec
 
 from nautilus_trader.common.config import NautilusConfig
 from nautilus_trader.common.config import PositiveFloat
+from nautilus_trader.common.config import PositiveInt
 from nautilus_trader.common.config import msgspec_encoding_hook
 from nautilus_trader.common.config import resolve_config_path
 from nautilus_trader.common.config import resolve_path
@@ -46,10 +47,22 @@ class ExecEngineConfig(NautilusConfig, frozen=True):
         be available in the cache.
     snapshot_positions_interval_secs : PositiveFloat, optional
         The interval (seconds) at which *additional* position state snapshots are persisted to a
-        backing database.
+        backing database. Only open positions are snapshot, with `ts_snapshot` set to the time
+        of each interval.
         If ``None`` then no additional snapshots will be taken.
         To include unrealized PnL in these snapshots, quotes for the position's instrument must be
         available in the cache.
+    fill_aggregation_window_ms : PositiveInt, optional
+        The window (milliseconds) over which fills for the same order are coalesced into a
+        single average-price `OrderFilled` event. Pending fills are emitted early once they
+        complete the order, or ahead of any other event for the order.
+        If ``None`` then fills are not aggregated.
+    fill_aggregation_venues : list[str], optional
+        The venues for which fills are aggregated (when `fill_aggregation_window_ms` is set).
+        If ``None`` then fills from all venues are aggregated.
+    fill_aggregation_strategies : list[str], optional
+        The strategy IDs for which fills are aggregated (when `fill_aggregation_window_ms` is set).
+        If ``None`` then fills for all strategies are aggregated.
     debug : bool, default False
         If debug mode is active (will provide extra debug logging).
 
@@ -59,6 +72,9 @@ class ExecEngineConfig(NautilusConfig, frozen=True):
     snapshot_orders: bool = False
     snapshot_positions: bool = False
     snapshot_positions_interval_secs: PositiveFloat | None = None
+    fill_aggregation_window_ms: PositiveInt | None = None
+    fill_aggregation_venues: list[str] | None = None
+    fill_aggregation_strategies: list[str] | None = None
     debug: bool = False
 
 
@@ -30,6 +30,7 @@ from nautilus_trader.model.events.order cimport OrderRejected
 from nautilus_trader.model.events.order cimport OrderUpdated
 from nautilus_trader.model.events.position cimport PositionEvent
 from nautilus_trader.model.identifiers cimport ClientId
+from nautilus_trader.model.identifiers cimport ClientOrderId
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport PositionId
 from nautilus_trader.model.identifiers cimport StrategyId
@@ -41,6 +42,7 @@ from nautilus_trader.model.orders.base cimport Order
 cdef class OrderEmulator(Actor):
     cdef OrderManager _manager
     cdef dict[InstrumentId, MatchingCore] _matching_cores
+    cdef dict[ClientOrderId, object] _conditions
 
     cdef set[InstrumentId] _subscribed_quotes
     cdef set[InstrumentId] _subscribed_trades
@@ -73,4 +75,6 @@ cdef class OrderEmulator(Actor):
     cpdef void _fill_limit_order(self, Order order)
 
     cdef void _iterate_orders(self, MatchingCore matching_core)
+    cdef void _arm_conditional_order(self, Order order)
+    cdef dict _condition_state(self, MatchingCore matching_core)
     cdef void _update_trailing_stop_order(self, MatchingCore matching_core, Order order)
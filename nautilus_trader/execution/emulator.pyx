@@ -14,6 +14,7 @@
 # -------------------------------------------------------------------------------------------------
 
 from nautilus_trader.common.config import OrderEmulatorConfig
+from nautilus_trader.execution.conditions import TriggerCondition
 
 from libc.stdint cimport uint8_t
 from libc.stdint cimport uint64_t
@@ -30,6 +31,7 @@ from nautilus_trader.common.component cimport MessageBus
 from nautilus_trader.common.component cimport is_logging_initialized
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.message cimport Event
+from nautilus_trader.core.rust.model cimport FIXED_SCALAR
 from nautilus_trader.core.rust.model cimport ContingencyType
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport OrderStatus
@@ -82,6 +84,11 @@ cdef class OrderEmulator(Actor):
     """
     Provides order emulation for specified trigger types.
 
+    Orders may also carry a user-defined trigger condition via a ``"CONDITION:<expr>"``
+    tag (see ``TriggerCondition``). A conditional order is held by the emulator and
+    only becomes eligible for matching once its condition evaluates ``True`` against
+    the trigger instruments market state on a book, quote or trade update.
+
     Parameters
     ----------
     portfolio : PortfolioFacade
@@ -130,6 +137,7 @@ cdef class OrderEmulator(Actor):
         )
 
         self._matching_cores: dict[InstrumentId, MatchingCore]  = {}
+        self._conditions: dict[ClientOrderId, TriggerCondition] = {}
 
         self._subscribed_quotes: set[InstrumentId] = set()
         self._subscribed_trades: set[InstrumentId] = set()
@@ -181,6 +189,17 @@ cdef class OrderEmulator(Actor):
         """
         return self._manager.get_submit_order_commands()
 
+    def get_conditions(self) -> dict[ClientOrderId, TriggerCondition]:
+        """
+        Return the emulators trigger conditions for orders not yet armed.
+
+        Returns
+        -------
+        dict[ClientOrderId, TriggerCondition]
+
+        """
+        return self._conditions.copy()
+
     def get_matching_core(self, InstrumentId instrument_id) -> MatchingCore | None:
         """
         Return the emulators matching core for the given instrument ID.
@@ -263,6 +282,7 @@ cdef class OrderEmulator(Actor):
 
         cdef MatchingCore matching_core = None
         if order.is_closed_c():
+            self._conditions.pop(order.client_order_id, None)
             matching_core = self._matching_cores.get(order.instrument_id)
             if matching_core is not None:
                 matching_core.delete_order(order)
@@ -273,6 +293,7 @@ cdef class OrderEmulator(Actor):
     cpdef void on_reset(self):
         self._manager.reset()
         self._matching_cores.clear()
+        self._conditions.clear()
 
         self.command_count = 0
         self.event_count = 0
@@ -365,6 +386,13 @@ cdef class OrderEmulator(Actor):
             self._manager.cancel_order(order=order)
             return
 
+        try:
+            condition = TriggerCondition.from_tags(order.tags)
+        except ValueError as e:
+            self._log.error(f"Cannot emulate order: {e}")
+            self._manager.cancel_order(order=order)
+            return
+
         self._check_monitoring(command.strategy_id, command.position_id)
 
         cdef InstrumentId trigger_instrument_id = order.instrument_id if order.trigger_instrument_id is None else order.trigger_instrument_id
@@ -402,8 +430,15 @@ cdef class OrderEmulator(Actor):
         # Cache command
         self._manager.cache_submit_order_command(command)
 
+        # Hold conditional order until its trigger condition is met
+        if condition is not None:
+            self._conditions[order.client_order_id] = condition
+            if condition.evaluate(self._condition_state(matching_core)):
+                self._arm_conditional_order(order)
+
         # Check if immediately marketable (initial match)
-        matching_core.match_order(order, initial=True)
+        if order.client_order_id not in self._conditions:
+            matching_core.match_order(order, initial=True)
 
         # Check data subscription
         if emulation_trigger == TriggerType.DEFAULT or emulation_trigger == TriggerType.BID_ASK:
@@ -582,6 +617,7 @@ cdef class OrderEmulator(Actor):
 
         # Remove emulation trigger
         order.emulation_trigger = TriggerType.NO_TRIGGER
+        self._conditions.pop(order.client_order_id, None)
 
         cdef InstrumentId trigger_instrument_id = order.instrument_id if order.trigger_instrument_id is None else order.trigger_instrument_id
         cdef MatchingCore matching_core = self._matching_cores.get(trigger_instrument_id)
@@ -856,10 +892,24 @@ cdef class OrderEmulator(Actor):
         self._iterate_orders(matching_core)
 
     cdef void _iterate_orders(self, MatchingCore matching_core):
-        matching_core.iterate(self._clock.timestamp_ns())
+        cdef Order order
+        cdef dict state = None
+        if not self._conditions:
+            matching_core.iterate(self._clock.timestamp_ns())
+        else:
+            for order in matching_core.get_orders():
+                if order.is_closed_c():
+                    continue  # Orders state has changed since iteration started
+                condition = self._conditions.get(order.client_order_id)
+                if condition is not None:
+                    if state is None:
+                        state = self._condition_state(matching_core)
+                    if not condition.evaluate(state):
+                        continue  # Not yet armed
+                    self._arm_conditional_order(order)
+                matching_core.match_order(order)
 
         cdef list orders = matching_core.get_orders()
-        cdef Order order
         for order in orders:
             if order.is_closed_c():
                 continue
@@ -868,6 +918,55 @@ cdef class OrderEmulator(Actor):
             if order.order_type == OrderType.TRAILING_STOP_MARKET or order.order_type == OrderType.TRAILING_STOP_LIMIT:
                 self._update_trailing_stop_order(matching_core, order)
 
+    cdef void _arm_conditional_order(self, Order order):
+        condition = self._conditions.pop(order.client_order_id)
+        self._log.info(
+            f"Trigger condition met for {order.client_order_id!r}: {condition.expression}",
+            LogColor.MAGENTA,
+        )
+
+    cdef dict _condition_state(self, MatchingCore matching_core):
+        cdef InstrumentId instrument_id = matching_core.instrument_id
+        cdef double bid = matching_core.bid_raw / FIXED_SCALAR if matching_core.is_bid_initialized else float("nan")
+        cdef double ask = matching_core.ask_raw / FIXED_SCALAR if matching_core.is_ask_initialized else float("nan")
+        cdef double increment = matching_core.price_increment.as_double()
+
+        bid_size = None
+        ask_size = None
+        cdef OrderBook book = self.cache.order_book(instrument_id)
+        cdef QuoteTick quote_tick
+        if book is not None and book.best_bid_size() is not None and book.best_ask_size() is not None:
+            bid_size = book.best_bid_size().as_double()
+            ask_size = book.best_ask_size().as_double()
+        else:
+            # Fall back to top-of-book sizes from the latest quote
+            quote_tick = self.cache.quote_tick(instrument_id)
+            if quote_tick is not None:
+                bid_size = quote_tick.bid_size.as_double()
+                ask_size = quote_tick.ask_size.as_double()
+
+        cdef dict state = {
+            "bid": bid if matching_core.is_bid_initialized else None,
+            "ask": ask if matching_core.is_ask_initialized else None,
+            "mid": None,
+            "last": matching_core.last_raw / FIXED_SCALAR if matching_core.is_last_initialized else None,
+            "spread": None,
+            "spread_ticks": None,
+            "bid_size": bid_size,
+            "ask_size": ask_size,
+            "imbalance": None,
+        }
+
+        if matching_core.is_bid_initialized and matching_core.is_ask_initialized:
+            state["mid"] = (bid + ask) / 2.0
+            state["spread"] = ask - bid
+            state["spread_ticks"] = round((ask - bid) / increment)
+
+        if bid_size is not None and ask_size is not None and bid_size + ask_size > 0:
+            state["imbalance"] = (bid_size - ask_size) / (bid_size + ask_size)
+
+        return state
+
     cdef void _update_trailing_stop_order(self, MatchingCore matching_core, Order order):
         # TODO: Improve efficiency of this ---------------------------------
         cdef Price bid = None
@@ -13,6 +13,8 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from libc.stdint cimport uint64_t
+
 from nautilus_trader.cache.cache cimport Cache
 from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.component cimport TimeEvent
@@ -31,10 +33,12 @@ from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.execution.messages cimport TradingCommand
 from nautilus_trader.model.events.order cimport OrderEvent
 from nautilus_trader.model.events.order cimport OrderFilled
+from nautilus_trader.model.identifiers cimport ClientOrderId
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport PositionId
 from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.identifiers cimport Venue
+from nautilus_trader.model.identifiers cimport VenueOrderId
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
@@ -52,6 +56,9 @@ cdef class ExecutionEngine(Component):
     cdef readonly dict[StrategyId, OmsType] _oms_overrides
     cdef readonly dict[InstrumentId, StrategyId] _external_order_claims
     cdef readonly str snapshot_positions_timer_name
+    cdef readonly dict[ClientOrderId, list] _pending_fills
+    cdef readonly set _fill_aggregation_venues
+    cdef readonly set _fill_aggregation_strategies
 
     cdef readonly bint debug
     """If debug mode is active (will provide extra debug logging).\n\n:returns: `bool`"""
@@ -61,6 +68,8 @@ cdef class ExecutionEngine(Component):
     """If position state snapshots should be persisted.\n\n:returns: `bool`"""
     cdef readonly double snapshot_positions_interval_secs
     """The interval (seconds) at which additional position state snapshots are persisted.\n\n:returns: `double`"""
+    cdef readonly int fill_aggregation_window_ms
+    """The window (milliseconds) over which fills for an order are aggregated (0 if disabled).\n\n:returns: `int`"""
     cdef readonly int command_count
     """The total count of commands received by the engine.\n\n:returns: `int`"""
     cdef readonly int event_count
@@ -103,11 +112,14 @@ cdef class ExecutionEngine(Component):
     cpdef void load_cache(self)
     cpdef void execute(self, TradingCommand command)
     cpdef void process(self, OrderEvent event)
+    cpdef void flush_pending_fills(self)
     cpdef void flush_db(self)
 
 # -- COMMAND HANDLERS -----------------------------------------------------------------------------
 
     cpdef void _execute_command(self, TradingCommand command)
+    cpdef void _abort_expired_command(self, TradingCommand command)
+    cpdef void _reject_expired_cancel(self, ClientOrderId client_order_id, VenueOrderId venue_order_id, TradingCommand command, str reason, uint64_t ts_now)
     cpdef void _handle_submit_order(self, ExecutionClient client, SubmitOrder command)
     cpdef void _handle_submit_order_list(self, ExecutionClient client, SubmitOrderList command)
     cpdef void _handle_modify_order(self, ExecutionClient client, ModifyOrder command)
@@ -119,6 +131,12 @@ cdef class ExecutionEngine(Component):
 # -- EVENT HANDLERS -------------------------------------------------------------------------------
 
     cpdef void _handle_event(self, OrderEvent event)
+    cpdef void _handle_fill(self, Order order, OrderFilled fill)
+    cpdef bint _should_aggregate_fill(self, OrderFilled fill)
+    cpdef void _buffer_fill(self, Order order, OrderFilled fill)
+    cpdef void _flush_pending_fills_for(self, ClientOrderId client_order_id)
+    cpdef void _on_fill_aggregation_timer(self, TimeEvent event)
+    cpdef OrderFilled _aggregate_fills(self, list fills)
     cpdef OmsType _determine_oms_type(self, OrderFilled fill)
     cpdef void _determine_position_id(self, OrderFilled fill, OmsType oms_type)
     cpdef PositionId _determine_hedging_position_id(self, OrderFilled fill)
@@ -130,5 +148,5 @@ cdef class ExecutionEngine(Component):
     cpdef bint _will_flip_position(self, Position position, OrderFilled fill)
     cpdef void _flip_position(self, Instrument instrument, Position position, OrderFilled fill, OmsType oms_type)
     cpdef void _create_order_state_snapshot(self, Order order)
-    cpdef void _create_position_state_snapshot(self, Position position)
+    cpdef void _create_position_state_snapshot(self, Position position, uint64_t ts_snapshot, bint open_only)
     cpdef void _snapshot_open_position_states(self, TimeEvent event)
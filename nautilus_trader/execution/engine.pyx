@@ -37,6 +37,7 @@ from nautilus_trader.execution.config import ExecEngineConfig
 from nautilus_trader.execution.reports import ExecutionMassStatus
 from nautilus_trader.execution.reports import ExecutionReport
 
+from libc.stdint cimport uint8_t
 from libc.stdint cimport uint64_t
 
 from nautilus_trader.accounting.accounts.base cimport Account
@@ -51,8 +52,10 @@ from nautilus_trader.common.component cimport Logger
 from nautilus_trader.common.component cimport MessageBus
 from nautilus_trader.common.component cimport TimeEvent
 from nautilus_trader.common.generators cimport PositionIdGenerator
+from nautilus_trader.common.messages cimport CommandExpired
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.fsm cimport InvalidStateTrigger
+from nautilus_trader.core.rust.core cimport millis_to_nanos
 from nautilus_trader.core.rust.core cimport secs_to_nanos
 from nautilus_trader.core.rust.model cimport ContingencyType
 from nautilus_trader.core.rust.model cimport OmsType
@@ -69,8 +72,10 @@ from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.execution.messages cimport TradingCommand
 from nautilus_trader.model.data cimport QuoteTick
 from nautilus_trader.model.data cimport TradeTick
+from nautilus_trader.model.events.order cimport OrderCancelRejected
 from nautilus_trader.model.events.order cimport OrderDenied
 from nautilus_trader.model.events.order cimport OrderEvent
+from nautilus_trader.model.events.order cimport OrderModifyRejected
 from nautilus_trader.model.events.order cimport OrderFilled
 from nautilus_trader.model.events.position cimport PositionChanged
 from nautilus_trader.model.events.position cimport PositionClosed
@@ -84,6 +89,7 @@ from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport PositionId
 from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.identifiers cimport Venue
+from nautilus_trader.model.identifiers cimport VenueOrderId
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.instruments.currency_pair cimport CurrencyPair
 from nautilus_trader.model.objects cimport Money
@@ -151,10 +157,19 @@ cdef class ExecutionEngine(Component):
         self.snapshot_positions = config.snapshot_positions
         self.snapshot_positions_interval_secs = config.snapshot_positions_interval_secs or 0
         self.snapshot_positions_timer_name = "ExecEngine_SNAPSHOT_POSITIONS"
+        self.fill_aggregation_window_ms = config.fill_aggregation_window_ms or 0
+        self._fill_aggregation_venues = None
+        if config.fill_aggregation_venues is not None:
+            self._fill_aggregation_venues = {Venue(v) for v in config.fill_aggregation_venues}
+        self._fill_aggregation_strategies = None
+        if config.fill_aggregation_strategies is not None:
+            self._fill_aggregation_strategies = {StrategyId(s) for s in config.fill_aggregation_strategies}
+        self._pending_fills: dict[ClientOrderId, list[OrderFilled]] = {}
 
         self._log.info(f"{config.snapshot_orders=}", LogColor.BLUE)
         self._log.info(f"{config.snapshot_positions=}", LogColor.BLUE)
         self._log.info(f"{config.snapshot_positions_interval_secs=}", LogColor.BLUE)
+        self._log.info(f"{config.fill_aggregation_window_ms=}", LogColor.BLUE)
 
         # Counters
         self.command_count: int = 0
@@ -569,6 +584,8 @@ cdef class ExecutionEngine(Component):
             self._log.info(f"Canceling position snapshots timer")
             self._clock.cancel_timer(self.snapshot_positions_timer_name)
 
+        self.flush_pending_fills()
+
         self._on_stop()
 
     cpdef void _reset(self):
@@ -577,6 +594,7 @@ cdef class ExecutionEngine(Component):
 
         self._cache.reset()
         self._pos_id_generator.reset()
+        self._pending_fills.clear()
 
         self.command_count = 0
         self.event_count = 0
@@ -644,6 +662,15 @@ cdef class ExecutionEngine(Component):
 
         self._handle_event(event)
 
+    cpdef void flush_pending_fills(self):
+        """
+        Flush all pending aggregated fills, applying them immediately.
+
+        """
+        cdef ClientOrderId client_order_id
+        for client_order_id in list(self._pending_fills.keys()):
+            self._flush_pending_fills_for(client_order_id)
+
     cpdef void flush_db(self):
         """
         Flush the execution database which permanently removes all persisted data.
@@ -755,6 +782,10 @@ cdef class ExecutionEngine(Component):
             self._log.debug(f"{RECV}{CMD} {command}", LogColor.MAGENTA)
         self.command_count += 1
 
+        if command.is_expired(self._clock.timestamp_ns()):
+            self._abort_expired_command(command)
+            return  # Stale command will not be sent
+
         cdef ExecutionClient client = self._clients.get(command.client_id)
         if client is None:
             client = self._routing_map.get(
@@ -788,6 +819,109 @@ cdef class ExecutionEngine(Component):
                 f"Cannot handle command: unrecognized {command}",  # pragma: no cover (design-time error)
             )
 
+    cpdef void _abort_expired_command(self, TradingCommand command):
+        cdef uint64_t ts_now = self._clock.timestamp_ns()
+        cdef str reason = f"DEADLINE_EXCEEDED by {ts_now - command.ts_deadline}ns"
+        self._log.warning(f"Aborting {command}: {reason}")
+
+        cdef Order order
+        if isinstance(command, SubmitOrder):
+            order = command.order
+            if not self._cache.order_exists(order.client_order_id):
+                self._cache.add_order(order, command.position_id, command.client_id)
+            self._deny_order(order, reason)
+        elif isinstance(command, SubmitOrderList):
+            for order in command.order_list.orders:
+                if not self._cache.order_exists(order.client_order_id):
+                    self._cache.add_order(order, command.position_id, command.client_id)
+                self._deny_order(order, reason)
+        elif isinstance(command, ModifyOrder):
+            order = self._cache.order(command.client_order_id)
+            self._handle_event(
+                OrderModifyRejected(
+                    trader_id=command.trader_id,
+                    strategy_id=command.strategy_id,
+                    instrument_id=command.instrument_id,
+                    client_order_id=command.client_order_id,
+                    venue_order_id=command.venue_order_id,
+                    account_id=order.account_id if order is not None else None,
+                    reason=reason,
+                    event_id=UUID4(),
+                    ts_event=ts_now,
+                    ts_init=ts_now,
+                ),
+            )
+        elif isinstance(command, CancelOrder):
+            self._reject_expired_cancel(
+                client_order_id=command.client_order_id,
+                venue_order_id=command.venue_order_id,
+                command=command,
+                reason=reason,
+                ts_now=ts_now,
+            )
+        elif isinstance(command, CancelAllOrders):
+            for order in self._cache.orders_open(
+                instrument_id=command.instrument_id,
+                strategy_id=command.strategy_id,
+                side=command.order_side,
+            ):
+                self._reject_expired_cancel(
+                    client_order_id=order.client_order_id,
+                    venue_order_id=order.venue_order_id,
+                    command=command,
+                    reason=reason,
+                    ts_now=ts_now,
+                )
+        elif isinstance(command, BatchCancelOrders):
+            for cancel in command.cancels:
+                self._reject_expired_cancel(
+                    client_order_id=cancel.client_order_id,
+                    venue_order_id=cancel.venue_order_id,
+                    command=command,
+                    reason=reason,
+                    ts_now=ts_now,
+                )
+        elif isinstance(command, QueryOrder):
+            # No order event reports a failed query, so the expiry is published as its own event
+            self._msgbus.publish_c(
+                topic=f"events.commands.{command.strategy_id}",
+                msg=CommandExpired(
+                    trader_id=command.trader_id,
+                    strategy_id=command.strategy_id,
+                    instrument_id=command.instrument_id,
+                    command_type=type(command).__name__,
+                    command_id=command.id,
+                    reason=reason,
+                    event_id=UUID4(),
+                    ts_event=ts_now,
+                    ts_init=ts_now,
+                ),
+            )
+
+    cpdef void _reject_expired_cancel(
+        self,
+        ClientOrderId client_order_id,
+        VenueOrderId venue_order_id,
+        TradingCommand command,
+        str reason,
+        uint64_t ts_now,
+    ):
+        cdef Order order = self._cache.order(client_order_id)
+        self._handle_event(
+            OrderCancelRejected(
+                trader_id=command.trader_id,
+                strategy_id=command.strategy_id,
+                instrument_id=command.instrument_id,
+                client_order_id=client_order_id,
+                venue_order_id=venue_order_id,
+                account_id=order.account_id if order is not None else None,
+                reason=reason,
+                event_id=UUID4(),
+                ts_event=ts_now,
+                ts_init=ts_now,
+            ),
+        )
+
     cpdef void _handle_submit_order(self, ExecutionClient client, SubmitOrder command):
         cdef Order order = command.order
         if not self._cache.order_exists(order.client_order_id):
@@ -922,15 +1056,138 @@ cdef class ExecutionEngine(Component):
                 color=LogColor.GREEN,
             )
 
-        cdef OmsType oms_type
         if isinstance(event, OrderFilled):
-            oms_type = self._determine_oms_type(event)
-            self._determine_position_id(event, oms_type)
-            self._apply_event_to_order(order, event)
-            self._handle_order_fill(order, event, oms_type)
+            if self.fill_aggregation_window_ms > 0 and self._should_aggregate_fill(event):
+                self._buffer_fill(order, event)
+            else:
+                self._handle_fill(order, event)
         else:
+            if client_order_id in self._pending_fills:
+                # Apply pending fills first to preserve event ordering
+                self._flush_pending_fills_for(client_order_id)
             self._apply_event_to_order(order, event)
 
+    cpdef void _handle_fill(self, Order order, OrderFilled fill):
+        cdef OmsType oms_type = self._determine_oms_type(fill)
+        self._determine_position_id(fill, oms_type)
+        self._apply_event_to_order(order, fill)
+        self._handle_order_fill(order, fill, oms_type)
+
+    cpdef bint _should_aggregate_fill(self, OrderFilled fill):
+        if fill._reconciliation:
+            return False  # Reconciliation must apply fills immediately
+        if self._fill_aggregation_venues is not None and fill.instrument_id.venue not in self._fill_aggregation_venues:
+            return False
+        if self._fill_aggregation_strategies is not None and fill.strategy_id not in self._fill_aggregation_strategies:
+            return False
+        return True
+
+    cpdef void _buffer_fill(self, Order order, OrderFilled fill):
+        cdef ClientOrderId client_order_id = order.client_order_id
+        cdef list fills = self._pending_fills.get(client_order_id)
+        cdef OrderFilled last
+        if fills is None:
+            fills = []
+            self._pending_fills[client_order_id] = fills
+            self._clock.set_time_alert_ns(
+                name=f"ExecEngine_FILL_AGGREGATION|{client_order_id}",
+                alert_time_ns=self._clock.timestamp_ns() + millis_to_nanos(self.fill_aggregation_window_ms),
+                callback=self._on_fill_aggregation_timer,
+            )
+        else:
+            for last in fills:
+                if last.trade_id == fill.trade_id:
+                    self._log.warning(f"Duplicate {fill.trade_id!r} for pending fills, did not apply {fill}")
+                    return
+
+            last = fills[-1]
+            if (
+                last.liquidity_side != fill.liquidity_side
+                or last.currency != fill.currency
+                or last.commission.currency != fill.commission.currency
+            ):
+                # Cannot be coalesced into a single fill
+                self._flush_pending_fills_for(client_order_id)
+                self._buffer_fill(order, fill)
+                return
+
+        fills.append(fill)
+
+        pending_qty = Decimal(0)
+        for last in fills:
+            pending_qty += last.last_qty.as_decimal()
+
+        if pending_qty >= order.leaves_qty.as_decimal():
+            # Pending fills complete the order
+            self._flush_pending_fills_for(client_order_id)
+
+    cpdef void _flush_pending_fills_for(self, ClientOrderId client_order_id):
+        cdef list fills = self._pending_fills.pop(client_order_id, None)
+        if not fills:
+            return
+
+        cdef str timer_name = f"ExecEngine_FILL_AGGREGATION|{client_order_id}"
+        if timer_name in self._clock.timer_names:
+            self._clock.cancel_timer(timer_name)
+
+        cdef Order order = self._cache.order(client_order_id)
+        if order is None:
+            self._log.error(
+                f"Cannot apply pending fills: "
+                f"{client_order_id!r} not found in the cache",
+            )
+            return
+
+        cdef OrderFilled fill = fills[0] if len(fills) == 1 else self._aggregate_fills(fills)
+        self._handle_fill(order, fill)
+
+    cpdef void _on_fill_aggregation_timer(self, TimeEvent event):
+        self._flush_pending_fills_for(ClientOrderId(event.name.partition("|")[2]))
+
+    cpdef OrderFilled _aggregate_fills(self, list fills):
+        cdef OrderFilled last = fills[-1]
+        cdef OrderFilled fill
+        cdef Instrument instrument = self._cache.instrument(last.instrument_id)
+        cdef uint8_t price_precision = last.last_px.precision
+        cdef uint8_t size_precision = last.last_qty.precision
+        if instrument is not None:
+            price_precision = instrument.price_precision
+            size_precision = instrument.size_precision
+
+        total_qty = Decimal(0)
+        notional = Decimal(0)
+        commission = Decimal(0)
+        for fill in fills:
+            total_qty += fill.last_qty.as_decimal()
+            notional += fill.last_qty.as_decimal() * fill.last_px.as_decimal()
+            commission += fill.commission.as_decimal()
+
+        cdef dict info = dict(last.info)
+        info["aggregated_trade_ids"] = [fill.trade_id.value for fill in fills]
+
+        return OrderFilled(
+            trader_id=last.trader_id,
+            strategy_id=last.strategy_id,
+            instrument_id=last.instrument_id,
+            client_order_id=last.client_order_id,
+            venue_order_id=last.venue_order_id,
+            account_id=last.account_id,
+            trade_id=last.trade_id,
+            position_id=last.position_id,
+            order_side=last.order_side,
+            order_type=last.order_type,
+            # Exact decimal arithmetic, rounded once to the instrument precision
+            last_qty=Quantity.from_str(str(total_qty.quantize(Decimal(1).scaleb(-size_precision)))),
+            last_px=Price.from_str(str((notional / total_qty).quantize(Decimal(1).scaleb(-price_precision)))),
+            currency=last.currency,
+            commission=Money(commission, last.commission.currency),
+            liquidity_side=last.liquidity_side,
+            event_id=UUID4(),
+            ts_event=last.ts_event,
+            ts_init=self._clock.timestamp_ns(),
+            info=info,
+        )
+
     cpdef OmsType _determine_oms_type(self, OrderFilled fill):
         cdef ExecutionClient client
         # Check for strategy OMS override
@@ -1101,7 +1358,7 @@ cdef class ExecutionEngine(Component):
             position = Position(instrument, fill)
             self._cache.add_position(position, oms_type)
             if self.snapshot_positions:
-                self._create_position_state_snapshot(position)
+                self._create_position_state_snapshot(position, position.ts_last, open_only=True)
         else:
             try:
                 # Always snapshot opening positions to handle NETTING OMS
@@ -1137,7 +1394,7 @@ cdef class ExecutionEngine(Component):
 
         self._cache.update_position(position)
         if self.snapshot_positions:
-            self._create_position_state_snapshot(position)
+            self._create_position_state_snapshot(position, position.ts_last, open_only=True)
 
         cdef PositionEvent event
         if position.is_closed_c():
@@ -1266,7 +1523,12 @@ cdef class ExecutionEngine(Component):
                 msg=self._msgbus.serializer.serialize(order.to_dict())
             )
 
-    cpdef void _create_position_state_snapshot(self, Position position):
+    cpdef void _create_position_state_snapshot(
+        self,
+        Position position,
+        uint64_t ts_snapshot,
+        bint open_only,
+    ):
         if self.debug:
             self._log.debug(f"Creating position state snapshot for {position}", LogColor.MAGENTA)
 
@@ -1274,6 +1536,7 @@ cdef class ExecutionEngine(Component):
         cdef dict[str, object] position_state = position.to_dict()
         if unrealized_pnl is not None:
             position_state["unrealized_pnl"] = str(unrealized_pnl)
+        position_state["ts_snapshot"] = ts_snapshot
 
         # TODO: Experimental internal message bus publishing
         self._msgbus.publish_c(
@@ -1285,8 +1548,9 @@ cdef class ExecutionEngine(Component):
         if self._cache.has_backing:
             self._cache.snapshot_position_state(
                 position,
-                position.ts_last,
+                ts_snapshot,
                 unrealized_pnl,
+                open_only,
             )
 
         if self._msgbus.has_backing and self._msgbus.serializer is not None:
@@ -1296,6 +1560,8 @@ cdef class ExecutionEngine(Component):
             )
 
     cpdef void _snapshot_open_position_states(self, TimeEvent event):
+        # Interval snapshots are stamped with the timer event time (rather than the last
+        # position event) so the position evolution between events can be reconstructed
         cdef Position position
         for position in self._cache.positions_open():
-            self._create_position_state_snapshot(position)
+            self._create_position_state_snapshot(position, event.ts_event, open_only=True)
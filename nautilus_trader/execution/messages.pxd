@@ -13,6 +13,8 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from libc.stdint cimport uint64_t
+
 from nautilus_trader.core.message cimport Command
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.model.identifiers cimport ClientId
@@ -38,6 +40,10 @@ cdef class TradingCommand(Command):
     """The strategy ID associated with the command.\n\n:returns: `StrategyId`"""
     cdef readonly InstrumentId instrument_id
     """The instrument ID associated with the command.\n\n:returns: `InstrumentId`"""
+    cdef readonly uint64_t ts_deadline
+    """UNIX timestamp (nanoseconds) after which the command will be aborted rather than sent (0 for no deadline).\n\n:returns: `uint64_t`"""
+
+    cpdef bint is_expired(self, uint64_t ts_now)
 
 
 cdef class SubmitOrder(TradingCommand):
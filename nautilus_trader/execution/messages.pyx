@@ -52,6 +52,9 @@ cdef class TradingCommand(Command):
         The commands ID.
     ts_init : uint64_t
         UNIX timestamp (nanoseconds) when the object was initialized.
+    ts_deadline : uint64_t, default 0
+        UNIX timestamp (nanoseconds) after which the command will be aborted
+        rather than sent to the venue (0 for no deadline).
 
     Warnings
     --------
@@ -66,6 +69,7 @@ cdef class TradingCommand(Command):
         InstrumentId instrument_id not None,
         UUID4 command_id not None,
         uint64_t ts_init,
+        uint64_t ts_deadline = 0,
     ):
         super().__init__(command_id, ts_init)
 
@@ -73,6 +77,24 @@ cdef class TradingCommand(Command):
         self.trader_id = trader_id
         self.strategy_id = strategy_id
         self.instrument_id = instrument_id
+        self.ts_deadline = ts_deadline
+
+    cpdef bint is_expired(self, uint64_t ts_now):
+        """
+        Return whether the command has passed its deadline at the given time.
+
+        Parameters
+        ----------
+        ts_now : uint64_t
+            UNIX timestamp (nanoseconds) for the current time.
+
+        Returns
+        -------
+        bool
+            ``False`` if the command has no deadline.
+
+        """
+        return self.ts_deadline != 0 and ts_now > self.ts_deadline
 
 
 cdef class SubmitOrder(TradingCommand):
@@ -95,6 +117,9 @@ cdef class SubmitOrder(TradingCommand):
         The position ID for the command.
     client_id : ClientId, optional
         The execution client ID for the command.
+    ts_deadline : uint64_t, default 0
+        UNIX timestamp (nanoseconds) after which the command will be aborted
+        rather than sent to the venue (0 for no deadline).
 
     References
     ----------
@@ -110,6 +135,7 @@ cdef class SubmitOrder(TradingCommand):
         uint64_t ts_init,
         PositionId position_id: PositionId | None = None,
         ClientId client_id = None,
+        uint64_t ts_deadline = 0,
     ):
         super().__init__(
             client_id=client_id,
@@ -118,6 +144,7 @@ cdef class SubmitOrder(TradingCommand):
             instrument_id=order.instrument_id,
             command_id=command_id,
             ts_init=ts_init,
+            ts_deadline=ts_deadline,
         )
 
         self.order = order
@@ -159,6 +186,7 @@ cdef class SubmitOrder(TradingCommand):
             position_id=PositionId(p) if p is not None else None,
             command_id=UUID4(values["command_id"]),
             ts_init=values["ts_init"],
+            ts_deadline=values.get("ts_deadline", 0),
         )
 
     @staticmethod
@@ -173,6 +201,7 @@ cdef class SubmitOrder(TradingCommand):
             "position_id": obj.position_id.to_str() if obj.position_id is not None else None,
             "command_id": obj.id.to_str(),
             "ts_init": obj.ts_init,
+            "ts_deadline": obj.ts_deadline,
         }
 
     @staticmethod
@@ -229,6 +258,9 @@ cdef class SubmitOrderList(TradingCommand):
         The position ID for the command.
     client_id : ClientId, optional
         The execution client ID for the command.
+    ts_deadline : uint64_t, default 0
+        UNIX timestamp (nanoseconds) after which the command will be aborted
+        rather than sent to the venue (0 for no deadline).
 
     References
     ----------
@@ -244,6 +276,7 @@ cdef class SubmitOrderList(TradingCommand):
         uint64_t ts_init,
         PositionId position_id: PositionId | None = None,
         ClientId client_id = None,
+        uint64_t ts_deadline = 0,
     ):
         super().__init__(
             client_id=client_id,
@@ -252,6 +285,7 @@ cdef class SubmitOrderList(TradingCommand):
             instrument_id=order_list.instrument_id,
             command_id=command_id,
             ts_init=ts_init,
+            ts_deadline=ts_deadline,
         )
 
         self.order_list = order_list
@@ -296,6 +330,7 @@ cdef class SubmitOrderList(TradingCommand):
             position_id=PositionId(p) if p is not None else None,
             command_id=UUID4(values["command_id"]),
             ts_init=values["ts_init"],
+            ts_deadline=values.get("ts_deadline", 0),
         )
 
     @staticmethod
@@ -312,6 +347,7 @@ cdef class SubmitOrderList(TradingCommand):
             "position_id": obj.position_id.to_str() if obj.position_id is not None else None,
             "command_id": obj.id.to_str(),
             "ts_init": obj.ts_init,
+            "ts_deadline": obj.ts_deadline,
         }
 
     @staticmethod
@@ -372,6 +408,9 @@ cdef class ModifyOrder(TradingCommand):
         UNIX timestamp (nanoseconds) when the object was initialized.
     client_id : ClientId, optional
         The execution client ID for the command.
+    ts_deadline : uint64_t, default 0
+        UNIX timestamp (nanoseconds) after which the command will be aborted
+        rather than sent to the venue (0 for no deadline).
 
     References
     ----------
@@ -391,6 +430,7 @@ cdef class ModifyOrder(TradingCommand):
         UUID4 command_id not None,
         uint64_t ts_init,
         ClientId client_id = None,
+        uint64_t ts_deadline = 0,
     ):
         super().__init__(
             client_id=client_id,
@@ -399,6 +439,7 @@ cdef class ModifyOrder(TradingCommand):
             instrument_id=instrument_id,
             command_id=command_id,
             ts_init=ts_init,
+            ts_deadline=ts_deadline,
         )
 
         self.client_order_id = client_order_id
@@ -454,6 +495,7 @@ cdef class ModifyOrder(TradingCommand):
             trigger_price=Price.from_str_c(t) if t is not None else None,
             command_id=UUID4(values["command_id"]),
             ts_init=values["ts_init"],
+            ts_deadline=values.get("ts_deadline", 0),
         )
 
     @staticmethod
@@ -472,6 +514,7 @@ cdef class ModifyOrder(TradingCommand):
             "trigger_price": str(obj.trigger_price) if obj.trigger_price is not None else None,
             "command_id": obj.id.to_str(),
             "ts_init": obj.ts_init,
+            "ts_deadline": obj.ts_deadline,
         }
 
     @staticmethod
@@ -526,6 +569,9 @@ cdef class CancelOrder(TradingCommand):
         UNIX timestamp (nanoseconds) when the object was initialized.
     client_id : ClientId, optional
         The execution client ID for the command.
+    ts_deadline : uint64_t, default 0
+        UNIX timestamp (nanoseconds) after which the command will be aborted
+        rather than sent to the venue (0 for no deadline).
 
     References
     ----------
@@ -542,6 +588,7 @@ cdef class CancelOrder(TradingCommand):
         UUID4 command_id not None,
         uint64_t ts_init,
         ClientId client_id = None,
+        uint64_t ts_deadline = 0,
     ):
         if client_id is None:
             client_id = ClientId(instrument_id.venue.value)
@@ -552,6 +599,7 @@ cdef class CancelOrder(TradingCommand):
             instrument_id=instrument_id,
             command_id=command_id,
             ts_init=ts_init,
+            ts_deadline=ts_deadline,
         )
 
         self.client_order_id = client_order_id
@@ -592,6 +640,7 @@ cdef class CancelOrder(TradingCommand):
             venue_order_id=VenueOrderId(v) if v is not None else None,
             command_id=UUID4(values["command_id"]),
             ts_init=values["ts_init"],
+            ts_deadline=values.get("ts_deadline", 0),
         )
 
     @staticmethod
@@ -607,6 +656,7 @@ cdef class CancelOrder(TradingCommand):
             "venue_order_id": obj.venue_order_id.to_str() if obj.venue_order_id is not None else None,
             "command_id": obj.id.to_str(),
             "ts_init": obj.ts_init,
+            "ts_deadline": obj.ts_deadline,
         }
 
     @staticmethod
@@ -659,6 +709,9 @@ cdef class CancelAllOrders(TradingCommand):
         UNIX timestamp (nanoseconds) when the object was initialized.
     client_id : ClientId, optional
         The execution client ID for the command.
+    ts_deadline : uint64_t, default 0
+        UNIX timestamp (nanoseconds) after which the command will be aborted
+        rather than sent to the venue (0 for no deadline).
     """
 
     def __init__(
@@ -670,6 +723,7 @@ cdef class CancelAllOrders(TradingCommand):
         UUID4 command_id not None,
         uint64_t ts_init,
         ClientId client_id = None,
+        uint64_t ts_deadline = 0,
     ):
         super().__init__(
             client_id=client_id,
@@ -678,6 +732,7 @@ cdef class CancelAllOrders(TradingCommand):
             instrument_id=instrument_id,
             command_id=command_id,
             ts_init=ts_init,
+            ts_deadline=ts_deadline,
         )
 
         self.order_side = order_side
@@ -713,6 +768,7 @@ cdef class CancelAllOrders(TradingCommand):
             order_side=order_side_from_str(values["order_side"]),
             command_id=UUID4(values["command_id"]),
             ts_init=values["ts_init"],
+            ts_deadline=values.get("ts_deadline", 0),
         )
 
     @staticmethod
@@ -727,6 +783,7 @@ cdef class CancelAllOrders(TradingCommand):
             "order_side": order_side_to_str(obj.order_side),
             "command_id": obj.id.to_str(),
             "ts_init": obj.ts_init,
+            "ts_deadline": obj.ts_deadline,
         }
 
     @staticmethod
@@ -779,6 +836,9 @@ cdef class BatchCancelOrders(TradingCommand):
         UNIX timestamp (nanoseconds) when the object was initialized.
     client_id : ClientId, optional
         The execution client ID for the command.
+    ts_deadline : uint64_t, default 0
+        UNIX timestamp (nanoseconds) after which the command will be aborted
+        rather than sent to the venue (0 for no deadline).
 
     Raises
     ------
@@ -797,6 +857,7 @@ cdef class BatchCancelOrders(TradingCommand):
         UUID4 command_id not None,
         uint64_t ts_init,
         ClientId client_id = None,
+        uint64_t ts_deadline = 0,
     ):
         Condition.not_empty(cancels, "cancels")
         Condition.list_type(cancels, CancelOrder, "cancels")
@@ -807,6 +868,7 @@ cdef class BatchCancelOrders(TradingCommand):
             instrument_id=instrument_id,
             command_id=command_id,
             ts_init=ts_init,
+            ts_deadline=ts_deadline,
         )
 
         self.cancels = cancels
@@ -842,6 +904,7 @@ cdef class BatchCancelOrders(TradingCommand):
             cancels=[CancelOrder.from_dict_c(cancel) for cancel in values["cancels"]],
             command_id=UUID4(values["command_id"]),
             ts_init=values["ts_init"],
+            ts_deadline=values.get("ts_deadline", 0),
         )
 
     @staticmethod
@@ -856,6 +919,7 @@ cdef class BatchCancelOrders(TradingCommand):
             "cancels": [CancelOrder.to_dict_c(cancel) for cancel in obj.cancels],
             "command_id": obj.id.to_str(),
             "ts_init": obj.ts_init,
+            "ts_deadline": obj.ts_deadline,
         }
 
     @staticmethod
@@ -910,6 +974,9 @@ cdef class QueryOrder(TradingCommand):
         UNIX timestamp (nanoseconds) when the object was initialized.
     client_id : ClientId, optional
         The execution client ID for the command.
+    ts_deadline : uint64_t, default 0
+        UNIX timestamp (nanoseconds) after which the command will be aborted
+        rather than sent to the venue (0 for no deadline).
     """
 
     def __init__(
@@ -922,6 +989,7 @@ cdef class QueryOrder(TradingCommand):
         UUID4 command_id not None,
         uint64_t ts_init,
         ClientId client_id = None,
+        uint64_t ts_deadline = 0,
     ):
         if client_id is None:
             client_id = ClientId(instrument_id.venue.value)
@@ -932,6 +1000,7 @@ cdef class QueryOrder(TradingCommand):
             instrument_id=instrument_id,
             command_id=command_id,
             ts_init=ts_init,
+            ts_deadline=ts_deadline,
         )
 
         self.client_order_id = client_order_id
@@ -972,6 +1041,7 @@ cdef class QueryOrder(TradingCommand):
             venue_order_id=VenueOrderId(v) if v is not None else None,
             command_id=UUID4(values["command_id"]),
             ts_init=values["ts_init"],
+            ts_deadline=values.get("ts_deadline", 0),
         )
 
     @staticmethod
@@ -987,6 +1057,7 @@ cdef class QueryOrder(TradingCommand):
             "venue_order_id": obj.venue_order_id.to_str() if obj.venue_order_id is not None else None,
             "command_id": obj.id.to_str(),
             "ts_init": obj.ts_init,
+            "ts_deadline": obj.ts_deadline,
         }
 
     @staticmethod
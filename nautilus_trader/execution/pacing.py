@@ -0,0 +1,222 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import random
+from collections import deque
+from collections.abc import Callable
+from datetime import timedelta
+from typing import Any
+
+from nautilus_trader.common.component import Clock
+from nautilus_trader.common.component import TimeEvent
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.datetime import secs_to_nanos
+
+
+class OrderPacer:
+    """
+    Provides a pacer which schedules child order submissions for execution algorithms.
+
+    Submissions are released in FIFO order, at most `limit` within any rolling
+    `interval`, with each release delayed by a uniformly random jitter within
+    [`jitter_min`, `jitter_max`] once it is within the rate budget. Scheduling is
+    driven by time alerts on the given clock, so pacing is deterministic in backtests
+    when a `seed` is provided.
+
+    Parameters
+    ----------
+    name : str
+        The unique name of the pacer (used for the clock time alert).
+    limit : int
+        The maximum number of submissions within any `interval`.
+    interval : timedelta
+        The rolling interval for the rate budget.
+    clock : Clock
+        The clock for the pacer.
+    output_send : Callable[[Any], None]
+        The output handler to submit orders from the pacer (e.g. `ExecAlgorithm.submit_order`).
+    jitter_min : timedelta, default timedelta(0)
+        The minimum random delay applied to each submission.
+    jitter_max : timedelta, default timedelta(0)
+        The maximum random delay applied to each submission.
+    seed : int, optional
+        The random seed for the jitter.
+
+    Raises
+    ------
+    ValueError
+        If `name` is not a valid string.
+    ValueError
+        If `limit` is not positive (> 0).
+    ValueError
+        If `interval` is not positive (> 0).
+    ValueError
+        If `jitter_min` is negative or greater than `jitter_max`.
+
+    Warnings
+    --------
+    This pacer is not thread-safe and must be called from the same thread as
+    the event loop.
+
+    """
+
+    def __init__(
+        self,
+        name: str,
+        limit: int,
+        interval: timedelta,
+        clock: Clock,
+        output_send: Callable[[Any], None],
+        jitter_min: timedelta = timedelta(0),
+        jitter_max: timedelta = timedelta(0),
+        seed: int | None = None,
+    ) -> None:
+        PyCondition.valid_string(name, "name")
+        PyCondition.positive_int(limit, "limit")
+        PyCondition.positive(interval.total_seconds(), "interval.total_seconds()")
+        PyCondition.callable(output_send, "output_send")
+        PyCondition.not_negative(jitter_min.total_seconds(), "jitter_min.total_seconds()")
+        PyCondition.is_true(jitter_min <= jitter_max, "jitter_min was > jitter_max")
+
+        self._clock = clock
+        self._interval_ns = secs_to_nanos(interval.total_seconds())
+        self._jitter_min_ns = secs_to_nanos(jitter_min.total_seconds())
+        self._jitter_max_ns = secs_to_nanos(jitter_max.total_seconds())
+        self._rng = random.Random(seed)  # noqa: S311
+        self._buffer: deque[Any] = deque()
+        self._timestamps: deque[int] = deque()
+        self._timer_name = f"{name}|PACE"
+        self._output_send = output_send
+
+        self.name = name
+        self.limit = limit
+        self.interval = interval
+        self.is_pacing = False
+        self.recv_count = 0
+        self.sent_count = 0
+
+    @property
+    def qsize(self) -> int:
+        """
+        Return the number of submissions waiting to be released.
+
+        Returns
+        -------
+        int
+
+        """
+        return len(self._buffer)
+
+    def used(self) -> int:
+        """
+        Return the number of submissions released within the current rolling interval.
+
+        Returns
+        -------
+        int
+
+        """
+        self._expire_timestamps(self._clock.timestamp_ns())
+        return len(self._timestamps)
+
+    def submit(self, order: Any) -> None:
+        """
+        Submit the given order to be released by the pacer.
+
+        Parameters
+        ----------
+        order : Any
+            The order (or command) to release.
+
+        """
+        self.recv_count += 1
+        self._buffer.append(order)
+        if not self.is_pacing:
+            self._schedule_next()
+
+    def cancel(self) -> list[Any]:
+        """
+        Cancel the pacer, returning all submissions which were not yet released.
+
+        Returns
+        -------
+        list[Any]
+
+        """
+        if self._timer_name in self._clock.timer_names:
+            self._clock.cancel_timer(self._timer_name)
+
+        pending = list(self._buffer)
+        self._buffer.clear()
+        self.is_pacing = False
+        return pending
+
+    def reset(self) -> None:
+        """
+        Reset the state of the pacer (pending submissions are discarded).
+
+        """
+        self.cancel()
+        self._timestamps.clear()
+        self.recv_count = 0
+        self.sent_count = 0
+
+    def _schedule_next(self) -> None:
+        while self._buffer:
+            now_ns = self._clock.timestamp_ns()
+            delay_ns = self._delta_next(now_ns) + self._next_jitter_ns()
+            if delay_ns <= 0:
+                self._send(self._buffer.popleft())
+                continue
+
+            if self._timer_name in self._clock.timer_names:
+                self._clock.cancel_timer(self._timer_name)
+
+            self._clock.set_time_alert_ns(
+                name=self._timer_name,
+                alert_time_ns=now_ns + delay_ns,
+                callback=self._release,
+            )
+            self.is_pacing = True
+            return
+
+        self.is_pacing = False
+
+    def _release(self, event: TimeEvent) -> None:
+        if self._buffer:
+            self._send(self._buffer.popleft())
+        self._schedule_next()
+
+    def _delta_next(self, now_ns: int) -> int:
+        self._expire_timestamps(now_ns)
+        if len(self._timestamps) < self.limit:
+            return 0
+
+        # Wait until the oldest release in the window rolls out of the interval
+        return self._timestamps[0] + self._interval_ns - now_ns
+
+    def _expire_timestamps(self, now_ns: int) -> None:
+        while self._timestamps and self._timestamps[0] + self._interval_ns <= now_ns:
+            self._timestamps.popleft()
+
+    def _next_jitter_ns(self) -> int:
+        if self._jitter_max_ns == 0:
+            return 0
+        return self._rng.randint(self._jitter_min_ns, self._jitter_max_ns)
+
+    def _send(self, order: Any) -> None:
+        self._timestamps.append(self._clock.timestamp_ns())
+        self._output_send(order)
+        self.sent_count += 1
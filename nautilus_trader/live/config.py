@@ -15,6 +15,8 @@
 
 from __future__ import annotations
 
+from typing import Literal
+
 import msgspec
 
 from nautilus_trader.common import Environment
@@ -87,6 +89,11 @@ class LiveExecEngineConfig(ExecEngineConfig, frozen=True):
     generate_missing_orders : bool, default True
         If MARKET order events will be generated during reconciliation to align discrepancies
         between internal and external positions.
+    unknown_open_orders_policy : {'ADOPT', 'CANCEL', 'LEAVE'}, default 'ADOPT'
+        The policy for open orders discovered at the venue during reconciliation which are
+        not known to the system (e.g. after a crash).
+        'ADOPT' reconciles the orders into the cache, 'CANCEL' reconciles then cancels the
+        orders at the venue (at most once per order), and 'LEAVE' ignores them entirely.
     inflight_check_interval_ms : NonNegativeInt, default 2_000
         The interval (milliseconds) between checking whether in-flight orders
         have exceeded their time-in-flight threshold.
@@ -113,6 +120,7 @@ class LiveExecEngineConfig(ExecEngineConfig, frozen=True):
     filter_unclaimed_external_orders: bool = False
     filter_position_reports: bool = False
     generate_missing_orders: bool = True
+    unknown_open_orders_policy: Literal["ADOPT", "CANCEL", "LEAVE"] = "ADOPT"
     inflight_check_interval_ms: NonNegativeInt = 2_000
     inflight_check_threshold_ms: NonNegativeInt = 5_000
     inflight_check_retries: NonNegativeInt = 5
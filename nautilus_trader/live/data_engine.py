@@ -27,6 +27,7 @@ from nautilus_trader.data.engine import DataEngine
 from nautilus_trader.data.messages import DataCommand
 from nautilus_trader.data.messages import DataRequest
 from nautilus_trader.data.messages import DataResponse
+from nautilus_trader.model.identifiers import ClientId
 
 
 class LiveDataEngine(DataEngine):
@@ -317,7 +318,7 @@ class LiveDataEngine(DataEngine):
             # Schedule the `put` operation to be executed once there is space in the queue
             self._loop.create_task(self._res_queue.put(response))
 
-    def process(self, data: Data) -> None:
+    def process(self, data: Data, client_id: ClientId | None = None) -> None:
         """
         Process the given data.
 
@@ -328,6 +329,8 @@ class LiveDataEngine(DataEngine):
         ----------
         data : Data
             The data to process.
+        client_id : ClientId, optional
+            The client ID which provided the data (if known).
 
         Warnings
         --------
@@ -339,14 +342,14 @@ class LiveDataEngine(DataEngine):
         # Do not allow None through (None is a sentinel value which stops the queue)
 
         try:
-            self._loop.call_soon_threadsafe(self._data_queue.put_nowait, data)
+            self._loop.call_soon_threadsafe(self._data_queue.put_nowait, (data, client_id))
         except asyncio.QueueFull:
             self._log.warning(
                 f"Blocking on `_data_queue.put` as queue full at "
                 f"{self._data_queue.qsize():_} items",
             )
             # Schedule the `put` operation to be executed once there is space in the queue
-            self._loop.create_task(self._data_queue.put(data))
+            self._loop.create_task(self._data_queue.put((data, client_id)))
 
     # -- INTERNAL -------------------------------------------------------------------------------------
 
@@ -445,10 +448,10 @@ class LiveDataEngine(DataEngine):
         self._log.debug(f"Data queue processing starting (qsize={self.data_qsize()})")
         try:
             while True:
-                data: Data | None = await self._data_queue.get()
-                if data is self._sentinel:
+                item: tuple[Data, ClientId | None] | None = await self._data_queue.get()
+                if item is self._sentinel:
                     break
-                self._handle_data(data)
+                self._handle_data(*item)
         except asyncio.CancelledError:
             self._log.warning("Data message queue canceled")
         except Exception as e:
@@ -32,6 +32,7 @@ from nautilus_trader.core.datetime import millis_to_nanos
 from nautilus_trader.core.fsm import InvalidStateTrigger
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.execution.engine import ExecutionEngine
+from nautilus_trader.execution.messages import CancelOrder
 from nautilus_trader.execution.messages import QueryOrder
 from nautilus_trader.execution.messages import TradingCommand
 from nautilus_trader.execution.reports import ExecutionMassStatus
@@ -72,6 +73,9 @@ from nautilus_trader.model.orders import OrderUnpacker
 from nautilus_trader.model.position import Position
 
 
+UNKNOWN_OPEN_ORDERS_POLICIES: Final[frozenset[str]] = frozenset({"ADOPT", "CANCEL", "LEAVE"})
+
+
 class LiveExecutionEngine(ExecutionEngine):
     """
     Provides a high-performance asynchronous live execution engine.
@@ -120,6 +124,7 @@ class LiveExecutionEngine(ExecutionEngine):
         self._cmd_queue: asyncio.Queue = Queue(maxsize=config.qsize)
         self._evt_queue: asyncio.Queue = Queue(maxsize=config.qsize)
         self._inflight_check_retries: Counter[ClientOrderId] = Counter()
+        self._unknown_order_cancels: set[VenueOrderId] = set()
 
         # Async tasks
         self._cmd_queue_task: asyncio.Task | None = None
@@ -134,16 +139,26 @@ class LiveExecutionEngine(ExecutionEngine):
         self.filter_unclaimed_external_orders: bool = config.filter_unclaimed_external_orders
         self.filter_position_reports: bool = config.filter_position_reports
         self.generate_missing_orders: bool = config.generate_missing_orders
+        self.unknown_open_orders_policy: str = config.unknown_open_orders_policy
         self.inflight_check_interval_ms: int = config.inflight_check_interval_ms
         self.inflight_check_threshold_ms: int = config.inflight_check_threshold_ms
         self.inflight_check_max_retries: int = config.inflight_check_retries
         self.open_check_interval_secs: float | None = config.open_check_interval_secs
         self._inflight_check_threshold_ns: int = millis_to_nanos(self.inflight_check_threshold_ms)
 
+        PyCondition.is_in(
+            self.unknown_open_orders_policy,
+            UNKNOWN_OPEN_ORDERS_POLICIES,
+            "unknown_open_orders_policy",
+            "UNKNOWN_OPEN_ORDERS_POLICIES",
+            ex_type=ValueError,
+        )
+
         self._log.info(f"{config.reconciliation=}", LogColor.BLUE)
         self._log.info(f"{config.reconciliation_lookback_mins=}", LogColor.BLUE)
         self._log.info(f"{config.filter_unclaimed_external_orders=}", LogColor.BLUE)
         self._log.info(f"{config.filter_position_reports=}", LogColor.BLUE)
+        self._log.info(f"{config.unknown_open_orders_policy=}", LogColor.BLUE)
         self._log.info(f"{config.inflight_check_interval_ms=}", LogColor.BLUE)
         self._log.info(f"{config.inflight_check_threshold_ms=}", LogColor.BLUE)
         self._log.info(f"{config.inflight_check_retries=}", LogColor.BLUE)
@@ -660,6 +675,7 @@ class LiveExecutionEngine(ExecutionEngine):
         results: list[bool] = []
         reconciled_orders: set[ClientOrderId] = set()
         reconciled_trades: set[TradeId] = set()
+        unknown_orders: Counter[str] = Counter()
 
         # Reconcile all reported orders
         for venue_order_id, order_report in mass_status.order_reports.items():
@@ -679,11 +695,23 @@ class LiveExecutionEngine(ExecutionEngine):
                     )
                 reconciled_trades.add(fill_report.trade_id)
 
+            is_unknown_open = self._is_unknown_open_order(order_report)
+            if is_unknown_open:
+                unknown_orders[self.unknown_open_orders_policy] += 1
+                if self.unknown_open_orders_policy == "LEAVE":
+                    self._log.warning(f"Leaving unknown open order at venue: {order_report}")
+                    results.append(True)
+                    continue
+
             try:
                 result = self._reconcile_order_report(order_report, trades)
             except InvalidStateTrigger as e:
                 self._log.error(str(e))
                 result = False
+            else:
+                # Only cancel unknown orders which reconciled into a valid state
+                if is_unknown_open and self.unknown_open_orders_policy == "CANCEL":
+                    self._cancel_unknown_order(order_report)
             results.append(result)
             reconciled_orders.add(order_report.client_order_id)
 
@@ -695,6 +723,12 @@ class LiveExecutionEngine(ExecutionEngine):
                     result = self._reconcile_position_report(report)
                     results.append(result)
 
+        if unknown_orders:
+            self._log.warning(
+                f"Unknown open orders for {mass_status.venue}: "
+                f"policy={self.unknown_open_orders_policy}, count={unknown_orders.total()}",
+            )
+
         # Publish mass status
         self._msgbus.publish(
             topic=f"reports.execution.{mass_status.venue}",
@@ -703,6 +737,34 @@ class LiveExecutionEngine(ExecutionEngine):
 
         return all(results)
 
+    def _is_unknown_open_order(self, report: OrderStatusReport) -> bool:
+        if not report.is_open:
+            return False
+        if report.client_order_id is not None and self._cache.order(report.client_order_id):
+            return False
+        return self._cache.client_order_id(report.venue_order_id) is None
+
+    def _cancel_unknown_order(self, report: OrderStatusReport) -> None:
+        if report.venue_order_id in self._unknown_order_cancels:
+            self._log.debug(f"Cancel already sent for unknown order {report.venue_order_id!r}")
+            return  # Idempotent
+
+        order: Order | None = self._cache.order(report.client_order_id)
+        strategy_id = order.strategy_id if order is not None else StrategyId("EXTERNAL")
+
+        command = CancelOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy_id,
+            instrument_id=report.instrument_id,
+            client_order_id=report.client_order_id,
+            venue_order_id=report.venue_order_id,
+            command_id=UUID4(),
+            ts_init=self._clock.timestamp_ns(),
+        )
+        self._log.warning(f"Canceling unknown open order at venue: {report}")
+        self._unknown_order_cancels.add(report.venue_order_id)
+        self._execute_command(command)
+
     def _reconcile_order_report(  # noqa: C901 (too complex)
         self,
         report: OrderStatusReport,
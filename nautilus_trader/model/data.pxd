@@ -99,6 +99,8 @@ cpdef enum BarAggregation:
     DAY = 14
     WEEK = 15
     MONTH = 16
+    RENKO = 17
+    RANGE = 18
 
 
 cdef class BarSpecification:
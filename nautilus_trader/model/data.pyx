@@ -427,6 +427,8 @@ cdef class BarSpecification:
             or aggregation == BarAggregation.VOLUME_IMBALANCE
             or aggregation == BarAggregation.VALUE
             or aggregation == BarAggregation.VALUE_IMBALANCE
+            or aggregation == BarAggregation.RENKO
+            or aggregation == BarAggregation.RANGE
         ):
             return True
         else:
@@ -598,6 +600,8 @@ cdef class BarSpecification:
         - ``VOLUME_IMBALANCE``
         - ``VALUE``
         - ``VALUE_IMBALANCE``
+        - ``RENKO``
+        - ``RANGE``
 
         Returns
         -------
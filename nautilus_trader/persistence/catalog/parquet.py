@@ -399,6 +399,146 @@ class ParquetDataCatalog(BaseDataCatalog):
                 **kwargs,
             )
 
+    def consolidate(
+        self,
+        data_cls: type | None = None,
+        instrument_ids: list[str] | None = None,
+        max_file_size: int = 128 * 1024 * 1024,
+        deduplicate: bool = True,
+        basename_template: str = "part-{i}",
+    ) -> list[str]:
+        """
+        Consolidate the small Parquet files in the catalog into larger sorted files.
+
+        For each data directory, all files smaller than `max_file_size` are read, sorted by
+        `ts_init` and rewritten as a single file, which then replaces the source files.
+        Directories with fewer than two small files are left unchanged.
+
+        Parameters
+        ----------
+        data_cls : type, optional
+            The data class to consolidate. If ``None`` then all data types are consolidated.
+        instrument_ids : list[str], optional
+            The instrument IDs (or bar types) to consolidate. If ``None`` then all are consolidated.
+        max_file_size : int, default 128 MiB
+            The size (bytes) below which a file is considered small and will be consolidated.
+        deduplicate : bool, default True
+            If overlapping rows should be deduplicated. Rows are considered duplicates only
+            if all of their values are identical.
+        basename_template : str, default 'part-{i}'
+            The template used to name the consolidated file, the token '{i}' will be replaced
+            with the first index which is not already used in the directory.
+
+        Returns
+        -------
+        list[str]
+            The paths of the consolidated files written.
+
+        Raises
+        ------
+        ValueError
+            If a source file is not monotonically increasing (or non-decreasing) based on `ts_init`.
+
+        """
+        PyCondition.positive_int(max_file_size, "max_file_size")
+
+        if data_cls is not None:
+            type_dirs = [self._make_path(data_cls=data_cls)]
+        else:
+            type_dirs = [p for p in self.fs.glob(f"{self.path}/data/*") if self.fs.isdir(p)]
+
+        written: list[str] = []
+        for type_dir in type_dirs:
+            if instrument_ids is not None:
+                dirs = [f"{type_dir}/{urisafe_instrument_id(i)}" for i in instrument_ids]
+            else:
+                dirs = [type_dir, *[p for p in self.fs.glob(f"{type_dir}/*") if self.fs.isdir(p)]]
+
+            for directory in dirs:
+                path = self._consolidate_directory(
+                    directory=directory,
+                    max_file_size=max_file_size,
+                    deduplicate=deduplicate,
+                    basename_template=basename_template,
+                )
+                if path is not None:
+                    written.append(path)
+
+        return written
+
+    def _consolidate_directory(
+        self,
+        directory: str,
+        max_file_size: int,
+        deduplicate: bool,
+        basename_template: str,
+    ) -> str | None:
+        if not self.fs.isdir(directory):
+            return None
+
+        files = sorted(
+            f for f in self.fs.glob(f"{directory}/*.parquet") if self.fs.size(f) < max_file_size
+        )
+        if len(files) < 2:
+            return None
+
+        tables = []
+        for file in files:
+            table = pq.read_table(file, filesystem=self.fs)
+            ts_init = table.column("ts_init").to_numpy()
+            if (ts_init[1:] < ts_init[:-1]).any():
+                raise ValueError(
+                    f"Data should be monotonically increasing (or non-decreasing) based on "
+                    f"`ts_init`, was not for {file}",
+                )
+            tables.append(table)
+
+        schema = tables[0].schema
+        table = pa.concat_tables([t.cast(schema) for t in tables])
+        if deduplicate:
+            table = self._deduplicate_table(table)
+        table = table.sort_by("ts_init")  # Stable, so retains the order of rows with equal `ts_init`
+
+        # Write alongside the source files under a name ignored by dataset discovery
+        tmp_file = f"{directory}/_consolidating.parquet"
+        pq.write_table(
+            table,
+            where=tmp_file,
+            filesystem=self.fs,
+            row_group_size=self.max_rows_per_group,
+        )
+
+        # Move the consolidated file into place before removing the source files,
+        # so that a failure part way through never loses data
+        existing = {Path(f).stem for f in self.fs.glob(f"{directory}/*.parquet")}
+        name = next(
+            basename_template.format(i=i)
+            for i in itertools.count()
+            if basename_template.format(i=i) not in existing
+        )
+        consolidated_file = f"{directory}/{name}.parquet"
+        self.fs.mv(tmp_file, consolidated_file)
+        self.fs.rm(files)
+
+        return consolidated_file
+
+    @staticmethod
+    def _deduplicate_table(table: pa.Table) -> pa.Table:
+        # Only identical rows are duplicates, as e.g. order book deltas can share
+        # the same `ts_event` and `sequence` while describing different orders
+        keys = table.column_names
+
+        # Keep the first occurrence of each row
+        index = pa.array(range(len(table)), type=pa.int64())
+        first = (
+            table.select(keys)
+            .append_column("__index", index)
+            .group_by(keys, use_threads=False)
+            .aggregate([("__index", "min")])
+            .column("__index_min")
+        )
+        return table.take(first.sort())
+
     # -- QUERIES ----------------------------------------------------------------------------------
 
     def query(
@@ -14,6 +14,7 @@
 # -------------------------------------------------------------------------------------------------
 
 import datetime as dt
+from dataclasses import dataclass
 from enum import Enum
 from io import TextIOWrapper
 from typing import Any, BinaryIO
@@ -35,6 +36,7 @@ from nautilus_trader.model.data import OrderBookDelta
 from nautilus_trader.model.data import OrderBookDeltas
 from nautilus_trader.model.data import QuoteTick
 from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.identifiers import InstrumentId
 from nautilus_trader.persistence.funcs import class_to_filename
 from nautilus_trader.persistence.funcs import urisafe_instrument_id
 from nautilus_trader.serialization.arrow.serializer import ArrowSerializer
@@ -48,6 +50,28 @@ class RotationMode(Enum):
     NO_ROTATION = 3
 
 
+@dataclass(frozen=True)
+class RecordingCommand:
+    """
+    Represents a command to start or stop recording a data type for specific
+    instruments at runtime.
+
+    Parameters
+    ----------
+    data_cls : type
+        The data type to adjust recording for (e.g. `QuoteTick`, `OrderBookDelta`).
+    instrument_ids : list[InstrumentId]
+        The instrument IDs to start or stop recording.
+    start : bool, default True
+        If recording should be started, otherwise stopped.
+
+    """
+
+    data_cls: type
+    instrument_ids: list[InstrumentId]
+    start: bool = True
+
+
 class StreamingFeatherWriter:
     """
     Provides a stream writer of Nautilus objects into feather files with rotation
@@ -80,6 +104,9 @@ class StreamingFeatherWriter:
         The time of day for file rotation (for `SCHEDULED_DATES` mode).
     rotation_timezone : str, default 'UTC'
         The timezone for rotation calculations(for `SCHEDULED_DATES` mode).
+    include_instrument_ids : dict[type, list[InstrumentId]], optional
+        The initial per instrument recording filters for each data type.
+        If a type is specified then **only** the included instruments will be written for it.
 
     """
 
@@ -97,6 +124,7 @@ class StreamingFeatherWriter:
         rotation_interval: pd.Timedelta = pd.Timedelta(days=1),
         rotation_time: dt.time = dt.time(0, 0, 0, 0),
         rotation_timezone: str = "UTC",
+        include_instrument_ids: dict[type, list[InstrumentId]] | None = None,
     ) -> None:
         self.path = path
         self.cache = cache
@@ -137,6 +165,10 @@ class StreamingFeatherWriter:
         self._file_sizes: dict[str | tuple[str, str], int] = {}
         self._file_creation_times: dict[str | tuple[str, str], pd.Timestamp] = {}
         self._next_rotation_times: dict[str | tuple[str, str], pd.Timestamp | None] = {}
+        self._recording_filters: dict[type, set[InstrumentId]] = {}
+        self._recording_exclusions: dict[type, set[InstrumentId]] = {}
+        for cls, instrument_ids in (include_instrument_ids or {}).items():
+            self.start_recording(cls, instrument_ids)
 
         self._create_writers()
 
@@ -298,7 +330,15 @@ class StreamingFeatherWriter:
         self.fs.makedirs(folder, exist_ok=True)
 
         timestamp = self.clock.timestamp_ns()
-        full_path = f"{folder}/{urisafe_instrument_id(obj.instrument_id.value)}_{timestamp}.feather"
+        stem = f"{folder}/{urisafe_instrument_id(obj.instrument_id.value)}_{timestamp}"
+
+        # A writer recreated at the same timestamp (such as when recording is resumed)
+        # must not reopen and truncate the previous file, so a sequence suffix is added
+        full_path = f"{stem}.feather"
+        sequence = 1
+        while self.fs.exists(full_path):
+            full_path = f"{stem}_{sequence}.feather"
+            sequence += 1
 
         f = self.fs.open(full_path, "wb")
         self._files[key] = f
@@ -362,6 +402,9 @@ class StreamingFeatherWriter:
         if self.include_types is not None and cls not in self.include_types:
             return
 
+        if not self._is_recording(obj):
+            return
+
         if isinstance(obj, CustomData):
             cls = obj.data_type.type
 
@@ -414,6 +457,144 @@ class StreamingFeatherWriter:
             self.logger.error(f"ERROR = `{e}`")
             self.logger.debug(f"data = {obj}")
 
+    def start_recording(self, cls: type, instrument_ids: list[InstrumentId]) -> None:
+        """
+        Start recording the given data type for the given instruments.
+
+        Once a recording filter exists for a data type, **only** the instruments
+        within the filter will be written for it. If instead the data type is
+        being recorded for all instruments other than those previously stopped,
+        then recording is resumed for the given instruments.
+
+        Parameters
+        ----------
+        cls : type
+            The data type to record.
+        instrument_ids : list[InstrumentId]
+            The instrument IDs to record.
+
+        """
+        PyCondition.not_none(cls, "cls")
+        PyCondition.not_none(instrument_ids, "instrument_ids")
+
+        cls = self._filter_cls(cls)
+        if cls in self._recording_exclusions:
+            # Recording all instruments, so resume only the given instruments
+            self._recording_exclusions[cls].difference_update(instrument_ids)
+        else:
+            self._recording_filters.setdefault(cls, set()).update(instrument_ids)
+        self.logger.info(
+            f"Started recording {cls.__name__} for {sorted(i.value for i in instrument_ids)}",
+        )
+
+    def stop_recording(
+        self,
+        cls: type,
+        instrument_ids: list[InstrumentId] | None = None,
+    ) -> None:
+        """
+        Stop recording the given data type for the given instruments.
+
+        Any open per instrument writers for the instruments are flushed and closed.
+        If no recording filter exists for the data type (all instruments are being
+        recorded), then only the given instruments are excluded from recording.
+
+        Parameters
+        ----------
+        cls : type
+            The data type to stop recording.
+        instrument_ids : list[InstrumentId], optional
+            The instrument IDs to stop recording. If ``None`` then recording is
+            stopped for all instruments of the data type.
+
+        """
+        PyCondition.not_none(cls, "cls")
+
+        cls = self._filter_cls(cls)
+        filtered = self._recording_filters.get(cls)
+        if instrument_ids is None:
+            self._recording_filters[cls] = set()
+            self._recording_exclusions.pop(cls, None)
+        elif filtered is None:
+            # Recording all instruments, so exclude only the given instruments
+            self._recording_exclusions.setdefault(cls, set()).update(instrument_ids)
+        else:
+            filtered.difference_update(instrument_ids)
+
+        table_name = class_to_filename(cls)
+        for key in tuple(self._instrument_writers):
+            if key[0] != table_name:
+                continue
+            if instrument_ids is not None and key[1] not in {i.value for i in instrument_ids}:
+                continue
+            self._files[key].flush()
+            self._instrument_writers[key].close()
+            self._files[key].close()
+            del self._instrument_writers[key]
+            del self._files[key]
+
+        self.logger.info(f"Stopped recording {cls.__name__} for {instrument_ids or 'all instruments'}")
+
+    def recording_instrument_ids(self, cls: type) -> set[InstrumentId] | None:
+        """
+        Return the instrument IDs currently being recorded for the given data type.
+
+        Parameters
+        ----------
+        cls : type
+            The data type for the query.
+
+        Returns
+        -------
+        set[InstrumentId] or ``None``
+            ``None`` if all instruments are recorded. If recording was stopped for
+            some instruments only, then the cached instruments other than those.
+
+        """
+        cls = self._filter_cls(cls)
+        excluded = self._recording_exclusions.get(cls)
+        if excluded:
+            return {i for i in self.cache.instrument_ids() if i not in excluded}
+
+        filtered = self._recording_filters.get(cls)
+        return None if filtered is None else set(filtered)
+
+    def execute(self, command: RecordingCommand) -> None:
+        """
+        Execute the given recording command.
+
+        Parameters
+        ----------
+        command : RecordingCommand
+            The command to execute.
+
+        """
+        if command.start:
+            self.start_recording(command.data_cls, command.instrument_ids)
+        else:
+            self.stop_recording(command.data_cls, command.instrument_ids)
+
+    def _filter_cls(self, cls: type) -> type:
+        return {OrderBookDeltas: OrderBookDelta}.get(cls, cls)
+
+    def _is_recording(self, obj: object) -> bool:
+        cls = self._filter_cls(obj.__class__)
+        filtered = self._recording_filters.get(cls)
+        excluded = self._recording_exclusions.get(cls)
+        if filtered is None and not excluded:
+            return True
+
+        if isinstance(obj, Bar):
+            instrument_id = obj.bar_type.instrument_id
+        else:
+            instrument_id = getattr(obj, "instrument_id", None)
+
+        if instrument_id is None:
+            return True
+        if filtered is None:
+            return instrument_id not in excluded
+        return instrument_id in filtered
+
     def check_flush(self) -> None:
         """
         Flush all stream writers if current time greater than the next flush interval.
@@ -36,6 +36,7 @@ cdef class PortfolioFacade:
     cpdef dict margins_maint(self, Venue venue)
     cpdef dict unrealized_pnls(self, Venue venue)
     cpdef dict realized_pnls(self, Venue venue)
+    cpdef dict daily_pnls(self, Venue venue)
     cpdef dict net_exposures(self, Venue venue)
 
     cpdef Money unrealized_pnl(self, InstrumentId instrument_id)
@@ -50,6 +50,10 @@ cdef class PortfolioFacade:
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `realized_pnls` must be implemented in the subclass")  # pragma: no cover
 
+    cpdef dict daily_pnls(self, Venue venue):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `daily_pnls` must be implemented in the subclass")  # pragma: no cover
+
     cpdef dict net_exposures(self, Venue venue):
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `net_exposure` must be implemented in the subclass")  # pragma: no cover
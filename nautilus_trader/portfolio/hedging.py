@@ -0,0 +1,255 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from collections import defaultdict
+from dataclasses import dataclass
+from datetime import timedelta
+
+from nautilus_trader.common.actor import Actor
+from nautilus_trader.common.component import TimeEvent
+from nautilus_trader.common.config import ActorConfig
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import PriceType
+from nautilus_trader.model.enums import order_side_to_str
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Quantity
+
+
+class HedgingAdvisorConfig(ActorConfig, frozen=True):
+    """
+    Configuration for ``HedgingAdvisor`` instances.
+
+    Parameters
+    ----------
+    base_currency : str, default "USD"
+        The currency exposures are hedged back into (no suggestions are made for it).
+    hedge_instruments : dict[str, str], optional
+        The hedge instrument ID per exposure currency code, e.g. ``{"EUR": "EUR/USD.SIM"}``.
+        The exposure currency must be either the base or quote currency of the instrument.
+    interval_secs : int, default 60
+        The interval (seconds) between exposure evaluations.
+    min_exposure : float, default 0.0
+        The minimum absolute residual exposure (in the exposure currency) to suggest a hedge for.
+    topic : str, default "hedging.suggestions"
+        The message bus topic suggestions are published on.
+
+    """
+
+    base_currency: str = "USD"
+    hedge_instruments: dict[str, str] | None = None
+    interval_secs: int = 60
+    min_exposure: float = 0.0
+    topic: str = "hedging.suggestions"
+
+
+@dataclass(frozen=True)
+class HedgeSuggestion:
+    """
+    Represents a suggested order to offset a residual currency exposure.
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID to hedge with.
+    side : OrderSide {``BUY``, ``SELL``}
+        The suggested order side.
+    quantity : Quantity
+        The suggested order quantity.
+    currency : Currency
+        The exposure currency being hedged.
+    exposure : float
+        The residual exposure (in `currency`) at the time of evaluation.
+    ts_event : int
+        UNIX timestamp (nanoseconds) when the exposure was evaluated.
+
+    """
+
+    instrument_id: InstrumentId
+    side: OrderSide
+    quantity: Quantity
+    currency: Currency
+    exposure: float
+    ts_event: int
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id}, "
+            f"side={order_side_to_str(self.side)}, "
+            f"quantity={self.quantity}, "
+            f"currency={self.currency.code}, "
+            f"exposure={self.exposure}, "
+            f"ts_event={self.ts_event})"
+        )
+
+
+class HedgingAdvisor(Actor):
+    """
+    Provides an advisor which computes residual currency exposures and suggests hedges.
+
+    On every timer interval the residual exposure per currency is computed from
+    the total balances of all cached accounts, plus the open positions held on
+    margin accounts (whose exposure is not already reflected in balances). For each
+    non-base currency with a configured hedge instrument and an absolute exposure
+    at or above the threshold, a `HedgeSuggestion` is published on the configured
+    topic for strategies (or an auto-hedger) to act on.
+
+    Parameters
+    ----------
+    config : HedgingAdvisorConfig, optional
+        The configuration for the advisor.
+
+    """
+
+    def __init__(self, config: HedgingAdvisorConfig | None = None) -> None:
+        if config is None:
+            config = HedgingAdvisorConfig()
+        PyCondition.positive_int(config.interval_secs, "config.interval_secs")
+        PyCondition.not_negative(config.min_exposure, "config.min_exposure")
+        super().__init__(config)
+
+        self.base_currency = Currency.from_str(config.base_currency)
+        self.hedge_instruments: dict[Currency, InstrumentId] = {
+            Currency.from_str(code): InstrumentId.from_str(instrument_id)
+            for code, instrument_id in (config.hedge_instruments or {}).items()
+        }
+        self.interval = timedelta(seconds=config.interval_secs)
+        self.min_exposure = config.min_exposure
+        self.topic = config.topic
+        self._timer_name = f"{self.id}-HEDGE"
+
+    def on_start(self) -> None:
+        self.clock.set_timer(
+            name=self._timer_name,
+            interval=self.interval,
+            callback=self.on_timer,
+        )
+
+    def on_stop(self) -> None:
+        if self._timer_name in self.clock.timer_names:
+            self.clock.cancel_timer(self._timer_name)
+
+    def on_timer(self, event: TimeEvent) -> None:
+        for suggestion in self.suggest_hedges(ts_event=event.ts_event):
+            self.log.info(f"{suggestion}")
+            self.msgbus.publish(topic=self.topic, msg=suggestion)
+
+    def exposures(self) -> dict[Currency, float]:
+        """
+        Return the current net exposure per currency.
+
+        Returns
+        -------
+        dict[Currency, float]
+
+        """
+        exposures: dict[Currency, float] = defaultdict(float)
+
+        for account in self.cache.accounts():
+            for currency, balance in account.balances_total().items():
+                exposures[currency] += balance.as_double()
+
+        for position in self.cache.positions_open():
+            account = self.cache.account(position.account_id)
+            if account is not None and not account.is_margin_account:
+                continue  # Cash account balances already reflect the position
+            if position.base_currency is None:
+                continue  # No currency conversion exposure (e.g. equities)
+
+            base_qty = position.signed_qty * position.multiplier.as_double()
+            exposures[position.base_currency] += base_qty
+            exposures[position.quote_currency] -= base_qty * position.avg_px_open
+
+        return dict(exposures)
+
+    def suggest_hedges(self, ts_event: int = 0) -> list[HedgeSuggestion]:
+        """
+        Return the suggested hedges for the current residual currency exposures.
+
+        Parameters
+        ----------
+        ts_event : int, default 0
+            UNIX timestamp (nanoseconds) to stamp the suggestions with.
+
+        Returns
+        -------
+        list[HedgeSuggestion]
+
+        """
+        suggestions: list[HedgeSuggestion] = []
+
+        for currency, exposure in self.exposures().items():
+            if currency == self.base_currency:
+                continue
+            if abs(exposure) < self.min_exposure or exposure == 0.0:
+                continue
+
+            instrument_id = self.hedge_instruments.get(currency)
+            if instrument_id is None:
+                continue
+
+            suggestion = self._build_suggestion(instrument_id, currency, exposure, ts_event)
+            if suggestion is not None:
+                suggestions.append(suggestion)
+
+        return suggestions
+
+    def _build_suggestion(
+        self,
+        instrument_id: InstrumentId,
+        currency: Currency,
+        exposure: float,
+        ts_event: int,
+    ) -> HedgeSuggestion | None:
+        instrument = self.cache.instrument(instrument_id)
+        if instrument is None:
+            self.log.warning(f"Cannot suggest hedge: no instrument found for {instrument_id}")
+            return None
+
+        base_currency = getattr(instrument, "base_currency", None)
+        if base_currency == currency:
+            # Long the base currency -> sell it, short -> buy it back
+            side = OrderSide.SELL if exposure > 0 else OrderSide.BUY
+            raw_qty = abs(exposure)
+        elif instrument.quote_currency == currency:
+            price = self.cache.price(instrument_id, PriceType.MID)
+            if price is None:
+                price = self.cache.price(instrument_id, PriceType.LAST)
+            if price is None or price.as_double() <= 0.0:
+                self.log.warning(f"Cannot suggest hedge: no price for {instrument_id}")
+                return None
+            # Long the quote currency -> buy the base with it, short -> sell the base
+            side = OrderSide.BUY if exposure > 0 else OrderSide.SELL
+            raw_qty = abs(exposure) / price.as_double()
+        else:
+            self.log.warning(
+                f"Cannot suggest hedge: {instrument_id} is not denominated in {currency}",
+            )
+            return None
+
+        quantity = instrument.make_qty(raw_qty / instrument.multiplier.as_double())
+        if quantity.as_double() == 0.0:
+            return None
+
+        return HedgeSuggestion(
+            instrument_id=instrument_id,
+            side=side,
+            quantity=quantity,
+            currency=currency,
+            exposure=exposure,
+            ts_event=ts_event,
+        )
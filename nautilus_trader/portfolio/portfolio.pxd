@@ -45,6 +45,7 @@ cdef class Portfolio(PortfolioFacade):
     cdef dict[InstrumentId, Money] _realized_pnls
     cdef dict[InstrumentId, Decimal] _net_positions
     cdef set[InstrumentId] _pending_calcs
+    cdef dict[Venue, dict] _daily_pnl_baselines
 
 # -- COMMANDS -------------------------------------------------------------------------------------
 
@@ -55,6 +56,7 @@ cdef class Portfolio(PortfolioFacade):
     cpdef void update_account(self, AccountState event)
     cpdef void update_order(self, OrderEvent event)
     cpdef void update_position(self, PositionEvent event)
+    cpdef void reset_daily_pnls(self, Venue venue)
 
 # -- INTERNAL -------------------------------------------------------------------------------------
 
@@ -62,5 +64,6 @@ cdef class Portfolio(PortfolioFacade):
     cdef void _update_net_position(self, InstrumentId instrument_id, list positions_open)
     cdef Money _calculate_unrealized_pnl(self, InstrumentId instrument_id)
     cdef Money _calculate_realized_pnl(self, InstrumentId instrument_id)
+    cdef dict _total_pnls(self, Venue venue)
     cdef Price _get_last_price(self, Position position)
     cdef double _calculate_xrate_to_base(self, Account account, Instrument instrument, OrderSide side)
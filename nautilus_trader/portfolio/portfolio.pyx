@@ -110,6 +110,7 @@ cdef class Portfolio(PortfolioFacade):
         self._realized_pnls: dict[InstrumentId, Money] = {}
         self._net_positions: dict[InstrumentId, Decimal] = {}
         self._pending_calcs: set[InstrumentId] = set()
+        self._daily_pnl_baselines: dict[Venue, dict[Currency, float]] = {}
 
         self.analyzer = PortfolioAnalyzer()
 
@@ -543,11 +544,29 @@ cdef class Portfolio(PortfolioFacade):
             ts_event=event.ts_event,
         )
 
+    cpdef void reset_daily_pnls(self, Venue venue):
+        """
+        Reset the daily PnLs for the given venue, starting a new session from
+        the current realized and unrealized PnLs.
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue for the reset.
+
+        """
+        Condition.not_none(venue, "venue")
+
+        self._daily_pnl_baselines[venue] = self._total_pnls(venue)
+
+        self._log.info(f"Reset daily PnLs for {venue}")
+
     def _reset(self) -> None:
         self._net_positions.clear()
         self._unrealized_pnls.clear()
         self._realized_pnls.clear()
         self._pending_calcs.clear()
+        self._daily_pnl_baselines.clear()
         self.analyzer.reset()
 
         self.initialized = False
@@ -772,6 +791,29 @@ cdef class Portfolio(PortfolioFacade):
 
         return {k: Money(v, k) for k, v in realized_pnls.items()}
 
+    cpdef dict daily_pnls(self, Venue venue):
+        """
+        Return the PnLs (realized and unrealized) for the given venue since the
+        daily PnLs were last reset.
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue for the daily PnL.
+
+        Returns
+        -------
+        dict[Currency, Money]
+
+        """
+        Condition.not_none(venue, "venue")
+
+        cdef dict[Currency, double] totals = self._total_pnls(venue)
+        cdef dict[Currency, double] baselines = self._daily_pnl_baselines.get(venue, {})
+
+        cdef set currencies = set(totals) | set(baselines)
+        return {c: Money(totals.get(c, 0.0) - baselines.get(c, 0.0), c) for c in currencies}
+
     cpdef dict net_exposures(self, Venue venue):
         """
         Return the net exposures for the given venue (if found).
@@ -1179,6 +1221,15 @@ cdef class Portfolio(PortfolioFacade):
 
         return Money(total_pnl, currency)
 
+    cdef dict _total_pnls(self, Venue venue):
+        cdef dict[Currency, double] totals = {}
+        cdef Money pnl
+        for pnls in (self.realized_pnls(venue), self.unrealized_pnls(venue)):
+            for pnl in (pnls or {}).values():
+                totals[pnl.currency] = totals.get(pnl.currency, 0.0) + pnl.as_f64_c()
+
+        return totals
+
     cdef Money _calculate_realized_pnl(self, InstrumentId instrument_id):
         cdef Account account = self._cache.account_for_venue(self._venue or instrument_id.venue)
         if account is None:
@@ -0,0 +1,429 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+
+from collections import defaultdict
+from collections.abc import Callable
+from dataclasses import dataclass
+from datetime import date
+from datetime import time
+
+import pandas as pd
+
+from nautilus_trader.common.actor import Actor
+from nautilus_trader.common.component import TimeEvent
+from nautilus_trader.common.config import ActorConfig
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.datetime import unix_nanos_to_dt
+from nautilus_trader.model.enums import PriceType
+from nautilus_trader.model.enums import price_type_from_str
+from nautilus_trader.model.events import OrderFilled
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import PositionId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.position import Position
+
+
+class SessionRolloverConfig(ActorConfig, frozen=True):
+    """
+    Configuration for ``SessionRollover`` instances.
+
+    Parameters
+    ----------
+    venue_close_times : dict[str, str], optional
+        The session close time per venue, formatted as 'HH:MM' or 'HH:MM:SS',
+        e.g. ``{"CME": "16:00"}``.
+    timezone : str, default "UTC"
+        The timezone the close times are expressed in.
+    settlement_price_type : str, default "LAST"
+        The price type used to mark positions when no explicit settlement price was set.
+    topic : str, default "portfolio.eod"
+        The message bus topic prefix reports are published on (suffixed with the venue).
+
+    """
+
+    venue_close_times: dict[str, str] | None = None
+    timezone: str = "UTC"
+    settlement_price_type: str = "LAST"
+    topic: str = "portfolio.eod"
+
+
+@dataclass(frozen=True)
+class PositionSettlement:
+    """
+    Represents the settlement of a single position at a session close.
+
+    Parameters
+    ----------
+    position_id : PositionId
+        The settled position ID.
+    instrument_id : InstrumentId
+        The instrument ID for the position.
+    settlement_price : Price, optional
+        The price the position was marked to (``None`` if closed during the session).
+    unrealized_pnl : Money
+        The unrealized PnL of the position at the settlement price.
+    daily_pnl : Money
+        The PnL of the position over the session (realized and unrealized changes).
+
+    """
+
+    position_id: PositionId
+    instrument_id: InstrumentId
+    settlement_price: Price | None
+    unrealized_pnl: Money
+    daily_pnl: Money
+
+
+@dataclass(frozen=True)
+class EndOfDayReport:
+    """
+    Represents the end-of-day report for a venue session.
+
+    Parameters
+    ----------
+    venue : Venue
+        The venue for the session.
+    session_date : date
+        The session date (in the configured timezone).
+    settlements : list[PositionSettlement]
+        The position settlements for the session.
+    daily_pnls : dict[Currency, Money]
+        The total session PnL per currency.
+    realized_pnls : dict[Currency, Money]
+        The session PnL realized per currency. For futures-style (margin) accounts
+        this includes the variation from marking open positions to settlement.
+    fill_count : int
+        The count of fills for the venue during the session.
+    commissions : dict[Currency, Money]
+        The total commissions for the venue during the session.
+    ts_event : int
+        UNIX timestamp (nanoseconds) when the session was rolled over.
+
+    """
+
+    venue: Venue
+    session_date: date
+    settlements: list[PositionSettlement]
+    daily_pnls: dict[Currency, Money]
+    realized_pnls: dict[Currency, Money]
+    fill_count: int
+    commissions: dict[Currency, Money]
+    ts_event: int
+
+
+class SessionRollover(Actor):
+    """
+    Provides end-of-day settlement and session rollover processing per venue.
+
+    At each configured venue close time, all positions at the venue are marked to
+    their settlement price, and the PnL over the session is calculated relative to
+    the previous settlement. For futures-style (margin) accounts the variation from
+    marking to settlement is treated as realized, as it is settled daily at the venue;
+    account balances are not adjusted, the settlement cash flows are expected to be
+    reported by the venue. Daily statistics are then reset, including the portfolio
+    daily PnLs and the risk engine daily limits for the venue (along with any registered
+    reset handlers), and an `EndOfDayReport` is published.
+
+    Parameters
+    ----------
+    config : SessionRolloverConfig, optional
+        The configuration for the component.
+
+    """
+
+    def __init__(self, config: SessionRolloverConfig | None = None) -> None:
+        if config is None:
+            config = SessionRolloverConfig()
+        PyCondition.valid_string(config.timezone, "config.timezone")
+        super().__init__(config)
+
+        self.close_times: dict[Venue, time] = {
+            Venue(venue): time.fromisoformat(close_time)
+            for venue, close_time in (config.venue_close_times or {}).items()
+        }
+        self.timezone = config.timezone
+        self.settlement_price_type: PriceType = price_type_from_str(config.settlement_price_type)
+        self.topic = config.topic
+
+        self._timer_venues: dict[str, Venue] = {}
+        self._settlement_prices: dict[InstrumentId, Price] = {}
+        self._marks: dict[PositionId, float] = {}
+        self._realized: dict[PositionId, float] = {}
+        self._last_rollover_ns: dict[Venue, int] = {}
+        self._fill_counts: dict[Venue, int] = defaultdict(int)
+        self._commissions: dict[Venue, dict[Currency, float]] = defaultdict(
+            lambda: defaultdict(float),
+        )
+        self._reset_handlers: list[Callable[[Venue], None]] = []
+
+    def on_start(self) -> None:
+        self.msgbus.subscribe(topic="events.order.*", handler=self._handle_order_event)
+
+        for venue in self.close_times:
+            self._schedule_rollover(venue, self.clock.timestamp_ns())
+
+    def on_stop(self) -> None:
+        self.msgbus.unsubscribe(topic="events.order.*", handler=self._handle_order_event)
+
+        for timer_name in self._timer_venues:
+            if timer_name in self.clock.timer_names:
+                self.clock.cancel_timer(timer_name)
+
+        self._timer_venues.clear()
+
+    def on_reset(self) -> None:
+        self._settlement_prices.clear()
+        self._marks.clear()
+        self._realized.clear()
+        self._last_rollover_ns.clear()
+        self._fill_counts.clear()
+        self._commissions.clear()
+
+    def set_settlement_price(self, instrument_id: InstrumentId, price: Price) -> None:
+        """
+        Set the settlement price for the given instrument ID at the next rollover.
+
+        Explicit settlement prices take precedence over cached prices, and are
+        cleared once used.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the settlement price.
+        price : Price
+            The settlement price.
+
+        """
+        PyCondition.not_none(instrument_id, "instrument_id")
+        PyCondition.not_none(price, "price")
+
+        self._settlement_prices[instrument_id] = price
+
+    def register_reset_handler(self, handler: Callable[[Venue], None]) -> None:
+        """
+        Register the given handler to be called when a venue session rolls over.
+
+        Use this to reset daily statistics and limits held by other components.
+
+        Parameters
+        ----------
+        handler : Callable[[Venue], None]
+            The handler to call with the venue being rolled over.
+
+        """
+        PyCondition.callable(handler, "handler")
+
+        self._reset_handlers.append(handler)
+
+    def fill_count(self, venue: Venue) -> int:
+        """
+        Return the count of fills for the given venue during the current session.
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue for the count.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._fill_counts.get(venue, 0)
+
+    def next_close_ns(self, venue: Venue, now_ns: int) -> int:
+        """
+        Return the next session close time for the given venue after `now_ns`.
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue for the close time.
+        now_ns : int
+            UNIX timestamp (nanoseconds) to find the next close after.
+
+        Returns
+        -------
+        int
+
+        Raises
+        ------
+        KeyError
+            If no close time is configured for `venue`.
+
+        """
+        close_time = self.close_times[venue]
+        now = unix_nanos_to_dt(now_ns).tz_convert(self.timezone)
+        close = now.replace(
+            hour=close_time.hour,
+            minute=close_time.minute,
+            second=close_time.second,
+            microsecond=0,
+            nanosecond=0,
+        )
+        if close <= now:
+            close = (now + pd.Timedelta(days=1)).replace(
+                hour=close_time.hour,
+                minute=close_time.minute,
+                second=close_time.second,
+                microsecond=0,
+                nanosecond=0,
+            )
+
+        return close.value
+
+    def rollover(self, venue: Venue, ts_event: int | None = None) -> EndOfDayReport:
+        """
+        Settle the positions for the given venue and roll over to the next session.
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue to roll over.
+        ts_event : int, optional
+            UNIX timestamp (nanoseconds) of the rollover (defaults to now).
+
+        Returns
+        -------
+        EndOfDayReport
+
+        """
+        PyCondition.not_none(venue, "venue")
+
+        if ts_event is None:
+            ts_event = self.clock.timestamp_ns()
+
+        account = self.cache.account_for_venue(venue)
+        is_margin = account is not None and account.is_margin_account
+        last_rollover_ns = self._last_rollover_ns.get(venue, 0)
+
+        settlements: list[PositionSettlement] = []
+        daily_pnls: dict[Currency, float] = defaultdict(float)
+        realized_pnls: dict[Currency, float] = defaultdict(float)
+
+        for position in self.cache.positions(venue=venue):
+            if position.is_closed and position.ts_closed <= last_rollover_ns:
+                continue  # Already settled in a previous session
+
+            settlement = self._settle_position(position, is_margin, realized_pnls)
+            if settlement is None:
+                continue
+
+            settlements.append(settlement)
+            daily_pnls[settlement.daily_pnl.currency] += settlement.daily_pnl.as_double()
+
+        commissions = self._commissions.pop(venue, {})
+        report = EndOfDayReport(
+            venue=venue,
+            session_date=unix_nanos_to_dt(ts_event).tz_convert(self.timezone).date(),
+            settlements=settlements,
+            daily_pnls={c: Money(v, c) for c, v in daily_pnls.items()},
+            realized_pnls={c: Money(v, c) for c, v in realized_pnls.items()},
+            fill_count=self._fill_counts.pop(venue, 0),
+            commissions={c: Money(v, c) for c, v in commissions.items()},
+            ts_event=ts_event,
+        )
+
+        self._last_rollover_ns[venue] = ts_event
+
+        self.portfolio.reset_daily_pnls(venue)
+        if "RiskEngine.reset_daily_limits" in self.msgbus.endpoints():
+            self.msgbus.send(endpoint="RiskEngine.reset_daily_limits", msg=venue)
+
+        for handler in self._reset_handlers:
+            handler(venue)
+
+        self.log.info(f"Rolled over {venue} session {report.session_date}")
+        self.msgbus.publish(topic=f"{self.topic}.{venue}", msg=report)
+
+        return report
+
+    def _settle_position(
+        self,
+        position: Position,
+        is_margin: bool,
+        realized_pnls: dict[Currency, float],
+    ) -> PositionSettlement | None:
+        currency = position.settlement_currency
+        realized = position.realized_pnl.as_double() if position.realized_pnl is not None else 0.0
+        realized_change = realized - self._realized.get(position.id, 0.0)
+
+        settlement_price: Price | None = None
+        unrealized = 0.0
+        if position.is_open:
+            settlement_price = self._settlement_price(position.instrument_id)
+            if settlement_price is None:
+                self.log.warning(
+                    f"Cannot settle {position.id!r}: no settlement price for {position.instrument_id}",
+                )
+                return None
+            unrealized = position.unrealized_pnl(settlement_price).as_double()
+
+        unrealized_change = unrealized - self._marks.get(position.id, 0.0)
+        realized_pnls[currency] += realized_change
+        if is_margin:
+            realized_pnls[currency] += unrealized_change  # Variation settled daily
+
+        if position.is_open:
+            self._marks[position.id] = unrealized
+            self._realized[position.id] = realized
+        else:
+            self._marks.pop(position.id, None)
+            self._realized.pop(position.id, None)
+
+        return PositionSettlement(
+            position_id=position.id,
+            instrument_id=position.instrument_id,
+            settlement_price=settlement_price,
+            unrealized_pnl=Money(unrealized, currency),
+            daily_pnl=Money(realized_change + unrealized_change, currency),
+        )
+
+    def _settlement_price(self, instrument_id: InstrumentId) -> Price | None:
+        price = self._settlement_prices.pop(instrument_id, None)
+        if price is None:
+            price = self.cache.price(instrument_id, self.settlement_price_type)
+
+        return price
+
+    def _schedule_rollover(self, venue: Venue, now_ns: int) -> None:
+        timer_name = f"{self.id}-EOD-{venue}"
+        self._timer_venues[timer_name] = venue
+        self.clock.set_time_alert(
+            name=timer_name,
+            alert_time=unix_nanos_to_dt(self.next_close_ns(venue, now_ns)),
+            callback=self._on_rollover_time,
+            override=True,
+        )
+
+    def _on_rollover_time(self, event: TimeEvent) -> None:
+        venue = self._timer_venues.get(event.name)
+        if venue is None:
+            return  # Rollover no longer scheduled
+
+        self.rollover(venue, event.ts_event)
+        self._schedule_rollover(venue, event.ts_event)
+
+    def _handle_order_event(self, event) -> None:
+        if not isinstance(event, OrderFilled):
+            return
+
+        venue = event.instrument_id.venue
+        self._fill_counts[venue] += 1
+        self._commissions[venue][event.commission.currency] += event.commission.as_double()
@@ -16,6 +16,7 @@
 from __future__ import annotations
 
 from nautilus_trader.common.config import NautilusConfig
+from nautilus_trader.common.config import PositiveFloat
 
 
 class RiskEngineConfig(NautilusConfig, frozen=True):
@@ -33,6 +34,19 @@ class RiskEngineConfig(NautilusConfig, frozen=True):
     max_notional_per_order : dict[str, int], default empty dict
         The maximum notional value of an order per instrument ID.
         The value should be a valid decimal format.
+    max_daily_loss_per_venue : dict[str, str], default empty dict
+        The maximum loss per venue over a trading session, as a money string
+        (e.g. "1000.00 USD"). Once breached, new orders for the venue which are
+        not reduce-only are denied until the daily limits are reset.
+    max_position_age_per_strategy : dict[str, str], default empty dict
+        The maximum duration a position may be held per strategy ID, as a timedelta string
+        (e.g. "04:00:00"). Positions held longer are flagged with a warning, and flattened
+        if `auto_flatten_aged_positions` is True.
+    auto_flatten_aged_positions : bool, default False
+        If positions held longer than the maximum age for their strategy should be automatically
+        flattened with a reduce-only market order.
+    position_age_check_interval_secs : PositiveFloat, default 1.0
+        The interval (seconds) at which position ages are checked.
     debug : bool, default False
         If debug mode is active (will provide extra debug logging).
 
@@ -42,4 +56,8 @@ class RiskEngineConfig(NautilusConfig, frozen=True):
     max_order_submit_rate: str = "100/00:00:01"
     max_order_modify_rate: str = "100/00:00:01"
     max_notional_per_order: dict[str, int] = {}
+    max_daily_loss_per_venue: dict[str, str] = {}
+    max_position_age_per_strategy: dict[str, str] = {}
+    auto_flatten_aged_positions: bool = False
+    position_age_check_interval_secs: PositiveFloat = 1.0
     debug: bool = False
@@ -13,11 +13,14 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from datetime import timedelta
 from decimal import Decimal
 
 from nautilus_trader.cache.cache cimport Cache
 from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.component cimport Throttler
+from nautilus_trader.common.component cimport TimeEvent
+from nautilus_trader.common.messages cimport SetInstrumentTrading
 from nautilus_trader.core.message cimport Command
 from nautilus_trader.core.message cimport Event
 from nautilus_trader.core.rust.model cimport TradingState
@@ -28,11 +31,15 @@ from nautilus_trader.execution.messages cimport SubmitOrder
 from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.execution.messages cimport TradingCommand
 from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport StrategyId
+from nautilus_trader.model.identifiers cimport Venue
 from nautilus_trader.model.instruments.base cimport Instrument
+from nautilus_trader.model.objects cimport Money
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
 from nautilus_trader.model.orders.base cimport Order
 from nautilus_trader.model.orders.list cimport OrderList
+from nautilus_trader.model.position cimport Position
 from nautilus_trader.portfolio.base cimport PortfolioFacade
 
 
@@ -40,8 +47,14 @@ cdef class RiskEngine(Component):
     cdef readonly PortfolioFacade _portfolio
     cdef readonly Cache _cache
     cdef readonly dict _max_notional_per_order
+    cdef readonly dict _max_daily_loss
+    cdef readonly set _daily_loss_breached
     cdef readonly Throttler _order_submit_throttler
     cdef readonly Throttler _order_modify_throttler
+    cdef readonly dict _max_position_age
+    cdef readonly set _flagged_positions
+    cdef readonly dict _flatten_orders
+    cdef readonly str position_age_timer_name
 
     cdef readonly TradingState trading_state
     """The current trading state for the engine.\n\n:returns: `TradingState`"""
@@ -49,6 +62,10 @@ cdef class RiskEngine(Component):
     """If the risk engine is completely bypassed.\n\n:returns: `bool`"""
     cdef readonly bint debug
     """If debug mode is active (will provide extra debug logging).\n\n:returns: `bool`"""
+    cdef readonly bint auto_flatten_aged_positions
+    """If positions exceeding their maximum age are automatically flattened.\n\n:returns: `bool`"""
+    cdef readonly double position_age_check_interval_secs
+    """The interval (seconds) at which position ages are checked.\n\n:returns: `double`"""
     cdef readonly int command_count
     """The total count of commands received by the engine.\n\n:returns: `int`"""
     cdef readonly int event_count
@@ -60,6 +77,11 @@ cdef class RiskEngine(Component):
     cpdef void process(self, Event event)
     cpdef void set_trading_state(self, TradingState state)
     cpdef void set_max_notional_per_order(self, InstrumentId instrument_id, new_value: Decimal)
+    cpdef void set_max_daily_loss(self, Venue venue, Money max_loss)
+    cpdef void reset_daily_limits(self, Venue venue)
+    cpdef void set_instrument_trading(self, InstrumentId instrument_id, bint enabled, bint cancel_orders=*, str reason=*)
+    cpdef void set_max_position_age(self, StrategyId strategy_id, max_age: timedelta | None)
+    cpdef list check_position_ages(self)
     cpdef void _log_state(self)
 
 # -- RISK SETTINGS --------------------------------------------------------------------------------
@@ -68,6 +90,9 @@ cdef class RiskEngine(Component):
     cpdef tuple max_order_modify_rate(self)
     cpdef dict max_notionals_per_order(self)
     cpdef object max_notional_per_order(self, InstrumentId instrument_id)
+    cpdef Money max_daily_loss(self, Venue venue)
+    cpdef dict max_position_ages(self)
+    cpdef object max_position_age(self, StrategyId strategy_id)
 
 # -- ABSTRACT METHODS -----------------------------------------------------------------------------
 
@@ -80,6 +105,11 @@ cdef class RiskEngine(Component):
     cpdef void _handle_submit_order(self, SubmitOrder command)
     cpdef void _handle_submit_order_list(self, SubmitOrderList command)
     cpdef void _handle_modify_order(self, ModifyOrder command)
+    cpdef void _handle_set_instrument_trading(self, SetInstrumentTrading command)
+    cdef void _cancel_instrument_orders(self, InstrumentId instrument_id)
+    cdef void _update_position_age_timer(self, bint active)
+    cpdef void _on_position_age_timer(self, TimeEvent event)
+    cdef void _flatten_position(self, Position position)
 
 # -- PRE-TRADE CHECKS -----------------------------------------------------------------------------
 
@@ -87,6 +117,7 @@ cdef class RiskEngine(Component):
     cpdef bint _check_order_price(self, Instrument instrument, Order order)
     cpdef bint _check_order_quantity(self, Instrument instrument, Order order)
     cpdef bint _check_orders_risk(self, Instrument instrument, list orders)
+    cpdef bint _check_daily_loss(self, Venue venue, list orders)
     cpdef str _check_price(self, Instrument instrument, Price price)
     cpdef str _check_quantity(self, Instrument instrument, Quantity quantity)
 
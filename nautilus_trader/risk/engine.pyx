@@ -13,6 +13,7 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from datetime import timedelta
 from decimal import Decimal
 
 import pandas as pd
@@ -31,15 +32,19 @@ from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.component cimport LogColor
 from nautilus_trader.common.component cimport MessageBus
 from nautilus_trader.common.component cimport Throttler
+from nautilus_trader.common.component cimport TimeEvent
+from nautilus_trader.common.messages cimport SetInstrumentTrading
 from nautilus_trader.common.messages cimport TradingStateChanged
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.message cimport Command
 from nautilus_trader.core.message cimport Event
+from nautilus_trader.core.rust.core cimport secs_to_nanos
 from nautilus_trader.core.rust.model cimport AccountType
 from nautilus_trader.core.rust.model cimport InstrumentClass
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport OrderStatus
 from nautilus_trader.core.rust.model cimport OrderType
+from nautilus_trader.core.rust.model cimport TimeInForce
 from nautilus_trader.core.rust.model cimport TradingState
 from nautilus_trader.core.rust.model cimport TriggerType
 from nautilus_trader.core.uuid cimport UUID4
@@ -56,8 +61,12 @@ from nautilus_trader.model.events.order cimport OrderDenied
 from nautilus_trader.model.events.order cimport OrderModifyRejected
 from nautilus_trader.model.functions cimport order_type_to_str
 from nautilus_trader.model.functions cimport trading_state_to_str
+from nautilus_trader.model.identifiers cimport ClientOrderId
 from nautilus_trader.model.identifiers cimport ComponentId
 from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport PositionId
+from nautilus_trader.model.identifiers cimport StrategyId
+from nautilus_trader.model.identifiers cimport Venue
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.instruments.currency_pair cimport CurrencyPair
 from nautilus_trader.model.objects cimport Currency
@@ -66,6 +75,7 @@ from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
 from nautilus_trader.model.orders.base cimport Order
 from nautilus_trader.model.orders.list cimport OrderList
+from nautilus_trader.model.orders.market cimport MarketOrder
 from nautilus_trader.model.position cimport Position
 from nautilus_trader.portfolio.base cimport PortfolioFacade
 
@@ -172,6 +182,14 @@ cdef class RiskEngine(Component):
 
         # Risk settings
         self._max_notional_per_order: dict[InstrumentId, Decimal] = {}
+        self._max_daily_loss: dict[Venue, Money] = {}
+        self._daily_loss_breached: set[Venue] = set()
+        self._max_position_age: dict[StrategyId, timedelta] = {}
+        self._flagged_positions: set[PositionId] = set()
+        self._flatten_orders: dict[PositionId, ClientOrderId] = {}
+        self.auto_flatten_aged_positions = config.auto_flatten_aged_positions
+        self.position_age_check_interval_secs = config.position_age_check_interval_secs
+        self.position_age_timer_name = "RiskEngine_POSITION_AGE_CHECK"
 
         # Configure
         self._initialize_risk_checks(config)
@@ -179,6 +197,7 @@ cdef class RiskEngine(Component):
         # Register endpoints
         self._msgbus.register(endpoint="RiskEngine.execute", handler=self.execute)
         self._msgbus.register(endpoint="RiskEngine.process", handler=self.process)
+        self._msgbus.register(endpoint="RiskEngine.reset_daily_limits", handler=self.reset_daily_limits)
 
         # Required subscriptions
         self._msgbus.subscribe(topic="events.order.*", handler=self._handle_event, priority=10)
@@ -189,6 +208,14 @@ cdef class RiskEngine(Component):
         for instrument_id, value in max_notional_config.items():
             self.set_max_notional_per_order(InstrumentId.from_str_c(instrument_id), Decimal(value))
 
+        cdef dict max_daily_loss_config = config.max_daily_loss_per_venue
+        for venue, value in max_daily_loss_config.items():
+            self.set_max_daily_loss(Venue(venue), Money.from_str(value))
+
+        cdef dict max_position_age_config = config.max_position_age_per_strategy
+        for strategy_id, value in max_position_age_config.items():
+            self.set_max_position_age(StrategyId(strategy_id), pd.to_timedelta(value))
+
 # -- COMMANDS -------------------------------------------------------------------------------------
 
     cpdef void execute(self, Command command):
@@ -304,6 +331,190 @@ cdef class RiskEngine(Component):
             color=LogColor.BLUE,
         )
 
+    cpdef void set_instrument_trading(
+        self,
+        InstrumentId instrument_id,
+        bint enabled,
+        bint cancel_orders = False,
+        str reason = None,
+    ):
+        """
+        Enable or disable trading for the given instrument ID.
+
+        While trading is disabled for an instrument all new orders for it will be
+        denied, regardless of the engines trading state. The setting is tracked
+        by the cache so it is visible to other components.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the setting.
+        enabled : bool
+            If trading should be enabled for the instrument.
+        cancel_orders : bool, default False
+            If open (and emulated) orders for the instrument should be canceled
+            when disabling trading.
+        reason : str, optional
+            The reason for the change (for logging).
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        cdef str reason_str = f": {reason}" if reason else ""
+        if enabled == self._cache.is_trading_enabled(instrument_id):
+            self._log.warning(
+                f"No change to trading for {instrument_id}: "
+                f"already {'enabled' if enabled else 'disabled'}",
+            )
+        else:
+            self._cache.set_trading_enabled(instrument_id, enabled)
+            self._log.info(
+                f"Trading {'ENABLED' if enabled else 'DISABLED'} for {instrument_id}{reason_str}",
+                color=LogColor.BLUE if enabled else LogColor.RED,
+            )
+
+        if not enabled and cancel_orders:
+            self._cancel_instrument_orders(instrument_id)
+
+    cpdef void set_max_daily_loss(self, Venue venue, Money max_loss):
+        """
+        Set the maximum loss over a trading session for the given venue.
+
+        Passing a `max_loss` of ``None`` will disable the check for the venue.
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue for the max daily loss.
+        max_loss : Money, optional
+            The maximum daily loss to set.
+
+        Raises
+        ------
+        ValueError
+            If `max_loss` is not ``None`` and not positive.
+
+        """
+        Condition.not_none(venue, "venue")
+
+        if max_loss is None:
+            self._max_daily_loss.pop(venue, None)
+        else:
+            Condition.positive(max_loss.as_double(), "max_loss")
+            self._max_daily_loss[venue] = max_loss
+
+        self._log.info(
+            f"Set MAX_DAILY_LOSS: {venue} {max_loss}",
+            color=LogColor.BLUE,
+        )
+
+    cpdef void reset_daily_limits(self, Venue venue):
+        """
+        Reset the daily limits for the given venue at the start of a new trading session.
+
+        Any breach of the max daily loss for the venue is cleared, allowing new
+        orders to be submitted again.
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue for the reset.
+
+        """
+        Condition.not_none(venue, "venue")
+
+        self._daily_loss_breached.discard(venue)
+
+        self._log.info(f"Reset daily limits for {venue}", color=LogColor.BLUE)
+
+    cpdef void set_max_position_age(self, StrategyId strategy_id, max_age: timedelta | None):
+        """
+        Set the maximum duration positions may be held for the given strategy ID.
+
+        Open positions held longer are flagged with a warning on each check, and
+        flattened with a reduce-only market order if `auto_flatten_aged_positions`
+        is enabled. Passing a `max_age` of ``None`` will disable the check for
+        the strategy.
+
+        Parameters
+        ----------
+        strategy_id : StrategyId
+            The strategy ID for the max position age.
+        max_age : timedelta, optional
+            The maximum position age to set.
+
+        Raises
+        ------
+        ValueError
+            If `max_age` is not ``None`` and not positive.
+
+        """
+        Condition.not_none(strategy_id, "strategy_id")
+
+        if max_age is None:
+            self._max_position_age.pop(strategy_id, None)
+        else:
+            Condition.type(max_age, timedelta, "max_age")
+            Condition.positive(max_age.total_seconds(), "max_age")
+            self._max_position_age[strategy_id] = max_age
+
+        self._log.info(
+            f"Set MAX_POSITION_AGE: {strategy_id} {max_age}",
+            color=LogColor.BLUE,
+        )
+
+        self._update_position_age_timer(self.is_running)
+
+    cpdef list check_position_ages(self):
+        """
+        Check the age of all open positions against the maximum position age for
+        their strategy.
+
+        Positions exceeding the maximum age are flagged with a warning, and flattened
+        if `auto_flatten_aged_positions` is enabled.
+
+        Returns
+        -------
+        list[Position]
+            The open positions exceeding the maximum age for their strategy.
+
+        """
+        cdef uint64_t ts_now = self._clock.timestamp_ns()
+        cdef list aged = []
+        cdef set open_position_ids = set()
+
+        cdef:
+            Position position
+            uint64_t max_age_ns
+        for position in self._cache.positions_open():
+            open_position_ids.add(position.id)
+            max_age = self._max_position_age.get(position.strategy_id)
+            if max_age is None:
+                continue
+
+            max_age_ns = secs_to_nanos(max_age.total_seconds())
+            if ts_now < position.ts_opened or ts_now - position.ts_opened <= max_age_ns:
+                continue
+
+            aged.append(position)
+            if position.id not in self._flagged_positions:
+                self._flagged_positions.add(position.id)
+                self._log.warning(
+                    f"{position.id!r} for {position.strategy_id} exceeded "
+                    f"MAX_POSITION_AGE of {max_age}",
+                )
+
+            if self.auto_flatten_aged_positions:
+                self._flatten_position(position)
+
+        # Stop tracking positions which have since closed
+        self._flagged_positions &= open_position_ids
+        for position_id in list(self._flatten_orders.keys()):
+            if position_id not in open_position_ids:
+                del self._flatten_orders[position_id]
+
+        return aged
+
 # -- RISK SETTINGS --------------------------------------------------------------------------------
 
     cpdef tuple max_order_submit_rate(self):
@@ -358,6 +569,49 @@ cdef class RiskEngine(Component):
         """
         return self._max_notional_per_order.get(instrument_id)
 
+    cpdef Money max_daily_loss(self, Venue venue):
+        """
+        Return the current maximum daily loss for the given venue.
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue for the setting.
+
+        Returns
+        -------
+        Money or ``None``
+
+        """
+        return self._max_daily_loss.get(venue)
+
+    cpdef dict max_position_ages(self):
+        """
+        Return the current maximum position age settings.
+
+        Returns
+        -------
+        dict[StrategyId, timedelta]
+
+        """
+        return self._max_position_age.copy()
+
+    cpdef object max_position_age(self, StrategyId strategy_id):
+        """
+        Return the current maximum position age for the given strategy ID.
+
+        Parameters
+        ----------
+        strategy_id : StrategyId
+            The strategy ID for the setting.
+
+        Returns
+        -------
+        timedelta or ``None``
+
+        """
+        return self._max_position_age.get(strategy_id)
+
 # -- ABSTRACT METHODS -----------------------------------------------------------------------------
 
     cpdef void _on_start(self):
@@ -369,16 +623,18 @@ cdef class RiskEngine(Component):
 # -- ACTION IMPLEMENTATIONS -----------------------------------------------------------------------
 
     cpdef void _start(self):
-        # Do nothing else for now
+        self._update_position_age_timer(True)
         self._on_start()
 
     cpdef void _stop(self):
-        # Do nothing else for now
+        self._update_position_age_timer(False)
         self._on_stop()
 
     cpdef void _reset(self):
         self.command_count = 0
         self.event_count = 0
+        self._flagged_positions.clear()
+        self._flatten_orders.clear()
         self._order_submit_throttler.reset()
         self._order_modify_throttler.reset()
 
@@ -399,6 +655,8 @@ cdef class RiskEngine(Component):
             self._handle_submit_order_list(command)
         elif isinstance(command, ModifyOrder):
             self._handle_modify_order(command)
+        elif isinstance(command, SetInstrumentTrading):
+            self._handle_set_instrument_trading(command)
         else:
             self._log.error(f"Cannot handle command: {command}")
 
@@ -437,6 +695,10 @@ cdef class RiskEngine(Component):
         if not self._check_order(instrument, order):
             return  # Denied
 
+        if not self._check_daily_loss(instrument.id.venue, [order]):
+            self._deny_command(command, "MAX_DAILY_LOSS_EXCEEDED")
+            return  # Denied
+
         if not self._check_orders_risk(instrument, [order]):
             return # Denied
 
@@ -464,6 +726,10 @@ cdef class RiskEngine(Component):
             if not self._check_order(instrument, order):
                 return  # Denied
 
+        if not self._check_daily_loss(instrument.id.venue, command.order_list.orders):
+            self._deny_order_list(command.order_list, "MAX_DAILY_LOSS_EXCEEDED")
+            return  # Denied
+
         if not self._check_orders_risk(instrument, command.order_list.orders):
             # Deny all orders in list
             self._deny_order_list(command.order_list, "OrderList {command.order_list.id.to_str()} DENIED")
@@ -547,6 +813,107 @@ cdef class RiskEngine(Component):
 
         self._order_modify_throttler.send(command)
 
+    cpdef void _handle_set_instrument_trading(self, SetInstrumentTrading command):
+        self.set_instrument_trading(
+            instrument_id=command.instrument_id,
+            enabled=command.enabled,
+            cancel_orders=command.cancel_orders,
+            reason=command.reason,
+        )
+
+    cdef void _cancel_instrument_orders(self, InstrumentId instrument_id):
+        cdef list open_orders = self._cache.orders_open(instrument_id=instrument_id)
+        cdef list emulated_orders = self._cache.orders_emulated(instrument_id=instrument_id)
+
+        if not open_orders and not emulated_orders:
+            self._log.info(f"No {instrument_id} open or emulated orders to cancel")
+            return
+
+        # Preserve order of strategy IDs for deterministic command sequencing
+        cdef dict open_strategy_ids = {o.strategy_id: None for o in open_orders}
+        cdef dict emulated_strategy_ids = {o.strategy_id: None for o in emulated_orders}
+
+        self._log.warning(
+            f"Canceling {len(open_orders)} open and {len(emulated_orders)} emulated "
+            f"{instrument_id} order(s) as trading disabled",
+        )
+
+        cdef uint64_t ts_now = self._clock.timestamp_ns()
+        cdef CancelAllOrders command
+        for strategy_id in open_strategy_ids:
+            command = CancelAllOrders(
+                trader_id=self.trader_id,
+                strategy_id=strategy_id,
+                instrument_id=instrument_id,
+                order_side=OrderSide.NO_ORDER_SIDE,
+                command_id=UUID4(),
+                ts_init=ts_now,
+            )
+            self._msgbus.send(endpoint="ExecEngine.execute", msg=command)
+
+        for strategy_id in emulated_strategy_ids:
+            command = CancelAllOrders(
+                trader_id=self.trader_id,
+                strategy_id=strategy_id,
+                instrument_id=instrument_id,
+                order_side=OrderSide.NO_ORDER_SIDE,
+                command_id=UUID4(),
+                ts_init=ts_now,
+            )
+            self._msgbus.send(endpoint="OrderEmulator.execute", msg=command)
+
+    cdef void _update_position_age_timer(self, bint active):
+        cdef bint has_timer = self.position_age_timer_name in self._clock.timer_names
+        if active and self._max_position_age and not has_timer:
+            self._clock.set_timer_ns(
+                name=self.position_age_timer_name,
+                interval_ns=secs_to_nanos(self.position_age_check_interval_secs),
+                start_time_ns=0,
+                stop_time_ns=0,  # Run as long as the risk engine is running
+                callback=self._on_position_age_timer,
+            )
+        elif (not active or not self._max_position_age) and has_timer:
+            self._clock.cancel_timer(self.position_age_timer_name)
+
+    cpdef void _on_position_age_timer(self, TimeEvent event):
+        self.check_position_ages()
+
+    cdef void _flatten_position(self, Position position):
+        cdef ClientOrderId pending_id = self._flatten_orders.get(position.id)
+        cdef Order pending
+        if pending_id is not None:
+            pending = self._cache.order(pending_id)
+            if pending is not None and not pending.is_closed_c():
+                return  # Flattening order already in flight
+
+        cdef uint64_t ts_now = self._clock.timestamp_ns()
+        cdef MarketOrder order = MarketOrder(
+            trader_id=self.trader_id,
+            strategy_id=position.strategy_id,
+            instrument_id=position.instrument_id,
+            client_order_id=ClientOrderId(f"O-RISK-{UUID4()}"),
+            order_side=Order.closing_side_c(position.side),
+            quantity=position.quantity,
+            init_id=UUID4(),
+            ts_init=ts_now,
+            time_in_force=TimeInForce.GTC,
+            reduce_only=True,
+            tags=["MAX_POSITION_AGE"],
+        )
+        self._flatten_orders[position.id] = order.client_order_id
+
+        cdef SubmitOrder command = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=position.strategy_id,
+            order=order,
+            command_id=UUID4(),
+            ts_init=ts_now,
+            position_id=position.id,
+        )
+
+        self._log.warning(f"Flattening {position.id!r} exceeding MAX_POSITION_AGE with {order}")
+        self._send_to_execution(command)
+
 # -- PRE-TRADE CHECKS -----------------------------------------------------------------------------
 
     cpdef bint _check_order(self, Instrument instrument, Order order):
@@ -590,6 +957,34 @@ cdef class RiskEngine(Component):
 
         return True  # Passed
 
+    cpdef bint _check_daily_loss(self, Venue venue, list orders):
+        cdef Money max_loss = self._max_daily_loss.get(venue)
+        if max_loss is None:
+            return True  # No limit set
+
+        cdef Order order
+        cdef bint reduce_only = True
+        for order in orders:
+            if not order.is_reduce_only:
+                reduce_only = False
+                break
+
+        if reduce_only:
+            return True  # Reducing exposure is always permitted
+
+        if venue in self._daily_loss_breached:
+            return False  # Breached until daily limits reset
+
+        cdef Money pnl = self._portfolio.daily_pnls(venue).get(max_loss.currency)
+        if pnl is None or pnl.as_double() > -max_loss.as_double():
+            return True
+
+        self._daily_loss_breached.add(venue)
+        self._log.warning(
+            f"MAX_DAILY_LOSS_EXCEEDED for {venue}: daily PnL {pnl}, max loss {max_loss}",
+        )
+        return False
+
     cpdef bint _check_orders_risk(self, Instrument instrument, list orders):
         ########################################################################
         # RISK CHECKS
@@ -856,6 +1251,21 @@ cdef class RiskEngine(Component):
 # -- EGRESS ---------------------------------------------------------------------------------------
 
     cpdef void _execution_gateway(self, Instrument instrument, TradingCommand command):
+        # Check instrument trading enabled
+        if not self._cache.is_trading_enabled(instrument.id):
+            if isinstance(command, SubmitOrder):
+                self._deny_command(
+                    command=command,
+                    reason=f"Trading disabled for {instrument.id}",
+                )
+                return  # Denied
+            elif isinstance(command, SubmitOrderList):
+                self._deny_order_list(
+                    order_list=command.order_list,
+                    reason=f"Trading disabled for {instrument.id}",
+                )
+                return  # Denied
+
         # Check TradingState
         cdef Order order
         if self.trading_state == TradingState.HALTED:
@@ -19,7 +19,10 @@ from typing import Callable
 from nautilus_trader.adapters.binance.common.types import BinanceBar
 from nautilus_trader.adapters.binance.common.types import BinanceTicker
 
+from nautilus_trader.common.messages cimport CommandExpired
 from nautilus_trader.common.messages cimport ComponentStateChanged
+from nautilus_trader.common.messages cimport InstrumentConflictResolved
+from nautilus_trader.common.messages cimport SetInstrumentTrading
 from nautilus_trader.common.messages cimport ShutdownSystem
 from nautilus_trader.common.messages cimport TradingStateChanged
 from nautilus_trader.core.correctness cimport Condition
@@ -76,8 +79,11 @@ _OBJECT_TO_DICT_MAP: dict[str, Callable[[None], dict]] = {
     SubmitOrderList.__name__: SubmitOrderList.to_dict_c,
     ModifyOrder.__name__: ModifyOrder.to_dict_c,
     ShutdownSystem.__name__: ShutdownSystem.to_dict_c,
+    SetInstrumentTrading.__name__: SetInstrumentTrading.to_dict_c,
     ComponentStateChanged.__name__: ComponentStateChanged.to_dict_c,
     TradingStateChanged.__name__: TradingStateChanged.to_dict_c,
+    InstrumentConflictResolved.__name__: InstrumentConflictResolved.to_dict_c,
+    CommandExpired.__name__: CommandExpired.to_dict_c,
     AccountState.__name__: AccountState.to_dict_c,
     OrderAccepted.__name__: OrderAccepted.to_dict_c,
     OrderCancelRejected.__name__: OrderCancelRejected.to_dict_c,
@@ -130,8 +136,11 @@ _OBJECT_FROM_DICT_MAP: dict[str, Callable[[dict], Any]] = {
     SubmitOrderList.__name__: SubmitOrderList.from_dict_c,
     ModifyOrder.__name__: ModifyOrder.from_dict_c,
     ShutdownSystem.__name__: ShutdownSystem.from_dict_c,
+    SetInstrumentTrading.__name__: SetInstrumentTrading.from_dict_c,
     ComponentStateChanged.__name__: ComponentStateChanged.from_dict_c,
     TradingStateChanged.__name__: TradingStateChanged.from_dict_c,
+    InstrumentConflictResolved.__name__: InstrumentConflictResolved.from_dict_c,
+    CommandExpired.__name__: CommandExpired.from_dict_c,
     AccountState.__name__: AccountState.from_dict_c,
     OrderAccepted.__name__: OrderAccepted.from_dict_c,
     OrderCancelRejected.__name__: OrderCancelRejected.from_dict_c,
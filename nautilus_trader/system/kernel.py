@@ -561,6 +561,10 @@ class NautilusKernel:
             rotation_timezone=config.rotation_timezone,
         )
         self._trader.subscribe("*", self._writer.write)
+        self._msgbus.register(
+            endpoint="StreamingFeatherWriter.execute",
+            handler=self._writer.execute,
+        )
         self._log.info(f"Writing data & events to {path}")
 
         # Save a copy of the config for this kernel to the streaming folder.
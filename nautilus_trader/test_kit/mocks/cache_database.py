@@ -15,6 +15,7 @@
 
 from nautilus_trader.accounting.accounts.base import Account
 from nautilus_trader.cache.facade import CacheDatabaseFacade
+from nautilus_trader.model.events import OrderFilled
 from nautilus_trader.model.identifiers import AccountId
 from nautilus_trader.model.identifiers import ClientId
 from nautilus_trader.model.identifiers import ClientOrderId
@@ -24,6 +25,7 @@ from nautilus_trader.model.identifiers import StrategyId
 from nautilus_trader.model.instruments import Instrument
 from nautilus_trader.model.instruments import SyntheticInstrument
 from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
 from nautilus_trader.model.orders import Order
 from nautilus_trader.model.position import Position
 from nautilus_trader.trading.strategy import Strategy
@@ -46,6 +48,7 @@ class MockCacheDatabase(CacheDatabaseFacade):
         self.positions: dict[PositionId, Position] = {}
         self._index_order_position: dict[ClientOrderId, PositionId] = {}
         self._index_order_client: dict[ClientOrderId, ClientId] = {}
+        self.position_state_snapshots: list[tuple[PositionId, int, Money | None]] = []
 
     def flush(self) -> None:
         self.general.clear()
@@ -57,6 +60,7 @@ class MockCacheDatabase(CacheDatabaseFacade):
         self.positions.clear()
         self._index_order_position.clear()
         self._index_order_client.clear()
+        self.position_state_snapshots.clear()
 
     def load(self) -> dict:
         return self.general.copy()
@@ -94,6 +98,23 @@ class MockCacheDatabase(CacheDatabaseFacade):
     def load_order(self, client_order_id: ClientOrderId) -> Order | None:
         return self.orders.get(client_order_id)
 
+    def load_fills(
+        self,
+        instrument_id: InstrumentId,
+        start: int = 0,
+        end: int = 0,
+    ) -> list[OrderFilled]:
+        fills = [
+            event
+            for order in self.orders.values()
+            for event in order.events
+            if isinstance(event, OrderFilled) and event.instrument_id == instrument_id
+        ]
+        return sorted(
+            [f for f in fills if f.ts_event >= start and (end == 0 or f.ts_event <= end)],
+            key=lambda f: f.ts_event,
+        )
+
     def load_index_order_position(self) -> dict[ClientOrderId, PositionId]:
         return self._index_order_position
 
@@ -148,3 +169,11 @@ class MockCacheDatabase(CacheDatabaseFacade):
 
     def update_strategy(self, strategy: Strategy) -> None:
         pass  # Would persist the user state dict
+
+    def snapshot_position_state(
+        self,
+        position: Position,
+        ts_snapshot: int,
+        unrealized_pnl: Money | None = None,
+    ) -> None:
+        self.position_state_snapshots.append((position.id, ts_snapshot, unrealized_pnl))
@@ -0,0 +1,485 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+"""
+Provides scriptable mock venue servers (HTTP + WebSocket) for hermetic adapter
+integration tests.
+
+A `MockVenueServer` serves scripted HTTP routes and reacts to inbound WebSocket
+messages with scripted steps (delayed acks, partial fills, malformed payloads,
+connection closes). The same `VenueScript` can be run in-process from a test
+fixture, or standalone (e.g. within a container) via::
+
+    python venue.py --port 8080 --script script.json
+
+This module only depends on `aiohttp`, so it can run without a compiled
+`nautilus_trader` installation.
+
+"""
+
+import argparse
+import asyncio
+import json
+import weakref
+from collections.abc import Callable
+from dataclasses import dataclass
+from dataclasses import field
+from typing import Any
+
+from aiohttp import WSCloseCode
+from aiohttp import WSMsgType
+from aiohttp import web
+
+
+Payload = dict[str, Any] | list[Any] | str | bytes
+
+
+def _encode(payload: Payload) -> str | bytes:
+    if isinstance(payload, (dict, list)):
+        return json.dumps(payload)
+    return payload
+
+
+def _matches(pattern: dict[str, Any], message: Any) -> bool:
+    # Recursive subset match of `pattern` against a decoded JSON `message`
+    if not isinstance(message, dict):
+        return False
+    for key, expected in pattern.items():
+        if key not in message:
+            return False
+        if isinstance(expected, dict):
+            if not _matches(expected, message[key]):
+                return False
+        elif message[key] != expected:
+            return False
+    return True
+
+
+@dataclass(frozen=True)
+class WsStep:
+    """
+    Represents a single scripted WebSocket server action.
+
+    Parameters
+    ----------
+    payload : dict, list, str or bytes, optional
+        The payload to send (dicts and lists are JSON encoded).
+    raw : str, optional
+        The raw text to send verbatim (e.g. a malformed message).
+    delay_secs : float, default 0.0
+        The delay before performing the step.
+    close : bool, default False
+        If the server should close the connection for this step.
+    close_code : int, default 1000
+        The WebSocket close code when `close` is True.
+
+    """
+
+    payload: Payload | None = None
+    raw: str | None = None
+    delay_secs: float = 0.0
+    close: bool = False
+    close_code: int = WSCloseCode.OK
+
+    @classmethod
+    def from_dict(cls, values: dict[str, Any]) -> "WsStep":
+        return cls(
+            payload=values.get("payload"),
+            raw=values.get("raw"),
+            delay_secs=values.get("delay_secs", 0.0),
+            close=values.get("close", False),
+            close_code=values.get("close_code", WSCloseCode.OK),
+        )
+
+
+@dataclass(frozen=True)
+class WsRule:
+    """
+    Represents a scripted reaction to inbound WebSocket messages.
+
+    Parameters
+    ----------
+    match : dict[str, Any]
+        The pattern which must be a (recursive) subset of the decoded JSON message.
+        An empty pattern matches every message.
+    steps : list[WsStep]
+        The steps to perform in order when a message matches.
+    once : bool, default False
+        If the rule should only fire for the first matching message.
+
+    """
+
+    match: dict[str, Any]
+    steps: list[WsStep]
+    once: bool = False
+
+    @classmethod
+    def from_dict(cls, values: dict[str, Any]) -> "WsRule":
+        return cls(
+            match=values.get("match", {}),
+            steps=[WsStep.from_dict(s) for s in values.get("steps", [])],
+            once=values.get("once", False),
+        )
+
+
+@dataclass(frozen=True)
+class HttpRoute:
+    """
+    Represents a scripted HTTP route.
+
+    Parameters
+    ----------
+    method : str
+        The HTTP method (e.g. 'GET', 'POST').
+    path : str
+        The route path (aiohttp path syntax, e.g. '/api/v3/order').
+    body : dict, list, str or bytes, optional
+        The response body (dicts and lists are JSON encoded).
+    status : int, default 200
+        The response status code.
+    delay_secs : float, default 0.0
+        The delay before responding.
+    headers : dict[str, str], optional
+        The additional response headers.
+
+    """
+
+    method: str
+    path: str
+    body: Payload | None = None
+    status: int = 200
+    delay_secs: float = 0.0
+    headers: dict[str, str] = field(default_factory=dict)
+
+    @classmethod
+    def from_dict(cls, values: dict[str, Any]) -> "HttpRoute":
+        return cls(
+            method=values["method"].upper(),
+            path=values["path"],
+            body=values.get("body"),
+            status=values.get("status", 200),
+            delay_secs=values.get("delay_secs", 0.0),
+            headers=values.get("headers", {}),
+        )
+
+
+@dataclass(frozen=True)
+class VenueScript:
+    """
+    Represents the scripted behaviors for a `MockVenueServer`.
+
+    Parameters
+    ----------
+    http : list[HttpRoute], optional
+        The scripted HTTP routes.
+    ws : list[WsRule], optional
+        The scripted WebSocket rules (the first matching rule wins).
+    ws_on_connect : list[WsStep], optional
+        The steps to perform for each new WebSocket connection.
+
+    """
+
+    http: list[HttpRoute] = field(default_factory=list)
+    ws: list[WsRule] = field(default_factory=list)
+    ws_on_connect: list[WsStep] = field(default_factory=list)
+
+    @classmethod
+    def from_dict(cls, values: dict[str, Any]) -> "VenueScript":
+        return cls(
+            http=[HttpRoute.from_dict(r) for r in values.get("http", [])],
+            ws=[WsRule.from_dict(r) for r in values.get("ws", [])],
+            ws_on_connect=[WsStep.from_dict(s) for s in values.get("ws_on_connect", [])],
+        )
+
+    @classmethod
+    def from_json(cls, data: str | bytes) -> "VenueScript":
+        return cls.from_dict(json.loads(data))
+
+
+class MockVenueServer:
+    """
+    Provides a scriptable mock venue server with HTTP and WebSocket endpoints.
+
+    All inbound HTTP requests and WebSocket messages are recorded, so tests can
+    assert on what an adapter sent to the venue.
+
+    Parameters
+    ----------
+    script : VenueScript, optional
+        The initial scripted behaviors.
+    host : str, default '127.0.0.1'
+        The host to bind to.
+    port : int, default 0
+        The port to bind to (0 for an ephemeral port).
+    ws_path : str, default '/ws'
+        The path for the WebSocket endpoint.
+
+    """
+
+    def __init__(
+        self,
+        script: VenueScript | None = None,
+        host: str = "127.0.0.1",
+        port: int = 0,
+        ws_path: str = "/ws",
+    ) -> None:
+        self._script = script or VenueScript()
+        self._host = host
+        self._port = port
+        self._ws_path = ws_path
+        self._http_handlers: dict[tuple[str, str], Callable[[web.Request], Any]] = {}
+        self._ws_rules: list[WsRule] = list(self._script.ws)
+        self._fired: set[int] = set()
+        self._websockets: weakref.WeakSet = weakref.WeakSet()
+        self._runner: web.AppRunner | None = None
+        self._site: web.TCPSite | None = None
+
+        self.http_requests: list[tuple[str, str, bytes]] = []
+        self.ws_messages: list[str | bytes] = []
+
+        for route in self._script.http:
+            self.add_http_route(route)
+
+    @property
+    def port(self) -> int:
+        """
+        Return the bound port for the server.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._port
+
+    @property
+    def http_url(self) -> str:
+        """
+        Return the base HTTP URL for the server.
+
+        Returns
+        -------
+        str
+
+        """
+        return f"http://{self._host}:{self._port}"
+
+    @property
+    def ws_url(self) -> str:
+        """
+        Return the WebSocket URL for the server.
+
+        Returns
+        -------
+        str
+
+        """
+        return f"ws://{self._host}:{self._port}{self._ws_path}"
+
+    @property
+    def ws_connection_count(self) -> int:
+        """
+        Return the count of open WebSocket connections.
+
+        Returns
+        -------
+        int
+
+        """
+        return len(self._websockets)
+
+    def add_http_route(self, route: HttpRoute) -> None:
+        """
+        Add (or replace) the given scripted HTTP route.
+
+        Routes may be replaced while the server is running.
+
+        Parameters
+        ----------
+        route : HttpRoute
+            The route to add.
+
+        """
+        async def handler(request: web.Request) -> web.Response:
+            if route.delay_secs > 0:
+                await asyncio.sleep(route.delay_secs)
+            body = _encode(route.body) if route.body is not None else None
+            return web.Response(
+                status=route.status,
+                text=body if isinstance(body, str) else None,
+                body=body if isinstance(body, bytes) else None,
+                headers=route.headers,
+                content_type="application/json" if isinstance(route.body, (dict, list)) else None,
+            )
+
+        self._http_handlers[(route.method.upper(), route.path)] = handler
+
+    def add_ws_rule(self, rule: WsRule) -> None:
+        """
+        Add the given scripted WebSocket rule (evaluated after existing rules).
+
+        Parameters
+        ----------
+        rule : WsRule
+            The rule to add.
+
+        """
+        self._ws_rules.append(rule)
+
+    async def push(self, payload: Payload) -> None:
+        """
+        Send the given payload to all open WebSocket connections.
+
+        Parameters
+        ----------
+        payload : dict, list, str or bytes
+            The payload to send (dicts and lists are JSON encoded).
+
+        """
+        for ws in set(self._websockets):
+            await self._send(ws, _encode(payload))
+
+    async def drop_connections(self, code: int = WSCloseCode.GOING_AWAY) -> None:
+        """
+        Close all open WebSocket connections (e.g. to exercise reconnects).
+
+        Parameters
+        ----------
+        code : int, default 1001
+            The WebSocket close code.
+
+        """
+        for ws in set(self._websockets):
+            await ws.close(code=code)
+
+    async def start(self) -> None:
+        """
+        Start the server.
+        """
+        app = web.Application()
+        app.router.add_get(self._ws_path, self._handle_ws)
+        app.router.add_route("*", "/{tail:.*}", self._handle_http)
+
+        self._runner = web.AppRunner(app)
+        await self._runner.setup()
+        self._site = web.TCPSite(self._runner, self._host, self._port)
+        await self._site.start()
+
+        if self._port == 0:
+            self._port = self._runner.addresses[0][1]
+
+    async def stop(self) -> None:
+        """
+        Stop the server, closing all open WebSocket connections.
+        """
+        await self.drop_connections()
+        if self._runner is not None:
+            await self._runner.cleanup()
+            self._runner = None
+            self._site = None
+
+    async def __aenter__(self) -> "MockVenueServer":
+        await self.start()
+        return self
+
+    async def __aexit__(self, *args: Any) -> None:
+        await self.stop()
+
+    async def _handle_http(self, request: web.Request) -> web.StreamResponse:
+        body = await request.read()
+        self.http_requests.append((request.method, request.path, body))
+
+        handler = self._http_handlers.get((request.method, request.path))
+        if handler is None:
+            return web.Response(status=404, text=f"No scripted route for {request.method} {request.path}")
+        return await handler(request)
+
+    async def _handle_ws(self, request: web.Request) -> web.WebSocketResponse:
+        ws = web.WebSocketResponse()
+        await ws.prepare(request)
+        self._websockets.add(ws)
+
+        await self._run_steps(ws, self._script.ws_on_connect)
+
+        async for msg in ws:
+            if msg.type not in (WSMsgType.TEXT, WSMsgType.BINARY):
+                continue
+            self.ws_messages.append(msg.data)
+            rule = self._find_rule(msg.data)
+            if rule is not None:
+                await self._run_steps(ws, rule.steps)
+
+        return ws
+
+    def _find_rule(self, data: str | bytes) -> WsRule | None:
+        try:
+            message = json.loads(data)
+        except ValueError:
+            message = None
+
+        for i, rule in enumerate(self._ws_rules):
+            if rule.once and i in self._fired:
+                continue
+            if rule.match and not _matches(rule.match, message):
+                continue
+            if rule.once:
+                self._fired.add(i)
+            return rule
+
+        return None
+
+    async def _run_steps(self, ws: web.WebSocketResponse, steps: list[WsStep]) -> None:
+        for step in steps:
+            if step.delay_secs > 0:
+                await asyncio.sleep(step.delay_secs)
+            if ws.closed:
+                return
+            if step.close:
+                await ws.close(code=step.close_code)
+                return
+            if step.raw is not None:
+                await self._send(ws, step.raw)
+            elif step.payload is not None:
+                await self._send(ws, _encode(step.payload))
+
+    async def _send(self, ws: web.WebSocketResponse, data: str | bytes) -> None:
+        if ws.closed:
+            return
+        if isinstance(data, bytes):
+            await ws.send_bytes(data)
+        else:
+            await ws.send_str(data)
+
+
+async def _serve(host: str, port: int, script: VenueScript) -> None:
+    server = MockVenueServer(script=script, host=host, port=port)
+    await server.start()
+    print(f"Mock venue serving on {server.http_url} (ws {server.ws_url})")  # noqa: T201
+    await asyncio.Event().wait()  # Serve until cancelled
+
+
+if __name__ == "__main__":
+    parser = argparse.ArgumentParser(description="Run a scriptable mock venue server")
+    parser.add_argument("--host", default="0.0.0.0")  # noqa: S104 (container usage)
+    parser.add_argument("--port", type=int, default=8080)
+    parser.add_argument("--script", help="Path to a JSON venue script")
+    args = parser.parse_args()
+
+    venue_script = VenueScript()
+    if args.script:
+        with open(args.script, "rb") as f:
+            venue_script = VenueScript.from_json(f.read())
+
+    asyncio.run(_serve(args.host, args.port, venue_script))
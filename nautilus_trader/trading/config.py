@@ -51,6 +51,9 @@ class StrategyConfig(NautilusConfig, kw_only=True, frozen=True):
     manage_gtd_expiry : bool, default False
         If all order GTD time in force expirations should be managed by the strategy.
         If True, then will ensure open orders have their GTD timers re-activated on start.
+    persist_subscriptions : bool, default False
+        If the strategy's data subscriptions should be persisted in the cache, and
+        automatically resubscribed on start (prior to `on_start`).
 
     """
 
@@ -60,6 +63,7 @@ class StrategyConfig(NautilusConfig, kw_only=True, frozen=True):
     external_order_claims: list[InstrumentId] | None = None
     manage_contingent_orders: bool = False
     manage_gtd_expiry: bool = False
+    persist_subscriptions: bool = False
 
 
 class ImportableStrategyConfig(NautilusConfig, frozen=True):
@@ -13,6 +13,8 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from libc.stdint cimport uint64_t
+
 from nautilus_trader.cache.base cimport CacheFacade
 from nautilus_trader.common.actor cimport Actor
 from nautilus_trader.common.component cimport Clock
@@ -126,12 +128,14 @@ cdef class Strategy(Actor):
         Order order,
         PositionId position_id=*,
         ClientId client_id=*,
+        uint64_t ts_deadline=*,
     )
     cpdef void submit_order_list(
         self,
         OrderList order_list,
         PositionId position_id=*,
         ClientId client_id=*,
+        uint64_t ts_deadline=*,
     )
     cpdef void modify_order(
         self,
@@ -140,8 +144,9 @@ cdef class Strategy(Actor):
         Price price=*,
         Price trigger_price=*,
         ClientId client_id=*,
+        uint64_t ts_deadline=*,
     )
-    cpdef void cancel_order(self, Order order, ClientId client_id=*)
+    cpdef void cancel_order(self, Order order, ClientId client_id=*, uint64_t ts_deadline=*)
     cpdef void cancel_orders(self, list orders, ClientId client_id=*)
     cpdef void cancel_all_orders(self, InstrumentId instrument_id, OrderSide order_side=*, ClientId client_id=*)
     cpdef void close_position(self, Position position, ClientId client_id=*, list[str] tags=*, bint reduce_only=*)
@@ -154,8 +159,9 @@ cdef class Strategy(Actor):
         Price price=*,
         Price trigger_price=*,
         ClientId client_id=*,
+        uint64_t ts_deadline=*,
     )
-    cdef CancelOrder _create_cancel_order(self, Order order, ClientId client_id=*)
+    cdef CancelOrder _create_cancel_order(self, Order order, ClientId client_id=*, uint64_t ts_deadline=*)
 
     cpdef void cancel_gtd_expiry(self, Order order)
     cdef bint _has_gtd_expiry_timer(self, ClientOrderId client_order_id)
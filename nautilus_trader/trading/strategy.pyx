@@ -375,6 +375,7 @@ cdef class Strategy(Actor):
                     if not self._has_gtd_expiry_timer(order.client_order_id):
                         self._set_gtd_expiry(order)
 
+        self.resubscribe_persisted()
         self.on_start()
 
     cpdef void _reset(self):
@@ -736,6 +737,7 @@ cdef class Strategy(Actor):
         Order order,
         PositionId position_id = None,
         ClientId client_id = None,
+        uint64_t ts_deadline = 0,
     ):
         """
         Submit the given order with optional position ID, execution algorithm
@@ -757,6 +759,9 @@ cdef class Strategy(Actor):
         client_id : ClientId, optional
             The specific execution client ID for the command.
             If ``None`` then will be inferred from the venue in the instrument ID.
+        ts_deadline : uint64_t, default 0
+            UNIX timestamp (nanoseconds) after which the command will be aborted
+            by the `ExecutionEngine` rather than sent to the venue (0 for no deadline).
 
         Raises
         ------
@@ -799,6 +804,7 @@ cdef class Strategy(Actor):
             ts_init=self.clock.timestamp_ns(),
             position_id=position_id,
             client_id=client_id,
+            ts_deadline=ts_deadline,
         )
 
         if self.manage_gtd_expiry and order.time_in_force == TimeInForce.GTD:
@@ -816,7 +822,8 @@ cdef class Strategy(Actor):
         self,
         OrderList order_list,
         PositionId position_id = None,
-        ClientId client_id = None
+        ClientId client_id = None,
+        uint64_t ts_deadline = 0,
     ):
         """
         Submit the given order list with optional position ID, execution algorithm
@@ -838,6 +845,9 @@ cdef class Strategy(Actor):
         client_id : ClientId, optional
             The specific execution client ID for the command.
             If ``None`` then will be inferred from the venue in the instrument ID.
+        ts_deadline : uint64_t, default 0
+            UNIX timestamp (nanoseconds) after which the command will be aborted
+            by the `ExecutionEngine` rather than sent to the venue (0 for no deadline).
 
         Raises
         ------
@@ -894,6 +904,7 @@ cdef class Strategy(Actor):
             command_id=UUID4(),
             ts_init=self.clock.timestamp_ns(),
             client_id=client_id,
+            ts_deadline=ts_deadline,
         )
 
         if self.manage_gtd_expiry:
@@ -916,6 +927,7 @@ cdef class Strategy(Actor):
         Price price = None,
         Price trigger_price = None,
         ClientId client_id = None,
+        uint64_t ts_deadline = 0,
     ):
         """
         Modify the given order with optional parameters and routing instructions.
@@ -943,6 +955,9 @@ cdef class Strategy(Actor):
         client_id : ClientId, optional
             The specific client ID for the command.
             If ``None`` then will be inferred from the venue in the instrument ID.
+        ts_deadline : uint64_t, default 0
+            UNIX timestamp (nanoseconds) after which the command will be aborted
+            by the `ExecutionEngine` rather than sent to the venue (0 for no deadline).
 
         Raises
         ------
@@ -970,6 +985,7 @@ cdef class Strategy(Actor):
             price=price,
             trigger_price=trigger_price,
             client_id=client_id,
+            ts_deadline=ts_deadline,
         )
         if command is None:
             return
@@ -979,7 +995,7 @@ cdef class Strategy(Actor):
         else:
             self._manager.send_risk_command(command)
 
-    cpdef void cancel_order(self, Order order, ClientId client_id = None):
+    cpdef void cancel_order(self, Order order, ClientId client_id = None, uint64_t ts_deadline = 0):
         """
         Cancel the given order with optional routing instructions.
 
@@ -993,6 +1009,9 @@ cdef class Strategy(Actor):
         client_id : ClientId, optional
             The specific client ID for the command.
             If ``None`` then will be inferred from the venue in the instrument ID.
+        ts_deadline : uint64_t, default 0
+            UNIX timestamp (nanoseconds) after which the command will be aborted
+            by the `ExecutionEngine` rather than sent to the venue (0 for no deadline).
 
         """
         Condition.is_true(self.trader_id is not None, "The strategy has not been registered")
@@ -1001,6 +1020,7 @@ cdef class Strategy(Actor):
         cdef CancelOrder command = self._create_cancel_order(
             order=order,
             client_id=client_id,
+            ts_deadline=ts_deadline,
         )
         if command is None:
             return
@@ -1338,6 +1358,7 @@ cdef class Strategy(Actor):
         Price price = None,
         Price trigger_price = None,
         ClientId client_id = None,
+        uint64_t ts_deadline = 0,
     ):
         cdef bint updating = False  # Set validation flag (must become true)
 
@@ -1406,9 +1427,10 @@ cdef class Strategy(Actor):
             command_id=UUID4(),
             ts_init=self.clock.timestamp_ns(),
             client_id=client_id,
+            ts_deadline=ts_deadline,
         )
 
-    cdef CancelOrder _create_cancel_order(self, Order order, ClientId client_id = None):
+    cdef CancelOrder _create_cancel_order(self, Order order, ClientId client_id = None, uint64_t ts_deadline = 0):
         if order.is_closed_c() or order.is_pending_cancel_c():
             self.log.warning(
                 f"Cannot cancel order: state is {order.status_string_c()}, {order}",
@@ -1443,6 +1465,7 @@ cdef class Strategy(Actor):
             command_id=UUID4(),
             ts_init=self.clock.timestamp_ns(),
             client_id=client_id,
+            ts_deadline=ts_deadline,
         )
 
     cpdef void cancel_gtd_expiry(self, Order order):
@@ -643,6 +643,7 @@ class Trader(Component):
         if actor.is_running:
             actor.stop()
 
+        self._data_engine.unsubscribe_all(actor.id)
         self._actors.pop(actor_id)
         deregister_component_clock(self._instance_id, actor.clock)
 
@@ -672,6 +673,7 @@ class Trader(Component):
         if strategy.is_running:
             strategy.stop()
 
+        self._data_engine.unsubscribe_all(strategy.id)
         self._strategies.pop(strategy_id)
         deregister_component_clock(self._instance_id, strategy.clock)
 
@@ -194,6 +194,9 @@ def mock_data_engine_process(mocker: MockerFixture, msgbus, data_engine):
         endpoint="DataEngine.process",
         handler=mock,
     )
+    for client_id, handler in data_engine._client_process_handlers.items():
+        msgbus.deregister(endpoint=f"DataEngine.process.{client_id}", handler=handler)
+        msgbus.register(endpoint=f"DataEngine.process.{client_id}", handler=mock)
     return mock
 
 
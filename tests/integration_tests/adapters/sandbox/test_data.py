@@ -0,0 +1,219 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+
+import asyncio
+
+import pytest
+
+from nautilus_trader.adapters.sandbox.config import SandboxReplayDataClientConfig
+from nautilus_trader.adapters.sandbox.data import SandboxReplayDataClient
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.persistence.catalog.parquet import ParquetDataCatalog
+from nautilus_trader.test_kit.functions import eventually
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
+
+
+_SECOND_NS = 1_000_000_000
+
+
+@pytest.fixture()
+def catalog(tmp_path, instrument) -> ParquetDataCatalog:
+    catalog = ParquetDataCatalog(path=tmp_path.as_posix(), fs_protocol="file")
+    catalog.write_data([instrument])
+    catalog.write_data(
+        [
+            TestDataStubs.quote_tick(
+                instrument,
+                bid_price=100.0 + i,
+                ask_price=101.0 + i,
+                ts_event=i * _SECOND_NS,
+                ts_init=i * _SECOND_NS,
+            )
+            for i in range(1, 4)
+        ],
+    )
+    return catalog
+
+
+def _make_replay_client(event_loop, msgbus, cache, live_clock, venue, catalog, **kwargs):
+    config = SandboxReplayDataClientConfig(
+        venue=venue.value,
+        catalog_path=catalog.path,
+        replay_start_delay_secs=0.0,
+        **kwargs,
+    )
+    return SandboxReplayDataClient(
+        loop=event_loop,
+        msgbus=msgbus,
+        cache=cache,
+        clock=live_clock,
+        config=config,
+        catalog=catalog,
+    )
+
+
+def test_replay_client_with_invalid_speed_raises(
+    event_loop,
+    msgbus,
+    cache,
+    live_clock,
+    venue,
+    catalog,
+):
+    # Arrange, Act, Assert
+    with pytest.raises(ValueError):
+        _make_replay_client(event_loop, msgbus, cache, live_clock, venue, catalog, speed=0.0)
+
+
+@pytest.mark.asyncio()
+async def test_connect_loads_instruments_from_catalog(
+    event_loop,
+    msgbus,
+    cache,
+    live_clock,
+    venue,
+    catalog,
+    instrument,
+    mock_data_engine_process,
+):
+    # Arrange
+    client = _make_replay_client(event_loop, msgbus, cache, live_clock, venue, catalog)
+
+    # Act
+    client.connect()
+    await eventually(lambda: client.is_connected)
+
+    # Assert
+    mock_data_engine_process.assert_called_with(instrument)
+
+
+@pytest.mark.asyncio()
+async def test_subscribe_quote_ticks_replays_as_fast_as_possible(
+    event_loop,
+    msgbus,
+    cache,
+    live_clock,
+    venue,
+    catalog,
+    instrument,
+    mock_data_engine_process,
+):
+    # Arrange
+    client = _make_replay_client(
+        event_loop,
+        msgbus,
+        cache,
+        live_clock,
+        venue,
+        catalog,
+        speed=None,
+    )
+    client.connect()
+    await eventually(lambda: client.is_connected)
+
+    # Act
+    client.subscribe_quote_ticks(instrument.id)
+    await eventually(lambda: client.replay_time_ns == 3 * _SECOND_NS)
+
+    # Assert
+    replayed = [
+        call.args[0]
+        for call in mock_data_engine_process.call_args_list
+        if isinstance(call.args[0], QuoteTick)
+    ]
+    assert [q.ts_init for q in replayed] == [_SECOND_NS, 2 * _SECOND_NS, 3 * _SECOND_NS]
+
+    client.disconnect()
+    await asyncio.sleep(0)
+
+
+@pytest.mark.asyncio()
+async def test_subscribe_quote_ticks_replays_with_scaled_timing(
+    event_loop,
+    msgbus,
+    cache,
+    live_clock,
+    venue,
+    catalog,
+    instrument,
+    mock_data_engine_process,
+):
+    # Arrange
+    client = _make_replay_client(
+        event_loop,
+        msgbus,
+        cache,
+        live_clock,
+        venue,
+        catalog,
+        speed=10.0,  # 2 seconds of recorded data replays in ~0.2 seconds
+    )
+    client.connect()
+    await eventually(lambda: client.is_connected)
+
+    # Act
+    start_ns = live_clock.timestamp_ns()
+    client.subscribe_quote_ticks(instrument.id)
+    await eventually(lambda: client.replay_time_ns == 3 * _SECOND_NS)
+    elapsed_ns = live_clock.timestamp_ns() - start_ns
+
+    # Assert
+    assert elapsed_ns >= 0.2 * _SECOND_NS
+
+    client.disconnect()
+    await asyncio.sleep(0)
+
+
+@pytest.mark.asyncio()
+async def test_unsubscribe_quote_ticks_stops_replay_of_stream(
+    event_loop,
+    msgbus,
+    cache,
+    live_clock,
+    venue,
+    catalog,
+    instrument,
+    mock_data_engine_process,
+):
+    # Arrange
+    client = _make_replay_client(
+        event_loop,
+        msgbus,
+        cache,
+        live_clock,
+        venue,
+        catalog,
+        speed=1.0,
+    )
+    client.connect()
+    await eventually(lambda: client.is_connected)
+    client.subscribe_quote_ticks(instrument.id)
+    await eventually(lambda: client.replay_time_ns == _SECOND_NS)
+
+    # Act
+    client.unsubscribe_quote_ticks(instrument.id)
+    await asyncio.sleep(0.1)
+
+    # Assert
+    replayed = [
+        call.args[0]
+        for call in mock_data_engine_process.call_args_list
+        if isinstance(call.args[0], QuoteTick)
+    ]
+    assert len(replayed) == 1
+
+    client.disconnect()
+    await asyncio.sleep(0)
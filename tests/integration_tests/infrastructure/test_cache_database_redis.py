@@ -353,6 +353,49 @@ class TestCacheDatabaseAdapter:
         # Assert
         assert self.database.load_order(order.client_order_id) == order
 
+    @pytest.mark.asyncio
+    async def test_load_fills_after_order_filled_returns_fills_in_range(self):
+        # Arrange
+        order = self.strategy.order_factory.market(
+            _AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        self.database.add_order(order)
+
+        # Allow MPSC thread to insert
+        await eventually(lambda: self.database.load_order(order.client_order_id))
+
+        order.apply(TestEventStubs.order_submitted(order))
+        self.database.update_order(order)
+
+        order.apply(TestEventStubs.order_accepted(order))
+        self.database.update_order(order)
+
+        fills = []
+        for i, ts in enumerate([1_000, 2_000]):
+            fill = TestEventStubs.order_filled(
+                order,
+                instrument=_AUDUSD_SIM,
+                trade_id=TradeId(f"E-{i}"),
+                last_qty=Quantity.from_int(50_000),
+                ts_filled_ns=ts,
+            )
+            order.apply(fill)
+            self.database.update_order(order)
+            fills.append(fill)
+
+        # Allow MPSC thread to insert
+        await eventually(lambda: len(self.database.load_fills(_AUDUSD_SIM.id)) == 2)
+
+        # Act
+        result = self.database.load_fills(_AUDUSD_SIM.id, start=1_500)
+
+        # Assert
+        assert self.database.load_fills(_AUDUSD_SIM.id) == fills
+        assert result == [fills[1]]
+
     @pytest.mark.asyncio
     async def test_update_position_for_closed_position(self):
         # Arrange
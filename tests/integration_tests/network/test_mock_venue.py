@@ -0,0 +1,160 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import json
+from pathlib import Path
+
+import aiohttp
+import pytest
+import pytest_asyncio
+
+from nautilus_trader.test_kit.functions import eventually
+from nautilus_trader.test_kit.mocks.venue import HttpRoute
+from nautilus_trader.test_kit.mocks.venue import MockVenueServer
+from nautilus_trader.test_kit.mocks.venue import VenueScript
+from nautilus_trader.test_kit.mocks.venue import WsRule
+from nautilus_trader.test_kit.mocks.venue import WsStep
+
+
+SCRIPT_PATH = Path(__file__).parent / "venue_scripts" / "venue.json"
+
+
+@pytest_asyncio.fixture(name="venue_server")
+async def fixture_venue_server():
+    script = VenueScript.from_json(SCRIPT_PATH.read_bytes())
+    async with MockVenueServer(script=script) as server:
+        yield server
+
+
+@pytest.mark.asyncio()
+async def test_http_scripted_route_returns_body(venue_server):
+    # Arrange
+    async with aiohttp.ClientSession() as session:
+        # Act
+        async with session.get(f"{venue_server.http_url}/api/v1/ping") as resp:
+            status = resp.status
+            body = await resp.json()
+
+    # Assert
+    assert status == 200
+    assert body == {}
+    assert venue_server.http_requests == [("GET", "/api/v1/ping", b"")]
+
+
+@pytest.mark.asyncio()
+async def test_http_unscripted_route_returns_404(venue_server):
+    # Arrange
+    async with aiohttp.ClientSession() as session:
+        # Act
+        async with session.get(f"{venue_server.http_url}/api/v1/unknown") as resp:
+            status = resp.status
+
+    # Assert
+    assert status == 404
+
+
+@pytest.mark.asyncio()
+async def test_http_route_replaced_while_running(venue_server):
+    # Arrange
+    venue_server.add_http_route(
+        HttpRoute(method="POST", path="/api/v1/order", body={"code": -1021}, status=400),
+    )
+
+    async with aiohttp.ClientSession() as session:
+        # Act
+        async with session.post(f"{venue_server.http_url}/api/v1/order", json={}) as resp:
+            status = resp.status
+            body = await resp.json()
+
+    # Assert
+    assert status == 400
+    assert body == {"code": -1021}
+
+
+@pytest.mark.asyncio()
+async def test_ws_scripted_partial_fills(venue_server):
+    # Arrange
+    received = []
+    async with aiohttp.ClientSession() as session:
+        async with session.ws_connect(venue_server.ws_url) as ws:
+            # Act
+            await ws.send_str(json.dumps({"op": "order", "args": {"side": "BUY", "qty": "1"}}))
+            for _ in range(4):
+                received.append(json.loads((await ws.receive()).data))
+
+    # Assert
+    assert [m["event"] for m in received] == ["connected", "ack", "fill", "fill"]
+    assert received[-1]["leaves"] == "0"
+    assert len(venue_server.ws_messages) == 1
+
+
+@pytest.mark.asyncio()
+async def test_ws_scripted_malformed_message(venue_server):
+    # Arrange
+    async with aiohttp.ClientSession() as session:
+        async with session.ws_connect(venue_server.ws_url) as ws:
+            await ws.receive()  # Connected
+
+            # Act
+            await ws.send_str(json.dumps({"op": "order", "args": {"side": "SELL"}}))
+            msg = await ws.receive()
+
+    # Assert
+    with pytest.raises(json.JSONDecodeError):
+        json.loads(msg.data)
+
+
+@pytest.mark.asyncio()
+async def test_ws_once_rule_and_close():
+    # Arrange
+    script = VenueScript(
+        ws=[
+            WsRule(match={"op": "ping"}, steps=[WsStep(payload={"op": "pong"})], once=True),
+            WsRule(match={}, steps=[WsStep(close=True, close_code=4000)]),
+        ],
+    )
+
+    async with MockVenueServer(script=script) as server:
+        async with aiohttp.ClientSession() as session:
+            async with session.ws_connect(server.ws_url) as ws:
+                # Act
+                await ws.send_str(json.dumps({"op": "ping"}))
+                first = await ws.receive()
+                await ws.send_str(json.dumps({"op": "ping"}))
+                second = await ws.receive()
+
+    # Assert
+    assert json.loads(first.data) == {"op": "pong"}
+    assert second.type == aiohttp.WSMsgType.CLOSE
+    assert second.data == 4000
+
+
+@pytest.mark.asyncio()
+async def test_ws_push_and_drop_connections(venue_server):
+    # Arrange
+    async with aiohttp.ClientSession() as session:
+        async with session.ws_connect(venue_server.ws_url) as ws:
+            await ws.receive()  # Connected
+            await eventually(lambda: venue_server.ws_connection_count == 1)
+
+            # Act
+            await venue_server.push({"event": "cancel", "orderId": "1"})
+            pushed = await ws.receive()
+            await venue_server.drop_connections()
+            closed = await ws.receive()
+
+    # Assert
+    assert json.loads(pushed.data) == {"event": "cancel", "orderId": "1"}
+    assert closed.type == aiohttp.WSMsgType.CLOSE
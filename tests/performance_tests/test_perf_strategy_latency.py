@@ -0,0 +1,221 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+
+import os
+import statistics
+import time
+
+from nautilus_trader.backtest.data_client import BacktestMarketDataClient
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.data.engine import DataEngine
+from nautilus_trader.execution.engine import ExecutionEngine
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.identifiers import ClientId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.portfolio.portfolio import Portfolio
+from nautilus_trader.risk.config import RiskEngineConfig
+from nautilus_trader.risk.engine import RiskEngine
+from nautilus_trader.test_kit.mocks.exec_clients import MockExecutionClient
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.component import TestComponentStubs
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
+from nautilus_trader.test_kit.stubs.events import TestEventStubs
+from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
+from nautilus_trader.trading.strategy import Strategy
+
+
+SIM = Venue("SIM")
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+
+# The p99 tick-to-order latency budget can be overridden for slower or noisier environments
+P99_BUDGET_US = float(os.environ.get("NAUTILUS_PERF_TICK_TO_ORDER_P99_BUDGET_US", "500"))
+TICK_COUNT = 5_000
+WARMUP_COUNT = 500
+
+
+class ReferenceSignalStrategy(Strategy):
+    """
+    Provides a reference strategy which submits a market order on every quote,
+    in the direction of the change in mid price.
+    """
+
+    def __init__(self) -> None:
+        super().__init__()
+        self.last_mid: float | None = None
+
+    def on_start(self) -> None:
+        self.subscribe_quote_ticks(AUDUSD_SIM.id)
+
+    def on_quote_tick(self, tick: QuoteTick) -> None:
+        mid = (tick.bid_price.as_double() + tick.ask_price.as_double()) / 2.0
+        side = OrderSide.BUY if self.last_mid is None or mid >= self.last_mid else OrderSide.SELL
+        self.last_mid = mid
+
+        order = self.order_factory.market(
+            instrument_id=tick.instrument_id,
+            order_side=side,
+            quantity=AUDUSD_SIM.make_qty(10_000),
+        )
+        self.submit_order(order)
+
+
+class LatencyRecordingExecutionClient(MockExecutionClient):
+    """
+    Provides a mock execution client which records the time each order is received.
+    """
+
+    def __init__(self, *args, **kwargs) -> None:
+        super().__init__(*args, **kwargs)
+        self.received_ns: list[int] = []
+
+    def submit_order(self, command) -> None:
+        self.received_ns.append(time.perf_counter_ns())
+        super().submit_order(command)
+
+
+class TestStrategyLatencyPerformance:
+    def setup(self):
+        # Fixture Setup
+        self.clock = LiveClock()
+        self.trader_id = TestIdStubs.trader_id()
+
+        self.msgbus = MessageBus(
+            trader_id=self.trader_id,
+            clock=self.clock,
+        )
+
+        self.cache = TestComponentStubs.cache()
+
+        self.portfolio = Portfolio(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.data_engine = DataEngine(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.exec_engine = ExecutionEngine(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=RiskEngineConfig(max_order_submit_rate="1000000/00:00:01"),
+        )
+
+        self.data_client = BacktestMarketDataClient(
+            client_id=ClientId("SIM"),
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.exec_client = LatencyRecordingExecutionClient(
+            client_id=ClientId("SIM"),
+            venue=SIM,
+            account_type=AccountType.MARGIN,
+            base_currency=None,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.data_engine.register_client(self.data_client)
+        self.exec_engine.register_client(self.exec_client)
+        self.portfolio.update_account(TestEventStubs.margin_account_state())
+        self.cache.add_instrument(AUDUSD_SIM)
+
+        self.strategy = ReferenceSignalStrategy()
+        self.strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.data_engine.start()
+        self.exec_engine.start()
+        self.risk_engine.start()
+        self.strategy.start()
+
+    def create_ticks(self, count: int) -> list[QuoteTick]:
+        return [
+            TestDataStubs.quote_tick(
+                instrument=AUDUSD_SIM,
+                bid_price=1.00000 + (i % 20) * 0.00001,
+                ask_price=1.00002 + (i % 20) * 0.00001,
+                ts_event=i,
+                ts_init=i,
+            )
+            for i in range(count)
+        ]
+
+    def measure_tick_to_order_latencies_us(self, ticks: list[QuoteTick]) -> list[float]:
+        latencies_us: list[float] = []
+        for tick in ticks:
+            start_ns = time.perf_counter_ns()
+            self.data_engine.process(tick)
+            latencies_us.append((self.exec_client.received_ns[-1] - start_ns) / 1_000)
+
+        return latencies_us
+
+    def test_every_tick_results_in_order_submission(self):
+        # Arrange
+        ticks = self.create_ticks(100)
+
+        # Act
+        self.measure_tick_to_order_latencies_us(ticks)
+
+        # Assert
+        assert len(self.exec_client.commands) == 100
+        assert len(self.exec_client.received_ns) == 100
+
+    def test_tick_to_order_p99_latency_within_budget(self):
+        # Arrange
+        ticks = self.create_ticks(WARMUP_COUNT + TICK_COUNT)
+
+        # Act
+        latencies_us = self.measure_tick_to_order_latencies_us(ticks)[WARMUP_COUNT:]
+
+        # Assert
+        p99_us = statistics.quantiles(latencies_us, n=100)[98]
+        assert len(latencies_us) == TICK_COUNT
+        assert p99_us <= P99_BUDGET_US, (
+            f"p99 tick-to-order latency {p99_us:.1f}us exceeded budget {P99_BUDGET_US:.1f}us "
+            f"(median {statistics.median(latencies_us):.1f}us)"
+        )
+
+    def test_tick_to_order(self, benchmark):
+        ticks = self.create_ticks(1_000)
+
+        def run():
+            for tick in ticks:
+                self.data_engine.process(tick)
+
+        benchmark(run)
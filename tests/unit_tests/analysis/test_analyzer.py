@@ -25,6 +25,7 @@ from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.identifiers import PositionId
 from nautilus_trader.model.identifiers import StrategyId
 from nautilus_trader.model.identifiers import TraderId
+from nautilus_trader.model.objects import Money
 from nautilus_trader.model.objects import Price
 from nautilus_trader.model.objects import Quantity
 from nautilus_trader.model.position import Position
@@ -198,3 +199,37 @@ class TestPortfolioAnalyzer:
         assert len(result) == 2
         assert result["P-1"] == 6.0
         assert result["P-2"] == 16.0
+
+    def test_get_performance_stats_monte_carlo_when_no_data_returns_empty_dict(self):
+        # Arrange, Act
+        result = self.analyzer.get_performance_stats_monte_carlo(USD, random_seed=42)
+
+        # Assert
+        assert result == {}
+
+    def test_get_performance_stats_monte_carlo_returns_confidence_intervals(self):
+        # Arrange
+        pnls = [100.0, -50.0, 75.0, -25.0, 120.0, -80.0, 60.0, 10.0]
+        for i, pnl in enumerate(pnls):
+            self.analyzer.add_trade(PositionId(f"P-{i}"), Money(pnl, USD))
+
+        # Act
+        result1 = self.analyzer.get_performance_stats_monte_carlo(
+            USD,
+            num_samples=500,
+            random_seed=42,
+        )
+        result2 = self.analyzer.get_performance_stats_monte_carlo(
+            USD,
+            num_samples=500,
+            random_seed=42,
+        )
+
+        # Assert
+        assert result1 == result2
+        for name in ("PnL (total)", "Max Drawdown", "Sharpe Ratio"):
+            lower = result1[f"MC {name} (lower)"]
+            median = result1[f"MC {name} (median)"]
+            upper = result1[f"MC {name} (upper)"]
+            assert lower <= median <= upper
+        assert result1["MC PnL (total) (lower)"] < sum(pnls) < result1["MC PnL (total) (upper)"]
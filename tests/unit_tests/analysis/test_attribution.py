@@ -0,0 +1,191 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pyarrow as pa
+import pytest
+
+from nautilus_trader.analysis.attribution import PerformanceAttribution
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.common.factories import OrderFactory
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.identifiers import PositionId
+from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.model.identifiers import TraderId
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.model.position import Position
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.events import TestEventStubs
+
+
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+GBPUSD_SIM = TestInstrumentProvider.default_fx_ccy("GBP/USD")
+
+MONDAY_10_00 = 1_704_103_200_000_000_000  # 2024-01-01T10:00:00Z
+TUESDAY_14_00 = 1_704_204_000_000_000_000  # 2024-01-02T14:00:00Z
+
+
+class TestPerformanceAttribution:
+    def setup(self):
+        # Fixture Setup
+        self.order_factory = OrderFactory(
+            trader_id=TraderId("TESTER-000"),
+            strategy_id=StrategyId("S-001"),
+            clock=TestClock(),
+        )
+        self.position_count = 0
+
+    def _closed_position(self, instrument, close_px: str, ts_closed: int) -> Position:
+        self.position_count += 1
+        position_id = PositionId(f"P-{self.position_count}")
+        open_order = self.order_factory.market(
+            instrument.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        close_order = self.order_factory.market(
+            instrument.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+        )
+        open_fill = TestEventStubs.order_filled(
+            open_order,
+            instrument=instrument,
+            position_id=position_id,
+            last_px=Price.from_str("1.00000"),
+            ts_filled_ns=ts_closed - 1,
+        )
+        close_fill = TestEventStubs.order_filled(
+            close_order,
+            instrument=instrument,
+            position_id=position_id,
+            last_px=Price.from_str(close_px),
+            ts_filled_ns=ts_closed,
+        )
+        position = Position(instrument=instrument, fill=open_fill)
+        position.apply(close_fill)
+        return position
+
+    def test_attribution_with_no_positions(self):
+        # Arrange, Act
+        attribution = PerformanceAttribution([])
+
+        # Assert
+        assert attribution.trade_count == 0
+        assert attribution.by_hour().empty
+        assert attribution.to_dict() == {"hour": {}, "weekday": {}, "instrument": {}}
+
+    def test_by_hour(self):
+        # Arrange
+        positions = [
+            self._closed_position(AUDUSD_SIM, "1.00010", MONDAY_10_00),
+            self._closed_position(AUDUSD_SIM, "0.99990", MONDAY_10_00),
+            self._closed_position(AUDUSD_SIM, "1.00010", TUESDAY_14_00),
+        ]
+
+        # Act
+        result = PerformanceAttribution(positions).by_hour()
+
+        # Assert
+        assert list(result.index) == [10, 14]
+        assert result.loc[10, "trades"] == 2
+        assert result.loc[10, "winners"] == 1
+        assert result.loc[10, "losers"] == 1
+        assert result.loc[10, "realized_pnl"] == pytest.approx(-8.0)
+        assert result.loc[10, "hit_rate"] == 0.5
+        assert result.loc[14, "hit_rate"] == 1.0
+
+    def test_by_weekday(self):
+        # Arrange
+        positions = [
+            self._closed_position(AUDUSD_SIM, "1.00010", TUESDAY_14_00),
+            self._closed_position(AUDUSD_SIM, "1.00010", MONDAY_10_00),
+        ]
+
+        # Act
+        result = PerformanceAttribution(positions).by_weekday()
+
+        # Assert
+        assert list(result.index) == ["Monday", "Tuesday"]
+        assert result.loc["Monday", "realized_pnl"] == pytest.approx(6.0)
+
+    def test_by_instrument(self):
+        # Arrange
+        positions = [
+            self._closed_position(AUDUSD_SIM, "1.00010", MONDAY_10_00),
+            self._closed_position(GBPUSD_SIM, "0.99990", MONDAY_10_00),
+        ]
+
+        # Act
+        result = PerformanceAttribution(positions).by_instrument()
+
+        # Assert
+        assert list(result.index) == ["AUD/USD.SIM", "GBP/USD.SIM"]
+        assert result.loc["GBP/USD.SIM", "hit_rate"] == 0.0
+
+    def test_open_positions_are_excluded(self):
+        # Arrange
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        fill = TestEventStubs.order_filled(
+            order,
+            instrument=AUDUSD_SIM,
+            position_id=PositionId("P-OPEN"),
+        )
+        positions = [
+            Position(instrument=AUDUSD_SIM, fill=fill),
+            self._closed_position(AUDUSD_SIM, "1.00010", MONDAY_10_00),
+        ]
+
+        # Act
+        attribution = PerformanceAttribution(positions)
+
+        # Assert
+        assert attribution.trade_count == 1
+
+    def test_to_arrow(self):
+        # Arrange
+        positions = [
+            self._closed_position(AUDUSD_SIM, "1.00010", MONDAY_10_00),
+            self._closed_position(GBPUSD_SIM, "0.99990", TUESDAY_14_00),
+        ]
+        attribution = PerformanceAttribution(positions)
+
+        # Act
+        table = attribution.to_arrow("instrument")
+
+        # Assert
+        assert isinstance(table, pa.Table)
+        assert table.num_rows == 2
+        assert table.column_names == [
+            "instrument",
+            "trades",
+            "winners",
+            "losers",
+            "realized_pnl",
+            "avg_pnl",
+            "hit_rate",
+        ]
+
+    def test_to_arrow_with_invalid_dimension_raises(self):
+        # Arrange
+        attribution = PerformanceAttribution([])
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            attribution.to_arrow("month")
@@ -197,6 +197,52 @@ class TestBacktestEngine:
         # Assert
         assert len(self.engine.trader.strategy_states()) == 1
 
+    def run_ema_cross(self, engine: BacktestEngine) -> None:
+        config = EMACrossConfig(
+            instrument_id=USDJPY_SIM.id,
+            bar_type=BarType.from_str("USD/JPY.SIM-1-MINUTE-BID-INTERNAL"),
+            trade_size=Decimal(100_000),
+            fast_ema_period=10,
+            slow_ema_period=20,
+        )
+        engine.add_strategy(EMACross(config=config))
+        engine.run()
+
+    def test_get_result_excludes_monte_carlo_stats_by_default(self):
+        # Arrange
+        self.run_ema_cross(self.engine)
+
+        # Act
+        result = self.engine.get_result()
+
+        # Assert
+        assert result.stats_pnls
+        assert result.stats_monte_carlo == {}
+
+    def test_get_result_with_monte_carlo_seed_is_reproducible(self):
+        # Arrange
+        config = BacktestEngineConfig(
+            logging=LoggingConfig(bypass_logging=True),
+            run_monte_carlo=True,
+            monte_carlo_num_samples=100,
+            monte_carlo_seed=42,
+        )
+        engine1 = self.create_engine(config)
+        engine2 = self.create_engine(config)
+        self.run_ema_cross(engine1)
+        self.run_ema_cross(engine2)
+
+        # Act
+        result1 = engine1.get_result()
+        result2 = engine2.get_result()
+
+        # Assert
+        assert result1.stats_monte_carlo
+        assert result1.stats_monte_carlo == result2.stats_monte_carlo
+
+        engine1.dispose()
+        engine2.dispose()
+
     def test_change_fill_model(self):
         # Arrange, Act
         self.engine.change_fill_model(Venue("SIM"), FillModel())
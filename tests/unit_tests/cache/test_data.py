@@ -17,6 +17,8 @@ from decimal import Decimal
 
 import pytest
 
+from nautilus_trader.cache.cache import Cache
+from nautilus_trader.cache.config import CacheConfig
 from nautilus_trader.core.rust.model import AggregationSource
 from nautilus_trader.model.currencies import AUD
 from nautilus_trader.model.currencies import JPY
@@ -25,7 +27,9 @@ from nautilus_trader.model.data import Bar
 from nautilus_trader.model.data import BarType
 from nautilus_trader.model.data import QuoteTick
 from nautilus_trader.model.enums import PriceType
+from nautilus_trader.model.identifiers import ClientId
 from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.instruments import CurrencyPair
 from nautilus_trader.model.objects import Price
 from nautilus_trader.model.objects import Quantity
 from nautilus_trader.test_kit.providers import TestInstrumentProvider
@@ -204,6 +208,60 @@ class TestCache:
         # Assert
         assert result == [instrument1]
 
+    def test_add_instrument_from_same_client_replaces_without_conflict(self):
+        # Arrange
+        instrument = TestInstrumentProvider.ethusdt_binance()
+        self.cache.add_instrument(instrument, ClientId("BINANCE"))
+
+        # Act
+        result = self.cache.add_instrument(instrument, ClientId("BINANCE"))
+
+        # Assert
+        assert result is None
+        assert self.cache.instrument(instrument.id) == instrument
+
+    def test_add_instrument_conflict_prefer_most_recent_keeps_latest_definition(self):
+        # Arrange
+        instrument = TestInstrumentProvider.ethusdt_binance()
+        newer = CurrencyPair.from_dict(CurrencyPair.to_dict(instrument) | {"ts_init": 1})
+        self.cache.add_instrument(newer, ClientId("BINANCE"))
+
+        # Act
+        result = self.cache.add_instrument(instrument, ClientId("DATABENTO"))
+
+        # Assert
+        assert result.policy == "PREFER_MOST_RECENT"
+        assert result.resolution == "KEPT_EXISTING"
+        assert result.existing_client_id == ClientId("BINANCE")
+        assert result.incoming_client_id == ClientId("DATABENTO")
+        assert self.cache.instrument(instrument.id).ts_init == 1
+
+    def test_add_instrument_conflict_prefer_venue_keeps_venue_definition(self):
+        # Arrange
+        cache = Cache(config=CacheConfig(instrument_conflict_policy="PREFER_VENUE"))
+        instrument = TestInstrumentProvider.ethusdt_binance()
+        newer = CurrencyPair.from_dict(CurrencyPair.to_dict(instrument) | {"ts_init": 1})
+        cache.add_instrument(instrument, ClientId("BINANCE"))
+
+        # Act
+        kept = cache.add_instrument(newer, ClientId("DATABENTO"))
+        replaced = cache.add_instrument(instrument, ClientId("BINANCE"))
+
+        # Assert
+        assert kept.resolution == "KEPT_EXISTING"
+        assert replaced.resolution == "REPLACED"
+        assert cache.instrument(instrument.id).ts_init == 0
+
+    def test_add_instrument_conflict_error_policy_raises(self):
+        # Arrange
+        cache = Cache(config=CacheConfig(instrument_conflict_policy="ERROR"))
+        instrument = TestInstrumentProvider.ethusdt_binance()
+        cache.add_instrument(instrument, ClientId("BINANCE"))
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            cache.add_instrument(instrument, ClientId("DATABENTO"))
+
     def test_synthetic_ids_when_one_synthetic_instrument_returns_expected_list(self):
         # Arrange
         synthetic = TestInstrumentProvider.synthetic_instrument()
@@ -307,6 +365,40 @@ class TestCache:
         # Assert
         assert result == [tick2, tick1]
 
+    def test_depth10_when_no_depth_returns_none(self):
+        # Arrange, Act, Assert
+        assert self.cache.depth10(AUDUSD_SIM.id) is None
+        assert self.cache.depth10s(AUDUSD_SIM.id) == []
+        assert self.cache.depth10_count(AUDUSD_SIM.id) == 0
+        assert not self.cache.has_depth10s(AUDUSD_SIM.id)
+
+    def test_add_depth10_returns_latest_first(self):
+        # Arrange
+        depth1 = TestDataStubs.order_book_depth10(instrument_id=AUDUSD_SIM.id, ts_event=1, ts_init=1)
+        depth2 = TestDataStubs.order_book_depth10(instrument_id=AUDUSD_SIM.id, ts_event=2, ts_init=2)
+
+        # Act
+        self.cache.add_depth10(depth1)
+        self.cache.add_depth10(depth2)
+
+        # Assert
+        assert self.cache.depth10(AUDUSD_SIM.id) == depth2
+        assert self.cache.depth10(AUDUSD_SIM.id, index=1) == depth1
+        assert self.cache.depth10(AUDUSD_SIM.id, index=2) is None
+        assert self.cache.depth10s(AUDUSD_SIM.id) == [depth2, depth1]
+        assert self.cache.depth10_count(AUDUSD_SIM.id) == 2
+        assert self.cache.has_depth10s(AUDUSD_SIM.id)
+
+    def test_reset_clears_depth10s(self):
+        # Arrange
+        self.cache.add_depth10(TestDataStubs.order_book_depth10(instrument_id=AUDUSD_SIM.id))
+
+        # Act
+        self.cache.reset()
+
+        # Assert
+        assert self.cache.depth10s(AUDUSD_SIM.id) == []
+
     def test_bars_when_one_bar_returns_expected_list(self):
         # Arrange
         bar = TestDataStubs.bar_5decimal()
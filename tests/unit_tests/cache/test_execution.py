@@ -52,6 +52,7 @@ from nautilus_trader.persistence.wranglers import QuoteTickDataWrangler
 from nautilus_trader.portfolio.portfolio import Portfolio
 from nautilus_trader.risk.engine import RiskEngine
 from nautilus_trader.test_kit.mocks.actors import MockActor
+from nautilus_trader.test_kit.mocks.cache_database import MockCacheDatabase
 from nautilus_trader.test_kit.providers import TestDataProvider
 from nautilus_trader.test_kit.providers import TestInstrumentProvider
 from nautilus_trader.test_kit.stubs.events import TestEventStubs
@@ -455,6 +456,47 @@ class TestCache:
         # Assert
         assert result == order
 
+    def test_load_fills_when_no_database_returns_empty_list(self):
+        # Arrange, Act
+        result = self.cache.load_fills(AUDUSD_SIM.id)
+
+        # Assert
+        assert result == []
+
+    def test_load_fills_returns_fills_within_time_range(self):
+        # Arrange
+        cache = Cache(database=MockCacheDatabase())
+        order = self.strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        cache.add_order(order)
+        order.apply(TestEventStubs.order_submitted(order))
+        order.apply(TestEventStubs.order_accepted(order))
+
+        fills = []
+        for i, ts in enumerate([1_000, 2_000, 3_000]):
+            fill = TestEventStubs.order_filled(
+                order,
+                instrument=AUDUSD_SIM,
+                trade_id=TradeId(f"E-{i}"),
+                last_qty=Quantity.from_int(10_000),
+                ts_filled_ns=ts,
+            )
+            order.apply(fill)
+            fills.append(fill)
+
+        # Act
+        all_fills = cache.load_fills(AUDUSD_SIM.id)
+        ranged_fills = cache.load_fills(AUDUSD_SIM.id, start=1_500, end=3_000)
+        other_fills = cache.load_fills(TestIdStubs.gbpusd_id())
+
+        # Assert
+        assert all_fills == fills
+        assert ranged_fills == fills[1:]
+        assert other_fills == []
+
     def test_add_position(self):
         # Arrange
         order = self.strategy.order_factory.market(
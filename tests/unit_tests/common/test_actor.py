@@ -69,6 +69,11 @@ GBPUSD_SIM = TestInstrumentProvider.default_fx_ccy("GBP/USD")
 USDJPY_SIM = TestInstrumentProvider.default_fx_ccy("USD/JPY")
 
 
+class QuoteSubscribingActor(Actor):
+    def on_start(self) -> None:
+        self.subscribe_quote_ticks(AUDUSD_SIM.id)
+
+
 class TestActor:
     def setup(self) -> None:
         # Fixture Setup
@@ -1765,6 +1770,236 @@ class TestActor:
         assert self.data_engine.subscribed_quote_ticks() == []
         assert self.data_engine.command_count == 2
 
+    def test_unsubscribe_all(self) -> None:
+        # Arrange
+        actor = MockActor()
+        actor.register_base(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        actor.subscribe_quote_ticks(AUDUSD_SIM.id)
+        actor.subscribe_trade_ticks(AUDUSD_SIM.id)
+
+        # Act
+        actor.unsubscribe_all()
+
+        # Assert
+        assert self.data_engine.component_subscriptions(actor.id) == []
+        assert self.data_engine.subscribed_quote_ticks() == []
+        assert self.data_engine.subscribed_trade_ticks() == []
+
+    def test_stop_unsubscribes_all(self) -> None:
+        # Arrange
+        actor = MockActor()
+        actor.register_base(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        actor.start()
+        actor.subscribe_quote_ticks(AUDUSD_SIM.id)
+
+        # Act
+        actor.stop()
+
+        # Assert
+        assert self.data_engine.component_subscriptions(actor.id) == []
+        assert self.data_engine.subscribed_quote_ticks() == []
+
+    def _create_persisting_actor(self) -> MockActor:
+        actor = MockActor(
+            config=ActorConfig(component_id=self.component_id, persist_subscriptions=True),
+        )
+        actor.register_base(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        return actor
+
+    def test_subscribe_without_persist_subscriptions_does_not_persist(self) -> None:
+        # Arrange
+        actor = MockActor(config=ActorConfig(component_id=self.component_id))
+        actor.register_base(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        # Act
+        actor.subscribe_quote_ticks(AUDUSD_SIM.id)
+
+        # Assert
+        assert actor.persisted_subscriptions() == []
+        assert self.cache.get(f"subscriptions:{self.component_id}") is None
+
+    def test_subscribe_with_persist_subscriptions_persists_to_cache(self) -> None:
+        # Arrange
+        actor = self._create_persisting_actor()
+        bar_type = TestDataStubs.bartype_audusd_1min_bid()
+
+        # Act
+        actor.subscribe_quote_ticks(AUDUSD_SIM.id)
+        actor.subscribe_bars(bar_type, await_partial=True)
+
+        # Assert
+        assert actor.persisted_subscriptions() == [
+            {"kind": "quote_ticks", "id": "AUD/USD.SIM", "client_id": None},
+            {"kind": "bars", "id": str(bar_type), "client_id": None, "await_partial": True},
+        ]
+        assert self.cache.get(f"subscriptions:{self.component_id}") is not None
+
+    def test_unsubscribe_with_persist_subscriptions_removes_from_cache(self) -> None:
+        # Arrange
+        actor = self._create_persisting_actor()
+        actor.subscribe_quote_ticks(AUDUSD_SIM.id)
+        actor.subscribe_trade_ticks(AUDUSD_SIM.id)
+
+        # Act
+        actor.unsubscribe_quote_ticks(AUDUSD_SIM.id)
+
+        # Assert
+        assert actor.persisted_subscriptions() == [
+            {"kind": "trade_ticks", "id": "AUD/USD.SIM", "client_id": None},
+        ]
+
+    def test_stop_retains_persisted_subscriptions(self) -> None:
+        # Arrange
+        actor = self._create_persisting_actor()
+        actor.start()
+        actor.subscribe_quote_ticks(AUDUSD_SIM.id)
+
+        # Act
+        actor.stop()
+
+        # Assert
+        assert self.data_engine.subscribed_quote_ticks() == []
+        assert len(actor.persisted_subscriptions()) == 1
+
+    def test_start_resubscribes_persisted_subscriptions(self) -> None:
+        # Arrange
+        actor1 = self._create_persisting_actor()
+        actor1.start()
+        actor1.subscribe_quote_ticks(AUDUSD_SIM.id)
+        actor1.subscribe_trade_ticks(GBPUSD_SIM.id)
+        actor1.stop()
+
+        # Simulate a restarted node with a new actor instance
+        actor2 = self._create_persisting_actor()
+
+        # Act
+        actor2.start()
+
+        # Assert
+        assert self.data_engine.subscribed_quote_ticks() == [AUDUSD_SIM.id]
+        assert self.data_engine.subscribed_trade_ticks() == [GBPUSD_SIM.id]
+        assert len(actor2.persisted_subscriptions()) == 2
+
+    def test_start_does_not_resubscribe_persisted_subscriptions_requested_by_on_start(
+        self,
+    ) -> None:
+        # Arrange
+        actor1 = self._create_persisting_actor()
+        actor1.start()
+        actor1.subscribe_quote_ticks(AUDUSD_SIM.id)
+        actor1.subscribe_trade_ticks(GBPUSD_SIM.id)
+        actor1.stop()
+
+        # Simulate a restarted node with an actor which subscribes on start
+        actor2 = QuoteSubscribingActor(
+            config=ActorConfig(component_id=self.component_id, persist_subscriptions=True),
+        )
+        actor2.register_base(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        command_count = self.data_engine.command_count
+
+        # Act
+        actor2.start()
+
+        # Assert
+        assert self.data_engine.command_count == command_count + 2
+        assert self.data_engine.subscribed_quote_ticks() == [AUDUSD_SIM.id]
+        assert self.data_engine.subscribed_trade_ticks() == [GBPUSD_SIM.id]
+        assert len(actor2.persisted_subscriptions()) == 2
+
+    def test_subscribe_data_and_order_book_at_interval_persists_to_cache(self) -> None:
+        # Arrange
+        actor = self._create_persisting_actor()
+        data_type = DataType(NewsEvent, {"type": "NEWS_WIRE", "topic": "Earthquake"})
+
+        # Act
+        actor.subscribe_data(data_type)
+        actor.subscribe_order_book_at_interval(AUDUSD_SIM.id, depth=5, interval_ms=500)
+
+        # Assert
+        assert actor.persisted_subscriptions() == [
+            {
+                "kind": "data",
+                "id": data_type.topic,
+                "client_id": None,
+                "type": "nautilus_trader.trading.filters:NewsEvent",
+                "metadata": {"type": "NEWS_WIRE", "topic": "Earthquake"},
+            },
+            {
+                "kind": "order_book_at_interval",
+                "id": "AUD/USD.SIM@500",
+                "client_id": None,
+                "instrument_id": "AUD/USD.SIM",
+                "book_type": BookType.L2_MBP.value,
+                "depth": 5,
+                "interval_ms": 500,
+                "managed": True,
+            },
+        ]
+
+    def test_unsubscribe_data_and_order_book_at_interval_removes_from_cache(self) -> None:
+        # Arrange
+        actor = self._create_persisting_actor()
+        data_type = DataType(NewsEvent, {"type": "NEWS_WIRE", "topic": "Earthquake"})
+        actor.subscribe_data(data_type)
+        actor.subscribe_order_book_at_interval(AUDUSD_SIM.id, interval_ms=500)
+
+        # Act
+        actor.unsubscribe_data(data_type)
+        actor.unsubscribe_order_book_at_interval(AUDUSD_SIM.id, interval_ms=500)
+
+        # Assert
+        assert actor.persisted_subscriptions() == []
+
+    def test_start_resubscribes_persisted_data_and_order_book_at_interval(self) -> None:
+        # Arrange
+        data_type = DataType(NewsEvent, {"type": "NEWS_WIRE", "topic": "Earthquake"})
+        actor1 = self._create_persisting_actor()
+        actor1.start()
+        actor1.subscribe_data(data_type)
+        actor1.subscribe_order_book_at_interval(AUDUSD_SIM.id, interval_ms=500)
+        actor1.stop()
+
+        # Simulate a restarted node with a new actor instance
+        actor2 = self._create_persisting_actor()
+
+        # Act
+        actor2.start()
+
+        # Assert
+        book_topic = (
+            f"data.book.snapshots.{AUDUSD_SIM.id.venue}.{AUDUSD_SIM.id.symbol.topic()}.500"
+        )
+        assert self.msgbus.is_subscribed(f"data.{data_type.topic}", actor2.handle_data)
+        assert self.msgbus.is_subscribed(book_topic, actor2.handle_order_book)
+        assert len(actor2.persisted_subscriptions()) == 2
+
     def test_subscribe_trade_ticks(self) -> None:
         # Arrange
         actor = MockActor()
@@ -17,12 +17,16 @@ import pytest
 
 from nautilus_trader.common.enums import ComponentState
 from nautilus_trader.common.messages import ComponentStateChanged
+from nautilus_trader.common.messages import InstrumentConflictResolved
+from nautilus_trader.common.messages import SetInstrumentTrading
 from nautilus_trader.common.messages import ShutdownSystem
 from nautilus_trader.common.messages import TradingStateChanged
 from nautilus_trader.config import ActorConfig
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.model.enums import TradingState
+from nautilus_trader.model.identifiers import ClientId
 from nautilus_trader.model.identifiers import ComponentId
+from nautilus_trader.model.identifiers import InstrumentId
 from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
 
 
@@ -49,6 +53,31 @@ def test_shutdown_system_command():
     )
 
 
+def test_set_instrument_trading_command():
+    # Arrange
+    uuid = UUID4()
+    command = SetInstrumentTrading(
+        trader_id=TestIdStubs.trader_id(),
+        instrument_id=InstrumentId.from_str("AUD/USD.SIM"),
+        enabled=False,
+        command_id=uuid,
+        ts_init=0,
+        cancel_orders=True,
+        reason="Venue incident",
+    )
+
+    # Act, Assert
+    assert SetInstrumentTrading.from_dict(SetInstrumentTrading.to_dict(command)) == command
+    assert (
+        str(command)
+        == f"SetInstrumentTrading(trader_id=TESTER-000, instrument_id=AUD/USD.SIM, enabled=False, cancel_orders=True, reason='Venue incident', command_id={uuid})"  # noqa
+    )
+    assert (
+        repr(command)
+        == f"SetInstrumentTrading(trader_id=TESTER-000, instrument_id=AUD/USD.SIM, enabled=False, cancel_orders=True, reason='Venue incident', command_id={uuid}, ts_init=0)"  # noqa
+    )
+
+
 def test_component_state_changed_event():
     # Arrange
     uuid = UUID4()
@@ -124,3 +153,25 @@ def test_trading_state_changed():
         repr(event)
         == f"TradingStateChanged(trader_id=TESTER-000, state=HALTED, config={{'max_order_submit_rate': '100/00:00:01'}}, event_id={uuid}, ts_init=0)"
     )
+
+
+def test_instrument_conflict_resolved():
+    # Arrange
+    uuid = UUID4()
+    event = InstrumentConflictResolved(
+        instrument_id=InstrumentId.from_str("ETHUSDT.BINANCE"),
+        policy="PREFER_VENUE",
+        resolution="KEPT_EXISTING",
+        existing_client_id=ClientId("BINANCE"),
+        incoming_client_id=None,
+        event_id=uuid,
+        ts_event=0,
+        ts_init=0,
+    )
+
+    # Act, Assert
+    assert InstrumentConflictResolved.from_dict(InstrumentConflictResolved.to_dict(event)) == event
+    assert (
+        str(event)
+        == f"InstrumentConflictResolved(instrument_id=ETHUSDT.BINANCE, policy=PREFER_VENUE, resolution=KEPT_EXISTING, existing_client_id=BINANCE, incoming_client_id=None, event_id={uuid})"  # noqa
+    )
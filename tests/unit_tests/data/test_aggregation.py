@@ -24,10 +24,15 @@ from nautilus_trader import TEST_DATA_DIR
 from nautilus_trader.common.component import TestClock
 from nautilus_trader.core.datetime import dt_to_unix_nanos
 from nautilus_trader.data.aggregation import BarBuilder
+from nautilus_trader.data.aggregation import ImbalanceBarAggregator
+from nautilus_trader.data.aggregation import RangeBarAggregator
+from nautilus_trader.data.aggregation import RenkoBarAggregator
+from nautilus_trader.data.aggregation import RunsBarAggregator
 from nautilus_trader.data.aggregation import TickBarAggregator
 from nautilus_trader.data.aggregation import TimeBarAggregator
 from nautilus_trader.data.aggregation import ValueBarAggregator
 from nautilus_trader.data.aggregation import VolumeBarAggregator
+from nautilus_trader.model.book import OrderBook
 from nautilus_trader.model.data import Bar
 from nautilus_trader.model.data import BarSpecification
 from nautilus_trader.model.data import BarType
@@ -36,6 +41,8 @@ from nautilus_trader.model.data import TradeTick
 from nautilus_trader.model.enums import AggregationSource
 from nautilus_trader.model.enums import AggressorSide
 from nautilus_trader.model.enums import BarAggregation
+from nautilus_trader.model.enums import BookType
+from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.enums import PriceType
 from nautilus_trader.model.identifiers import TradeId
 from nautilus_trader.model.objects import Price
@@ -765,6 +772,72 @@ class TestTickBarAggregator:
         assert last_bar.volume == Quantity.from_str("45")
 
 
+    def test_handle_order_book_with_mid_price_type_sends_bar_to_handler(self):
+        # Arrange
+        handler = []
+        instrument = AUDUSD_SIM
+        bar_spec = BarSpecification(2, BarAggregation.TICK, PriceType.MID)
+        bar_type = BarType(instrument.id, bar_spec)
+        aggregator = TickBarAggregator(
+            instrument,
+            bar_type,
+            handler.append,
+        )
+
+        book = OrderBook(instrument.id, BookType.L2_MBP)
+        book.add(TestDataStubs.order(instrument, OrderSide.BUY, 1.00000, 10), 0)
+        book.add(TestDataStubs.order(instrument, OrderSide.SELL, 1.00002, 20), 0)
+
+        # Act
+        aggregator.handle_order_book(book)
+        book.add(TestDataStubs.order(instrument, OrderSide.BUY, 1.00001, 10), 1)
+        aggregator.handle_order_book(book)
+
+        # Assert
+        assert len(handler) == 1
+        assert handler[0].open == Price.from_str("1.000010")
+        assert handler[0].high == Price.from_str("1.000015")
+        assert handler[0].low == Price.from_str("1.000010")
+        assert handler[0].close == Price.from_str("1.000015")
+        assert handler[0].volume == Quantity.from_str("30.0")
+
+    def test_handle_order_book_when_side_empty_ignores_update(self):
+        # Arrange
+        handler = []
+        instrument = AUDUSD_SIM
+        bar_spec = BarSpecification(1, BarAggregation.TICK, PriceType.ASK)
+        bar_type = BarType(instrument.id, bar_spec)
+        aggregator = TickBarAggregator(
+            instrument,
+            bar_type,
+            handler.append,
+        )
+
+        book = OrderBook(instrument.id, BookType.L2_MBP)
+        book.add(TestDataStubs.order(instrument, OrderSide.BUY, 1.00000, 10), 0)
+
+        # Act
+        aggregator.handle_order_book(book)
+
+        # Assert
+        assert len(handler) == 0
+
+    def test_handle_order_book_with_last_price_type_raises_value_error(self):
+        # Arrange
+        instrument = AUDUSD_SIM
+        bar_spec = BarSpecification(1, BarAggregation.TICK, PriceType.LAST)
+        bar_type = BarType(instrument.id, bar_spec)
+        aggregator = TickBarAggregator(
+            instrument,
+            bar_type,
+            [].append,
+        )
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            aggregator.handle_order_book(OrderBook(instrument.id, BookType.L2_MBP))
+
+
 class TestVolumeBarAggregator:
     def test_handle_quote_tick_when_volume_below_threshold_updates(self):
         # Arrange
@@ -1695,6 +1768,228 @@ class TestTestValueBarAggregator:
         assert last_bar.volume == Quantity.from_str("30")
 
 
+class TestRenkoBarAggregator:
+    def test_handle_trade_tick_when_brick_not_completed_does_not_send(self):
+        # Arrange
+        handler = []
+        instrument = AUDUSD_SIM
+        bar_spec = BarSpecification(10, BarAggregation.RENKO, PriceType.LAST)
+        bar_type = BarType(instrument.id, bar_spec)
+        aggregator = RenkoBarAggregator(
+            instrument,
+            bar_type,
+            handler.append,
+        )
+
+        # Act
+        aggregator.handle_trade_tick(TestDataStubs.trade_tick(price=1.00000, size=1))
+        aggregator.handle_trade_tick(TestDataStubs.trade_tick(price=1.00009, size=1))
+
+        # Assert
+        assert len(handler) == 0
+
+    def test_handle_trade_ticks_builds_bricks_and_reversal(self):
+        # Arrange
+        handler = []
+        instrument = AUDUSD_SIM
+        bar_spec = BarSpecification(10, BarAggregation.RENKO, PriceType.LAST)
+        bar_type = BarType(instrument.id, bar_spec)
+        aggregator = RenkoBarAggregator(
+            instrument,
+            bar_type,
+            handler.append,
+        )
+
+        # Act
+        for price in [1.00000, 1.00005, 1.00012, 1.00035, 1.00015, 1.00005]:
+            aggregator.handle_trade_tick(TestDataStubs.trade_tick(price=price, size=1))
+
+        # Assert
+        assert len(handler) == 4
+        assert handler[0].open == Price.from_str("1.00000")
+        assert handler[0].high == Price.from_str("1.00010")
+        assert handler[0].low == Price.from_str("1.00000")
+        assert handler[0].close == Price.from_str("1.00010")
+        assert handler[0].volume == Quantity.from_int(3)
+        assert handler[1].open == Price.from_str("1.00010")
+        assert handler[1].close == Price.from_str("1.00020")
+        assert handler[1].volume == Quantity.from_int(1)
+        assert handler[2].open == Price.from_str("1.00020")
+        assert handler[2].close == Price.from_str("1.00030")
+        assert handler[2].volume == Quantity.from_int(0)
+        assert handler[3].open == Price.from_str("1.00020")
+        assert handler[3].high == Price.from_str("1.00020")
+        assert handler[3].low == Price.from_str("1.00010")
+        assert handler[3].close == Price.from_str("1.00010")
+        assert handler[3].volume == Quantity.from_int(2)
+
+
+class TestRangeBarAggregator:
+    def test_handle_trade_ticks_when_range_reached_sends_bar_to_handler(self):
+        # Arrange
+        handler = []
+        instrument = AUDUSD_SIM
+        bar_spec = BarSpecification(10, BarAggregation.RANGE, PriceType.LAST)
+        bar_type = BarType(instrument.id, bar_spec)
+        aggregator = RangeBarAggregator(
+            instrument,
+            bar_type,
+            handler.append,
+        )
+
+        # Act
+        for price in [1.00000, 1.00004, 0.99995]:
+            aggregator.handle_trade_tick(TestDataStubs.trade_tick(price=price, size=1))
+
+        no_bar_count = len(handler)
+        aggregator.handle_trade_tick(TestDataStubs.trade_tick(price=1.00005, size=1))
+
+        # Assert
+        assert no_bar_count == 0
+        assert len(handler) == 1
+        assert handler[0].open == Price.from_str("1.00000")
+        assert handler[0].high == Price.from_str("1.00005")
+        assert handler[0].low == Price.from_str("0.99995")
+        assert handler[0].close == Price.from_str("1.00005")
+        assert handler[0].volume == Quantity.from_int(4)
+
+
+class TestImbalanceBarAggregator:
+    def test_handle_trade_ticks_when_imbalance_below_threshold_updates(self):
+        # Arrange
+        handler = []
+        instrument = AUDUSD_SIM
+        bar_spec = BarSpecification(3, BarAggregation.TICK_IMBALANCE, PriceType.LAST)
+        bar_type = BarType(instrument.id, bar_spec)
+        aggregator = ImbalanceBarAggregator(
+            instrument,
+            bar_type,
+            handler.append,
+        )
+
+        # Act
+        for side in [AggressorSide.BUYER, AggressorSide.BUYER, AggressorSide.SELLER]:
+            aggregator.handle_trade_tick(TestDataStubs.trade_tick(aggressor_side=side, size=1))
+
+        # Assert
+        assert len(handler) == 0
+        assert aggregator.get_imbalance() == 1.0
+
+    def test_handle_trade_ticks_when_imbalance_at_threshold_sends_bar_to_handler(self):
+        # Arrange
+        handler = []
+        instrument = AUDUSD_SIM
+        bar_spec = BarSpecification(3, BarAggregation.TICK_IMBALANCE, PriceType.LAST)
+        bar_type = BarType(instrument.id, bar_spec)
+        aggregator = ImbalanceBarAggregator(
+            instrument,
+            bar_type,
+            handler.append,
+        )
+        sides = [
+            AggressorSide.BUYER,
+            AggressorSide.BUYER,
+            AggressorSide.SELLER,
+            AggressorSide.BUYER,
+            AggressorSide.BUYER,
+        ]
+
+        # Act
+        for side in sides:
+            aggregator.handle_trade_tick(TestDataStubs.trade_tick(aggressor_side=side, size=1))
+
+        # Assert
+        assert len(handler) == 1
+        assert handler[0].volume == Quantity.from_int(5)
+        assert aggregator.get_imbalance() == 0.0
+
+    def test_handle_trade_ticks_with_no_aggressor_applies_tick_rule(self):
+        # Arrange
+        handler = []
+        instrument = AUDUSD_SIM
+        bar_spec = BarSpecification(2, BarAggregation.VOLUME_IMBALANCE, PriceType.LAST)
+        bar_type = BarType(instrument.id, bar_spec)
+        aggregator = ImbalanceBarAggregator(
+            instrument,
+            bar_type,
+            handler.append,
+        )
+
+        # Act
+        for price in [1.00000, 1.00001, 1.00001]:
+            aggregator.handle_trade_tick(
+                TestDataStubs.trade_tick(
+                    price=price,
+                    size=1,
+                    aggressor_side=AggressorSide.NO_AGGRESSOR,
+                ),
+            )
+
+        # Assert
+        assert len(handler) == 1
+        assert handler[0].open == Price.from_str("1.00000")
+        assert handler[0].close == Price.from_str("1.00001")
+        assert handler[0].volume == Quantity.from_int(3)
+
+
+class TestRunsBarAggregator:
+    def test_handle_trade_ticks_when_run_at_threshold_sends_bar_to_handler(self):
+        # Arrange
+        handler = []
+        instrument = AUDUSD_SIM
+        bar_spec = BarSpecification(3, BarAggregation.TICK_RUNS, PriceType.LAST)
+        bar_type = BarType(instrument.id, bar_spec)
+        aggregator = RunsBarAggregator(
+            instrument,
+            bar_type,
+            handler.append,
+        )
+        sides = [
+            AggressorSide.BUYER,
+            AggressorSide.SELLER,
+            AggressorSide.BUYER,
+            AggressorSide.SELLER,
+        ]
+
+        # Act
+        for side in sides:
+            aggregator.handle_trade_tick(TestDataStubs.trade_tick(aggressor_side=side, size=1))
+
+        no_bar_count = len(handler)
+        aggregator.handle_trade_tick(
+            TestDataStubs.trade_tick(aggressor_side=AggressorSide.BUYER, size=1),
+        )
+
+        # Assert
+        assert no_bar_count == 0
+        assert len(handler) == 1
+        assert handler[0].volume == Quantity.from_int(5)
+        assert aggregator.get_buy_run() == 0.0
+        assert aggregator.get_sell_run() == 0.0
+
+    def test_handle_trade_ticks_value_runs_accumulates_notional(self):
+        # Arrange
+        handler = []
+        instrument = AUDUSD_SIM
+        bar_spec = BarSpecification(1_000, BarAggregation.VALUE_RUNS, PriceType.LAST)
+        bar_type = BarType(instrument.id, bar_spec)
+        aggregator = RunsBarAggregator(
+            instrument,
+            bar_type,
+            handler.append,
+        )
+
+        # Act
+        aggregator.handle_trade_tick(
+            TestDataStubs.trade_tick(price=2.0, size=300, aggressor_side=AggressorSide.SELLER),
+        )
+
+        # Assert
+        assert len(handler) == 0
+        assert aggregator.get_buy_run() == 0.0
+        assert aggregator.get_sell_run() == 600.0
+
+
 class TestTimeBarAggregator:
     def test_instantiate_given_invalid_bar_spec_raises_value_error(self):
         # Arrange
@@ -176,11 +176,15 @@ class TestMarketDataClient:
         )
 
     def test_handle_instrument_sends_to_data_engine(self):
-        # Arrange, Act
+        # Arrange
+        self.data_engine.register_client(self.client)
+
+        # Act
         self.client._handle_data_py(AUDUSD_SIM)
 
         # Assert
         assert self.data_engine.data_count == 1
+        assert self.cache.instrument(AUDUSD_SIM.id) == AUDUSD_SIM
 
     def test_handle_order_book_snapshot_sends_to_data_engine(self):
         # Arrange
@@ -42,10 +42,13 @@ from nautilus_trader.model.data import QuoteTick
 from nautilus_trader.model.data import TradeTick
 from nautilus_trader.model.enums import AggressorSide
 from nautilus_trader.model.enums import BarAggregation
+from nautilus_trader.model.enums import BookAction
 from nautilus_trader.model.enums import BookType
+from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.enums import PriceType
 from nautilus_trader.model.enums import RecordFlag
 from nautilus_trader.model.identifiers import ClientId
+from nautilus_trader.model.identifiers import ComponentId
 from nautilus_trader.model.identifiers import InstrumentId
 from nautilus_trader.model.identifiers import Symbol
 from nautilus_trader.model.identifiers import TradeId
@@ -528,6 +531,72 @@ class TestDataEngine:
         # Assert
         assert self.data_engine.response_count == 1
 
+    def test_receive_instrument_responses_from_different_clients_publishes_conflict(self):
+        # Arrange
+        handler = []
+        self.msgbus.subscribe(
+            topic="events.instrument_conflict.BINANCE.ETHUSDT",
+            handler=handler.append,
+        )
+
+        for client_id in (ClientId(BINANCE.value), ClientId("DATABENTO")):
+            response = DataResponse(
+                client_id=client_id,
+                venue=BINANCE,
+                data_type=DataType(Instrument),
+                data=ETHUSDT_BINANCE,
+                correlation_id=UUID4(),
+                response_id=UUID4(),
+                ts_init=self.clock.timestamp_ns(),
+            )
+
+            # Act
+            self.data_engine.response(response)
+
+        # Assert
+        assert len(handler) == 1
+        assert handler[0].instrument_id == ETHUSDT_BINANCE.id
+        assert handler[0].resolution == "REPLACED"
+        assert handler[0].existing_client_id == ClientId(BINANCE.value)
+        assert handler[0].incoming_client_id == ClientId("DATABENTO")
+
+    def test_process_instruments_from_different_clients_publishes_conflict(self):
+        # Arrange
+        self.data_engine.register_client(self.binance_client)
+        self.data_engine.register_client(self.quandl)
+
+        handler = []
+        self.msgbus.subscribe(
+            topic="events.instrument_conflict.BINANCE.ETHUSDT",
+            handler=handler.append,
+        )
+
+        # Act
+        self.binance_client._handle_data_py(ETHUSDT_BINANCE)
+        self.quandl._handle_data_py(ETHUSDT_BINANCE)
+
+        # Assert
+        assert len(handler) == 1
+        assert handler[0].existing_client_id == ClientId(BINANCE.value)
+        assert handler[0].incoming_client_id == ClientId("QUANDL")
+
+    def test_internal_update_instruments_from_different_clients_publishes_conflict(self):
+        # Arrange
+        handler = []
+        self.msgbus.subscribe(
+            topic="events.instrument_conflict.BINANCE.ETHUSDT",
+            handler=handler.append,
+        )
+
+        # Act
+        self.data_engine._internal_update_instruments([ETHUSDT_BINANCE], ClientId(BINANCE.value))
+        self.data_engine._internal_update_instruments([ETHUSDT_BINANCE], ClientId("QUANDL"))
+
+        # Assert
+        assert len(handler) == 1
+        assert handler[0].existing_client_id == ClientId(BINANCE.value)
+        assert handler[0].incoming_client_id == ClientId("QUANDL")
+
     def test_process_unrecognized_data_type_logs_and_does_nothing(self):
         # Arrange
         data = Data(0, 0)
@@ -1301,6 +1370,20 @@ class TestDataEngine:
         assert handler1[0] == depth
         assert handler2[0] == depth
 
+    def test_process_order_book_depth_adds_to_cache(self):
+        # Arrange
+        depth = TestDataStubs.order_book_depth10(
+            instrument_id=BTCUSDT_PERP_BINANCE.id,
+            ts_event=1,
+        )
+
+        # Act
+        self.data_engine.process(depth)
+
+        # Assert
+        assert self.cache.depth10(BTCUSDT_PERP_BINANCE.id) == depth
+        assert self.cache.depth10_count(BTCUSDT_PERP_BINANCE.id) == 1
+
     def test_order_book_delta_creates_book(self):
         # Arrange
         self.data_engine.register_client(self.betfair)
@@ -1340,6 +1423,83 @@ class TestDataEngine:
         assert cached_book.instrument_id == ETHUSDT_BINANCE.id
         assert cached_book.best_bid_price() == 100
 
+    def test_subscribe_internal_bars_with_book_type_aggregates_from_book(self):
+        # Arrange
+        self.data_engine.register_client(self.binance_client)
+        self.binance_client.start()
+        self.data_engine.process(ETHUSDT_BINANCE)  # <-- add necessary instrument for test
+
+        bar_type = BarType.from_str(f"{ETHUSDT_BINANCE.id}-3-TICK-BID-INTERNAL")
+
+        handler = []
+        self.msgbus.subscribe(topic=f"data.bars.{bar_type}", handler=handler.append)
+
+        subscribe = Subscribe(
+            client_id=ClientId(BINANCE.value),
+            venue=BINANCE,
+            data_type=DataType(Bar, metadata={"bar_type": bar_type}),
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            params={"book_type": BookType.L2_MBP},
+        )
+
+        self.data_engine.execute(subscribe)
+
+        # Act
+        for price in [100.0, 102.0, 101.0]:
+            delta = TestDataStubs.order_book_delta(
+                instrument_id=ETHUSDT_BINANCE.id,
+                action=BookAction.ADD,
+                order=TestDataStubs.order(
+                    instrument=ETHUSDT_BINANCE,
+                    side=OrderSide.BUY,
+                    price=price,
+                ),
+            )
+            self.data_engine.process(OrderBookDeltas(ETHUSDT_BINANCE.id, [delta]))
+
+        # Assert
+        assert self.binance_client.subscribed_order_book_deltas() == [ETHUSDT_BINANCE.id]
+        assert len(handler) == 1
+        assert handler[0].open == Price.from_str("100.00")
+        assert handler[0].high == Price.from_str("102.00")
+        assert handler[0].low == Price.from_str("100.00")
+        assert handler[0].close == Price.from_str("102.00")
+        assert self.cache.bar(bar_type) == handler[0]
+
+    def test_unsubscribe_internal_bars_with_book_type_unsubscribes_from_deltas(self):
+        # Arrange
+        self.data_engine.register_client(self.binance_client)
+        self.binance_client.start()
+        self.data_engine.process(ETHUSDT_BINANCE)  # <-- add necessary instrument for test
+
+        bar_type = BarType.from_str(f"{ETHUSDT_BINANCE.id}-3-TICK-MID-INTERNAL")
+
+        subscribe = Subscribe(
+            client_id=ClientId(BINANCE.value),
+            venue=BINANCE,
+            data_type=DataType(Bar, metadata={"bar_type": bar_type}),
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            params={"book_type": BookType.L2_MBP},
+        )
+        self.data_engine.execute(subscribe)
+
+        unsubscribe = Unsubscribe(
+            client_id=ClientId(BINANCE.value),
+            venue=BINANCE,
+            data_type=DataType(Bar, metadata={"bar_type": bar_type}),
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.data_engine.execute(unsubscribe)
+
+        # Assert
+        assert self.data_engine.subscribed_bars() == []
+        assert self.binance_client.subscribed_order_book_deltas() == []
+
     def test_execute_subscribe_quote_ticks(self):
         # Arrange
         self.data_engine.register_client(self.binance_client)
@@ -1397,6 +1557,88 @@ class TestDataEngine:
         assert self.data_engine.subscribed_quote_ticks() == []
         assert self.binance_client.subscribed_quote_ticks() == []
 
+    def test_execute_subscribe_with_component_id_tracks_subscription(self):
+        # Arrange
+        self.data_engine.register_client(self.binance_client)
+        self.binance_client.start()
+
+        component_id = ComponentId("MyActor-001")
+        subscribe = Subscribe(
+            client_id=ClientId(BINANCE.value),
+            venue=BINANCE,
+            data_type=DataType(QuoteTick, metadata={"instrument_id": ETHUSDT_BINANCE.id}),
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            component_id=component_id,
+        )
+
+        # Act
+        self.data_engine.execute(subscribe)
+        self.data_engine.execute(subscribe)  # Duplicate subscription is tracked once
+
+        # Assert
+        assert self.data_engine.component_subscriptions(component_id) == [subscribe]
+
+    def test_execute_unsubscribe_with_component_id_removes_tracked_subscription(self):
+        # Arrange
+        self.data_engine.register_client(self.binance_client)
+        self.binance_client.start()
+
+        component_id = ComponentId("MyActor-001")
+        data_type = DataType(QuoteTick, metadata={"instrument_id": ETHUSDT_BINANCE.id})
+        subscribe = Subscribe(
+            client_id=ClientId(BINANCE.value),
+            venue=BINANCE,
+            data_type=data_type,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            component_id=component_id,
+        )
+        self.data_engine.execute(subscribe)
+
+        unsubscribe = Unsubscribe(
+            client_id=ClientId(BINANCE.value),
+            venue=BINANCE,
+            data_type=data_type,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            component_id=component_id,
+        )
+
+        # Act
+        self.data_engine.execute(unsubscribe)
+
+        # Assert
+        assert self.data_engine.component_subscriptions(component_id) == []
+
+    def test_unsubscribe_all_unsubscribes_client_when_no_other_subscribers(self):
+        # Arrange
+        self.data_engine.register_client(self.binance_client)
+        self.binance_client.start()
+
+        component_id = ComponentId("MyActor-001")
+        for instrument_id in (ETHUSDT_BINANCE.id, BTCUSDT_BINANCE.id):
+            subscribe = Subscribe(
+                client_id=ClientId(BINANCE.value),
+                venue=BINANCE,
+                data_type=DataType(QuoteTick, metadata={"instrument_id": instrument_id}),
+                command_id=UUID4(),
+                ts_init=self.clock.timestamp_ns(),
+                component_id=component_id,
+            )
+            self.data_engine.execute(subscribe)
+
+        # Another component still subscribed to ETHUSDT quotes
+        handler = []
+        self.msgbus.subscribe(topic="data.quotes.BINANCE.ETHUSDT", handler=handler.append)
+
+        # Act
+        self.data_engine.unsubscribe_all(component_id)
+
+        # Assert
+        assert self.data_engine.component_subscriptions(component_id) == []
+        assert self.binance_client.subscribed_quote_ticks() == [ETHUSDT_BINANCE.id]
+
     def test_process_quote_tick_when_subscriber_then_sends_to_registered_handler(self):
         # Arrange
         self.data_engine.register_client(self.binance_client)
@@ -3204,3 +3446,48 @@ class TestDataBufferEngine:
         assert len(handler) == 2
         assert len(handler[0].deltas) == 1
         assert len(handler[1].deltas) == 1
+
+    def test_process_order_book_delta_with_combined_flags_publishes_on_last(self):
+        # Arrange
+        self.data_engine.register_client(self.binance_client)
+        self.binance_client.start()
+
+        self.data_engine.process(ETHUSDT_BINANCE)  # <-- add necessary instrument for test
+
+        handler = []
+        self.msgbus.subscribe(topic="data.book.deltas.BINANCE.ETHUSDT", handler=handler.append)
+
+        subscribe = Subscribe(
+            client_id=ClientId(BINANCE.value),
+            venue=BINANCE,
+            data_type=DataType(
+                OrderBookDelta,
+                {
+                    "instrument_id": ETHUSDT_BINANCE.id,
+                    "book_type": BookType.L3_MBO,
+                    "depth": 5,
+                    "managed": True,
+                },
+            ),
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        self.data_engine.execute(subscribe)
+
+        delta = TestDataStubs.order_book_delta(ETHUSDT_BINANCE.id, flags=RecordFlag.F_SNAPSHOT)
+        last_delta = TestDataStubs.order_book_delta(
+            ETHUSDT_BINANCE.id,
+            flags=RecordFlag.F_LAST | RecordFlag.F_SNAPSHOT,
+        )
+
+        self.data_engine.process(delta)
+
+        assert handler == []
+
+        # Act
+        self.data_engine.process(last_delta)
+
+        # Assert
+        assert len(handler) == 1
+        assert handler[0].deltas == [delta, last_delta]
@@ -0,0 +1,187 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.data.resample import resample_bars
+from nautilus_trader.data.resample import resample_quotes
+from nautilus_trader.data.resample import resample_trades
+from nautilus_trader.model.data import Bar
+from nautilus_trader.model.data import BarType
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
+
+
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+
+ONE_SECOND = 1_000_000_000
+ONE_MINUTE = 60 * ONE_SECOND
+
+BAR_TYPE_1MIN = BarType.from_str("AUD/USD.SIM-1-MINUTE-LAST-EXTERNAL")
+BAR_TYPE_5MIN = BarType.from_str("AUD/USD.SIM-5-MINUTE-LAST-INTERNAL")
+
+
+def _minute_bar(minute: int, close: str, volume: int = 100) -> Bar:
+    return Bar(
+        bar_type=BAR_TYPE_1MIN,
+        open=Price.from_str("1.00000"),
+        high=Price.from_str(close),
+        low=Price.from_str("0.99000"),
+        close=Price.from_str(close),
+        volume=Quantity.from_int(volume),
+        ts_event=minute * ONE_MINUTE,
+        ts_init=minute * ONE_MINUTE,
+    )
+
+
+class TestResampleBars:
+    def test_resample_when_no_bars_returns_empty_list(self):
+        # Arrange, Act
+        result = resample_bars([], BAR_TYPE_5MIN)
+
+        # Assert
+        assert result == []
+
+    def test_resample_minute_bars_to_five_minute_bars(self):
+        # Arrange
+        bars = [_minute_bar(m, f"1.000{m:02d}") for m in range(1, 11)]
+
+        # Act
+        result = resample_bars(reversed(bars), BAR_TYPE_5MIN)  # Cache order is newest first
+
+        # Assert
+        assert len(result) == 2
+        assert result[0].bar_type == BAR_TYPE_5MIN
+        assert result[0].open == Price.from_str("1.00000")
+        assert result[0].high == Price.from_str("1.00005")
+        assert result[0].low == Price.from_str("0.99000")
+        assert result[0].close == Price.from_str("1.00005")
+        assert result[0].volume == Quantity.from_int(500)
+        assert result[0].ts_event == 5 * ONE_MINUTE
+        assert result[1].close == Price.from_str("1.00010")
+        assert result[1].ts_event == 10 * ONE_MINUTE
+
+    def test_resample_drops_partial_leading_and_trailing_intervals(self):
+        # Arrange
+        bars = [_minute_bar(m, "1.00001") for m in range(3, 13)]
+
+        # Act
+        dropped = resample_bars(bars, BAR_TYPE_5MIN)
+        kept = resample_bars(bars, BAR_TYPE_5MIN, drop_partial=False)
+
+        # Assert
+        assert [b.ts_event for b in dropped] == [10 * ONE_MINUTE]
+        assert [b.ts_event for b in kept] == [5 * ONE_MINUTE, 10 * ONE_MINUTE, 15 * ONE_MINUTE]
+        assert kept[0].volume == Quantity.from_int(300)
+
+    def test_resample_with_gap_skips_or_fills_interval(self):
+        # Arrange
+        bars = [_minute_bar(m, "1.00001") for m in range(1, 6)]
+        bars += [_minute_bar(m, "1.00002") for m in range(11, 16)]
+
+        # Act
+        skipped = resample_bars(bars, BAR_TYPE_5MIN)
+        filled = resample_bars(bars, BAR_TYPE_5MIN, fill_gaps=True)
+
+        # Assert
+        assert [b.ts_event for b in skipped] == [5 * ONE_MINUTE, 15 * ONE_MINUTE]
+        assert len(filled) == 3
+        assert filled[1].ts_event == 10 * ONE_MINUTE
+        assert filled[1].open == filled[1].close == Price.from_str("1.00001")
+        assert filled[1].volume == Quantity.zero()
+
+    def test_resample_to_non_multiple_interval_raises(self):
+        # Arrange
+        bars = [_minute_bar(1, "1.00001")]
+        bar_type = BarType.from_str("AUD/USD.SIM-90-SECOND-LAST-INTERNAL")
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            resample_bars(bars, bar_type)
+
+    def test_resample_to_different_price_type_raises(self):
+        # Arrange
+        bars = [_minute_bar(1, "1.00001")]
+        bar_type = BarType.from_str("AUD/USD.SIM-5-MINUTE-MID-INTERNAL")
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            resample_bars(bars, bar_type)
+
+
+class TestResampleTicks:
+    def test_resample_trades_to_second_bars(self):
+        # Arrange
+        bar_type = BarType.from_str("AUD/USD.SIM-1-SECOND-LAST-INTERNAL")
+        trades = [
+            TestDataStubs.trade_tick(AUDUSD_SIM, price=1.00001, size=1, ts_event=500_000_000),
+            TestDataStubs.trade_tick(AUDUSD_SIM, price=1.00003, size=2, ts_event=ONE_SECOND),
+            TestDataStubs.trade_tick(AUDUSD_SIM, price=1.00002, size=3, ts_event=1_500_000_000),
+            TestDataStubs.trade_tick(AUDUSD_SIM, price=1.00004, size=4, ts_event=2 * ONE_SECOND),
+        ]
+
+        # Act
+        result = resample_trades(trades, bar_type, start_ns=0)
+
+        # Assert
+        assert len(result) == 2
+        assert result[0].open == Price.from_str("1.00001")
+        assert result[0].high == Price.from_str("1.00003")
+        assert result[0].close == Price.from_str("1.00003")
+        assert result[0].volume == Quantity.from_int(3)
+        assert result[0].ts_event == ONE_SECOND
+        assert result[1].low == Price.from_str("1.00002")
+        assert result[1].volume == Quantity.from_int(7)
+
+    def test_resample_trades_drops_partial_leading_interval_by_default(self):
+        # Arrange
+        bar_type = BarType.from_str("AUD/USD.SIM-1-SECOND-LAST-INTERNAL")
+        trades = [
+            TestDataStubs.trade_tick(AUDUSD_SIM, ts_event=500_000_000),
+            TestDataStubs.trade_tick(AUDUSD_SIM, ts_event=2 * ONE_SECOND),
+        ]
+
+        # Act
+        result = resample_trades(trades, bar_type)
+
+        # Assert
+        assert [b.ts_event for b in result] == [2 * ONE_SECOND]
+
+    def test_resample_trades_to_non_last_price_type_raises(self):
+        # Arrange
+        bar_type = BarType.from_str("AUD/USD.SIM-1-SECOND-BID-INTERNAL")
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            resample_trades([], bar_type)
+
+    def test_resample_quotes_to_mid_bars(self):
+        # Arrange
+        bar_type = BarType.from_str("AUD/USD.SIM-1-SECOND-MID-INTERNAL")
+        quotes = [
+            TestDataStubs.quote_tick(AUDUSD_SIM, 1.00000, 1.00002, ts_event=500_000_000),
+            TestDataStubs.quote_tick(AUDUSD_SIM, 1.00002, 1.00004, ts_event=ONE_SECOND),
+        ]
+
+        # Act
+        result = resample_quotes(quotes, bar_type, start_ns=0)
+
+        # Assert
+        assert len(result) == 1
+        assert result[0].open == Price.from_str("1.000010")
+        assert result[0].close == Price.from_str("1.000030")
+        assert result[0].ts_event == ONE_SECOND
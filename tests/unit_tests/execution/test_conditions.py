@@ -0,0 +1,129 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.execution.conditions import TriggerCondition
+
+
+STATE = {
+    "bid": 100.0,
+    "ask": 100.5,
+    "mid": 100.25,
+    "last": 100.25,
+    "spread": 0.5,
+    "spread_ticks": 5,
+    "bid_size": 30.0,
+    "ask_size": 10.0,
+    "imbalance": 0.5,
+}
+
+
+class TestTriggerCondition:
+    @pytest.mark.parametrize(
+        "expression",
+        [
+            "",
+            "imbalance >",
+            "volume > 10",
+            "imbalance > 'a'",
+            "__import__('os')",
+            "imbalance in [1, 2]",
+            "bid ** 2 > 1",
+        ],
+    )
+    def test_invalid_expression_raises_value_error(self, expression: str) -> None:
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            TriggerCondition(expression)
+
+    @pytest.mark.parametrize(
+        ("expression", "expected"),
+        [
+            ["imbalance > 0.3", True],
+            ["imbalance > 0.5", False],
+            ["spread_ticks < 3", False],
+            ["spread_ticks <= 5 and bid_size > ask_size", True],
+            ["spread_ticks < 3 or imbalance >= 0.5", True],
+            ["not imbalance > 0.3", False],
+            ["0 < imbalance < 1", True],
+            ["ask - bid == spread", True],
+            ["bid_size / ask_size > 2", True],
+            ["-imbalance < 0", True],
+        ],
+    )
+    def test_evaluate(self, expression: str, expected: bool) -> None:
+        # Arrange
+        condition = TriggerCondition(expression)
+
+        # Act
+        result = condition.evaluate(STATE)
+
+        # Assert
+        assert result == expected
+
+    def test_evaluate_with_unavailable_variable_returns_false(self) -> None:
+        # Arrange
+        condition = TriggerCondition("imbalance > 0.3 or spread_ticks < 10")
+        state = {**STATE, "imbalance": None}
+
+        # Act
+        result = condition.evaluate(state)
+
+        # Assert
+        assert not result
+
+    def test_evaluate_with_division_by_zero_returns_false(self) -> None:
+        # Arrange
+        condition = TriggerCondition("bid_size / ask_size > 2")
+        state = {**STATE, "ask_size": 0.0}
+
+        # Act
+        result = condition.evaluate(state)
+
+        # Assert
+        assert not result
+
+    def test_variables(self) -> None:
+        # Arrange
+        condition = TriggerCondition("imbalance > 0.3 and spread_ticks < 2")
+
+        # Act, Assert
+        assert condition.variables == frozenset({"imbalance", "spread_ticks"})
+
+    def test_equality_and_repr(self) -> None:
+        # Arrange
+        condition1 = TriggerCondition("imbalance > 0.3")
+        condition2 = TriggerCondition(" imbalance > 0.3 ")
+
+        # Act, Assert
+        assert condition1 == condition2
+        assert hash(condition1) == hash(condition2)
+        assert repr(condition1) == "TriggerCondition('imbalance > 0.3')"
+
+    def test_from_tags_when_no_condition_tag_returns_none(self) -> None:
+        # Arrange, Act, Assert
+        assert TriggerCondition.from_tags(None) is None
+        assert TriggerCondition.from_tags(["ENTRY"]) is None
+
+    def test_from_tags_returns_condition(self) -> None:
+        # Arrange
+        tags = ["ENTRY", "CONDITION:imbalance > 0.3"]
+
+        # Act
+        condition = TriggerCondition.from_tags(tags)
+
+        # Assert
+        assert condition == TriggerCondition("imbalance > 0.3")
@@ -1232,3 +1232,83 @@ class TestOrderEmulatorWithSingleOrders:
         assert isinstance(order.events[2], OrderInitialized)
         assert isinstance(order.events[3], OrderReleased)
         assert self.exec_client.calls == ["_start", "submit_order"]
+
+    def test_submit_limit_order_with_invalid_condition_then_cancels(self) -> None:
+        # Arrange
+        order = self.strategy.order_factory.limit(
+            instrument_id=ETHUSDT_PERP_BINANCE.id,
+            order_side=OrderSide.BUY,
+            quantity=Quantity.from_int(10),
+            price=ETHUSDT_PERP_BINANCE.make_price(5_000),
+            emulation_trigger=TriggerType.DEFAULT,
+            tags=["CONDITION:volume > 10"],
+        )
+
+        # Act
+        self.strategy.submit_order(order)
+
+        # Assert
+        assert order.is_canceled
+        assert not self.emulator.get_conditions()
+
+    def test_submit_conditional_limit_order_holds_until_condition_met(self) -> None:
+        # Arrange
+        order = self.strategy.order_factory.limit(
+            instrument_id=ETHUSDT_PERP_BINANCE.id,
+            order_side=OrderSide.BUY,
+            quantity=Quantity.from_int(10),
+            price=ETHUSDT_PERP_BINANCE.make_price(5_000),
+            emulation_trigger=TriggerType.DEFAULT,
+            tags=["CONDITION:imbalance > 0.5"],
+        )
+
+        self.strategy.submit_order(order)
+
+        balanced = TestDataStubs.quote_tick(
+            instrument=ETHUSDT_PERP_BINANCE,
+            bid_price=4_999.0,
+            ask_price=5_000.0,
+            bid_size=10.0,
+            ask_size=10.0,
+        )
+        imbalanced = TestDataStubs.quote_tick(
+            instrument=ETHUSDT_PERP_BINANCE,
+            bid_price=4_999.0,
+            ask_price=5_000.0,
+            bid_size=90.0,
+            ask_size=10.0,
+        )
+
+        # Act
+        self.data_engine.process(balanced)
+        held = self.cache.order(order.client_order_id)
+        held_type = held.order_type
+        self.data_engine.process(imbalanced)
+
+        # Assert
+        assert held_type == OrderType.LIMIT
+        assert not self.emulator.get_conditions()
+        order = self.cache.order(order.client_order_id)  # Recover transformed order from cache
+        assert order.order_type == OrderType.MARKET
+        assert isinstance(order.events[-1], OrderReleased)
+        assert self.exec_client.calls == ["_start", "submit_order"]
+
+    def test_cancel_conditional_order_removes_condition(self) -> None:
+        # Arrange
+        order = self.strategy.order_factory.limit(
+            instrument_id=ETHUSDT_PERP_BINANCE.id,
+            order_side=OrderSide.BUY,
+            quantity=Quantity.from_int(10),
+            price=ETHUSDT_PERP_BINANCE.make_price(5_000),
+            emulation_trigger=TriggerType.DEFAULT,
+            tags=["CONDITION:spread_ticks <= 1"],
+        )
+
+        self.strategy.submit_order(order)
+
+        # Act
+        self.strategy.cancel_order(order)
+
+        # Assert
+        assert order.is_canceled
+        assert not self.emulator.get_conditions()
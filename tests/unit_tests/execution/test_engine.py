@@ -18,15 +18,21 @@ import pytest
 from nautilus_trader.cache.cache import Cache
 from nautilus_trader.common.component import MessageBus
 from nautilus_trader.common.component import TestClock
+from nautilus_trader.common.component import TimeEventHandler
+from nautilus_trader.common.events import CommandExpired
 from nautilus_trader.common.factories import OrderFactory
 from nautilus_trader.config import ExecEngineConfig
 from nautilus_trader.config import InvalidConfiguration
 from nautilus_trader.config import StrategyConfig
+from nautilus_trader.core.datetime import secs_to_nanos
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.data.engine import DataEngine
 from nautilus_trader.execution.engine import ExecutionEngine
+from nautilus_trader.execution.messages import BatchCancelOrders
+from nautilus_trader.execution.messages import CancelAllOrders
 from nautilus_trader.execution.messages import CancelOrder
 from nautilus_trader.execution.messages import ModifyOrder
+from nautilus_trader.execution.messages import QueryOrder
 from nautilus_trader.execution.messages import SubmitOrder
 from nautilus_trader.execution.messages import SubmitOrderList
 from nautilus_trader.execution.messages import TradingCommand
@@ -35,11 +41,13 @@ from nautilus_trader.model.data import QuoteTick
 from nautilus_trader.model.data import TradeTick
 from nautilus_trader.model.enums import AccountType
 from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import OmsType
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.enums import OrderStatus
 from nautilus_trader.model.enums import PositionSide
 from nautilus_trader.model.enums import TriggerType
 from nautilus_trader.model.events import OrderCanceled
+from nautilus_trader.model.events import OrderCancelRejected
 from nautilus_trader.model.events import OrderDenied
 from nautilus_trader.model.events import OrderUpdated
 from nautilus_trader.model.identifiers import ClientId
@@ -573,6 +581,316 @@ class TestExecutionEngine:
         assert submit_order in self.exec_client.commands
         assert self.cache.order_exists(order.client_order_id)
 
+    def test_submit_order_within_deadline_sends_to_client(self) -> None:
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            ts_deadline=self.clock.timestamp_ns() + 1_000,
+        )
+
+        # Act
+        self.exec_engine.execute(submit_order)
+
+        # Assert
+        assert submit_order in self.exec_client.commands
+        assert order.status == OrderStatus.INITIALIZED
+
+    def test_submit_order_after_deadline_denies_order(self) -> None:
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            ts_deadline=self.clock.timestamp_ns() + 1_000,
+        )
+
+        self.clock.set_time(self.clock.timestamp_ns() + 2_000)
+
+        # Act
+        self.exec_engine.execute(submit_order)
+
+        # Assert
+        assert submit_order not in self.exec_client.commands
+        assert order.status == OrderStatus.DENIED
+        assert isinstance(order.last_event, OrderDenied)
+        assert order.last_event.reason.startswith("DEADLINE_EXCEEDED")
+        assert self.cache.order_exists(order.client_order_id)
+
+    def test_cancel_order_after_deadline_rejects_cancel(self) -> None:
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00000"),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        self.risk_engine.execute(submit_order)
+        self.exec_engine.process(TestEventStubs.order_submitted(order))
+        self.exec_engine.process(TestEventStubs.order_accepted(order))
+
+        cancel_order = CancelOrder(
+            trader_id=self.trader_id,
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=order.venue_order_id,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            ts_deadline=self.clock.timestamp_ns() + 1_000,
+        )
+
+        self.clock.set_time(self.clock.timestamp_ns() + 2_000)
+
+        # Act
+        self.exec_engine.execute(cancel_order)
+
+        # Assert
+        assert cancel_order not in self.exec_client.commands
+        assert order.status == OrderStatus.ACCEPTED
+        assert isinstance(order.last_event, OrderCancelRejected)
+        assert order.last_event.reason.startswith("DEADLINE_EXCEEDED")
+
+    def test_cancel_all_orders_after_deadline_rejects_cancel_for_open_orders(self) -> None:
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00000"),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        self.risk_engine.execute(submit_order)
+        self.exec_engine.process(TestEventStubs.order_submitted(order))
+        self.exec_engine.process(TestEventStubs.order_accepted(order))
+
+        cancel_all = CancelAllOrders(
+            trader_id=self.trader_id,
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            order_side=OrderSide.NO_ORDER_SIDE,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            ts_deadline=self.clock.timestamp_ns() + 1_000,
+        )
+
+        self.clock.set_time(self.clock.timestamp_ns() + 2_000)
+
+        # Act
+        self.exec_engine.execute(cancel_all)
+
+        # Assert
+        assert cancel_all not in self.exec_client.commands
+        assert order.status == OrderStatus.ACCEPTED
+        assert isinstance(order.last_event, OrderCancelRejected)
+        assert order.last_event.reason.startswith("DEADLINE_EXCEEDED")
+
+    def test_batch_cancel_orders_after_deadline_rejects_each_cancel(self) -> None:
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00000"),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        self.risk_engine.execute(submit_order)
+        self.exec_engine.process(TestEventStubs.order_submitted(order))
+        self.exec_engine.process(TestEventStubs.order_accepted(order))
+
+        cancel_order = CancelOrder(
+            trader_id=self.trader_id,
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=order.venue_order_id,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        batch_cancel = BatchCancelOrders(
+            trader_id=self.trader_id,
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            cancels=[cancel_order],
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            ts_deadline=self.clock.timestamp_ns() + 1_000,
+        )
+
+        self.clock.set_time(self.clock.timestamp_ns() + 2_000)
+
+        # Act
+        self.exec_engine.execute(batch_cancel)
+
+        # Assert
+        assert batch_cancel not in self.exec_client.commands
+        assert order.status == OrderStatus.ACCEPTED
+        assert isinstance(order.last_event, OrderCancelRejected)
+        assert order.last_event.reason.startswith("DEADLINE_EXCEEDED")
+
+    def test_query_order_after_deadline_publishes_command_expired(self) -> None:
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00000"),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        self.risk_engine.execute(submit_order)
+        self.exec_engine.process(TestEventStubs.order_submitted(order))
+        self.exec_engine.process(TestEventStubs.order_accepted(order))
+
+        events: list[CommandExpired] = []
+        self.msgbus.subscribe(topic=f"events.commands.{strategy.id}", handler=events.append)
+
+        query = QueryOrder(
+            trader_id=self.trader_id,
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=order.venue_order_id,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            ts_deadline=self.clock.timestamp_ns() + 1_000,
+        )
+
+        self.clock.set_time(self.clock.timestamp_ns() + 2_000)
+
+        # Act
+        self.exec_engine.execute(query)
+
+        # Assert
+        assert query not in self.exec_client.commands
+        assert len(events) == 1
+        assert isinstance(events[0], CommandExpired)
+        assert events[0].command_id == query.id
+        assert events[0].command_type == "QueryOrder"
+        assert events[0].reason.startswith("DEADLINE_EXCEEDED")
+
     def test_submit_order_with_cleared_cache_logs_error(self) -> None:
         # Arrange
         self.exec_engine.start()
@@ -1290,6 +1608,205 @@ class TestExecutionEngine:
         assert self.cache.positions_open_count() == 1
         assert self.cache.positions_closed_count() == 0
 
+    def test_snapshot_positions_interval_persists_open_position_states(self) -> None:
+        # Arrange
+        clock = TestClock()
+        msgbus = MessageBus(trader_id=self.trader_id, clock=clock)
+        cache_db = MockCacheDatabase()
+        cache = Cache(database=cache_db)
+        exec_engine = ExecutionEngine(
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            config=ExecEngineConfig(snapshot_positions_interval_secs=10.0),
+        )
+        cache.add_instrument(AUDUSD_SIM)
+
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        fill = TestEventStubs.order_filled(order, AUDUSD_SIM, position_id=PositionId("P-1"))
+        cache.add_position(Position(AUDUSD_SIM, fill), OmsType.HEDGING)
+
+        exec_engine.start()
+
+        # Act
+        events: list[TimeEventHandler] = clock.advance_time(secs_to_nanos(20.0))
+        for event in events:
+            event.handle()
+
+        # Assert
+        assert exec_engine.snapshot_positions_timer_name in clock.timer_names
+        assert [(s[0], s[1]) for s in cache_db.position_state_snapshots] == [
+            (PositionId("P-1"), secs_to_nanos(10.0)),
+            (PositionId("P-1"), secs_to_nanos(20.0)),
+        ]
+
+        exec_engine.stop()
+        assert exec_engine.snapshot_positions_timer_name not in clock.timer_names
+
+    def test_snapshot_positions_persists_closed_position_state(self) -> None:
+        # Arrange
+        clock = TestClock()
+        msgbus = MessageBus(trader_id=self.trader_id, clock=clock)
+        cache_db = MockCacheDatabase()
+        cache = Cache(database=cache_db)
+        exec_engine = ExecutionEngine(
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            config=ExecEngineConfig(snapshot_positions=True),
+        )
+        cache.add_instrument(AUDUSD_SIM)
+
+        order1 = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        order2 = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+        )
+        position_id = PositionId("P-1")
+        fill1 = TestEventStubs.order_filled(order1, AUDUSD_SIM, position_id=position_id)
+        fill2 = TestEventStubs.order_filled(order2, AUDUSD_SIM, position_id=position_id)
+        position = Position(AUDUSD_SIM, fill1)
+        cache.add_position(position, OmsType.HEDGING)
+
+        # Act
+        exec_engine._update_position(AUDUSD_SIM, position, fill2, OmsType.HEDGING)
+
+        # Assert
+        assert position.is_closed
+        assert [s[0] for s in cache_db.position_state_snapshots] == [position_id]
+
+    def _setup_fill_aggregation(self, **kwargs) -> tuple[TestClock, ExecutionEngine, list]:
+        clock = TestClock()
+        msgbus = MessageBus(trader_id=self.trader_id, clock=clock)
+        cache = Cache(database=MockCacheDatabase())
+        exec_engine = ExecutionEngine(
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            config=ExecEngineConfig(fill_aggregation_window_ms=100, **kwargs),
+        )
+        cache.add_instrument(AUDUSD_SIM)
+
+        events: list = []
+        msgbus.subscribe(topic=f"events.order.{self.strategy_id}", handler=events.append)
+        return clock, exec_engine, events
+
+    def _submit_and_accept(self, exec_engine: ExecutionEngine, quantity: int = 100_000):
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(quantity),
+        )
+        exec_engine._cache.add_order(order)
+        exec_engine.process(TestEventStubs.order_submitted(order))
+        exec_engine.process(TestEventStubs.order_accepted(order))
+        return order
+
+    def _partial_fill(self, order, trade_id: str, qty: int, px: str):
+        return TestEventStubs.order_filled(
+            order,
+            AUDUSD_SIM,
+            trade_id=TradeId(trade_id),
+            last_qty=Quantity.from_int(qty),
+            last_px=Price.from_str(px),
+        )
+
+    def test_fill_aggregation_coalesces_fills_within_window(self) -> None:
+        # Arrange
+        clock, exec_engine, events = self._setup_fill_aggregation()
+        order = self._submit_and_accept(exec_engine)
+
+        # Act
+        exec_engine.process(self._partial_fill(order, "T-1", 20_000, "1.00000"))
+        exec_engine.process(self._partial_fill(order, "T-2", 20_000, "1.00010"))
+        filled_qty_before_window = order.filled_qty
+
+        for handler in clock.advance_time(100_000_000):
+            handler.handle()
+
+        # Assert
+        fills = [e for e in events if e.__class__.__name__ == "OrderFilled"]
+        assert filled_qty_before_window == Quantity.zero()
+        assert len(fills) == 1
+        assert fills[0].last_qty == Quantity.from_int(40_000)
+        assert fills[0].last_px.as_double() == pytest.approx(1.00005)
+        assert fills[0].trade_id == TradeId("T-2")
+        assert fills[0].info["aggregated_trade_ids"] == ["T-1", "T-2"]
+        assert order.filled_qty == Quantity.from_int(40_000)
+        assert order.avg_px == pytest.approx(1.00005)
+        assert not exec_engine._pending_fills
+
+    def test_fill_aggregation_rounds_average_price_to_instrument_precision(self) -> None:
+        # Arrange
+        clock, exec_engine, events = self._setup_fill_aggregation()
+        order = self._submit_and_accept(exec_engine)
+
+        # Act
+        exec_engine.process(self._partial_fill(order, "T-1", 10_000, "1.00000"))
+        exec_engine.process(self._partial_fill(order, "T-2", 20_000, "1.00010"))
+
+        for handler in clock.advance_time(100_000_000):
+            handler.handle()
+
+        # Assert
+        fills = [e for e in events if e.__class__.__name__ == "OrderFilled"]
+        assert fills[0].last_qty == Quantity.from_int(30_000)
+        assert fills[0].last_qty.precision == AUDUSD_SIM.size_precision
+        assert fills[0].last_px == Price.from_str("1.00007")
+        assert fills[0].last_px.precision == AUDUSD_SIM.price_precision
+
+    def test_fill_aggregation_flushes_when_order_completed(self) -> None:
+        # Arrange
+        clock, exec_engine, events = self._setup_fill_aggregation()
+        order = self._submit_and_accept(exec_engine)
+
+        # Act
+        exec_engine.process(self._partial_fill(order, "T-1", 60_000, "1.00000"))
+        exec_engine.process(self._partial_fill(order, "T-2", 40_000, "1.00000"))
+
+        # Assert
+        assert order.status == OrderStatus.FILLED
+        assert len([e for e in events if e.__class__.__name__ == "OrderFilled"]) == 1
+        assert not exec_engine._pending_fills
+        assert not clock.timer_names
+
+    def test_fill_aggregation_flushes_pending_fills_before_other_events(self) -> None:
+        # Arrange
+        clock, exec_engine, events = self._setup_fill_aggregation()
+        order = self._submit_and_accept(exec_engine)
+        exec_engine.process(self._partial_fill(order, "T-1", 20_000, "1.00000"))
+
+        # Act
+        exec_engine.process(TestEventStubs.order_canceled(order))
+
+        # Assert
+        assert [e.__class__.__name__ for e in events[-2:]] == ["OrderFilled", "OrderCanceled"]
+        assert order.filled_qty == Quantity.from_int(20_000)
+        assert order.status == OrderStatus.CANCELED
+
+    def test_fill_aggregation_excludes_other_venues(self) -> None:
+        # Arrange
+        clock, exec_engine, events = self._setup_fill_aggregation(
+            fill_aggregation_venues=["BINANCE"],
+        )
+        order = self._submit_and_accept(exec_engine)
+
+        # Act
+        exec_engine.process(self._partial_fill(order, "T-1", 20_000, "1.00000"))
+
+        # Assert
+        assert order.filled_qty == Quantity.from_int(20_000)
+        assert not exec_engine._pending_fills
+
     def test_add_to_existing_position_on_order_fill(self) -> None:
         # Arrange
         self.exec_engine.start()
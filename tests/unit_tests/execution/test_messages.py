@@ -83,6 +83,62 @@ class TestCommands:
             == f"SubmitOrder(client_id=None, trader_id=TRADER-001, strategy_id=S-001, instrument_id=AUD/USD.SIM, client_order_id=O-19700101-000000-000-001-1, order=LimitOrder(BUY 100_000 AUD/USD.SIM LIMIT @ 1.00000 GTC, status=INITIALIZED, client_order_id=O-19700101-000000-000-001-1, venue_order_id=None, position_id=None, tags=None), position_id=P-001, command_id={uuid}, ts_init=0)"  # noqa
         )
 
+    def test_submit_order_command_with_deadline_from_dict(self):
+        # Arrange
+        order = self.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00000"),
+        )
+
+        command = SubmitOrder(
+            trader_id=TraderId("TRADER-001"),
+            strategy_id=StrategyId("S-001"),
+            order=order,
+            command_id=UUID4(),
+            ts_init=0,
+            ts_deadline=1_000,
+        )
+
+        # Act
+        result = SubmitOrder.from_dict(SubmitOrder.to_dict(command))
+
+        # Assert
+        assert result == command
+        assert result.ts_deadline == 1_000
+
+    def test_command_is_expired(self):
+        # Arrange
+        order = self.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00000"),
+        )
+
+        no_deadline = SubmitOrder(
+            trader_id=TraderId("TRADER-001"),
+            strategy_id=StrategyId("S-001"),
+            order=order,
+            command_id=UUID4(),
+            ts_init=0,
+        )
+        with_deadline = SubmitOrder(
+            trader_id=TraderId("TRADER-001"),
+            strategy_id=StrategyId("S-001"),
+            order=order,
+            command_id=UUID4(),
+            ts_init=0,
+            ts_deadline=1_000,
+        )
+
+        # Act, Assert
+        assert no_deadline.ts_deadline == 0
+        assert not no_deadline.is_expired(10_000)
+        assert not with_deadline.is_expired(1_000)
+        assert with_deadline.is_expired(1_001)
+
     def test_submit_order_command_with_exec_algorithm_from_dict_and_str_repr(self):
         # Arrange
         uuid = UUID4()
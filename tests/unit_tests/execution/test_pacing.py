@@ -0,0 +1,177 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from datetime import timedelta
+
+import pytest
+
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.execution.pacing import OrderPacer
+
+
+class TestOrderPacer:
+    def setup(self):
+        # Fixture Setup
+        self.clock = TestClock()
+        self.handler = []
+
+    def _create_pacer(self, **kwargs) -> OrderPacer:
+        return OrderPacer(
+            name="Pacer",
+            limit=kwargs.pop("limit", 2),
+            interval=timedelta(seconds=1),
+            clock=self.clock,
+            output_send=self.handler.append,
+            **kwargs,
+        )
+
+    def _advance(self, to_time_ns: int) -> None:
+        for event in self.clock.advance_time(to_time_ns):
+            event.handle()
+
+    def test_instantiation(self):
+        # Arrange, Act
+        pacer = self._create_pacer()
+
+        # Assert
+        assert pacer.name == "Pacer"
+        assert not pacer.is_pacing
+        assert pacer.qsize == 0
+        assert pacer.used() == 0
+        assert pacer.recv_count == 0
+        assert pacer.sent_count == 0
+
+    def test_instantiation_with_invalid_jitter_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            self._create_pacer(
+                jitter_min=timedelta(milliseconds=200),
+                jitter_max=timedelta(milliseconds=100),
+            )
+
+    def test_submit_within_budget_without_jitter_sends_immediately(self):
+        # Arrange
+        pacer = self._create_pacer()
+
+        # Act
+        pacer.submit("ORDER-1")
+        pacer.submit("ORDER-2")
+
+        # Assert
+        assert self.handler == ["ORDER-1", "ORDER-2"]
+        assert not pacer.is_pacing
+        assert pacer.used() == 2
+        assert pacer.sent_count == 2
+
+    def test_submit_beyond_budget_paces_until_interval_rolls(self):
+        # Arrange
+        pacer = self._create_pacer()
+
+        # Act
+        pacer.submit("ORDER-1")
+        pacer.submit("ORDER-2")
+        pacer.submit("ORDER-3")
+
+        # Assert
+        assert self.handler == ["ORDER-1", "ORDER-2"]
+        assert pacer.is_pacing
+        assert pacer.qsize == 1
+        assert self.clock.timer_names == ["Pacer|PACE"]
+        assert self.clock.next_time_ns("Pacer|PACE") == 1_000_000_000
+
+        self._advance(1_000_000_000)
+
+        assert self.handler == ["ORDER-1", "ORDER-2", "ORDER-3"]
+        assert not pacer.is_pacing
+        assert pacer.qsize == 0
+        assert pacer.recv_count == 3
+        assert pacer.sent_count == 3
+
+    def test_submit_with_jitter_delays_each_release(self):
+        # Arrange
+        pacer = self._create_pacer(
+            limit=10,
+            jitter_min=timedelta(milliseconds=100),
+            jitter_max=timedelta(milliseconds=100),
+        )
+
+        # Act
+        pacer.submit("ORDER-1")
+        pacer.submit("ORDER-2")
+
+        # Assert
+        assert self.handler == []
+        assert pacer.is_pacing
+        assert pacer.qsize == 2
+
+        self._advance(100_000_000)
+        assert self.handler == ["ORDER-1"]
+
+        self._advance(200_000_000)
+        assert self.handler == ["ORDER-1", "ORDER-2"]
+        assert not pacer.is_pacing
+
+    def test_jitter_is_within_bounds_and_deterministic_with_seed(self):
+        # Arrange
+        pacer1 = OrderPacer(
+            name="Pacer1",
+            limit=10,
+            interval=timedelta(seconds=1),
+            clock=self.clock,
+            output_send=self.handler.append,
+            jitter_min=timedelta(milliseconds=50),
+            jitter_max=timedelta(milliseconds=500),
+            seed=42,
+        )
+        pacer2 = OrderPacer(
+            name="Pacer2",
+            limit=10,
+            interval=timedelta(seconds=1),
+            clock=self.clock,
+            output_send=self.handler.append,
+            jitter_min=timedelta(milliseconds=50),
+            jitter_max=timedelta(milliseconds=500),
+            seed=42,
+        )
+
+        # Act
+        pacer1.submit("ORDER-1")
+        pacer2.submit("ORDER-2")
+
+        # Assert
+        next_time1 = self.clock.next_time_ns("Pacer1|PACE")
+        next_time2 = self.clock.next_time_ns("Pacer2|PACE")
+        assert next_time1 == next_time2
+        assert 50_000_000 <= next_time1 <= 500_000_000
+
+    def test_cancel_returns_pending_submissions(self):
+        # Arrange
+        pacer = self._create_pacer(limit=1)
+        pacer.submit("ORDER-1")
+        pacer.submit("ORDER-2")
+        pacer.submit("ORDER-3")
+
+        # Act
+        pending = pacer.cancel()
+
+        # Assert
+        assert pending == ["ORDER-2", "ORDER-3"]
+        assert self.handler == ["ORDER-1"]
+        assert not pacer.is_pacing
+        assert pacer.qsize == 0
+        assert self.clock.timer_count == 0
+
+        self._advance(5_000_000_000)
+        assert self.handler == ["ORDER-1"]
@@ -16,6 +16,7 @@
 import asyncio
 from decimal import Decimal
 
+import msgspec
 import pandas as pd
 import pytest
 
@@ -23,9 +24,11 @@ from nautilus_trader.common.component import LiveClock
 from nautilus_trader.common.component import MessageBus
 from nautilus_trader.common.factories import OrderFactory
 from nautilus_trader.common.providers import InstrumentProvider
+from nautilus_trader.core.fsm import InvalidStateTrigger
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.execution.reports import FillReport
 from nautilus_trader.execution.reports import OrderStatusReport
+from nautilus_trader.live.config import LiveExecEngineConfig
 from nautilus_trader.live.data_engine import LiveDataEngine
 from nautilus_trader.live.execution_engine import LiveExecutionEngine
 from nautilus_trader.live.risk_engine import LiveRiskEngine
@@ -206,6 +209,156 @@ class TestLiveExecutionReconciliation:
         assert len(self.cache.orders_open()) == 1
         assert self.cache.orders()[0].status == OrderStatus.ACCEPTED
 
+    @pytest.mark.asyncio()
+    async def test_reconcile_state_unknown_open_order_with_cancel_policy(self):
+        # Arrange
+        self.exec_engine.unknown_open_orders_policy = "CANCEL"
+        report = OrderStatusReport(
+            account_id=self.account_id,
+            instrument_id=AUDUSD_SIM.id,
+            client_order_id=ClientOrderId("O-123456"),
+            venue_order_id=VenueOrderId("1"),
+            order_side=OrderSide.BUY,
+            order_type=OrderType.LIMIT,
+            time_in_force=TimeInForce.GTC,
+            order_status=OrderStatus.ACCEPTED,
+            price=Price.from_str("1.00000"),
+            quantity=Quantity.from_int(10_000),
+            filled_qty=Quantity.from_int(0),
+            post_only=True,
+            report_id=UUID4(),
+            ts_accepted=0,
+            ts_last=0,
+            ts_init=0,
+        )
+
+        self.client.add_order_status_report(report)
+
+        # Act
+        result = await self.exec_engine.reconcile_state()
+
+        # Assert
+        assert result
+        assert len(self.cache.orders()) == 1
+        assert self.client.calls.count("cancel_order") == 1
+        assert self.client.commands[0].venue_order_id == VenueOrderId("1")
+
+    @pytest.mark.asyncio()
+    async def test_reconcile_state_unknown_open_order_with_cancel_policy_is_idempotent(self):
+        # Arrange
+        self.exec_engine.unknown_open_orders_policy = "CANCEL"
+        report = OrderStatusReport(
+            account_id=self.account_id,
+            instrument_id=AUDUSD_SIM.id,
+            client_order_id=None,
+            venue_order_id=VenueOrderId("1"),
+            order_side=OrderSide.BUY,
+            order_type=OrderType.LIMIT,
+            time_in_force=TimeInForce.GTC,
+            order_status=OrderStatus.ACCEPTED,
+            price=Price.from_str("1.00000"),
+            quantity=Quantity.from_int(10_000),
+            filled_qty=Quantity.from_int(0),
+            post_only=True,
+            report_id=UUID4(),
+            ts_accepted=0,
+            ts_last=0,
+            ts_init=0,
+        )
+
+        self.client.add_order_status_report(report)
+
+        # Act
+        await self.exec_engine.reconcile_state()
+
+        # Drop the adopted order so it is unknown again on the next reconciliation
+        self.cache.reset()
+        self.cache.add_instrument(AUDUSD_SIM)
+        await self.exec_engine.reconcile_state()
+
+        # Assert
+        assert len(self.cache.orders()) == 1
+        assert self.client.calls.count("cancel_order") == 1
+        assert len(self.client.commands) == 1
+
+    @pytest.mark.asyncio()
+    async def test_reconcile_state_unknown_open_order_with_leave_policy(self):
+        # Arrange
+        self.exec_engine.unknown_open_orders_policy = "LEAVE"
+        report = OrderStatusReport(
+            account_id=self.account_id,
+            instrument_id=AUDUSD_SIM.id,
+            client_order_id=ClientOrderId("O-123456"),
+            venue_order_id=VenueOrderId("1"),
+            order_side=OrderSide.BUY,
+            order_type=OrderType.LIMIT,
+            time_in_force=TimeInForce.GTC,
+            order_status=OrderStatus.ACCEPTED,
+            price=Price.from_str("1.00000"),
+            quantity=Quantity.from_int(10_000),
+            filled_qty=Quantity.from_int(0),
+            post_only=True,
+            report_id=UUID4(),
+            ts_accepted=0,
+            ts_last=0,
+            ts_init=0,
+        )
+
+        self.client.add_order_status_report(report)
+
+        # Act
+        result = await self.exec_engine.reconcile_state()
+
+        # Assert
+        assert result
+        assert len(self.cache.orders()) == 0
+        assert "cancel_order" not in self.client.calls
+
+    @pytest.mark.asyncio()
+    async def test_reconcile_state_unknown_open_order_with_cancel_policy_skips_invalid_state(self):
+        # Arrange
+        self.exec_engine.unknown_open_orders_policy = "CANCEL"
+        report = OrderStatusReport(
+            account_id=self.account_id,
+            instrument_id=AUDUSD_SIM.id,
+            client_order_id=ClientOrderId("O-123456"),
+            venue_order_id=VenueOrderId("1"),
+            order_side=OrderSide.BUY,
+            order_type=OrderType.LIMIT,
+            time_in_force=TimeInForce.GTC,
+            order_status=OrderStatus.ACCEPTED,
+            price=Price.from_str("1.00000"),
+            quantity=Quantity.from_int(10_000),
+            filled_qty=Quantity.from_int(0),
+            post_only=True,
+            report_id=UUID4(),
+            ts_accepted=0,
+            ts_last=0,
+            ts_init=0,
+        )
+
+        self.client.add_order_status_report(report)
+
+        def reconcile_order_report(report, trades):
+            raise InvalidStateTrigger("ACCEPTED -> ACCEPTED")
+
+        self.exec_engine._reconcile_order_report = reconcile_order_report
+
+        # Act
+        result = await self.exec_engine.reconcile_state()
+
+        # Assert
+        assert not result
+        assert "cancel_order" not in self.client.calls
+
+    def test_config_with_invalid_unknown_open_orders_policy_raises_validation_error(self):
+        # Arrange
+        config = LiveExecEngineConfig(unknown_open_orders_policy="IGNORE")
+
+        # Act, Assert
+        with pytest.raises(msgspec.ValidationError):
+            config.validate()
+
     @pytest.mark.asyncio()
     async def test_reconcile_state_no_cached_with_canceled_order(self):
         # Arrange
@@ -217,6 +217,18 @@ class TestBarSpecification:
                 True,
                 False,
             ],
+            [
+                BarSpecification(10, BarAggregation.RENKO, PriceType.LAST),
+                False,
+                True,
+                False,
+            ],
+            [
+                BarSpecification(10, BarAggregation.RANGE, PriceType.LAST),
+                False,
+                True,
+                False,
+            ],
             [
                 BarSpecification(10000, BarAggregation.VALUE_RUNS, PriceType.MID),
                 False,
@@ -456,6 +456,62 @@ class TestMessageBus:
         assert len(subscriber) == 2
         assert subscriber == ["DUMMY EVENT", "TRADER EVENT"]
 
+    def test_publish_delivers_to_handlers_in_priority_order(self):
+        # Arrange
+        calls = []
+
+        def handler(name):
+            return lambda msg: calls.append(name)
+
+        self.msgbus.subscribe(topic="events.order.*", handler=handler("strategy"))
+        self.msgbus.subscribe(topic="events.order.*", handler=handler("risk"), priority=10)
+        self.msgbus.subscribe(topic="events.*", handler=handler("portfolio"), priority=5)
+
+        # Act
+        self.msgbus.publish("events.order.SCALPER-001", "ORDER")
+
+        # Assert
+        assert calls == ["risk", "portfolio", "strategy"]
+
+    def test_publish_with_equal_priorities_delivers_in_subscription_order(self):
+        # Arrange
+        calls = []
+
+        def handler(name):
+            return lambda msg: calls.append(name)
+
+        names = [f"handler-{i}" for i in range(5)]
+        for i, name in enumerate(names):
+            topic = "events.*" if i % 2 == 0 else "events.order.SCALPER-001"
+            self.msgbus.subscribe(topic=topic, handler=handler(name), priority=1)
+
+        # Act
+        self.msgbus.publish("events.order.SCALPER-001", "ORDER1")
+        self.msgbus.publish("events.order.SCALPER-001", "ORDER2")
+
+        # Assert
+        assert calls == names + names
+
+    def test_subscribe_after_publish_retains_priority_and_subscription_order(self):
+        # Arrange
+        calls = []
+
+        def handler(name):
+            return lambda msg: calls.append(name)
+
+        self.msgbus.subscribe(topic="events.order.*", handler=handler("first"))
+        self.msgbus.publish("events.order.SCALPER-001", "ORDER1")  # Caches matching subscriptions
+        calls.clear()
+
+        self.msgbus.subscribe(topic="events.*", handler=handler("second"))
+        self.msgbus.subscribe(topic="events.*", handler=handler("risk"), priority=10)
+
+        # Act
+        self.msgbus.publish("events.order.SCALPER-001", "ORDER2")
+
+        # Assert
+        assert calls == ["risk", "first", "second"]
+
 
 @pytest.mark.parametrize(
     ("topic", "pattern", "expected"),
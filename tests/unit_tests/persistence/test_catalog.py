@@ -302,6 +302,68 @@ def test_catalog_append_data(catalog: ParquetDataCatalog) -> None:
     assert len(bars) == len(all_bars) == 20
 
 
+def test_catalog_consolidate_merges_and_deduplicates_small_files(
+    catalog: ParquetDataCatalog,
+) -> None:
+    # Arrange
+    instrument = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+    trades = [
+        TestDataStubs.trade_tick(instrument, trade_id=str(i), ts_event=i, ts_init=i)
+        for i in range(10)
+    ]
+    catalog.write_data(trades[:6], basename_template="part-0")
+    catalog.write_data(trades[4:], basename_template="part-1")  # Overlaps by two trades
+
+    # Act
+    written = catalog.consolidate()
+
+    # Assert
+    result = catalog.trade_ticks(instrument_ids=[instrument.id.value])
+    assert len(written) == 1
+    assert len(catalog.fs.glob(f"{written[0].rsplit('/', 1)[0]}/*.parquet")) == 1
+    assert [t.ts_init for t in result] == list(range(10))
+
+
+def test_catalog_consolidate_retains_distinct_rows_with_same_sequence(
+    catalog: ParquetDataCatalog,
+) -> None:
+    # Arrange
+    deltas = [
+        TestDataStubs.order_book_delta(
+            order=TestDataStubs.order(price=100.0 + i),
+            sequence=0,
+            ts_event=1,
+            ts_init=1,
+        )
+        for i in range(3)
+    ]
+    catalog.write_data(deltas[:2], basename_template="part-0")
+    catalog.write_data(deltas[1:], basename_template="part-1")  # Overlaps by one delta
+
+    # Act
+    written = catalog.consolidate()
+
+    # Assert
+    result = catalog.order_book_deltas()
+    assert len(written) == 1
+    assert sorted(d.order.price.as_double() for d in result) == [100.0, 101.0, 102.0]
+
+
+def test_catalog_consolidate_skips_directories_with_single_file(
+    catalog: ParquetDataCatalog,
+) -> None:
+    # Arrange
+    instrument = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+    catalog.write_data([TestDataStubs.trade_tick(instrument)])
+
+    # Act
+    written = catalog.consolidate(data_cls=TradeTick)
+
+    # Assert
+    assert written == []
+    assert len(catalog.trade_ticks()) == 1
+
+
 def test_catalog_bars_querying_by_instrument_id(catalog: ParquetDataCatalog) -> None:
     # Arrange
     bar_type = TestDataStubs.bartype_adabtc_binance_1min_last()
@@ -17,8 +17,21 @@ from io import BytesIO
 
 import pyarrow as pa
 
+from nautilus_trader.common.component import TestClock
 from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.model.data import OrderBookDelta
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.persistence.funcs import urisafe_instrument_id
+from nautilus_trader.persistence.writer import RecordingCommand
+from nautilus_trader.persistence.writer import StreamingFeatherWriter
+from nautilus_trader.test_kit.mocks.data import setup_catalog
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.component import TestComponentStubs
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
+
+
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+GBPUSD_SIM = TestInstrumentProvider.default_fx_ccy("GBP/USD")
 
 
 def test_legacy_deltas_to_record_batch_reader() -> None:
@@ -51,3 +64,194 @@ def test_legacy_deltas_to_record_batch_reader() -> None:
     assert len(ticks) == 1
     assert len(reader.read_all()) == len(ticks)
     reader.close()
+
+
+def test_streaming_writer_recording_toggles_per_instrument() -> None:
+    # Arrange
+    clock = TestClock()
+    cache = TestComponentStubs.cache()
+    cache.add_instrument(AUDUSD_SIM)
+    cache.add_instrument(GBPUSD_SIM)
+    catalog = setup_catalog(protocol="memory", path="/catalog")
+    writer = StreamingFeatherWriter(
+        path=catalog.path,
+        cache=cache,
+        clock=clock,
+        fs_protocol=catalog.fs_protocol,
+        replace=True,
+        include_instrument_ids={QuoteTick: [AUDUSD_SIM.id]},
+    )
+
+    # Act
+    writer.write(TestDataStubs.quote_tick(AUDUSD_SIM))
+    writer.write(TestDataStubs.quote_tick(GBPUSD_SIM))
+
+    # Assert
+    assert writer.recording_instrument_ids(QuoteTick) == {AUDUSD_SIM.id}
+    assert _written_instrument_files(catalog, AUDUSD_SIM)
+    assert not _written_instrument_files(catalog, GBPUSD_SIM)
+
+
+def test_streaming_writer_execute_recording_commands() -> None:
+    # Arrange
+    clock = TestClock()
+    cache = TestComponentStubs.cache()
+    cache.add_instrument(AUDUSD_SIM)
+    cache.add_instrument(GBPUSD_SIM)
+    catalog = setup_catalog(protocol="memory", path="/catalog")
+    writer = StreamingFeatherWriter(
+        path=catalog.path,
+        cache=cache,
+        clock=clock,
+        fs_protocol=catalog.fs_protocol,
+        replace=True,
+    )
+    writer.write(TestDataStubs.quote_tick(AUDUSD_SIM))
+
+    # Act
+    writer.execute(RecordingCommand(QuoteTick, [GBPUSD_SIM.id], start=True))
+    writer.execute(RecordingCommand(QuoteTick, [AUDUSD_SIM.id], start=False))
+    writer.write(TestDataStubs.quote_tick(AUDUSD_SIM))
+    writer.write(TestDataStubs.quote_tick(GBPUSD_SIM))
+
+    # Assert
+    assert writer.recording_instrument_ids(QuoteTick) == {GBPUSD_SIM.id}
+    assert _written_instrument_files(catalog, GBPUSD_SIM)
+
+
+def test_streaming_writer_stop_recording_when_unfiltered_excludes_only_given_instruments() -> None:
+    # Arrange
+    clock = TestClock()
+    cache = TestComponentStubs.cache()
+    cache.add_instrument(AUDUSD_SIM)
+    cache.add_instrument(GBPUSD_SIM)
+    catalog = setup_catalog(protocol="memory", path="/catalog")
+    writer = StreamingFeatherWriter(
+        path=catalog.path,
+        cache=cache,
+        clock=clock,
+        fs_protocol=catalog.fs_protocol,
+        replace=True,
+    )
+
+    # Act
+    writer.stop_recording(QuoteTick, [AUDUSD_SIM.id])
+    writer.write(TestDataStubs.quote_tick(AUDUSD_SIM))
+    writer.write(TestDataStubs.quote_tick(GBPUSD_SIM))
+
+    # Assert
+    assert writer.recording_instrument_ids(QuoteTick) == {GBPUSD_SIM.id}
+    assert not _written_instrument_files(catalog, AUDUSD_SIM)
+    assert _written_instrument_files(catalog, GBPUSD_SIM)
+
+
+def test_streaming_writer_start_recording_resumes_stopped_instrument() -> None:
+    # Arrange
+    clock = TestClock()
+    cache = TestComponentStubs.cache()
+    cache.add_instrument(AUDUSD_SIM)
+    cache.add_instrument(GBPUSD_SIM)
+    catalog = setup_catalog(protocol="memory", path="/catalog")
+    writer = StreamingFeatherWriter(
+        path=catalog.path,
+        cache=cache,
+        clock=clock,
+        fs_protocol=catalog.fs_protocol,
+        replace=True,
+    )
+    writer.stop_recording(QuoteTick, [AUDUSD_SIM.id])
+
+    # Act
+    writer.start_recording(QuoteTick, [AUDUSD_SIM.id])
+    writer.write(TestDataStubs.quote_tick(AUDUSD_SIM))
+    writer.write(TestDataStubs.quote_tick(GBPUSD_SIM))
+
+    # Assert
+    assert writer.recording_instrument_ids(QuoteTick) is None
+    assert _written_instrument_files(catalog, AUDUSD_SIM)
+    assert _written_instrument_files(catalog, GBPUSD_SIM)
+
+
+def test_streaming_writer_start_recording_after_resume_keeps_recording_all_instruments() -> None:
+    # Arrange
+    clock = TestClock()
+    cache = TestComponentStubs.cache()
+    cache.add_instrument(AUDUSD_SIM)
+    cache.add_instrument(GBPUSD_SIM)
+    catalog = setup_catalog(protocol="memory", path="/catalog")
+    writer = StreamingFeatherWriter(
+        path=catalog.path,
+        cache=cache,
+        clock=clock,
+        fs_protocol=catalog.fs_protocol,
+        replace=True,
+    )
+    writer.stop_recording(QuoteTick, [AUDUSD_SIM.id])
+    writer.start_recording(QuoteTick, [AUDUSD_SIM.id])
+
+    # Act
+    writer.start_recording(QuoteTick, [GBPUSD_SIM.id])
+    writer.write(TestDataStubs.quote_tick(AUDUSD_SIM))
+    writer.write(TestDataStubs.quote_tick(GBPUSD_SIM))
+
+    # Assert
+    assert writer.recording_instrument_ids(QuoteTick) is None
+    assert _written_instrument_files(catalog, AUDUSD_SIM)
+    assert _written_instrument_files(catalog, GBPUSD_SIM)
+
+
+def test_streaming_writer_resume_recording_at_same_timestamp_keeps_previous_file() -> None:
+    # Arrange
+    clock = TestClock()
+    cache = TestComponentStubs.cache()
+    cache.add_instrument(AUDUSD_SIM)
+    catalog = setup_catalog(protocol="memory", path="/catalog")
+    writer = StreamingFeatherWriter(
+        path=catalog.path,
+        cache=cache,
+        clock=clock,
+        fs_protocol=catalog.fs_protocol,
+        replace=True,
+    )
+    writer.write(TestDataStubs.quote_tick(AUDUSD_SIM))
+    writer.stop_recording(QuoteTick, [AUDUSD_SIM.id])
+
+    # Act
+    writer.start_recording(QuoteTick, [AUDUSD_SIM.id])
+    writer.write(TestDataStubs.quote_tick(AUDUSD_SIM))
+    writer.close()
+
+    # Assert
+    files = sorted(_written_instrument_files(catalog, AUDUSD_SIM))
+    assert len(files) == 2
+    for path in files:
+        with catalog.fs.open(path) as f:
+            assert pa.ipc.open_stream(f).read_all().num_rows == 1
+
+
+def test_streaming_writer_stop_recording_all_instruments() -> None:
+    # Arrange
+    clock = TestClock()
+    cache = TestComponentStubs.cache()
+    cache.add_instrument(AUDUSD_SIM)
+    catalog = setup_catalog(protocol="memory", path="/catalog")
+    writer = StreamingFeatherWriter(
+        path=catalog.path,
+        cache=cache,
+        clock=clock,
+        fs_protocol=catalog.fs_protocol,
+        replace=True,
+    )
+
+    # Act
+    writer.stop_recording(QuoteTick)
+    writer.write(TestDataStubs.quote_tick(AUDUSD_SIM))
+
+    # Assert
+    assert writer.recording_instrument_ids(QuoteTick) == set()
+    assert not _written_instrument_files(catalog, AUDUSD_SIM)
+
+
+def _written_instrument_files(catalog, instrument) -> list[str]:
+    file_prefix = urisafe_instrument_id(instrument.id.value)
+    return catalog.fs.glob(f"{catalog.path}/quote_tick/{file_prefix}_*.feather")
@@ -0,0 +1,256 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.accounting.factory import AccountFactory
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.common.factories import OrderFactory
+from nautilus_trader.core.datetime import secs_to_nanos
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.model.currencies import AUD
+from nautilus_trader.model.currencies import EUR
+from nautilus_trader.model.currencies import JPY
+from nautilus_trader.model.currencies import USD
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import OmsType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.events import AccountState
+from nautilus_trader.model.identifiers import PositionId
+from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.model.objects import AccountBalance
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.model.position import Position
+from nautilus_trader.portfolio.hedging import HedgeSuggestion
+from nautilus_trader.portfolio.hedging import HedgingAdvisor
+from nautilus_trader.portfolio.hedging import HedgingAdvisorConfig
+from nautilus_trader.portfolio.portfolio import Portfolio
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.component import TestComponentStubs
+from nautilus_trader.test_kit.stubs.events import TestEventStubs
+from nautilus_trader.test_kit.stubs.execution import TestExecStubs
+from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
+
+
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+EURUSD_SIM = TestInstrumentProvider.default_fx_ccy("EUR/USD")
+USDJPY_SIM = TestInstrumentProvider.default_fx_ccy("USD/JPY")
+
+
+class TestHedgingAdvisor:
+    def setup(self):
+        # Fixture Setup
+        self.clock = TestClock()
+        self.trader_id = TestIdStubs.trader_id()
+        self.account_id = TestIdStubs.account_id()
+
+        self.order_factory = OrderFactory(
+            trader_id=self.trader_id,
+            strategy_id=StrategyId("S-001"),
+            clock=TestClock(),
+        )
+
+        self.msgbus = MessageBus(
+            trader_id=self.trader_id,
+            clock=self.clock,
+        )
+
+        self.cache = TestComponentStubs.cache()
+        self.cache.add_instrument(AUDUSD_SIM)
+        self.cache.add_instrument(EURUSD_SIM)
+        self.cache.add_instrument(USDJPY_SIM)
+
+        self.portfolio = Portfolio(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+    def _create_advisor(self, **kwargs) -> HedgingAdvisor:
+        config = HedgingAdvisorConfig(
+            hedge_instruments={
+                "AUD": str(AUDUSD_SIM.id),
+                "EUR": str(EURUSD_SIM.id),
+                "JPY": str(USDJPY_SIM.id),
+            },
+            **kwargs,
+        )
+        advisor = HedgingAdvisor(config)
+        advisor.register_base(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        return advisor
+
+    def _add_cash_account(self, *balances: Money) -> None:
+        state = AccountState(
+            account_id=self.account_id,
+            account_type=AccountType.CASH,
+            base_currency=None,  # Multi-currency account
+            reported=True,
+            balances=[AccountBalance(b, Money(0, b.currency), b) for b in balances],
+            margins=[],
+            info={},
+            event_id=UUID4(),
+            ts_event=0,
+        )
+        self.cache.add_account(AccountFactory.create(state))
+
+    def _add_margin_position(self, side: OrderSide, quantity: int, px: str) -> None:
+        self.cache.add_account(TestExecStubs.margin_account(self.account_id))
+
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            side,
+            Quantity.from_int(quantity),
+        )
+        fill = TestEventStubs.order_filled(
+            order=order,
+            instrument=AUDUSD_SIM,
+            account_id=self.account_id,
+            position_id=PositionId("P-1"),
+            last_px=Price.from_str(px),
+        )
+        self.cache.add_position(Position(instrument=AUDUSD_SIM, fill=fill), OmsType.HEDGING)
+
+    def test_exposures_with_no_accounts_returns_empty_dict(self):
+        # Arrange
+        advisor = self._create_advisor()
+
+        # Act, Assert
+        assert advisor.exposures() == {}
+        assert advisor.suggest_hedges() == []
+
+    def test_exposures_from_cash_account_balances(self):
+        # Arrange
+        advisor = self._create_advisor()
+        self._add_cash_account(Money(1_000_000, USD), Money(50_000, EUR))
+
+        # Act
+        exposures = advisor.exposures()
+
+        # Assert
+        assert exposures == {USD: 1_000_000.0, EUR: 50_000.0}
+
+    def test_suggest_hedges_for_long_base_currency_exposure_sells_base(self):
+        # Arrange
+        advisor = self._create_advisor()
+        self._add_cash_account(Money(1_000_000, USD), Money(50_000, EUR))
+
+        # Act
+        suggestions = advisor.suggest_hedges(ts_event=1)
+
+        # Assert
+        assert suggestions == [
+            HedgeSuggestion(
+                instrument_id=EURUSD_SIM.id,
+                side=OrderSide.SELL,
+                quantity=Quantity.from_int(50_000),
+                currency=EUR,
+                exposure=50_000.0,
+                ts_event=1,
+            ),
+        ]
+
+    def test_suggest_hedges_for_margin_position_exposure(self):
+        # Arrange
+        advisor = self._create_advisor()
+        self._add_margin_position(OrderSide.BUY, 100_000, "0.80000")
+
+        # Act
+        exposures = advisor.exposures()
+        suggestions = advisor.suggest_hedges()
+
+        # Assert
+        assert exposures[AUD] == 100_000.0
+        assert exposures[USD] == pytest.approx(1_000_000.0 - 80_000.0)
+        assert len(suggestions) == 1
+        assert suggestions[0].instrument_id == AUDUSD_SIM.id
+        assert suggestions[0].side == OrderSide.SELL
+        assert suggestions[0].quantity == Quantity.from_int(100_000)
+
+    def test_suggest_hedges_for_quote_currency_exposure_uses_mid_price(self):
+        # Arrange
+        advisor = self._create_advisor()
+        self._add_cash_account(Money(1_000_000, USD), Money(-11_000_000, JPY))
+        self.cache.add_quote_tick(
+            QuoteTick(
+                instrument_id=USDJPY_SIM.id,
+                bid_price=Price.from_str("109.990"),
+                ask_price=Price.from_str("110.010"),
+                bid_size=Quantity.from_int(1_000_000),
+                ask_size=Quantity.from_int(1_000_000),
+                ts_event=0,
+                ts_init=0,
+            ),
+        )
+
+        # Act
+        suggestions = advisor.suggest_hedges()
+
+        # Assert
+        assert len(suggestions) == 1
+        assert suggestions[0].instrument_id == USDJPY_SIM.id
+        assert suggestions[0].side == OrderSide.SELL
+        assert suggestions[0].quantity == Quantity.from_int(100_000)
+
+    def test_suggest_hedges_for_quote_currency_exposure_without_price_returns_none(self):
+        # Arrange
+        advisor = self._create_advisor()
+        self._add_cash_account(Money(1_000_000, USD), Money(11_000_000, JPY))
+
+        # Act
+        suggestions = advisor.suggest_hedges()
+
+        # Assert
+        assert suggestions == []
+
+    def test_suggest_hedges_below_min_exposure_returns_none(self):
+        # Arrange
+        advisor = self._create_advisor(min_exposure=100_000.0)
+        self._add_cash_account(Money(1_000_000, USD), Money(50_000, EUR))
+
+        # Act
+        suggestions = advisor.suggest_hedges()
+
+        # Assert
+        assert suggestions == []
+
+    def test_timer_publishes_suggestions_to_topic(self):
+        # Arrange
+        advisor = self._create_advisor(interval_secs=60)
+        self._add_cash_account(Money(1_000_000, USD), Money(50_000, EUR))
+
+        received: list[HedgeSuggestion] = []
+        self.msgbus.subscribe(topic="hedging.suggestions", handler=received.append)
+
+        advisor.start()
+
+        # Act
+        events = self.clock.advance_time(to_time_ns=secs_to_nanos(60))
+        for event in events:
+            event.handle()
+
+        # Assert
+        assert len(received) == 1
+        assert received[0].instrument_id == EURUSD_SIM.id
+        assert received[0].side == OrderSide.SELL
+        assert received[0].ts_event == secs_to_nanos(60)
@@ -0,0 +1,308 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+
+from datetime import date
+
+import pytest
+
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.common.factories import OrderFactory
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.execution.engine import ExecutionEngine
+from nautilus_trader.execution.messages import SubmitOrder
+from nautilus_trader.model.currencies import USD
+from nautilus_trader.model.enums import OmsType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.identifiers import PositionId
+from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.model.position import Position
+from nautilus_trader.portfolio.portfolio import Portfolio
+from nautilus_trader.portfolio.rollover import EndOfDayReport
+from nautilus_trader.portfolio.rollover import SessionRollover
+from nautilus_trader.portfolio.rollover import SessionRolloverConfig
+from nautilus_trader.risk.config import RiskEngineConfig
+from nautilus_trader.risk.engine import RiskEngine
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.component import TestComponentStubs
+from nautilus_trader.test_kit.stubs.events import TestEventStubs
+from nautilus_trader.test_kit.stubs.execution import TestExecStubs
+from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
+
+
+SIM = Venue("SIM")
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+
+HOUR_NS = 3_600_000_000_000
+DAY_NS = 24 * HOUR_NS
+
+
+class TestSessionRollover:
+    def setup(self):
+        # Fixture Setup
+        self.clock = TestClock()
+        self.trader_id = TestIdStubs.trader_id()
+        self.account_id = TestIdStubs.account_id()
+
+        self.order_factory = OrderFactory(
+            trader_id=self.trader_id,
+            strategy_id=StrategyId("S-001"),
+            clock=TestClock(),
+        )
+
+        self.msgbus = MessageBus(
+            trader_id=self.trader_id,
+            clock=self.clock,
+        )
+
+        self.cache = TestComponentStubs.cache()
+        self.cache.add_instrument(AUDUSD_SIM)
+
+        self.portfolio = Portfolio(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.reports: list[EndOfDayReport] = []
+        self.msgbus.subscribe(topic="portfolio.eod.*", handler=self.reports.append)
+
+    def _create_rollover(self, **kwargs) -> SessionRollover:
+        config = SessionRolloverConfig(
+            venue_close_times={"SIM": "21:00"},
+            **kwargs,
+        )
+        rollover = SessionRollover(config)
+        rollover.register_base(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        return rollover
+
+    def _open_position(self, quantity: int, px: str) -> Position:
+        self.cache.add_account(TestExecStubs.margin_account(self.account_id))
+
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(quantity),
+        )
+        fill = TestEventStubs.order_filled(
+            order=order,
+            instrument=AUDUSD_SIM,
+            account_id=self.account_id,
+            position_id=PositionId("P-1"),
+            last_px=Price.from_str(px),
+        )
+        position = Position(instrument=AUDUSD_SIM, fill=fill)
+        self.cache.add_position(position, OmsType.HEDGING)
+        return position
+
+    def _submit_order(self, order) -> SubmitOrder:
+        self.cache.add_order(order)
+        return SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=order.strategy_id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+    def test_next_close_ns_before_close_returns_same_day(self):
+        # Arrange
+        rollover = self._create_rollover()
+
+        # Act
+        result = rollover.next_close_ns(SIM, 0)
+
+        # Assert
+        assert result == 21 * HOUR_NS
+
+    def test_next_close_ns_at_close_returns_next_day(self):
+        # Arrange
+        rollover = self._create_rollover()
+
+        # Act
+        result = rollover.next_close_ns(SIM, 21 * HOUR_NS)
+
+        # Assert
+        assert result == DAY_NS + 21 * HOUR_NS
+
+    def test_next_close_ns_with_timezone(self):
+        # Arrange
+        rollover = self._create_rollover(timezone="Asia/Tokyo")  # UTC+9
+
+        # Act
+        result = rollover.next_close_ns(SIM, 0)
+
+        # Assert
+        assert result == 12 * HOUR_NS
+
+    def test_rollover_marks_margin_position_to_settlement_and_realizes_variation(self):
+        # Arrange
+        rollover = self._create_rollover()
+        position = self._open_position(100_000, "0.80000")
+        rollover.set_settlement_price(AUDUSD_SIM.id, Price.from_str("0.80100"))
+
+        # Act
+        report = rollover.rollover(SIM, ts_event=21 * HOUR_NS)
+
+        # Assert
+        assert report.session_date == date(1970, 1, 1)
+        assert len(report.settlements) == 1
+        assert report.settlements[0].position_id == position.id
+        assert report.settlements[0].settlement_price == Price.from_str("0.80100")
+        assert report.settlements[0].unrealized_pnl == Money(100.00, USD)
+        # Session PnL includes the opening commission (realized on the position)
+        expected_pnl = 100.00 + position.realized_pnl.as_double()
+        assert report.daily_pnls[USD].as_double() == pytest.approx(expected_pnl)
+        assert report.realized_pnls[USD].as_double() == pytest.approx(expected_pnl)
+        assert self.reports == [report]
+
+    def test_rollover_daily_pnl_is_relative_to_previous_settlement(self):
+        # Arrange
+        rollover = self._create_rollover()
+        self._open_position(100_000, "0.80000")
+        rollover.set_settlement_price(AUDUSD_SIM.id, Price.from_str("0.80100"))
+        rollover.rollover(SIM, ts_event=21 * HOUR_NS)
+        rollover.set_settlement_price(AUDUSD_SIM.id, Price.from_str("0.80050"))
+
+        # Act
+        report = rollover.rollover(SIM, ts_event=DAY_NS + 21 * HOUR_NS)
+
+        # Assert
+        assert report.session_date == date(1970, 1, 2)
+        assert report.settlements[0].unrealized_pnl == Money(50.00, USD)
+        assert report.daily_pnls == {USD: Money(-50.00, USD)}
+        assert report.realized_pnls == {USD: Money(-50.00, USD)}
+
+    def test_rollover_without_settlement_price_skips_position(self):
+        # Arrange
+        rollover = self._create_rollover()
+        self._open_position(100_000, "0.80000")
+
+        # Act
+        report = rollover.rollover(SIM)
+
+        # Assert
+        assert report.settlements == []
+        assert report.daily_pnls == {}
+
+    def test_rollover_resets_daily_statistics_and_calls_reset_handlers(self):
+        # Arrange
+        rollover = self._create_rollover()
+        reset_venues: list[Venue] = []
+        rollover.register_reset_handler(reset_venues.append)
+        rollover.start()
+
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        fill = TestEventStubs.order_filled(order=order, instrument=AUDUSD_SIM)
+        self.msgbus.publish(topic=f"events.order.{order.strategy_id}", msg=fill)
+
+        # Act
+        report = rollover.rollover(SIM)
+
+        # Assert
+        assert report.fill_count == 1
+        assert report.commissions == {USD: fill.commission}
+        assert rollover.fill_count(SIM) == 0
+        assert reset_venues == [SIM]
+
+    def test_rollover_resets_portfolio_daily_pnls(self):
+        # Arrange
+        rollover = self._create_rollover()
+        position = self._open_position(100_000, "0.80000")
+        daily_pnl_before = self.portfolio.daily_pnls(SIM)
+
+        # Act
+        rollover.rollover(SIM)
+
+        # Assert
+        assert daily_pnl_before == {USD: position.realized_pnl}
+        assert self.portfolio.daily_pnls(SIM) == {USD: Money(0, USD)}
+
+    def test_rollover_resets_risk_engine_daily_limits(self):
+        # Arrange
+        ExecutionEngine(msgbus=self.msgbus, cache=self.cache, clock=self.clock)
+        risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=RiskEngineConfig(max_daily_loss_per_venue={"SIM": "0.01 USD"}),
+        )
+        rollover = self._create_rollover()
+        self._open_position(100_000, "0.80000")  # Opening commission breaches the limit
+
+        denied_order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        risk_engine.execute(self._submit_order(denied_order))
+
+        # Act
+        rollover.rollover(SIM)
+
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        risk_engine.execute(self._submit_order(order))
+
+        # Assert
+        assert denied_order.status == OrderStatus.DENIED
+        assert order.status == OrderStatus.INITIALIZED
+
+    def test_rollover_at_close_time_publishes_report_and_reschedules(self):
+        # Arrange
+        rollover = self._create_rollover()
+        rollover.start()
+
+        # Act
+        events = self.clock.advance_time(21 * HOUR_NS)
+        for event in events:
+            event.handle()
+
+        # Assert
+        assert len(self.reports) == 1
+        assert self.reports[0].venue == SIM
+        assert self.reports[0].ts_event == 21 * HOUR_NS
+        assert f"{rollover.id}-EOD-SIM" in self.clock.timer_names
+
+    def test_stop_cancels_scheduled_rollovers(self):
+        # Arrange
+        rollover = self._create_rollover()
+        rollover.start()
+
+        # Act
+        rollover.stop()
+
+        # Assert
+        assert self.clock.timer_names == []
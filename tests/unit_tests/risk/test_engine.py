@@ -20,6 +20,7 @@ import pytest
 
 from nautilus_trader.common.component import MessageBus
 from nautilus_trader.common.component import TestClock
+from nautilus_trader.common.messages import SetInstrumentTrading
 from nautilus_trader.common.messages import TradingStateChanged
 from nautilus_trader.config import ExecEngineConfig
 from nautilus_trader.config import RiskEngineConfig
@@ -38,6 +39,7 @@ from nautilus_trader.model.currencies import USD
 from nautilus_trader.model.currencies import USDT
 from nautilus_trader.model.data import QuoteTick
 from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import OmsType
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.enums import OrderStatus
 from nautilus_trader.model.enums import TradingState
@@ -58,6 +60,7 @@ from nautilus_trader.model.objects import Money
 from nautilus_trader.model.objects import Price
 from nautilus_trader.model.objects import Quantity
 from nautilus_trader.model.orders.list import OrderList
+from nautilus_trader.model.position import Position
 from nautilus_trader.portfolio.portfolio import Portfolio
 from nautilus_trader.risk.engine import RiskEngine
 from nautilus_trader.test_kit.mocks.exec_clients import MockExecutionClient
@@ -162,6 +165,142 @@ class TestRiskEngineWithCashAccount:
         assert risk_engine.max_notionals_per_order() == {_GBPUSD_SIM.id: Decimal("2000000")}
         assert risk_engine.max_notional_per_order(_GBPUSD_SIM.id) == 2_000_000
 
+    def test_config_risk_engine_with_max_position_age(self):
+        # Arrange
+        self.msgbus.deregister("RiskEngine.execute", self.risk_engine.execute)
+        self.msgbus.deregister("RiskEngine.process", self.risk_engine.process)
+
+        config = RiskEngineConfig(
+            max_position_age_per_strategy={"S-001": "01:00:00"},
+            auto_flatten_aged_positions=True,
+        )
+
+        # Act
+        risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=config,
+        )
+
+        # Assert
+        assert risk_engine.auto_flatten_aged_positions
+        assert risk_engine.max_position_ages() == {StrategyId("S-001"): timedelta(hours=1)}
+        assert risk_engine.max_position_age(StrategyId("S-001")) == timedelta(hours=1)
+
+    def test_set_max_position_age_with_none_removes_setting(self):
+        # Arrange
+        strategy_id = StrategyId("S-001")
+        self.risk_engine.set_max_position_age(strategy_id, timedelta(minutes=5))
+
+        # Act
+        self.risk_engine.set_max_position_age(strategy_id, None)
+
+        # Assert
+        assert self.risk_engine.max_position_age(strategy_id) is None
+        assert self.risk_engine.max_position_ages() == {}
+
+    def test_set_max_position_age_when_not_positive_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            self.risk_engine.set_max_position_age(StrategyId("S-001"), timedelta(0))
+
+    def test_position_age_timer_set_on_start_and_cancelled_on_stop(self):
+        # Arrange
+        self.risk_engine.set_max_position_age(StrategyId("S-001"), timedelta(minutes=5))
+
+        # Act
+        self.risk_engine.start()
+        timer_names_running = self.clock.timer_names
+        self.risk_engine.stop()
+
+        # Assert
+        assert self.risk_engine.position_age_timer_name in timer_names_running
+        assert self.risk_engine.position_age_timer_name not in self.clock.timer_names
+
+    def test_check_position_ages_when_within_max_age_returns_empty_list(self):
+        # Arrange
+        position = self._open_position()
+        self.risk_engine.set_max_position_age(position.strategy_id, timedelta(minutes=5))
+        self.clock.set_time(60_000_000_000)  # 1 minute
+
+        # Act
+        result = self.risk_engine.check_position_ages()
+
+        # Assert
+        assert result == []
+
+    def test_check_position_ages_when_exceeded_returns_aged_positions(self):
+        # Arrange
+        self.exec_engine.start()
+        position = self._open_position()
+        self.risk_engine.set_max_position_age(position.strategy_id, timedelta(minutes=5))
+        self.clock.set_time(600_000_000_000)  # 10 minutes
+
+        # Act
+        result = self.risk_engine.check_position_ages()
+
+        # Assert
+        assert result == [position]
+        assert "submit_order" not in self.exec_client.calls  # Auto-flatten disabled
+
+    def test_check_position_ages_with_auto_flatten_submits_single_reduce_only_order(self):
+        # Arrange
+        self.exec_engine.start()
+        self.msgbus.deregister("RiskEngine.execute", self.risk_engine.execute)
+        self.msgbus.deregister("RiskEngine.process", self.risk_engine.process)
+
+        risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=RiskEngineConfig(auto_flatten_aged_positions=True),
+        )
+
+        position = self._open_position()
+        risk_engine.set_max_position_age(position.strategy_id, timedelta(minutes=5))
+        self.clock.set_time(600_000_000_000)  # 10 minutes
+
+        # Act
+        risk_engine.check_position_ages()
+        risk_engine.check_position_ages()  # Flattening order still in flight
+
+        # Assert
+        assert self.exec_client.calls.count("submit_order") == 1
+        order = self.cache.orders()[0]
+        assert order.side == OrderSide.SELL
+        assert order.quantity == position.quantity
+        assert order.is_reduce_only
+        assert order.tags == ["MAX_POSITION_AGE"]
+        assert self.cache.position_id(order.client_order_id) == position.id
+
+    def _open_position(self) -> Position:
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.market(
+            _AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        fill = TestEventStubs.order_filled(
+            order,
+            instrument=_AUDUSD_SIM,
+            position_id=PositionId("P-1"),
+        )
+
+        position = Position(instrument=_AUDUSD_SIM, fill=fill)
+        self.cache.add_position(position, OmsType.NETTING)
+        return position
+
     def test_risk_engine_on_stop(self):
         # Arrange, Act
         self.risk_engine.start()
@@ -245,6 +384,13 @@ class TestRiskEngineWithCashAccount:
         assert max_notionals == {_AUDUSD_SIM.id: Decimal("1000000")}
         assert max_notional == Decimal(1_000_000)
 
+    def test_set_max_daily_loss_changes_setting(self):
+        # Arrange, Act
+        self.risk_engine.set_max_daily_loss(Venue("SIM"), Money(1_000, USD))
+
+        # Assert
+        assert self.risk_engine.max_daily_loss(Venue("SIM")) == Money(1_000, USD)
+
     def test_given_random_command_then_logs_and_continues(self):
         # Arrange
         random = TradingCommand(
@@ -1389,6 +1535,156 @@ class TestRiskEngineWithCashAccount:
         assert order.status == OrderStatus.DENIED
         assert self.risk_engine.command_count == 1  # <-- Command never reaches engine
 
+    def test_set_instrument_trading_disabled_tracks_flag_in_cache(self):
+        # Arrange, Act
+        self.risk_engine.set_instrument_trading(_AUDUSD_SIM.id, enabled=False)
+
+        # Assert
+        assert not self.cache.is_trading_enabled(_AUDUSD_SIM.id)
+        assert self.cache.is_trading_enabled(_GBPUSD_SIM.id)
+        assert self.cache.trading_disabled_instrument_ids() == [_AUDUSD_SIM.id]
+
+    def test_set_instrument_trading_when_no_change_logs_warning(self):
+        # Arrange, Act
+        self.risk_engine.set_instrument_trading(_AUDUSD_SIM.id, enabled=True)
+
+        # Assert
+        assert self.cache.is_trading_enabled(_AUDUSD_SIM.id)
+        assert self.cache.trading_disabled_instrument_ids() == []
+
+    def test_submit_order_when_instrument_trading_disabled_then_denies_order(self):
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.market(
+            _AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        command = SetInstrumentTrading(
+            trader_id=self.trader_id,
+            instrument_id=_AUDUSD_SIM.id,
+            enabled=False,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            reason="Venue incident",
+        )
+        self.risk_engine.execute(command)
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order.status == OrderStatus.DENIED
+        assert self.exec_engine.command_count == 0
+
+    def test_submit_order_when_instrument_trading_reenabled_then_sends_to_client(self):
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.market(
+            _AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        self.risk_engine.set_instrument_trading(_AUDUSD_SIM.id, enabled=False)
+        self.risk_engine.set_instrument_trading(_AUDUSD_SIM.id, enabled=True)
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert self.exec_engine.command_count == 1
+        assert self.exec_client.calls == ["_start", "submit_order"]
+
+    def test_set_instrument_trading_disabled_with_cancel_orders_cancels_open_orders(self):
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.limit(
+            _AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00000"),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        self.risk_engine.execute(submit_order)
+        self.exec_engine.process(TestEventStubs.order_submitted(order))
+        self.exec_engine.process(TestEventStubs.order_accepted(order))
+
+        command = SetInstrumentTrading(
+            trader_id=self.trader_id,
+            instrument_id=_AUDUSD_SIM.id,
+            enabled=False,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            cancel_orders=True,
+        )
+
+        # Act
+        self.risk_engine.execute(command)
+
+        # Assert
+        assert not self.cache.is_trading_enabled(_AUDUSD_SIM.id)
+        assert self.exec_engine.command_count == 2
+        assert self.exec_client.calls == ["_start", "submit_order", "cancel_all_orders"]
+
     def test_submit_order_beyond_rate_limit_then_denies_order(self):
         # Arrange
         self.exec_engine.start()